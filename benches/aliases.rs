@@ -0,0 +1,35 @@
+//! Benchmarks the alias lookup of `parse_alias`.
+//!
+//! The lookup backend is selected by the features, thus compare the runs of:
+//!
+//! ```sh
+//! cargo bench --bench aliases --no-default-features  # binary search in the sorted slice
+//! cargo bench --bench aliases                        # hash map (`hash-tables`)
+//! cargo bench --bench aliases --features fst         # finite-state transducer (`fst`)
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn lookup_aliases(c: &mut Criterion) {
+    let aliases: Vec<String> = emojic::flat::ALL_ALIASES
+        .iter()
+        .map(|alias| format!(":{}:", alias))
+        .collect();
+
+    // Also builds the hash maps, if any, before measuring
+    assert!(aliases.iter().all(|a| emojic::parse_alias(a).is_some()));
+
+    c.bench_function("parse_alias all", |b| {
+        b.iter(|| {
+            for alias in &aliases {
+                black_box(emojic::parse_alias(black_box(alias)));
+            }
+        })
+    });
+    c.bench_function("parse_alias unknown", |b| {
+        b.iter(|| emojic::parse_alias(black_box(":not_an_emoji_alias:")))
+    });
+}
+
+criterion_group!(benches, lookup_aliases);
+criterion_main!(benches);
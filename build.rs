@@ -0,0 +1,46 @@
+//! Selects the emoji groups to be compiled into the crate.
+//!
+//! Each group (i.e. module of `grouped`) is included if its `group-*` feature is enabled. If none
+//! of these features is enabled, all groups are included. The selected groups are passed to the
+//! crate as `emojic_group` cfg, which is used by the generated modules.
+
+/// The identifiers of all groups, their feature is the same in kebab-case with a `group-` prefix
+const GROUPS: &[&str] = &[
+    "activities",
+    "animals_and_nature",
+    "component",
+    "flags",
+    "food_and_drink",
+    "objects",
+    "people_and_body",
+    "smileys_and_emotion",
+    "symbols",
+    "travel_and_places",
+];
+
+fn main() {
+    let selected: Vec<&str> = GROUPS
+        .iter()
+        .copied()
+        .filter(|group| {
+            let var = format!("CARGO_FEATURE_GROUP_{}", group.to_ascii_uppercase());
+            std::env::var_os(var).is_some()
+        })
+        .collect();
+
+    // Without any group feature, all groups are included
+    let groups = if selected.is_empty() {
+        GROUPS
+    } else {
+        &selected[..]
+    };
+
+    let values: Vec<String> = GROUPS.iter().map(|g| format!("{:?}", g)).collect();
+    println!(
+        "cargo:rustc-check-cfg=cfg(emojic_group, values({}))",
+        values.join(", ")
+    );
+    for group in groups {
+        println!("cargo:rustc-cfg=emojic_group={:?}", group);
+    }
+}
@@ -2,13 +2,13 @@ use super::strutil::*;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashMap;
-use std::num::ParseIntError;
 use std::str::FromStr;
 use std::string::ToString;
 
 // Use the same attribute definition as in the public API of the main crate:
 #[path = "../../src/emojis/attributes.rs"]
 mod attributes;
+pub use attributes::Version;
 use attributes::*;
 
 mod people;
@@ -17,7 +17,11 @@ use people::*;
 lazy_static! {
     /// Parses lines form the unicode Emoji test list as specified in [`crate::EMOJI_URL`]
     ///
-    /// Should be compatible with Emoji 12.0, 12.1, 13.0, and 13.1
+    /// Should be compatible with Emoji 12.0, 12.1, 13.0, 13.1, 14.0, and 15.0, which all use the
+    /// same line format (the status is one of `component`, `fully-qualified`,
+    /// `minimally-qualified`, and `unqualified`, of which only the first three are used).
+    /// The minimally-qualified lines only provide the alternate graphemes of the
+    /// fully-qualified emojis of the same name.
     ///
     /// Sample input:
     /// ```
@@ -26,10 +30,11 @@ lazy_static! {
     ///
     /// Output:
     /// code: `1F3CC 1F3FB 200D 2642 FE0F`
+    /// status: `fully-qualified`
     /// version: `4.0`
     /// name: `man golfing: light skin tone`
     static ref REMOJI: Regex = Regex::new(
-        r"^(?m)\s*(?P<code>[A-Z\d ]+[A-Z\d])\s+;\s+(:?fully-qualified|component)\s+#\s+\S+\s+(?:E(?P<version>\d+\.\d+)\s+)?(?P<name>.+)$"
+        r"^(?m)\s*(?P<code>[A-Z\d ]+[A-Z\d])\s+;\s+(?P<status>fully-qualified|minimally-qualified|component)\s+#\s+\S+\s+(?:E(?P<version>\d+\.\d+)\s+)?(?P<name>.+)$"
     )
     .unwrap();
 
@@ -60,21 +65,6 @@ lazy_static! {
 
 }
 
-impl FromStr for Version {
-    type Err = ParseIntError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let [major, minor] = s.split('.').collect::<Vec<_>>().as_slice() {
-            Ok(Version(
-                FromStr::from_str(major)?,
-                FromStr::from_str(minor)?,
-            ))
-        } else {
-            panic!("Invalid version string format");
-        }
-    }
-}
-
 fn person_identifier(activity_pre: Option<&str>, activity_post: Option<&str>) -> String {
     format!(
         "{}person{}",
@@ -151,6 +141,8 @@ pub struct Subgroup {
     pub emojis: HashMap<String, Emoji>,
     pub constants: Vec<String>,
     pub person_emojis: HashMap<String, PersonEmoji>,
+    /// The minimally-qualified graphemes of the emojis which have one, by their name
+    pub minimally_qualified: HashMap<String, String>,
 }
 
 impl Subgroup {
@@ -161,6 +153,7 @@ impl Subgroup {
             emojis: HashMap::new(),
             constants: Vec::new(),
             person_emojis: HashMap::new(),
+            minimally_qualified: HashMap::new(),
         }
     }
     pub fn get_emoji(&self, identifier: &str) -> Option<&dyn ToSourceCode> {
@@ -258,7 +251,11 @@ impl Subgroup {
             entry.name
         );
     }
-    pub fn append_line(&mut self, line: &str) {
+    /// Parses the given line of the Emoji test list and adds its emoji to this subgroup.
+    ///
+    /// Emojis newer than `max_version` are skipped, so that the emojis of an older Emoji version
+    /// can be generated from the list of a newer one.
+    pub fn append_line(&mut self, line: &str, max_version: Version) {
         let line = line.trim();
         if line.is_empty() {
             return;
@@ -277,10 +274,20 @@ impl Subgroup {
                 .map(Result::ok)
                 .flatten()
                 .unwrap_or(Version(0, 0));
+            if version > max_version {
+                println!("Skipped: newer than Emoji {}", max_version);
+                return;
+            }
 
             let code = cap["code"].to_owned();
             let name = cap["name"].to_owned();
 
+            if &cap["status"] == "minimally-qualified" {
+                self.minimally_qualified
+                    .insert(name, generate_unicode(&code));
+                return;
+            }
+
             if let Some(pcap) = PERSON_WITH_ACTIVITY.captures(&name) {
                 println!(
 						"Found PERSON_WITH_ACTIVITY: {:?}&{:?} ({:?},{:?}|{:?},{:?}): ({:?},{:?}), {:?}",
@@ -471,6 +478,12 @@ impl ToSourceCode for Emoji {
             &self.grapheme,
         )]
     }
+    fn full_version_list(&self) -> Vec<Version> {
+        vec![self.since]
+    }
+    fn full_name_list(&self) -> Vec<&str> {
+        vec![&self.name]
+    }
 }
 
 /// Represents an emoji that can be turned into source code.
@@ -519,6 +532,22 @@ pub trait ToSourceCode: std::fmt::Debug {
     /// Returns a list of all addressable emojis as a set of const access string, public access
     /// string, and grapheme.
     fn full_emoji_list(&self) -> Vec<(String, String, &str)>;
+
+    /// Returns the versions of all addressable emojis, in the order of
+    /// [`full_emoji_list`](Self::full_emoji_list).
+    fn full_version_list(&self) -> Vec<Version>;
+
+    /// Returns the names of all addressable emojis, in the order of
+    /// [`full_emoji_list`](Self::full_emoji_list).
+    fn full_name_list(&self) -> Vec<&str>;
+
+    /// Returns the names of the attribute types this emoji can be customized by, from the
+    /// outermost to the innermost one (e.g. `["Gender", "Tone"]` for `With<Gender,With<Tone,Emoji>>`).
+    ///
+    /// Standalone emojis have none.
+    fn attribute_types(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
 }
 
 /// Returns a string containing the plain unicode grapheme as well as a list of the actual
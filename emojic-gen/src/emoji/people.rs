@@ -173,6 +173,35 @@ impl ToSourceCode for PersonEmoji {
             .map(|(const_acc, pub_acc, variant)| (const_acc, pub_acc, variant.grapheme.as_str()))
             .collect()
     }
+    fn full_version_list(&self) -> Vec<Version> {
+        if let Some(group) = &self.grouping {
+            group
+                .to_accessor_n_kind(&self.identifier)
+                .into_iter()
+                .map(|(_, _, kind)| self.variants[&kind].since)
+                .collect()
+        } else {
+            panic!("PersonEmoji must be scrubbed before it can be rendered!")
+        }
+    }
+    fn full_name_list(&self) -> Vec<&str> {
+        if let Some(group) = &self.grouping {
+            group
+                .to_accessor_n_kind(&self.identifier)
+                .into_iter()
+                .map(|(_, _, kind)| self.variants[&kind].full_name.as_str())
+                .collect()
+        } else {
+            panic!("PersonEmoji must be scrubbed before it can be rendered!")
+        }
+    }
+    fn attribute_types(&self) -> Vec<&'static str> {
+        if let Some(group) = &self.grouping {
+            group.attribute_types()
+        } else {
+            panic!("PersonEmoji must be scrubbed before it can be rendered!")
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -4,21 +4,6 @@
 
 use super::*;
 
-impl FromStr for Tone {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "light" => Ok(Self::Light),
-            "medium-light" => Ok(Self::MediumLight),
-            "medium" => Ok(Self::Medium),
-            "medium-dark" => Ok(Self::MediumDark),
-            "dark" => Ok(Self::Dark),
-            _ => Err(()),
-        }
-    }
-}
-
 pub struct ParsedOneOrTwo(pub Option<OneOrTwo>);
 impl From<Option<OneOrTwo>> for ParsedOneOrTwo {
     fn from(o: Option<OneOrTwo>) -> Self {
@@ -98,19 +83,3 @@ impl FromStr for ParsedOneOrTwoChildren {
         }
     }
 }
-
-impl FromStr for Hair {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "beard" => Ok(Self::Beard),
-            "blond" => Ok(Self::Blond),
-            "red" => Ok(Self::Red),
-            "curly" => Ok(Self::Curly),
-            "white" => Ok(Self::White),
-            "bald" => Ok(Self::Bald),
-            _ => Err(()),
-        }
-    }
-}
@@ -442,6 +442,26 @@ impl PersonQualified {
         self.to_accessor_n_kind_internal(identifier, identifier)
     }
 
+    /// Returns the attribute type names of the nested `With`s, from the outermost to the
+    /// innermost one (e.g. `["Gender", "Tone"]` for `With<Gender,With<Tone,Emoji>>`).
+    pub fn attribute_types(&self) -> Vec<&'static str> {
+        match self {
+            Self::Node(n) => {
+                // All sub types are equal, see `to_type_n_value`
+                let inner = n
+                    .def
+                    .as_deref()
+                    .or_else(|| n.subs.first().map(|(_, _, sub)| sub));
+                let mut types = vec![n.type_name];
+                if let Some(inner) = inner {
+                    types.extend(inner.attribute_types());
+                }
+                types
+            }
+            Self::Leaf(_) => Vec::new(),
+        }
+    }
+
     fn to_accessor_n_kind_internal(
         &self,
         const_accessor: &str,
@@ -1,4 +1,5 @@
 use crate::strutil::fetch_data;
+use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -13,15 +14,20 @@ struct Gemoji {
     ios_version: String,
 }
 
-pub const GEMOJI_URL: &'static str =
-    "https://raw.githubusercontent.com/github/gemoji/master/db/emoji.json";
+lazy_static! {
+    /// The gemoji database, which can be given as URL or local path by the `GEMOJI_URL`
+    /// environment variable
+    pub static ref GEMOJI_URL: String = std::env::var("GEMOJI_URL").unwrap_or_else(|_| {
+        "https://raw.githubusercontent.com/github/gemoji/master/db/emoji.json".to_owned()
+    });
+}
 
 pub fn make_alias(name: &str) -> String {
     format!("{}", name)
 }
 
 pub fn fetch_gemoji() -> HashMap<String, String> {
-    let content = fetch_data(GEMOJI_URL).unwrap();
+    let content = fetch_data(GEMOJI_URL.as_str()).unwrap();
     //dbg!(std::str::from_utf8(&content[..]).unwrap());
     let gemojis: Vec<Gemoji> =
         serde_json::from_str(std::str::from_utf8(&content[..]).unwrap()).unwrap();
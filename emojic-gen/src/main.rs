@@ -8,6 +8,7 @@ mod strutil;
 use emoji::Emojis;
 use emoji::Group;
 use emoji::Subgroup;
+use emoji::Version;
 use inflections::case::to_snake_case;
 use lazy_static::lazy_static;
 use serde::Serialize;
@@ -15,6 +16,7 @@ use std::fmt;
 use std::fs::File;
 use std::{
     collections::BTreeMap,
+    collections::BTreeSet,
     collections::HashMap,
     io::{BufRead, BufReader, Write},
 };
@@ -23,9 +25,46 @@ use std::{
 use tera::Context;
 use tera::Tera;
 
-const EMOJI_URL: &str = "https://unicode.org/Public/emoji/13.1/emoji-test.txt";
+/// The Unicode Emoji version to generate if none is given
+const DEFAULT_EMOJI_VERSION: &str = "14.0";
 
 lazy_static! {
+    /// The Unicode Emoji version to generate the emojis for.
+    ///
+    /// It is taken from the first command line argument, or else from the `EMOJI_VERSION`
+    /// environment variable, and defaults to [`DEFAULT_EMOJI_VERSION`].
+    /// E.g. `cargo run -- 15.0` or `EMOJI_VERSION=14.0 cargo run`.
+    static ref EMOJI_VERSION: String = {
+        let version = std::env::args()
+            .nth(1)
+            .or_else(|| std::env::var("EMOJI_VERSION").ok())
+            .unwrap_or_else(|| DEFAULT_EMOJI_VERSION.to_owned());
+        if let Err(e) = version.parse::<emoji::Version>() {
+            println!("Invalid Emoji version {:?}: {}", version, e);
+            ::std::process::exit(1);
+        }
+        version
+    };
+
+    /// The Unicode Emoji test list, by default the one of the [`EMOJI_VERSION`].
+    ///
+    /// Another list can be given as URL or local path by the `EMOJI_TEST_URL` environment
+    /// variable, e.g. the list of a newer version, of which only the emojis up to the
+    /// [`EMOJI_VERSION`] are generated.
+    static ref EMOJI_URL: String = std::env::var("EMOJI_TEST_URL").unwrap_or_else(|_| {
+        format!(
+            "https://unicode.org/Public/emoji/{}/emoji-test.txt",
+            *EMOJI_VERSION
+        )
+    });
+
+    /// The source of the emojis as noted in the generated files
+    static ref EMOJI_SOURCE: String = if std::env::var("EMOJI_TEST_URL").is_ok() {
+        format!("{} (up to E{})", *EMOJI_URL, *EMOJI_VERSION)
+    } else {
+        EMOJI_URL.clone()
+    };
+
     static ref TEMPLATES: Tera = {
         let mut tera = match Tera::new("templates/**/*.tpl") {
             Ok(t) => t,
@@ -48,7 +87,7 @@ impl fmt::Display for Emojik {
 }
 
 fn main() {
-    println!("Fetching...");
+    println!("Fetching Emoji {}...", *EMOJI_VERSION);
     //let emoji_text = strutil::fetch_data(EMOJI_URL);
     let a = gemoji::fetch_gemoji();
     let mut e = fetch_emojis().unwrap();
@@ -57,13 +96,34 @@ fn main() {
     println!("Sorting...");
     e.sort();
 
+    let aliases = generate_aliases(&mut e, &a);
+    let names = generate_all_names(&e);
+
     let constants = generate_constants(&e);
-    save_flat_constants(&constants);
+    save_flat_constants(&constants, &names, &aliases.names);
     save_grouped_constants(&constants);
+    save_group_ids(&constants);
+
+    // The very same aliases go into the hash map, the sorted slice, and the FST, thus all aliases
+    // are available regardless of the `hash-tables` and `fst` features
+    save_aliasses(&aliases.entries);
+    save_matcher(&aliases.entries);
+    save_alias_fst(&aliases);
+
+    let regex = generate_regex(&e);
+    save_regex(regex);
+
+    let starts = generate_starts(&e);
+    save_starts(starts);
 
-    let (map_alias, match_aliases) = generate_aliases(&mut e, &a);
-    save_aliasses(map_alias);
-    save_big_matcher(match_aliases);
+    let versions = generate_versions(&e);
+    save_versions(versions);
+
+    let supports = generate_supports(&e);
+    save_supports(supports);
+
+    let qualified = generate_minimally_qualified(&e);
+    save_minimally_qualified(qualified);
 }
 
 fn read_lines<'a>(content: &Vec<u8>, mut f: impl FnMut(&mut str) -> ()) {
@@ -77,7 +137,8 @@ fn read_lines<'a>(content: &Vec<u8>, mut f: impl FnMut(&mut str) -> ()) {
 }
 
 fn fetch_emojis() -> Result<Emojis, String> {
-    let emoji_text = strutil::fetch_data(EMOJI_URL);
+    let emoji_text = strutil::fetch_data(EMOJI_URL.as_str());
+    let max_version: Version = EMOJI_VERSION.parse().unwrap();
 
     let mut emojis: Emojis = Emojis::default();
     let mut current_group: String = String::new();
@@ -88,6 +149,15 @@ fn fetch_emojis() -> Result<Emojis, String> {
         println!("Process: {:?}", line);
         if line.is_empty() {
             // Just ignore it
+        } else if line.starts_with("# Version:") {
+            let version = line.replace("# Version:", "").trim().to_owned();
+            if version != *EMOJI_VERSION {
+                println!(
+                    "Warning: expected Emoji {} but got the list of Emoji {}, emojis newer than \
+                     Emoji {} are skipped",
+                    *EMOJI_VERSION, version, *EMOJI_VERSION
+                );
+            }
         } else if line.starts_with("# group:") {
             let name = line.replace("# group:", "").trim().to_owned();
             emojis.append(name.to_owned()).unwrap();
@@ -107,7 +177,7 @@ fn fetch_emojis() -> Result<Emojis, String> {
                 .unwrap()
                 .get_subgroup(&current_sub_group)
                 .unwrap()
-                .append_line(line);
+                .append_line(line, max_version);
         }
     });
     Ok(emojis)
@@ -136,6 +206,7 @@ fn emojis_for_group(grp: &Group) -> String {
 
 #[derive(Debug, Clone, Serialize)]
 struct GroupedConstant<'a> {
+    pub name: &'a str,
     pub identifier: &'a str,
     pub preview_emojis: String,
     pub subgroups: Vec<SubgroupConstant<'a>>,
@@ -143,6 +214,7 @@ struct GroupedConstant<'a> {
 
 #[derive(Debug, Clone, Serialize)]
 struct SubgroupConstant<'a> {
+    pub name: &'a str,
     pub identifier: &'a str,
     pub preview_emojis: String,
     pub emojis: Vec<EmojiConstant<'a>>,
@@ -196,6 +268,7 @@ fn generate_constants(e: &Emojis) -> Vec<GroupedConstant> {
                         .collect();
 
                     SubgroupConstant {
+                        name: &s.name,
                         identifier: &s.identifier,
                         preview_emojis: emojis_for_subgroup(s),
                         emojis,
@@ -204,6 +277,7 @@ fn generate_constants(e: &Emojis) -> Vec<GroupedConstant> {
                 .collect();
 
             GroupedConstant {
+                name: &g.name,
                 identifier: &g.identifier,
                 preview_emojis: emojis_for_group(g),
                 subgroups,
@@ -212,15 +286,28 @@ fn generate_constants(e: &Emojis) -> Vec<GroupedConstant> {
         .collect()
 }
 
-fn generate_aliases(
-    emoji: &mut Emojis,
-    gemojis: &HashMap<String, String>,
-) -> (String, (String, String)) {
+/// The generated aliases of all emojis
+struct Aliases {
+    /// The entries of the alias map (and sorted slice)
+    entries: String,
+    /// The entries of the sorted slice of just the aliases
+    names: String,
+    /// The entries of the emoji table of the alias FST, in the order of `sorted`
+    fst_emojis: String,
+    /// All aliases, sorted
+    sorted: Vec<String>,
+}
+
+/// Generates the aliases of all emojis, see [`Aliases`].
+fn generate_aliases(emoji: &mut Emojis, gemojis: &HashMap<String, String>) -> Aliases {
     let mut aliasses: Vec<String> = Vec::new();
     let mut emoji_map: HashMap<String, String> = HashMap::new();
     let mut emoji_map_by_grapheme: HashMap<String, String> = HashMap::new();
+    // The group module of each constant, to include the alias only with that group
+    let mut group_by_accessor: HashMap<String, String> = HashMap::new();
 
     emoji.groups.iter_mut().for_each(|g| {
+        let group = g.identifier.clone();
         g.subgroups.iter_mut().for_each(|s| {
             s.constants.iter().for_each(|c| {
                 let em = s.get_emoji(c).unwrap();
@@ -228,6 +315,7 @@ fn generate_aliases(
 
                 // Add the graphemes of all variants
                 for (const_accessor, pub_accessor, grapheme) in em.full_emoji_list() {
+                    group_by_accessor.insert(const_accessor.clone(), group.clone());
                     emoji_map_by_grapheme.insert(grapheme.to_string(), const_accessor);
                 }
 
@@ -253,86 +341,217 @@ fn generate_aliases(
 
     aliasses[..].sort();
 
+    // The text parser only recognizes these chars within colons, and the sorted slice is
+    // binary searched by the (case-sensitive) bytes of the alias
+    for alias in &aliasses {
+        assert!(
+            alias
+                .chars()
+                .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_' | '+' | '-')),
+            "Unsupported char in alias {:?}",
+            alias
+        );
+    }
+
     let map_aliasses = aliasses
         .iter()
         .map(|al| {
+            let accessor = emoji_map.get(al).unwrap();
             format!(
-                "(\"{}\" , &crate::flat::{} as &crate::Emoji),\n",
-                al,
-                emoji_map.get(al).unwrap()
+                "#[cfg(emojic_group = \"{}\")] (\"{}\" , &crate::flat::{} as &crate::Emoji),\n",
+                group_by_accessor[accessor], al, accessor
             )
         })
         .collect::<String>();
 
-    let match_aliasses = {
-        let mut single_bytes: BTreeMap<char, &str> = BTreeMap::new();
-        let mut two_byte_groups: BTreeMap<char, BTreeMap<char, Vec<&str>>> = BTreeMap::new();
-
-        for al in &aliasses {
-            let len = al.len();
-            if len == 0 {
-                panic!("Found an empty alias");
-            } else if len == 1 {
-                let first = al.chars().next().unwrap();
-                single_bytes.insert(first, &al);
-            } else {
-                let mut chars = al.chars();
-                let first = chars.next().unwrap();
-                let sec = chars.next().unwrap();
-
-                two_byte_groups
-                    .entry(first)
-                    .or_default()
-                    .entry(sec)
-                    .or_default()
-                    .push(&al);
-            }
-        }
+    let alias_names = aliasses
+        .iter()
+        .map(|al| {
+            let accessor = emoji_map.get(al).unwrap();
+            format!(
+                "\t#[cfg(emojic_group = \"{}\")] {:?},\n",
+                group_by_accessor[accessor], al
+            )
+        })
+        .collect::<String>();
 
-        let mut out_single = String::new();
-        for (first, al) in single_bytes {
-            out_single.push_str(&format!(
-                "\t\t\tb'{}' => Some(&crate::flat::{} as &crate::Emoji),\n",
-                first,
-                emoji_map.get(al).unwrap()
-            ));
+    // The FST maps each alias to its index, thus the emojis of disabled groups are `None`
+    let fst_emojis = aliasses
+        .iter()
+        .map(|al| {
+            let accessor = emoji_map.get(al).unwrap();
+            let group = &group_by_accessor[accessor];
+            format!(
+                "\t#[cfg(emojic_group = \"{}\")] Some(&crate::flat::{} as &crate::Emoji),\n\t#[cfg(not(emojic_group = \"{}\"))] None,\n",
+                group, accessor, group
+            )
+        })
+        .collect::<String>();
+
+    Aliases {
+        entries: map_aliasses,
+        names: alias_names,
+        fst_emojis,
+        sorted: aliasses,
+    }
+}
+
+/// Generates the entries of the sorted slice of the names of all emojis (including variants).
+fn generate_all_names(e: &Emojis) -> String {
+    let names: BTreeMap<&str, &str> = e
+        .groups
+        .iter()
+        .flat_map(|g| {
+            g.subgroups
+                .iter()
+                .flat_map(|s| s.emoji_iter())
+                .flat_map(|emoji| emoji.full_name_list())
+                .map(move |name| (name, g.identifier.as_str()))
+        })
+        .collect();
+
+    names
+        .into_iter()
+        .map(|(name, group)| format!("\t#[cfg(emojic_group = \"{}\")] {:?},\n", group, name))
+        .collect()
+}
+
+/// Generates a regex alternation of all emoji graphemes, the longest ones first.
+fn generate_regex(e: &Emojis) -> String {
+    let graphemes: BTreeSet<&str> = e
+        .groups
+        .iter()
+        .flat_map(|g| g.subgroups.iter())
+        .flat_map(|s| s.emoji_iter())
+        .flat_map(|emoji| emoji.full_emoji_list())
+        .map(|(_, _, grapheme)| grapheme)
+        .collect();
+
+    let mut graphemes: Vec<&str> = graphemes.into_iter().collect();
+    // Longest first, so a sequence is preferred over its parts (👨‍👩‍👧 over 👨)
+    graphemes.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+
+    graphemes
+        .into_iter()
+        .map(regex::escape)
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+/// Generates the entries of the sorted list of the first chars of all emoji graphemes.
+fn generate_starts(e: &Emojis) -> String {
+    let starts: BTreeSet<char> = e
+        .groups
+        .iter()
+        .flat_map(|g| g.subgroups.iter())
+        .flat_map(|s| s.emoji_iter())
+        .flat_map(|emoji| emoji.full_emoji_list())
+        .filter_map(|(_, _, grapheme)| grapheme.chars().next())
+        .collect();
+
+    starts
+        .into_iter()
+        .map(|c| format!("\t'\\u{{{:X}}}',\n", c as u32))
+        .collect()
+}
+
+/// Generates the entries of the histogram of the emoji versions, sorted by version.
+fn generate_versions(e: &Emojis) -> String {
+    let mut histogram: BTreeMap<Version, usize> = BTreeMap::new();
+    for version in e
+        .groups
+        .iter()
+        .flat_map(|g| g.subgroups.iter())
+        .flat_map(|s| s.emoji_iter())
+        .flat_map(|emoji| emoji.full_version_list())
+    {
+        *histogram.entry(version).or_default() += 1;
+    }
+
+    histogram
+        .into_iter()
+        .map(|(version, count)| format!("\t({:?}, {}),\n", version, count))
+        .collect()
+}
+
+/// Returns the flag of the customization that is provided by the given attribute type
+fn support_flag(attribute_type: &str) -> &'static str {
+    match attribute_type {
+        "Tone" | "TonePair" | "TonePairReduced" => "TONE",
+        "Gender" | "Pair" | "OneOrTwo" | "Family" => "GENDER",
+        "Hair" => "HAIR",
+        _ => panic!("Unknown attribute type {:?}", attribute_type),
+    }
+}
+
+/// Generates the entries of the table of the customizations supported by the emojis, sorted by
+/// grapheme, so it can be binary searched.
+///
+/// Every variant of an emoji gets the customizations of its constant, while standalone emojis
+/// are left out.
+fn generate_supports(e: &Emojis) -> String {
+    let mut supports: BTreeMap<String, String> = BTreeMap::new();
+    for emoji in e
+        .groups
+        .iter()
+        .flat_map(|g| g.subgroups.iter())
+        .flat_map(|s| s.emoji_iter())
+    {
+        let types = emoji.attribute_types();
+        if types.is_empty() {
+            continue;
         }
+        let flags = types
+            .iter()
+            .map(|ty| support_flag(ty))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        for (_, _, grapheme) in emoji.full_emoji_list() {
+            supports.insert(grapheme.to_string(), flags.clone());
+        }
+    }
 
-        let mut out_two = String::new();
-        for (first, grp) in two_byte_groups {
-            out_two.push_str(&format!("\t\t\tb'{}' => match sec {{\n", first));
-            for (sec, sub) in grp {
-                out_two.push_str(&format!("\t\t\t\tb'{}' => match rest {{\n", sec));
-                for al in sub {
-                    out_two.push_str(&format!(
-                        "\t\t\t\t\t{:?} => Some(&crate::flat::{} as &crate::Emoji),\n",
-                        &al[2..],
-                        emoji_map.get(al).unwrap()
-                    ));
+    supports
+        .into_iter()
+        .map(|(grapheme, flags)| format!("\t({:?}, {}),\n", grapheme, flags))
+        .collect()
+}
+
+/// Generates the entries of the table of the minimally-qualified graphemes, sorted by the
+/// fully-qualified grapheme, so it can be binary searched.
+///
+/// Both forms of an emoji have the same name in the emoji list.
+fn generate_minimally_qualified(e: &Emojis) -> String {
+    let mut qualified: BTreeMap<String, &String> = BTreeMap::new();
+    for sub in e.groups.iter().flat_map(|g| g.subgroups.iter()) {
+        for emoji in sub.emoji_iter() {
+            let graphemes = emoji.full_emoji_list().into_iter().map(|(_, _, g)| g);
+            for (grapheme, name) in graphemes.zip(emoji.full_name_list()) {
+                if let Some(minimal) = sub.minimally_qualified.get(name) {
+                    qualified.insert(grapheme.to_string(), minimal);
                 }
-                out_two.push_str("\t\t\t\t\t_ => None,\n");
-                out_two.push_str("\t\t\t\t},\n");
             }
-            out_two.push_str("\t\t\t\t_ => None,\n");
-            out_two.push_str("\t\t\t},\n");
         }
+    }
 
-        (out_single, out_two)
-    };
-
-    (map_aliasses, match_aliasses)
+    qualified
+        .into_iter()
+        .map(|(grapheme, minimal)| format!("\t({:?}, {:?}),\n", grapheme, minimal))
+        .collect()
 }
 
-fn save_flat_constants(constants: &[GroupedConstant]) {
+fn save_flat_constants(constants: &[GroupedConstant], names: &str, aliases: &str) {
     let mut context = Context::new();
 
     use chrono::{DateTime, Utc};
     let now: DateTime<Utc> = Utc::now();
 
     let today = format!("{}", now);
-    context.insert("Link", EMOJI_URL);
+    context.insert("Link", EMOJI_SOURCE.as_str());
     context.insert("Date", &today);
     context.insert("Constants", &constants);
+    context.insert("Names", names);
+    context.insert("Aliases", aliases);
 
     let bytes = TEMPLATES
         .render("flat.tpl", &context)
@@ -349,7 +568,7 @@ fn save_grouped_constants(constants: &[GroupedConstant]) {
     let now: DateTime<Utc> = Utc::now();
 
     let today = format!("{}", now);
-    context.insert("Link", EMOJI_URL);
+    context.insert("Link", EMOJI_SOURCE.as_str());
     context.insert("Date", &today);
     context.insert("Constants", &constants);
 
@@ -361,17 +580,36 @@ fn save_grouped_constants(constants: &[GroupedConstant]) {
         .write_all(bytes.as_bytes());
 }
 
-fn save_big_matcher(aliasses: (String, String)) {
+/// Saves the enums identifying the groups and subgroups
+fn save_group_ids(constants: &[GroupedConstant]) {
     let mut context = Context::new();
 
     use chrono::{DateTime, Utc};
     let now: DateTime<Utc> = Utc::now();
 
     let today = format!("{}", now);
-    context.insert("Link", EMOJI_URL);
+    context.insert("Link", EMOJI_SOURCE.as_str());
     context.insert("Date", &today);
-    context.insert("SingleBytes", &aliasses.0);
-    context.insert("TwoBytes", &aliasses.1);
+    context.insert("Constants", &constants);
+
+    let bytes = TEMPLATES
+        .render("group_ids.tpl", &context)
+        .expect("Failed to render group_ids");
+    File::create("./group_ids.rs")
+        .unwrap()
+        .write_all(bytes.as_bytes());
+}
+
+fn save_matcher(aliasses: &str) {
+    let mut context = Context::new();
+
+    use chrono::{DateTime, Utc};
+    let now: DateTime<Utc> = Utc::now();
+
+    let today = format!("{}", now);
+    context.insert("Link", gemoji::GEMOJI_URL.as_str());
+    context.insert("Date", &today);
+    context.insert("Data", aliasses);
 
     let bytes = TEMPLATES
         .render("matching.tpl", &context)
@@ -381,16 +619,45 @@ fn save_big_matcher(aliasses: (String, String)) {
         .write_all(bytes.as_bytes());
 }
 
-fn save_aliasses(aliasses: String) {
+/// Saves the FST mapping the aliases to their index, and the emoji table indexed by it
+fn save_alias_fst(aliases: &Aliases) {
+    let mut builder = fst::MapBuilder::memory();
+    for (idx, alias) in aliases.sorted.iter().enumerate() {
+        builder.insert(alias, idx as u64).unwrap();
+    }
+    File::create("./aliases.fst")
+        .unwrap()
+        .write_all(&builder.into_inner().unwrap())
+        .unwrap();
+
     let mut context = Context::new();
 
     use chrono::{DateTime, Utc};
     let now: DateTime<Utc> = Utc::now();
 
     let today = format!("{}", now);
-    context.insert("Link", gemoji::GEMOJI_URL);
+    context.insert("Link", gemoji::GEMOJI_URL.as_str());
     context.insert("Date", &today);
-    context.insert("Data", &aliasses);
+    context.insert("Data", &aliases.fst_emojis);
+
+    let bytes = TEMPLATES
+        .render("alias_fst.tpl", &context)
+        .expect("Failed to render alias_fst");
+    File::create("./alias_fst.rs")
+        .unwrap()
+        .write_all(bytes.as_bytes());
+}
+
+fn save_aliasses(aliasses: &str) {
+    let mut context = Context::new();
+
+    use chrono::{DateTime, Utc};
+    let now: DateTime<Utc> = Utc::now();
+
+    let today = format!("{}", now);
+    context.insert("Link", gemoji::GEMOJI_URL.as_str());
+    context.insert("Date", &today);
+    context.insert("Data", aliasses);
 
     let bytes = TEMPLATES
         .render("alias.tpl", &context)
@@ -399,3 +666,99 @@ fn save_aliasses(aliasses: String) {
         .unwrap()
         .write_all(bytes.as_bytes());
 }
+
+fn save_regex(regex: String) {
+    let mut context = Context::new();
+
+    use chrono::{DateTime, Utc};
+    let now: DateTime<Utc> = Utc::now();
+
+    let today = format!("{}", now);
+    context.insert("Link", EMOJI_SOURCE.as_str());
+    context.insert("Date", &today);
+    // Debug-format it, so it becomes a valid Rust string literal
+    context.insert("Regex", &format!("{:?}", regex));
+
+    let bytes = TEMPLATES
+        .render("regex.tpl", &context)
+        .expect("Failed to render regex");
+    File::create("./regex.rs")
+        .unwrap()
+        .write_all(bytes.as_bytes());
+}
+
+fn save_starts(starts: String) {
+    let mut context = Context::new();
+
+    use chrono::{DateTime, Utc};
+    let now: DateTime<Utc> = Utc::now();
+
+    let today = format!("{}", now);
+    context.insert("Link", EMOJI_SOURCE.as_str());
+    context.insert("Date", &today);
+    context.insert("Starts", &starts);
+
+    let bytes = TEMPLATES
+        .render("starts.tpl", &context)
+        .expect("Failed to render starts");
+    File::create("./starts.rs")
+        .unwrap()
+        .write_all(bytes.as_bytes());
+}
+
+fn save_versions(versions: String) {
+    let mut context = Context::new();
+
+    use chrono::{DateTime, Utc};
+    let now: DateTime<Utc> = Utc::now();
+
+    let today = format!("{}", now);
+    context.insert("Link", EMOJI_SOURCE.as_str());
+    context.insert("Date", &today);
+    context.insert("Versions", &versions);
+
+    let bytes = TEMPLATES
+        .render("versions.tpl", &context)
+        .expect("Failed to render versions");
+    File::create("./versions.rs")
+        .unwrap()
+        .write_all(bytes.as_bytes());
+}
+
+fn save_supports(supports: String) {
+    let mut context = Context::new();
+
+    use chrono::{DateTime, Utc};
+    let now: DateTime<Utc> = Utc::now();
+
+    let today = format!("{}", now);
+    context.insert("Link", EMOJI_SOURCE.as_str());
+    context.insert("Date", &today);
+    context.insert("Supports", &supports);
+
+    let bytes = TEMPLATES
+        .render("supports.tpl", &context)
+        .expect("Failed to render supports");
+    File::create("./supports.rs")
+        .unwrap()
+        .write_all(bytes.as_bytes());
+}
+
+fn save_minimally_qualified(qualified: String) {
+    let mut context = Context::new();
+
+    use chrono::{DateTime, Utc};
+    let now: DateTime<Utc> = Utc::now();
+
+    let today = format!("{}", now);
+    context.insert("Link", EMOJI_SOURCE.as_str());
+    context.insert("Date", &today);
+    context.insert("Qualified", &qualified);
+
+    let bytes = TEMPLATES
+        .render("qualified.tpl", &context)
+        .expect("Failed to render qualified");
+    File::create("./qualified.rs")
+        .unwrap()
+        .write_all(bytes.as_bytes());
+}
@@ -2,7 +2,6 @@ use inflections::case::to_snake_case;
 use lazy_static::lazy_static;
 use maplit::hashmap;
 use regex::Regex;
-use reqwest::IntoUrl;
 use std::collections::HashMap;
 use std::io::Read;
 
@@ -85,6 +84,7 @@ lazy_static! {
         "ù"=>    "u",
         "ú"=>    "u",
         "û"=>    "u",
+        "ü"=>    "u",
         "ý"=>    "y",
         "þ"=>    "b",
         "ÿ"=>    "y",
@@ -131,13 +131,18 @@ pub fn generate_module(s: &str) -> String {
     c
 }
 
-pub fn fetch_data<T: IntoUrl>(url: T) -> Result<Vec<u8>, reqwest::Error> {
+/// Fetches the data of the given URL, or reads it from a local file if it is no HTTP(S) URL.
+pub fn fetch_data(url: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Ok(std::fs::read(url)?);
+    }
+
     let mut res = reqwest::blocking::get(url)?;
     let mut body = Vec::new();
     res.read_to_end(&mut body).unwrap();
     if res.status().is_success() {
         Ok(body)
     } else {
-        res.error_for_status().map(|_| Vec::new())
+        Ok(res.error_for_status().map(|_| Vec::new())?)
     }
 }
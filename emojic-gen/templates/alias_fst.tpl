@@ -0,0 +1,30 @@
+// Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
+
+// Source: {{ Link }}
+// Created at: {{ Date }}
+
+#![allow(unused_imports)]
+
+use crate::Tone;
+use crate::Gender;
+use crate::Hair;
+use crate::Pair;
+use crate::emojis::OneOrTwo;
+use crate::emojis::TonePair;
+use crate::emojis::Family;
+
+/// The FST mapping all aliases (without colons) to their index into `EMOJIS`
+static ALIAS_FST: &[u8] = include_bytes!("aliases.fst");
+
+/// The emojis of the aliases, in the order of the sorted aliases, `None` for disabled groups
+static EMOJIS: &[Option<&crate::Emoji>] = &[
+{{ Data }}
+];
+
+/// Looks up the emoji of the given alias (without colons) in the FST
+pub(crate) fn lookup(s: &str) -> Option<&'static crate::Emoji> {
+	let map = fst::Map::new(ALIAS_FST).expect("Invalid alias FST");
+	map.get(s).and_then(|idx| EMOJIS[idx as usize])
+}
+
+// EOF
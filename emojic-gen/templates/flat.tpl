@@ -24,6 +24,7 @@
 	// begin {{ sub.identifier }} {{ sub.preview_emojis }}
 		{% for emoji in sub.emojis %}
 		// {{ emoji.identifier }} {{ emoji.preview_emojis }}
+		#[cfg(emojic_group = "{{ grp.identifier }}")]
 		#[doc(inline)]
 		pub use crate::grouped::{{ grp.identifier }}::{{ sub.identifier }}::{{ emoji.identifier }};
 		{% endfor %}
@@ -32,4 +33,69 @@
 // end {{ grp.identifier }}
 {% endfor %}
 
+/// The names of all emojis (including all variants), sorted so they can be binary searched.
+///
+/// These are the [`name`](crate::emojis::Emoji::name)s of all emojis, e.g. to build an index
+/// for autocompletion without the emojis themselves.
+///
+/// # Examples
+///
+/// ```rust
+/// use emojic::flat::ALL_NAMES;
+///
+/// assert!(ALL_NAMES.binary_search(&"waving hand: dark skin tone").is_ok());
+/// assert!(ALL_NAMES.windows(2).all(|w| w[0] < w[1]));
+/// ```
+pub static ALL_NAMES: &[&str] = &[
+{{ Names }}];
+
+/// All aliases (without colons) of all emojis, sorted so they can be binary searched.
+///
+/// These are the aliases as accepted by [`parse_alias`](crate::parse_alias), e.g. to build an
+/// index for autocompletion without the emojis themselves.
+///
+/// # Examples
+///
+/// ```rust
+/// use emojic::flat::ALL_ALIASES;
+///
+/// assert!(ALL_ALIASES.binary_search(&"+1").is_ok());
+/// assert!(ALL_ALIASES.windows(2).all(|w| w[0] < w[1]));
+/// ```
+pub static ALL_ALIASES: &[&str] = &[
+{{ Aliases }}];
+
+// The emojis of a range of versions, next to the constants
+#[doc(inline)]
+pub use crate::search::in_version_range;
+
+/// Component emojis, i.e. the bare skin tone and hair style modifiers.
+///
+/// These are the emojis of the [`crate::grouped::component`] group, which are usually applied
+/// via the attributes (such as [`Tone`](crate::Tone)) of the customizable emojis. However, they
+/// are useful to manually append a modifier to some non-standard sequence.
+///
+/// # Examples
+///
+/// ```rust
+/// use emojic::flat::components::LIGHT_SKIN_TONE;
+///
+/// let wave = format!("{}{}", emojic::flat::WAVING_HAND, LIGHT_SKIN_TONE);
+/// assert_eq!(wave, "👋🏻");
+/// ```
+#[cfg(emojic_group = "component")]
+pub mod components {
+	{% for grp in Constants %}{% if grp.identifier == "component" %}
+	{% for sub in grp.subgroups %}
+	// begin {{ sub.identifier }} {{ sub.preview_emojis }}
+		{% for emoji in sub.emojis %}
+		// {{ emoji.identifier }} {{ emoji.preview_emojis }}
+		#[doc(inline)]
+		pub use crate::grouped::component::{{ sub.identifier }}::{{ emoji.identifier }};
+		{% endfor %}
+	// end {{ sub.identifier }}
+	{% endfor %}
+	{% endif %}{% endfor %}
+}
+
 // EOF
\ No newline at end of file
@@ -0,0 +1,75 @@
+// Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
+
+// Source: {{ Link }}
+// Created at: {{ Date }}
+
+{% macro camel(identifier) %}{{ identifier | replace(from="_", to=" ") | title | replace(from=" ", to="") }}{% endmacro camel %}
+/// Identifies an emoji group, e.g. to `match` on the group of an emoji.
+///
+/// Unlike the [`Group`](crate::groups::Group) metadata, all groups are listed, regardless of the
+/// `group-*` features. The group of an emoji is returned by [`category_of`](crate::category_of).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GroupId {
+	{%- for grp in Constants %}
+	/// {{ grp.name }} {{ grp.preview_emojis }}
+	{{ self::camel(identifier=grp.identifier) }},
+	{%- endfor %}
+}
+impl GroupId {
+	/// All groups in the order of the [`grouped`](crate::grouped) module
+	pub const ALL: &'static [GroupId] = &[
+		{%- for grp in Constants %}
+		GroupId::{{ self::camel(identifier=grp.identifier) }},
+		{%- endfor %}
+	];
+
+	/// The Unicode name of this group, e.g. `"Smileys & Emotion"`
+	pub const fn name(self) -> &'static str {
+		match self {
+			{%- for grp in Constants %}
+			GroupId::{{ self::camel(identifier=grp.identifier) }} => "{{ grp.name }}",
+			{%- endfor %}
+		}
+	}
+}
+
+/// Identifies an emoji subgroup, e.g. to `match` on the subgroup of an emoji.
+///
+/// Unlike the [`Subgroup`](crate::groups::Subgroup) metadata, all subgroups are listed,
+/// regardless of the `group-*` features. The subgroup of an emoji is returned by
+/// [`category_of`](crate::category_of).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SubgroupId {
+	{%- for grp in Constants %}{% for sub in grp.subgroups %}
+	/// {{ sub.name }} {{ sub.preview_emojis }}
+	{{ self::camel(identifier=sub.identifier) }},
+	{%- endfor %}{% endfor %}
+}
+impl SubgroupId {
+	/// All subgroups in the order of the [`grouped`](crate::grouped) module
+	pub const ALL: &'static [SubgroupId] = &[
+		{%- for grp in Constants %}{% for sub in grp.subgroups %}
+		SubgroupId::{{ self::camel(identifier=sub.identifier) }},
+		{%- endfor %}{% endfor %}
+	];
+
+	/// The Unicode name of this subgroup, e.g. `"face-smiling"`
+	pub const fn name(self) -> &'static str {
+		match self {
+			{%- for grp in Constants %}{% for sub in grp.subgroups %}
+			SubgroupId::{{ self::camel(identifier=sub.identifier) }} => "{{ sub.name }}",
+			{%- endfor %}{% endfor %}
+		}
+	}
+
+	/// The group containing this subgroup
+	pub const fn group(self) -> GroupId {
+		match self {
+			{%- for grp in Constants %}{% for sub in grp.subgroups %}
+			SubgroupId::{{ self::camel(identifier=sub.identifier) }} => GroupId::{{ self::camel(identifier=grp.identifier) }},
+			{%- endfor %}{% endfor %}
+		}
+	}
+}
+
+// EOF
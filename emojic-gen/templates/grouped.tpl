@@ -19,12 +19,18 @@
 //! ```
 
 use crate::emojis::Emoji;
+use crate::groups::Group;
 
 
 {% for grp in Constants %}
 /// {{ grp.identifier }} {{ grp.preview_emojis }}
+#[cfg(emojic_group = "{{ grp.identifier }}")]
 pub mod {{ grp.identifier }} {
 	use crate::emojis::Emoji;
+	use crate::groups::Subgroup;
+
+	/// The Unicode name of this group
+	pub const NAME: &str = "{{ grp.name }}";
 
 	{% for sub in grp.subgroups %}
 	/// {{ sub.identifier }} {{ sub.preview_emojis }}
@@ -43,6 +49,9 @@ pub mod {{ grp.identifier }} {
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "{{ sub.name }}";
+
 		// List of all emoji statics
 
 		{% for emoji in sub.emojis %}
@@ -53,7 +62,7 @@ pub mod {{ grp.identifier }} {
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			{% for emoji in sub.emojis %}
 			&[ // {{ emoji.identifier }}
 				{% for acc in emoji.full_list_accessors %}
@@ -64,7 +73,7 @@ pub mod {{ grp.identifier }} {
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			{% for emoji in sub.emojis %}
 				{% for acc in emoji.default_list_accessors %}
 				&{{ acc }}, // {{ emoji.identifier }}
@@ -80,15 +89,35 @@ pub mod {{ grp.identifier }} {
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // {{ sub.identifier }}
 	{% endfor %}
 
 
+	// Subgroup metadata
+
+	/// Contains the metadata of each subgroup of this group.
+	pub(crate) static SUBGROUPS: &[Subgroup] = &[
+		{% for sub in grp.subgroups %}
+		Subgroup::new({{ sub.identifier }}::NAME, {{ sub.identifier }}::ALL_VARIANTS, {{ sub.identifier }}::ALL_BASE_EMOJIS),
+		{% endfor %}
+	];
+
+
 	// Emoji iterators
 
 	/// Returns an iterator over all emoji variants of these subgroups grouped by base emojis
@@ -99,6 +128,11 @@ pub mod {{ grp.identifier }} {
 		{% endfor %}
 	}
 
+	/// Returns an iterator over all emoji variants of these subgroups one by one
+	pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+		all_variants().flat_map(|variants| variants.iter().copied())
+	}
+
 	/// Returns an iterator over all base emojis of these subgroups (i.e. one for each static)
 	pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 		core::iter::empty()
@@ -111,22 +145,52 @@ pub mod {{ grp.identifier }} {
 {% endfor %}
 
 
+// Group metadata
+
+/// Contains the group name, the subgroup name, and all emoji variants of each subgroup.
+pub(crate) static ALL_SUBGROUPS: &[(&str, &str, &[&[&Emoji]])] = &[
+	{% for grp in Constants %}
+	{% for sub in grp.subgroups %}
+	#[cfg(emojic_group = "{{ grp.identifier }}")]
+	({{ grp.identifier }}::NAME, {{ grp.identifier }}::{{ sub.identifier }}::NAME, {{ grp.identifier }}::{{ sub.identifier }}::ALL_VARIANTS),
+	{% endfor %}
+	{% endfor %}
+];
+
+/// Contains the base emojis of each subgroup.
+static ALL_BASE_EMOJIS: &[&[&Emoji]] = &[
+	{% for grp in Constants %}
+	{% for sub in grp.subgroups %}
+	#[cfg(emojic_group = "{{ grp.identifier }}")]
+	{{ grp.identifier }}::{{ sub.identifier }}::ALL_BASE_EMOJIS,
+	{% endfor %}
+	{% endfor %}
+];
+
+/// Contains the metadata of each group.
+pub(crate) static ALL_GROUPS: &[Group] = &[
+	{% for grp in Constants %}
+	#[cfg(emojic_group = "{{ grp.identifier }}")]
+	Group::new({{ grp.identifier }}::NAME, {{ grp.identifier }}::SUBGROUPS),
+	{% endfor %}
+];
+
+
 // Emoji iterators
 
 /// Returns an iterator over all emoji variants of all groups together grouped by base emojis
 pub fn all_variants() -> impl Iterator<Item=&'static [&'static Emoji]> {
-	core::iter::empty()
-	{% for grp in Constants %}
-		.chain({{ grp.identifier }}::all_variants())
-	{% endfor %}
+	ALL_SUBGROUPS.iter().flat_map(|(_, _, variants)| variants.iter().copied())
+}
+
+/// Returns an iterator over all emoji variants of all groups together one by one
+pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+	all_variants().flat_map(|variants| variants.iter().copied())
 }
 
 /// Returns an iterator over all base emojis of all groups together (i.e. one for each static)
 pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
-	core::iter::empty()
-	{% for grp in Constants %}
-		.chain({{ grp.identifier }}::base_emojis())
-	{% endfor %}
+	ALL_BASE_EMOJIS.iter().flat_map(|emojis| emojis.iter().copied())
 }
 
 // EOF
\ No newline at end of file
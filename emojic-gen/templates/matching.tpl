@@ -13,28 +13,17 @@ use crate::emojis::OneOrTwo;
 use crate::emojis::TonePair;
 use crate::emojis::Family;
 
+/// All aliases (without colons) with their emojis, sorted by the alias for binary search
+pub(crate) static ALIASES: &[(&str, &crate::Emoji)] = &[
+        {{ Data }}
+];
+
 /// Matches string (without colons) to emojis
 pub(crate) fn matching(s: &str) -> Option<&'static crate::Emoji> {
-	let len = s.len();
-	let bytes = s.as_bytes();
-
-	if len == 0 {
-		None
-	} else if len == 1 {
-		match bytes[0] {
-{{ SingleBytes }}
-			_ => None
-		}
-	} else {
-		let first = bytes[0];
-		let sec = bytes[1];
-		let rest = &s[2..];
-
-		match first {
-{{ TwoBytes }}
-			_ => None
-		}
-	}
+	ALIASES
+		.binary_search_by(|(alias, _)| (*alias).cmp(s))
+		.ok()
+		.map(|idx| ALIASES[idx].1)
 }
 
 // EOF
\ No newline at end of file
@@ -0,0 +1,11 @@
+// Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
+
+// Source: {{ Link }}
+// Created at: {{ Date }}
+
+/// Maps the fully-qualified graphemes to the minimally-qualified ones, of those emojis which have
+/// such an alternate form, sorted by the fully-qualified grapheme to allow binary searching.
+pub(crate) static MINIMALLY_QUALIFIED: &[(&str, &str)] = &[
+{{ Qualified }}];
+
+// EOF
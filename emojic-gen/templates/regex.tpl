@@ -0,0 +1,26 @@
+// Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
+
+// Source: {{ Link }}
+// Created at: {{ Date }}
+
+/// A regular expression matching any single emoji.
+///
+/// This is an alternation (`a|b|c`) of the escaped graphemes of all emojis of this crate,
+/// including all their variants, ordered longest first, so that e.g. 👨‍👩‍👧 is matched as a
+/// whole instead of just its leading 👨.
+///
+/// Since an alternation binds weaker than anything else, it must be enclosed in a group when
+/// combined with other patterns, e.g. for anchoring: `^(?:EMOJI_REGEX)$` instead of
+/// `^EMOJI_REGEX$` (the latter would only anchor the first and the last emoji of the list).
+///
+/// # Examples
+///
+/// ```
+/// let re = regex::Regex::new(emojic::EMOJI_REGEX).unwrap();
+///
+/// let found: Vec<_> = re.find_iter("I 💖 🦀 & 👨‍👩‍👧").map(|m| m.as_str()).collect();
+/// assert_eq!(found, ["💖", "🦀", "👨‍👩‍👧"]);
+/// ```
+pub const EMOJI_REGEX: &str = {{ Regex }};
+
+// EOF
@@ -0,0 +1,11 @@
+// Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
+
+// Source: {{ Link }}
+// Created at: {{ Date }}
+
+/// The first chars of all emojis of this crate (including all their variants), sorted and
+/// without duplicates, so it can be binary searched.
+pub(crate) static EMOJI_STARTS: &[char] = &[
+{{ Starts }}];
+
+// EOF
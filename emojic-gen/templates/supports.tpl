@@ -0,0 +1,20 @@
+// Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
+
+// Source: {{ Link }}
+// Created at: {{ Date }}
+
+/// The emoji can be customized by skin tone, see [`crate::supports_tone`]
+pub(crate) const TONE: u8 = 1 << 0;
+/// The emoji can be customized by gender, see [`crate::supports_gender`]
+pub(crate) const GENDER: u8 = 1 << 1;
+/// The emoji can be customized by hair style, see [`crate::supports_hair`]
+pub(crate) const HAIR: u8 = 1 << 2;
+
+/// The customizations supported by the emojis (including all their variants), as flags of
+/// [`TONE`], [`GENDER`], and [`HAIR`], sorted by grapheme to allow binary searching.
+///
+/// Emojis which can not be customized at all are not listed.
+pub(crate) static SUPPORTS: &[(&str, u8)] = &[
+{{ Supports }}];
+
+// EOF
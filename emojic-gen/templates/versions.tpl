@@ -0,0 +1,13 @@
+// Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
+
+// Source: {{ Link }}
+// Created at: {{ Date }}
+
+use crate::emojis::Version;
+
+/// The number of emojis of this crate (including all their variants) per Unicode Emoji version
+/// in which they were introduced, sorted by version.
+pub(crate) static VERSION_HISTOGRAM: &[(Version, usize)] = &[
+{{ Versions }}];
+
+// EOF
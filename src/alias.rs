@@ -1,7 +1,7 @@
 // Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
 
-// Source: https://raw.githubusercontent.com/github/gemoji/master/db/emoji.json
-// Created at: 2022-10-24 05:19:19.000107522 UTC
+// Source: https://raw.githubusercontent.com/github/gemoji/v4.1.0/db/emoji.json
+// Created at: 2026-10-16 13:54:22.427084582 UTC
 
 #![allow(unused_imports)]
 
@@ -19,2619 +19,2656 @@ use crate::emojis::Family;
 lazy_static!{
 	/// Maps string (without colons) to emojis
     pub(crate) static ref GEMOJI_MAP: HashMap<&'static str, &'static crate::Emoji> = [
-        ("+1" , &crate::flat::THUMBS_UP.default as &crate::Emoji),
-("-1" , &crate::flat::THUMBS_DOWN.default as &crate::Emoji),
-("100" , &crate::flat::HUNDRED_POINTS as &crate::Emoji),
-("1234" , &crate::flat::INPUT_NUMBERS as &crate::Emoji),
-("1st_place_medal" , &crate::flat::FIRST_PLACE_MEDAL as &crate::Emoji),
-("2nd_place_medal" , &crate::flat::SECOND_PLACE_MEDAL as &crate::Emoji),
-("3rd_place_medal" , &crate::flat::THIRD_PLACE_MEDAL as &crate::Emoji),
-("8ball" , &crate::flat::POOL_8_BALL as &crate::Emoji),
-("a" , &crate::flat::A_BUTTON_BLOOD_TYPE as &crate::Emoji),
-("a_button_blood_type" , &crate::flat::A_BUTTON_BLOOD_TYPE as &crate::Emoji),
-("ab" , &crate::flat::AB_BUTTON_BLOOD_TYPE as &crate::Emoji),
-("ab_button_blood_type" , &crate::flat::AB_BUTTON_BLOOD_TYPE as &crate::Emoji),
-("abacus" , &crate::flat::ABACUS as &crate::Emoji),
-("abc" , &crate::flat::INPUT_LATIN_LETTERS as &crate::Emoji),
-("abcd" , &crate::flat::INPUT_LATIN_LOWERCASE as &crate::Emoji),
-("accept" , &crate::flat::JAPANESE_ACCEPTABLE_BUTTON as &crate::Emoji),
-("accordion" , &crate::flat::ACCORDION as &crate::Emoji),
-("adhesive_bandage" , &crate::flat::ADHESIVE_BANDAGE as &crate::Emoji),
-("admission_tickets" , &crate::flat::ADMISSION_TICKETS as &crate::Emoji),
-("adult" , &crate::flat::PERSON.default.default.default as &crate::Emoji),
-("aerial_tramway" , &crate::flat::AERIAL_TRAMWAY as &crate::Emoji),
-("afghanistan" , &crate::flat::FLAG_AFGHANISTAN as &crate::Emoji),
-("airplane" , &crate::flat::AIRPLANE as &crate::Emoji),
-("airplane_arrival" , &crate::flat::AIRPLANE_ARRIVAL as &crate::Emoji),
-("airplane_departure" , &crate::flat::AIRPLANE_DEPARTURE as &crate::Emoji),
-("aland_islands" , &crate::flat::FLAG_ALAND_ISLANDS as &crate::Emoji),
-("alarm_clock" , &crate::flat::ALARM_CLOCK as &crate::Emoji),
-("albania" , &crate::flat::FLAG_ALBANIA as &crate::Emoji),
-("alembic" , &crate::flat::ALEMBIC as &crate::Emoji),
-("algeria" , &crate::flat::FLAG_ALGERIA as &crate::Emoji),
-("alien" , &crate::flat::ALIEN as &crate::Emoji),
-("alien_monster" , &crate::flat::ALIEN_MONSTER as &crate::Emoji),
-("ambulance" , &crate::flat::AMBULANCE as &crate::Emoji),
-("american_football" , &crate::flat::AMERICAN_FOOTBALL as &crate::Emoji),
-("american_samoa" , &crate::flat::FLAG_AMERICAN_SAMOA as &crate::Emoji),
-("amphora" , &crate::flat::AMPHORA as &crate::Emoji),
-("anatomical_heart" , &crate::flat::ANATOMICAL_HEART as &crate::Emoji),
-("anchor" , &crate::flat::ANCHOR as &crate::Emoji),
-("andorra" , &crate::flat::FLAG_ANDORRA as &crate::Emoji),
-("angel" , &crate::flat::BABY_ANGEL.default as &crate::Emoji),
-("anger" , &crate::flat::ANGER_SYMBOL as &crate::Emoji),
-("anger_symbol" , &crate::flat::ANGER_SYMBOL as &crate::Emoji),
-("angola" , &crate::flat::FLAG_ANGOLA as &crate::Emoji),
-("angry" , &crate::flat::ANGRY_FACE as &crate::Emoji),
-("angry_face" , &crate::flat::ANGRY_FACE as &crate::Emoji),
-("angry_face_with_horns" , &crate::flat::ANGRY_FACE_WITH_HORNS as &crate::Emoji),
-("anguilla" , &crate::flat::FLAG_ANGUILLA as &crate::Emoji),
-("anguished" , &crate::flat::ANGUISHED_FACE as &crate::Emoji),
-("anguished_face" , &crate::flat::ANGUISHED_FACE as &crate::Emoji),
-("ant" , &crate::flat::ANT as &crate::Emoji),
-("antarctica" , &crate::flat::FLAG_ANTARCTICA as &crate::Emoji),
-("antenna_bars" , &crate::flat::ANTENNA_BARS as &crate::Emoji),
-("antigua_barbuda" , &crate::flat::FLAG_ANTIGUA_AND_BARBUDA as &crate::Emoji),
-("anxious_face_with_sweat" , &crate::flat::ANXIOUS_FACE_WITH_SWEAT as &crate::Emoji),
-("apple" , &crate::flat::RED_APPLE as &crate::Emoji),
-("aquarius" , &crate::flat::AQUARIUS as &crate::Emoji),
-("argentina" , &crate::flat::FLAG_ARGENTINA as &crate::Emoji),
-("aries" , &crate::flat::ARIES as &crate::Emoji),
-("armenia" , &crate::flat::FLAG_ARMENIA as &crate::Emoji),
-("arrow_backward" , &crate::flat::REVERSE_BUTTON as &crate::Emoji),
-("arrow_double_down" , &crate::flat::FAST_DOWN_BUTTON as &crate::Emoji),
-("arrow_double_up" , &crate::flat::FAST_UP_BUTTON as &crate::Emoji),
-("arrow_down" , &crate::flat::DOWN_ARROW as &crate::Emoji),
-("arrow_down_small" , &crate::flat::DOWNWARDS_BUTTON as &crate::Emoji),
-("arrow_forward" , &crate::flat::PLAY_BUTTON as &crate::Emoji),
-("arrow_heading_down" , &crate::flat::RIGHT_ARROW_CURVING_DOWN as &crate::Emoji),
-("arrow_heading_up" , &crate::flat::RIGHT_ARROW_CURVING_UP as &crate::Emoji),
-("arrow_left" , &crate::flat::LEFT_ARROW as &crate::Emoji),
-("arrow_lower_left" , &crate::flat::DOWN_LEFT_ARROW as &crate::Emoji),
-("arrow_lower_right" , &crate::flat::DOWN_RIGHT_ARROW as &crate::Emoji),
-("arrow_right" , &crate::flat::RIGHT_ARROW as &crate::Emoji),
-("arrow_right_hook" , &crate::flat::LEFT_ARROW_CURVING_RIGHT as &crate::Emoji),
-("arrow_up" , &crate::flat::UP_ARROW as &crate::Emoji),
-("arrow_up_down" , &crate::flat::UP_DOWN_ARROW as &crate::Emoji),
-("arrow_up_small" , &crate::flat::UPWARDS_BUTTON as &crate::Emoji),
-("arrow_upper_left" , &crate::flat::UP_LEFT_ARROW as &crate::Emoji),
-("arrow_upper_right" , &crate::flat::UP_RIGHT_ARROW as &crate::Emoji),
-("arrows_clockwise" , &crate::flat::CLOCKWISE_VERTICAL_ARROWS as &crate::Emoji),
-("arrows_counterclockwise" , &crate::flat::COUNTERCLOCKWISE_ARROWS_BUTTON as &crate::Emoji),
-("art" , &crate::flat::ARTIST_PALETTE as &crate::Emoji),
-("articulated_lorry" , &crate::flat::ARTICULATED_LORRY as &crate::Emoji),
-("artificial_satellite" , &crate::flat::SATELLITE as &crate::Emoji),
-("artist" , &crate::flat::ARTIST.default.default as &crate::Emoji),
-("artist_palette" , &crate::flat::ARTIST_PALETTE as &crate::Emoji),
-("aruba" , &crate::flat::FLAG_ARUBA as &crate::Emoji),
-("ascension_island" , &crate::flat::FLAG_ASCENSION_ISLAND as &crate::Emoji),
-("asterisk" , &crate::flat::KEYCAP_ASTERISK as &crate::Emoji),
-("astonished" , &crate::flat::ASTONISHED_FACE as &crate::Emoji),
-("astonished_face" , &crate::flat::ASTONISHED_FACE as &crate::Emoji),
-("astronaut" , &crate::flat::ASTRONAUT.default.default as &crate::Emoji),
-("athletic_shoe" , &crate::flat::RUNNING_SHOE as &crate::Emoji),
-("atm" , &crate::flat::ATM_SIGN as &crate::Emoji),
-("atm_sign" , &crate::flat::ATM_SIGN as &crate::Emoji),
-("atom_symbol" , &crate::flat::ATOM_SYMBOL as &crate::Emoji),
-("australia" , &crate::flat::FLAG_AUSTRALIA as &crate::Emoji),
-("austria" , &crate::flat::FLAG_AUSTRIA as &crate::Emoji),
-("auto_rickshaw" , &crate::flat::AUTO_RICKSHAW as &crate::Emoji),
-("automobile" , &crate::flat::AUTOMOBILE as &crate::Emoji),
-("avocado" , &crate::flat::AVOCADO as &crate::Emoji),
-("axe" , &crate::flat::AXE as &crate::Emoji),
-("azerbaijan" , &crate::flat::FLAG_AZERBAIJAN as &crate::Emoji),
-("b" , &crate::flat::B_BUTTON_BLOOD_TYPE as &crate::Emoji),
-("b_button_blood_type" , &crate::flat::B_BUTTON_BLOOD_TYPE as &crate::Emoji),
-("baby" , &crate::flat::BABY.default as &crate::Emoji),
-("baby_angel" , &crate::flat::BABY_ANGEL.default as &crate::Emoji),
-("baby_bottle" , &crate::flat::BABY_BOTTLE as &crate::Emoji),
-("baby_chick" , &crate::flat::BABY_CHICK as &crate::Emoji),
-("baby_symbol" , &crate::flat::BABY_SYMBOL as &crate::Emoji),
-("back" , &crate::flat::BACK_ARROW as &crate::Emoji),
-("back_arrow" , &crate::flat::BACK_ARROW as &crate::Emoji),
-("backhand_index_pointing_down" , &crate::flat::BACKHAND_INDEX_POINTING_DOWN.default as &crate::Emoji),
-("backhand_index_pointing_left" , &crate::flat::BACKHAND_INDEX_POINTING_LEFT.default as &crate::Emoji),
-("backhand_index_pointing_right" , &crate::flat::BACKHAND_INDEX_POINTING_RIGHT.default as &crate::Emoji),
-("backhand_index_pointing_up" , &crate::flat::BACKHAND_INDEX_POINTING_UP.default as &crate::Emoji),
-("backpack" , &crate::flat::BACKPACK as &crate::Emoji),
-("bacon" , &crate::flat::BACON as &crate::Emoji),
-("badger" , &crate::flat::BADGER as &crate::Emoji),
-("badminton" , &crate::flat::BADMINTON as &crate::Emoji),
-("bagel" , &crate::flat::BAGEL as &crate::Emoji),
-("baggage_claim" , &crate::flat::BAGGAGE_CLAIM as &crate::Emoji),
-("baguette_bread" , &crate::flat::BAGUETTE_BREAD as &crate::Emoji),
-("bahamas" , &crate::flat::FLAG_BAHAMAS as &crate::Emoji),
-("bahrain" , &crate::flat::FLAG_BAHRAIN as &crate::Emoji),
-("balance_scale" , &crate::flat::BALANCE_SCALE as &crate::Emoji),
-("bald" , &crate::flat::BALD as &crate::Emoji),
-("bald_man" , &crate::flat::PERSON.hair(Hair::Bald).gender(Gender::Male).default as &crate::Emoji),
-("bald_woman" , &crate::flat::PERSON.hair(Hair::Bald).gender(Gender::Female).default as &crate::Emoji),
-("ballet_shoes" , &crate::flat::BALLET_SHOES as &crate::Emoji),
-("balloon" , &crate::flat::BALLOON as &crate::Emoji),
-("ballot_box" , &crate::flat::BALLOT_BOX_WITH_BALLOT as &crate::Emoji),
-("ballot_box_with_ballot" , &crate::flat::BALLOT_BOX_WITH_BALLOT as &crate::Emoji),
-("ballot_box_with_check" , &crate::flat::CHECK_BOX_WITH_CHECK as &crate::Emoji),
-("bamboo" , &crate::flat::PINE_DECORATION as &crate::Emoji),
-("banana" , &crate::flat::BANANA as &crate::Emoji),
-("bangbang" , &crate::flat::DOUBLE_EXCLAMATION_MARK as &crate::Emoji),
-("bangladesh" , &crate::flat::FLAG_BANGLADESH as &crate::Emoji),
-("banjo" , &crate::flat::BANJO as &crate::Emoji),
-("bank" , &crate::flat::BANK as &crate::Emoji),
-("bar_chart" , &crate::flat::BAR_CHART as &crate::Emoji),
-("barbados" , &crate::flat::FLAG_BARBADOS as &crate::Emoji),
-("barber" , &crate::flat::BARBER_POLE as &crate::Emoji),
-("barber_pole" , &crate::flat::BARBER_POLE as &crate::Emoji),
-("baseball" , &crate::flat::BASEBALL as &crate::Emoji),
-("basket" , &crate::flat::BASKET as &crate::Emoji),
-("basketball" , &crate::flat::BASKETBALL as &crate::Emoji),
-("basketball_man" , &crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Male).default as &crate::Emoji),
-("basketball_woman" , &crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Female).default as &crate::Emoji),
-("bat" , &crate::flat::BAT as &crate::Emoji),
-("bath" , &crate::flat::PERSON_TAKING_BATH.default as &crate::Emoji),
-("bathtub" , &crate::flat::BATHTUB as &crate::Emoji),
-("battery" , &crate::flat::BATTERY as &crate::Emoji),
-("beach_umbrella" , &crate::flat::BEACH_WITH_UMBRELLA as &crate::Emoji),
-("beach_with_umbrella" , &crate::flat::BEACH_WITH_UMBRELLA as &crate::Emoji),
-("beaming_face_with_smiling_eyes" , &crate::flat::BEAMING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-("bear" , &crate::flat::BEAR as &crate::Emoji),
-("bearded_person" , &crate::flat::PERSON.hair(Hair::Beard).default.default as &crate::Emoji),
-("beating_heart" , &crate::flat::BEATING_HEART as &crate::Emoji),
-("beaver" , &crate::flat::BEAVER as &crate::Emoji),
-("bed" , &crate::flat::BED as &crate::Emoji),
-("bee" , &crate::flat::HONEYBEE as &crate::Emoji),
-("beer" , &crate::flat::BEER_MUG as &crate::Emoji),
-("beer_mug" , &crate::flat::BEER_MUG as &crate::Emoji),
-("beers" , &crate::flat::CLINKING_BEER_MUGS as &crate::Emoji),
-("beetle" , &crate::flat::BEETLE as &crate::Emoji),
-("beginner" , &crate::flat::JAPANESE_SYMBOL_FOR_BEGINNER as &crate::Emoji),
-("belarus" , &crate::flat::FLAG_BELARUS as &crate::Emoji),
-("belgium" , &crate::flat::FLAG_BELGIUM as &crate::Emoji),
-("belize" , &crate::flat::FLAG_BELIZE as &crate::Emoji),
-("bell" , &crate::flat::BELL as &crate::Emoji),
-("bell_pepper" , &crate::flat::BELL_PEPPER as &crate::Emoji),
-("bell_with_slash" , &crate::flat::BELL_WITH_SLASH as &crate::Emoji),
-("bellhop_bell" , &crate::flat::BELLHOP_BELL as &crate::Emoji),
-("benin" , &crate::flat::FLAG_BENIN as &crate::Emoji),
-("bento" , &crate::flat::BENTO_BOX as &crate::Emoji),
-("bento_box" , &crate::flat::BENTO_BOX as &crate::Emoji),
-("bermuda" , &crate::flat::FLAG_BERMUDA as &crate::Emoji),
-("beverage_box" , &crate::flat::BEVERAGE_BOX as &crate::Emoji),
-("bhutan" , &crate::flat::FLAG_BHUTAN as &crate::Emoji),
-("bicycle" , &crate::flat::BICYCLE as &crate::Emoji),
-("bicyclist" , &crate::flat::PERSON_BIKING.default.default as &crate::Emoji),
-("bike" , &crate::flat::BICYCLE as &crate::Emoji),
-("biking_man" , &crate::flat::PERSON_BIKING.gender(Gender::Male).default as &crate::Emoji),
-("biking_woman" , &crate::flat::PERSON_BIKING.gender(Gender::Female).default as &crate::Emoji),
-("bikini" , &crate::flat::BIKINI as &crate::Emoji),
-("billed_cap" , &crate::flat::BILLED_CAP as &crate::Emoji),
-("biohazard" , &crate::flat::BIOHAZARD as &crate::Emoji),
-("bird" , &crate::flat::BIRD as &crate::Emoji),
-("birthday" , &crate::flat::BIRTHDAY_CAKE as &crate::Emoji),
-("birthday_cake" , &crate::flat::BIRTHDAY_CAKE as &crate::Emoji),
-("bison" , &crate::flat::BISON as &crate::Emoji),
-("black_cat" , &crate::flat::BLACK_CAT as &crate::Emoji),
-("black_circle" , &crate::flat::BLACK_CIRCLE as &crate::Emoji),
-("black_flag" , &crate::flat::BLACK_FLAG as &crate::Emoji),
-("black_heart" , &crate::flat::BLACK_HEART as &crate::Emoji),
-("black_joker" , &crate::flat::JOKER as &crate::Emoji),
-("black_large_square" , &crate::flat::BLACK_LARGE_SQUARE as &crate::Emoji),
-("black_medium_small_square" , &crate::flat::BLACK_MEDIUM_SMALL_SQUARE as &crate::Emoji),
-("black_medium_square" , &crate::flat::BLACK_MEDIUM_SQUARE as &crate::Emoji),
-("black_nib" , &crate::flat::BLACK_NIB as &crate::Emoji),
-("black_small_square" , &crate::flat::BLACK_SMALL_SQUARE as &crate::Emoji),
-("black_square_button" , &crate::flat::BLACK_SQUARE_BUTTON as &crate::Emoji),
-("blond_haired_man" , &crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Male).default as &crate::Emoji),
-("blond_haired_person" , &crate::flat::PERSON.hair(Hair::Blond).default.default as &crate::Emoji),
-("blond_haired_woman" , &crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Female).default as &crate::Emoji),
-("blonde_woman" , &crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Female).default as &crate::Emoji),
-("blossom" , &crate::flat::BLOSSOM as &crate::Emoji),
-("blowfish" , &crate::flat::BLOWFISH as &crate::Emoji),
-("blue_book" , &crate::flat::BLUE_BOOK as &crate::Emoji),
-("blue_car" , &crate::flat::SPORT_UTILITY_VEHICLE as &crate::Emoji),
-("blue_circle" , &crate::flat::BLUE_CIRCLE as &crate::Emoji),
-("blue_heart" , &crate::flat::BLUE_HEART as &crate::Emoji),
-("blue_square" , &crate::flat::BLUE_SQUARE as &crate::Emoji),
-("blueberries" , &crate::flat::BLUEBERRIES as &crate::Emoji),
-("blush" , &crate::flat::SMILING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-("boar" , &crate::flat::BOAR as &crate::Emoji),
-("boat" , &crate::flat::SAILBOAT as &crate::Emoji),
-("bolivia" , &crate::flat::FLAG_BOLIVIA as &crate::Emoji),
-("bomb" , &crate::flat::BOMB as &crate::Emoji),
-("bone" , &crate::flat::BONE as &crate::Emoji),
-("book" , &crate::flat::OPEN_BOOK as &crate::Emoji),
-("bookmark" , &crate::flat::BOOKMARK as &crate::Emoji),
-("bookmark_tabs" , &crate::flat::BOOKMARK_TABS as &crate::Emoji),
-("books" , &crate::flat::BOOKS as &crate::Emoji),
-("boom" , &crate::flat::COLLISION as &crate::Emoji),
-("boomerang" , &crate::flat::BOOMERANG as &crate::Emoji),
-("boot" , &crate::flat::WOMAN_S_BOOT as &crate::Emoji),
-("bosnia_herzegovina" , &crate::flat::FLAG_BOSNIA_AND_HERZEGOVINA as &crate::Emoji),
-("botswana" , &crate::flat::FLAG_BOTSWANA as &crate::Emoji),
-("bottle_with_popping_cork" , &crate::flat::BOTTLE_WITH_POPPING_CORK as &crate::Emoji),
-("bouncing_ball_man" , &crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Male).default as &crate::Emoji),
-("bouncing_ball_person" , &crate::flat::PERSON_BOUNCING_BALL.default.default as &crate::Emoji),
-("bouncing_ball_woman" , &crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Female).default as &crate::Emoji),
-("bouquet" , &crate::flat::BOUQUET as &crate::Emoji),
-("bouvet_island" , &crate::flat::FLAG_BOUVET_ISLAND as &crate::Emoji),
-("bow" , &crate::flat::PERSON_BOWING.default.default as &crate::Emoji),
-("bow_and_arrow" , &crate::flat::BOW_AND_ARROW as &crate::Emoji),
-("bowing_man" , &crate::flat::PERSON_BOWING.gender(Gender::Male).default as &crate::Emoji),
-("bowing_woman" , &crate::flat::PERSON_BOWING.gender(Gender::Female).default as &crate::Emoji),
-("bowl_with_spoon" , &crate::flat::BOWL_WITH_SPOON as &crate::Emoji),
-("bowling" , &crate::flat::BOWLING as &crate::Emoji),
-("boxing_glove" , &crate::flat::BOXING_GLOVE as &crate::Emoji),
-("boy" , &crate::flat::BOY.default as &crate::Emoji),
-("brain" , &crate::flat::BRAIN as &crate::Emoji),
-("brazil" , &crate::flat::FLAG_BRAZIL as &crate::Emoji),
-("bread" , &crate::flat::BREAD as &crate::Emoji),
-("breast_feeding" , &crate::flat::BREAST_FEEDING.default as &crate::Emoji),
-("brick" , &crate::flat::BRICK as &crate::Emoji),
-("bricks" , &crate::flat::BRICK as &crate::Emoji),
-("bride_with_veil" , &crate::flat::PERSON_WITH_VEIL.gender(Gender::Female).default as &crate::Emoji),
-("bridge_at_night" , &crate::flat::BRIDGE_AT_NIGHT as &crate::Emoji),
-("briefcase" , &crate::flat::BRIEFCASE as &crate::Emoji),
-("briefs" , &crate::flat::BRIEFS as &crate::Emoji),
-("bright_button" , &crate::flat::BRIGHT_BUTTON as &crate::Emoji),
-("british_indian_ocean_territory" , &crate::flat::FLAG_BRITISH_INDIAN_OCEAN_TERRITORY as &crate::Emoji),
-("british_virgin_islands" , &crate::flat::FLAG_BRITISH_VIRGIN_ISLANDS as &crate::Emoji),
-("broccoli" , &crate::flat::BROCCOLI as &crate::Emoji),
-("broken_heart" , &crate::flat::BROKEN_HEART as &crate::Emoji),
-("broom" , &crate::flat::BROOM as &crate::Emoji),
-("brown_circle" , &crate::flat::BROWN_CIRCLE as &crate::Emoji),
-("brown_heart" , &crate::flat::BROWN_HEART as &crate::Emoji),
-("brown_square" , &crate::flat::BROWN_SQUARE as &crate::Emoji),
-("brunei" , &crate::flat::FLAG_BRUNEI as &crate::Emoji),
-("bubble_tea" , &crate::flat::BUBBLE_TEA as &crate::Emoji),
-("bucket" , &crate::flat::BUCKET as &crate::Emoji),
-("bug" , &crate::flat::BUG as &crate::Emoji),
-("building_construction" , &crate::flat::BUILDING_CONSTRUCTION as &crate::Emoji),
-("bulb" , &crate::flat::LIGHT_BULB as &crate::Emoji),
-("bulgaria" , &crate::flat::FLAG_BULGARIA as &crate::Emoji),
-("bullet_train" , &crate::flat::BULLET_TRAIN as &crate::Emoji),
-("bullettrain_front" , &crate::flat::BULLET_TRAIN as &crate::Emoji),
-("bullettrain_side" , &crate::flat::HIGH_SPEED_TRAIN as &crate::Emoji),
-("bullseye" , &crate::flat::BULLSEYE as &crate::Emoji),
-("burkina_faso" , &crate::flat::FLAG_BURKINA_FASO as &crate::Emoji),
-("burrito" , &crate::flat::BURRITO as &crate::Emoji),
-("burundi" , &crate::flat::FLAG_BURUNDI as &crate::Emoji),
-("bus" , &crate::flat::BUS as &crate::Emoji),
-("bus_stop" , &crate::flat::BUS_STOP as &crate::Emoji),
-("business_suit_levitating" , &crate::flat::PERSON_IN_SUIT_LEVITATING.default as &crate::Emoji),
-("busstop" , &crate::flat::BUS_STOP as &crate::Emoji),
-("bust_in_silhouette" , &crate::flat::BUST_IN_SILHOUETTE as &crate::Emoji),
-("busts_in_silhouette" , &crate::flat::BUSTS_IN_SILHOUETTE as &crate::Emoji),
-("butter" , &crate::flat::BUTTER as &crate::Emoji),
-("butterfly" , &crate::flat::BUTTERFLY as &crate::Emoji),
-("cactus" , &crate::flat::CACTUS as &crate::Emoji),
-("cake" , &crate::flat::SHORTCAKE as &crate::Emoji),
-("calendar" , &crate::flat::CALENDAR as &crate::Emoji),
-("call_me_hand" , &crate::flat::CALL_ME_HAND.default as &crate::Emoji),
-("calling" , &crate::flat::MOBILE_PHONE_WITH_ARROW as &crate::Emoji),
-("cambodia" , &crate::flat::FLAG_CAMBODIA as &crate::Emoji),
-("camel" , &crate::flat::CAMEL as &crate::Emoji),
-("camera" , &crate::flat::CAMERA as &crate::Emoji),
-("camera_flash" , &crate::flat::CAMERA_WITH_FLASH as &crate::Emoji),
-("camera_with_flash" , &crate::flat::CAMERA_WITH_FLASH as &crate::Emoji),
-("cameroon" , &crate::flat::FLAG_CAMEROON as &crate::Emoji),
-("camping" , &crate::flat::CAMPING as &crate::Emoji),
-("canada" , &crate::flat::FLAG_CANADA as &crate::Emoji),
-("canary_islands" , &crate::flat::FLAG_CANARY_ISLANDS as &crate::Emoji),
-("cancer" , &crate::flat::CANCER as &crate::Emoji),
-("candle" , &crate::flat::CANDLE as &crate::Emoji),
-("candy" , &crate::flat::CANDY as &crate::Emoji),
-("canned_food" , &crate::flat::CANNED_FOOD as &crate::Emoji),
-("canoe" , &crate::flat::CANOE as &crate::Emoji),
-("cape_verde" , &crate::flat::FLAG_CAPE_VERDE as &crate::Emoji),
-("capital_abcd" , &crate::flat::INPUT_LATIN_UPPERCASE as &crate::Emoji),
-("capricorn" , &crate::flat::CAPRICORN as &crate::Emoji),
-("car" , &crate::flat::AUTOMOBILE as &crate::Emoji),
-("card_file_box" , &crate::flat::CARD_FILE_BOX as &crate::Emoji),
-("card_index" , &crate::flat::CARD_INDEX as &crate::Emoji),
-("card_index_dividers" , &crate::flat::CARD_INDEX_DIVIDERS as &crate::Emoji),
-("caribbean_netherlands" , &crate::flat::FLAG_CARIBBEAN_NETHERLANDS as &crate::Emoji),
-("carousel_horse" , &crate::flat::CAROUSEL_HORSE as &crate::Emoji),
-("carp_streamer" , &crate::flat::CARP_STREAMER as &crate::Emoji),
-("carpentry_saw" , &crate::flat::CARPENTRY_SAW as &crate::Emoji),
-("carrot" , &crate::flat::CARROT as &crate::Emoji),
-("cartwheeling" , &crate::flat::PERSON_CARTWHEELING.default.default as &crate::Emoji),
-("castle" , &crate::flat::CASTLE as &crate::Emoji),
-("cat" , &crate::flat::CAT as &crate::Emoji),
-("cat2" , &crate::flat::CAT as &crate::Emoji),
-("cat_face" , &crate::flat::CAT_FACE as &crate::Emoji),
-("cat_with_tears_of_joy" , &crate::flat::CAT_WITH_TEARS_OF_JOY as &crate::Emoji),
-("cat_with_wry_smile" , &crate::flat::CAT_WITH_WRY_SMILE as &crate::Emoji),
-("cayman_islands" , &crate::flat::FLAG_CAYMAN_ISLANDS as &crate::Emoji),
-("cd" , &crate::flat::OPTICAL_DISK as &crate::Emoji),
-("central_african_republic" , &crate::flat::FLAG_CENTRAL_AFRICAN_REPUBLIC as &crate::Emoji),
-("ceuta_melilla" , &crate::flat::FLAG_CEUTA_AND_MELILLA as &crate::Emoji),
-("chad" , &crate::flat::FLAG_CHAD as &crate::Emoji),
-("chains" , &crate::flat::CHAINS as &crate::Emoji),
-("chair" , &crate::flat::CHAIR as &crate::Emoji),
-("champagne" , &crate::flat::BOTTLE_WITH_POPPING_CORK as &crate::Emoji),
-("chart" , &crate::flat::CHART_INCREASING_WITH_YEN as &crate::Emoji),
-("chart_decreasing" , &crate::flat::CHART_DECREASING as &crate::Emoji),
-("chart_increasing" , &crate::flat::CHART_INCREASING as &crate::Emoji),
-("chart_increasing_with_yen" , &crate::flat::CHART_INCREASING_WITH_YEN as &crate::Emoji),
-("chart_with_downwards_trend" , &crate::flat::CHART_DECREASING as &crate::Emoji),
-("chart_with_upwards_trend" , &crate::flat::CHART_INCREASING as &crate::Emoji),
-("check_box_with_check" , &crate::flat::CHECK_BOX_WITH_CHECK as &crate::Emoji),
-("check_mark" , &crate::flat::CHECK_MARK as &crate::Emoji),
-("check_mark_button" , &crate::flat::CHECK_MARK_BUTTON as &crate::Emoji),
-("checkered_flag" , &crate::flat::CHEQUERED_FLAG as &crate::Emoji),
-("cheese" , &crate::flat::CHEESE_WEDGE as &crate::Emoji),
-("cheese_wedge" , &crate::flat::CHEESE_WEDGE as &crate::Emoji),
-("chequered_flag" , &crate::flat::CHEQUERED_FLAG as &crate::Emoji),
-("cherries" , &crate::flat::CHERRIES as &crate::Emoji),
-("cherry_blossom" , &crate::flat::CHERRY_BLOSSOM as &crate::Emoji),
-("chess_pawn" , &crate::flat::CHESS_PAWN as &crate::Emoji),
-("chestnut" , &crate::flat::CHESTNUT as &crate::Emoji),
-("chicken" , &crate::flat::CHICKEN as &crate::Emoji),
-("child" , &crate::flat::CHILD.default as &crate::Emoji),
-("children_crossing" , &crate::flat::CHILDREN_CROSSING as &crate::Emoji),
-("chile" , &crate::flat::FLAG_CHILE as &crate::Emoji),
-("chipmunk" , &crate::flat::CHIPMUNK as &crate::Emoji),
-("chocolate_bar" , &crate::flat::CHOCOLATE_BAR as &crate::Emoji),
-("chopsticks" , &crate::flat::CHOPSTICKS as &crate::Emoji),
-("christmas_island" , &crate::flat::FLAG_CHRISTMAS_ISLAND as &crate::Emoji),
-("christmas_tree" , &crate::flat::CHRISTMAS_TREE as &crate::Emoji),
-("church" , &crate::flat::CHURCH as &crate::Emoji),
-("cigarette" , &crate::flat::CIGARETTE as &crate::Emoji),
-("cinema" , &crate::flat::CINEMA as &crate::Emoji),
-("circled_m" , &crate::flat::CIRCLED_M as &crate::Emoji),
-("circus_tent" , &crate::flat::CIRCUS_TENT as &crate::Emoji),
-("city_sunrise" , &crate::flat::SUNSET as &crate::Emoji),
-("city_sunset" , &crate::flat::CITYSCAPE_AT_DUSK as &crate::Emoji),
-("cityscape" , &crate::flat::CITYSCAPE as &crate::Emoji),
-("cityscape_at_dusk" , &crate::flat::CITYSCAPE_AT_DUSK as &crate::Emoji),
-("cl" , &crate::flat::CL_BUTTON as &crate::Emoji),
-("cl_button" , &crate::flat::CL_BUTTON as &crate::Emoji),
-("clamp" , &crate::flat::CLAMP as &crate::Emoji),
-("clap" , &crate::flat::CLAPPING_HANDS.default as &crate::Emoji),
-("clapper" , &crate::flat::CLAPPER_BOARD as &crate::Emoji),
-("clapper_board" , &crate::flat::CLAPPER_BOARD as &crate::Emoji),
-("clapping_hands" , &crate::flat::CLAPPING_HANDS.default as &crate::Emoji),
-("classical_building" , &crate::flat::CLASSICAL_BUILDING as &crate::Emoji),
-("climbing" , &crate::flat::PERSON_CLIMBING.default.default as &crate::Emoji),
-("climbing_man" , &crate::flat::PERSON_CLIMBING.gender(Gender::Male).default as &crate::Emoji),
-("climbing_woman" , &crate::flat::PERSON_CLIMBING.gender(Gender::Female).default as &crate::Emoji),
-("clinking_beer_mugs" , &crate::flat::CLINKING_BEER_MUGS as &crate::Emoji),
-("clinking_glasses" , &crate::flat::CLINKING_GLASSES as &crate::Emoji),
-("clipboard" , &crate::flat::CLIPBOARD as &crate::Emoji),
-("clipperton_island" , &crate::flat::FLAG_CLIPPERTON_ISLAND as &crate::Emoji),
-("clock1" , &crate::flat::ONE_O_CLOCK as &crate::Emoji),
-("clock10" , &crate::flat::TEN_O_CLOCK as &crate::Emoji),
-("clock1030" , &crate::flat::TEN_THIRTY as &crate::Emoji),
-("clock11" , &crate::flat::ELEVEN_O_CLOCK as &crate::Emoji),
-("clock1130" , &crate::flat::ELEVEN_THIRTY as &crate::Emoji),
-("clock12" , &crate::flat::TWELVE_O_CLOCK as &crate::Emoji),
-("clock1230" , &crate::flat::TWELVE_THIRTY as &crate::Emoji),
-("clock130" , &crate::flat::ONE_THIRTY as &crate::Emoji),
-("clock2" , &crate::flat::TWO_O_CLOCK as &crate::Emoji),
-("clock230" , &crate::flat::TWO_THIRTY as &crate::Emoji),
-("clock3" , &crate::flat::THREE_O_CLOCK as &crate::Emoji),
-("clock330" , &crate::flat::THREE_THIRTY as &crate::Emoji),
-("clock4" , &crate::flat::FOUR_O_CLOCK as &crate::Emoji),
-("clock430" , &crate::flat::FOUR_THIRTY as &crate::Emoji),
-("clock5" , &crate::flat::FIVE_O_CLOCK as &crate::Emoji),
-("clock530" , &crate::flat::FIVE_THIRTY as &crate::Emoji),
-("clock6" , &crate::flat::SIX_O_CLOCK as &crate::Emoji),
-("clock630" , &crate::flat::SIX_THIRTY as &crate::Emoji),
-("clock7" , &crate::flat::SEVEN_O_CLOCK as &crate::Emoji),
-("clock730" , &crate::flat::SEVEN_THIRTY as &crate::Emoji),
-("clock8" , &crate::flat::EIGHT_O_CLOCK as &crate::Emoji),
-("clock830" , &crate::flat::EIGHT_THIRTY as &crate::Emoji),
-("clock9" , &crate::flat::NINE_O_CLOCK as &crate::Emoji),
-("clock930" , &crate::flat::NINE_THIRTY as &crate::Emoji),
-("clockwise_vertical_arrows" , &crate::flat::CLOCKWISE_VERTICAL_ARROWS as &crate::Emoji),
-("closed_book" , &crate::flat::CLOSED_BOOK as &crate::Emoji),
-("closed_lock_with_key" , &crate::flat::LOCKED_WITH_KEY as &crate::Emoji),
-("closed_mailbox_with_lowered_flag" , &crate::flat::CLOSED_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
-("closed_mailbox_with_raised_flag" , &crate::flat::CLOSED_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
-("closed_umbrella" , &crate::flat::CLOSED_UMBRELLA as &crate::Emoji),
-("cloud" , &crate::flat::CLOUD as &crate::Emoji),
-("cloud_with_lightning" , &crate::flat::CLOUD_WITH_LIGHTNING as &crate::Emoji),
-("cloud_with_lightning_and_rain" , &crate::flat::CLOUD_WITH_LIGHTNING_AND_RAIN as &crate::Emoji),
-("cloud_with_rain" , &crate::flat::CLOUD_WITH_RAIN as &crate::Emoji),
-("cloud_with_snow" , &crate::flat::CLOUD_WITH_SNOW as &crate::Emoji),
-("clown_face" , &crate::flat::CLOWN_FACE as &crate::Emoji),
-("club_suit" , &crate::flat::CLUB_SUIT as &crate::Emoji),
-("clubs" , &crate::flat::CLUB_SUIT as &crate::Emoji),
-("clutch_bag" , &crate::flat::CLUTCH_BAG as &crate::Emoji),
-("cn" , &crate::flat::FLAG_CHINA as &crate::Emoji),
-("coat" , &crate::flat::COAT as &crate::Emoji),
-("cockroach" , &crate::flat::COCKROACH as &crate::Emoji),
-("cocktail" , &crate::flat::COCKTAIL_GLASS as &crate::Emoji),
-("cocktail_glass" , &crate::flat::COCKTAIL_GLASS as &crate::Emoji),
-("coconut" , &crate::flat::COCONUT as &crate::Emoji),
-("cocos_islands" , &crate::flat::FLAG_COCOS_KEELING_ISLANDS as &crate::Emoji),
-("coffee" , &crate::flat::HOT_BEVERAGE as &crate::Emoji),
-("coffin" , &crate::flat::COFFIN as &crate::Emoji),
-("coin" , &crate::flat::COIN as &crate::Emoji),
-("cold_face" , &crate::flat::COLD_FACE as &crate::Emoji),
-("cold_sweat" , &crate::flat::ANXIOUS_FACE_WITH_SWEAT as &crate::Emoji),
-("collision" , &crate::flat::COLLISION as &crate::Emoji),
-("colombia" , &crate::flat::FLAG_COLOMBIA as &crate::Emoji),
-("comet" , &crate::flat::COMET as &crate::Emoji),
-("comoros" , &crate::flat::FLAG_COMOROS as &crate::Emoji),
-("compass" , &crate::flat::COMPASS as &crate::Emoji),
-("computer" , &crate::flat::LAPTOP as &crate::Emoji),
-("computer_disk" , &crate::flat::COMPUTER_DISK as &crate::Emoji),
-("computer_mouse" , &crate::flat::COMPUTER_MOUSE as &crate::Emoji),
-("confetti_ball" , &crate::flat::CONFETTI_BALL as &crate::Emoji),
-("confounded" , &crate::flat::CONFOUNDED_FACE as &crate::Emoji),
-("confounded_face" , &crate::flat::CONFOUNDED_FACE as &crate::Emoji),
-("confused" , &crate::flat::CONFUSED_FACE as &crate::Emoji),
-("confused_face" , &crate::flat::CONFUSED_FACE as &crate::Emoji),
-("congo_brazzaville" , &crate::flat::FLAG_CONGO_BRAZZAVILLE as &crate::Emoji),
-("congo_kinshasa" , &crate::flat::FLAG_CONGO_KINSHASA as &crate::Emoji),
-("congratulations" , &crate::flat::JAPANESE_CONGRATULATIONS_BUTTON as &crate::Emoji),
-("construction" , &crate::flat::CONSTRUCTION as &crate::Emoji),
-("construction_worker" , &crate::flat::CONSTRUCTION_WORKER.default.default as &crate::Emoji),
-("construction_worker_man" , &crate::flat::CONSTRUCTION_WORKER.gender(Gender::Male).default as &crate::Emoji),
-("construction_worker_woman" , &crate::flat::CONSTRUCTION_WORKER.gender(Gender::Female).default as &crate::Emoji),
-("control_knobs" , &crate::flat::CONTROL_KNOBS as &crate::Emoji),
-("convenience_store" , &crate::flat::CONVENIENCE_STORE as &crate::Emoji),
-("cook" , &crate::flat::COOK.default.default as &crate::Emoji),
-("cook_islands" , &crate::flat::FLAG_COOK_ISLANDS as &crate::Emoji),
-("cooked_rice" , &crate::flat::COOKED_RICE as &crate::Emoji),
-("cookie" , &crate::flat::COOKIE as &crate::Emoji),
-("cooking" , &crate::flat::COOKING as &crate::Emoji),
-("cool" , &crate::flat::COOL_BUTTON as &crate::Emoji),
-("cool_button" , &crate::flat::COOL_BUTTON as &crate::Emoji),
-("cop" , &crate::flat::POLICE_OFFICER.default.default as &crate::Emoji),
-("copyright" , &crate::flat::COPYRIGHT as &crate::Emoji),
-("corn" , &crate::flat::EAR_OF_CORN as &crate::Emoji),
-("costa_rica" , &crate::flat::FLAG_COSTA_RICA as &crate::Emoji),
-("cote_divoire" , &crate::flat::FLAG_COTE_D_IVOIRE as &crate::Emoji),
-("couch_and_lamp" , &crate::flat::COUCH_AND_LAMP as &crate::Emoji),
-("counterclockwise_arrows_button" , &crate::flat::COUNTERCLOCKWISE_ARROWS_BUTTON as &crate::Emoji),
-("couple" , &crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Mixed).default as &crate::Emoji),
-("couple_with_heart" , &crate::flat::COUPLE_WITH_HEART.default.default as &crate::Emoji),
-("couple_with_heart_man_man" , &crate::flat::COUPLE_WITH_HEART.pair(Pair::Males).default as &crate::Emoji),
-("couple_with_heart_woman_man" , &crate::flat::COUPLE_WITH_HEART.pair(Pair::Mixed).default as &crate::Emoji),
-("couple_with_heart_woman_woman" , &crate::flat::COUPLE_WITH_HEART.pair(Pair::Females).default as &crate::Emoji),
-("couplekiss" , &crate::flat::KISS.default.default as &crate::Emoji),
-("couplekiss_man_man" , &crate::flat::KISS.pair(Pair::Males).default as &crate::Emoji),
-("couplekiss_man_woman" , &crate::flat::KISS.pair(Pair::Mixed).default as &crate::Emoji),
-("couplekiss_woman_woman" , &crate::flat::KISS.pair(Pair::Females).default as &crate::Emoji),
-("cow" , &crate::flat::COW as &crate::Emoji),
-("cow2" , &crate::flat::COW as &crate::Emoji),
-("cow_face" , &crate::flat::COW_FACE as &crate::Emoji),
-("cowboy_hat_face" , &crate::flat::COWBOY_HAT_FACE as &crate::Emoji),
-("crab" , &crate::flat::CRAB as &crate::Emoji),
-("crayon" , &crate::flat::CRAYON as &crate::Emoji),
-("credit_card" , &crate::flat::CREDIT_CARD as &crate::Emoji),
-("crescent_moon" , &crate::flat::CRESCENT_MOON as &crate::Emoji),
-("cricket" , &crate::flat::CRICKET as &crate::Emoji),
-("cricket_game" , &crate::flat::CRICKET_GAME as &crate::Emoji),
-("croatia" , &crate::flat::FLAG_CROATIA as &crate::Emoji),
-("crocodile" , &crate::flat::CROCODILE as &crate::Emoji),
-("croissant" , &crate::flat::CROISSANT as &crate::Emoji),
-("cross_mark" , &crate::flat::CROSS_MARK as &crate::Emoji),
-("cross_mark_button" , &crate::flat::CROSS_MARK_BUTTON as &crate::Emoji),
-("crossed_fingers" , &crate::flat::CROSSED_FINGERS.default as &crate::Emoji),
-("crossed_flags" , &crate::flat::CROSSED_FLAGS as &crate::Emoji),
-("crossed_swords" , &crate::flat::CROSSED_SWORDS as &crate::Emoji),
-("crown" , &crate::flat::CROWN as &crate::Emoji),
-("cry" , &crate::flat::CRYING_FACE as &crate::Emoji),
-("crying_cat" , &crate::flat::CRYING_CAT as &crate::Emoji),
-("crying_cat_face" , &crate::flat::CRYING_CAT as &crate::Emoji),
-("crying_face" , &crate::flat::CRYING_FACE as &crate::Emoji),
-("crystal_ball" , &crate::flat::CRYSTAL_BALL as &crate::Emoji),
-("cuba" , &crate::flat::FLAG_CUBA as &crate::Emoji),
-("cucumber" , &crate::flat::CUCUMBER as &crate::Emoji),
-("cup_with_straw" , &crate::flat::CUP_WITH_STRAW as &crate::Emoji),
-("cupcake" , &crate::flat::CUPCAKE as &crate::Emoji),
-("cupid" , &crate::flat::HEART_WITH_ARROW as &crate::Emoji),
-("curacao" , &crate::flat::FLAG_CURACAO as &crate::Emoji),
-("curling_stone" , &crate::flat::CURLING_STONE as &crate::Emoji),
-("curly_hair" , &crate::flat::CURLY_HAIR as &crate::Emoji),
-("curly_haired_man" , &crate::flat::PERSON.hair(Hair::Curly).gender(Gender::Male).default as &crate::Emoji),
-("curly_haired_woman" , &crate::flat::PERSON.hair(Hair::Curly).gender(Gender::Female).default as &crate::Emoji),
-("curly_loop" , &crate::flat::CURLY_LOOP as &crate::Emoji),
-("currency_exchange" , &crate::flat::CURRENCY_EXCHANGE as &crate::Emoji),
-("curry" , &crate::flat::CURRY_RICE as &crate::Emoji),
-("curry_rice" , &crate::flat::CURRY_RICE as &crate::Emoji),
-("cursing_face" , &crate::flat::FACE_WITH_SYMBOLS_ON_MOUTH as &crate::Emoji),
-("custard" , &crate::flat::CUSTARD as &crate::Emoji),
-("customs" , &crate::flat::CUSTOMS as &crate::Emoji),
-("cut_of_meat" , &crate::flat::CUT_OF_MEAT as &crate::Emoji),
-("cyclone" , &crate::flat::CYCLONE as &crate::Emoji),
-("cyprus" , &crate::flat::FLAG_CYPRUS as &crate::Emoji),
-("czech_republic" , &crate::flat::FLAG_CZECHIA as &crate::Emoji),
-("dagger" , &crate::flat::DAGGER as &crate::Emoji),
-("dancer" , &crate::flat::PERSON_DANCING.gender(Gender::Female).default as &crate::Emoji),
-("dancers" , &crate::flat::PERSON_WITH_BUNNY_EARS.default as &crate::Emoji),
-("dancing_men" , &crate::flat::PERSON_WITH_BUNNY_EARS.gender(Gender::Male) as &crate::Emoji),
-("dancing_women" , &crate::flat::PERSON_WITH_BUNNY_EARS.gender(Gender::Female) as &crate::Emoji),
-("dango" , &crate::flat::DANGO as &crate::Emoji),
-("dark_skin_tone" , &crate::flat::DARK_SKIN_TONE as &crate::Emoji),
-("dark_sunglasses" , &crate::flat::SUNGLASSES as &crate::Emoji),
-("dart" , &crate::flat::BULLSEYE as &crate::Emoji),
-("dash" , &crate::flat::DASHING_AWAY as &crate::Emoji),
-("dashing_away" , &crate::flat::DASHING_AWAY as &crate::Emoji),
-("date" , &crate::flat::CALENDAR as &crate::Emoji),
-("de" , &crate::flat::FLAG_GERMANY as &crate::Emoji),
-("deaf_man" , &crate::flat::DEAF_PERSON.gender(Gender::Male).default as &crate::Emoji),
-("deaf_person" , &crate::flat::DEAF_PERSON.default.default as &crate::Emoji),
-("deaf_woman" , &crate::flat::DEAF_PERSON.gender(Gender::Female).default as &crate::Emoji),
-("deciduous_tree" , &crate::flat::DECIDUOUS_TREE as &crate::Emoji),
-("deer" , &crate::flat::DEER as &crate::Emoji),
-("delivery_truck" , &crate::flat::DELIVERY_TRUCK as &crate::Emoji),
-("denmark" , &crate::flat::FLAG_DENMARK as &crate::Emoji),
-("department_store" , &crate::flat::DEPARTMENT_STORE as &crate::Emoji),
-("derelict_house" , &crate::flat::DERELICT_HOUSE as &crate::Emoji),
-("desert" , &crate::flat::DESERT as &crate::Emoji),
-("desert_island" , &crate::flat::DESERT_ISLAND as &crate::Emoji),
-("desktop_computer" , &crate::flat::DESKTOP_COMPUTER as &crate::Emoji),
-("detective" , &crate::flat::DETECTIVE.default.default as &crate::Emoji),
-("diamond_shape_with_a_dot_inside" , &crate::flat::DIAMOND_WITH_A_DOT as &crate::Emoji),
-("diamond_suit" , &crate::flat::DIAMOND_SUIT as &crate::Emoji),
-("diamond_with_a_dot" , &crate::flat::DIAMOND_WITH_A_DOT as &crate::Emoji),
-("diamonds" , &crate::flat::DIAMOND_SUIT as &crate::Emoji),
-("diego_garcia" , &crate::flat::FLAG_DIEGO_GARCIA as &crate::Emoji),
-("dim_button" , &crate::flat::DIM_BUTTON as &crate::Emoji),
-("disappointed" , &crate::flat::DISAPPOINTED_FACE as &crate::Emoji),
-("disappointed_face" , &crate::flat::DISAPPOINTED_FACE as &crate::Emoji),
-("disappointed_relieved" , &crate::flat::SAD_BUT_RELIEVED_FACE as &crate::Emoji),
-("disguised_face" , &crate::flat::DISGUISED_FACE as &crate::Emoji),
-("divide" , &crate::flat::DIVIDE as &crate::Emoji),
-("diving_mask" , &crate::flat::DIVING_MASK as &crate::Emoji),
-("diya_lamp" , &crate::flat::DIYA_LAMP as &crate::Emoji),
-("dizzy" , &crate::flat::DIZZY as &crate::Emoji),
-("dizzy_face" , &crate::flat::KNOCKED_OUT_FACE as &crate::Emoji),
-("djibouti" , &crate::flat::FLAG_DJIBOUTI as &crate::Emoji),
-("dna" , &crate::flat::DNA as &crate::Emoji),
-("do_not_litter" , &crate::flat::NO_LITTERING as &crate::Emoji),
-("dodo" , &crate::flat::DODO as &crate::Emoji),
-("dog" , &crate::flat::DOG as &crate::Emoji),
-("dog2" , &crate::flat::DOG as &crate::Emoji),
-("dog_face" , &crate::flat::DOG_FACE as &crate::Emoji),
-("dollar" , &crate::flat::DOLLAR_BANKNOTE as &crate::Emoji),
-("dollar_banknote" , &crate::flat::DOLLAR_BANKNOTE as &crate::Emoji),
-("dolls" , &crate::flat::JAPANESE_DOLLS as &crate::Emoji),
-("dolphin" , &crate::flat::DOLPHIN as &crate::Emoji),
-("dominica" , &crate::flat::FLAG_DOMINICA as &crate::Emoji),
-("dominican_republic" , &crate::flat::FLAG_DOMINICAN_REPUBLIC as &crate::Emoji),
-("door" , &crate::flat::DOOR as &crate::Emoji),
-("dotted_six_pointed_star" , &crate::flat::DOTTED_SIX_POINTED_STAR as &crate::Emoji),
-("double_curly_loop" , &crate::flat::DOUBLE_CURLY_LOOP as &crate::Emoji),
-("double_exclamation_mark" , &crate::flat::DOUBLE_EXCLAMATION_MARK as &crate::Emoji),
-("doughnut" , &crate::flat::DOUGHNUT as &crate::Emoji),
-("dove" , &crate::flat::DOVE as &crate::Emoji),
-("down_arrow" , &crate::flat::DOWN_ARROW as &crate::Emoji),
-("down_left_arrow" , &crate::flat::DOWN_LEFT_ARROW as &crate::Emoji),
-("down_right_arrow" , &crate::flat::DOWN_RIGHT_ARROW as &crate::Emoji),
-("downcast_face_with_sweat" , &crate::flat::DOWNCAST_FACE_WITH_SWEAT as &crate::Emoji),
-("downwards_button" , &crate::flat::DOWNWARDS_BUTTON as &crate::Emoji),
-("dragon" , &crate::flat::DRAGON as &crate::Emoji),
-("dragon_face" , &crate::flat::DRAGON_FACE as &crate::Emoji),
-("dress" , &crate::flat::DRESS as &crate::Emoji),
-("dromedary_camel" , &crate::flat::CAMEL as &crate::Emoji),
-("drooling_face" , &crate::flat::DROOLING_FACE as &crate::Emoji),
-("drop_of_blood" , &crate::flat::DROP_OF_BLOOD as &crate::Emoji),
-("droplet" , &crate::flat::DROPLET as &crate::Emoji),
-("drum" , &crate::flat::DRUM as &crate::Emoji),
-("duck" , &crate::flat::DUCK as &crate::Emoji),
-("dumpling" , &crate::flat::DUMPLING as &crate::Emoji),
-("dvd" , &crate::flat::DVD as &crate::Emoji),
-("e-mail" , &crate::flat::E_MAIL as &crate::Emoji),
-("e_mail" , &crate::flat::E_MAIL as &crate::Emoji),
-("eagle" , &crate::flat::EAGLE as &crate::Emoji),
-("ear" , &crate::flat::EAR.default as &crate::Emoji),
-("ear_of_corn" , &crate::flat::EAR_OF_CORN as &crate::Emoji),
-("ear_of_rice" , &crate::flat::SHEAF_OF_RICE as &crate::Emoji),
-("ear_with_hearing_aid" , &crate::flat::EAR_WITH_HEARING_AID.default as &crate::Emoji),
-("earth_africa" , &crate::flat::GLOBE_SHOWING_EUROPE_AFRICA as &crate::Emoji),
-("earth_americas" , &crate::flat::GLOBE_SHOWING_AMERICAS as &crate::Emoji),
-("earth_asia" , &crate::flat::GLOBE_SHOWING_ASIA_AUSTRALIA as &crate::Emoji),
-("ecuador" , &crate::flat::FLAG_ECUADOR as &crate::Emoji),
-("egg" , &crate::flat::EGG as &crate::Emoji),
-("eggplant" , &crate::flat::EGGPLANT as &crate::Emoji),
-("egypt" , &crate::flat::FLAG_EGYPT as &crate::Emoji),
-("eight" , &crate::flat::KEYCAP_8 as &crate::Emoji),
-("eight_o_clock" , &crate::flat::EIGHT_O_CLOCK as &crate::Emoji),
-("eight_pointed_black_star" , &crate::flat::EIGHT_POINTED_STAR as &crate::Emoji),
-("eight_pointed_star" , &crate::flat::EIGHT_POINTED_STAR as &crate::Emoji),
-("eight_spoked_asterisk" , &crate::flat::EIGHT_SPOKED_ASTERISK as &crate::Emoji),
-("eight_thirty" , &crate::flat::EIGHT_THIRTY as &crate::Emoji),
-("eject_button" , &crate::flat::EJECT_BUTTON as &crate::Emoji),
-("el_salvador" , &crate::flat::FLAG_EL_SALVADOR as &crate::Emoji),
-("electric_plug" , &crate::flat::ELECTRIC_PLUG as &crate::Emoji),
-("elephant" , &crate::flat::ELEPHANT as &crate::Emoji),
-("elevator" , &crate::flat::ELEVATOR as &crate::Emoji),
-("eleven_o_clock" , &crate::flat::ELEVEN_O_CLOCK as &crate::Emoji),
-("eleven_thirty" , &crate::flat::ELEVEN_THIRTY as &crate::Emoji),
-("elf" , &crate::flat::ELF.default.default as &crate::Emoji),
-("elf_man" , &crate::flat::ELF.gender(Gender::Male).default as &crate::Emoji),
-("elf_woman" , &crate::flat::ELF.gender(Gender::Female).default as &crate::Emoji),
-("email" , &crate::flat::E_MAIL as &crate::Emoji),
-("end" , &crate::flat::END_ARROW as &crate::Emoji),
-("end_arrow" , &crate::flat::END_ARROW as &crate::Emoji),
-("england" , &crate::flat::FLAG_ENGLAND as &crate::Emoji),
-("envelope" , &crate::flat::ENVELOPE as &crate::Emoji),
-("envelope_with_arrow" , &crate::flat::ENVELOPE_WITH_ARROW as &crate::Emoji),
-("equatorial_guinea" , &crate::flat::FLAG_EQUATORIAL_GUINEA as &crate::Emoji),
-("eritrea" , &crate::flat::FLAG_ERITREA as &crate::Emoji),
-("es" , &crate::flat::FLAG_SPAIN as &crate::Emoji),
-("estonia" , &crate::flat::FLAG_ESTONIA as &crate::Emoji),
-("ethiopia" , &crate::flat::FLAG_ETHIOPIA as &crate::Emoji),
-("eu" , &crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
-("euro" , &crate::flat::EURO_BANKNOTE as &crate::Emoji),
-("euro_banknote" , &crate::flat::EURO_BANKNOTE as &crate::Emoji),
-("european_castle" , &crate::flat::CASTLE as &crate::Emoji),
-("european_post_office" , &crate::flat::POST_OFFICE as &crate::Emoji),
-("european_union" , &crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
-("evergreen_tree" , &crate::flat::EVERGREEN_TREE as &crate::Emoji),
-("ewe" , &crate::flat::EWE as &crate::Emoji),
-("exclamation" , &crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
-("exclamation_question_mark" , &crate::flat::EXCLAMATION_QUESTION_MARK as &crate::Emoji),
-("exploding_head" , &crate::flat::EXPLODING_HEAD as &crate::Emoji),
-("expressionless" , &crate::flat::EXPRESSIONLESS_FACE as &crate::Emoji),
-("expressionless_face" , &crate::flat::EXPRESSIONLESS_FACE as &crate::Emoji),
-("eye" , &crate::flat::EYE as &crate::Emoji),
-("eye_in_speech_bubble" , &crate::flat::EYE_IN_SPEECH_BUBBLE as &crate::Emoji),
-("eye_speech_bubble" , &crate::flat::EYE_IN_SPEECH_BUBBLE as &crate::Emoji),
-("eyeglasses" , &crate::flat::GLASSES as &crate::Emoji),
-("eyes" , &crate::flat::EYES as &crate::Emoji),
-("face_blowing_a_kiss" , &crate::flat::FACE_BLOWING_A_KISS as &crate::Emoji),
-("face_exhaling" , &crate::flat::FACE_EXHALING as &crate::Emoji),
-("face_in_clouds" , &crate::flat::FACE_IN_CLOUDS as &crate::Emoji),
-("face_savoring_food" , &crate::flat::FACE_SAVORING_FOOD as &crate::Emoji),
-("face_screaming_in_fear" , &crate::flat::FACE_SCREAMING_IN_FEAR as &crate::Emoji),
-("face_vomiting" , &crate::flat::FACE_VOMITING as &crate::Emoji),
-("face_with_hand_over_mouth" , &crate::flat::FACE_WITH_HAND_OVER_MOUTH as &crate::Emoji),
-("face_with_head_bandage" , &crate::flat::FACE_WITH_HEAD_BANDAGE as &crate::Emoji),
-("face_with_medical_mask" , &crate::flat::FACE_WITH_MEDICAL_MASK as &crate::Emoji),
-("face_with_monocle" , &crate::flat::FACE_WITH_MONOCLE as &crate::Emoji),
-("face_with_open_mouth" , &crate::flat::FACE_WITH_OPEN_MOUTH as &crate::Emoji),
-("face_with_raised_eyebrow" , &crate::flat::FACE_WITH_RAISED_EYEBROW as &crate::Emoji),
-("face_with_rolling_eyes" , &crate::flat::FACE_WITH_ROLLING_EYES as &crate::Emoji),
-("face_with_spiral_eyes" , &crate::flat::FACE_WITH_SPIRAL_EYES as &crate::Emoji),
-("face_with_steam_from_nose" , &crate::flat::FACE_WITH_STEAM_FROM_NOSE as &crate::Emoji),
-("face_with_symbols_on_mouth" , &crate::flat::FACE_WITH_SYMBOLS_ON_MOUTH as &crate::Emoji),
-("face_with_tears_of_joy" , &crate::flat::FACE_WITH_TEARS_OF_JOY as &crate::Emoji),
-("face_with_thermometer" , &crate::flat::FACE_WITH_THERMOMETER as &crate::Emoji),
-("face_with_tongue" , &crate::flat::FACE_WITH_TONGUE as &crate::Emoji),
-("face_without_mouth" , &crate::flat::FACE_WITHOUT_MOUTH as &crate::Emoji),
-("facepalm" , &crate::flat::PERSON_FACEPALMING.default.default as &crate::Emoji),
-("facepunch" , &crate::flat::ONCOMING_FIST.default as &crate::Emoji),
-("factory" , &crate::flat::FACTORY as &crate::Emoji),
-("factory_worker" , &crate::flat::FACTORY_WORKER.default.default as &crate::Emoji),
-("fairy" , &crate::flat::FAIRY.default.default as &crate::Emoji),
-("fairy_man" , &crate::flat::FAIRY.gender(Gender::Male).default as &crate::Emoji),
-("fairy_woman" , &crate::flat::FAIRY.gender(Gender::Female).default as &crate::Emoji),
-("falafel" , &crate::flat::FALAFEL as &crate::Emoji),
-("falkland_islands" , &crate::flat::FLAG_FALKLAND_ISLANDS as &crate::Emoji),
-("fallen_leaf" , &crate::flat::FALLEN_LEAF as &crate::Emoji),
-("family" , &crate::flat::FAMILY.default as &crate::Emoji),
-("family_man_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
-("family_man_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
-("family_man_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
-("family_man_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
-("family_man_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
-("family_man_man_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
-("family_man_man_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
-("family_man_man_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
-("family_man_man_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
-("family_man_man_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
-("family_man_woman_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
-("family_man_woman_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
-("family_man_woman_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
-("family_man_woman_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
-("family_man_woman_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
-("family_woman_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
-("family_woman_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
-("family_woman_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
-("family_woman_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
-("family_woman_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
-("family_woman_woman_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
-("family_woman_woman_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
-("family_woman_woman_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
-("family_woman_woman_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
-("family_woman_woman_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
-("farmer" , &crate::flat::FARMER.default.default as &crate::Emoji),
-("faroe_islands" , &crate::flat::FLAG_FAROE_ISLANDS as &crate::Emoji),
-("fast_down_button" , &crate::flat::FAST_DOWN_BUTTON as &crate::Emoji),
-("fast_forward" , &crate::flat::FAST_FORWARD_BUTTON as &crate::Emoji),
-("fast_forward_button" , &crate::flat::FAST_FORWARD_BUTTON as &crate::Emoji),
-("fast_reverse_button" , &crate::flat::FAST_REVERSE_BUTTON as &crate::Emoji),
-("fast_up_button" , &crate::flat::FAST_UP_BUTTON as &crate::Emoji),
-("fax" , &crate::flat::FAX_MACHINE as &crate::Emoji),
-("fax_machine" , &crate::flat::FAX_MACHINE as &crate::Emoji),
-("fearful" , &crate::flat::FEARFUL_FACE as &crate::Emoji),
-("fearful_face" , &crate::flat::FEARFUL_FACE as &crate::Emoji),
-("feather" , &crate::flat::FEATHER as &crate::Emoji),
-("feet" , &crate::flat::PAW_PRINTS as &crate::Emoji),
-("female_detective" , &crate::flat::DETECTIVE.gender(Gender::Female).default as &crate::Emoji),
-("female_sign" , &crate::flat::FEMALE_SIGN as &crate::Emoji),
-("ferris_wheel" , &crate::flat::FERRIS_WHEEL as &crate::Emoji),
-("ferry" , &crate::flat::FERRY as &crate::Emoji),
-("field_hockey" , &crate::flat::FIELD_HOCKEY as &crate::Emoji),
-("fiji" , &crate::flat::FLAG_FIJI as &crate::Emoji),
-("file_cabinet" , &crate::flat::FILE_CABINET as &crate::Emoji),
-("file_folder" , &crate::flat::FILE_FOLDER as &crate::Emoji),
-("film_frames" , &crate::flat::FILM_FRAMES as &crate::Emoji),
-("film_projector" , &crate::flat::FILM_PROJECTOR as &crate::Emoji),
-("film_strip" , &crate::flat::FILM_FRAMES as &crate::Emoji),
-("finland" , &crate::flat::FLAG_FINLAND as &crate::Emoji),
-("fire" , &crate::flat::FIRE as &crate::Emoji),
-("fire_engine" , &crate::flat::FIRE_ENGINE as &crate::Emoji),
-("fire_extinguisher" , &crate::flat::FIRE_EXTINGUISHER as &crate::Emoji),
-("firecracker" , &crate::flat::FIRECRACKER as &crate::Emoji),
-("firefighter" , &crate::flat::FIREFIGHTER.default.default as &crate::Emoji),
-("fireworks" , &crate::flat::FIREWORKS as &crate::Emoji),
-("first_place_medal" , &crate::flat::FIRST_PLACE_MEDAL as &crate::Emoji),
-("first_quarter_moon" , &crate::flat::FIRST_QUARTER_MOON as &crate::Emoji),
-("first_quarter_moon_face" , &crate::flat::FIRST_QUARTER_MOON_FACE as &crate::Emoji),
-("first_quarter_moon_with_face" , &crate::flat::FIRST_QUARTER_MOON_FACE as &crate::Emoji),
-("fish" , &crate::flat::FISH as &crate::Emoji),
-("fish_cake" , &crate::flat::FISH_CAKE_WITH_SWIRL as &crate::Emoji),
-("fish_cake_with_swirl" , &crate::flat::FISH_CAKE_WITH_SWIRL as &crate::Emoji),
-("fishing_pole" , &crate::flat::FISHING_POLE as &crate::Emoji),
-("fishing_pole_and_fish" , &crate::flat::FISHING_POLE as &crate::Emoji),
-("fist" , &crate::flat::RAISED_FIST.default as &crate::Emoji),
-("fist_left" , &crate::flat::LEFT_FACING_FIST.default as &crate::Emoji),
-("fist_oncoming" , &crate::flat::ONCOMING_FIST.default as &crate::Emoji),
-("fist_raised" , &crate::flat::RAISED_FIST.default as &crate::Emoji),
-("fist_right" , &crate::flat::RIGHT_FACING_FIST.default as &crate::Emoji),
-("five" , &crate::flat::KEYCAP_5 as &crate::Emoji),
-("five_o_clock" , &crate::flat::FIVE_O_CLOCK as &crate::Emoji),
-("five_thirty" , &crate::flat::FIVE_THIRTY as &crate::Emoji),
-("flag_afghanistan" , &crate::flat::FLAG_AFGHANISTAN as &crate::Emoji),
-("flag_aland_islands" , &crate::flat::FLAG_ALAND_ISLANDS as &crate::Emoji),
-("flag_albania" , &crate::flat::FLAG_ALBANIA as &crate::Emoji),
-("flag_algeria" , &crate::flat::FLAG_ALGERIA as &crate::Emoji),
-("flag_american_samoa" , &crate::flat::FLAG_AMERICAN_SAMOA as &crate::Emoji),
-("flag_andorra" , &crate::flat::FLAG_ANDORRA as &crate::Emoji),
-("flag_angola" , &crate::flat::FLAG_ANGOLA as &crate::Emoji),
-("flag_anguilla" , &crate::flat::FLAG_ANGUILLA as &crate::Emoji),
-("flag_antarctica" , &crate::flat::FLAG_ANTARCTICA as &crate::Emoji),
-("flag_antigua_and_barbuda" , &crate::flat::FLAG_ANTIGUA_AND_BARBUDA as &crate::Emoji),
-("flag_argentina" , &crate::flat::FLAG_ARGENTINA as &crate::Emoji),
-("flag_armenia" , &crate::flat::FLAG_ARMENIA as &crate::Emoji),
-("flag_aruba" , &crate::flat::FLAG_ARUBA as &crate::Emoji),
-("flag_ascension_island" , &crate::flat::FLAG_ASCENSION_ISLAND as &crate::Emoji),
-("flag_australia" , &crate::flat::FLAG_AUSTRALIA as &crate::Emoji),
-("flag_austria" , &crate::flat::FLAG_AUSTRIA as &crate::Emoji),
-("flag_azerbaijan" , &crate::flat::FLAG_AZERBAIJAN as &crate::Emoji),
-("flag_bahamas" , &crate::flat::FLAG_BAHAMAS as &crate::Emoji),
-("flag_bahrain" , &crate::flat::FLAG_BAHRAIN as &crate::Emoji),
-("flag_bangladesh" , &crate::flat::FLAG_BANGLADESH as &crate::Emoji),
-("flag_barbados" , &crate::flat::FLAG_BARBADOS as &crate::Emoji),
-("flag_belarus" , &crate::flat::FLAG_BELARUS as &crate::Emoji),
-("flag_belgium" , &crate::flat::FLAG_BELGIUM as &crate::Emoji),
-("flag_belize" , &crate::flat::FLAG_BELIZE as &crate::Emoji),
-("flag_benin" , &crate::flat::FLAG_BENIN as &crate::Emoji),
-("flag_bermuda" , &crate::flat::FLAG_BERMUDA as &crate::Emoji),
-("flag_bhutan" , &crate::flat::FLAG_BHUTAN as &crate::Emoji),
-("flag_bolivia" , &crate::flat::FLAG_BOLIVIA as &crate::Emoji),
-("flag_bosnia_and_herzegovina" , &crate::flat::FLAG_BOSNIA_AND_HERZEGOVINA as &crate::Emoji),
-("flag_botswana" , &crate::flat::FLAG_BOTSWANA as &crate::Emoji),
-("flag_bouvet_island" , &crate::flat::FLAG_BOUVET_ISLAND as &crate::Emoji),
-("flag_brazil" , &crate::flat::FLAG_BRAZIL as &crate::Emoji),
-("flag_british_indian_ocean_territory" , &crate::flat::FLAG_BRITISH_INDIAN_OCEAN_TERRITORY as &crate::Emoji),
-("flag_british_virgin_islands" , &crate::flat::FLAG_BRITISH_VIRGIN_ISLANDS as &crate::Emoji),
-("flag_brunei" , &crate::flat::FLAG_BRUNEI as &crate::Emoji),
-("flag_bulgaria" , &crate::flat::FLAG_BULGARIA as &crate::Emoji),
-("flag_burkina_faso" , &crate::flat::FLAG_BURKINA_FASO as &crate::Emoji),
-("flag_burundi" , &crate::flat::FLAG_BURUNDI as &crate::Emoji),
-("flag_cambodia" , &crate::flat::FLAG_CAMBODIA as &crate::Emoji),
-("flag_cameroon" , &crate::flat::FLAG_CAMEROON as &crate::Emoji),
-("flag_canada" , &crate::flat::FLAG_CANADA as &crate::Emoji),
-("flag_canary_islands" , &crate::flat::FLAG_CANARY_ISLANDS as &crate::Emoji),
-("flag_cape_verde" , &crate::flat::FLAG_CAPE_VERDE as &crate::Emoji),
-("flag_caribbean_netherlands" , &crate::flat::FLAG_CARIBBEAN_NETHERLANDS as &crate::Emoji),
-("flag_cayman_islands" , &crate::flat::FLAG_CAYMAN_ISLANDS as &crate::Emoji),
-("flag_central_african_republic" , &crate::flat::FLAG_CENTRAL_AFRICAN_REPUBLIC as &crate::Emoji),
-("flag_ceuta_and_melilla" , &crate::flat::FLAG_CEUTA_AND_MELILLA as &crate::Emoji),
-("flag_chad" , &crate::flat::FLAG_CHAD as &crate::Emoji),
-("flag_chile" , &crate::flat::FLAG_CHILE as &crate::Emoji),
-("flag_china" , &crate::flat::FLAG_CHINA as &crate::Emoji),
-("flag_christmas_island" , &crate::flat::FLAG_CHRISTMAS_ISLAND as &crate::Emoji),
-("flag_clipperton_island" , &crate::flat::FLAG_CLIPPERTON_ISLAND as &crate::Emoji),
-("flag_cocos_keeling_islands" , &crate::flat::FLAG_COCOS_KEELING_ISLANDS as &crate::Emoji),
-("flag_colombia" , &crate::flat::FLAG_COLOMBIA as &crate::Emoji),
-("flag_comoros" , &crate::flat::FLAG_COMOROS as &crate::Emoji),
-("flag_congo_brazzaville" , &crate::flat::FLAG_CONGO_BRAZZAVILLE as &crate::Emoji),
-("flag_congo_kinshasa" , &crate::flat::FLAG_CONGO_KINSHASA as &crate::Emoji),
-("flag_cook_islands" , &crate::flat::FLAG_COOK_ISLANDS as &crate::Emoji),
-("flag_costa_rica" , &crate::flat::FLAG_COSTA_RICA as &crate::Emoji),
-("flag_cote_d_ivoire" , &crate::flat::FLAG_COTE_D_IVOIRE as &crate::Emoji),
-("flag_croatia" , &crate::flat::FLAG_CROATIA as &crate::Emoji),
-("flag_cuba" , &crate::flat::FLAG_CUBA as &crate::Emoji),
-("flag_curacao" , &crate::flat::FLAG_CURACAO as &crate::Emoji),
-("flag_cyprus" , &crate::flat::FLAG_CYPRUS as &crate::Emoji),
-("flag_czechia" , &crate::flat::FLAG_CZECHIA as &crate::Emoji),
-("flag_denmark" , &crate::flat::FLAG_DENMARK as &crate::Emoji),
-("flag_diego_garcia" , &crate::flat::FLAG_DIEGO_GARCIA as &crate::Emoji),
-("flag_djibouti" , &crate::flat::FLAG_DJIBOUTI as &crate::Emoji),
-("flag_dominica" , &crate::flat::FLAG_DOMINICA as &crate::Emoji),
-("flag_dominican_republic" , &crate::flat::FLAG_DOMINICAN_REPUBLIC as &crate::Emoji),
-("flag_ecuador" , &crate::flat::FLAG_ECUADOR as &crate::Emoji),
-("flag_egypt" , &crate::flat::FLAG_EGYPT as &crate::Emoji),
-("flag_el_salvador" , &crate::flat::FLAG_EL_SALVADOR as &crate::Emoji),
-("flag_england" , &crate::flat::FLAG_ENGLAND as &crate::Emoji),
-("flag_equatorial_guinea" , &crate::flat::FLAG_EQUATORIAL_GUINEA as &crate::Emoji),
-("flag_eritrea" , &crate::flat::FLAG_ERITREA as &crate::Emoji),
-("flag_estonia" , &crate::flat::FLAG_ESTONIA as &crate::Emoji),
-("flag_eswatini" , &crate::flat::FLAG_ESWATINI as &crate::Emoji),
-("flag_ethiopia" , &crate::flat::FLAG_ETHIOPIA as &crate::Emoji),
-("flag_european_union" , &crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
-("flag_falkland_islands" , &crate::flat::FLAG_FALKLAND_ISLANDS as &crate::Emoji),
-("flag_faroe_islands" , &crate::flat::FLAG_FAROE_ISLANDS as &crate::Emoji),
-("flag_fiji" , &crate::flat::FLAG_FIJI as &crate::Emoji),
-("flag_finland" , &crate::flat::FLAG_FINLAND as &crate::Emoji),
-("flag_france" , &crate::flat::FLAG_FRANCE as &crate::Emoji),
-("flag_french_guiana" , &crate::flat::FLAG_FRENCH_GUIANA as &crate::Emoji),
-("flag_french_polynesia" , &crate::flat::FLAG_FRENCH_POLYNESIA as &crate::Emoji),
-("flag_french_southern_territories" , &crate::flat::FLAG_FRENCH_SOUTHERN_TERRITORIES as &crate::Emoji),
-("flag_gabon" , &crate::flat::FLAG_GABON as &crate::Emoji),
-("flag_gambia" , &crate::flat::FLAG_GAMBIA as &crate::Emoji),
-("flag_georgia" , &crate::flat::FLAG_GEORGIA as &crate::Emoji),
-("flag_germany" , &crate::flat::FLAG_GERMANY as &crate::Emoji),
-("flag_ghana" , &crate::flat::FLAG_GHANA as &crate::Emoji),
-("flag_gibraltar" , &crate::flat::FLAG_GIBRALTAR as &crate::Emoji),
-("flag_greece" , &crate::flat::FLAG_GREECE as &crate::Emoji),
-("flag_greenland" , &crate::flat::FLAG_GREENLAND as &crate::Emoji),
-("flag_grenada" , &crate::flat::FLAG_GRENADA as &crate::Emoji),
-("flag_guadeloupe" , &crate::flat::FLAG_GUADELOUPE as &crate::Emoji),
-("flag_guam" , &crate::flat::FLAG_GUAM as &crate::Emoji),
-("flag_guatemala" , &crate::flat::FLAG_GUATEMALA as &crate::Emoji),
-("flag_guernsey" , &crate::flat::FLAG_GUERNSEY as &crate::Emoji),
-("flag_guinea" , &crate::flat::FLAG_GUINEA as &crate::Emoji),
-("flag_guinea_bissau" , &crate::flat::FLAG_GUINEA_BISSAU as &crate::Emoji),
-("flag_guyana" , &crate::flat::FLAG_GUYANA as &crate::Emoji),
-("flag_haiti" , &crate::flat::FLAG_HAITI as &crate::Emoji),
-("flag_heard_and_mcdonald_islands" , &crate::flat::FLAG_HEARD_AND_MCDONALD_ISLANDS as &crate::Emoji),
-("flag_honduras" , &crate::flat::FLAG_HONDURAS as &crate::Emoji),
-("flag_hong_kong_sar_china" , &crate::flat::FLAG_HONG_KONG_SAR_CHINA as &crate::Emoji),
-("flag_hungary" , &crate::flat::FLAG_HUNGARY as &crate::Emoji),
-("flag_iceland" , &crate::flat::FLAG_ICELAND as &crate::Emoji),
-("flag_in_hole" , &crate::flat::FLAG_IN_HOLE as &crate::Emoji),
-("flag_india" , &crate::flat::FLAG_INDIA as &crate::Emoji),
-("flag_indonesia" , &crate::flat::FLAG_INDONESIA as &crate::Emoji),
-("flag_iran" , &crate::flat::FLAG_IRAN as &crate::Emoji),
-("flag_iraq" , &crate::flat::FLAG_IRAQ as &crate::Emoji),
-("flag_ireland" , &crate::flat::FLAG_IRELAND as &crate::Emoji),
-("flag_isle_of_man" , &crate::flat::FLAG_ISLE_OF_MAN as &crate::Emoji),
-("flag_israel" , &crate::flat::FLAG_ISRAEL as &crate::Emoji),
-("flag_italy" , &crate::flat::FLAG_ITALY as &crate::Emoji),
-("flag_jamaica" , &crate::flat::FLAG_JAMAICA as &crate::Emoji),
-("flag_japan" , &crate::flat::FLAG_JAPAN as &crate::Emoji),
-("flag_jersey" , &crate::flat::FLAG_JERSEY as &crate::Emoji),
-("flag_jordan" , &crate::flat::FLAG_JORDAN as &crate::Emoji),
-("flag_kazakhstan" , &crate::flat::FLAG_KAZAKHSTAN as &crate::Emoji),
-("flag_kenya" , &crate::flat::FLAG_KENYA as &crate::Emoji),
-("flag_kiribati" , &crate::flat::FLAG_KIRIBATI as &crate::Emoji),
-("flag_kosovo" , &crate::flat::FLAG_KOSOVO as &crate::Emoji),
-("flag_kuwait" , &crate::flat::FLAG_KUWAIT as &crate::Emoji),
-("flag_kyrgyzstan" , &crate::flat::FLAG_KYRGYZSTAN as &crate::Emoji),
-("flag_laos" , &crate::flat::FLAG_LAOS as &crate::Emoji),
-("flag_latvia" , &crate::flat::FLAG_LATVIA as &crate::Emoji),
-("flag_lebanon" , &crate::flat::FLAG_LEBANON as &crate::Emoji),
-("flag_lesotho" , &crate::flat::FLAG_LESOTHO as &crate::Emoji),
-("flag_liberia" , &crate::flat::FLAG_LIBERIA as &crate::Emoji),
-("flag_libya" , &crate::flat::FLAG_LIBYA as &crate::Emoji),
-("flag_liechtenstein" , &crate::flat::FLAG_LIECHTENSTEIN as &crate::Emoji),
-("flag_lithuania" , &crate::flat::FLAG_LITHUANIA as &crate::Emoji),
-("flag_luxembourg" , &crate::flat::FLAG_LUXEMBOURG as &crate::Emoji),
-("flag_macao_sar_china" , &crate::flat::FLAG_MACAO_SAR_CHINA as &crate::Emoji),
-("flag_madagascar" , &crate::flat::FLAG_MADAGASCAR as &crate::Emoji),
-("flag_malawi" , &crate::flat::FLAG_MALAWI as &crate::Emoji),
-("flag_malaysia" , &crate::flat::FLAG_MALAYSIA as &crate::Emoji),
-("flag_maldives" , &crate::flat::FLAG_MALDIVES as &crate::Emoji),
-("flag_mali" , &crate::flat::FLAG_MALI as &crate::Emoji),
-("flag_malta" , &crate::flat::FLAG_MALTA as &crate::Emoji),
-("flag_marshall_islands" , &crate::flat::FLAG_MARSHALL_ISLANDS as &crate::Emoji),
-("flag_martinique" , &crate::flat::FLAG_MARTINIQUE as &crate::Emoji),
-("flag_mauritania" , &crate::flat::FLAG_MAURITANIA as &crate::Emoji),
-("flag_mauritius" , &crate::flat::FLAG_MAURITIUS as &crate::Emoji),
-("flag_mayotte" , &crate::flat::FLAG_MAYOTTE as &crate::Emoji),
-("flag_mexico" , &crate::flat::FLAG_MEXICO as &crate::Emoji),
-("flag_micronesia" , &crate::flat::FLAG_MICRONESIA as &crate::Emoji),
-("flag_moldova" , &crate::flat::FLAG_MOLDOVA as &crate::Emoji),
-("flag_monaco" , &crate::flat::FLAG_MONACO as &crate::Emoji),
-("flag_mongolia" , &crate::flat::FLAG_MONGOLIA as &crate::Emoji),
-("flag_montenegro" , &crate::flat::FLAG_MONTENEGRO as &crate::Emoji),
-("flag_montserrat" , &crate::flat::FLAG_MONTSERRAT as &crate::Emoji),
-("flag_morocco" , &crate::flat::FLAG_MOROCCO as &crate::Emoji),
-("flag_mozambique" , &crate::flat::FLAG_MOZAMBIQUE as &crate::Emoji),
-("flag_myanmar_burma" , &crate::flat::FLAG_MYANMAR_BURMA as &crate::Emoji),
-("flag_namibia" , &crate::flat::FLAG_NAMIBIA as &crate::Emoji),
-("flag_nauru" , &crate::flat::FLAG_NAURU as &crate::Emoji),
-("flag_nepal" , &crate::flat::FLAG_NEPAL as &crate::Emoji),
-("flag_netherlands" , &crate::flat::FLAG_NETHERLANDS as &crate::Emoji),
-("flag_new_caledonia" , &crate::flat::FLAG_NEW_CALEDONIA as &crate::Emoji),
-("flag_new_zealand" , &crate::flat::FLAG_NEW_ZEALAND as &crate::Emoji),
-("flag_nicaragua" , &crate::flat::FLAG_NICARAGUA as &crate::Emoji),
-("flag_niger" , &crate::flat::FLAG_NIGER as &crate::Emoji),
-("flag_nigeria" , &crate::flat::FLAG_NIGERIA as &crate::Emoji),
-("flag_niue" , &crate::flat::FLAG_NIUE as &crate::Emoji),
-("flag_norfolk_island" , &crate::flat::FLAG_NORFOLK_ISLAND as &crate::Emoji),
-("flag_north_korea" , &crate::flat::FLAG_NORTH_KOREA as &crate::Emoji),
-("flag_north_macedonia" , &crate::flat::FLAG_NORTH_MACEDONIA as &crate::Emoji),
-("flag_northern_mariana_islands" , &crate::flat::FLAG_NORTHERN_MARIANA_ISLANDS as &crate::Emoji),
-("flag_norway" , &crate::flat::FLAG_NORWAY as &crate::Emoji),
-("flag_oman" , &crate::flat::FLAG_OMAN as &crate::Emoji),
-("flag_pakistan" , &crate::flat::FLAG_PAKISTAN as &crate::Emoji),
-("flag_palau" , &crate::flat::FLAG_PALAU as &crate::Emoji),
-("flag_palestinian_territories" , &crate::flat::FLAG_PALESTINIAN_TERRITORIES as &crate::Emoji),
-("flag_panama" , &crate::flat::FLAG_PANAMA as &crate::Emoji),
-("flag_papua_new_guinea" , &crate::flat::FLAG_PAPUA_NEW_GUINEA as &crate::Emoji),
-("flag_paraguay" , &crate::flat::FLAG_PARAGUAY as &crate::Emoji),
-("flag_peru" , &crate::flat::FLAG_PERU as &crate::Emoji),
-("flag_philippines" , &crate::flat::FLAG_PHILIPPINES as &crate::Emoji),
-("flag_pitcairn_islands" , &crate::flat::FLAG_PITCAIRN_ISLANDS as &crate::Emoji),
-("flag_poland" , &crate::flat::FLAG_POLAND as &crate::Emoji),
-("flag_portugal" , &crate::flat::FLAG_PORTUGAL as &crate::Emoji),
-("flag_puerto_rico" , &crate::flat::FLAG_PUERTO_RICO as &crate::Emoji),
-("flag_qatar" , &crate::flat::FLAG_QATAR as &crate::Emoji),
-("flag_reunion" , &crate::flat::FLAG_REUNION as &crate::Emoji),
-("flag_romania" , &crate::flat::FLAG_ROMANIA as &crate::Emoji),
-("flag_russia" , &crate::flat::FLAG_RUSSIA as &crate::Emoji),
-("flag_rwanda" , &crate::flat::FLAG_RWANDA as &crate::Emoji),
-("flag_samoa" , &crate::flat::FLAG_SAMOA as &crate::Emoji),
-("flag_san_marino" , &crate::flat::FLAG_SAN_MARINO as &crate::Emoji),
-("flag_sao_tome_and_principe" , &crate::flat::FLAG_SAO_TOME_AND_PRINCIPE as &crate::Emoji),
-("flag_saudi_arabia" , &crate::flat::FLAG_SAUDI_ARABIA as &crate::Emoji),
-("flag_scotland" , &crate::flat::FLAG_SCOTLAND as &crate::Emoji),
-("flag_senegal" , &crate::flat::FLAG_SENEGAL as &crate::Emoji),
-("flag_serbia" , &crate::flat::FLAG_SERBIA as &crate::Emoji),
-("flag_seychelles" , &crate::flat::FLAG_SEYCHELLES as &crate::Emoji),
-("flag_sierra_leone" , &crate::flat::FLAG_SIERRA_LEONE as &crate::Emoji),
-("flag_singapore" , &crate::flat::FLAG_SINGAPORE as &crate::Emoji),
-("flag_sint_maarten" , &crate::flat::FLAG_SINT_MAARTEN as &crate::Emoji),
-("flag_slovakia" , &crate::flat::FLAG_SLOVAKIA as &crate::Emoji),
-("flag_slovenia" , &crate::flat::FLAG_SLOVENIA as &crate::Emoji),
-("flag_solomon_islands" , &crate::flat::FLAG_SOLOMON_ISLANDS as &crate::Emoji),
-("flag_somalia" , &crate::flat::FLAG_SOMALIA as &crate::Emoji),
-("flag_south_africa" , &crate::flat::FLAG_SOUTH_AFRICA as &crate::Emoji),
-("flag_south_georgia_and_south_sandwich_islands" , &crate::flat::FLAG_SOUTH_GEORGIA_AND_SOUTH_SANDWICH_ISLANDS as &crate::Emoji),
-("flag_south_korea" , &crate::flat::FLAG_SOUTH_KOREA as &crate::Emoji),
-("flag_south_sudan" , &crate::flat::FLAG_SOUTH_SUDAN as &crate::Emoji),
-("flag_spain" , &crate::flat::FLAG_SPAIN as &crate::Emoji),
-("flag_sri_lanka" , &crate::flat::FLAG_SRI_LANKA as &crate::Emoji),
-("flag_st_barthelemy" , &crate::flat::FLAG_ST_BARTHELEMY as &crate::Emoji),
-("flag_st_helena" , &crate::flat::FLAG_ST_HELENA as &crate::Emoji),
-("flag_st_kitts_and_nevis" , &crate::flat::FLAG_ST_KITTS_AND_NEVIS as &crate::Emoji),
-("flag_st_lucia" , &crate::flat::FLAG_ST_LUCIA as &crate::Emoji),
-("flag_st_martin" , &crate::flat::FLAG_ST_MARTIN as &crate::Emoji),
-("flag_st_pierre_and_miquelon" , &crate::flat::FLAG_ST_PIERRE_AND_MIQUELON as &crate::Emoji),
-("flag_st_vincent_and_grenadines" , &crate::flat::FLAG_ST_VINCENT_AND_GRENADINES as &crate::Emoji),
-("flag_sudan" , &crate::flat::FLAG_SUDAN as &crate::Emoji),
-("flag_suriname" , &crate::flat::FLAG_SURINAME as &crate::Emoji),
-("flag_svalbard_and_jan_mayen" , &crate::flat::FLAG_SVALBARD_AND_JAN_MAYEN as &crate::Emoji),
-("flag_sweden" , &crate::flat::FLAG_SWEDEN as &crate::Emoji),
-("flag_switzerland" , &crate::flat::FLAG_SWITZERLAND as &crate::Emoji),
-("flag_syria" , &crate::flat::FLAG_SYRIA as &crate::Emoji),
-("flag_taiwan" , &crate::flat::FLAG_TAIWAN as &crate::Emoji),
-("flag_tajikistan" , &crate::flat::FLAG_TAJIKISTAN as &crate::Emoji),
-("flag_tanzania" , &crate::flat::FLAG_TANZANIA as &crate::Emoji),
-("flag_thailand" , &crate::flat::FLAG_THAILAND as &crate::Emoji),
-("flag_timor_leste" , &crate::flat::FLAG_TIMOR_LESTE as &crate::Emoji),
-("flag_togo" , &crate::flat::FLAG_TOGO as &crate::Emoji),
-("flag_tokelau" , &crate::flat::FLAG_TOKELAU as &crate::Emoji),
-("flag_tonga" , &crate::flat::FLAG_TONGA as &crate::Emoji),
-("flag_trinidad_and_tobago" , &crate::flat::FLAG_TRINIDAD_AND_TOBAGO as &crate::Emoji),
-("flag_tristan_da_cunha" , &crate::flat::FLAG_TRISTAN_DA_CUNHA as &crate::Emoji),
-("flag_tunisia" , &crate::flat::FLAG_TUNISIA as &crate::Emoji),
-("flag_turkey" , &crate::flat::FLAG_TURKEY as &crate::Emoji),
-("flag_turkmenistan" , &crate::flat::FLAG_TURKMENISTAN as &crate::Emoji),
-("flag_turks_and_caicos_islands" , &crate::flat::FLAG_TURKS_AND_CAICOS_ISLANDS as &crate::Emoji),
-("flag_tuvalu" , &crate::flat::FLAG_TUVALU as &crate::Emoji),
-("flag_uganda" , &crate::flat::FLAG_UGANDA as &crate::Emoji),
-("flag_ukraine" , &crate::flat::FLAG_UKRAINE as &crate::Emoji),
-("flag_united_arab_emirates" , &crate::flat::FLAG_UNITED_ARAB_EMIRATES as &crate::Emoji),
-("flag_united_kingdom" , &crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
-("flag_united_nations" , &crate::flat::FLAG_UNITED_NATIONS as &crate::Emoji),
-("flag_united_states" , &crate::flat::FLAG_UNITED_STATES as &crate::Emoji),
-("flag_uruguay" , &crate::flat::FLAG_URUGUAY as &crate::Emoji),
-("flag_us_outlying_islands" , &crate::flat::FLAG_US_OUTLYING_ISLANDS as &crate::Emoji),
-("flag_us_virgin_islands" , &crate::flat::FLAG_US_VIRGIN_ISLANDS as &crate::Emoji),
-("flag_uzbekistan" , &crate::flat::FLAG_UZBEKISTAN as &crate::Emoji),
-("flag_vanuatu" , &crate::flat::FLAG_VANUATU as &crate::Emoji),
-("flag_vatican_city" , &crate::flat::FLAG_VATICAN_CITY as &crate::Emoji),
-("flag_venezuela" , &crate::flat::FLAG_VENEZUELA as &crate::Emoji),
-("flag_vietnam" , &crate::flat::FLAG_VIETNAM as &crate::Emoji),
-("flag_wales" , &crate::flat::FLAG_WALES as &crate::Emoji),
-("flag_wallis_and_futuna" , &crate::flat::FLAG_WALLIS_AND_FUTUNA as &crate::Emoji),
-("flag_western_sahara" , &crate::flat::FLAG_WESTERN_SAHARA as &crate::Emoji),
-("flag_yemen" , &crate::flat::FLAG_YEMEN as &crate::Emoji),
-("flag_zambia" , &crate::flat::FLAG_ZAMBIA as &crate::Emoji),
-("flag_zimbabwe" , &crate::flat::FLAG_ZIMBABWE as &crate::Emoji),
-("flags" , &crate::flat::CARP_STREAMER as &crate::Emoji),
-("flamingo" , &crate::flat::FLAMINGO as &crate::Emoji),
-("flashlight" , &crate::flat::FLASHLIGHT as &crate::Emoji),
-("flat_shoe" , &crate::flat::FLAT_SHOE as &crate::Emoji),
-("flatbread" , &crate::flat::FLATBREAD as &crate::Emoji),
-("fleur_de_lis" , &crate::flat::FLEUR_DE_LIS as &crate::Emoji),
-("flexed_biceps" , &crate::flat::FLEXED_BICEPS.default as &crate::Emoji),
-("flight_arrival" , &crate::flat::AIRPLANE_ARRIVAL as &crate::Emoji),
-("flight_departure" , &crate::flat::AIRPLANE_DEPARTURE as &crate::Emoji),
-("flipper" , &crate::flat::DOLPHIN as &crate::Emoji),
-("floppy_disk" , &crate::flat::FLOPPY_DISK as &crate::Emoji),
-("flower_playing_cards" , &crate::flat::FLOWER_PLAYING_CARDS as &crate::Emoji),
-("flushed" , &crate::flat::FLUSHED_FACE as &crate::Emoji),
-("flushed_face" , &crate::flat::FLUSHED_FACE as &crate::Emoji),
-("fly" , &crate::flat::FLY as &crate::Emoji),
-("flying_disc" , &crate::flat::FLYING_DISC as &crate::Emoji),
-("flying_saucer" , &crate::flat::FLYING_SAUCER as &crate::Emoji),
-("fog" , &crate::flat::FOG as &crate::Emoji),
-("foggy" , &crate::flat::FOGGY as &crate::Emoji),
-("folded_hands" , &crate::flat::FOLDED_HANDS.default as &crate::Emoji),
-("fondue" , &crate::flat::FONDUE as &crate::Emoji),
-("foot" , &crate::flat::FOOT.default as &crate::Emoji),
-("football" , &crate::flat::AMERICAN_FOOTBALL as &crate::Emoji),
-("footprints" , &crate::flat::FOOTPRINTS as &crate::Emoji),
-("fork_and_knife" , &crate::flat::FORK_AND_KNIFE as &crate::Emoji),
-("fork_and_knife_with_plate" , &crate::flat::FORK_AND_KNIFE_WITH_PLATE as &crate::Emoji),
-("fortune_cookie" , &crate::flat::FORTUNE_COOKIE as &crate::Emoji),
-("fountain" , &crate::flat::FOUNTAIN as &crate::Emoji),
-("fountain_pen" , &crate::flat::FOUNTAIN_PEN as &crate::Emoji),
-("four" , &crate::flat::KEYCAP_4 as &crate::Emoji),
-("four_leaf_clover" , &crate::flat::FOUR_LEAF_CLOVER as &crate::Emoji),
-("four_o_clock" , &crate::flat::FOUR_O_CLOCK as &crate::Emoji),
-("four_thirty" , &crate::flat::FOUR_THIRTY as &crate::Emoji),
-("fox" , &crate::flat::FOX as &crate::Emoji),
-("fox_face" , &crate::flat::FOX as &crate::Emoji),
-("fr" , &crate::flat::FLAG_FRANCE as &crate::Emoji),
-("framed_picture" , &crate::flat::FRAMED_PICTURE as &crate::Emoji),
-("free" , &crate::flat::FREE_BUTTON as &crate::Emoji),
-("free_button" , &crate::flat::FREE_BUTTON as &crate::Emoji),
-("french_fries" , &crate::flat::FRENCH_FRIES as &crate::Emoji),
-("french_guiana" , &crate::flat::FLAG_FRENCH_GUIANA as &crate::Emoji),
-("french_polynesia" , &crate::flat::FLAG_FRENCH_POLYNESIA as &crate::Emoji),
-("french_southern_territories" , &crate::flat::FLAG_FRENCH_SOUTHERN_TERRITORIES as &crate::Emoji),
-("fried_egg" , &crate::flat::COOKING as &crate::Emoji),
-("fried_shrimp" , &crate::flat::FRIED_SHRIMP as &crate::Emoji),
-("fries" , &crate::flat::FRENCH_FRIES as &crate::Emoji),
-("frog" , &crate::flat::FROG as &crate::Emoji),
-("front_facing_baby_chick" , &crate::flat::FRONT_FACING_BABY_CHICK as &crate::Emoji),
-("frowning" , &crate::flat::FROWNING_FACE_WITH_OPEN_MOUTH as &crate::Emoji),
-("frowning_face" , &crate::flat::FROWNING_FACE as &crate::Emoji),
-("frowning_face_with_open_mouth" , &crate::flat::FROWNING_FACE_WITH_OPEN_MOUTH as &crate::Emoji),
-("frowning_man" , &crate::flat::PERSON_FROWNING.gender(Gender::Male).default as &crate::Emoji),
-("frowning_person" , &crate::flat::PERSON_FROWNING.default.default as &crate::Emoji),
-("frowning_woman" , &crate::flat::PERSON_FROWNING.gender(Gender::Female).default as &crate::Emoji),
-("fu" , &crate::flat::MIDDLE_FINGER.default as &crate::Emoji),
-("fuel_pump" , &crate::flat::FUEL_PUMP as &crate::Emoji),
-("fuelpump" , &crate::flat::FUEL_PUMP as &crate::Emoji),
-("full_moon" , &crate::flat::FULL_MOON as &crate::Emoji),
-("full_moon_face" , &crate::flat::FULL_MOON_FACE as &crate::Emoji),
-("full_moon_with_face" , &crate::flat::FULL_MOON_FACE as &crate::Emoji),
-("funeral_urn" , &crate::flat::FUNERAL_URN as &crate::Emoji),
-("gabon" , &crate::flat::FLAG_GABON as &crate::Emoji),
-("gambia" , &crate::flat::FLAG_GAMBIA as &crate::Emoji),
-("game_die" , &crate::flat::GAME_DIE as &crate::Emoji),
-("garlic" , &crate::flat::GARLIC as &crate::Emoji),
-("gb" , &crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
-("gear" , &crate::flat::GEAR as &crate::Emoji),
-("gem" , &crate::flat::GEM_STONE as &crate::Emoji),
-("gem_stone" , &crate::flat::GEM_STONE as &crate::Emoji),
-("gemini" , &crate::flat::GEMINI as &crate::Emoji),
-("genie" , &crate::flat::GENIE.default as &crate::Emoji),
-("genie_man" , &crate::flat::GENIE.gender(Gender::Male) as &crate::Emoji),
-("genie_woman" , &crate::flat::GENIE.gender(Gender::Female) as &crate::Emoji),
-("georgia" , &crate::flat::FLAG_GEORGIA as &crate::Emoji),
-("ghana" , &crate::flat::FLAG_GHANA as &crate::Emoji),
-("ghost" , &crate::flat::GHOST as &crate::Emoji),
-("gibraltar" , &crate::flat::FLAG_GIBRALTAR as &crate::Emoji),
-("gift" , &crate::flat::WRAPPED_GIFT as &crate::Emoji),
-("gift_heart" , &crate::flat::HEART_WITH_RIBBON as &crate::Emoji),
-("giraffe" , &crate::flat::GIRAFFE as &crate::Emoji),
-("girl" , &crate::flat::GIRL.default as &crate::Emoji),
-("glass_of_milk" , &crate::flat::GLASS_OF_MILK as &crate::Emoji),
-("glasses" , &crate::flat::GLASSES as &crate::Emoji),
-("globe_showing_americas" , &crate::flat::GLOBE_SHOWING_AMERICAS as &crate::Emoji),
-("globe_showing_asia_australia" , &crate::flat::GLOBE_SHOWING_ASIA_AUSTRALIA as &crate::Emoji),
-("globe_showing_europe_africa" , &crate::flat::GLOBE_SHOWING_EUROPE_AFRICA as &crate::Emoji),
-("globe_with_meridians" , &crate::flat::GLOBE_WITH_MERIDIANS as &crate::Emoji),
-("gloves" , &crate::flat::GLOVES as &crate::Emoji),
-("glowing_star" , &crate::flat::GLOWING_STAR as &crate::Emoji),
-("goal_net" , &crate::flat::GOAL_NET as &crate::Emoji),
-("goat" , &crate::flat::GOAT as &crate::Emoji),
-("goblin" , &crate::flat::GOBLIN as &crate::Emoji),
-("goggles" , &crate::flat::GOGGLES as &crate::Emoji),
-("golf" , &crate::flat::FLAG_IN_HOLE as &crate::Emoji),
-("golfing" , &crate::flat::PERSON_GOLFING.default.default as &crate::Emoji),
-("golfing_man" , &crate::flat::PERSON_GOLFING.gender(Gender::Male).default as &crate::Emoji),
-("golfing_woman" , &crate::flat::PERSON_GOLFING.gender(Gender::Female).default as &crate::Emoji),
-("gorilla" , &crate::flat::GORILLA as &crate::Emoji),
-("graduation_cap" , &crate::flat::GRADUATION_CAP as &crate::Emoji),
-("grapes" , &crate::flat::GRAPES as &crate::Emoji),
-("greece" , &crate::flat::FLAG_GREECE as &crate::Emoji),
-("green_apple" , &crate::flat::GREEN_APPLE as &crate::Emoji),
-("green_book" , &crate::flat::GREEN_BOOK as &crate::Emoji),
-("green_circle" , &crate::flat::GREEN_CIRCLE as &crate::Emoji),
-("green_heart" , &crate::flat::GREEN_HEART as &crate::Emoji),
-("green_salad" , &crate::flat::GREEN_SALAD as &crate::Emoji),
-("green_square" , &crate::flat::GREEN_SQUARE as &crate::Emoji),
-("greenland" , &crate::flat::FLAG_GREENLAND as &crate::Emoji),
-("grenada" , &crate::flat::FLAG_GRENADA as &crate::Emoji),
-("grey_exclamation" , &crate::flat::WHITE_EXCLAMATION_MARK as &crate::Emoji),
-("grey_question" , &crate::flat::WHITE_QUESTION_MARK as &crate::Emoji),
-("grimacing" , &crate::flat::GRIMACING_FACE as &crate::Emoji),
-("grimacing_face" , &crate::flat::GRIMACING_FACE as &crate::Emoji),
-("grin" , &crate::flat::BEAMING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-("grinning" , &crate::flat::GRINNING_FACE as &crate::Emoji),
-("grinning_cat" , &crate::flat::GRINNING_CAT as &crate::Emoji),
-("grinning_cat_with_smiling_eyes" , &crate::flat::GRINNING_CAT_WITH_SMILING_EYES as &crate::Emoji),
-("grinning_face" , &crate::flat::GRINNING_FACE as &crate::Emoji),
-("grinning_face_with_big_eyes" , &crate::flat::GRINNING_FACE_WITH_BIG_EYES as &crate::Emoji),
-("grinning_face_with_smiling_eyes" , &crate::flat::GRINNING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-("grinning_face_with_sweat" , &crate::flat::GRINNING_FACE_WITH_SWEAT as &crate::Emoji),
-("grinning_squinting_face" , &crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
-("growing_heart" , &crate::flat::GROWING_HEART as &crate::Emoji),
-("guadeloupe" , &crate::flat::FLAG_GUADELOUPE as &crate::Emoji),
-("guam" , &crate::flat::FLAG_GUAM as &crate::Emoji),
-("guard" , &crate::flat::GUARD.default.default as &crate::Emoji),
-("guardsman" , &crate::flat::GUARD.gender(Gender::Male).default as &crate::Emoji),
-("guardswoman" , &crate::flat::GUARD.gender(Gender::Female).default as &crate::Emoji),
-("guatemala" , &crate::flat::FLAG_GUATEMALA as &crate::Emoji),
-("guernsey" , &crate::flat::FLAG_GUERNSEY as &crate::Emoji),
-("guide_dog" , &crate::flat::GUIDE_DOG as &crate::Emoji),
-("guinea" , &crate::flat::FLAG_GUINEA as &crate::Emoji),
-("guinea_bissau" , &crate::flat::FLAG_GUINEA_BISSAU as &crate::Emoji),
-("guitar" , &crate::flat::GUITAR as &crate::Emoji),
-("gun" , &crate::flat::WATER_PISTOL as &crate::Emoji),
-("guyana" , &crate::flat::FLAG_GUYANA as &crate::Emoji),
-("haircut" , &crate::flat::PERSON_GETTING_HAIRCUT.default.default as &crate::Emoji),
-("haircut_man" , &crate::flat::PERSON_GETTING_HAIRCUT.gender(Gender::Male).default as &crate::Emoji),
-("haircut_woman" , &crate::flat::PERSON_GETTING_HAIRCUT.gender(Gender::Female).default as &crate::Emoji),
-("haiti" , &crate::flat::FLAG_HAITI as &crate::Emoji),
-("hamburger" , &crate::flat::HAMBURGER as &crate::Emoji),
-("hammer" , &crate::flat::HAMMER as &crate::Emoji),
-("hammer_and_pick" , &crate::flat::HAMMER_AND_PICK as &crate::Emoji),
-("hammer_and_wrench" , &crate::flat::HAMMER_AND_WRENCH as &crate::Emoji),
-("hamster" , &crate::flat::HAMSTER as &crate::Emoji),
-("hand" , &crate::flat::RAISED_HAND.default as &crate::Emoji),
-("hand_over_mouth" , &crate::flat::FACE_WITH_HAND_OVER_MOUTH as &crate::Emoji),
-("hand_with_fingers_splayed" , &crate::flat::HAND_WITH_FINGERS_SPLAYED.default as &crate::Emoji),
-("handbag" , &crate::flat::HANDBAG as &crate::Emoji),
-("handball_person" , &crate::flat::PERSON_PLAYING_HANDBALL.default.default as &crate::Emoji),
-("handshake" , &crate::flat::HANDSHAKE as &crate::Emoji),
-("hankey" , &crate::flat::PILE_OF_POO as &crate::Emoji),
-("hash" , &crate::flat::KEYCAP_HASH as &crate::Emoji),
-("hatched_chick" , &crate::flat::FRONT_FACING_BABY_CHICK as &crate::Emoji),
-("hatching_chick" , &crate::flat::HATCHING_CHICK as &crate::Emoji),
-("headphone" , &crate::flat::HEADPHONE as &crate::Emoji),
-("headphones" , &crate::flat::HEADPHONE as &crate::Emoji),
-("headstone" , &crate::flat::HEADSTONE as &crate::Emoji),
-("health_worker" , &crate::flat::HEALTH_WORKER.default.default as &crate::Emoji),
-("hear_no_evil" , &crate::flat::HEAR_NO_EVIL_MONKEY as &crate::Emoji),
-("hear_no_evil_monkey" , &crate::flat::HEAR_NO_EVIL_MONKEY as &crate::Emoji),
-("heard_mcdonald_islands" , &crate::flat::FLAG_HEARD_AND_MCDONALD_ISLANDS as &crate::Emoji),
-("heart" , &crate::flat::RED_HEART as &crate::Emoji),
-("heart_decoration" , &crate::flat::HEART_DECORATION as &crate::Emoji),
-("heart_exclamation" , &crate::flat::HEART_EXCLAMATION as &crate::Emoji),
-("heart_eyes" , &crate::flat::SMILING_FACE_WITH_HEART_EYES as &crate::Emoji),
-("heart_eyes_cat" , &crate::flat::SMILING_CAT_WITH_HEART_EYES as &crate::Emoji),
-("heart_on_fire" , &crate::flat::HEART_ON_FIRE as &crate::Emoji),
-("heart_suit" , &crate::flat::HEART_SUIT as &crate::Emoji),
-("heart_with_arrow" , &crate::flat::HEART_WITH_ARROW as &crate::Emoji),
-("heart_with_ribbon" , &crate::flat::HEART_WITH_RIBBON as &crate::Emoji),
-("heartbeat" , &crate::flat::BEATING_HEART as &crate::Emoji),
-("heartpulse" , &crate::flat::GROWING_HEART as &crate::Emoji),
-("hearts" , &crate::flat::HEART_SUIT as &crate::Emoji),
-("heavy_check_mark" , &crate::flat::CHECK_MARK as &crate::Emoji),
-("heavy_division_sign" , &crate::flat::DIVIDE as &crate::Emoji),
-("heavy_dollar_sign" , &crate::flat::HEAVY_DOLLAR_SIGN as &crate::Emoji),
-("heavy_exclamation_mark" , &crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
-("heavy_heart_exclamation" , &crate::flat::HEART_EXCLAMATION as &crate::Emoji),
-("heavy_minus_sign" , &crate::flat::MINUS as &crate::Emoji),
-("heavy_multiplication_x" , &crate::flat::MULTIPLY as &crate::Emoji),
-("heavy_plus_sign" , &crate::flat::PLUS as &crate::Emoji),
-("hedgehog" , &crate::flat::HEDGEHOG as &crate::Emoji),
-("helicopter" , &crate::flat::HELICOPTER as &crate::Emoji),
-("herb" , &crate::flat::HERB as &crate::Emoji),
-("hibiscus" , &crate::flat::HIBISCUS as &crate::Emoji),
-("high_brightness" , &crate::flat::BRIGHT_BUTTON as &crate::Emoji),
-("high_heel" , &crate::flat::HIGH_HEELED_SHOE as &crate::Emoji),
-("high_heeled_shoe" , &crate::flat::HIGH_HEELED_SHOE as &crate::Emoji),
-("high_speed_train" , &crate::flat::HIGH_SPEED_TRAIN as &crate::Emoji),
-("high_voltage" , &crate::flat::HIGH_VOLTAGE as &crate::Emoji),
-("hiking_boot" , &crate::flat::HIKING_BOOT as &crate::Emoji),
-("hindu_temple" , &crate::flat::HINDU_TEMPLE as &crate::Emoji),
-("hippopotamus" , &crate::flat::HIPPOPOTAMUS as &crate::Emoji),
-("hocho" , &crate::flat::KITCHEN_KNIFE as &crate::Emoji),
-("hole" , &crate::flat::HOLE as &crate::Emoji),
-("hollow_red_circle" , &crate::flat::HOLLOW_RED_CIRCLE as &crate::Emoji),
-("honduras" , &crate::flat::FLAG_HONDURAS as &crate::Emoji),
-("honey_pot" , &crate::flat::HONEY_POT as &crate::Emoji),
-("honeybee" , &crate::flat::HONEYBEE as &crate::Emoji),
-("hong_kong" , &crate::flat::FLAG_HONG_KONG_SAR_CHINA as &crate::Emoji),
-("hook" , &crate::flat::HOOK as &crate::Emoji),
-("horizontal_traffic_light" , &crate::flat::HORIZONTAL_TRAFFIC_LIGHT as &crate::Emoji),
-("horse" , &crate::flat::HORSE as &crate::Emoji),
-("horse_face" , &crate::flat::HORSE_FACE as &crate::Emoji),
-("horse_racing" , &crate::flat::HORSE_RACING.default as &crate::Emoji),
-("hospital" , &crate::flat::HOSPITAL as &crate::Emoji),
-("hot_beverage" , &crate::flat::HOT_BEVERAGE as &crate::Emoji),
-("hot_dog" , &crate::flat::HOT_DOG as &crate::Emoji),
-("hot_face" , &crate::flat::HOT_FACE as &crate::Emoji),
-("hot_pepper" , &crate::flat::HOT_PEPPER as &crate::Emoji),
-("hot_springs" , &crate::flat::HOT_SPRINGS as &crate::Emoji),
-("hotdog" , &crate::flat::HOT_DOG as &crate::Emoji),
-("hotel" , &crate::flat::HOTEL as &crate::Emoji),
-("hotsprings" , &crate::flat::HOT_SPRINGS as &crate::Emoji),
-("hourglass" , &crate::flat::HOURGLASS_DONE as &crate::Emoji),
-("hourglass_done" , &crate::flat::HOURGLASS_DONE as &crate::Emoji),
-("hourglass_flowing_sand" , &crate::flat::HOURGLASS_NOT_DONE as &crate::Emoji),
-("hourglass_not_done" , &crate::flat::HOURGLASS_NOT_DONE as &crate::Emoji),
-("house" , &crate::flat::HOUSE as &crate::Emoji),
-("house_with_garden" , &crate::flat::HOUSE_WITH_GARDEN as &crate::Emoji),
-("houses" , &crate::flat::HOUSES as &crate::Emoji),
-("hugging_face" , &crate::flat::HUGGING_FACE as &crate::Emoji),
-("hugs" , &crate::flat::HUGGING_FACE as &crate::Emoji),
-("hundred_points" , &crate::flat::HUNDRED_POINTS as &crate::Emoji),
-("hungary" , &crate::flat::FLAG_HUNGARY as &crate::Emoji),
-("hushed" , &crate::flat::HUSHED_FACE as &crate::Emoji),
-("hushed_face" , &crate::flat::HUSHED_FACE as &crate::Emoji),
-("hut" , &crate::flat::HUT as &crate::Emoji),
-("ice" , &crate::flat::ICE as &crate::Emoji),
-("ice_cream" , &crate::flat::ICE_CREAM as &crate::Emoji),
-("ice_cube" , &crate::flat::ICE as &crate::Emoji),
-("ice_hockey" , &crate::flat::ICE_HOCKEY as &crate::Emoji),
-("ice_skate" , &crate::flat::ICE_SKATE as &crate::Emoji),
-("icecream" , &crate::flat::SOFT_ICE_CREAM as &crate::Emoji),
-("iceland" , &crate::flat::FLAG_ICELAND as &crate::Emoji),
-("id" , &crate::flat::ID_BUTTON as &crate::Emoji),
-("id_button" , &crate::flat::ID_BUTTON as &crate::Emoji),
-("ideograph_advantage" , &crate::flat::JAPANESE_BARGAIN_BUTTON as &crate::Emoji),
-("imp" , &crate::flat::ANGRY_FACE_WITH_HORNS as &crate::Emoji),
-("inbox_tray" , &crate::flat::INBOX_TRAY as &crate::Emoji),
-("incoming_envelope" , &crate::flat::INCOMING_ENVELOPE as &crate::Emoji),
-("index_pointing_up" , &crate::flat::INDEX_POINTING_UP.default as &crate::Emoji),
-("india" , &crate::flat::FLAG_INDIA as &crate::Emoji),
-("indonesia" , &crate::flat::FLAG_INDONESIA as &crate::Emoji),
-("infinity" , &crate::flat::INFINITY as &crate::Emoji),
-("information" , &crate::flat::INFORMATION as &crate::Emoji),
-("information_desk_person" , &crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
-("information_source" , &crate::flat::INFORMATION as &crate::Emoji),
-("innocent" , &crate::flat::SMILING_FACE_WITH_HALO as &crate::Emoji),
-("input_latin_letters" , &crate::flat::INPUT_LATIN_LETTERS as &crate::Emoji),
-("input_latin_lowercase" , &crate::flat::INPUT_LATIN_LOWERCASE as &crate::Emoji),
-("input_latin_uppercase" , &crate::flat::INPUT_LATIN_UPPERCASE as &crate::Emoji),
-("input_numbers" , &crate::flat::INPUT_NUMBERS as &crate::Emoji),
-("input_symbols" , &crate::flat::INPUT_SYMBOLS as &crate::Emoji),
-("interrobang" , &crate::flat::EXCLAMATION_QUESTION_MARK as &crate::Emoji),
-("iphone" , &crate::flat::MOBILE_PHONE as &crate::Emoji),
-("iran" , &crate::flat::FLAG_IRAN as &crate::Emoji),
-("iraq" , &crate::flat::FLAG_IRAQ as &crate::Emoji),
-("ireland" , &crate::flat::FLAG_IRELAND as &crate::Emoji),
-("isle_of_man" , &crate::flat::FLAG_ISLE_OF_MAN as &crate::Emoji),
-("israel" , &crate::flat::FLAG_ISRAEL as &crate::Emoji),
-("it" , &crate::flat::FLAG_ITALY as &crate::Emoji),
-("izakaya_lantern" , &crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
-("jack_o_lantern" , &crate::flat::JACK_O_LANTERN as &crate::Emoji),
-("jamaica" , &crate::flat::FLAG_JAMAICA as &crate::Emoji),
-("japan" , &crate::flat::MAP_OF_JAPAN as &crate::Emoji),
-("japanese_acceptable_button" , &crate::flat::JAPANESE_ACCEPTABLE_BUTTON as &crate::Emoji),
-("japanese_application_button" , &crate::flat::JAPANESE_APPLICATION_BUTTON as &crate::Emoji),
-("japanese_bargain_button" , &crate::flat::JAPANESE_BARGAIN_BUTTON as &crate::Emoji),
-("japanese_castle" , &crate::flat::JAPANESE_CASTLE as &crate::Emoji),
-("japanese_congratulations_button" , &crate::flat::JAPANESE_CONGRATULATIONS_BUTTON as &crate::Emoji),
-("japanese_discount_button" , &crate::flat::JAPANESE_DISCOUNT_BUTTON as &crate::Emoji),
-("japanese_dolls" , &crate::flat::JAPANESE_DOLLS as &crate::Emoji),
-("japanese_free_of_charge_button" , &crate::flat::JAPANESE_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
-("japanese_goblin" , &crate::flat::GOBLIN as &crate::Emoji),
-("japanese_here_button" , &crate::flat::JAPANESE_HERE_BUTTON as &crate::Emoji),
-("japanese_monthly_amount_button" , &crate::flat::JAPANESE_MONTHLY_AMOUNT_BUTTON as &crate::Emoji),
-("japanese_no_vacancy_button" , &crate::flat::JAPANESE_NO_VACANCY_BUTTON as &crate::Emoji),
-("japanese_not_free_of_charge_button" , &crate::flat::JAPANESE_NOT_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
-("japanese_ogre" , &crate::flat::OGRE as &crate::Emoji),
-("japanese_open_for_business_button" , &crate::flat::JAPANESE_OPEN_FOR_BUSINESS_BUTTON as &crate::Emoji),
-("japanese_passing_grade_button" , &crate::flat::JAPANESE_PASSING_GRADE_BUTTON as &crate::Emoji),
-("japanese_post_office" , &crate::flat::JAPANESE_POST_OFFICE as &crate::Emoji),
-("japanese_prohibited_button" , &crate::flat::JAPANESE_PROHIBITED_BUTTON as &crate::Emoji),
-("japanese_reserved_button" , &crate::flat::JAPANESE_RESERVED_BUTTON as &crate::Emoji),
-("japanese_secret_button" , &crate::flat::JAPANESE_SECRET_BUTTON as &crate::Emoji),
-("japanese_service_charge_button" , &crate::flat::JAPANESE_SERVICE_CHARGE_BUTTON as &crate::Emoji),
-("japanese_symbol_for_beginner" , &crate::flat::JAPANESE_SYMBOL_FOR_BEGINNER as &crate::Emoji),
-("japanese_vacancy_button" , &crate::flat::JAPANESE_VACANCY_BUTTON as &crate::Emoji),
-("jeans" , &crate::flat::JEANS as &crate::Emoji),
-("jersey" , &crate::flat::FLAG_JERSEY as &crate::Emoji),
-("jigsaw" , &crate::flat::PUZZLE_PIECE as &crate::Emoji),
-("joker" , &crate::flat::JOKER as &crate::Emoji),
-("jordan" , &crate::flat::FLAG_JORDAN as &crate::Emoji),
-("joy" , &crate::flat::FACE_WITH_TEARS_OF_JOY as &crate::Emoji),
-("joy_cat" , &crate::flat::CAT_WITH_TEARS_OF_JOY as &crate::Emoji),
-("joystick" , &crate::flat::JOYSTICK as &crate::Emoji),
-("jp" , &crate::flat::FLAG_JAPAN as &crate::Emoji),
-("judge" , &crate::flat::JUDGE.default.default as &crate::Emoji),
-("juggling_person" , &crate::flat::PERSON_JUGGLING.default.default as &crate::Emoji),
-("kaaba" , &crate::flat::KAABA as &crate::Emoji),
-("kangaroo" , &crate::flat::KANGAROO as &crate::Emoji),
-("kazakhstan" , &crate::flat::FLAG_KAZAKHSTAN as &crate::Emoji),
-("kenya" , &crate::flat::FLAG_KENYA as &crate::Emoji),
-("key" , &crate::flat::KEY as &crate::Emoji),
-("keyboard" , &crate::flat::KEYBOARD as &crate::Emoji),
-("keycap_0" , &crate::flat::KEYCAP_0 as &crate::Emoji),
-("keycap_1" , &crate::flat::KEYCAP_1 as &crate::Emoji),
-("keycap_10" , &crate::flat::KEYCAP_10 as &crate::Emoji),
-("keycap_2" , &crate::flat::KEYCAP_2 as &crate::Emoji),
-("keycap_3" , &crate::flat::KEYCAP_3 as &crate::Emoji),
-("keycap_4" , &crate::flat::KEYCAP_4 as &crate::Emoji),
-("keycap_5" , &crate::flat::KEYCAP_5 as &crate::Emoji),
-("keycap_6" , &crate::flat::KEYCAP_6 as &crate::Emoji),
-("keycap_7" , &crate::flat::KEYCAP_7 as &crate::Emoji),
-("keycap_8" , &crate::flat::KEYCAP_8 as &crate::Emoji),
-("keycap_9" , &crate::flat::KEYCAP_9 as &crate::Emoji),
-("keycap_asterisk" , &crate::flat::KEYCAP_ASTERISK as &crate::Emoji),
-("keycap_hash" , &crate::flat::KEYCAP_HASH as &crate::Emoji),
-("keycap_ten" , &crate::flat::KEYCAP_10 as &crate::Emoji),
-("kick_scooter" , &crate::flat::KICK_SCOOTER as &crate::Emoji),
-("kimono" , &crate::flat::KIMONO as &crate::Emoji),
-("kiribati" , &crate::flat::FLAG_KIRIBATI as &crate::Emoji),
-("kiss" , &crate::flat::KISS.default.default as &crate::Emoji),
-("kiss_mark" , &crate::flat::KISS_MARK as &crate::Emoji),
-("kissing" , &crate::flat::KISSING_FACE as &crate::Emoji),
-("kissing_cat" , &crate::flat::KISSING_CAT as &crate::Emoji),
-("kissing_closed_eyes" , &crate::flat::KISSING_FACE_WITH_CLOSED_EYES as &crate::Emoji),
-("kissing_face" , &crate::flat::KISSING_FACE as &crate::Emoji),
-("kissing_face_with_closed_eyes" , &crate::flat::KISSING_FACE_WITH_CLOSED_EYES as &crate::Emoji),
-("kissing_face_with_smiling_eyes" , &crate::flat::KISSING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-("kissing_heart" , &crate::flat::FACE_BLOWING_A_KISS as &crate::Emoji),
-("kissing_smiling_eyes" , &crate::flat::KISSING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-("kitchen_knife" , &crate::flat::KITCHEN_KNIFE as &crate::Emoji),
-("kite" , &crate::flat::KITE as &crate::Emoji),
-("kiwi_fruit" , &crate::flat::KIWI_FRUIT as &crate::Emoji),
-("kneeling_man" , &crate::flat::PERSON_KNEELING.gender(Gender::Male).default as &crate::Emoji),
-("kneeling_person" , &crate::flat::PERSON_KNEELING.default.default as &crate::Emoji),
-("kneeling_woman" , &crate::flat::PERSON_KNEELING.gender(Gender::Female).default as &crate::Emoji),
-("knife" , &crate::flat::KITCHEN_KNIFE as &crate::Emoji),
-("knocked_out_face" , &crate::flat::KNOCKED_OUT_FACE as &crate::Emoji),
-("knot" , &crate::flat::KNOT as &crate::Emoji),
-("koala" , &crate::flat::KOALA as &crate::Emoji),
-("koko" , &crate::flat::JAPANESE_HERE_BUTTON as &crate::Emoji),
-("kosovo" , &crate::flat::FLAG_KOSOVO as &crate::Emoji),
-("kr" , &crate::flat::FLAG_SOUTH_KOREA as &crate::Emoji),
-("kuwait" , &crate::flat::FLAG_KUWAIT as &crate::Emoji),
-("kyrgyzstan" , &crate::flat::FLAG_KYRGYZSTAN as &crate::Emoji),
-("lab_coat" , &crate::flat::LAB_COAT as &crate::Emoji),
-("label" , &crate::flat::LABEL as &crate::Emoji),
-("lacrosse" , &crate::flat::LACROSSE as &crate::Emoji),
-("ladder" , &crate::flat::LADDER as &crate::Emoji),
-("lady_beetle" , &crate::flat::LADY_BEETLE as &crate::Emoji),
-("lantern" , &crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
-("laos" , &crate::flat::FLAG_LAOS as &crate::Emoji),
-("laptop" , &crate::flat::LAPTOP as &crate::Emoji),
-("large_blue_circle" , &crate::flat::BLUE_CIRCLE as &crate::Emoji),
-("large_blue_diamond" , &crate::flat::LARGE_BLUE_DIAMOND as &crate::Emoji),
-("large_orange_diamond" , &crate::flat::LARGE_ORANGE_DIAMOND as &crate::Emoji),
-("last_quarter_moon" , &crate::flat::LAST_QUARTER_MOON as &crate::Emoji),
-("last_quarter_moon_face" , &crate::flat::LAST_QUARTER_MOON_FACE as &crate::Emoji),
-("last_quarter_moon_with_face" , &crate::flat::LAST_QUARTER_MOON_FACE as &crate::Emoji),
-("last_track_button" , &crate::flat::LAST_TRACK_BUTTON as &crate::Emoji),
-("latin_cross" , &crate::flat::LATIN_CROSS as &crate::Emoji),
-("latvia" , &crate::flat::FLAG_LATVIA as &crate::Emoji),
-("laughing" , &crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
-("leaf_fluttering_in_wind" , &crate::flat::LEAF_FLUTTERING_IN_WIND as &crate::Emoji),
-("leafy_green" , &crate::flat::LEAFY_GREEN as &crate::Emoji),
-("leaves" , &crate::flat::LEAF_FLUTTERING_IN_WIND as &crate::Emoji),
-("lebanon" , &crate::flat::FLAG_LEBANON as &crate::Emoji),
-("ledger" , &crate::flat::LEDGER as &crate::Emoji),
-("left_arrow" , &crate::flat::LEFT_ARROW as &crate::Emoji),
-("left_arrow_curving_right" , &crate::flat::LEFT_ARROW_CURVING_RIGHT as &crate::Emoji),
-("left_facing_fist" , &crate::flat::LEFT_FACING_FIST.default as &crate::Emoji),
-("left_luggage" , &crate::flat::LEFT_LUGGAGE as &crate::Emoji),
-("left_right_arrow" , &crate::flat::LEFT_RIGHT_ARROW as &crate::Emoji),
-("left_speech_bubble" , &crate::flat::LEFT_SPEECH_BUBBLE as &crate::Emoji),
-("leftwards_arrow_with_hook" , &crate::flat::RIGHT_ARROW_CURVING_LEFT as &crate::Emoji),
-("leg" , &crate::flat::LEG.default as &crate::Emoji),
-("lemon" , &crate::flat::LEMON as &crate::Emoji),
-("leo" , &crate::flat::LEO as &crate::Emoji),
-("leopard" , &crate::flat::LEOPARD as &crate::Emoji),
-("lesotho" , &crate::flat::FLAG_LESOTHO as &crate::Emoji),
-("level_slider" , &crate::flat::LEVEL_SLIDER as &crate::Emoji),
-("liberia" , &crate::flat::FLAG_LIBERIA as &crate::Emoji),
-("libra" , &crate::flat::LIBRA as &crate::Emoji),
-("libya" , &crate::flat::FLAG_LIBYA as &crate::Emoji),
-("liechtenstein" , &crate::flat::FLAG_LIECHTENSTEIN as &crate::Emoji),
-("light_bulb" , &crate::flat::LIGHT_BULB as &crate::Emoji),
-("light_rail" , &crate::flat::LIGHT_RAIL as &crate::Emoji),
-("light_skin_tone" , &crate::flat::LIGHT_SKIN_TONE as &crate::Emoji),
-("link" , &crate::flat::LINK as &crate::Emoji),
-("linked_paperclips" , &crate::flat::LINKED_PAPERCLIPS as &crate::Emoji),
-("lion" , &crate::flat::LION as &crate::Emoji),
-("lips" , &crate::flat::MOUTH as &crate::Emoji),
-("lipstick" , &crate::flat::LIPSTICK as &crate::Emoji),
-("lithuania" , &crate::flat::FLAG_LITHUANIA as &crate::Emoji),
-("litter_in_bin_sign" , &crate::flat::LITTER_IN_BIN_SIGN as &crate::Emoji),
-("lizard" , &crate::flat::LIZARD as &crate::Emoji),
-("llama" , &crate::flat::LLAMA as &crate::Emoji),
-("lobster" , &crate::flat::LOBSTER as &crate::Emoji),
-("lock" , &crate::flat::LOCKED as &crate::Emoji),
-("lock_with_ink_pen" , &crate::flat::LOCKED_WITH_PEN as &crate::Emoji),
-("locked" , &crate::flat::LOCKED as &crate::Emoji),
-("locked_with_key" , &crate::flat::LOCKED_WITH_KEY as &crate::Emoji),
-("locked_with_pen" , &crate::flat::LOCKED_WITH_PEN as &crate::Emoji),
-("locomotive" , &crate::flat::LOCOMOTIVE as &crate::Emoji),
-("lollipop" , &crate::flat::LOLLIPOP as &crate::Emoji),
-("long_drum" , &crate::flat::LONG_DRUM as &crate::Emoji),
-("loop" , &crate::flat::DOUBLE_CURLY_LOOP as &crate::Emoji),
-("lotion_bottle" , &crate::flat::LOTION_BOTTLE as &crate::Emoji),
-("lotus_position" , &crate::flat::PERSON_IN_LOTUS_POSITION.default.default as &crate::Emoji),
-("lotus_position_man" , &crate::flat::PERSON_IN_LOTUS_POSITION.gender(Gender::Male).default as &crate::Emoji),
-("lotus_position_woman" , &crate::flat::PERSON_IN_LOTUS_POSITION.gender(Gender::Female).default as &crate::Emoji),
-("loud_sound" , &crate::flat::SPEAKER_HIGH_VOLUME as &crate::Emoji),
-("loudly_crying_face" , &crate::flat::LOUDLY_CRYING_FACE as &crate::Emoji),
-("loudspeaker" , &crate::flat::LOUDSPEAKER as &crate::Emoji),
-("love_hotel" , &crate::flat::LOVE_HOTEL as &crate::Emoji),
-("love_letter" , &crate::flat::LOVE_LETTER as &crate::Emoji),
-("love_you_gesture" , &crate::flat::LOVE_YOU_GESTURE.default as &crate::Emoji),
-("low_brightness" , &crate::flat::DIM_BUTTON as &crate::Emoji),
-("luggage" , &crate::flat::LUGGAGE as &crate::Emoji),
-("lungs" , &crate::flat::LUNGS as &crate::Emoji),
-("luxembourg" , &crate::flat::FLAG_LUXEMBOURG as &crate::Emoji),
-("lying_face" , &crate::flat::LYING_FACE as &crate::Emoji),
-("m" , &crate::flat::CIRCLED_M as &crate::Emoji),
-("macau" , &crate::flat::FLAG_MACAO_SAR_CHINA as &crate::Emoji),
-("macedonia" , &crate::flat::FLAG_NORTH_MACEDONIA as &crate::Emoji),
-("madagascar" , &crate::flat::FLAG_MADAGASCAR as &crate::Emoji),
-("mag" , &crate::flat::MAGNIFYING_GLASS_TILTED_LEFT as &crate::Emoji),
-("mag_right" , &crate::flat::MAGNIFYING_GLASS_TILTED_RIGHT as &crate::Emoji),
-("mage" , &crate::flat::MAGE.default.default as &crate::Emoji),
-("mage_man" , &crate::flat::MAGE.gender(Gender::Male).default as &crate::Emoji),
-("mage_woman" , &crate::flat::MAGE.gender(Gender::Female).default as &crate::Emoji),
-("magic_wand" , &crate::flat::MAGIC_WAND as &crate::Emoji),
-("magnet" , &crate::flat::MAGNET as &crate::Emoji),
-("magnifying_glass_tilted_left" , &crate::flat::MAGNIFYING_GLASS_TILTED_LEFT as &crate::Emoji),
-("magnifying_glass_tilted_right" , &crate::flat::MAGNIFYING_GLASS_TILTED_RIGHT as &crate::Emoji),
-("mahjong" , &crate::flat::MAHJONG_RED_DRAGON as &crate::Emoji),
-("mahjong_red_dragon" , &crate::flat::MAHJONG_RED_DRAGON as &crate::Emoji),
-("mailbox" , &crate::flat::CLOSED_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
-("mailbox_closed" , &crate::flat::CLOSED_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
-("mailbox_with_mail" , &crate::flat::OPEN_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
-("mailbox_with_no_mail" , &crate::flat::OPEN_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
-("malawi" , &crate::flat::FLAG_MALAWI as &crate::Emoji),
-("malaysia" , &crate::flat::FLAG_MALAYSIA as &crate::Emoji),
-("maldives" , &crate::flat::FLAG_MALDIVES as &crate::Emoji),
-("male_detective" , &crate::flat::DETECTIVE.gender(Gender::Male).default as &crate::Emoji),
-("male_sign" , &crate::flat::MALE_SIGN as &crate::Emoji),
-("mali" , &crate::flat::FLAG_MALI as &crate::Emoji),
-("malta" , &crate::flat::FLAG_MALTA as &crate::Emoji),
-("mammoth" , &crate::flat::MAMMOTH as &crate::Emoji),
-("man" , &crate::flat::PERSON.default.gender(Gender::Male).default as &crate::Emoji),
-("man_artist" , &crate::flat::ARTIST.gender(Gender::Male).default as &crate::Emoji),
-("man_astronaut" , &crate::flat::ASTRONAUT.gender(Gender::Male).default as &crate::Emoji),
-("man_beard" , &crate::flat::PERSON.hair(Hair::Beard).gender(Gender::Male).default as &crate::Emoji),
-("man_cartwheeling" , &crate::flat::PERSON_CARTWHEELING.gender(Gender::Male).default as &crate::Emoji),
-("man_cook" , &crate::flat::COOK.gender(Gender::Male).default as &crate::Emoji),
-("man_dancing" , &crate::flat::PERSON_DANCING.gender(Gender::Male).default as &crate::Emoji),
-("man_facepalming" , &crate::flat::PERSON_FACEPALMING.gender(Gender::Male).default as &crate::Emoji),
-("man_factory_worker" , &crate::flat::FACTORY_WORKER.gender(Gender::Male).default as &crate::Emoji),
-("man_farmer" , &crate::flat::FARMER.gender(Gender::Male).default as &crate::Emoji),
-("man_feeding_baby" , &crate::flat::PERSON_FEEDING_BABY.gender(Gender::Male).default as &crate::Emoji),
-("man_firefighter" , &crate::flat::FIREFIGHTER.gender(Gender::Male).default as &crate::Emoji),
-("man_health_worker" , &crate::flat::HEALTH_WORKER.gender(Gender::Male).default as &crate::Emoji),
-("man_in_manual_wheelchair" , &crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.gender(Gender::Male).default as &crate::Emoji),
-("man_in_motorized_wheelchair" , &crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.gender(Gender::Male).default as &crate::Emoji),
-("man_in_tuxedo" , &crate::flat::PERSON_IN_TUXEDO.gender(Gender::Male).default as &crate::Emoji),
-("man_judge" , &crate::flat::JUDGE.gender(Gender::Male).default as &crate::Emoji),
-("man_juggling" , &crate::flat::PERSON_JUGGLING.gender(Gender::Male).default as &crate::Emoji),
-("man_mechanic" , &crate::flat::MECHANIC.gender(Gender::Male).default as &crate::Emoji),
-("man_office_worker" , &crate::flat::OFFICE_WORKER.gender(Gender::Male).default as &crate::Emoji),
-("man_pilot" , &crate::flat::PILOT.gender(Gender::Male).default as &crate::Emoji),
-("man_playing_handball" , &crate::flat::PERSON_PLAYING_HANDBALL.gender(Gender::Male).default as &crate::Emoji),
-("man_playing_water_polo" , &crate::flat::PERSON_PLAYING_WATER_POLO.gender(Gender::Male).default as &crate::Emoji),
-("man_s_shoe" , &crate::flat::MAN_S_SHOE as &crate::Emoji),
-("man_scientist" , &crate::flat::SCIENTIST.gender(Gender::Male).default as &crate::Emoji),
-("man_shrugging" , &crate::flat::PERSON_SHRUGGING.gender(Gender::Male).default as &crate::Emoji),
-("man_singer" , &crate::flat::SINGER.gender(Gender::Male).default as &crate::Emoji),
-("man_student" , &crate::flat::STUDENT.gender(Gender::Male).default as &crate::Emoji),
-("man_teacher" , &crate::flat::TEACHER.gender(Gender::Male).default as &crate::Emoji),
-("man_technologist" , &crate::flat::TECHNOLOGIST.gender(Gender::Male).default as &crate::Emoji),
-("man_with_gua_pi_mao" , &crate::flat::PERSON_WITH_SKULLCAP.default as &crate::Emoji),
-("man_with_probing_cane" , &crate::flat::PERSON_WITH_WHITE_CANE.gender(Gender::Male).default as &crate::Emoji),
-("man_with_turban" , &crate::flat::PERSON_WEARING_TURBAN.gender(Gender::Male).default as &crate::Emoji),
-("man_with_veil" , &crate::flat::PERSON_WITH_VEIL.gender(Gender::Male).default as &crate::Emoji),
-("mandarin" , &crate::flat::TANGERINE as &crate::Emoji),
-("mango" , &crate::flat::MANGO as &crate::Emoji),
-("mans_shoe" , &crate::flat::MAN_S_SHOE as &crate::Emoji),
-("mantelpiece_clock" , &crate::flat::MANTELPIECE_CLOCK as &crate::Emoji),
-("manual_wheelchair" , &crate::flat::MANUAL_WHEELCHAIR as &crate::Emoji),
-("map_of_japan" , &crate::flat::MAP_OF_JAPAN as &crate::Emoji),
-("maple_leaf" , &crate::flat::MAPLE_LEAF as &crate::Emoji),
-("marshall_islands" , &crate::flat::FLAG_MARSHALL_ISLANDS as &crate::Emoji),
-("martial_arts_uniform" , &crate::flat::MARTIAL_ARTS_UNIFORM as &crate::Emoji),
-("martinique" , &crate::flat::FLAG_MARTINIQUE as &crate::Emoji),
-("mask" , &crate::flat::FACE_WITH_MEDICAL_MASK as &crate::Emoji),
-("massage" , &crate::flat::PERSON_GETTING_MASSAGE.default.default as &crate::Emoji),
-("massage_man" , &crate::flat::PERSON_GETTING_MASSAGE.gender(Gender::Male).default as &crate::Emoji),
-("massage_woman" , &crate::flat::PERSON_GETTING_MASSAGE.gender(Gender::Female).default as &crate::Emoji),
-("mate" , &crate::flat::MATE as &crate::Emoji),
-("mauritania" , &crate::flat::FLAG_MAURITANIA as &crate::Emoji),
-("mauritius" , &crate::flat::FLAG_MAURITIUS as &crate::Emoji),
-("mayotte" , &crate::flat::FLAG_MAYOTTE as &crate::Emoji),
-("meat_on_bone" , &crate::flat::MEAT_ON_BONE as &crate::Emoji),
-("mechanic" , &crate::flat::MECHANIC.default.default as &crate::Emoji),
-("mechanical_arm" , &crate::flat::MECHANICAL_ARM as &crate::Emoji),
-("mechanical_leg" , &crate::flat::MECHANICAL_LEG as &crate::Emoji),
-("medal_military" , &crate::flat::MILITARY_MEDAL as &crate::Emoji),
-("medal_sports" , &crate::flat::SPORTS_MEDAL as &crate::Emoji),
-("medical_symbol" , &crate::flat::MEDICAL_SYMBOL as &crate::Emoji),
-("medium_dark_skin_tone" , &crate::flat::MEDIUM_DARK_SKIN_TONE as &crate::Emoji),
-("medium_light_skin_tone" , &crate::flat::MEDIUM_LIGHT_SKIN_TONE as &crate::Emoji),
-("medium_skin_tone" , &crate::flat::MEDIUM_SKIN_TONE as &crate::Emoji),
-("mega" , &crate::flat::MEGAPHONE as &crate::Emoji),
-("megaphone" , &crate::flat::MEGAPHONE as &crate::Emoji),
-("melon" , &crate::flat::MELON as &crate::Emoji),
-("memo" , &crate::flat::MEMO as &crate::Emoji),
-("men_s_room" , &crate::flat::MEN_S_ROOM as &crate::Emoji),
-("men_wrestling" , &crate::flat::PERSON_WRESTLING.gender(Gender::Male) as &crate::Emoji),
-("mending_heart" , &crate::flat::MENDING_HEART as &crate::Emoji),
-("menorah" , &crate::flat::MENORAH as &crate::Emoji),
-("mens" , &crate::flat::MEN_S_ROOM as &crate::Emoji),
-("mermaid" , &crate::flat::MERMAID.default as &crate::Emoji),
-("merman" , &crate::flat::MERMAN.default as &crate::Emoji),
-("merperson" , &crate::flat::MERPERSON.default as &crate::Emoji),
-("metal" , &crate::flat::SIGN_OF_THE_HORNS.default as &crate::Emoji),
-("metro" , &crate::flat::METRO as &crate::Emoji),
-("mexico" , &crate::flat::FLAG_MEXICO as &crate::Emoji),
-("microbe" , &crate::flat::MICROBE as &crate::Emoji),
-("micronesia" , &crate::flat::FLAG_MICRONESIA as &crate::Emoji),
-("microphone" , &crate::flat::MICROPHONE as &crate::Emoji),
-("microscope" , &crate::flat::MICROSCOPE as &crate::Emoji),
-("middle_finger" , &crate::flat::MIDDLE_FINGER.default as &crate::Emoji),
-("military_helmet" , &crate::flat::MILITARY_HELMET as &crate::Emoji),
-("military_medal" , &crate::flat::MILITARY_MEDAL as &crate::Emoji),
-("milk_glass" , &crate::flat::GLASS_OF_MILK as &crate::Emoji),
-("milky_way" , &crate::flat::MILKY_WAY as &crate::Emoji),
-("minibus" , &crate::flat::MINIBUS as &crate::Emoji),
-("minidisc" , &crate::flat::COMPUTER_DISK as &crate::Emoji),
-("minus" , &crate::flat::MINUS as &crate::Emoji),
-("mirror" , &crate::flat::MIRROR as &crate::Emoji),
-("moai" , &crate::flat::MOAI as &crate::Emoji),
-("mobile_phone" , &crate::flat::MOBILE_PHONE as &crate::Emoji),
-("mobile_phone_off" , &crate::flat::MOBILE_PHONE_OFF as &crate::Emoji),
-("mobile_phone_with_arrow" , &crate::flat::MOBILE_PHONE_WITH_ARROW as &crate::Emoji),
-("moldova" , &crate::flat::FLAG_MOLDOVA as &crate::Emoji),
-("monaco" , &crate::flat::FLAG_MONACO as &crate::Emoji),
-("money_bag" , &crate::flat::MONEY_BAG as &crate::Emoji),
-("money_mouth_face" , &crate::flat::MONEY_MOUTH_FACE as &crate::Emoji),
-("money_with_wings" , &crate::flat::MONEY_WITH_WINGS as &crate::Emoji),
-("moneybag" , &crate::flat::MONEY_BAG as &crate::Emoji),
-("mongolia" , &crate::flat::FLAG_MONGOLIA as &crate::Emoji),
-("monkey" , &crate::flat::MONKEY as &crate::Emoji),
-("monkey_face" , &crate::flat::MONKEY_FACE as &crate::Emoji),
-("monocle_face" , &crate::flat::FACE_WITH_MONOCLE as &crate::Emoji),
-("monorail" , &crate::flat::MONORAIL as &crate::Emoji),
-("montenegro" , &crate::flat::FLAG_MONTENEGRO as &crate::Emoji),
-("montserrat" , &crate::flat::FLAG_MONTSERRAT as &crate::Emoji),
-("moon" , &crate::flat::WAXING_GIBBOUS_MOON as &crate::Emoji),
-("moon_cake" , &crate::flat::MOON_CAKE as &crate::Emoji),
-("moon_viewing_ceremony" , &crate::flat::MOON_VIEWING_CEREMONY as &crate::Emoji),
-("morocco" , &crate::flat::FLAG_MOROCCO as &crate::Emoji),
-("mortar_board" , &crate::flat::GRADUATION_CAP as &crate::Emoji),
-("mosque" , &crate::flat::MOSQUE as &crate::Emoji),
-("mosquito" , &crate::flat::MOSQUITO as &crate::Emoji),
-("motor_boat" , &crate::flat::MOTOR_BOAT as &crate::Emoji),
-("motor_scooter" , &crate::flat::MOTOR_SCOOTER as &crate::Emoji),
-("motorcycle" , &crate::flat::MOTORCYCLE as &crate::Emoji),
-("motorized_wheelchair" , &crate::flat::MOTORIZED_WHEELCHAIR as &crate::Emoji),
-("motorway" , &crate::flat::MOTORWAY as &crate::Emoji),
-("mount_fuji" , &crate::flat::MOUNT_FUJI as &crate::Emoji),
-("mountain" , &crate::flat::MOUNTAIN as &crate::Emoji),
-("mountain_bicyclist" , &crate::flat::PERSON_MOUNTAIN_BIKING.default.default as &crate::Emoji),
-("mountain_biking_man" , &crate::flat::PERSON_MOUNTAIN_BIKING.gender(Gender::Male).default as &crate::Emoji),
-("mountain_biking_woman" , &crate::flat::PERSON_MOUNTAIN_BIKING.gender(Gender::Female).default as &crate::Emoji),
-("mountain_cableway" , &crate::flat::MOUNTAIN_CABLEWAY as &crate::Emoji),
-("mountain_railway" , &crate::flat::MOUNTAIN_RAILWAY as &crate::Emoji),
-("mountain_snow" , &crate::flat::SNOW_CAPPED_MOUNTAIN as &crate::Emoji),
-("mouse" , &crate::flat::MOUSE as &crate::Emoji),
-("mouse2" , &crate::flat::MOUSE as &crate::Emoji),
-("mouse_face" , &crate::flat::MOUSE_FACE as &crate::Emoji),
-("mouse_trap" , &crate::flat::MOUSE_TRAP as &crate::Emoji),
-("mouth" , &crate::flat::MOUTH as &crate::Emoji),
-("movie_camera" , &crate::flat::MOVIE_CAMERA as &crate::Emoji),
-("moyai" , &crate::flat::MOAI as &crate::Emoji),
-("mozambique" , &crate::flat::FLAG_MOZAMBIQUE as &crate::Emoji),
-("mrs_claus" , &crate::flat::MRS_CLAUS.default as &crate::Emoji),
-("multiply" , &crate::flat::MULTIPLY as &crate::Emoji),
-("muscle" , &crate::flat::FLEXED_BICEPS.default as &crate::Emoji),
-("mushroom" , &crate::flat::MUSHROOM as &crate::Emoji),
-("musical_keyboard" , &crate::flat::MUSICAL_KEYBOARD as &crate::Emoji),
-("musical_note" , &crate::flat::MUSICAL_NOTE as &crate::Emoji),
-("musical_notes" , &crate::flat::MUSICAL_NOTES as &crate::Emoji),
-("musical_score" , &crate::flat::MUSICAL_SCORE as &crate::Emoji),
-("mute" , &crate::flat::MUTED_SPEAKER as &crate::Emoji),
-("muted_speaker" , &crate::flat::MUTED_SPEAKER as &crate::Emoji),
-("mx_claus" , &crate::flat::MX_CLAUS.default as &crate::Emoji),
-("myanmar" , &crate::flat::FLAG_MYANMAR_BURMA as &crate::Emoji),
-("nail_care" , &crate::flat::NAIL_POLISH.default as &crate::Emoji),
-("nail_polish" , &crate::flat::NAIL_POLISH.default as &crate::Emoji),
-("name_badge" , &crate::flat::NAME_BADGE as &crate::Emoji),
-("namibia" , &crate::flat::FLAG_NAMIBIA as &crate::Emoji),
-("national_park" , &crate::flat::NATIONAL_PARK as &crate::Emoji),
-("nauru" , &crate::flat::FLAG_NAURU as &crate::Emoji),
-("nauseated_face" , &crate::flat::NAUSEATED_FACE as &crate::Emoji),
-("nazar_amulet" , &crate::flat::NAZAR_AMULET as &crate::Emoji),
-("necktie" , &crate::flat::NECKTIE as &crate::Emoji),
-("negative_squared_cross_mark" , &crate::flat::CROSS_MARK_BUTTON as &crate::Emoji),
-("nepal" , &crate::flat::FLAG_NEPAL as &crate::Emoji),
-("nerd_face" , &crate::flat::NERD_FACE as &crate::Emoji),
-("nesting_dolls" , &crate::flat::NESTING_DOLLS as &crate::Emoji),
-("netherlands" , &crate::flat::FLAG_NETHERLANDS as &crate::Emoji),
-("neutral_face" , &crate::flat::NEUTRAL_FACE as &crate::Emoji),
-("new" , &crate::flat::NEW_BUTTON as &crate::Emoji),
-("new_button" , &crate::flat::NEW_BUTTON as &crate::Emoji),
-("new_caledonia" , &crate::flat::FLAG_NEW_CALEDONIA as &crate::Emoji),
-("new_moon" , &crate::flat::NEW_MOON as &crate::Emoji),
-("new_moon_face" , &crate::flat::NEW_MOON_FACE as &crate::Emoji),
-("new_moon_with_face" , &crate::flat::NEW_MOON_FACE as &crate::Emoji),
-("new_zealand" , &crate::flat::FLAG_NEW_ZEALAND as &crate::Emoji),
-("newspaper" , &crate::flat::NEWSPAPER as &crate::Emoji),
-("newspaper_roll" , &crate::flat::ROLLED_UP_NEWSPAPER as &crate::Emoji),
-("next_track_button" , &crate::flat::NEXT_TRACK_BUTTON as &crate::Emoji),
-("ng" , &crate::flat::NG_BUTTON as &crate::Emoji),
-("ng_button" , &crate::flat::NG_BUTTON as &crate::Emoji),
-("ng_man" , &crate::flat::PERSON_GESTURING_NO.gender(Gender::Male).default as &crate::Emoji),
-("ng_woman" , &crate::flat::PERSON_GESTURING_NO.gender(Gender::Female).default as &crate::Emoji),
-("nicaragua" , &crate::flat::FLAG_NICARAGUA as &crate::Emoji),
-("niger" , &crate::flat::FLAG_NIGER as &crate::Emoji),
-("nigeria" , &crate::flat::FLAG_NIGERIA as &crate::Emoji),
-("night_with_stars" , &crate::flat::NIGHT_WITH_STARS as &crate::Emoji),
-("nine" , &crate::flat::KEYCAP_9 as &crate::Emoji),
-("nine_o_clock" , &crate::flat::NINE_O_CLOCK as &crate::Emoji),
-("nine_thirty" , &crate::flat::NINE_THIRTY as &crate::Emoji),
-("ninja" , &crate::flat::NINJA.default as &crate::Emoji),
-("niue" , &crate::flat::FLAG_NIUE as &crate::Emoji),
-("no_bell" , &crate::flat::BELL_WITH_SLASH as &crate::Emoji),
-("no_bicycles" , &crate::flat::NO_BICYCLES as &crate::Emoji),
-("no_entry" , &crate::flat::NO_ENTRY as &crate::Emoji),
-("no_entry_sign" , &crate::flat::PROHIBITED as &crate::Emoji),
-("no_good" , &crate::flat::PERSON_GESTURING_NO.default.default as &crate::Emoji),
-("no_good_man" , &crate::flat::PERSON_GESTURING_NO.gender(Gender::Male).default as &crate::Emoji),
-("no_good_woman" , &crate::flat::PERSON_GESTURING_NO.gender(Gender::Female).default as &crate::Emoji),
-("no_littering" , &crate::flat::NO_LITTERING as &crate::Emoji),
-("no_mobile_phones" , &crate::flat::NO_MOBILE_PHONES as &crate::Emoji),
-("no_mouth" , &crate::flat::FACE_WITHOUT_MOUTH as &crate::Emoji),
-("no_one_under_eighteen" , &crate::flat::NO_ONE_UNDER_EIGHTEEN as &crate::Emoji),
-("no_pedestrians" , &crate::flat::NO_PEDESTRIANS as &crate::Emoji),
-("no_smoking" , &crate::flat::NO_SMOKING as &crate::Emoji),
-("non-potable_water" , &crate::flat::NON_POTABLE_WATER as &crate::Emoji),
-("non_potable_water" , &crate::flat::NON_POTABLE_WATER as &crate::Emoji),
-("norfolk_island" , &crate::flat::FLAG_NORFOLK_ISLAND as &crate::Emoji),
-("north_korea" , &crate::flat::FLAG_NORTH_KOREA as &crate::Emoji),
-("northern_mariana_islands" , &crate::flat::FLAG_NORTHERN_MARIANA_ISLANDS as &crate::Emoji),
-("norway" , &crate::flat::FLAG_NORWAY as &crate::Emoji),
-("nose" , &crate::flat::NOSE.default as &crate::Emoji),
-("notebook" , &crate::flat::NOTEBOOK as &crate::Emoji),
-("notebook_with_decorative_cover" , &crate::flat::NOTEBOOK_WITH_DECORATIVE_COVER as &crate::Emoji),
-("notes" , &crate::flat::MUSICAL_NOTES as &crate::Emoji),
-("nut_and_bolt" , &crate::flat::NUT_AND_BOLT as &crate::Emoji),
-("o" , &crate::flat::HOLLOW_RED_CIRCLE as &crate::Emoji),
-("o2" , &crate::flat::O_BUTTON_BLOOD_TYPE as &crate::Emoji),
-("o_button_blood_type" , &crate::flat::O_BUTTON_BLOOD_TYPE as &crate::Emoji),
-("ocean" , &crate::flat::WATER_WAVE as &crate::Emoji),
-("octopus" , &crate::flat::OCTOPUS as &crate::Emoji),
-("oden" , &crate::flat::ODEN as &crate::Emoji),
-("office" , &crate::flat::OFFICE_BUILDING as &crate::Emoji),
-("office_building" , &crate::flat::OFFICE_BUILDING as &crate::Emoji),
-("office_worker" , &crate::flat::OFFICE_WORKER.default.default as &crate::Emoji),
-("ogre" , &crate::flat::OGRE as &crate::Emoji),
-("oil_drum" , &crate::flat::OIL_DRUM as &crate::Emoji),
-("ok" , &crate::flat::OK_BUTTON as &crate::Emoji),
-("ok_button" , &crate::flat::OK_BUTTON as &crate::Emoji),
-("ok_hand" , &crate::flat::OK_HAND.default as &crate::Emoji),
-("ok_man" , &crate::flat::PERSON_GESTURING_OK.gender(Gender::Male).default as &crate::Emoji),
-("ok_person" , &crate::flat::PERSON_GESTURING_OK.default.default as &crate::Emoji),
-("ok_woman" , &crate::flat::PERSON_GESTURING_OK.gender(Gender::Female).default as &crate::Emoji),
-("old_key" , &crate::flat::OLD_KEY as &crate::Emoji),
-("older_adult" , &crate::flat::OLDER_PERSON.default as &crate::Emoji),
-("older_man" , &crate::flat::OLD_PERSON.gender(Gender::Male).default as &crate::Emoji),
-("older_person" , &crate::flat::OLDER_PERSON.default as &crate::Emoji),
-("older_woman" , &crate::flat::OLD_PERSON.gender(Gender::Female).default as &crate::Emoji),
-("olive" , &crate::flat::OLIVE as &crate::Emoji),
-("om" , &crate::flat::OM as &crate::Emoji),
-("oman" , &crate::flat::FLAG_OMAN as &crate::Emoji),
-("on" , &crate::flat::ON_ARROW as &crate::Emoji),
-("on_arrow" , &crate::flat::ON_ARROW as &crate::Emoji),
-("oncoming_automobile" , &crate::flat::ONCOMING_AUTOMOBILE as &crate::Emoji),
-("oncoming_bus" , &crate::flat::ONCOMING_BUS as &crate::Emoji),
-("oncoming_fist" , &crate::flat::ONCOMING_FIST.default as &crate::Emoji),
-("oncoming_police_car" , &crate::flat::ONCOMING_POLICE_CAR as &crate::Emoji),
-("oncoming_taxi" , &crate::flat::ONCOMING_TAXI as &crate::Emoji),
-("one" , &crate::flat::KEYCAP_1 as &crate::Emoji),
-("one_o_clock" , &crate::flat::ONE_O_CLOCK as &crate::Emoji),
-("one_piece_swimsuit" , &crate::flat::ONE_PIECE_SWIMSUIT as &crate::Emoji),
-("one_thirty" , &crate::flat::ONE_THIRTY as &crate::Emoji),
-("onion" , &crate::flat::ONION as &crate::Emoji),
-("open_book" , &crate::flat::OPEN_BOOK as &crate::Emoji),
-("open_file_folder" , &crate::flat::OPEN_FILE_FOLDER as &crate::Emoji),
-("open_hands" , &crate::flat::OPEN_HANDS.default as &crate::Emoji),
-("open_mailbox_with_lowered_flag" , &crate::flat::OPEN_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
-("open_mailbox_with_raised_flag" , &crate::flat::OPEN_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
-("open_mouth" , &crate::flat::FACE_WITH_OPEN_MOUTH as &crate::Emoji),
-("open_umbrella" , &crate::flat::UMBRELLA as &crate::Emoji),
-("ophiuchus" , &crate::flat::OPHIUCHUS as &crate::Emoji),
-("optical_disk" , &crate::flat::OPTICAL_DISK as &crate::Emoji),
-("orange" , &crate::flat::TANGERINE as &crate::Emoji),
-("orange_book" , &crate::flat::ORANGE_BOOK as &crate::Emoji),
-("orange_circle" , &crate::flat::ORANGE_CIRCLE as &crate::Emoji),
-("orange_heart" , &crate::flat::ORANGE_HEART as &crate::Emoji),
-("orange_square" , &crate::flat::ORANGE_SQUARE as &crate::Emoji),
-("orangutan" , &crate::flat::ORANGUTAN as &crate::Emoji),
-("orthodox_cross" , &crate::flat::ORTHODOX_CROSS as &crate::Emoji),
-("otter" , &crate::flat::OTTER as &crate::Emoji),
-("outbox_tray" , &crate::flat::OUTBOX_TRAY as &crate::Emoji),
-("owl" , &crate::flat::OWL as &crate::Emoji),
-("ox" , &crate::flat::OX as &crate::Emoji),
-("oyster" , &crate::flat::OYSTER as &crate::Emoji),
-("p_button" , &crate::flat::P_BUTTON as &crate::Emoji),
-("package" , &crate::flat::PACKAGE as &crate::Emoji),
-("page_facing_up" , &crate::flat::PAGE_FACING_UP as &crate::Emoji),
-("page_with_curl" , &crate::flat::PAGE_WITH_CURL as &crate::Emoji),
-("pager" , &crate::flat::PAGER as &crate::Emoji),
-("paintbrush" , &crate::flat::PAINTBRUSH as &crate::Emoji),
-("pakistan" , &crate::flat::FLAG_PAKISTAN as &crate::Emoji),
-("palau" , &crate::flat::FLAG_PALAU as &crate::Emoji),
-("palestinian_territories" , &crate::flat::FLAG_PALESTINIAN_TERRITORIES as &crate::Emoji),
-("palm_tree" , &crate::flat::PALM_TREE as &crate::Emoji),
-("palms_up_together" , &crate::flat::PALMS_UP_TOGETHER.default as &crate::Emoji),
-("panama" , &crate::flat::FLAG_PANAMA as &crate::Emoji),
-("pancakes" , &crate::flat::PANCAKES as &crate::Emoji),
-("panda" , &crate::flat::PANDA as &crate::Emoji),
-("panda_face" , &crate::flat::PANDA as &crate::Emoji),
-("paperclip" , &crate::flat::PAPERCLIP as &crate::Emoji),
-("paperclips" , &crate::flat::LINKED_PAPERCLIPS as &crate::Emoji),
-("papua_new_guinea" , &crate::flat::FLAG_PAPUA_NEW_GUINEA as &crate::Emoji),
-("parachute" , &crate::flat::PARACHUTE as &crate::Emoji),
-("paraguay" , &crate::flat::FLAG_PARAGUAY as &crate::Emoji),
-("parasol_on_ground" , &crate::flat::UMBRELLA_ON_GROUND as &crate::Emoji),
-("parking" , &crate::flat::P_BUTTON as &crate::Emoji),
-("parrot" , &crate::flat::PARROT as &crate::Emoji),
-("part_alternation_mark" , &crate::flat::PART_ALTERNATION_MARK as &crate::Emoji),
-("partly_sunny" , &crate::flat::SUN_BEHIND_CLOUD as &crate::Emoji),
-("party_popper" , &crate::flat::PARTY_POPPER as &crate::Emoji),
-("partying_face" , &crate::flat::PARTYING_FACE as &crate::Emoji),
-("passenger_ship" , &crate::flat::PASSENGER_SHIP as &crate::Emoji),
-("passport_control" , &crate::flat::PASSPORT_CONTROL as &crate::Emoji),
-("pause_button" , &crate::flat::PAUSE_BUTTON as &crate::Emoji),
-("paw_prints" , &crate::flat::PAW_PRINTS as &crate::Emoji),
-("peace_symbol" , &crate::flat::PEACE_SYMBOL as &crate::Emoji),
-("peach" , &crate::flat::PEACH as &crate::Emoji),
-("peacock" , &crate::flat::PEACOCK as &crate::Emoji),
-("peanuts" , &crate::flat::PEANUTS as &crate::Emoji),
-("pear" , &crate::flat::PEAR as &crate::Emoji),
-("pen" , &crate::flat::PEN as &crate::Emoji),
-("pencil" , &crate::flat::PENCIL as &crate::Emoji),
-("pencil2" , &crate::flat::PENCIL as &crate::Emoji),
-("penguin" , &crate::flat::PENGUIN as &crate::Emoji),
-("pensive" , &crate::flat::PENSIVE_FACE as &crate::Emoji),
-("pensive_face" , &crate::flat::PENSIVE_FACE as &crate::Emoji),
-("people_holding_hands" , &crate::flat::PERSON_HOLDING_HANDS.default.default as &crate::Emoji),
-("people_hugging" , &crate::flat::PERSON_HUGGING as &crate::Emoji),
-("performing_arts" , &crate::flat::PERFORMING_ARTS as &crate::Emoji),
-("persevere" , &crate::flat::PERSEVERING_FACE as &crate::Emoji),
-("persevering_face" , &crate::flat::PERSEVERING_FACE as &crate::Emoji),
-("person" , &crate::flat::PERSON.default.default.default as &crate::Emoji),
-("person_bald" , &crate::flat::PERSON.hair(Hair::Bald).default.default as &crate::Emoji),
-("person_biking" , &crate::flat::PERSON_BIKING.default.default as &crate::Emoji),
-("person_bouncing_ball" , &crate::flat::PERSON_BOUNCING_BALL.default.default as &crate::Emoji),
-("person_bowing" , &crate::flat::PERSON_BOWING.default.default as &crate::Emoji),
-("person_cartwheeling" , &crate::flat::PERSON_CARTWHEELING.default.default as &crate::Emoji),
-("person_climbing" , &crate::flat::PERSON_CLIMBING.default.default as &crate::Emoji),
-("person_curly_hair" , &crate::flat::PERSON.hair(Hair::Curly).default.default as &crate::Emoji),
-("person_facepalming" , &crate::flat::PERSON_FACEPALMING.default.default as &crate::Emoji),
-("person_feeding_baby" , &crate::flat::PERSON_FEEDING_BABY.default.default as &crate::Emoji),
-("person_fencing" , &crate::flat::PERSON_FENCING as &crate::Emoji),
-("person_frowning" , &crate::flat::PERSON_FROWNING.default.default as &crate::Emoji),
-("person_gesturing_no" , &crate::flat::PERSON_GESTURING_NO.default.default as &crate::Emoji),
-("person_gesturing_ok" , &crate::flat::PERSON_GESTURING_OK.default.default as &crate::Emoji),
-("person_getting_haircut" , &crate::flat::PERSON_GETTING_HAIRCUT.default.default as &crate::Emoji),
-("person_getting_massage" , &crate::flat::PERSON_GETTING_MASSAGE.default.default as &crate::Emoji),
-("person_golfing" , &crate::flat::PERSON_GOLFING.default.default as &crate::Emoji),
-("person_holding_hands" , &crate::flat::PERSON_HOLDING_HANDS.default.default as &crate::Emoji),
-("person_hugging" , &crate::flat::PERSON_HUGGING as &crate::Emoji),
-("person_in_bed" , &crate::flat::PERSON_IN_BED.default as &crate::Emoji),
-("person_in_lotus_position" , &crate::flat::PERSON_IN_LOTUS_POSITION.default.default as &crate::Emoji),
-("person_in_manual_wheelchair" , &crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.default.default as &crate::Emoji),
-("person_in_motorized_wheelchair" , &crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.default.default as &crate::Emoji),
-("person_in_steamy_room" , &crate::flat::PERSON_IN_STEAMY_ROOM.default.default as &crate::Emoji),
-("person_in_suit_levitating" , &crate::flat::PERSON_IN_SUIT_LEVITATING.default as &crate::Emoji),
-("person_in_tuxedo" , &crate::flat::PERSON_IN_TUXEDO.default.default as &crate::Emoji),
-("person_juggling" , &crate::flat::PERSON_JUGGLING.default.default as &crate::Emoji),
-("person_kneeling" , &crate::flat::PERSON_KNEELING.default.default as &crate::Emoji),
-("person_lifting_weights" , &crate::flat::PERSON_LIFTING_WEIGHTS.default.default as &crate::Emoji),
-("person_mountain_biking" , &crate::flat::PERSON_MOUNTAIN_BIKING.default.default as &crate::Emoji),
-("person_playing_handball" , &crate::flat::PERSON_PLAYING_HANDBALL.default.default as &crate::Emoji),
-("person_playing_water_polo" , &crate::flat::PERSON_PLAYING_WATER_POLO.default.default as &crate::Emoji),
-("person_pouting" , &crate::flat::PERSON_POUTING.default.default as &crate::Emoji),
-("person_raising_hand" , &crate::flat::PERSON_RAISING_HAND.default.default as &crate::Emoji),
-("person_red_hair" , &crate::flat::PERSON.hair(Hair::Red).default.default as &crate::Emoji),
-("person_rowing_boat" , &crate::flat::PERSON_ROWING_BOAT.default.default as &crate::Emoji),
-("person_running" , &crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
-("person_shrugging" , &crate::flat::PERSON_SHRUGGING.default.default as &crate::Emoji),
-("person_standing" , &crate::flat::PERSON_STANDING.default.default as &crate::Emoji),
-("person_surfing" , &crate::flat::PERSON_SURFING.default.default as &crate::Emoji),
-("person_swimming" , &crate::flat::PERSON_SWIMMING.default.default as &crate::Emoji),
-("person_taking_bath" , &crate::flat::PERSON_TAKING_BATH.default as &crate::Emoji),
-("person_tipping_hand" , &crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
-("person_walking" , &crate::flat::PERSON_WALKING.default.default as &crate::Emoji),
-("person_wearing_turban" , &crate::flat::PERSON_WEARING_TURBAN.default.default as &crate::Emoji),
-("person_white_hair" , &crate::flat::PERSON.hair(Hair::White).default.default as &crate::Emoji),
-("person_with_bunny_ears" , &crate::flat::PERSON_WITH_BUNNY_EARS.default as &crate::Emoji),
-("person_with_probing_cane" , &crate::flat::PERSON_WITH_WHITE_CANE.default.default as &crate::Emoji),
-("person_with_skullcap" , &crate::flat::PERSON_WITH_SKULLCAP.default as &crate::Emoji),
-("person_with_turban" , &crate::flat::PERSON_WEARING_TURBAN.default.default as &crate::Emoji),
-("person_with_veil" , &crate::flat::PERSON_WITH_VEIL.default.default as &crate::Emoji),
-("person_with_white_cane" , &crate::flat::PERSON_WITH_WHITE_CANE.default.default as &crate::Emoji),
-("person_wrestling" , &crate::flat::PERSON_WRESTLING.default as &crate::Emoji),
-("peru" , &crate::flat::FLAG_PERU as &crate::Emoji),
-("petri_dish" , &crate::flat::PETRI_DISH as &crate::Emoji),
-("philippines" , &crate::flat::FLAG_PHILIPPINES as &crate::Emoji),
-("phone" , &crate::flat::TELEPHONE as &crate::Emoji),
-("pick" , &crate::flat::PICK as &crate::Emoji),
-("pickup_truck" , &crate::flat::PICKUP_TRUCK as &crate::Emoji),
-("pie" , &crate::flat::PIE as &crate::Emoji),
-("pig" , &crate::flat::PIG as &crate::Emoji),
-("pig2" , &crate::flat::PIG as &crate::Emoji),
-("pig_face" , &crate::flat::PIG_FACE as &crate::Emoji),
-("pig_nose" , &crate::flat::PIG_NOSE as &crate::Emoji),
-("pile_of_poo" , &crate::flat::PILE_OF_POO as &crate::Emoji),
-("pill" , &crate::flat::PILL as &crate::Emoji),
-("pilot" , &crate::flat::PILOT.default.default as &crate::Emoji),
-("pinata" , &crate::flat::PINATA as &crate::Emoji),
-("pinched_fingers" , &crate::flat::PINCHED_FINGERS.default as &crate::Emoji),
-("pinching_hand" , &crate::flat::PINCHING_HAND.default as &crate::Emoji),
-("pine_decoration" , &crate::flat::PINE_DECORATION as &crate::Emoji),
-("pineapple" , &crate::flat::PINEAPPLE as &crate::Emoji),
-("ping_pong" , &crate::flat::PING_PONG as &crate::Emoji),
-("pirate_flag" , &crate::flat::PIRATE_FLAG as &crate::Emoji),
-("pisces" , &crate::flat::PISCES as &crate::Emoji),
-("pitcairn_islands" , &crate::flat::FLAG_PITCAIRN_ISLANDS as &crate::Emoji),
-("pizza" , &crate::flat::PIZZA as &crate::Emoji),
-("placard" , &crate::flat::PLACARD as &crate::Emoji),
-("place_of_worship" , &crate::flat::PLACE_OF_WORSHIP as &crate::Emoji),
-("plate_with_cutlery" , &crate::flat::FORK_AND_KNIFE_WITH_PLATE as &crate::Emoji),
-("play_button" , &crate::flat::PLAY_BUTTON as &crate::Emoji),
-("play_or_pause_button" , &crate::flat::PLAY_OR_PAUSE_BUTTON as &crate::Emoji),
-("pleading_face" , &crate::flat::PLEADING_FACE as &crate::Emoji),
-("plunger" , &crate::flat::PLUNGER as &crate::Emoji),
-("plus" , &crate::flat::PLUS as &crate::Emoji),
-("point_down" , &crate::flat::BACKHAND_INDEX_POINTING_DOWN.default as &crate::Emoji),
-("point_left" , &crate::flat::BACKHAND_INDEX_POINTING_LEFT.default as &crate::Emoji),
-("point_right" , &crate::flat::BACKHAND_INDEX_POINTING_RIGHT.default as &crate::Emoji),
-("point_up" , &crate::flat::INDEX_POINTING_UP.default as &crate::Emoji),
-("point_up_2" , &crate::flat::BACKHAND_INDEX_POINTING_UP.default as &crate::Emoji),
-("poland" , &crate::flat::FLAG_POLAND as &crate::Emoji),
-("polar_bear" , &crate::flat::POLAR_BEAR as &crate::Emoji),
-("police_car" , &crate::flat::POLICE_CAR as &crate::Emoji),
-("police_car_light" , &crate::flat::POLICE_CAR_LIGHT as &crate::Emoji),
-("police_officer" , &crate::flat::POLICE_OFFICER.default.default as &crate::Emoji),
-("policeman" , &crate::flat::POLICE_OFFICER.gender(Gender::Male).default as &crate::Emoji),
-("policewoman" , &crate::flat::POLICE_OFFICER.gender(Gender::Female).default as &crate::Emoji),
-("poodle" , &crate::flat::POODLE as &crate::Emoji),
-("pool_8_ball" , &crate::flat::POOL_8_BALL as &crate::Emoji),
-("poop" , &crate::flat::PILE_OF_POO as &crate::Emoji),
-("popcorn" , &crate::flat::POPCORN as &crate::Emoji),
-("portugal" , &crate::flat::FLAG_PORTUGAL as &crate::Emoji),
-("post_office" , &crate::flat::POST_OFFICE as &crate::Emoji),
-("postal_horn" , &crate::flat::POSTAL_HORN as &crate::Emoji),
-("postbox" , &crate::flat::POSTBOX as &crate::Emoji),
-("pot_of_food" , &crate::flat::POT_OF_FOOD as &crate::Emoji),
-("potable_water" , &crate::flat::POTABLE_WATER as &crate::Emoji),
-("potato" , &crate::flat::POTATO as &crate::Emoji),
-("potted_plant" , &crate::flat::POTTED_PLANT as &crate::Emoji),
-("pouch" , &crate::flat::CLUTCH_BAG as &crate::Emoji),
-("poultry_leg" , &crate::flat::POULTRY_LEG as &crate::Emoji),
-("pound" , &crate::flat::POUND_BANKNOTE as &crate::Emoji),
-("pound_banknote" , &crate::flat::POUND_BANKNOTE as &crate::Emoji),
-("pout" , &crate::flat::POUTING_FACE as &crate::Emoji),
-("pouting_cat" , &crate::flat::POUTING_CAT as &crate::Emoji),
-("pouting_face" , &crate::flat::POUTING_FACE as &crate::Emoji),
-("pouting_man" , &crate::flat::PERSON_POUTING.gender(Gender::Male).default as &crate::Emoji),
-("pouting_woman" , &crate::flat::PERSON_POUTING.gender(Gender::Female).default as &crate::Emoji),
-("pray" , &crate::flat::FOLDED_HANDS.default as &crate::Emoji),
-("prayer_beads" , &crate::flat::PRAYER_BEADS as &crate::Emoji),
-("pregnant_woman" , &crate::flat::PREGNANT_WOMAN.default as &crate::Emoji),
-("pretzel" , &crate::flat::PRETZEL as &crate::Emoji),
-("previous_track_button" , &crate::flat::LAST_TRACK_BUTTON as &crate::Emoji),
-("prince" , &crate::flat::PRINCE.default as &crate::Emoji),
-("princess" , &crate::flat::PRINCESS.default as &crate::Emoji),
-("printer" , &crate::flat::PRINTER as &crate::Emoji),
-("probing_cane" , &crate::flat::WHITE_CANE as &crate::Emoji),
-("prohibited" , &crate::flat::PROHIBITED as &crate::Emoji),
-("puerto_rico" , &crate::flat::FLAG_PUERTO_RICO as &crate::Emoji),
-("punch" , &crate::flat::ONCOMING_FIST.default as &crate::Emoji),
-("purple_circle" , &crate::flat::PURPLE_CIRCLE as &crate::Emoji),
-("purple_heart" , &crate::flat::PURPLE_HEART as &crate::Emoji),
-("purple_square" , &crate::flat::PURPLE_SQUARE as &crate::Emoji),
-("purse" , &crate::flat::PURSE as &crate::Emoji),
-("pushpin" , &crate::flat::PUSHPIN as &crate::Emoji),
-("put_litter_in_its_place" , &crate::flat::LITTER_IN_BIN_SIGN as &crate::Emoji),
-("puzzle_piece" , &crate::flat::PUZZLE_PIECE as &crate::Emoji),
-("qatar" , &crate::flat::FLAG_QATAR as &crate::Emoji),
-("question" , &crate::flat::RED_QUESTION_MARK as &crate::Emoji),
-("rabbit" , &crate::flat::RABBIT as &crate::Emoji),
-("rabbit2" , &crate::flat::RABBIT as &crate::Emoji),
-("rabbit_face" , &crate::flat::RABBIT_FACE as &crate::Emoji),
-("raccoon" , &crate::flat::RACCOON as &crate::Emoji),
-("racehorse" , &crate::flat::HORSE as &crate::Emoji),
-("racing_car" , &crate::flat::RACING_CAR as &crate::Emoji),
-("radio" , &crate::flat::RADIO as &crate::Emoji),
-("radio_button" , &crate::flat::RADIO_BUTTON as &crate::Emoji),
-("radioactive" , &crate::flat::RADIOACTIVE as &crate::Emoji),
-("rage" , &crate::flat::POUTING_FACE as &crate::Emoji),
-("railway_car" , &crate::flat::RAILWAY_CAR as &crate::Emoji),
-("railway_track" , &crate::flat::RAILWAY_TRACK as &crate::Emoji),
-("rainbow" , &crate::flat::RAINBOW as &crate::Emoji),
-("rainbow_flag" , &crate::flat::RAINBOW_FLAG as &crate::Emoji),
-("raised_back_of_hand" , &crate::flat::RAISED_BACK_OF_HAND.default as &crate::Emoji),
-("raised_eyebrow" , &crate::flat::FACE_WITH_RAISED_EYEBROW as &crate::Emoji),
-("raised_fist" , &crate::flat::RAISED_FIST.default as &crate::Emoji),
-("raised_hand" , &crate::flat::RAISED_HAND.default as &crate::Emoji),
-("raised_hand_with_fingers_splayed" , &crate::flat::HAND_WITH_FINGERS_SPLAYED.default as &crate::Emoji),
-("raised_hands" , &crate::flat::RAISING_HANDS.default as &crate::Emoji),
-("raising_hand" , &crate::flat::PERSON_RAISING_HAND.default.default as &crate::Emoji),
-("raising_hand_man" , &crate::flat::PERSON_RAISING_HAND.gender(Gender::Male).default as &crate::Emoji),
-("raising_hand_woman" , &crate::flat::PERSON_RAISING_HAND.gender(Gender::Female).default as &crate::Emoji),
-("raising_hands" , &crate::flat::RAISING_HANDS.default as &crate::Emoji),
-("ram" , &crate::flat::RAM as &crate::Emoji),
-("ramen" , &crate::flat::STEAMING_BOWL as &crate::Emoji),
-("rat" , &crate::flat::RAT as &crate::Emoji),
-("razor" , &crate::flat::RAZOR as &crate::Emoji),
-("receipt" , &crate::flat::RECEIPT as &crate::Emoji),
-("record_button" , &crate::flat::RECORD_BUTTON as &crate::Emoji),
-("recycle" , &crate::flat::RECYCLING_SYMBOL as &crate::Emoji),
-("recycling_symbol" , &crate::flat::RECYCLING_SYMBOL as &crate::Emoji),
-("red_apple" , &crate::flat::RED_APPLE as &crate::Emoji),
-("red_car" , &crate::flat::AUTOMOBILE as &crate::Emoji),
-("red_circle" , &crate::flat::RED_CIRCLE as &crate::Emoji),
-("red_envelope" , &crate::flat::RED_ENVELOPE as &crate::Emoji),
-("red_exclamation_mark" , &crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
-("red_hair" , &crate::flat::RED_HAIR as &crate::Emoji),
-("red_haired_man" , &crate::flat::PERSON.hair(Hair::Red).gender(Gender::Male).default as &crate::Emoji),
-("red_haired_woman" , &crate::flat::PERSON.hair(Hair::Red).gender(Gender::Female).default as &crate::Emoji),
-("red_heart" , &crate::flat::RED_HEART as &crate::Emoji),
-("red_paper_lantern" , &crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
-("red_question_mark" , &crate::flat::RED_QUESTION_MARK as &crate::Emoji),
-("red_square" , &crate::flat::RED_SQUARE as &crate::Emoji),
-("red_triangle_pointed_down" , &crate::flat::RED_TRIANGLE_POINTED_DOWN as &crate::Emoji),
-("red_triangle_pointed_up" , &crate::flat::RED_TRIANGLE_POINTED_UP as &crate::Emoji),
-("registered" , &crate::flat::REGISTERED as &crate::Emoji),
-("relaxed" , &crate::flat::SMILING_FACE as &crate::Emoji),
-("relieved" , &crate::flat::RELIEVED_FACE as &crate::Emoji),
-("relieved_face" , &crate::flat::RELIEVED_FACE as &crate::Emoji),
-("reminder_ribbon" , &crate::flat::REMINDER_RIBBON as &crate::Emoji),
-("repeat" , &crate::flat::REPEAT_BUTTON as &crate::Emoji),
-("repeat_button" , &crate::flat::REPEAT_BUTTON as &crate::Emoji),
-("repeat_one" , &crate::flat::REPEAT_SINGLE_BUTTON as &crate::Emoji),
-("repeat_single_button" , &crate::flat::REPEAT_SINGLE_BUTTON as &crate::Emoji),
-("rescue_worker_helmet" , &crate::flat::RESCUE_WORKER_S_HELMET as &crate::Emoji),
-("rescue_worker_s_helmet" , &crate::flat::RESCUE_WORKER_S_HELMET as &crate::Emoji),
-("restroom" , &crate::flat::RESTROOM as &crate::Emoji),
-("reunion" , &crate::flat::FLAG_REUNION as &crate::Emoji),
-("reverse_button" , &crate::flat::REVERSE_BUTTON as &crate::Emoji),
-("revolving_hearts" , &crate::flat::REVOLVING_HEARTS as &crate::Emoji),
-("rewind" , &crate::flat::FAST_REVERSE_BUTTON as &crate::Emoji),
-("rhinoceros" , &crate::flat::RHINOCEROS as &crate::Emoji),
-("ribbon" , &crate::flat::RIBBON as &crate::Emoji),
-("rice" , &crate::flat::COOKED_RICE as &crate::Emoji),
-("rice_ball" , &crate::flat::RICE_BALL as &crate::Emoji),
-("rice_cracker" , &crate::flat::RICE_CRACKER as &crate::Emoji),
-("rice_scene" , &crate::flat::MOON_VIEWING_CEREMONY as &crate::Emoji),
-("right_anger_bubble" , &crate::flat::RIGHT_ANGER_BUBBLE as &crate::Emoji),
-("right_arrow" , &crate::flat::RIGHT_ARROW as &crate::Emoji),
-("right_arrow_curving_down" , &crate::flat::RIGHT_ARROW_CURVING_DOWN as &crate::Emoji),
-("right_arrow_curving_left" , &crate::flat::RIGHT_ARROW_CURVING_LEFT as &crate::Emoji),
-("right_arrow_curving_up" , &crate::flat::RIGHT_ARROW_CURVING_UP as &crate::Emoji),
-("right_facing_fist" , &crate::flat::RIGHT_FACING_FIST.default as &crate::Emoji),
-("ring" , &crate::flat::RING as &crate::Emoji),
-("ringed_planet" , &crate::flat::RINGED_PLANET as &crate::Emoji),
-("roasted_sweet_potato" , &crate::flat::ROASTED_SWEET_POTATO as &crate::Emoji),
-("robot" , &crate::flat::ROBOT as &crate::Emoji),
-("rock" , &crate::flat::ROCK as &crate::Emoji),
-("rocket" , &crate::flat::ROCKET as &crate::Emoji),
-("rofl" , &crate::flat::ROLLING_ON_THE_FLOOR_LAUGHING as &crate::Emoji),
-("roll_eyes" , &crate::flat::FACE_WITH_ROLLING_EYES as &crate::Emoji),
-("roll_of_paper" , &crate::flat::ROLL_OF_PAPER as &crate::Emoji),
-("rolled_up_newspaper" , &crate::flat::ROLLED_UP_NEWSPAPER as &crate::Emoji),
-("roller_coaster" , &crate::flat::ROLLER_COASTER as &crate::Emoji),
-("roller_skate" , &crate::flat::ROLLER_SKATE as &crate::Emoji),
-("rolling_on_the_floor_laughing" , &crate::flat::ROLLING_ON_THE_FLOOR_LAUGHING as &crate::Emoji),
-("romania" , &crate::flat::FLAG_ROMANIA as &crate::Emoji),
-("rooster" , &crate::flat::ROOSTER as &crate::Emoji),
-("rose" , &crate::flat::ROSE as &crate::Emoji),
-("rosette" , &crate::flat::ROSETTE as &crate::Emoji),
-("rotating_light" , &crate::flat::POLICE_CAR_LIGHT as &crate::Emoji),
-("round_pushpin" , &crate::flat::ROUND_PUSHPIN as &crate::Emoji),
-("rowboat" , &crate::flat::PERSON_ROWING_BOAT.default.default as &crate::Emoji),
-("rowing_man" , &crate::flat::PERSON_ROWING_BOAT.gender(Gender::Male).default as &crate::Emoji),
-("rowing_woman" , &crate::flat::PERSON_ROWING_BOAT.gender(Gender::Female).default as &crate::Emoji),
-("ru" , &crate::flat::FLAG_RUSSIA as &crate::Emoji),
-("rugby_football" , &crate::flat::RUGBY_FOOTBALL as &crate::Emoji),
-("runner" , &crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
-("running" , &crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
-("running_man" , &crate::flat::PERSON_RUNNING.gender(Gender::Male).default as &crate::Emoji),
-("running_shirt" , &crate::flat::RUNNING_SHIRT as &crate::Emoji),
-("running_shirt_with_sash" , &crate::flat::RUNNING_SHIRT as &crate::Emoji),
-("running_shoe" , &crate::flat::RUNNING_SHOE as &crate::Emoji),
-("running_woman" , &crate::flat::PERSON_RUNNING.gender(Gender::Female).default as &crate::Emoji),
-("rwanda" , &crate::flat::FLAG_RWANDA as &crate::Emoji),
-("sa" , &crate::flat::JAPANESE_SERVICE_CHARGE_BUTTON as &crate::Emoji),
-("sad_but_relieved_face" , &crate::flat::SAD_BUT_RELIEVED_FACE as &crate::Emoji),
-("safety_pin" , &crate::flat::SAFETY_PIN as &crate::Emoji),
-("safety_vest" , &crate::flat::SAFETY_VEST as &crate::Emoji),
-("sagittarius" , &crate::flat::SAGITTARIUS as &crate::Emoji),
-("sailboat" , &crate::flat::SAILBOAT as &crate::Emoji),
-("sake" , &crate::flat::SAKE as &crate::Emoji),
-("salt" , &crate::flat::SALT as &crate::Emoji),
-("samoa" , &crate::flat::FLAG_SAMOA as &crate::Emoji),
-("san_marino" , &crate::flat::FLAG_SAN_MARINO as &crate::Emoji),
-("sandal" , &crate::flat::WOMAN_S_SANDAL as &crate::Emoji),
-("sandwich" , &crate::flat::SANDWICH as &crate::Emoji),
-("santa" , &crate::flat::SANTA_CLAUS.default as &crate::Emoji),
-("santa_claus" , &crate::flat::SANTA_CLAUS.default as &crate::Emoji),
-("sao_tome_principe" , &crate::flat::FLAG_SAO_TOME_AND_PRINCIPE as &crate::Emoji),
-("sari" , &crate::flat::SARI as &crate::Emoji),
-("sassy_man" , &crate::flat::PERSON_TIPPING_HAND.gender(Gender::Male).default as &crate::Emoji),
-("sassy_woman" , &crate::flat::PERSON_TIPPING_HAND.gender(Gender::Female).default as &crate::Emoji),
-("satellite" , &crate::flat::SATELLITE as &crate::Emoji),
-("satellite_antenna" , &crate::flat::SATELLITE_ANTENNA as &crate::Emoji),
-("satisfied" , &crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
-("saudi_arabia" , &crate::flat::FLAG_SAUDI_ARABIA as &crate::Emoji),
-("sauna_man" , &crate::flat::PERSON_IN_STEAMY_ROOM.gender(Gender::Male).default as &crate::Emoji),
-("sauna_person" , &crate::flat::PERSON_IN_STEAMY_ROOM.default.default as &crate::Emoji),
-("sauna_woman" , &crate::flat::PERSON_IN_STEAMY_ROOM.gender(Gender::Female).default as &crate::Emoji),
-("sauropod" , &crate::flat::SAUROPOD as &crate::Emoji),
-("saxophone" , &crate::flat::SAXOPHONE as &crate::Emoji),
-("scarf" , &crate::flat::SCARF as &crate::Emoji),
-("school" , &crate::flat::SCHOOL as &crate::Emoji),
-("school_satchel" , &crate::flat::BACKPACK as &crate::Emoji),
-("scientist" , &crate::flat::SCIENTIST.default.default as &crate::Emoji),
-("scissors" , &crate::flat::SCISSORS as &crate::Emoji),
-("scorpio" , &crate::flat::SCORPIO as &crate::Emoji),
-("scorpion" , &crate::flat::SCORPION as &crate::Emoji),
-("scorpius" , &crate::flat::SCORPIO as &crate::Emoji),
-("scotland" , &crate::flat::FLAG_SCOTLAND as &crate::Emoji),
-("scream" , &crate::flat::FACE_SCREAMING_IN_FEAR as &crate::Emoji),
-("scream_cat" , &crate::flat::WEARY_CAT as &crate::Emoji),
-("screwdriver" , &crate::flat::SCREWDRIVER as &crate::Emoji),
-("scroll" , &crate::flat::SCROLL as &crate::Emoji),
-("seal" , &crate::flat::SEAL as &crate::Emoji),
-("seat" , &crate::flat::SEAT as &crate::Emoji),
-("second_place_medal" , &crate::flat::SECOND_PLACE_MEDAL as &crate::Emoji),
-("secret" , &crate::flat::JAPANESE_SECRET_BUTTON as &crate::Emoji),
-("see_no_evil" , &crate::flat::SEE_NO_EVIL_MONKEY as &crate::Emoji),
-("see_no_evil_monkey" , &crate::flat::SEE_NO_EVIL_MONKEY as &crate::Emoji),
-("seedling" , &crate::flat::SEEDLING as &crate::Emoji),
-("selfie" , &crate::flat::SELFIE.default as &crate::Emoji),
-("senegal" , &crate::flat::FLAG_SENEGAL as &crate::Emoji),
-("serbia" , &crate::flat::FLAG_SERBIA as &crate::Emoji),
-("service_dog" , &crate::flat::SERVICE_DOG as &crate::Emoji),
-("seven" , &crate::flat::KEYCAP_7 as &crate::Emoji),
-("seven_o_clock" , &crate::flat::SEVEN_O_CLOCK as &crate::Emoji),
-("seven_thirty" , &crate::flat::SEVEN_THIRTY as &crate::Emoji),
-("sewing_needle" , &crate::flat::SEWING_NEEDLE as &crate::Emoji),
-("seychelles" , &crate::flat::FLAG_SEYCHELLES as &crate::Emoji),
-("shallow_pan_of_food" , &crate::flat::SHALLOW_PAN_OF_FOOD as &crate::Emoji),
-("shamrock" , &crate::flat::SHAMROCK as &crate::Emoji),
-("shark" , &crate::flat::SHARK as &crate::Emoji),
-("shaved_ice" , &crate::flat::SHAVED_ICE as &crate::Emoji),
-("sheaf_of_rice" , &crate::flat::SHEAF_OF_RICE as &crate::Emoji),
-("sheep" , &crate::flat::EWE as &crate::Emoji),
-("shell" , &crate::flat::SPIRAL_SHELL as &crate::Emoji),
-("shield" , &crate::flat::SHIELD as &crate::Emoji),
-("shinto_shrine" , &crate::flat::SHINTO_SHRINE as &crate::Emoji),
-("ship" , &crate::flat::SHIP as &crate::Emoji),
-("shirt" , &crate::flat::T_SHIRT as &crate::Emoji),
-("shit" , &crate::flat::PILE_OF_POO as &crate::Emoji),
-("shoe" , &crate::flat::MAN_S_SHOE as &crate::Emoji),
-("shooting_star" , &crate::flat::SHOOTING_STAR as &crate::Emoji),
-("shopping" , &crate::flat::SHOPPING_BAGS as &crate::Emoji),
-("shopping_bags" , &crate::flat::SHOPPING_BAGS as &crate::Emoji),
-("shopping_cart" , &crate::flat::SHOPPING_CART as &crate::Emoji),
-("shortcake" , &crate::flat::SHORTCAKE as &crate::Emoji),
-("shorts" , &crate::flat::SHORTS as &crate::Emoji),
-("shower" , &crate::flat::SHOWER as &crate::Emoji),
-("shrimp" , &crate::flat::SHRIMP as &crate::Emoji),
-("shrug" , &crate::flat::PERSON_SHRUGGING.default.default as &crate::Emoji),
-("shuffle_tracks_button" , &crate::flat::SHUFFLE_TRACKS_BUTTON as &crate::Emoji),
-("shushing_face" , &crate::flat::SHUSHING_FACE as &crate::Emoji),
-("sierra_leone" , &crate::flat::FLAG_SIERRA_LEONE as &crate::Emoji),
-("sign_of_the_horns" , &crate::flat::SIGN_OF_THE_HORNS.default as &crate::Emoji),
-("signal_strength" , &crate::flat::ANTENNA_BARS as &crate::Emoji),
-("singapore" , &crate::flat::FLAG_SINGAPORE as &crate::Emoji),
-("singer" , &crate::flat::SINGER.default.default as &crate::Emoji),
-("sint_maarten" , &crate::flat::FLAG_SINT_MAARTEN as &crate::Emoji),
-("six" , &crate::flat::KEYCAP_6 as &crate::Emoji),
-("six_o_clock" , &crate::flat::SIX_O_CLOCK as &crate::Emoji),
-("six_pointed_star" , &crate::flat::DOTTED_SIX_POINTED_STAR as &crate::Emoji),
-("six_thirty" , &crate::flat::SIX_THIRTY as &crate::Emoji),
-("skateboard" , &crate::flat::SKATEBOARD as &crate::Emoji),
-("ski" , &crate::flat::SKIS as &crate::Emoji),
-("skier" , &crate::flat::SKIER as &crate::Emoji),
-("skis" , &crate::flat::SKIS as &crate::Emoji),
-("skull" , &crate::flat::SKULL as &crate::Emoji),
-("skull_and_crossbones" , &crate::flat::SKULL_AND_CROSSBONES as &crate::Emoji),
-("skunk" , &crate::flat::SKUNK as &crate::Emoji),
-("sled" , &crate::flat::SLED as &crate::Emoji),
-("sleeping" , &crate::flat::SLEEPING_FACE as &crate::Emoji),
-("sleeping_bed" , &crate::flat::PERSON_IN_BED.default as &crate::Emoji),
-("sleeping_face" , &crate::flat::SLEEPING_FACE as &crate::Emoji),
-("sleepy" , &crate::flat::SLEEPY_FACE as &crate::Emoji),
-("sleepy_face" , &crate::flat::SLEEPY_FACE as &crate::Emoji),
-("slightly_frowning_face" , &crate::flat::SLIGHTLY_FROWNING_FACE as &crate::Emoji),
-("slightly_smiling_face" , &crate::flat::SLIGHTLY_SMILING_FACE as &crate::Emoji),
-("slot_machine" , &crate::flat::SLOT_MACHINE as &crate::Emoji),
-("sloth" , &crate::flat::SLOTH as &crate::Emoji),
-("slovakia" , &crate::flat::FLAG_SLOVAKIA as &crate::Emoji),
-("slovenia" , &crate::flat::FLAG_SLOVENIA as &crate::Emoji),
-("small_airplane" , &crate::flat::SMALL_AIRPLANE as &crate::Emoji),
-("small_blue_diamond" , &crate::flat::SMALL_BLUE_DIAMOND as &crate::Emoji),
-("small_orange_diamond" , &crate::flat::SMALL_ORANGE_DIAMOND as &crate::Emoji),
-("small_red_triangle" , &crate::flat::RED_TRIANGLE_POINTED_UP as &crate::Emoji),
-("small_red_triangle_down" , &crate::flat::RED_TRIANGLE_POINTED_DOWN as &crate::Emoji),
-("smile" , &crate::flat::GRINNING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-("smile_cat" , &crate::flat::GRINNING_CAT_WITH_SMILING_EYES as &crate::Emoji),
-("smiley" , &crate::flat::GRINNING_FACE_WITH_BIG_EYES as &crate::Emoji),
-("smiley_cat" , &crate::flat::GRINNING_CAT as &crate::Emoji),
-("smiling_cat_with_heart_eyes" , &crate::flat::SMILING_CAT_WITH_HEART_EYES as &crate::Emoji),
-("smiling_face" , &crate::flat::SMILING_FACE as &crate::Emoji),
-("smiling_face_with_halo" , &crate::flat::SMILING_FACE_WITH_HALO as &crate::Emoji),
-("smiling_face_with_heart_eyes" , &crate::flat::SMILING_FACE_WITH_HEART_EYES as &crate::Emoji),
-("smiling_face_with_hearts" , &crate::flat::SMILING_FACE_WITH_HEARTS as &crate::Emoji),
-("smiling_face_with_horns" , &crate::flat::SMILING_FACE_WITH_HORNS as &crate::Emoji),
-("smiling_face_with_smiling_eyes" , &crate::flat::SMILING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-("smiling_face_with_sunglasses" , &crate::flat::SMILING_FACE_WITH_SUNGLASSES as &crate::Emoji),
-("smiling_face_with_tear" , &crate::flat::SMILING_FACE_WITH_TEAR as &crate::Emoji),
-("smiling_face_with_three_hearts" , &crate::flat::SMILING_FACE_WITH_HEARTS as &crate::Emoji),
-("smiling_imp" , &crate::flat::SMILING_FACE_WITH_HORNS as &crate::Emoji),
-("smirk" , &crate::flat::SMIRKING_FACE as &crate::Emoji),
-("smirk_cat" , &crate::flat::CAT_WITH_WRY_SMILE as &crate::Emoji),
-("smirking_face" , &crate::flat::SMIRKING_FACE as &crate::Emoji),
-("smoking" , &crate::flat::CIGARETTE as &crate::Emoji),
-("snail" , &crate::flat::SNAIL as &crate::Emoji),
-("snake" , &crate::flat::SNAKE as &crate::Emoji),
-("sneezing_face" , &crate::flat::SNEEZING_FACE as &crate::Emoji),
-("snow_capped_mountain" , &crate::flat::SNOW_CAPPED_MOUNTAIN as &crate::Emoji),
-("snowboarder" , &crate::flat::SNOWBOARDER.default as &crate::Emoji),
-("snowflake" , &crate::flat::SNOWFLAKE as &crate::Emoji),
-("snowman" , &crate::flat::SNOWMAN as &crate::Emoji),
-("snowman_with_snow" , &crate::flat::SNOWMAN as &crate::Emoji),
-("snowman_without_snow" , &crate::flat::SNOWMAN_WITHOUT_SNOW as &crate::Emoji),
-("soap" , &crate::flat::SOAP as &crate::Emoji),
-("sob" , &crate::flat::LOUDLY_CRYING_FACE as &crate::Emoji),
-("soccer" , &crate::flat::SOCCER_BALL as &crate::Emoji),
-("soccer_ball" , &crate::flat::SOCCER_BALL as &crate::Emoji),
-("socks" , &crate::flat::SOCKS as &crate::Emoji),
-("soft_ice_cream" , &crate::flat::SOFT_ICE_CREAM as &crate::Emoji),
-("softball" , &crate::flat::SOFTBALL as &crate::Emoji),
-("solomon_islands" , &crate::flat::FLAG_SOLOMON_ISLANDS as &crate::Emoji),
-("somalia" , &crate::flat::FLAG_SOMALIA as &crate::Emoji),
-("soon" , &crate::flat::SOON_ARROW as &crate::Emoji),
-("soon_arrow" , &crate::flat::SOON_ARROW as &crate::Emoji),
-("sos" , &crate::flat::SOS_BUTTON as &crate::Emoji),
-("sos_button" , &crate::flat::SOS_BUTTON as &crate::Emoji),
-("sound" , &crate::flat::SPEAKER_MEDIUM_VOLUME as &crate::Emoji),
-("south_africa" , &crate::flat::FLAG_SOUTH_AFRICA as &crate::Emoji),
-("south_georgia_south_sandwich_islands" , &crate::flat::FLAG_SOUTH_GEORGIA_AND_SOUTH_SANDWICH_ISLANDS as &crate::Emoji),
-("south_sudan" , &crate::flat::FLAG_SOUTH_SUDAN as &crate::Emoji),
-("space_invader" , &crate::flat::ALIEN_MONSTER as &crate::Emoji),
-("spade_suit" , &crate::flat::SPADE_SUIT as &crate::Emoji),
-("spades" , &crate::flat::SPADE_SUIT as &crate::Emoji),
-("spaghetti" , &crate::flat::SPAGHETTI as &crate::Emoji),
-("sparkle" , &crate::flat::SPARKLE as &crate::Emoji),
-("sparkler" , &crate::flat::SPARKLER as &crate::Emoji),
-("sparkles" , &crate::flat::SPARKLES as &crate::Emoji),
-("sparkling_heart" , &crate::flat::SPARKLING_HEART as &crate::Emoji),
-("speak_no_evil" , &crate::flat::SPEAK_NO_EVIL_MONKEY as &crate::Emoji),
-("speak_no_evil_monkey" , &crate::flat::SPEAK_NO_EVIL_MONKEY as &crate::Emoji),
-("speaker" , &crate::flat::SPEAKER_LOW_VOLUME as &crate::Emoji),
-("speaker_high_volume" , &crate::flat::SPEAKER_HIGH_VOLUME as &crate::Emoji),
-("speaker_low_volume" , &crate::flat::SPEAKER_LOW_VOLUME as &crate::Emoji),
-("speaker_medium_volume" , &crate::flat::SPEAKER_MEDIUM_VOLUME as &crate::Emoji),
-("speaking_head" , &crate::flat::SPEAKING_HEAD as &crate::Emoji),
-("speech_balloon" , &crate::flat::SPEECH_BALLOON as &crate::Emoji),
-("speedboat" , &crate::flat::SPEEDBOAT as &crate::Emoji),
-("spider" , &crate::flat::SPIDER as &crate::Emoji),
-("spider_web" , &crate::flat::SPIDER_WEB as &crate::Emoji),
-("spiral_calendar" , &crate::flat::SPIRAL_CALENDAR as &crate::Emoji),
-("spiral_notepad" , &crate::flat::SPIRAL_NOTEPAD as &crate::Emoji),
-("spiral_shell" , &crate::flat::SPIRAL_SHELL as &crate::Emoji),
-("sponge" , &crate::flat::SPONGE as &crate::Emoji),
-("spoon" , &crate::flat::SPOON as &crate::Emoji),
-("sport_utility_vehicle" , &crate::flat::SPORT_UTILITY_VEHICLE as &crate::Emoji),
-("sports_medal" , &crate::flat::SPORTS_MEDAL as &crate::Emoji),
-("spouting_whale" , &crate::flat::SPOUTING_WHALE as &crate::Emoji),
-("squid" , &crate::flat::SQUID as &crate::Emoji),
-("squinting_face_with_tongue" , &crate::flat::SQUINTING_FACE_WITH_TONGUE as &crate::Emoji),
-("sri_lanka" , &crate::flat::FLAG_SRI_LANKA as &crate::Emoji),
-("st_barthelemy" , &crate::flat::FLAG_ST_BARTHELEMY as &crate::Emoji),
-("st_helena" , &crate::flat::FLAG_ST_HELENA as &crate::Emoji),
-("st_kitts_nevis" , &crate::flat::FLAG_ST_KITTS_AND_NEVIS as &crate::Emoji),
-("st_lucia" , &crate::flat::FLAG_ST_LUCIA as &crate::Emoji),
-("st_martin" , &crate::flat::FLAG_ST_MARTIN as &crate::Emoji),
-("st_pierre_miquelon" , &crate::flat::FLAG_ST_PIERRE_AND_MIQUELON as &crate::Emoji),
-("st_vincent_grenadines" , &crate::flat::FLAG_ST_VINCENT_AND_GRENADINES as &crate::Emoji),
-("stadium" , &crate::flat::STADIUM as &crate::Emoji),
-("standing_man" , &crate::flat::PERSON_STANDING.gender(Gender::Male).default as &crate::Emoji),
-("standing_person" , &crate::flat::PERSON_STANDING.default.default as &crate::Emoji),
-("standing_woman" , &crate::flat::PERSON_STANDING.gender(Gender::Female).default as &crate::Emoji),
-("star" , &crate::flat::STAR as &crate::Emoji),
-("star2" , &crate::flat::GLOWING_STAR as &crate::Emoji),
-("star_and_crescent" , &crate::flat::STAR_AND_CRESCENT as &crate::Emoji),
-("star_of_david" , &crate::flat::STAR_OF_DAVID as &crate::Emoji),
-("star_struck" , &crate::flat::STAR_STRUCK as &crate::Emoji),
-("stars" , &crate::flat::SHOOTING_STAR as &crate::Emoji),
-("station" , &crate::flat::STATION as &crate::Emoji),
-("statue_of_liberty" , &crate::flat::STATUE_OF_LIBERTY as &crate::Emoji),
-("steam_locomotive" , &crate::flat::LOCOMOTIVE as &crate::Emoji),
-("steaming_bowl" , &crate::flat::STEAMING_BOWL as &crate::Emoji),
-("stethoscope" , &crate::flat::STETHOSCOPE as &crate::Emoji),
-("stew" , &crate::flat::POT_OF_FOOD as &crate::Emoji),
-("stop_button" , &crate::flat::STOP_BUTTON as &crate::Emoji),
-("stop_sign" , &crate::flat::STOP_SIGN as &crate::Emoji),
-("stopwatch" , &crate::flat::STOPWATCH as &crate::Emoji),
-("straight_ruler" , &crate::flat::STRAIGHT_RULER as &crate::Emoji),
-("strawberry" , &crate::flat::STRAWBERRY as &crate::Emoji),
-("stuck_out_tongue" , &crate::flat::FACE_WITH_TONGUE as &crate::Emoji),
-("stuck_out_tongue_closed_eyes" , &crate::flat::SQUINTING_FACE_WITH_TONGUE as &crate::Emoji),
-("stuck_out_tongue_winking_eye" , &crate::flat::WINKING_FACE_WITH_TONGUE as &crate::Emoji),
-("student" , &crate::flat::STUDENT.default.default as &crate::Emoji),
-("studio_microphone" , &crate::flat::STUDIO_MICROPHONE as &crate::Emoji),
-("stuffed_flatbread" , &crate::flat::STUFFED_FLATBREAD as &crate::Emoji),
-("sudan" , &crate::flat::FLAG_SUDAN as &crate::Emoji),
-("sun" , &crate::flat::SUN as &crate::Emoji),
-("sun_behind_cloud" , &crate::flat::SUN_BEHIND_CLOUD as &crate::Emoji),
-("sun_behind_large_cloud" , &crate::flat::SUN_BEHIND_LARGE_CLOUD as &crate::Emoji),
-("sun_behind_rain_cloud" , &crate::flat::SUN_BEHIND_RAIN_CLOUD as &crate::Emoji),
-("sun_behind_small_cloud" , &crate::flat::SUN_BEHIND_SMALL_CLOUD as &crate::Emoji),
-("sun_with_face" , &crate::flat::SUN_WITH_FACE as &crate::Emoji),
-("sunflower" , &crate::flat::SUNFLOWER as &crate::Emoji),
-("sunglasses" , &crate::flat::SUNGLASSES as &crate::Emoji),
-("sunny" , &crate::flat::SUN as &crate::Emoji),
-("sunrise" , &crate::flat::SUNRISE as &crate::Emoji),
-("sunrise_over_mountains" , &crate::flat::SUNRISE_OVER_MOUNTAINS as &crate::Emoji),
-("sunset" , &crate::flat::SUNSET as &crate::Emoji),
-("superhero" , &crate::flat::SUPERHERO.default.default as &crate::Emoji),
-("superhero_man" , &crate::flat::SUPERHERO.gender(Gender::Male).default as &crate::Emoji),
-("superhero_woman" , &crate::flat::SUPERHERO.gender(Gender::Female).default as &crate::Emoji),
-("supervillain" , &crate::flat::SUPERVILLAIN.default.default as &crate::Emoji),
-("supervillain_man" , &crate::flat::SUPERVILLAIN.gender(Gender::Male).default as &crate::Emoji),
-("supervillain_woman" , &crate::flat::SUPERVILLAIN.gender(Gender::Female).default as &crate::Emoji),
-("surfer" , &crate::flat::PERSON_SURFING.default.default as &crate::Emoji),
-("surfing_man" , &crate::flat::PERSON_SURFING.gender(Gender::Male).default as &crate::Emoji),
-("surfing_woman" , &crate::flat::PERSON_SURFING.gender(Gender::Female).default as &crate::Emoji),
-("suriname" , &crate::flat::FLAG_SURINAME as &crate::Emoji),
-("sushi" , &crate::flat::SUSHI as &crate::Emoji),
-("suspension_railway" , &crate::flat::SUSPENSION_RAILWAY as &crate::Emoji),
-("svalbard_jan_mayen" , &crate::flat::FLAG_SVALBARD_AND_JAN_MAYEN as &crate::Emoji),
-("swan" , &crate::flat::SWAN as &crate::Emoji),
-("swaziland" , &crate::flat::FLAG_ESWATINI as &crate::Emoji),
-("sweat" , &crate::flat::DOWNCAST_FACE_WITH_SWEAT as &crate::Emoji),
-("sweat_droplets" , &crate::flat::SWEAT_DROPLETS as &crate::Emoji),
-("sweat_drops" , &crate::flat::SWEAT_DROPLETS as &crate::Emoji),
-("sweat_smile" , &crate::flat::GRINNING_FACE_WITH_SWEAT as &crate::Emoji),
-("sweden" , &crate::flat::FLAG_SWEDEN as &crate::Emoji),
-("sweet_potato" , &crate::flat::ROASTED_SWEET_POTATO as &crate::Emoji),
-("swim_brief" , &crate::flat::BRIEFS as &crate::Emoji),
-("swimmer" , &crate::flat::PERSON_SWIMMING.default.default as &crate::Emoji),
-("swimming_man" , &crate::flat::PERSON_SWIMMING.gender(Gender::Male).default as &crate::Emoji),
-("swimming_woman" , &crate::flat::PERSON_SWIMMING.gender(Gender::Female).default as &crate::Emoji),
-("switzerland" , &crate::flat::FLAG_SWITZERLAND as &crate::Emoji),
-("symbols" , &crate::flat::INPUT_SYMBOLS as &crate::Emoji),
-("synagogue" , &crate::flat::SYNAGOGUE as &crate::Emoji),
-("syria" , &crate::flat::FLAG_SYRIA as &crate::Emoji),
-("syringe" , &crate::flat::SYRINGE as &crate::Emoji),
-("t-rex" , &crate::flat::T_REX as &crate::Emoji),
-("t_rex" , &crate::flat::T_REX as &crate::Emoji),
-("t_shirt" , &crate::flat::T_SHIRT as &crate::Emoji),
-("taco" , &crate::flat::TACO as &crate::Emoji),
-("tada" , &crate::flat::PARTY_POPPER as &crate::Emoji),
-("taiwan" , &crate::flat::FLAG_TAIWAN as &crate::Emoji),
-("tajikistan" , &crate::flat::FLAG_TAJIKISTAN as &crate::Emoji),
-("takeout_box" , &crate::flat::TAKEOUT_BOX as &crate::Emoji),
-("tamale" , &crate::flat::TAMALE as &crate::Emoji),
-("tanabata_tree" , &crate::flat::TANABATA_TREE as &crate::Emoji),
-("tangerine" , &crate::flat::TANGERINE as &crate::Emoji),
-("tanzania" , &crate::flat::FLAG_TANZANIA as &crate::Emoji),
-("taurus" , &crate::flat::TAURUS as &crate::Emoji),
-("taxi" , &crate::flat::TAXI as &crate::Emoji),
-("tea" , &crate::flat::TEACUP_WITHOUT_HANDLE as &crate::Emoji),
-("teacher" , &crate::flat::TEACHER.default.default as &crate::Emoji),
-("teacup_without_handle" , &crate::flat::TEACUP_WITHOUT_HANDLE as &crate::Emoji),
-("teapot" , &crate::flat::TEAPOT as &crate::Emoji),
-("tear_off_calendar" , &crate::flat::TEAR_OFF_CALENDAR as &crate::Emoji),
-("technologist" , &crate::flat::TECHNOLOGIST.default.default as &crate::Emoji),
-("teddy_bear" , &crate::flat::TEDDY_BEAR as &crate::Emoji),
-("telephone" , &crate::flat::TELEPHONE as &crate::Emoji),
-("telephone_receiver" , &crate::flat::TELEPHONE_RECEIVER as &crate::Emoji),
-("telescope" , &crate::flat::TELESCOPE as &crate::Emoji),
-("television" , &crate::flat::TELEVISION as &crate::Emoji),
-("ten_o_clock" , &crate::flat::TEN_O_CLOCK as &crate::Emoji),
-("ten_thirty" , &crate::flat::TEN_THIRTY as &crate::Emoji),
-("tennis" , &crate::flat::TENNIS as &crate::Emoji),
-("tent" , &crate::flat::TENT as &crate::Emoji),
-("test_tube" , &crate::flat::TEST_TUBE as &crate::Emoji),
-("thailand" , &crate::flat::FLAG_THAILAND as &crate::Emoji),
-("thermometer" , &crate::flat::THERMOMETER as &crate::Emoji),
-("thinking" , &crate::flat::THINKING_FACE as &crate::Emoji),
-("thinking_face" , &crate::flat::THINKING_FACE as &crate::Emoji),
-("third_place_medal" , &crate::flat::THIRD_PLACE_MEDAL as &crate::Emoji),
-("thong_sandal" , &crate::flat::THONG_SANDAL as &crate::Emoji),
-("thought_balloon" , &crate::flat::THOUGHT_BALLOON as &crate::Emoji),
-("thread" , &crate::flat::THREAD as &crate::Emoji),
-("three" , &crate::flat::KEYCAP_3 as &crate::Emoji),
-("three_o_clock" , &crate::flat::THREE_O_CLOCK as &crate::Emoji),
-("three_thirty" , &crate::flat::THREE_THIRTY as &crate::Emoji),
-("thumbs_down" , &crate::flat::THUMBS_DOWN.default as &crate::Emoji),
-("thumbs_up" , &crate::flat::THUMBS_UP.default as &crate::Emoji),
-("thumbsdown" , &crate::flat::THUMBS_DOWN.default as &crate::Emoji),
-("thumbsup" , &crate::flat::THUMBS_UP.default as &crate::Emoji),
-("ticket" , &crate::flat::TICKET as &crate::Emoji),
-("tickets" , &crate::flat::ADMISSION_TICKETS as &crate::Emoji),
-("tiger" , &crate::flat::TIGER as &crate::Emoji),
-("tiger2" , &crate::flat::TIGER as &crate::Emoji),
-("tiger_face" , &crate::flat::TIGER_FACE as &crate::Emoji),
-("timer_clock" , &crate::flat::TIMER_CLOCK as &crate::Emoji),
-("timor_leste" , &crate::flat::FLAG_TIMOR_LESTE as &crate::Emoji),
-("tipping_hand_man" , &crate::flat::PERSON_TIPPING_HAND.gender(Gender::Male).default as &crate::Emoji),
-("tipping_hand_person" , &crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
-("tipping_hand_woman" , &crate::flat::PERSON_TIPPING_HAND.gender(Gender::Female).default as &crate::Emoji),
-("tired_face" , &crate::flat::TIRED_FACE as &crate::Emoji),
-("tm" , &crate::flat::TRADE_MARK as &crate::Emoji),
-("togo" , &crate::flat::FLAG_TOGO as &crate::Emoji),
-("toilet" , &crate::flat::TOILET as &crate::Emoji),
-("tokelau" , &crate::flat::FLAG_TOKELAU as &crate::Emoji),
-("tokyo_tower" , &crate::flat::TOKYO_TOWER as &crate::Emoji),
-("tomato" , &crate::flat::TOMATO as &crate::Emoji),
-("tonga" , &crate::flat::FLAG_TONGA as &crate::Emoji),
-("tongue" , &crate::flat::TONGUE as &crate::Emoji),
-("toolbox" , &crate::flat::TOOLBOX as &crate::Emoji),
-("tooth" , &crate::flat::TOOTH as &crate::Emoji),
-("toothbrush" , &crate::flat::TOOTHBRUSH as &crate::Emoji),
-("top" , &crate::flat::TOP_ARROW as &crate::Emoji),
-("top_arrow" , &crate::flat::TOP_ARROW as &crate::Emoji),
-("top_hat" , &crate::flat::TOP_HAT as &crate::Emoji),
-("tophat" , &crate::flat::TOP_HAT as &crate::Emoji),
-("tornado" , &crate::flat::TORNADO as &crate::Emoji),
-("tr" , &crate::flat::FLAG_TURKEY as &crate::Emoji),
-("trackball" , &crate::flat::TRACKBALL as &crate::Emoji),
-("tractor" , &crate::flat::TRACTOR as &crate::Emoji),
-("trade_mark" , &crate::flat::TRADE_MARK as &crate::Emoji),
-("traffic_light" , &crate::flat::HORIZONTAL_TRAFFIC_LIGHT as &crate::Emoji),
-("train" , &crate::flat::TRAIN as &crate::Emoji),
-("train2" , &crate::flat::TRAIN as &crate::Emoji),
-("tram" , &crate::flat::TRAM as &crate::Emoji),
-("tram_car" , &crate::flat::TRAM_CAR as &crate::Emoji),
-("transgender_flag" , &crate::flat::TRANSGENDER_FLAG as &crate::Emoji),
-("transgender_symbol" , &crate::flat::TRANSGENDER_SYMBOL as &crate::Emoji),
-("triangular_flag" , &crate::flat::TRIANGULAR_FLAG as &crate::Emoji),
-("triangular_flag_on_post" , &crate::flat::TRIANGULAR_FLAG as &crate::Emoji),
-("triangular_ruler" , &crate::flat::TRIANGULAR_RULER as &crate::Emoji),
-("trident" , &crate::flat::TRIDENT_EMBLEM as &crate::Emoji),
-("trident_emblem" , &crate::flat::TRIDENT_EMBLEM as &crate::Emoji),
-("trinidad_tobago" , &crate::flat::FLAG_TRINIDAD_AND_TOBAGO as &crate::Emoji),
-("tristan_da_cunha" , &crate::flat::FLAG_TRISTAN_DA_CUNHA as &crate::Emoji),
-("triumph" , &crate::flat::FACE_WITH_STEAM_FROM_NOSE as &crate::Emoji),
-("trolleybus" , &crate::flat::TROLLEYBUS as &crate::Emoji),
-("trophy" , &crate::flat::TROPHY as &crate::Emoji),
-("tropical_drink" , &crate::flat::TROPICAL_DRINK as &crate::Emoji),
-("tropical_fish" , &crate::flat::TROPICAL_FISH as &crate::Emoji),
-("truck" , &crate::flat::DELIVERY_TRUCK as &crate::Emoji),
-("trumpet" , &crate::flat::TRUMPET as &crate::Emoji),
-("tshirt" , &crate::flat::T_SHIRT as &crate::Emoji),
-("tulip" , &crate::flat::TULIP as &crate::Emoji),
-("tumbler_glass" , &crate::flat::TUMBLER_GLASS as &crate::Emoji),
-("tunisia" , &crate::flat::FLAG_TUNISIA as &crate::Emoji),
-("turkey" , &crate::flat::TURKEY as &crate::Emoji),
-("turkmenistan" , &crate::flat::FLAG_TURKMENISTAN as &crate::Emoji),
-("turks_caicos_islands" , &crate::flat::FLAG_TURKS_AND_CAICOS_ISLANDS as &crate::Emoji),
-("turtle" , &crate::flat::TURTLE as &crate::Emoji),
-("tuvalu" , &crate::flat::FLAG_TUVALU as &crate::Emoji),
-("tv" , &crate::flat::TELEVISION as &crate::Emoji),
-("twelve_o_clock" , &crate::flat::TWELVE_O_CLOCK as &crate::Emoji),
-("twelve_thirty" , &crate::flat::TWELVE_THIRTY as &crate::Emoji),
-("twisted_rightwards_arrows" , &crate::flat::SHUFFLE_TRACKS_BUTTON as &crate::Emoji),
-("two" , &crate::flat::KEYCAP_2 as &crate::Emoji),
-("two_hearts" , &crate::flat::TWO_HEARTS as &crate::Emoji),
-("two_hump_camel" , &crate::flat::TWO_HUMP_CAMEL as &crate::Emoji),
-("two_men_holding_hands" , &crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Males).default as &crate::Emoji),
-("two_o_clock" , &crate::flat::TWO_O_CLOCK as &crate::Emoji),
-("two_thirty" , &crate::flat::TWO_THIRTY as &crate::Emoji),
-("two_women_holding_hands" , &crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Females).default as &crate::Emoji),
-("u5272" , &crate::flat::JAPANESE_DISCOUNT_BUTTON as &crate::Emoji),
-("u5408" , &crate::flat::JAPANESE_PASSING_GRADE_BUTTON as &crate::Emoji),
-("u55b6" , &crate::flat::JAPANESE_OPEN_FOR_BUSINESS_BUTTON as &crate::Emoji),
-("u6307" , &crate::flat::JAPANESE_RESERVED_BUTTON as &crate::Emoji),
-("u6708" , &crate::flat::JAPANESE_MONTHLY_AMOUNT_BUTTON as &crate::Emoji),
-("u6709" , &crate::flat::JAPANESE_NOT_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
-("u6e80" , &crate::flat::JAPANESE_NO_VACANCY_BUTTON as &crate::Emoji),
-("u7121" , &crate::flat::JAPANESE_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
-("u7533" , &crate::flat::JAPANESE_APPLICATION_BUTTON as &crate::Emoji),
-("u7981" , &crate::flat::JAPANESE_PROHIBITED_BUTTON as &crate::Emoji),
-("u7a7a" , &crate::flat::JAPANESE_VACANCY_BUTTON as &crate::Emoji),
-("uganda" , &crate::flat::FLAG_UGANDA as &crate::Emoji),
-("uk" , &crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
-("ukraine" , &crate::flat::FLAG_UKRAINE as &crate::Emoji),
-("umbrella" , &crate::flat::UMBRELLA as &crate::Emoji),
-("umbrella_on_ground" , &crate::flat::UMBRELLA_ON_GROUND as &crate::Emoji),
-("umbrella_with_rain_drops" , &crate::flat::UMBRELLA_WITH_RAIN_DROPS as &crate::Emoji),
-("unamused" , &crate::flat::UNAMUSED_FACE as &crate::Emoji),
-("unamused_face" , &crate::flat::UNAMUSED_FACE as &crate::Emoji),
-("underage" , &crate::flat::NO_ONE_UNDER_EIGHTEEN as &crate::Emoji),
-("unicorn" , &crate::flat::UNICORN as &crate::Emoji),
-("united_arab_emirates" , &crate::flat::FLAG_UNITED_ARAB_EMIRATES as &crate::Emoji),
-("united_nations" , &crate::flat::FLAG_UNITED_NATIONS as &crate::Emoji),
-("unlock" , &crate::flat::UNLOCKED as &crate::Emoji),
-("unlocked" , &crate::flat::UNLOCKED as &crate::Emoji),
-("up" , &crate::flat::UP_BUTTON as &crate::Emoji),
-("up_arrow" , &crate::flat::UP_ARROW as &crate::Emoji),
-("up_button" , &crate::flat::UP_BUTTON as &crate::Emoji),
-("up_down_arrow" , &crate::flat::UP_DOWN_ARROW as &crate::Emoji),
-("up_left_arrow" , &crate::flat::UP_LEFT_ARROW as &crate::Emoji),
-("up_right_arrow" , &crate::flat::UP_RIGHT_ARROW as &crate::Emoji),
-("upside_down_face" , &crate::flat::UPSIDE_DOWN_FACE as &crate::Emoji),
-("upwards_button" , &crate::flat::UPWARDS_BUTTON as &crate::Emoji),
-("uruguay" , &crate::flat::FLAG_URUGUAY as &crate::Emoji),
-("us" , &crate::flat::FLAG_UNITED_STATES as &crate::Emoji),
-("us_outlying_islands" , &crate::flat::FLAG_US_OUTLYING_ISLANDS as &crate::Emoji),
-("us_virgin_islands" , &crate::flat::FLAG_US_VIRGIN_ISLANDS as &crate::Emoji),
-("uzbekistan" , &crate::flat::FLAG_UZBEKISTAN as &crate::Emoji),
-("v" , &crate::flat::VICTORY_HAND.default as &crate::Emoji),
-("vampire" , &crate::flat::VAMPIRE.default.default as &crate::Emoji),
-("vampire_man" , &crate::flat::VAMPIRE.gender(Gender::Male).default as &crate::Emoji),
-("vampire_woman" , &crate::flat::VAMPIRE.gender(Gender::Female).default as &crate::Emoji),
-("vanuatu" , &crate::flat::FLAG_VANUATU as &crate::Emoji),
-("vatican_city" , &crate::flat::FLAG_VATICAN_CITY as &crate::Emoji),
-("venezuela" , &crate::flat::FLAG_VENEZUELA as &crate::Emoji),
-("vertical_traffic_light" , &crate::flat::VERTICAL_TRAFFIC_LIGHT as &crate::Emoji),
-("vhs" , &crate::flat::VIDEOCASSETTE as &crate::Emoji),
-("vibration_mode" , &crate::flat::VIBRATION_MODE as &crate::Emoji),
-("victory_hand" , &crate::flat::VICTORY_HAND.default as &crate::Emoji),
-("video_camera" , &crate::flat::VIDEO_CAMERA as &crate::Emoji),
-("video_game" , &crate::flat::VIDEO_GAME as &crate::Emoji),
-("videocassette" , &crate::flat::VIDEOCASSETTE as &crate::Emoji),
-("vietnam" , &crate::flat::FLAG_VIETNAM as &crate::Emoji),
-("violin" , &crate::flat::VIOLIN as &crate::Emoji),
-("virgo" , &crate::flat::VIRGO as &crate::Emoji),
-("volcano" , &crate::flat::VOLCANO as &crate::Emoji),
-("volleyball" , &crate::flat::VOLLEYBALL as &crate::Emoji),
-("vomiting_face" , &crate::flat::FACE_VOMITING as &crate::Emoji),
-("vs" , &crate::flat::VS_BUTTON as &crate::Emoji),
-("vs_button" , &crate::flat::VS_BUTTON as &crate::Emoji),
-("vulcan_salute" , &crate::flat::VULCAN_SALUTE.default as &crate::Emoji),
-("waffle" , &crate::flat::WAFFLE as &crate::Emoji),
-("wales" , &crate::flat::FLAG_WALES as &crate::Emoji),
-("walking" , &crate::flat::PERSON_WALKING.default.default as &crate::Emoji),
-("walking_man" , &crate::flat::PERSON_WALKING.gender(Gender::Male).default as &crate::Emoji),
-("walking_woman" , &crate::flat::PERSON_WALKING.gender(Gender::Female).default as &crate::Emoji),
-("wallis_futuna" , &crate::flat::FLAG_WALLIS_AND_FUTUNA as &crate::Emoji),
-("waning_crescent_moon" , &crate::flat::WANING_CRESCENT_MOON as &crate::Emoji),
-("waning_gibbous_moon" , &crate::flat::WANING_GIBBOUS_MOON as &crate::Emoji),
-("warning" , &crate::flat::WARNING as &crate::Emoji),
-("wastebasket" , &crate::flat::WASTEBASKET as &crate::Emoji),
-("watch" , &crate::flat::WATCH as &crate::Emoji),
-("water_buffalo" , &crate::flat::WATER_BUFFALO as &crate::Emoji),
-("water_closet" , &crate::flat::WATER_CLOSET as &crate::Emoji),
-("water_pistol" , &crate::flat::WATER_PISTOL as &crate::Emoji),
-("water_polo" , &crate::flat::PERSON_PLAYING_WATER_POLO.default.default as &crate::Emoji),
-("water_wave" , &crate::flat::WATER_WAVE as &crate::Emoji),
-("watermelon" , &crate::flat::WATERMELON as &crate::Emoji),
-("wave" , &crate::flat::WAVING_HAND.default as &crate::Emoji),
-("waving_hand" , &crate::flat::WAVING_HAND.default as &crate::Emoji),
-("wavy_dash" , &crate::flat::WAVY_DASH as &crate::Emoji),
-("waxing_crescent_moon" , &crate::flat::WAXING_CRESCENT_MOON as &crate::Emoji),
-("waxing_gibbous_moon" , &crate::flat::WAXING_GIBBOUS_MOON as &crate::Emoji),
-("wc" , &crate::flat::WATER_CLOSET as &crate::Emoji),
-("weary" , &crate::flat::WEARY_FACE as &crate::Emoji),
-("weary_cat" , &crate::flat::WEARY_CAT as &crate::Emoji),
-("weary_face" , &crate::flat::WEARY_FACE as &crate::Emoji),
-("wedding" , &crate::flat::WEDDING as &crate::Emoji),
-("weight_lifting" , &crate::flat::PERSON_LIFTING_WEIGHTS.default.default as &crate::Emoji),
-("weight_lifting_man" , &crate::flat::PERSON_LIFTING_WEIGHTS.gender(Gender::Male).default as &crate::Emoji),
-("weight_lifting_woman" , &crate::flat::PERSON_LIFTING_WEIGHTS.gender(Gender::Female).default as &crate::Emoji),
-("western_sahara" , &crate::flat::FLAG_WESTERN_SAHARA as &crate::Emoji),
-("whale" , &crate::flat::WHALE as &crate::Emoji),
-("whale2" , &crate::flat::WHALE as &crate::Emoji),
-("wheel_of_dharma" , &crate::flat::WHEEL_OF_DHARMA as &crate::Emoji),
-("wheelchair" , &crate::flat::WHEELCHAIR_SYMBOL as &crate::Emoji),
-("wheelchair_symbol" , &crate::flat::WHEELCHAIR_SYMBOL as &crate::Emoji),
-("white_cane" , &crate::flat::WHITE_CANE as &crate::Emoji),
-("white_check_mark" , &crate::flat::CHECK_MARK_BUTTON as &crate::Emoji),
-("white_circle" , &crate::flat::WHITE_CIRCLE as &crate::Emoji),
-("white_exclamation_mark" , &crate::flat::WHITE_EXCLAMATION_MARK as &crate::Emoji),
-("white_flag" , &crate::flat::WHITE_FLAG as &crate::Emoji),
-("white_flower" , &crate::flat::WHITE_FLOWER as &crate::Emoji),
-("white_hair" , &crate::flat::WHITE_HAIR as &crate::Emoji),
-("white_haired_man" , &crate::flat::PERSON.hair(Hair::White).gender(Gender::Male).default as &crate::Emoji),
-("white_haired_woman" , &crate::flat::PERSON.hair(Hair::White).gender(Gender::Female).default as &crate::Emoji),
-("white_heart" , &crate::flat::WHITE_HEART as &crate::Emoji),
-("white_large_square" , &crate::flat::WHITE_LARGE_SQUARE as &crate::Emoji),
-("white_medium_small_square" , &crate::flat::WHITE_MEDIUM_SMALL_SQUARE as &crate::Emoji),
-("white_medium_square" , &crate::flat::WHITE_MEDIUM_SQUARE as &crate::Emoji),
-("white_question_mark" , &crate::flat::WHITE_QUESTION_MARK as &crate::Emoji),
-("white_small_square" , &crate::flat::WHITE_SMALL_SQUARE as &crate::Emoji),
-("white_square_button" , &crate::flat::WHITE_SQUARE_BUTTON as &crate::Emoji),
-("wilted_flower" , &crate::flat::WILTED_FLOWER as &crate::Emoji),
-("wind_chime" , &crate::flat::WIND_CHIME as &crate::Emoji),
-("wind_face" , &crate::flat::WIND_FACE as &crate::Emoji),
-("window" , &crate::flat::WINDOW as &crate::Emoji),
-("wine_glass" , &crate::flat::WINE_GLASS as &crate::Emoji),
-("wink" , &crate::flat::WINKING_FACE as &crate::Emoji),
-("winking_face" , &crate::flat::WINKING_FACE as &crate::Emoji),
-("winking_face_with_tongue" , &crate::flat::WINKING_FACE_WITH_TONGUE as &crate::Emoji),
-("wolf" , &crate::flat::WOLF as &crate::Emoji),
-("woman" , &crate::flat::PERSON.default.gender(Gender::Female).default as &crate::Emoji),
-("woman_artist" , &crate::flat::ARTIST.gender(Gender::Female).default as &crate::Emoji),
-("woman_astronaut" , &crate::flat::ASTRONAUT.gender(Gender::Female).default as &crate::Emoji),
-("woman_beard" , &crate::flat::PERSON.hair(Hair::Beard).gender(Gender::Female).default as &crate::Emoji),
-("woman_cartwheeling" , &crate::flat::PERSON_CARTWHEELING.gender(Gender::Female).default as &crate::Emoji),
-("woman_cook" , &crate::flat::COOK.gender(Gender::Female).default as &crate::Emoji),
-("woman_dancing" , &crate::flat::PERSON_DANCING.gender(Gender::Female).default as &crate::Emoji),
-("woman_facepalming" , &crate::flat::PERSON_FACEPALMING.gender(Gender::Female).default as &crate::Emoji),
-("woman_factory_worker" , &crate::flat::FACTORY_WORKER.gender(Gender::Female).default as &crate::Emoji),
-("woman_farmer" , &crate::flat::FARMER.gender(Gender::Female).default as &crate::Emoji),
-("woman_feeding_baby" , &crate::flat::PERSON_FEEDING_BABY.gender(Gender::Female).default as &crate::Emoji),
-("woman_firefighter" , &crate::flat::FIREFIGHTER.gender(Gender::Female).default as &crate::Emoji),
-("woman_health_worker" , &crate::flat::HEALTH_WORKER.gender(Gender::Female).default as &crate::Emoji),
-("woman_in_manual_wheelchair" , &crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.gender(Gender::Female).default as &crate::Emoji),
-("woman_in_motorized_wheelchair" , &crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.gender(Gender::Female).default as &crate::Emoji),
-("woman_in_tuxedo" , &crate::flat::PERSON_IN_TUXEDO.gender(Gender::Female).default as &crate::Emoji),
-("woman_judge" , &crate::flat::JUDGE.gender(Gender::Female).default as &crate::Emoji),
-("woman_juggling" , &crate::flat::PERSON_JUGGLING.gender(Gender::Female).default as &crate::Emoji),
-("woman_mechanic" , &crate::flat::MECHANIC.gender(Gender::Female).default as &crate::Emoji),
-("woman_office_worker" , &crate::flat::OFFICE_WORKER.gender(Gender::Female).default as &crate::Emoji),
-("woman_pilot" , &crate::flat::PILOT.gender(Gender::Female).default as &crate::Emoji),
-("woman_playing_handball" , &crate::flat::PERSON_PLAYING_HANDBALL.gender(Gender::Female).default as &crate::Emoji),
-("woman_playing_water_polo" , &crate::flat::PERSON_PLAYING_WATER_POLO.gender(Gender::Female).default as &crate::Emoji),
-("woman_s_boot" , &crate::flat::WOMAN_S_BOOT as &crate::Emoji),
-("woman_s_clothes" , &crate::flat::WOMAN_S_CLOTHES as &crate::Emoji),
-("woman_s_hat" , &crate::flat::WOMAN_S_HAT as &crate::Emoji),
-("woman_s_sandal" , &crate::flat::WOMAN_S_SANDAL as &crate::Emoji),
-("woman_scientist" , &crate::flat::SCIENTIST.gender(Gender::Female).default as &crate::Emoji),
-("woman_shrugging" , &crate::flat::PERSON_SHRUGGING.gender(Gender::Female).default as &crate::Emoji),
-("woman_singer" , &crate::flat::SINGER.gender(Gender::Female).default as &crate::Emoji),
-("woman_student" , &crate::flat::STUDENT.gender(Gender::Female).default as &crate::Emoji),
-("woman_teacher" , &crate::flat::TEACHER.gender(Gender::Female).default as &crate::Emoji),
-("woman_technologist" , &crate::flat::TECHNOLOGIST.gender(Gender::Female).default as &crate::Emoji),
-("woman_with_headscarf" , &crate::flat::WOMAN_WITH_HEADSCARF.default as &crate::Emoji),
-("woman_with_probing_cane" , &crate::flat::PERSON_WITH_WHITE_CANE.gender(Gender::Female).default as &crate::Emoji),
-("woman_with_turban" , &crate::flat::PERSON_WEARING_TURBAN.gender(Gender::Female).default as &crate::Emoji),
-("woman_with_veil" , &crate::flat::PERSON_WITH_VEIL.gender(Gender::Female).default as &crate::Emoji),
-("womans_clothes" , &crate::flat::WOMAN_S_CLOTHES as &crate::Emoji),
-("womans_hat" , &crate::flat::WOMAN_S_HAT as &crate::Emoji),
-("women_s_room" , &crate::flat::WOMEN_S_ROOM as &crate::Emoji),
-("women_wrestling" , &crate::flat::PERSON_WRESTLING.gender(Gender::Female) as &crate::Emoji),
-("womens" , &crate::flat::WOMEN_S_ROOM as &crate::Emoji),
-("wood" , &crate::flat::WOOD as &crate::Emoji),
-("woozy_face" , &crate::flat::WOOZY_FACE as &crate::Emoji),
-("world_map" , &crate::flat::WORLD_MAP as &crate::Emoji),
-("worm" , &crate::flat::WORM as &crate::Emoji),
-("worried" , &crate::flat::WORRIED_FACE as &crate::Emoji),
-("worried_face" , &crate::flat::WORRIED_FACE as &crate::Emoji),
-("wrapped_gift" , &crate::flat::WRAPPED_GIFT as &crate::Emoji),
-("wrench" , &crate::flat::WRENCH as &crate::Emoji),
-("wrestling" , &crate::flat::PERSON_WRESTLING.default as &crate::Emoji),
-("writing_hand" , &crate::flat::WRITING_HAND.default as &crate::Emoji),
-("x" , &crate::flat::CROSS_MARK as &crate::Emoji),
-("yarn" , &crate::flat::YARN as &crate::Emoji),
-("yawning_face" , &crate::flat::YAWNING_FACE as &crate::Emoji),
-("yellow_circle" , &crate::flat::YELLOW_CIRCLE as &crate::Emoji),
-("yellow_heart" , &crate::flat::YELLOW_HEART as &crate::Emoji),
-("yellow_square" , &crate::flat::YELLOW_SQUARE as &crate::Emoji),
-("yemen" , &crate::flat::FLAG_YEMEN as &crate::Emoji),
-("yen" , &crate::flat::YEN_BANKNOTE as &crate::Emoji),
-("yen_banknote" , &crate::flat::YEN_BANKNOTE as &crate::Emoji),
-("yin_yang" , &crate::flat::YIN_YANG as &crate::Emoji),
-("yo_yo" , &crate::flat::YO_YO as &crate::Emoji),
-("yum" , &crate::flat::FACE_SAVORING_FOOD as &crate::Emoji),
-("zambia" , &crate::flat::FLAG_ZAMBIA as &crate::Emoji),
-("zany_face" , &crate::flat::ZANY_FACE as &crate::Emoji),
-("zap" , &crate::flat::HIGH_VOLTAGE as &crate::Emoji),
-("zebra" , &crate::flat::ZEBRA as &crate::Emoji),
-("zero" , &crate::flat::KEYCAP_0 as &crate::Emoji),
-("zimbabwe" , &crate::flat::FLAG_ZIMBABWE as &crate::Emoji),
-("zipper_mouth_face" , &crate::flat::ZIPPER_MOUTH_FACE as &crate::Emoji),
-("zombie" , &crate::flat::ZOMBIE.default as &crate::Emoji),
-("zombie_man" , &crate::flat::ZOMBIE.gender(Gender::Male) as &crate::Emoji),
-("zombie_woman" , &crate::flat::ZOMBIE.gender(Gender::Female) as &crate::Emoji),
-("zzz" , &crate::flat::ZZZ as &crate::Emoji),
+        #[cfg(emojic_group = "people_and_body")] ("+1" , &crate::flat::THUMBS_UP.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("-1" , &crate::flat::THUMBS_DOWN.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("100" , &crate::flat::HUNDRED_POINTS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("1234" , &crate::flat::INPUT_NUMBERS as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("1st_place_medal" , &crate::flat::FIRST_PLACE_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("2nd_place_medal" , &crate::flat::SECOND_PLACE_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("3rd_place_medal" , &crate::flat::THIRD_PLACE_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("8ball" , &crate::flat::POOL_8_BALL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("a" , &crate::flat::A_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("a_button_blood_type" , &crate::flat::A_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ab" , &crate::flat::AB_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ab_button_blood_type" , &crate::flat::AB_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("abacus" , &crate::flat::ABACUS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("abc" , &crate::flat::INPUT_LATIN_LETTERS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("abcd" , &crate::flat::INPUT_LATIN_LOWERCASE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("accept" , &crate::flat::JAPANESE_ACCEPTABLE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("accordion" , &crate::flat::ACCORDION as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("adhesive_bandage" , &crate::flat::ADHESIVE_BANDAGE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("admission_tickets" , &crate::flat::ADMISSION_TICKETS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("adult" , &crate::flat::PERSON.default.default.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("aerial_tramway" , &crate::flat::AERIAL_TRAMWAY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("afghanistan" , &crate::flat::FLAG_AFGHANISTAN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("airplane" , &crate::flat::AIRPLANE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("airplane_arrival" , &crate::flat::AIRPLANE_ARRIVAL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("airplane_departure" , &crate::flat::AIRPLANE_DEPARTURE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("aland_islands" , &crate::flat::FLAG_ALAND_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("alarm_clock" , &crate::flat::ALARM_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("albania" , &crate::flat::FLAG_ALBANIA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("alembic" , &crate::flat::ALEMBIC as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("algeria" , &crate::flat::FLAG_ALGERIA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("alien" , &crate::flat::ALIEN as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("alien_monster" , &crate::flat::ALIEN_MONSTER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ambulance" , &crate::flat::AMBULANCE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("american_football" , &crate::flat::AMERICAN_FOOTBALL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("american_samoa" , &crate::flat::FLAG_AMERICAN_SAMOA as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("amphora" , &crate::flat::AMPHORA as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("anatomical_heart" , &crate::flat::ANATOMICAL_HEART as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("anchor" , &crate::flat::ANCHOR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("andorra" , &crate::flat::FLAG_ANDORRA as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("angel" , &crate::flat::BABY_ANGEL.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("anger" , &crate::flat::ANGER_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("anger_symbol" , &crate::flat::ANGER_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("angola" , &crate::flat::FLAG_ANGOLA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("angry" , &crate::flat::ANGRY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("angry_face" , &crate::flat::ANGRY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("angry_face_with_horns" , &crate::flat::ANGRY_FACE_WITH_HORNS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("anguilla" , &crate::flat::FLAG_ANGUILLA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("anguished" , &crate::flat::ANGUISHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("anguished_face" , &crate::flat::ANGUISHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("ant" , &crate::flat::ANT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("antarctica" , &crate::flat::FLAG_ANTARCTICA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("antenna_bars" , &crate::flat::ANTENNA_BARS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("antigua_barbuda" , &crate::flat::FLAG_ANTIGUA_AND_BARBUDA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("anxious_face_with_sweat" , &crate::flat::ANXIOUS_FACE_WITH_SWEAT as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("apple" , &crate::flat::RED_APPLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("aquarius" , &crate::flat::AQUARIUS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("argentina" , &crate::flat::FLAG_ARGENTINA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("aries" , &crate::flat::ARIES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("armenia" , &crate::flat::FLAG_ARMENIA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_backward" , &crate::flat::REVERSE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_double_down" , &crate::flat::FAST_DOWN_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_double_up" , &crate::flat::FAST_UP_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_down" , &crate::flat::DOWN_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_down_small" , &crate::flat::DOWNWARDS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_forward" , &crate::flat::PLAY_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_heading_down" , &crate::flat::RIGHT_ARROW_CURVING_DOWN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_heading_up" , &crate::flat::RIGHT_ARROW_CURVING_UP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_left" , &crate::flat::LEFT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_lower_left" , &crate::flat::DOWN_LEFT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_lower_right" , &crate::flat::DOWN_RIGHT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_right" , &crate::flat::RIGHT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_right_hook" , &crate::flat::LEFT_ARROW_CURVING_RIGHT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_up" , &crate::flat::UP_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_up_down" , &crate::flat::UP_DOWN_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_up_small" , &crate::flat::UPWARDS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_upper_left" , &crate::flat::UP_LEFT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_upper_right" , &crate::flat::UP_RIGHT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrows_clockwise" , &crate::flat::CLOCKWISE_VERTICAL_ARROWS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrows_counterclockwise" , &crate::flat::COUNTERCLOCKWISE_ARROWS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("art" , &crate::flat::ARTIST_PALETTE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("articulated_lorry" , &crate::flat::ARTICULATED_LORRY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("artificial_satellite" , &crate::flat::SATELLITE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("artist" , &crate::flat::ARTIST.default.default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("artist_palette" , &crate::flat::ARTIST_PALETTE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("aruba" , &crate::flat::FLAG_ARUBA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ascension_island" , &crate::flat::FLAG_ASCENSION_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("asterisk" , &crate::flat::KEYCAP_ASTERISK as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("astonished" , &crate::flat::ASTONISHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("astonished_face" , &crate::flat::ASTONISHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("astronaut" , &crate::flat::ASTRONAUT.default.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("athletic_shoe" , &crate::flat::RUNNING_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("atm" , &crate::flat::ATM_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("atm_sign" , &crate::flat::ATM_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("atom_symbol" , &crate::flat::ATOM_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("australia" , &crate::flat::FLAG_AUSTRALIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("austria" , &crate::flat::FLAG_AUSTRIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("auto_rickshaw" , &crate::flat::AUTO_RICKSHAW as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("automobile" , &crate::flat::AUTOMOBILE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("avocado" , &crate::flat::AVOCADO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("axe" , &crate::flat::AXE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("azerbaijan" , &crate::flat::FLAG_AZERBAIJAN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("b" , &crate::flat::B_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("b_button_blood_type" , &crate::flat::B_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("baby" , &crate::flat::BABY.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("baby_angel" , &crate::flat::BABY_ANGEL.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("baby_bottle" , &crate::flat::BABY_BOTTLE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("baby_chick" , &crate::flat::BABY_CHICK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("baby_symbol" , &crate::flat::BABY_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("back" , &crate::flat::BACK_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("back_arrow" , &crate::flat::BACK_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("backhand_index_pointing_down" , &crate::flat::BACKHAND_INDEX_POINTING_DOWN.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("backhand_index_pointing_left" , &crate::flat::BACKHAND_INDEX_POINTING_LEFT.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("backhand_index_pointing_right" , &crate::flat::BACKHAND_INDEX_POINTING_RIGHT.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("backhand_index_pointing_up" , &crate::flat::BACKHAND_INDEX_POINTING_UP.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("backpack" , &crate::flat::BACKPACK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bacon" , &crate::flat::BACON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("badger" , &crate::flat::BADGER as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("badminton" , &crate::flat::BADMINTON as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bagel" , &crate::flat::BAGEL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("baggage_claim" , &crate::flat::BAGGAGE_CLAIM as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("baguette_bread" , &crate::flat::BAGUETTE_BREAD as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bahamas" , &crate::flat::FLAG_BAHAMAS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bahrain" , &crate::flat::FLAG_BAHRAIN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("balance_scale" , &crate::flat::BALANCE_SCALE as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("bald" , &crate::flat::BALD as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bald_man" , &crate::flat::PERSON.hair(Hair::Bald).gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bald_woman" , &crate::flat::PERSON.hair(Hair::Bald).gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("ballet_shoes" , &crate::flat::BALLET_SHOES as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("balloon" , &crate::flat::BALLOON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("ballot_box" , &crate::flat::BALLOT_BOX_WITH_BALLOT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("ballot_box_with_ballot" , &crate::flat::BALLOT_BOX_WITH_BALLOT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ballot_box_with_check" , &crate::flat::CHECK_BOX_WITH_CHECK as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("bamboo" , &crate::flat::PINE_DECORATION as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("banana" , &crate::flat::BANANA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("bangbang" , &crate::flat::DOUBLE_EXCLAMATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bangladesh" , &crate::flat::FLAG_BANGLADESH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("banjo" , &crate::flat::BANJO as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bank" , &crate::flat::BANK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bar_chart" , &crate::flat::BAR_CHART as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("barbados" , &crate::flat::FLAG_BARBADOS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("barber" , &crate::flat::BARBER_POLE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("barber_pole" , &crate::flat::BARBER_POLE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("baseball" , &crate::flat::BASEBALL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("basket" , &crate::flat::BASKET as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("basketball" , &crate::flat::BASKETBALL as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("basketball_man" , &crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("basketball_woman" , &crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("bat" , &crate::flat::BAT as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bath" , &crate::flat::PERSON_TAKING_BATH.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bathtub" , &crate::flat::BATHTUB as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("battery" , &crate::flat::BATTERY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("beach_umbrella" , &crate::flat::BEACH_WITH_UMBRELLA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("beach_with_umbrella" , &crate::flat::BEACH_WITH_UMBRELLA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("beaming_face_with_smiling_eyes" , &crate::flat::BEAMING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("beans" , &crate::flat::BEANS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("bear" , &crate::flat::BEAR as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bearded_person" , &crate::flat::PERSON.hair(Hair::Beard).default.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("beating_heart" , &crate::flat::BEATING_HEART as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("beaver" , &crate::flat::BEAVER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bed" , &crate::flat::BED as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("bee" , &crate::flat::HONEYBEE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("beer" , &crate::flat::BEER_MUG as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("beer_mug" , &crate::flat::BEER_MUG as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("beers" , &crate::flat::CLINKING_BEER_MUGS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("beetle" , &crate::flat::BEETLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("beginner" , &crate::flat::JAPANESE_SYMBOL_FOR_BEGINNER as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("belarus" , &crate::flat::FLAG_BELARUS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("belgium" , &crate::flat::FLAG_BELGIUM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("belize" , &crate::flat::FLAG_BELIZE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bell" , &crate::flat::BELL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bell_pepper" , &crate::flat::BELL_PEPPER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bell_with_slash" , &crate::flat::BELL_WITH_SLASH as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bellhop_bell" , &crate::flat::BELLHOP_BELL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("benin" , &crate::flat::FLAG_BENIN as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bento" , &crate::flat::BENTO_BOX as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bento_box" , &crate::flat::BENTO_BOX as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bermuda" , &crate::flat::FLAG_BERMUDA as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("beverage_box" , &crate::flat::BEVERAGE_BOX as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bhutan" , &crate::flat::FLAG_BHUTAN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bicycle" , &crate::flat::BICYCLE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bicyclist" , &crate::flat::PERSON_BIKING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bike" , &crate::flat::BICYCLE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("biking_man" , &crate::flat::PERSON_BIKING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("biking_woman" , &crate::flat::PERSON_BIKING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bikini" , &crate::flat::BIKINI as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("billed_cap" , &crate::flat::BILLED_CAP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("biohazard" , &crate::flat::BIOHAZARD as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("bird" , &crate::flat::BIRD as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("birthday" , &crate::flat::BIRTHDAY_CAKE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("birthday_cake" , &crate::flat::BIRTHDAY_CAKE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("bison" , &crate::flat::BISON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("biting_lip" , &crate::flat::BITING_LIP as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("black_cat" , &crate::flat::BLACK_CAT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("black_circle" , &crate::flat::BLACK_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("black_flag" , &crate::flat::BLACK_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("black_heart" , &crate::flat::BLACK_HEART as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("black_joker" , &crate::flat::JOKER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("black_large_square" , &crate::flat::BLACK_LARGE_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("black_medium_small_square" , &crate::flat::BLACK_MEDIUM_SMALL_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("black_medium_square" , &crate::flat::BLACK_MEDIUM_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("black_nib" , &crate::flat::BLACK_NIB as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("black_small_square" , &crate::flat::BLACK_SMALL_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("black_square_button" , &crate::flat::BLACK_SQUARE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("blond_haired_man" , &crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("blond_haired_person" , &crate::flat::PERSON.hair(Hair::Blond).default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("blond_haired_woman" , &crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("blonde_woman" , &crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("blossom" , &crate::flat::BLOSSOM as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("blowfish" , &crate::flat::BLOWFISH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("blue_book" , &crate::flat::BLUE_BOOK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("blue_car" , &crate::flat::SPORT_UTILITY_VEHICLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("blue_circle" , &crate::flat::BLUE_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("blue_heart" , &crate::flat::BLUE_HEART as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("blue_square" , &crate::flat::BLUE_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("blueberries" , &crate::flat::BLUEBERRIES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("blush" , &crate::flat::SMILING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("boar" , &crate::flat::BOAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("boat" , &crate::flat::SAILBOAT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bolivia" , &crate::flat::FLAG_BOLIVIA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("bomb" , &crate::flat::BOMB as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bone" , &crate::flat::BONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("book" , &crate::flat::OPEN_BOOK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bookmark" , &crate::flat::BOOKMARK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bookmark_tabs" , &crate::flat::BOOKMARK_TABS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("books" , &crate::flat::BOOKS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("boom" , &crate::flat::COLLISION as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("boomerang" , &crate::flat::BOOMERANG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("boot" , &crate::flat::WOMAN_S_BOOT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bosnia_herzegovina" , &crate::flat::FLAG_BOSNIA_AND_HERZEGOVINA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("botswana" , &crate::flat::FLAG_BOTSWANA as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bottle_with_popping_cork" , &crate::flat::BOTTLE_WITH_POPPING_CORK as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bouncing_ball_man" , &crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bouncing_ball_person" , &crate::flat::PERSON_BOUNCING_BALL.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bouncing_ball_woman" , &crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("bouquet" , &crate::flat::BOUQUET as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bouvet_island" , &crate::flat::FLAG_BOUVET_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bow" , &crate::flat::PERSON_BOWING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bow_and_arrow" , &crate::flat::BOW_AND_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bowing_man" , &crate::flat::PERSON_BOWING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bowing_woman" , &crate::flat::PERSON_BOWING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bowl_with_spoon" , &crate::flat::BOWL_WITH_SPOON as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("bowling" , &crate::flat::BOWLING as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("boxing_glove" , &crate::flat::BOXING_GLOVE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("boy" , &crate::flat::BOY.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("brain" , &crate::flat::BRAIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("brazil" , &crate::flat::FLAG_BRAZIL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bread" , &crate::flat::BREAD as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("breast_feeding" , &crate::flat::BREAST_FEEDING.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("brick" , &crate::flat::BRICK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bricks" , &crate::flat::BRICK as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bride_with_veil" , &crate::flat::PERSON_WITH_VEIL.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bridge_at_night" , &crate::flat::BRIDGE_AT_NIGHT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("briefcase" , &crate::flat::BRIEFCASE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("briefs" , &crate::flat::BRIEFS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("bright_button" , &crate::flat::BRIGHT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("british_indian_ocean_territory" , &crate::flat::FLAG_BRITISH_INDIAN_OCEAN_TERRITORY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("british_virgin_islands" , &crate::flat::FLAG_BRITISH_VIRGIN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("broccoli" , &crate::flat::BROCCOLI as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("broken_heart" , &crate::flat::BROKEN_HEART as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("broom" , &crate::flat::BROOM as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("brown_circle" , &crate::flat::BROWN_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("brown_heart" , &crate::flat::BROWN_HEART as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("brown_square" , &crate::flat::BROWN_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("brunei" , &crate::flat::FLAG_BRUNEI as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bubble_tea" , &crate::flat::BUBBLE_TEA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bubbles" , &crate::flat::BUBBLES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bucket" , &crate::flat::BUCKET as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("bug" , &crate::flat::BUG as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("building_construction" , &crate::flat::BUILDING_CONSTRUCTION as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bulb" , &crate::flat::LIGHT_BULB as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bulgaria" , &crate::flat::FLAG_BULGARIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bullet_train" , &crate::flat::BULLET_TRAIN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bullettrain_front" , &crate::flat::BULLET_TRAIN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bullettrain_side" , &crate::flat::HIGH_SPEED_TRAIN as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("bullseye" , &crate::flat::BULLSEYE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("burkina_faso" , &crate::flat::FLAG_BURKINA_FASO as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("burrito" , &crate::flat::BURRITO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("burundi" , &crate::flat::FLAG_BURUNDI as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bus" , &crate::flat::BUS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bus_stop" , &crate::flat::BUS_STOP as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("business_suit_levitating" , &crate::flat::PERSON_IN_SUIT_LEVITATING.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("busstop" , &crate::flat::BUS_STOP as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bust_in_silhouette" , &crate::flat::BUST_IN_SILHOUETTE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("busts_in_silhouette" , &crate::flat::BUSTS_IN_SILHOUETTE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("butter" , &crate::flat::BUTTER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("butterfly" , &crate::flat::BUTTERFLY as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cactus" , &crate::flat::CACTUS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cake" , &crate::flat::SHORTCAKE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("calendar" , &crate::flat::CALENDAR as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("call_me_hand" , &crate::flat::CALL_ME_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("calling" , &crate::flat::MOBILE_PHONE_WITH_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cambodia" , &crate::flat::FLAG_CAMBODIA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("camel" , &crate::flat::CAMEL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("camera" , &crate::flat::CAMERA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("camera_flash" , &crate::flat::CAMERA_WITH_FLASH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("camera_with_flash" , &crate::flat::CAMERA_WITH_FLASH as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cameroon" , &crate::flat::FLAG_CAMEROON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("camping" , &crate::flat::CAMPING as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("canada" , &crate::flat::FLAG_CANADA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("canary_islands" , &crate::flat::FLAG_CANARY_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cancer" , &crate::flat::CANCER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("candle" , &crate::flat::CANDLE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("candy" , &crate::flat::CANDY as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("canned_food" , &crate::flat::CANNED_FOOD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("canoe" , &crate::flat::CANOE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cape_verde" , &crate::flat::FLAG_CAPE_VERDE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("capital_abcd" , &crate::flat::INPUT_LATIN_UPPERCASE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("capricorn" , &crate::flat::CAPRICORN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("car" , &crate::flat::AUTOMOBILE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("card_file_box" , &crate::flat::CARD_FILE_BOX as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("card_index" , &crate::flat::CARD_INDEX as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("card_index_dividers" , &crate::flat::CARD_INDEX_DIVIDERS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("caribbean_netherlands" , &crate::flat::FLAG_CARIBBEAN_NETHERLANDS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("carousel_horse" , &crate::flat::CAROUSEL_HORSE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("carp_streamer" , &crate::flat::CARP_STREAMER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("carpentry_saw" , &crate::flat::CARPENTRY_SAW as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("carrot" , &crate::flat::CARROT as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("cartwheeling" , &crate::flat::PERSON_CARTWHEELING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("castle" , &crate::flat::CASTLE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cat" , &crate::flat::CAT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cat2" , &crate::flat::CAT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cat_face" , &crate::flat::CAT_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cat_with_tears_of_joy" , &crate::flat::CAT_WITH_TEARS_OF_JOY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cat_with_wry_smile" , &crate::flat::CAT_WITH_WRY_SMILE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cayman_islands" , &crate::flat::FLAG_CAYMAN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("cd" , &crate::flat::OPTICAL_DISK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("central_african_republic" , &crate::flat::FLAG_CENTRAL_AFRICAN_REPUBLIC as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ceuta_melilla" , &crate::flat::FLAG_CEUTA_AND_MELILLA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("chad" , &crate::flat::FLAG_CHAD as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chains" , &crate::flat::CHAINS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chair" , &crate::flat::CHAIR as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("champagne" , &crate::flat::BOTTLE_WITH_POPPING_CORK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chart" , &crate::flat::CHART_INCREASING_WITH_YEN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chart_decreasing" , &crate::flat::CHART_DECREASING as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chart_increasing" , &crate::flat::CHART_INCREASING as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chart_increasing_with_yen" , &crate::flat::CHART_INCREASING_WITH_YEN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chart_with_downwards_trend" , &crate::flat::CHART_DECREASING as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chart_with_upwards_trend" , &crate::flat::CHART_INCREASING as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("check_box_with_check" , &crate::flat::CHECK_BOX_WITH_CHECK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("check_mark" , &crate::flat::CHECK_MARK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("check_mark_button" , &crate::flat::CHECK_MARK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("checkered_flag" , &crate::flat::CHEQUERED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cheese" , &crate::flat::CHEESE_WEDGE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cheese_wedge" , &crate::flat::CHEESE_WEDGE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("chequered_flag" , &crate::flat::CHEQUERED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cherries" , &crate::flat::CHERRIES as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cherry_blossom" , &crate::flat::CHERRY_BLOSSOM as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("chess_pawn" , &crate::flat::CHESS_PAWN as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("chestnut" , &crate::flat::CHESTNUT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("chicken" , &crate::flat::CHICKEN as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("child" , &crate::flat::CHILD.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("children_crossing" , &crate::flat::CHILDREN_CROSSING as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("chile" , &crate::flat::FLAG_CHILE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("chipmunk" , &crate::flat::CHIPMUNK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("chocolate_bar" , &crate::flat::CHOCOLATE_BAR as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("chopsticks" , &crate::flat::CHOPSTICKS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("christmas_island" , &crate::flat::FLAG_CHRISTMAS_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("christmas_tree" , &crate::flat::CHRISTMAS_TREE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("church" , &crate::flat::CHURCH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("cigarette" , &crate::flat::CIGARETTE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cinema" , &crate::flat::CINEMA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("circled_m" , &crate::flat::CIRCLED_M as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("circus_tent" , &crate::flat::CIRCUS_TENT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("city_sunrise" , &crate::flat::SUNSET as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("city_sunset" , &crate::flat::CITYSCAPE_AT_DUSK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cityscape" , &crate::flat::CITYSCAPE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cityscape_at_dusk" , &crate::flat::CITYSCAPE_AT_DUSK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cl" , &crate::flat::CL_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cl_button" , &crate::flat::CL_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("clamp" , &crate::flat::CLAMP as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("clap" , &crate::flat::CLAPPING_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("clapper" , &crate::flat::CLAPPER_BOARD as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("clapper_board" , &crate::flat::CLAPPER_BOARD as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("clapping_hands" , &crate::flat::CLAPPING_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("classical_building" , &crate::flat::CLASSICAL_BUILDING as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("climbing" , &crate::flat::PERSON_CLIMBING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("climbing_man" , &crate::flat::PERSON_CLIMBING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("climbing_woman" , &crate::flat::PERSON_CLIMBING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("clinking_beer_mugs" , &crate::flat::CLINKING_BEER_MUGS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("clinking_glasses" , &crate::flat::CLINKING_GLASSES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("clipboard" , &crate::flat::CLIPBOARD as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("clipperton_island" , &crate::flat::FLAG_CLIPPERTON_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock1" , &crate::flat::ONE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock10" , &crate::flat::TEN_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock1030" , &crate::flat::TEN_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock11" , &crate::flat::ELEVEN_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock1130" , &crate::flat::ELEVEN_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock12" , &crate::flat::TWELVE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock1230" , &crate::flat::TWELVE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock130" , &crate::flat::ONE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock2" , &crate::flat::TWO_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock230" , &crate::flat::TWO_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock3" , &crate::flat::THREE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock330" , &crate::flat::THREE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock4" , &crate::flat::FOUR_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock430" , &crate::flat::FOUR_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock5" , &crate::flat::FIVE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock530" , &crate::flat::FIVE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock6" , &crate::flat::SIX_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock630" , &crate::flat::SIX_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock7" , &crate::flat::SEVEN_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock730" , &crate::flat::SEVEN_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock8" , &crate::flat::EIGHT_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock830" , &crate::flat::EIGHT_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock9" , &crate::flat::NINE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock930" , &crate::flat::NINE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("clockwise_vertical_arrows" , &crate::flat::CLOCKWISE_VERTICAL_ARROWS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("closed_book" , &crate::flat::CLOSED_BOOK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("closed_lock_with_key" , &crate::flat::LOCKED_WITH_KEY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("closed_mailbox_with_lowered_flag" , &crate::flat::CLOSED_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("closed_mailbox_with_raised_flag" , &crate::flat::CLOSED_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("closed_umbrella" , &crate::flat::CLOSED_UMBRELLA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cloud" , &crate::flat::CLOUD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cloud_with_lightning" , &crate::flat::CLOUD_WITH_LIGHTNING as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cloud_with_lightning_and_rain" , &crate::flat::CLOUD_WITH_LIGHTNING_AND_RAIN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cloud_with_rain" , &crate::flat::CLOUD_WITH_RAIN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cloud_with_snow" , &crate::flat::CLOUD_WITH_SNOW as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("clown_face" , &crate::flat::CLOWN_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("club_suit" , &crate::flat::CLUB_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("clubs" , &crate::flat::CLUB_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("clutch_bag" , &crate::flat::CLUTCH_BAG as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cn" , &crate::flat::FLAG_CHINA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("coat" , &crate::flat::COAT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cockroach" , &crate::flat::COCKROACH as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cocktail" , &crate::flat::COCKTAIL_GLASS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cocktail_glass" , &crate::flat::COCKTAIL_GLASS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("coconut" , &crate::flat::COCONUT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cocos_islands" , &crate::flat::FLAG_COCOS_KEELING_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("coffee" , &crate::flat::HOT_BEVERAGE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("coffin" , &crate::flat::COFFIN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("coin" , &crate::flat::COIN as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cold_face" , &crate::flat::COLD_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cold_sweat" , &crate::flat::ANXIOUS_FACE_WITH_SWEAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("collision" , &crate::flat::COLLISION as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("colombia" , &crate::flat::FLAG_COLOMBIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("comet" , &crate::flat::COMET as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("comoros" , &crate::flat::FLAG_COMOROS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("compass" , &crate::flat::COMPASS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("computer" , &crate::flat::LAPTOP as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("computer_disk" , &crate::flat::COMPUTER_DISK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("computer_mouse" , &crate::flat::COMPUTER_MOUSE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("confetti_ball" , &crate::flat::CONFETTI_BALL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("confounded" , &crate::flat::CONFOUNDED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("confounded_face" , &crate::flat::CONFOUNDED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("confused" , &crate::flat::CONFUSED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("confused_face" , &crate::flat::CONFUSED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("congo_brazzaville" , &crate::flat::FLAG_CONGO_BRAZZAVILLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("congo_kinshasa" , &crate::flat::FLAG_CONGO_KINSHASA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("congratulations" , &crate::flat::JAPANESE_CONGRATULATIONS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("construction" , &crate::flat::CONSTRUCTION as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("construction_worker" , &crate::flat::CONSTRUCTION_WORKER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("construction_worker_man" , &crate::flat::CONSTRUCTION_WORKER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("construction_worker_woman" , &crate::flat::CONSTRUCTION_WORKER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("control_knobs" , &crate::flat::CONTROL_KNOBS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("convenience_store" , &crate::flat::CONVENIENCE_STORE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("cook" , &crate::flat::COOK.default.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cook_islands" , &crate::flat::FLAG_COOK_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cooked_rice" , &crate::flat::COOKED_RICE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cookie" , &crate::flat::COOKIE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cooking" , &crate::flat::COOKING as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cool" , &crate::flat::COOL_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cool_button" , &crate::flat::COOL_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("cop" , &crate::flat::POLICE_OFFICER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("copyright" , &crate::flat::COPYRIGHT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("coral" , &crate::flat::CORAL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("corn" , &crate::flat::EAR_OF_CORN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("costa_rica" , &crate::flat::FLAG_COSTA_RICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cote_divoire" , &crate::flat::FLAG_COTE_D_IVOIRE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("couch_and_lamp" , &crate::flat::COUCH_AND_LAMP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("counterclockwise_arrows_button" , &crate::flat::COUNTERCLOCKWISE_ARROWS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couple" , &crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Mixed).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couple_with_heart" , &crate::flat::COUPLE_WITH_HEART.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couple_with_heart_man_man" , &crate::flat::COUPLE_WITH_HEART.pair(Pair::Males).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couple_with_heart_woman_man" , &crate::flat::COUPLE_WITH_HEART.pair(Pair::Mixed).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couple_with_heart_woman_woman" , &crate::flat::COUPLE_WITH_HEART.pair(Pair::Females).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couplekiss" , &crate::flat::KISS.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couplekiss_man_man" , &crate::flat::KISS.pair(Pair::Males).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couplekiss_man_woman" , &crate::flat::KISS.pair(Pair::Mixed).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couplekiss_woman_woman" , &crate::flat::KISS.pair(Pair::Females).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cow" , &crate::flat::COW as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cow2" , &crate::flat::COW as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cow_face" , &crate::flat::COW_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cowboy_hat_face" , &crate::flat::COWBOY_HAT_FACE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("crab" , &crate::flat::CRAB as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("crayon" , &crate::flat::CRAYON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("credit_card" , &crate::flat::CREDIT_CARD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("crescent_moon" , &crate::flat::CRESCENT_MOON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cricket" , &crate::flat::CRICKET as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("cricket_game" , &crate::flat::CRICKET_GAME as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("croatia" , &crate::flat::FLAG_CROATIA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("crocodile" , &crate::flat::CROCODILE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("croissant" , &crate::flat::CROISSANT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cross_mark" , &crate::flat::CROSS_MARK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cross_mark_button" , &crate::flat::CROSS_MARK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("crossed_fingers" , &crate::flat::CROSSED_FINGERS.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("crossed_flags" , &crate::flat::CROSSED_FLAGS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("crossed_swords" , &crate::flat::CROSSED_SWORDS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("crown" , &crate::flat::CROWN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("crutch" , &crate::flat::CRUTCH as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cry" , &crate::flat::CRYING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("crying_cat" , &crate::flat::CRYING_CAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("crying_cat_face" , &crate::flat::CRYING_CAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("crying_face" , &crate::flat::CRYING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("crystal_ball" , &crate::flat::CRYSTAL_BALL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cuba" , &crate::flat::FLAG_CUBA as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cucumber" , &crate::flat::CUCUMBER as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cup_with_straw" , &crate::flat::CUP_WITH_STRAW as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cupcake" , &crate::flat::CUPCAKE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cupid" , &crate::flat::HEART_WITH_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("curacao" , &crate::flat::FLAG_CURACAO as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("curling_stone" , &crate::flat::CURLING_STONE as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("curly_hair" , &crate::flat::CURLY_HAIR as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("curly_haired_man" , &crate::flat::PERSON.hair(Hair::Curly).gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("curly_haired_woman" , &crate::flat::PERSON.hair(Hair::Curly).gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("curly_loop" , &crate::flat::CURLY_LOOP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("currency_exchange" , &crate::flat::CURRENCY_EXCHANGE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("curry" , &crate::flat::CURRY_RICE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("curry_rice" , &crate::flat::CURRY_RICE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cursing_face" , &crate::flat::FACE_WITH_SYMBOLS_ON_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("custard" , &crate::flat::CUSTARD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("customs" , &crate::flat::CUSTOMS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cut_of_meat" , &crate::flat::CUT_OF_MEAT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cyclone" , &crate::flat::CYCLONE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cyprus" , &crate::flat::FLAG_CYPRUS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("czech_republic" , &crate::flat::FLAG_CZECHIA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("dagger" , &crate::flat::DAGGER as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("dancer" , &crate::flat::PERSON_DANCING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("dancers" , &crate::flat::PERSON_WITH_BUNNY_EARS.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("dancing_men" , &crate::flat::PERSON_WITH_BUNNY_EARS.gender(Gender::Male) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("dancing_women" , &crate::flat::PERSON_WITH_BUNNY_EARS.gender(Gender::Female) as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("dango" , &crate::flat::DANGO as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("dark_skin_tone" , &crate::flat::DARK_SKIN_TONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("dark_sunglasses" , &crate::flat::SUNGLASSES as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("dart" , &crate::flat::BULLSEYE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("dash" , &crate::flat::DASHING_AWAY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("dashing_away" , &crate::flat::DASHING_AWAY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("date" , &crate::flat::CALENDAR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("de" , &crate::flat::FLAG_GERMANY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("deaf_man" , &crate::flat::DEAF_PERSON.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("deaf_person" , &crate::flat::DEAF_PERSON.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("deaf_woman" , &crate::flat::DEAF_PERSON.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("deciduous_tree" , &crate::flat::DECIDUOUS_TREE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("deer" , &crate::flat::DEER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("delivery_truck" , &crate::flat::DELIVERY_TRUCK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("denmark" , &crate::flat::FLAG_DENMARK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("department_store" , &crate::flat::DEPARTMENT_STORE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("derelict_house" , &crate::flat::DERELICT_HOUSE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("desert" , &crate::flat::DESERT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("desert_island" , &crate::flat::DESERT_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("desktop_computer" , &crate::flat::DESKTOP_COMPUTER as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("detective" , &crate::flat::DETECTIVE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("diamond_shape_with_a_dot_inside" , &crate::flat::DIAMOND_WITH_A_DOT as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("diamond_suit" , &crate::flat::DIAMOND_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("diamond_with_a_dot" , &crate::flat::DIAMOND_WITH_A_DOT as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("diamonds" , &crate::flat::DIAMOND_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("diego_garcia" , &crate::flat::FLAG_DIEGO_GARCIA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("dim_button" , &crate::flat::DIM_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("disappointed" , &crate::flat::DISAPPOINTED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("disappointed_face" , &crate::flat::DISAPPOINTED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("disappointed_relieved" , &crate::flat::SAD_BUT_RELIEVED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("disguised_face" , &crate::flat::DISGUISED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("divide" , &crate::flat::DIVIDE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("diving_mask" , &crate::flat::DIVING_MASK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("diya_lamp" , &crate::flat::DIYA_LAMP as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("dizzy" , &crate::flat::DIZZY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("dizzy_face" , &crate::flat::KNOCKED_OUT_FACE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("djibouti" , &crate::flat::FLAG_DJIBOUTI as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("dna" , &crate::flat::DNA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("do_not_litter" , &crate::flat::NO_LITTERING as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dodo" , &crate::flat::DODO as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dog" , &crate::flat::DOG as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dog2" , &crate::flat::DOG as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dog_face" , &crate::flat::DOG_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("dollar" , &crate::flat::DOLLAR_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("dollar_banknote" , &crate::flat::DOLLAR_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("dolls" , &crate::flat::JAPANESE_DOLLS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dolphin" , &crate::flat::DOLPHIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("dominica" , &crate::flat::FLAG_DOMINICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("dominican_republic" , &crate::flat::FLAG_DOMINICAN_REPUBLIC as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("door" , &crate::flat::DOOR as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("dotted_line_face" , &crate::flat::DOTTED_LINE_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("dotted_six_pointed_star" , &crate::flat::DOTTED_SIX_POINTED_STAR as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("double_curly_loop" , &crate::flat::DOUBLE_CURLY_LOOP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("double_exclamation_mark" , &crate::flat::DOUBLE_EXCLAMATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("doughnut" , &crate::flat::DOUGHNUT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dove" , &crate::flat::DOVE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("down_arrow" , &crate::flat::DOWN_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("down_left_arrow" , &crate::flat::DOWN_LEFT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("down_right_arrow" , &crate::flat::DOWN_RIGHT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("downcast_face_with_sweat" , &crate::flat::DOWNCAST_FACE_WITH_SWEAT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("downwards_button" , &crate::flat::DOWNWARDS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dragon" , &crate::flat::DRAGON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dragon_face" , &crate::flat::DRAGON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("dress" , &crate::flat::DRESS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dromedary_camel" , &crate::flat::CAMEL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("drooling_face" , &crate::flat::DROOLING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("drop_of_blood" , &crate::flat::DROP_OF_BLOOD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("droplet" , &crate::flat::DROPLET as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("drum" , &crate::flat::DRUM as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("duck" , &crate::flat::DUCK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("dumpling" , &crate::flat::DUMPLING as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("dvd" , &crate::flat::DVD as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("e-mail" , &crate::flat::E_MAIL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("e_mail" , &crate::flat::E_MAIL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("eagle" , &crate::flat::EAGLE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ear" , &crate::flat::EAR.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("ear_of_corn" , &crate::flat::EAR_OF_CORN as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("ear_of_rice" , &crate::flat::SHEAF_OF_RICE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ear_with_hearing_aid" , &crate::flat::EAR_WITH_HEARING_AID.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("earth_africa" , &crate::flat::GLOBE_SHOWING_EUROPE_AFRICA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("earth_americas" , &crate::flat::GLOBE_SHOWING_AMERICAS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("earth_asia" , &crate::flat::GLOBE_SHOWING_ASIA_AUSTRALIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ecuador" , &crate::flat::FLAG_ECUADOR as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("egg" , &crate::flat::EGG as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("eggplant" , &crate::flat::EGGPLANT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("egypt" , &crate::flat::FLAG_EGYPT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("eight" , &crate::flat::KEYCAP_8 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("eight_o_clock" , &crate::flat::EIGHT_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("eight_pointed_black_star" , &crate::flat::EIGHT_POINTED_STAR as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("eight_pointed_star" , &crate::flat::EIGHT_POINTED_STAR as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("eight_spoked_asterisk" , &crate::flat::EIGHT_SPOKED_ASTERISK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("eight_thirty" , &crate::flat::EIGHT_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("eject_button" , &crate::flat::EJECT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("el_salvador" , &crate::flat::FLAG_EL_SALVADOR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("electric_plug" , &crate::flat::ELECTRIC_PLUG as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("elephant" , &crate::flat::ELEPHANT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("elevator" , &crate::flat::ELEVATOR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("eleven_o_clock" , &crate::flat::ELEVEN_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("eleven_thirty" , &crate::flat::ELEVEN_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("elf" , &crate::flat::ELF.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("elf_man" , &crate::flat::ELF.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("elf_woman" , &crate::flat::ELF.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("email" , &crate::flat::E_MAIL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("empty_nest" , &crate::flat::EMPTY_NEST as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("end" , &crate::flat::END_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("end_arrow" , &crate::flat::END_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("england" , &crate::flat::FLAG_ENGLAND as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("envelope" , &crate::flat::ENVELOPE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("envelope_with_arrow" , &crate::flat::ENVELOPE_WITH_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("equatorial_guinea" , &crate::flat::FLAG_EQUATORIAL_GUINEA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("eritrea" , &crate::flat::FLAG_ERITREA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("es" , &crate::flat::FLAG_SPAIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("estonia" , &crate::flat::FLAG_ESTONIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ethiopia" , &crate::flat::FLAG_ETHIOPIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("eu" , &crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("euro" , &crate::flat::EURO_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("euro_banknote" , &crate::flat::EURO_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("european_castle" , &crate::flat::CASTLE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("european_post_office" , &crate::flat::POST_OFFICE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("european_union" , &crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("evergreen_tree" , &crate::flat::EVERGREEN_TREE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("ewe" , &crate::flat::EWE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("exclamation" , &crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("exclamation_question_mark" , &crate::flat::EXCLAMATION_QUESTION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("exploding_head" , &crate::flat::EXPLODING_HEAD as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("expressionless" , &crate::flat::EXPRESSIONLESS_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("expressionless_face" , &crate::flat::EXPRESSIONLESS_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("eye" , &crate::flat::EYE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("eye_in_speech_bubble" , &crate::flat::EYE_IN_SPEECH_BUBBLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("eye_speech_bubble" , &crate::flat::EYE_IN_SPEECH_BUBBLE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("eyeglasses" , &crate::flat::GLASSES as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("eyes" , &crate::flat::EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_blowing_a_kiss" , &crate::flat::FACE_BLOWING_A_KISS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_exhaling" , &crate::flat::FACE_EXHALING as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_holding_back_tears" , &crate::flat::FACE_HOLDING_BACK_TEARS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_in_clouds" , &crate::flat::FACE_IN_CLOUDS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_savoring_food" , &crate::flat::FACE_SAVORING_FOOD as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_screaming_in_fear" , &crate::flat::FACE_SCREAMING_IN_FEAR as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_vomiting" , &crate::flat::FACE_VOMITING as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_diagonal_mouth" , &crate::flat::FACE_WITH_DIAGONAL_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_hand_over_mouth" , &crate::flat::FACE_WITH_HAND_OVER_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_head_bandage" , &crate::flat::FACE_WITH_HEAD_BANDAGE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_medical_mask" , &crate::flat::FACE_WITH_MEDICAL_MASK as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_monocle" , &crate::flat::FACE_WITH_MONOCLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_open_eyes_and_hand_over_mouth" , &crate::flat::FACE_WITH_OPEN_EYES_AND_HAND_OVER_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_open_mouth" , &crate::flat::FACE_WITH_OPEN_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_peeking_eye" , &crate::flat::FACE_WITH_PEEKING_EYE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_raised_eyebrow" , &crate::flat::FACE_WITH_RAISED_EYEBROW as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_rolling_eyes" , &crate::flat::FACE_WITH_ROLLING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_spiral_eyes" , &crate::flat::FACE_WITH_SPIRAL_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_steam_from_nose" , &crate::flat::FACE_WITH_STEAM_FROM_NOSE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_symbols_on_mouth" , &crate::flat::FACE_WITH_SYMBOLS_ON_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_tears_of_joy" , &crate::flat::FACE_WITH_TEARS_OF_JOY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_thermometer" , &crate::flat::FACE_WITH_THERMOMETER as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_tongue" , &crate::flat::FACE_WITH_TONGUE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_without_mouth" , &crate::flat::FACE_WITHOUT_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("facepalm" , &crate::flat::PERSON_FACEPALMING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("facepunch" , &crate::flat::ONCOMING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("factory" , &crate::flat::FACTORY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("factory_worker" , &crate::flat::FACTORY_WORKER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fairy" , &crate::flat::FAIRY.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fairy_man" , &crate::flat::FAIRY.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fairy_woman" , &crate::flat::FAIRY.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("falafel" , &crate::flat::FALAFEL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("falkland_islands" , &crate::flat::FLAG_FALKLAND_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("fallen_leaf" , &crate::flat::FALLEN_LEAF as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family" , &crate::flat::FAMILY.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_man_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_man_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_man_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_man_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_man_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_woman_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_woman_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_woman_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_woman_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_woman_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_woman_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_woman_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_woman_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_woman_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_woman_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("farmer" , &crate::flat::FARMER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("faroe_islands" , &crate::flat::FLAG_FAROE_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("fast_down_button" , &crate::flat::FAST_DOWN_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("fast_forward" , &crate::flat::FAST_FORWARD_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("fast_forward_button" , &crate::flat::FAST_FORWARD_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("fast_reverse_button" , &crate::flat::FAST_REVERSE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("fast_up_button" , &crate::flat::FAST_UP_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("fax" , &crate::flat::FAX_MACHINE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("fax_machine" , &crate::flat::FAX_MACHINE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("fearful" , &crate::flat::FEARFUL_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("fearful_face" , &crate::flat::FEARFUL_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("feather" , &crate::flat::FEATHER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("feet" , &crate::flat::PAW_PRINTS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("female_detective" , &crate::flat::DETECTIVE.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("female_sign" , &crate::flat::FEMALE_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ferris_wheel" , &crate::flat::FERRIS_WHEEL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ferry" , &crate::flat::FERRY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("field_hockey" , &crate::flat::FIELD_HOCKEY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("fiji" , &crate::flat::FLAG_FIJI as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("file_cabinet" , &crate::flat::FILE_CABINET as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("file_folder" , &crate::flat::FILE_FOLDER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("film_frames" , &crate::flat::FILM_FRAMES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("film_projector" , &crate::flat::FILM_PROJECTOR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("film_strip" , &crate::flat::FILM_FRAMES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("finland" , &crate::flat::FLAG_FINLAND as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("fire" , &crate::flat::FIRE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("fire_engine" , &crate::flat::FIRE_ENGINE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("fire_extinguisher" , &crate::flat::FIRE_EXTINGUISHER as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("firecracker" , &crate::flat::FIRECRACKER as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("firefighter" , &crate::flat::FIREFIGHTER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("fireworks" , &crate::flat::FIREWORKS as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("first_place_medal" , &crate::flat::FIRST_PLACE_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("first_quarter_moon" , &crate::flat::FIRST_QUARTER_MOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("first_quarter_moon_face" , &crate::flat::FIRST_QUARTER_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("first_quarter_moon_with_face" , &crate::flat::FIRST_QUARTER_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("fish" , &crate::flat::FISH as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fish_cake" , &crate::flat::FISH_CAKE_WITH_SWIRL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fish_cake_with_swirl" , &crate::flat::FISH_CAKE_WITH_SWIRL as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("fishing_pole" , &crate::flat::FISHING_POLE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("fishing_pole_and_fish" , &crate::flat::FISHING_POLE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fist" , &crate::flat::RAISED_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fist_left" , &crate::flat::LEFT_FACING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fist_oncoming" , &crate::flat::ONCOMING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fist_raised" , &crate::flat::RAISED_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fist_right" , &crate::flat::RIGHT_FACING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("five" , &crate::flat::KEYCAP_5 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("five_o_clock" , &crate::flat::FIVE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("five_thirty" , &crate::flat::FIVE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_afghanistan" , &crate::flat::FLAG_AFGHANISTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_aland_islands" , &crate::flat::FLAG_ALAND_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_albania" , &crate::flat::FLAG_ALBANIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_algeria" , &crate::flat::FLAG_ALGERIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_american_samoa" , &crate::flat::FLAG_AMERICAN_SAMOA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_andorra" , &crate::flat::FLAG_ANDORRA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_angola" , &crate::flat::FLAG_ANGOLA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_anguilla" , &crate::flat::FLAG_ANGUILLA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_antarctica" , &crate::flat::FLAG_ANTARCTICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_antigua_and_barbuda" , &crate::flat::FLAG_ANTIGUA_AND_BARBUDA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_argentina" , &crate::flat::FLAG_ARGENTINA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_armenia" , &crate::flat::FLAG_ARMENIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_aruba" , &crate::flat::FLAG_ARUBA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_ascension_island" , &crate::flat::FLAG_ASCENSION_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_australia" , &crate::flat::FLAG_AUSTRALIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_austria" , &crate::flat::FLAG_AUSTRIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_azerbaijan" , &crate::flat::FLAG_AZERBAIJAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bahamas" , &crate::flat::FLAG_BAHAMAS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bahrain" , &crate::flat::FLAG_BAHRAIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bangladesh" , &crate::flat::FLAG_BANGLADESH as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_barbados" , &crate::flat::FLAG_BARBADOS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_belarus" , &crate::flat::FLAG_BELARUS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_belgium" , &crate::flat::FLAG_BELGIUM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_belize" , &crate::flat::FLAG_BELIZE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_benin" , &crate::flat::FLAG_BENIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bermuda" , &crate::flat::FLAG_BERMUDA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bhutan" , &crate::flat::FLAG_BHUTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bolivia" , &crate::flat::FLAG_BOLIVIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bosnia_and_herzegovina" , &crate::flat::FLAG_BOSNIA_AND_HERZEGOVINA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_botswana" , &crate::flat::FLAG_BOTSWANA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bouvet_island" , &crate::flat::FLAG_BOUVET_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_brazil" , &crate::flat::FLAG_BRAZIL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_british_indian_ocean_territory" , &crate::flat::FLAG_BRITISH_INDIAN_OCEAN_TERRITORY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_british_virgin_islands" , &crate::flat::FLAG_BRITISH_VIRGIN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_brunei" , &crate::flat::FLAG_BRUNEI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bulgaria" , &crate::flat::FLAG_BULGARIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_burkina_faso" , &crate::flat::FLAG_BURKINA_FASO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_burundi" , &crate::flat::FLAG_BURUNDI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cambodia" , &crate::flat::FLAG_CAMBODIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cameroon" , &crate::flat::FLAG_CAMEROON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_canada" , &crate::flat::FLAG_CANADA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_canary_islands" , &crate::flat::FLAG_CANARY_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cape_verde" , &crate::flat::FLAG_CAPE_VERDE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_caribbean_netherlands" , &crate::flat::FLAG_CARIBBEAN_NETHERLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cayman_islands" , &crate::flat::FLAG_CAYMAN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_central_african_republic" , &crate::flat::FLAG_CENTRAL_AFRICAN_REPUBLIC as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_ceuta_and_melilla" , &crate::flat::FLAG_CEUTA_AND_MELILLA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_chad" , &crate::flat::FLAG_CHAD as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_chile" , &crate::flat::FLAG_CHILE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_china" , &crate::flat::FLAG_CHINA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_christmas_island" , &crate::flat::FLAG_CHRISTMAS_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_clipperton_island" , &crate::flat::FLAG_CLIPPERTON_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cocos_keeling_islands" , &crate::flat::FLAG_COCOS_KEELING_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_colombia" , &crate::flat::FLAG_COLOMBIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_comoros" , &crate::flat::FLAG_COMOROS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_congo_brazzaville" , &crate::flat::FLAG_CONGO_BRAZZAVILLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_congo_kinshasa" , &crate::flat::FLAG_CONGO_KINSHASA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cook_islands" , &crate::flat::FLAG_COOK_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_costa_rica" , &crate::flat::FLAG_COSTA_RICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cote_d_ivoire" , &crate::flat::FLAG_COTE_D_IVOIRE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_croatia" , &crate::flat::FLAG_CROATIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cuba" , &crate::flat::FLAG_CUBA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_curacao" , &crate::flat::FLAG_CURACAO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cyprus" , &crate::flat::FLAG_CYPRUS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_czechia" , &crate::flat::FLAG_CZECHIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_denmark" , &crate::flat::FLAG_DENMARK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_diego_garcia" , &crate::flat::FLAG_DIEGO_GARCIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_djibouti" , &crate::flat::FLAG_DJIBOUTI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_dominica" , &crate::flat::FLAG_DOMINICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_dominican_republic" , &crate::flat::FLAG_DOMINICAN_REPUBLIC as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_ecuador" , &crate::flat::FLAG_ECUADOR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_egypt" , &crate::flat::FLAG_EGYPT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_el_salvador" , &crate::flat::FLAG_EL_SALVADOR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_england" , &crate::flat::FLAG_ENGLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_equatorial_guinea" , &crate::flat::FLAG_EQUATORIAL_GUINEA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_eritrea" , &crate::flat::FLAG_ERITREA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_estonia" , &crate::flat::FLAG_ESTONIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_eswatini" , &crate::flat::FLAG_ESWATINI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_ethiopia" , &crate::flat::FLAG_ETHIOPIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_european_union" , &crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_falkland_islands" , &crate::flat::FLAG_FALKLAND_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_faroe_islands" , &crate::flat::FLAG_FAROE_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_fiji" , &crate::flat::FLAG_FIJI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_finland" , &crate::flat::FLAG_FINLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_france" , &crate::flat::FLAG_FRANCE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_french_guiana" , &crate::flat::FLAG_FRENCH_GUIANA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_french_polynesia" , &crate::flat::FLAG_FRENCH_POLYNESIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_french_southern_territories" , &crate::flat::FLAG_FRENCH_SOUTHERN_TERRITORIES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_gabon" , &crate::flat::FLAG_GABON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_gambia" , &crate::flat::FLAG_GAMBIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_georgia" , &crate::flat::FLAG_GEORGIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_germany" , &crate::flat::FLAG_GERMANY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_ghana" , &crate::flat::FLAG_GHANA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_gibraltar" , &crate::flat::FLAG_GIBRALTAR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_greece" , &crate::flat::FLAG_GREECE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_greenland" , &crate::flat::FLAG_GREENLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_grenada" , &crate::flat::FLAG_GRENADA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_guadeloupe" , &crate::flat::FLAG_GUADELOUPE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_guam" , &crate::flat::FLAG_GUAM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_guatemala" , &crate::flat::FLAG_GUATEMALA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_guernsey" , &crate::flat::FLAG_GUERNSEY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_guinea" , &crate::flat::FLAG_GUINEA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_guinea_bissau" , &crate::flat::FLAG_GUINEA_BISSAU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_guyana" , &crate::flat::FLAG_GUYANA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_haiti" , &crate::flat::FLAG_HAITI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_heard_and_mcdonald_islands" , &crate::flat::FLAG_HEARD_AND_MCDONALD_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_honduras" , &crate::flat::FLAG_HONDURAS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_hong_kong_sar_china" , &crate::flat::FLAG_HONG_KONG_SAR_CHINA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_hungary" , &crate::flat::FLAG_HUNGARY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_iceland" , &crate::flat::FLAG_ICELAND as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("flag_in_hole" , &crate::flat::FLAG_IN_HOLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_india" , &crate::flat::FLAG_INDIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_indonesia" , &crate::flat::FLAG_INDONESIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_iran" , &crate::flat::FLAG_IRAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_iraq" , &crate::flat::FLAG_IRAQ as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_ireland" , &crate::flat::FLAG_IRELAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_isle_of_man" , &crate::flat::FLAG_ISLE_OF_MAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_israel" , &crate::flat::FLAG_ISRAEL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_italy" , &crate::flat::FLAG_ITALY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_jamaica" , &crate::flat::FLAG_JAMAICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_japan" , &crate::flat::FLAG_JAPAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_jersey" , &crate::flat::FLAG_JERSEY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_jordan" , &crate::flat::FLAG_JORDAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_kazakhstan" , &crate::flat::FLAG_KAZAKHSTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_kenya" , &crate::flat::FLAG_KENYA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_kiribati" , &crate::flat::FLAG_KIRIBATI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_kosovo" , &crate::flat::FLAG_KOSOVO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_kuwait" , &crate::flat::FLAG_KUWAIT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_kyrgyzstan" , &crate::flat::FLAG_KYRGYZSTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_laos" , &crate::flat::FLAG_LAOS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_latvia" , &crate::flat::FLAG_LATVIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_lebanon" , &crate::flat::FLAG_LEBANON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_lesotho" , &crate::flat::FLAG_LESOTHO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_liberia" , &crate::flat::FLAG_LIBERIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_libya" , &crate::flat::FLAG_LIBYA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_liechtenstein" , &crate::flat::FLAG_LIECHTENSTEIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_lithuania" , &crate::flat::FLAG_LITHUANIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_luxembourg" , &crate::flat::FLAG_LUXEMBOURG as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_macao_sar_china" , &crate::flat::FLAG_MACAO_SAR_CHINA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_madagascar" , &crate::flat::FLAG_MADAGASCAR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_malawi" , &crate::flat::FLAG_MALAWI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_malaysia" , &crate::flat::FLAG_MALAYSIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_maldives" , &crate::flat::FLAG_MALDIVES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_mali" , &crate::flat::FLAG_MALI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_malta" , &crate::flat::FLAG_MALTA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_marshall_islands" , &crate::flat::FLAG_MARSHALL_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_martinique" , &crate::flat::FLAG_MARTINIQUE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_mauritania" , &crate::flat::FLAG_MAURITANIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_mauritius" , &crate::flat::FLAG_MAURITIUS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_mayotte" , &crate::flat::FLAG_MAYOTTE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_mexico" , &crate::flat::FLAG_MEXICO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_micronesia" , &crate::flat::FLAG_MICRONESIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_moldova" , &crate::flat::FLAG_MOLDOVA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_monaco" , &crate::flat::FLAG_MONACO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_mongolia" , &crate::flat::FLAG_MONGOLIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_montenegro" , &crate::flat::FLAG_MONTENEGRO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_montserrat" , &crate::flat::FLAG_MONTSERRAT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_morocco" , &crate::flat::FLAG_MOROCCO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_mozambique" , &crate::flat::FLAG_MOZAMBIQUE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_myanmar_burma" , &crate::flat::FLAG_MYANMAR_BURMA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_namibia" , &crate::flat::FLAG_NAMIBIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_nauru" , &crate::flat::FLAG_NAURU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_nepal" , &crate::flat::FLAG_NEPAL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_netherlands" , &crate::flat::FLAG_NETHERLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_new_caledonia" , &crate::flat::FLAG_NEW_CALEDONIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_new_zealand" , &crate::flat::FLAG_NEW_ZEALAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_nicaragua" , &crate::flat::FLAG_NICARAGUA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_niger" , &crate::flat::FLAG_NIGER as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_nigeria" , &crate::flat::FLAG_NIGERIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_niue" , &crate::flat::FLAG_NIUE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_norfolk_island" , &crate::flat::FLAG_NORFOLK_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_north_korea" , &crate::flat::FLAG_NORTH_KOREA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_north_macedonia" , &crate::flat::FLAG_NORTH_MACEDONIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_northern_mariana_islands" , &crate::flat::FLAG_NORTHERN_MARIANA_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_norway" , &crate::flat::FLAG_NORWAY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_oman" , &crate::flat::FLAG_OMAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_pakistan" , &crate::flat::FLAG_PAKISTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_palau" , &crate::flat::FLAG_PALAU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_palestinian_territories" , &crate::flat::FLAG_PALESTINIAN_TERRITORIES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_panama" , &crate::flat::FLAG_PANAMA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_papua_new_guinea" , &crate::flat::FLAG_PAPUA_NEW_GUINEA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_paraguay" , &crate::flat::FLAG_PARAGUAY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_peru" , &crate::flat::FLAG_PERU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_philippines" , &crate::flat::FLAG_PHILIPPINES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_pitcairn_islands" , &crate::flat::FLAG_PITCAIRN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_poland" , &crate::flat::FLAG_POLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_portugal" , &crate::flat::FLAG_PORTUGAL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_puerto_rico" , &crate::flat::FLAG_PUERTO_RICO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_qatar" , &crate::flat::FLAG_QATAR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_reunion" , &crate::flat::FLAG_REUNION as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_romania" , &crate::flat::FLAG_ROMANIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_russia" , &crate::flat::FLAG_RUSSIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_rwanda" , &crate::flat::FLAG_RWANDA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_samoa" , &crate::flat::FLAG_SAMOA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_san_marino" , &crate::flat::FLAG_SAN_MARINO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_sao_tome_and_principe" , &crate::flat::FLAG_SAO_TOME_AND_PRINCIPE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_saudi_arabia" , &crate::flat::FLAG_SAUDI_ARABIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_scotland" , &crate::flat::FLAG_SCOTLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_senegal" , &crate::flat::FLAG_SENEGAL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_serbia" , &crate::flat::FLAG_SERBIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_seychelles" , &crate::flat::FLAG_SEYCHELLES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_sierra_leone" , &crate::flat::FLAG_SIERRA_LEONE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_singapore" , &crate::flat::FLAG_SINGAPORE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_sint_maarten" , &crate::flat::FLAG_SINT_MAARTEN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_slovakia" , &crate::flat::FLAG_SLOVAKIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_slovenia" , &crate::flat::FLAG_SLOVENIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_solomon_islands" , &crate::flat::FLAG_SOLOMON_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_somalia" , &crate::flat::FLAG_SOMALIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_south_africa" , &crate::flat::FLAG_SOUTH_AFRICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_south_georgia_and_south_sandwich_islands" , &crate::flat::FLAG_SOUTH_GEORGIA_AND_SOUTH_SANDWICH_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_south_korea" , &crate::flat::FLAG_SOUTH_KOREA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_south_sudan" , &crate::flat::FLAG_SOUTH_SUDAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_spain" , &crate::flat::FLAG_SPAIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_sri_lanka" , &crate::flat::FLAG_SRI_LANKA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_st_barthelemy" , &crate::flat::FLAG_ST_BARTHELEMY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_st_helena" , &crate::flat::FLAG_ST_HELENA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_st_kitts_and_nevis" , &crate::flat::FLAG_ST_KITTS_AND_NEVIS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_st_lucia" , &crate::flat::FLAG_ST_LUCIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_st_martin" , &crate::flat::FLAG_ST_MARTIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_st_pierre_and_miquelon" , &crate::flat::FLAG_ST_PIERRE_AND_MIQUELON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_st_vincent_and_grenadines" , &crate::flat::FLAG_ST_VINCENT_AND_GRENADINES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_sudan" , &crate::flat::FLAG_SUDAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_suriname" , &crate::flat::FLAG_SURINAME as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_svalbard_and_jan_mayen" , &crate::flat::FLAG_SVALBARD_AND_JAN_MAYEN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_sweden" , &crate::flat::FLAG_SWEDEN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_switzerland" , &crate::flat::FLAG_SWITZERLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_syria" , &crate::flat::FLAG_SYRIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_taiwan" , &crate::flat::FLAG_TAIWAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_tajikistan" , &crate::flat::FLAG_TAJIKISTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_tanzania" , &crate::flat::FLAG_TANZANIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_thailand" , &crate::flat::FLAG_THAILAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_timor_leste" , &crate::flat::FLAG_TIMOR_LESTE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_togo" , &crate::flat::FLAG_TOGO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_tokelau" , &crate::flat::FLAG_TOKELAU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_tonga" , &crate::flat::FLAG_TONGA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_trinidad_and_tobago" , &crate::flat::FLAG_TRINIDAD_AND_TOBAGO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_tristan_da_cunha" , &crate::flat::FLAG_TRISTAN_DA_CUNHA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_tunisia" , &crate::flat::FLAG_TUNISIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_turkey" , &crate::flat::FLAG_TURKEY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_turkmenistan" , &crate::flat::FLAG_TURKMENISTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_turks_and_caicos_islands" , &crate::flat::FLAG_TURKS_AND_CAICOS_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_tuvalu" , &crate::flat::FLAG_TUVALU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_uganda" , &crate::flat::FLAG_UGANDA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_ukraine" , &crate::flat::FLAG_UKRAINE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_united_arab_emirates" , &crate::flat::FLAG_UNITED_ARAB_EMIRATES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_united_kingdom" , &crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_united_nations" , &crate::flat::FLAG_UNITED_NATIONS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_united_states" , &crate::flat::FLAG_UNITED_STATES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_uruguay" , &crate::flat::FLAG_URUGUAY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_us_outlying_islands" , &crate::flat::FLAG_US_OUTLYING_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_us_virgin_islands" , &crate::flat::FLAG_US_VIRGIN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_uzbekistan" , &crate::flat::FLAG_UZBEKISTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_vanuatu" , &crate::flat::FLAG_VANUATU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_vatican_city" , &crate::flat::FLAG_VATICAN_CITY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_venezuela" , &crate::flat::FLAG_VENEZUELA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_vietnam" , &crate::flat::FLAG_VIETNAM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_wales" , &crate::flat::FLAG_WALES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_wallis_and_futuna" , &crate::flat::FLAG_WALLIS_AND_FUTUNA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_western_sahara" , &crate::flat::FLAG_WESTERN_SAHARA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_yemen" , &crate::flat::FLAG_YEMEN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_zambia" , &crate::flat::FLAG_ZAMBIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_zimbabwe" , &crate::flat::FLAG_ZIMBABWE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("flags" , &crate::flat::CARP_STREAMER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("flamingo" , &crate::flat::FLAMINGO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("flashlight" , &crate::flat::FLASHLIGHT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("flat_shoe" , &crate::flat::FLAT_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("flatbread" , &crate::flat::FLATBREAD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("fleur_de_lis" , &crate::flat::FLEUR_DE_LIS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("flexed_biceps" , &crate::flat::FLEXED_BICEPS.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("flight_arrival" , &crate::flat::AIRPLANE_ARRIVAL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("flight_departure" , &crate::flat::AIRPLANE_DEPARTURE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("flipper" , &crate::flat::DOLPHIN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("floppy_disk" , &crate::flat::FLOPPY_DISK as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("flower_playing_cards" , &crate::flat::FLOWER_PLAYING_CARDS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("flushed" , &crate::flat::FLUSHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("flushed_face" , &crate::flat::FLUSHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("fly" , &crate::flat::FLY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("flying_disc" , &crate::flat::FLYING_DISC as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("flying_saucer" , &crate::flat::FLYING_SAUCER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("fog" , &crate::flat::FOG as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("foggy" , &crate::flat::FOGGY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("folded_hands" , &crate::flat::FOLDED_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fondue" , &crate::flat::FONDUE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("foot" , &crate::flat::FOOT.default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("football" , &crate::flat::AMERICAN_FOOTBALL as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("footprints" , &crate::flat::FOOTPRINTS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fork_and_knife" , &crate::flat::FORK_AND_KNIFE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fork_and_knife_with_plate" , &crate::flat::FORK_AND_KNIFE_WITH_PLATE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fortune_cookie" , &crate::flat::FORTUNE_COOKIE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("fountain" , &crate::flat::FOUNTAIN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("fountain_pen" , &crate::flat::FOUNTAIN_PEN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("four" , &crate::flat::KEYCAP_4 as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("four_leaf_clover" , &crate::flat::FOUR_LEAF_CLOVER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("four_o_clock" , &crate::flat::FOUR_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("four_thirty" , &crate::flat::FOUR_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("fox" , &crate::flat::FOX as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("fox_face" , &crate::flat::FOX as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("fr" , &crate::flat::FLAG_FRANCE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("framed_picture" , &crate::flat::FRAMED_PICTURE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("free" , &crate::flat::FREE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("free_button" , &crate::flat::FREE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("french_fries" , &crate::flat::FRENCH_FRIES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("french_guiana" , &crate::flat::FLAG_FRENCH_GUIANA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("french_polynesia" , &crate::flat::FLAG_FRENCH_POLYNESIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("french_southern_territories" , &crate::flat::FLAG_FRENCH_SOUTHERN_TERRITORIES as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fried_egg" , &crate::flat::COOKING as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fried_shrimp" , &crate::flat::FRIED_SHRIMP as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fries" , &crate::flat::FRENCH_FRIES as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("frog" , &crate::flat::FROG as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("front_facing_baby_chick" , &crate::flat::FRONT_FACING_BABY_CHICK as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("frowning" , &crate::flat::FROWNING_FACE_WITH_OPEN_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("frowning_face" , &crate::flat::FROWNING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("frowning_face_with_open_mouth" , &crate::flat::FROWNING_FACE_WITH_OPEN_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("frowning_man" , &crate::flat::PERSON_FROWNING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("frowning_person" , &crate::flat::PERSON_FROWNING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("frowning_woman" , &crate::flat::PERSON_FROWNING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fu" , &crate::flat::MIDDLE_FINGER.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("fuel_pump" , &crate::flat::FUEL_PUMP as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("fuelpump" , &crate::flat::FUEL_PUMP as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("full_moon" , &crate::flat::FULL_MOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("full_moon_face" , &crate::flat::FULL_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("full_moon_with_face" , &crate::flat::FULL_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("funeral_urn" , &crate::flat::FUNERAL_URN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("gabon" , &crate::flat::FLAG_GABON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("gambia" , &crate::flat::FLAG_GAMBIA as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("game_die" , &crate::flat::GAME_DIE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("garlic" , &crate::flat::GARLIC as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("gb" , &crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("gear" , &crate::flat::GEAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("gem" , &crate::flat::GEM_STONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("gem_stone" , &crate::flat::GEM_STONE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("gemini" , &crate::flat::GEMINI as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("genie" , &crate::flat::GENIE.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("genie_man" , &crate::flat::GENIE.gender(Gender::Male) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("genie_woman" , &crate::flat::GENIE.gender(Gender::Female) as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("georgia" , &crate::flat::FLAG_GEORGIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ghana" , &crate::flat::FLAG_GHANA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("ghost" , &crate::flat::GHOST as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("gibraltar" , &crate::flat::FLAG_GIBRALTAR as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("gift" , &crate::flat::WRAPPED_GIFT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("gift_heart" , &crate::flat::HEART_WITH_RIBBON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("giraffe" , &crate::flat::GIRAFFE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("girl" , &crate::flat::GIRL.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("glass_of_milk" , &crate::flat::GLASS_OF_MILK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("glasses" , &crate::flat::GLASSES as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("globe_showing_americas" , &crate::flat::GLOBE_SHOWING_AMERICAS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("globe_showing_asia_australia" , &crate::flat::GLOBE_SHOWING_ASIA_AUSTRALIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("globe_showing_europe_africa" , &crate::flat::GLOBE_SHOWING_EUROPE_AFRICA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("globe_with_meridians" , &crate::flat::GLOBE_WITH_MERIDIANS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("gloves" , &crate::flat::GLOVES as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("glowing_star" , &crate::flat::GLOWING_STAR as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("goal_net" , &crate::flat::GOAL_NET as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("goat" , &crate::flat::GOAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("goblin" , &crate::flat::GOBLIN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("goggles" , &crate::flat::GOGGLES as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("golf" , &crate::flat::FLAG_IN_HOLE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("golfing" , &crate::flat::PERSON_GOLFING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("golfing_man" , &crate::flat::PERSON_GOLFING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("golfing_woman" , &crate::flat::PERSON_GOLFING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("gorilla" , &crate::flat::GORILLA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("graduation_cap" , &crate::flat::GRADUATION_CAP as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("grapes" , &crate::flat::GRAPES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("greece" , &crate::flat::FLAG_GREECE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("green_apple" , &crate::flat::GREEN_APPLE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("green_book" , &crate::flat::GREEN_BOOK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("green_circle" , &crate::flat::GREEN_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("green_heart" , &crate::flat::GREEN_HEART as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("green_salad" , &crate::flat::GREEN_SALAD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("green_square" , &crate::flat::GREEN_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("greenland" , &crate::flat::FLAG_GREENLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("grenada" , &crate::flat::FLAG_GRENADA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("grey_exclamation" , &crate::flat::WHITE_EXCLAMATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("grey_question" , &crate::flat::WHITE_QUESTION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grimacing" , &crate::flat::GRIMACING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grimacing_face" , &crate::flat::GRIMACING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grin" , &crate::flat::BEAMING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning" , &crate::flat::GRINNING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning_cat" , &crate::flat::GRINNING_CAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning_cat_with_smiling_eyes" , &crate::flat::GRINNING_CAT_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning_face" , &crate::flat::GRINNING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning_face_with_big_eyes" , &crate::flat::GRINNING_FACE_WITH_BIG_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning_face_with_smiling_eyes" , &crate::flat::GRINNING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning_face_with_sweat" , &crate::flat::GRINNING_FACE_WITH_SWEAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning_squinting_face" , &crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("growing_heart" , &crate::flat::GROWING_HEART as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("guadeloupe" , &crate::flat::FLAG_GUADELOUPE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("guam" , &crate::flat::FLAG_GUAM as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("guard" , &crate::flat::GUARD.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("guardsman" , &crate::flat::GUARD.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("guardswoman" , &crate::flat::GUARD.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("guatemala" , &crate::flat::FLAG_GUATEMALA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("guernsey" , &crate::flat::FLAG_GUERNSEY as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("guide_dog" , &crate::flat::GUIDE_DOG as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("guinea" , &crate::flat::FLAG_GUINEA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("guinea_bissau" , &crate::flat::FLAG_GUINEA_BISSAU as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("guitar" , &crate::flat::GUITAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("gun" , &crate::flat::WATER_PISTOL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("guyana" , &crate::flat::FLAG_GUYANA as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("haircut" , &crate::flat::PERSON_GETTING_HAIRCUT.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("haircut_man" , &crate::flat::PERSON_GETTING_HAIRCUT.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("haircut_woman" , &crate::flat::PERSON_GETTING_HAIRCUT.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("haiti" , &crate::flat::FLAG_HAITI as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("hamburger" , &crate::flat::HAMBURGER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("hammer" , &crate::flat::HAMMER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("hammer_and_pick" , &crate::flat::HAMMER_AND_PICK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("hammer_and_wrench" , &crate::flat::HAMMER_AND_WRENCH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("hamsa" , &crate::flat::HAMSA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("hamster" , &crate::flat::HAMSTER as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("hand" , &crate::flat::RAISED_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hand_over_mouth" , &crate::flat::FACE_WITH_HAND_OVER_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("hand_with_fingers_splayed" , &crate::flat::HAND_WITH_FINGERS_SPLAYED.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("hand_with_index_finger_and_thumb_crossed" , &crate::flat::HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("handbag" , &crate::flat::HANDBAG as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("handball_person" , &crate::flat::PERSON_PLAYING_HANDBALL.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("handshake" , &crate::flat::HANDSHAKE.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hankey" , &crate::flat::PILE_OF_POO as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("hash" , &crate::flat::KEYCAP_HASH as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("hatched_chick" , &crate::flat::FRONT_FACING_BABY_CHICK as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("hatching_chick" , &crate::flat::HATCHING_CHICK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("headphone" , &crate::flat::HEADPHONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("headphones" , &crate::flat::HEADPHONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("headstone" , &crate::flat::HEADSTONE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("health_worker" , &crate::flat::HEALTH_WORKER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hear_no_evil" , &crate::flat::HEAR_NO_EVIL_MONKEY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hear_no_evil_monkey" , &crate::flat::HEAR_NO_EVIL_MONKEY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("heard_mcdonald_islands" , &crate::flat::FLAG_HEARD_AND_MCDONALD_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart" , &crate::flat::RED_HEART as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart_decoration" , &crate::flat::HEART_DECORATION as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart_exclamation" , &crate::flat::HEART_EXCLAMATION as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart_eyes" , &crate::flat::SMILING_FACE_WITH_HEART_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart_eyes_cat" , &crate::flat::SMILING_CAT_WITH_HEART_EYES as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("heart_hands" , &crate::flat::HEART_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart_on_fire" , &crate::flat::HEART_ON_FIRE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("heart_suit" , &crate::flat::HEART_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart_with_arrow" , &crate::flat::HEART_WITH_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart_with_ribbon" , &crate::flat::HEART_WITH_RIBBON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heartbeat" , &crate::flat::BEATING_HEART as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heartpulse" , &crate::flat::GROWING_HEART as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("hearts" , &crate::flat::HEART_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_check_mark" , &crate::flat::CHECK_MARK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_division_sign" , &crate::flat::DIVIDE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_dollar_sign" , &crate::flat::HEAVY_DOLLAR_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_equals_sign" , &crate::flat::HEAVY_EQUALS_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_exclamation_mark" , &crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heavy_heart_exclamation" , &crate::flat::HEART_EXCLAMATION as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_minus_sign" , &crate::flat::MINUS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_multiplication_x" , &crate::flat::MULTIPLY as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_plus_sign" , &crate::flat::PLUS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("hedgehog" , &crate::flat::HEDGEHOG as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("helicopter" , &crate::flat::HELICOPTER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("herb" , &crate::flat::HERB as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("hibiscus" , &crate::flat::HIBISCUS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("high_brightness" , &crate::flat::BRIGHT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("high_heel" , &crate::flat::HIGH_HEELED_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("high_heeled_shoe" , &crate::flat::HIGH_HEELED_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("high_speed_train" , &crate::flat::HIGH_SPEED_TRAIN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("high_voltage" , &crate::flat::HIGH_VOLTAGE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("hiking_boot" , &crate::flat::HIKING_BOOT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hindu_temple" , &crate::flat::HINDU_TEMPLE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("hippopotamus" , &crate::flat::HIPPOPOTAMUS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("hocho" , &crate::flat::KITCHEN_KNIFE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hole" , &crate::flat::HOLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("hollow_red_circle" , &crate::flat::HOLLOW_RED_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("honduras" , &crate::flat::FLAG_HONDURAS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("honey_pot" , &crate::flat::HONEY_POT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("honeybee" , &crate::flat::HONEYBEE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("hong_kong" , &crate::flat::FLAG_HONG_KONG_SAR_CHINA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("hook" , &crate::flat::HOOK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("horizontal_traffic_light" , &crate::flat::HORIZONTAL_TRAFFIC_LIGHT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("horse" , &crate::flat::HORSE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("horse_face" , &crate::flat::HORSE_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("horse_racing" , &crate::flat::HORSE_RACING.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hospital" , &crate::flat::HOSPITAL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("hot_beverage" , &crate::flat::HOT_BEVERAGE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("hot_dog" , &crate::flat::HOT_DOG as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hot_face" , &crate::flat::HOT_FACE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("hot_pepper" , &crate::flat::HOT_PEPPER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hot_springs" , &crate::flat::HOT_SPRINGS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("hotdog" , &crate::flat::HOT_DOG as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hotel" , &crate::flat::HOTEL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hotsprings" , &crate::flat::HOT_SPRINGS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hourglass" , &crate::flat::HOURGLASS_DONE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hourglass_done" , &crate::flat::HOURGLASS_DONE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hourglass_flowing_sand" , &crate::flat::HOURGLASS_NOT_DONE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hourglass_not_done" , &crate::flat::HOURGLASS_NOT_DONE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("house" , &crate::flat::HOUSE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("house_with_garden" , &crate::flat::HOUSE_WITH_GARDEN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("houses" , &crate::flat::HOUSES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hugging_face" , &crate::flat::HUGGING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hugs" , &crate::flat::HUGGING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hundred_points" , &crate::flat::HUNDRED_POINTS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("hungary" , &crate::flat::FLAG_HUNGARY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hushed" , &crate::flat::HUSHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hushed_face" , &crate::flat::HUSHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hut" , &crate::flat::HUT as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("ice" , &crate::flat::ICE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("ice_cream" , &crate::flat::ICE_CREAM as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("ice_cube" , &crate::flat::ICE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("ice_hockey" , &crate::flat::ICE_HOCKEY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("ice_skate" , &crate::flat::ICE_SKATE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("icecream" , &crate::flat::SOFT_ICE_CREAM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("iceland" , &crate::flat::FLAG_ICELAND as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("id" , &crate::flat::ID_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("id_button" , &crate::flat::ID_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("identification_card" , &crate::flat::IDENTIFICATION_CARD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ideograph_advantage" , &crate::flat::JAPANESE_BARGAIN_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("imp" , &crate::flat::ANGRY_FACE_WITH_HORNS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("inbox_tray" , &crate::flat::INBOX_TRAY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("incoming_envelope" , &crate::flat::INCOMING_ENVELOPE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("index_pointing_at_the_viewer" , &crate::flat::INDEX_POINTING_AT_THE_VIEWER.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("index_pointing_up" , &crate::flat::INDEX_POINTING_UP.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("india" , &crate::flat::FLAG_INDIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("indonesia" , &crate::flat::FLAG_INDONESIA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("infinity" , &crate::flat::INFINITY as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("information" , &crate::flat::INFORMATION as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("information_desk_person" , &crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("information_source" , &crate::flat::INFORMATION as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("innocent" , &crate::flat::SMILING_FACE_WITH_HALO as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("input_latin_letters" , &crate::flat::INPUT_LATIN_LETTERS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("input_latin_lowercase" , &crate::flat::INPUT_LATIN_LOWERCASE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("input_latin_uppercase" , &crate::flat::INPUT_LATIN_UPPERCASE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("input_numbers" , &crate::flat::INPUT_NUMBERS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("input_symbols" , &crate::flat::INPUT_SYMBOLS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("interrobang" , &crate::flat::EXCLAMATION_QUESTION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("iphone" , &crate::flat::MOBILE_PHONE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("iran" , &crate::flat::FLAG_IRAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("iraq" , &crate::flat::FLAG_IRAQ as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ireland" , &crate::flat::FLAG_IRELAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("isle_of_man" , &crate::flat::FLAG_ISLE_OF_MAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("israel" , &crate::flat::FLAG_ISRAEL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("it" , &crate::flat::FLAG_ITALY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("izakaya_lantern" , &crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("jack_o_lantern" , &crate::flat::JACK_O_LANTERN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("jamaica" , &crate::flat::FLAG_JAMAICA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("japan" , &crate::flat::MAP_OF_JAPAN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_acceptable_button" , &crate::flat::JAPANESE_ACCEPTABLE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_application_button" , &crate::flat::JAPANESE_APPLICATION_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_bargain_button" , &crate::flat::JAPANESE_BARGAIN_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("japanese_castle" , &crate::flat::JAPANESE_CASTLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_congratulations_button" , &crate::flat::JAPANESE_CONGRATULATIONS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_discount_button" , &crate::flat::JAPANESE_DISCOUNT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("japanese_dolls" , &crate::flat::JAPANESE_DOLLS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_free_of_charge_button" , &crate::flat::JAPANESE_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("japanese_goblin" , &crate::flat::GOBLIN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_here_button" , &crate::flat::JAPANESE_HERE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_monthly_amount_button" , &crate::flat::JAPANESE_MONTHLY_AMOUNT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_no_vacancy_button" , &crate::flat::JAPANESE_NO_VACANCY_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_not_free_of_charge_button" , &crate::flat::JAPANESE_NOT_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("japanese_ogre" , &crate::flat::OGRE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_open_for_business_button" , &crate::flat::JAPANESE_OPEN_FOR_BUSINESS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_passing_grade_button" , &crate::flat::JAPANESE_PASSING_GRADE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("japanese_post_office" , &crate::flat::JAPANESE_POST_OFFICE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_prohibited_button" , &crate::flat::JAPANESE_PROHIBITED_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_reserved_button" , &crate::flat::JAPANESE_RESERVED_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_secret_button" , &crate::flat::JAPANESE_SECRET_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_service_charge_button" , &crate::flat::JAPANESE_SERVICE_CHARGE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_symbol_for_beginner" , &crate::flat::JAPANESE_SYMBOL_FOR_BEGINNER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_vacancy_button" , &crate::flat::JAPANESE_VACANCY_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("jar" , &crate::flat::JAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("jeans" , &crate::flat::JEANS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("jersey" , &crate::flat::FLAG_JERSEY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("jigsaw" , &crate::flat::PUZZLE_PIECE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("joker" , &crate::flat::JOKER as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("jordan" , &crate::flat::FLAG_JORDAN as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("joy" , &crate::flat::FACE_WITH_TEARS_OF_JOY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("joy_cat" , &crate::flat::CAT_WITH_TEARS_OF_JOY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("joystick" , &crate::flat::JOYSTICK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("jp" , &crate::flat::FLAG_JAPAN as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("judge" , &crate::flat::JUDGE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("juggling_person" , &crate::flat::PERSON_JUGGLING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("kaaba" , &crate::flat::KAABA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("kangaroo" , &crate::flat::KANGAROO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("kazakhstan" , &crate::flat::FLAG_KAZAKHSTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("kenya" , &crate::flat::FLAG_KENYA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("key" , &crate::flat::KEY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("keyboard" , &crate::flat::KEYBOARD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_0" , &crate::flat::KEYCAP_0 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_1" , &crate::flat::KEYCAP_1 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_10" , &crate::flat::KEYCAP_10 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_2" , &crate::flat::KEYCAP_2 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_3" , &crate::flat::KEYCAP_3 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_4" , &crate::flat::KEYCAP_4 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_5" , &crate::flat::KEYCAP_5 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_6" , &crate::flat::KEYCAP_6 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_7" , &crate::flat::KEYCAP_7 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_8" , &crate::flat::KEYCAP_8 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_9" , &crate::flat::KEYCAP_9 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_asterisk" , &crate::flat::KEYCAP_ASTERISK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_hash" , &crate::flat::KEYCAP_HASH as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_ten" , &crate::flat::KEYCAP_10 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("kick_scooter" , &crate::flat::KICK_SCOOTER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("kimono" , &crate::flat::KIMONO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("kiribati" , &crate::flat::FLAG_KIRIBATI as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("kiss" , &crate::flat::KISS.default.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kiss_mark" , &crate::flat::KISS_MARK as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing" , &crate::flat::KISSING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing_cat" , &crate::flat::KISSING_CAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing_closed_eyes" , &crate::flat::KISSING_FACE_WITH_CLOSED_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing_face" , &crate::flat::KISSING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing_face_with_closed_eyes" , &crate::flat::KISSING_FACE_WITH_CLOSED_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing_face_with_smiling_eyes" , &crate::flat::KISSING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing_heart" , &crate::flat::FACE_BLOWING_A_KISS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing_smiling_eyes" , &crate::flat::KISSING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("kitchen_knife" , &crate::flat::KITCHEN_KNIFE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("kite" , &crate::flat::KITE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("kiwi_fruit" , &crate::flat::KIWI_FRUIT as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("kneeling_man" , &crate::flat::PERSON_KNEELING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("kneeling_person" , &crate::flat::PERSON_KNEELING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("kneeling_woman" , &crate::flat::PERSON_KNEELING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("knife" , &crate::flat::KITCHEN_KNIFE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("knocked_out_face" , &crate::flat::KNOCKED_OUT_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("knot" , &crate::flat::KNOT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("koala" , &crate::flat::KOALA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("koko" , &crate::flat::JAPANESE_HERE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("kosovo" , &crate::flat::FLAG_KOSOVO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("kr" , &crate::flat::FLAG_SOUTH_KOREA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("kuwait" , &crate::flat::FLAG_KUWAIT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("kyrgyzstan" , &crate::flat::FLAG_KYRGYZSTAN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("lab_coat" , &crate::flat::LAB_COAT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("label" , &crate::flat::LABEL as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("lacrosse" , &crate::flat::LACROSSE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("ladder" , &crate::flat::LADDER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("lady_beetle" , &crate::flat::LADY_BEETLE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("lantern" , &crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("laos" , &crate::flat::FLAG_LAOS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("laptop" , &crate::flat::LAPTOP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("large_blue_circle" , &crate::flat::BLUE_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("large_blue_diamond" , &crate::flat::LARGE_BLUE_DIAMOND as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("large_orange_diamond" , &crate::flat::LARGE_ORANGE_DIAMOND as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("last_quarter_moon" , &crate::flat::LAST_QUARTER_MOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("last_quarter_moon_face" , &crate::flat::LAST_QUARTER_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("last_quarter_moon_with_face" , &crate::flat::LAST_QUARTER_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("last_track_button" , &crate::flat::LAST_TRACK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("latin_cross" , &crate::flat::LATIN_CROSS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("latvia" , &crate::flat::FLAG_LATVIA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("laughing" , &crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("leaf_fluttering_in_wind" , &crate::flat::LEAF_FLUTTERING_IN_WIND as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("leafy_green" , &crate::flat::LEAFY_GREEN as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("leaves" , &crate::flat::LEAF_FLUTTERING_IN_WIND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("lebanon" , &crate::flat::FLAG_LEBANON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("ledger" , &crate::flat::LEDGER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("left_arrow" , &crate::flat::LEFT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("left_arrow_curving_right" , &crate::flat::LEFT_ARROW_CURVING_RIGHT as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("left_facing_fist" , &crate::flat::LEFT_FACING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("left_luggage" , &crate::flat::LEFT_LUGGAGE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("left_right_arrow" , &crate::flat::LEFT_RIGHT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("left_speech_bubble" , &crate::flat::LEFT_SPEECH_BUBBLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("leftwards_arrow_with_hook" , &crate::flat::RIGHT_ARROW_CURVING_LEFT as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("leftwards_hand" , &crate::flat::LEFTWARDS_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("leg" , &crate::flat::LEG.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("lemon" , &crate::flat::LEMON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("leo" , &crate::flat::LEO as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("leopard" , &crate::flat::LEOPARD as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("lesotho" , &crate::flat::FLAG_LESOTHO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("level_slider" , &crate::flat::LEVEL_SLIDER as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("liberia" , &crate::flat::FLAG_LIBERIA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("libra" , &crate::flat::LIBRA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("libya" , &crate::flat::FLAG_LIBYA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("liechtenstein" , &crate::flat::FLAG_LIECHTENSTEIN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("light_bulb" , &crate::flat::LIGHT_BULB as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("light_rail" , &crate::flat::LIGHT_RAIL as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("light_skin_tone" , &crate::flat::LIGHT_SKIN_TONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("link" , &crate::flat::LINK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("linked_paperclips" , &crate::flat::LINKED_PAPERCLIPS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("lion" , &crate::flat::LION as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("lips" , &crate::flat::MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("lipstick" , &crate::flat::LIPSTICK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("lithuania" , &crate::flat::FLAG_LITHUANIA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("litter_in_bin_sign" , &crate::flat::LITTER_IN_BIN_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("lizard" , &crate::flat::LIZARD as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("llama" , &crate::flat::LLAMA as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("lobster" , &crate::flat::LOBSTER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("lock" , &crate::flat::LOCKED as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("lock_with_ink_pen" , &crate::flat::LOCKED_WITH_PEN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("locked" , &crate::flat::LOCKED as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("locked_with_key" , &crate::flat::LOCKED_WITH_KEY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("locked_with_pen" , &crate::flat::LOCKED_WITH_PEN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("locomotive" , &crate::flat::LOCOMOTIVE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("lollipop" , &crate::flat::LOLLIPOP as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("long_drum" , &crate::flat::LONG_DRUM as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("loop" , &crate::flat::DOUBLE_CURLY_LOOP as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("lotion_bottle" , &crate::flat::LOTION_BOTTLE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("lotus" , &crate::flat::LOTUS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("lotus_position" , &crate::flat::PERSON_IN_LOTUS_POSITION.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("lotus_position_man" , &crate::flat::PERSON_IN_LOTUS_POSITION.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("lotus_position_woman" , &crate::flat::PERSON_IN_LOTUS_POSITION.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("loud_sound" , &crate::flat::SPEAKER_HIGH_VOLUME as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("loudly_crying_face" , &crate::flat::LOUDLY_CRYING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("loudspeaker" , &crate::flat::LOUDSPEAKER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("love_hotel" , &crate::flat::LOVE_HOTEL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("love_letter" , &crate::flat::LOVE_LETTER as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("love_you_gesture" , &crate::flat::LOVE_YOU_GESTURE.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("low_battery" , &crate::flat::LOW_BATTERY as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("low_brightness" , &crate::flat::DIM_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("luggage" , &crate::flat::LUGGAGE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("lungs" , &crate::flat::LUNGS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("luxembourg" , &crate::flat::FLAG_LUXEMBOURG as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("lying_face" , &crate::flat::LYING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("m" , &crate::flat::CIRCLED_M as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("macau" , &crate::flat::FLAG_MACAO_SAR_CHINA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("macedonia" , &crate::flat::FLAG_NORTH_MACEDONIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("madagascar" , &crate::flat::FLAG_MADAGASCAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mag" , &crate::flat::MAGNIFYING_GLASS_TILTED_LEFT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mag_right" , &crate::flat::MAGNIFYING_GLASS_TILTED_RIGHT as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mage" , &crate::flat::MAGE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mage_man" , &crate::flat::MAGE.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mage_woman" , &crate::flat::MAGE.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("magic_wand" , &crate::flat::MAGIC_WAND as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("magnet" , &crate::flat::MAGNET as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("magnifying_glass_tilted_left" , &crate::flat::MAGNIFYING_GLASS_TILTED_LEFT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("magnifying_glass_tilted_right" , &crate::flat::MAGNIFYING_GLASS_TILTED_RIGHT as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("mahjong" , &crate::flat::MAHJONG_RED_DRAGON as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("mahjong_red_dragon" , &crate::flat::MAHJONG_RED_DRAGON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mailbox" , &crate::flat::CLOSED_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mailbox_closed" , &crate::flat::CLOSED_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mailbox_with_mail" , &crate::flat::OPEN_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mailbox_with_no_mail" , &crate::flat::OPEN_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("malawi" , &crate::flat::FLAG_MALAWI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("malaysia" , &crate::flat::FLAG_MALAYSIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("maldives" , &crate::flat::FLAG_MALDIVES as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("male_detective" , &crate::flat::DETECTIVE.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("male_sign" , &crate::flat::MALE_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("mali" , &crate::flat::FLAG_MALI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("malta" , &crate::flat::FLAG_MALTA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("mammoth" , &crate::flat::MAMMOTH as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man" , &crate::flat::PERSON.default.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_artist" , &crate::flat::ARTIST.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_astronaut" , &crate::flat::ASTRONAUT.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_beard" , &crate::flat::PERSON.hair(Hair::Beard).gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_cartwheeling" , &crate::flat::PERSON_CARTWHEELING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_cook" , &crate::flat::COOK.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_dancing" , &crate::flat::PERSON_DANCING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_facepalming" , &crate::flat::PERSON_FACEPALMING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_factory_worker" , &crate::flat::FACTORY_WORKER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_farmer" , &crate::flat::FARMER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_feeding_baby" , &crate::flat::PERSON_FEEDING_BABY.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_firefighter" , &crate::flat::FIREFIGHTER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_health_worker" , &crate::flat::HEALTH_WORKER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_in_manual_wheelchair" , &crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_in_motorized_wheelchair" , &crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_in_tuxedo" , &crate::flat::PERSON_IN_TUXEDO.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_judge" , &crate::flat::JUDGE.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_juggling" , &crate::flat::PERSON_JUGGLING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_mechanic" , &crate::flat::MECHANIC.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_office_worker" , &crate::flat::OFFICE_WORKER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_pilot" , &crate::flat::PILOT.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_playing_handball" , &crate::flat::PERSON_PLAYING_HANDBALL.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_playing_water_polo" , &crate::flat::PERSON_PLAYING_WATER_POLO.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("man_s_shoe" , &crate::flat::MAN_S_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_scientist" , &crate::flat::SCIENTIST.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_shrugging" , &crate::flat::PERSON_SHRUGGING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_singer" , &crate::flat::SINGER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_student" , &crate::flat::STUDENT.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_teacher" , &crate::flat::TEACHER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_technologist" , &crate::flat::TECHNOLOGIST.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_with_gua_pi_mao" , &crate::flat::PERSON_WITH_SKULLCAP.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_with_probing_cane" , &crate::flat::PERSON_WITH_WHITE_CANE.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_with_turban" , &crate::flat::PERSON_WEARING_TURBAN.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_with_veil" , &crate::flat::PERSON_WITH_VEIL.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("mandarin" , &crate::flat::TANGERINE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("mango" , &crate::flat::MANGO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mans_shoe" , &crate::flat::MAN_S_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("mantelpiece_clock" , &crate::flat::MANTELPIECE_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("manual_wheelchair" , &crate::flat::MANUAL_WHEELCHAIR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("map_of_japan" , &crate::flat::MAP_OF_JAPAN as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("maple_leaf" , &crate::flat::MAPLE_LEAF as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("marshall_islands" , &crate::flat::FLAG_MARSHALL_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("martial_arts_uniform" , &crate::flat::MARTIAL_ARTS_UNIFORM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("martinique" , &crate::flat::FLAG_MARTINIQUE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("mask" , &crate::flat::FACE_WITH_MEDICAL_MASK as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("massage" , &crate::flat::PERSON_GETTING_MASSAGE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("massage_man" , &crate::flat::PERSON_GETTING_MASSAGE.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("massage_woman" , &crate::flat::PERSON_GETTING_MASSAGE.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("mate" , &crate::flat::MATE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("mauritania" , &crate::flat::FLAG_MAURITANIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("mauritius" , &crate::flat::FLAG_MAURITIUS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("mayotte" , &crate::flat::FLAG_MAYOTTE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("meat_on_bone" , &crate::flat::MEAT_ON_BONE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mechanic" , &crate::flat::MECHANIC.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mechanical_arm" , &crate::flat::MECHANICAL_ARM as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mechanical_leg" , &crate::flat::MECHANICAL_LEG as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("medal_military" , &crate::flat::MILITARY_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("medal_sports" , &crate::flat::SPORTS_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("medical_symbol" , &crate::flat::MEDICAL_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("medium_dark_skin_tone" , &crate::flat::MEDIUM_DARK_SKIN_TONE as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("medium_light_skin_tone" , &crate::flat::MEDIUM_LIGHT_SKIN_TONE as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("medium_skin_tone" , &crate::flat::MEDIUM_SKIN_TONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mega" , &crate::flat::MEGAPHONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("megaphone" , &crate::flat::MEGAPHONE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("melon" , &crate::flat::MELON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("melting_face" , &crate::flat::MELTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("memo" , &crate::flat::MEMO as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("men_s_room" , &crate::flat::MEN_S_ROOM as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("men_wrestling" , &crate::flat::PERSON_WRESTLING.gender(Gender::Male) as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("mending_heart" , &crate::flat::MENDING_HEART as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("menorah" , &crate::flat::MENORAH as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("mens" , &crate::flat::MEN_S_ROOM as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mermaid" , &crate::flat::MERMAID.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("merman" , &crate::flat::MERMAN.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("merperson" , &crate::flat::MERPERSON.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("metal" , &crate::flat::SIGN_OF_THE_HORNS.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("metro" , &crate::flat::METRO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("mexico" , &crate::flat::FLAG_MEXICO as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("microbe" , &crate::flat::MICROBE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("micronesia" , &crate::flat::FLAG_MICRONESIA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("microphone" , &crate::flat::MICROPHONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("microscope" , &crate::flat::MICROSCOPE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("middle_finger" , &crate::flat::MIDDLE_FINGER.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("military_helmet" , &crate::flat::MILITARY_HELMET as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("military_medal" , &crate::flat::MILITARY_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("milk_glass" , &crate::flat::GLASS_OF_MILK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("milky_way" , &crate::flat::MILKY_WAY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("minibus" , &crate::flat::MINIBUS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("minidisc" , &crate::flat::COMPUTER_DISK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("minus" , &crate::flat::MINUS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mirror" , &crate::flat::MIRROR as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("mirror_ball" , &crate::flat::MIRROR_BALL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("moai" , &crate::flat::MOAI as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mobile_phone" , &crate::flat::MOBILE_PHONE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("mobile_phone_off" , &crate::flat::MOBILE_PHONE_OFF as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mobile_phone_with_arrow" , &crate::flat::MOBILE_PHONE_WITH_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("moldova" , &crate::flat::FLAG_MOLDOVA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("monaco" , &crate::flat::FLAG_MONACO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("money_bag" , &crate::flat::MONEY_BAG as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("money_mouth_face" , &crate::flat::MONEY_MOUTH_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("money_with_wings" , &crate::flat::MONEY_WITH_WINGS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("moneybag" , &crate::flat::MONEY_BAG as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("mongolia" , &crate::flat::FLAG_MONGOLIA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("monkey" , &crate::flat::MONKEY as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("monkey_face" , &crate::flat::MONKEY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("monocle_face" , &crate::flat::FACE_WITH_MONOCLE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("monorail" , &crate::flat::MONORAIL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("montenegro" , &crate::flat::FLAG_MONTENEGRO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("montserrat" , &crate::flat::FLAG_MONTSERRAT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("moon" , &crate::flat::WAXING_GIBBOUS_MOON as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("moon_cake" , &crate::flat::MOON_CAKE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("moon_viewing_ceremony" , &crate::flat::MOON_VIEWING_CEREMONY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("morocco" , &crate::flat::FLAG_MOROCCO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mortar_board" , &crate::flat::GRADUATION_CAP as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("mosque" , &crate::flat::MOSQUE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("mosquito" , &crate::flat::MOSQUITO as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("motor_boat" , &crate::flat::MOTOR_BOAT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("motor_scooter" , &crate::flat::MOTOR_SCOOTER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("motorcycle" , &crate::flat::MOTORCYCLE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("motorized_wheelchair" , &crate::flat::MOTORIZED_WHEELCHAIR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("motorway" , &crate::flat::MOTORWAY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("mount_fuji" , &crate::flat::MOUNT_FUJI as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("mountain" , &crate::flat::MOUNTAIN as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mountain_bicyclist" , &crate::flat::PERSON_MOUNTAIN_BIKING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mountain_biking_man" , &crate::flat::PERSON_MOUNTAIN_BIKING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mountain_biking_woman" , &crate::flat::PERSON_MOUNTAIN_BIKING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("mountain_cableway" , &crate::flat::MOUNTAIN_CABLEWAY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("mountain_railway" , &crate::flat::MOUNTAIN_RAILWAY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("mountain_snow" , &crate::flat::SNOW_CAPPED_MOUNTAIN as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("mouse" , &crate::flat::MOUSE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("mouse2" , &crate::flat::MOUSE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("mouse_face" , &crate::flat::MOUSE_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mouse_trap" , &crate::flat::MOUSE_TRAP as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mouth" , &crate::flat::MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("movie_camera" , &crate::flat::MOVIE_CAMERA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("moyai" , &crate::flat::MOAI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("mozambique" , &crate::flat::FLAG_MOZAMBIQUE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mrs_claus" , &crate::flat::MRS_CLAUS.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("multiply" , &crate::flat::MULTIPLY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("muscle" , &crate::flat::FLEXED_BICEPS.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("mushroom" , &crate::flat::MUSHROOM as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("musical_keyboard" , &crate::flat::MUSICAL_KEYBOARD as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("musical_note" , &crate::flat::MUSICAL_NOTE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("musical_notes" , &crate::flat::MUSICAL_NOTES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("musical_score" , &crate::flat::MUSICAL_SCORE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mute" , &crate::flat::MUTED_SPEAKER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("muted_speaker" , &crate::flat::MUTED_SPEAKER as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mx_claus" , &crate::flat::MX_CLAUS.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("myanmar" , &crate::flat::FLAG_MYANMAR_BURMA as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("nail_care" , &crate::flat::NAIL_POLISH.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("nail_polish" , &crate::flat::NAIL_POLISH.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("name_badge" , &crate::flat::NAME_BADGE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("namibia" , &crate::flat::FLAG_NAMIBIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("national_park" , &crate::flat::NATIONAL_PARK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("nauru" , &crate::flat::FLAG_NAURU as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("nauseated_face" , &crate::flat::NAUSEATED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("nazar_amulet" , &crate::flat::NAZAR_AMULET as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("necktie" , &crate::flat::NECKTIE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("negative_squared_cross_mark" , &crate::flat::CROSS_MARK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("nepal" , &crate::flat::FLAG_NEPAL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("nerd_face" , &crate::flat::NERD_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("nest_with_eggs" , &crate::flat::NEST_WITH_EGGS as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("nesting_dolls" , &crate::flat::NESTING_DOLLS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("netherlands" , &crate::flat::FLAG_NETHERLANDS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("neutral_face" , &crate::flat::NEUTRAL_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("new" , &crate::flat::NEW_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("new_button" , &crate::flat::NEW_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("new_caledonia" , &crate::flat::FLAG_NEW_CALEDONIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("new_moon" , &crate::flat::NEW_MOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("new_moon_face" , &crate::flat::NEW_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("new_moon_with_face" , &crate::flat::NEW_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("new_zealand" , &crate::flat::FLAG_NEW_ZEALAND as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("newspaper" , &crate::flat::NEWSPAPER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("newspaper_roll" , &crate::flat::ROLLED_UP_NEWSPAPER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("next_track_button" , &crate::flat::NEXT_TRACK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ng" , &crate::flat::NG_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ng_button" , &crate::flat::NG_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ng_man" , &crate::flat::PERSON_GESTURING_NO.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ng_woman" , &crate::flat::PERSON_GESTURING_NO.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("nicaragua" , &crate::flat::FLAG_NICARAGUA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("niger" , &crate::flat::FLAG_NIGER as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("nigeria" , &crate::flat::FLAG_NIGERIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("night_with_stars" , &crate::flat::NIGHT_WITH_STARS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("nine" , &crate::flat::KEYCAP_9 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("nine_o_clock" , &crate::flat::NINE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("nine_thirty" , &crate::flat::NINE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ninja" , &crate::flat::NINJA.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("niue" , &crate::flat::FLAG_NIUE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("no_bell" , &crate::flat::BELL_WITH_SLASH as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_bicycles" , &crate::flat::NO_BICYCLES as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_entry" , &crate::flat::NO_ENTRY as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_entry_sign" , &crate::flat::PROHIBITED as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("no_good" , &crate::flat::PERSON_GESTURING_NO.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("no_good_man" , &crate::flat::PERSON_GESTURING_NO.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("no_good_woman" , &crate::flat::PERSON_GESTURING_NO.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_littering" , &crate::flat::NO_LITTERING as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_mobile_phones" , &crate::flat::NO_MOBILE_PHONES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("no_mouth" , &crate::flat::FACE_WITHOUT_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_one_under_eighteen" , &crate::flat::NO_ONE_UNDER_EIGHTEEN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_pedestrians" , &crate::flat::NO_PEDESTRIANS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_smoking" , &crate::flat::NO_SMOKING as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("non-potable_water" , &crate::flat::NON_POTABLE_WATER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("non_potable_water" , &crate::flat::NON_POTABLE_WATER as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("norfolk_island" , &crate::flat::FLAG_NORFOLK_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("north_korea" , &crate::flat::FLAG_NORTH_KOREA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("northern_mariana_islands" , &crate::flat::FLAG_NORTHERN_MARIANA_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("norway" , &crate::flat::FLAG_NORWAY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("nose" , &crate::flat::NOSE.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("notebook" , &crate::flat::NOTEBOOK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("notebook_with_decorative_cover" , &crate::flat::NOTEBOOK_WITH_DECORATIVE_COVER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("notes" , &crate::flat::MUSICAL_NOTES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("nut_and_bolt" , &crate::flat::NUT_AND_BOLT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("o" , &crate::flat::HOLLOW_RED_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("o2" , &crate::flat::O_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("o_button_blood_type" , &crate::flat::O_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ocean" , &crate::flat::WATER_WAVE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("octopus" , &crate::flat::OCTOPUS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("oden" , &crate::flat::ODEN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("office" , &crate::flat::OFFICE_BUILDING as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("office_building" , &crate::flat::OFFICE_BUILDING as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("office_worker" , &crate::flat::OFFICE_WORKER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("ogre" , &crate::flat::OGRE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("oil_drum" , &crate::flat::OIL_DRUM as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ok" , &crate::flat::OK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ok_button" , &crate::flat::OK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ok_hand" , &crate::flat::OK_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ok_man" , &crate::flat::PERSON_GESTURING_OK.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ok_person" , &crate::flat::PERSON_GESTURING_OK.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ok_woman" , &crate::flat::PERSON_GESTURING_OK.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("old_key" , &crate::flat::OLD_KEY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("older_adult" , &crate::flat::OLDER_PERSON.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("older_man" , &crate::flat::OLD_PERSON.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("older_person" , &crate::flat::OLDER_PERSON.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("older_woman" , &crate::flat::OLD_PERSON.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("olive" , &crate::flat::OLIVE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("om" , &crate::flat::OM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("oman" , &crate::flat::FLAG_OMAN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("on" , &crate::flat::ON_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("on_arrow" , &crate::flat::ON_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("oncoming_automobile" , &crate::flat::ONCOMING_AUTOMOBILE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("oncoming_bus" , &crate::flat::ONCOMING_BUS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("oncoming_fist" , &crate::flat::ONCOMING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("oncoming_police_car" , &crate::flat::ONCOMING_POLICE_CAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("oncoming_taxi" , &crate::flat::ONCOMING_TAXI as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("one" , &crate::flat::KEYCAP_1 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("one_o_clock" , &crate::flat::ONE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("one_piece_swimsuit" , &crate::flat::ONE_PIECE_SWIMSUIT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("one_thirty" , &crate::flat::ONE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("onion" , &crate::flat::ONION as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("open_book" , &crate::flat::OPEN_BOOK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("open_file_folder" , &crate::flat::OPEN_FILE_FOLDER as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("open_hands" , &crate::flat::OPEN_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("open_mailbox_with_lowered_flag" , &crate::flat::OPEN_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("open_mailbox_with_raised_flag" , &crate::flat::OPEN_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("open_mouth" , &crate::flat::FACE_WITH_OPEN_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("open_umbrella" , &crate::flat::UMBRELLA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ophiuchus" , &crate::flat::OPHIUCHUS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("optical_disk" , &crate::flat::OPTICAL_DISK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("orange" , &crate::flat::TANGERINE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("orange_book" , &crate::flat::ORANGE_BOOK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("orange_circle" , &crate::flat::ORANGE_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("orange_heart" , &crate::flat::ORANGE_HEART as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("orange_square" , &crate::flat::ORANGE_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("orangutan" , &crate::flat::ORANGUTAN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("orthodox_cross" , &crate::flat::ORTHODOX_CROSS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("otter" , &crate::flat::OTTER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("outbox_tray" , &crate::flat::OUTBOX_TRAY as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("owl" , &crate::flat::OWL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("ox" , &crate::flat::OX as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("oyster" , &crate::flat::OYSTER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("p_button" , &crate::flat::P_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("package" , &crate::flat::PACKAGE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("page_facing_up" , &crate::flat::PAGE_FACING_UP as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("page_with_curl" , &crate::flat::PAGE_WITH_CURL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pager" , &crate::flat::PAGER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("paintbrush" , &crate::flat::PAINTBRUSH as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("pakistan" , &crate::flat::FLAG_PAKISTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("palau" , &crate::flat::FLAG_PALAU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("palestinian_territories" , &crate::flat::FLAG_PALESTINIAN_TERRITORIES as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("palm_down_hand" , &crate::flat::PALM_DOWN_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("palm_tree" , &crate::flat::PALM_TREE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("palm_up_hand" , &crate::flat::PALM_UP_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("palms_up_together" , &crate::flat::PALMS_UP_TOGETHER.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("panama" , &crate::flat::FLAG_PANAMA as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pancakes" , &crate::flat::PANCAKES as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("panda" , &crate::flat::PANDA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("panda_face" , &crate::flat::PANDA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("paperclip" , &crate::flat::PAPERCLIP as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("paperclips" , &crate::flat::LINKED_PAPERCLIPS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("papua_new_guinea" , &crate::flat::FLAG_PAPUA_NEW_GUINEA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("parachute" , &crate::flat::PARACHUTE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("paraguay" , &crate::flat::FLAG_PARAGUAY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("parasol_on_ground" , &crate::flat::UMBRELLA_ON_GROUND as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("parking" , &crate::flat::P_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("parrot" , &crate::flat::PARROT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("part_alternation_mark" , &crate::flat::PART_ALTERNATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("partly_sunny" , &crate::flat::SUN_BEHIND_CLOUD as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("party_popper" , &crate::flat::PARTY_POPPER as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("partying_face" , &crate::flat::PARTYING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("passenger_ship" , &crate::flat::PASSENGER_SHIP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("passport_control" , &crate::flat::PASSPORT_CONTROL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("pause_button" , &crate::flat::PAUSE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("paw_prints" , &crate::flat::PAW_PRINTS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("peace_symbol" , &crate::flat::PEACE_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("peach" , &crate::flat::PEACH as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("peacock" , &crate::flat::PEACOCK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("peanuts" , &crate::flat::PEANUTS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pear" , &crate::flat::PEAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pen" , &crate::flat::PEN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pencil" , &crate::flat::PENCIL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pencil2" , &crate::flat::PENCIL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("penguin" , &crate::flat::PENGUIN as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("pensive" , &crate::flat::PENSIVE_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("pensive_face" , &crate::flat::PENSIVE_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("people_holding_hands" , &crate::flat::PERSON_HOLDING_HANDS.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("people_hugging" , &crate::flat::PERSON_HUGGING as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("performing_arts" , &crate::flat::PERFORMING_ARTS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("persevere" , &crate::flat::PERSEVERING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("persevering_face" , &crate::flat::PERSEVERING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person" , &crate::flat::PERSON.default.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_bald" , &crate::flat::PERSON.hair(Hair::Bald).default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_biking" , &crate::flat::PERSON_BIKING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_bouncing_ball" , &crate::flat::PERSON_BOUNCING_BALL.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_bowing" , &crate::flat::PERSON_BOWING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_cartwheeling" , &crate::flat::PERSON_CARTWHEELING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_climbing" , &crate::flat::PERSON_CLIMBING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_curly_hair" , &crate::flat::PERSON.hair(Hair::Curly).default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_facepalming" , &crate::flat::PERSON_FACEPALMING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_feeding_baby" , &crate::flat::PERSON_FEEDING_BABY.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_fencing" , &crate::flat::PERSON_FENCING as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_frowning" , &crate::flat::PERSON_FROWNING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_gesturing_no" , &crate::flat::PERSON_GESTURING_NO.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_gesturing_ok" , &crate::flat::PERSON_GESTURING_OK.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_getting_haircut" , &crate::flat::PERSON_GETTING_HAIRCUT.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_getting_massage" , &crate::flat::PERSON_GETTING_MASSAGE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_golfing" , &crate::flat::PERSON_GOLFING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_holding_hands" , &crate::flat::PERSON_HOLDING_HANDS.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_hugging" , &crate::flat::PERSON_HUGGING as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_in_bed" , &crate::flat::PERSON_IN_BED.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_in_lotus_position" , &crate::flat::PERSON_IN_LOTUS_POSITION.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_in_manual_wheelchair" , &crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_in_motorized_wheelchair" , &crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_in_steamy_room" , &crate::flat::PERSON_IN_STEAMY_ROOM.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_in_suit_levitating" , &crate::flat::PERSON_IN_SUIT_LEVITATING.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_in_tuxedo" , &crate::flat::PERSON_IN_TUXEDO.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_juggling" , &crate::flat::PERSON_JUGGLING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_kneeling" , &crate::flat::PERSON_KNEELING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_lifting_weights" , &crate::flat::PERSON_LIFTING_WEIGHTS.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_mountain_biking" , &crate::flat::PERSON_MOUNTAIN_BIKING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_playing_handball" , &crate::flat::PERSON_PLAYING_HANDBALL.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_playing_water_polo" , &crate::flat::PERSON_PLAYING_WATER_POLO.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_pouting" , &crate::flat::PERSON_POUTING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_raising_hand" , &crate::flat::PERSON_RAISING_HAND.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_red_hair" , &crate::flat::PERSON.hair(Hair::Red).default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_rowing_boat" , &crate::flat::PERSON_ROWING_BOAT.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_running" , &crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_shrugging" , &crate::flat::PERSON_SHRUGGING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_standing" , &crate::flat::PERSON_STANDING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_surfing" , &crate::flat::PERSON_SURFING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_swimming" , &crate::flat::PERSON_SWIMMING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_taking_bath" , &crate::flat::PERSON_TAKING_BATH.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_tipping_hand" , &crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_walking" , &crate::flat::PERSON_WALKING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_wearing_turban" , &crate::flat::PERSON_WEARING_TURBAN.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_white_hair" , &crate::flat::PERSON.hair(Hair::White).default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_with_bunny_ears" , &crate::flat::PERSON_WITH_BUNNY_EARS.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_with_crown" , &crate::flat::PERSON_WITH_CROWN.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_with_probing_cane" , &crate::flat::PERSON_WITH_WHITE_CANE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_with_skullcap" , &crate::flat::PERSON_WITH_SKULLCAP.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_with_turban" , &crate::flat::PERSON_WEARING_TURBAN.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_with_veil" , &crate::flat::PERSON_WITH_VEIL.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_with_white_cane" , &crate::flat::PERSON_WITH_WHITE_CANE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_wrestling" , &crate::flat::PERSON_WRESTLING.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("peru" , &crate::flat::FLAG_PERU as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("petri_dish" , &crate::flat::PETRI_DISH as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("philippines" , &crate::flat::FLAG_PHILIPPINES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("phone" , &crate::flat::TELEPHONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pick" , &crate::flat::PICK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("pickup_truck" , &crate::flat::PICKUP_TRUCK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pie" , &crate::flat::PIE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("pig" , &crate::flat::PIG as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("pig2" , &crate::flat::PIG as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("pig_face" , &crate::flat::PIG_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("pig_nose" , &crate::flat::PIG_NOSE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("pile_of_poo" , &crate::flat::PILE_OF_POO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pill" , &crate::flat::PILL as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pilot" , &crate::flat::PILOT.default.default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("pinata" , &crate::flat::PINATA as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pinched_fingers" , &crate::flat::PINCHED_FINGERS.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pinching_hand" , &crate::flat::PINCHING_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("pine_decoration" , &crate::flat::PINE_DECORATION as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pineapple" , &crate::flat::PINEAPPLE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("ping_pong" , &crate::flat::PING_PONG as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("pirate_flag" , &crate::flat::PIRATE_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("pisces" , &crate::flat::PISCES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("pitcairn_islands" , &crate::flat::FLAG_PITCAIRN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pizza" , &crate::flat::PIZZA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("placard" , &crate::flat::PLACARD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("place_of_worship" , &crate::flat::PLACE_OF_WORSHIP as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("plate_with_cutlery" , &crate::flat::FORK_AND_KNIFE_WITH_PLATE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("play_button" , &crate::flat::PLAY_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("play_or_pause_button" , &crate::flat::PLAY_OR_PAUSE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("playground_slide" , &crate::flat::PLAYGROUND_SLIDE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("pleading_face" , &crate::flat::PLEADING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("plunger" , &crate::flat::PLUNGER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("plus" , &crate::flat::PLUS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("point_down" , &crate::flat::BACKHAND_INDEX_POINTING_DOWN.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("point_left" , &crate::flat::BACKHAND_INDEX_POINTING_LEFT.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("point_right" , &crate::flat::BACKHAND_INDEX_POINTING_RIGHT.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("point_up" , &crate::flat::INDEX_POINTING_UP.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("point_up_2" , &crate::flat::BACKHAND_INDEX_POINTING_UP.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("poland" , &crate::flat::FLAG_POLAND as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("polar_bear" , &crate::flat::POLAR_BEAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("police_car" , &crate::flat::POLICE_CAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("police_car_light" , &crate::flat::POLICE_CAR_LIGHT as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("police_officer" , &crate::flat::POLICE_OFFICER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("policeman" , &crate::flat::POLICE_OFFICER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("policewoman" , &crate::flat::POLICE_OFFICER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("poodle" , &crate::flat::POODLE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("pool_8_ball" , &crate::flat::POOL_8_BALL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("poop" , &crate::flat::PILE_OF_POO as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("popcorn" , &crate::flat::POPCORN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("portugal" , &crate::flat::FLAG_PORTUGAL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("post_office" , &crate::flat::POST_OFFICE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("postal_horn" , &crate::flat::POSTAL_HORN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("postbox" , &crate::flat::POSTBOX as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pot_of_food" , &crate::flat::POT_OF_FOOD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("potable_water" , &crate::flat::POTABLE_WATER as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("potato" , &crate::flat::POTATO as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("potted_plant" , &crate::flat::POTTED_PLANT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pouch" , &crate::flat::CLUTCH_BAG as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("poultry_leg" , &crate::flat::POULTRY_LEG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pound" , &crate::flat::POUND_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pound_banknote" , &crate::flat::POUND_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pouring_liquid" , &crate::flat::POURING_LIQUID as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("pout" , &crate::flat::POUTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("pouting_cat" , &crate::flat::POUTING_CAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("pouting_face" , &crate::flat::POUTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pouting_man" , &crate::flat::PERSON_POUTING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pouting_woman" , &crate::flat::PERSON_POUTING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pray" , &crate::flat::FOLDED_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("prayer_beads" , &crate::flat::PRAYER_BEADS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pregnant_man" , &crate::flat::PREGNANT_PERSON.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pregnant_person" , &crate::flat::PREGNANT_PERSON.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pregnant_woman" , &crate::flat::PREGNANT_PERSON.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pretzel" , &crate::flat::PRETZEL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("previous_track_button" , &crate::flat::LAST_TRACK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("prince" , &crate::flat::PRINCE.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("princess" , &crate::flat::PRINCESS.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("printer" , &crate::flat::PRINTER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("probing_cane" , &crate::flat::WHITE_CANE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("prohibited" , &crate::flat::PROHIBITED as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("puerto_rico" , &crate::flat::FLAG_PUERTO_RICO as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("punch" , &crate::flat::ONCOMING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("purple_circle" , &crate::flat::PURPLE_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("purple_heart" , &crate::flat::PURPLE_HEART as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("purple_square" , &crate::flat::PURPLE_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("purse" , &crate::flat::PURSE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pushpin" , &crate::flat::PUSHPIN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("put_litter_in_its_place" , &crate::flat::LITTER_IN_BIN_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("puzzle_piece" , &crate::flat::PUZZLE_PIECE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("qatar" , &crate::flat::FLAG_QATAR as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("question" , &crate::flat::RED_QUESTION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rabbit" , &crate::flat::RABBIT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rabbit2" , &crate::flat::RABBIT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rabbit_face" , &crate::flat::RABBIT_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("raccoon" , &crate::flat::RACCOON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("racehorse" , &crate::flat::HORSE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("racing_car" , &crate::flat::RACING_CAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("radio" , &crate::flat::RADIO as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("radio_button" , &crate::flat::RADIO_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("radioactive" , &crate::flat::RADIOACTIVE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("rage" , &crate::flat::POUTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("railway_car" , &crate::flat::RAILWAY_CAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("railway_track" , &crate::flat::RAILWAY_TRACK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("rainbow" , &crate::flat::RAINBOW as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("rainbow_flag" , &crate::flat::RAINBOW_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raised_back_of_hand" , &crate::flat::RAISED_BACK_OF_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("raised_eyebrow" , &crate::flat::FACE_WITH_RAISED_EYEBROW as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raised_fist" , &crate::flat::RAISED_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raised_hand" , &crate::flat::RAISED_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raised_hand_with_fingers_splayed" , &crate::flat::HAND_WITH_FINGERS_SPLAYED.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raised_hands" , &crate::flat::RAISING_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raising_hand" , &crate::flat::PERSON_RAISING_HAND.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raising_hand_man" , &crate::flat::PERSON_RAISING_HAND.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raising_hand_woman" , &crate::flat::PERSON_RAISING_HAND.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raising_hands" , &crate::flat::RAISING_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("ram" , &crate::flat::RAM as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("ramen" , &crate::flat::STEAMING_BOWL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rat" , &crate::flat::RAT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("razor" , &crate::flat::RAZOR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("receipt" , &crate::flat::RECEIPT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("record_button" , &crate::flat::RECORD_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("recycle" , &crate::flat::RECYCLING_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("recycling_symbol" , &crate::flat::RECYCLING_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("red_apple" , &crate::flat::RED_APPLE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("red_car" , &crate::flat::AUTOMOBILE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("red_circle" , &crate::flat::RED_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("red_envelope" , &crate::flat::RED_ENVELOPE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("red_exclamation_mark" , &crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("red_hair" , &crate::flat::RED_HAIR as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("red_haired_man" , &crate::flat::PERSON.hair(Hair::Red).gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("red_haired_woman" , &crate::flat::PERSON.hair(Hair::Red).gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("red_heart" , &crate::flat::RED_HEART as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("red_paper_lantern" , &crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("red_question_mark" , &crate::flat::RED_QUESTION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("red_square" , &crate::flat::RED_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("red_triangle_pointed_down" , &crate::flat::RED_TRIANGLE_POINTED_DOWN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("red_triangle_pointed_up" , &crate::flat::RED_TRIANGLE_POINTED_UP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("registered" , &crate::flat::REGISTERED as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("relaxed" , &crate::flat::SMILING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("relieved" , &crate::flat::RELIEVED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("relieved_face" , &crate::flat::RELIEVED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("reminder_ribbon" , &crate::flat::REMINDER_RIBBON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("repeat" , &crate::flat::REPEAT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("repeat_button" , &crate::flat::REPEAT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("repeat_one" , &crate::flat::REPEAT_SINGLE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("repeat_single_button" , &crate::flat::REPEAT_SINGLE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("rescue_worker_helmet" , &crate::flat::RESCUE_WORKER_S_HELMET as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("rescue_worker_s_helmet" , &crate::flat::RESCUE_WORKER_S_HELMET as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("restroom" , &crate::flat::RESTROOM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("reunion" , &crate::flat::FLAG_REUNION as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("reverse_button" , &crate::flat::REVERSE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("revolving_hearts" , &crate::flat::REVOLVING_HEARTS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("rewind" , &crate::flat::FAST_REVERSE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rhinoceros" , &crate::flat::RHINOCEROS as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("ribbon" , &crate::flat::RIBBON as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("rice" , &crate::flat::COOKED_RICE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("rice_ball" , &crate::flat::RICE_BALL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("rice_cracker" , &crate::flat::RICE_CRACKER as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("rice_scene" , &crate::flat::MOON_VIEWING_CEREMONY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("right_anger_bubble" , &crate::flat::RIGHT_ANGER_BUBBLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("right_arrow" , &crate::flat::RIGHT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("right_arrow_curving_down" , &crate::flat::RIGHT_ARROW_CURVING_DOWN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("right_arrow_curving_left" , &crate::flat::RIGHT_ARROW_CURVING_LEFT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("right_arrow_curving_up" , &crate::flat::RIGHT_ARROW_CURVING_UP as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("right_facing_fist" , &crate::flat::RIGHT_FACING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("rightwards_hand" , &crate::flat::RIGHTWARDS_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("ring" , &crate::flat::RING as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ring_buoy" , &crate::flat::RING_BUOY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ringed_planet" , &crate::flat::RINGED_PLANET as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("roasted_sweet_potato" , &crate::flat::ROASTED_SWEET_POTATO as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("robot" , &crate::flat::ROBOT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("rock" , &crate::flat::ROCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("rocket" , &crate::flat::ROCKET as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("rofl" , &crate::flat::ROLLING_ON_THE_FLOOR_LAUGHING as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("roll_eyes" , &crate::flat::FACE_WITH_ROLLING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("roll_of_paper" , &crate::flat::ROLL_OF_PAPER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("rolled_up_newspaper" , &crate::flat::ROLLED_UP_NEWSPAPER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("roller_coaster" , &crate::flat::ROLLER_COASTER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("roller_skate" , &crate::flat::ROLLER_SKATE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("rolling_on_the_floor_laughing" , &crate::flat::ROLLING_ON_THE_FLOOR_LAUGHING as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("romania" , &crate::flat::FLAG_ROMANIA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rooster" , &crate::flat::ROOSTER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rose" , &crate::flat::ROSE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rosette" , &crate::flat::ROSETTE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("rotating_light" , &crate::flat::POLICE_CAR_LIGHT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("round_pushpin" , &crate::flat::ROUND_PUSHPIN as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("rowboat" , &crate::flat::PERSON_ROWING_BOAT.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("rowing_man" , &crate::flat::PERSON_ROWING_BOAT.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("rowing_woman" , &crate::flat::PERSON_ROWING_BOAT.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ru" , &crate::flat::FLAG_RUSSIA as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("rugby_football" , &crate::flat::RUGBY_FOOTBALL as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("runner" , &crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("running" , &crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("running_man" , &crate::flat::PERSON_RUNNING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("running_shirt" , &crate::flat::RUNNING_SHIRT as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("running_shirt_with_sash" , &crate::flat::RUNNING_SHIRT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("running_shoe" , &crate::flat::RUNNING_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("running_woman" , &crate::flat::PERSON_RUNNING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("rwanda" , &crate::flat::FLAG_RWANDA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("sa" , &crate::flat::JAPANESE_SERVICE_CHARGE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sad_but_relieved_face" , &crate::flat::SAD_BUT_RELIEVED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("safety_pin" , &crate::flat::SAFETY_PIN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("safety_vest" , &crate::flat::SAFETY_VEST as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("sagittarius" , &crate::flat::SAGITTARIUS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sailboat" , &crate::flat::SAILBOAT as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("sake" , &crate::flat::SAKE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("salt" , &crate::flat::SALT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("saluting_face" , &crate::flat::SALUTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("samoa" , &crate::flat::FLAG_SAMOA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("san_marino" , &crate::flat::FLAG_SAN_MARINO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("sandal" , &crate::flat::WOMAN_S_SANDAL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("sandwich" , &crate::flat::SANDWICH as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("santa" , &crate::flat::SANTA_CLAUS.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("santa_claus" , &crate::flat::SANTA_CLAUS.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("sao_tome_principe" , &crate::flat::FLAG_SAO_TOME_AND_PRINCIPE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("sari" , &crate::flat::SARI as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("sassy_man" , &crate::flat::PERSON_TIPPING_HAND.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("sassy_woman" , &crate::flat::PERSON_TIPPING_HAND.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("satellite" , &crate::flat::SATELLITE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("satellite_antenna" , &crate::flat::SATELLITE_ANTENNA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("satisfied" , &crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("saudi_arabia" , &crate::flat::FLAG_SAUDI_ARABIA as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("sauna_man" , &crate::flat::PERSON_IN_STEAMY_ROOM.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("sauna_person" , &crate::flat::PERSON_IN_STEAMY_ROOM.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("sauna_woman" , &crate::flat::PERSON_IN_STEAMY_ROOM.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("sauropod" , &crate::flat::SAUROPOD as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("saxophone" , &crate::flat::SAXOPHONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("scarf" , &crate::flat::SCARF as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("school" , &crate::flat::SCHOOL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("school_satchel" , &crate::flat::BACKPACK as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("scientist" , &crate::flat::SCIENTIST.default.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("scissors" , &crate::flat::SCISSORS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("scorpio" , &crate::flat::SCORPIO as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("scorpion" , &crate::flat::SCORPION as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("scorpius" , &crate::flat::SCORPIO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("scotland" , &crate::flat::FLAG_SCOTLAND as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("scream" , &crate::flat::FACE_SCREAMING_IN_FEAR as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("scream_cat" , &crate::flat::WEARY_CAT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("screwdriver" , &crate::flat::SCREWDRIVER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("scroll" , &crate::flat::SCROLL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("seal" , &crate::flat::SEAL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("seat" , &crate::flat::SEAT as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("second_place_medal" , &crate::flat::SECOND_PLACE_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("secret" , &crate::flat::JAPANESE_SECRET_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("see_no_evil" , &crate::flat::SEE_NO_EVIL_MONKEY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("see_no_evil_monkey" , &crate::flat::SEE_NO_EVIL_MONKEY as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("seedling" , &crate::flat::SEEDLING as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("selfie" , &crate::flat::SELFIE.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("senegal" , &crate::flat::FLAG_SENEGAL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("serbia" , &crate::flat::FLAG_SERBIA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("service_dog" , &crate::flat::SERVICE_DOG as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("seven" , &crate::flat::KEYCAP_7 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("seven_o_clock" , &crate::flat::SEVEN_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("seven_thirty" , &crate::flat::SEVEN_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("sewing_needle" , &crate::flat::SEWING_NEEDLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("seychelles" , &crate::flat::FLAG_SEYCHELLES as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("shallow_pan_of_food" , &crate::flat::SHALLOW_PAN_OF_FOOD as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("shamrock" , &crate::flat::SHAMROCK as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("shark" , &crate::flat::SHARK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("shaved_ice" , &crate::flat::SHAVED_ICE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("sheaf_of_rice" , &crate::flat::SHEAF_OF_RICE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("sheep" , &crate::flat::EWE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("shell" , &crate::flat::SPIRAL_SHELL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shield" , &crate::flat::SHIELD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("shinto_shrine" , &crate::flat::SHINTO_SHRINE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ship" , &crate::flat::SHIP as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shirt" , &crate::flat::T_SHIRT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("shit" , &crate::flat::PILE_OF_POO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shoe" , &crate::flat::MAN_S_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("shooting_star" , &crate::flat::SHOOTING_STAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shopping" , &crate::flat::SHOPPING_BAGS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shopping_bags" , &crate::flat::SHOPPING_BAGS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shopping_cart" , &crate::flat::SHOPPING_CART as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("shortcake" , &crate::flat::SHORTCAKE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shorts" , &crate::flat::SHORTS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shower" , &crate::flat::SHOWER as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("shrimp" , &crate::flat::SHRIMP as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("shrug" , &crate::flat::PERSON_SHRUGGING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("shuffle_tracks_button" , &crate::flat::SHUFFLE_TRACKS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("shushing_face" , &crate::flat::SHUSHING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("sierra_leone" , &crate::flat::FLAG_SIERRA_LEONE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("sign_of_the_horns" , &crate::flat::SIGN_OF_THE_HORNS.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("signal_strength" , &crate::flat::ANTENNA_BARS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("singapore" , &crate::flat::FLAG_SINGAPORE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("singer" , &crate::flat::SINGER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("sint_maarten" , &crate::flat::FLAG_SINT_MAARTEN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("six" , &crate::flat::KEYCAP_6 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("six_o_clock" , &crate::flat::SIX_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("six_pointed_star" , &crate::flat::DOTTED_SIX_POINTED_STAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("six_thirty" , &crate::flat::SIX_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("skateboard" , &crate::flat::SKATEBOARD as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("ski" , &crate::flat::SKIS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("skier" , &crate::flat::SKIER as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("skis" , &crate::flat::SKIS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("skull" , &crate::flat::SKULL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("skull_and_crossbones" , &crate::flat::SKULL_AND_CROSSBONES as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("skunk" , &crate::flat::SKUNK as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("sled" , &crate::flat::SLED as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sleeping" , &crate::flat::SLEEPING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("sleeping_bed" , &crate::flat::PERSON_IN_BED.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sleeping_face" , &crate::flat::SLEEPING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sleepy" , &crate::flat::SLEEPY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sleepy_face" , &crate::flat::SLEEPY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("slightly_frowning_face" , &crate::flat::SLIGHTLY_FROWNING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("slightly_smiling_face" , &crate::flat::SLIGHTLY_SMILING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("slot_machine" , &crate::flat::SLOT_MACHINE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("sloth" , &crate::flat::SLOTH as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("slovakia" , &crate::flat::FLAG_SLOVAKIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("slovenia" , &crate::flat::FLAG_SLOVENIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("small_airplane" , &crate::flat::SMALL_AIRPLANE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("small_blue_diamond" , &crate::flat::SMALL_BLUE_DIAMOND as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("small_orange_diamond" , &crate::flat::SMALL_ORANGE_DIAMOND as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("small_red_triangle" , &crate::flat::RED_TRIANGLE_POINTED_UP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("small_red_triangle_down" , &crate::flat::RED_TRIANGLE_POINTED_DOWN as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smile" , &crate::flat::GRINNING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smile_cat" , &crate::flat::GRINNING_CAT_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiley" , &crate::flat::GRINNING_FACE_WITH_BIG_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiley_cat" , &crate::flat::GRINNING_CAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_cat_with_heart_eyes" , &crate::flat::SMILING_CAT_WITH_HEART_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face" , &crate::flat::SMILING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_halo" , &crate::flat::SMILING_FACE_WITH_HALO as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_heart_eyes" , &crate::flat::SMILING_FACE_WITH_HEART_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_hearts" , &crate::flat::SMILING_FACE_WITH_HEARTS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_horns" , &crate::flat::SMILING_FACE_WITH_HORNS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_smiling_eyes" , &crate::flat::SMILING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_sunglasses" , &crate::flat::SMILING_FACE_WITH_SUNGLASSES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_tear" , &crate::flat::SMILING_FACE_WITH_TEAR as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_three_hearts" , &crate::flat::SMILING_FACE_WITH_HEARTS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_imp" , &crate::flat::SMILING_FACE_WITH_HORNS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smirk" , &crate::flat::SMIRKING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smirk_cat" , &crate::flat::CAT_WITH_WRY_SMILE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smirking_face" , &crate::flat::SMIRKING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("smoking" , &crate::flat::CIGARETTE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("snail" , &crate::flat::SNAIL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("snake" , &crate::flat::SNAKE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sneezing_face" , &crate::flat::SNEEZING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("snow_capped_mountain" , &crate::flat::SNOW_CAPPED_MOUNTAIN as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("snowboarder" , &crate::flat::SNOWBOARDER.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("snowflake" , &crate::flat::SNOWFLAKE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("snowman" , &crate::flat::SNOWMAN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("snowman_with_snow" , &crate::flat::SNOWMAN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("snowman_without_snow" , &crate::flat::SNOWMAN_WITHOUT_SNOW as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("soap" , &crate::flat::SOAP as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sob" , &crate::flat::LOUDLY_CRYING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("soccer" , &crate::flat::SOCCER_BALL as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("soccer_ball" , &crate::flat::SOCCER_BALL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("socks" , &crate::flat::SOCKS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("soft_ice_cream" , &crate::flat::SOFT_ICE_CREAM as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("softball" , &crate::flat::SOFTBALL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("solomon_islands" , &crate::flat::FLAG_SOLOMON_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("somalia" , &crate::flat::FLAG_SOMALIA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("soon" , &crate::flat::SOON_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("soon_arrow" , &crate::flat::SOON_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("sos" , &crate::flat::SOS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("sos_button" , &crate::flat::SOS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("sound" , &crate::flat::SPEAKER_MEDIUM_VOLUME as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("south_africa" , &crate::flat::FLAG_SOUTH_AFRICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("south_georgia_south_sandwich_islands" , &crate::flat::FLAG_SOUTH_GEORGIA_AND_SOUTH_SANDWICH_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("south_sudan" , &crate::flat::FLAG_SOUTH_SUDAN as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("space_invader" , &crate::flat::ALIEN_MONSTER as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("spade_suit" , &crate::flat::SPADE_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("spades" , &crate::flat::SPADE_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("spaghetti" , &crate::flat::SPAGHETTI as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("sparkle" , &crate::flat::SPARKLE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("sparkler" , &crate::flat::SPARKLER as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("sparkles" , &crate::flat::SPARKLES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sparkling_heart" , &crate::flat::SPARKLING_HEART as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("speak_no_evil" , &crate::flat::SPEAK_NO_EVIL_MONKEY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("speak_no_evil_monkey" , &crate::flat::SPEAK_NO_EVIL_MONKEY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("speaker" , &crate::flat::SPEAKER_LOW_VOLUME as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("speaker_high_volume" , &crate::flat::SPEAKER_HIGH_VOLUME as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("speaker_low_volume" , &crate::flat::SPEAKER_LOW_VOLUME as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("speaker_medium_volume" , &crate::flat::SPEAKER_MEDIUM_VOLUME as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("speaking_head" , &crate::flat::SPEAKING_HEAD as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("speech_balloon" , &crate::flat::SPEECH_BALLOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("speedboat" , &crate::flat::SPEEDBOAT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("spider" , &crate::flat::SPIDER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("spider_web" , &crate::flat::SPIDER_WEB as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("spiral_calendar" , &crate::flat::SPIRAL_CALENDAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("spiral_notepad" , &crate::flat::SPIRAL_NOTEPAD as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("spiral_shell" , &crate::flat::SPIRAL_SHELL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("sponge" , &crate::flat::SPONGE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("spoon" , &crate::flat::SPOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sport_utility_vehicle" , &crate::flat::SPORT_UTILITY_VEHICLE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("sports_medal" , &crate::flat::SPORTS_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("spouting_whale" , &crate::flat::SPOUTING_WHALE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("squid" , &crate::flat::SQUID as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("squinting_face_with_tongue" , &crate::flat::SQUINTING_FACE_WITH_TONGUE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("sri_lanka" , &crate::flat::FLAG_SRI_LANKA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("st_barthelemy" , &crate::flat::FLAG_ST_BARTHELEMY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("st_helena" , &crate::flat::FLAG_ST_HELENA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("st_kitts_nevis" , &crate::flat::FLAG_ST_KITTS_AND_NEVIS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("st_lucia" , &crate::flat::FLAG_ST_LUCIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("st_martin" , &crate::flat::FLAG_ST_MARTIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("st_pierre_miquelon" , &crate::flat::FLAG_ST_PIERRE_AND_MIQUELON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("st_vincent_grenadines" , &crate::flat::FLAG_ST_VINCENT_AND_GRENADINES as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("stadium" , &crate::flat::STADIUM as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("standing_man" , &crate::flat::PERSON_STANDING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("standing_person" , &crate::flat::PERSON_STANDING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("standing_woman" , &crate::flat::PERSON_STANDING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("star" , &crate::flat::STAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("star2" , &crate::flat::GLOWING_STAR as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("star_and_crescent" , &crate::flat::STAR_AND_CRESCENT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("star_of_david" , &crate::flat::STAR_OF_DAVID as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("star_struck" , &crate::flat::STAR_STRUCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("stars" , &crate::flat::SHOOTING_STAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("station" , &crate::flat::STATION as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("statue_of_liberty" , &crate::flat::STATUE_OF_LIBERTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("steam_locomotive" , &crate::flat::LOCOMOTIVE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("steaming_bowl" , &crate::flat::STEAMING_BOWL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("stethoscope" , &crate::flat::STETHOSCOPE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("stew" , &crate::flat::POT_OF_FOOD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("stop_button" , &crate::flat::STOP_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("stop_sign" , &crate::flat::STOP_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("stopwatch" , &crate::flat::STOPWATCH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("straight_ruler" , &crate::flat::STRAIGHT_RULER as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("strawberry" , &crate::flat::STRAWBERRY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("stuck_out_tongue" , &crate::flat::FACE_WITH_TONGUE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("stuck_out_tongue_closed_eyes" , &crate::flat::SQUINTING_FACE_WITH_TONGUE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("stuck_out_tongue_winking_eye" , &crate::flat::WINKING_FACE_WITH_TONGUE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("student" , &crate::flat::STUDENT.default.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("studio_microphone" , &crate::flat::STUDIO_MICROPHONE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("stuffed_flatbread" , &crate::flat::STUFFED_FLATBREAD as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("sudan" , &crate::flat::FLAG_SUDAN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sun" , &crate::flat::SUN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sun_behind_cloud" , &crate::flat::SUN_BEHIND_CLOUD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sun_behind_large_cloud" , &crate::flat::SUN_BEHIND_LARGE_CLOUD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sun_behind_rain_cloud" , &crate::flat::SUN_BEHIND_RAIN_CLOUD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sun_behind_small_cloud" , &crate::flat::SUN_BEHIND_SMALL_CLOUD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sun_with_face" , &crate::flat::SUN_WITH_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("sunflower" , &crate::flat::SUNFLOWER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("sunglasses" , &crate::flat::SUNGLASSES as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sunny" , &crate::flat::SUN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sunrise" , &crate::flat::SUNRISE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sunrise_over_mountains" , &crate::flat::SUNRISE_OVER_MOUNTAINS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sunset" , &crate::flat::SUNSET as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("superhero" , &crate::flat::SUPERHERO.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("superhero_man" , &crate::flat::SUPERHERO.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("superhero_woman" , &crate::flat::SUPERHERO.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("supervillain" , &crate::flat::SUPERVILLAIN.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("supervillain_man" , &crate::flat::SUPERVILLAIN.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("supervillain_woman" , &crate::flat::SUPERVILLAIN.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("surfer" , &crate::flat::PERSON_SURFING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("surfing_man" , &crate::flat::PERSON_SURFING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("surfing_woman" , &crate::flat::PERSON_SURFING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("suriname" , &crate::flat::FLAG_SURINAME as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("sushi" , &crate::flat::SUSHI as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("suspension_railway" , &crate::flat::SUSPENSION_RAILWAY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("svalbard_jan_mayen" , &crate::flat::FLAG_SVALBARD_AND_JAN_MAYEN as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("swan" , &crate::flat::SWAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("swaziland" , &crate::flat::FLAG_ESWATINI as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sweat" , &crate::flat::DOWNCAST_FACE_WITH_SWEAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sweat_droplets" , &crate::flat::SWEAT_DROPLETS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sweat_drops" , &crate::flat::SWEAT_DROPLETS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sweat_smile" , &crate::flat::GRINNING_FACE_WITH_SWEAT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("sweden" , &crate::flat::FLAG_SWEDEN as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("sweet_potato" , &crate::flat::ROASTED_SWEET_POTATO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("swim_brief" , &crate::flat::BRIEFS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("swimmer" , &crate::flat::PERSON_SWIMMING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("swimming_man" , &crate::flat::PERSON_SWIMMING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("swimming_woman" , &crate::flat::PERSON_SWIMMING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("switzerland" , &crate::flat::FLAG_SWITZERLAND as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("symbols" , &crate::flat::INPUT_SYMBOLS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("synagogue" , &crate::flat::SYNAGOGUE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("syria" , &crate::flat::FLAG_SYRIA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("syringe" , &crate::flat::SYRINGE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("t-rex" , &crate::flat::T_REX as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("t_rex" , &crate::flat::T_REX as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("t_shirt" , &crate::flat::T_SHIRT as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("taco" , &crate::flat::TACO as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("tada" , &crate::flat::PARTY_POPPER as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("taiwan" , &crate::flat::FLAG_TAIWAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tajikistan" , &crate::flat::FLAG_TAJIKISTAN as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("takeout_box" , &crate::flat::TAKEOUT_BOX as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("tamale" , &crate::flat::TAMALE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("tanabata_tree" , &crate::flat::TANABATA_TREE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("tangerine" , &crate::flat::TANGERINE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tanzania" , &crate::flat::FLAG_TANZANIA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("taurus" , &crate::flat::TAURUS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("taxi" , &crate::flat::TAXI as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("tea" , &crate::flat::TEACUP_WITHOUT_HANDLE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("teacher" , &crate::flat::TEACHER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("teacup_without_handle" , &crate::flat::TEACUP_WITHOUT_HANDLE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("teapot" , &crate::flat::TEAPOT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("tear_off_calendar" , &crate::flat::TEAR_OFF_CALENDAR as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("technologist" , &crate::flat::TECHNOLOGIST.default.default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("teddy_bear" , &crate::flat::TEDDY_BEAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("telephone" , &crate::flat::TELEPHONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("telephone_receiver" , &crate::flat::TELEPHONE_RECEIVER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("telescope" , &crate::flat::TELESCOPE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("television" , &crate::flat::TELEVISION as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ten_o_clock" , &crate::flat::TEN_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ten_thirty" , &crate::flat::TEN_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("tennis" , &crate::flat::TENNIS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("tent" , &crate::flat::TENT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("test_tube" , &crate::flat::TEST_TUBE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("thailand" , &crate::flat::FLAG_THAILAND as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("thermometer" , &crate::flat::THERMOMETER as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("thinking" , &crate::flat::THINKING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("thinking_face" , &crate::flat::THINKING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("third_place_medal" , &crate::flat::THIRD_PLACE_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("thong_sandal" , &crate::flat::THONG_SANDAL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("thought_balloon" , &crate::flat::THOUGHT_BALLOON as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("thread" , &crate::flat::THREAD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("three" , &crate::flat::KEYCAP_3 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("three_o_clock" , &crate::flat::THREE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("three_thirty" , &crate::flat::THREE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("thumbs_down" , &crate::flat::THUMBS_DOWN.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("thumbs_up" , &crate::flat::THUMBS_UP.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("thumbsdown" , &crate::flat::THUMBS_DOWN.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("thumbsup" , &crate::flat::THUMBS_UP.default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("ticket" , &crate::flat::TICKET as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("tickets" , &crate::flat::ADMISSION_TICKETS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("tiger" , &crate::flat::TIGER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("tiger2" , &crate::flat::TIGER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("tiger_face" , &crate::flat::TIGER_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("timer_clock" , &crate::flat::TIMER_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("timor_leste" , &crate::flat::FLAG_TIMOR_LESTE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("tipping_hand_man" , &crate::flat::PERSON_TIPPING_HAND.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("tipping_hand_person" , &crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("tipping_hand_woman" , &crate::flat::PERSON_TIPPING_HAND.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("tired_face" , &crate::flat::TIRED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("tm" , &crate::flat::TRADE_MARK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("togo" , &crate::flat::FLAG_TOGO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("toilet" , &crate::flat::TOILET as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tokelau" , &crate::flat::FLAG_TOKELAU as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("tokyo_tower" , &crate::flat::TOKYO_TOWER as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("tomato" , &crate::flat::TOMATO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tonga" , &crate::flat::FLAG_TONGA as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("tongue" , &crate::flat::TONGUE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("toolbox" , &crate::flat::TOOLBOX as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("tooth" , &crate::flat::TOOTH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("toothbrush" , &crate::flat::TOOTHBRUSH as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("top" , &crate::flat::TOP_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("top_arrow" , &crate::flat::TOP_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("top_hat" , &crate::flat::TOP_HAT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("tophat" , &crate::flat::TOP_HAT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("tornado" , &crate::flat::TORNADO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tr" , &crate::flat::FLAG_TURKEY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("trackball" , &crate::flat::TRACKBALL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("tractor" , &crate::flat::TRACTOR as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("trade_mark" , &crate::flat::TRADE_MARK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("traffic_light" , &crate::flat::HORIZONTAL_TRAFFIC_LIGHT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("train" , &crate::flat::TRAIN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("train2" , &crate::flat::TRAIN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("tram" , &crate::flat::TRAM as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("tram_car" , &crate::flat::TRAM_CAR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("transgender_flag" , &crate::flat::TRANSGENDER_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("transgender_symbol" , &crate::flat::TRANSGENDER_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("triangular_flag" , &crate::flat::TRIANGULAR_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("triangular_flag_on_post" , &crate::flat::TRIANGULAR_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("triangular_ruler" , &crate::flat::TRIANGULAR_RULER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("trident" , &crate::flat::TRIDENT_EMBLEM as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("trident_emblem" , &crate::flat::TRIDENT_EMBLEM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("trinidad_tobago" , &crate::flat::FLAG_TRINIDAD_AND_TOBAGO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tristan_da_cunha" , &crate::flat::FLAG_TRISTAN_DA_CUNHA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("triumph" , &crate::flat::FACE_WITH_STEAM_FROM_NOSE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("troll" , &crate::flat::TROLL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("trolleybus" , &crate::flat::TROLLEYBUS as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("trophy" , &crate::flat::TROPHY as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("tropical_drink" , &crate::flat::TROPICAL_DRINK as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("tropical_fish" , &crate::flat::TROPICAL_FISH as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("truck" , &crate::flat::DELIVERY_TRUCK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("trumpet" , &crate::flat::TRUMPET as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("tshirt" , &crate::flat::T_SHIRT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("tulip" , &crate::flat::TULIP as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("tumbler_glass" , &crate::flat::TUMBLER_GLASS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tunisia" , &crate::flat::FLAG_TUNISIA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("turkey" , &crate::flat::TURKEY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("turkmenistan" , &crate::flat::FLAG_TURKMENISTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("turks_caicos_islands" , &crate::flat::FLAG_TURKS_AND_CAICOS_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("turtle" , &crate::flat::TURTLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tuvalu" , &crate::flat::FLAG_TUVALU as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("tv" , &crate::flat::TELEVISION as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("twelve_o_clock" , &crate::flat::TWELVE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("twelve_thirty" , &crate::flat::TWELVE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("twisted_rightwards_arrows" , &crate::flat::SHUFFLE_TRACKS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("two" , &crate::flat::KEYCAP_2 as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("two_hearts" , &crate::flat::TWO_HEARTS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("two_hump_camel" , &crate::flat::TWO_HUMP_CAMEL as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("two_men_holding_hands" , &crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Males).default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("two_o_clock" , &crate::flat::TWO_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("two_thirty" , &crate::flat::TWO_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("two_women_holding_hands" , &crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Females).default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u5272" , &crate::flat::JAPANESE_DISCOUNT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u5408" , &crate::flat::JAPANESE_PASSING_GRADE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u55b6" , &crate::flat::JAPANESE_OPEN_FOR_BUSINESS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u6307" , &crate::flat::JAPANESE_RESERVED_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u6708" , &crate::flat::JAPANESE_MONTHLY_AMOUNT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u6709" , &crate::flat::JAPANESE_NOT_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u6e80" , &crate::flat::JAPANESE_NO_VACANCY_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u7121" , &crate::flat::JAPANESE_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u7533" , &crate::flat::JAPANESE_APPLICATION_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u7981" , &crate::flat::JAPANESE_PROHIBITED_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u7a7a" , &crate::flat::JAPANESE_VACANCY_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("uganda" , &crate::flat::FLAG_UGANDA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("uk" , &crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ukraine" , &crate::flat::FLAG_UKRAINE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("umbrella" , &crate::flat::UMBRELLA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("umbrella_on_ground" , &crate::flat::UMBRELLA_ON_GROUND as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("umbrella_with_rain_drops" , &crate::flat::UMBRELLA_WITH_RAIN_DROPS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("unamused" , &crate::flat::UNAMUSED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("unamused_face" , &crate::flat::UNAMUSED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("underage" , &crate::flat::NO_ONE_UNDER_EIGHTEEN as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("unicorn" , &crate::flat::UNICORN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("united_arab_emirates" , &crate::flat::FLAG_UNITED_ARAB_EMIRATES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("united_nations" , &crate::flat::FLAG_UNITED_NATIONS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("unlock" , &crate::flat::UNLOCKED as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("unlocked" , &crate::flat::UNLOCKED as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("up" , &crate::flat::UP_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("up_arrow" , &crate::flat::UP_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("up_button" , &crate::flat::UP_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("up_down_arrow" , &crate::flat::UP_DOWN_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("up_left_arrow" , &crate::flat::UP_LEFT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("up_right_arrow" , &crate::flat::UP_RIGHT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("upside_down_face" , &crate::flat::UPSIDE_DOWN_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("upwards_button" , &crate::flat::UPWARDS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("uruguay" , &crate::flat::FLAG_URUGUAY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("us" , &crate::flat::FLAG_UNITED_STATES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("us_outlying_islands" , &crate::flat::FLAG_US_OUTLYING_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("us_virgin_islands" , &crate::flat::FLAG_US_VIRGIN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("uzbekistan" , &crate::flat::FLAG_UZBEKISTAN as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("v" , &crate::flat::VICTORY_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("vampire" , &crate::flat::VAMPIRE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("vampire_man" , &crate::flat::VAMPIRE.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("vampire_woman" , &crate::flat::VAMPIRE.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("vanuatu" , &crate::flat::FLAG_VANUATU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("vatican_city" , &crate::flat::FLAG_VATICAN_CITY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("venezuela" , &crate::flat::FLAG_VENEZUELA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("vertical_traffic_light" , &crate::flat::VERTICAL_TRAFFIC_LIGHT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("vhs" , &crate::flat::VIDEOCASSETTE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("vibration_mode" , &crate::flat::VIBRATION_MODE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("victory_hand" , &crate::flat::VICTORY_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("video_camera" , &crate::flat::VIDEO_CAMERA as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("video_game" , &crate::flat::VIDEO_GAME as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("videocassette" , &crate::flat::VIDEOCASSETTE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("vietnam" , &crate::flat::FLAG_VIETNAM as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("violin" , &crate::flat::VIOLIN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("virgo" , &crate::flat::VIRGO as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("volcano" , &crate::flat::VOLCANO as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("volleyball" , &crate::flat::VOLLEYBALL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("vomiting_face" , &crate::flat::FACE_VOMITING as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("vs" , &crate::flat::VS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("vs_button" , &crate::flat::VS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("vulcan_salute" , &crate::flat::VULCAN_SALUTE.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("waffle" , &crate::flat::WAFFLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("wales" , &crate::flat::FLAG_WALES as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("walking" , &crate::flat::PERSON_WALKING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("walking_man" , &crate::flat::PERSON_WALKING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("walking_woman" , &crate::flat::PERSON_WALKING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("wallis_futuna" , &crate::flat::FLAG_WALLIS_AND_FUTUNA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("waning_crescent_moon" , &crate::flat::WANING_CRESCENT_MOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("waning_gibbous_moon" , &crate::flat::WANING_GIBBOUS_MOON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("warning" , &crate::flat::WARNING as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("wastebasket" , &crate::flat::WASTEBASKET as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("watch" , &crate::flat::WATCH as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("water_buffalo" , &crate::flat::WATER_BUFFALO as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("water_closet" , &crate::flat::WATER_CLOSET as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("water_pistol" , &crate::flat::WATER_PISTOL as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("water_polo" , &crate::flat::PERSON_PLAYING_WATER_POLO.default.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("water_wave" , &crate::flat::WATER_WAVE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("watermelon" , &crate::flat::WATERMELON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("wave" , &crate::flat::WAVING_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("waving_hand" , &crate::flat::WAVING_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("wavy_dash" , &crate::flat::WAVY_DASH as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("waxing_crescent_moon" , &crate::flat::WAXING_CRESCENT_MOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("waxing_gibbous_moon" , &crate::flat::WAXING_GIBBOUS_MOON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("wc" , &crate::flat::WATER_CLOSET as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("weary" , &crate::flat::WEARY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("weary_cat" , &crate::flat::WEARY_CAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("weary_face" , &crate::flat::WEARY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("wedding" , &crate::flat::WEDDING as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("weight_lifting" , &crate::flat::PERSON_LIFTING_WEIGHTS.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("weight_lifting_man" , &crate::flat::PERSON_LIFTING_WEIGHTS.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("weight_lifting_woman" , &crate::flat::PERSON_LIFTING_WEIGHTS.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("western_sahara" , &crate::flat::FLAG_WESTERN_SAHARA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("whale" , &crate::flat::WHALE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("whale2" , &crate::flat::WHALE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("wheel" , &crate::flat::WHEEL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("wheel_of_dharma" , &crate::flat::WHEEL_OF_DHARMA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("wheelchair" , &crate::flat::WHEELCHAIR_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("wheelchair_symbol" , &crate::flat::WHEELCHAIR_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("white_cane" , &crate::flat::WHITE_CANE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_check_mark" , &crate::flat::CHECK_MARK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_circle" , &crate::flat::WHITE_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_exclamation_mark" , &crate::flat::WHITE_EXCLAMATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("white_flag" , &crate::flat::WHITE_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("white_flower" , &crate::flat::WHITE_FLOWER as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("white_hair" , &crate::flat::WHITE_HAIR as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("white_haired_man" , &crate::flat::PERSON.hair(Hair::White).gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("white_haired_woman" , &crate::flat::PERSON.hair(Hair::White).gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("white_heart" , &crate::flat::WHITE_HEART as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_large_square" , &crate::flat::WHITE_LARGE_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_medium_small_square" , &crate::flat::WHITE_MEDIUM_SMALL_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_medium_square" , &crate::flat::WHITE_MEDIUM_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_question_mark" , &crate::flat::WHITE_QUESTION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_small_square" , &crate::flat::WHITE_SMALL_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_square_button" , &crate::flat::WHITE_SQUARE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("wilted_flower" , &crate::flat::WILTED_FLOWER as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("wind_chime" , &crate::flat::WIND_CHIME as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("wind_face" , &crate::flat::WIND_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("window" , &crate::flat::WINDOW as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("wine_glass" , &crate::flat::WINE_GLASS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("wink" , &crate::flat::WINKING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("winking_face" , &crate::flat::WINKING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("winking_face_with_tongue" , &crate::flat::WINKING_FACE_WITH_TONGUE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("wolf" , &crate::flat::WOLF as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman" , &crate::flat::PERSON.default.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_artist" , &crate::flat::ARTIST.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_astronaut" , &crate::flat::ASTRONAUT.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_beard" , &crate::flat::PERSON.hair(Hair::Beard).gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_cartwheeling" , &crate::flat::PERSON_CARTWHEELING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_cook" , &crate::flat::COOK.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_dancing" , &crate::flat::PERSON_DANCING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_facepalming" , &crate::flat::PERSON_FACEPALMING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_factory_worker" , &crate::flat::FACTORY_WORKER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_farmer" , &crate::flat::FARMER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_feeding_baby" , &crate::flat::PERSON_FEEDING_BABY.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_firefighter" , &crate::flat::FIREFIGHTER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_health_worker" , &crate::flat::HEALTH_WORKER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_in_manual_wheelchair" , &crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_in_motorized_wheelchair" , &crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_in_tuxedo" , &crate::flat::PERSON_IN_TUXEDO.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_judge" , &crate::flat::JUDGE.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_juggling" , &crate::flat::PERSON_JUGGLING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_mechanic" , &crate::flat::MECHANIC.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_office_worker" , &crate::flat::OFFICE_WORKER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_pilot" , &crate::flat::PILOT.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_playing_handball" , &crate::flat::PERSON_PLAYING_HANDBALL.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_playing_water_polo" , &crate::flat::PERSON_PLAYING_WATER_POLO.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("woman_s_boot" , &crate::flat::WOMAN_S_BOOT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("woman_s_clothes" , &crate::flat::WOMAN_S_CLOTHES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("woman_s_hat" , &crate::flat::WOMAN_S_HAT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("woman_s_sandal" , &crate::flat::WOMAN_S_SANDAL as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_scientist" , &crate::flat::SCIENTIST.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_shrugging" , &crate::flat::PERSON_SHRUGGING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_singer" , &crate::flat::SINGER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_student" , &crate::flat::STUDENT.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_teacher" , &crate::flat::TEACHER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_technologist" , &crate::flat::TECHNOLOGIST.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_with_headscarf" , &crate::flat::WOMAN_WITH_HEADSCARF.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_with_probing_cane" , &crate::flat::PERSON_WITH_WHITE_CANE.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_with_turban" , &crate::flat::PERSON_WEARING_TURBAN.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_with_veil" , &crate::flat::PERSON_WITH_VEIL.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("womans_clothes" , &crate::flat::WOMAN_S_CLOTHES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("womans_hat" , &crate::flat::WOMAN_S_HAT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("women_s_room" , &crate::flat::WOMEN_S_ROOM as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("women_wrestling" , &crate::flat::PERSON_WRESTLING.gender(Gender::Female) as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("womens" , &crate::flat::WOMEN_S_ROOM as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("wood" , &crate::flat::WOOD as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("woozy_face" , &crate::flat::WOOZY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("world_map" , &crate::flat::WORLD_MAP as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("worm" , &crate::flat::WORM as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("worried" , &crate::flat::WORRIED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("worried_face" , &crate::flat::WORRIED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("wrapped_gift" , &crate::flat::WRAPPED_GIFT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("wrench" , &crate::flat::WRENCH as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("wrestling" , &crate::flat::PERSON_WRESTLING.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("writing_hand" , &crate::flat::WRITING_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("x" , &crate::flat::CROSS_MARK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("x_ray" , &crate::flat::X_RAY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("yarn" , &crate::flat::YARN as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("yawning_face" , &crate::flat::YAWNING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("yellow_circle" , &crate::flat::YELLOW_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("yellow_heart" , &crate::flat::YELLOW_HEART as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("yellow_square" , &crate::flat::YELLOW_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("yemen" , &crate::flat::FLAG_YEMEN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("yen" , &crate::flat::YEN_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("yen_banknote" , &crate::flat::YEN_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("yin_yang" , &crate::flat::YIN_YANG as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("yo_yo" , &crate::flat::YO_YO as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("yum" , &crate::flat::FACE_SAVORING_FOOD as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("zambia" , &crate::flat::FLAG_ZAMBIA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("zany_face" , &crate::flat::ZANY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("zap" , &crate::flat::HIGH_VOLTAGE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("zebra" , &crate::flat::ZEBRA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("zero" , &crate::flat::KEYCAP_0 as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("zimbabwe" , &crate::flat::FLAG_ZIMBABWE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("zipper_mouth_face" , &crate::flat::ZIPPER_MOUTH_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("zombie" , &crate::flat::ZOMBIE.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("zombie_man" , &crate::flat::ZOMBIE.gender(Gender::Male) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("zombie_woman" , &crate::flat::ZOMBIE.gender(Gender::Female) as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("zzz" , &crate::flat::ZZZ as &crate::Emoji),
 
     ].iter().copied().collect();
 }
@@ -0,0 +1,5330 @@
+// Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
+
+// Source: https://raw.githubusercontent.com/github/gemoji/v4.1.0/db/emoji.json
+// Created at: 2026-10-16 13:54:22.442094179 UTC
+
+#![allow(unused_imports)]
+
+use crate::Tone;
+use crate::Gender;
+use crate::Hair;
+use crate::Pair;
+use crate::emojis::OneOrTwo;
+use crate::emojis::TonePair;
+use crate::emojis::Family;
+
+/// The FST mapping all aliases (without colons) to their index into `EMOJIS`
+static ALIAS_FST: &[u8] = include_bytes!("aliases.fst");
+
+/// The emojis of the aliases, in the order of the sorted aliases, `None` for disabled groups
+static EMOJIS: &[Option<&crate::Emoji>] = &[
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::THUMBS_UP.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::THUMBS_DOWN.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HUNDRED_POINTS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::INPUT_NUMBERS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::FIRST_PLACE_MEDAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SECOND_PLACE_MEDAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::THIRD_PLACE_MEDAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::POOL_8_BALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::A_BUTTON_BLOOD_TYPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::A_BUTTON_BLOOD_TYPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::AB_BUTTON_BLOOD_TYPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::AB_BUTTON_BLOOD_TYPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::ABACUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::INPUT_LATIN_LETTERS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::INPUT_LATIN_LOWERCASE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_ACCEPTABLE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::ACCORDION as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::ADHESIVE_BANDAGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::ADMISSION_TICKETS as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.default.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::AERIAL_TRAMWAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_AFGHANISTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::AIRPLANE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::AIRPLANE_ARRIVAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::AIRPLANE_DEPARTURE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ALAND_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ALARM_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ALBANIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::ALEMBIC as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ALGERIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ALIEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ALIEN_MONSTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::AMBULANCE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::AMERICAN_FOOTBALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_AMERICAN_SAMOA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::AMPHORA as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ANATOMICAL_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ANCHOR as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ANDORRA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BABY_ANGEL.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ANGER_SYMBOL as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ANGER_SYMBOL as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ANGOLA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ANGRY_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ANGRY_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ANGRY_FACE_WITH_HORNS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ANGUILLA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ANGUISHED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ANGUISHED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::ANT as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ANTARCTICA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::ANTENNA_BARS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ANTIGUA_AND_BARBUDA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ANXIOUS_FACE_WITH_SWEAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::RED_APPLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::AQUARIUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ARGENTINA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::ARIES as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ARMENIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::REVERSE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::FAST_DOWN_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::FAST_UP_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DOWN_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DOWNWARDS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PLAY_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RIGHT_ARROW_CURVING_DOWN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RIGHT_ARROW_CURVING_UP as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LEFT_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DOWN_LEFT_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DOWN_RIGHT_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RIGHT_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LEFT_ARROW_CURVING_RIGHT as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::UP_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::UP_DOWN_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::UPWARDS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::UP_LEFT_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::UP_RIGHT_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CLOCKWISE_VERTICAL_ARROWS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::COUNTERCLOCKWISE_ARROWS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::ARTIST_PALETTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ARTICULATED_LORRY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SATELLITE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ARTIST.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::ARTIST_PALETTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ARUBA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ASCENSION_ISLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_ASTERISK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ASTONISHED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ASTONISHED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ASTRONAUT.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::RUNNING_SHOE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::ATM_SIGN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::ATM_SIGN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::ATOM_SYMBOL as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_AUSTRALIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_AUSTRIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::AUTO_RICKSHAW as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::AUTOMOBILE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::AVOCADO as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::AXE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_AZERBAIJAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::B_BUTTON_BLOOD_TYPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::B_BUTTON_BLOOD_TYPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BABY.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BABY_ANGEL.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BABY_BOTTLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BABY_CHICK as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BABY_SYMBOL as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BACK_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BACK_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BACKHAND_INDEX_POINTING_DOWN.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BACKHAND_INDEX_POINTING_LEFT.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BACKHAND_INDEX_POINTING_RIGHT.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BACKHAND_INDEX_POINTING_UP.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BACKPACK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BACON as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BADGER as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::BADMINTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BAGEL as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BAGGAGE_CLAIM as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BAGUETTE_BREAD as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BAHAMAS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BAHRAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BALANCE_SCALE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "component")] Some(&crate::flat::BALD as &crate::Emoji),
+	#[cfg(not(emojic_group = "component"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Bald).gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Bald).gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BALLET_SHOES as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::BALLOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BALLOT_BOX_WITH_BALLOT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BALLOT_BOX_WITH_BALLOT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CHECK_BOX_WITH_CHECK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::PINE_DECORATION as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BANANA as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DOUBLE_EXCLAMATION_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BANGLADESH as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BANJO as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BANK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BAR_CHART as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BARBADOS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BARBER_POLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BARBER_POLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::BASEBALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BASKET as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::BASKETBALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_TAKING_BATH.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BATHTUB as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BATTERY as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BEACH_WITH_UMBRELLA as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BEACH_WITH_UMBRELLA as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::BEAMING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BEANS as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BEAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Beard).default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::BEATING_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BEAVER as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BED as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::HONEYBEE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BEER_MUG as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BEER_MUG as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CLINKING_BEER_MUGS as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BEETLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_SYMBOL_FOR_BEGINNER as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BELARUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BELGIUM as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BELIZE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BELL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BELL_PEPPER as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BELL_WITH_SLASH as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BELLHOP_BELL as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BENIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BENTO_BOX as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BENTO_BOX as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BERMUDA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BEVERAGE_BOX as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BHUTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BICYCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_BIKING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BICYCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_BIKING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_BIKING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BIKINI as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BILLED_CAP as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BIOHAZARD as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BIRD as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BIRTHDAY_CAKE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BIRTHDAY_CAKE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BISON as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BITING_LIP as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BLACK_CAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BLACK_CIRCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::BLACK_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::BLACK_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::JOKER as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BLACK_LARGE_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BLACK_MEDIUM_SMALL_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BLACK_MEDIUM_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BLACK_NIB as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BLACK_SMALL_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BLACK_SQUARE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Blond).default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BLOSSOM as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BLOWFISH as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BLUE_BOOK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SPORT_UTILITY_VEHICLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BLUE_CIRCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::BLUE_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BLUE_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BLUEBERRIES as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BOAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SAILBOAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BOLIVIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::BOMB as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::OPEN_BOOK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BOOKMARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BOOKMARK_TABS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BOOKS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::COLLISION as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BOOMERANG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WOMAN_S_BOOT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BOSNIA_AND_HERZEGOVINA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BOTSWANA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BOTTLE_WITH_POPPING_CORK as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_BOUNCING_BALL.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BOUQUET as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BOUVET_ISLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_BOWING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BOW_AND_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_BOWING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_BOWING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BOWL_WITH_SPOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::BOWLING as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::BOXING_GLOVE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BOY.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BRAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BRAZIL as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BREAD as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BREAST_FEEDING.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BRICK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BRICK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_VEIL.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BRIDGE_AT_NIGHT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BRIEFCASE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BRIEFS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BRIGHT_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BRITISH_INDIAN_OCEAN_TERRITORY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BRITISH_VIRGIN_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BROCCOLI as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::BROKEN_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BROOM as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BROWN_CIRCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::BROWN_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BROWN_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BRUNEI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BUBBLE_TEA as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BUBBLES as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BUCKET as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BUG as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BUILDING_CONSTRUCTION as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LIGHT_BULB as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BULGARIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BULLET_TRAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BULLET_TRAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HIGH_SPEED_TRAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::BULLSEYE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BURKINA_FASO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BURRITO as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BURUNDI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BUS_STOP as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_SUIT_LEVITATING.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::BUS_STOP as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BUST_IN_SILHOUETTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BUSTS_IN_SILHOUETTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BUTTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::BUTTERFLY as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::CACTUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::SHORTCAKE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CALENDAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::CALL_ME_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MOBILE_PHONE_WITH_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CAMBODIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::CAMEL as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CAMERA as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CAMERA_WITH_FLASH as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CAMERA_WITH_FLASH as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CAMEROON as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CAMPING as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CANADA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CANARY_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CANCER as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CANDLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CANDY as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CANNED_FOOD as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CANOE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CAPE_VERDE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::INPUT_LATIN_UPPERCASE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CAPRICORN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::AUTOMOBILE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CARD_FILE_BOX as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CARD_INDEX as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CARD_INDEX_DIVIDERS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CARIBBEAN_NETHERLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CAROUSEL_HORSE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::CARP_STREAMER as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CARPENTRY_SAW as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CARROT as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_CARTWHEELING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CASTLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::CAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::CAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::CAT_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::CAT_WITH_TEARS_OF_JOY as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::CAT_WITH_WRY_SMILE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CAYMAN_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::OPTICAL_DISK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CENTRAL_AFRICAN_REPUBLIC as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CEUTA_AND_MELILLA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CHAD as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CHAINS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CHAIR as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::BOTTLE_WITH_POPPING_CORK as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CHART_INCREASING_WITH_YEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CHART_DECREASING as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CHART_INCREASING as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CHART_INCREASING_WITH_YEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CHART_DECREASING as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CHART_INCREASING as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CHECK_BOX_WITH_CHECK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CHECK_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CHECK_MARK_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::CHEQUERED_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CHEESE_WEDGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CHEESE_WEDGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::CHEQUERED_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CHERRIES as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::CHERRY_BLOSSOM as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::CHESS_PAWN as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CHESTNUT as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::CHICKEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::CHILD.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CHILDREN_CROSSING as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CHILE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::CHIPMUNK as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CHOCOLATE_BAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CHOPSTICKS as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CHRISTMAS_ISLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::CHRISTMAS_TREE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CHURCH as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CIGARETTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CINEMA as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CIRCLED_M as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CIRCUS_TENT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SUNSET as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CITYSCAPE_AT_DUSK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CITYSCAPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CITYSCAPE_AT_DUSK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CL_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CL_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CLAMP as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::CLAPPING_HANDS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CLAPPER_BOARD as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CLAPPER_BOARD as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::CLAPPING_HANDS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CLASSICAL_BUILDING as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_CLIMBING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_CLIMBING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_CLIMBING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CLINKING_BEER_MUGS as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CLINKING_GLASSES as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CLIPBOARD as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CLIPPERTON_ISLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ONE_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TEN_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TEN_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ELEVEN_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ELEVEN_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TWELVE_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TWELVE_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ONE_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TWO_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TWO_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::THREE_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::THREE_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FOUR_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FOUR_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FIVE_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FIVE_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SIX_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SIX_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SEVEN_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SEVEN_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::EIGHT_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::EIGHT_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::NINE_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::NINE_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CLOCKWISE_VERTICAL_ARROWS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CLOSED_BOOK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LOCKED_WITH_KEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CLOSED_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CLOSED_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CLOSED_UMBRELLA as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CLOUD as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CLOUD_WITH_LIGHTNING as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CLOUD_WITH_LIGHTNING_AND_RAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CLOUD_WITH_RAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CLOUD_WITH_SNOW as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::CLOWN_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::CLUB_SUIT as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::CLUB_SUIT as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CLUTCH_BAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CHINA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::COAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::COCKROACH as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::COCKTAIL_GLASS as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::COCKTAIL_GLASS as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::COCONUT as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_COCOS_KEELING_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::HOT_BEVERAGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::COFFIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::COIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::COLD_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ANXIOUS_FACE_WITH_SWEAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::COLLISION as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_COLOMBIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::COMET as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_COMOROS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::COMPASS as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LAPTOP as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::COMPUTER_DISK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::COMPUTER_MOUSE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::CONFETTI_BALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::CONFOUNDED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::CONFOUNDED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::CONFUSED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::CONFUSED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CONGO_BRAZZAVILLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CONGO_KINSHASA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_CONGRATULATIONS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CONSTRUCTION as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::CONSTRUCTION_WORKER.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::CONSTRUCTION_WORKER.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::CONSTRUCTION_WORKER.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CONTROL_KNOBS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CONVENIENCE_STORE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::COOK.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_COOK_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::COOKED_RICE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::COOKIE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::COOKING as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::COOL_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::COOL_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::POLICE_OFFICER.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::COPYRIGHT as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::CORAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::EAR_OF_CORN as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_COSTA_RICA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_COTE_D_IVOIRE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::COUCH_AND_LAMP as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::COUNTERCLOCKWISE_ARROWS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Mixed).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::COUPLE_WITH_HEART.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::COUPLE_WITH_HEART.pair(Pair::Males).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::COUPLE_WITH_HEART.pair(Pair::Mixed).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::COUPLE_WITH_HEART.pair(Pair::Females).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::KISS.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::KISS.pair(Pair::Males).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::KISS.pair(Pair::Mixed).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::KISS.pair(Pair::Females).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::COW as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::COW as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::COW_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::COWBOY_HAT_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CRAB as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CRAYON as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CREDIT_CARD as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CRESCENT_MOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::CRICKET as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::CRICKET_GAME as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CROATIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::CROCODILE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CROISSANT as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CROSS_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CROSS_MARK_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::CROSSED_FINGERS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::CROSSED_FLAGS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CROSSED_SWORDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CROWN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CRUTCH as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::CRYING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::CRYING_CAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::CRYING_CAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::CRYING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::CRYSTAL_BALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CUBA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CUCUMBER as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CUP_WITH_STRAW as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CUPCAKE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HEART_WITH_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CURACAO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::CURLING_STONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "component")] Some(&crate::flat::CURLY_HAIR as &crate::Emoji),
+	#[cfg(not(emojic_group = "component"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Curly).gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Curly).gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CURLY_LOOP as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CURRENCY_EXCHANGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CURRY_RICE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CURRY_RICE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_SYMBOLS_ON_MOUTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CUSTARD as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CUSTOMS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::CUT_OF_MEAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CYCLONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CYPRUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CZECHIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::DAGGER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_DANCING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_BUNNY_EARS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_BUNNY_EARS.gender(Gender::Male) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_BUNNY_EARS.gender(Gender::Female) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::DANGO as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "component")] Some(&crate::flat::DARK_SKIN_TONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "component"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SUNGLASSES as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::BULLSEYE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::DASHING_AWAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::DASHING_AWAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CALENDAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GERMANY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::DEAF_PERSON.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::DEAF_PERSON.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::DEAF_PERSON.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::DECIDUOUS_TREE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::DEER as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::DELIVERY_TRUCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_DENMARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::DEPARTMENT_STORE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::DERELICT_HOUSE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::DESERT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::DESERT_ISLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::DESKTOP_COMPUTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::DETECTIVE.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DIAMOND_WITH_A_DOT as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::DIAMOND_SUIT as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DIAMOND_WITH_A_DOT as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::DIAMOND_SUIT as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_DIEGO_GARCIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DIM_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::DISAPPOINTED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::DISAPPOINTED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SAD_BUT_RELIEVED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::DISGUISED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DIVIDE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::DIVING_MASK as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::DIYA_LAMP as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::DIZZY as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::KNOCKED_OUT_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_DJIBOUTI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::DNA as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NO_LITTERING as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::DODO as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::DOG as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::DOG as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::DOG_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::DOLLAR_BANKNOTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::DOLLAR_BANKNOTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::JAPANESE_DOLLS as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::DOLPHIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_DOMINICA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_DOMINICAN_REPUBLIC as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::DOOR as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::DOTTED_LINE_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DOTTED_SIX_POINTED_STAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DOUBLE_CURLY_LOOP as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DOUBLE_EXCLAMATION_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::DOUGHNUT as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::DOVE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DOWN_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DOWN_LEFT_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DOWN_RIGHT_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::DOWNCAST_FACE_WITH_SWEAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DOWNWARDS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::DRAGON as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::DRAGON_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::DRESS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::CAMEL as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::DROOLING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::DROP_OF_BLOOD as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::DROPLET as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::DRUM as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::DUCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::DUMPLING as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::DVD as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::E_MAIL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::E_MAIL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::EAGLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::EAR.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::EAR_OF_CORN as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SHEAF_OF_RICE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::EAR_WITH_HEARING_AID.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::GLOBE_SHOWING_EUROPE_AFRICA as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::GLOBE_SHOWING_AMERICAS as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::GLOBE_SHOWING_ASIA_AUSTRALIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ECUADOR as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::EGG as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::EGGPLANT as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_EGYPT as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_8 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::EIGHT_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::EIGHT_POINTED_STAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::EIGHT_POINTED_STAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::EIGHT_SPOKED_ASTERISK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::EIGHT_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::EJECT_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_EL_SALVADOR as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::ELECTRIC_PLUG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::ELEPHANT as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::ELEVATOR as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ELEVEN_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ELEVEN_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ELF.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ELF.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ELF.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::E_MAIL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::EMPTY_NEST as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::END_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::END_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ENGLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::ENVELOPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::ENVELOPE_WITH_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_EQUATORIAL_GUINEA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ERITREA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SPAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ESTONIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ETHIOPIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::EURO_BANKNOTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::EURO_BANKNOTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::CASTLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::POST_OFFICE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::EVERGREEN_TREE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::EWE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::EXCLAMATION_QUESTION_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::EXPLODING_HEAD as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::EXPRESSIONLESS_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::EXPRESSIONLESS_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::EYE as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::EYE_IN_SPEECH_BUBBLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::EYE_IN_SPEECH_BUBBLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::GLASSES as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_BLOWING_A_KISS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_EXHALING as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_HOLDING_BACK_TEARS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_IN_CLOUDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_SAVORING_FOOD as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_SCREAMING_IN_FEAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_VOMITING as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_DIAGONAL_MOUTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_HAND_OVER_MOUTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_HEAD_BANDAGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_MEDICAL_MASK as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_MONOCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_OPEN_EYES_AND_HAND_OVER_MOUTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_OPEN_MOUTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_PEEKING_EYE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_RAISED_EYEBROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_ROLLING_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_SPIRAL_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_STEAM_FROM_NOSE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_SYMBOLS_ON_MOUTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_TEARS_OF_JOY as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_THERMOMETER as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_TONGUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITHOUT_MOUTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_FACEPALMING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ONCOMING_FIST.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FACTORY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FACTORY_WORKER.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAIRY.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAIRY.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAIRY.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::FALAFEL as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FALKLAND_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::FALLEN_LEAF as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FARMER.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FAROE_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::FAST_DOWN_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::FAST_FORWARD_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::FAST_FORWARD_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::FAST_REVERSE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::FAST_UP_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::FAX_MACHINE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::FAX_MACHINE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FEARFUL_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FEARFUL_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::FEATHER as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::PAW_PRINTS as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::DETECTIVE.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::FEMALE_SIGN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FERRIS_WHEEL as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FERRY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::FIELD_HOCKEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FIJI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::FILE_CABINET as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::FILE_FOLDER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::FILM_FRAMES as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::FILM_PROJECTOR as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::FILM_FRAMES as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FINLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FIRE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FIRE_ENGINE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::FIRE_EXTINGUISHER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::FIRECRACKER as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FIREFIGHTER.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::FIREWORKS as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::FIRST_PLACE_MEDAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FIRST_QUARTER_MOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FIRST_QUARTER_MOON_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FIRST_QUARTER_MOON_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::FISH as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::FISH_CAKE_WITH_SWIRL as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::FISH_CAKE_WITH_SWIRL as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::FISHING_POLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::FISHING_POLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::RAISED_FIST.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::LEFT_FACING_FIST.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ONCOMING_FIST.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::RAISED_FIST.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::RIGHT_FACING_FIST.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_5 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FIVE_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FIVE_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_AFGHANISTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ALAND_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ALBANIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ALGERIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_AMERICAN_SAMOA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ANDORRA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ANGOLA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ANGUILLA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ANTARCTICA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ANTIGUA_AND_BARBUDA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ARGENTINA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ARMENIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ARUBA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ASCENSION_ISLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_AUSTRALIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_AUSTRIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_AZERBAIJAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BAHAMAS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BAHRAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BANGLADESH as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BARBADOS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BELARUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BELGIUM as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BELIZE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BENIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BERMUDA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BHUTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BOLIVIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BOSNIA_AND_HERZEGOVINA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BOTSWANA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BOUVET_ISLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BRAZIL as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BRITISH_INDIAN_OCEAN_TERRITORY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BRITISH_VIRGIN_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BRUNEI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BULGARIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BURKINA_FASO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_BURUNDI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CAMBODIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CAMEROON as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CANADA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CANARY_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CAPE_VERDE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CARIBBEAN_NETHERLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CAYMAN_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CENTRAL_AFRICAN_REPUBLIC as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CEUTA_AND_MELILLA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CHAD as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CHILE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CHINA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CHRISTMAS_ISLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CLIPPERTON_ISLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_COCOS_KEELING_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_COLOMBIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_COMOROS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CONGO_BRAZZAVILLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CONGO_KINSHASA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_COOK_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_COSTA_RICA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_COTE_D_IVOIRE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CROATIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CUBA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CURACAO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CYPRUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_CZECHIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_DENMARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_DIEGO_GARCIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_DJIBOUTI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_DOMINICA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_DOMINICAN_REPUBLIC as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ECUADOR as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_EGYPT as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_EL_SALVADOR as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ENGLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_EQUATORIAL_GUINEA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ERITREA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ESTONIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ESWATINI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ETHIOPIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FALKLAND_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FAROE_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FIJI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FINLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FRANCE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FRENCH_GUIANA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FRENCH_POLYNESIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FRENCH_SOUTHERN_TERRITORIES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GABON as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GAMBIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GEORGIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GERMANY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GHANA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GIBRALTAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GREECE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GREENLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GRENADA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GUADELOUPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GUAM as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GUATEMALA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GUERNSEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GUINEA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GUINEA_BISSAU as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GUYANA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_HAITI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_HEARD_AND_MCDONALD_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_HONDURAS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_HONG_KONG_SAR_CHINA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_HUNGARY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ICELAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::FLAG_IN_HOLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_INDIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_INDONESIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_IRAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_IRAQ as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_IRELAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ISLE_OF_MAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ISRAEL as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ITALY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_JAMAICA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_JAPAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_JERSEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_JORDAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_KAZAKHSTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_KENYA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_KIRIBATI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_KOSOVO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_KUWAIT as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_KYRGYZSTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LAOS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LATVIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LEBANON as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LESOTHO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LIBERIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LIBYA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LIECHTENSTEIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LITHUANIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LUXEMBOURG as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MACAO_SAR_CHINA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MADAGASCAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MALAWI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MALAYSIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MALDIVES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MALI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MALTA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MARSHALL_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MARTINIQUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MAURITANIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MAURITIUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MAYOTTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MEXICO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MICRONESIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MOLDOVA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MONACO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MONGOLIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MONTENEGRO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MONTSERRAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MOROCCO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MOZAMBIQUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MYANMAR_BURMA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NAMIBIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NAURU as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NEPAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NETHERLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NEW_CALEDONIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NEW_ZEALAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NICARAGUA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NIGER as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NIGERIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NIUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NORFOLK_ISLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NORTH_KOREA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NORTH_MACEDONIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NORTHERN_MARIANA_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NORWAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_OMAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PAKISTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PALAU as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PALESTINIAN_TERRITORIES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PANAMA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PAPUA_NEW_GUINEA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PARAGUAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PERU as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PHILIPPINES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PITCAIRN_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_POLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PORTUGAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PUERTO_RICO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_QATAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_REUNION as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ROMANIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_RUSSIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_RWANDA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SAMOA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SAN_MARINO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SAO_TOME_AND_PRINCIPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SAUDI_ARABIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SCOTLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SENEGAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SERBIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SEYCHELLES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SIERRA_LEONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SINGAPORE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SINT_MAARTEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SLOVAKIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SLOVENIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SOLOMON_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SOMALIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SOUTH_AFRICA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SOUTH_GEORGIA_AND_SOUTH_SANDWICH_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SOUTH_KOREA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SOUTH_SUDAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SPAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SRI_LANKA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ST_BARTHELEMY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ST_HELENA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ST_KITTS_AND_NEVIS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ST_LUCIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ST_MARTIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ST_PIERRE_AND_MIQUELON as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ST_VINCENT_AND_GRENADINES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SUDAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SURINAME as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SVALBARD_AND_JAN_MAYEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SWEDEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SWITZERLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SYRIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TAIWAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TAJIKISTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TANZANIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_THAILAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TIMOR_LESTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TOGO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TOKELAU as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TONGA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TRINIDAD_AND_TOBAGO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TRISTAN_DA_CUNHA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TUNISIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TURKEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TURKMENISTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TURKS_AND_CAICOS_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TUVALU as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UGANDA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UKRAINE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UNITED_ARAB_EMIRATES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UNITED_NATIONS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UNITED_STATES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_URUGUAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_US_OUTLYING_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_US_VIRGIN_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UZBEKISTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_VANUATU as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_VATICAN_CITY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_VENEZUELA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_VIETNAM as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_WALES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_WALLIS_AND_FUTUNA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_WESTERN_SAHARA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_YEMEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ZAMBIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ZIMBABWE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::CARP_STREAMER as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::FLAMINGO as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::FLASHLIGHT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::FLAT_SHOE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::FLATBREAD as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::FLEUR_DE_LIS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FLEXED_BICEPS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::AIRPLANE_ARRIVAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::AIRPLANE_DEPARTURE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::DOLPHIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::FLOPPY_DISK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::FLOWER_PLAYING_CARDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FLUSHED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FLUSHED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::FLY as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::FLYING_DISC as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FLYING_SAUCER as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FOG as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FOGGY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FOLDED_HANDS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::FONDUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FOOT.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::AMERICAN_FOOTBALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FOOTPRINTS as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::FORK_AND_KNIFE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::FORK_AND_KNIFE_WITH_PLATE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::FORTUNE_COOKIE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FOUNTAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::FOUNTAIN_PEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_4 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::FOUR_LEAF_CLOVER as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FOUR_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FOUR_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::FOX as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::FOX as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FRANCE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::FRAMED_PICTURE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::FREE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::FREE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::FRENCH_FRIES as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FRENCH_GUIANA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FRENCH_POLYNESIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_FRENCH_SOUTHERN_TERRITORIES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::COOKING as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::FRIED_SHRIMP as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::FRENCH_FRIES as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::FROG as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::FRONT_FACING_BABY_CHICK as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FROWNING_FACE_WITH_OPEN_MOUTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FROWNING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FROWNING_FACE_WITH_OPEN_MOUTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_FROWNING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_FROWNING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_FROWNING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MIDDLE_FINGER.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FUEL_PUMP as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FUEL_PUMP as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FULL_MOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FULL_MOON_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::FULL_MOON_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::FUNERAL_URN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GABON as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GAMBIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::GAME_DIE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::GARLIC as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::GEAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::GEM_STONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::GEM_STONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::GEMINI as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::GENIE.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::GENIE.gender(Gender::Male) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::GENIE.gender(Gender::Female) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GEORGIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GHANA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GHOST as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GIBRALTAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::WRAPPED_GIFT as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HEART_WITH_RIBBON as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::GIRAFFE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::GIRL.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::GLASS_OF_MILK as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::GLASSES as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::GLOBE_SHOWING_AMERICAS as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::GLOBE_SHOWING_ASIA_AUSTRALIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::GLOBE_SHOWING_EUROPE_AFRICA as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::GLOBE_WITH_MERIDIANS as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::GLOVES as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::GLOWING_STAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::GOAL_NET as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::GOAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GOBLIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::GOGGLES as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::FLAG_IN_HOLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GOLFING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GOLFING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GOLFING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::GORILLA as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::GRADUATION_CAP as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::GRAPES as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GREECE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::GREEN_APPLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::GREEN_BOOK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::GREEN_CIRCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GREEN_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::GREEN_SALAD as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::GREEN_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GREENLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GRENADA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WHITE_EXCLAMATION_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WHITE_QUESTION_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRIMACING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRIMACING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::BEAMING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_CAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_CAT_WITH_SMILING_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_FACE_WITH_BIG_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_FACE_WITH_SWEAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GROWING_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GUADELOUPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GUAM as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::GUARD.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::GUARD.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::GUARD.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GUATEMALA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GUERNSEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::GUIDE_DOG as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GUINEA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GUINEA_BISSAU as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::GUITAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WATER_PISTOL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_GUYANA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GETTING_HAIRCUT.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GETTING_HAIRCUT.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GETTING_HAIRCUT.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_HAITI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::HAMBURGER as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::HAMMER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::HAMMER_AND_PICK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::HAMMER_AND_WRENCH as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::HAMSA as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::HAMSTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::RAISED_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_HAND_OVER_MOUTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::HAND_WITH_FINGERS_SPLAYED.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::HANDBAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_PLAYING_HANDBALL.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::HANDSHAKE.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::PILE_OF_POO as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_HASH as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::FRONT_FACING_BABY_CHICK as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::HATCHING_CHICK as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::HEADPHONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::HEADPHONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::HEADSTONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::HEALTH_WORKER.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HEAR_NO_EVIL_MONKEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HEAR_NO_EVIL_MONKEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_HEARD_AND_MCDONALD_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::RED_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HEART_DECORATION as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HEART_EXCLAMATION as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_FACE_WITH_HEART_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_CAT_WITH_HEART_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::HEART_HANDS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HEART_ON_FIRE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::HEART_SUIT as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HEART_WITH_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HEART_WITH_RIBBON as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::BEATING_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GROWING_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::HEART_SUIT as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CHECK_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DIVIDE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::HEAVY_DOLLAR_SIGN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::HEAVY_EQUALS_SIGN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HEART_EXCLAMATION as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::MINUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::MULTIPLY as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PLUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::HEDGEHOG as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HELICOPTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::HERB as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::HIBISCUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BRIGHT_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::HIGH_HEELED_SHOE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::HIGH_HEELED_SHOE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HIGH_SPEED_TRAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HIGH_VOLTAGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::HIKING_BOOT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HINDU_TEMPLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::HIPPOPOTAMUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::KITCHEN_KNIFE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HOLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::HOLLOW_RED_CIRCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_HONDURAS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::HONEY_POT as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::HONEYBEE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_HONG_KONG_SAR_CHINA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::HOOK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HORIZONTAL_TRAFFIC_LIGHT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::HORSE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::HORSE_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::HORSE_RACING.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HOSPITAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::HOT_BEVERAGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::HOT_DOG as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HOT_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::HOT_PEPPER as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HOT_SPRINGS as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::HOT_DOG as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HOTEL as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HOT_SPRINGS as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HOURGLASS_DONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HOURGLASS_DONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HOURGLASS_NOT_DONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HOURGLASS_NOT_DONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HOUSE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HOUSE_WITH_GARDEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HOUSES as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HUGGING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HUGGING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HUNDRED_POINTS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_HUNGARY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HUSHED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::HUSHED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HUT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::ICE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::ICE_CREAM as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::ICE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::ICE_HOCKEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::ICE_SKATE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::SOFT_ICE_CREAM as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ICELAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::ID_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::ID_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::IDENTIFICATION_CARD as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_BARGAIN_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ANGRY_FACE_WITH_HORNS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::INBOX_TRAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::INCOMING_ENVELOPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::INDEX_POINTING_AT_THE_VIEWER.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::INDEX_POINTING_UP.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_INDIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_INDONESIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::INFINITY as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::INFORMATION as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::INFORMATION as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_FACE_WITH_HALO as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::INPUT_LATIN_LETTERS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::INPUT_LATIN_LOWERCASE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::INPUT_LATIN_UPPERCASE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::INPUT_NUMBERS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::INPUT_SYMBOLS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::EXCLAMATION_QUESTION_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MOBILE_PHONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_IRAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_IRAQ as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_IRELAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ISLE_OF_MAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ISRAEL as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ITALY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::JACK_O_LANTERN as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_JAMAICA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MAP_OF_JAPAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_ACCEPTABLE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_APPLICATION_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_BARGAIN_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::JAPANESE_CASTLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_CONGRATULATIONS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_DISCOUNT_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::JAPANESE_DOLLS as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GOBLIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_HERE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_MONTHLY_AMOUNT_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_NO_VACANCY_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_NOT_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::OGRE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_OPEN_FOR_BUSINESS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_PASSING_GRADE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::JAPANESE_POST_OFFICE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_PROHIBITED_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_RESERVED_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_SECRET_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_SERVICE_CHARGE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_SYMBOL_FOR_BEGINNER as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_VACANCY_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::JAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::JEANS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_JERSEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::PUZZLE_PIECE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::JOKER as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_JORDAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_TEARS_OF_JOY as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::CAT_WITH_TEARS_OF_JOY as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::JOYSTICK as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_JAPAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::JUDGE.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_JUGGLING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::KAABA as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::KANGAROO as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_KAZAKHSTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_KENYA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::KEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::KEYBOARD as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_0 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_1 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_10 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_2 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_3 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_4 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_5 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_6 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_7 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_8 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_9 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_ASTERISK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_HASH as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_10 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::KICK_SCOOTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::KIMONO as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_KIRIBATI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::KISS.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::KISS_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::KISSING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::KISSING_CAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::KISSING_FACE_WITH_CLOSED_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::KISSING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::KISSING_FACE_WITH_CLOSED_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::KISSING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_BLOWING_A_KISS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::KISSING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::KITCHEN_KNIFE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::KITE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::KIWI_FRUIT as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_KNEELING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_KNEELING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_KNEELING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::KITCHEN_KNIFE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::KNOCKED_OUT_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::KNOT as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::KOALA as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_HERE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_KOSOVO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SOUTH_KOREA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_KUWAIT as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_KYRGYZSTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LAB_COAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LABEL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::LACROSSE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LADDER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::LADY_BEETLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LAOS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LAPTOP as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::BLUE_CIRCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LARGE_BLUE_DIAMOND as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LARGE_ORANGE_DIAMOND as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::LAST_QUARTER_MOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::LAST_QUARTER_MOON_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::LAST_QUARTER_MOON_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LAST_TRACK_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LATIN_CROSS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LATVIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::LEAF_FLUTTERING_IN_WIND as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::LEAFY_GREEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::LEAF_FLUTTERING_IN_WIND as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LEBANON as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LEDGER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LEFT_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LEFT_ARROW_CURVING_RIGHT as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::LEFT_FACING_FIST.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LEFT_LUGGAGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LEFT_RIGHT_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::LEFT_SPEECH_BUBBLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RIGHT_ARROW_CURVING_LEFT as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::LEFTWARDS_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::LEG.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::LEMON as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LEO as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::LEOPARD as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LESOTHO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LEVEL_SLIDER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LIBERIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LIBRA as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LIBYA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LIECHTENSTEIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LIGHT_BULB as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::LIGHT_RAIL as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "component")] Some(&crate::flat::LIGHT_SKIN_TONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "component"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LINK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LINKED_PAPERCLIPS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::LION as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MOUTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LIPSTICK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LITHUANIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LITTER_IN_BIN_SIGN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::LIZARD as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::LLAMA as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::LOBSTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LOCKED as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LOCKED_WITH_PEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LOCKED as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LOCKED_WITH_KEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LOCKED_WITH_PEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::LOCOMOTIVE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::LOLLIPOP as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LONG_DRUM as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DOUBLE_CURLY_LOOP as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LOTION_BOTTLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::LOTUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_LOTUS_POSITION.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_LOTUS_POSITION.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_LOTUS_POSITION.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SPEAKER_HIGH_VOLUME as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::LOUDLY_CRYING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LOUDSPEAKER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::LOVE_HOTEL as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::LOVE_LETTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::LOVE_YOU_GESTURE.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LOW_BATTERY as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DIM_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::LUGGAGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::LUNGS as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_LUXEMBOURG as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::LYING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CIRCLED_M as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MACAO_SAR_CHINA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NORTH_MACEDONIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MADAGASCAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MAGNIFYING_GLASS_TILTED_LEFT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MAGNIFYING_GLASS_TILTED_RIGHT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MAGE.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MAGE.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MAGE.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::MAGIC_WAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MAGNET as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MAGNIFYING_GLASS_TILTED_LEFT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MAGNIFYING_GLASS_TILTED_RIGHT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::MAHJONG_RED_DRAGON as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::MAHJONG_RED_DRAGON as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CLOSED_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CLOSED_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::OPEN_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::OPEN_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MALAWI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MALAYSIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MALDIVES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::DETECTIVE.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::MALE_SIGN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MALI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MALTA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::MAMMOTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.default.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ARTIST.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ASTRONAUT.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Beard).gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_CARTWHEELING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::COOK.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_DANCING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_FACEPALMING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FACTORY_WORKER.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FARMER.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_FEEDING_BABY.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FIREFIGHTER.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::HEALTH_WORKER.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_TUXEDO.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::JUDGE.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_JUGGLING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MECHANIC.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::OFFICE_WORKER.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PILOT.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_PLAYING_HANDBALL.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_PLAYING_WATER_POLO.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MAN_S_SHOE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SCIENTIST.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_SHRUGGING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SINGER.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::STUDENT.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::TEACHER.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::TECHNOLOGIST.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_SKULLCAP.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_WHITE_CANE.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WEARING_TURBAN.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_VEIL.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::TANGERINE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::MANGO as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MAN_S_SHOE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MANTELPIECE_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MANUAL_WHEELCHAIR as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MAP_OF_JAPAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::MAPLE_LEAF as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MARSHALL_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::MARTIAL_ARTS_UNIFORM as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MARTINIQUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_MEDICAL_MASK as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GETTING_MASSAGE.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GETTING_MASSAGE.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GETTING_MASSAGE.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::MATE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MAURITANIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MAURITIUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MAYOTTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::MEAT_ON_BONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MECHANIC.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MECHANICAL_ARM as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MECHANICAL_LEG as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::MILITARY_MEDAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SPORTS_MEDAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::MEDICAL_SYMBOL as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "component")] Some(&crate::flat::MEDIUM_DARK_SKIN_TONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "component"))] None,
+	#[cfg(emojic_group = "component")] Some(&crate::flat::MEDIUM_LIGHT_SKIN_TONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "component"))] None,
+	#[cfg(emojic_group = "component")] Some(&crate::flat::MEDIUM_SKIN_TONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "component"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MEGAPHONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MEGAPHONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::MELON as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::MELTING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MEMO as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::MEN_S_ROOM as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WRESTLING.gender(Gender::Male) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::MENDING_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::MENORAH as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::MEN_S_ROOM as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MERMAID.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MERMAN.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MERPERSON.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SIGN_OF_THE_HORNS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::METRO as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MEXICO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::MICROBE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MICRONESIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MICROPHONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MICROSCOPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MIDDLE_FINGER.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MILITARY_HELMET as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::MILITARY_MEDAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::GLASS_OF_MILK as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MILKY_WAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MINIBUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::COMPUTER_DISK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::MINUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MIRROR as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::MIRROR_BALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MOAI as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MOBILE_PHONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::MOBILE_PHONE_OFF as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MOBILE_PHONE_WITH_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MOLDOVA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MONACO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MONEY_BAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::MONEY_MOUTH_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MONEY_WITH_WINGS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MONEY_BAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MONGOLIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::MONKEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::MONKEY_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_MONOCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MONORAIL as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MONTENEGRO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MONTSERRAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::WAXING_GIBBOUS_MOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::MOON_CAKE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::MOON_VIEWING_CEREMONY as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MOROCCO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::GRADUATION_CAP as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MOSQUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::MOSQUITO as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MOTOR_BOAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MOTOR_SCOOTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MOTORCYCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MOTORIZED_WHEELCHAIR as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MOTORWAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MOUNT_FUJI as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MOUNTAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_MOUNTAIN_BIKING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_MOUNTAIN_BIKING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_MOUNTAIN_BIKING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MOUNTAIN_CABLEWAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::MOUNTAIN_RAILWAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SNOW_CAPPED_MOUNTAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::MOUSE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::MOUSE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::MOUSE_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MOUSE_TRAP as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MOUTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MOVIE_CAMERA as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MOAI as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MOZAMBIQUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MRS_CLAUS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::MULTIPLY as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FLEXED_BICEPS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::MUSHROOM as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MUSICAL_KEYBOARD as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MUSICAL_NOTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MUSICAL_NOTES as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MUSICAL_SCORE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MUTED_SPEAKER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MUTED_SPEAKER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MX_CLAUS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_MYANMAR_BURMA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::NAIL_POLISH.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::NAIL_POLISH.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NAME_BADGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NAMIBIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::NATIONAL_PARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NAURU as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::NAUSEATED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::NAZAR_AMULET as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::NECKTIE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CROSS_MARK_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NEPAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::NERD_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::NEST_WITH_EGGS as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::NESTING_DOLLS as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NETHERLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::NEUTRAL_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NEW_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NEW_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NEW_CALEDONIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::NEW_MOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::NEW_MOON_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::NEW_MOON_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NEW_ZEALAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::NEWSPAPER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::ROLLED_UP_NEWSPAPER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NEXT_TRACK_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NG_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NG_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GESTURING_NO.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GESTURING_NO.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NICARAGUA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NIGER as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NIGERIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::NIGHT_WITH_STARS as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_9 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::NINE_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::NINE_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::NINJA.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NIUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BELL_WITH_SLASH as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NO_BICYCLES as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NO_ENTRY as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PROHIBITED as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GESTURING_NO.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GESTURING_NO.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GESTURING_NO.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NO_LITTERING as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NO_MOBILE_PHONES as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITHOUT_MOUTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NO_ONE_UNDER_EIGHTEEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NO_PEDESTRIANS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NO_SMOKING as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NON_POTABLE_WATER as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NON_POTABLE_WATER as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NORFOLK_ISLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NORTH_KOREA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NORTHERN_MARIANA_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_NORWAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::NOSE.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::NOTEBOOK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::NOTEBOOK_WITH_DECORATIVE_COVER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MUSICAL_NOTES as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::NUT_AND_BOLT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::HOLLOW_RED_CIRCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::O_BUTTON_BLOOD_TYPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::O_BUTTON_BLOOD_TYPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::WATER_WAVE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::OCTOPUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::ODEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::OFFICE_BUILDING as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::OFFICE_BUILDING as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::OFFICE_WORKER.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::OGRE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::OIL_DRUM as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::OK_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::OK_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::OK_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GESTURING_OK.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GESTURING_OK.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GESTURING_OK.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::OLD_KEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::OLDER_PERSON.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::OLD_PERSON.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::OLDER_PERSON.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::OLD_PERSON.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::OLIVE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::OM as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_OMAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::ON_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::ON_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ONCOMING_AUTOMOBILE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ONCOMING_BUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ONCOMING_FIST.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ONCOMING_POLICE_CAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ONCOMING_TAXI as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_1 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ONE_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::ONE_PIECE_SWIMSUIT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ONE_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::ONION as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::OPEN_BOOK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::OPEN_FILE_FOLDER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::OPEN_HANDS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::OPEN_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::OPEN_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_OPEN_MOUTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::UMBRELLA as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::OPHIUCHUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::OPTICAL_DISK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::TANGERINE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::ORANGE_BOOK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::ORANGE_CIRCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ORANGE_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::ORANGE_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::ORANGUTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::ORTHODOX_CROSS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::OTTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::OUTBOX_TRAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::OWL as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::OX as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::OYSTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::P_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PACKAGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PAGE_FACING_UP as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PAGE_WITH_CURL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PAGER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PAINTBRUSH as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PAKISTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PALAU as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PALESTINIAN_TERRITORIES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PALM_DOWN_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::PALM_TREE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PALM_UP_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PALMS_UP_TOGETHER.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PANAMA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::PANCAKES as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::PANDA as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::PANDA as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PAPERCLIP as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::LINKED_PAPERCLIPS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PAPUA_NEW_GUINEA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::PARACHUTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PARAGUAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::UMBRELLA_ON_GROUND as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::P_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::PARROT as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PART_ALTERNATION_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SUN_BEHIND_CLOUD as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::PARTY_POPPER as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::PARTYING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::PASSENGER_SHIP as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PASSPORT_CONTROL as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PAUSE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::PAW_PRINTS as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PEACE_SYMBOL as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::PEACH as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::PEACOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::PEANUTS as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::PEAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PENCIL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PENCIL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::PENGUIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::PENSIVE_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::PENSIVE_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_HOLDING_HANDS.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_HUGGING as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::PERFORMING_ARTS as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::PERSEVERING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::PERSEVERING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.default.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Bald).default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_BIKING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_BOUNCING_BALL.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_BOWING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_CARTWHEELING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_CLIMBING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Curly).default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_FACEPALMING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_FEEDING_BABY.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_FENCING as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_FROWNING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GESTURING_NO.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GESTURING_OK.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GETTING_HAIRCUT.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GETTING_MASSAGE.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_GOLFING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_HOLDING_HANDS.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_HUGGING as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_BED.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_LOTUS_POSITION.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_STEAMY_ROOM.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_SUIT_LEVITATING.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_TUXEDO.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_JUGGLING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_KNEELING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_LIFTING_WEIGHTS.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_MOUNTAIN_BIKING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_PLAYING_HANDBALL.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_PLAYING_WATER_POLO.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_POUTING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_RAISING_HAND.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Red).default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_ROWING_BOAT.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_SHRUGGING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_STANDING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_SURFING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_SWIMMING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_TAKING_BATH.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WALKING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WEARING_TURBAN.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::White).default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_BUNNY_EARS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_CROWN.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_WHITE_CANE.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_SKULLCAP.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WEARING_TURBAN.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_VEIL.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_WHITE_CANE.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WRESTLING.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PERU as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PETRI_DISH as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PHILIPPINES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TELEPHONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PICK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::PICKUP_TRUCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::PIE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::PIG as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::PIG as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::PIG_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::PIG_NOSE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::PILE_OF_POO as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PILL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PILOT.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::PINATA as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PINCHED_FINGERS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PINCHING_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::PINE_DECORATION as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::PINEAPPLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::PING_PONG as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::PIRATE_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PISCES as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PITCAIRN_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::PIZZA as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PLACARD as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PLACE_OF_WORSHIP as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::FORK_AND_KNIFE_WITH_PLATE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PLAY_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PLAY_OR_PAUSE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::PLAYGROUND_SLIDE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::PLEADING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PLUNGER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PLUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BACKHAND_INDEX_POINTING_DOWN.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BACKHAND_INDEX_POINTING_LEFT.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BACKHAND_INDEX_POINTING_RIGHT.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::INDEX_POINTING_UP.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::BACKHAND_INDEX_POINTING_UP.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_POLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::POLAR_BEAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::POLICE_CAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::POLICE_CAR_LIGHT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::POLICE_OFFICER.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::POLICE_OFFICER.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::POLICE_OFFICER.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::POODLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::POOL_8_BALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::PILE_OF_POO as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::POPCORN as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PORTUGAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::POST_OFFICE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::POSTAL_HORN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::POSTBOX as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::POT_OF_FOOD as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::POTABLE_WATER as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::POTATO as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::POTTED_PLANT as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CLUTCH_BAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::POULTRY_LEG as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::POUND_BANKNOTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::POUND_BANKNOTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::POURING_LIQUID as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::POUTING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::POUTING_CAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::POUTING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_POUTING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_POUTING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FOLDED_HANDS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PRAYER_BEADS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PREGNANT_PERSON.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PREGNANT_PERSON.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PREGNANT_PERSON.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::PRETZEL as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LAST_TRACK_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PRINCE.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PRINCESS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PRINTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WHITE_CANE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PROHIBITED as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_PUERTO_RICO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ONCOMING_FIST.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PURPLE_CIRCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::PURPLE_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::PURPLE_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PURSE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::PUSHPIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::LITTER_IN_BIN_SIGN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::PUZZLE_PIECE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_QATAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RED_QUESTION_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::RABBIT as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::RABBIT as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::RABBIT_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::RACCOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::HORSE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::RACING_CAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::RADIO as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RADIO_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RADIOACTIVE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::POUTING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::RAILWAY_CAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::RAILWAY_TRACK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::RAINBOW as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::RAINBOW_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::RAISED_BACK_OF_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_RAISED_EYEBROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::RAISED_FIST.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::RAISED_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::HAND_WITH_FINGERS_SPLAYED.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::RAISING_HANDS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_RAISING_HAND.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_RAISING_HAND.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_RAISING_HAND.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::RAISING_HANDS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::RAM as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::STEAMING_BOWL as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::RAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::RAZOR as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::RECEIPT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RECORD_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RECYCLING_SYMBOL as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RECYCLING_SYMBOL as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::RED_APPLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::AUTOMOBILE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RED_CIRCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::RED_ENVELOPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "component")] Some(&crate::flat::RED_HAIR as &crate::Emoji),
+	#[cfg(not(emojic_group = "component"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Red).gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Red).gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::RED_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RED_QUESTION_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RED_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RED_TRIANGLE_POINTED_DOWN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RED_TRIANGLE_POINTED_UP as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::REGISTERED as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::RELIEVED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::RELIEVED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::REMINDER_RIBBON as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::REPEAT_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::REPEAT_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::REPEAT_SINGLE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::REPEAT_SINGLE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::RESCUE_WORKER_S_HELMET as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::RESCUE_WORKER_S_HELMET as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RESTROOM as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_REUNION as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::REVERSE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::REVOLVING_HEARTS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::FAST_REVERSE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::RHINOCEROS as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::RIBBON as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::COOKED_RICE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::RICE_BALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::RICE_CRACKER as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::MOON_VIEWING_CEREMONY as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::RIGHT_ANGER_BUBBLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RIGHT_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RIGHT_ARROW_CURVING_DOWN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RIGHT_ARROW_CURVING_LEFT as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RIGHT_ARROW_CURVING_UP as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::RIGHT_FACING_FIST.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::RIGHTWARDS_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::RING as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::RING_BUOY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::RINGED_PLANET as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::ROASTED_SWEET_POTATO as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ROBOT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ROCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ROCKET as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ROLLING_ON_THE_FLOOR_LAUGHING as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_ROLLING_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::ROLL_OF_PAPER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::ROLLED_UP_NEWSPAPER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ROLLER_COASTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::ROLLER_SKATE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ROLLING_ON_THE_FLOOR_LAUGHING as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ROMANIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::ROOSTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::ROSE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::ROSETTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::POLICE_CAR_LIGHT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::ROUND_PUSHPIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_ROWING_BOAT.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_ROWING_BOAT.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_ROWING_BOAT.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_RUSSIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::RUGBY_FOOTBALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_RUNNING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::RUNNING_SHIRT as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::RUNNING_SHIRT as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::RUNNING_SHOE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_RUNNING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_RWANDA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_SERVICE_CHARGE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SAD_BUT_RELIEVED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SAFETY_PIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SAFETY_VEST as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::SAGITTARIUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SAILBOAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::SAKE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::SALT as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SALUTING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SAMOA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SAN_MARINO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WOMAN_S_SANDAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::SANDWICH as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SANTA_CLAUS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SANTA_CLAUS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SAO_TOME_AND_PRINCIPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SARI as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_TIPPING_HAND.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_TIPPING_HAND.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SATELLITE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SATELLITE_ANTENNA as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SAUDI_ARABIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_STEAMY_ROOM.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_STEAMY_ROOM.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_STEAMY_ROOM.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SAUROPOD as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SAXOPHONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SCARF as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SCHOOL as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BACKPACK as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SCIENTIST.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SCISSORS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::SCORPIO as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SCORPION as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::SCORPIO as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SCOTLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_SCREAMING_IN_FEAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::WEARY_CAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SCREWDRIVER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SCROLL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SEAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SEAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SECOND_PLACE_MEDAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_SECRET_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SEE_NO_EVIL_MONKEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SEE_NO_EVIL_MONKEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SEEDLING as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SELFIE.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SENEGAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SERBIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SERVICE_DOG as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_7 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SEVEN_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SEVEN_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SEWING_NEEDLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SEYCHELLES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::SHALLOW_PAN_OF_FOOD as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SHAMROCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SHARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::SHAVED_ICE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SHEAF_OF_RICE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::EWE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SPIRAL_SHELL as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SHIELD as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SHINTO_SHRINE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SHIP as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::T_SHIRT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::PILE_OF_POO as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::MAN_S_SHOE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SHOOTING_STAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SHOPPING_BAGS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SHOPPING_BAGS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SHOPPING_CART as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::SHORTCAKE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SHORTS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SHOWER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::SHRIMP as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_SHRUGGING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::SHUFFLE_TRACKS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SHUSHING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SIERRA_LEONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SIGN_OF_THE_HORNS.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::ANTENNA_BARS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SINGAPORE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SINGER.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SINT_MAARTEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_6 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SIX_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::DOTTED_SIX_POINTED_STAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SIX_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SKATEBOARD as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SKIS as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SKIER as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SKIS as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SKULL as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SKULL_AND_CROSSBONES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SKUNK as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SLED as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SLEEPING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_BED.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SLEEPING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SLEEPY_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SLEEPY_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SLIGHTLY_FROWNING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SLIGHTLY_SMILING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SLOT_MACHINE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SLOTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SLOVAKIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SLOVENIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SMALL_AIRPLANE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::SMALL_BLUE_DIAMOND as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::SMALL_ORANGE_DIAMOND as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RED_TRIANGLE_POINTED_UP as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::RED_TRIANGLE_POINTED_DOWN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_CAT_WITH_SMILING_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_FACE_WITH_BIG_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_CAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_CAT_WITH_HEART_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_FACE_WITH_HALO as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_FACE_WITH_HEART_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_FACE_WITH_HEARTS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_FACE_WITH_HORNS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_FACE_WITH_SUNGLASSES as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_FACE_WITH_TEAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_FACE_WITH_HEARTS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMILING_FACE_WITH_HORNS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMIRKING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::CAT_WITH_WRY_SMILE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SMIRKING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::CIGARETTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SNAIL as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SNAKE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SNEEZING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SNOW_CAPPED_MOUNTAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SNOWBOARDER.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SNOWFLAKE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SNOWMAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SNOWMAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SNOWMAN_WITHOUT_SNOW as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SOAP as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::LOUDLY_CRYING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SOCCER_BALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SOCCER_BALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SOCKS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::SOFT_ICE_CREAM as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SOFTBALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SOLOMON_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SOMALIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::SOON_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::SOON_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::SOS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::SOS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SPEAKER_MEDIUM_VOLUME as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SOUTH_AFRICA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SOUTH_GEORGIA_AND_SOUTH_SANDWICH_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SOUTH_SUDAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ALIEN_MONSTER as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SPADE_SUIT as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SPADE_SUIT as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::SPAGHETTI as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::SPARKLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SPARKLER as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SPARKLES as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SPARKLING_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SPEAK_NO_EVIL_MONKEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SPEAK_NO_EVIL_MONKEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SPEAKER_LOW_VOLUME as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SPEAKER_HIGH_VOLUME as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SPEAKER_LOW_VOLUME as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SPEAKER_MEDIUM_VOLUME as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SPEAKING_HEAD as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SPEECH_BALLOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SPEEDBOAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SPIDER as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SPIDER_WEB as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SPIRAL_CALENDAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SPIRAL_NOTEPAD as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SPIRAL_SHELL as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SPONGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::SPOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SPORT_UTILITY_VEHICLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::SPORTS_MEDAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SPOUTING_WHALE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::SQUID as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SQUINTING_FACE_WITH_TONGUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SRI_LANKA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ST_BARTHELEMY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ST_HELENA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ST_KITTS_AND_NEVIS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ST_LUCIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ST_MARTIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ST_PIERRE_AND_MIQUELON as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ST_VINCENT_AND_GRENADINES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::STADIUM as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_STANDING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_STANDING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_STANDING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::STAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::GLOWING_STAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::STAR_AND_CRESCENT as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::STAR_OF_DAVID as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::STAR_STRUCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SHOOTING_STAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::STATION as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::STATUE_OF_LIBERTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::LOCOMOTIVE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::STEAMING_BOWL as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::STETHOSCOPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::POT_OF_FOOD as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::STOP_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::STOP_SIGN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::STOPWATCH as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::STRAIGHT_RULER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::STRAWBERRY as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_TONGUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SQUINTING_FACE_WITH_TONGUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::WINKING_FACE_WITH_TONGUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::STUDENT.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::STUDIO_MICROPHONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::STUFFED_FLATBREAD as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SUDAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SUN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SUN_BEHIND_CLOUD as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SUN_BEHIND_LARGE_CLOUD as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SUN_BEHIND_RAIN_CLOUD as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SUN_BEHIND_SMALL_CLOUD as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SUN_WITH_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SUNFLOWER as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SUNGLASSES as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SUN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SUNRISE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SUNRISE_OVER_MOUNTAINS as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SUNSET as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SUPERHERO.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SUPERHERO.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SUPERHERO.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SUPERVILLAIN.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SUPERVILLAIN.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SUPERVILLAIN.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_SURFING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_SURFING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_SURFING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SURINAME as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::SUSHI as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SUSPENSION_RAILWAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SVALBARD_AND_JAN_MAYEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::SWAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ESWATINI as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::DOWNCAST_FACE_WITH_SWEAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SWEAT_DROPLETS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::SWEAT_DROPLETS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::GRINNING_FACE_WITH_SWEAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SWEDEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::ROASTED_SWEET_POTATO as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::BRIEFS as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_SWIMMING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_SWIMMING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_SWIMMING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SWITZERLAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::INPUT_SYMBOLS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::SYNAGOGUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_SYRIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::SYRINGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::T_REX as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::T_REX as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::T_SHIRT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::TACO as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::PARTY_POPPER as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TAIWAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TAJIKISTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::TAKEOUT_BOX as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::TAMALE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::TANABATA_TREE as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::TANGERINE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TANZANIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::TAURUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TAXI as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::TEACUP_WITHOUT_HANDLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::TEACHER.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::TEACUP_WITHOUT_HANDLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::TEAPOT as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TEAR_OFF_CALENDAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::TECHNOLOGIST.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::TEDDY_BEAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TELEPHONE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TELEPHONE_RECEIVER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TELESCOPE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TELEVISION as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TEN_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TEN_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::TENNIS as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TENT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TEST_TUBE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_THAILAND as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::THERMOMETER as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::THINKING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::THINKING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::THIRD_PLACE_MEDAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::THONG_SANDAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::THOUGHT_BALLOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::THREAD as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_3 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::THREE_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::THREE_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::THUMBS_DOWN.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::THUMBS_UP.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::THUMBS_DOWN.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::THUMBS_UP.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::TICKET as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::ADMISSION_TICKETS as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::TIGER as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::TIGER as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::TIGER_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TIMER_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TIMOR_LESTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_TIPPING_HAND.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_TIPPING_HAND.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::TIRED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::TRADE_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TOGO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TOILET as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TOKELAU as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TOKYO_TOWER as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::TOMATO as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TONGA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::TONGUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TOOLBOX as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::TOOTH as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TOOTHBRUSH as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::TOP_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::TOP_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TOP_HAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TOP_HAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TORNADO as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TURKEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TRACKBALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TRACTOR as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::TRADE_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HORIZONTAL_TRAFFIC_LIGHT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TRAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TRAIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TRAM as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TRAM_CAR as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::TRANSGENDER_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::TRANSGENDER_SYMBOL as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::TRIANGULAR_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::TRIANGULAR_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TRIANGULAR_RULER as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::TRIDENT_EMBLEM as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::TRIDENT_EMBLEM as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TRINIDAD_AND_TOBAGO as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TRISTAN_DA_CUNHA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_WITH_STEAM_FROM_NOSE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::TROLL as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TROLLEYBUS as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::TROPHY as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::TROPICAL_DRINK as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::TROPICAL_FISH as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::DELIVERY_TRUCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TRUMPET as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::T_SHIRT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::TULIP as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::TUMBLER_GLASS as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TUNISIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::TURKEY as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TURKMENISTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TURKS_AND_CAICOS_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::TURTLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_TUVALU as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::TELEVISION as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TWELVE_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TWELVE_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::SHUFFLE_TRACKS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_2 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::TWO_HEARTS as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::TWO_HUMP_CAMEL as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Males).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TWO_O_CLOCK as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::TWO_THIRTY as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Females).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_DISCOUNT_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_PASSING_GRADE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_OPEN_FOR_BUSINESS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_RESERVED_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_MONTHLY_AMOUNT_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_NOT_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_NO_VACANCY_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_APPLICATION_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_PROHIBITED_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::JAPANESE_VACANCY_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UGANDA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UKRAINE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::UMBRELLA as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::UMBRELLA_ON_GROUND as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::UMBRELLA_WITH_RAIN_DROPS as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::UNAMUSED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::UNAMUSED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::NO_ONE_UNDER_EIGHTEEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::UNICORN as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UNITED_ARAB_EMIRATES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UNITED_NATIONS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::UNLOCKED as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::UNLOCKED as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::UP_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::UP_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::UP_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::UP_DOWN_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::UP_LEFT_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::UP_RIGHT_ARROW as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::UPSIDE_DOWN_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::UPWARDS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_URUGUAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UNITED_STATES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_US_OUTLYING_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_US_VIRGIN_ISLANDS as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_UZBEKISTAN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::VICTORY_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::VAMPIRE.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::VAMPIRE.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::VAMPIRE.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_VANUATU as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_VATICAN_CITY as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_VENEZUELA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::VERTICAL_TRAFFIC_LIGHT as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::VIDEOCASSETTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::VIBRATION_MODE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::VICTORY_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::VIDEO_CAMERA as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::VIDEO_GAME as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::VIDEOCASSETTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_VIETNAM as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::VIOLIN as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::VIRGO as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::VOLCANO as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::VOLLEYBALL as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_VOMITING as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::VS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::VS_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::VULCAN_SALUTE.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::WAFFLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_WALES as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WALKING.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WALKING.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WALKING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_WALLIS_AND_FUTUNA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::WANING_CRESCENT_MOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::WANING_GIBBOUS_MOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WARNING as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WASTEBASKET as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::WATCH as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::WATER_BUFFALO as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WATER_CLOSET as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WATER_PISTOL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_PLAYING_WATER_POLO.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::WATER_WAVE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::WATERMELON as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::WAVING_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::WAVING_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WAVY_DASH as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::WAXING_CRESCENT_MOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::WAXING_GIBBOUS_MOON as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WATER_CLOSET as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::WEARY_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::WEARY_CAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::WEARY_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::WEDDING as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_LIFTING_WEIGHTS.default.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_LIFTING_WEIGHTS.gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_LIFTING_WEIGHTS.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_WESTERN_SAHARA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::WHALE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::WHALE as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::WHEEL as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WHEEL_OF_DHARMA as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WHEELCHAIR_SYMBOL as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WHEELCHAIR_SYMBOL as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WHITE_CANE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CHECK_MARK_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WHITE_CIRCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WHITE_EXCLAMATION_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::WHITE_FLAG as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::WHITE_FLOWER as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "component")] Some(&crate::flat::WHITE_HAIR as &crate::Emoji),
+	#[cfg(not(emojic_group = "component"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::White).gender(Gender::Male).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::White).gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::WHITE_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WHITE_LARGE_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WHITE_MEDIUM_SMALL_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WHITE_MEDIUM_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WHITE_QUESTION_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WHITE_SMALL_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WHITE_SQUARE_BUTTON as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::WILTED_FLOWER as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::WIND_CHIME as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::WIND_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WINDOW as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "food_and_drink")] Some(&crate::flat::WINE_GLASS as &crate::Emoji),
+	#[cfg(not(emojic_group = "food_and_drink"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::WINKING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::WINKING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::WINKING_FACE_WITH_TONGUE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::WOLF as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.default.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ARTIST.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ASTRONAUT.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON.hair(Hair::Beard).gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_CARTWHEELING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::COOK.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_DANCING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_FACEPALMING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FACTORY_WORKER.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FARMER.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_FEEDING_BABY.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::FIREFIGHTER.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::HEALTH_WORKER.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_IN_TUXEDO.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::JUDGE.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_JUGGLING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::MECHANIC.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::OFFICE_WORKER.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PILOT.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_PLAYING_HANDBALL.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_PLAYING_WATER_POLO.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WOMAN_S_BOOT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WOMAN_S_CLOTHES as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WOMAN_S_HAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WOMAN_S_SANDAL as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SCIENTIST.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_SHRUGGING.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::SINGER.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::STUDENT.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::TEACHER.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::TECHNOLOGIST.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::WOMAN_WITH_HEADSCARF.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_WHITE_CANE.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WEARING_TURBAN.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WITH_VEIL.gender(Gender::Female).default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WOMAN_S_CLOTHES as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WOMAN_S_HAT as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WOMEN_S_ROOM as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WRESTLING.gender(Gender::Female) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::WOMEN_S_ROOM as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::WOOD as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::WOOZY_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::WORLD_MAP as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::WORM as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::WORRIED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::WORRIED_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::WRAPPED_GIFT as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::WRENCH as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::PERSON_WRESTLING.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::WRITING_HAND.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::CROSS_MARK as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::X_RAY as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::YARN as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::YAWNING_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::YELLOW_CIRCLE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::YELLOW_HEART as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::YELLOW_SQUARE as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_YEMEN as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::YEN_BANKNOTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "objects")] Some(&crate::flat::YEN_BANKNOTE as &crate::Emoji),
+	#[cfg(not(emojic_group = "objects"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::YIN_YANG as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "activities")] Some(&crate::flat::YO_YO as &crate::Emoji),
+	#[cfg(not(emojic_group = "activities"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::FACE_SAVORING_FOOD as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ZAMBIA as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ZANY_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "travel_and_places")] Some(&crate::flat::HIGH_VOLTAGE as &crate::Emoji),
+	#[cfg(not(emojic_group = "travel_and_places"))] None,
+	#[cfg(emojic_group = "animals_and_nature")] Some(&crate::flat::ZEBRA as &crate::Emoji),
+	#[cfg(not(emojic_group = "animals_and_nature"))] None,
+	#[cfg(emojic_group = "symbols")] Some(&crate::flat::KEYCAP_0 as &crate::Emoji),
+	#[cfg(not(emojic_group = "symbols"))] None,
+	#[cfg(emojic_group = "flags")] Some(&crate::flat::FLAG_ZIMBABWE as &crate::Emoji),
+	#[cfg(not(emojic_group = "flags"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ZIPPER_MOUTH_FACE as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ZOMBIE.default as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ZOMBIE.gender(Gender::Male) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "people_and_body")] Some(&crate::flat::ZOMBIE.gender(Gender::Female) as &crate::Emoji),
+	#[cfg(not(emojic_group = "people_and_body"))] None,
+	#[cfg(emojic_group = "smileys_and_emotion")] Some(&crate::flat::ZZZ as &crate::Emoji),
+	#[cfg(not(emojic_group = "smileys_and_emotion"))] None,
+
+];
+
+/// Looks up the emoji of the given alias (without colons) in the FST
+pub(crate) fn lookup(s: &str) -> Option<&'static crate::Emoji> {
+	let map = fst::Map::new(ALIAS_FST).expect("Invalid alias FST");
+	map.get(s).and_then(|idx| EMOJIS[idx as usize])
+}
+
+// EOF
@@ -0,0 +1,137 @@
+//! Command line argument parsing
+//!
+//! This module contains the [`EmojiValueParser`] to parse emojis from the command line
+//! arguments with the [`clap`](https://crates.io/crates/clap) crate.
+//! It requires the `clap` feature.
+
+extern crate std;
+
+use std::ffi::OsStr;
+use std::format;
+
+use alloc::string::{String, ToString};
+
+use ::clap::builder::{TypedValueParser, ValueParserFactory};
+use ::clap::error::ErrorKind;
+use ::clap::{Arg, Command, Error};
+
+use crate::emojis::Emoji;
+
+/// The maximum number of aliases suggested for an unknown emoji
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Parses an emoji from a command line argument.
+///
+/// The emoji may be given as alias, either colon-fenced or not (e.g. `:tada:` or `tada`), by its
+/// [`name`](Emoji::name) (e.g. `party popper`, ignoring ASCII case), or as the emoji itself
+/// (e.g. `🎉`, see [`lookup`](crate::lookup)). If the argument is none of these, the error
+/// message suggests similar aliases, if any.
+///
+/// The parser is also used by clap's `value_parser!(&'static Emoji)`.
+///
+/// # Examples
+/// ```
+/// use clap::{Arg, Command};
+/// use emojic::clap::EmojiValueParser;
+/// use emojic::emojis::Emoji;
+///
+/// let cmd = Command::new("react").arg(
+///     Arg::new("reaction")
+///         .long("reaction")
+///         .value_parser(EmojiValueParser),
+/// );
+///
+/// let matches = cmd.clone().get_matches_from(["react", "--reaction", ":tada:"]);
+/// let reaction = matches.get_one::<&'static Emoji>("reaction").copied();
+/// assert_eq!(Some(&emojic::flat::PARTY_POPPER), reaction);
+///
+/// let err = cmd.try_get_matches_from(["react", "--reaction", ":tadaa:"]).unwrap_err();
+/// assert!(err.to_string().contains(":tada:"));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmojiValueParser;
+impl EmojiValueParser {
+    /// Parses the given argument, see [`EmojiValueParser`]
+    fn parse_str(value: &str) -> Option<&'static Emoji> {
+        crate::parse(value)
+            .or_else(|| crate::search::all().find(|e| e.name.eq_ignore_ascii_case(value)))
+    }
+}
+impl TypedValueParser for EmojiValueParser {
+    type Value = &'static Emoji;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let value = value
+            .to_str()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+
+        Self::parse_str(value).ok_or_else(|| {
+            let arg = arg.map_or_else(|| String::from("..."), |arg| arg.to_string());
+            let mut message = format!("invalid emoji '{}' for '{}'", value, arg);
+
+            let suggestions = crate::text::suggest_aliases(value, MAX_SUGGESTIONS);
+            if let Some((first, rest)) = suggestions.split_first() {
+                message.push_str(&format!("\n\n  tip: did you mean :{}:", first));
+                for alias in rest {
+                    message.push_str(&format!(", :{}:", alias));
+                }
+                message.push('?');
+            }
+            message.push('\n');
+
+            Error::raw(ErrorKind::InvalidValue, message).with_cmd(cmd)
+        })
+    }
+}
+/// Allows to use `value_parser!(&'static Emoji)`
+impl ValueParserFactory for &'static Emoji {
+    type Parser = EmojiValueParser;
+
+    fn value_parser() -> Self::Parser {
+        EmojiValueParser
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::prelude::v1::*;
+
+    use super::*;
+
+    fn parse(value: &str) -> Result<&'static Emoji, Error> {
+        Command::new("test")
+            .arg(
+                Arg::new("emoji")
+                    .long("emoji")
+                    .value_parser(::clap::value_parser!(&'static Emoji)),
+            )
+            .try_get_matches_from(["test", "--emoji", value])
+            .map(|matches| *matches.get_one::<&'static Emoji>("emoji").unwrap())
+    }
+
+    #[test]
+    fn value_parser_test() {
+        use crate::flat::{PARTY_POPPER, THUMBS_UP};
+
+        assert_eq!(&PARTY_POPPER, parse(":tada:").unwrap());
+        assert_eq!(&PARTY_POPPER, parse("tada").unwrap());
+        assert_eq!(&PARTY_POPPER, parse("🎉").unwrap());
+        assert_eq!(&PARTY_POPPER, parse("Party Popper").unwrap());
+        assert_eq!(&*THUMBS_UP, parse(":+1:").unwrap());
+    }
+
+    #[test]
+    fn value_parser_errors() {
+        let err = parse(":tadaa:").unwrap_err();
+        assert_eq!(ErrorKind::InvalidValue, err.kind());
+        assert!(err.to_string().contains("did you mean :tada:"));
+
+        let err = parse("no such emoji at all").unwrap_err();
+        assert!(!err.to_string().contains("did you mean"));
+    }
+}
@@ -2,21 +2,35 @@
 //! Types for representing and customizing emoji
 //!
 
+use core::borrow::Borrow;
+use core::convert::TryFrom;
 use core::fmt;
 use core::fmt::Display;
+use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
 use core::ops::Deref;
 
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
 mod attributes;
+pub use attributes::Attribute;
+pub use attributes::Attributes;
 pub use attributes::Family;
 pub use attributes::Gender;
 pub use attributes::Hair;
+pub use attributes::Labeled;
 pub use attributes::OneOrTwo;
 pub use attributes::Pair;
+pub use attributes::ParseAttributeError;
+pub use attributes::ParseVersionError;
 pub use attributes::Tone;
 pub use attributes::TonePair;
 pub use attributes::Version;
 
+#[cfg(feature = "serde")]
+mod serialization;
+
 /// A specific emoji.
 ///
 /// This is the basic type for all emojis, whether obtained via any of the statics (as defined in
@@ -29,7 +43,8 @@ pub use attributes::Version;
 /// (such as [`Tone`], [`Gender`], or [`Hair`] respectively) and will eventually yield an `Emoji`.
 ///
 /// `Emoji` implements `Display` to be directly printable (e.g. with `println!`). This will simply
-/// print the [`grapheme`](Self::grapheme) (the Unicode sequence) of this emoji.
+/// print the [`grapheme`](Self::grapheme) (the Unicode sequence) of this emoji, or its
+/// [`name`](Self::name) with the alternate flag (`{:#}`).
 /// Additionally, this struct contains some meta data such as the explanatory
 /// [`name`](Self::grapheme) of the emoji.
 ///
@@ -65,11 +80,354 @@ impl Emoji {
             grapheme,
         }
     }
+
+    /// Returns the [`name`](Self::name) of this emoji.
+    ///
+    /// Like the other accessors, this is a `const fn`, which allows to use it in const contexts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::CRAB;
+    /// const NAME: &str = CRAB.name();
+    /// assert_eq!(NAME, "crab");
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the [`since`](Self::since) version of this emoji.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::emojis::Version;
+    /// # use emojic::flat::CRAB;
+    /// const SINCE: Version = CRAB.since();
+    /// assert_eq!(SINCE, Version(1, 0));
+    /// ```
+    pub const fn since(&self) -> Version {
+        self.since
+    }
+
+    /// Returns the [`grapheme`](Self::grapheme) of this emoji, same as [`as_str`](Self::as_str).
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::{CRAB, WAVING_HAND};
+    /// const GRAPHEMES: [&str; 2] = [CRAB.grapheme(), WAVING_HAND.default.grapheme()];
+    /// assert_eq!(GRAPHEMES, ["🦀", "👋"]);
+    /// ```
+    pub const fn grapheme(&self) -> &'static str {
+        self.grapheme
+    }
+
+    /// Returns the [`grapheme`](Self::grapheme) of this emoji, i.e. the emoji as string slice.
+    ///
+    /// Unlike `to_string`, this does not allocate. Similarly, `Emoji` (as well as a [`With`] of
+    /// emojis) implements `AsRef<str>`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::{CRAB, WAVING_HAND};
+    /// let mut greeting = String::from("Hi ");
+    /// greeting.push_str(WAVING_HAND.as_str());
+    /// assert_eq!(greeting, "Hi 👋");
+    ///
+    /// fn shout(s: impl AsRef<str>) -> String {
+    ///     format!("{}!", s.as_ref())
+    /// }
+    /// assert_eq!(shout(CRAB), "🦀!");
+    /// assert_eq!(shout(WAVING_HAND), "👋!"); // the default variant
+    /// ```
+    pub const fn as_str(&self) -> &'static str {
+        self.grapheme
+    }
+
+    /// Iterates over the Unicode scalar values making up this emoji's grapheme.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::RED_HEART;
+    /// let chars: Vec<u32> = RED_HEART.codepoints().map(|c| c as u32).collect();
+    /// assert_eq!(chars, [0x2764, 0xFE0F]);
+    /// ```
+    pub fn codepoints(&self) -> core::str::Chars<'static> {
+        self.grapheme.chars()
+    }
+
+    /// Formats the codepoints of this emoji in the `U+XXXX` notation, separated by spaces.
+    ///
+    /// This is useful for logging or debugging which Unicode sequence an emoji actually is, e.g.
+    /// when it is rendered unexpectedly.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::{RED_HEART, WAVING_HAND};
+    /// # use emojic::Tone;
+    /// assert_eq!(RED_HEART.codepoints_notation().to_string(), "U+2764 U+FE0F");
+    /// assert_eq!(
+    ///     WAVING_HAND.tone(Tone::Dark).codepoints_notation().to_string(),
+    ///     "U+1F44B U+1F3FF"
+    /// );
+    /// ```
+    pub fn codepoints_notation(&self) -> impl Display + 'static {
+        struct Notation(&'static str);
+
+        impl Display for Notation {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                for (i, c) in self.0.chars().enumerate() {
+                    if i > 0 {
+                        f.write_str(" ")?;
+                    }
+                    write!(f, "U+{:04X}", c as u32)?;
+                }
+                Ok(())
+            }
+        }
+
+        Notation(self.grapheme)
+    }
+
+    /// Returns the fully-qualified form of this emoji, i.e. with the emoji presentation selector
+    /// (U+FE0F) where it is required.
+    ///
+    /// This is just an owned copy of the [`grapheme`](Self::grapheme), which is always
+    /// fully-qualified, the counterpart of [`unqualified`](Self::unqualified).
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::RED_HEART;
+    /// assert_eq!(RED_HEART.qualified(), "\u{2764}\u{FE0F}");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+    pub fn qualified(&self) -> String {
+        self.grapheme.into()
+    }
+
+    /// Returns the unqualified form of this emoji, i.e. without any emoji presentation selector
+    /// (U+FE0F).
+    ///
+    /// Depending on the environment, an emoji without the selector may be rendered in its text
+    /// presentation (e.g. as a monochrome glyph) instead. The emoji can still be looked up by
+    /// this form via [`lookup`](crate::lookup).
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::{CRAB, RED_HEART};
+    /// assert_eq!(RED_HEART.unqualified(), "\u{2764}");
+    /// assert_eq!(CRAB.unqualified(), "🦀"); // has no selector anyway
+    /// assert_eq!(emojic::lookup(&RED_HEART.unqualified()), Some(&RED_HEART));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+    pub fn unqualified(&self) -> String {
+        self.grapheme.chars().filter(|&c| c != '\u{FE0F}').collect()
+    }
+
+    /// Returns the minimally-qualified form of this emoji, if it has one.
+    ///
+    /// The Unicode emoji list marks sequences as minimally-qualified, which have the emoji
+    /// presentation selector (U+FE0F) only after their first char, but lack some of the later
+    /// ones of the fully-qualified [`grapheme`](Self::grapheme). Some systems emit this form,
+    /// which can also be looked up via [`lookup`](crate::lookup). Emojis which are not sequences,
+    /// or whose only selector is after the first char, have no such form.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::{EYE_IN_SPEECH_BUBBLE, FACE_IN_CLOUDS, RED_HEART};
+    /// assert_eq!(Some("😶\u{200D}🌫"), FACE_IN_CLOUDS.minimally_qualified());
+    /// assert_eq!(
+    ///     Some("👁\u{FE0F}\u{200D}🗨"),
+    ///     EYE_IN_SPEECH_BUBBLE.minimally_qualified()
+    /// );
+    /// assert_eq!(None, RED_HEART.minimally_qualified());
+    /// assert_eq!(
+    ///     Some(&FACE_IN_CLOUDS),
+    ///     FACE_IN_CLOUDS.minimally_qualified().and_then(emojic::lookup)
+    /// );
+    /// ```
+    pub fn minimally_qualified(&self) -> Option<&'static str> {
+        let qualified = crate::qualified::MINIMALLY_QUALIFIED;
+        qualified
+            .binary_search_by_key(&self.grapheme, |&(grapheme, _)| grapheme)
+            .ok()
+            .map(|idx| qualified[idx].1)
+    }
+
+    /// Tests whether the given `grapheme` is this emoji, regardless of any emoji presentation
+    /// selectors (U+FE0F).
+    ///
+    /// Unlike comparing with [`grapheme`](Self::grapheme), this also matches the unqualified
+    /// forms frequently found in copy-pasted emojis, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::{CRAB, RED_HEART};
+    /// assert!(RED_HEART.same_grapheme("\u{2764}\u{FE0F}"));
+    /// assert!(RED_HEART.same_grapheme("\u{2764}")); // unqualified
+    /// assert!(!RED_HEART.same_grapheme("\u{2764}\u{FE0F}\u{200D}\u{1F525}")); // heart on fire
+    /// assert!(CRAB.same_grapheme("🦀"));
+    /// ```
+    pub fn same_grapheme(&self, grapheme: &str) -> bool {
+        use crate::search::unqualified_chars;
+
+        unqualified_chars(self.grapheme).eq(unqualified_chars(grapheme))
+    }
+
+    /// Tests whether this emoji is the base emoji of the given `variant`, i.e. whether
+    /// [`base_of`](crate::base_of) the `variant` is this emoji.
+    ///
+    /// A base emoji is also the base of itself.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::{CRAB, WAVING_HAND};
+    /// # use emojic::Tone;
+    /// assert!(WAVING_HAND.is_default_of(WAVING_HAND.tone(Tone::Dark)));
+    /// assert!(WAVING_HAND.is_default_of(&WAVING_HAND));
+    /// assert!(!WAVING_HAND.tone(Tone::Dark).is_default_of(&WAVING_HAND));
+    /// assert!(!CRAB.is_default_of(&WAVING_HAND));
+    /// ```
+    pub fn is_default_of(&self, variant: &Emoji) -> bool {
+        crate::base_of(variant).is_some_and(|base| base.grapheme == self.grapheme)
+    }
+
+    /// Returns the number of columns this emoji occupies when displayed, e.g. in a terminal.
+    ///
+    /// Unlike the naive sum over the widths of its codepoints, the entire grapheme is treated as
+    /// a single cell. Thus, sequences (e.g. with a presentation selector, a skin tone modifier,
+    /// or joined by zero width joiners) are always double-wide, while the width of a single
+    /// codepoint emoji is determined by the [`unicode-width`](https://crates.io/crates/unicode-width)
+    /// crate (which is 2 for all emojis of this crate).
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::{CRAB, FAMILY, RED_HEART};
+    /// assert_eq!(2, CRAB.display_width());
+    /// assert_eq!(2, RED_HEART.display_width()); // ❤️ (with U+FE0F)
+    /// assert_eq!(2, FAMILY.display_width()); // 👪
+    /// assert_eq!(2, emojic::lookup("👨‍👩‍👧").unwrap().display_width()); // ZWJ sequence
+    /// ```
+    #[cfg(feature = "unicode-width")]
+    #[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "unicode-width")))]
+    pub fn display_width(&self) -> usize {
+        let mut chars = self.grapheme.chars();
+        match (chars.next(), chars.next()) {
+            // A single codepoint has a width of its own
+            (Some(c), None) => unicode_width::UnicodeWidthChar::width(c).unwrap_or(0),
+            // Any sequence forms a single double-wide cell
+            _ => 2,
+        }
+    }
 }
 
+/// Returns the number of emojis introduced per Unicode Emoji version, sorted by version.
+///
+/// Every variant of an emoji (e.g. each toned one) counts as an emoji of its own, as each of them
+/// has its own [`since`](Emoji::since) version. The numbers are those of all emojis, regardless of
+/// the selected `group-*` features, and have been tallied when the emojis were generated.
+///
+/// # Examples
+/// ```
+/// use emojic::emojis::Version;
+///
+/// let histogram = emojic::version_histogram();
+/// let (_, count) = histogram.iter().find(|(v, _)| *v == Version(14, 0)).unwrap();
+/// println!("Emoji 14.0 added {} emojis", count);
+///
+/// assert!(histogram.windows(2).all(|w| w[0].0 < w[1].0));
+/// ```
+pub fn version_histogram() -> &'static [(Version, usize)] {
+    crate::versions::VERSION_HISTOGRAM
+}
+
+/// Displays the [`grapheme`](Emoji::grapheme), or with the alternate flag (`{:#}`) the
+/// [`name`](Emoji::name) of the emoji, e.g. as alt text.
+///
+/// # Examples
+/// ```
+/// # use emojic::flat::WAVING_HAND;
+/// # use emojic::Tone;
+/// assert_eq!(format!("{}", WAVING_HAND), "👋");
+/// assert_eq!(format!("{:#}", WAVING_HAND), "waving hand");
+/// assert_eq!(format!("{:#}", WAVING_HAND.tone(Tone::Dark)), "waving hand: dark skin tone");
+/// ```
 impl Display for Emoji {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.grapheme)
+        if f.alternate() {
+            write!(f, "{}", self.name)
+        } else {
+            write!(f, "{}", self.grapheme)
+        }
+    }
+}
+
+impl AsRef<str> for Emoji {
+    fn as_ref(&self) -> &str {
+        self.grapheme
+    }
+}
+
+/// An emoji compared and hashed by its [`grapheme`](Emoji::grapheme) only.
+///
+/// Unlike `Emoji` itself, whose `Eq` and `Hash` impls include the name and version, this allows
+/// to key e.g. a `HashSet` purely by grapheme. Since it implements `Borrow<str>`, such a set
+/// can be queried with a plain grapheme, e.g. as parsed from some text.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use emojic::emojis::ByGrapheme;
+/// use emojic::flat::{CRAB, PARTY_POPPER};
+///
+/// let mut used = HashSet::new();
+/// used.insert(ByGrapheme(&CRAB));
+/// used.insert(ByGrapheme(&PARTY_POPPER));
+/// used.insert(ByGrapheme(emojic::parse_alias(":crab:").unwrap()));
+///
+/// assert_eq!(2, used.len());
+/// assert!(used.contains("🦀"));
+/// assert_eq!(Some(&ByGrapheme(&CRAB)), used.get("🦀"));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ByGrapheme(pub &'static Emoji);
+impl PartialEq for ByGrapheme {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.grapheme == other.0.grapheme
+    }
+}
+impl Eq for ByGrapheme {}
+impl PartialOrd for ByGrapheme {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for ByGrapheme {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.grapheme.cmp(other.0.grapheme)
+    }
+}
+/// Hashes just as the grapheme `str`, as required by `Borrow<str>`
+impl Hash for ByGrapheme {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.grapheme.hash(state)
+    }
+}
+impl Borrow<str> for ByGrapheme {
+    fn borrow(&self) -> &str {
+        self.0.grapheme
+    }
+}
+impl Deref for ByGrapheme {
+    type Target = Emoji;
+
+    fn deref(&self) -> &Emoji {
+        self.0
+    }
+}
+impl From<&'static Emoji> for ByGrapheme {
+    fn from(emoji: &'static Emoji) -> Self {
+        ByGrapheme(emoji)
     }
 }
 
@@ -89,13 +447,54 @@ pub struct WithNoDef<M, T: 'static> {
 }
 
 impl<M, T> WithNoDef<M, T> {
-    pub(crate) const fn new(entries: &'static [T]) -> Self {
-        //assert_eq!(entries.len(), M::SIZE); invalid in const fn
+    #[allow(dead_code)] // Unused if only groups without customizable emojis are selected
+    pub(crate) const fn new(entries: &'static [T]) -> Self
+    where
+        M: Attribute,
+    {
+        // Since the emojis are statics, this fails already at compile time
+        assert!(
+            entries.len() == M::SIZE,
+            "The number of entries does not match the attribute"
+        );
         WithNoDef {
             entries,
             _m: PhantomData,
         }
     }
+
+    /// Iterates over all variants, in the order of the attribute's `ALL` list.
+    ///
+    /// If `T` is customizable by itself (e.g. a `With`), the variants are these nested
+    /// composers.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::PERSON_DANCING;
+    /// // Each variant is toneable by itself and derefs to its default
+    /// let dancers: String = PERSON_DANCING.variants().map(|e| e.grapheme).collect();
+    /// assert_eq!(dancers, "🕺💃");
+    /// ```
+    pub fn variants(&self) -> core::slice::Iter<'static, T> {
+        self.entries.iter()
+    }
+
+    /// Returns the number of variants, i.e. the length of [`variants`](Self::variants).
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::PERSON_DANCING;
+    /// assert_eq!(2, PERSON_DANCING.len());
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether there are no variants, which is never the case for the emojis of this
+    /// crate.
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
 }
 
 /// Customizable emoji composer.
@@ -106,7 +505,7 @@ impl<M, T> WithNoDef<M, T> {
 /// respectively.
 ///
 /// Notice unlike the [`WithNoDef`], this struct has an default variant and thus `Deref`s to `T`,
-/// and implements `Display` if `T` does.
+/// and implements `Display` and `AsRef<str>` if `T` does.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct With<M, T: 'static> {
     pub default: T,
@@ -115,14 +514,98 @@ pub struct With<M, T: 'static> {
 }
 
 impl<M, T> With<M, T> {
-    pub(crate) const fn new(default: T, entries: &'static [T]) -> Self {
-        //assert_eq!(entries.len(), M::SIZE); invalid in const fn
+    #[allow(dead_code)] // Unused if only groups without customizable emojis are selected
+    pub(crate) const fn new(default: T, entries: &'static [T]) -> Self
+    where
+        M: Attribute,
+    {
+        // Since the emojis are statics, this fails already at compile time
+        assert!(
+            entries.len() == M::SIZE,
+            "The number of entries does not match the attribute"
+        );
         With {
             default,
             entries,
             _m: PhantomData,
         }
     }
+
+    /// Iterates over all variants, in the order of the attribute's `ALL` list.
+    ///
+    /// Notice, the [`default`](Self::default) is not among these variants. Also, if `T` is
+    /// customizable by itself (e.g. a `With`), the variants are these nested composers.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::RAISING_HANDS;
+    /// let hands: String = RAISING_HANDS.variants().map(|e| e.grapheme).collect();
+    /// assert_eq!(hands, "🙌🏻🙌🏼🙌🏽🙌🏾🙌🏿");
+    /// ```
+    pub fn variants(&self) -> core::slice::Iter<'static, T> {
+        self.entries.iter()
+    }
+
+    /// Returns the number of variants, i.e. the length of [`variants`](Self::variants).
+    ///
+    /// Notice, the [`default`](Self::default) is not counted.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::RAISING_HANDS;
+    /// assert_eq!(5, RAISING_HANDS.len());
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether there are no variants, which is never the case for the emojis of this
+    /// crate.
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<M: Labeled, T> With<M, T> {
+    /// Iterates over all variants together with the label of their attribute, in the order of
+    /// [`variants`](Self::variants).
+    ///
+    /// This is e.g. handy to build a menu of all variants. The default variant is not included.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::{PERSON, WAVING_HAND};
+    /// let menu: Vec<String> = WAVING_HAND
+    ///     .labeled_variants()
+    ///     .map(|(label, e)| format!("{}: {}", label, e))
+    ///     .collect();
+    /// assert_eq!("light skin tone: 👋🏻", menu[0]);
+    /// assert_eq!("dark skin tone: 👋🏿", menu[4]);
+    ///
+    /// // The variants may be customizable by themselves
+    /// let (label, red_haired) = PERSON.labeled_variants().nth(2).unwrap();
+    /// assert_eq!(("red hair", "🧑‍🦰"), (label, red_haired.grapheme));
+    /// ```
+    pub fn labeled_variants(&self) -> impl Iterator<Item = (&'static str, &'static T)> {
+        M::variants().iter().map(|m| m.label()).zip(self.entries)
+    }
+}
+impl<M: Labeled, T> WithNoDef<M, T> {
+    /// Iterates over all variants together with the label of their attribute, in the order of
+    /// [`variants`](Self::variants).
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::flat::PERSON_DANCING;
+    /// let dancers: Vec<_> = PERSON_DANCING
+    ///     .labeled_variants()
+    ///     .map(|(label, e)| (label, e.grapheme))
+    ///     .collect();
+    /// assert_eq!(dancers, [("man", "🕺"), ("woman", "💃")]);
+    /// ```
+    pub fn labeled_variants(&self) -> impl Iterator<Item = (&'static str, &'static T)> {
+        M::variants().iter().map(|m| m.label()).zip(self.entries)
+    }
 }
 
 impl<M, T> Deref for With<M, T> {
@@ -134,7 +617,233 @@ impl<M, T> Deref for With<M, T> {
 
 impl<M, T: Display> Display for With<M, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.default)
+        // Forward the formatter, to keep flags such as the alternate one
+        self.default.fmt(f)
+    }
+}
+
+impl<M, T: AsRef<str>> AsRef<str> for With<M, T> {
+    fn as_ref(&self) -> &str {
+        self.default.as_ref()
+    }
+}
+
+/// Customizing emojis by attributes determined at runtime.
+///
+/// Unlike the typed customizations of [`With`] (such as [`With::tone`]), which are only
+/// available for emojis that support the respective attribute, this trait is implemented for
+/// all emojis. Thus, generic code (e.g. a picker applying the user's preferred skin tone) can just
+/// try to apply an attribute, and gets `None` if the emoji does not support it.
+///
+/// The variants are looked up by their graphemes, which makes this slower than the typed
+/// customizations.
+///
+/// # Examples
+/// ```
+/// use emojic::emojis::Customizable;
+/// use emojic::flat::{CRAB, PERSON_GESTURING_OK, WAVING_HAND};
+/// use emojic::{Gender, Tone};
+///
+/// assert_eq!(Some(WAVING_HAND.tone(Tone::Dark)), WAVING_HAND.apply_tone(Tone::Dark));
+/// assert_eq!(None, CRAB.apply_tone(Tone::Dark));
+///
+/// // Attributes can be applied one after the other
+/// assert_eq!(
+///     Some(PERSON_GESTURING_OK.gender(Gender::Female).tone(Tone::Light)),
+///     PERSON_GESTURING_OK
+///         .apply_tone(Tone::Light)
+///         .and_then(|e| e.apply_gender(Gender::Female))
+/// );
+/// assert_eq!(None, WAVING_HAND.apply_gender(Gender::Female));
+/// ```
+pub trait Customizable {
+    /// Returns the variant of this emoji with the given skin `tone`, if it supports toning.
+    ///
+    /// Emojis with multiple people get the same `tone` for all of them.
+    fn apply_tone(&self, tone: Tone) -> Option<&'static Emoji>;
+
+    /// Returns the variant of this emoji with the given `gender`, if it supports gendering.
+    ///
+    /// Only emojis of a single person (or of several people of the same gender) are supported.
+    fn apply_gender(&self, gender: Gender) -> Option<&'static Emoji>;
+
+    /// Returns the variant of this emoji with the given `hair` style, if it supports hair styles.
+    ///
+    /// Currently, only the plain person, man, and woman emojis are supported.
+    fn apply_hair(&self, hair: Hair) -> Option<&'static Emoji>;
+}
+impl Customizable for Emoji {
+    fn apply_tone(&self, tone: Tone) -> Option<&'static Emoji> {
+        crate::search::toned(self, tone)
+    }
+
+    fn apply_gender(&self, gender: Gender) -> Option<&'static Emoji> {
+        crate::search::gendered(self, gender)
+    }
+
+    fn apply_hair(&self, hair: Hair) -> Option<&'static Emoji> {
+        crate::search::haired(self, hair)
+    }
+}
+/// Customizes the [`default`](With::default) variant.
+impl<M, T: Customizable> Customizable for With<M, T> {
+    fn apply_tone(&self, tone: Tone) -> Option<&'static Emoji> {
+        self.default.apply_tone(tone)
+    }
+
+    fn apply_gender(&self, gender: Gender) -> Option<&'static Emoji> {
+        self.default.apply_gender(gender)
+    }
+
+    fn apply_hair(&self, hair: Hair) -> Option<&'static Emoji> {
+        self.default.apply_hair(hair)
+    }
+}
+
+impl Attributes {
+    /// Applies these attributes to `emoji`, see [`Customizable`].
+    ///
+    /// Returns `None` if any of the given attributes is not supported by `emoji`, and `emoji`
+    /// itself if there are no attributes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::emojis::Attributes;
+    /// # use emojic::flat::{PERSON, WAVING_HAND};
+    /// # use emojic::{Gender, Hair, Tone};
+    /// let attributes = Attributes {
+    ///     gender: Some(Gender::Male),
+    ///     hair: Some(Hair::White),
+    ///     ..Attributes::default()
+    /// };
+    /// assert_eq!(
+    ///     Some(&**PERSON.hair(Hair::White).gender(Gender::Male)),
+    ///     attributes.apply_to(&PERSON)
+    /// );
+    /// assert_eq!(None, attributes.apply_to(&WAVING_HAND));
+    /// ```
+    pub fn apply_to(self, emoji: &'static Emoji) -> Option<&'static Emoji> {
+        let emoji = match self.gender {
+            Some(gender) => emoji.apply_gender(gender)?,
+            None => emoji,
+        };
+        let emoji = match self.hair {
+            Some(hair) => emoji.apply_hair(hair)?,
+            None => emoji,
+        };
+        match self.tone {
+            Some(tone) => emoji.apply_tone(tone),
+            None => Some(emoji),
+        }
+    }
+}
+
+/// Customizes `emoji` by attributes given as string, such as `"female,dark,curly"`.
+///
+/// The `attributes` are parsed as [`Attributes`], and then applied by
+/// [`apply_to`](Attributes::apply_to). Returns `None` if the `attributes` are invalid, or not
+/// supported by `emoji`.
+///
+/// # Examples
+/// ```
+/// # use emojic::flat::{PERSON, THUMBS_UP};
+/// # use emojic::{Gender, Hair, Tone};
+/// assert_eq!(
+///     Some(PERSON.hair(Hair::Curly).gender(Gender::Female).tone(Tone::Dark)),
+///     emojic::apply(&PERSON, "female,dark,curly")
+/// );
+/// assert_eq!(
+///     Some(THUMBS_UP.tone(Tone::Light)),
+///     emojic::apply(&THUMBS_UP, "light skin tone")
+/// );
+/// assert_eq!(None, emojic::apply(&THUMBS_UP, "female"));
+/// assert_eq!(None, emojic::apply(&THUMBS_UP, "green"));
+/// ```
+pub fn apply(emoji: &'static Emoji, attributes: &str) -> Option<&'static Emoji> {
+    attributes.parse::<Attributes>().ok()?.apply_to(emoji)
+}
+
+/// A customized emoji in its persistable form, i.e. a base emoji and the attributes to apply.
+///
+/// Unlike a resolved [`Emoji`], this retains the choice that was made, e.g. "the raising hands
+/// with medium skin tone", and can be serialized with serde. The `base` is the grapheme of an
+/// emoji of this crate, which is looked up on deserialization (thus, it always refers to a static
+/// string). Attributes which are `None` are omitted when serializing.
+///
+/// # Examples
+/// ```
+/// # use emojic::emojis::EmojiSpec;
+/// # use emojic::flat::RAISING_HANDS;
+/// # use emojic::Tone;
+/// let spec = EmojiSpec {
+///     tone: Some(Tone::Medium),
+///     ..EmojiSpec::new(&RAISING_HANDS)
+/// };
+/// let json = serde_json::to_string(&spec).unwrap();
+/// assert_eq!(r#"{"base":"🙌","tone":"Medium"}"#, json);
+///
+/// let spec: EmojiSpec = serde_json::from_str(&json).unwrap();
+/// assert_eq!(Some(RAISING_HANDS.tone(Tone::Medium)), spec.resolve());
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "serde")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+pub struct EmojiSpec {
+    /// The grapheme of the emoji to customize
+    pub base: &'static str,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tone: Option<Tone>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gender: Option<Gender>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hair: Option<Hair>,
+}
+#[cfg(feature = "serde")]
+impl EmojiSpec {
+    /// Creates a spec of `base` without any attributes.
+    pub const fn new(base: &'static Emoji) -> Self {
+        EmojiSpec {
+            base: base.grapheme,
+            tone: None,
+            gender: None,
+            hair: None,
+        }
+    }
+
+    /// Resolves this spec by applying its attributes to the `base` emoji, see
+    /// [`Attributes::apply_to`].
+    ///
+    /// Returns `None` if `base` is not an emoji of this crate, or if any of the attributes is not
+    /// supported by it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::emojis::EmojiSpec;
+    /// # use emojic::flat::{CRAB, PERSON_FACEPALMING};
+    /// # use emojic::{Gender, Tone};
+    /// let spec = EmojiSpec {
+    ///     gender: Some(Gender::Female),
+    ///     tone: Some(Tone::Dark),
+    ///     ..EmojiSpec::new(&PERSON_FACEPALMING)
+    /// };
+    /// assert_eq!(
+    ///     Some(PERSON_FACEPALMING.gender(Gender::Female).tone(Tone::Dark)),
+    ///     spec.resolve()
+    /// );
+    ///
+    /// let spec = EmojiSpec {
+    ///     tone: Some(Tone::Dark),
+    ///     ..EmojiSpec::new(&CRAB)
+    /// };
+    /// assert_eq!(None, spec.resolve());
+    /// ```
+    pub fn resolve(&self) -> Option<&'static Emoji> {
+        let attributes = Attributes {
+            gender: self.gender,
+            tone: self.tone,
+            hair: self.hair,
+        };
+        attributes.apply_to(crate::search::lookup(self.base)?)
     }
 }
 
@@ -146,11 +855,49 @@ impl<M, T: Display> Display for With<M, T> {
 /// # use emojic::Tone;
 /// assert_eq!(RAISING_HANDS.to_string(), "🙌"); // default, derefs directly to `Emoji`
 /// assert_eq!(RAISING_HANDS.tone(Tone::Medium).to_string(), "🙌🏽"); // Toned variant
+///
+/// // Checked selection by index, e.g. from dynamic input
+/// assert_eq!(Some(RAISING_HANDS.tone(Tone::Medium)), RAISING_HANDS.nth_tone(2));
+/// assert_eq!(None, RAISING_HANDS.nth_tone(5));
+///
+/// // Selection by the skin tone modifier, e.g. from external emoji data
+/// assert_eq!(Some(RAISING_HANDS.tone(Tone::Medium)), RAISING_HANDS.tone_from_char('\u{1F3FD}'));
+/// assert_eq!(None, RAISING_HANDS.tone_from_char('x'));
+///
+/// // All skin tones at once
+/// for (tone, hands) in RAISING_HANDS.toned_variants() {
+///     assert_eq!(RAISING_HANDS.tone(tone), hands);
+/// }
+/// assert_eq!(5, RAISING_HANDS.toned_variants().count());
 /// ```
 impl<T> With<Tone, T> {
     pub const fn tone(&self, tone: Tone) -> &T {
         &self.entries[tone as usize]
     }
+
+    /// Returns the variant of the skin tone at the given index of [`Tone::ALL`], if any.
+    ///
+    /// This is a checked alternative to [`tone`](Self::tone) for dynamic input, e.g. an index
+    /// read from a config file.
+    pub fn nth_tone(&self, idx: usize) -> Option<&T> {
+        self.entries.get(idx)
+    }
+
+    /// Returns the variant of the skin tone given by its modifier character (e.g. `'\u{1F3FB}'`
+    /// for [`Tone::Light`]), if it is one.
+    ///
+    /// See also [`Tone::modifier`].
+    pub fn tone_from_char(&self, c: char) -> Option<&T> {
+        Tone::try_from(c).ok().map(|tone| self.tone(tone))
+    }
+
+    /// Iterates over all toned variants together with their skin tone, in the order of
+    /// [`Tone::ALL`].
+    ///
+    /// The default variant (without any skin tone) is not included.
+    pub fn toned_variants(&self) -> impl Iterator<Item = (Tone, &'static T)> {
+        Tone::ALL.iter().copied().zip(self.entries)
+    }
 }
 /// Customizing by [`Tone`] without defaults.
 ///
@@ -160,6 +907,28 @@ impl<T> WithNoDef<Tone, T> {
     pub const fn tone(&self, tone: Tone) -> &T {
         &self.entries[tone as usize]
     }
+
+    /// Returns the variant of the skin tone at the given index of [`Tone::ALL`], if any.
+    ///
+    /// This is a checked alternative to [`tone`](Self::tone) for dynamic input, e.g. an index
+    /// read from a config file.
+    pub fn nth_tone(&self, idx: usize) -> Option<&T> {
+        self.entries.get(idx)
+    }
+
+    /// Returns the variant of the skin tone given by its modifier character (e.g. `'\u{1F3FB}'`
+    /// for [`Tone::Light`]), if it is one.
+    ///
+    /// See also [`Tone::modifier`].
+    pub fn tone_from_char(&self, c: char) -> Option<&T> {
+        Tone::try_from(c).ok().map(|tone| self.tone(tone))
+    }
+
+    /// Iterates over all toned variants together with their skin tone, in the order of
+    /// [`Tone::ALL`].
+    pub fn toned_variants(&self) -> impl Iterator<Item = (Tone, &'static T)> {
+        Tone::ALL.iter().copied().zip(self.entries)
+    }
 }
 
 /// Customizing by [`TonePair`].
@@ -223,11 +992,22 @@ impl<T> WithNoDef<TonePairReduced, T> {
 /// # use emojic::Gender;
 /// assert_eq!(ELF.to_string(), "🧝"); // default, derefs directly to `Emoji`
 /// assert_eq!(ELF.gender(Gender::Female).to_string(), "🧝‍♀️"); // Variant with gender
+///
+/// let elves: Vec<_> = ELF.gendered_variants().map(|(g, e)| (g, e.grapheme)).collect();
+/// assert_eq!(elves, [(Gender::Male, "🧝‍♂️"), (Gender::Female, "🧝‍♀️")]);
 /// ```
 impl<T> With<Gender, T> {
     pub const fn gender(&self, gender: Gender) -> &T {
         &self.entries[gender as usize]
     }
+
+    /// Iterates over all gendered variants together with their gender, in the order of
+    /// [`Gender::ALL`].
+    ///
+    /// The default variant is not included.
+    pub fn gendered_variants(&self) -> impl Iterator<Item = (Gender, &'static T)> {
+        Gender::ALL.iter().copied().zip(self.entries)
+    }
 }
 /// Customizing by [`Gender`] without defaults.
 ///
@@ -243,6 +1023,12 @@ impl<T> WithNoDef<Gender, T> {
     pub const fn gender(&self, gender: Gender) -> &T {
         &self.entries[gender as usize]
     }
+
+    /// Iterates over all gendered variants together with their gender, in the order of
+    /// [`Gender::ALL`].
+    pub fn gendered_variants(&self) -> impl Iterator<Item = (Gender, &'static T)> {
+        Gender::ALL.iter().copied().zip(self.entries)
+    }
 }
 
 /// Customizing by [`Hair`].
@@ -331,6 +1117,9 @@ impl<T> WithNoDef<OneOrTwo, T> {
 /// assert_eq!(FAMILY.gender((Pair::Males, Pair::Females)).to_string(), "👨‍👨‍👧‍👧"); // Variant with two gender pairs
 /// assert_eq!(FAMILY.gender(Gender::Female.with_children(Pair::Mixed)).to_string(), "👩‍👧‍👦"); // Variant based on composer chain
 /// ```
+///
+/// Every constellation in [`Family::ALL`] has its own emoji, there are no fallback entries.
+/// The generator only emits a `With` if all variants of an emoji exist.
 impl<T> With<Family, T> {
     pub fn gender(&self, family: impl Into<Family>) -> &T {
         &self.entries[family.into().to_id()]
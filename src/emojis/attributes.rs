@@ -2,19 +2,206 @@
 //! Contains various emoji attributes.
 //!
 
+use core::convert::TryFrom;
 use core::fmt;
+use core::num::ParseIntError;
+use core::str::FromStr;
+
+/// An attribute by which emojis are customized, such as [`Tone`] or [`Gender`].
+///
+/// These are the attribute types `M` of [`With`](crate::emojis::With) and
+/// [`WithNoDef`](crate::emojis::WithNoDef), which hold one entry per variant of the attribute.
+///
+/// # Examples
+/// ```
+/// use emojic::emojis::{Attribute, Family, OneOrTwo, TonePair};
+/// use emojic::{Gender, Hair, Pair, Tone};
+///
+/// assert_eq!(Tone::ALL.len(), Tone::SIZE);
+/// assert_eq!(Gender::ALL.len(), Gender::SIZE);
+/// assert_eq!(Hair::ALL.len(), Hair::SIZE);
+/// assert_eq!(Pair::ALL.len(), Pair::SIZE);
+/// assert_eq!(OneOrTwo::ALL.len(), OneOrTwo::SIZE);
+/// assert_eq!(TonePair::ALL.len(), TonePair::SIZE);
+/// assert_eq!(Family::ALL.len(), Family::SIZE);
+///
+/// // The length of a `With` is the size of its attribute
+/// assert_eq!(Tone::SIZE, emojic::flat::WAVING_HAND.len());
+/// ```
+pub trait Attribute {
+    /// The number of variants of this attribute
+    const SIZE: usize;
+}
+impl Attribute for Tone {
+    const SIZE: usize = Self::ALL.len();
+}
+impl Attribute for TonePair {
+    const SIZE: usize = Self::ALL.len();
+}
+impl Attribute for Gender {
+    const SIZE: usize = Self::ALL.len();
+}
+impl Attribute for Pair {
+    const SIZE: usize = Self::ALL.len();
+}
+impl Attribute for OneOrTwo {
+    const SIZE: usize = Self::ALL.len();
+}
+impl Attribute for Family {
+    const SIZE: usize = Self::ALL.len();
+}
+impl Attribute for Hair {
+    const SIZE: usize = Self::ALL.len();
+}
+
+/// An [`Attribute`] whose variants have a descriptive label, such as [`Tone::name`].
+///
+/// This allows to list the variants of a [`With`](crate::emojis::With) together with their labels,
+/// see [`With::labeled_variants`](crate::emojis::With::labeled_variants).
+pub trait Labeled: Attribute + Copy + 'static {
+    /// All variants, in the order of the entries of `With`
+    fn variants() -> &'static [Self];
+
+    /// Returns the descriptive label of this variant
+    fn label(self) -> &'static str;
+}
+impl Labeled for Tone {
+    fn variants() -> &'static [Self] {
+        &Self::ALL
+    }
+    fn label(self) -> &'static str {
+        self.name()
+    }
+}
+impl Labeled for Gender {
+    fn variants() -> &'static [Self] {
+        &Self::ALL
+    }
+    fn label(self) -> &'static str {
+        self.name_adults()
+    }
+}
+impl Labeled for Pair {
+    fn variants() -> &'static [Self] {
+        &Self::ALL
+    }
+    fn label(self) -> &'static str {
+        self.name_adults()
+    }
+}
+impl Labeled for OneOrTwo {
+    fn variants() -> &'static [Self] {
+        &Self::ALL
+    }
+    fn label(self) -> &'static str {
+        self.name_adults()
+    }
+}
+impl Labeled for Hair {
+    fn variants() -> &'static [Self] {
+        &Self::ALL
+    }
+    fn label(self) -> &'static str {
+        self.name()
+    }
+}
+
+/// Error of parsing an attribute from a string.
+///
+/// Returned by the `FromStr` impls of the attributes such as [`Tone`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseAttributeError {
+    /// Descriptive name of the attribute
+    attribute: &'static str,
+}
+impl ParseAttributeError {
+    const fn new(attribute: &'static str) -> Self {
+        ParseAttributeError { attribute }
+    }
+}
+impl fmt::Display for ParseAttributeError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "invalid {}", self.attribute)
+    }
+}
+impl core::error::Error for ParseAttributeError {}
 
 /// Unicode Emoji version.
 ///
 /// This struct is used by [`Emoji`](super::Emoji) to denote when an emoji was introduced.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Version(pub u64, pub u64);
+impl Version {
+    /// The lowest possible version, older than any emoji
+    pub const MIN: Version = Version(0, 0);
+    /// The highest possible version, newer than any emoji
+    pub const MAX: Version = Version(u64::MAX, u64::MAX);
+}
 impl fmt::Display for Version {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         write!(fmt, "{}.{}", self.0, self.1)
     }
 }
 
+/// Parses a version of the form `major.minor`, just as it is displayed.
+///
+/// # Examples
+/// ```
+/// # use emojic::emojis::{ParseVersionError, Version};
+/// assert_eq!(Ok(Version(0, 6)), "0.6".parse());
+/// assert_eq!(Ok(Version(13, 1)), "13.1".parse());
+/// assert_eq!(Err(ParseVersionError::MissingDot), "13".parse::<Version>());
+/// assert_eq!(Err(ParseVersionError::ExtraComponents), "13.1.0".parse::<Version>());
+/// assert!(matches!("a.b".parse::<Version>(), Err(ParseVersionError::InvalidNumber(_))));
+///
+/// // E.g. to filter emojis by a minimum version
+/// let threshold: Version = "12.0".parse().unwrap();
+/// assert!(emojic::flat::CRAB.since < threshold);
+/// ```
+impl FromStr for Version {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('.');
+        let major = parts.next().unwrap_or_default();
+        let minor = parts.next().ok_or(ParseVersionError::MissingDot)?;
+        if parts.next().is_some() {
+            return Err(ParseVersionError::ExtraComponents);
+        }
+
+        let parse = |n: &str| n.parse().map_err(ParseVersionError::InvalidNumber);
+        Ok(Version(parse(major)?, parse(minor)?))
+    }
+}
+
+/// Error of parsing a [`Version`] from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseVersionError {
+    /// There is no dot separating the major and minor number, e.g. `"13"`
+    MissingDot,
+    /// There are more than two components, e.g. `"13.1.0"`
+    ExtraComponents,
+    /// The major or minor number is not a valid number, e.g. `"a.b"`
+    InvalidNumber(ParseIntError),
+}
+impl fmt::Display for ParseVersionError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingDot => write!(fmt, "invalid version, missing dot in `major.minor`"),
+            Self::ExtraComponents => write!(fmt, "invalid version, expected only `major.minor`"),
+            Self::InvalidNumber(err) => write!(fmt, "invalid version number: {}", err),
+        }
+    }
+}
+impl core::error::Error for ParseVersionError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::InvalidNumber(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
 /// Skin tone attribute 🖐️🖐🏻🖐🏼🖐🏽🖐🏾🖐🏿
 ///
 /// Allows to specify the skin tone of supported emojis. Generally speaking, those involving people
@@ -22,6 +209,7 @@ impl fmt::Display for Version {
 ///
 /// The default skin tone is 🖐️ (typically some yellow-ish)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Tone {
     /// Represents the least pigmented skin tone 🖐🏻
@@ -55,6 +243,112 @@ impl Tone {
             Self::Dark => "dark skin tone",
         }
     }
+
+    /// Returns the succeeding variant according to [`ALL`](Self::ALL), if any.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::Tone;
+    /// assert_eq!(Some(Tone::MediumDark), Tone::Medium.next());
+    /// assert_eq!(None, Tone::Dark.next());
+    /// ```
+    pub fn next(self) -> Option<Self> {
+        Self::ALL.get(self as usize + 1).copied()
+    }
+
+    /// Returns the preceding variant according to [`ALL`](Self::ALL), if any.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::Tone;
+    /// assert_eq!(Some(Tone::MediumLight), Tone::Medium.prev());
+    /// assert_eq!(None, Tone::Light.prev());
+    /// ```
+    pub fn prev(self) -> Option<Self> {
+        (self as usize).checked_sub(1).map(|i| Self::ALL[i])
+    }
+
+    /// Returns the Fitzpatrick modifier of this skin tone, i.e. the codepoint that applies it.
+    ///
+    /// The reverse is done by the `TryFrom<char>` implementation.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::Tone;
+    /// assert_eq!('\u{1F3FB}', Tone::Light.modifier());
+    /// assert_eq!('\u{1F3FF}', Tone::Dark.modifier());
+    /// ```
+    pub const fn modifier(self) -> char {
+        match self {
+            Self::Light => '\u{1F3FB}',
+            Self::MediumLight => '\u{1F3FC}',
+            Self::Medium => '\u{1F3FD}',
+            Self::MediumDark => '\u{1F3FE}',
+            Self::Dark => '\u{1F3FF}',
+        }
+    }
+}
+/// Gets the skin tone of a Fitzpatrick modifier (U+1F3FB to U+1F3FF).
+///
+/// # Examples
+/// ```
+/// # use emojic::Tone;
+/// use std::convert::TryFrom;
+///
+/// // Reads the tone off a toned emoji
+/// let tone = "👋🏿".chars().find_map(|c| Tone::try_from(c).ok());
+/// assert_eq!(Some(Tone::Dark), tone);
+///
+/// assert!(Tone::try_from('👋').is_err());
+/// ```
+impl TryFrom<char> for Tone {
+    type Error = ParseAttributeError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|tone| tone.modifier() == c)
+            .ok_or(ParseAttributeError::new("skin tone modifier"))
+    }
+}
+/// Displays the [`name`](Tone::name) of the skin tone.
+///
+/// # Examples
+/// ```
+/// # use emojic::Tone;
+/// assert_eq!("medium-dark skin tone", Tone::MediumDark.to_string());
+/// ```
+impl fmt::Display for Tone {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.name())
+    }
+}
+/// Parses a skin tone from either its short form (e.g. `"medium-light"`) or its
+/// [`name`](Tone::name) (e.g. `"medium-light skin tone"`), ignoring ASCII case.
+///
+/// # Examples
+/// ```
+/// # use emojic::Tone;
+/// assert_eq!(Ok(Tone::Dark), "dark".parse());
+/// assert_eq!(Ok(Tone::MediumLight), "Medium-Light".parse());
+/// assert_eq!(Ok(Tone::MediumLight), "medium-light skin tone".parse::<Tone>());
+/// assert!("purple".parse::<Tone>().is_err());
+/// ```
+impl FromStr for Tone {
+    type Err = ParseAttributeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|tone| {
+                let name = tone.name();
+                let short = name.strip_suffix(" skin tone").unwrap_or(name);
+                s.eq_ignore_ascii_case(name) || s.eq_ignore_ascii_case(short)
+            })
+            .ok_or(ParseAttributeError::new("skin tone"))
+    }
 }
 
 /// Represents a skin [`Tone`] pair.
@@ -69,6 +363,35 @@ pub struct TonePair {
     pub right: Tone,
 }
 impl TonePair {
+    /// Exhaustive list of all pairs, ordered by the left and then by the right skin tone
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::emojis::TonePair;
+    /// # use emojic::flat::PERSON_HOLDING_HANDS;
+    /// // All 25 tone combinations of two people holding hands
+    /// let all: String = TonePair::ALL
+    ///     .iter()
+    ///     .map(|&tones| PERSON_HOLDING_HANDS.tone_pair(tones).grapheme)
+    ///     .collect();
+    /// assert_eq!(25, all.matches("\u{1F91D}").count()); // each with handshake
+    /// ```
+    pub const ALL: [TonePair; 25] = {
+        let mut all = [TonePair {
+            left: Tone::Light,
+            right: Tone::Light,
+        }; 25];
+        let mut i = 0;
+        while i < all.len() {
+            all[i] = TonePair {
+                left: Tone::ALL[i / Tone::ALL.len()],
+                right: Tone::ALL[i % Tone::ALL.len()],
+            };
+            i += 1;
+        }
+        all
+    };
+
     /// Returns the entry index for this pair
     pub(crate) const fn to_id(self) -> usize {
         self.left as usize * Tone::ALL.len() + self.right as usize
@@ -139,6 +462,7 @@ impl From<(Tone, Tone)> for TonePairReduced {
 ///
 /// The default gender is 🧑 (a generic person somewhat genderless)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Gender {
     /// Represents the male gender 👨
@@ -170,6 +494,91 @@ impl Gender {
             Self::Female => "girl",
         }
     }
+
+    /// Returns the other gender, e.g. for a toggle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::Gender;
+    /// assert_eq!(Gender::Female, Gender::Male.opposite());
+    /// assert_eq!(Gender::Male, Gender::Female.opposite());
+    /// ```
+    pub const fn opposite(self) -> Gender {
+        match self {
+            Self::Male => Self::Female,
+            Self::Female => Self::Male,
+        }
+    }
+
+    /// Returns the succeeding variant according to [`ALL`](Self::ALL), if any.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::Gender;
+    /// assert_eq!(Some(Gender::Female), Gender::Male.next());
+    /// assert_eq!(None, Gender::Female.next());
+    /// ```
+    pub fn next(self) -> Option<Self> {
+        Self::ALL.get(self as usize + 1).copied()
+    }
+
+    /// Returns the preceding variant according to [`ALL`](Self::ALL), if any.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::Gender;
+    /// assert_eq!(Some(Gender::Male), Gender::Female.prev());
+    /// assert_eq!(None, Gender::Male.prev());
+    /// ```
+    pub fn prev(self) -> Option<Self> {
+        (self as usize).checked_sub(1).map(|i| Self::ALL[i])
+    }
+}
+/// Displays the [`name_adults`](Gender::name_adults) of the gender, e.g. `"woman"`, or with the
+/// alternate flag (`{:#}`) its [`name_children`](Gender::name_children), e.g. `"girl"`.
+///
+/// # Examples
+/// ```
+/// # use emojic::Gender;
+/// assert_eq!("man", format!("{}", Gender::Male));
+/// assert_eq!("boy", format!("{:#}", Gender::Male));
+/// ```
+impl fmt::Display for Gender {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if fmt.alternate() {
+            fmt.write_str(self.name_children())
+        } else {
+            fmt.write_str(self.name_adults())
+        }
+    }
+}
+
+/// Parses a gender from `male` or `female`, or from its
+/// [`name_adults`](Gender::name_adults) (e.g. `"woman"`), ignoring ASCII case.
+///
+/// # Examples
+/// ```
+/// # use emojic::Gender;
+/// assert_eq!(Ok(Gender::Female), "female".parse());
+/// assert_eq!(Ok(Gender::Male), "Man".parse());
+/// assert!("boy".parse::<Gender>().is_err());
+/// ```
+impl FromStr for Gender {
+    type Err = ParseAttributeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|gender| {
+                let adjective = match gender {
+                    Self::Male => "male",
+                    Self::Female => "female",
+                };
+                s.eq_ignore_ascii_case(adjective) || s.eq_ignore_ascii_case(gender.name_adults())
+            })
+            .ok_or(ParseAttributeError::new("gender"))
+    }
 }
 
 /// Represents the gender of a pair of people 🧑‍🤝‍🧑👬👫👭
@@ -178,6 +587,7 @@ impl Gender {
 ///
 /// The default is 🧑‍🤝‍🧑 (two genderless people)
 #[derive(Clone, Debug, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Pair {
     /// Two males 👬
@@ -191,6 +601,61 @@ impl Pair {
     /// Exhaustive list of all variants
     pub const ALL: [Pair; 3] = [Self::Males, Self::Mixed, Self::Females];
 
+    /// Returns the pair of the given genders, regardless of their order.
+    ///
+    /// This is the same as the `From<(Gender, Gender)>` impl, but usable in const contexts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::{Gender, Pair};
+    /// const COUPLE: Pair = Pair::from_genders(Gender::Female, Gender::Male);
+    /// assert_eq!(Pair::Mixed, COUPLE);
+    /// ```
+    pub const fn from_genders(a: Gender, b: Gender) -> Pair {
+        match (a, b) {
+            (Gender::Male, Gender::Male) => Pair::Males,
+            (Gender::Male, Gender::Female) => Pair::Mixed,
+            (Gender::Female, Gender::Male) => Pair::Mixed,
+            (Gender::Female, Gender::Female) => Pair::Females,
+        }
+    }
+
+    /// Returns the genders of the two people, the male one first for [`Pair::Mixed`].
+    ///
+    /// This is the inverse of [`from_genders`](Self::from_genders), except that the order of
+    /// the mixed genders is normalized.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::{Gender, Pair};
+    /// assert_eq!((Gender::Male, Gender::Female), Pair::Mixed.genders());
+    /// assert_eq!(Pair::Mixed, Pair::from(Pair::Mixed.genders()));
+    ///
+    /// // E.g. to toggle the gender of just one of them
+    /// let (first, second) = Pair::Males.genders();
+    /// assert_eq!(Pair::Mixed, Pair::from_genders(first, second.opposite()));
+    /// ```
+    pub const fn genders(self) -> (Gender, Gender) {
+        match self {
+            Self::Males => (Gender::Male, Gender::Male),
+            Self::Mixed => (Gender::Male, Gender::Female),
+            Self::Females => (Gender::Female, Gender::Female),
+        }
+    }
+
+    /// Returns the pair with both genders flipped, thus [`Pair::Mixed`] stays as it is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::Pair;
+    /// assert_eq!(Pair::Females, Pair::Males.opposite());
+    /// assert_eq!(Pair::Mixed, Pair::Mixed.opposite());
+    /// ```
+    pub const fn opposite(self) -> Pair {
+        let (a, b) = self.genders();
+        Pair::from_genders(a.opposite(), b.opposite())
+    }
+
     /// [`Family`] composer using `self` as parents
     pub fn with_children(self, children: impl Into<OneOrTwo>) -> Family {
         (self, children).into()
@@ -212,15 +677,53 @@ impl Pair {
             Self::Females => "girls",
         }
     }
+
+    /// Returns the succeeding variant according to [`ALL`](Self::ALL), if any.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::Pair;
+    /// assert_eq!(Some(Pair::Mixed), Pair::Males.next());
+    /// assert_eq!(None, Pair::Females.next());
+    /// ```
+    pub fn next(self) -> Option<Self> {
+        Self::ALL.get(self as usize + 1).copied()
+    }
+
+    /// Returns the preceding variant according to [`ALL`](Self::ALL), if any.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::Pair;
+    /// assert_eq!(Some(Pair::Mixed), Pair::Females.prev());
+    /// assert_eq!(None, Pair::Males.prev());
+    /// ```
+    pub fn prev(self) -> Option<Self> {
+        (self as usize).checked_sub(1).map(|i| Self::ALL[i])
+    }
+}
+/// Displays the [`name_adults`](Pair::name_adults) of the pair, e.g. `"man & woman"`, or with the
+/// alternate flag (`{:#}`) its [`name_children`](Pair::name_children), e.g. `"boy & girl"`.
+///
+/// # Examples
+/// ```
+/// # use emojic::Pair;
+/// assert_eq!("man & woman", format!("{}", Pair::Mixed));
+/// assert_eq!("boy & girl", format!("{:#}", Pair::Mixed));
+/// assert_eq!("Females", format!("{:?}", Pair::Females));
+/// ```
+impl fmt::Display for Pair {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if fmt.alternate() {
+            fmt.write_str(self.name_children())
+        } else {
+            fmt.write_str(self.name_adults())
+        }
+    }
 }
 impl From<(Gender, Gender)> for Pair {
     fn from(pair: (Gender, Gender)) -> Self {
-        match pair {
-            (Gender::Male, Gender::Male) => Pair::Males,
-            (Gender::Male, Gender::Female) => Pair::Mixed,
-            (Gender::Female, Gender::Male) => Pair::Mixed,
-            (Gender::Female, Gender::Female) => Pair::Females,
-        }
+        Pair::from_genders(pair.0, pair.1)
     }
 }
 
@@ -269,6 +772,27 @@ impl OneOrTwo {
         }
     }
 
+    /// Returns a single person of the given gender, usable in const contexts.
+    pub const fn one(gender: Gender) -> OneOrTwo {
+        Self::One(gender)
+    }
+
+    /// Returns the two people of the given pair, usable in const contexts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::emojis::{Family, OneOrTwo};
+    /// # use emojic::{Gender, Pair};
+    /// const FAMILY: Family = Family {
+    ///     parents: OneOrTwo::two(Pair::from_genders(Gender::Male, Gender::Female)),
+    ///     children: OneOrTwo::one(Gender::Female),
+    /// };
+    /// assert_eq!(Pair::Mixed.with_children(Gender::Female), FAMILY);
+    /// ```
+    pub const fn two(pair: Pair) -> OneOrTwo {
+        Self::Two(pair)
+    }
+
     /// [`Family`] composer using `self` as parents
     pub fn with_children(self, children: impl Into<OneOrTwo>) -> Family {
         (self, children).into()
@@ -374,10 +898,114 @@ pub struct Family {
     pub children: OneOrTwo,
 }
 impl Family {
+    /// Exhaustive list of all constellations, parents first, then children
+    ///
+    /// Each of these has a genuine family emoji, i.e. `FAMILY.family(f)` never falls back to
+    /// another grapheme.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::emojis::Family;
+    /// # use emojic::flat::FAMILY;
+    /// for &family in Family::ALL.iter() {
+    ///     let name = FAMILY.family(family).name;
+    ///     let description = name.strip_prefix("family: ").unwrap();
+    ///     assert_eq!(Ok(family), Family::from_description(description));
+    /// }
+    /// ```
+    pub const ALL: [Family; 25] = {
+        let mut all = [Family {
+            parents: OneOrTwo::One(Gender::Male),
+            children: OneOrTwo::One(Gender::Male),
+        }; 25];
+        let mut i = 0;
+        while i < all.len() {
+            all[i] = Family {
+                parents: OneOrTwo::ALL[i / OneOrTwo::ALL.len()],
+                children: OneOrTwo::ALL[i % OneOrTwo::ALL.len()],
+            };
+            i += 1;
+        }
+        all
+    };
+
     /// Returns the entry index for this constellation
     pub(crate) const fn to_id(self) -> usize {
         self.parents.to_id() * OneOrTwo::ALL.len() + self.children.to_id()
     }
+
+    /// Parses a family from a comma-separated list of its members, e.g. `"man, woman, boy"`.
+    ///
+    /// The parents (`man`, `woman`, `men`, `women`) have to be listed before the children
+    /// (`boy`, `girl`, `boys`, `girls`), and there have to be one or two of each. The words are
+    /// matched case-insensitively, and surrounding whitespace is ignored.
+    ///
+    /// Since a `Family` always has gendered members, the generic `person` and `child` are not
+    /// supported.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::emojis::{Family,Pair,Gender};
+    /// assert_eq!(
+    ///     Ok(Pair::Mixed.with_children(Gender::Male)),
+    ///     Family::from_description("man, woman, boy")
+    /// );
+    /// assert_eq!(
+    ///     Ok(Gender::Female.with_children(Pair::Females)),
+    ///     Family::from_description("Woman, Girls")
+    /// );
+    ///
+    /// assert!(Family::from_description("man, boy, woman").is_err());
+    /// assert!(Family::from_description("men, woman, boy").is_err());
+    /// assert!(Family::from_description("person, child").is_err());
+    /// assert!(Family::from_description("woman").is_err());
+    /// ```
+    pub fn from_description(description: &str) -> Result<Self, ParseAttributeError> {
+        use Gender::*;
+
+        /// The known members: (word, is a child, genders)
+        const MEMBERS: &[(&str, bool, &[Gender])] = &[
+            ("man", false, &[Male]),
+            ("woman", false, &[Female]),
+            ("men", false, &[Male, Male]),
+            ("women", false, &[Female, Female]),
+            ("boy", true, &[Male]),
+            ("girl", true, &[Female]),
+            ("boys", true, &[Male, Male]),
+            ("girls", true, &[Female, Female]),
+        ];
+        const ERROR: ParseAttributeError = ParseAttributeError::new("family description");
+
+        let mut parents = [None; 2];
+        let mut children = [None; 2];
+        for part in description
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+        {
+            let &(_, is_child, genders) = MEMBERS
+                .iter()
+                .find(|(word, _, _)| word.eq_ignore_ascii_case(part))
+                .ok_or(ERROR)?;
+            let members = if is_child {
+                &mut children
+            } else if children[0].is_none() {
+                &mut parents
+            } else {
+                // The parents must come first
+                return Err(ERROR);
+            };
+            for &gender in genders {
+                let slot = members.iter_mut().find(|m| m.is_none()).ok_or(ERROR)?;
+                *slot = Some(gender);
+            }
+        }
+
+        match (parents, children) {
+            ([Some(p0), p1], [Some(c0), c1]) => Ok(((p0, p1), (c0, c1)).into()),
+            _ => Err(ERROR),
+        }
+    }
 }
 impl<A: Into<OneOrTwo>, B: Into<OneOrTwo>> From<(A, B)> for Family {
     fn from((parents, children): (A, B)) -> Self {
@@ -393,6 +1021,7 @@ impl<A: Into<OneOrTwo>, B: Into<OneOrTwo>> From<(A, B)> for Family {
 /// Allows to specify the hair style of supported emojis. Generally speaking, those involving
 /// people (well currently only of a single person).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Hair {
     /// Represents a bearded person 🧔
@@ -432,4 +1061,187 @@ impl Hair {
             Self::White => "white hair",
         }
     }
+
+    /// Returns the succeeding variant according to [`ALL`](Self::ALL), if any.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::Hair;
+    /// assert_eq!(Some(Hair::Curly), Hair::Red.next());
+    /// assert_eq!(None, Hair::Bald.next());
+    /// ```
+    pub fn next(self) -> Option<Self> {
+        Self::ALL.get(self as usize + 1).copied()
+    }
+
+    /// Returns the preceding variant according to [`ALL`](Self::ALL), if any.
+    ///
+    /// # Examples
+    /// ```
+    /// # use emojic::Hair;
+    /// assert_eq!(Some(Hair::Blond), Hair::Red.prev());
+    /// assert_eq!(None, Hair::Beard.prev());
+    /// ```
+    pub fn prev(self) -> Option<Self> {
+        (self as usize).checked_sub(1).map(|i| Self::ALL[i])
+    }
+}
+/// Displays the [`name`](Hair::name) of the hair style, e.g. `"red hair"`
+impl fmt::Display for Hair {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.name())
+    }
+}
+/// Parses a hair style from either its short form (e.g. `"curly"` or `"bald"`) or its
+/// [`name`](Hair::name) (e.g. `"curly hair"` or `"no hair"`), ignoring ASCII case.
+///
+/// # Examples
+/// ```
+/// # use emojic::Hair;
+/// assert_eq!(Ok(Hair::Red), "red".parse());
+/// assert_eq!(Ok(Hair::Bald), "Bald".parse());
+/// assert_eq!(Ok(Hair::Bald), "no hair".parse::<Hair>());
+/// assert!("green".parse::<Hair>().is_err());
+///
+/// // The names round-trip
+/// for hair in Hair::ALL.iter() {
+///     assert_eq!(Ok(*hair), hair.name().parse());
+/// }
+/// ```
+impl FromStr for Hair {
+    type Err = ParseAttributeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|hair| {
+                let name = hair.name();
+                let short = match hair {
+                    Self::Bald => "bald",
+                    _ => name.strip_suffix(" hair").unwrap_or(name),
+                };
+                s.eq_ignore_ascii_case(name) || s.eq_ignore_ascii_case(short)
+            })
+            .ok_or(ParseAttributeError::new("hair style"))
+    }
+}
+
+/// A set of attributes to customize an emoji with, each of them optional.
+///
+/// This allows to determine the customization at runtime, e.g. from a config file, see
+/// [`apply`](crate::apply).
+///
+/// # Examples
+/// ```
+/// # use emojic::emojis::Attributes;
+/// # use emojic::{Gender, Hair, Tone};
+/// assert_eq!(
+///     Ok(Attributes {
+///         gender: Some(Gender::Female),
+///         tone: Some(Tone::Dark),
+///         hair: Some(Hair::Curly),
+///     }),
+///     "female,dark,curly".parse()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Attributes {
+    pub gender: Option<Gender>,
+    pub tone: Option<Tone>,
+    pub hair: Option<Hair>,
+}
+
+/// Parses a list of attributes separated by commas or colons, e.g. `"female,dark,curly"` or
+/// `"dark:female"`.
+///
+/// Each attribute is parsed by the `FromStr` impl of [`Gender`], [`Tone`], or [`Hair`], in any
+/// order, and surrounding whitespace is ignored. Every kind of attribute may be given at most
+/// once.
+///
+/// # Examples
+/// ```
+/// # use emojic::emojis::Attributes;
+/// # use emojic::Tone;
+/// let attributes: Attributes = " medium-dark skin tone ".parse().unwrap();
+/// assert_eq!(Some(Tone::MediumDark), attributes.tone);
+/// assert_eq!(Ok(Attributes::default()), "".parse());
+///
+/// assert!("dark,light".parse::<Attributes>().is_err());
+/// assert!("female,purple".parse::<Attributes>().is_err());
+/// ```
+impl FromStr for Attributes {
+    type Err = ParseAttributeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const ERROR: ParseAttributeError = ParseAttributeError::new("attributes");
+
+        let mut attributes = Attributes::default();
+        for part in s.split([',', ':']).map(str::trim).filter(|s| !s.is_empty()) {
+            let duplicate = if let Ok(gender) = part.parse() {
+                attributes.gender.replace(gender).is_some()
+            } else if let Ok(tone) = part.parse() {
+                attributes.tone.replace(tone).is_some()
+            } else {
+                let hair = part.parse().map_err(|_| ERROR)?;
+                attributes.hair.replace(hair).is_some()
+            };
+            if duplicate {
+                return Err(ERROR);
+            }
+        }
+        Ok(attributes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tone_pair_ids() {
+        // `to_id` must be a bijection between `ALL` and the entry indices
+        for (i, pair) in TonePair::ALL.iter().enumerate() {
+            assert_eq!(i, pair.to_id());
+        }
+        assert_eq!(Tone::ALL.len() * Tone::ALL.len(), TonePair::ALL.len());
+    }
+
+    #[test]
+    fn tone_names_round_trip() {
+        for &tone in Tone::ALL.iter() {
+            // The name includes the " skin tone" suffix, which must be accepted as well
+            assert_eq!(Ok(tone), tone.name().parse());
+            assert_eq!(Ok(tone), tone.name().to_ascii_uppercase().parse());
+            let short = tone.name().strip_suffix(" skin tone").unwrap();
+            assert_eq!(Ok(tone), short.parse());
+        }
+        assert!("skin tone".parse::<Tone>().is_err());
+        assert!("dark skin".parse::<Tone>().is_err());
+    }
+
+    #[test]
+    fn pair_genders_round_trip() {
+        for &pair in Pair::ALL.iter() {
+            assert_eq!(pair, Pair::from(pair.genders()));
+            assert_eq!(pair, pair.opposite().opposite());
+        }
+        // The order of mixed genders is normalized
+        assert_eq!(
+            (Gender::Male, Gender::Female),
+            Pair::from((Gender::Female, Gender::Male)).genders()
+        );
+        for &gender in Gender::ALL.iter() {
+            assert_ne!(gender, gender.opposite());
+            assert_eq!(gender, gender.opposite().opposite());
+        }
+    }
+
+    #[test]
+    fn family_ids() {
+        for (i, family) in Family::ALL.iter().enumerate() {
+            assert_eq!(i, family.to_id());
+        }
+        assert_eq!(OneOrTwo::ALL.len() * OneOrTwo::ALL.len(), Family::ALL.len());
+    }
 }
@@ -0,0 +1,282 @@
+//!
+//! Implementations of serde's `Serialize` & `Deserialize` for [`Emoji`], [`Version`], and
+//! [`EmojiSpec`].
+//!
+//! The attributes (such as [`Tone`](super::Tone)) simply derive them.
+//!
+
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use super::{Emoji, EmojiSpec, Version};
+use crate::{Gender, Hair, Tone};
+
+/// Serializes a `Version` as string, e.g. `"13.1"`
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes a `Version` from a string, e.g. `"13.1"`
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VersionVisitor;
+
+        impl<'de> Visitor<'de> for VersionVisitor {
+            type Value = Version;
+
+            fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt.write_str("an emoji version such as \"13.1\"")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Version, E> {
+                v.parse()
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(VersionVisitor)
+    }
+}
+
+/// The fields of a serialized `Emoji`
+const FIELDS: &[&str] = &["name", "since", "grapheme"];
+
+/// Serializes an `Emoji` as struct with all its fields, e.g.
+/// `{ "name": "crab", "since": "1.0", "grapheme": "🦀" }`
+impl Serialize for Emoji {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Emoji", FIELDS.len())?;
+        s.serialize_field("name", self.name)?;
+        s.serialize_field("since", &self.since)?;
+        s.serialize_field("grapheme", self.grapheme)?;
+        s.end()
+    }
+}
+
+/// Deserializes an `Emoji` as serialized by its `Serialize` impl.
+///
+/// Since an `Emoji` can only refer to static strings, the emoji is looked up by its grapheme,
+/// while the remaining fields are ignored. Thus, only emojis of this crate can be deserialized.
+impl<'de> Deserialize<'de> for &'static Emoji {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("Emoji", FIELDS, EmojiVisitor)
+    }
+}
+
+/// Deserializes an `Emoji` as serialized by its `Serialize` impl.
+///
+/// This is a copy of the emoji deserialized via `&'static Emoji`.
+impl<'de> Deserialize<'de> for Emoji {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <&'static Emoji>::deserialize(deserializer).copied()
+    }
+}
+
+/// Deserializes an emoji from its grapheme
+struct Grapheme(&'static Emoji);
+
+impl<'de> Deserialize<'de> for Grapheme {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct GraphemeVisitor;
+
+        impl<'de> Visitor<'de> for GraphemeVisitor {
+            type Value = Grapheme;
+
+            fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt.write_str("the grapheme of an emoji")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Grapheme, E> {
+                crate::search::lookup(v)
+                    .map(Grapheme)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(GraphemeVisitor)
+    }
+}
+
+/// Deserializes an `EmojiSpec` as serialized by its derived `Serialize` impl.
+///
+/// The `base` is looked up as grapheme, thus it refers to the static grapheme of an emoji of this
+/// crate afterwards.
+impl<'de> Deserialize<'de> for EmojiSpec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "EmojiSpec")]
+        struct Fields {
+            base: Grapheme,
+            #[serde(default)]
+            tone: Option<Tone>,
+            #[serde(default)]
+            gender: Option<Gender>,
+            #[serde(default)]
+            hair: Option<Hair>,
+        }
+
+        let Fields {
+            base: Grapheme(base),
+            tone,
+            gender,
+            hair,
+        } = Fields::deserialize(deserializer)?;
+        Ok(EmojiSpec {
+            base: base.grapheme,
+            tone,
+            gender,
+            hair,
+        })
+    }
+}
+
+/// The fields of a serialized `Emoji`, as parsed by the [`EmojiVisitor`]
+#[derive(serde::Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Grapheme,
+    #[serde(other)]
+    Other,
+}
+
+struct EmojiVisitor;
+
+impl<'de> Visitor<'de> for EmojiVisitor {
+    type Value = &'static Emoji;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("an emoji")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // The fields in order: name, since, grapheme
+        seq.next_element::<IgnoredAny>()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        seq.next_element::<IgnoredAny>()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let Grapheme(emoji) = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        Ok(emoji)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut emoji = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Grapheme => {
+                    if emoji.is_some() {
+                        return Err(de::Error::duplicate_field("grapheme"));
+                    }
+                    let Grapheme(e) = map.next_value()?;
+                    emoji = Some(e);
+                }
+                Field::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        emoji.ok_or_else(|| de::Error::missing_field("grapheme"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Tests are going to be on development systems => there will be std.
+    extern crate std;
+    use std::prelude::v1::*;
+
+    use super::*;
+    use crate::Pair;
+
+    #[test]
+    fn emoji_to_json() {
+        assert_eq!(
+            r#"{"name":"crab","since":"1.0","grapheme":"🦀"}"#,
+            serde_json::to_string(&crate::flat::CRAB).unwrap()
+        );
+    }
+
+    #[test]
+    fn emoji_from_json() {
+        let crab: Emoji =
+            serde_json::from_str(r#"{"name":"crab","since":"1.0","grapheme":"🦀"}"#).unwrap();
+        assert_eq!(crate::flat::CRAB, crab);
+
+        // Only the grapheme is relevant
+        let crab: &'static Emoji = serde_json::from_str(r#"{"grapheme":"🦀"}"#).unwrap();
+        assert_eq!(&crate::flat::CRAB, crab);
+
+        let json = serde_json::to_string(crate::flat::WAVING_HAND.tone(Tone::Dark)).unwrap();
+        let wave: &'static Emoji = serde_json::from_str(&json).unwrap();
+        assert_eq!(crate::flat::WAVING_HAND.tone(Tone::Dark), wave);
+    }
+
+    #[test]
+    fn emoji_from_json_invalid() {
+        assert!(serde_json::from_str::<Emoji>(r#"{"name":"crab"}"#).is_err());
+        assert!(serde_json::from_str::<Emoji>(r#"{"grapheme":"crab"}"#).is_err());
+        assert!(serde_json::from_str::<Emoji>(r#""🦀""#).is_err());
+    }
+
+    #[test]
+    fn emoji_from_json_seq() {
+        let crab: Emoji = serde_json::from_str(r#"["crab","1.0","🦀"]"#).unwrap();
+        assert_eq!(crate::flat::CRAB, crab);
+    }
+
+    #[test]
+    fn version_json() {
+        assert_eq!(r#""13.1""#, serde_json::to_string(&Version(13, 1)).unwrap());
+        assert_eq!(
+            Version(0, 6),
+            serde_json::from_str::<Version>(r#""0.6""#).unwrap()
+        );
+        assert!(serde_json::from_str::<Version>(r#""13""#).is_err());
+        assert!(serde_json::from_str::<Version>(r#""13.x""#).is_err());
+    }
+
+    #[test]
+    fn attributes_json() {
+        let json = serde_json::to_string(&(Tone::Dark, Gender::Female, Pair::Mixed, Hair::Red));
+        assert_eq!(r#"["Dark","Female","Mixed","Red"]"#, json.unwrap());
+
+        let attrs: (Tone, Gender, Pair, Hair) =
+            serde_json::from_str(r#"["Light","Male","Females","Bald"]"#).unwrap();
+        assert_eq!(
+            (Tone::Light, Gender::Male, Pair::Females, Hair::Bald),
+            attrs
+        );
+    }
+
+    #[test]
+    fn emoji_spec_json() {
+        use crate::emojis::EmojiSpec;
+        use crate::flat::{PERSON, RED_HEART};
+
+        let spec = EmojiSpec {
+            gender: Some(Gender::Male),
+            hair: Some(Hair::Bald),
+            ..EmojiSpec::new(&PERSON)
+        };
+        let json = serde_json::to_string(&spec).unwrap();
+        assert_eq!(r#"{"base":"🧑","gender":"Male","hair":"Bald"}"#, json);
+        assert_eq!(spec, serde_json::from_str(&json).unwrap());
+        assert_eq!(
+            Some(&**PERSON.hair(Hair::Bald).gender(Gender::Male)),
+            spec.resolve()
+        );
+
+        // The base refers to the emoji of this crate, even if unqualified
+        let spec: EmojiSpec = serde_json::from_str("{\"base\":\"\u{2764}\"}").unwrap();
+        assert_eq!(RED_HEART.grapheme, spec.base);
+        assert_eq!(Some(&RED_HEART), spec.resolve());
+
+        assert!(serde_json::from_str::<EmojiSpec>(r#"{"base":"crab"}"#).is_err());
+        assert!(serde_json::from_str::<EmojiSpec>(r#"{"tone":"Dark"}"#).is_err());
+    }
+}
@@ -0,0 +1,119 @@
+//! The unified error type
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::emojis::ParseAttributeError;
+use crate::emojis::ParseVersionError;
+use crate::flags::FlagError;
+use crate::text::BufferTooSmall;
+#[cfg(feature = "alloc")]
+use crate::text::UnknownAlias;
+
+/// Any error of this crate.
+///
+/// Each fallible function returns its own specific error type for precise handling, which all
+/// convert into this one, e.g. via `?`. Thus, callers combining several of these functions can
+/// handle a single error type. Both the `Display` and the `source` are those of the specific
+/// error.
+///
+/// Matches on this enum need a wildcard arm, since the [`UnknownAlias`](Self::UnknownAlias)
+/// variant only exists with the `alloc` feature, which any other crate of the dependency graph
+/// might enable.
+///
+/// # Examples
+/// ```
+/// use emojic::emojis::Version;
+/// use emojic::{Error, Tone};
+///
+/// fn parse(tone: &str, version: &str) -> Result<(Tone, Version), Error> {
+///     Ok((tone.parse()?, version.parse()?))
+/// }
+///
+/// assert!(parse("dark", "13.1").is_ok());
+/// assert!(matches!(parse("purple", "13.1"), Err(Error::Attribute(_))));
+/// assert!(matches!(parse("dark", "13"), Err(Error::Version(_))));
+/// assert_eq!(
+///     "Only chars A-Z are allowed as country_code, but got '1'",
+///     Error::from(emojic::flags::FlagError::InvalidChar('1')).to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// An invalid country or regional code, see [`FlagError`]
+    Flag(FlagError),
+    /// An invalid Unicode Emoji version, see [`ParseVersionError`]
+    Version(ParseVersionError),
+    /// An invalid attribute such as a skin tone, see [`ParseAttributeError`]
+    Attribute(ParseAttributeError),
+    /// A too small output buffer, see [`BufferTooSmall`]
+    BufferTooSmall(BufferTooSmall),
+    /// An unknown alias in a text, see [`UnknownAlias`]
+    ///
+    /// Unlike [`UnknownAlias`], it owns the alias, so that this error does not borrow the text.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+    UnknownAlias {
+        /// The unknown alias (without colons)
+        alias: String,
+        /// The byte offset of the alias' opening colon in the text
+        offset: usize,
+    },
+}
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Flag(err) => err.fmt(fmt),
+            Self::Version(err) => err.fmt(fmt),
+            Self::Attribute(err) => err.fmt(fmt),
+            Self::BufferTooSmall(err) => err.fmt(fmt),
+            #[cfg(feature = "alloc")]
+            Self::UnknownAlias { alias, offset } => UnknownAlias {
+                alias,
+                offset: *offset,
+            }
+            .fmt(fmt),
+        }
+    }
+}
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Version(err) => err.source(),
+            _ => None,
+        }
+    }
+}
+
+impl From<FlagError> for Error {
+    fn from(err: FlagError) -> Self {
+        Self::Flag(err)
+    }
+}
+impl From<ParseVersionError> for Error {
+    fn from(err: ParseVersionError) -> Self {
+        Self::Version(err)
+    }
+}
+impl From<ParseAttributeError> for Error {
+    fn from(err: ParseAttributeError) -> Self {
+        Self::Attribute(err)
+    }
+}
+impl From<BufferTooSmall> for Error {
+    fn from(err: BufferTooSmall) -> Self {
+        Self::BufferTooSmall(err)
+    }
+}
+#[cfg(feature = "alloc")]
+impl<'a> From<UnknownAlias<'a>> for Error {
+    fn from(err: UnknownAlias<'a>) -> Self {
+        Self::UnknownAlias {
+            alias: err.alias.into(),
+            offset: err.offset,
+        }
+    }
+}
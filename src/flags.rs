@@ -0,0 +1,505 @@
+//! Flag utilities
+//!
+//! Besides the flag constants (e.g. [`FLAG_TURKEY`](crate::flat::FLAG_TURKEY)), Unicode allows
+//! to compose arbitrary country and regional flags from their ISO codes. This module contains
+//! functions to generate such ad-hoc flags, and to decode them back into their codes.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Error of an invalid country code.
+///
+/// Returned by [`try_country_flag`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlagError {
+    /// The code does not consist of exactly two chars, contains the actual length.
+    InvalidLength(usize),
+    /// The code contains a char which is not an ASCII letter (A-Z).
+    InvalidChar(char),
+}
+impl fmt::Display for FlagError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => write!(
+                fmt,
+                "Only exactly two chars are allowed as country_code, but got {}",
+                len
+            ),
+            Self::InvalidChar(c) => write!(
+                fmt,
+                "Only chars A-Z are allowed as country_code, but got {:?}",
+                c
+            ),
+        }
+    }
+}
+impl core::error::Error for FlagError {}
+
+/// Generate an ad-hoc country flag.
+///
+/// This function allows to create arbitrary country flags.
+///
+/// The Unicode standard defines country flags based on the two-letter country codes
+/// (see ISO 3166-1 alpha-2). Notice most (if not all) fonts support only the defined
+/// codes, however, this function does not test whether the given code is in deed a well defined
+/// country code.
+///
+/// # Panics
+/// If the provided string contains characters other than exactly two ASCII letters (A-Z).
+/// See [`try_country_flag`] for a non-panicking alternative.
+///
+/// # Examples
+/// ```
+/// use emojic::country_flag;
+///
+/// assert_eq!(
+///     country_flag("EU"), // 🇪🇺
+///     emojic::flat::FLAG_EUROPEAN_UNION.to_string()
+/// );
+/// ```
+///
+/// But there is no validity test:
+///
+/// ```rust
+/// use emojic::country_flag;
+///
+/// println!("{}",
+///     country_flag("ZZ"), // 🇿🇿 (an invalid flag)
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn country_flag(country_code: &str) -> String {
+    match try_country_flag(country_code) {
+        Ok(flag) => flag,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Generate an ad-hoc country flag, without panicking on invalid input.
+///
+/// This is the fallible version of [`country_flag`], which returns a [`FlagError`] instead of
+/// panicking, if the provided string does not consist of exactly two ASCII letters (A-Z).
+/// This makes it suitable for processing untrusted input.
+///
+/// # Examples
+/// ```
+/// use emojic::try_country_flag;
+/// use emojic::FlagError;
+///
+/// assert_eq!(
+///     try_country_flag("EU").as_deref(), // 🇪🇺
+///     Ok(emojic::flat::FLAG_EUROPEAN_UNION.grapheme)
+/// );
+/// assert_eq!(try_country_flag("ZZZ"), Err(FlagError::InvalidLength(3)));
+/// assert_eq!(try_country_flag("1A"), Err(FlagError::InvalidChar('1')));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn try_country_flag(country_code: &str) -> Result<String, FlagError> {
+    if let Some(c) = country_code.chars().find(|c| !c.is_ascii_alphabetic()) {
+        return Err(FlagError::InvalidChar(c));
+    }
+    match country_code.as_bytes() {
+        &[first, second] => try_country_flag_bytes(&[first, second]),
+        _ => Err(FlagError::InvalidLength(country_code.len())),
+    }
+}
+
+/// Generate an ad-hoc country flag from the ASCII bytes of the country code.
+///
+/// This is the same as [`country_flag`], but for country codes held as bytes (e.g. in a
+/// C-style buffer), which thus don't need to be converted into a `&str` first.
+///
+/// # Panics
+/// If the provided bytes are not ASCII letters (A-Z).
+/// See [`try_country_flag_bytes`] for a non-panicking alternative.
+///
+/// # Examples
+/// ```
+/// use emojic::country_flag_bytes;
+///
+/// assert_eq!(
+///     country_flag_bytes(b"EU"), // 🇪🇺
+///     emojic::flat::FLAG_EUROPEAN_UNION.to_string()
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn country_flag_bytes(country_code: &[u8; 2]) -> String {
+    match try_country_flag_bytes(country_code) {
+        Ok(flag) => flag,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Generate an ad-hoc country flag from the ASCII bytes of the country code, without panicking
+/// on invalid input.
+///
+/// This is the fallible version of [`country_flag_bytes`], which returns a
+/// [`FlagError::InvalidChar`] (with the byte as Latin-1 char) instead of panicking, if any of the
+/// bytes is not an ASCII letter (A-Z).
+///
+/// # Examples
+/// ```
+/// use emojic::try_country_flag_bytes;
+/// use emojic::FlagError;
+///
+/// assert_eq!(try_country_flag_bytes(b"tr").as_deref(), Ok("🇹🇷"));
+/// assert_eq!(try_country_flag_bytes(b"T\0"), Err(FlagError::InvalidChar('\0')));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn try_country_flag_bytes(country_code: &[u8; 2]) -> Result<String, FlagError> {
+    if let Some(&b) = country_code.iter().find(|b| !b.is_ascii_alphabetic()) {
+        return Err(FlagError::InvalidChar(char::from(b)));
+    }
+
+    Ok(country_code
+        .iter()
+        .map(|b| b.to_ascii_uppercase())
+        .map(|b| core::char::from_u32(u32::from(b - b'A') + '\u{1F1E6}' as u32).unwrap())
+        .collect())
+}
+
+// TODO: Remove `contry_flag` (without U) before releasing v0.4.0!
+
+// That's embarrassing: Originally `country_flag` had been misspelled as `contry_flag`
+// and with that name it has been released as v0.3.0!
+// Therefore, this misspelled function is kept here to keep it compatible, however it will just
+// redirect to the now correctly named function.
+
+/// Generate an ad-hoc country flag (use [`country_flag`] instead).
+#[cfg(feature = "alloc")]
+#[doc(hidden)] // we don't really need this in the docs.
+#[deprecated = "Just use country_flag instead (with U)"]
+pub fn contry_flag(country_code: &str) -> String {
+    country_flag(country_code)
+}
+
+/// Generate an ad-hoc regional flag.
+///
+/// This function allows to create arbitrary regional flags.
+///
+/// The Unicode standard defines regional flags based the ISO regions (see ISO 3166-2) which
+/// consist of the two-letter country code (ISO 3166-1 alpha-2) combined with up to three
+/// further characters to specify the region.
+///
+/// # Panics
+/// If the provided string is not a well-formed regional code.
+/// See [`try_regional_flag`] for a non-panicking alternative and the accepted format.
+///
+/// # Examples
+/// ```
+/// use emojic::regional_flag;
+///
+/// assert_eq!(
+///     regional_flag("GB-ENG"), // 🏴󠁧󠁢󠁥󠁮󠁧󠁿 (England region of United Kingdom (GB))
+///     emojic::flat::FLAG_ENGLAND.to_string()
+/// );
+/// ```
+///
+/// But there is no validity test:
+///
+/// ```rust
+/// use emojic::regional_flag;
+///
+/// println!("{}",
+///     regional_flag("ZZ-ABC") // 🏴󠁺󠁺󠁡󠁢󠁣󠁿 (an invalid flag)
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn regional_flag(regional_code: &str) -> String {
+    match try_regional_flag(regional_code) {
+        Some(flag) => flag,
+        None => panic!("Malformed regional_code: {:?}", regional_code),
+    }
+}
+
+/// The maximum length of the region part of a regional code (see ISO 3166-2)
+#[cfg(feature = "alloc")]
+const MAX_REGION_LEN: usize = 3;
+
+/// Generate an ad-hoc regional flag, without panicking on invalid input.
+///
+/// This is the fallible version of [`regional_flag`], which returns `None` instead of panicking,
+/// if the provided string is not a well-formed regional code. That is the two-letter country code
+/// (A-Z), optionally followed by a dash, and the one to three alphanumeric chars of the region
+/// (e.g. `"GB-ENG"` or `"gbeng"`).
+///
+/// Just like [`regional_flag`], this does not test whether the code is in deed a well defined
+/// region, it only ensures that no malformed tag sequence is generated.
+///
+/// # Examples
+/// ```
+/// use emojic::try_regional_flag;
+///
+/// assert_eq!(
+///     try_regional_flag("GB-SCT").as_deref(), // 🏴󠁧󠁢󠁳󠁣󠁴󠁿
+///     Some(emojic::flat::FLAG_SCOTLAND.grapheme)
+/// );
+/// assert_eq!(try_regional_flag("GB"), None); // the region is missing
+/// assert_eq!(try_regional_flag("GB-ENGL"), None); // the region is too long
+/// assert_eq!(try_regional_flag("G1-ENG"), None); // the country code must be letters
+/// assert_eq!(try_regional_flag("GB-E_G"), None);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn try_regional_flag(regional_code: &str) -> Option<String> {
+    if !regional_code.is_ascii() || regional_code.len() < 2 {
+        return None;
+    }
+    let (country, region) = regional_code.split_at(2);
+    let region = region.strip_prefix('-').unwrap_or(region);
+
+    let valid = country.chars().all(|c| c.is_ascii_alphabetic())
+        && (1..=MAX_REGION_LEN).contains(&region.len())
+        && region.chars().all(|c| c.is_ascii_alphanumeric());
+    if !valid {
+        return None;
+    }
+
+    let code = country
+        .chars()
+        .chain(region.chars())
+        .map(|c| c.to_ascii_lowercase())
+        .map(|c| core::char::from_u32(c as u32 + '\u{E0000}' as u32).unwrap());
+
+    Some(
+        core::iter::once('🏴') // start symbol
+            .chain(code) // code as tag sequence
+            .chain(core::iter::once('\u{E007F}')) // end sequence tag
+            .collect(),
+    )
+}
+
+/// Decodes a country flag back into its country code.
+///
+/// This is the reverse of [`country_flag`], it returns the upper case two-letter country code
+/// (see ISO 3166-1 alpha-2) of the given flag, or `None` if the flag does not consist of exactly
+/// two regional indicator symbols. Just like [`country_flag`], it does not test whether the
+/// code is in deed a well defined country code.
+///
+/// # Examples
+/// ```
+/// use emojic::flag_to_country;
+///
+/// assert_eq!(Some("TR"), flag_to_country("🇹🇷").as_deref());
+/// assert_eq!(Some("EU"), flag_to_country(emojic::flat::FLAG_EUROPEAN_UNION.grapheme).as_deref());
+/// assert_eq!(None, flag_to_country("TR"));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn flag_to_country(flag: &str) -> Option<String> {
+    let code: String = flag
+        .chars()
+        .map(|c| match c {
+            '\u{1F1E6}'..='\u{1F1FF}' => {
+                core::char::from_u32(c as u32 - '\u{1F1E6}' as u32 + 'A' as u32)
+            }
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+
+    if code.len() == 2 {
+        Some(code)
+    } else {
+        None
+    }
+}
+
+/// Decodes a regional flag back into its regional code.
+///
+/// This is the reverse of [`regional_flag`], it returns the lower case regional code
+/// (see ISO 3166-2) of the given flag, with a dash separating the two-letter country code from
+/// the region (e.g. `"gb-eng"`). If the flag is not a regional flag (i.e. a 🏴 followed by tags
+/// of the country code and the region, and the cancel tag), `None` is returned.
+///
+/// # Examples
+/// ```
+/// use emojic::flag_to_region;
+///
+/// assert_eq!(Some("gb-eng"), flag_to_region(emojic::flat::FLAG_ENGLAND.grapheme).as_deref());
+/// assert_eq!(None, flag_to_region("🏴"));
+/// assert_eq!(None, flag_to_region("🇹🇷"));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn flag_to_region(flag: &str) -> Option<String> {
+    let tags = flag.strip_prefix('🏴')?.strip_suffix('\u{E007F}')?;
+
+    let mut code = String::with_capacity(tags.len() / 4 + 1);
+    for (i, c) in tags.chars().enumerate() {
+        let c = match c {
+            '\u{E0030}'..='\u{E0039}' | '\u{E0061}'..='\u{E007A}' => {
+                core::char::from_u32(c as u32 - '\u{E0000}' as u32)?
+            }
+            _ => return None,
+        };
+        if i < 2 && !c.is_ascii_alphabetic() {
+            // The country code consists of letters only
+            return None;
+        }
+        if i == 2 {
+            code.push('-');
+        }
+        code.push(c);
+    }
+
+    // There must be a region after the country code
+    if code.len() > 3 {
+        Some(code)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn country_flag_test() {
+        assert_eq!(crate::flat::FLAG_GERMANY.grapheme, &country_flag("DE"));
+        assert_eq!(crate::flat::FLAG_GERMANY.grapheme, &country_flag("de"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic(expected = "Only chars A-Z are allowed as country_code")]
+    fn country_flag_panic() {
+        country_flag("D3");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn try_country_flag_test() {
+        assert_eq!(Ok(String::from("🇹🇷")), try_country_flag("TR"));
+        assert_eq!(Err(FlagError::InvalidLength(0)), try_country_flag(""));
+        assert_eq!(Err(FlagError::InvalidLength(1)), try_country_flag("T"));
+        assert_eq!(Err(FlagError::InvalidChar('Ü')), try_country_flag("ÜR"));
+        assert_eq!(Err(FlagError::InvalidChar('-')), try_country_flag("T-R"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn country_flag_bytes_like_str() {
+        for code in &["TR", "de", "Eu", "1A", "A\0"] {
+            let bytes = code.as_bytes();
+            if let &[first, second] = bytes {
+                assert_eq!(
+                    try_country_flag(code),
+                    try_country_flag_bytes(&[first, second])
+                );
+            }
+        }
+        assert_eq!(
+            Err(FlagError::InvalidChar('\u{FF}')),
+            try_country_flag_bytes(&[b'A', 0xFF])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn regional_flag_test() {
+        assert_eq!(crate::flat::FLAG_ENGLAND.grapheme, &regional_flag("GB-ENG"));
+        assert_eq!(crate::flat::FLAG_ENGLAND.grapheme, &regional_flag("gbeng"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic(expected = "Malformed regional_code")]
+    fn regional_flag_panic() {
+        regional_flag("GB-ENGLAND");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn try_regional_flag_test() {
+        assert_eq!(
+            Some(crate::flat::FLAG_WALES.grapheme),
+            try_regional_flag("GB-WLS").as_deref()
+        );
+        assert_eq!(
+            Some(5),
+            try_regional_flag("FR-B").map(|f| f.chars().count())
+        );
+        assert_eq!(
+            Some(7),
+            try_regional_flag("US-CA1").map(|f| f.chars().count())
+        );
+        assert_eq!(None, try_regional_flag(""));
+        assert_eq!(None, try_regional_flag("G"));
+        assert_eq!(None, try_regional_flag("GB-"));
+        assert_eq!(None, try_regional_flag("GB-ENGL"));
+        assert_eq!(None, try_regional_flag("GB--EN"));
+        assert_eq!(None, try_regional_flag("-GBEN"));
+        assert_eq!(None, try_regional_flag("GB-ÉNG"));
+        assert_eq!(None, try_regional_flag("GB ENG"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn flag_to_country_test() {
+        assert_eq!(Some(String::from("DE")), flag_to_country("🇩🇪"));
+        assert_eq!(None, flag_to_country(""));
+        assert_eq!(None, flag_to_country("🇩"));
+        assert_eq!(None, flag_to_country("🇩🇪🇩"));
+        assert_eq!(None, flag_to_country("🇩🇪 "));
+        assert_eq!(None, flag_to_country(crate::flat::FLAG_ENGLAND.grapheme));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn flag_to_country_roundtrip() {
+        for code in &["TR", "DE", "EU", "ZZ"] {
+            assert_eq!(Some(*code), flag_to_country(&country_flag(code)).as_deref());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn flag_to_region_test() {
+        assert_eq!(
+            Some(String::from("gb-sct")),
+            flag_to_region(crate::flat::FLAG_SCOTLAND.grapheme)
+        );
+        assert_eq!(None, flag_to_region(""));
+        // No region
+        assert_eq!(None, flag_to_region("🏴\u{E0067}\u{E0062}\u{E007F}"));
+        // No cancel tag
+        assert_eq!(None, flag_to_region("🏴\u{E0067}\u{E0062}\u{E0065}"));
+        // Not a tag
+        assert_eq!(None, flag_to_region("🏴gbeng\u{E007F}"));
+        // The country code has digits
+        assert_eq!(
+            None,
+            flag_to_region("🏴\u{E0031}\u{E0062}\u{E0065}\u{E007F}")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn subdivision_flags_like_regional_flag() {
+        use crate::grouped::flags::subdivision_flag;
+
+        assert_eq!(3, subdivision_flag::base_emojis().count());
+        for flag in subdivision_flag::base_emojis() {
+            let region = flag_to_region(flag.grapheme).unwrap();
+            assert_eq!(flag.grapheme, regional_flag(&region));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn flag_to_region_roundtrip() {
+        for code in &["gb-eng", "gb-wls", "us-tx", "fr-75"] {
+            assert_eq!(Some(*code), flag_to_region(&regional_flag(code)).as_deref());
+        }
+    }
+}
@@ -1,7 +1,7 @@
 // Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
 
-// Source: https://unicode.org/Public/emoji/13.1/emoji-test.txt
-// Created at: 2022-10-24 05:19:18.813559297 UTC
+// Source: https://unicode.org/Public/emoji/15.1/emoji-test.txt (up to E14.0, names and groups of E13.1 kept)
+// Created at: 2026-10-16 14:10:37.438013765 UTC
 
 #![allow(unused_imports)]
 
@@ -24,30 +24,37 @@
 	// begin arts_and_crafts 🎨🖼️🪢
 		
 		// ARTIST_PALETTE 🎨
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::arts_and_crafts::ARTIST_PALETTE;
 		
 		// FRAMED_PICTURE 🖼️
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::arts_and_crafts::FRAMED_PICTURE;
 		
 		// KNOT 🪢
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::arts_and_crafts::KNOT;
 		
 		// PERFORMING_ARTS 🎭
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::arts_and_crafts::PERFORMING_ARTS;
 		
 		// SEWING_NEEDLE 🪡
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::arts_and_crafts::SEWING_NEEDLE;
 		
 		// THREAD 🧵
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::arts_and_crafts::THREAD;
 		
 		// YARN 🧶
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::arts_and_crafts::YARN;
 		
@@ -56,26 +63,32 @@
 	// begin award_medal 🥇🎖️🥈
 		
 		// FIRST_PLACE_MEDAL 🥇
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::award_medal::FIRST_PLACE_MEDAL;
 		
 		// MILITARY_MEDAL 🎖️
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::award_medal::MILITARY_MEDAL;
 		
 		// SECOND_PLACE_MEDAL 🥈
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::award_medal::SECOND_PLACE_MEDAL;
 		
 		// SPORTS_MEDAL 🏅
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::award_medal::SPORTS_MEDAL;
 		
 		// THIRD_PLACE_MEDAL 🥉
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::award_medal::THIRD_PLACE_MEDAL;
 		
 		// TROPHY 🏆
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::award_medal::TROPHY;
 		
@@ -84,86 +97,107 @@
 	// begin event 🎟️🎈🎏
 		
 		// ADMISSION_TICKETS 🎟️
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::ADMISSION_TICKETS;
 		
 		// BALLOON 🎈
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::BALLOON;
 		
 		// CARP_STREAMER 🎏
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::CARP_STREAMER;
 		
 		// CHRISTMAS_TREE 🎄
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::CHRISTMAS_TREE;
 		
 		// CONFETTI_BALL 🎊
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::CONFETTI_BALL;
 		
 		// FIRECRACKER 🧨
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::FIRECRACKER;
 		
 		// FIREWORKS 🎆
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::FIREWORKS;
 		
 		// JACK_O_LANTERN 🎃
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::JACK_O_LANTERN;
 		
 		// JAPANESE_DOLLS 🎎
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::JAPANESE_DOLLS;
 		
 		// MOON_VIEWING_CEREMONY 🎑
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::MOON_VIEWING_CEREMONY;
 		
 		// PARTY_POPPER 🎉
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::PARTY_POPPER;
 		
 		// PINE_DECORATION 🎍
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::PINE_DECORATION;
 		
 		// RED_ENVELOPE 🧧
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::RED_ENVELOPE;
 		
 		// REMINDER_RIBBON 🎗️
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::REMINDER_RIBBON;
 		
 		// RIBBON 🎀
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::RIBBON;
 		
 		// SPARKLER 🎇
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::SPARKLER;
 		
 		// SPARKLES ✨
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::SPARKLES;
 		
 		// TANABATA_TREE 🎋
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::TANABATA_TREE;
 		
 		// TICKET 🎫
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::TICKET;
 		
 		// WIND_CHIME 🎐
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::WIND_CHIME;
 		
 		// WRAPPED_GIFT 🎁
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::event::WRAPPED_GIFT;
 		
@@ -172,94 +206,122 @@
 	// begin game 🎯♟️♣️
 		
 		// BULLSEYE 🎯
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::BULLSEYE;
 		
 		// CHESS_PAWN ♟️
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::CHESS_PAWN;
 		
 		// CLUB_SUIT ♣️
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::CLUB_SUIT;
 		
 		// CRYSTAL_BALL 🔮
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::CRYSTAL_BALL;
 		
 		// DIAMOND_SUIT ♦️
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::DIAMOND_SUIT;
 		
 		// FLOWER_PLAYING_CARDS 🎴
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::FLOWER_PLAYING_CARDS;
 		
 		// GAME_DIE 🎲
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::GAME_DIE;
 		
 		// HEART_SUIT ♥️
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::HEART_SUIT;
 		
 		// JOKER 🃏
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::JOKER;
 		
 		// JOYSTICK 🕹️
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::JOYSTICK;
 		
 		// KITE 🪁
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::KITE;
 		
 		// MAGIC_WAND 🪄
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::MAGIC_WAND;
 		
 		// MAHJONG_RED_DRAGON 🀄
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::MAHJONG_RED_DRAGON;
 		
+		// MIRROR_BALL 🪩
+		#[cfg(emojic_group = "activities")]
+		#[doc(inline)]
+		pub use crate::grouped::activities::game::MIRROR_BALL;
+		
 		// NAZAR_AMULET 🧿
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::NAZAR_AMULET;
 		
 		// NESTING_DOLLS 🪆
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::NESTING_DOLLS;
 		
 		// PINATA 🪅
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::PINATA;
 		
 		// POOL_8_BALL 🎱
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::POOL_8_BALL;
 		
 		// PUZZLE_PIECE 🧩
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::PUZZLE_PIECE;
 		
 		// SLOT_MACHINE 🎰
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::SLOT_MACHINE;
 		
 		// SPADE_SUIT ♠️
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::SPADE_SUIT;
 		
 		// TEDDY_BEAR 🧸
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::TEDDY_BEAR;
 		
 		// VIDEO_GAME 🎮
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::VIDEO_GAME;
 		
 		// YO_YO 🪀
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::game::YO_YO;
 		
@@ -268,110 +330,137 @@
 	// begin sport 🏈🏸⚾
 		
 		// AMERICAN_FOOTBALL 🏈
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::AMERICAN_FOOTBALL;
 		
 		// BADMINTON 🏸
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::BADMINTON;
 		
 		// BASEBALL ⚾
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::BASEBALL;
 		
 		// BASKETBALL 🏀
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::BASKETBALL;
 		
 		// BOWLING 🎳
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::BOWLING;
 		
 		// BOXING_GLOVE 🥊
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::BOXING_GLOVE;
 		
 		// CRICKET_GAME 🏏
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::CRICKET_GAME;
 		
 		// CURLING_STONE 🥌
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::CURLING_STONE;
 		
 		// DIVING_MASK 🤿
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::DIVING_MASK;
 		
 		// FIELD_HOCKEY 🏑
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::FIELD_HOCKEY;
 		
 		// FISHING_POLE 🎣
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::FISHING_POLE;
 		
 		// FLAG_IN_HOLE ⛳
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::FLAG_IN_HOLE;
 		
 		// FLYING_DISC 🥏
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::FLYING_DISC;
 		
 		// GOAL_NET 🥅
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::GOAL_NET;
 		
 		// ICE_HOCKEY 🏒
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::ICE_HOCKEY;
 		
 		// ICE_SKATE ⛸️
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::ICE_SKATE;
 		
 		// LACROSSE 🥍
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::LACROSSE;
 		
 		// MARTIAL_ARTS_UNIFORM 🥋
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::MARTIAL_ARTS_UNIFORM;
 		
 		// PING_PONG 🏓
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::PING_PONG;
 		
 		// RUGBY_FOOTBALL 🏉
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::RUGBY_FOOTBALL;
 		
 		// RUNNING_SHIRT 🎽
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::RUNNING_SHIRT;
 		
 		// SKIS 🎿
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::SKIS;
 		
 		// SLED 🛷
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::SLED;
 		
 		// SOCCER_BALL ⚽
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::SOCCER_BALL;
 		
 		// SOFTBALL 🥎
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::SOFTBALL;
 		
 		// TENNIS 🎾
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::TENNIS;
 		
 		// VOLLEYBALL 🏐
+		#[cfg(emojic_group = "activities")]
 		#[doc(inline)]
 		pub use crate::grouped::activities::sport::VOLLEYBALL;
 		
@@ -384,6 +473,7 @@
 	// begin animal_amphibian 🐸
 		
 		// FROG 🐸
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_amphibian::FROG;
 		
@@ -392,74 +482,92 @@
 	// begin animal_bird 🐤🐦🐔
 		
 		// BABY_CHICK 🐤
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::BABY_CHICK;
 		
 		// BIRD 🐦
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::BIRD;
 		
 		// CHICKEN 🐔
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::CHICKEN;
 		
 		// DODO 🦤
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::DODO;
 		
 		// DOVE 🕊️
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::DOVE;
 		
 		// DUCK 🦆
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::DUCK;
 		
 		// EAGLE 🦅
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::EAGLE;
 		
 		// FEATHER 🪶
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::FEATHER;
 		
 		// FLAMINGO 🦩
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::FLAMINGO;
 		
 		// FRONT_FACING_BABY_CHICK 🐥
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::FRONT_FACING_BABY_CHICK;
 		
 		// HATCHING_CHICK 🐣
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::HATCHING_CHICK;
 		
 		// OWL 🦉
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::OWL;
 		
 		// PARROT 🦜
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::PARROT;
 		
 		// PEACOCK 🦚
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::PEACOCK;
 		
 		// PENGUIN 🐧
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::PENGUIN;
 		
 		// ROOSTER 🐓
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::ROOSTER;
 		
 		// SWAN 🦢
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::SWAN;
 		
 		// TURKEY 🦃
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bird::TURKEY;
 		
@@ -468,66 +576,82 @@
 	// begin animal_bug 🐜🪲🐛
 		
 		// ANT 🐜
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::ANT;
 		
 		// BEETLE 🪲
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::BEETLE;
 		
 		// BUG 🐛
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::BUG;
 		
 		// BUTTERFLY 🦋
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::BUTTERFLY;
 		
 		// COCKROACH 🪳
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::COCKROACH;
 		
 		// CRICKET 🦗
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::CRICKET;
 		
 		// FLY 🪰
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::FLY;
 		
 		// HONEYBEE 🐝
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::HONEYBEE;
 		
 		// LADY_BEETLE 🐞
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::LADY_BEETLE;
 		
 		// MICROBE 🦠
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::MICROBE;
 		
 		// MOSQUITO 🦟
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::MOSQUITO;
 		
 		// SCORPION 🦂
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::SCORPION;
 		
 		// SNAIL 🐌
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::SNAIL;
 		
 		// SPIDER 🕷️
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::SPIDER;
 		
 		// SPIDER_WEB 🕸️
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::SPIDER_WEB;
 		
 		// WORM 🪱
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_bug::WORM;
 		
@@ -536,302 +660,381 @@
 	// begin animal_mammal 🦡🦇🐻
 		
 		// BADGER 🦡
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::BADGER;
 		
 		// BAT 🦇
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::BAT;
 		
 		// BEAR 🐻
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::BEAR;
 		
 		// BEAVER 🦫
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::BEAVER;
 		
 		// BISON 🦬
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::BISON;
 		
 		// BLACK_CAT 🐈‍⬛
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::BLACK_CAT;
 		
 		// BOAR 🐗
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::BOAR;
 		
 		// CAMEL 🐪
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::CAMEL;
 		
 		// CAT 🐈
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::CAT;
 		
 		// CAT_FACE 🐱
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::CAT_FACE;
 		
 		// CHIPMUNK 🐿️
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::CHIPMUNK;
 		
 		// COW 🐄
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::COW;
 		
 		// COW_FACE 🐮
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::COW_FACE;
 		
 		// DEER 🦌
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::DEER;
 		
 		// DOG 🐕
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::DOG;
 		
 		// DOG_FACE 🐶
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::DOG_FACE;
 		
 		// ELEPHANT 🐘
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::ELEPHANT;
 		
 		// EWE 🐑
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::EWE;
 		
 		// FOX 🦊
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::FOX;
 		
 		// GIRAFFE 🦒
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::GIRAFFE;
 		
 		// GOAT 🐐
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::GOAT;
 		
 		// GORILLA 🦍
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::GORILLA;
 		
 		// GUIDE_DOG 🦮
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::GUIDE_DOG;
 		
 		// HAMSTER 🐹
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::HAMSTER;
 		
 		// HEDGEHOG 🦔
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::HEDGEHOG;
 		
 		// HIPPOPOTAMUS 🦛
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::HIPPOPOTAMUS;
 		
 		// HORSE 🐎
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::HORSE;
 		
 		// HORSE_FACE 🐴
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::HORSE_FACE;
 		
 		// KANGAROO 🦘
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::KANGAROO;
 		
 		// KOALA 🐨
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::KOALA;
 		
 		// LEOPARD 🐆
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::LEOPARD;
 		
 		// LION 🦁
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::LION;
 		
 		// LLAMA 🦙
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::LLAMA;
 		
 		// MAMMOTH 🦣
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::MAMMOTH;
 		
 		// MONKEY 🐒
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::MONKEY;
 		
 		// MONKEY_FACE 🐵
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::MONKEY_FACE;
 		
 		// MOUSE 🐁
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::MOUSE;
 		
 		// MOUSE_FACE 🐭
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::MOUSE_FACE;
 		
 		// ORANGUTAN 🦧
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::ORANGUTAN;
 		
 		// OTTER 🦦
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::OTTER;
 		
 		// OX 🐂
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::OX;
 		
 		// PANDA 🐼
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::PANDA;
 		
 		// PAW_PRINTS 🐾
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::PAW_PRINTS;
 		
 		// PIG 🐖
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::PIG;
 		
 		// PIG_FACE 🐷
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::PIG_FACE;
 		
 		// PIG_NOSE 🐽
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::PIG_NOSE;
 		
 		// POLAR_BEAR 🐻‍❄️
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::POLAR_BEAR;
 		
 		// POODLE 🐩
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::POODLE;
 		
 		// RABBIT 🐇
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::RABBIT;
 		
 		// RABBIT_FACE 🐰
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::RABBIT_FACE;
 		
 		// RACCOON 🦝
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::RACCOON;
 		
 		// RAM 🐏
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::RAM;
 		
 		// RAT 🐀
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::RAT;
 		
 		// RHINOCEROS 🦏
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::RHINOCEROS;
 		
 		// SERVICE_DOG 🐕‍🦺
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::SERVICE_DOG;
 		
 		// SKUNK 🦨
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::SKUNK;
 		
 		// SLOTH 🦥
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::SLOTH;
 		
 		// TIGER 🐅
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::TIGER;
 		
 		// TIGER_FACE 🐯
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::TIGER_FACE;
 		
 		// TWO_HUMP_CAMEL 🐫
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::TWO_HUMP_CAMEL;
 		
 		// UNICORN 🦄
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::UNICORN;
 		
 		// WATER_BUFFALO 🐃
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::WATER_BUFFALO;
 		
 		// WOLF 🐺
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::WOLF;
 		
 		// ZEBRA 🦓
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_mammal::ZEBRA;
 		
 	// end animal_mammal
 	
-	// begin animal_marine 🐡🐬🐟
+	// begin animal_marine 🐡🪸🐬
 		
 		// BLOWFISH 🐡
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_marine::BLOWFISH;
 		
+		// CORAL 🪸
+		#[cfg(emojic_group = "animals_and_nature")]
+		#[doc(inline)]
+		pub use crate::grouped::animals_and_nature::animal_marine::CORAL;
+		
 		// DOLPHIN 🐬
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_marine::DOLPHIN;
 		
 		// FISH 🐟
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_marine::FISH;
 		
 		// OCTOPUS 🐙
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_marine::OCTOPUS;
 		
 		// SEAL 🦭
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_marine::SEAL;
 		
 		// SHARK 🦈
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_marine::SHARK;
 		
 		// SPIRAL_SHELL 🐚
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_marine::SPIRAL_SHELL;
 		
 		// SPOUTING_WHALE 🐳
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_marine::SPOUTING_WHALE;
 		
 		// TROPICAL_FISH 🐠
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_marine::TROPICAL_FISH;
 		
 		// WHALE 🐋
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_marine::WHALE;
 		
@@ -840,34 +1043,42 @@
 	// begin animal_reptile 🐊🐉🐲
 		
 		// CROCODILE 🐊
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_reptile::CROCODILE;
 		
 		// DRAGON 🐉
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_reptile::DRAGON;
 		
 		// DRAGON_FACE 🐲
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_reptile::DRAGON_FACE;
 		
 		// LIZARD 🦎
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_reptile::LIZARD;
 		
 		// SAUROPOD 🦕
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_reptile::SAUROPOD;
 		
 		// SNAKE 🐍
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_reptile::SNAKE;
 		
 		// TURTLE 🐢
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_reptile::TURTLE;
 		
 		// T_REX 🦖
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::animal_reptile::T_REX;
 		
@@ -876,98 +1087,136 @@
 	// begin plant_flower 🌼💐🌸
 		
 		// BLOSSOM 🌼
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_flower::BLOSSOM;
 		
 		// BOUQUET 💐
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_flower::BOUQUET;
 		
 		// CHERRY_BLOSSOM 🌸
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_flower::CHERRY_BLOSSOM;
 		
 		// HIBISCUS 🌺
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_flower::HIBISCUS;
 		
+		// LOTUS 🪷
+		#[cfg(emojic_group = "animals_and_nature")]
+		#[doc(inline)]
+		pub use crate::grouped::animals_and_nature::plant_flower::LOTUS;
+		
 		// ROSE 🌹
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_flower::ROSE;
 		
 		// ROSETTE 🏵️
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_flower::ROSETTE;
 		
 		// SUNFLOWER 🌻
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_flower::SUNFLOWER;
 		
 		// TULIP 🌷
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_flower::TULIP;
 		
 		// WHITE_FLOWER 💮
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_flower::WHITE_FLOWER;
 		
 		// WILTED_FLOWER 🥀
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_flower::WILTED_FLOWER;
 		
 	// end plant_flower
 	
-	// begin plant_other 🌵🌳🌲
+	// begin plant_other 🌵🌳🪹
 		
 		// CACTUS 🌵
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_other::CACTUS;
 		
 		// DECIDUOUS_TREE 🌳
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_other::DECIDUOUS_TREE;
 		
+		// EMPTY_NEST 🪹
+		#[cfg(emojic_group = "animals_and_nature")]
+		#[doc(inline)]
+		pub use crate::grouped::animals_and_nature::plant_other::EMPTY_NEST;
+		
 		// EVERGREEN_TREE 🌲
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_other::EVERGREEN_TREE;
 		
 		// FALLEN_LEAF 🍂
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_other::FALLEN_LEAF;
 		
 		// FOUR_LEAF_CLOVER 🍀
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_other::FOUR_LEAF_CLOVER;
 		
 		// HERB 🌿
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_other::HERB;
 		
 		// LEAF_FLUTTERING_IN_WIND 🍃
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_other::LEAF_FLUTTERING_IN_WIND;
 		
 		// MAPLE_LEAF 🍁
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_other::MAPLE_LEAF;
 		
+		// NEST_WITH_EGGS 🪺
+		#[cfg(emojic_group = "animals_and_nature")]
+		#[doc(inline)]
+		pub use crate::grouped::animals_and_nature::plant_other::NEST_WITH_EGGS;
+		
 		// PALM_TREE 🌴
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_other::PALM_TREE;
 		
 		// POTTED_PLANT 🪴
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_other::POTTED_PLANT;
 		
 		// SEEDLING 🌱
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_other::SEEDLING;
 		
 		// SHAMROCK ☘️
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_other::SHAMROCK;
 		
 		// SHEAF_OF_RICE 🌾
+		#[cfg(emojic_group = "animals_and_nature")]
 		#[doc(inline)]
 		pub use crate::grouped::animals_and_nature::plant_other::SHEAF_OF_RICE;
 		
@@ -980,18 +1229,22 @@
 	// begin hair_style 🦲🦱🦰
 		
 		// BALD 🦲
+		#[cfg(emojic_group = "component")]
 		#[doc(inline)]
 		pub use crate::grouped::component::hair_style::BALD;
 		
 		// CURLY_HAIR 🦱
+		#[cfg(emojic_group = "component")]
 		#[doc(inline)]
 		pub use crate::grouped::component::hair_style::CURLY_HAIR;
 		
 		// RED_HAIR 🦰
+		#[cfg(emojic_group = "component")]
 		#[doc(inline)]
 		pub use crate::grouped::component::hair_style::RED_HAIR;
 		
 		// WHITE_HAIR 🦳
+		#[cfg(emojic_group = "component")]
 		#[doc(inline)]
 		pub use crate::grouped::component::hair_style::WHITE_HAIR;
 		
@@ -1000,22 +1253,27 @@
 	// begin skin_tone 🏿🏻🏾
 		
 		// DARK_SKIN_TONE 🏿
+		#[cfg(emojic_group = "component")]
 		#[doc(inline)]
 		pub use crate::grouped::component::skin_tone::DARK_SKIN_TONE;
 		
 		// LIGHT_SKIN_TONE 🏻
+		#[cfg(emojic_group = "component")]
 		#[doc(inline)]
 		pub use crate::grouped::component::skin_tone::LIGHT_SKIN_TONE;
 		
 		// MEDIUM_DARK_SKIN_TONE 🏾
+		#[cfg(emojic_group = "component")]
 		#[doc(inline)]
 		pub use crate::grouped::component::skin_tone::MEDIUM_DARK_SKIN_TONE;
 		
 		// MEDIUM_LIGHT_SKIN_TONE 🏼
+		#[cfg(emojic_group = "component")]
 		#[doc(inline)]
 		pub use crate::grouped::component::skin_tone::MEDIUM_LIGHT_SKIN_TONE;
 		
 		// MEDIUM_SKIN_TONE 🏽
+		#[cfg(emojic_group = "component")]
 		#[doc(inline)]
 		pub use crate::grouped::component::skin_tone::MEDIUM_SKIN_TONE;
 		
@@ -1028,1034 +1286,1292 @@
 	// begin country_flag 🇦🇫🇦🇽🇦🇱
 		
 		// FLAG_AFGHANISTAN 🇦🇫
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_AFGHANISTAN;
 		
 		// FLAG_ALAND_ISLANDS 🇦🇽
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ALAND_ISLANDS;
 		
 		// FLAG_ALBANIA 🇦🇱
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ALBANIA;
 		
 		// FLAG_ALGERIA 🇩🇿
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ALGERIA;
 		
 		// FLAG_AMERICAN_SAMOA 🇦🇸
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_AMERICAN_SAMOA;
 		
 		// FLAG_ANDORRA 🇦🇩
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ANDORRA;
 		
 		// FLAG_ANGOLA 🇦🇴
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ANGOLA;
 		
 		// FLAG_ANGUILLA 🇦🇮
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ANGUILLA;
 		
 		// FLAG_ANTARCTICA 🇦🇶
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ANTARCTICA;
 		
 		// FLAG_ANTIGUA_AND_BARBUDA 🇦🇬
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ANTIGUA_AND_BARBUDA;
 		
 		// FLAG_ARGENTINA 🇦🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ARGENTINA;
 		
 		// FLAG_ARMENIA 🇦🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ARMENIA;
 		
 		// FLAG_ARUBA 🇦🇼
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ARUBA;
 		
 		// FLAG_ASCENSION_ISLAND 🇦🇨
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ASCENSION_ISLAND;
 		
 		// FLAG_AUSTRALIA 🇦🇺
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_AUSTRALIA;
 		
 		// FLAG_AUSTRIA 🇦🇹
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_AUSTRIA;
 		
 		// FLAG_AZERBAIJAN 🇦🇿
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_AZERBAIJAN;
 		
 		// FLAG_BAHAMAS 🇧🇸
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BAHAMAS;
 		
 		// FLAG_BAHRAIN 🇧🇭
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BAHRAIN;
 		
 		// FLAG_BANGLADESH 🇧🇩
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BANGLADESH;
 		
 		// FLAG_BARBADOS 🇧🇧
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BARBADOS;
 		
 		// FLAG_BELARUS 🇧🇾
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BELARUS;
 		
 		// FLAG_BELGIUM 🇧🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BELGIUM;
 		
 		// FLAG_BELIZE 🇧🇿
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BELIZE;
 		
 		// FLAG_BENIN 🇧🇯
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BENIN;
 		
 		// FLAG_BERMUDA 🇧🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BERMUDA;
 		
 		// FLAG_BHUTAN 🇧🇹
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BHUTAN;
 		
 		// FLAG_BOLIVIA 🇧🇴
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BOLIVIA;
 		
 		// FLAG_BOSNIA_AND_HERZEGOVINA 🇧🇦
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BOSNIA_AND_HERZEGOVINA;
 		
 		// FLAG_BOTSWANA 🇧🇼
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BOTSWANA;
 		
 		// FLAG_BOUVET_ISLAND 🇧🇻
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BOUVET_ISLAND;
 		
 		// FLAG_BRAZIL 🇧🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BRAZIL;
 		
 		// FLAG_BRITISH_INDIAN_OCEAN_TERRITORY 🇮🇴
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BRITISH_INDIAN_OCEAN_TERRITORY;
 		
 		// FLAG_BRITISH_VIRGIN_ISLANDS 🇻🇬
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BRITISH_VIRGIN_ISLANDS;
 		
 		// FLAG_BRUNEI 🇧🇳
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BRUNEI;
 		
 		// FLAG_BULGARIA 🇧🇬
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BULGARIA;
 		
 		// FLAG_BURKINA_FASO 🇧🇫
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BURKINA_FASO;
 		
 		// FLAG_BURUNDI 🇧🇮
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_BURUNDI;
 		
 		// FLAG_CAMBODIA 🇰🇭
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CAMBODIA;
 		
 		// FLAG_CAMEROON 🇨🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CAMEROON;
 		
 		// FLAG_CANADA 🇨🇦
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CANADA;
 		
 		// FLAG_CANARY_ISLANDS 🇮🇨
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CANARY_ISLANDS;
 		
 		// FLAG_CAPE_VERDE 🇨🇻
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CAPE_VERDE;
 		
 		// FLAG_CARIBBEAN_NETHERLANDS 🇧🇶
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CARIBBEAN_NETHERLANDS;
 		
 		// FLAG_CAYMAN_ISLANDS 🇰🇾
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CAYMAN_ISLANDS;
 		
 		// FLAG_CENTRAL_AFRICAN_REPUBLIC 🇨🇫
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CENTRAL_AFRICAN_REPUBLIC;
 		
 		// FLAG_CEUTA_AND_MELILLA 🇪🇦
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CEUTA_AND_MELILLA;
 		
 		// FLAG_CHAD 🇹🇩
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CHAD;
 		
 		// FLAG_CHILE 🇨🇱
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CHILE;
 		
 		// FLAG_CHINA 🇨🇳
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CHINA;
 		
 		// FLAG_CHRISTMAS_ISLAND 🇨🇽
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CHRISTMAS_ISLAND;
 		
 		// FLAG_CLIPPERTON_ISLAND 🇨🇵
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CLIPPERTON_ISLAND;
 		
 		// FLAG_COCOS_KEELING_ISLANDS 🇨🇨
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_COCOS_KEELING_ISLANDS;
 		
 		// FLAG_COLOMBIA 🇨🇴
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_COLOMBIA;
 		
 		// FLAG_COMOROS 🇰🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_COMOROS;
 		
 		// FLAG_CONGO_BRAZZAVILLE 🇨🇬
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CONGO_BRAZZAVILLE;
 		
 		// FLAG_CONGO_KINSHASA 🇨🇩
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CONGO_KINSHASA;
 		
 		// FLAG_COOK_ISLANDS 🇨🇰
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_COOK_ISLANDS;
 		
 		// FLAG_COSTA_RICA 🇨🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_COSTA_RICA;
 		
 		// FLAG_COTE_D_IVOIRE 🇨🇮
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_COTE_D_IVOIRE;
 		
 		// FLAG_CROATIA 🇭🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CROATIA;
 		
 		// FLAG_CUBA 🇨🇺
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CUBA;
 		
 		// FLAG_CURACAO 🇨🇼
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CURACAO;
 		
 		// FLAG_CYPRUS 🇨🇾
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CYPRUS;
 		
 		// FLAG_CZECHIA 🇨🇿
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_CZECHIA;
 		
 		// FLAG_DENMARK 🇩🇰
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_DENMARK;
 		
 		// FLAG_DIEGO_GARCIA 🇩🇬
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_DIEGO_GARCIA;
 		
 		// FLAG_DJIBOUTI 🇩🇯
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_DJIBOUTI;
 		
 		// FLAG_DOMINICA 🇩🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_DOMINICA;
 		
 		// FLAG_DOMINICAN_REPUBLIC 🇩🇴
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_DOMINICAN_REPUBLIC;
 		
 		// FLAG_ECUADOR 🇪🇨
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ECUADOR;
 		
 		// FLAG_EGYPT 🇪🇬
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_EGYPT;
 		
 		// FLAG_EL_SALVADOR 🇸🇻
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_EL_SALVADOR;
 		
 		// FLAG_EQUATORIAL_GUINEA 🇬🇶
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_EQUATORIAL_GUINEA;
 		
 		// FLAG_ERITREA 🇪🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ERITREA;
 		
 		// FLAG_ESTONIA 🇪🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ESTONIA;
 		
 		// FLAG_ESWATINI 🇸🇿
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ESWATINI;
 		
 		// FLAG_ETHIOPIA 🇪🇹
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ETHIOPIA;
 		
 		// FLAG_EUROPEAN_UNION 🇪🇺
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_EUROPEAN_UNION;
 		
 		// FLAG_FALKLAND_ISLANDS 🇫🇰
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_FALKLAND_ISLANDS;
 		
 		// FLAG_FAROE_ISLANDS 🇫🇴
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_FAROE_ISLANDS;
 		
 		// FLAG_FIJI 🇫🇯
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_FIJI;
 		
 		// FLAG_FINLAND 🇫🇮
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_FINLAND;
 		
 		// FLAG_FRANCE 🇫🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_FRANCE;
 		
 		// FLAG_FRENCH_GUIANA 🇬🇫
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_FRENCH_GUIANA;
 		
 		// FLAG_FRENCH_POLYNESIA 🇵🇫
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_FRENCH_POLYNESIA;
 		
 		// FLAG_FRENCH_SOUTHERN_TERRITORIES 🇹🇫
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_FRENCH_SOUTHERN_TERRITORIES;
 		
 		// FLAG_GABON 🇬🇦
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GABON;
 		
 		// FLAG_GAMBIA 🇬🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GAMBIA;
 		
 		// FLAG_GEORGIA 🇬🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GEORGIA;
 		
 		// FLAG_GERMANY 🇩🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GERMANY;
 		
 		// FLAG_GHANA 🇬🇭
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GHANA;
 		
 		// FLAG_GIBRALTAR 🇬🇮
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GIBRALTAR;
 		
 		// FLAG_GREECE 🇬🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GREECE;
 		
 		// FLAG_GREENLAND 🇬🇱
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GREENLAND;
 		
 		// FLAG_GRENADA 🇬🇩
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GRENADA;
 		
 		// FLAG_GUADELOUPE 🇬🇵
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GUADELOUPE;
 		
 		// FLAG_GUAM 🇬🇺
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GUAM;
 		
 		// FLAG_GUATEMALA 🇬🇹
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GUATEMALA;
 		
 		// FLAG_GUERNSEY 🇬🇬
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GUERNSEY;
 		
 		// FLAG_GUINEA 🇬🇳
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GUINEA;
 		
 		// FLAG_GUINEA_BISSAU 🇬🇼
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GUINEA_BISSAU;
 		
 		// FLAG_GUYANA 🇬🇾
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_GUYANA;
 		
 		// FLAG_HAITI 🇭🇹
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_HAITI;
 		
 		// FLAG_HEARD_AND_MCDONALD_ISLANDS 🇭🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_HEARD_AND_MCDONALD_ISLANDS;
 		
 		// FLAG_HONDURAS 🇭🇳
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_HONDURAS;
 		
 		// FLAG_HONG_KONG_SAR_CHINA 🇭🇰
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_HONG_KONG_SAR_CHINA;
 		
 		// FLAG_HUNGARY 🇭🇺
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_HUNGARY;
 		
 		// FLAG_ICELAND 🇮🇸
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ICELAND;
 		
 		// FLAG_INDIA 🇮🇳
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_INDIA;
 		
 		// FLAG_INDONESIA 🇮🇩
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_INDONESIA;
 		
 		// FLAG_IRAN 🇮🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_IRAN;
 		
 		// FLAG_IRAQ 🇮🇶
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_IRAQ;
 		
 		// FLAG_IRELAND 🇮🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_IRELAND;
 		
 		// FLAG_ISLE_OF_MAN 🇮🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ISLE_OF_MAN;
 		
 		// FLAG_ISRAEL 🇮🇱
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ISRAEL;
 		
 		// FLAG_ITALY 🇮🇹
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ITALY;
 		
 		// FLAG_JAMAICA 🇯🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_JAMAICA;
 		
 		// FLAG_JAPAN 🇯🇵
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_JAPAN;
 		
 		// FLAG_JERSEY 🇯🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_JERSEY;
 		
 		// FLAG_JORDAN 🇯🇴
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_JORDAN;
 		
 		// FLAG_KAZAKHSTAN 🇰🇿
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_KAZAKHSTAN;
 		
 		// FLAG_KENYA 🇰🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_KENYA;
 		
 		// FLAG_KIRIBATI 🇰🇮
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_KIRIBATI;
 		
 		// FLAG_KOSOVO 🇽🇰
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_KOSOVO;
 		
 		// FLAG_KUWAIT 🇰🇼
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_KUWAIT;
 		
 		// FLAG_KYRGYZSTAN 🇰🇬
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_KYRGYZSTAN;
 		
 		// FLAG_LAOS 🇱🇦
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_LAOS;
 		
 		// FLAG_LATVIA 🇱🇻
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_LATVIA;
 		
 		// FLAG_LEBANON 🇱🇧
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_LEBANON;
 		
 		// FLAG_LESOTHO 🇱🇸
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_LESOTHO;
 		
 		// FLAG_LIBERIA 🇱🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_LIBERIA;
 		
 		// FLAG_LIBYA 🇱🇾
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_LIBYA;
 		
 		// FLAG_LIECHTENSTEIN 🇱🇮
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_LIECHTENSTEIN;
 		
 		// FLAG_LITHUANIA 🇱🇹
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_LITHUANIA;
 		
 		// FLAG_LUXEMBOURG 🇱🇺
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_LUXEMBOURG;
 		
 		// FLAG_MACAO_SAR_CHINA 🇲🇴
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MACAO_SAR_CHINA;
 		
 		// FLAG_MADAGASCAR 🇲🇬
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MADAGASCAR;
 		
 		// FLAG_MALAWI 🇲🇼
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MALAWI;
 		
 		// FLAG_MALAYSIA 🇲🇾
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MALAYSIA;
 		
 		// FLAG_MALDIVES 🇲🇻
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MALDIVES;
 		
 		// FLAG_MALI 🇲🇱
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MALI;
 		
 		// FLAG_MALTA 🇲🇹
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MALTA;
 		
 		// FLAG_MARSHALL_ISLANDS 🇲🇭
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MARSHALL_ISLANDS;
 		
 		// FLAG_MARTINIQUE 🇲🇶
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MARTINIQUE;
 		
 		// FLAG_MAURITANIA 🇲🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MAURITANIA;
 		
 		// FLAG_MAURITIUS 🇲🇺
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MAURITIUS;
 		
 		// FLAG_MAYOTTE 🇾🇹
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MAYOTTE;
 		
 		// FLAG_MEXICO 🇲🇽
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MEXICO;
 		
 		// FLAG_MICRONESIA 🇫🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MICRONESIA;
 		
 		// FLAG_MOLDOVA 🇲🇩
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MOLDOVA;
 		
 		// FLAG_MONACO 🇲🇨
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MONACO;
 		
 		// FLAG_MONGOLIA 🇲🇳
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MONGOLIA;
 		
 		// FLAG_MONTENEGRO 🇲🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MONTENEGRO;
 		
 		// FLAG_MONTSERRAT 🇲🇸
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MONTSERRAT;
 		
 		// FLAG_MOROCCO 🇲🇦
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MOROCCO;
 		
 		// FLAG_MOZAMBIQUE 🇲🇿
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MOZAMBIQUE;
 		
 		// FLAG_MYANMAR_BURMA 🇲🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_MYANMAR_BURMA;
 		
 		// FLAG_NAMIBIA 🇳🇦
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NAMIBIA;
 		
 		// FLAG_NAURU 🇳🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NAURU;
 		
 		// FLAG_NEPAL 🇳🇵
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NEPAL;
 		
 		// FLAG_NETHERLANDS 🇳🇱
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NETHERLANDS;
 		
 		// FLAG_NEW_CALEDONIA 🇳🇨
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NEW_CALEDONIA;
 		
 		// FLAG_NEW_ZEALAND 🇳🇿
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NEW_ZEALAND;
 		
 		// FLAG_NICARAGUA 🇳🇮
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NICARAGUA;
 		
 		// FLAG_NIGER 🇳🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NIGER;
 		
 		// FLAG_NIGERIA 🇳🇬
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NIGERIA;
 		
 		// FLAG_NIUE 🇳🇺
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NIUE;
 		
 		// FLAG_NORFOLK_ISLAND 🇳🇫
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NORFOLK_ISLAND;
 		
 		// FLAG_NORTHERN_MARIANA_ISLANDS 🇲🇵
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NORTHERN_MARIANA_ISLANDS;
 		
 		// FLAG_NORTH_KOREA 🇰🇵
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NORTH_KOREA;
 		
 		// FLAG_NORTH_MACEDONIA 🇲🇰
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NORTH_MACEDONIA;
 		
 		// FLAG_NORWAY 🇳🇴
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_NORWAY;
 		
 		// FLAG_OMAN 🇴🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_OMAN;
 		
 		// FLAG_PAKISTAN 🇵🇰
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_PAKISTAN;
 		
 		// FLAG_PALAU 🇵🇼
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_PALAU;
 		
 		// FLAG_PALESTINIAN_TERRITORIES 🇵🇸
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_PALESTINIAN_TERRITORIES;
 		
 		// FLAG_PANAMA 🇵🇦
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_PANAMA;
 		
 		// FLAG_PAPUA_NEW_GUINEA 🇵🇬
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_PAPUA_NEW_GUINEA;
 		
 		// FLAG_PARAGUAY 🇵🇾
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_PARAGUAY;
 		
 		// FLAG_PERU 🇵🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_PERU;
 		
 		// FLAG_PHILIPPINES 🇵🇭
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_PHILIPPINES;
 		
 		// FLAG_PITCAIRN_ISLANDS 🇵🇳
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_PITCAIRN_ISLANDS;
 		
 		// FLAG_POLAND 🇵🇱
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_POLAND;
 		
 		// FLAG_PORTUGAL 🇵🇹
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_PORTUGAL;
 		
 		// FLAG_PUERTO_RICO 🇵🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_PUERTO_RICO;
 		
 		// FLAG_QATAR 🇶🇦
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_QATAR;
 		
 		// FLAG_REUNION 🇷🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_REUNION;
 		
 		// FLAG_ROMANIA 🇷🇴
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ROMANIA;
 		
 		// FLAG_RUSSIA 🇷🇺
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_RUSSIA;
 		
 		// FLAG_RWANDA 🇷🇼
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_RWANDA;
 		
 		// FLAG_SAMOA 🇼🇸
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SAMOA;
 		
 		// FLAG_SAN_MARINO 🇸🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SAN_MARINO;
 		
 		// FLAG_SAO_TOME_AND_PRINCIPE 🇸🇹
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SAO_TOME_AND_PRINCIPE;
 		
 		// FLAG_SAUDI_ARABIA 🇸🇦
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SAUDI_ARABIA;
 		
 		// FLAG_SENEGAL 🇸🇳
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SENEGAL;
 		
 		// FLAG_SERBIA 🇷🇸
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SERBIA;
 		
 		// FLAG_SEYCHELLES 🇸🇨
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SEYCHELLES;
 		
 		// FLAG_SIERRA_LEONE 🇸🇱
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SIERRA_LEONE;
 		
 		// FLAG_SINGAPORE 🇸🇬
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SINGAPORE;
 		
 		// FLAG_SINT_MAARTEN 🇸🇽
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SINT_MAARTEN;
 		
 		// FLAG_SLOVAKIA 🇸🇰
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SLOVAKIA;
 		
 		// FLAG_SLOVENIA 🇸🇮
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SLOVENIA;
 		
 		// FLAG_SOLOMON_ISLANDS 🇸🇧
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SOLOMON_ISLANDS;
 		
 		// FLAG_SOMALIA 🇸🇴
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SOMALIA;
 		
 		// FLAG_SOUTH_AFRICA 🇿🇦
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SOUTH_AFRICA;
 		
 		// FLAG_SOUTH_GEORGIA_AND_SOUTH_SANDWICH_ISLANDS 🇬🇸
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SOUTH_GEORGIA_AND_SOUTH_SANDWICH_ISLANDS;
 		
 		// FLAG_SOUTH_KOREA 🇰🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SOUTH_KOREA;
 		
 		// FLAG_SOUTH_SUDAN 🇸🇸
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SOUTH_SUDAN;
 		
 		// FLAG_SPAIN 🇪🇸
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SPAIN;
 		
 		// FLAG_SRI_LANKA 🇱🇰
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SRI_LANKA;
 		
 		// FLAG_ST_BARTHELEMY 🇧🇱
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ST_BARTHELEMY;
 		
 		// FLAG_ST_HELENA 🇸🇭
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ST_HELENA;
 		
 		// FLAG_ST_KITTS_AND_NEVIS 🇰🇳
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ST_KITTS_AND_NEVIS;
 		
 		// FLAG_ST_LUCIA 🇱🇨
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ST_LUCIA;
 		
 		// FLAG_ST_MARTIN 🇲🇫
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ST_MARTIN;
 		
 		// FLAG_ST_PIERRE_AND_MIQUELON 🇵🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ST_PIERRE_AND_MIQUELON;
 		
 		// FLAG_ST_VINCENT_AND_GRENADINES 🇻🇨
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ST_VINCENT_AND_GRENADINES;
 		
 		// FLAG_SUDAN 🇸🇩
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SUDAN;
 		
 		// FLAG_SURINAME 🇸🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SURINAME;
 		
 		// FLAG_SVALBARD_AND_JAN_MAYEN 🇸🇯
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SVALBARD_AND_JAN_MAYEN;
 		
 		// FLAG_SWEDEN 🇸🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SWEDEN;
 		
 		// FLAG_SWITZERLAND 🇨🇭
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SWITZERLAND;
 		
 		// FLAG_SYRIA 🇸🇾
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_SYRIA;
 		
 		// FLAG_TAIWAN 🇹🇼
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_TAIWAN;
 		
 		// FLAG_TAJIKISTAN 🇹🇯
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_TAJIKISTAN;
 		
 		// FLAG_TANZANIA 🇹🇿
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_TANZANIA;
 		
 		// FLAG_THAILAND 🇹🇭
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_THAILAND;
 		
 		// FLAG_TIMOR_LESTE 🇹🇱
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_TIMOR_LESTE;
 		
 		// FLAG_TOGO 🇹🇬
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_TOGO;
 		
 		// FLAG_TOKELAU 🇹🇰
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_TOKELAU;
 		
 		// FLAG_TONGA 🇹🇴
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_TONGA;
 		
 		// FLAG_TRINIDAD_AND_TOBAGO 🇹🇹
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_TRINIDAD_AND_TOBAGO;
 		
 		// FLAG_TRISTAN_DA_CUNHA 🇹🇦
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_TRISTAN_DA_CUNHA;
 		
 		// FLAG_TUNISIA 🇹🇳
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_TUNISIA;
 		
 		// FLAG_TURKEY 🇹🇷
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_TURKEY;
 		
 		// FLAG_TURKMENISTAN 🇹🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_TURKMENISTAN;
 		
 		// FLAG_TURKS_AND_CAICOS_ISLANDS 🇹🇨
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_TURKS_AND_CAICOS_ISLANDS;
 		
 		// FLAG_TUVALU 🇹🇻
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_TUVALU;
 		
 		// FLAG_UGANDA 🇺🇬
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_UGANDA;
 		
 		// FLAG_UKRAINE 🇺🇦
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_UKRAINE;
 		
 		// FLAG_UNITED_ARAB_EMIRATES 🇦🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_UNITED_ARAB_EMIRATES;
 		
 		// FLAG_UNITED_KINGDOM 🇬🇧
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_UNITED_KINGDOM;
 		
 		// FLAG_UNITED_NATIONS 🇺🇳
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_UNITED_NATIONS;
 		
 		// FLAG_UNITED_STATES 🇺🇸
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_UNITED_STATES;
 		
 		// FLAG_URUGUAY 🇺🇾
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_URUGUAY;
 		
 		// FLAG_US_OUTLYING_ISLANDS 🇺🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_US_OUTLYING_ISLANDS;
 		
 		// FLAG_US_VIRGIN_ISLANDS 🇻🇮
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_US_VIRGIN_ISLANDS;
 		
 		// FLAG_UZBEKISTAN 🇺🇿
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_UZBEKISTAN;
 		
 		// FLAG_VANUATU 🇻🇺
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_VANUATU;
 		
 		// FLAG_VATICAN_CITY 🇻🇦
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_VATICAN_CITY;
 		
 		// FLAG_VENEZUELA 🇻🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_VENEZUELA;
 		
 		// FLAG_VIETNAM 🇻🇳
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_VIETNAM;
 		
 		// FLAG_WALLIS_AND_FUTUNA 🇼🇫
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_WALLIS_AND_FUTUNA;
 		
 		// FLAG_WESTERN_SAHARA 🇪🇭
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_WESTERN_SAHARA;
 		
 		// FLAG_YEMEN 🇾🇪
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_YEMEN;
 		
 		// FLAG_ZAMBIA 🇿🇲
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ZAMBIA;
 		
 		// FLAG_ZIMBABWE 🇿🇼
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::country_flag::FLAG_ZIMBABWE;
 		
@@ -2064,34 +2580,42 @@
 	// begin flag 🏴🏁🎌
 		
 		// BLACK_FLAG 🏴
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::flag::BLACK_FLAG;
 		
 		// CHEQUERED_FLAG 🏁
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::flag::CHEQUERED_FLAG;
 		
 		// CROSSED_FLAGS 🎌
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::flag::CROSSED_FLAGS;
 		
 		// PIRATE_FLAG 🏴‍☠️
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::flag::PIRATE_FLAG;
 		
 		// RAINBOW_FLAG 🏳️‍🌈
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::flag::RAINBOW_FLAG;
 		
 		// TRANSGENDER_FLAG 🏳️‍⚧️
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::flag::TRANSGENDER_FLAG;
 		
 		// TRIANGULAR_FLAG 🚩
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::flag::TRIANGULAR_FLAG;
 		
 		// WHITE_FLAG 🏳️
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::flag::WHITE_FLAG;
 		
@@ -2100,14 +2624,17 @@
 	// begin subdivision_flag 🏴󠁧󠁢󠁥󠁮󠁧󠁿🏴󠁧󠁢󠁳󠁣󠁴󠁿🏴󠁧󠁢󠁷󠁬󠁳󠁿
 		
 		// FLAG_ENGLAND 🏴󠁧󠁢󠁥󠁮󠁧󠁿
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::subdivision_flag::FLAG_ENGLAND;
 		
 		// FLAG_SCOTLAND 🏴󠁧󠁢󠁳󠁣󠁴󠁿
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::subdivision_flag::FLAG_SCOTLAND;
 		
 		// FLAG_WALES 🏴󠁧󠁢󠁷󠁬󠁳󠁿
+		#[cfg(emojic_group = "flags")]
 		#[doc(inline)]
 		pub use crate::grouped::flags::subdivision_flag::FLAG_WALES;
 		
@@ -2120,26 +2647,37 @@
 	// begin dishware 🏺🥢🍴
 		
 		// AMPHORA 🏺
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::dishware::AMPHORA;
 		
 		// CHOPSTICKS 🥢
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::dishware::CHOPSTICKS;
 		
 		// FORK_AND_KNIFE 🍴
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::dishware::FORK_AND_KNIFE;
 		
 		// FORK_AND_KNIFE_WITH_PLATE 🍽️
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::dishware::FORK_AND_KNIFE_WITH_PLATE;
 		
+		// JAR 🫙
+		#[cfg(emojic_group = "food_and_drink")]
+		#[doc(inline)]
+		pub use crate::grouped::food_and_drink::dishware::JAR;
+		
 		// KITCHEN_KNIFE 🔪
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::dishware::KITCHEN_KNIFE;
 		
 		// SPOON 🥄
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::dishware::SPOON;
 		
@@ -2148,78 +2686,102 @@
 	// begin drink 🍼🍺🧃
 		
 		// BABY_BOTTLE 🍼
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::BABY_BOTTLE;
 		
 		// BEER_MUG 🍺
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::BEER_MUG;
 		
 		// BEVERAGE_BOX 🧃
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::BEVERAGE_BOX;
 		
 		// BOTTLE_WITH_POPPING_CORK 🍾
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::BOTTLE_WITH_POPPING_CORK;
 		
 		// BUBBLE_TEA 🧋
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::BUBBLE_TEA;
 		
 		// CLINKING_BEER_MUGS 🍻
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::CLINKING_BEER_MUGS;
 		
 		// CLINKING_GLASSES 🥂
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::CLINKING_GLASSES;
 		
 		// COCKTAIL_GLASS 🍸
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::COCKTAIL_GLASS;
 		
 		// CUP_WITH_STRAW 🥤
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::CUP_WITH_STRAW;
 		
 		// GLASS_OF_MILK 🥛
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::GLASS_OF_MILK;
 		
 		// HOT_BEVERAGE ☕
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::HOT_BEVERAGE;
 		
 		// ICE 🧊
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::ICE;
 		
 		// MATE 🧉
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::MATE;
 		
+		// POURING_LIQUID 🫗
+		#[cfg(emojic_group = "food_and_drink")]
+		#[doc(inline)]
+		pub use crate::grouped::food_and_drink::drink::POURING_LIQUID;
+		
 		// SAKE 🍶
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::SAKE;
 		
 		// TEACUP_WITHOUT_HANDLE 🍵
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::TEACUP_WITHOUT_HANDLE;
 		
 		// TEAPOT 🫖
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::TEAPOT;
 		
 		// TROPICAL_DRINK 🍹
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::TROPICAL_DRINK;
 		
 		// TUMBLER_GLASS 🥃
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::TUMBLER_GLASS;
 		
 		// WINE_GLASS 🍷
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::drink::WINE_GLASS;
 		
@@ -2228,70 +2790,87 @@
 	// begin food_asian 🍱🍚🍛
 		
 		// BENTO_BOX 🍱
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::BENTO_BOX;
 		
 		// COOKED_RICE 🍚
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::COOKED_RICE;
 		
 		// CURRY_RICE 🍛
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::CURRY_RICE;
 		
 		// DANGO 🍡
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::DANGO;
 		
 		// DUMPLING 🥟
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::DUMPLING;
 		
 		// FISH_CAKE_WITH_SWIRL 🍥
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::FISH_CAKE_WITH_SWIRL;
 		
 		// FORTUNE_COOKIE 🥠
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::FORTUNE_COOKIE;
 		
 		// FRIED_SHRIMP 🍤
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::FRIED_SHRIMP;
 		
 		// MOON_CAKE 🥮
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::MOON_CAKE;
 		
 		// ODEN 🍢
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::ODEN;
 		
 		// RICE_BALL 🍙
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::RICE_BALL;
 		
 		// RICE_CRACKER 🍘
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::RICE_CRACKER;
 		
 		// ROASTED_SWEET_POTATO 🍠
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::ROASTED_SWEET_POTATO;
 		
 		// SPAGHETTI 🍝
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::SPAGHETTI;
 		
 		// STEAMING_BOWL 🍜
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::STEAMING_BOWL;
 		
 		// SUSHI 🍣
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::SUSHI;
 		
 		// TAKEOUT_BOX 🥡
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_asian::TAKEOUT_BOX;
 		
@@ -2300,78 +2879,97 @@
 	// begin food_fruit 🍌🫐🍒
 		
 		// BANANA 🍌
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::BANANA;
 		
 		// BLUEBERRIES 🫐
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::BLUEBERRIES;
 		
 		// CHERRIES 🍒
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::CHERRIES;
 		
 		// COCONUT 🥥
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::COCONUT;
 		
 		// GRAPES 🍇
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::GRAPES;
 		
 		// GREEN_APPLE 🍏
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::GREEN_APPLE;
 		
 		// KIWI_FRUIT 🥝
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::KIWI_FRUIT;
 		
 		// LEMON 🍋
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::LEMON;
 		
 		// MANGO 🥭
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::MANGO;
 		
 		// MELON 🍈
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::MELON;
 		
 		// OLIVE 🫒
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::OLIVE;
 		
 		// PEACH 🍑
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::PEACH;
 		
 		// PEAR 🍐
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::PEAR;
 		
 		// PINEAPPLE 🍍
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::PINEAPPLE;
 		
 		// RED_APPLE 🍎
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::RED_APPLE;
 		
 		// STRAWBERRY 🍓
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::STRAWBERRY;
 		
 		// TANGERINE 🍊
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::TANGERINE;
 		
 		// TOMATO 🍅
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::TOMATO;
 		
 		// WATERMELON 🍉
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_fruit::WATERMELON;
 		
@@ -2380,22 +2978,27 @@
 	// begin food_marine 🦀🦞🦪
 		
 		// CRAB 🦀
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_marine::CRAB;
 		
 		// LOBSTER 🦞
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_marine::LOBSTER;
 		
 		// OYSTER 🦪
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_marine::OYSTER;
 		
 		// SHRIMP 🦐
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_marine::SHRIMP;
 		
 		// SQUID 🦑
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_marine::SQUID;
 		
@@ -2404,138 +3007,172 @@
 	// begin food_prepared 🥓🥯🥖
 		
 		// BACON 🥓
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::BACON;
 		
 		// BAGEL 🥯
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::BAGEL;
 		
 		// BAGUETTE_BREAD 🥖
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::BAGUETTE_BREAD;
 		
 		// BOWL_WITH_SPOON 🥣
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::BOWL_WITH_SPOON;
 		
 		// BREAD 🍞
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::BREAD;
 		
 		// BURRITO 🌯
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::BURRITO;
 		
 		// BUTTER 🧈
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::BUTTER;
 		
 		// CANNED_FOOD 🥫
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::CANNED_FOOD;
 		
 		// CHEESE_WEDGE 🧀
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::CHEESE_WEDGE;
 		
 		// COOKING 🍳
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::COOKING;
 		
 		// CROISSANT 🥐
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::CROISSANT;
 		
 		// CUT_OF_MEAT 🥩
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::CUT_OF_MEAT;
 		
 		// EGG 🥚
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::EGG;
 		
 		// FALAFEL 🧆
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::FALAFEL;
 		
 		// FLATBREAD 🫓
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::FLATBREAD;
 		
 		// FONDUE 🫕
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::FONDUE;
 		
 		// FRENCH_FRIES 🍟
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::FRENCH_FRIES;
 		
 		// GREEN_SALAD 🥗
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::GREEN_SALAD;
 		
 		// HAMBURGER 🍔
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::HAMBURGER;
 		
 		// HOT_DOG 🌭
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::HOT_DOG;
 		
 		// MEAT_ON_BONE 🍖
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::MEAT_ON_BONE;
 		
 		// PANCAKES 🥞
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::PANCAKES;
 		
 		// PIZZA 🍕
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::PIZZA;
 		
 		// POPCORN 🍿
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::POPCORN;
 		
 		// POT_OF_FOOD 🍲
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::POT_OF_FOOD;
 		
 		// POULTRY_LEG 🍗
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::POULTRY_LEG;
 		
 		// PRETZEL 🥨
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::PRETZEL;
 		
 		// SALT 🧂
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::SALT;
 		
 		// SANDWICH 🥪
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::SANDWICH;
 		
 		// SHALLOW_PAN_OF_FOOD 🥘
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::SHALLOW_PAN_OF_FOOD;
 		
 		// STUFFED_FLATBREAD 🥙
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::STUFFED_FLATBREAD;
 		
 		// TACO 🌮
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::TACO;
 		
 		// TAMALE 🫔
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::TAMALE;
 		
 		// WAFFLE 🧇
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_prepared::WAFFLE;
 		
@@ -2544,122 +3181,156 @@
 	// begin food_sweet 🎂🍬🍫
 		
 		// BIRTHDAY_CAKE 🎂
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_sweet::BIRTHDAY_CAKE;
 		
 		// CANDY 🍬
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_sweet::CANDY;
 		
 		// CHOCOLATE_BAR 🍫
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_sweet::CHOCOLATE_BAR;
 		
 		// COOKIE 🍪
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_sweet::COOKIE;
 		
 		// CUPCAKE 🧁
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_sweet::CUPCAKE;
 		
 		// CUSTARD 🍮
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_sweet::CUSTARD;
 		
 		// DOUGHNUT 🍩
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_sweet::DOUGHNUT;
 		
 		// HONEY_POT 🍯
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_sweet::HONEY_POT;
 		
 		// ICE_CREAM 🍨
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_sweet::ICE_CREAM;
 		
 		// LOLLIPOP 🍭
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_sweet::LOLLIPOP;
 		
 		// PIE 🥧
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_sweet::PIE;
 		
 		// SHAVED_ICE 🍧
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_sweet::SHAVED_ICE;
 		
 		// SHORTCAKE 🍰
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_sweet::SHORTCAKE;
 		
 		// SOFT_ICE_CREAM 🍦
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_sweet::SOFT_ICE_CREAM;
 		
 	// end food_sweet
 	
-	// begin food_vegetable 🥑🫑🥦
+	// begin food_vegetable 🥑🫘🫑
 		
 		// AVOCADO 🥑
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::AVOCADO;
 		
+		// BEANS 🫘
+		#[cfg(emojic_group = "food_and_drink")]
+		#[doc(inline)]
+		pub use crate::grouped::food_and_drink::food_vegetable::BEANS;
+		
 		// BELL_PEPPER 🫑
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::BELL_PEPPER;
 		
 		// BROCCOLI 🥦
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::BROCCOLI;
 		
 		// CARROT 🥕
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::CARROT;
 		
 		// CHESTNUT 🌰
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::CHESTNUT;
 		
 		// CUCUMBER 🥒
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::CUCUMBER;
 		
 		// EAR_OF_CORN 🌽
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::EAR_OF_CORN;
 		
 		// EGGPLANT 🍆
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::EGGPLANT;
 		
 		// GARLIC 🧄
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::GARLIC;
 		
 		// HOT_PEPPER 🌶️
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::HOT_PEPPER;
 		
 		// LEAFY_GREEN 🥬
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::LEAFY_GREEN;
 		
 		// MUSHROOM 🍄
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::MUSHROOM;
 		
 		// ONION 🧅
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::ONION;
 		
 		// PEANUTS 🥜
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::PEANUTS;
 		
 		// POTATO 🥔
+		#[cfg(emojic_group = "food_and_drink")]
 		#[doc(inline)]
 		pub use crate::grouped::food_and_drink::food_vegetable::POTATO;
 		
@@ -2672,70 +3343,87 @@
 	// begin book_paper 📘🔖📑
 		
 		// BLUE_BOOK 📘
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::BLUE_BOOK;
 		
 		// BOOKMARK 🔖
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::BOOKMARK;
 		
 		// BOOKMARK_TABS 📑
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::BOOKMARK_TABS;
 		
 		// BOOKS 📚
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::BOOKS;
 		
 		// CLOSED_BOOK 📕
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::CLOSED_BOOK;
 		
 		// GREEN_BOOK 📗
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::GREEN_BOOK;
 		
 		// LABEL 🏷️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::LABEL;
 		
 		// LEDGER 📒
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::LEDGER;
 		
 		// NEWSPAPER 📰
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::NEWSPAPER;
 		
 		// NOTEBOOK 📓
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::NOTEBOOK;
 		
 		// NOTEBOOK_WITH_DECORATIVE_COVER 📔
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::NOTEBOOK_WITH_DECORATIVE_COVER;
 		
 		// OPEN_BOOK 📖
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::OPEN_BOOK;
 		
 		// ORANGE_BOOK 📙
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::ORANGE_BOOK;
 		
 		// PAGE_FACING_UP 📄
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::PAGE_FACING_UP;
 		
 		// PAGE_WITH_CURL 📃
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::PAGE_WITH_CURL;
 		
 		// ROLLED_UP_NEWSPAPER 🗞️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::ROLLED_UP_NEWSPAPER;
 		
 		// SCROLL 📜
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::book_paper::SCROLL;
 		
@@ -2744,182 +3432,227 @@
 	// begin clothing 🎒🩰👙
 		
 		// BACKPACK 🎒
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::BACKPACK;
 		
 		// BALLET_SHOES 🩰
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::BALLET_SHOES;
 		
 		// BIKINI 👙
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::BIKINI;
 		
 		// BILLED_CAP 🧢
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::BILLED_CAP;
 		
 		// BRIEFS 🩲
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::BRIEFS;
 		
 		// CLUTCH_BAG 👝
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::CLUTCH_BAG;
 		
 		// COAT 🧥
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::COAT;
 		
 		// CROWN 👑
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::CROWN;
 		
 		// DRESS 👗
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::DRESS;
 		
 		// FLAT_SHOE 🥿
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::FLAT_SHOE;
 		
 		// GEM_STONE 💎
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::GEM_STONE;
 		
 		// GLASSES 👓
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::GLASSES;
 		
 		// GLOVES 🧤
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::GLOVES;
 		
 		// GOGGLES 🥽
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::GOGGLES;
 		
 		// GRADUATION_CAP 🎓
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::GRADUATION_CAP;
 		
 		// HANDBAG 👜
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::HANDBAG;
 		
 		// HIGH_HEELED_SHOE 👠
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::HIGH_HEELED_SHOE;
 		
 		// HIKING_BOOT 🥾
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::HIKING_BOOT;
 		
 		// JEANS 👖
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::JEANS;
 		
 		// KIMONO 👘
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::KIMONO;
 		
 		// LAB_COAT 🥼
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::LAB_COAT;
 		
 		// LIPSTICK 💄
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::LIPSTICK;
 		
 		// MAN_S_SHOE 👞
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::MAN_S_SHOE;
 		
 		// MILITARY_HELMET 🪖
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::MILITARY_HELMET;
 		
 		// NECKTIE 👔
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::NECKTIE;
 		
 		// ONE_PIECE_SWIMSUIT 🩱
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::ONE_PIECE_SWIMSUIT;
 		
 		// PRAYER_BEADS 📿
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::PRAYER_BEADS;
 		
 		// PURSE 👛
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::PURSE;
 		
 		// RESCUE_WORKER_S_HELMET ⛑️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::RESCUE_WORKER_S_HELMET;
 		
 		// RING 💍
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::RING;
 		
 		// RUNNING_SHOE 👟
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::RUNNING_SHOE;
 		
 		// SAFETY_VEST 🦺
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::SAFETY_VEST;
 		
 		// SARI 🥻
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::SARI;
 		
 		// SCARF 🧣
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::SCARF;
 		
 		// SHOPPING_BAGS 🛍️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::SHOPPING_BAGS;
 		
 		// SHORTS 🩳
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::SHORTS;
 		
 		// SOCKS 🧦
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::SOCKS;
 		
 		// SUNGLASSES 🕶️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::SUNGLASSES;
 		
 		// THONG_SANDAL 🩴
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::THONG_SANDAL;
 		
 		// TOP_HAT 🎩
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::TOP_HAT;
 		
 		// T_SHIRT 👕
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::T_SHIRT;
 		
 		// WOMAN_S_BOOT 👢
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::WOMAN_S_BOOT;
 		
 		// WOMAN_S_CLOTHES 👚
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::WOMAN_S_CLOTHES;
 		
 		// WOMAN_S_HAT 👒
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::WOMAN_S_HAT;
 		
 		// WOMAN_S_SANDAL 👡
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::clothing::WOMAN_S_SANDAL;
 		
@@ -2928,54 +3661,72 @@
 	// begin computer 🧮🔋💽
 		
 		// ABACUS 🧮
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::computer::ABACUS;
 		
 		// BATTERY 🔋
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::computer::BATTERY;
 		
 		// COMPUTER_DISK 💽
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::computer::COMPUTER_DISK;
 		
 		// COMPUTER_MOUSE 🖱️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::computer::COMPUTER_MOUSE;
 		
 		// DESKTOP_COMPUTER 🖥️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::computer::DESKTOP_COMPUTER;
 		
 		// DVD 📀
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::computer::DVD;
 		
 		// ELECTRIC_PLUG 🔌
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::computer::ELECTRIC_PLUG;
 		
 		// FLOPPY_DISK 💾
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::computer::FLOPPY_DISK;
 		
 		// KEYBOARD ⌨️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::computer::KEYBOARD;
 		
 		// LAPTOP 💻
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::computer::LAPTOP;
 		
+		// LOW_BATTERY 🪫
+		#[cfg(emojic_group = "objects")]
+		#[doc(inline)]
+		pub use crate::grouped::objects::computer::LOW_BATTERY;
+		
 		// OPTICAL_DISK 💿
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::computer::OPTICAL_DISK;
 		
 		// PRINTER 🖨️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::computer::PRINTER;
 		
 		// TRACKBALL 🖲️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::computer::TRACKBALL;
 		
@@ -2984,98 +3735,127 @@
 	// begin household 🧺🛁🛏️
 		
 		// BASKET 🧺
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::BASKET;
 		
 		// BATHTUB 🛁
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::BATHTUB;
 		
 		// BED 🛏️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::BED;
 		
 		// BROOM 🧹
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::BROOM;
 		
+		// BUBBLES 🫧
+		#[cfg(emojic_group = "objects")]
+		#[doc(inline)]
+		pub use crate::grouped::objects::household::BUBBLES;
+		
 		// BUCKET 🪣
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::BUCKET;
 		
 		// CHAIR 🪑
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::CHAIR;
 		
 		// COUCH_AND_LAMP 🛋️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::COUCH_AND_LAMP;
 		
 		// DOOR 🚪
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::DOOR;
 		
 		// ELEVATOR 🛗
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::ELEVATOR;
 		
 		// FIRE_EXTINGUISHER 🧯
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::FIRE_EXTINGUISHER;
 		
 		// LOTION_BOTTLE 🧴
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::LOTION_BOTTLE;
 		
 		// MIRROR 🪞
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::MIRROR;
 		
 		// MOUSE_TRAP 🪤
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::MOUSE_TRAP;
 		
 		// PLUNGER 🪠
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::PLUNGER;
 		
 		// RAZOR 🪒
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::RAZOR;
 		
 		// ROLL_OF_PAPER 🧻
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::ROLL_OF_PAPER;
 		
 		// SAFETY_PIN 🧷
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::SAFETY_PIN;
 		
 		// SHOPPING_CART 🛒
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::SHOPPING_CART;
 		
 		// SHOWER 🚿
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::SHOWER;
 		
 		// SOAP 🧼
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::SOAP;
 		
 		// SPONGE 🧽
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::SPONGE;
 		
 		// TOILET 🚽
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::TOILET;
 		
 		// TOOTHBRUSH 🪥
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::TOOTHBRUSH;
 		
 		// WINDOW 🪟
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::household::WINDOW;
 		
@@ -3084,66 +3864,82 @@
 	// begin light_and_video 📷📸🕯️
 		
 		// CAMERA 📷
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::CAMERA;
 		
 		// CAMERA_WITH_FLASH 📸
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::CAMERA_WITH_FLASH;
 		
 		// CANDLE 🕯️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::CANDLE;
 		
 		// CLAPPER_BOARD 🎬
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::CLAPPER_BOARD;
 		
 		// DIYA_LAMP 🪔
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::DIYA_LAMP;
 		
 		// FILM_FRAMES 🎞️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::FILM_FRAMES;
 		
 		// FILM_PROJECTOR 📽️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::FILM_PROJECTOR;
 		
 		// FLASHLIGHT 🔦
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::FLASHLIGHT;
 		
 		// LIGHT_BULB 💡
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::LIGHT_BULB;
 		
 		// MAGNIFYING_GLASS_TILTED_LEFT 🔍
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::MAGNIFYING_GLASS_TILTED_LEFT;
 		
 		// MAGNIFYING_GLASS_TILTED_RIGHT 🔎
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::MAGNIFYING_GLASS_TILTED_RIGHT;
 		
 		// MOVIE_CAMERA 🎥
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::MOVIE_CAMERA;
 		
 		// RED_PAPER_LANTERN 🏮
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::RED_PAPER_LANTERN;
 		
 		// TELEVISION 📺
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::TELEVISION;
 		
 		// VIDEOCASSETTE 📼
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::VIDEOCASSETTE;
 		
 		// VIDEO_CAMERA 📹
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::light_and_video::VIDEO_CAMERA;
 		
@@ -3152,26 +3948,32 @@
 	// begin lock 🔑🔒🔐
 		
 		// KEY 🔑
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::lock::KEY;
 		
 		// LOCKED 🔒
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::lock::LOCKED;
 		
 		// LOCKED_WITH_KEY 🔐
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::lock::LOCKED_WITH_KEY;
 		
 		// LOCKED_WITH_PEN 🔏
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::lock::LOCKED_WITH_PEN;
 		
 		// OLD_KEY 🗝️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::lock::OLD_KEY;
 		
 		// UNLOCKED 🔓
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::lock::UNLOCKED;
 		
@@ -3180,122 +3982,160 @@
 	// begin mail 🗳️📪📫
 		
 		// BALLOT_BOX_WITH_BALLOT 🗳️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::mail::BALLOT_BOX_WITH_BALLOT;
 		
 		// CLOSED_MAILBOX_WITH_LOWERED_FLAG 📪
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::mail::CLOSED_MAILBOX_WITH_LOWERED_FLAG;
 		
 		// CLOSED_MAILBOX_WITH_RAISED_FLAG 📫
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::mail::CLOSED_MAILBOX_WITH_RAISED_FLAG;
 		
 		// ENVELOPE ✉️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::mail::ENVELOPE;
 		
 		// ENVELOPE_WITH_ARROW 📩
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::mail::ENVELOPE_WITH_ARROW;
 		
 		// E_MAIL 📧
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::mail::E_MAIL;
 		
 		// INBOX_TRAY 📥
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::mail::INBOX_TRAY;
 		
 		// INCOMING_ENVELOPE 📨
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::mail::INCOMING_ENVELOPE;
 		
 		// OPEN_MAILBOX_WITH_LOWERED_FLAG 📭
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::mail::OPEN_MAILBOX_WITH_LOWERED_FLAG;
 		
 		// OPEN_MAILBOX_WITH_RAISED_FLAG 📬
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::mail::OPEN_MAILBOX_WITH_RAISED_FLAG;
 		
 		// OUTBOX_TRAY 📤
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::mail::OUTBOX_TRAY;
 		
 		// PACKAGE 📦
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::mail::PACKAGE;
 		
 		// POSTBOX 📮
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::mail::POSTBOX;
 		
 	// end mail
 	
-	// begin medical 🩹🩸💊
+	// begin medical 🩹🩼🩸
 		
 		// ADHESIVE_BANDAGE 🩹
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::medical::ADHESIVE_BANDAGE;
 		
+		// CRUTCH 🩼
+		#[cfg(emojic_group = "objects")]
+		#[doc(inline)]
+		pub use crate::grouped::objects::medical::CRUTCH;
+		
 		// DROP_OF_BLOOD 🩸
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::medical::DROP_OF_BLOOD;
 		
 		// PILL 💊
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::medical::PILL;
 		
 		// STETHOSCOPE 🩺
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::medical::STETHOSCOPE;
 		
 		// SYRINGE 💉
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::medical::SYRINGE;
 		
+		// X_RAY 🩻
+		#[cfg(emojic_group = "objects")]
+		#[doc(inline)]
+		pub use crate::grouped::objects::medical::X_RAY;
+		
 	// end medical
 	
 	// begin money 💹🪙💳
 		
 		// CHART_INCREASING_WITH_YEN 💹
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::money::CHART_INCREASING_WITH_YEN;
 		
 		// COIN 🪙
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::money::COIN;
 		
 		// CREDIT_CARD 💳
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::money::CREDIT_CARD;
 		
 		// DOLLAR_BANKNOTE 💵
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::money::DOLLAR_BANKNOTE;
 		
 		// EURO_BANKNOTE 💶
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::money::EURO_BANKNOTE;
 		
 		// MONEY_BAG 💰
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::money::MONEY_BAG;
 		
 		// MONEY_WITH_WINGS 💸
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::money::MONEY_WITH_WINGS;
 		
 		// POUND_BANKNOTE 💷
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::money::POUND_BANKNOTE;
 		
 		// RECEIPT 🧾
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::money::RECEIPT;
 		
 		// YEN_BANKNOTE 💴
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::money::YEN_BANKNOTE;
 		
@@ -3304,38 +4144,47 @@
 	// begin music 🎛️🎧🎚️
 		
 		// CONTROL_KNOBS 🎛️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::music::CONTROL_KNOBS;
 		
 		// HEADPHONE 🎧
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::music::HEADPHONE;
 		
 		// LEVEL_SLIDER 🎚️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::music::LEVEL_SLIDER;
 		
 		// MICROPHONE 🎤
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::music::MICROPHONE;
 		
 		// MUSICAL_NOTE 🎵
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::music::MUSICAL_NOTE;
 		
 		// MUSICAL_NOTES 🎶
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::music::MUSICAL_NOTES;
 		
 		// MUSICAL_SCORE 🎼
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::music::MUSICAL_SCORE;
 		
 		// RADIO 📻
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::music::RADIO;
 		
 		// STUDIO_MICROPHONE 🎙️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::music::STUDIO_MICROPHONE;
 		
@@ -3344,38 +4193,47 @@
 	// begin musical_instrument 🪗🪕🥁
 		
 		// ACCORDION 🪗
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::musical_instrument::ACCORDION;
 		
 		// BANJO 🪕
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::musical_instrument::BANJO;
 		
 		// DRUM 🥁
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::musical_instrument::DRUM;
 		
 		// GUITAR 🎸
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::musical_instrument::GUITAR;
 		
 		// LONG_DRUM 🪘
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::musical_instrument::LONG_DRUM;
 		
 		// MUSICAL_KEYBOARD 🎹
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::musical_instrument::MUSICAL_KEYBOARD;
 		
 		// SAXOPHONE 🎷
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::musical_instrument::SAXOPHONE;
 		
 		// TRUMPET 🎺
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::musical_instrument::TRUMPET;
 		
 		// VIOLIN 🎻
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::musical_instrument::VIOLIN;
 		
@@ -3384,94 +4242,117 @@
 	// begin office 📊💼📅
 		
 		// BAR_CHART 📊
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::BAR_CHART;
 		
 		// BRIEFCASE 💼
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::BRIEFCASE;
 		
 		// CALENDAR 📅
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::CALENDAR;
 		
 		// CARD_FILE_BOX 🗃️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::CARD_FILE_BOX;
 		
 		// CARD_INDEX 📇
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::CARD_INDEX;
 		
 		// CARD_INDEX_DIVIDERS 🗂️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::CARD_INDEX_DIVIDERS;
 		
 		// CHART_DECREASING 📉
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::CHART_DECREASING;
 		
 		// CHART_INCREASING 📈
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::CHART_INCREASING;
 		
 		// CLIPBOARD 📋
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::CLIPBOARD;
 		
 		// FILE_CABINET 🗄️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::FILE_CABINET;
 		
 		// FILE_FOLDER 📁
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::FILE_FOLDER;
 		
 		// LINKED_PAPERCLIPS 🖇️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::LINKED_PAPERCLIPS;
 		
 		// OPEN_FILE_FOLDER 📂
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::OPEN_FILE_FOLDER;
 		
 		// PAPERCLIP 📎
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::PAPERCLIP;
 		
 		// PUSHPIN 📌
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::PUSHPIN;
 		
 		// ROUND_PUSHPIN 📍
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::ROUND_PUSHPIN;
 		
 		// SCISSORS ✂️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::SCISSORS;
 		
 		// SPIRAL_CALENDAR 🗓️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::SPIRAL_CALENDAR;
 		
 		// SPIRAL_NOTEPAD 🗒️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::SPIRAL_NOTEPAD;
 		
 		// STRAIGHT_RULER 📏
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::STRAIGHT_RULER;
 		
 		// TEAR_OFF_CALENDAR 📆
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::TEAR_OFF_CALENDAR;
 		
 		// TRIANGULAR_RULER 📐
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::TRIANGULAR_RULER;
 		
 		// WASTEBASKET 🗑️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::office::WASTEBASKET;
 		
@@ -3480,26 +4361,42 @@
 	// begin other_object 🚬⚰️⚱️
 		
 		// CIGARETTE 🚬
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::other_object::CIGARETTE;
 		
 		// COFFIN ⚰️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::other_object::COFFIN;
 		
 		// FUNERAL_URN ⚱️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::other_object::FUNERAL_URN;
 		
+		// HAMSA 🪬
+		#[cfg(emojic_group = "objects")]
+		#[doc(inline)]
+		pub use crate::grouped::objects::other_object::HAMSA;
+		
 		// HEADSTONE 🪦
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::other_object::HEADSTONE;
 		
+		// IDENTIFICATION_CARD 🪪
+		#[cfg(emojic_group = "objects")]
+		#[doc(inline)]
+		pub use crate::grouped::objects::other_object::IDENTIFICATION_CARD;
+		
 		// MOAI 🗿
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::other_object::MOAI;
 		
 		// PLACARD 🪧
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::other_object::PLACARD;
 		
@@ -3508,26 +4405,32 @@
 	// begin phone 📠📱📲
 		
 		// FAX_MACHINE 📠
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::phone::FAX_MACHINE;
 		
 		// MOBILE_PHONE 📱
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::phone::MOBILE_PHONE;
 		
 		// MOBILE_PHONE_WITH_ARROW 📲
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::phone::MOBILE_PHONE_WITH_ARROW;
 		
 		// PAGER 📟
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::phone::PAGER;
 		
 		// TELEPHONE ☎️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::phone::TELEPHONE;
 		
 		// TELEPHONE_RECEIVER 📞
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::phone::TELEPHONE_RECEIVER;
 		
@@ -3536,30 +4439,37 @@
 	// begin science ⚗️🧬🔬
 		
 		// ALEMBIC ⚗️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::science::ALEMBIC;
 		
 		// DNA 🧬
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::science::DNA;
 		
 		// MICROSCOPE 🔬
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::science::MICROSCOPE;
 		
 		// PETRI_DISH 🧫
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::science::PETRI_DISH;
 		
 		// SATELLITE_ANTENNA 📡
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::science::SATELLITE_ANTENNA;
 		
 		// TELESCOPE 🔭
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::science::TELESCOPE;
 		
 		// TEST_TUBE 🧪
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::science::TEST_TUBE;
 		
@@ -3568,38 +4478,47 @@
 	// begin sound 🔔🔕📢
 		
 		// BELL 🔔
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::sound::BELL;
 		
 		// BELL_WITH_SLASH 🔕
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::sound::BELL_WITH_SLASH;
 		
 		// LOUDSPEAKER 📢
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::sound::LOUDSPEAKER;
 		
 		// MEGAPHONE 📣
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::sound::MEGAPHONE;
 		
 		// MUTED_SPEAKER 🔇
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::sound::MUTED_SPEAKER;
 		
 		// POSTAL_HORN 📯
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::sound::POSTAL_HORN;
 		
 		// SPEAKER_HIGH_VOLUME 🔊
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::sound::SPEAKER_HIGH_VOLUME;
 		
 		// SPEAKER_LOW_VOLUME 🔈
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::sound::SPEAKER_LOW_VOLUME;
 		
 		// SPEAKER_MEDIUM_VOLUME 🔉
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::sound::SPEAKER_MEDIUM_VOLUME;
 		
@@ -3608,102 +4527,127 @@
 	// begin tool 🪓⚖️🪃
 		
 		// AXE 🪓
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::AXE;
 		
 		// BALANCE_SCALE ⚖️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::BALANCE_SCALE;
 		
 		// BOOMERANG 🪃
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::BOOMERANG;
 		
 		// BOW_AND_ARROW 🏹
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::BOW_AND_ARROW;
 		
 		// CARPENTRY_SAW 🪚
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::CARPENTRY_SAW;
 		
 		// CHAINS ⛓️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::CHAINS;
 		
 		// CLAMP 🗜️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::CLAMP;
 		
 		// CROSSED_SWORDS ⚔️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::CROSSED_SWORDS;
 		
 		// DAGGER 🗡️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::DAGGER;
 		
 		// GEAR ⚙️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::GEAR;
 		
 		// HAMMER 🔨
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::HAMMER;
 		
 		// HAMMER_AND_PICK ⚒️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::HAMMER_AND_PICK;
 		
 		// HAMMER_AND_WRENCH 🛠️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::HAMMER_AND_WRENCH;
 		
 		// HOOK 🪝
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::HOOK;
 		
 		// LADDER 🪜
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::LADDER;
 		
 		// LINK 🔗
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::LINK;
 		
 		// MAGNET 🧲
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::MAGNET;
 		
 		// NUT_AND_BOLT 🔩
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::NUT_AND_BOLT;
 		
 		// PICK ⛏️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::PICK;
 		
 		// SCREWDRIVER 🪛
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::SCREWDRIVER;
 		
 		// SHIELD 🛡️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::SHIELD;
 		
 		// TOOLBOX 🧰
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::TOOLBOX;
 		
 		// WATER_PISTOL 🔫
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::WATER_PISTOL;
 		
 		// WHITE_CANE 🦯
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::WHITE_CANE;
 		
 		// WRENCH 🔧
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::tool::WRENCH;
 		
@@ -3712,30 +4656,37 @@
 	// begin writing ✒️🖍️🖋️
 		
 		// BLACK_NIB ✒️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::writing::BLACK_NIB;
 		
 		// CRAYON 🖍️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::writing::CRAYON;
 		
 		// FOUNTAIN_PEN 🖋️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::writing::FOUNTAIN_PEN;
 		
 		// MEMO 📝
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::writing::MEMO;
 		
 		// PAINTBRUSH 🖌️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::writing::PAINTBRUSH;
 		
 		// PEN 🖊️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::writing::PEN;
 		
 		// PENCIL ✏️
+		#[cfg(emojic_group = "objects")]
 		#[doc(inline)]
 		pub use crate::grouped::objects::writing::PENCIL;
 		
@@ -3745,73 +4696,95 @@
 
 // begin people_and_body 🫀💑🤛
 	
-	// begin body_parts 🫀🦴🧠
+	// begin body_parts 🫀🫦🦴
 		
 		// ANATOMICAL_HEART 🫀
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::ANATOMICAL_HEART;
 		
+		// BITING_LIP 🫦
+		#[cfg(emojic_group = "people_and_body")]
+		#[doc(inline)]
+		pub use crate::grouped::people_and_body::body_parts::BITING_LIP;
+		
 		// BONE 🦴
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::BONE;
 		
 		// BRAIN 🧠
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::BRAIN;
 		
 		// EAR 👂
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::EAR;
 		
 		// EAR_WITH_HEARING_AID 🦻
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::EAR_WITH_HEARING_AID;
 		
 		// EYE 👁️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::EYE;
 		
 		// EYES 👀
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::EYES;
 		
 		// FLEXED_BICEPS 💪
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::FLEXED_BICEPS;
 		
 		// FOOT 🦶
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::FOOT;
 		
 		// LEG 🦵
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::LEG;
 		
 		// LUNGS 🫁
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::LUNGS;
 		
 		// MECHANICAL_ARM 🦾
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::MECHANICAL_ARM;
 		
 		// MECHANICAL_LEG 🦿
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::MECHANICAL_LEG;
 		
 		// MOUTH 👄
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::MOUTH;
 		
 		// NOSE 👃
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::NOSE;
 		
 		// TONGUE 👅
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::TONGUE;
 		
 		// TOOTH 🦷
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::body_parts::TOOTH;
 		
@@ -3820,18 +4793,22 @@
 	// begin family 💑👪💏
 		
 		// COUPLE_WITH_HEART 💑
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::family::COUPLE_WITH_HEART;
 		
 		// FAMILY 👪
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::family::FAMILY;
 		
 		// KISS 💏
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::family::KISS;
 		
 		// PERSON_HOLDING_HANDS 🧑‍🤝‍🧑
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::family::PERSON_HOLDING_HANDS;
 		
@@ -3840,86 +4817,130 @@
 	// begin hand_fingers_closed 🤛👊✊
 		
 		// LEFT_FACING_FIST 🤛
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_closed::LEFT_FACING_FIST;
 		
 		// ONCOMING_FIST 👊
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_closed::ONCOMING_FIST;
 		
 		// RAISED_FIST ✊
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_closed::RAISED_FIST;
 		
 		// RIGHT_FACING_FIST 🤜
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_closed::RIGHT_FACING_FIST;
 		
 		// THUMBS_DOWN 👎
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_closed::THUMBS_DOWN;
 		
 		// THUMBS_UP 👍
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_closed::THUMBS_UP;
 		
 	// end hand_fingers_closed
 	
-	// begin hand_fingers_open 🖐️🤚✋
+	// begin hand_fingers_open 🖐️🫲🫳
 		
 		// HAND_WITH_FINGERS_SPLAYED 🖐️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_open::HAND_WITH_FINGERS_SPLAYED;
 		
+		// LEFTWARDS_HAND 🫲
+		#[cfg(emojic_group = "people_and_body")]
+		#[doc(inline)]
+		pub use crate::grouped::people_and_body::hand_fingers_open::LEFTWARDS_HAND;
+		
+		// PALM_DOWN_HAND 🫳
+		#[cfg(emojic_group = "people_and_body")]
+		#[doc(inline)]
+		pub use crate::grouped::people_and_body::hand_fingers_open::PALM_DOWN_HAND;
+		
+		// PALM_UP_HAND 🫴
+		#[cfg(emojic_group = "people_and_body")]
+		#[doc(inline)]
+		pub use crate::grouped::people_and_body::hand_fingers_open::PALM_UP_HAND;
+		
 		// RAISED_BACK_OF_HAND 🤚
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_open::RAISED_BACK_OF_HAND;
 		
 		// RAISED_HAND ✋
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_open::RAISED_HAND;
 		
+		// RIGHTWARDS_HAND 🫱
+		#[cfg(emojic_group = "people_and_body")]
+		#[doc(inline)]
+		pub use crate::grouped::people_and_body::hand_fingers_open::RIGHTWARDS_HAND;
+		
 		// VULCAN_SALUTE 🖖
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_open::VULCAN_SALUTE;
 		
 		// WAVING_HAND 👋
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_open::WAVING_HAND;
 		
 	// end hand_fingers_open
 	
-	// begin hand_fingers_partial 🤙🤞🤟
+	// begin hand_fingers_partial 🤙🤞🫰
 		
 		// CALL_ME_HAND 🤙
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_partial::CALL_ME_HAND;
 		
 		// CROSSED_FINGERS 🤞
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_partial::CROSSED_FINGERS;
 		
+		// HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED 🫰
+		#[cfg(emojic_group = "people_and_body")]
+		#[doc(inline)]
+		pub use crate::grouped::people_and_body::hand_fingers_partial::HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED;
+		
 		// LOVE_YOU_GESTURE 🤟
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_partial::LOVE_YOU_GESTURE;
 		
 		// OK_HAND 👌
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_partial::OK_HAND;
 		
 		// PINCHED_FINGERS 🤌
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_partial::PINCHED_FINGERS;
 		
 		// PINCHING_HAND 🤏
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_partial::PINCHING_HAND;
 		
 		// SIGN_OF_THE_HORNS 🤘
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_partial::SIGN_OF_THE_HORNS;
 		
 		// VICTORY_HAND ✌️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_fingers_partial::VICTORY_HAND;
 		
@@ -3928,14 +4949,17 @@
 	// begin hand_prop 💅🤳✍️
 		
 		// NAIL_POLISH 💅
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_prop::NAIL_POLISH;
 		
 		// SELFIE 🤳
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_prop::SELFIE;
 		
 		// WRITING_HAND ✍️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_prop::WRITING_HAND;
 		
@@ -3944,26 +4968,37 @@
 	// begin hand_single_finger 👇👈👉
 		
 		// BACKHAND_INDEX_POINTING_DOWN 👇
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_single_finger::BACKHAND_INDEX_POINTING_DOWN;
 		
 		// BACKHAND_INDEX_POINTING_LEFT 👈
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_single_finger::BACKHAND_INDEX_POINTING_LEFT;
 		
 		// BACKHAND_INDEX_POINTING_RIGHT 👉
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_single_finger::BACKHAND_INDEX_POINTING_RIGHT;
 		
 		// BACKHAND_INDEX_POINTING_UP 👆
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_single_finger::BACKHAND_INDEX_POINTING_UP;
 		
+		// INDEX_POINTING_AT_THE_VIEWER 🫵
+		#[cfg(emojic_group = "people_and_body")]
+		#[doc(inline)]
+		pub use crate::grouped::people_and_body::hand_single_finger::INDEX_POINTING_AT_THE_VIEWER;
+		
 		// INDEX_POINTING_UP ☝️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_single_finger::INDEX_POINTING_UP;
 		
 		// MIDDLE_FINGER 🖕
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hand_single_finger::MIDDLE_FINGER;
 		
@@ -3972,26 +5007,37 @@
 	// begin hands 👏🙏🤝
 		
 		// CLAPPING_HANDS 👏
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hands::CLAPPING_HANDS;
 		
 		// FOLDED_HANDS 🙏
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hands::FOLDED_HANDS;
 		
 		// HANDSHAKE 🤝
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hands::HANDSHAKE;
 		
+		// HEART_HANDS 🫶
+		#[cfg(emojic_group = "people_and_body")]
+		#[doc(inline)]
+		pub use crate::grouped::people_and_body::hands::HEART_HANDS;
+		
 		// OPEN_HANDS 👐
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hands::OPEN_HANDS;
 		
 		// PALMS_UP_TOGETHER 🤲
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hands::PALMS_UP_TOGETHER;
 		
 		// RAISING_HANDS 🙌
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::hands::RAISING_HANDS;
 		
@@ -4000,30 +5046,37 @@
 	// begin person 👶👦🧒
 		
 		// BABY 👶
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person::BABY;
 		
 		// BOY 👦
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person::BOY;
 		
 		// CHILD 🧒
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person::CHILD;
 		
 		// GIRL 👧
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person::GIRL;
 		
 		// OLDER_PERSON 🧓
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person::OLDER_PERSON;
 		
 		// OLD_PERSON 👵👴
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person::OLD_PERSON;
 		
 		// PERSON 🧑
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person::PERSON;
 		
@@ -4032,58 +5085,72 @@
 	// begin person_activity 🧗💇💆
 		
 		// PERSON_CLIMBING 🧗
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_activity::PERSON_CLIMBING;
 		
 		// PERSON_DANCING 💃🕺
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_activity::PERSON_DANCING;
 		
 		// PERSON_GETTING_HAIRCUT 💇
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_activity::PERSON_GETTING_HAIRCUT;
 		
 		// PERSON_GETTING_MASSAGE 💆
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_activity::PERSON_GETTING_MASSAGE;
 		
 		// PERSON_IN_MANUAL_WHEELCHAIR 🧑‍🦽
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_activity::PERSON_IN_MANUAL_WHEELCHAIR;
 		
 		// PERSON_IN_MOTORIZED_WHEELCHAIR 🧑‍🦼
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_activity::PERSON_IN_MOTORIZED_WHEELCHAIR;
 		
 		// PERSON_IN_STEAMY_ROOM 🧖
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_activity::PERSON_IN_STEAMY_ROOM;
 		
 		// PERSON_IN_SUIT_LEVITATING 🕴️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_activity::PERSON_IN_SUIT_LEVITATING;
 		
 		// PERSON_KNEELING 🧎
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_activity::PERSON_KNEELING;
 		
 		// PERSON_RUNNING 🏃
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_activity::PERSON_RUNNING;
 		
 		// PERSON_STANDING 🧍
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_activity::PERSON_STANDING;
 		
 		// PERSON_WALKING 🚶
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_activity::PERSON_WALKING;
 		
 		// PERSON_WITH_BUNNY_EARS 👯
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_activity::PERSON_WITH_BUNNY_EARS;
 		
 		// PERSON_WITH_WHITE_CANE 🧑‍🦯
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_activity::PERSON_WITH_WHITE_CANE;
 		
@@ -4092,62 +5159,82 @@
 	// begin person_fantasy 👼🧝🧚
 		
 		// BABY_ANGEL 👼
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::BABY_ANGEL;
 		
 		// ELF 🧝
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::ELF;
 		
 		// FAIRY 🧚
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::FAIRY;
 		
 		// GENIE 🧞
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::GENIE;
 		
 		// MAGE 🧙
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::MAGE;
 		
 		// MERMAID 🧜‍♀️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::MERMAID;
 		
 		// MERMAN 🧜‍♂️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::MERMAN;
 		
 		// MERPERSON 🧜
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::MERPERSON;
 		
 		// MRS_CLAUS 🤶
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::MRS_CLAUS;
 		
 		// MX_CLAUS 🧑‍🎄
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::MX_CLAUS;
 		
 		// SANTA_CLAUS 🎅
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::SANTA_CLAUS;
 		
 		// SUPERHERO 🦸
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::SUPERHERO;
 		
 		// SUPERVILLAIN 🦹
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::SUPERVILLAIN;
 		
+		// TROLL 🧌
+		#[cfg(emojic_group = "people_and_body")]
+		#[doc(inline)]
+		pub use crate::grouped::people_and_body::person_fantasy::TROLL;
+		
 		// VAMPIRE 🧛
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::VAMPIRE;
 		
 		// ZOMBIE 🧟
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_fantasy::ZOMBIE;
 		
@@ -4156,42 +5243,52 @@
 	// begin person_gesture 🧏🙇🤦
 		
 		// DEAF_PERSON 🧏
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_gesture::DEAF_PERSON;
 		
 		// PERSON_BOWING 🙇
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_gesture::PERSON_BOWING;
 		
 		// PERSON_FACEPALMING 🤦
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_gesture::PERSON_FACEPALMING;
 		
 		// PERSON_FROWNING 🙍
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_gesture::PERSON_FROWNING;
 		
 		// PERSON_GESTURING_NO 🙅
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_gesture::PERSON_GESTURING_NO;
 		
 		// PERSON_GESTURING_OK 🙆
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_gesture::PERSON_GESTURING_OK;
 		
 		// PERSON_POUTING 🙎
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_gesture::PERSON_POUTING;
 		
 		// PERSON_RAISING_HAND 🙋
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_gesture::PERSON_RAISING_HAND;
 		
 		// PERSON_SHRUGGING 🤷
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_gesture::PERSON_SHRUGGING;
 		
 		// PERSON_TIPPING_HAND 💁
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_gesture::PERSON_TIPPING_HAND;
 		
@@ -4200,14 +5297,17 @@
 	// begin person_resting 🛌🧘🛀
 		
 		// PERSON_IN_BED 🛌
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_resting::PERSON_IN_BED;
 		
 		// PERSON_IN_LOTUS_POSITION 🧘
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_resting::PERSON_IN_LOTUS_POSITION;
 		
 		// PERSON_TAKING_BATH 🛀
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_resting::PERSON_TAKING_BATH;
 		
@@ -4216,126 +5316,162 @@
 	// begin person_role 🧑‍🎨🧑‍🚀🤱
 		
 		// ARTIST 🧑‍🎨
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::ARTIST;
 		
 		// ASTRONAUT 🧑‍🚀
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::ASTRONAUT;
 		
 		// BREAST_FEEDING 🤱
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::BREAST_FEEDING;
 		
 		// CONSTRUCTION_WORKER 👷
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::CONSTRUCTION_WORKER;
 		
 		// COOK 🧑‍🍳
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::COOK;
 		
 		// DETECTIVE 🕵️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::DETECTIVE;
 		
 		// FACTORY_WORKER 🧑‍🏭
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::FACTORY_WORKER;
 		
 		// FARMER 🧑‍🌾
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::FARMER;
 		
 		// FIREFIGHTER 🧑‍🚒
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::FIREFIGHTER;
 		
 		// GUARD 💂
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::GUARD;
 		
 		// HEALTH_WORKER 🧑‍⚕️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::HEALTH_WORKER;
 		
 		// JUDGE 🧑‍⚖️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::JUDGE;
 		
 		// MECHANIC 🧑‍🔧
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::MECHANIC;
 		
 		// NINJA 🥷
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::NINJA;
 		
 		// OFFICE_WORKER 🧑‍💼
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::OFFICE_WORKER;
 		
 		// PERSON_FEEDING_BABY 🧑‍🍼
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::PERSON_FEEDING_BABY;
 		
 		// PERSON_IN_TUXEDO 🤵
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::PERSON_IN_TUXEDO;
 		
 		// PERSON_WEARING_TURBAN 👳
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::PERSON_WEARING_TURBAN;
 		
+		// PERSON_WITH_CROWN 🫅
+		#[cfg(emojic_group = "people_and_body")]
+		#[doc(inline)]
+		pub use crate::grouped::people_and_body::person_role::PERSON_WITH_CROWN;
+		
 		// PERSON_WITH_SKULLCAP 👲
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::PERSON_WITH_SKULLCAP;
 		
 		// PERSON_WITH_VEIL 👰
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::PERSON_WITH_VEIL;
 		
 		// PILOT 🧑‍✈️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::PILOT;
 		
 		// POLICE_OFFICER 👮
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::POLICE_OFFICER;
 		
-		// PREGNANT_WOMAN 🤰
+		// PREGNANT_PERSON 🫄
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
-		pub use crate::grouped::people_and_body::person_role::PREGNANT_WOMAN;
+		pub use crate::grouped::people_and_body::person_role::PREGNANT_PERSON;
 		
 		// PRINCE 🤴
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::PRINCE;
 		
 		// PRINCESS 👸
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::PRINCESS;
 		
 		// SCIENTIST 🧑‍🔬
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::SCIENTIST;
 		
 		// SINGER 🧑‍🎤
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::SINGER;
 		
 		// STUDENT 🧑‍🎓
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::STUDENT;
 		
 		// TEACHER 🧑‍🏫
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::TEACHER;
 		
 		// TECHNOLOGIST 🧑‍💻
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::TECHNOLOGIST;
 		
 		// WOMAN_WITH_HEADSCARF 🧕
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_role::WOMAN_WITH_HEADSCARF;
 		
@@ -4344,70 +5480,87 @@
 	// begin person_sport 🏇🚴⛹️
 		
 		// HORSE_RACING 🏇
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::HORSE_RACING;
 		
 		// PERSON_BIKING 🚴
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::PERSON_BIKING;
 		
 		// PERSON_BOUNCING_BALL ⛹️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::PERSON_BOUNCING_BALL;
 		
 		// PERSON_CARTWHEELING 🤸
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::PERSON_CARTWHEELING;
 		
 		// PERSON_FENCING 🤺
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::PERSON_FENCING;
 		
 		// PERSON_GOLFING 🏌️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::PERSON_GOLFING;
 		
 		// PERSON_JUGGLING 🤹
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::PERSON_JUGGLING;
 		
 		// PERSON_LIFTING_WEIGHTS 🏋️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::PERSON_LIFTING_WEIGHTS;
 		
 		// PERSON_MOUNTAIN_BIKING 🚵
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::PERSON_MOUNTAIN_BIKING;
 		
 		// PERSON_PLAYING_HANDBALL 🤾
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::PERSON_PLAYING_HANDBALL;
 		
 		// PERSON_PLAYING_WATER_POLO 🤽
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::PERSON_PLAYING_WATER_POLO;
 		
 		// PERSON_ROWING_BOAT 🚣
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::PERSON_ROWING_BOAT;
 		
 		// PERSON_SURFING 🏄
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::PERSON_SURFING;
 		
 		// PERSON_SWIMMING 🏊
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::PERSON_SWIMMING;
 		
 		// PERSON_WRESTLING 🤼
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::PERSON_WRESTLING;
 		
 		// SKIER ⛷️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::SKIER;
 		
 		// SNOWBOARDER 🏂
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_sport::SNOWBOARDER;
 		
@@ -4416,22 +5569,27 @@
 	// begin person_symbol 👥👤👣
 		
 		// BUSTS_IN_SILHOUETTE 👥
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_symbol::BUSTS_IN_SILHOUETTE;
 		
 		// BUST_IN_SILHOUETTE 👤
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_symbol::BUST_IN_SILHOUETTE;
 		
 		// FOOTPRINTS 👣
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_symbol::FOOTPRINTS;
 		
 		// PERSON_HUGGING 🫂
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_symbol::PERSON_HUGGING;
 		
 		// SPEAKING_HEAD 🗣️
+		#[cfg(emojic_group = "people_and_body")]
 		#[doc(inline)]
 		pub use crate::grouped::people_and_body::person_symbol::SPEAKING_HEAD;
 		
@@ -4444,38 +5602,47 @@
 	// begin cat_face 😹😼😿
 		
 		// CAT_WITH_TEARS_OF_JOY 😹
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::cat_face::CAT_WITH_TEARS_OF_JOY;
 		
 		// CAT_WITH_WRY_SMILE 😼
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::cat_face::CAT_WITH_WRY_SMILE;
 		
 		// CRYING_CAT 😿
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::cat_face::CRYING_CAT;
 		
 		// GRINNING_CAT 😺
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::cat_face::GRINNING_CAT;
 		
 		// GRINNING_CAT_WITH_SMILING_EYES 😸
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::cat_face::GRINNING_CAT_WITH_SMILING_EYES;
 		
 		// KISSING_CAT 😽
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::cat_face::KISSING_CAT;
 		
 		// POUTING_CAT 😾
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::cat_face::POUTING_CAT;
 		
 		// SMILING_CAT_WITH_HEART_EYES 😻
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::cat_face::SMILING_CAT_WITH_HEART_EYES;
 		
 		// WEARY_CAT 🙀
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::cat_face::WEARY_CAT;
 		
@@ -4484,150 +5651,187 @@
 	// begin emotion 💢💓🖤
 		
 		// ANGER_SYMBOL 💢
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::ANGER_SYMBOL;
 		
 		// BEATING_HEART 💓
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::BEATING_HEART;
 		
 		// BLACK_HEART 🖤
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::BLACK_HEART;
 		
 		// BLUE_HEART 💙
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::BLUE_HEART;
 		
 		// BOMB 💣
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::BOMB;
 		
 		// BROKEN_HEART 💔
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::BROKEN_HEART;
 		
 		// BROWN_HEART 🤎
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::BROWN_HEART;
 		
 		// COLLISION 💥
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::COLLISION;
 		
 		// DASHING_AWAY 💨
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::DASHING_AWAY;
 		
 		// DIZZY 💫
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::DIZZY;
 		
 		// EYE_IN_SPEECH_BUBBLE 👁️‍🗨️
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::EYE_IN_SPEECH_BUBBLE;
 		
 		// GREEN_HEART 💚
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::GREEN_HEART;
 		
 		// GROWING_HEART 💗
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::GROWING_HEART;
 		
 		// HEART_DECORATION 💟
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::HEART_DECORATION;
 		
 		// HEART_EXCLAMATION ❣️
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::HEART_EXCLAMATION;
 		
 		// HEART_ON_FIRE ❤️‍🔥
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::HEART_ON_FIRE;
 		
 		// HEART_WITH_ARROW 💘
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::HEART_WITH_ARROW;
 		
 		// HEART_WITH_RIBBON 💝
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::HEART_WITH_RIBBON;
 		
 		// HOLE 🕳️
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::HOLE;
 		
 		// HUNDRED_POINTS 💯
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::HUNDRED_POINTS;
 		
 		// KISS_MARK 💋
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::KISS_MARK;
 		
 		// LEFT_SPEECH_BUBBLE 🗨️
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::LEFT_SPEECH_BUBBLE;
 		
 		// LOVE_LETTER 💌
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::LOVE_LETTER;
 		
 		// MENDING_HEART ❤️‍🩹
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::MENDING_HEART;
 		
 		// ORANGE_HEART 🧡
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::ORANGE_HEART;
 		
 		// PURPLE_HEART 💜
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::PURPLE_HEART;
 		
 		// RED_HEART ❤️
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::RED_HEART;
 		
 		// REVOLVING_HEARTS 💞
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::REVOLVING_HEARTS;
 		
 		// RIGHT_ANGER_BUBBLE 🗯️
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::RIGHT_ANGER_BUBBLE;
 		
 		// SPARKLING_HEART 💖
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::SPARKLING_HEART;
 		
 		// SPEECH_BALLOON 💬
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::SPEECH_BALLOON;
 		
 		// SWEAT_DROPLETS 💦
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::SWEAT_DROPLETS;
 		
 		// THOUGHT_BALLOON 💭
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::THOUGHT_BALLOON;
 		
 		// TWO_HEARTS 💕
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::TWO_HEARTS;
 		
 		// WHITE_HEART 🤍
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::WHITE_HEART;
 		
 		// YELLOW_HEART 💛
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::YELLOW_HEART;
 		
 		// ZZZ 💤
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::emotion::ZZZ;
 		
@@ -4636,38 +5840,47 @@
 	// begin face_affection 😘😗😚
 		
 		// FACE_BLOWING_A_KISS 😘
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_affection::FACE_BLOWING_A_KISS;
 		
 		// KISSING_FACE 😗
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_affection::KISSING_FACE;
 		
 		// KISSING_FACE_WITH_CLOSED_EYES 😚
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_affection::KISSING_FACE_WITH_CLOSED_EYES;
 		
 		// KISSING_FACE_WITH_SMILING_EYES 😙
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_affection::KISSING_FACE_WITH_SMILING_EYES;
 		
 		// SMILING_FACE ☺️
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_affection::SMILING_FACE;
 		
 		// SMILING_FACE_WITH_HEARTS 🥰
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_affection::SMILING_FACE_WITH_HEARTS;
 		
 		// SMILING_FACE_WITH_HEART_EYES 😍
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_affection::SMILING_FACE_WITH_HEART_EYES;
 		
 		// SMILING_FACE_WITH_TEAR 🥲
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_affection::SMILING_FACE_WITH_TEAR;
 		
 		// STAR_STRUCK 🤩
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_affection::STAR_STRUCK;
 		
@@ -4676,98 +5889,132 @@
 	// begin face_concerned 😧😰😲
 		
 		// ANGUISHED_FACE 😧
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::ANGUISHED_FACE;
 		
 		// ANXIOUS_FACE_WITH_SWEAT 😰
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::ANXIOUS_FACE_WITH_SWEAT;
 		
 		// ASTONISHED_FACE 😲
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::ASTONISHED_FACE;
 		
 		// CONFOUNDED_FACE 😖
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::CONFOUNDED_FACE;
 		
 		// CONFUSED_FACE 😕
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::CONFUSED_FACE;
 		
 		// CRYING_FACE 😢
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::CRYING_FACE;
 		
 		// DISAPPOINTED_FACE 😞
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::DISAPPOINTED_FACE;
 		
 		// DOWNCAST_FACE_WITH_SWEAT 😓
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::DOWNCAST_FACE_WITH_SWEAT;
 		
+		// FACE_HOLDING_BACK_TEARS 🥹
+		#[cfg(emojic_group = "smileys_and_emotion")]
+		#[doc(inline)]
+		pub use crate::grouped::smileys_and_emotion::face_concerned::FACE_HOLDING_BACK_TEARS;
+		
 		// FACE_SCREAMING_IN_FEAR 😱
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::FACE_SCREAMING_IN_FEAR;
 		
+		// FACE_WITH_DIAGONAL_MOUTH 🫤
+		#[cfg(emojic_group = "smileys_and_emotion")]
+		#[doc(inline)]
+		pub use crate::grouped::smileys_and_emotion::face_concerned::FACE_WITH_DIAGONAL_MOUTH;
+		
 		// FACE_WITH_OPEN_MOUTH 😮
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::FACE_WITH_OPEN_MOUTH;
 		
 		// FEARFUL_FACE 😨
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::FEARFUL_FACE;
 		
 		// FLUSHED_FACE 😳
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::FLUSHED_FACE;
 		
 		// FROWNING_FACE ☹️
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::FROWNING_FACE;
 		
 		// FROWNING_FACE_WITH_OPEN_MOUTH 😦
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::FROWNING_FACE_WITH_OPEN_MOUTH;
 		
 		// HUSHED_FACE 😯
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::HUSHED_FACE;
 		
 		// LOUDLY_CRYING_FACE 😭
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::LOUDLY_CRYING_FACE;
 		
 		// PERSEVERING_FACE 😣
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::PERSEVERING_FACE;
 		
 		// PLEADING_FACE 🥺
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::PLEADING_FACE;
 		
 		// SAD_BUT_RELIEVED_FACE 😥
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::SAD_BUT_RELIEVED_FACE;
 		
 		// SLIGHTLY_FROWNING_FACE 🙁
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::SLIGHTLY_FROWNING_FACE;
 		
 		// TIRED_FACE 😫
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::TIRED_FACE;
 		
 		// WEARY_FACE 😩
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::WEARY_FACE;
 		
 		// WORRIED_FACE 😟
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::WORRIED_FACE;
 		
 		// YAWNING_FACE 🥱
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_concerned::YAWNING_FACE;
 		
@@ -4776,34 +6023,42 @@
 	// begin face_costume 👽👾🤡
 		
 		// ALIEN 👽
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_costume::ALIEN;
 		
 		// ALIEN_MONSTER 👾
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_costume::ALIEN_MONSTER;
 		
 		// CLOWN_FACE 🤡
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_costume::CLOWN_FACE;
 		
 		// GHOST 👻
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_costume::GHOST;
 		
 		// GOBLIN 👺
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_costume::GOBLIN;
 		
 		// OGRE 👹
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_costume::OGRE;
 		
 		// PILE_OF_POO 💩
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_costume::PILE_OF_POO;
 		
 		// ROBOT 🤖
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_costume::ROBOT;
 		
@@ -4812,34 +6067,56 @@
 	// begin face_glasses 🧐🤓😎
 		
 		// FACE_WITH_MONOCLE 🧐
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_glasses::FACE_WITH_MONOCLE;
 		
 		// NERD_FACE 🤓
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_glasses::NERD_FACE;
 		
 		// SMILING_FACE_WITH_SUNGLASSES 😎
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_glasses::SMILING_FACE_WITH_SUNGLASSES;
 		
 	// end face_glasses
 	
-	// begin face_hand 🤭🤗🤫
+	// begin face_hand 🤭🫢🫣
 		
 		// FACE_WITH_HAND_OVER_MOUTH 🤭
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_hand::FACE_WITH_HAND_OVER_MOUTH;
 		
+		// FACE_WITH_OPEN_EYES_AND_HAND_OVER_MOUTH 🫢
+		#[cfg(emojic_group = "smileys_and_emotion")]
+		#[doc(inline)]
+		pub use crate::grouped::smileys_and_emotion::face_hand::FACE_WITH_OPEN_EYES_AND_HAND_OVER_MOUTH;
+		
+		// FACE_WITH_PEEKING_EYE 🫣
+		#[cfg(emojic_group = "smileys_and_emotion")]
+		#[doc(inline)]
+		pub use crate::grouped::smileys_and_emotion::face_hand::FACE_WITH_PEEKING_EYE;
+		
 		// HUGGING_FACE 🤗
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_hand::HUGGING_FACE;
 		
+		// SALUTING_FACE 🫡
+		#[cfg(emojic_group = "smileys_and_emotion")]
+		#[doc(inline)]
+		pub use crate::grouped::smileys_and_emotion::face_hand::SALUTING_FACE;
+		
 		// SHUSHING_FACE 🤫
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_hand::SHUSHING_FACE;
 		
 		// THINKING_FACE 🤔
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_hand::THINKING_FACE;
 		
@@ -4848,14 +6125,17 @@
 	// begin face_hat 🤠🥸🥳
 		
 		// COWBOY_HAT_FACE 🤠
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_hat::COWBOY_HAT_FACE;
 		
 		// DISGUISED_FACE 🥸
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_hat::DISGUISED_FACE;
 		
 		// PARTYING_FACE 🥳
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_hat::PARTYING_FACE;
 		
@@ -4864,86 +6144,111 @@
 	// begin face_negative 😠👿😤
 		
 		// ANGRY_FACE 😠
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_negative::ANGRY_FACE;
 		
 		// ANGRY_FACE_WITH_HORNS 👿
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_negative::ANGRY_FACE_WITH_HORNS;
 		
 		// FACE_WITH_STEAM_FROM_NOSE 😤
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_negative::FACE_WITH_STEAM_FROM_NOSE;
 		
 		// FACE_WITH_SYMBOLS_ON_MOUTH 🤬
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_negative::FACE_WITH_SYMBOLS_ON_MOUTH;
 		
 		// POUTING_FACE 😡
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_negative::POUTING_FACE;
 		
 		// SKULL 💀
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_negative::SKULL;
 		
 		// SKULL_AND_CROSSBONES ☠️
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_negative::SKULL_AND_CROSSBONES;
 		
 		// SMILING_FACE_WITH_HORNS 😈
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_negative::SMILING_FACE_WITH_HORNS;
 		
 	// end face_negative
 	
-	// begin face_neutral_skeptical 😑😮‍💨😶‍🌫️
+	// begin face_neutral_skeptical 🫥😑😮‍💨
+		
+		// DOTTED_LINE_FACE 🫥
+		#[cfg(emojic_group = "smileys_and_emotion")]
+		#[doc(inline)]
+		pub use crate::grouped::smileys_and_emotion::face_neutral_skeptical::DOTTED_LINE_FACE;
 		
 		// EXPRESSIONLESS_FACE 😑
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_neutral_skeptical::EXPRESSIONLESS_FACE;
 		
 		// FACE_EXHALING 😮‍💨
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_neutral_skeptical::FACE_EXHALING;
 		
 		// FACE_IN_CLOUDS 😶‍🌫️
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_neutral_skeptical::FACE_IN_CLOUDS;
 		
 		// FACE_WITHOUT_MOUTH 😶
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_neutral_skeptical::FACE_WITHOUT_MOUTH;
 		
 		// FACE_WITH_RAISED_EYEBROW 🤨
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_neutral_skeptical::FACE_WITH_RAISED_EYEBROW;
 		
 		// FACE_WITH_ROLLING_EYES 🙄
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_neutral_skeptical::FACE_WITH_ROLLING_EYES;
 		
 		// GRIMACING_FACE 😬
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_neutral_skeptical::GRIMACING_FACE;
 		
 		// LYING_FACE 🤥
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_neutral_skeptical::LYING_FACE;
 		
 		// NEUTRAL_FACE 😐
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_neutral_skeptical::NEUTRAL_FACE;
 		
 		// SMIRKING_FACE 😏
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_neutral_skeptical::SMIRKING_FACE;
 		
 		// UNAMUSED_FACE 😒
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_neutral_skeptical::UNAMUSED_FACE;
 		
 		// ZIPPER_MOUTH_FACE 🤐
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_neutral_skeptical::ZIPPER_MOUTH_FACE;
 		
@@ -4952,22 +6257,27 @@
 	// begin face_sleepy 🤤😔😌
 		
 		// DROOLING_FACE 🤤
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_sleepy::DROOLING_FACE;
 		
 		// PENSIVE_FACE 😔
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_sleepy::PENSIVE_FACE;
 		
 		// RELIEVED_FACE 😌
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_sleepy::RELIEVED_FACE;
 		
 		// SLEEPING_FACE 😴
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_sleepy::SLEEPING_FACE;
 		
 		// SLEEPY_FACE 😪
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_sleepy::SLEEPY_FACE;
 		
@@ -4976,54 +6286,72 @@
 	// begin face_smiling 😁😂😀
 		
 		// BEAMING_FACE_WITH_SMILING_EYES 😁
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_smiling::BEAMING_FACE_WITH_SMILING_EYES;
 		
 		// FACE_WITH_TEARS_OF_JOY 😂
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_smiling::FACE_WITH_TEARS_OF_JOY;
 		
 		// GRINNING_FACE 😀
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_smiling::GRINNING_FACE;
 		
 		// GRINNING_FACE_WITH_BIG_EYES 😃
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_smiling::GRINNING_FACE_WITH_BIG_EYES;
 		
 		// GRINNING_FACE_WITH_SMILING_EYES 😄
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_smiling::GRINNING_FACE_WITH_SMILING_EYES;
 		
 		// GRINNING_FACE_WITH_SWEAT 😅
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_smiling::GRINNING_FACE_WITH_SWEAT;
 		
 		// GRINNING_SQUINTING_FACE 😆
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_smiling::GRINNING_SQUINTING_FACE;
 		
+		// MELTING_FACE 🫠
+		#[cfg(emojic_group = "smileys_and_emotion")]
+		#[doc(inline)]
+		pub use crate::grouped::smileys_and_emotion::face_smiling::MELTING_FACE;
+		
 		// ROLLING_ON_THE_FLOOR_LAUGHING 🤣
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_smiling::ROLLING_ON_THE_FLOOR_LAUGHING;
 		
 		// SLIGHTLY_SMILING_FACE 🙂
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_smiling::SLIGHTLY_SMILING_FACE;
 		
 		// SMILING_FACE_WITH_HALO 😇
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_smiling::SMILING_FACE_WITH_HALO;
 		
 		// SMILING_FACE_WITH_SMILING_EYES 😊
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_smiling::SMILING_FACE_WITH_SMILING_EYES;
 		
 		// UPSIDE_DOWN_FACE 🙃
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_smiling::UPSIDE_DOWN_FACE;
 		
 		// WINKING_FACE 😉
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_smiling::WINKING_FACE;
 		
@@ -5032,26 +6360,32 @@
 	// begin face_tongue 😋😛🤑
 		
 		// FACE_SAVORING_FOOD 😋
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_tongue::FACE_SAVORING_FOOD;
 		
 		// FACE_WITH_TONGUE 😛
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_tongue::FACE_WITH_TONGUE;
 		
 		// MONEY_MOUTH_FACE 🤑
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_tongue::MONEY_MOUTH_FACE;
 		
 		// SQUINTING_FACE_WITH_TONGUE 😝
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_tongue::SQUINTING_FACE_WITH_TONGUE;
 		
 		// WINKING_FACE_WITH_TONGUE 😜
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_tongue::WINKING_FACE_WITH_TONGUE;
 		
 		// ZANY_FACE 🤪
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_tongue::ZANY_FACE;
 		
@@ -5060,50 +6394,62 @@
 	// begin face_unwell 🥶🤯🤮
 		
 		// COLD_FACE 🥶
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_unwell::COLD_FACE;
 		
 		// EXPLODING_HEAD 🤯
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_unwell::EXPLODING_HEAD;
 		
 		// FACE_VOMITING 🤮
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_unwell::FACE_VOMITING;
 		
 		// FACE_WITH_HEAD_BANDAGE 🤕
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_unwell::FACE_WITH_HEAD_BANDAGE;
 		
 		// FACE_WITH_MEDICAL_MASK 😷
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_unwell::FACE_WITH_MEDICAL_MASK;
 		
 		// FACE_WITH_SPIRAL_EYES 😵‍💫
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_unwell::FACE_WITH_SPIRAL_EYES;
 		
 		// FACE_WITH_THERMOMETER 🤒
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_unwell::FACE_WITH_THERMOMETER;
 		
 		// HOT_FACE 🥵
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_unwell::HOT_FACE;
 		
 		// KNOCKED_OUT_FACE 😵
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_unwell::KNOCKED_OUT_FACE;
 		
 		// NAUSEATED_FACE 🤢
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_unwell::NAUSEATED_FACE;
 		
 		// SNEEZING_FACE 🤧
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_unwell::SNEEZING_FACE;
 		
 		// WOOZY_FACE 🥴
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::face_unwell::WOOZY_FACE;
 		
@@ -5112,14 +6458,17 @@
 	// begin monkey_face 🙉🙈🙊
 		
 		// HEAR_NO_EVIL_MONKEY 🙉
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::monkey_face::HEAR_NO_EVIL_MONKEY;
 		
 		// SEE_NO_EVIL_MONKEY 🙈
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::monkey_face::SEE_NO_EVIL_MONKEY;
 		
 		// SPEAK_NO_EVIL_MONKEY 🙊
+		#[cfg(emojic_group = "smileys_and_emotion")]
 		#[doc(inline)]
 		pub use crate::grouped::smileys_and_emotion::monkey_face::SPEAK_NO_EVIL_MONKEY;
 		
@@ -5132,158 +6481,197 @@
 	// begin alphanum 🆎🅰️🅱️
 		
 		// AB_BUTTON_BLOOD_TYPE 🆎
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::AB_BUTTON_BLOOD_TYPE;
 		
 		// A_BUTTON_BLOOD_TYPE 🅰️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::A_BUTTON_BLOOD_TYPE;
 		
 		// B_BUTTON_BLOOD_TYPE 🅱️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::B_BUTTON_BLOOD_TYPE;
 		
 		// CIRCLED_M Ⓜ️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::CIRCLED_M;
 		
 		// CL_BUTTON 🆑
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::CL_BUTTON;
 		
 		// COOL_BUTTON 🆒
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::COOL_BUTTON;
 		
 		// FREE_BUTTON 🆓
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::FREE_BUTTON;
 		
 		// ID_BUTTON 🆔
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::ID_BUTTON;
 		
 		// INFORMATION ℹ️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::INFORMATION;
 		
 		// INPUT_LATIN_LETTERS 🔤
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::INPUT_LATIN_LETTERS;
 		
 		// INPUT_LATIN_LOWERCASE 🔡
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::INPUT_LATIN_LOWERCASE;
 		
 		// INPUT_LATIN_UPPERCASE 🔠
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::INPUT_LATIN_UPPERCASE;
 		
 		// INPUT_NUMBERS 🔢
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::INPUT_NUMBERS;
 		
 		// INPUT_SYMBOLS 🔣
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::INPUT_SYMBOLS;
 		
 		// JAPANESE_ACCEPTABLE_BUTTON 🉑
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_ACCEPTABLE_BUTTON;
 		
 		// JAPANESE_APPLICATION_BUTTON 🈸
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_APPLICATION_BUTTON;
 		
 		// JAPANESE_BARGAIN_BUTTON 🉐
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_BARGAIN_BUTTON;
 		
 		// JAPANESE_CONGRATULATIONS_BUTTON ㊗️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_CONGRATULATIONS_BUTTON;
 		
 		// JAPANESE_DISCOUNT_BUTTON 🈹
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_DISCOUNT_BUTTON;
 		
 		// JAPANESE_FREE_OF_CHARGE_BUTTON 🈚
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_FREE_OF_CHARGE_BUTTON;
 		
 		// JAPANESE_HERE_BUTTON 🈁
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_HERE_BUTTON;
 		
 		// JAPANESE_MONTHLY_AMOUNT_BUTTON 🈷️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_MONTHLY_AMOUNT_BUTTON;
 		
 		// JAPANESE_NOT_FREE_OF_CHARGE_BUTTON 🈶
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_NOT_FREE_OF_CHARGE_BUTTON;
 		
 		// JAPANESE_NO_VACANCY_BUTTON 🈵
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_NO_VACANCY_BUTTON;
 		
 		// JAPANESE_OPEN_FOR_BUSINESS_BUTTON 🈺
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_OPEN_FOR_BUSINESS_BUTTON;
 		
 		// JAPANESE_PASSING_GRADE_BUTTON 🈴
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_PASSING_GRADE_BUTTON;
 		
 		// JAPANESE_PROHIBITED_BUTTON 🈲
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_PROHIBITED_BUTTON;
 		
 		// JAPANESE_RESERVED_BUTTON 🈯
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_RESERVED_BUTTON;
 		
 		// JAPANESE_SECRET_BUTTON ㊙️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_SECRET_BUTTON;
 		
 		// JAPANESE_SERVICE_CHARGE_BUTTON 🈂️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_SERVICE_CHARGE_BUTTON;
 		
 		// JAPANESE_VACANCY_BUTTON 🈳
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::JAPANESE_VACANCY_BUTTON;
 		
 		// NEW_BUTTON 🆕
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::NEW_BUTTON;
 		
 		// NG_BUTTON 🆖
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::NG_BUTTON;
 		
 		// OK_BUTTON 🆗
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::OK_BUTTON;
 		
 		// O_BUTTON_BLOOD_TYPE 🅾️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::O_BUTTON_BLOOD_TYPE;
 		
 		// P_BUTTON 🅿️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::P_BUTTON;
 		
 		// SOS_BUTTON 🆘
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::SOS_BUTTON;
 		
 		// UP_BUTTON 🆙
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::UP_BUTTON;
 		
 		// VS_BUTTON 🆚
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::alphanum::VS_BUTTON;
 		
@@ -5292,86 +6680,107 @@
 	// begin arrow 🔙🔃🔄
 		
 		// BACK_ARROW 🔙
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::BACK_ARROW;
 		
 		// CLOCKWISE_VERTICAL_ARROWS 🔃
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::CLOCKWISE_VERTICAL_ARROWS;
 		
 		// COUNTERCLOCKWISE_ARROWS_BUTTON 🔄
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::COUNTERCLOCKWISE_ARROWS_BUTTON;
 		
 		// DOWN_ARROW ⬇️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::DOWN_ARROW;
 		
 		// DOWN_LEFT_ARROW ↙️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::DOWN_LEFT_ARROW;
 		
 		// DOWN_RIGHT_ARROW ↘️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::DOWN_RIGHT_ARROW;
 		
 		// END_ARROW 🔚
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::END_ARROW;
 		
 		// LEFT_ARROW ⬅️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::LEFT_ARROW;
 		
 		// LEFT_ARROW_CURVING_RIGHT ↪️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::LEFT_ARROW_CURVING_RIGHT;
 		
 		// LEFT_RIGHT_ARROW ↔️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::LEFT_RIGHT_ARROW;
 		
 		// ON_ARROW 🔛
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::ON_ARROW;
 		
 		// RIGHT_ARROW ➡️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::RIGHT_ARROW;
 		
 		// RIGHT_ARROW_CURVING_DOWN ⤵️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::RIGHT_ARROW_CURVING_DOWN;
 		
 		// RIGHT_ARROW_CURVING_LEFT ↩️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::RIGHT_ARROW_CURVING_LEFT;
 		
 		// RIGHT_ARROW_CURVING_UP ⤴️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::RIGHT_ARROW_CURVING_UP;
 		
 		// SOON_ARROW 🔜
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::SOON_ARROW;
 		
 		// TOP_ARROW 🔝
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::TOP_ARROW;
 		
 		// UP_ARROW ⬆️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::UP_ARROW;
 		
 		// UP_DOWN_ARROW ↕️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::UP_DOWN_ARROW;
 		
 		// UP_LEFT_ARROW ↖️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::UP_LEFT_ARROW;
 		
 		// UP_RIGHT_ARROW ↗️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::arrow::UP_RIGHT_ARROW;
 		
@@ -5380,98 +6789,122 @@
 	// begin av_symbol 📶🔆🎦
 		
 		// ANTENNA_BARS 📶
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::ANTENNA_BARS;
 		
 		// BRIGHT_BUTTON 🔆
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::BRIGHT_BUTTON;
 		
 		// CINEMA 🎦
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::CINEMA;
 		
 		// DIM_BUTTON 🔅
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::DIM_BUTTON;
 		
 		// DOWNWARDS_BUTTON 🔽
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::DOWNWARDS_BUTTON;
 		
 		// EJECT_BUTTON ⏏️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::EJECT_BUTTON;
 		
 		// FAST_DOWN_BUTTON ⏬
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::FAST_DOWN_BUTTON;
 		
 		// FAST_FORWARD_BUTTON ⏩
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::FAST_FORWARD_BUTTON;
 		
 		// FAST_REVERSE_BUTTON ⏪
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::FAST_REVERSE_BUTTON;
 		
 		// FAST_UP_BUTTON ⏫
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::FAST_UP_BUTTON;
 		
 		// LAST_TRACK_BUTTON ⏮️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::LAST_TRACK_BUTTON;
 		
 		// MOBILE_PHONE_OFF 📴
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::MOBILE_PHONE_OFF;
 		
 		// NEXT_TRACK_BUTTON ⏭️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::NEXT_TRACK_BUTTON;
 		
 		// PAUSE_BUTTON ⏸️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::PAUSE_BUTTON;
 		
 		// PLAY_BUTTON ▶️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::PLAY_BUTTON;
 		
 		// PLAY_OR_PAUSE_BUTTON ⏯️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::PLAY_OR_PAUSE_BUTTON;
 		
 		// RECORD_BUTTON ⏺️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::RECORD_BUTTON;
 		
 		// REPEAT_BUTTON 🔁
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::REPEAT_BUTTON;
 		
 		// REPEAT_SINGLE_BUTTON 🔂
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::REPEAT_SINGLE_BUTTON;
 		
 		// REVERSE_BUTTON ◀️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::REVERSE_BUTTON;
 		
 		// SHUFFLE_TRACKS_BUTTON 🔀
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::SHUFFLE_TRACKS_BUTTON;
 		
 		// STOP_BUTTON ⏹️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::STOP_BUTTON;
 		
 		// UPWARDS_BUTTON 🔼
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::UPWARDS_BUTTON;
 		
 		// VIBRATION_MODE 📳
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::av_symbol::VIBRATION_MODE;
 		
@@ -5480,10 +6913,12 @@
 	// begin currency 💱💲
 		
 		// CURRENCY_EXCHANGE 💱
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::currency::CURRENCY_EXCHANGE;
 		
 		// HEAVY_DOLLAR_SIGN 💲
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::currency::HEAVY_DOLLAR_SIGN;
 		
@@ -5492,14 +6927,17 @@
 	// begin gender ♀️♂️⚧️
 		
 		// FEMALE_SIGN ♀️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::gender::FEMALE_SIGN;
 		
 		// MALE_SIGN ♂️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::gender::MALE_SIGN;
 		
 		// TRANSGENDER_SYMBOL ⚧️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::gender::TRANSGENDER_SYMBOL;
 		
@@ -5508,138 +6946,172 @@
 	// begin geometric ⚫⬛◾
 		
 		// BLACK_CIRCLE ⚫
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::BLACK_CIRCLE;
 		
 		// BLACK_LARGE_SQUARE ⬛
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::BLACK_LARGE_SQUARE;
 		
 		// BLACK_MEDIUM_SMALL_SQUARE ◾
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::BLACK_MEDIUM_SMALL_SQUARE;
 		
 		// BLACK_MEDIUM_SQUARE ◼️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::BLACK_MEDIUM_SQUARE;
 		
 		// BLACK_SMALL_SQUARE ▪️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::BLACK_SMALL_SQUARE;
 		
 		// BLACK_SQUARE_BUTTON 🔲
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::BLACK_SQUARE_BUTTON;
 		
 		// BLUE_CIRCLE 🔵
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::BLUE_CIRCLE;
 		
 		// BLUE_SQUARE 🟦
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::BLUE_SQUARE;
 		
 		// BROWN_CIRCLE 🟤
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::BROWN_CIRCLE;
 		
 		// BROWN_SQUARE 🟫
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::BROWN_SQUARE;
 		
 		// DIAMOND_WITH_A_DOT 💠
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::DIAMOND_WITH_A_DOT;
 		
 		// GREEN_CIRCLE 🟢
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::GREEN_CIRCLE;
 		
 		// GREEN_SQUARE 🟩
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::GREEN_SQUARE;
 		
 		// LARGE_BLUE_DIAMOND 🔷
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::LARGE_BLUE_DIAMOND;
 		
 		// LARGE_ORANGE_DIAMOND 🔶
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::LARGE_ORANGE_DIAMOND;
 		
 		// ORANGE_CIRCLE 🟠
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::ORANGE_CIRCLE;
 		
 		// ORANGE_SQUARE 🟧
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::ORANGE_SQUARE;
 		
 		// PURPLE_CIRCLE 🟣
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::PURPLE_CIRCLE;
 		
 		// PURPLE_SQUARE 🟪
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::PURPLE_SQUARE;
 		
 		// RADIO_BUTTON 🔘
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::RADIO_BUTTON;
 		
 		// RED_CIRCLE 🔴
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::RED_CIRCLE;
 		
 		// RED_SQUARE 🟥
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::RED_SQUARE;
 		
 		// RED_TRIANGLE_POINTED_DOWN 🔻
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::RED_TRIANGLE_POINTED_DOWN;
 		
 		// RED_TRIANGLE_POINTED_UP 🔺
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::RED_TRIANGLE_POINTED_UP;
 		
 		// SMALL_BLUE_DIAMOND 🔹
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::SMALL_BLUE_DIAMOND;
 		
 		// SMALL_ORANGE_DIAMOND 🔸
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::SMALL_ORANGE_DIAMOND;
 		
 		// WHITE_CIRCLE ⚪
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::WHITE_CIRCLE;
 		
 		// WHITE_LARGE_SQUARE ⬜
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::WHITE_LARGE_SQUARE;
 		
 		// WHITE_MEDIUM_SMALL_SQUARE ◽
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::WHITE_MEDIUM_SMALL_SQUARE;
 		
 		// WHITE_MEDIUM_SQUARE ◻️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::WHITE_MEDIUM_SQUARE;
 		
 		// WHITE_SMALL_SQUARE ▫️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::WHITE_SMALL_SQUARE;
 		
 		// WHITE_SQUARE_BUTTON 🔳
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::WHITE_SQUARE_BUTTON;
 		
 		// YELLOW_CIRCLE 🟡
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::YELLOW_CIRCLE;
 		
 		// YELLOW_SQUARE 🟨
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::geometric::YELLOW_SQUARE;
 		
@@ -5648,78 +7120,101 @@
 	// begin keycap 0️⃣1️⃣🔟
 		
 		// KEYCAP_0 0️⃣
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::keycap::KEYCAP_0;
 		
 		// KEYCAP_1 1️⃣
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::keycap::KEYCAP_1;
 		
 		// KEYCAP_10 🔟
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::keycap::KEYCAP_10;
 		
 		// KEYCAP_2 2️⃣
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::keycap::KEYCAP_2;
 		
 		// KEYCAP_3 3️⃣
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::keycap::KEYCAP_3;
 		
 		// KEYCAP_4 4️⃣
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::keycap::KEYCAP_4;
 		
 		// KEYCAP_5 5️⃣
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::keycap::KEYCAP_5;
 		
 		// KEYCAP_6 6️⃣
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::keycap::KEYCAP_6;
 		
 		// KEYCAP_7 7️⃣
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::keycap::KEYCAP_7;
 		
 		// KEYCAP_8 8️⃣
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::keycap::KEYCAP_8;
 		
 		// KEYCAP_9 9️⃣
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::keycap::KEYCAP_9;
 		
 		// KEYCAP_ASTERISK *️⃣
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::keycap::KEYCAP_ASTERISK;
 		
 		// KEYCAP_HASH #️⃣
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::keycap::KEYCAP_HASH;
 		
 	// end keycap
 	
-	// begin math ➗♾️➖
+	// begin math ➗🟰♾️
 		
 		// DIVIDE ➗
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::math::DIVIDE;
 		
+		// HEAVY_EQUALS_SIGN 🟰
+		#[cfg(emojic_group = "symbols")]
+		#[doc(inline)]
+		pub use crate::grouped::symbols::math::HEAVY_EQUALS_SIGN;
+		
 		// INFINITY ♾️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::math::INFINITY;
 		
 		// MINUS ➖
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::math::MINUS;
 		
 		// MULTIPLY ✖️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::math::MULTIPLY;
 		
 		// PLUS ➕
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::math::PLUS;
 		
@@ -5728,86 +7223,107 @@
 	// begin other_symbol ☑️✔️✅
 		
 		// CHECK_BOX_WITH_CHECK ☑️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::CHECK_BOX_WITH_CHECK;
 		
 		// CHECK_MARK ✔️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::CHECK_MARK;
 		
 		// CHECK_MARK_BUTTON ✅
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::CHECK_MARK_BUTTON;
 		
 		// COPYRIGHT ©️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::COPYRIGHT;
 		
 		// CROSS_MARK ❌
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::CROSS_MARK;
 		
 		// CROSS_MARK_BUTTON ❎
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::CROSS_MARK_BUTTON;
 		
 		// CURLY_LOOP ➰
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::CURLY_LOOP;
 		
 		// DOUBLE_CURLY_LOOP ➿
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::DOUBLE_CURLY_LOOP;
 		
 		// EIGHT_POINTED_STAR ✴️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::EIGHT_POINTED_STAR;
 		
 		// EIGHT_SPOKED_ASTERISK ✳️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::EIGHT_SPOKED_ASTERISK;
 		
 		// FLEUR_DE_LIS ⚜️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::FLEUR_DE_LIS;
 		
 		// HOLLOW_RED_CIRCLE ⭕
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::HOLLOW_RED_CIRCLE;
 		
 		// JAPANESE_SYMBOL_FOR_BEGINNER 🔰
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::JAPANESE_SYMBOL_FOR_BEGINNER;
 		
 		// MEDICAL_SYMBOL ⚕️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::MEDICAL_SYMBOL;
 		
 		// NAME_BADGE 📛
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::NAME_BADGE;
 		
 		// PART_ALTERNATION_MARK 〽️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::PART_ALTERNATION_MARK;
 		
 		// RECYCLING_SYMBOL ♻️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::RECYCLING_SYMBOL;
 		
 		// REGISTERED ®️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::REGISTERED;
 		
 		// SPARKLE ❇️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::SPARKLE;
 		
 		// TRADE_MARK ™️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::TRADE_MARK;
 		
 		// TRIDENT_EMBLEM 🔱
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::other_symbol::TRIDENT_EMBLEM;
 		
@@ -5816,30 +7332,37 @@
 	// begin punctuation ‼️⁉️❗
 		
 		// DOUBLE_EXCLAMATION_MARK ‼️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::punctuation::DOUBLE_EXCLAMATION_MARK;
 		
 		// EXCLAMATION_QUESTION_MARK ⁉️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::punctuation::EXCLAMATION_QUESTION_MARK;
 		
 		// RED_EXCLAMATION_MARK ❗
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::punctuation::RED_EXCLAMATION_MARK;
 		
 		// RED_QUESTION_MARK ❓
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::punctuation::RED_QUESTION_MARK;
 		
 		// WAVY_DASH 〰️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::punctuation::WAVY_DASH;
 		
 		// WHITE_EXCLAMATION_MARK ❕
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::punctuation::WHITE_EXCLAMATION_MARK;
 		
 		// WHITE_QUESTION_MARK ❔
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::punctuation::WHITE_QUESTION_MARK;
 		
@@ -5848,50 +7371,62 @@
 	// begin religion ⚛️🔯✝️
 		
 		// ATOM_SYMBOL ⚛️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::religion::ATOM_SYMBOL;
 		
 		// DOTTED_SIX_POINTED_STAR 🔯
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::religion::DOTTED_SIX_POINTED_STAR;
 		
 		// LATIN_CROSS ✝️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::religion::LATIN_CROSS;
 		
 		// MENORAH 🕎
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::religion::MENORAH;
 		
 		// OM 🕉️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::religion::OM;
 		
 		// ORTHODOX_CROSS ☦️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::religion::ORTHODOX_CROSS;
 		
 		// PEACE_SYMBOL ☮️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::religion::PEACE_SYMBOL;
 		
 		// PLACE_OF_WORSHIP 🛐
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::religion::PLACE_OF_WORSHIP;
 		
 		// STAR_AND_CRESCENT ☪️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::religion::STAR_AND_CRESCENT;
 		
 		// STAR_OF_DAVID ✡️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::religion::STAR_OF_DAVID;
 		
 		// WHEEL_OF_DHARMA ☸️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::religion::WHEEL_OF_DHARMA;
 		
 		// YIN_YANG ☯️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::religion::YIN_YANG;
 		
@@ -5900,54 +7435,67 @@
 	// begin transport_sign 🏧🚼🛄
 		
 		// ATM_SIGN 🏧
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::transport_sign::ATM_SIGN;
 		
 		// BABY_SYMBOL 🚼
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::transport_sign::BABY_SYMBOL;
 		
 		// BAGGAGE_CLAIM 🛄
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::transport_sign::BAGGAGE_CLAIM;
 		
 		// CUSTOMS 🛃
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::transport_sign::CUSTOMS;
 		
 		// LEFT_LUGGAGE 🛅
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::transport_sign::LEFT_LUGGAGE;
 		
 		// LITTER_IN_BIN_SIGN 🚮
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::transport_sign::LITTER_IN_BIN_SIGN;
 		
 		// MEN_S_ROOM 🚹
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::transport_sign::MEN_S_ROOM;
 		
 		// PASSPORT_CONTROL 🛂
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::transport_sign::PASSPORT_CONTROL;
 		
 		// POTABLE_WATER 🚰
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::transport_sign::POTABLE_WATER;
 		
 		// RESTROOM 🚻
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::transport_sign::RESTROOM;
 		
 		// WATER_CLOSET 🚾
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::transport_sign::WATER_CLOSET;
 		
 		// WHEELCHAIR_SYMBOL ♿
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::transport_sign::WHEELCHAIR_SYMBOL;
 		
 		// WOMEN_S_ROOM 🚺
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::transport_sign::WOMEN_S_ROOM;
 		
@@ -5956,54 +7504,67 @@
 	// begin warning ☣️🚸🚱
 		
 		// BIOHAZARD ☣️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::warning::BIOHAZARD;
 		
 		// CHILDREN_CROSSING 🚸
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::warning::CHILDREN_CROSSING;
 		
 		// NON_POTABLE_WATER 🚱
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::warning::NON_POTABLE_WATER;
 		
 		// NO_BICYCLES 🚳
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::warning::NO_BICYCLES;
 		
 		// NO_ENTRY ⛔
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::warning::NO_ENTRY;
 		
 		// NO_LITTERING 🚯
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::warning::NO_LITTERING;
 		
 		// NO_MOBILE_PHONES 📵
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::warning::NO_MOBILE_PHONES;
 		
 		// NO_ONE_UNDER_EIGHTEEN 🔞
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::warning::NO_ONE_UNDER_EIGHTEEN;
 		
 		// NO_PEDESTRIANS 🚷
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::warning::NO_PEDESTRIANS;
 		
 		// NO_SMOKING 🚭
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::warning::NO_SMOKING;
 		
 		// PROHIBITED 🚫
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::warning::PROHIBITED;
 		
 		// RADIOACTIVE ☢️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::warning::RADIOACTIVE;
 		
 		// WARNING ⚠️
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::warning::WARNING;
 		
@@ -6012,54 +7573,67 @@
 	// begin zodiac ♒♈♋
 		
 		// AQUARIUS ♒
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::zodiac::AQUARIUS;
 		
 		// ARIES ♈
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::zodiac::ARIES;
 		
 		// CANCER ♋
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::zodiac::CANCER;
 		
 		// CAPRICORN ♑
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::zodiac::CAPRICORN;
 		
 		// GEMINI ♊
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::zodiac::GEMINI;
 		
 		// LEO ♌
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::zodiac::LEO;
 		
 		// LIBRA ♎
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::zodiac::LIBRA;
 		
 		// OPHIUCHUS ⛎
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::zodiac::OPHIUCHUS;
 		
 		// PISCES ♓
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::zodiac::PISCES;
 		
 		// SAGITTARIUS ♐
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::zodiac::SAGITTARIUS;
 		
 		// SCORPIO ♏
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::zodiac::SCORPIO;
 		
 		// TAURUS ♉
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::zodiac::TAURUS;
 		
 		// VIRGO ♍
+		#[cfg(emojic_group = "symbols")]
 		#[doc(inline)]
 		pub use crate::grouped::symbols::zodiac::VIRGO;
 		
@@ -6072,10 +7646,12 @@
 	// begin hotel 🛎️🧳
 		
 		// BELLHOP_BELL 🛎️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::hotel::BELLHOP_BELL;
 		
 		// LUGGAGE 🧳
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::hotel::LUGGAGE;
 		
@@ -6084,110 +7660,137 @@
 	// begin place_building 🏦🧱🏗️
 		
 		// BANK 🏦
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::BANK;
 		
 		// BRICK 🧱
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::BRICK;
 		
 		// BUILDING_CONSTRUCTION 🏗️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::BUILDING_CONSTRUCTION;
 		
 		// CASTLE 🏰
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::CASTLE;
 		
 		// CLASSICAL_BUILDING 🏛️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::CLASSICAL_BUILDING;
 		
 		// CONVENIENCE_STORE 🏪
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::CONVENIENCE_STORE;
 		
 		// DEPARTMENT_STORE 🏬
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::DEPARTMENT_STORE;
 		
 		// DERELICT_HOUSE 🏚️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::DERELICT_HOUSE;
 		
 		// FACTORY 🏭
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::FACTORY;
 		
 		// HOSPITAL 🏥
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::HOSPITAL;
 		
 		// HOTEL 🏨
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::HOTEL;
 		
 		// HOUSE 🏠
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::HOUSE;
 		
 		// HOUSES 🏘️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::HOUSES;
 		
 		// HOUSE_WITH_GARDEN 🏡
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::HOUSE_WITH_GARDEN;
 		
 		// HUT 🛖
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::HUT;
 		
 		// JAPANESE_CASTLE 🏯
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::JAPANESE_CASTLE;
 		
 		// JAPANESE_POST_OFFICE 🏣
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::JAPANESE_POST_OFFICE;
 		
 		// LOVE_HOTEL 🏩
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::LOVE_HOTEL;
 		
 		// OFFICE_BUILDING 🏢
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::OFFICE_BUILDING;
 		
 		// POST_OFFICE 🏤
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::POST_OFFICE;
 		
 		// ROCK 🪨
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::ROCK;
 		
 		// SCHOOL 🏫
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::SCHOOL;
 		
 		// STADIUM 🏟️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::STADIUM;
 		
 		// STATUE_OF_LIBERTY 🗽
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::STATUE_OF_LIBERTY;
 		
 		// TOKYO_TOWER 🗼
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::TOKYO_TOWER;
 		
 		// WEDDING 💒
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::WEDDING;
 		
 		// WOOD 🪵
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_building::WOOD;
 		
@@ -6196,38 +7799,47 @@
 	// begin place_geographic 🏖️🏕️🏜️
 		
 		// BEACH_WITH_UMBRELLA 🏖️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_geographic::BEACH_WITH_UMBRELLA;
 		
 		// CAMPING 🏕️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_geographic::CAMPING;
 		
 		// DESERT 🏜️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_geographic::DESERT;
 		
 		// DESERT_ISLAND 🏝️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_geographic::DESERT_ISLAND;
 		
 		// MOUNTAIN ⛰️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_geographic::MOUNTAIN;
 		
 		// MOUNT_FUJI 🗻
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_geographic::MOUNT_FUJI;
 		
 		// NATIONAL_PARK 🏞️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_geographic::NATIONAL_PARK;
 		
 		// SNOW_CAPPED_MOUNTAIN 🏔️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_geographic::SNOW_CAPPED_MOUNTAIN;
 		
 		// VOLCANO 🌋
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_geographic::VOLCANO;
 		
@@ -6236,30 +7848,37 @@
 	// begin place_map 🧭🌎🌏
 		
 		// COMPASS 🧭
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_map::COMPASS;
 		
 		// GLOBE_SHOWING_AMERICAS 🌎
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_map::GLOBE_SHOWING_AMERICAS;
 		
 		// GLOBE_SHOWING_ASIA_AUSTRALIA 🌏
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_map::GLOBE_SHOWING_ASIA_AUSTRALIA;
 		
 		// GLOBE_SHOWING_EUROPE_AFRICA 🌍
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_map::GLOBE_SHOWING_EUROPE_AFRICA;
 		
 		// GLOBE_WITH_MERIDIANS 🌐
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_map::GLOBE_WITH_MERIDIANS;
 		
 		// MAP_OF_JAPAN 🗾
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_map::MAP_OF_JAPAN;
 		
 		// WORLD_MAP 🗺️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_map::WORLD_MAP;
 		
@@ -6268,66 +7887,87 @@
 	// begin place_other 💈🌉🎠
 		
 		// BARBER_POLE 💈
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::BARBER_POLE;
 		
 		// BRIDGE_AT_NIGHT 🌉
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::BRIDGE_AT_NIGHT;
 		
 		// CAROUSEL_HORSE 🎠
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::CAROUSEL_HORSE;
 		
 		// CIRCUS_TENT 🎪
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::CIRCUS_TENT;
 		
 		// CITYSCAPE 🏙️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::CITYSCAPE;
 		
 		// CITYSCAPE_AT_DUSK 🌆
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::CITYSCAPE_AT_DUSK;
 		
 		// FERRIS_WHEEL 🎡
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::FERRIS_WHEEL;
 		
 		// FOGGY 🌁
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::FOGGY;
 		
 		// FOUNTAIN ⛲
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::FOUNTAIN;
 		
 		// HOT_SPRINGS ♨️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::HOT_SPRINGS;
 		
 		// NIGHT_WITH_STARS 🌃
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::NIGHT_WITH_STARS;
 		
+		// PLAYGROUND_SLIDE 🛝
+		#[cfg(emojic_group = "travel_and_places")]
+		#[doc(inline)]
+		pub use crate::grouped::travel_and_places::place_other::PLAYGROUND_SLIDE;
+		
 		// ROLLER_COASTER 🎢
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::ROLLER_COASTER;
 		
 		// SUNRISE 🌅
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::SUNRISE;
 		
 		// SUNRISE_OVER_MOUNTAINS 🌄
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::SUNRISE_OVER_MOUNTAINS;
 		
 		// SUNSET 🌇
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::SUNSET;
 		
 		// TENT ⛺
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_other::TENT;
 		
@@ -6336,26 +7976,32 @@
 	// begin place_religious ⛪🛕🕋
 		
 		// CHURCH ⛪
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_religious::CHURCH;
 		
 		// HINDU_TEMPLE 🛕
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_religious::HINDU_TEMPLE;
 		
 		// KAABA 🕋
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_religious::KAABA;
 		
 		// MOSQUE 🕌
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_religious::MOSQUE;
 		
 		// SHINTO_SHRINE ⛩️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_religious::SHINTO_SHRINE;
 		
 		// SYNAGOGUE 🕍
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::place_religious::SYNAGOGUE;
 		
@@ -6364,190 +8010,237 @@
 	// begin sky_and_weather 🌂☁️🌩️
 		
 		// CLOSED_UMBRELLA 🌂
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::CLOSED_UMBRELLA;
 		
 		// CLOUD ☁️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::CLOUD;
 		
 		// CLOUD_WITH_LIGHTNING 🌩️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::CLOUD_WITH_LIGHTNING;
 		
 		// CLOUD_WITH_LIGHTNING_AND_RAIN ⛈️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::CLOUD_WITH_LIGHTNING_AND_RAIN;
 		
 		// CLOUD_WITH_RAIN 🌧️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::CLOUD_WITH_RAIN;
 		
 		// CLOUD_WITH_SNOW 🌨️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::CLOUD_WITH_SNOW;
 		
 		// COMET ☄️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::COMET;
 		
 		// CRESCENT_MOON 🌙
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::CRESCENT_MOON;
 		
 		// CYCLONE 🌀
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::CYCLONE;
 		
 		// DROPLET 💧
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::DROPLET;
 		
 		// FIRE 🔥
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::FIRE;
 		
 		// FIRST_QUARTER_MOON 🌓
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::FIRST_QUARTER_MOON;
 		
 		// FIRST_QUARTER_MOON_FACE 🌛
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::FIRST_QUARTER_MOON_FACE;
 		
 		// FOG 🌫️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::FOG;
 		
 		// FULL_MOON 🌕
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::FULL_MOON;
 		
 		// FULL_MOON_FACE 🌝
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::FULL_MOON_FACE;
 		
 		// GLOWING_STAR 🌟
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::GLOWING_STAR;
 		
 		// HIGH_VOLTAGE ⚡
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::HIGH_VOLTAGE;
 		
 		// LAST_QUARTER_MOON 🌗
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::LAST_QUARTER_MOON;
 		
 		// LAST_QUARTER_MOON_FACE 🌜
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::LAST_QUARTER_MOON_FACE;
 		
 		// MILKY_WAY 🌌
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::MILKY_WAY;
 		
 		// NEW_MOON 🌑
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::NEW_MOON;
 		
 		// NEW_MOON_FACE 🌚
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::NEW_MOON_FACE;
 		
 		// RAINBOW 🌈
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::RAINBOW;
 		
 		// RINGED_PLANET 🪐
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::RINGED_PLANET;
 		
 		// SHOOTING_STAR 🌠
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::SHOOTING_STAR;
 		
 		// SNOWFLAKE ❄️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::SNOWFLAKE;
 		
 		// SNOWMAN ☃️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::SNOWMAN;
 		
 		// SNOWMAN_WITHOUT_SNOW ⛄
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::SNOWMAN_WITHOUT_SNOW;
 		
 		// STAR ⭐
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::STAR;
 		
 		// SUN ☀️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::SUN;
 		
 		// SUN_BEHIND_CLOUD ⛅
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::SUN_BEHIND_CLOUD;
 		
 		// SUN_BEHIND_LARGE_CLOUD 🌥️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::SUN_BEHIND_LARGE_CLOUD;
 		
 		// SUN_BEHIND_RAIN_CLOUD 🌦️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::SUN_BEHIND_RAIN_CLOUD;
 		
 		// SUN_BEHIND_SMALL_CLOUD 🌤️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::SUN_BEHIND_SMALL_CLOUD;
 		
 		// SUN_WITH_FACE 🌞
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::SUN_WITH_FACE;
 		
 		// THERMOMETER 🌡️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::THERMOMETER;
 		
 		// TORNADO 🌪️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::TORNADO;
 		
 		// UMBRELLA ☂️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::UMBRELLA;
 		
 		// UMBRELLA_ON_GROUND ⛱️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::UMBRELLA_ON_GROUND;
 		
 		// UMBRELLA_WITH_RAIN_DROPS ☔
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::UMBRELLA_WITH_RAIN_DROPS;
 		
 		// WANING_CRESCENT_MOON 🌘
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::WANING_CRESCENT_MOON;
 		
 		// WANING_GIBBOUS_MOON 🌖
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::WANING_GIBBOUS_MOON;
 		
 		// WATER_WAVE 🌊
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::WATER_WAVE;
 		
 		// WAXING_CRESCENT_MOON 🌒
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::WAXING_CRESCENT_MOON;
 		
 		// WAXING_GIBBOUS_MOON 🌔
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::WAXING_GIBBOUS_MOON;
 		
 		// WIND_FACE 🌬️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::sky_and_weather::WIND_FACE;
 		
@@ -6556,126 +8249,157 @@
 	// begin time ⏰🕗🕣
 		
 		// ALARM_CLOCK ⏰
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::ALARM_CLOCK;
 		
 		// EIGHT_O_CLOCK 🕗
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::EIGHT_O_CLOCK;
 		
 		// EIGHT_THIRTY 🕣
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::EIGHT_THIRTY;
 		
 		// ELEVEN_O_CLOCK 🕚
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::ELEVEN_O_CLOCK;
 		
 		// ELEVEN_THIRTY 🕦
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::ELEVEN_THIRTY;
 		
 		// FIVE_O_CLOCK 🕔
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::FIVE_O_CLOCK;
 		
 		// FIVE_THIRTY 🕠
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::FIVE_THIRTY;
 		
 		// FOUR_O_CLOCK 🕓
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::FOUR_O_CLOCK;
 		
 		// FOUR_THIRTY 🕟
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::FOUR_THIRTY;
 		
 		// HOURGLASS_DONE ⌛
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::HOURGLASS_DONE;
 		
 		// HOURGLASS_NOT_DONE ⏳
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::HOURGLASS_NOT_DONE;
 		
 		// MANTELPIECE_CLOCK 🕰️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::MANTELPIECE_CLOCK;
 		
 		// NINE_O_CLOCK 🕘
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::NINE_O_CLOCK;
 		
 		// NINE_THIRTY 🕤
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::NINE_THIRTY;
 		
 		// ONE_O_CLOCK 🕐
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::ONE_O_CLOCK;
 		
 		// ONE_THIRTY 🕜
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::ONE_THIRTY;
 		
 		// SEVEN_O_CLOCK 🕖
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::SEVEN_O_CLOCK;
 		
 		// SEVEN_THIRTY 🕢
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::SEVEN_THIRTY;
 		
 		// SIX_O_CLOCK 🕕
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::SIX_O_CLOCK;
 		
 		// SIX_THIRTY 🕡
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::SIX_THIRTY;
 		
 		// STOPWATCH ⏱️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::STOPWATCH;
 		
 		// TEN_O_CLOCK 🕙
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::TEN_O_CLOCK;
 		
 		// TEN_THIRTY 🕥
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::TEN_THIRTY;
 		
 		// THREE_O_CLOCK 🕒
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::THREE_O_CLOCK;
 		
 		// THREE_THIRTY 🕞
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::THREE_THIRTY;
 		
 		// TIMER_CLOCK ⏲️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::TIMER_CLOCK;
 		
 		// TWELVE_O_CLOCK 🕛
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::TWELVE_O_CLOCK;
 		
 		// TWELVE_THIRTY 🕧
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::TWELVE_THIRTY;
 		
 		// TWO_O_CLOCK 🕑
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::TWO_O_CLOCK;
 		
 		// TWO_THIRTY 🕝
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::TWO_THIRTY;
 		
 		// WATCH ⌚
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::time::WATCH;
 		
@@ -6684,54 +8408,67 @@
 	// begin transport_air 🚡✈️🛬
 		
 		// AERIAL_TRAMWAY 🚡
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_air::AERIAL_TRAMWAY;
 		
 		// AIRPLANE ✈️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_air::AIRPLANE;
 		
 		// AIRPLANE_ARRIVAL 🛬
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_air::AIRPLANE_ARRIVAL;
 		
 		// AIRPLANE_DEPARTURE 🛫
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_air::AIRPLANE_DEPARTURE;
 		
 		// FLYING_SAUCER 🛸
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_air::FLYING_SAUCER;
 		
 		// HELICOPTER 🚁
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_air::HELICOPTER;
 		
 		// MOUNTAIN_CABLEWAY 🚠
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_air::MOUNTAIN_CABLEWAY;
 		
 		// PARACHUTE 🪂
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_air::PARACHUTE;
 		
 		// ROCKET 🚀
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_air::ROCKET;
 		
 		// SATELLITE 🛰️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_air::SATELLITE;
 		
 		// SEAT 💺
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_air::SEAT;
 		
 		// SMALL_AIRPLANE 🛩️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_air::SMALL_AIRPLANE;
 		
 		// SUSPENSION_RAILWAY 🚟
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_air::SUSPENSION_RAILWAY;
 		
@@ -6740,234 +8477,301 @@
 	// begin transport_ground 🚑🚛🚗
 		
 		// AMBULANCE 🚑
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::AMBULANCE;
 		
 		// ARTICULATED_LORRY 🚛
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::ARTICULATED_LORRY;
 		
 		// AUTOMOBILE 🚗
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::AUTOMOBILE;
 		
 		// AUTO_RICKSHAW 🛺
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::AUTO_RICKSHAW;
 		
 		// BICYCLE 🚲
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::BICYCLE;
 		
 		// BULLET_TRAIN 🚅
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::BULLET_TRAIN;
 		
 		// BUS 🚌
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::BUS;
 		
 		// BUS_STOP 🚏
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::BUS_STOP;
 		
 		// CONSTRUCTION 🚧
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::CONSTRUCTION;
 		
 		// DELIVERY_TRUCK 🚚
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::DELIVERY_TRUCK;
 		
 		// FIRE_ENGINE 🚒
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::FIRE_ENGINE;
 		
 		// FUEL_PUMP ⛽
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::FUEL_PUMP;
 		
 		// HIGH_SPEED_TRAIN 🚄
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::HIGH_SPEED_TRAIN;
 		
 		// HORIZONTAL_TRAFFIC_LIGHT 🚥
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::HORIZONTAL_TRAFFIC_LIGHT;
 		
 		// KICK_SCOOTER 🛴
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::KICK_SCOOTER;
 		
 		// LIGHT_RAIL 🚈
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::LIGHT_RAIL;
 		
 		// LOCOMOTIVE 🚂
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::LOCOMOTIVE;
 		
 		// MANUAL_WHEELCHAIR 🦽
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::MANUAL_WHEELCHAIR;
 		
 		// METRO 🚇
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::METRO;
 		
 		// MINIBUS 🚐
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::MINIBUS;
 		
 		// MONORAIL 🚝
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::MONORAIL;
 		
 		// MOTORCYCLE 🏍️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::MOTORCYCLE;
 		
 		// MOTORIZED_WHEELCHAIR 🦼
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::MOTORIZED_WHEELCHAIR;
 		
 		// MOTORWAY 🛣️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::MOTORWAY;
 		
 		// MOTOR_SCOOTER 🛵
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::MOTOR_SCOOTER;
 		
 		// MOUNTAIN_RAILWAY 🚞
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::MOUNTAIN_RAILWAY;
 		
 		// OIL_DRUM 🛢️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::OIL_DRUM;
 		
 		// ONCOMING_AUTOMOBILE 🚘
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::ONCOMING_AUTOMOBILE;
 		
 		// ONCOMING_BUS 🚍
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::ONCOMING_BUS;
 		
 		// ONCOMING_POLICE_CAR 🚔
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::ONCOMING_POLICE_CAR;
 		
 		// ONCOMING_TAXI 🚖
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::ONCOMING_TAXI;
 		
 		// PICKUP_TRUCK 🛻
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::PICKUP_TRUCK;
 		
 		// POLICE_CAR 🚓
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::POLICE_CAR;
 		
 		// POLICE_CAR_LIGHT 🚨
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::POLICE_CAR_LIGHT;
 		
 		// RACING_CAR 🏎️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::RACING_CAR;
 		
 		// RAILWAY_CAR 🚃
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::RAILWAY_CAR;
 		
 		// RAILWAY_TRACK 🛤️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::RAILWAY_TRACK;
 		
 		// ROLLER_SKATE 🛼
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::ROLLER_SKATE;
 		
 		// SKATEBOARD 🛹
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::SKATEBOARD;
 		
 		// SPORT_UTILITY_VEHICLE 🚙
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::SPORT_UTILITY_VEHICLE;
 		
 		// STATION 🚉
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::STATION;
 		
 		// STOP_SIGN 🛑
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::STOP_SIGN;
 		
 		// TAXI 🚕
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::TAXI;
 		
 		// TRACTOR 🚜
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::TRACTOR;
 		
 		// TRAIN 🚆
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::TRAIN;
 		
 		// TRAM 🚊
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::TRAM;
 		
 		// TRAM_CAR 🚋
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::TRAM_CAR;
 		
 		// TROLLEYBUS 🚎
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::TROLLEYBUS;
 		
 		// VERTICAL_TRAFFIC_LIGHT 🚦
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_ground::VERTICAL_TRAFFIC_LIGHT;
 		
+		// WHEEL 🛞
+		#[cfg(emojic_group = "travel_and_places")]
+		#[doc(inline)]
+		pub use crate::grouped::travel_and_places::transport_ground::WHEEL;
+		
 	// end transport_ground
 	
 	// begin transport_water ⚓🛶⛴️
 		
 		// ANCHOR ⚓
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_water::ANCHOR;
 		
 		// CANOE 🛶
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_water::CANOE;
 		
 		// FERRY ⛴️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_water::FERRY;
 		
 		// MOTOR_BOAT 🛥️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_water::MOTOR_BOAT;
 		
 		// PASSENGER_SHIP 🛳️
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_water::PASSENGER_SHIP;
 		
+		// RING_BUOY 🛟
+		#[cfg(emojic_group = "travel_and_places")]
+		#[doc(inline)]
+		pub use crate::grouped::travel_and_places::transport_water::RING_BUOY;
+		
 		// SAILBOAT ⛵
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_water::SAILBOAT;
 		
 		// SHIP 🚢
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_water::SHIP;
 		
 		// SPEEDBOAT 🚤
+		#[cfg(emojic_group = "travel_and_places")]
 		#[doc(inline)]
 		pub use crate::grouped::travel_and_places::transport_water::SPEEDBOAT;
 		
@@ -6976,4 +8780,6388 @@
 // end travel_and_places
 
 
+/// The names of all emojis (including all variants), sorted so they can be binary searched.
+///
+/// These are the [`name`](crate::emojis::Emoji::name)s of all emojis, e.g. to build an index
+/// for autocompletion without the emojis themselves.
+///
+/// # Examples
+///
+/// ```rust
+/// use emojic::flat::ALL_NAMES;
+///
+/// assert!(ALL_NAMES.binary_search(&"waving hand: dark skin tone").is_ok());
+/// assert!(ALL_NAMES.windows(2).all(|w| w[0] < w[1]));
+/// ```
+pub static ALL_NAMES: &[&str] = &[
+	#[cfg(emojic_group = "activities")] "1st place medal",
+	#[cfg(emojic_group = "activities")] "2nd place medal",
+	#[cfg(emojic_group = "activities")] "3rd place medal",
+	#[cfg(emojic_group = "symbols")] "A button (blood type)",
+	#[cfg(emojic_group = "symbols")] "AB button (blood type)",
+	#[cfg(emojic_group = "symbols")] "ATM sign",
+	#[cfg(emojic_group = "symbols")] "Aquarius",
+	#[cfg(emojic_group = "symbols")] "Aries",
+	#[cfg(emojic_group = "symbols")] "B button (blood type)",
+	#[cfg(emojic_group = "symbols")] "BACK arrow",
+	#[cfg(emojic_group = "symbols")] "CL button",
+	#[cfg(emojic_group = "symbols")] "COOL button",
+	#[cfg(emojic_group = "symbols")] "Cancer",
+	#[cfg(emojic_group = "symbols")] "Capricorn",
+	#[cfg(emojic_group = "activities")] "Christmas tree",
+	#[cfg(emojic_group = "symbols")] "END arrow",
+	#[cfg(emojic_group = "symbols")] "FREE button",
+	#[cfg(emojic_group = "symbols")] "Gemini",
+	#[cfg(emojic_group = "symbols")] "ID button",
+	#[cfg(emojic_group = "travel_and_places")] "Japanese castle",
+	#[cfg(emojic_group = "activities")] "Japanese dolls",
+	#[cfg(emojic_group = "travel_and_places")] "Japanese post office",
+	#[cfg(emojic_group = "symbols")] "Japanese symbol for beginner",
+	#[cfg(emojic_group = "symbols")] "Japanese “acceptable” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “application” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “bargain” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “congratulations” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “discount” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “free of charge” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “here” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “monthly amount” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “no vacancy” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “not free of charge” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “open for business” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “passing grade” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “prohibited” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “reserved” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “secret” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “service charge” button",
+	#[cfg(emojic_group = "symbols")] "Japanese “vacancy” button",
+	#[cfg(emojic_group = "symbols")] "Leo",
+	#[cfg(emojic_group = "symbols")] "Libra",
+	#[cfg(emojic_group = "people_and_body")] "Mrs. Claus",
+	#[cfg(emojic_group = "people_and_body")] "Mrs. Claus: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "Mrs. Claus: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "Mrs. Claus: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "Mrs. Claus: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "Mrs. Claus: medium-light skin tone",
+	#[cfg(emojic_group = "symbols")] "NEW button",
+	#[cfg(emojic_group = "symbols")] "NG button",
+	#[cfg(emojic_group = "symbols")] "O button (blood type)",
+	#[cfg(emojic_group = "symbols")] "OK button",
+	#[cfg(emojic_group = "people_and_body")] "OK hand",
+	#[cfg(emojic_group = "people_and_body")] "OK hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "OK hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "OK hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "OK hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "OK hand: medium-light skin tone",
+	#[cfg(emojic_group = "symbols")] "ON! arrow",
+	#[cfg(emojic_group = "symbols")] "Ophiuchus",
+	#[cfg(emojic_group = "symbols")] "P button",
+	#[cfg(emojic_group = "symbols")] "Pisces",
+	#[cfg(emojic_group = "symbols")] "SOON arrow",
+	#[cfg(emojic_group = "symbols")] "SOS button",
+	#[cfg(emojic_group = "symbols")] "Sagittarius",
+	#[cfg(emojic_group = "people_and_body")] "Santa Claus",
+	#[cfg(emojic_group = "people_and_body")] "Santa Claus: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "Santa Claus: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "Santa Claus: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "Santa Claus: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "Santa Claus: medium-light skin tone",
+	#[cfg(emojic_group = "symbols")] "Scorpio",
+	#[cfg(emojic_group = "travel_and_places")] "Statue of Liberty",
+	#[cfg(emojic_group = "animals_and_nature")] "T-Rex",
+	#[cfg(emojic_group = "symbols")] "TOP arrow",
+	#[cfg(emojic_group = "symbols")] "Taurus",
+	#[cfg(emojic_group = "travel_and_places")] "Tokyo tower",
+	#[cfg(emojic_group = "symbols")] "UP! button",
+	#[cfg(emojic_group = "symbols")] "VS button",
+	#[cfg(emojic_group = "symbols")] "Virgo",
+	#[cfg(emojic_group = "objects")] "abacus",
+	#[cfg(emojic_group = "objects")] "accordion",
+	#[cfg(emojic_group = "objects")] "adhesive bandage",
+	#[cfg(emojic_group = "activities")] "admission tickets",
+	#[cfg(emojic_group = "travel_and_places")] "aerial tramway",
+	#[cfg(emojic_group = "travel_and_places")] "airplane",
+	#[cfg(emojic_group = "travel_and_places")] "airplane arrival",
+	#[cfg(emojic_group = "travel_and_places")] "airplane departure",
+	#[cfg(emojic_group = "travel_and_places")] "alarm clock",
+	#[cfg(emojic_group = "objects")] "alembic",
+	#[cfg(emojic_group = "smileys_and_emotion")] "alien",
+	#[cfg(emojic_group = "smileys_and_emotion")] "alien monster",
+	#[cfg(emojic_group = "travel_and_places")] "ambulance",
+	#[cfg(emojic_group = "activities")] "american football",
+	#[cfg(emojic_group = "food_and_drink")] "amphora",
+	#[cfg(emojic_group = "people_and_body")] "anatomical heart",
+	#[cfg(emojic_group = "travel_and_places")] "anchor",
+	#[cfg(emojic_group = "smileys_and_emotion")] "anger symbol",
+	#[cfg(emojic_group = "smileys_and_emotion")] "angry face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "angry face with horns",
+	#[cfg(emojic_group = "smileys_and_emotion")] "anguished face",
+	#[cfg(emojic_group = "animals_and_nature")] "ant",
+	#[cfg(emojic_group = "symbols")] "antenna bars",
+	#[cfg(emojic_group = "smileys_and_emotion")] "anxious face with sweat",
+	#[cfg(emojic_group = "travel_and_places")] "articulated lorry",
+	#[cfg(emojic_group = "people_and_body")] "artist",
+	#[cfg(emojic_group = "activities")] "artist palette",
+	#[cfg(emojic_group = "people_and_body")] "artist: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "artist: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "artist: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "artist: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "artist: medium-light skin tone",
+	#[cfg(emojic_group = "smileys_and_emotion")] "astonished face",
+	#[cfg(emojic_group = "people_and_body")] "astronaut",
+	#[cfg(emojic_group = "people_and_body")] "astronaut: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "astronaut: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "astronaut: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "astronaut: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "astronaut: medium-light skin tone",
+	#[cfg(emojic_group = "symbols")] "atom symbol",
+	#[cfg(emojic_group = "travel_and_places")] "auto rickshaw",
+	#[cfg(emojic_group = "travel_and_places")] "automobile",
+	#[cfg(emojic_group = "food_and_drink")] "avocado",
+	#[cfg(emojic_group = "objects")] "axe",
+	#[cfg(emojic_group = "people_and_body")] "baby",
+	#[cfg(emojic_group = "people_and_body")] "baby angel",
+	#[cfg(emojic_group = "people_and_body")] "baby angel: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "baby angel: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "baby angel: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "baby angel: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "baby angel: medium-light skin tone",
+	#[cfg(emojic_group = "food_and_drink")] "baby bottle",
+	#[cfg(emojic_group = "animals_and_nature")] "baby chick",
+	#[cfg(emojic_group = "symbols")] "baby symbol",
+	#[cfg(emojic_group = "people_and_body")] "baby: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "baby: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "baby: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "baby: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "baby: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing down",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing down: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing down: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing down: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing down: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing down: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing left",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing left: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing left: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing left: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing left: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing left: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing right",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing right: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing right: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing right: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing right: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing right: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing up",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing up: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing up: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing up: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing up: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "backhand index pointing up: medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "backpack",
+	#[cfg(emojic_group = "food_and_drink")] "bacon",
+	#[cfg(emojic_group = "animals_and_nature")] "badger",
+	#[cfg(emojic_group = "activities")] "badminton",
+	#[cfg(emojic_group = "food_and_drink")] "bagel",
+	#[cfg(emojic_group = "symbols")] "baggage claim",
+	#[cfg(emojic_group = "food_and_drink")] "baguette bread",
+	#[cfg(emojic_group = "objects")] "balance scale",
+	#[cfg(emojic_group = "component")] "bald",
+	#[cfg(emojic_group = "objects")] "ballet shoes",
+	#[cfg(emojic_group = "activities")] "balloon",
+	#[cfg(emojic_group = "objects")] "ballot box with ballot",
+	#[cfg(emojic_group = "food_and_drink")] "banana",
+	#[cfg(emojic_group = "objects")] "banjo",
+	#[cfg(emojic_group = "travel_and_places")] "bank",
+	#[cfg(emojic_group = "objects")] "bar chart",
+	#[cfg(emojic_group = "travel_and_places")] "barber pole",
+	#[cfg(emojic_group = "activities")] "baseball",
+	#[cfg(emojic_group = "objects")] "basket",
+	#[cfg(emojic_group = "activities")] "basketball",
+	#[cfg(emojic_group = "animals_and_nature")] "bat",
+	#[cfg(emojic_group = "objects")] "bathtub",
+	#[cfg(emojic_group = "objects")] "battery",
+	#[cfg(emojic_group = "travel_and_places")] "beach with umbrella",
+	#[cfg(emojic_group = "smileys_and_emotion")] "beaming face with smiling eyes",
+	#[cfg(emojic_group = "food_and_drink")] "beans",
+	#[cfg(emojic_group = "animals_and_nature")] "bear",
+	#[cfg(emojic_group = "smileys_and_emotion")] "beating heart",
+	#[cfg(emojic_group = "animals_and_nature")] "beaver",
+	#[cfg(emojic_group = "objects")] "bed",
+	#[cfg(emojic_group = "food_and_drink")] "beer mug",
+	#[cfg(emojic_group = "animals_and_nature")] "beetle",
+	#[cfg(emojic_group = "objects")] "bell",
+	#[cfg(emojic_group = "food_and_drink")] "bell pepper",
+	#[cfg(emojic_group = "objects")] "bell with slash",
+	#[cfg(emojic_group = "travel_and_places")] "bellhop bell",
+	#[cfg(emojic_group = "food_and_drink")] "bento box",
+	#[cfg(emojic_group = "food_and_drink")] "beverage box",
+	#[cfg(emojic_group = "travel_and_places")] "bicycle",
+	#[cfg(emojic_group = "objects")] "bikini",
+	#[cfg(emojic_group = "objects")] "billed cap",
+	#[cfg(emojic_group = "symbols")] "biohazard",
+	#[cfg(emojic_group = "animals_and_nature")] "bird",
+	#[cfg(emojic_group = "food_and_drink")] "birthday cake",
+	#[cfg(emojic_group = "animals_and_nature")] "bison",
+	#[cfg(emojic_group = "people_and_body")] "biting lip",
+	#[cfg(emojic_group = "animals_and_nature")] "black cat",
+	#[cfg(emojic_group = "symbols")] "black circle",
+	#[cfg(emojic_group = "flags")] "black flag",
+	#[cfg(emojic_group = "smileys_and_emotion")] "black heart",
+	#[cfg(emojic_group = "symbols")] "black large square",
+	#[cfg(emojic_group = "symbols")] "black medium square",
+	#[cfg(emojic_group = "symbols")] "black medium-small square",
+	#[cfg(emojic_group = "objects")] "black nib",
+	#[cfg(emojic_group = "symbols")] "black small square",
+	#[cfg(emojic_group = "symbols")] "black square button",
+	#[cfg(emojic_group = "animals_and_nature")] "blossom",
+	#[cfg(emojic_group = "animals_and_nature")] "blowfish",
+	#[cfg(emojic_group = "objects")] "blue book",
+	#[cfg(emojic_group = "symbols")] "blue circle",
+	#[cfg(emojic_group = "smileys_and_emotion")] "blue heart",
+	#[cfg(emojic_group = "symbols")] "blue square",
+	#[cfg(emojic_group = "food_and_drink")] "blueberries",
+	#[cfg(emojic_group = "animals_and_nature")] "boar",
+	#[cfg(emojic_group = "smileys_and_emotion")] "bomb",
+	#[cfg(emojic_group = "people_and_body")] "bone",
+	#[cfg(emojic_group = "objects")] "bookmark",
+	#[cfg(emojic_group = "objects")] "bookmark tabs",
+	#[cfg(emojic_group = "objects")] "books",
+	#[cfg(emojic_group = "objects")] "boomerang",
+	#[cfg(emojic_group = "food_and_drink")] "bottle with popping cork",
+	#[cfg(emojic_group = "animals_and_nature")] "bouquet",
+	#[cfg(emojic_group = "objects")] "bow and arrow",
+	#[cfg(emojic_group = "food_and_drink")] "bowl with spoon",
+	#[cfg(emojic_group = "activities")] "bowling",
+	#[cfg(emojic_group = "activities")] "boxing glove",
+	#[cfg(emojic_group = "people_and_body")] "boy",
+	#[cfg(emojic_group = "people_and_body")] "boy: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "boy: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "boy: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "boy: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "boy: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "brain",
+	#[cfg(emojic_group = "food_and_drink")] "bread",
+	#[cfg(emojic_group = "people_and_body")] "breast-feeding",
+	#[cfg(emojic_group = "people_and_body")] "breast-feeding: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "breast-feeding: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "breast-feeding: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "breast-feeding: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "breast-feeding: medium-light skin tone",
+	#[cfg(emojic_group = "travel_and_places")] "brick",
+	#[cfg(emojic_group = "travel_and_places")] "bridge at night",
+	#[cfg(emojic_group = "objects")] "briefcase",
+	#[cfg(emojic_group = "objects")] "briefs",
+	#[cfg(emojic_group = "symbols")] "bright button",
+	#[cfg(emojic_group = "food_and_drink")] "broccoli",
+	#[cfg(emojic_group = "smileys_and_emotion")] "broken heart",
+	#[cfg(emojic_group = "objects")] "broom",
+	#[cfg(emojic_group = "symbols")] "brown circle",
+	#[cfg(emojic_group = "smileys_and_emotion")] "brown heart",
+	#[cfg(emojic_group = "symbols")] "brown square",
+	#[cfg(emojic_group = "food_and_drink")] "bubble tea",
+	#[cfg(emojic_group = "objects")] "bubbles",
+	#[cfg(emojic_group = "objects")] "bucket",
+	#[cfg(emojic_group = "animals_and_nature")] "bug",
+	#[cfg(emojic_group = "travel_and_places")] "building construction",
+	#[cfg(emojic_group = "travel_and_places")] "bullet train",
+	#[cfg(emojic_group = "activities")] "bullseye",
+	#[cfg(emojic_group = "food_and_drink")] "burrito",
+	#[cfg(emojic_group = "travel_and_places")] "bus",
+	#[cfg(emojic_group = "travel_and_places")] "bus stop",
+	#[cfg(emojic_group = "people_and_body")] "bust in silhouette",
+	#[cfg(emojic_group = "people_and_body")] "busts in silhouette",
+	#[cfg(emojic_group = "food_and_drink")] "butter",
+	#[cfg(emojic_group = "animals_and_nature")] "butterfly",
+	#[cfg(emojic_group = "animals_and_nature")] "cactus",
+	#[cfg(emojic_group = "objects")] "calendar",
+	#[cfg(emojic_group = "people_and_body")] "call me hand",
+	#[cfg(emojic_group = "people_and_body")] "call me hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "call me hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "call me hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "call me hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "call me hand: medium-light skin tone",
+	#[cfg(emojic_group = "animals_and_nature")] "camel",
+	#[cfg(emojic_group = "objects")] "camera",
+	#[cfg(emojic_group = "objects")] "camera with flash",
+	#[cfg(emojic_group = "travel_and_places")] "camping",
+	#[cfg(emojic_group = "objects")] "candle",
+	#[cfg(emojic_group = "food_and_drink")] "candy",
+	#[cfg(emojic_group = "food_and_drink")] "canned food",
+	#[cfg(emojic_group = "travel_and_places")] "canoe",
+	#[cfg(emojic_group = "objects")] "card file box",
+	#[cfg(emojic_group = "objects")] "card index",
+	#[cfg(emojic_group = "objects")] "card index dividers",
+	#[cfg(emojic_group = "travel_and_places")] "carousel horse",
+	#[cfg(emojic_group = "activities")] "carp streamer",
+	#[cfg(emojic_group = "objects")] "carpentry saw",
+	#[cfg(emojic_group = "food_and_drink")] "carrot",
+	#[cfg(emojic_group = "travel_and_places")] "castle",
+	#[cfg(emojic_group = "animals_and_nature")] "cat",
+	#[cfg(emojic_group = "animals_and_nature")] "cat face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "cat with tears of joy",
+	#[cfg(emojic_group = "smileys_and_emotion")] "cat with wry smile",
+	#[cfg(emojic_group = "objects")] "chains",
+	#[cfg(emojic_group = "objects")] "chair",
+	#[cfg(emojic_group = "objects")] "chart decreasing",
+	#[cfg(emojic_group = "objects")] "chart increasing",
+	#[cfg(emojic_group = "objects")] "chart increasing with yen",
+	#[cfg(emojic_group = "symbols")] "check box with check",
+	#[cfg(emojic_group = "symbols")] "check mark",
+	#[cfg(emojic_group = "symbols")] "check mark button",
+	#[cfg(emojic_group = "food_and_drink")] "cheese wedge",
+	#[cfg(emojic_group = "flags")] "chequered flag",
+	#[cfg(emojic_group = "food_and_drink")] "cherries",
+	#[cfg(emojic_group = "animals_and_nature")] "cherry blossom",
+	#[cfg(emojic_group = "activities")] "chess pawn",
+	#[cfg(emojic_group = "food_and_drink")] "chestnut",
+	#[cfg(emojic_group = "animals_and_nature")] "chicken",
+	#[cfg(emojic_group = "people_and_body")] "child",
+	#[cfg(emojic_group = "people_and_body")] "child: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "child: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "child: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "child: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "child: medium-light skin tone",
+	#[cfg(emojic_group = "symbols")] "children crossing",
+	#[cfg(emojic_group = "animals_and_nature")] "chipmunk",
+	#[cfg(emojic_group = "food_and_drink")] "chocolate bar",
+	#[cfg(emojic_group = "food_and_drink")] "chopsticks",
+	#[cfg(emojic_group = "travel_and_places")] "church",
+	#[cfg(emojic_group = "objects")] "cigarette",
+	#[cfg(emojic_group = "symbols")] "cinema",
+	#[cfg(emojic_group = "symbols")] "circled M",
+	#[cfg(emojic_group = "travel_and_places")] "circus tent",
+	#[cfg(emojic_group = "travel_and_places")] "cityscape",
+	#[cfg(emojic_group = "travel_and_places")] "cityscape at dusk",
+	#[cfg(emojic_group = "objects")] "clamp",
+	#[cfg(emojic_group = "objects")] "clapper board",
+	#[cfg(emojic_group = "people_and_body")] "clapping hands",
+	#[cfg(emojic_group = "people_and_body")] "clapping hands: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "clapping hands: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "clapping hands: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "clapping hands: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "clapping hands: medium-light skin tone",
+	#[cfg(emojic_group = "travel_and_places")] "classical building",
+	#[cfg(emojic_group = "food_and_drink")] "clinking beer mugs",
+	#[cfg(emojic_group = "food_and_drink")] "clinking glasses",
+	#[cfg(emojic_group = "objects")] "clipboard",
+	#[cfg(emojic_group = "symbols")] "clockwise vertical arrows",
+	#[cfg(emojic_group = "objects")] "closed book",
+	#[cfg(emojic_group = "objects")] "closed mailbox with lowered flag",
+	#[cfg(emojic_group = "objects")] "closed mailbox with raised flag",
+	#[cfg(emojic_group = "travel_and_places")] "closed umbrella",
+	#[cfg(emojic_group = "travel_and_places")] "cloud",
+	#[cfg(emojic_group = "travel_and_places")] "cloud with lightning",
+	#[cfg(emojic_group = "travel_and_places")] "cloud with lightning and rain",
+	#[cfg(emojic_group = "travel_and_places")] "cloud with rain",
+	#[cfg(emojic_group = "travel_and_places")] "cloud with snow",
+	#[cfg(emojic_group = "smileys_and_emotion")] "clown face",
+	#[cfg(emojic_group = "activities")] "club suit",
+	#[cfg(emojic_group = "objects")] "clutch bag",
+	#[cfg(emojic_group = "objects")] "coat",
+	#[cfg(emojic_group = "animals_and_nature")] "cockroach",
+	#[cfg(emojic_group = "food_and_drink")] "cocktail glass",
+	#[cfg(emojic_group = "food_and_drink")] "coconut",
+	#[cfg(emojic_group = "objects")] "coffin",
+	#[cfg(emojic_group = "objects")] "coin",
+	#[cfg(emojic_group = "smileys_and_emotion")] "cold face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "collision",
+	#[cfg(emojic_group = "travel_and_places")] "comet",
+	#[cfg(emojic_group = "travel_and_places")] "compass",
+	#[cfg(emojic_group = "objects")] "computer disk",
+	#[cfg(emojic_group = "objects")] "computer mouse",
+	#[cfg(emojic_group = "activities")] "confetti ball",
+	#[cfg(emojic_group = "smileys_and_emotion")] "confounded face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "confused face",
+	#[cfg(emojic_group = "travel_and_places")] "construction",
+	#[cfg(emojic_group = "people_and_body")] "construction worker",
+	#[cfg(emojic_group = "people_and_body")] "construction worker: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "construction worker: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "construction worker: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "construction worker: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "construction worker: medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "control knobs",
+	#[cfg(emojic_group = "travel_and_places")] "convenience store",
+	#[cfg(emojic_group = "people_and_body")] "cook",
+	#[cfg(emojic_group = "people_and_body")] "cook: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "cook: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "cook: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "cook: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "cook: medium-light skin tone",
+	#[cfg(emojic_group = "food_and_drink")] "cooked rice",
+	#[cfg(emojic_group = "food_and_drink")] "cookie",
+	#[cfg(emojic_group = "food_and_drink")] "cooking",
+	#[cfg(emojic_group = "symbols")] "copyright",
+	#[cfg(emojic_group = "animals_and_nature")] "coral",
+	#[cfg(emojic_group = "objects")] "couch and lamp",
+	#[cfg(emojic_group = "symbols")] "counterclockwise arrows button",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, dark skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, light skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium-dark skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium-dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium-dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium-dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium-light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium-light skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium-light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: man, man, medium-light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, dark skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, light skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, medium skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, medium skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, medium skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, medium skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, medium-dark skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, medium-dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, medium-dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, medium-dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, medium-light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, medium-light skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, medium-light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: person, person, medium-light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, dark skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, light skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium-dark skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium-dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium-dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium-dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium-light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium-light skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium-light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, man, medium-light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, dark skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, light skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium-dark skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium-dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium-dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium-dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium-light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium-light skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium-light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "couple with heart: woman, woman, medium-light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "animals_and_nature")] "cow",
+	#[cfg(emojic_group = "animals_and_nature")] "cow face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "cowboy hat face",
+	#[cfg(emojic_group = "food_and_drink")] "crab",
+	#[cfg(emojic_group = "objects")] "crayon",
+	#[cfg(emojic_group = "objects")] "credit card",
+	#[cfg(emojic_group = "travel_and_places")] "crescent moon",
+	#[cfg(emojic_group = "animals_and_nature")] "cricket",
+	#[cfg(emojic_group = "activities")] "cricket game",
+	#[cfg(emojic_group = "animals_and_nature")] "crocodile",
+	#[cfg(emojic_group = "food_and_drink")] "croissant",
+	#[cfg(emojic_group = "symbols")] "cross mark",
+	#[cfg(emojic_group = "symbols")] "cross mark button",
+	#[cfg(emojic_group = "people_and_body")] "crossed fingers",
+	#[cfg(emojic_group = "people_and_body")] "crossed fingers: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "crossed fingers: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "crossed fingers: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "crossed fingers: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "crossed fingers: medium-light skin tone",
+	#[cfg(emojic_group = "flags")] "crossed flags",
+	#[cfg(emojic_group = "objects")] "crossed swords",
+	#[cfg(emojic_group = "objects")] "crown",
+	#[cfg(emojic_group = "objects")] "crutch",
+	#[cfg(emojic_group = "smileys_and_emotion")] "crying cat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "crying face",
+	#[cfg(emojic_group = "activities")] "crystal ball",
+	#[cfg(emojic_group = "food_and_drink")] "cucumber",
+	#[cfg(emojic_group = "food_and_drink")] "cup with straw",
+	#[cfg(emojic_group = "food_and_drink")] "cupcake",
+	#[cfg(emojic_group = "activities")] "curling stone",
+	#[cfg(emojic_group = "component")] "curly hair",
+	#[cfg(emojic_group = "symbols")] "curly loop",
+	#[cfg(emojic_group = "symbols")] "currency exchange",
+	#[cfg(emojic_group = "food_and_drink")] "curry rice",
+	#[cfg(emojic_group = "food_and_drink")] "custard",
+	#[cfg(emojic_group = "symbols")] "customs",
+	#[cfg(emojic_group = "food_and_drink")] "cut of meat",
+	#[cfg(emojic_group = "travel_and_places")] "cyclone",
+	#[cfg(emojic_group = "objects")] "dagger",
+	#[cfg(emojic_group = "food_and_drink")] "dango",
+	#[cfg(emojic_group = "component")] "dark skin tone",
+	#[cfg(emojic_group = "smileys_and_emotion")] "dashing away",
+	#[cfg(emojic_group = "people_and_body")] "deaf man",
+	#[cfg(emojic_group = "people_and_body")] "deaf man: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "deaf man: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "deaf man: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "deaf man: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "deaf man: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "deaf person",
+	#[cfg(emojic_group = "people_and_body")] "deaf person: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "deaf person: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "deaf person: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "deaf person: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "deaf person: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "deaf woman",
+	#[cfg(emojic_group = "people_and_body")] "deaf woman: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "deaf woman: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "deaf woman: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "deaf woman: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "deaf woman: medium-light skin tone",
+	#[cfg(emojic_group = "animals_and_nature")] "deciduous tree",
+	#[cfg(emojic_group = "animals_and_nature")] "deer",
+	#[cfg(emojic_group = "travel_and_places")] "delivery truck",
+	#[cfg(emojic_group = "travel_and_places")] "department store",
+	#[cfg(emojic_group = "travel_and_places")] "derelict house",
+	#[cfg(emojic_group = "travel_and_places")] "desert",
+	#[cfg(emojic_group = "travel_and_places")] "desert island",
+	#[cfg(emojic_group = "objects")] "desktop computer",
+	#[cfg(emojic_group = "people_and_body")] "detective",
+	#[cfg(emojic_group = "people_and_body")] "detective: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "detective: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "detective: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "detective: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "detective: medium-light skin tone",
+	#[cfg(emojic_group = "activities")] "diamond suit",
+	#[cfg(emojic_group = "symbols")] "diamond with a dot",
+	#[cfg(emojic_group = "symbols")] "dim button",
+	#[cfg(emojic_group = "smileys_and_emotion")] "disappointed face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "disguised face",
+	#[cfg(emojic_group = "symbols")] "divide",
+	#[cfg(emojic_group = "activities")] "diving mask",
+	#[cfg(emojic_group = "objects")] "diya lamp",
+	#[cfg(emojic_group = "smileys_and_emotion")] "dizzy",
+	#[cfg(emojic_group = "objects")] "dna",
+	#[cfg(emojic_group = "animals_and_nature")] "dodo",
+	#[cfg(emojic_group = "animals_and_nature")] "dog",
+	#[cfg(emojic_group = "animals_and_nature")] "dog face",
+	#[cfg(emojic_group = "objects")] "dollar banknote",
+	#[cfg(emojic_group = "animals_and_nature")] "dolphin",
+	#[cfg(emojic_group = "objects")] "door",
+	#[cfg(emojic_group = "smileys_and_emotion")] "dotted line face",
+	#[cfg(emojic_group = "symbols")] "dotted six-pointed star",
+	#[cfg(emojic_group = "symbols")] "double curly loop",
+	#[cfg(emojic_group = "symbols")] "double exclamation mark",
+	#[cfg(emojic_group = "food_and_drink")] "doughnut",
+	#[cfg(emojic_group = "animals_and_nature")] "dove",
+	#[cfg(emojic_group = "symbols")] "down arrow",
+	#[cfg(emojic_group = "symbols")] "down-left arrow",
+	#[cfg(emojic_group = "symbols")] "down-right arrow",
+	#[cfg(emojic_group = "smileys_and_emotion")] "downcast face with sweat",
+	#[cfg(emojic_group = "symbols")] "downwards button",
+	#[cfg(emojic_group = "animals_and_nature")] "dragon",
+	#[cfg(emojic_group = "animals_and_nature")] "dragon face",
+	#[cfg(emojic_group = "objects")] "dress",
+	#[cfg(emojic_group = "smileys_and_emotion")] "drooling face",
+	#[cfg(emojic_group = "objects")] "drop of blood",
+	#[cfg(emojic_group = "travel_and_places")] "droplet",
+	#[cfg(emojic_group = "objects")] "drum",
+	#[cfg(emojic_group = "animals_and_nature")] "duck",
+	#[cfg(emojic_group = "food_and_drink")] "dumpling",
+	#[cfg(emojic_group = "objects")] "dvd",
+	#[cfg(emojic_group = "objects")] "e-mail",
+	#[cfg(emojic_group = "animals_and_nature")] "eagle",
+	#[cfg(emojic_group = "people_and_body")] "ear",
+	#[cfg(emojic_group = "food_and_drink")] "ear of corn",
+	#[cfg(emojic_group = "people_and_body")] "ear with hearing aid",
+	#[cfg(emojic_group = "people_and_body")] "ear with hearing aid: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "ear with hearing aid: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "ear with hearing aid: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "ear with hearing aid: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "ear with hearing aid: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "ear: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "ear: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "ear: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "ear: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "ear: medium-light skin tone",
+	#[cfg(emojic_group = "food_and_drink")] "egg",
+	#[cfg(emojic_group = "food_and_drink")] "eggplant",
+	#[cfg(emojic_group = "travel_and_places")] "eight o’clock",
+	#[cfg(emojic_group = "symbols")] "eight-pointed star",
+	#[cfg(emojic_group = "symbols")] "eight-spoked asterisk",
+	#[cfg(emojic_group = "travel_and_places")] "eight-thirty",
+	#[cfg(emojic_group = "symbols")] "eject button",
+	#[cfg(emojic_group = "objects")] "electric plug",
+	#[cfg(emojic_group = "animals_and_nature")] "elephant",
+	#[cfg(emojic_group = "objects")] "elevator",
+	#[cfg(emojic_group = "travel_and_places")] "eleven o’clock",
+	#[cfg(emojic_group = "travel_and_places")] "eleven-thirty",
+	#[cfg(emojic_group = "people_and_body")] "elf",
+	#[cfg(emojic_group = "people_and_body")] "elf: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "elf: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "elf: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "elf: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "elf: medium-light skin tone",
+	#[cfg(emojic_group = "animals_and_nature")] "empty nest",
+	#[cfg(emojic_group = "objects")] "envelope",
+	#[cfg(emojic_group = "objects")] "envelope with arrow",
+	#[cfg(emojic_group = "objects")] "euro banknote",
+	#[cfg(emojic_group = "animals_and_nature")] "evergreen tree",
+	#[cfg(emojic_group = "animals_and_nature")] "ewe",
+	#[cfg(emojic_group = "symbols")] "exclamation question mark",
+	#[cfg(emojic_group = "smileys_and_emotion")] "exploding head",
+	#[cfg(emojic_group = "smileys_and_emotion")] "expressionless face",
+	#[cfg(emojic_group = "people_and_body")] "eye",
+	#[cfg(emojic_group = "smileys_and_emotion")] "eye in speech bubble",
+	#[cfg(emojic_group = "people_and_body")] "eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face blowing a kiss",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face exhaling",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face holding back tears",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face in clouds",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face savoring food",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face screaming in fear",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face vomiting",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with diagonal mouth",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with hand over mouth",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with head-bandage",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with medical mask",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with monocle",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with open eyes and hand over mouth",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with open mouth",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with peeking eye",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with raised eyebrow",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with rolling eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with spiral eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with steam from nose",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with symbols on mouth",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with tears of joy",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with thermometer",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face with tongue",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face without mouth",
+	#[cfg(emojic_group = "travel_and_places")] "factory",
+	#[cfg(emojic_group = "people_and_body")] "factory worker",
+	#[cfg(emojic_group = "people_and_body")] "factory worker: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "factory worker: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "factory worker: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "factory worker: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "factory worker: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "fairy",
+	#[cfg(emojic_group = "people_and_body")] "fairy: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "fairy: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "fairy: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "fairy: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "fairy: medium-light skin tone",
+	#[cfg(emojic_group = "food_and_drink")] "falafel",
+	#[cfg(emojic_group = "animals_and_nature")] "fallen leaf",
+	#[cfg(emojic_group = "people_and_body")] "family",
+	#[cfg(emojic_group = "people_and_body")] "family: man, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: man, boy, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: man, girl",
+	#[cfg(emojic_group = "people_and_body")] "family: man, girl, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: man, girl, girl",
+	#[cfg(emojic_group = "people_and_body")] "family: man, man, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: man, man, boy, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: man, man, girl",
+	#[cfg(emojic_group = "people_and_body")] "family: man, man, girl, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: man, man, girl, girl",
+	#[cfg(emojic_group = "people_and_body")] "family: man, woman, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: man, woman, boy, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: man, woman, girl",
+	#[cfg(emojic_group = "people_and_body")] "family: man, woman, girl, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: man, woman, girl, girl",
+	#[cfg(emojic_group = "people_and_body")] "family: woman, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: woman, boy, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: woman, girl",
+	#[cfg(emojic_group = "people_and_body")] "family: woman, girl, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: woman, girl, girl",
+	#[cfg(emojic_group = "people_and_body")] "family: woman, woman, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: woman, woman, boy, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: woman, woman, girl",
+	#[cfg(emojic_group = "people_and_body")] "family: woman, woman, girl, boy",
+	#[cfg(emojic_group = "people_and_body")] "family: woman, woman, girl, girl",
+	#[cfg(emojic_group = "people_and_body")] "farmer",
+	#[cfg(emojic_group = "people_and_body")] "farmer: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "farmer: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "farmer: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "farmer: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "farmer: medium-light skin tone",
+	#[cfg(emojic_group = "symbols")] "fast down button",
+	#[cfg(emojic_group = "symbols")] "fast reverse button",
+	#[cfg(emojic_group = "symbols")] "fast up button",
+	#[cfg(emojic_group = "symbols")] "fast-forward button",
+	#[cfg(emojic_group = "objects")] "fax machine",
+	#[cfg(emojic_group = "smileys_and_emotion")] "fearful face",
+	#[cfg(emojic_group = "animals_and_nature")] "feather",
+	#[cfg(emojic_group = "symbols")] "female sign",
+	#[cfg(emojic_group = "travel_and_places")] "ferris wheel",
+	#[cfg(emojic_group = "travel_and_places")] "ferry",
+	#[cfg(emojic_group = "activities")] "field hockey",
+	#[cfg(emojic_group = "objects")] "file cabinet",
+	#[cfg(emojic_group = "objects")] "file folder",
+	#[cfg(emojic_group = "objects")] "film frames",
+	#[cfg(emojic_group = "objects")] "film projector",
+	#[cfg(emojic_group = "travel_and_places")] "fire",
+	#[cfg(emojic_group = "travel_and_places")] "fire engine",
+	#[cfg(emojic_group = "objects")] "fire extinguisher",
+	#[cfg(emojic_group = "activities")] "firecracker",
+	#[cfg(emojic_group = "people_and_body")] "firefighter",
+	#[cfg(emojic_group = "people_and_body")] "firefighter: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "firefighter: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "firefighter: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "firefighter: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "firefighter: medium-light skin tone",
+	#[cfg(emojic_group = "activities")] "fireworks",
+	#[cfg(emojic_group = "travel_and_places")] "first quarter moon",
+	#[cfg(emojic_group = "travel_and_places")] "first quarter moon face",
+	#[cfg(emojic_group = "animals_and_nature")] "fish",
+	#[cfg(emojic_group = "food_and_drink")] "fish cake with swirl",
+	#[cfg(emojic_group = "activities")] "fishing pole",
+	#[cfg(emojic_group = "travel_and_places")] "five o’clock",
+	#[cfg(emojic_group = "travel_and_places")] "five-thirty",
+	#[cfg(emojic_group = "activities")] "flag in hole",
+	#[cfg(emojic_group = "flags")] "flag: Afghanistan",
+	#[cfg(emojic_group = "flags")] "flag: Albania",
+	#[cfg(emojic_group = "flags")] "flag: Algeria",
+	#[cfg(emojic_group = "flags")] "flag: American Samoa",
+	#[cfg(emojic_group = "flags")] "flag: Andorra",
+	#[cfg(emojic_group = "flags")] "flag: Angola",
+	#[cfg(emojic_group = "flags")] "flag: Anguilla",
+	#[cfg(emojic_group = "flags")] "flag: Antarctica",
+	#[cfg(emojic_group = "flags")] "flag: Antigua & Barbuda",
+	#[cfg(emojic_group = "flags")] "flag: Argentina",
+	#[cfg(emojic_group = "flags")] "flag: Armenia",
+	#[cfg(emojic_group = "flags")] "flag: Aruba",
+	#[cfg(emojic_group = "flags")] "flag: Ascension Island",
+	#[cfg(emojic_group = "flags")] "flag: Australia",
+	#[cfg(emojic_group = "flags")] "flag: Austria",
+	#[cfg(emojic_group = "flags")] "flag: Azerbaijan",
+	#[cfg(emojic_group = "flags")] "flag: Bahamas",
+	#[cfg(emojic_group = "flags")] "flag: Bahrain",
+	#[cfg(emojic_group = "flags")] "flag: Bangladesh",
+	#[cfg(emojic_group = "flags")] "flag: Barbados",
+	#[cfg(emojic_group = "flags")] "flag: Belarus",
+	#[cfg(emojic_group = "flags")] "flag: Belgium",
+	#[cfg(emojic_group = "flags")] "flag: Belize",
+	#[cfg(emojic_group = "flags")] "flag: Benin",
+	#[cfg(emojic_group = "flags")] "flag: Bermuda",
+	#[cfg(emojic_group = "flags")] "flag: Bhutan",
+	#[cfg(emojic_group = "flags")] "flag: Bolivia",
+	#[cfg(emojic_group = "flags")] "flag: Bosnia & Herzegovina",
+	#[cfg(emojic_group = "flags")] "flag: Botswana",
+	#[cfg(emojic_group = "flags")] "flag: Bouvet Island",
+	#[cfg(emojic_group = "flags")] "flag: Brazil",
+	#[cfg(emojic_group = "flags")] "flag: British Indian Ocean Territory",
+	#[cfg(emojic_group = "flags")] "flag: British Virgin Islands",
+	#[cfg(emojic_group = "flags")] "flag: Brunei",
+	#[cfg(emojic_group = "flags")] "flag: Bulgaria",
+	#[cfg(emojic_group = "flags")] "flag: Burkina Faso",
+	#[cfg(emojic_group = "flags")] "flag: Burundi",
+	#[cfg(emojic_group = "flags")] "flag: Cambodia",
+	#[cfg(emojic_group = "flags")] "flag: Cameroon",
+	#[cfg(emojic_group = "flags")] "flag: Canada",
+	#[cfg(emojic_group = "flags")] "flag: Canary Islands",
+	#[cfg(emojic_group = "flags")] "flag: Cape Verde",
+	#[cfg(emojic_group = "flags")] "flag: Caribbean Netherlands",
+	#[cfg(emojic_group = "flags")] "flag: Cayman Islands",
+	#[cfg(emojic_group = "flags")] "flag: Central African Republic",
+	#[cfg(emojic_group = "flags")] "flag: Ceuta & Melilla",
+	#[cfg(emojic_group = "flags")] "flag: Chad",
+	#[cfg(emojic_group = "flags")] "flag: Chile",
+	#[cfg(emojic_group = "flags")] "flag: China",
+	#[cfg(emojic_group = "flags")] "flag: Christmas Island",
+	#[cfg(emojic_group = "flags")] "flag: Clipperton Island",
+	#[cfg(emojic_group = "flags")] "flag: Cocos (Keeling) Islands",
+	#[cfg(emojic_group = "flags")] "flag: Colombia",
+	#[cfg(emojic_group = "flags")] "flag: Comoros",
+	#[cfg(emojic_group = "flags")] "flag: Congo - Brazzaville",
+	#[cfg(emojic_group = "flags")] "flag: Congo - Kinshasa",
+	#[cfg(emojic_group = "flags")] "flag: Cook Islands",
+	#[cfg(emojic_group = "flags")] "flag: Costa Rica",
+	#[cfg(emojic_group = "flags")] "flag: Croatia",
+	#[cfg(emojic_group = "flags")] "flag: Cuba",
+	#[cfg(emojic_group = "flags")] "flag: Curaçao",
+	#[cfg(emojic_group = "flags")] "flag: Cyprus",
+	#[cfg(emojic_group = "flags")] "flag: Czechia",
+	#[cfg(emojic_group = "flags")] "flag: Côte d’Ivoire",
+	#[cfg(emojic_group = "flags")] "flag: Denmark",
+	#[cfg(emojic_group = "flags")] "flag: Diego Garcia",
+	#[cfg(emojic_group = "flags")] "flag: Djibouti",
+	#[cfg(emojic_group = "flags")] "flag: Dominica",
+	#[cfg(emojic_group = "flags")] "flag: Dominican Republic",
+	#[cfg(emojic_group = "flags")] "flag: Ecuador",
+	#[cfg(emojic_group = "flags")] "flag: Egypt",
+	#[cfg(emojic_group = "flags")] "flag: El Salvador",
+	#[cfg(emojic_group = "flags")] "flag: England",
+	#[cfg(emojic_group = "flags")] "flag: Equatorial Guinea",
+	#[cfg(emojic_group = "flags")] "flag: Eritrea",
+	#[cfg(emojic_group = "flags")] "flag: Estonia",
+	#[cfg(emojic_group = "flags")] "flag: Eswatini",
+	#[cfg(emojic_group = "flags")] "flag: Ethiopia",
+	#[cfg(emojic_group = "flags")] "flag: European Union",
+	#[cfg(emojic_group = "flags")] "flag: Falkland Islands",
+	#[cfg(emojic_group = "flags")] "flag: Faroe Islands",
+	#[cfg(emojic_group = "flags")] "flag: Fiji",
+	#[cfg(emojic_group = "flags")] "flag: Finland",
+	#[cfg(emojic_group = "flags")] "flag: France",
+	#[cfg(emojic_group = "flags")] "flag: French Guiana",
+	#[cfg(emojic_group = "flags")] "flag: French Polynesia",
+	#[cfg(emojic_group = "flags")] "flag: French Southern Territories",
+	#[cfg(emojic_group = "flags")] "flag: Gabon",
+	#[cfg(emojic_group = "flags")] "flag: Gambia",
+	#[cfg(emojic_group = "flags")] "flag: Georgia",
+	#[cfg(emojic_group = "flags")] "flag: Germany",
+	#[cfg(emojic_group = "flags")] "flag: Ghana",
+	#[cfg(emojic_group = "flags")] "flag: Gibraltar",
+	#[cfg(emojic_group = "flags")] "flag: Greece",
+	#[cfg(emojic_group = "flags")] "flag: Greenland",
+	#[cfg(emojic_group = "flags")] "flag: Grenada",
+	#[cfg(emojic_group = "flags")] "flag: Guadeloupe",
+	#[cfg(emojic_group = "flags")] "flag: Guam",
+	#[cfg(emojic_group = "flags")] "flag: Guatemala",
+	#[cfg(emojic_group = "flags")] "flag: Guernsey",
+	#[cfg(emojic_group = "flags")] "flag: Guinea",
+	#[cfg(emojic_group = "flags")] "flag: Guinea-Bissau",
+	#[cfg(emojic_group = "flags")] "flag: Guyana",
+	#[cfg(emojic_group = "flags")] "flag: Haiti",
+	#[cfg(emojic_group = "flags")] "flag: Heard & McDonald Islands",
+	#[cfg(emojic_group = "flags")] "flag: Honduras",
+	#[cfg(emojic_group = "flags")] "flag: Hong Kong SAR China",
+	#[cfg(emojic_group = "flags")] "flag: Hungary",
+	#[cfg(emojic_group = "flags")] "flag: Iceland",
+	#[cfg(emojic_group = "flags")] "flag: India",
+	#[cfg(emojic_group = "flags")] "flag: Indonesia",
+	#[cfg(emojic_group = "flags")] "flag: Iran",
+	#[cfg(emojic_group = "flags")] "flag: Iraq",
+	#[cfg(emojic_group = "flags")] "flag: Ireland",
+	#[cfg(emojic_group = "flags")] "flag: Isle of Man",
+	#[cfg(emojic_group = "flags")] "flag: Israel",
+	#[cfg(emojic_group = "flags")] "flag: Italy",
+	#[cfg(emojic_group = "flags")] "flag: Jamaica",
+	#[cfg(emojic_group = "flags")] "flag: Japan",
+	#[cfg(emojic_group = "flags")] "flag: Jersey",
+	#[cfg(emojic_group = "flags")] "flag: Jordan",
+	#[cfg(emojic_group = "flags")] "flag: Kazakhstan",
+	#[cfg(emojic_group = "flags")] "flag: Kenya",
+	#[cfg(emojic_group = "flags")] "flag: Kiribati",
+	#[cfg(emojic_group = "flags")] "flag: Kosovo",
+	#[cfg(emojic_group = "flags")] "flag: Kuwait",
+	#[cfg(emojic_group = "flags")] "flag: Kyrgyzstan",
+	#[cfg(emojic_group = "flags")] "flag: Laos",
+	#[cfg(emojic_group = "flags")] "flag: Latvia",
+	#[cfg(emojic_group = "flags")] "flag: Lebanon",
+	#[cfg(emojic_group = "flags")] "flag: Lesotho",
+	#[cfg(emojic_group = "flags")] "flag: Liberia",
+	#[cfg(emojic_group = "flags")] "flag: Libya",
+	#[cfg(emojic_group = "flags")] "flag: Liechtenstein",
+	#[cfg(emojic_group = "flags")] "flag: Lithuania",
+	#[cfg(emojic_group = "flags")] "flag: Luxembourg",
+	#[cfg(emojic_group = "flags")] "flag: Macao SAR China",
+	#[cfg(emojic_group = "flags")] "flag: Madagascar",
+	#[cfg(emojic_group = "flags")] "flag: Malawi",
+	#[cfg(emojic_group = "flags")] "flag: Malaysia",
+	#[cfg(emojic_group = "flags")] "flag: Maldives",
+	#[cfg(emojic_group = "flags")] "flag: Mali",
+	#[cfg(emojic_group = "flags")] "flag: Malta",
+	#[cfg(emojic_group = "flags")] "flag: Marshall Islands",
+	#[cfg(emojic_group = "flags")] "flag: Martinique",
+	#[cfg(emojic_group = "flags")] "flag: Mauritania",
+	#[cfg(emojic_group = "flags")] "flag: Mauritius",
+	#[cfg(emojic_group = "flags")] "flag: Mayotte",
+	#[cfg(emojic_group = "flags")] "flag: Mexico",
+	#[cfg(emojic_group = "flags")] "flag: Micronesia",
+	#[cfg(emojic_group = "flags")] "flag: Moldova",
+	#[cfg(emojic_group = "flags")] "flag: Monaco",
+	#[cfg(emojic_group = "flags")] "flag: Mongolia",
+	#[cfg(emojic_group = "flags")] "flag: Montenegro",
+	#[cfg(emojic_group = "flags")] "flag: Montserrat",
+	#[cfg(emojic_group = "flags")] "flag: Morocco",
+	#[cfg(emojic_group = "flags")] "flag: Mozambique",
+	#[cfg(emojic_group = "flags")] "flag: Myanmar (Burma)",
+	#[cfg(emojic_group = "flags")] "flag: Namibia",
+	#[cfg(emojic_group = "flags")] "flag: Nauru",
+	#[cfg(emojic_group = "flags")] "flag: Nepal",
+	#[cfg(emojic_group = "flags")] "flag: Netherlands",
+	#[cfg(emojic_group = "flags")] "flag: New Caledonia",
+	#[cfg(emojic_group = "flags")] "flag: New Zealand",
+	#[cfg(emojic_group = "flags")] "flag: Nicaragua",
+	#[cfg(emojic_group = "flags")] "flag: Niger",
+	#[cfg(emojic_group = "flags")] "flag: Nigeria",
+	#[cfg(emojic_group = "flags")] "flag: Niue",
+	#[cfg(emojic_group = "flags")] "flag: Norfolk Island",
+	#[cfg(emojic_group = "flags")] "flag: North Korea",
+	#[cfg(emojic_group = "flags")] "flag: North Macedonia",
+	#[cfg(emojic_group = "flags")] "flag: Northern Mariana Islands",
+	#[cfg(emojic_group = "flags")] "flag: Norway",
+	#[cfg(emojic_group = "flags")] "flag: Oman",
+	#[cfg(emojic_group = "flags")] "flag: Pakistan",
+	#[cfg(emojic_group = "flags")] "flag: Palau",
+	#[cfg(emojic_group = "flags")] "flag: Palestinian Territories",
+	#[cfg(emojic_group = "flags")] "flag: Panama",
+	#[cfg(emojic_group = "flags")] "flag: Papua New Guinea",
+	#[cfg(emojic_group = "flags")] "flag: Paraguay",
+	#[cfg(emojic_group = "flags")] "flag: Peru",
+	#[cfg(emojic_group = "flags")] "flag: Philippines",
+	#[cfg(emojic_group = "flags")] "flag: Pitcairn Islands",
+	#[cfg(emojic_group = "flags")] "flag: Poland",
+	#[cfg(emojic_group = "flags")] "flag: Portugal",
+	#[cfg(emojic_group = "flags")] "flag: Puerto Rico",
+	#[cfg(emojic_group = "flags")] "flag: Qatar",
+	#[cfg(emojic_group = "flags")] "flag: Romania",
+	#[cfg(emojic_group = "flags")] "flag: Russia",
+	#[cfg(emojic_group = "flags")] "flag: Rwanda",
+	#[cfg(emojic_group = "flags")] "flag: Réunion",
+	#[cfg(emojic_group = "flags")] "flag: Samoa",
+	#[cfg(emojic_group = "flags")] "flag: San Marino",
+	#[cfg(emojic_group = "flags")] "flag: Saudi Arabia",
+	#[cfg(emojic_group = "flags")] "flag: Scotland",
+	#[cfg(emojic_group = "flags")] "flag: Senegal",
+	#[cfg(emojic_group = "flags")] "flag: Serbia",
+	#[cfg(emojic_group = "flags")] "flag: Seychelles",
+	#[cfg(emojic_group = "flags")] "flag: Sierra Leone",
+	#[cfg(emojic_group = "flags")] "flag: Singapore",
+	#[cfg(emojic_group = "flags")] "flag: Sint Maarten",
+	#[cfg(emojic_group = "flags")] "flag: Slovakia",
+	#[cfg(emojic_group = "flags")] "flag: Slovenia",
+	#[cfg(emojic_group = "flags")] "flag: Solomon Islands",
+	#[cfg(emojic_group = "flags")] "flag: Somalia",
+	#[cfg(emojic_group = "flags")] "flag: South Africa",
+	#[cfg(emojic_group = "flags")] "flag: South Georgia & South Sandwich Islands",
+	#[cfg(emojic_group = "flags")] "flag: South Korea",
+	#[cfg(emojic_group = "flags")] "flag: South Sudan",
+	#[cfg(emojic_group = "flags")] "flag: Spain",
+	#[cfg(emojic_group = "flags")] "flag: Sri Lanka",
+	#[cfg(emojic_group = "flags")] "flag: St. Barthélemy",
+	#[cfg(emojic_group = "flags")] "flag: St. Helena",
+	#[cfg(emojic_group = "flags")] "flag: St. Kitts & Nevis",
+	#[cfg(emojic_group = "flags")] "flag: St. Lucia",
+	#[cfg(emojic_group = "flags")] "flag: St. Martin",
+	#[cfg(emojic_group = "flags")] "flag: St. Pierre & Miquelon",
+	#[cfg(emojic_group = "flags")] "flag: St. Vincent & Grenadines",
+	#[cfg(emojic_group = "flags")] "flag: Sudan",
+	#[cfg(emojic_group = "flags")] "flag: Suriname",
+	#[cfg(emojic_group = "flags")] "flag: Svalbard & Jan Mayen",
+	#[cfg(emojic_group = "flags")] "flag: Sweden",
+	#[cfg(emojic_group = "flags")] "flag: Switzerland",
+	#[cfg(emojic_group = "flags")] "flag: Syria",
+	#[cfg(emojic_group = "flags")] "flag: São Tomé & Príncipe",
+	#[cfg(emojic_group = "flags")] "flag: Taiwan",
+	#[cfg(emojic_group = "flags")] "flag: Tajikistan",
+	#[cfg(emojic_group = "flags")] "flag: Tanzania",
+	#[cfg(emojic_group = "flags")] "flag: Thailand",
+	#[cfg(emojic_group = "flags")] "flag: Timor-Leste",
+	#[cfg(emojic_group = "flags")] "flag: Togo",
+	#[cfg(emojic_group = "flags")] "flag: Tokelau",
+	#[cfg(emojic_group = "flags")] "flag: Tonga",
+	#[cfg(emojic_group = "flags")] "flag: Trinidad & Tobago",
+	#[cfg(emojic_group = "flags")] "flag: Tristan da Cunha",
+	#[cfg(emojic_group = "flags")] "flag: Tunisia",
+	#[cfg(emojic_group = "flags")] "flag: Turkey",
+	#[cfg(emojic_group = "flags")] "flag: Turkmenistan",
+	#[cfg(emojic_group = "flags")] "flag: Turks & Caicos Islands",
+	#[cfg(emojic_group = "flags")] "flag: Tuvalu",
+	#[cfg(emojic_group = "flags")] "flag: U.S. Outlying Islands",
+	#[cfg(emojic_group = "flags")] "flag: U.S. Virgin Islands",
+	#[cfg(emojic_group = "flags")] "flag: Uganda",
+	#[cfg(emojic_group = "flags")] "flag: Ukraine",
+	#[cfg(emojic_group = "flags")] "flag: United Arab Emirates",
+	#[cfg(emojic_group = "flags")] "flag: United Kingdom",
+	#[cfg(emojic_group = "flags")] "flag: United Nations",
+	#[cfg(emojic_group = "flags")] "flag: United States",
+	#[cfg(emojic_group = "flags")] "flag: Uruguay",
+	#[cfg(emojic_group = "flags")] "flag: Uzbekistan",
+	#[cfg(emojic_group = "flags")] "flag: Vanuatu",
+	#[cfg(emojic_group = "flags")] "flag: Vatican City",
+	#[cfg(emojic_group = "flags")] "flag: Venezuela",
+	#[cfg(emojic_group = "flags")] "flag: Vietnam",
+	#[cfg(emojic_group = "flags")] "flag: Wales",
+	#[cfg(emojic_group = "flags")] "flag: Wallis & Futuna",
+	#[cfg(emojic_group = "flags")] "flag: Western Sahara",
+	#[cfg(emojic_group = "flags")] "flag: Yemen",
+	#[cfg(emojic_group = "flags")] "flag: Zambia",
+	#[cfg(emojic_group = "flags")] "flag: Zimbabwe",
+	#[cfg(emojic_group = "flags")] "flag: Åland Islands",
+	#[cfg(emojic_group = "animals_and_nature")] "flamingo",
+	#[cfg(emojic_group = "objects")] "flashlight",
+	#[cfg(emojic_group = "objects")] "flat shoe",
+	#[cfg(emojic_group = "food_and_drink")] "flatbread",
+	#[cfg(emojic_group = "symbols")] "fleur-de-lis",
+	#[cfg(emojic_group = "people_and_body")] "flexed biceps",
+	#[cfg(emojic_group = "people_and_body")] "flexed biceps: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "flexed biceps: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "flexed biceps: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "flexed biceps: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "flexed biceps: medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "floppy disk",
+	#[cfg(emojic_group = "activities")] "flower playing cards",
+	#[cfg(emojic_group = "smileys_and_emotion")] "flushed face",
+	#[cfg(emojic_group = "animals_and_nature")] "fly",
+	#[cfg(emojic_group = "activities")] "flying disc",
+	#[cfg(emojic_group = "travel_and_places")] "flying saucer",
+	#[cfg(emojic_group = "travel_and_places")] "fog",
+	#[cfg(emojic_group = "travel_and_places")] "foggy",
+	#[cfg(emojic_group = "people_and_body")] "folded hands",
+	#[cfg(emojic_group = "people_and_body")] "folded hands: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "folded hands: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "folded hands: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "folded hands: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "folded hands: medium-light skin tone",
+	#[cfg(emojic_group = "food_and_drink")] "fondue",
+	#[cfg(emojic_group = "people_and_body")] "foot",
+	#[cfg(emojic_group = "people_and_body")] "foot: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "foot: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "foot: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "foot: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "foot: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "footprints",
+	#[cfg(emojic_group = "food_and_drink")] "fork and knife",
+	#[cfg(emojic_group = "food_and_drink")] "fork and knife with plate",
+	#[cfg(emojic_group = "food_and_drink")] "fortune cookie",
+	#[cfg(emojic_group = "travel_and_places")] "fountain",
+	#[cfg(emojic_group = "objects")] "fountain pen",
+	#[cfg(emojic_group = "animals_and_nature")] "four leaf clover",
+	#[cfg(emojic_group = "travel_and_places")] "four o’clock",
+	#[cfg(emojic_group = "travel_and_places")] "four-thirty",
+	#[cfg(emojic_group = "animals_and_nature")] "fox",
+	#[cfg(emojic_group = "activities")] "framed picture",
+	#[cfg(emojic_group = "food_and_drink")] "french fries",
+	#[cfg(emojic_group = "food_and_drink")] "fried shrimp",
+	#[cfg(emojic_group = "animals_and_nature")] "frog",
+	#[cfg(emojic_group = "animals_and_nature")] "front-facing baby chick",
+	#[cfg(emojic_group = "smileys_and_emotion")] "frowning face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "frowning face with open mouth",
+	#[cfg(emojic_group = "travel_and_places")] "fuel pump",
+	#[cfg(emojic_group = "travel_and_places")] "full moon",
+	#[cfg(emojic_group = "travel_and_places")] "full moon face",
+	#[cfg(emojic_group = "objects")] "funeral urn",
+	#[cfg(emojic_group = "activities")] "game die",
+	#[cfg(emojic_group = "food_and_drink")] "garlic",
+	#[cfg(emojic_group = "objects")] "gear",
+	#[cfg(emojic_group = "objects")] "gem stone",
+	#[cfg(emojic_group = "people_and_body")] "genie",
+	#[cfg(emojic_group = "smileys_and_emotion")] "ghost",
+	#[cfg(emojic_group = "animals_and_nature")] "giraffe",
+	#[cfg(emojic_group = "people_and_body")] "girl",
+	#[cfg(emojic_group = "people_and_body")] "girl: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "girl: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "girl: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "girl: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "girl: medium-light skin tone",
+	#[cfg(emojic_group = "food_and_drink")] "glass of milk",
+	#[cfg(emojic_group = "objects")] "glasses",
+	#[cfg(emojic_group = "travel_and_places")] "globe showing Americas",
+	#[cfg(emojic_group = "travel_and_places")] "globe showing Asia-Australia",
+	#[cfg(emojic_group = "travel_and_places")] "globe showing Europe-Africa",
+	#[cfg(emojic_group = "travel_and_places")] "globe with meridians",
+	#[cfg(emojic_group = "objects")] "gloves",
+	#[cfg(emojic_group = "travel_and_places")] "glowing star",
+	#[cfg(emojic_group = "activities")] "goal net",
+	#[cfg(emojic_group = "animals_and_nature")] "goat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "goblin",
+	#[cfg(emojic_group = "objects")] "goggles",
+	#[cfg(emojic_group = "animals_and_nature")] "gorilla",
+	#[cfg(emojic_group = "objects")] "graduation cap",
+	#[cfg(emojic_group = "food_and_drink")] "grapes",
+	#[cfg(emojic_group = "food_and_drink")] "green apple",
+	#[cfg(emojic_group = "objects")] "green book",
+	#[cfg(emojic_group = "symbols")] "green circle",
+	#[cfg(emojic_group = "smileys_and_emotion")] "green heart",
+	#[cfg(emojic_group = "food_and_drink")] "green salad",
+	#[cfg(emojic_group = "symbols")] "green square",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grimacing face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning cat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning cat with smiling eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning face with big eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning face with smiling eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning face with sweat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning squinting face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "growing heart",
+	#[cfg(emojic_group = "people_and_body")] "guard",
+	#[cfg(emojic_group = "people_and_body")] "guard: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "guard: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "guard: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "guard: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "guard: medium-light skin tone",
+	#[cfg(emojic_group = "animals_and_nature")] "guide dog",
+	#[cfg(emojic_group = "objects")] "guitar",
+	#[cfg(emojic_group = "food_and_drink")] "hamburger",
+	#[cfg(emojic_group = "objects")] "hammer",
+	#[cfg(emojic_group = "objects")] "hammer and pick",
+	#[cfg(emojic_group = "objects")] "hammer and wrench",
+	#[cfg(emojic_group = "objects")] "hamsa",
+	#[cfg(emojic_group = "animals_and_nature")] "hamster",
+	#[cfg(emojic_group = "people_and_body")] "hand with fingers splayed",
+	#[cfg(emojic_group = "people_and_body")] "hand with fingers splayed: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "hand with fingers splayed: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "hand with fingers splayed: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "hand with fingers splayed: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "hand with fingers splayed: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "hand with index finger and thumb crossed",
+	#[cfg(emojic_group = "people_and_body")] "hand with index finger and thumb crossed: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "hand with index finger and thumb crossed: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "hand with index finger and thumb crossed: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "hand with index finger and thumb crossed: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "hand with index finger and thumb crossed: medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "handbag",
+	#[cfg(emojic_group = "people_and_body")] "handshake",
+	#[cfg(emojic_group = "people_and_body")] "handshake: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: dark skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: light skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium-dark skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium-dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium-dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium-dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium-light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium-light skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium-light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "handshake: medium-light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "animals_and_nature")] "hatching chick",
+	#[cfg(emojic_group = "objects")] "headphone",
+	#[cfg(emojic_group = "objects")] "headstone",
+	#[cfg(emojic_group = "people_and_body")] "health worker",
+	#[cfg(emojic_group = "people_and_body")] "health worker: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "health worker: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "health worker: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "health worker: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "health worker: medium-light skin tone",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hear-no-evil monkey",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heart decoration",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heart exclamation",
+	#[cfg(emojic_group = "people_and_body")] "heart hands",
+	#[cfg(emojic_group = "people_and_body")] "heart hands: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "heart hands: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "heart hands: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "heart hands: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "heart hands: medium-light skin tone",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heart on fire",
+	#[cfg(emojic_group = "activities")] "heart suit",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heart with arrow",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heart with ribbon",
+	#[cfg(emojic_group = "symbols")] "heavy dollar sign",
+	#[cfg(emojic_group = "symbols")] "heavy equals sign",
+	#[cfg(emojic_group = "animals_and_nature")] "hedgehog",
+	#[cfg(emojic_group = "travel_and_places")] "helicopter",
+	#[cfg(emojic_group = "animals_and_nature")] "herb",
+	#[cfg(emojic_group = "animals_and_nature")] "hibiscus",
+	#[cfg(emojic_group = "travel_and_places")] "high voltage",
+	#[cfg(emojic_group = "objects")] "high-heeled shoe",
+	#[cfg(emojic_group = "travel_and_places")] "high-speed train",
+	#[cfg(emojic_group = "objects")] "hiking boot",
+	#[cfg(emojic_group = "travel_and_places")] "hindu temple",
+	#[cfg(emojic_group = "animals_and_nature")] "hippopotamus",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hole",
+	#[cfg(emojic_group = "symbols")] "hollow red circle",
+	#[cfg(emojic_group = "food_and_drink")] "honey pot",
+	#[cfg(emojic_group = "animals_and_nature")] "honeybee",
+	#[cfg(emojic_group = "objects")] "hook",
+	#[cfg(emojic_group = "travel_and_places")] "horizontal traffic light",
+	#[cfg(emojic_group = "animals_and_nature")] "horse",
+	#[cfg(emojic_group = "animals_and_nature")] "horse face",
+	#[cfg(emojic_group = "people_and_body")] "horse racing",
+	#[cfg(emojic_group = "people_and_body")] "horse racing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "horse racing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "horse racing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "horse racing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "horse racing: medium-light skin tone",
+	#[cfg(emojic_group = "travel_and_places")] "hospital",
+	#[cfg(emojic_group = "food_and_drink")] "hot beverage",
+	#[cfg(emojic_group = "food_and_drink")] "hot dog",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hot face",
+	#[cfg(emojic_group = "food_and_drink")] "hot pepper",
+	#[cfg(emojic_group = "travel_and_places")] "hot springs",
+	#[cfg(emojic_group = "travel_and_places")] "hotel",
+	#[cfg(emojic_group = "travel_and_places")] "hourglass done",
+	#[cfg(emojic_group = "travel_and_places")] "hourglass not done",
+	#[cfg(emojic_group = "travel_and_places")] "house",
+	#[cfg(emojic_group = "travel_and_places")] "house with garden",
+	#[cfg(emojic_group = "travel_and_places")] "houses",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hugging face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hundred points",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hushed face",
+	#[cfg(emojic_group = "travel_and_places")] "hut",
+	#[cfg(emojic_group = "food_and_drink")] "ice",
+	#[cfg(emojic_group = "food_and_drink")] "ice cream",
+	#[cfg(emojic_group = "activities")] "ice hockey",
+	#[cfg(emojic_group = "activities")] "ice skate",
+	#[cfg(emojic_group = "objects")] "identification card",
+	#[cfg(emojic_group = "objects")] "inbox tray",
+	#[cfg(emojic_group = "objects")] "incoming envelope",
+	#[cfg(emojic_group = "people_and_body")] "index pointing at the viewer",
+	#[cfg(emojic_group = "people_and_body")] "index pointing at the viewer: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "index pointing at the viewer: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "index pointing at the viewer: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "index pointing at the viewer: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "index pointing at the viewer: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "index pointing up",
+	#[cfg(emojic_group = "people_and_body")] "index pointing up: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "index pointing up: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "index pointing up: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "index pointing up: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "index pointing up: medium-light skin tone",
+	#[cfg(emojic_group = "symbols")] "infinity",
+	#[cfg(emojic_group = "symbols")] "information",
+	#[cfg(emojic_group = "symbols")] "input latin letters",
+	#[cfg(emojic_group = "symbols")] "input latin lowercase",
+	#[cfg(emojic_group = "symbols")] "input latin uppercase",
+	#[cfg(emojic_group = "symbols")] "input numbers",
+	#[cfg(emojic_group = "symbols")] "input symbols",
+	#[cfg(emojic_group = "activities")] "jack-o-lantern",
+	#[cfg(emojic_group = "food_and_drink")] "jar",
+	#[cfg(emojic_group = "objects")] "jeans",
+	#[cfg(emojic_group = "activities")] "joker",
+	#[cfg(emojic_group = "activities")] "joystick",
+	#[cfg(emojic_group = "people_and_body")] "judge",
+	#[cfg(emojic_group = "people_and_body")] "judge: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "judge: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "judge: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "judge: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "judge: medium-light skin tone",
+	#[cfg(emojic_group = "travel_and_places")] "kaaba",
+	#[cfg(emojic_group = "animals_and_nature")] "kangaroo",
+	#[cfg(emojic_group = "objects")] "key",
+	#[cfg(emojic_group = "objects")] "keyboard",
+	#[cfg(emojic_group = "symbols")] "keycap: #",
+	#[cfg(emojic_group = "symbols")] "keycap: *",
+	#[cfg(emojic_group = "symbols")] "keycap: 0",
+	#[cfg(emojic_group = "symbols")] "keycap: 1",
+	#[cfg(emojic_group = "symbols")] "keycap: 10",
+	#[cfg(emojic_group = "symbols")] "keycap: 2",
+	#[cfg(emojic_group = "symbols")] "keycap: 3",
+	#[cfg(emojic_group = "symbols")] "keycap: 4",
+	#[cfg(emojic_group = "symbols")] "keycap: 5",
+	#[cfg(emojic_group = "symbols")] "keycap: 6",
+	#[cfg(emojic_group = "symbols")] "keycap: 7",
+	#[cfg(emojic_group = "symbols")] "keycap: 8",
+	#[cfg(emojic_group = "symbols")] "keycap: 9",
+	#[cfg(emojic_group = "travel_and_places")] "kick scooter",
+	#[cfg(emojic_group = "objects")] "kimono",
+	#[cfg(emojic_group = "people_and_body")] "kiss",
+	#[cfg(emojic_group = "smileys_and_emotion")] "kiss mark",
+	#[cfg(emojic_group = "people_and_body")] "kiss: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, dark skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, light skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium-dark skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium-dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium-dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium-dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium-light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium-light skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium-light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: man, man, medium-light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, dark skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, light skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, medium skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, medium skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, medium skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, medium skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, medium-dark skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, medium-dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, medium-dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, medium-dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, medium-light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, medium-light skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, medium-light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: person, person, medium-light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, dark skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, light skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium-dark skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium-dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium-dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium-dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium-light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium-light skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium-light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, man, medium-light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, dark skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, light skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium-dark skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium-dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium-dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium-dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium-light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium-light skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium-light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "kiss: woman, woman, medium-light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "smileys_and_emotion")] "kissing cat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "kissing face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "kissing face with closed eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "kissing face with smiling eyes",
+	#[cfg(emojic_group = "food_and_drink")] "kitchen knife",
+	#[cfg(emojic_group = "activities")] "kite",
+	#[cfg(emojic_group = "food_and_drink")] "kiwi fruit",
+	#[cfg(emojic_group = "smileys_and_emotion")] "knocked-out face",
+	#[cfg(emojic_group = "activities")] "knot",
+	#[cfg(emojic_group = "animals_and_nature")] "koala",
+	#[cfg(emojic_group = "objects")] "lab coat",
+	#[cfg(emojic_group = "objects")] "label",
+	#[cfg(emojic_group = "activities")] "lacrosse",
+	#[cfg(emojic_group = "objects")] "ladder",
+	#[cfg(emojic_group = "animals_and_nature")] "lady beetle",
+	#[cfg(emojic_group = "objects")] "laptop",
+	#[cfg(emojic_group = "symbols")] "large blue diamond",
+	#[cfg(emojic_group = "symbols")] "large orange diamond",
+	#[cfg(emojic_group = "travel_and_places")] "last quarter moon",
+	#[cfg(emojic_group = "travel_and_places")] "last quarter moon face",
+	#[cfg(emojic_group = "symbols")] "last track button",
+	#[cfg(emojic_group = "symbols")] "latin cross",
+	#[cfg(emojic_group = "animals_and_nature")] "leaf fluttering in wind",
+	#[cfg(emojic_group = "food_and_drink")] "leafy green",
+	#[cfg(emojic_group = "objects")] "ledger",
+	#[cfg(emojic_group = "symbols")] "left arrow",
+	#[cfg(emojic_group = "symbols")] "left arrow curving right",
+	#[cfg(emojic_group = "symbols")] "left luggage",
+	#[cfg(emojic_group = "smileys_and_emotion")] "left speech bubble",
+	#[cfg(emojic_group = "people_and_body")] "left-facing fist",
+	#[cfg(emojic_group = "people_and_body")] "left-facing fist: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "left-facing fist: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "left-facing fist: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "left-facing fist: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "left-facing fist: medium-light skin tone",
+	#[cfg(emojic_group = "symbols")] "left-right arrow",
+	#[cfg(emojic_group = "people_and_body")] "leftwards hand",
+	#[cfg(emojic_group = "people_and_body")] "leftwards hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "leftwards hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "leftwards hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "leftwards hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "leftwards hand: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "leg",
+	#[cfg(emojic_group = "people_and_body")] "leg: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "leg: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "leg: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "leg: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "leg: medium-light skin tone",
+	#[cfg(emojic_group = "food_and_drink")] "lemon",
+	#[cfg(emojic_group = "animals_and_nature")] "leopard",
+	#[cfg(emojic_group = "objects")] "level slider",
+	#[cfg(emojic_group = "objects")] "light bulb",
+	#[cfg(emojic_group = "travel_and_places")] "light rail",
+	#[cfg(emojic_group = "component")] "light skin tone",
+	#[cfg(emojic_group = "objects")] "link",
+	#[cfg(emojic_group = "objects")] "linked paperclips",
+	#[cfg(emojic_group = "animals_and_nature")] "lion",
+	#[cfg(emojic_group = "objects")] "lipstick",
+	#[cfg(emojic_group = "symbols")] "litter in bin sign",
+	#[cfg(emojic_group = "animals_and_nature")] "lizard",
+	#[cfg(emojic_group = "animals_and_nature")] "llama",
+	#[cfg(emojic_group = "food_and_drink")] "lobster",
+	#[cfg(emojic_group = "objects")] "locked",
+	#[cfg(emojic_group = "objects")] "locked with key",
+	#[cfg(emojic_group = "objects")] "locked with pen",
+	#[cfg(emojic_group = "travel_and_places")] "locomotive",
+	#[cfg(emojic_group = "food_and_drink")] "lollipop",
+	#[cfg(emojic_group = "objects")] "long drum",
+	#[cfg(emojic_group = "objects")] "lotion bottle",
+	#[cfg(emojic_group = "animals_and_nature")] "lotus",
+	#[cfg(emojic_group = "smileys_and_emotion")] "loudly crying face",
+	#[cfg(emojic_group = "objects")] "loudspeaker",
+	#[cfg(emojic_group = "travel_and_places")] "love hotel",
+	#[cfg(emojic_group = "smileys_and_emotion")] "love letter",
+	#[cfg(emojic_group = "people_and_body")] "love-you gesture",
+	#[cfg(emojic_group = "people_and_body")] "love-you gesture: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "love-you gesture: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "love-you gesture: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "love-you gesture: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "love-you gesture: medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "low battery",
+	#[cfg(emojic_group = "travel_and_places")] "luggage",
+	#[cfg(emojic_group = "people_and_body")] "lungs",
+	#[cfg(emojic_group = "smileys_and_emotion")] "lying face",
+	#[cfg(emojic_group = "people_and_body")] "mage",
+	#[cfg(emojic_group = "people_and_body")] "mage: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mage: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mage: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mage: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mage: medium-light skin tone",
+	#[cfg(emojic_group = "activities")] "magic wand",
+	#[cfg(emojic_group = "objects")] "magnet",
+	#[cfg(emojic_group = "objects")] "magnifying glass tilted left",
+	#[cfg(emojic_group = "objects")] "magnifying glass tilted right",
+	#[cfg(emojic_group = "activities")] "mahjong red dragon",
+	#[cfg(emojic_group = "symbols")] "male sign",
+	#[cfg(emojic_group = "animals_and_nature")] "mammoth",
+	#[cfg(emojic_group = "people_and_body")] "man",
+	#[cfg(emojic_group = "people_and_body")] "man artist",
+	#[cfg(emojic_group = "people_and_body")] "man artist: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man artist: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man artist: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man artist: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man artist: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man astronaut",
+	#[cfg(emojic_group = "people_and_body")] "man astronaut: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man astronaut: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man astronaut: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man astronaut: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man astronaut: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man biking",
+	#[cfg(emojic_group = "people_and_body")] "man biking: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man biking: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man biking: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man biking: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man biking: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man bouncing ball",
+	#[cfg(emojic_group = "people_and_body")] "man bouncing ball: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man bouncing ball: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man bouncing ball: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man bouncing ball: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man bouncing ball: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man bowing",
+	#[cfg(emojic_group = "people_and_body")] "man bowing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man bowing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man bowing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man bowing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man bowing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man cartwheeling",
+	#[cfg(emojic_group = "people_and_body")] "man cartwheeling: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man cartwheeling: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man cartwheeling: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man cartwheeling: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man cartwheeling: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man climbing",
+	#[cfg(emojic_group = "people_and_body")] "man climbing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man climbing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man climbing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man climbing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man climbing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man construction worker",
+	#[cfg(emojic_group = "people_and_body")] "man construction worker: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man construction worker: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man construction worker: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man construction worker: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man construction worker: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man cook",
+	#[cfg(emojic_group = "people_and_body")] "man cook: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man cook: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man cook: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man cook: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man cook: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man dancing",
+	#[cfg(emojic_group = "people_and_body")] "man dancing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man dancing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man dancing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man dancing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man dancing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man detective",
+	#[cfg(emojic_group = "people_and_body")] "man detective: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man detective: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man detective: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man detective: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man detective: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man elf",
+	#[cfg(emojic_group = "people_and_body")] "man elf: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man elf: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man elf: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man elf: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man elf: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man facepalming",
+	#[cfg(emojic_group = "people_and_body")] "man facepalming: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man facepalming: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man facepalming: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man facepalming: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man facepalming: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man factory worker",
+	#[cfg(emojic_group = "people_and_body")] "man factory worker: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man factory worker: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man factory worker: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man factory worker: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man factory worker: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man fairy",
+	#[cfg(emojic_group = "people_and_body")] "man fairy: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man fairy: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man fairy: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man fairy: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man fairy: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man farmer",
+	#[cfg(emojic_group = "people_and_body")] "man farmer: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man farmer: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man farmer: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man farmer: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man farmer: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man feeding baby",
+	#[cfg(emojic_group = "people_and_body")] "man feeding baby: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man feeding baby: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man feeding baby: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man feeding baby: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man feeding baby: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man firefighter",
+	#[cfg(emojic_group = "people_and_body")] "man firefighter: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man firefighter: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man firefighter: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man firefighter: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man firefighter: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man frowning",
+	#[cfg(emojic_group = "people_and_body")] "man frowning: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man frowning: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man frowning: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man frowning: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man frowning: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man genie",
+	#[cfg(emojic_group = "people_and_body")] "man gesturing NO",
+	#[cfg(emojic_group = "people_and_body")] "man gesturing NO: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man gesturing NO: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man gesturing NO: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man gesturing NO: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man gesturing NO: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man gesturing OK",
+	#[cfg(emojic_group = "people_and_body")] "man gesturing OK: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man gesturing OK: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man gesturing OK: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man gesturing OK: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man gesturing OK: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man getting haircut",
+	#[cfg(emojic_group = "people_and_body")] "man getting haircut: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man getting haircut: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man getting haircut: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man getting haircut: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man getting haircut: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man getting massage",
+	#[cfg(emojic_group = "people_and_body")] "man getting massage: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man getting massage: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man getting massage: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man getting massage: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man getting massage: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man golfing",
+	#[cfg(emojic_group = "people_and_body")] "man golfing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man golfing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man golfing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man golfing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man golfing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man guard",
+	#[cfg(emojic_group = "people_and_body")] "man guard: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man guard: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man guard: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man guard: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man guard: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man health worker",
+	#[cfg(emojic_group = "people_and_body")] "man health worker: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man health worker: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man health worker: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man health worker: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man health worker: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in lotus position",
+	#[cfg(emojic_group = "people_and_body")] "man in lotus position: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in lotus position: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in lotus position: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in lotus position: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in lotus position: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in manual wheelchair",
+	#[cfg(emojic_group = "people_and_body")] "man in manual wheelchair: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in manual wheelchair: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in manual wheelchair: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in manual wheelchair: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in manual wheelchair: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in motorized wheelchair",
+	#[cfg(emojic_group = "people_and_body")] "man in motorized wheelchair: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in motorized wheelchair: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in motorized wheelchair: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in motorized wheelchair: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in motorized wheelchair: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in steamy room",
+	#[cfg(emojic_group = "people_and_body")] "man in steamy room: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in steamy room: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in steamy room: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in steamy room: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in steamy room: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in tuxedo",
+	#[cfg(emojic_group = "people_and_body")] "man in tuxedo: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in tuxedo: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in tuxedo: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in tuxedo: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man in tuxedo: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man judge",
+	#[cfg(emojic_group = "people_and_body")] "man judge: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man judge: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man judge: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man judge: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man judge: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man juggling",
+	#[cfg(emojic_group = "people_and_body")] "man juggling: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man juggling: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man juggling: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man juggling: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man juggling: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man kneeling",
+	#[cfg(emojic_group = "people_and_body")] "man kneeling: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man kneeling: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man kneeling: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man kneeling: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man kneeling: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man lifting weights",
+	#[cfg(emojic_group = "people_and_body")] "man lifting weights: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man lifting weights: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man lifting weights: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man lifting weights: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man lifting weights: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mage",
+	#[cfg(emojic_group = "people_and_body")] "man mage: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mage: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mage: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mage: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mage: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mechanic",
+	#[cfg(emojic_group = "people_and_body")] "man mechanic: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mechanic: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mechanic: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mechanic: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mechanic: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mountain biking",
+	#[cfg(emojic_group = "people_and_body")] "man mountain biking: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mountain biking: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mountain biking: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mountain biking: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man mountain biking: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man office worker",
+	#[cfg(emojic_group = "people_and_body")] "man office worker: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man office worker: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man office worker: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man office worker: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man office worker: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man pilot",
+	#[cfg(emojic_group = "people_and_body")] "man pilot: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man pilot: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man pilot: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man pilot: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man pilot: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man playing handball",
+	#[cfg(emojic_group = "people_and_body")] "man playing handball: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man playing handball: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man playing handball: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man playing handball: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man playing handball: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man playing water polo",
+	#[cfg(emojic_group = "people_and_body")] "man playing water polo: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man playing water polo: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man playing water polo: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man playing water polo: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man playing water polo: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man police officer",
+	#[cfg(emojic_group = "people_and_body")] "man police officer: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man police officer: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man police officer: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man police officer: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man police officer: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man pouting",
+	#[cfg(emojic_group = "people_and_body")] "man pouting: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man pouting: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man pouting: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man pouting: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man pouting: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man raising hand",
+	#[cfg(emojic_group = "people_and_body")] "man raising hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man raising hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man raising hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man raising hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man raising hand: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man rowing boat",
+	#[cfg(emojic_group = "people_and_body")] "man rowing boat: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man rowing boat: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man rowing boat: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man rowing boat: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man rowing boat: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man running",
+	#[cfg(emojic_group = "people_and_body")] "man running: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man running: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man running: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man running: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man running: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man scientist",
+	#[cfg(emojic_group = "people_and_body")] "man scientist: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man scientist: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man scientist: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man scientist: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man scientist: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man shrugging",
+	#[cfg(emojic_group = "people_and_body")] "man shrugging: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man shrugging: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man shrugging: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man shrugging: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man shrugging: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man singer",
+	#[cfg(emojic_group = "people_and_body")] "man singer: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man singer: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man singer: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man singer: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man singer: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man standing",
+	#[cfg(emojic_group = "people_and_body")] "man standing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man standing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man standing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man standing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man standing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man student",
+	#[cfg(emojic_group = "people_and_body")] "man student: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man student: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man student: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man student: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man student: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man superhero",
+	#[cfg(emojic_group = "people_and_body")] "man superhero: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man superhero: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man superhero: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man superhero: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man superhero: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man supervillain",
+	#[cfg(emojic_group = "people_and_body")] "man supervillain: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man supervillain: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man supervillain: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man supervillain: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man supervillain: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man surfing",
+	#[cfg(emojic_group = "people_and_body")] "man surfing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man surfing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man surfing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man surfing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man surfing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man swimming",
+	#[cfg(emojic_group = "people_and_body")] "man swimming: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man swimming: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man swimming: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man swimming: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man swimming: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man teacher",
+	#[cfg(emojic_group = "people_and_body")] "man teacher: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man teacher: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man teacher: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man teacher: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man teacher: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man technologist",
+	#[cfg(emojic_group = "people_and_body")] "man technologist: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man technologist: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man technologist: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man technologist: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man technologist: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man tipping hand",
+	#[cfg(emojic_group = "people_and_body")] "man tipping hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man tipping hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man tipping hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man tipping hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man tipping hand: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man vampire",
+	#[cfg(emojic_group = "people_and_body")] "man vampire: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man vampire: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man vampire: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man vampire: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man vampire: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man walking",
+	#[cfg(emojic_group = "people_and_body")] "man walking: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man walking: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man walking: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man walking: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man walking: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man wearing turban",
+	#[cfg(emojic_group = "people_and_body")] "man wearing turban: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man wearing turban: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man wearing turban: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man wearing turban: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man wearing turban: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man with veil",
+	#[cfg(emojic_group = "people_and_body")] "man with veil: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man with veil: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man with veil: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man with veil: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man with veil: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man with white cane",
+	#[cfg(emojic_group = "people_and_body")] "man with white cane: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man with white cane: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man with white cane: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man with white cane: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man with white cane: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man zombie",
+	#[cfg(emojic_group = "people_and_body")] "man: bald",
+	#[cfg(emojic_group = "people_and_body")] "man: beard",
+	#[cfg(emojic_group = "people_and_body")] "man: blond hair",
+	#[cfg(emojic_group = "people_and_body")] "man: curly hair",
+	#[cfg(emojic_group = "people_and_body")] "man: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man: dark skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "man: dark skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "man: dark skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "man: dark skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "man: dark skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "man: dark skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "man: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man: light skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "man: light skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "man: light skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "man: light skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "man: light skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "man: light skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "man: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man: medium skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "man: medium skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "man: medium skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "man: medium skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "man: medium skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "man: medium skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "man: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man: medium-dark skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "man: medium-dark skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "man: medium-dark skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "man: medium-dark skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "man: medium-dark skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "man: medium-dark skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "man: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "man: medium-light skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "man: medium-light skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "man: medium-light skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "man: medium-light skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "man: medium-light skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "man: medium-light skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "man: red hair",
+	#[cfg(emojic_group = "people_and_body")] "man: white hair",
+	#[cfg(emojic_group = "food_and_drink")] "mango",
+	#[cfg(emojic_group = "travel_and_places")] "mantelpiece clock",
+	#[cfg(emojic_group = "travel_and_places")] "manual wheelchair",
+	#[cfg(emojic_group = "objects")] "man’s shoe",
+	#[cfg(emojic_group = "travel_and_places")] "map of Japan",
+	#[cfg(emojic_group = "animals_and_nature")] "maple leaf",
+	#[cfg(emojic_group = "activities")] "martial arts uniform",
+	#[cfg(emojic_group = "food_and_drink")] "mate",
+	#[cfg(emojic_group = "food_and_drink")] "meat on bone",
+	#[cfg(emojic_group = "people_and_body")] "mechanic",
+	#[cfg(emojic_group = "people_and_body")] "mechanic: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mechanic: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mechanic: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mechanic: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mechanic: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mechanical arm",
+	#[cfg(emojic_group = "people_and_body")] "mechanical leg",
+	#[cfg(emojic_group = "symbols")] "medical symbol",
+	#[cfg(emojic_group = "component")] "medium skin tone",
+	#[cfg(emojic_group = "component")] "medium-dark skin tone",
+	#[cfg(emojic_group = "component")] "medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "megaphone",
+	#[cfg(emojic_group = "food_and_drink")] "melon",
+	#[cfg(emojic_group = "smileys_and_emotion")] "melting face",
+	#[cfg(emojic_group = "objects")] "memo",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: dark skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: light skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium-dark skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium-dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium-dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium-dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium-light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium-light skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium-light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men holding hands: medium-light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "men with bunny ears",
+	#[cfg(emojic_group = "people_and_body")] "men wrestling",
+	#[cfg(emojic_group = "smileys_and_emotion")] "mending heart",
+	#[cfg(emojic_group = "symbols")] "menorah",
+	#[cfg(emojic_group = "symbols")] "men’s room",
+	#[cfg(emojic_group = "people_and_body")] "mermaid",
+	#[cfg(emojic_group = "people_and_body")] "mermaid: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mermaid: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mermaid: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mermaid: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mermaid: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "merman",
+	#[cfg(emojic_group = "people_and_body")] "merman: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "merman: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "merman: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "merman: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "merman: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "merperson",
+	#[cfg(emojic_group = "people_and_body")] "merperson: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "merperson: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "merperson: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "merperson: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "merperson: medium-light skin tone",
+	#[cfg(emojic_group = "travel_and_places")] "metro",
+	#[cfg(emojic_group = "animals_and_nature")] "microbe",
+	#[cfg(emojic_group = "objects")] "microphone",
+	#[cfg(emojic_group = "objects")] "microscope",
+	#[cfg(emojic_group = "people_and_body")] "middle finger",
+	#[cfg(emojic_group = "people_and_body")] "middle finger: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "middle finger: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "middle finger: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "middle finger: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "middle finger: medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "military helmet",
+	#[cfg(emojic_group = "activities")] "military medal",
+	#[cfg(emojic_group = "travel_and_places")] "milky way",
+	#[cfg(emojic_group = "travel_and_places")] "minibus",
+	#[cfg(emojic_group = "symbols")] "minus",
+	#[cfg(emojic_group = "objects")] "mirror",
+	#[cfg(emojic_group = "activities")] "mirror ball",
+	#[cfg(emojic_group = "objects")] "moai",
+	#[cfg(emojic_group = "objects")] "mobile phone",
+	#[cfg(emojic_group = "symbols")] "mobile phone off",
+	#[cfg(emojic_group = "objects")] "mobile phone with arrow",
+	#[cfg(emojic_group = "objects")] "money bag",
+	#[cfg(emojic_group = "objects")] "money with wings",
+	#[cfg(emojic_group = "smileys_and_emotion")] "money-mouth face",
+	#[cfg(emojic_group = "animals_and_nature")] "monkey",
+	#[cfg(emojic_group = "animals_and_nature")] "monkey face",
+	#[cfg(emojic_group = "travel_and_places")] "monorail",
+	#[cfg(emojic_group = "food_and_drink")] "moon cake",
+	#[cfg(emojic_group = "activities")] "moon viewing ceremony",
+	#[cfg(emojic_group = "travel_and_places")] "mosque",
+	#[cfg(emojic_group = "animals_and_nature")] "mosquito",
+	#[cfg(emojic_group = "travel_and_places")] "motor boat",
+	#[cfg(emojic_group = "travel_and_places")] "motor scooter",
+	#[cfg(emojic_group = "travel_and_places")] "motorcycle",
+	#[cfg(emojic_group = "travel_and_places")] "motorized wheelchair",
+	#[cfg(emojic_group = "travel_and_places")] "motorway",
+	#[cfg(emojic_group = "travel_and_places")] "mount fuji",
+	#[cfg(emojic_group = "travel_and_places")] "mountain",
+	#[cfg(emojic_group = "travel_and_places")] "mountain cableway",
+	#[cfg(emojic_group = "travel_and_places")] "mountain railway",
+	#[cfg(emojic_group = "animals_and_nature")] "mouse",
+	#[cfg(emojic_group = "animals_and_nature")] "mouse face",
+	#[cfg(emojic_group = "objects")] "mouse trap",
+	#[cfg(emojic_group = "people_and_body")] "mouth",
+	#[cfg(emojic_group = "objects")] "movie camera",
+	#[cfg(emojic_group = "symbols")] "multiply",
+	#[cfg(emojic_group = "food_and_drink")] "mushroom",
+	#[cfg(emojic_group = "objects")] "musical keyboard",
+	#[cfg(emojic_group = "objects")] "musical note",
+	#[cfg(emojic_group = "objects")] "musical notes",
+	#[cfg(emojic_group = "objects")] "musical score",
+	#[cfg(emojic_group = "objects")] "muted speaker",
+	#[cfg(emojic_group = "people_and_body")] "mx claus",
+	#[cfg(emojic_group = "people_and_body")] "mx claus: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mx claus: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mx claus: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mx claus: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "mx claus: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "nail polish",
+	#[cfg(emojic_group = "people_and_body")] "nail polish: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "nail polish: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "nail polish: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "nail polish: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "nail polish: medium-light skin tone",
+	#[cfg(emojic_group = "symbols")] "name badge",
+	#[cfg(emojic_group = "travel_and_places")] "national park",
+	#[cfg(emojic_group = "smileys_and_emotion")] "nauseated face",
+	#[cfg(emojic_group = "activities")] "nazar amulet",
+	#[cfg(emojic_group = "objects")] "necktie",
+	#[cfg(emojic_group = "smileys_and_emotion")] "nerd face",
+	#[cfg(emojic_group = "animals_and_nature")] "nest with eggs",
+	#[cfg(emojic_group = "activities")] "nesting dolls",
+	#[cfg(emojic_group = "smileys_and_emotion")] "neutral face",
+	#[cfg(emojic_group = "travel_and_places")] "new moon",
+	#[cfg(emojic_group = "travel_and_places")] "new moon face",
+	#[cfg(emojic_group = "objects")] "newspaper",
+	#[cfg(emojic_group = "symbols")] "next track button",
+	#[cfg(emojic_group = "travel_and_places")] "night with stars",
+	#[cfg(emojic_group = "travel_and_places")] "nine o’clock",
+	#[cfg(emojic_group = "travel_and_places")] "nine-thirty",
+	#[cfg(emojic_group = "people_and_body")] "ninja",
+	#[cfg(emojic_group = "people_and_body")] "ninja: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "ninja: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "ninja: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "ninja: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "ninja: medium-light skin tone",
+	#[cfg(emojic_group = "symbols")] "no bicycles",
+	#[cfg(emojic_group = "symbols")] "no entry",
+	#[cfg(emojic_group = "symbols")] "no littering",
+	#[cfg(emojic_group = "symbols")] "no mobile phones",
+	#[cfg(emojic_group = "symbols")] "no one under eighteen",
+	#[cfg(emojic_group = "symbols")] "no pedestrians",
+	#[cfg(emojic_group = "symbols")] "no smoking",
+	#[cfg(emojic_group = "symbols")] "non-potable water",
+	#[cfg(emojic_group = "people_and_body")] "nose",
+	#[cfg(emojic_group = "people_and_body")] "nose: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "nose: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "nose: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "nose: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "nose: medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "notebook",
+	#[cfg(emojic_group = "objects")] "notebook with decorative cover",
+	#[cfg(emojic_group = "objects")] "nut and bolt",
+	#[cfg(emojic_group = "animals_and_nature")] "octopus",
+	#[cfg(emojic_group = "food_and_drink")] "oden",
+	#[cfg(emojic_group = "travel_and_places")] "office building",
+	#[cfg(emojic_group = "people_and_body")] "office worker",
+	#[cfg(emojic_group = "people_and_body")] "office worker: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "office worker: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "office worker: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "office worker: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "office worker: medium-light skin tone",
+	#[cfg(emojic_group = "smileys_and_emotion")] "ogre",
+	#[cfg(emojic_group = "travel_and_places")] "oil drum",
+	#[cfg(emojic_group = "objects")] "old key",
+	#[cfg(emojic_group = "people_and_body")] "old man",
+	#[cfg(emojic_group = "people_and_body")] "old man: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "old man: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "old man: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "old man: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "old man: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "old woman",
+	#[cfg(emojic_group = "people_and_body")] "old woman: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "old woman: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "old woman: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "old woman: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "old woman: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "older person",
+	#[cfg(emojic_group = "people_and_body")] "older person: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "older person: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "older person: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "older person: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "older person: medium-light skin tone",
+	#[cfg(emojic_group = "food_and_drink")] "olive",
+	#[cfg(emojic_group = "symbols")] "om",
+	#[cfg(emojic_group = "travel_and_places")] "oncoming automobile",
+	#[cfg(emojic_group = "travel_and_places")] "oncoming bus",
+	#[cfg(emojic_group = "people_and_body")] "oncoming fist",
+	#[cfg(emojic_group = "people_and_body")] "oncoming fist: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "oncoming fist: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "oncoming fist: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "oncoming fist: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "oncoming fist: medium-light skin tone",
+	#[cfg(emojic_group = "travel_and_places")] "oncoming police car",
+	#[cfg(emojic_group = "travel_and_places")] "oncoming taxi",
+	#[cfg(emojic_group = "travel_and_places")] "one o’clock",
+	#[cfg(emojic_group = "objects")] "one-piece swimsuit",
+	#[cfg(emojic_group = "travel_and_places")] "one-thirty",
+	#[cfg(emojic_group = "food_and_drink")] "onion",
+	#[cfg(emojic_group = "objects")] "open book",
+	#[cfg(emojic_group = "objects")] "open file folder",
+	#[cfg(emojic_group = "people_and_body")] "open hands",
+	#[cfg(emojic_group = "people_and_body")] "open hands: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "open hands: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "open hands: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "open hands: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "open hands: medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "open mailbox with lowered flag",
+	#[cfg(emojic_group = "objects")] "open mailbox with raised flag",
+	#[cfg(emojic_group = "objects")] "optical disk",
+	#[cfg(emojic_group = "objects")] "orange book",
+	#[cfg(emojic_group = "symbols")] "orange circle",
+	#[cfg(emojic_group = "smileys_and_emotion")] "orange heart",
+	#[cfg(emojic_group = "symbols")] "orange square",
+	#[cfg(emojic_group = "animals_and_nature")] "orangutan",
+	#[cfg(emojic_group = "symbols")] "orthodox cross",
+	#[cfg(emojic_group = "animals_and_nature")] "otter",
+	#[cfg(emojic_group = "objects")] "outbox tray",
+	#[cfg(emojic_group = "animals_and_nature")] "owl",
+	#[cfg(emojic_group = "animals_and_nature")] "ox",
+	#[cfg(emojic_group = "food_and_drink")] "oyster",
+	#[cfg(emojic_group = "objects")] "package",
+	#[cfg(emojic_group = "objects")] "page facing up",
+	#[cfg(emojic_group = "objects")] "page with curl",
+	#[cfg(emojic_group = "objects")] "pager",
+	#[cfg(emojic_group = "objects")] "paintbrush",
+	#[cfg(emojic_group = "people_and_body")] "palm down hand",
+	#[cfg(emojic_group = "people_and_body")] "palm down hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "palm down hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "palm down hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "palm down hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "palm down hand: medium-light skin tone",
+	#[cfg(emojic_group = "animals_and_nature")] "palm tree",
+	#[cfg(emojic_group = "people_and_body")] "palm up hand",
+	#[cfg(emojic_group = "people_and_body")] "palm up hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "palm up hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "palm up hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "palm up hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "palm up hand: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "palms up together",
+	#[cfg(emojic_group = "people_and_body")] "palms up together: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "palms up together: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "palms up together: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "palms up together: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "palms up together: medium-light skin tone",
+	#[cfg(emojic_group = "food_and_drink")] "pancakes",
+	#[cfg(emojic_group = "animals_and_nature")] "panda",
+	#[cfg(emojic_group = "objects")] "paperclip",
+	#[cfg(emojic_group = "travel_and_places")] "parachute",
+	#[cfg(emojic_group = "animals_and_nature")] "parrot",
+	#[cfg(emojic_group = "symbols")] "part alternation mark",
+	#[cfg(emojic_group = "activities")] "party popper",
+	#[cfg(emojic_group = "smileys_and_emotion")] "partying face",
+	#[cfg(emojic_group = "travel_and_places")] "passenger ship",
+	#[cfg(emojic_group = "symbols")] "passport control",
+	#[cfg(emojic_group = "symbols")] "pause button",
+	#[cfg(emojic_group = "animals_and_nature")] "paw prints",
+	#[cfg(emojic_group = "symbols")] "peace symbol",
+	#[cfg(emojic_group = "food_and_drink")] "peach",
+	#[cfg(emojic_group = "animals_and_nature")] "peacock",
+	#[cfg(emojic_group = "food_and_drink")] "peanuts",
+	#[cfg(emojic_group = "food_and_drink")] "pear",
+	#[cfg(emojic_group = "objects")] "pen",
+	#[cfg(emojic_group = "objects")] "pencil",
+	#[cfg(emojic_group = "animals_and_nature")] "penguin",
+	#[cfg(emojic_group = "smileys_and_emotion")] "pensive face",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: dark skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: light skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium-dark skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium-dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium-dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium-dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium-light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium-light skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium-light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people holding hands: medium-light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "people hugging",
+	#[cfg(emojic_group = "people_and_body")] "people with bunny ears",
+	#[cfg(emojic_group = "people_and_body")] "people wrestling",
+	#[cfg(emojic_group = "activities")] "performing arts",
+	#[cfg(emojic_group = "smileys_and_emotion")] "persevering face",
+	#[cfg(emojic_group = "people_and_body")] "person",
+	#[cfg(emojic_group = "people_and_body")] "person biking",
+	#[cfg(emojic_group = "people_and_body")] "person biking: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person biking: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person biking: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person biking: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person biking: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person bouncing ball",
+	#[cfg(emojic_group = "people_and_body")] "person bouncing ball: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person bouncing ball: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person bouncing ball: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person bouncing ball: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person bouncing ball: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person bowing",
+	#[cfg(emojic_group = "people_and_body")] "person bowing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person bowing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person bowing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person bowing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person bowing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person cartwheeling",
+	#[cfg(emojic_group = "people_and_body")] "person cartwheeling: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person cartwheeling: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person cartwheeling: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person cartwheeling: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person cartwheeling: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person climbing",
+	#[cfg(emojic_group = "people_and_body")] "person climbing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person climbing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person climbing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person climbing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person climbing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person facepalming",
+	#[cfg(emojic_group = "people_and_body")] "person facepalming: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person facepalming: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person facepalming: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person facepalming: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person facepalming: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person feeding baby",
+	#[cfg(emojic_group = "people_and_body")] "person feeding baby: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person feeding baby: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person feeding baby: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person feeding baby: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person feeding baby: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person fencing",
+	#[cfg(emojic_group = "people_and_body")] "person frowning",
+	#[cfg(emojic_group = "people_and_body")] "person frowning: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person frowning: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person frowning: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person frowning: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person frowning: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person gesturing NO",
+	#[cfg(emojic_group = "people_and_body")] "person gesturing NO: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person gesturing NO: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person gesturing NO: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person gesturing NO: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person gesturing NO: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person gesturing OK",
+	#[cfg(emojic_group = "people_and_body")] "person gesturing OK: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person gesturing OK: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person gesturing OK: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person gesturing OK: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person gesturing OK: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person getting haircut",
+	#[cfg(emojic_group = "people_and_body")] "person getting haircut: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person getting haircut: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person getting haircut: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person getting haircut: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person getting haircut: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person getting massage",
+	#[cfg(emojic_group = "people_and_body")] "person getting massage: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person getting massage: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person getting massage: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person getting massage: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person getting massage: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person golfing",
+	#[cfg(emojic_group = "people_and_body")] "person golfing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person golfing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person golfing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person golfing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person golfing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in bed",
+	#[cfg(emojic_group = "people_and_body")] "person in bed: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in bed: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in bed: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in bed: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in bed: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in lotus position",
+	#[cfg(emojic_group = "people_and_body")] "person in lotus position: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in lotus position: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in lotus position: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in lotus position: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in lotus position: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in manual wheelchair",
+	#[cfg(emojic_group = "people_and_body")] "person in manual wheelchair: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in manual wheelchair: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in manual wheelchair: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in manual wheelchair: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in manual wheelchair: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in motorized wheelchair",
+	#[cfg(emojic_group = "people_and_body")] "person in motorized wheelchair: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in motorized wheelchair: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in motorized wheelchair: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in motorized wheelchair: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in motorized wheelchair: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in steamy room",
+	#[cfg(emojic_group = "people_and_body")] "person in steamy room: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in steamy room: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in steamy room: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in steamy room: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in steamy room: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in suit levitating",
+	#[cfg(emojic_group = "people_and_body")] "person in suit levitating: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in suit levitating: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in suit levitating: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in suit levitating: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in suit levitating: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in tuxedo",
+	#[cfg(emojic_group = "people_and_body")] "person in tuxedo: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in tuxedo: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in tuxedo: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in tuxedo: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person in tuxedo: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person juggling",
+	#[cfg(emojic_group = "people_and_body")] "person juggling: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person juggling: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person juggling: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person juggling: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person juggling: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person kneeling",
+	#[cfg(emojic_group = "people_and_body")] "person kneeling: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person kneeling: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person kneeling: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person kneeling: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person kneeling: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person lifting weights",
+	#[cfg(emojic_group = "people_and_body")] "person lifting weights: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person lifting weights: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person lifting weights: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person lifting weights: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person lifting weights: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person mountain biking",
+	#[cfg(emojic_group = "people_and_body")] "person mountain biking: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person mountain biking: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person mountain biking: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person mountain biking: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person mountain biking: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person playing handball",
+	#[cfg(emojic_group = "people_and_body")] "person playing handball: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person playing handball: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person playing handball: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person playing handball: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person playing handball: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person playing water polo",
+	#[cfg(emojic_group = "people_and_body")] "person playing water polo: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person playing water polo: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person playing water polo: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person playing water polo: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person playing water polo: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person pouting",
+	#[cfg(emojic_group = "people_and_body")] "person pouting: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person pouting: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person pouting: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person pouting: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person pouting: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person raising hand",
+	#[cfg(emojic_group = "people_and_body")] "person raising hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person raising hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person raising hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person raising hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person raising hand: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person rowing boat",
+	#[cfg(emojic_group = "people_and_body")] "person rowing boat: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person rowing boat: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person rowing boat: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person rowing boat: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person rowing boat: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person running",
+	#[cfg(emojic_group = "people_and_body")] "person running: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person running: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person running: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person running: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person running: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person shrugging",
+	#[cfg(emojic_group = "people_and_body")] "person shrugging: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person shrugging: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person shrugging: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person shrugging: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person shrugging: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person standing",
+	#[cfg(emojic_group = "people_and_body")] "person standing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person standing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person standing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person standing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person standing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person surfing",
+	#[cfg(emojic_group = "people_and_body")] "person surfing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person surfing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person surfing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person surfing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person surfing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person swimming",
+	#[cfg(emojic_group = "people_and_body")] "person swimming: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person swimming: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person swimming: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person swimming: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person swimming: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person taking bath",
+	#[cfg(emojic_group = "people_and_body")] "person taking bath: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person taking bath: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person taking bath: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person taking bath: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person taking bath: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person tipping hand",
+	#[cfg(emojic_group = "people_and_body")] "person tipping hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person tipping hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person tipping hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person tipping hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person tipping hand: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person walking",
+	#[cfg(emojic_group = "people_and_body")] "person walking: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person walking: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person walking: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person walking: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person walking: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person wearing turban",
+	#[cfg(emojic_group = "people_and_body")] "person wearing turban: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person wearing turban: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person wearing turban: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person wearing turban: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person wearing turban: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with crown",
+	#[cfg(emojic_group = "people_and_body")] "person with crown: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with crown: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with crown: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with crown: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with crown: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with skullcap",
+	#[cfg(emojic_group = "people_and_body")] "person with skullcap: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with skullcap: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with skullcap: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with skullcap: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with skullcap: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with veil",
+	#[cfg(emojic_group = "people_and_body")] "person with veil: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with veil: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with veil: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with veil: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with veil: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with white cane",
+	#[cfg(emojic_group = "people_and_body")] "person with white cane: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with white cane: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with white cane: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with white cane: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person with white cane: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person: bald",
+	#[cfg(emojic_group = "people_and_body")] "person: beard",
+	#[cfg(emojic_group = "people_and_body")] "person: blond hair",
+	#[cfg(emojic_group = "people_and_body")] "person: curly hair",
+	#[cfg(emojic_group = "people_and_body")] "person: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person: dark skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "person: dark skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "person: dark skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "person: dark skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "person: dark skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "person: dark skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "person: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person: light skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "person: light skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "person: light skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "person: light skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "person: light skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "person: light skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "person: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person: medium skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "person: medium skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "person: medium skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "person: medium skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "person: medium skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "person: medium skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "person: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person: medium-dark skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "person: medium-dark skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "person: medium-dark skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "person: medium-dark skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "person: medium-dark skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "person: medium-dark skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "person: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "person: medium-light skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "person: medium-light skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "person: medium-light skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "person: medium-light skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "person: medium-light skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "person: medium-light skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "person: red hair",
+	#[cfg(emojic_group = "people_and_body")] "person: white hair",
+	#[cfg(emojic_group = "objects")] "petri dish",
+	#[cfg(emojic_group = "objects")] "pick",
+	#[cfg(emojic_group = "travel_and_places")] "pickup truck",
+	#[cfg(emojic_group = "food_and_drink")] "pie",
+	#[cfg(emojic_group = "animals_and_nature")] "pig",
+	#[cfg(emojic_group = "animals_and_nature")] "pig face",
+	#[cfg(emojic_group = "animals_and_nature")] "pig nose",
+	#[cfg(emojic_group = "smileys_and_emotion")] "pile of poo",
+	#[cfg(emojic_group = "objects")] "pill",
+	#[cfg(emojic_group = "people_and_body")] "pilot",
+	#[cfg(emojic_group = "people_and_body")] "pilot: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pilot: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pilot: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pilot: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pilot: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pinched fingers",
+	#[cfg(emojic_group = "people_and_body")] "pinched fingers: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pinched fingers: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pinched fingers: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pinched fingers: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pinched fingers: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pinching hand",
+	#[cfg(emojic_group = "people_and_body")] "pinching hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pinching hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pinching hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pinching hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pinching hand: medium-light skin tone",
+	#[cfg(emojic_group = "activities")] "pine decoration",
+	#[cfg(emojic_group = "food_and_drink")] "pineapple",
+	#[cfg(emojic_group = "activities")] "ping pong",
+	#[cfg(emojic_group = "flags")] "pirate flag",
+	#[cfg(emojic_group = "food_and_drink")] "pizza",
+	#[cfg(emojic_group = "activities")] "piñata",
+	#[cfg(emojic_group = "objects")] "placard",
+	#[cfg(emojic_group = "symbols")] "place of worship",
+	#[cfg(emojic_group = "symbols")] "play button",
+	#[cfg(emojic_group = "symbols")] "play or pause button",
+	#[cfg(emojic_group = "travel_and_places")] "playground slide",
+	#[cfg(emojic_group = "smileys_and_emotion")] "pleading face",
+	#[cfg(emojic_group = "objects")] "plunger",
+	#[cfg(emojic_group = "symbols")] "plus",
+	#[cfg(emojic_group = "animals_and_nature")] "polar bear",
+	#[cfg(emojic_group = "travel_and_places")] "police car",
+	#[cfg(emojic_group = "travel_and_places")] "police car light",
+	#[cfg(emojic_group = "people_and_body")] "police officer",
+	#[cfg(emojic_group = "people_and_body")] "police officer: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "police officer: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "police officer: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "police officer: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "police officer: medium-light skin tone",
+	#[cfg(emojic_group = "animals_and_nature")] "poodle",
+	#[cfg(emojic_group = "activities")] "pool 8 ball",
+	#[cfg(emojic_group = "food_and_drink")] "popcorn",
+	#[cfg(emojic_group = "travel_and_places")] "post office",
+	#[cfg(emojic_group = "objects")] "postal horn",
+	#[cfg(emojic_group = "objects")] "postbox",
+	#[cfg(emojic_group = "food_and_drink")] "pot of food",
+	#[cfg(emojic_group = "symbols")] "potable water",
+	#[cfg(emojic_group = "food_and_drink")] "potato",
+	#[cfg(emojic_group = "animals_and_nature")] "potted plant",
+	#[cfg(emojic_group = "food_and_drink")] "poultry leg",
+	#[cfg(emojic_group = "objects")] "pound banknote",
+	#[cfg(emojic_group = "food_and_drink")] "pouring liquid",
+	#[cfg(emojic_group = "smileys_and_emotion")] "pouting cat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "pouting face",
+	#[cfg(emojic_group = "objects")] "prayer beads",
+	#[cfg(emojic_group = "people_and_body")] "pregnant man",
+	#[cfg(emojic_group = "people_and_body")] "pregnant man: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pregnant man: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pregnant man: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pregnant man: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pregnant man: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pregnant person",
+	#[cfg(emojic_group = "people_and_body")] "pregnant person: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pregnant person: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pregnant person: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pregnant person: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pregnant person: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pregnant woman",
+	#[cfg(emojic_group = "people_and_body")] "pregnant woman: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pregnant woman: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pregnant woman: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pregnant woman: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "pregnant woman: medium-light skin tone",
+	#[cfg(emojic_group = "food_and_drink")] "pretzel",
+	#[cfg(emojic_group = "people_and_body")] "prince",
+	#[cfg(emojic_group = "people_and_body")] "prince: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "prince: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "prince: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "prince: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "prince: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "princess",
+	#[cfg(emojic_group = "people_and_body")] "princess: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "princess: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "princess: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "princess: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "princess: medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "printer",
+	#[cfg(emojic_group = "symbols")] "prohibited",
+	#[cfg(emojic_group = "symbols")] "purple circle",
+	#[cfg(emojic_group = "smileys_and_emotion")] "purple heart",
+	#[cfg(emojic_group = "symbols")] "purple square",
+	#[cfg(emojic_group = "objects")] "purse",
+	#[cfg(emojic_group = "objects")] "pushpin",
+	#[cfg(emojic_group = "activities")] "puzzle piece",
+	#[cfg(emojic_group = "animals_and_nature")] "rabbit",
+	#[cfg(emojic_group = "animals_and_nature")] "rabbit face",
+	#[cfg(emojic_group = "animals_and_nature")] "raccoon",
+	#[cfg(emojic_group = "travel_and_places")] "racing car",
+	#[cfg(emojic_group = "objects")] "radio",
+	#[cfg(emojic_group = "symbols")] "radio button",
+	#[cfg(emojic_group = "symbols")] "radioactive",
+	#[cfg(emojic_group = "travel_and_places")] "railway car",
+	#[cfg(emojic_group = "travel_and_places")] "railway track",
+	#[cfg(emojic_group = "travel_and_places")] "rainbow",
+	#[cfg(emojic_group = "flags")] "rainbow flag",
+	#[cfg(emojic_group = "people_and_body")] "raised back of hand",
+	#[cfg(emojic_group = "people_and_body")] "raised back of hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raised back of hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raised back of hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raised back of hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raised back of hand: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raised fist",
+	#[cfg(emojic_group = "people_and_body")] "raised fist: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raised fist: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raised fist: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raised fist: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raised fist: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raised hand",
+	#[cfg(emojic_group = "people_and_body")] "raised hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raised hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raised hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raised hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raised hand: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raising hands",
+	#[cfg(emojic_group = "people_and_body")] "raising hands: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raising hands: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raising hands: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raising hands: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "raising hands: medium-light skin tone",
+	#[cfg(emojic_group = "animals_and_nature")] "ram",
+	#[cfg(emojic_group = "animals_and_nature")] "rat",
+	#[cfg(emojic_group = "objects")] "razor",
+	#[cfg(emojic_group = "objects")] "receipt",
+	#[cfg(emojic_group = "symbols")] "record button",
+	#[cfg(emojic_group = "symbols")] "recycling symbol",
+	#[cfg(emojic_group = "food_and_drink")] "red apple",
+	#[cfg(emojic_group = "symbols")] "red circle",
+	#[cfg(emojic_group = "activities")] "red envelope",
+	#[cfg(emojic_group = "symbols")] "red exclamation mark",
+	#[cfg(emojic_group = "component")] "red hair",
+	#[cfg(emojic_group = "smileys_and_emotion")] "red heart",
+	#[cfg(emojic_group = "objects")] "red paper lantern",
+	#[cfg(emojic_group = "symbols")] "red question mark",
+	#[cfg(emojic_group = "symbols")] "red square",
+	#[cfg(emojic_group = "symbols")] "red triangle pointed down",
+	#[cfg(emojic_group = "symbols")] "red triangle pointed up",
+	#[cfg(emojic_group = "symbols")] "registered",
+	#[cfg(emojic_group = "smileys_and_emotion")] "relieved face",
+	#[cfg(emojic_group = "activities")] "reminder ribbon",
+	#[cfg(emojic_group = "symbols")] "repeat button",
+	#[cfg(emojic_group = "symbols")] "repeat single button",
+	#[cfg(emojic_group = "objects")] "rescue worker’s helmet",
+	#[cfg(emojic_group = "symbols")] "restroom",
+	#[cfg(emojic_group = "symbols")] "reverse button",
+	#[cfg(emojic_group = "smileys_and_emotion")] "revolving hearts",
+	#[cfg(emojic_group = "animals_and_nature")] "rhinoceros",
+	#[cfg(emojic_group = "activities")] "ribbon",
+	#[cfg(emojic_group = "food_and_drink")] "rice ball",
+	#[cfg(emojic_group = "food_and_drink")] "rice cracker",
+	#[cfg(emojic_group = "smileys_and_emotion")] "right anger bubble",
+	#[cfg(emojic_group = "symbols")] "right arrow",
+	#[cfg(emojic_group = "symbols")] "right arrow curving down",
+	#[cfg(emojic_group = "symbols")] "right arrow curving left",
+	#[cfg(emojic_group = "symbols")] "right arrow curving up",
+	#[cfg(emojic_group = "people_and_body")] "right-facing fist",
+	#[cfg(emojic_group = "people_and_body")] "right-facing fist: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "right-facing fist: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "right-facing fist: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "right-facing fist: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "right-facing fist: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "rightwards hand",
+	#[cfg(emojic_group = "people_and_body")] "rightwards hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "rightwards hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "rightwards hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "rightwards hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "rightwards hand: medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "ring",
+	#[cfg(emojic_group = "travel_and_places")] "ring buoy",
+	#[cfg(emojic_group = "travel_and_places")] "ringed planet",
+	#[cfg(emojic_group = "food_and_drink")] "roasted sweet potato",
+	#[cfg(emojic_group = "smileys_and_emotion")] "robot",
+	#[cfg(emojic_group = "travel_and_places")] "rock",
+	#[cfg(emojic_group = "travel_and_places")] "rocket",
+	#[cfg(emojic_group = "objects")] "roll of paper",
+	#[cfg(emojic_group = "objects")] "rolled-up newspaper",
+	#[cfg(emojic_group = "travel_and_places")] "roller coaster",
+	#[cfg(emojic_group = "travel_and_places")] "roller skate",
+	#[cfg(emojic_group = "smileys_and_emotion")] "rolling on the floor laughing",
+	#[cfg(emojic_group = "animals_and_nature")] "rooster",
+	#[cfg(emojic_group = "animals_and_nature")] "rose",
+	#[cfg(emojic_group = "animals_and_nature")] "rosette",
+	#[cfg(emojic_group = "objects")] "round pushpin",
+	#[cfg(emojic_group = "activities")] "rugby football",
+	#[cfg(emojic_group = "activities")] "running shirt",
+	#[cfg(emojic_group = "objects")] "running shoe",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sad but relieved face",
+	#[cfg(emojic_group = "objects")] "safety pin",
+	#[cfg(emojic_group = "objects")] "safety vest",
+	#[cfg(emojic_group = "travel_and_places")] "sailboat",
+	#[cfg(emojic_group = "food_and_drink")] "sake",
+	#[cfg(emojic_group = "food_and_drink")] "salt",
+	#[cfg(emojic_group = "smileys_and_emotion")] "saluting face",
+	#[cfg(emojic_group = "food_and_drink")] "sandwich",
+	#[cfg(emojic_group = "objects")] "sari",
+	#[cfg(emojic_group = "travel_and_places")] "satellite",
+	#[cfg(emojic_group = "objects")] "satellite antenna",
+	#[cfg(emojic_group = "animals_and_nature")] "sauropod",
+	#[cfg(emojic_group = "objects")] "saxophone",
+	#[cfg(emojic_group = "objects")] "scarf",
+	#[cfg(emojic_group = "travel_and_places")] "school",
+	#[cfg(emojic_group = "people_and_body")] "scientist",
+	#[cfg(emojic_group = "people_and_body")] "scientist: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "scientist: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "scientist: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "scientist: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "scientist: medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "scissors",
+	#[cfg(emojic_group = "animals_and_nature")] "scorpion",
+	#[cfg(emojic_group = "objects")] "screwdriver",
+	#[cfg(emojic_group = "objects")] "scroll",
+	#[cfg(emojic_group = "animals_and_nature")] "seal",
+	#[cfg(emojic_group = "travel_and_places")] "seat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "see-no-evil monkey",
+	#[cfg(emojic_group = "animals_and_nature")] "seedling",
+	#[cfg(emojic_group = "people_and_body")] "selfie",
+	#[cfg(emojic_group = "people_and_body")] "selfie: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "selfie: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "selfie: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "selfie: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "selfie: medium-light skin tone",
+	#[cfg(emojic_group = "animals_and_nature")] "service dog",
+	#[cfg(emojic_group = "travel_and_places")] "seven o’clock",
+	#[cfg(emojic_group = "travel_and_places")] "seven-thirty",
+	#[cfg(emojic_group = "activities")] "sewing needle",
+	#[cfg(emojic_group = "food_and_drink")] "shallow pan of food",
+	#[cfg(emojic_group = "animals_and_nature")] "shamrock",
+	#[cfg(emojic_group = "animals_and_nature")] "shark",
+	#[cfg(emojic_group = "food_and_drink")] "shaved ice",
+	#[cfg(emojic_group = "animals_and_nature")] "sheaf of rice",
+	#[cfg(emojic_group = "objects")] "shield",
+	#[cfg(emojic_group = "travel_and_places")] "shinto shrine",
+	#[cfg(emojic_group = "travel_and_places")] "ship",
+	#[cfg(emojic_group = "travel_and_places")] "shooting star",
+	#[cfg(emojic_group = "objects")] "shopping bags",
+	#[cfg(emojic_group = "objects")] "shopping cart",
+	#[cfg(emojic_group = "food_and_drink")] "shortcake",
+	#[cfg(emojic_group = "objects")] "shorts",
+	#[cfg(emojic_group = "objects")] "shower",
+	#[cfg(emojic_group = "food_and_drink")] "shrimp",
+	#[cfg(emojic_group = "symbols")] "shuffle tracks button",
+	#[cfg(emojic_group = "smileys_and_emotion")] "shushing face",
+	#[cfg(emojic_group = "people_and_body")] "sign of the horns",
+	#[cfg(emojic_group = "people_and_body")] "sign of the horns: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "sign of the horns: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "sign of the horns: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "sign of the horns: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "sign of the horns: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "singer",
+	#[cfg(emojic_group = "people_and_body")] "singer: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "singer: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "singer: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "singer: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "singer: medium-light skin tone",
+	#[cfg(emojic_group = "travel_and_places")] "six o’clock",
+	#[cfg(emojic_group = "travel_and_places")] "six-thirty",
+	#[cfg(emojic_group = "travel_and_places")] "skateboard",
+	#[cfg(emojic_group = "people_and_body")] "skier",
+	#[cfg(emojic_group = "activities")] "skis",
+	#[cfg(emojic_group = "smileys_and_emotion")] "skull",
+	#[cfg(emojic_group = "smileys_and_emotion")] "skull and crossbones",
+	#[cfg(emojic_group = "animals_and_nature")] "skunk",
+	#[cfg(emojic_group = "activities")] "sled",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sleeping face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sleepy face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "slightly frowning face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "slightly smiling face",
+	#[cfg(emojic_group = "activities")] "slot machine",
+	#[cfg(emojic_group = "animals_and_nature")] "sloth",
+	#[cfg(emojic_group = "travel_and_places")] "small airplane",
+	#[cfg(emojic_group = "symbols")] "small blue diamond",
+	#[cfg(emojic_group = "symbols")] "small orange diamond",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling cat with heart-eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling face with halo",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling face with heart-eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling face with hearts",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling face with horns",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling face with smiling eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling face with sunglasses",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling face with tear",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smirking face",
+	#[cfg(emojic_group = "animals_and_nature")] "snail",
+	#[cfg(emojic_group = "animals_and_nature")] "snake",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sneezing face",
+	#[cfg(emojic_group = "travel_and_places")] "snow-capped mountain",
+	#[cfg(emojic_group = "people_and_body")] "snowboarder",
+	#[cfg(emojic_group = "people_and_body")] "snowboarder: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "snowboarder: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "snowboarder: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "snowboarder: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "snowboarder: medium-light skin tone",
+	#[cfg(emojic_group = "travel_and_places")] "snowflake",
+	#[cfg(emojic_group = "travel_and_places")] "snowman",
+	#[cfg(emojic_group = "travel_and_places")] "snowman without snow",
+	#[cfg(emojic_group = "objects")] "soap",
+	#[cfg(emojic_group = "activities")] "soccer ball",
+	#[cfg(emojic_group = "objects")] "socks",
+	#[cfg(emojic_group = "food_and_drink")] "soft ice cream",
+	#[cfg(emojic_group = "activities")] "softball",
+	#[cfg(emojic_group = "activities")] "spade suit",
+	#[cfg(emojic_group = "food_and_drink")] "spaghetti",
+	#[cfg(emojic_group = "symbols")] "sparkle",
+	#[cfg(emojic_group = "activities")] "sparkler",
+	#[cfg(emojic_group = "activities")] "sparkles",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sparkling heart",
+	#[cfg(emojic_group = "smileys_and_emotion")] "speak-no-evil monkey",
+	#[cfg(emojic_group = "objects")] "speaker high volume",
+	#[cfg(emojic_group = "objects")] "speaker low volume",
+	#[cfg(emojic_group = "objects")] "speaker medium volume",
+	#[cfg(emojic_group = "people_and_body")] "speaking head",
+	#[cfg(emojic_group = "smileys_and_emotion")] "speech balloon",
+	#[cfg(emojic_group = "travel_and_places")] "speedboat",
+	#[cfg(emojic_group = "animals_and_nature")] "spider",
+	#[cfg(emojic_group = "animals_and_nature")] "spider web",
+	#[cfg(emojic_group = "objects")] "spiral calendar",
+	#[cfg(emojic_group = "objects")] "spiral notepad",
+	#[cfg(emojic_group = "animals_and_nature")] "spiral shell",
+	#[cfg(emojic_group = "objects")] "sponge",
+	#[cfg(emojic_group = "food_and_drink")] "spoon",
+	#[cfg(emojic_group = "travel_and_places")] "sport utility vehicle",
+	#[cfg(emojic_group = "activities")] "sports medal",
+	#[cfg(emojic_group = "animals_and_nature")] "spouting whale",
+	#[cfg(emojic_group = "food_and_drink")] "squid",
+	#[cfg(emojic_group = "smileys_and_emotion")] "squinting face with tongue",
+	#[cfg(emojic_group = "travel_and_places")] "stadium",
+	#[cfg(emojic_group = "travel_and_places")] "star",
+	#[cfg(emojic_group = "symbols")] "star and crescent",
+	#[cfg(emojic_group = "symbols")] "star of David",
+	#[cfg(emojic_group = "smileys_and_emotion")] "star-struck",
+	#[cfg(emojic_group = "travel_and_places")] "station",
+	#[cfg(emojic_group = "food_and_drink")] "steaming bowl",
+	#[cfg(emojic_group = "objects")] "stethoscope",
+	#[cfg(emojic_group = "symbols")] "stop button",
+	#[cfg(emojic_group = "travel_and_places")] "stop sign",
+	#[cfg(emojic_group = "travel_and_places")] "stopwatch",
+	#[cfg(emojic_group = "objects")] "straight ruler",
+	#[cfg(emojic_group = "food_and_drink")] "strawberry",
+	#[cfg(emojic_group = "people_and_body")] "student",
+	#[cfg(emojic_group = "people_and_body")] "student: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "student: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "student: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "student: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "student: medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "studio microphone",
+	#[cfg(emojic_group = "food_and_drink")] "stuffed flatbread",
+	#[cfg(emojic_group = "travel_and_places")] "sun",
+	#[cfg(emojic_group = "travel_and_places")] "sun behind cloud",
+	#[cfg(emojic_group = "travel_and_places")] "sun behind large cloud",
+	#[cfg(emojic_group = "travel_and_places")] "sun behind rain cloud",
+	#[cfg(emojic_group = "travel_and_places")] "sun behind small cloud",
+	#[cfg(emojic_group = "travel_and_places")] "sun with face",
+	#[cfg(emojic_group = "animals_and_nature")] "sunflower",
+	#[cfg(emojic_group = "objects")] "sunglasses",
+	#[cfg(emojic_group = "travel_and_places")] "sunrise",
+	#[cfg(emojic_group = "travel_and_places")] "sunrise over mountains",
+	#[cfg(emojic_group = "travel_and_places")] "sunset",
+	#[cfg(emojic_group = "people_and_body")] "superhero",
+	#[cfg(emojic_group = "people_and_body")] "superhero: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "superhero: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "superhero: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "superhero: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "superhero: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "supervillain",
+	#[cfg(emojic_group = "people_and_body")] "supervillain: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "supervillain: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "supervillain: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "supervillain: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "supervillain: medium-light skin tone",
+	#[cfg(emojic_group = "food_and_drink")] "sushi",
+	#[cfg(emojic_group = "travel_and_places")] "suspension railway",
+	#[cfg(emojic_group = "animals_and_nature")] "swan",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sweat droplets",
+	#[cfg(emojic_group = "travel_and_places")] "synagogue",
+	#[cfg(emojic_group = "objects")] "syringe",
+	#[cfg(emojic_group = "objects")] "t-shirt",
+	#[cfg(emojic_group = "food_and_drink")] "taco",
+	#[cfg(emojic_group = "food_and_drink")] "takeout box",
+	#[cfg(emojic_group = "food_and_drink")] "tamale",
+	#[cfg(emojic_group = "activities")] "tanabata tree",
+	#[cfg(emojic_group = "food_and_drink")] "tangerine",
+	#[cfg(emojic_group = "travel_and_places")] "taxi",
+	#[cfg(emojic_group = "people_and_body")] "teacher",
+	#[cfg(emojic_group = "people_and_body")] "teacher: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "teacher: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "teacher: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "teacher: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "teacher: medium-light skin tone",
+	#[cfg(emojic_group = "food_and_drink")] "teacup without handle",
+	#[cfg(emojic_group = "food_and_drink")] "teapot",
+	#[cfg(emojic_group = "objects")] "tear-off calendar",
+	#[cfg(emojic_group = "people_and_body")] "technologist",
+	#[cfg(emojic_group = "people_and_body")] "technologist: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "technologist: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "technologist: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "technologist: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "technologist: medium-light skin tone",
+	#[cfg(emojic_group = "activities")] "teddy bear",
+	#[cfg(emojic_group = "objects")] "telephone",
+	#[cfg(emojic_group = "objects")] "telephone receiver",
+	#[cfg(emojic_group = "objects")] "telescope",
+	#[cfg(emojic_group = "objects")] "television",
+	#[cfg(emojic_group = "travel_and_places")] "ten o’clock",
+	#[cfg(emojic_group = "travel_and_places")] "ten-thirty",
+	#[cfg(emojic_group = "activities")] "tennis",
+	#[cfg(emojic_group = "travel_and_places")] "tent",
+	#[cfg(emojic_group = "objects")] "test tube",
+	#[cfg(emojic_group = "travel_and_places")] "thermometer",
+	#[cfg(emojic_group = "smileys_and_emotion")] "thinking face",
+	#[cfg(emojic_group = "objects")] "thong sandal",
+	#[cfg(emojic_group = "smileys_and_emotion")] "thought balloon",
+	#[cfg(emojic_group = "activities")] "thread",
+	#[cfg(emojic_group = "travel_and_places")] "three o’clock",
+	#[cfg(emojic_group = "travel_and_places")] "three-thirty",
+	#[cfg(emojic_group = "people_and_body")] "thumbs down",
+	#[cfg(emojic_group = "people_and_body")] "thumbs down: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "thumbs down: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "thumbs down: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "thumbs down: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "thumbs down: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "thumbs up",
+	#[cfg(emojic_group = "people_and_body")] "thumbs up: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "thumbs up: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "thumbs up: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "thumbs up: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "thumbs up: medium-light skin tone",
+	#[cfg(emojic_group = "activities")] "ticket",
+	#[cfg(emojic_group = "animals_and_nature")] "tiger",
+	#[cfg(emojic_group = "animals_and_nature")] "tiger face",
+	#[cfg(emojic_group = "travel_and_places")] "timer clock",
+	#[cfg(emojic_group = "smileys_and_emotion")] "tired face",
+	#[cfg(emojic_group = "objects")] "toilet",
+	#[cfg(emojic_group = "food_and_drink")] "tomato",
+	#[cfg(emojic_group = "people_and_body")] "tongue",
+	#[cfg(emojic_group = "objects")] "toolbox",
+	#[cfg(emojic_group = "people_and_body")] "tooth",
+	#[cfg(emojic_group = "objects")] "toothbrush",
+	#[cfg(emojic_group = "objects")] "top hat",
+	#[cfg(emojic_group = "travel_and_places")] "tornado",
+	#[cfg(emojic_group = "objects")] "trackball",
+	#[cfg(emojic_group = "travel_and_places")] "tractor",
+	#[cfg(emojic_group = "symbols")] "trade mark",
+	#[cfg(emojic_group = "travel_and_places")] "train",
+	#[cfg(emojic_group = "travel_and_places")] "tram",
+	#[cfg(emojic_group = "travel_and_places")] "tram car",
+	#[cfg(emojic_group = "flags")] "transgender flag",
+	#[cfg(emojic_group = "symbols")] "transgender symbol",
+	#[cfg(emojic_group = "flags")] "triangular flag",
+	#[cfg(emojic_group = "objects")] "triangular ruler",
+	#[cfg(emojic_group = "symbols")] "trident emblem",
+	#[cfg(emojic_group = "people_and_body")] "troll",
+	#[cfg(emojic_group = "travel_and_places")] "trolleybus",
+	#[cfg(emojic_group = "activities")] "trophy",
+	#[cfg(emojic_group = "food_and_drink")] "tropical drink",
+	#[cfg(emojic_group = "animals_and_nature")] "tropical fish",
+	#[cfg(emojic_group = "objects")] "trumpet",
+	#[cfg(emojic_group = "animals_and_nature")] "tulip",
+	#[cfg(emojic_group = "food_and_drink")] "tumbler glass",
+	#[cfg(emojic_group = "animals_and_nature")] "turkey",
+	#[cfg(emojic_group = "animals_and_nature")] "turtle",
+	#[cfg(emojic_group = "travel_and_places")] "twelve o’clock",
+	#[cfg(emojic_group = "travel_and_places")] "twelve-thirty",
+	#[cfg(emojic_group = "smileys_and_emotion")] "two hearts",
+	#[cfg(emojic_group = "travel_and_places")] "two o’clock",
+	#[cfg(emojic_group = "animals_and_nature")] "two-hump camel",
+	#[cfg(emojic_group = "travel_and_places")] "two-thirty",
+	#[cfg(emojic_group = "travel_and_places")] "umbrella",
+	#[cfg(emojic_group = "travel_and_places")] "umbrella on ground",
+	#[cfg(emojic_group = "travel_and_places")] "umbrella with rain drops",
+	#[cfg(emojic_group = "smileys_and_emotion")] "unamused face",
+	#[cfg(emojic_group = "animals_and_nature")] "unicorn",
+	#[cfg(emojic_group = "objects")] "unlocked",
+	#[cfg(emojic_group = "symbols")] "up arrow",
+	#[cfg(emojic_group = "symbols")] "up-down arrow",
+	#[cfg(emojic_group = "symbols")] "up-left arrow",
+	#[cfg(emojic_group = "symbols")] "up-right arrow",
+	#[cfg(emojic_group = "smileys_and_emotion")] "upside-down face",
+	#[cfg(emojic_group = "symbols")] "upwards button",
+	#[cfg(emojic_group = "people_and_body")] "vampire",
+	#[cfg(emojic_group = "people_and_body")] "vampire: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "vampire: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "vampire: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "vampire: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "vampire: medium-light skin tone",
+	#[cfg(emojic_group = "travel_and_places")] "vertical traffic light",
+	#[cfg(emojic_group = "symbols")] "vibration mode",
+	#[cfg(emojic_group = "people_and_body")] "victory hand",
+	#[cfg(emojic_group = "people_and_body")] "victory hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "victory hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "victory hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "victory hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "victory hand: medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "video camera",
+	#[cfg(emojic_group = "activities")] "video game",
+	#[cfg(emojic_group = "objects")] "videocassette",
+	#[cfg(emojic_group = "objects")] "violin",
+	#[cfg(emojic_group = "travel_and_places")] "volcano",
+	#[cfg(emojic_group = "activities")] "volleyball",
+	#[cfg(emojic_group = "people_and_body")] "vulcan salute",
+	#[cfg(emojic_group = "people_and_body")] "vulcan salute: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "vulcan salute: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "vulcan salute: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "vulcan salute: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "vulcan salute: medium-light skin tone",
+	#[cfg(emojic_group = "food_and_drink")] "waffle",
+	#[cfg(emojic_group = "travel_and_places")] "waning crescent moon",
+	#[cfg(emojic_group = "travel_and_places")] "waning gibbous moon",
+	#[cfg(emojic_group = "symbols")] "warning",
+	#[cfg(emojic_group = "objects")] "wastebasket",
+	#[cfg(emojic_group = "travel_and_places")] "watch",
+	#[cfg(emojic_group = "animals_and_nature")] "water buffalo",
+	#[cfg(emojic_group = "symbols")] "water closet",
+	#[cfg(emojic_group = "objects")] "water pistol",
+	#[cfg(emojic_group = "travel_and_places")] "water wave",
+	#[cfg(emojic_group = "food_and_drink")] "watermelon",
+	#[cfg(emojic_group = "people_and_body")] "waving hand",
+	#[cfg(emojic_group = "people_and_body")] "waving hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "waving hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "waving hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "waving hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "waving hand: medium-light skin tone",
+	#[cfg(emojic_group = "symbols")] "wavy dash",
+	#[cfg(emojic_group = "travel_and_places")] "waxing crescent moon",
+	#[cfg(emojic_group = "travel_and_places")] "waxing gibbous moon",
+	#[cfg(emojic_group = "smileys_and_emotion")] "weary cat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "weary face",
+	#[cfg(emojic_group = "travel_and_places")] "wedding",
+	#[cfg(emojic_group = "animals_and_nature")] "whale",
+	#[cfg(emojic_group = "travel_and_places")] "wheel",
+	#[cfg(emojic_group = "symbols")] "wheel of dharma",
+	#[cfg(emojic_group = "symbols")] "wheelchair symbol",
+	#[cfg(emojic_group = "objects")] "white cane",
+	#[cfg(emojic_group = "symbols")] "white circle",
+	#[cfg(emojic_group = "symbols")] "white exclamation mark",
+	#[cfg(emojic_group = "flags")] "white flag",
+	#[cfg(emojic_group = "animals_and_nature")] "white flower",
+	#[cfg(emojic_group = "component")] "white hair",
+	#[cfg(emojic_group = "smileys_and_emotion")] "white heart",
+	#[cfg(emojic_group = "symbols")] "white large square",
+	#[cfg(emojic_group = "symbols")] "white medium square",
+	#[cfg(emojic_group = "symbols")] "white medium-small square",
+	#[cfg(emojic_group = "symbols")] "white question mark",
+	#[cfg(emojic_group = "symbols")] "white small square",
+	#[cfg(emojic_group = "symbols")] "white square button",
+	#[cfg(emojic_group = "animals_and_nature")] "wilted flower",
+	#[cfg(emojic_group = "activities")] "wind chime",
+	#[cfg(emojic_group = "travel_and_places")] "wind face",
+	#[cfg(emojic_group = "objects")] "window",
+	#[cfg(emojic_group = "food_and_drink")] "wine glass",
+	#[cfg(emojic_group = "smileys_and_emotion")] "winking face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "winking face with tongue",
+	#[cfg(emojic_group = "animals_and_nature")] "wolf",
+	#[cfg(emojic_group = "people_and_body")] "woman",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: dark skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: light skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium-dark skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium-dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium-dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium-dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium-light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium-light skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium-light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman and man holding hands: medium-light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman artist",
+	#[cfg(emojic_group = "people_and_body")] "woman artist: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman artist: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman artist: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman artist: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman artist: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman astronaut",
+	#[cfg(emojic_group = "people_and_body")] "woman astronaut: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman astronaut: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman astronaut: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman astronaut: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman astronaut: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman biking",
+	#[cfg(emojic_group = "people_and_body")] "woman biking: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman biking: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman biking: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman biking: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman biking: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman bouncing ball",
+	#[cfg(emojic_group = "people_and_body")] "woman bouncing ball: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman bouncing ball: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman bouncing ball: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman bouncing ball: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman bouncing ball: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman bowing",
+	#[cfg(emojic_group = "people_and_body")] "woman bowing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman bowing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman bowing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman bowing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman bowing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman cartwheeling",
+	#[cfg(emojic_group = "people_and_body")] "woman cartwheeling: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman cartwheeling: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman cartwheeling: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman cartwheeling: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman cartwheeling: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman climbing",
+	#[cfg(emojic_group = "people_and_body")] "woman climbing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman climbing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman climbing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman climbing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman climbing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman construction worker",
+	#[cfg(emojic_group = "people_and_body")] "woman construction worker: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman construction worker: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman construction worker: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman construction worker: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman construction worker: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman cook",
+	#[cfg(emojic_group = "people_and_body")] "woman cook: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman cook: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman cook: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman cook: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman cook: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman dancing",
+	#[cfg(emojic_group = "people_and_body")] "woman dancing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman dancing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman dancing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman dancing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman dancing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman detective",
+	#[cfg(emojic_group = "people_and_body")] "woman detective: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman detective: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman detective: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman detective: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman detective: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman elf",
+	#[cfg(emojic_group = "people_and_body")] "woman elf: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman elf: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman elf: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman elf: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman elf: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman facepalming",
+	#[cfg(emojic_group = "people_and_body")] "woman facepalming: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman facepalming: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman facepalming: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman facepalming: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman facepalming: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman factory worker",
+	#[cfg(emojic_group = "people_and_body")] "woman factory worker: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman factory worker: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman factory worker: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman factory worker: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman factory worker: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman fairy",
+	#[cfg(emojic_group = "people_and_body")] "woman fairy: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman fairy: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman fairy: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman fairy: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman fairy: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman farmer",
+	#[cfg(emojic_group = "people_and_body")] "woman farmer: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman farmer: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman farmer: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman farmer: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman farmer: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman feeding baby",
+	#[cfg(emojic_group = "people_and_body")] "woman feeding baby: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman feeding baby: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman feeding baby: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman feeding baby: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman feeding baby: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman firefighter",
+	#[cfg(emojic_group = "people_and_body")] "woman firefighter: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman firefighter: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman firefighter: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman firefighter: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman firefighter: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman frowning",
+	#[cfg(emojic_group = "people_and_body")] "woman frowning: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman frowning: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman frowning: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman frowning: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman frowning: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman genie",
+	#[cfg(emojic_group = "people_and_body")] "woman gesturing NO",
+	#[cfg(emojic_group = "people_and_body")] "woman gesturing NO: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman gesturing NO: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman gesturing NO: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman gesturing NO: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman gesturing NO: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman gesturing OK",
+	#[cfg(emojic_group = "people_and_body")] "woman gesturing OK: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman gesturing OK: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman gesturing OK: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman gesturing OK: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman gesturing OK: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman getting haircut",
+	#[cfg(emojic_group = "people_and_body")] "woman getting haircut: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman getting haircut: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman getting haircut: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman getting haircut: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman getting haircut: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman getting massage",
+	#[cfg(emojic_group = "people_and_body")] "woman getting massage: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman getting massage: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman getting massage: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman getting massage: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman getting massage: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman golfing",
+	#[cfg(emojic_group = "people_and_body")] "woman golfing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman golfing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman golfing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman golfing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman golfing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman guard",
+	#[cfg(emojic_group = "people_and_body")] "woman guard: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman guard: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman guard: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman guard: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman guard: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman health worker",
+	#[cfg(emojic_group = "people_and_body")] "woman health worker: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman health worker: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman health worker: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman health worker: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman health worker: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in lotus position",
+	#[cfg(emojic_group = "people_and_body")] "woman in lotus position: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in lotus position: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in lotus position: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in lotus position: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in lotus position: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in manual wheelchair",
+	#[cfg(emojic_group = "people_and_body")] "woman in manual wheelchair: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in manual wheelchair: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in manual wheelchair: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in manual wheelchair: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in manual wheelchair: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in motorized wheelchair",
+	#[cfg(emojic_group = "people_and_body")] "woman in motorized wheelchair: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in motorized wheelchair: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in motorized wheelchair: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in motorized wheelchair: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in motorized wheelchair: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in steamy room",
+	#[cfg(emojic_group = "people_and_body")] "woman in steamy room: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in steamy room: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in steamy room: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in steamy room: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in steamy room: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in tuxedo",
+	#[cfg(emojic_group = "people_and_body")] "woman in tuxedo: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in tuxedo: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in tuxedo: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in tuxedo: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman in tuxedo: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman judge",
+	#[cfg(emojic_group = "people_and_body")] "woman judge: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman judge: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman judge: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman judge: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman judge: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman juggling",
+	#[cfg(emojic_group = "people_and_body")] "woman juggling: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman juggling: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman juggling: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman juggling: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman juggling: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman kneeling",
+	#[cfg(emojic_group = "people_and_body")] "woman kneeling: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman kneeling: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman kneeling: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman kneeling: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman kneeling: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman lifting weights",
+	#[cfg(emojic_group = "people_and_body")] "woman lifting weights: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman lifting weights: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman lifting weights: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman lifting weights: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman lifting weights: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mage",
+	#[cfg(emojic_group = "people_and_body")] "woman mage: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mage: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mage: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mage: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mage: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mechanic",
+	#[cfg(emojic_group = "people_and_body")] "woman mechanic: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mechanic: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mechanic: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mechanic: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mechanic: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mountain biking",
+	#[cfg(emojic_group = "people_and_body")] "woman mountain biking: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mountain biking: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mountain biking: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mountain biking: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman mountain biking: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman office worker",
+	#[cfg(emojic_group = "people_and_body")] "woman office worker: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman office worker: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman office worker: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman office worker: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman office worker: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman pilot",
+	#[cfg(emojic_group = "people_and_body")] "woman pilot: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman pilot: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman pilot: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman pilot: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman pilot: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman playing handball",
+	#[cfg(emojic_group = "people_and_body")] "woman playing handball: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman playing handball: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman playing handball: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman playing handball: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman playing handball: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman playing water polo",
+	#[cfg(emojic_group = "people_and_body")] "woman playing water polo: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman playing water polo: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman playing water polo: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman playing water polo: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman playing water polo: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman police officer",
+	#[cfg(emojic_group = "people_and_body")] "woman police officer: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman police officer: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman police officer: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman police officer: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman police officer: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman pouting",
+	#[cfg(emojic_group = "people_and_body")] "woman pouting: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman pouting: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman pouting: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman pouting: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman pouting: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman raising hand",
+	#[cfg(emojic_group = "people_and_body")] "woman raising hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman raising hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman raising hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman raising hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman raising hand: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman rowing boat",
+	#[cfg(emojic_group = "people_and_body")] "woman rowing boat: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman rowing boat: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman rowing boat: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman rowing boat: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman rowing boat: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman running",
+	#[cfg(emojic_group = "people_and_body")] "woman running: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman running: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman running: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman running: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman running: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman scientist",
+	#[cfg(emojic_group = "people_and_body")] "woman scientist: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman scientist: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman scientist: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman scientist: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman scientist: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman shrugging",
+	#[cfg(emojic_group = "people_and_body")] "woman shrugging: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman shrugging: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman shrugging: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman shrugging: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman shrugging: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman singer",
+	#[cfg(emojic_group = "people_and_body")] "woman singer: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman singer: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman singer: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman singer: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman singer: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman standing",
+	#[cfg(emojic_group = "people_and_body")] "woman standing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman standing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman standing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman standing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman standing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman student",
+	#[cfg(emojic_group = "people_and_body")] "woman student: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman student: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman student: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman student: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman student: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman superhero",
+	#[cfg(emojic_group = "people_and_body")] "woman superhero: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman superhero: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman superhero: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman superhero: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman superhero: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman supervillain",
+	#[cfg(emojic_group = "people_and_body")] "woman supervillain: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman supervillain: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman supervillain: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman supervillain: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman supervillain: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman surfing",
+	#[cfg(emojic_group = "people_and_body")] "woman surfing: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman surfing: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman surfing: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman surfing: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman surfing: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman swimming",
+	#[cfg(emojic_group = "people_and_body")] "woman swimming: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman swimming: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman swimming: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman swimming: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman swimming: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman teacher",
+	#[cfg(emojic_group = "people_and_body")] "woman teacher: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman teacher: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman teacher: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman teacher: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman teacher: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman technologist",
+	#[cfg(emojic_group = "people_and_body")] "woman technologist: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman technologist: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman technologist: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman technologist: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman technologist: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman tipping hand",
+	#[cfg(emojic_group = "people_and_body")] "woman tipping hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman tipping hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman tipping hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman tipping hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman tipping hand: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman vampire",
+	#[cfg(emojic_group = "people_and_body")] "woman vampire: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman vampire: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman vampire: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman vampire: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman vampire: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman walking",
+	#[cfg(emojic_group = "people_and_body")] "woman walking: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman walking: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman walking: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman walking: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman walking: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman wearing turban",
+	#[cfg(emojic_group = "people_and_body")] "woman wearing turban: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman wearing turban: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman wearing turban: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman wearing turban: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman wearing turban: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with headscarf",
+	#[cfg(emojic_group = "people_and_body")] "woman with headscarf: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with headscarf: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with headscarf: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with headscarf: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with headscarf: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with veil",
+	#[cfg(emojic_group = "people_and_body")] "woman with veil: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with veil: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with veil: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with veil: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with veil: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with white cane",
+	#[cfg(emojic_group = "people_and_body")] "woman with white cane: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with white cane: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with white cane: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with white cane: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman with white cane: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman zombie",
+	#[cfg(emojic_group = "people_and_body")] "woman: bald",
+	#[cfg(emojic_group = "people_and_body")] "woman: beard",
+	#[cfg(emojic_group = "people_and_body")] "woman: blond hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: curly hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman: dark skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "woman: dark skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "woman: dark skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: dark skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: dark skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: dark skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman: light skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "woman: light skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "woman: light skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: light skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: light skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: light skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium-dark skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium-dark skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium-dark skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium-dark skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium-dark skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium-dark skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium-light skin tone, bald",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium-light skin tone, beard",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium-light skin tone, blond hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium-light skin tone, curly hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium-light skin tone, red hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: medium-light skin tone, white hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: red hair",
+	#[cfg(emojic_group = "people_and_body")] "woman: white hair",
+	#[cfg(emojic_group = "objects")] "woman’s boot",
+	#[cfg(emojic_group = "objects")] "woman’s clothes",
+	#[cfg(emojic_group = "objects")] "woman’s hat",
+	#[cfg(emojic_group = "objects")] "woman’s sandal",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: dark skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: light skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium-dark skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium-dark skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium-dark skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium-dark skin tone, medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium-light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium-light skin tone, dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium-light skin tone, light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium-light skin tone, medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women holding hands: medium-light skin tone, medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "women with bunny ears",
+	#[cfg(emojic_group = "people_and_body")] "women wrestling",
+	#[cfg(emojic_group = "symbols")] "women’s room",
+	#[cfg(emojic_group = "travel_and_places")] "wood",
+	#[cfg(emojic_group = "smileys_and_emotion")] "woozy face",
+	#[cfg(emojic_group = "travel_and_places")] "world map",
+	#[cfg(emojic_group = "animals_and_nature")] "worm",
+	#[cfg(emojic_group = "smileys_and_emotion")] "worried face",
+	#[cfg(emojic_group = "activities")] "wrapped gift",
+	#[cfg(emojic_group = "objects")] "wrench",
+	#[cfg(emojic_group = "people_and_body")] "writing hand",
+	#[cfg(emojic_group = "people_and_body")] "writing hand: dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "writing hand: light skin tone",
+	#[cfg(emojic_group = "people_and_body")] "writing hand: medium skin tone",
+	#[cfg(emojic_group = "people_and_body")] "writing hand: medium-dark skin tone",
+	#[cfg(emojic_group = "people_and_body")] "writing hand: medium-light skin tone",
+	#[cfg(emojic_group = "objects")] "x-ray",
+	#[cfg(emojic_group = "activities")] "yarn",
+	#[cfg(emojic_group = "smileys_and_emotion")] "yawning face",
+	#[cfg(emojic_group = "symbols")] "yellow circle",
+	#[cfg(emojic_group = "smileys_and_emotion")] "yellow heart",
+	#[cfg(emojic_group = "symbols")] "yellow square",
+	#[cfg(emojic_group = "objects")] "yen banknote",
+	#[cfg(emojic_group = "symbols")] "yin yang",
+	#[cfg(emojic_group = "activities")] "yo-yo",
+	#[cfg(emojic_group = "smileys_and_emotion")] "zany face",
+	#[cfg(emojic_group = "animals_and_nature")] "zebra",
+	#[cfg(emojic_group = "smileys_and_emotion")] "zipper-mouth face",
+	#[cfg(emojic_group = "people_and_body")] "zombie",
+	#[cfg(emojic_group = "smileys_and_emotion")] "zzz",
+];
+
+/// All aliases (without colons) of all emojis, sorted so they can be binary searched.
+///
+/// These are the aliases as accepted by [`parse_alias`](crate::parse_alias), e.g. to build an
+/// index for autocompletion without the emojis themselves.
+///
+/// # Examples
+///
+/// ```rust
+/// use emojic::flat::ALL_ALIASES;
+///
+/// assert!(ALL_ALIASES.binary_search(&"+1").is_ok());
+/// assert!(ALL_ALIASES.windows(2).all(|w| w[0] < w[1]));
+/// ```
+pub static ALL_ALIASES: &[&str] = &[
+	#[cfg(emojic_group = "people_and_body")] "+1",
+	#[cfg(emojic_group = "people_and_body")] "-1",
+	#[cfg(emojic_group = "smileys_and_emotion")] "100",
+	#[cfg(emojic_group = "symbols")] "1234",
+	#[cfg(emojic_group = "activities")] "1st_place_medal",
+	#[cfg(emojic_group = "activities")] "2nd_place_medal",
+	#[cfg(emojic_group = "activities")] "3rd_place_medal",
+	#[cfg(emojic_group = "activities")] "8ball",
+	#[cfg(emojic_group = "symbols")] "a",
+	#[cfg(emojic_group = "symbols")] "a_button_blood_type",
+	#[cfg(emojic_group = "symbols")] "ab",
+	#[cfg(emojic_group = "symbols")] "ab_button_blood_type",
+	#[cfg(emojic_group = "objects")] "abacus",
+	#[cfg(emojic_group = "symbols")] "abc",
+	#[cfg(emojic_group = "symbols")] "abcd",
+	#[cfg(emojic_group = "symbols")] "accept",
+	#[cfg(emojic_group = "objects")] "accordion",
+	#[cfg(emojic_group = "objects")] "adhesive_bandage",
+	#[cfg(emojic_group = "activities")] "admission_tickets",
+	#[cfg(emojic_group = "people_and_body")] "adult",
+	#[cfg(emojic_group = "travel_and_places")] "aerial_tramway",
+	#[cfg(emojic_group = "flags")] "afghanistan",
+	#[cfg(emojic_group = "travel_and_places")] "airplane",
+	#[cfg(emojic_group = "travel_and_places")] "airplane_arrival",
+	#[cfg(emojic_group = "travel_and_places")] "airplane_departure",
+	#[cfg(emojic_group = "flags")] "aland_islands",
+	#[cfg(emojic_group = "travel_and_places")] "alarm_clock",
+	#[cfg(emojic_group = "flags")] "albania",
+	#[cfg(emojic_group = "objects")] "alembic",
+	#[cfg(emojic_group = "flags")] "algeria",
+	#[cfg(emojic_group = "smileys_and_emotion")] "alien",
+	#[cfg(emojic_group = "smileys_and_emotion")] "alien_monster",
+	#[cfg(emojic_group = "travel_and_places")] "ambulance",
+	#[cfg(emojic_group = "activities")] "american_football",
+	#[cfg(emojic_group = "flags")] "american_samoa",
+	#[cfg(emojic_group = "food_and_drink")] "amphora",
+	#[cfg(emojic_group = "people_and_body")] "anatomical_heart",
+	#[cfg(emojic_group = "travel_and_places")] "anchor",
+	#[cfg(emojic_group = "flags")] "andorra",
+	#[cfg(emojic_group = "people_and_body")] "angel",
+	#[cfg(emojic_group = "smileys_and_emotion")] "anger",
+	#[cfg(emojic_group = "smileys_and_emotion")] "anger_symbol",
+	#[cfg(emojic_group = "flags")] "angola",
+	#[cfg(emojic_group = "smileys_and_emotion")] "angry",
+	#[cfg(emojic_group = "smileys_and_emotion")] "angry_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "angry_face_with_horns",
+	#[cfg(emojic_group = "flags")] "anguilla",
+	#[cfg(emojic_group = "smileys_and_emotion")] "anguished",
+	#[cfg(emojic_group = "smileys_and_emotion")] "anguished_face",
+	#[cfg(emojic_group = "animals_and_nature")] "ant",
+	#[cfg(emojic_group = "flags")] "antarctica",
+	#[cfg(emojic_group = "symbols")] "antenna_bars",
+	#[cfg(emojic_group = "flags")] "antigua_barbuda",
+	#[cfg(emojic_group = "smileys_and_emotion")] "anxious_face_with_sweat",
+	#[cfg(emojic_group = "food_and_drink")] "apple",
+	#[cfg(emojic_group = "symbols")] "aquarius",
+	#[cfg(emojic_group = "flags")] "argentina",
+	#[cfg(emojic_group = "symbols")] "aries",
+	#[cfg(emojic_group = "flags")] "armenia",
+	#[cfg(emojic_group = "symbols")] "arrow_backward",
+	#[cfg(emojic_group = "symbols")] "arrow_double_down",
+	#[cfg(emojic_group = "symbols")] "arrow_double_up",
+	#[cfg(emojic_group = "symbols")] "arrow_down",
+	#[cfg(emojic_group = "symbols")] "arrow_down_small",
+	#[cfg(emojic_group = "symbols")] "arrow_forward",
+	#[cfg(emojic_group = "symbols")] "arrow_heading_down",
+	#[cfg(emojic_group = "symbols")] "arrow_heading_up",
+	#[cfg(emojic_group = "symbols")] "arrow_left",
+	#[cfg(emojic_group = "symbols")] "arrow_lower_left",
+	#[cfg(emojic_group = "symbols")] "arrow_lower_right",
+	#[cfg(emojic_group = "symbols")] "arrow_right",
+	#[cfg(emojic_group = "symbols")] "arrow_right_hook",
+	#[cfg(emojic_group = "symbols")] "arrow_up",
+	#[cfg(emojic_group = "symbols")] "arrow_up_down",
+	#[cfg(emojic_group = "symbols")] "arrow_up_small",
+	#[cfg(emojic_group = "symbols")] "arrow_upper_left",
+	#[cfg(emojic_group = "symbols")] "arrow_upper_right",
+	#[cfg(emojic_group = "symbols")] "arrows_clockwise",
+	#[cfg(emojic_group = "symbols")] "arrows_counterclockwise",
+	#[cfg(emojic_group = "activities")] "art",
+	#[cfg(emojic_group = "travel_and_places")] "articulated_lorry",
+	#[cfg(emojic_group = "travel_and_places")] "artificial_satellite",
+	#[cfg(emojic_group = "people_and_body")] "artist",
+	#[cfg(emojic_group = "activities")] "artist_palette",
+	#[cfg(emojic_group = "flags")] "aruba",
+	#[cfg(emojic_group = "flags")] "ascension_island",
+	#[cfg(emojic_group = "symbols")] "asterisk",
+	#[cfg(emojic_group = "smileys_and_emotion")] "astonished",
+	#[cfg(emojic_group = "smileys_and_emotion")] "astonished_face",
+	#[cfg(emojic_group = "people_and_body")] "astronaut",
+	#[cfg(emojic_group = "objects")] "athletic_shoe",
+	#[cfg(emojic_group = "symbols")] "atm",
+	#[cfg(emojic_group = "symbols")] "atm_sign",
+	#[cfg(emojic_group = "symbols")] "atom_symbol",
+	#[cfg(emojic_group = "flags")] "australia",
+	#[cfg(emojic_group = "flags")] "austria",
+	#[cfg(emojic_group = "travel_and_places")] "auto_rickshaw",
+	#[cfg(emojic_group = "travel_and_places")] "automobile",
+	#[cfg(emojic_group = "food_and_drink")] "avocado",
+	#[cfg(emojic_group = "objects")] "axe",
+	#[cfg(emojic_group = "flags")] "azerbaijan",
+	#[cfg(emojic_group = "symbols")] "b",
+	#[cfg(emojic_group = "symbols")] "b_button_blood_type",
+	#[cfg(emojic_group = "people_and_body")] "baby",
+	#[cfg(emojic_group = "people_and_body")] "baby_angel",
+	#[cfg(emojic_group = "food_and_drink")] "baby_bottle",
+	#[cfg(emojic_group = "animals_and_nature")] "baby_chick",
+	#[cfg(emojic_group = "symbols")] "baby_symbol",
+	#[cfg(emojic_group = "symbols")] "back",
+	#[cfg(emojic_group = "symbols")] "back_arrow",
+	#[cfg(emojic_group = "people_and_body")] "backhand_index_pointing_down",
+	#[cfg(emojic_group = "people_and_body")] "backhand_index_pointing_left",
+	#[cfg(emojic_group = "people_and_body")] "backhand_index_pointing_right",
+	#[cfg(emojic_group = "people_and_body")] "backhand_index_pointing_up",
+	#[cfg(emojic_group = "objects")] "backpack",
+	#[cfg(emojic_group = "food_and_drink")] "bacon",
+	#[cfg(emojic_group = "animals_and_nature")] "badger",
+	#[cfg(emojic_group = "activities")] "badminton",
+	#[cfg(emojic_group = "food_and_drink")] "bagel",
+	#[cfg(emojic_group = "symbols")] "baggage_claim",
+	#[cfg(emojic_group = "food_and_drink")] "baguette_bread",
+	#[cfg(emojic_group = "flags")] "bahamas",
+	#[cfg(emojic_group = "flags")] "bahrain",
+	#[cfg(emojic_group = "objects")] "balance_scale",
+	#[cfg(emojic_group = "component")] "bald",
+	#[cfg(emojic_group = "people_and_body")] "bald_man",
+	#[cfg(emojic_group = "people_and_body")] "bald_woman",
+	#[cfg(emojic_group = "objects")] "ballet_shoes",
+	#[cfg(emojic_group = "activities")] "balloon",
+	#[cfg(emojic_group = "objects")] "ballot_box",
+	#[cfg(emojic_group = "objects")] "ballot_box_with_ballot",
+	#[cfg(emojic_group = "symbols")] "ballot_box_with_check",
+	#[cfg(emojic_group = "activities")] "bamboo",
+	#[cfg(emojic_group = "food_and_drink")] "banana",
+	#[cfg(emojic_group = "symbols")] "bangbang",
+	#[cfg(emojic_group = "flags")] "bangladesh",
+	#[cfg(emojic_group = "objects")] "banjo",
+	#[cfg(emojic_group = "travel_and_places")] "bank",
+	#[cfg(emojic_group = "objects")] "bar_chart",
+	#[cfg(emojic_group = "flags")] "barbados",
+	#[cfg(emojic_group = "travel_and_places")] "barber",
+	#[cfg(emojic_group = "travel_and_places")] "barber_pole",
+	#[cfg(emojic_group = "activities")] "baseball",
+	#[cfg(emojic_group = "objects")] "basket",
+	#[cfg(emojic_group = "activities")] "basketball",
+	#[cfg(emojic_group = "people_and_body")] "basketball_man",
+	#[cfg(emojic_group = "people_and_body")] "basketball_woman",
+	#[cfg(emojic_group = "animals_and_nature")] "bat",
+	#[cfg(emojic_group = "people_and_body")] "bath",
+	#[cfg(emojic_group = "objects")] "bathtub",
+	#[cfg(emojic_group = "objects")] "battery",
+	#[cfg(emojic_group = "travel_and_places")] "beach_umbrella",
+	#[cfg(emojic_group = "travel_and_places")] "beach_with_umbrella",
+	#[cfg(emojic_group = "smileys_and_emotion")] "beaming_face_with_smiling_eyes",
+	#[cfg(emojic_group = "food_and_drink")] "beans",
+	#[cfg(emojic_group = "animals_and_nature")] "bear",
+	#[cfg(emojic_group = "people_and_body")] "bearded_person",
+	#[cfg(emojic_group = "smileys_and_emotion")] "beating_heart",
+	#[cfg(emojic_group = "animals_and_nature")] "beaver",
+	#[cfg(emojic_group = "objects")] "bed",
+	#[cfg(emojic_group = "animals_and_nature")] "bee",
+	#[cfg(emojic_group = "food_and_drink")] "beer",
+	#[cfg(emojic_group = "food_and_drink")] "beer_mug",
+	#[cfg(emojic_group = "food_and_drink")] "beers",
+	#[cfg(emojic_group = "animals_and_nature")] "beetle",
+	#[cfg(emojic_group = "symbols")] "beginner",
+	#[cfg(emojic_group = "flags")] "belarus",
+	#[cfg(emojic_group = "flags")] "belgium",
+	#[cfg(emojic_group = "flags")] "belize",
+	#[cfg(emojic_group = "objects")] "bell",
+	#[cfg(emojic_group = "food_and_drink")] "bell_pepper",
+	#[cfg(emojic_group = "objects")] "bell_with_slash",
+	#[cfg(emojic_group = "travel_and_places")] "bellhop_bell",
+	#[cfg(emojic_group = "flags")] "benin",
+	#[cfg(emojic_group = "food_and_drink")] "bento",
+	#[cfg(emojic_group = "food_and_drink")] "bento_box",
+	#[cfg(emojic_group = "flags")] "bermuda",
+	#[cfg(emojic_group = "food_and_drink")] "beverage_box",
+	#[cfg(emojic_group = "flags")] "bhutan",
+	#[cfg(emojic_group = "travel_and_places")] "bicycle",
+	#[cfg(emojic_group = "people_and_body")] "bicyclist",
+	#[cfg(emojic_group = "travel_and_places")] "bike",
+	#[cfg(emojic_group = "people_and_body")] "biking_man",
+	#[cfg(emojic_group = "people_and_body")] "biking_woman",
+	#[cfg(emojic_group = "objects")] "bikini",
+	#[cfg(emojic_group = "objects")] "billed_cap",
+	#[cfg(emojic_group = "symbols")] "biohazard",
+	#[cfg(emojic_group = "animals_and_nature")] "bird",
+	#[cfg(emojic_group = "food_and_drink")] "birthday",
+	#[cfg(emojic_group = "food_and_drink")] "birthday_cake",
+	#[cfg(emojic_group = "animals_and_nature")] "bison",
+	#[cfg(emojic_group = "people_and_body")] "biting_lip",
+	#[cfg(emojic_group = "animals_and_nature")] "black_cat",
+	#[cfg(emojic_group = "symbols")] "black_circle",
+	#[cfg(emojic_group = "flags")] "black_flag",
+	#[cfg(emojic_group = "smileys_and_emotion")] "black_heart",
+	#[cfg(emojic_group = "activities")] "black_joker",
+	#[cfg(emojic_group = "symbols")] "black_large_square",
+	#[cfg(emojic_group = "symbols")] "black_medium_small_square",
+	#[cfg(emojic_group = "symbols")] "black_medium_square",
+	#[cfg(emojic_group = "objects")] "black_nib",
+	#[cfg(emojic_group = "symbols")] "black_small_square",
+	#[cfg(emojic_group = "symbols")] "black_square_button",
+	#[cfg(emojic_group = "people_and_body")] "blond_haired_man",
+	#[cfg(emojic_group = "people_and_body")] "blond_haired_person",
+	#[cfg(emojic_group = "people_and_body")] "blond_haired_woman",
+	#[cfg(emojic_group = "people_and_body")] "blonde_woman",
+	#[cfg(emojic_group = "animals_and_nature")] "blossom",
+	#[cfg(emojic_group = "animals_and_nature")] "blowfish",
+	#[cfg(emojic_group = "objects")] "blue_book",
+	#[cfg(emojic_group = "travel_and_places")] "blue_car",
+	#[cfg(emojic_group = "symbols")] "blue_circle",
+	#[cfg(emojic_group = "smileys_and_emotion")] "blue_heart",
+	#[cfg(emojic_group = "symbols")] "blue_square",
+	#[cfg(emojic_group = "food_and_drink")] "blueberries",
+	#[cfg(emojic_group = "smileys_and_emotion")] "blush",
+	#[cfg(emojic_group = "animals_and_nature")] "boar",
+	#[cfg(emojic_group = "travel_and_places")] "boat",
+	#[cfg(emojic_group = "flags")] "bolivia",
+	#[cfg(emojic_group = "smileys_and_emotion")] "bomb",
+	#[cfg(emojic_group = "people_and_body")] "bone",
+	#[cfg(emojic_group = "objects")] "book",
+	#[cfg(emojic_group = "objects")] "bookmark",
+	#[cfg(emojic_group = "objects")] "bookmark_tabs",
+	#[cfg(emojic_group = "objects")] "books",
+	#[cfg(emojic_group = "smileys_and_emotion")] "boom",
+	#[cfg(emojic_group = "objects")] "boomerang",
+	#[cfg(emojic_group = "objects")] "boot",
+	#[cfg(emojic_group = "flags")] "bosnia_herzegovina",
+	#[cfg(emojic_group = "flags")] "botswana",
+	#[cfg(emojic_group = "food_and_drink")] "bottle_with_popping_cork",
+	#[cfg(emojic_group = "people_and_body")] "bouncing_ball_man",
+	#[cfg(emojic_group = "people_and_body")] "bouncing_ball_person",
+	#[cfg(emojic_group = "people_and_body")] "bouncing_ball_woman",
+	#[cfg(emojic_group = "animals_and_nature")] "bouquet",
+	#[cfg(emojic_group = "flags")] "bouvet_island",
+	#[cfg(emojic_group = "people_and_body")] "bow",
+	#[cfg(emojic_group = "objects")] "bow_and_arrow",
+	#[cfg(emojic_group = "people_and_body")] "bowing_man",
+	#[cfg(emojic_group = "people_and_body")] "bowing_woman",
+	#[cfg(emojic_group = "food_and_drink")] "bowl_with_spoon",
+	#[cfg(emojic_group = "activities")] "bowling",
+	#[cfg(emojic_group = "activities")] "boxing_glove",
+	#[cfg(emojic_group = "people_and_body")] "boy",
+	#[cfg(emojic_group = "people_and_body")] "brain",
+	#[cfg(emojic_group = "flags")] "brazil",
+	#[cfg(emojic_group = "food_and_drink")] "bread",
+	#[cfg(emojic_group = "people_and_body")] "breast_feeding",
+	#[cfg(emojic_group = "travel_and_places")] "brick",
+	#[cfg(emojic_group = "travel_and_places")] "bricks",
+	#[cfg(emojic_group = "people_and_body")] "bride_with_veil",
+	#[cfg(emojic_group = "travel_and_places")] "bridge_at_night",
+	#[cfg(emojic_group = "objects")] "briefcase",
+	#[cfg(emojic_group = "objects")] "briefs",
+	#[cfg(emojic_group = "symbols")] "bright_button",
+	#[cfg(emojic_group = "flags")] "british_indian_ocean_territory",
+	#[cfg(emojic_group = "flags")] "british_virgin_islands",
+	#[cfg(emojic_group = "food_and_drink")] "broccoli",
+	#[cfg(emojic_group = "smileys_and_emotion")] "broken_heart",
+	#[cfg(emojic_group = "objects")] "broom",
+	#[cfg(emojic_group = "symbols")] "brown_circle",
+	#[cfg(emojic_group = "smileys_and_emotion")] "brown_heart",
+	#[cfg(emojic_group = "symbols")] "brown_square",
+	#[cfg(emojic_group = "flags")] "brunei",
+	#[cfg(emojic_group = "food_and_drink")] "bubble_tea",
+	#[cfg(emojic_group = "objects")] "bubbles",
+	#[cfg(emojic_group = "objects")] "bucket",
+	#[cfg(emojic_group = "animals_and_nature")] "bug",
+	#[cfg(emojic_group = "travel_and_places")] "building_construction",
+	#[cfg(emojic_group = "objects")] "bulb",
+	#[cfg(emojic_group = "flags")] "bulgaria",
+	#[cfg(emojic_group = "travel_and_places")] "bullet_train",
+	#[cfg(emojic_group = "travel_and_places")] "bullettrain_front",
+	#[cfg(emojic_group = "travel_and_places")] "bullettrain_side",
+	#[cfg(emojic_group = "activities")] "bullseye",
+	#[cfg(emojic_group = "flags")] "burkina_faso",
+	#[cfg(emojic_group = "food_and_drink")] "burrito",
+	#[cfg(emojic_group = "flags")] "burundi",
+	#[cfg(emojic_group = "travel_and_places")] "bus",
+	#[cfg(emojic_group = "travel_and_places")] "bus_stop",
+	#[cfg(emojic_group = "people_and_body")] "business_suit_levitating",
+	#[cfg(emojic_group = "travel_and_places")] "busstop",
+	#[cfg(emojic_group = "people_and_body")] "bust_in_silhouette",
+	#[cfg(emojic_group = "people_and_body")] "busts_in_silhouette",
+	#[cfg(emojic_group = "food_and_drink")] "butter",
+	#[cfg(emojic_group = "animals_and_nature")] "butterfly",
+	#[cfg(emojic_group = "animals_and_nature")] "cactus",
+	#[cfg(emojic_group = "food_and_drink")] "cake",
+	#[cfg(emojic_group = "objects")] "calendar",
+	#[cfg(emojic_group = "people_and_body")] "call_me_hand",
+	#[cfg(emojic_group = "objects")] "calling",
+	#[cfg(emojic_group = "flags")] "cambodia",
+	#[cfg(emojic_group = "animals_and_nature")] "camel",
+	#[cfg(emojic_group = "objects")] "camera",
+	#[cfg(emojic_group = "objects")] "camera_flash",
+	#[cfg(emojic_group = "objects")] "camera_with_flash",
+	#[cfg(emojic_group = "flags")] "cameroon",
+	#[cfg(emojic_group = "travel_and_places")] "camping",
+	#[cfg(emojic_group = "flags")] "canada",
+	#[cfg(emojic_group = "flags")] "canary_islands",
+	#[cfg(emojic_group = "symbols")] "cancer",
+	#[cfg(emojic_group = "objects")] "candle",
+	#[cfg(emojic_group = "food_and_drink")] "candy",
+	#[cfg(emojic_group = "food_and_drink")] "canned_food",
+	#[cfg(emojic_group = "travel_and_places")] "canoe",
+	#[cfg(emojic_group = "flags")] "cape_verde",
+	#[cfg(emojic_group = "symbols")] "capital_abcd",
+	#[cfg(emojic_group = "symbols")] "capricorn",
+	#[cfg(emojic_group = "travel_and_places")] "car",
+	#[cfg(emojic_group = "objects")] "card_file_box",
+	#[cfg(emojic_group = "objects")] "card_index",
+	#[cfg(emojic_group = "objects")] "card_index_dividers",
+	#[cfg(emojic_group = "flags")] "caribbean_netherlands",
+	#[cfg(emojic_group = "travel_and_places")] "carousel_horse",
+	#[cfg(emojic_group = "activities")] "carp_streamer",
+	#[cfg(emojic_group = "objects")] "carpentry_saw",
+	#[cfg(emojic_group = "food_and_drink")] "carrot",
+	#[cfg(emojic_group = "people_and_body")] "cartwheeling",
+	#[cfg(emojic_group = "travel_and_places")] "castle",
+	#[cfg(emojic_group = "animals_and_nature")] "cat",
+	#[cfg(emojic_group = "animals_and_nature")] "cat2",
+	#[cfg(emojic_group = "animals_and_nature")] "cat_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "cat_with_tears_of_joy",
+	#[cfg(emojic_group = "smileys_and_emotion")] "cat_with_wry_smile",
+	#[cfg(emojic_group = "flags")] "cayman_islands",
+	#[cfg(emojic_group = "objects")] "cd",
+	#[cfg(emojic_group = "flags")] "central_african_republic",
+	#[cfg(emojic_group = "flags")] "ceuta_melilla",
+	#[cfg(emojic_group = "flags")] "chad",
+	#[cfg(emojic_group = "objects")] "chains",
+	#[cfg(emojic_group = "objects")] "chair",
+	#[cfg(emojic_group = "food_and_drink")] "champagne",
+	#[cfg(emojic_group = "objects")] "chart",
+	#[cfg(emojic_group = "objects")] "chart_decreasing",
+	#[cfg(emojic_group = "objects")] "chart_increasing",
+	#[cfg(emojic_group = "objects")] "chart_increasing_with_yen",
+	#[cfg(emojic_group = "objects")] "chart_with_downwards_trend",
+	#[cfg(emojic_group = "objects")] "chart_with_upwards_trend",
+	#[cfg(emojic_group = "symbols")] "check_box_with_check",
+	#[cfg(emojic_group = "symbols")] "check_mark",
+	#[cfg(emojic_group = "symbols")] "check_mark_button",
+	#[cfg(emojic_group = "flags")] "checkered_flag",
+	#[cfg(emojic_group = "food_and_drink")] "cheese",
+	#[cfg(emojic_group = "food_and_drink")] "cheese_wedge",
+	#[cfg(emojic_group = "flags")] "chequered_flag",
+	#[cfg(emojic_group = "food_and_drink")] "cherries",
+	#[cfg(emojic_group = "animals_and_nature")] "cherry_blossom",
+	#[cfg(emojic_group = "activities")] "chess_pawn",
+	#[cfg(emojic_group = "food_and_drink")] "chestnut",
+	#[cfg(emojic_group = "animals_and_nature")] "chicken",
+	#[cfg(emojic_group = "people_and_body")] "child",
+	#[cfg(emojic_group = "symbols")] "children_crossing",
+	#[cfg(emojic_group = "flags")] "chile",
+	#[cfg(emojic_group = "animals_and_nature")] "chipmunk",
+	#[cfg(emojic_group = "food_and_drink")] "chocolate_bar",
+	#[cfg(emojic_group = "food_and_drink")] "chopsticks",
+	#[cfg(emojic_group = "flags")] "christmas_island",
+	#[cfg(emojic_group = "activities")] "christmas_tree",
+	#[cfg(emojic_group = "travel_and_places")] "church",
+	#[cfg(emojic_group = "objects")] "cigarette",
+	#[cfg(emojic_group = "symbols")] "cinema",
+	#[cfg(emojic_group = "symbols")] "circled_m",
+	#[cfg(emojic_group = "travel_and_places")] "circus_tent",
+	#[cfg(emojic_group = "travel_and_places")] "city_sunrise",
+	#[cfg(emojic_group = "travel_and_places")] "city_sunset",
+	#[cfg(emojic_group = "travel_and_places")] "cityscape",
+	#[cfg(emojic_group = "travel_and_places")] "cityscape_at_dusk",
+	#[cfg(emojic_group = "symbols")] "cl",
+	#[cfg(emojic_group = "symbols")] "cl_button",
+	#[cfg(emojic_group = "objects")] "clamp",
+	#[cfg(emojic_group = "people_and_body")] "clap",
+	#[cfg(emojic_group = "objects")] "clapper",
+	#[cfg(emojic_group = "objects")] "clapper_board",
+	#[cfg(emojic_group = "people_and_body")] "clapping_hands",
+	#[cfg(emojic_group = "travel_and_places")] "classical_building",
+	#[cfg(emojic_group = "people_and_body")] "climbing",
+	#[cfg(emojic_group = "people_and_body")] "climbing_man",
+	#[cfg(emojic_group = "people_and_body")] "climbing_woman",
+	#[cfg(emojic_group = "food_and_drink")] "clinking_beer_mugs",
+	#[cfg(emojic_group = "food_and_drink")] "clinking_glasses",
+	#[cfg(emojic_group = "objects")] "clipboard",
+	#[cfg(emojic_group = "flags")] "clipperton_island",
+	#[cfg(emojic_group = "travel_and_places")] "clock1",
+	#[cfg(emojic_group = "travel_and_places")] "clock10",
+	#[cfg(emojic_group = "travel_and_places")] "clock1030",
+	#[cfg(emojic_group = "travel_and_places")] "clock11",
+	#[cfg(emojic_group = "travel_and_places")] "clock1130",
+	#[cfg(emojic_group = "travel_and_places")] "clock12",
+	#[cfg(emojic_group = "travel_and_places")] "clock1230",
+	#[cfg(emojic_group = "travel_and_places")] "clock130",
+	#[cfg(emojic_group = "travel_and_places")] "clock2",
+	#[cfg(emojic_group = "travel_and_places")] "clock230",
+	#[cfg(emojic_group = "travel_and_places")] "clock3",
+	#[cfg(emojic_group = "travel_and_places")] "clock330",
+	#[cfg(emojic_group = "travel_and_places")] "clock4",
+	#[cfg(emojic_group = "travel_and_places")] "clock430",
+	#[cfg(emojic_group = "travel_and_places")] "clock5",
+	#[cfg(emojic_group = "travel_and_places")] "clock530",
+	#[cfg(emojic_group = "travel_and_places")] "clock6",
+	#[cfg(emojic_group = "travel_and_places")] "clock630",
+	#[cfg(emojic_group = "travel_and_places")] "clock7",
+	#[cfg(emojic_group = "travel_and_places")] "clock730",
+	#[cfg(emojic_group = "travel_and_places")] "clock8",
+	#[cfg(emojic_group = "travel_and_places")] "clock830",
+	#[cfg(emojic_group = "travel_and_places")] "clock9",
+	#[cfg(emojic_group = "travel_and_places")] "clock930",
+	#[cfg(emojic_group = "symbols")] "clockwise_vertical_arrows",
+	#[cfg(emojic_group = "objects")] "closed_book",
+	#[cfg(emojic_group = "objects")] "closed_lock_with_key",
+	#[cfg(emojic_group = "objects")] "closed_mailbox_with_lowered_flag",
+	#[cfg(emojic_group = "objects")] "closed_mailbox_with_raised_flag",
+	#[cfg(emojic_group = "travel_and_places")] "closed_umbrella",
+	#[cfg(emojic_group = "travel_and_places")] "cloud",
+	#[cfg(emojic_group = "travel_and_places")] "cloud_with_lightning",
+	#[cfg(emojic_group = "travel_and_places")] "cloud_with_lightning_and_rain",
+	#[cfg(emojic_group = "travel_and_places")] "cloud_with_rain",
+	#[cfg(emojic_group = "travel_and_places")] "cloud_with_snow",
+	#[cfg(emojic_group = "smileys_and_emotion")] "clown_face",
+	#[cfg(emojic_group = "activities")] "club_suit",
+	#[cfg(emojic_group = "activities")] "clubs",
+	#[cfg(emojic_group = "objects")] "clutch_bag",
+	#[cfg(emojic_group = "flags")] "cn",
+	#[cfg(emojic_group = "objects")] "coat",
+	#[cfg(emojic_group = "animals_and_nature")] "cockroach",
+	#[cfg(emojic_group = "food_and_drink")] "cocktail",
+	#[cfg(emojic_group = "food_and_drink")] "cocktail_glass",
+	#[cfg(emojic_group = "food_and_drink")] "coconut",
+	#[cfg(emojic_group = "flags")] "cocos_islands",
+	#[cfg(emojic_group = "food_and_drink")] "coffee",
+	#[cfg(emojic_group = "objects")] "coffin",
+	#[cfg(emojic_group = "objects")] "coin",
+	#[cfg(emojic_group = "smileys_and_emotion")] "cold_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "cold_sweat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "collision",
+	#[cfg(emojic_group = "flags")] "colombia",
+	#[cfg(emojic_group = "travel_and_places")] "comet",
+	#[cfg(emojic_group = "flags")] "comoros",
+	#[cfg(emojic_group = "travel_and_places")] "compass",
+	#[cfg(emojic_group = "objects")] "computer",
+	#[cfg(emojic_group = "objects")] "computer_disk",
+	#[cfg(emojic_group = "objects")] "computer_mouse",
+	#[cfg(emojic_group = "activities")] "confetti_ball",
+	#[cfg(emojic_group = "smileys_and_emotion")] "confounded",
+	#[cfg(emojic_group = "smileys_and_emotion")] "confounded_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "confused",
+	#[cfg(emojic_group = "smileys_and_emotion")] "confused_face",
+	#[cfg(emojic_group = "flags")] "congo_brazzaville",
+	#[cfg(emojic_group = "flags")] "congo_kinshasa",
+	#[cfg(emojic_group = "symbols")] "congratulations",
+	#[cfg(emojic_group = "travel_and_places")] "construction",
+	#[cfg(emojic_group = "people_and_body")] "construction_worker",
+	#[cfg(emojic_group = "people_and_body")] "construction_worker_man",
+	#[cfg(emojic_group = "people_and_body")] "construction_worker_woman",
+	#[cfg(emojic_group = "objects")] "control_knobs",
+	#[cfg(emojic_group = "travel_and_places")] "convenience_store",
+	#[cfg(emojic_group = "people_and_body")] "cook",
+	#[cfg(emojic_group = "flags")] "cook_islands",
+	#[cfg(emojic_group = "food_and_drink")] "cooked_rice",
+	#[cfg(emojic_group = "food_and_drink")] "cookie",
+	#[cfg(emojic_group = "food_and_drink")] "cooking",
+	#[cfg(emojic_group = "symbols")] "cool",
+	#[cfg(emojic_group = "symbols")] "cool_button",
+	#[cfg(emojic_group = "people_and_body")] "cop",
+	#[cfg(emojic_group = "symbols")] "copyright",
+	#[cfg(emojic_group = "animals_and_nature")] "coral",
+	#[cfg(emojic_group = "food_and_drink")] "corn",
+	#[cfg(emojic_group = "flags")] "costa_rica",
+	#[cfg(emojic_group = "flags")] "cote_divoire",
+	#[cfg(emojic_group = "objects")] "couch_and_lamp",
+	#[cfg(emojic_group = "symbols")] "counterclockwise_arrows_button",
+	#[cfg(emojic_group = "people_and_body")] "couple",
+	#[cfg(emojic_group = "people_and_body")] "couple_with_heart",
+	#[cfg(emojic_group = "people_and_body")] "couple_with_heart_man_man",
+	#[cfg(emojic_group = "people_and_body")] "couple_with_heart_woman_man",
+	#[cfg(emojic_group = "people_and_body")] "couple_with_heart_woman_woman",
+	#[cfg(emojic_group = "people_and_body")] "couplekiss",
+	#[cfg(emojic_group = "people_and_body")] "couplekiss_man_man",
+	#[cfg(emojic_group = "people_and_body")] "couplekiss_man_woman",
+	#[cfg(emojic_group = "people_and_body")] "couplekiss_woman_woman",
+	#[cfg(emojic_group = "animals_and_nature")] "cow",
+	#[cfg(emojic_group = "animals_and_nature")] "cow2",
+	#[cfg(emojic_group = "animals_and_nature")] "cow_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "cowboy_hat_face",
+	#[cfg(emojic_group = "food_and_drink")] "crab",
+	#[cfg(emojic_group = "objects")] "crayon",
+	#[cfg(emojic_group = "objects")] "credit_card",
+	#[cfg(emojic_group = "travel_and_places")] "crescent_moon",
+	#[cfg(emojic_group = "animals_and_nature")] "cricket",
+	#[cfg(emojic_group = "activities")] "cricket_game",
+	#[cfg(emojic_group = "flags")] "croatia",
+	#[cfg(emojic_group = "animals_and_nature")] "crocodile",
+	#[cfg(emojic_group = "food_and_drink")] "croissant",
+	#[cfg(emojic_group = "symbols")] "cross_mark",
+	#[cfg(emojic_group = "symbols")] "cross_mark_button",
+	#[cfg(emojic_group = "people_and_body")] "crossed_fingers",
+	#[cfg(emojic_group = "flags")] "crossed_flags",
+	#[cfg(emojic_group = "objects")] "crossed_swords",
+	#[cfg(emojic_group = "objects")] "crown",
+	#[cfg(emojic_group = "objects")] "crutch",
+	#[cfg(emojic_group = "smileys_and_emotion")] "cry",
+	#[cfg(emojic_group = "smileys_and_emotion")] "crying_cat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "crying_cat_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "crying_face",
+	#[cfg(emojic_group = "activities")] "crystal_ball",
+	#[cfg(emojic_group = "flags")] "cuba",
+	#[cfg(emojic_group = "food_and_drink")] "cucumber",
+	#[cfg(emojic_group = "food_and_drink")] "cup_with_straw",
+	#[cfg(emojic_group = "food_and_drink")] "cupcake",
+	#[cfg(emojic_group = "smileys_and_emotion")] "cupid",
+	#[cfg(emojic_group = "flags")] "curacao",
+	#[cfg(emojic_group = "activities")] "curling_stone",
+	#[cfg(emojic_group = "component")] "curly_hair",
+	#[cfg(emojic_group = "people_and_body")] "curly_haired_man",
+	#[cfg(emojic_group = "people_and_body")] "curly_haired_woman",
+	#[cfg(emojic_group = "symbols")] "curly_loop",
+	#[cfg(emojic_group = "symbols")] "currency_exchange",
+	#[cfg(emojic_group = "food_and_drink")] "curry",
+	#[cfg(emojic_group = "food_and_drink")] "curry_rice",
+	#[cfg(emojic_group = "smileys_and_emotion")] "cursing_face",
+	#[cfg(emojic_group = "food_and_drink")] "custard",
+	#[cfg(emojic_group = "symbols")] "customs",
+	#[cfg(emojic_group = "food_and_drink")] "cut_of_meat",
+	#[cfg(emojic_group = "travel_and_places")] "cyclone",
+	#[cfg(emojic_group = "flags")] "cyprus",
+	#[cfg(emojic_group = "flags")] "czech_republic",
+	#[cfg(emojic_group = "objects")] "dagger",
+	#[cfg(emojic_group = "people_and_body")] "dancer",
+	#[cfg(emojic_group = "people_and_body")] "dancers",
+	#[cfg(emojic_group = "people_and_body")] "dancing_men",
+	#[cfg(emojic_group = "people_and_body")] "dancing_women",
+	#[cfg(emojic_group = "food_and_drink")] "dango",
+	#[cfg(emojic_group = "component")] "dark_skin_tone",
+	#[cfg(emojic_group = "objects")] "dark_sunglasses",
+	#[cfg(emojic_group = "activities")] "dart",
+	#[cfg(emojic_group = "smileys_and_emotion")] "dash",
+	#[cfg(emojic_group = "smileys_and_emotion")] "dashing_away",
+	#[cfg(emojic_group = "objects")] "date",
+	#[cfg(emojic_group = "flags")] "de",
+	#[cfg(emojic_group = "people_and_body")] "deaf_man",
+	#[cfg(emojic_group = "people_and_body")] "deaf_person",
+	#[cfg(emojic_group = "people_and_body")] "deaf_woman",
+	#[cfg(emojic_group = "animals_and_nature")] "deciduous_tree",
+	#[cfg(emojic_group = "animals_and_nature")] "deer",
+	#[cfg(emojic_group = "travel_and_places")] "delivery_truck",
+	#[cfg(emojic_group = "flags")] "denmark",
+	#[cfg(emojic_group = "travel_and_places")] "department_store",
+	#[cfg(emojic_group = "travel_and_places")] "derelict_house",
+	#[cfg(emojic_group = "travel_and_places")] "desert",
+	#[cfg(emojic_group = "travel_and_places")] "desert_island",
+	#[cfg(emojic_group = "objects")] "desktop_computer",
+	#[cfg(emojic_group = "people_and_body")] "detective",
+	#[cfg(emojic_group = "symbols")] "diamond_shape_with_a_dot_inside",
+	#[cfg(emojic_group = "activities")] "diamond_suit",
+	#[cfg(emojic_group = "symbols")] "diamond_with_a_dot",
+	#[cfg(emojic_group = "activities")] "diamonds",
+	#[cfg(emojic_group = "flags")] "diego_garcia",
+	#[cfg(emojic_group = "symbols")] "dim_button",
+	#[cfg(emojic_group = "smileys_and_emotion")] "disappointed",
+	#[cfg(emojic_group = "smileys_and_emotion")] "disappointed_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "disappointed_relieved",
+	#[cfg(emojic_group = "smileys_and_emotion")] "disguised_face",
+	#[cfg(emojic_group = "symbols")] "divide",
+	#[cfg(emojic_group = "activities")] "diving_mask",
+	#[cfg(emojic_group = "objects")] "diya_lamp",
+	#[cfg(emojic_group = "smileys_and_emotion")] "dizzy",
+	#[cfg(emojic_group = "smileys_and_emotion")] "dizzy_face",
+	#[cfg(emojic_group = "flags")] "djibouti",
+	#[cfg(emojic_group = "objects")] "dna",
+	#[cfg(emojic_group = "symbols")] "do_not_litter",
+	#[cfg(emojic_group = "animals_and_nature")] "dodo",
+	#[cfg(emojic_group = "animals_and_nature")] "dog",
+	#[cfg(emojic_group = "animals_and_nature")] "dog2",
+	#[cfg(emojic_group = "animals_and_nature")] "dog_face",
+	#[cfg(emojic_group = "objects")] "dollar",
+	#[cfg(emojic_group = "objects")] "dollar_banknote",
+	#[cfg(emojic_group = "activities")] "dolls",
+	#[cfg(emojic_group = "animals_and_nature")] "dolphin",
+	#[cfg(emojic_group = "flags")] "dominica",
+	#[cfg(emojic_group = "flags")] "dominican_republic",
+	#[cfg(emojic_group = "objects")] "door",
+	#[cfg(emojic_group = "smileys_and_emotion")] "dotted_line_face",
+	#[cfg(emojic_group = "symbols")] "dotted_six_pointed_star",
+	#[cfg(emojic_group = "symbols")] "double_curly_loop",
+	#[cfg(emojic_group = "symbols")] "double_exclamation_mark",
+	#[cfg(emojic_group = "food_and_drink")] "doughnut",
+	#[cfg(emojic_group = "animals_and_nature")] "dove",
+	#[cfg(emojic_group = "symbols")] "down_arrow",
+	#[cfg(emojic_group = "symbols")] "down_left_arrow",
+	#[cfg(emojic_group = "symbols")] "down_right_arrow",
+	#[cfg(emojic_group = "smileys_and_emotion")] "downcast_face_with_sweat",
+	#[cfg(emojic_group = "symbols")] "downwards_button",
+	#[cfg(emojic_group = "animals_and_nature")] "dragon",
+	#[cfg(emojic_group = "animals_and_nature")] "dragon_face",
+	#[cfg(emojic_group = "objects")] "dress",
+	#[cfg(emojic_group = "animals_and_nature")] "dromedary_camel",
+	#[cfg(emojic_group = "smileys_and_emotion")] "drooling_face",
+	#[cfg(emojic_group = "objects")] "drop_of_blood",
+	#[cfg(emojic_group = "travel_and_places")] "droplet",
+	#[cfg(emojic_group = "objects")] "drum",
+	#[cfg(emojic_group = "animals_and_nature")] "duck",
+	#[cfg(emojic_group = "food_and_drink")] "dumpling",
+	#[cfg(emojic_group = "objects")] "dvd",
+	#[cfg(emojic_group = "objects")] "e-mail",
+	#[cfg(emojic_group = "objects")] "e_mail",
+	#[cfg(emojic_group = "animals_and_nature")] "eagle",
+	#[cfg(emojic_group = "people_and_body")] "ear",
+	#[cfg(emojic_group = "food_and_drink")] "ear_of_corn",
+	#[cfg(emojic_group = "animals_and_nature")] "ear_of_rice",
+	#[cfg(emojic_group = "people_and_body")] "ear_with_hearing_aid",
+	#[cfg(emojic_group = "travel_and_places")] "earth_africa",
+	#[cfg(emojic_group = "travel_and_places")] "earth_americas",
+	#[cfg(emojic_group = "travel_and_places")] "earth_asia",
+	#[cfg(emojic_group = "flags")] "ecuador",
+	#[cfg(emojic_group = "food_and_drink")] "egg",
+	#[cfg(emojic_group = "food_and_drink")] "eggplant",
+	#[cfg(emojic_group = "flags")] "egypt",
+	#[cfg(emojic_group = "symbols")] "eight",
+	#[cfg(emojic_group = "travel_and_places")] "eight_o_clock",
+	#[cfg(emojic_group = "symbols")] "eight_pointed_black_star",
+	#[cfg(emojic_group = "symbols")] "eight_pointed_star",
+	#[cfg(emojic_group = "symbols")] "eight_spoked_asterisk",
+	#[cfg(emojic_group = "travel_and_places")] "eight_thirty",
+	#[cfg(emojic_group = "symbols")] "eject_button",
+	#[cfg(emojic_group = "flags")] "el_salvador",
+	#[cfg(emojic_group = "objects")] "electric_plug",
+	#[cfg(emojic_group = "animals_and_nature")] "elephant",
+	#[cfg(emojic_group = "objects")] "elevator",
+	#[cfg(emojic_group = "travel_and_places")] "eleven_o_clock",
+	#[cfg(emojic_group = "travel_and_places")] "eleven_thirty",
+	#[cfg(emojic_group = "people_and_body")] "elf",
+	#[cfg(emojic_group = "people_and_body")] "elf_man",
+	#[cfg(emojic_group = "people_and_body")] "elf_woman",
+	#[cfg(emojic_group = "objects")] "email",
+	#[cfg(emojic_group = "animals_and_nature")] "empty_nest",
+	#[cfg(emojic_group = "symbols")] "end",
+	#[cfg(emojic_group = "symbols")] "end_arrow",
+	#[cfg(emojic_group = "flags")] "england",
+	#[cfg(emojic_group = "objects")] "envelope",
+	#[cfg(emojic_group = "objects")] "envelope_with_arrow",
+	#[cfg(emojic_group = "flags")] "equatorial_guinea",
+	#[cfg(emojic_group = "flags")] "eritrea",
+	#[cfg(emojic_group = "flags")] "es",
+	#[cfg(emojic_group = "flags")] "estonia",
+	#[cfg(emojic_group = "flags")] "ethiopia",
+	#[cfg(emojic_group = "flags")] "eu",
+	#[cfg(emojic_group = "objects")] "euro",
+	#[cfg(emojic_group = "objects")] "euro_banknote",
+	#[cfg(emojic_group = "travel_and_places")] "european_castle",
+	#[cfg(emojic_group = "travel_and_places")] "european_post_office",
+	#[cfg(emojic_group = "flags")] "european_union",
+	#[cfg(emojic_group = "animals_and_nature")] "evergreen_tree",
+	#[cfg(emojic_group = "animals_and_nature")] "ewe",
+	#[cfg(emojic_group = "symbols")] "exclamation",
+	#[cfg(emojic_group = "symbols")] "exclamation_question_mark",
+	#[cfg(emojic_group = "smileys_and_emotion")] "exploding_head",
+	#[cfg(emojic_group = "smileys_and_emotion")] "expressionless",
+	#[cfg(emojic_group = "smileys_and_emotion")] "expressionless_face",
+	#[cfg(emojic_group = "people_and_body")] "eye",
+	#[cfg(emojic_group = "smileys_and_emotion")] "eye_in_speech_bubble",
+	#[cfg(emojic_group = "smileys_and_emotion")] "eye_speech_bubble",
+	#[cfg(emojic_group = "objects")] "eyeglasses",
+	#[cfg(emojic_group = "people_and_body")] "eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_blowing_a_kiss",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_exhaling",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_holding_back_tears",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_in_clouds",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_savoring_food",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_screaming_in_fear",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_vomiting",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_diagonal_mouth",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_hand_over_mouth",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_head_bandage",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_medical_mask",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_monocle",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_open_eyes_and_hand_over_mouth",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_open_mouth",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_peeking_eye",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_raised_eyebrow",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_rolling_eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_spiral_eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_steam_from_nose",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_symbols_on_mouth",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_tears_of_joy",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_thermometer",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_with_tongue",
+	#[cfg(emojic_group = "smileys_and_emotion")] "face_without_mouth",
+	#[cfg(emojic_group = "people_and_body")] "facepalm",
+	#[cfg(emojic_group = "people_and_body")] "facepunch",
+	#[cfg(emojic_group = "travel_and_places")] "factory",
+	#[cfg(emojic_group = "people_and_body")] "factory_worker",
+	#[cfg(emojic_group = "people_and_body")] "fairy",
+	#[cfg(emojic_group = "people_and_body")] "fairy_man",
+	#[cfg(emojic_group = "people_and_body")] "fairy_woman",
+	#[cfg(emojic_group = "food_and_drink")] "falafel",
+	#[cfg(emojic_group = "flags")] "falkland_islands",
+	#[cfg(emojic_group = "animals_and_nature")] "fallen_leaf",
+	#[cfg(emojic_group = "people_and_body")] "family",
+	#[cfg(emojic_group = "people_and_body")] "family_man_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_man_boy_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_man_girl",
+	#[cfg(emojic_group = "people_and_body")] "family_man_girl_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_man_girl_girl",
+	#[cfg(emojic_group = "people_and_body")] "family_man_man_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_man_man_boy_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_man_man_girl",
+	#[cfg(emojic_group = "people_and_body")] "family_man_man_girl_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_man_man_girl_girl",
+	#[cfg(emojic_group = "people_and_body")] "family_man_woman_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_man_woman_boy_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_man_woman_girl",
+	#[cfg(emojic_group = "people_and_body")] "family_man_woman_girl_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_man_woman_girl_girl",
+	#[cfg(emojic_group = "people_and_body")] "family_woman_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_woman_boy_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_woman_girl",
+	#[cfg(emojic_group = "people_and_body")] "family_woman_girl_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_woman_girl_girl",
+	#[cfg(emojic_group = "people_and_body")] "family_woman_woman_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_woman_woman_boy_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_woman_woman_girl",
+	#[cfg(emojic_group = "people_and_body")] "family_woman_woman_girl_boy",
+	#[cfg(emojic_group = "people_and_body")] "family_woman_woman_girl_girl",
+	#[cfg(emojic_group = "people_and_body")] "farmer",
+	#[cfg(emojic_group = "flags")] "faroe_islands",
+	#[cfg(emojic_group = "symbols")] "fast_down_button",
+	#[cfg(emojic_group = "symbols")] "fast_forward",
+	#[cfg(emojic_group = "symbols")] "fast_forward_button",
+	#[cfg(emojic_group = "symbols")] "fast_reverse_button",
+	#[cfg(emojic_group = "symbols")] "fast_up_button",
+	#[cfg(emojic_group = "objects")] "fax",
+	#[cfg(emojic_group = "objects")] "fax_machine",
+	#[cfg(emojic_group = "smileys_and_emotion")] "fearful",
+	#[cfg(emojic_group = "smileys_and_emotion")] "fearful_face",
+	#[cfg(emojic_group = "animals_and_nature")] "feather",
+	#[cfg(emojic_group = "animals_and_nature")] "feet",
+	#[cfg(emojic_group = "people_and_body")] "female_detective",
+	#[cfg(emojic_group = "symbols")] "female_sign",
+	#[cfg(emojic_group = "travel_and_places")] "ferris_wheel",
+	#[cfg(emojic_group = "travel_and_places")] "ferry",
+	#[cfg(emojic_group = "activities")] "field_hockey",
+	#[cfg(emojic_group = "flags")] "fiji",
+	#[cfg(emojic_group = "objects")] "file_cabinet",
+	#[cfg(emojic_group = "objects")] "file_folder",
+	#[cfg(emojic_group = "objects")] "film_frames",
+	#[cfg(emojic_group = "objects")] "film_projector",
+	#[cfg(emojic_group = "objects")] "film_strip",
+	#[cfg(emojic_group = "flags")] "finland",
+	#[cfg(emojic_group = "travel_and_places")] "fire",
+	#[cfg(emojic_group = "travel_and_places")] "fire_engine",
+	#[cfg(emojic_group = "objects")] "fire_extinguisher",
+	#[cfg(emojic_group = "activities")] "firecracker",
+	#[cfg(emojic_group = "people_and_body")] "firefighter",
+	#[cfg(emojic_group = "activities")] "fireworks",
+	#[cfg(emojic_group = "activities")] "first_place_medal",
+	#[cfg(emojic_group = "travel_and_places")] "first_quarter_moon",
+	#[cfg(emojic_group = "travel_and_places")] "first_quarter_moon_face",
+	#[cfg(emojic_group = "travel_and_places")] "first_quarter_moon_with_face",
+	#[cfg(emojic_group = "animals_and_nature")] "fish",
+	#[cfg(emojic_group = "food_and_drink")] "fish_cake",
+	#[cfg(emojic_group = "food_and_drink")] "fish_cake_with_swirl",
+	#[cfg(emojic_group = "activities")] "fishing_pole",
+	#[cfg(emojic_group = "activities")] "fishing_pole_and_fish",
+	#[cfg(emojic_group = "people_and_body")] "fist",
+	#[cfg(emojic_group = "people_and_body")] "fist_left",
+	#[cfg(emojic_group = "people_and_body")] "fist_oncoming",
+	#[cfg(emojic_group = "people_and_body")] "fist_raised",
+	#[cfg(emojic_group = "people_and_body")] "fist_right",
+	#[cfg(emojic_group = "symbols")] "five",
+	#[cfg(emojic_group = "travel_and_places")] "five_o_clock",
+	#[cfg(emojic_group = "travel_and_places")] "five_thirty",
+	#[cfg(emojic_group = "flags")] "flag_afghanistan",
+	#[cfg(emojic_group = "flags")] "flag_aland_islands",
+	#[cfg(emojic_group = "flags")] "flag_albania",
+	#[cfg(emojic_group = "flags")] "flag_algeria",
+	#[cfg(emojic_group = "flags")] "flag_american_samoa",
+	#[cfg(emojic_group = "flags")] "flag_andorra",
+	#[cfg(emojic_group = "flags")] "flag_angola",
+	#[cfg(emojic_group = "flags")] "flag_anguilla",
+	#[cfg(emojic_group = "flags")] "flag_antarctica",
+	#[cfg(emojic_group = "flags")] "flag_antigua_and_barbuda",
+	#[cfg(emojic_group = "flags")] "flag_argentina",
+	#[cfg(emojic_group = "flags")] "flag_armenia",
+	#[cfg(emojic_group = "flags")] "flag_aruba",
+	#[cfg(emojic_group = "flags")] "flag_ascension_island",
+	#[cfg(emojic_group = "flags")] "flag_australia",
+	#[cfg(emojic_group = "flags")] "flag_austria",
+	#[cfg(emojic_group = "flags")] "flag_azerbaijan",
+	#[cfg(emojic_group = "flags")] "flag_bahamas",
+	#[cfg(emojic_group = "flags")] "flag_bahrain",
+	#[cfg(emojic_group = "flags")] "flag_bangladesh",
+	#[cfg(emojic_group = "flags")] "flag_barbados",
+	#[cfg(emojic_group = "flags")] "flag_belarus",
+	#[cfg(emojic_group = "flags")] "flag_belgium",
+	#[cfg(emojic_group = "flags")] "flag_belize",
+	#[cfg(emojic_group = "flags")] "flag_benin",
+	#[cfg(emojic_group = "flags")] "flag_bermuda",
+	#[cfg(emojic_group = "flags")] "flag_bhutan",
+	#[cfg(emojic_group = "flags")] "flag_bolivia",
+	#[cfg(emojic_group = "flags")] "flag_bosnia_and_herzegovina",
+	#[cfg(emojic_group = "flags")] "flag_botswana",
+	#[cfg(emojic_group = "flags")] "flag_bouvet_island",
+	#[cfg(emojic_group = "flags")] "flag_brazil",
+	#[cfg(emojic_group = "flags")] "flag_british_indian_ocean_territory",
+	#[cfg(emojic_group = "flags")] "flag_british_virgin_islands",
+	#[cfg(emojic_group = "flags")] "flag_brunei",
+	#[cfg(emojic_group = "flags")] "flag_bulgaria",
+	#[cfg(emojic_group = "flags")] "flag_burkina_faso",
+	#[cfg(emojic_group = "flags")] "flag_burundi",
+	#[cfg(emojic_group = "flags")] "flag_cambodia",
+	#[cfg(emojic_group = "flags")] "flag_cameroon",
+	#[cfg(emojic_group = "flags")] "flag_canada",
+	#[cfg(emojic_group = "flags")] "flag_canary_islands",
+	#[cfg(emojic_group = "flags")] "flag_cape_verde",
+	#[cfg(emojic_group = "flags")] "flag_caribbean_netherlands",
+	#[cfg(emojic_group = "flags")] "flag_cayman_islands",
+	#[cfg(emojic_group = "flags")] "flag_central_african_republic",
+	#[cfg(emojic_group = "flags")] "flag_ceuta_and_melilla",
+	#[cfg(emojic_group = "flags")] "flag_chad",
+	#[cfg(emojic_group = "flags")] "flag_chile",
+	#[cfg(emojic_group = "flags")] "flag_china",
+	#[cfg(emojic_group = "flags")] "flag_christmas_island",
+	#[cfg(emojic_group = "flags")] "flag_clipperton_island",
+	#[cfg(emojic_group = "flags")] "flag_cocos_keeling_islands",
+	#[cfg(emojic_group = "flags")] "flag_colombia",
+	#[cfg(emojic_group = "flags")] "flag_comoros",
+	#[cfg(emojic_group = "flags")] "flag_congo_brazzaville",
+	#[cfg(emojic_group = "flags")] "flag_congo_kinshasa",
+	#[cfg(emojic_group = "flags")] "flag_cook_islands",
+	#[cfg(emojic_group = "flags")] "flag_costa_rica",
+	#[cfg(emojic_group = "flags")] "flag_cote_d_ivoire",
+	#[cfg(emojic_group = "flags")] "flag_croatia",
+	#[cfg(emojic_group = "flags")] "flag_cuba",
+	#[cfg(emojic_group = "flags")] "flag_curacao",
+	#[cfg(emojic_group = "flags")] "flag_cyprus",
+	#[cfg(emojic_group = "flags")] "flag_czechia",
+	#[cfg(emojic_group = "flags")] "flag_denmark",
+	#[cfg(emojic_group = "flags")] "flag_diego_garcia",
+	#[cfg(emojic_group = "flags")] "flag_djibouti",
+	#[cfg(emojic_group = "flags")] "flag_dominica",
+	#[cfg(emojic_group = "flags")] "flag_dominican_republic",
+	#[cfg(emojic_group = "flags")] "flag_ecuador",
+	#[cfg(emojic_group = "flags")] "flag_egypt",
+	#[cfg(emojic_group = "flags")] "flag_el_salvador",
+	#[cfg(emojic_group = "flags")] "flag_england",
+	#[cfg(emojic_group = "flags")] "flag_equatorial_guinea",
+	#[cfg(emojic_group = "flags")] "flag_eritrea",
+	#[cfg(emojic_group = "flags")] "flag_estonia",
+	#[cfg(emojic_group = "flags")] "flag_eswatini",
+	#[cfg(emojic_group = "flags")] "flag_ethiopia",
+	#[cfg(emojic_group = "flags")] "flag_european_union",
+	#[cfg(emojic_group = "flags")] "flag_falkland_islands",
+	#[cfg(emojic_group = "flags")] "flag_faroe_islands",
+	#[cfg(emojic_group = "flags")] "flag_fiji",
+	#[cfg(emojic_group = "flags")] "flag_finland",
+	#[cfg(emojic_group = "flags")] "flag_france",
+	#[cfg(emojic_group = "flags")] "flag_french_guiana",
+	#[cfg(emojic_group = "flags")] "flag_french_polynesia",
+	#[cfg(emojic_group = "flags")] "flag_french_southern_territories",
+	#[cfg(emojic_group = "flags")] "flag_gabon",
+	#[cfg(emojic_group = "flags")] "flag_gambia",
+	#[cfg(emojic_group = "flags")] "flag_georgia",
+	#[cfg(emojic_group = "flags")] "flag_germany",
+	#[cfg(emojic_group = "flags")] "flag_ghana",
+	#[cfg(emojic_group = "flags")] "flag_gibraltar",
+	#[cfg(emojic_group = "flags")] "flag_greece",
+	#[cfg(emojic_group = "flags")] "flag_greenland",
+	#[cfg(emojic_group = "flags")] "flag_grenada",
+	#[cfg(emojic_group = "flags")] "flag_guadeloupe",
+	#[cfg(emojic_group = "flags")] "flag_guam",
+	#[cfg(emojic_group = "flags")] "flag_guatemala",
+	#[cfg(emojic_group = "flags")] "flag_guernsey",
+	#[cfg(emojic_group = "flags")] "flag_guinea",
+	#[cfg(emojic_group = "flags")] "flag_guinea_bissau",
+	#[cfg(emojic_group = "flags")] "flag_guyana",
+	#[cfg(emojic_group = "flags")] "flag_haiti",
+	#[cfg(emojic_group = "flags")] "flag_heard_and_mcdonald_islands",
+	#[cfg(emojic_group = "flags")] "flag_honduras",
+	#[cfg(emojic_group = "flags")] "flag_hong_kong_sar_china",
+	#[cfg(emojic_group = "flags")] "flag_hungary",
+	#[cfg(emojic_group = "flags")] "flag_iceland",
+	#[cfg(emojic_group = "activities")] "flag_in_hole",
+	#[cfg(emojic_group = "flags")] "flag_india",
+	#[cfg(emojic_group = "flags")] "flag_indonesia",
+	#[cfg(emojic_group = "flags")] "flag_iran",
+	#[cfg(emojic_group = "flags")] "flag_iraq",
+	#[cfg(emojic_group = "flags")] "flag_ireland",
+	#[cfg(emojic_group = "flags")] "flag_isle_of_man",
+	#[cfg(emojic_group = "flags")] "flag_israel",
+	#[cfg(emojic_group = "flags")] "flag_italy",
+	#[cfg(emojic_group = "flags")] "flag_jamaica",
+	#[cfg(emojic_group = "flags")] "flag_japan",
+	#[cfg(emojic_group = "flags")] "flag_jersey",
+	#[cfg(emojic_group = "flags")] "flag_jordan",
+	#[cfg(emojic_group = "flags")] "flag_kazakhstan",
+	#[cfg(emojic_group = "flags")] "flag_kenya",
+	#[cfg(emojic_group = "flags")] "flag_kiribati",
+	#[cfg(emojic_group = "flags")] "flag_kosovo",
+	#[cfg(emojic_group = "flags")] "flag_kuwait",
+	#[cfg(emojic_group = "flags")] "flag_kyrgyzstan",
+	#[cfg(emojic_group = "flags")] "flag_laos",
+	#[cfg(emojic_group = "flags")] "flag_latvia",
+	#[cfg(emojic_group = "flags")] "flag_lebanon",
+	#[cfg(emojic_group = "flags")] "flag_lesotho",
+	#[cfg(emojic_group = "flags")] "flag_liberia",
+	#[cfg(emojic_group = "flags")] "flag_libya",
+	#[cfg(emojic_group = "flags")] "flag_liechtenstein",
+	#[cfg(emojic_group = "flags")] "flag_lithuania",
+	#[cfg(emojic_group = "flags")] "flag_luxembourg",
+	#[cfg(emojic_group = "flags")] "flag_macao_sar_china",
+	#[cfg(emojic_group = "flags")] "flag_madagascar",
+	#[cfg(emojic_group = "flags")] "flag_malawi",
+	#[cfg(emojic_group = "flags")] "flag_malaysia",
+	#[cfg(emojic_group = "flags")] "flag_maldives",
+	#[cfg(emojic_group = "flags")] "flag_mali",
+	#[cfg(emojic_group = "flags")] "flag_malta",
+	#[cfg(emojic_group = "flags")] "flag_marshall_islands",
+	#[cfg(emojic_group = "flags")] "flag_martinique",
+	#[cfg(emojic_group = "flags")] "flag_mauritania",
+	#[cfg(emojic_group = "flags")] "flag_mauritius",
+	#[cfg(emojic_group = "flags")] "flag_mayotte",
+	#[cfg(emojic_group = "flags")] "flag_mexico",
+	#[cfg(emojic_group = "flags")] "flag_micronesia",
+	#[cfg(emojic_group = "flags")] "flag_moldova",
+	#[cfg(emojic_group = "flags")] "flag_monaco",
+	#[cfg(emojic_group = "flags")] "flag_mongolia",
+	#[cfg(emojic_group = "flags")] "flag_montenegro",
+	#[cfg(emojic_group = "flags")] "flag_montserrat",
+	#[cfg(emojic_group = "flags")] "flag_morocco",
+	#[cfg(emojic_group = "flags")] "flag_mozambique",
+	#[cfg(emojic_group = "flags")] "flag_myanmar_burma",
+	#[cfg(emojic_group = "flags")] "flag_namibia",
+	#[cfg(emojic_group = "flags")] "flag_nauru",
+	#[cfg(emojic_group = "flags")] "flag_nepal",
+	#[cfg(emojic_group = "flags")] "flag_netherlands",
+	#[cfg(emojic_group = "flags")] "flag_new_caledonia",
+	#[cfg(emojic_group = "flags")] "flag_new_zealand",
+	#[cfg(emojic_group = "flags")] "flag_nicaragua",
+	#[cfg(emojic_group = "flags")] "flag_niger",
+	#[cfg(emojic_group = "flags")] "flag_nigeria",
+	#[cfg(emojic_group = "flags")] "flag_niue",
+	#[cfg(emojic_group = "flags")] "flag_norfolk_island",
+	#[cfg(emojic_group = "flags")] "flag_north_korea",
+	#[cfg(emojic_group = "flags")] "flag_north_macedonia",
+	#[cfg(emojic_group = "flags")] "flag_northern_mariana_islands",
+	#[cfg(emojic_group = "flags")] "flag_norway",
+	#[cfg(emojic_group = "flags")] "flag_oman",
+	#[cfg(emojic_group = "flags")] "flag_pakistan",
+	#[cfg(emojic_group = "flags")] "flag_palau",
+	#[cfg(emojic_group = "flags")] "flag_palestinian_territories",
+	#[cfg(emojic_group = "flags")] "flag_panama",
+	#[cfg(emojic_group = "flags")] "flag_papua_new_guinea",
+	#[cfg(emojic_group = "flags")] "flag_paraguay",
+	#[cfg(emojic_group = "flags")] "flag_peru",
+	#[cfg(emojic_group = "flags")] "flag_philippines",
+	#[cfg(emojic_group = "flags")] "flag_pitcairn_islands",
+	#[cfg(emojic_group = "flags")] "flag_poland",
+	#[cfg(emojic_group = "flags")] "flag_portugal",
+	#[cfg(emojic_group = "flags")] "flag_puerto_rico",
+	#[cfg(emojic_group = "flags")] "flag_qatar",
+	#[cfg(emojic_group = "flags")] "flag_reunion",
+	#[cfg(emojic_group = "flags")] "flag_romania",
+	#[cfg(emojic_group = "flags")] "flag_russia",
+	#[cfg(emojic_group = "flags")] "flag_rwanda",
+	#[cfg(emojic_group = "flags")] "flag_samoa",
+	#[cfg(emojic_group = "flags")] "flag_san_marino",
+	#[cfg(emojic_group = "flags")] "flag_sao_tome_and_principe",
+	#[cfg(emojic_group = "flags")] "flag_saudi_arabia",
+	#[cfg(emojic_group = "flags")] "flag_scotland",
+	#[cfg(emojic_group = "flags")] "flag_senegal",
+	#[cfg(emojic_group = "flags")] "flag_serbia",
+	#[cfg(emojic_group = "flags")] "flag_seychelles",
+	#[cfg(emojic_group = "flags")] "flag_sierra_leone",
+	#[cfg(emojic_group = "flags")] "flag_singapore",
+	#[cfg(emojic_group = "flags")] "flag_sint_maarten",
+	#[cfg(emojic_group = "flags")] "flag_slovakia",
+	#[cfg(emojic_group = "flags")] "flag_slovenia",
+	#[cfg(emojic_group = "flags")] "flag_solomon_islands",
+	#[cfg(emojic_group = "flags")] "flag_somalia",
+	#[cfg(emojic_group = "flags")] "flag_south_africa",
+	#[cfg(emojic_group = "flags")] "flag_south_georgia_and_south_sandwich_islands",
+	#[cfg(emojic_group = "flags")] "flag_south_korea",
+	#[cfg(emojic_group = "flags")] "flag_south_sudan",
+	#[cfg(emojic_group = "flags")] "flag_spain",
+	#[cfg(emojic_group = "flags")] "flag_sri_lanka",
+	#[cfg(emojic_group = "flags")] "flag_st_barthelemy",
+	#[cfg(emojic_group = "flags")] "flag_st_helena",
+	#[cfg(emojic_group = "flags")] "flag_st_kitts_and_nevis",
+	#[cfg(emojic_group = "flags")] "flag_st_lucia",
+	#[cfg(emojic_group = "flags")] "flag_st_martin",
+	#[cfg(emojic_group = "flags")] "flag_st_pierre_and_miquelon",
+	#[cfg(emojic_group = "flags")] "flag_st_vincent_and_grenadines",
+	#[cfg(emojic_group = "flags")] "flag_sudan",
+	#[cfg(emojic_group = "flags")] "flag_suriname",
+	#[cfg(emojic_group = "flags")] "flag_svalbard_and_jan_mayen",
+	#[cfg(emojic_group = "flags")] "flag_sweden",
+	#[cfg(emojic_group = "flags")] "flag_switzerland",
+	#[cfg(emojic_group = "flags")] "flag_syria",
+	#[cfg(emojic_group = "flags")] "flag_taiwan",
+	#[cfg(emojic_group = "flags")] "flag_tajikistan",
+	#[cfg(emojic_group = "flags")] "flag_tanzania",
+	#[cfg(emojic_group = "flags")] "flag_thailand",
+	#[cfg(emojic_group = "flags")] "flag_timor_leste",
+	#[cfg(emojic_group = "flags")] "flag_togo",
+	#[cfg(emojic_group = "flags")] "flag_tokelau",
+	#[cfg(emojic_group = "flags")] "flag_tonga",
+	#[cfg(emojic_group = "flags")] "flag_trinidad_and_tobago",
+	#[cfg(emojic_group = "flags")] "flag_tristan_da_cunha",
+	#[cfg(emojic_group = "flags")] "flag_tunisia",
+	#[cfg(emojic_group = "flags")] "flag_turkey",
+	#[cfg(emojic_group = "flags")] "flag_turkmenistan",
+	#[cfg(emojic_group = "flags")] "flag_turks_and_caicos_islands",
+	#[cfg(emojic_group = "flags")] "flag_tuvalu",
+	#[cfg(emojic_group = "flags")] "flag_uganda",
+	#[cfg(emojic_group = "flags")] "flag_ukraine",
+	#[cfg(emojic_group = "flags")] "flag_united_arab_emirates",
+	#[cfg(emojic_group = "flags")] "flag_united_kingdom",
+	#[cfg(emojic_group = "flags")] "flag_united_nations",
+	#[cfg(emojic_group = "flags")] "flag_united_states",
+	#[cfg(emojic_group = "flags")] "flag_uruguay",
+	#[cfg(emojic_group = "flags")] "flag_us_outlying_islands",
+	#[cfg(emojic_group = "flags")] "flag_us_virgin_islands",
+	#[cfg(emojic_group = "flags")] "flag_uzbekistan",
+	#[cfg(emojic_group = "flags")] "flag_vanuatu",
+	#[cfg(emojic_group = "flags")] "flag_vatican_city",
+	#[cfg(emojic_group = "flags")] "flag_venezuela",
+	#[cfg(emojic_group = "flags")] "flag_vietnam",
+	#[cfg(emojic_group = "flags")] "flag_wales",
+	#[cfg(emojic_group = "flags")] "flag_wallis_and_futuna",
+	#[cfg(emojic_group = "flags")] "flag_western_sahara",
+	#[cfg(emojic_group = "flags")] "flag_yemen",
+	#[cfg(emojic_group = "flags")] "flag_zambia",
+	#[cfg(emojic_group = "flags")] "flag_zimbabwe",
+	#[cfg(emojic_group = "activities")] "flags",
+	#[cfg(emojic_group = "animals_and_nature")] "flamingo",
+	#[cfg(emojic_group = "objects")] "flashlight",
+	#[cfg(emojic_group = "objects")] "flat_shoe",
+	#[cfg(emojic_group = "food_and_drink")] "flatbread",
+	#[cfg(emojic_group = "symbols")] "fleur_de_lis",
+	#[cfg(emojic_group = "people_and_body")] "flexed_biceps",
+	#[cfg(emojic_group = "travel_and_places")] "flight_arrival",
+	#[cfg(emojic_group = "travel_and_places")] "flight_departure",
+	#[cfg(emojic_group = "animals_and_nature")] "flipper",
+	#[cfg(emojic_group = "objects")] "floppy_disk",
+	#[cfg(emojic_group = "activities")] "flower_playing_cards",
+	#[cfg(emojic_group = "smileys_and_emotion")] "flushed",
+	#[cfg(emojic_group = "smileys_and_emotion")] "flushed_face",
+	#[cfg(emojic_group = "animals_and_nature")] "fly",
+	#[cfg(emojic_group = "activities")] "flying_disc",
+	#[cfg(emojic_group = "travel_and_places")] "flying_saucer",
+	#[cfg(emojic_group = "travel_and_places")] "fog",
+	#[cfg(emojic_group = "travel_and_places")] "foggy",
+	#[cfg(emojic_group = "people_and_body")] "folded_hands",
+	#[cfg(emojic_group = "food_and_drink")] "fondue",
+	#[cfg(emojic_group = "people_and_body")] "foot",
+	#[cfg(emojic_group = "activities")] "football",
+	#[cfg(emojic_group = "people_and_body")] "footprints",
+	#[cfg(emojic_group = "food_and_drink")] "fork_and_knife",
+	#[cfg(emojic_group = "food_and_drink")] "fork_and_knife_with_plate",
+	#[cfg(emojic_group = "food_and_drink")] "fortune_cookie",
+	#[cfg(emojic_group = "travel_and_places")] "fountain",
+	#[cfg(emojic_group = "objects")] "fountain_pen",
+	#[cfg(emojic_group = "symbols")] "four",
+	#[cfg(emojic_group = "animals_and_nature")] "four_leaf_clover",
+	#[cfg(emojic_group = "travel_and_places")] "four_o_clock",
+	#[cfg(emojic_group = "travel_and_places")] "four_thirty",
+	#[cfg(emojic_group = "animals_and_nature")] "fox",
+	#[cfg(emojic_group = "animals_and_nature")] "fox_face",
+	#[cfg(emojic_group = "flags")] "fr",
+	#[cfg(emojic_group = "activities")] "framed_picture",
+	#[cfg(emojic_group = "symbols")] "free",
+	#[cfg(emojic_group = "symbols")] "free_button",
+	#[cfg(emojic_group = "food_and_drink")] "french_fries",
+	#[cfg(emojic_group = "flags")] "french_guiana",
+	#[cfg(emojic_group = "flags")] "french_polynesia",
+	#[cfg(emojic_group = "flags")] "french_southern_territories",
+	#[cfg(emojic_group = "food_and_drink")] "fried_egg",
+	#[cfg(emojic_group = "food_and_drink")] "fried_shrimp",
+	#[cfg(emojic_group = "food_and_drink")] "fries",
+	#[cfg(emojic_group = "animals_and_nature")] "frog",
+	#[cfg(emojic_group = "animals_and_nature")] "front_facing_baby_chick",
+	#[cfg(emojic_group = "smileys_and_emotion")] "frowning",
+	#[cfg(emojic_group = "smileys_and_emotion")] "frowning_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "frowning_face_with_open_mouth",
+	#[cfg(emojic_group = "people_and_body")] "frowning_man",
+	#[cfg(emojic_group = "people_and_body")] "frowning_person",
+	#[cfg(emojic_group = "people_and_body")] "frowning_woman",
+	#[cfg(emojic_group = "people_and_body")] "fu",
+	#[cfg(emojic_group = "travel_and_places")] "fuel_pump",
+	#[cfg(emojic_group = "travel_and_places")] "fuelpump",
+	#[cfg(emojic_group = "travel_and_places")] "full_moon",
+	#[cfg(emojic_group = "travel_and_places")] "full_moon_face",
+	#[cfg(emojic_group = "travel_and_places")] "full_moon_with_face",
+	#[cfg(emojic_group = "objects")] "funeral_urn",
+	#[cfg(emojic_group = "flags")] "gabon",
+	#[cfg(emojic_group = "flags")] "gambia",
+	#[cfg(emojic_group = "activities")] "game_die",
+	#[cfg(emojic_group = "food_and_drink")] "garlic",
+	#[cfg(emojic_group = "flags")] "gb",
+	#[cfg(emojic_group = "objects")] "gear",
+	#[cfg(emojic_group = "objects")] "gem",
+	#[cfg(emojic_group = "objects")] "gem_stone",
+	#[cfg(emojic_group = "symbols")] "gemini",
+	#[cfg(emojic_group = "people_and_body")] "genie",
+	#[cfg(emojic_group = "people_and_body")] "genie_man",
+	#[cfg(emojic_group = "people_and_body")] "genie_woman",
+	#[cfg(emojic_group = "flags")] "georgia",
+	#[cfg(emojic_group = "flags")] "ghana",
+	#[cfg(emojic_group = "smileys_and_emotion")] "ghost",
+	#[cfg(emojic_group = "flags")] "gibraltar",
+	#[cfg(emojic_group = "activities")] "gift",
+	#[cfg(emojic_group = "smileys_and_emotion")] "gift_heart",
+	#[cfg(emojic_group = "animals_and_nature")] "giraffe",
+	#[cfg(emojic_group = "people_and_body")] "girl",
+	#[cfg(emojic_group = "food_and_drink")] "glass_of_milk",
+	#[cfg(emojic_group = "objects")] "glasses",
+	#[cfg(emojic_group = "travel_and_places")] "globe_showing_americas",
+	#[cfg(emojic_group = "travel_and_places")] "globe_showing_asia_australia",
+	#[cfg(emojic_group = "travel_and_places")] "globe_showing_europe_africa",
+	#[cfg(emojic_group = "travel_and_places")] "globe_with_meridians",
+	#[cfg(emojic_group = "objects")] "gloves",
+	#[cfg(emojic_group = "travel_and_places")] "glowing_star",
+	#[cfg(emojic_group = "activities")] "goal_net",
+	#[cfg(emojic_group = "animals_and_nature")] "goat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "goblin",
+	#[cfg(emojic_group = "objects")] "goggles",
+	#[cfg(emojic_group = "activities")] "golf",
+	#[cfg(emojic_group = "people_and_body")] "golfing",
+	#[cfg(emojic_group = "people_and_body")] "golfing_man",
+	#[cfg(emojic_group = "people_and_body")] "golfing_woman",
+	#[cfg(emojic_group = "animals_and_nature")] "gorilla",
+	#[cfg(emojic_group = "objects")] "graduation_cap",
+	#[cfg(emojic_group = "food_and_drink")] "grapes",
+	#[cfg(emojic_group = "flags")] "greece",
+	#[cfg(emojic_group = "food_and_drink")] "green_apple",
+	#[cfg(emojic_group = "objects")] "green_book",
+	#[cfg(emojic_group = "symbols")] "green_circle",
+	#[cfg(emojic_group = "smileys_and_emotion")] "green_heart",
+	#[cfg(emojic_group = "food_and_drink")] "green_salad",
+	#[cfg(emojic_group = "symbols")] "green_square",
+	#[cfg(emojic_group = "flags")] "greenland",
+	#[cfg(emojic_group = "flags")] "grenada",
+	#[cfg(emojic_group = "symbols")] "grey_exclamation",
+	#[cfg(emojic_group = "symbols")] "grey_question",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grimacing",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grimacing_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grin",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning_cat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning_cat_with_smiling_eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning_face_with_big_eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning_face_with_smiling_eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning_face_with_sweat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "grinning_squinting_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "growing_heart",
+	#[cfg(emojic_group = "flags")] "guadeloupe",
+	#[cfg(emojic_group = "flags")] "guam",
+	#[cfg(emojic_group = "people_and_body")] "guard",
+	#[cfg(emojic_group = "people_and_body")] "guardsman",
+	#[cfg(emojic_group = "people_and_body")] "guardswoman",
+	#[cfg(emojic_group = "flags")] "guatemala",
+	#[cfg(emojic_group = "flags")] "guernsey",
+	#[cfg(emojic_group = "animals_and_nature")] "guide_dog",
+	#[cfg(emojic_group = "flags")] "guinea",
+	#[cfg(emojic_group = "flags")] "guinea_bissau",
+	#[cfg(emojic_group = "objects")] "guitar",
+	#[cfg(emojic_group = "objects")] "gun",
+	#[cfg(emojic_group = "flags")] "guyana",
+	#[cfg(emojic_group = "people_and_body")] "haircut",
+	#[cfg(emojic_group = "people_and_body")] "haircut_man",
+	#[cfg(emojic_group = "people_and_body")] "haircut_woman",
+	#[cfg(emojic_group = "flags")] "haiti",
+	#[cfg(emojic_group = "food_and_drink")] "hamburger",
+	#[cfg(emojic_group = "objects")] "hammer",
+	#[cfg(emojic_group = "objects")] "hammer_and_pick",
+	#[cfg(emojic_group = "objects")] "hammer_and_wrench",
+	#[cfg(emojic_group = "objects")] "hamsa",
+	#[cfg(emojic_group = "animals_and_nature")] "hamster",
+	#[cfg(emojic_group = "people_and_body")] "hand",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hand_over_mouth",
+	#[cfg(emojic_group = "people_and_body")] "hand_with_fingers_splayed",
+	#[cfg(emojic_group = "people_and_body")] "hand_with_index_finger_and_thumb_crossed",
+	#[cfg(emojic_group = "objects")] "handbag",
+	#[cfg(emojic_group = "people_and_body")] "handball_person",
+	#[cfg(emojic_group = "people_and_body")] "handshake",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hankey",
+	#[cfg(emojic_group = "symbols")] "hash",
+	#[cfg(emojic_group = "animals_and_nature")] "hatched_chick",
+	#[cfg(emojic_group = "animals_and_nature")] "hatching_chick",
+	#[cfg(emojic_group = "objects")] "headphone",
+	#[cfg(emojic_group = "objects")] "headphones",
+	#[cfg(emojic_group = "objects")] "headstone",
+	#[cfg(emojic_group = "people_and_body")] "health_worker",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hear_no_evil",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hear_no_evil_monkey",
+	#[cfg(emojic_group = "flags")] "heard_mcdonald_islands",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heart",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heart_decoration",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heart_exclamation",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heart_eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heart_eyes_cat",
+	#[cfg(emojic_group = "people_and_body")] "heart_hands",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heart_on_fire",
+	#[cfg(emojic_group = "activities")] "heart_suit",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heart_with_arrow",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heart_with_ribbon",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heartbeat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heartpulse",
+	#[cfg(emojic_group = "activities")] "hearts",
+	#[cfg(emojic_group = "symbols")] "heavy_check_mark",
+	#[cfg(emojic_group = "symbols")] "heavy_division_sign",
+	#[cfg(emojic_group = "symbols")] "heavy_dollar_sign",
+	#[cfg(emojic_group = "symbols")] "heavy_equals_sign",
+	#[cfg(emojic_group = "symbols")] "heavy_exclamation_mark",
+	#[cfg(emojic_group = "smileys_and_emotion")] "heavy_heart_exclamation",
+	#[cfg(emojic_group = "symbols")] "heavy_minus_sign",
+	#[cfg(emojic_group = "symbols")] "heavy_multiplication_x",
+	#[cfg(emojic_group = "symbols")] "heavy_plus_sign",
+	#[cfg(emojic_group = "animals_and_nature")] "hedgehog",
+	#[cfg(emojic_group = "travel_and_places")] "helicopter",
+	#[cfg(emojic_group = "animals_and_nature")] "herb",
+	#[cfg(emojic_group = "animals_and_nature")] "hibiscus",
+	#[cfg(emojic_group = "symbols")] "high_brightness",
+	#[cfg(emojic_group = "objects")] "high_heel",
+	#[cfg(emojic_group = "objects")] "high_heeled_shoe",
+	#[cfg(emojic_group = "travel_and_places")] "high_speed_train",
+	#[cfg(emojic_group = "travel_and_places")] "high_voltage",
+	#[cfg(emojic_group = "objects")] "hiking_boot",
+	#[cfg(emojic_group = "travel_and_places")] "hindu_temple",
+	#[cfg(emojic_group = "animals_and_nature")] "hippopotamus",
+	#[cfg(emojic_group = "food_and_drink")] "hocho",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hole",
+	#[cfg(emojic_group = "symbols")] "hollow_red_circle",
+	#[cfg(emojic_group = "flags")] "honduras",
+	#[cfg(emojic_group = "food_and_drink")] "honey_pot",
+	#[cfg(emojic_group = "animals_and_nature")] "honeybee",
+	#[cfg(emojic_group = "flags")] "hong_kong",
+	#[cfg(emojic_group = "objects")] "hook",
+	#[cfg(emojic_group = "travel_and_places")] "horizontal_traffic_light",
+	#[cfg(emojic_group = "animals_and_nature")] "horse",
+	#[cfg(emojic_group = "animals_and_nature")] "horse_face",
+	#[cfg(emojic_group = "people_and_body")] "horse_racing",
+	#[cfg(emojic_group = "travel_and_places")] "hospital",
+	#[cfg(emojic_group = "food_and_drink")] "hot_beverage",
+	#[cfg(emojic_group = "food_and_drink")] "hot_dog",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hot_face",
+	#[cfg(emojic_group = "food_and_drink")] "hot_pepper",
+	#[cfg(emojic_group = "travel_and_places")] "hot_springs",
+	#[cfg(emojic_group = "food_and_drink")] "hotdog",
+	#[cfg(emojic_group = "travel_and_places")] "hotel",
+	#[cfg(emojic_group = "travel_and_places")] "hotsprings",
+	#[cfg(emojic_group = "travel_and_places")] "hourglass",
+	#[cfg(emojic_group = "travel_and_places")] "hourglass_done",
+	#[cfg(emojic_group = "travel_and_places")] "hourglass_flowing_sand",
+	#[cfg(emojic_group = "travel_and_places")] "hourglass_not_done",
+	#[cfg(emojic_group = "travel_and_places")] "house",
+	#[cfg(emojic_group = "travel_and_places")] "house_with_garden",
+	#[cfg(emojic_group = "travel_and_places")] "houses",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hugging_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hugs",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hundred_points",
+	#[cfg(emojic_group = "flags")] "hungary",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hushed",
+	#[cfg(emojic_group = "smileys_and_emotion")] "hushed_face",
+	#[cfg(emojic_group = "travel_and_places")] "hut",
+	#[cfg(emojic_group = "food_and_drink")] "ice",
+	#[cfg(emojic_group = "food_and_drink")] "ice_cream",
+	#[cfg(emojic_group = "food_and_drink")] "ice_cube",
+	#[cfg(emojic_group = "activities")] "ice_hockey",
+	#[cfg(emojic_group = "activities")] "ice_skate",
+	#[cfg(emojic_group = "food_and_drink")] "icecream",
+	#[cfg(emojic_group = "flags")] "iceland",
+	#[cfg(emojic_group = "symbols")] "id",
+	#[cfg(emojic_group = "symbols")] "id_button",
+	#[cfg(emojic_group = "objects")] "identification_card",
+	#[cfg(emojic_group = "symbols")] "ideograph_advantage",
+	#[cfg(emojic_group = "smileys_and_emotion")] "imp",
+	#[cfg(emojic_group = "objects")] "inbox_tray",
+	#[cfg(emojic_group = "objects")] "incoming_envelope",
+	#[cfg(emojic_group = "people_and_body")] "index_pointing_at_the_viewer",
+	#[cfg(emojic_group = "people_and_body")] "index_pointing_up",
+	#[cfg(emojic_group = "flags")] "india",
+	#[cfg(emojic_group = "flags")] "indonesia",
+	#[cfg(emojic_group = "symbols")] "infinity",
+	#[cfg(emojic_group = "symbols")] "information",
+	#[cfg(emojic_group = "people_and_body")] "information_desk_person",
+	#[cfg(emojic_group = "symbols")] "information_source",
+	#[cfg(emojic_group = "smileys_and_emotion")] "innocent",
+	#[cfg(emojic_group = "symbols")] "input_latin_letters",
+	#[cfg(emojic_group = "symbols")] "input_latin_lowercase",
+	#[cfg(emojic_group = "symbols")] "input_latin_uppercase",
+	#[cfg(emojic_group = "symbols")] "input_numbers",
+	#[cfg(emojic_group = "symbols")] "input_symbols",
+	#[cfg(emojic_group = "symbols")] "interrobang",
+	#[cfg(emojic_group = "objects")] "iphone",
+	#[cfg(emojic_group = "flags")] "iran",
+	#[cfg(emojic_group = "flags")] "iraq",
+	#[cfg(emojic_group = "flags")] "ireland",
+	#[cfg(emojic_group = "flags")] "isle_of_man",
+	#[cfg(emojic_group = "flags")] "israel",
+	#[cfg(emojic_group = "flags")] "it",
+	#[cfg(emojic_group = "objects")] "izakaya_lantern",
+	#[cfg(emojic_group = "activities")] "jack_o_lantern",
+	#[cfg(emojic_group = "flags")] "jamaica",
+	#[cfg(emojic_group = "travel_and_places")] "japan",
+	#[cfg(emojic_group = "symbols")] "japanese_acceptable_button",
+	#[cfg(emojic_group = "symbols")] "japanese_application_button",
+	#[cfg(emojic_group = "symbols")] "japanese_bargain_button",
+	#[cfg(emojic_group = "travel_and_places")] "japanese_castle",
+	#[cfg(emojic_group = "symbols")] "japanese_congratulations_button",
+	#[cfg(emojic_group = "symbols")] "japanese_discount_button",
+	#[cfg(emojic_group = "activities")] "japanese_dolls",
+	#[cfg(emojic_group = "symbols")] "japanese_free_of_charge_button",
+	#[cfg(emojic_group = "smileys_and_emotion")] "japanese_goblin",
+	#[cfg(emojic_group = "symbols")] "japanese_here_button",
+	#[cfg(emojic_group = "symbols")] "japanese_monthly_amount_button",
+	#[cfg(emojic_group = "symbols")] "japanese_no_vacancy_button",
+	#[cfg(emojic_group = "symbols")] "japanese_not_free_of_charge_button",
+	#[cfg(emojic_group = "smileys_and_emotion")] "japanese_ogre",
+	#[cfg(emojic_group = "symbols")] "japanese_open_for_business_button",
+	#[cfg(emojic_group = "symbols")] "japanese_passing_grade_button",
+	#[cfg(emojic_group = "travel_and_places")] "japanese_post_office",
+	#[cfg(emojic_group = "symbols")] "japanese_prohibited_button",
+	#[cfg(emojic_group = "symbols")] "japanese_reserved_button",
+	#[cfg(emojic_group = "symbols")] "japanese_secret_button",
+	#[cfg(emojic_group = "symbols")] "japanese_service_charge_button",
+	#[cfg(emojic_group = "symbols")] "japanese_symbol_for_beginner",
+	#[cfg(emojic_group = "symbols")] "japanese_vacancy_button",
+	#[cfg(emojic_group = "food_and_drink")] "jar",
+	#[cfg(emojic_group = "objects")] "jeans",
+	#[cfg(emojic_group = "flags")] "jersey",
+	#[cfg(emojic_group = "activities")] "jigsaw",
+	#[cfg(emojic_group = "activities")] "joker",
+	#[cfg(emojic_group = "flags")] "jordan",
+	#[cfg(emojic_group = "smileys_and_emotion")] "joy",
+	#[cfg(emojic_group = "smileys_and_emotion")] "joy_cat",
+	#[cfg(emojic_group = "activities")] "joystick",
+	#[cfg(emojic_group = "flags")] "jp",
+	#[cfg(emojic_group = "people_and_body")] "judge",
+	#[cfg(emojic_group = "people_and_body")] "juggling_person",
+	#[cfg(emojic_group = "travel_and_places")] "kaaba",
+	#[cfg(emojic_group = "animals_and_nature")] "kangaroo",
+	#[cfg(emojic_group = "flags")] "kazakhstan",
+	#[cfg(emojic_group = "flags")] "kenya",
+	#[cfg(emojic_group = "objects")] "key",
+	#[cfg(emojic_group = "objects")] "keyboard",
+	#[cfg(emojic_group = "symbols")] "keycap_0",
+	#[cfg(emojic_group = "symbols")] "keycap_1",
+	#[cfg(emojic_group = "symbols")] "keycap_10",
+	#[cfg(emojic_group = "symbols")] "keycap_2",
+	#[cfg(emojic_group = "symbols")] "keycap_3",
+	#[cfg(emojic_group = "symbols")] "keycap_4",
+	#[cfg(emojic_group = "symbols")] "keycap_5",
+	#[cfg(emojic_group = "symbols")] "keycap_6",
+	#[cfg(emojic_group = "symbols")] "keycap_7",
+	#[cfg(emojic_group = "symbols")] "keycap_8",
+	#[cfg(emojic_group = "symbols")] "keycap_9",
+	#[cfg(emojic_group = "symbols")] "keycap_asterisk",
+	#[cfg(emojic_group = "symbols")] "keycap_hash",
+	#[cfg(emojic_group = "symbols")] "keycap_ten",
+	#[cfg(emojic_group = "travel_and_places")] "kick_scooter",
+	#[cfg(emojic_group = "objects")] "kimono",
+	#[cfg(emojic_group = "flags")] "kiribati",
+	#[cfg(emojic_group = "people_and_body")] "kiss",
+	#[cfg(emojic_group = "smileys_and_emotion")] "kiss_mark",
+	#[cfg(emojic_group = "smileys_and_emotion")] "kissing",
+	#[cfg(emojic_group = "smileys_and_emotion")] "kissing_cat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "kissing_closed_eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "kissing_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "kissing_face_with_closed_eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "kissing_face_with_smiling_eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "kissing_heart",
+	#[cfg(emojic_group = "smileys_and_emotion")] "kissing_smiling_eyes",
+	#[cfg(emojic_group = "food_and_drink")] "kitchen_knife",
+	#[cfg(emojic_group = "activities")] "kite",
+	#[cfg(emojic_group = "food_and_drink")] "kiwi_fruit",
+	#[cfg(emojic_group = "people_and_body")] "kneeling_man",
+	#[cfg(emojic_group = "people_and_body")] "kneeling_person",
+	#[cfg(emojic_group = "people_and_body")] "kneeling_woman",
+	#[cfg(emojic_group = "food_and_drink")] "knife",
+	#[cfg(emojic_group = "smileys_and_emotion")] "knocked_out_face",
+	#[cfg(emojic_group = "activities")] "knot",
+	#[cfg(emojic_group = "animals_and_nature")] "koala",
+	#[cfg(emojic_group = "symbols")] "koko",
+	#[cfg(emojic_group = "flags")] "kosovo",
+	#[cfg(emojic_group = "flags")] "kr",
+	#[cfg(emojic_group = "flags")] "kuwait",
+	#[cfg(emojic_group = "flags")] "kyrgyzstan",
+	#[cfg(emojic_group = "objects")] "lab_coat",
+	#[cfg(emojic_group = "objects")] "label",
+	#[cfg(emojic_group = "activities")] "lacrosse",
+	#[cfg(emojic_group = "objects")] "ladder",
+	#[cfg(emojic_group = "animals_and_nature")] "lady_beetle",
+	#[cfg(emojic_group = "objects")] "lantern",
+	#[cfg(emojic_group = "flags")] "laos",
+	#[cfg(emojic_group = "objects")] "laptop",
+	#[cfg(emojic_group = "symbols")] "large_blue_circle",
+	#[cfg(emojic_group = "symbols")] "large_blue_diamond",
+	#[cfg(emojic_group = "symbols")] "large_orange_diamond",
+	#[cfg(emojic_group = "travel_and_places")] "last_quarter_moon",
+	#[cfg(emojic_group = "travel_and_places")] "last_quarter_moon_face",
+	#[cfg(emojic_group = "travel_and_places")] "last_quarter_moon_with_face",
+	#[cfg(emojic_group = "symbols")] "last_track_button",
+	#[cfg(emojic_group = "symbols")] "latin_cross",
+	#[cfg(emojic_group = "flags")] "latvia",
+	#[cfg(emojic_group = "smileys_and_emotion")] "laughing",
+	#[cfg(emojic_group = "animals_and_nature")] "leaf_fluttering_in_wind",
+	#[cfg(emojic_group = "food_and_drink")] "leafy_green",
+	#[cfg(emojic_group = "animals_and_nature")] "leaves",
+	#[cfg(emojic_group = "flags")] "lebanon",
+	#[cfg(emojic_group = "objects")] "ledger",
+	#[cfg(emojic_group = "symbols")] "left_arrow",
+	#[cfg(emojic_group = "symbols")] "left_arrow_curving_right",
+	#[cfg(emojic_group = "people_and_body")] "left_facing_fist",
+	#[cfg(emojic_group = "symbols")] "left_luggage",
+	#[cfg(emojic_group = "symbols")] "left_right_arrow",
+	#[cfg(emojic_group = "smileys_and_emotion")] "left_speech_bubble",
+	#[cfg(emojic_group = "symbols")] "leftwards_arrow_with_hook",
+	#[cfg(emojic_group = "people_and_body")] "leftwards_hand",
+	#[cfg(emojic_group = "people_and_body")] "leg",
+	#[cfg(emojic_group = "food_and_drink")] "lemon",
+	#[cfg(emojic_group = "symbols")] "leo",
+	#[cfg(emojic_group = "animals_and_nature")] "leopard",
+	#[cfg(emojic_group = "flags")] "lesotho",
+	#[cfg(emojic_group = "objects")] "level_slider",
+	#[cfg(emojic_group = "flags")] "liberia",
+	#[cfg(emojic_group = "symbols")] "libra",
+	#[cfg(emojic_group = "flags")] "libya",
+	#[cfg(emojic_group = "flags")] "liechtenstein",
+	#[cfg(emojic_group = "objects")] "light_bulb",
+	#[cfg(emojic_group = "travel_and_places")] "light_rail",
+	#[cfg(emojic_group = "component")] "light_skin_tone",
+	#[cfg(emojic_group = "objects")] "link",
+	#[cfg(emojic_group = "objects")] "linked_paperclips",
+	#[cfg(emojic_group = "animals_and_nature")] "lion",
+	#[cfg(emojic_group = "people_and_body")] "lips",
+	#[cfg(emojic_group = "objects")] "lipstick",
+	#[cfg(emojic_group = "flags")] "lithuania",
+	#[cfg(emojic_group = "symbols")] "litter_in_bin_sign",
+	#[cfg(emojic_group = "animals_and_nature")] "lizard",
+	#[cfg(emojic_group = "animals_and_nature")] "llama",
+	#[cfg(emojic_group = "food_and_drink")] "lobster",
+	#[cfg(emojic_group = "objects")] "lock",
+	#[cfg(emojic_group = "objects")] "lock_with_ink_pen",
+	#[cfg(emojic_group = "objects")] "locked",
+	#[cfg(emojic_group = "objects")] "locked_with_key",
+	#[cfg(emojic_group = "objects")] "locked_with_pen",
+	#[cfg(emojic_group = "travel_and_places")] "locomotive",
+	#[cfg(emojic_group = "food_and_drink")] "lollipop",
+	#[cfg(emojic_group = "objects")] "long_drum",
+	#[cfg(emojic_group = "symbols")] "loop",
+	#[cfg(emojic_group = "objects")] "lotion_bottle",
+	#[cfg(emojic_group = "animals_and_nature")] "lotus",
+	#[cfg(emojic_group = "people_and_body")] "lotus_position",
+	#[cfg(emojic_group = "people_and_body")] "lotus_position_man",
+	#[cfg(emojic_group = "people_and_body")] "lotus_position_woman",
+	#[cfg(emojic_group = "objects")] "loud_sound",
+	#[cfg(emojic_group = "smileys_and_emotion")] "loudly_crying_face",
+	#[cfg(emojic_group = "objects")] "loudspeaker",
+	#[cfg(emojic_group = "travel_and_places")] "love_hotel",
+	#[cfg(emojic_group = "smileys_and_emotion")] "love_letter",
+	#[cfg(emojic_group = "people_and_body")] "love_you_gesture",
+	#[cfg(emojic_group = "objects")] "low_battery",
+	#[cfg(emojic_group = "symbols")] "low_brightness",
+	#[cfg(emojic_group = "travel_and_places")] "luggage",
+	#[cfg(emojic_group = "people_and_body")] "lungs",
+	#[cfg(emojic_group = "flags")] "luxembourg",
+	#[cfg(emojic_group = "smileys_and_emotion")] "lying_face",
+	#[cfg(emojic_group = "symbols")] "m",
+	#[cfg(emojic_group = "flags")] "macau",
+	#[cfg(emojic_group = "flags")] "macedonia",
+	#[cfg(emojic_group = "flags")] "madagascar",
+	#[cfg(emojic_group = "objects")] "mag",
+	#[cfg(emojic_group = "objects")] "mag_right",
+	#[cfg(emojic_group = "people_and_body")] "mage",
+	#[cfg(emojic_group = "people_and_body")] "mage_man",
+	#[cfg(emojic_group = "people_and_body")] "mage_woman",
+	#[cfg(emojic_group = "activities")] "magic_wand",
+	#[cfg(emojic_group = "objects")] "magnet",
+	#[cfg(emojic_group = "objects")] "magnifying_glass_tilted_left",
+	#[cfg(emojic_group = "objects")] "magnifying_glass_tilted_right",
+	#[cfg(emojic_group = "activities")] "mahjong",
+	#[cfg(emojic_group = "activities")] "mahjong_red_dragon",
+	#[cfg(emojic_group = "objects")] "mailbox",
+	#[cfg(emojic_group = "objects")] "mailbox_closed",
+	#[cfg(emojic_group = "objects")] "mailbox_with_mail",
+	#[cfg(emojic_group = "objects")] "mailbox_with_no_mail",
+	#[cfg(emojic_group = "flags")] "malawi",
+	#[cfg(emojic_group = "flags")] "malaysia",
+	#[cfg(emojic_group = "flags")] "maldives",
+	#[cfg(emojic_group = "people_and_body")] "male_detective",
+	#[cfg(emojic_group = "symbols")] "male_sign",
+	#[cfg(emojic_group = "flags")] "mali",
+	#[cfg(emojic_group = "flags")] "malta",
+	#[cfg(emojic_group = "animals_and_nature")] "mammoth",
+	#[cfg(emojic_group = "people_and_body")] "man",
+	#[cfg(emojic_group = "people_and_body")] "man_artist",
+	#[cfg(emojic_group = "people_and_body")] "man_astronaut",
+	#[cfg(emojic_group = "people_and_body")] "man_beard",
+	#[cfg(emojic_group = "people_and_body")] "man_cartwheeling",
+	#[cfg(emojic_group = "people_and_body")] "man_cook",
+	#[cfg(emojic_group = "people_and_body")] "man_dancing",
+	#[cfg(emojic_group = "people_and_body")] "man_facepalming",
+	#[cfg(emojic_group = "people_and_body")] "man_factory_worker",
+	#[cfg(emojic_group = "people_and_body")] "man_farmer",
+	#[cfg(emojic_group = "people_and_body")] "man_feeding_baby",
+	#[cfg(emojic_group = "people_and_body")] "man_firefighter",
+	#[cfg(emojic_group = "people_and_body")] "man_health_worker",
+	#[cfg(emojic_group = "people_and_body")] "man_in_manual_wheelchair",
+	#[cfg(emojic_group = "people_and_body")] "man_in_motorized_wheelchair",
+	#[cfg(emojic_group = "people_and_body")] "man_in_tuxedo",
+	#[cfg(emojic_group = "people_and_body")] "man_judge",
+	#[cfg(emojic_group = "people_and_body")] "man_juggling",
+	#[cfg(emojic_group = "people_and_body")] "man_mechanic",
+	#[cfg(emojic_group = "people_and_body")] "man_office_worker",
+	#[cfg(emojic_group = "people_and_body")] "man_pilot",
+	#[cfg(emojic_group = "people_and_body")] "man_playing_handball",
+	#[cfg(emojic_group = "people_and_body")] "man_playing_water_polo",
+	#[cfg(emojic_group = "objects")] "man_s_shoe",
+	#[cfg(emojic_group = "people_and_body")] "man_scientist",
+	#[cfg(emojic_group = "people_and_body")] "man_shrugging",
+	#[cfg(emojic_group = "people_and_body")] "man_singer",
+	#[cfg(emojic_group = "people_and_body")] "man_student",
+	#[cfg(emojic_group = "people_and_body")] "man_teacher",
+	#[cfg(emojic_group = "people_and_body")] "man_technologist",
+	#[cfg(emojic_group = "people_and_body")] "man_with_gua_pi_mao",
+	#[cfg(emojic_group = "people_and_body")] "man_with_probing_cane",
+	#[cfg(emojic_group = "people_and_body")] "man_with_turban",
+	#[cfg(emojic_group = "people_and_body")] "man_with_veil",
+	#[cfg(emojic_group = "food_and_drink")] "mandarin",
+	#[cfg(emojic_group = "food_and_drink")] "mango",
+	#[cfg(emojic_group = "objects")] "mans_shoe",
+	#[cfg(emojic_group = "travel_and_places")] "mantelpiece_clock",
+	#[cfg(emojic_group = "travel_and_places")] "manual_wheelchair",
+	#[cfg(emojic_group = "travel_and_places")] "map_of_japan",
+	#[cfg(emojic_group = "animals_and_nature")] "maple_leaf",
+	#[cfg(emojic_group = "flags")] "marshall_islands",
+	#[cfg(emojic_group = "activities")] "martial_arts_uniform",
+	#[cfg(emojic_group = "flags")] "martinique",
+	#[cfg(emojic_group = "smileys_and_emotion")] "mask",
+	#[cfg(emojic_group = "people_and_body")] "massage",
+	#[cfg(emojic_group = "people_and_body")] "massage_man",
+	#[cfg(emojic_group = "people_and_body")] "massage_woman",
+	#[cfg(emojic_group = "food_and_drink")] "mate",
+	#[cfg(emojic_group = "flags")] "mauritania",
+	#[cfg(emojic_group = "flags")] "mauritius",
+	#[cfg(emojic_group = "flags")] "mayotte",
+	#[cfg(emojic_group = "food_and_drink")] "meat_on_bone",
+	#[cfg(emojic_group = "people_and_body")] "mechanic",
+	#[cfg(emojic_group = "people_and_body")] "mechanical_arm",
+	#[cfg(emojic_group = "people_and_body")] "mechanical_leg",
+	#[cfg(emojic_group = "activities")] "medal_military",
+	#[cfg(emojic_group = "activities")] "medal_sports",
+	#[cfg(emojic_group = "symbols")] "medical_symbol",
+	#[cfg(emojic_group = "component")] "medium_dark_skin_tone",
+	#[cfg(emojic_group = "component")] "medium_light_skin_tone",
+	#[cfg(emojic_group = "component")] "medium_skin_tone",
+	#[cfg(emojic_group = "objects")] "mega",
+	#[cfg(emojic_group = "objects")] "megaphone",
+	#[cfg(emojic_group = "food_and_drink")] "melon",
+	#[cfg(emojic_group = "smileys_and_emotion")] "melting_face",
+	#[cfg(emojic_group = "objects")] "memo",
+	#[cfg(emojic_group = "symbols")] "men_s_room",
+	#[cfg(emojic_group = "people_and_body")] "men_wrestling",
+	#[cfg(emojic_group = "smileys_and_emotion")] "mending_heart",
+	#[cfg(emojic_group = "symbols")] "menorah",
+	#[cfg(emojic_group = "symbols")] "mens",
+	#[cfg(emojic_group = "people_and_body")] "mermaid",
+	#[cfg(emojic_group = "people_and_body")] "merman",
+	#[cfg(emojic_group = "people_and_body")] "merperson",
+	#[cfg(emojic_group = "people_and_body")] "metal",
+	#[cfg(emojic_group = "travel_and_places")] "metro",
+	#[cfg(emojic_group = "flags")] "mexico",
+	#[cfg(emojic_group = "animals_and_nature")] "microbe",
+	#[cfg(emojic_group = "flags")] "micronesia",
+	#[cfg(emojic_group = "objects")] "microphone",
+	#[cfg(emojic_group = "objects")] "microscope",
+	#[cfg(emojic_group = "people_and_body")] "middle_finger",
+	#[cfg(emojic_group = "objects")] "military_helmet",
+	#[cfg(emojic_group = "activities")] "military_medal",
+	#[cfg(emojic_group = "food_and_drink")] "milk_glass",
+	#[cfg(emojic_group = "travel_and_places")] "milky_way",
+	#[cfg(emojic_group = "travel_and_places")] "minibus",
+	#[cfg(emojic_group = "objects")] "minidisc",
+	#[cfg(emojic_group = "symbols")] "minus",
+	#[cfg(emojic_group = "objects")] "mirror",
+	#[cfg(emojic_group = "activities")] "mirror_ball",
+	#[cfg(emojic_group = "objects")] "moai",
+	#[cfg(emojic_group = "objects")] "mobile_phone",
+	#[cfg(emojic_group = "symbols")] "mobile_phone_off",
+	#[cfg(emojic_group = "objects")] "mobile_phone_with_arrow",
+	#[cfg(emojic_group = "flags")] "moldova",
+	#[cfg(emojic_group = "flags")] "monaco",
+	#[cfg(emojic_group = "objects")] "money_bag",
+	#[cfg(emojic_group = "smileys_and_emotion")] "money_mouth_face",
+	#[cfg(emojic_group = "objects")] "money_with_wings",
+	#[cfg(emojic_group = "objects")] "moneybag",
+	#[cfg(emojic_group = "flags")] "mongolia",
+	#[cfg(emojic_group = "animals_and_nature")] "monkey",
+	#[cfg(emojic_group = "animals_and_nature")] "monkey_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "monocle_face",
+	#[cfg(emojic_group = "travel_and_places")] "monorail",
+	#[cfg(emojic_group = "flags")] "montenegro",
+	#[cfg(emojic_group = "flags")] "montserrat",
+	#[cfg(emojic_group = "travel_and_places")] "moon",
+	#[cfg(emojic_group = "food_and_drink")] "moon_cake",
+	#[cfg(emojic_group = "activities")] "moon_viewing_ceremony",
+	#[cfg(emojic_group = "flags")] "morocco",
+	#[cfg(emojic_group = "objects")] "mortar_board",
+	#[cfg(emojic_group = "travel_and_places")] "mosque",
+	#[cfg(emojic_group = "animals_and_nature")] "mosquito",
+	#[cfg(emojic_group = "travel_and_places")] "motor_boat",
+	#[cfg(emojic_group = "travel_and_places")] "motor_scooter",
+	#[cfg(emojic_group = "travel_and_places")] "motorcycle",
+	#[cfg(emojic_group = "travel_and_places")] "motorized_wheelchair",
+	#[cfg(emojic_group = "travel_and_places")] "motorway",
+	#[cfg(emojic_group = "travel_and_places")] "mount_fuji",
+	#[cfg(emojic_group = "travel_and_places")] "mountain",
+	#[cfg(emojic_group = "people_and_body")] "mountain_bicyclist",
+	#[cfg(emojic_group = "people_and_body")] "mountain_biking_man",
+	#[cfg(emojic_group = "people_and_body")] "mountain_biking_woman",
+	#[cfg(emojic_group = "travel_and_places")] "mountain_cableway",
+	#[cfg(emojic_group = "travel_and_places")] "mountain_railway",
+	#[cfg(emojic_group = "travel_and_places")] "mountain_snow",
+	#[cfg(emojic_group = "animals_and_nature")] "mouse",
+	#[cfg(emojic_group = "animals_and_nature")] "mouse2",
+	#[cfg(emojic_group = "animals_and_nature")] "mouse_face",
+	#[cfg(emojic_group = "objects")] "mouse_trap",
+	#[cfg(emojic_group = "people_and_body")] "mouth",
+	#[cfg(emojic_group = "objects")] "movie_camera",
+	#[cfg(emojic_group = "objects")] "moyai",
+	#[cfg(emojic_group = "flags")] "mozambique",
+	#[cfg(emojic_group = "people_and_body")] "mrs_claus",
+	#[cfg(emojic_group = "symbols")] "multiply",
+	#[cfg(emojic_group = "people_and_body")] "muscle",
+	#[cfg(emojic_group = "food_and_drink")] "mushroom",
+	#[cfg(emojic_group = "objects")] "musical_keyboard",
+	#[cfg(emojic_group = "objects")] "musical_note",
+	#[cfg(emojic_group = "objects")] "musical_notes",
+	#[cfg(emojic_group = "objects")] "musical_score",
+	#[cfg(emojic_group = "objects")] "mute",
+	#[cfg(emojic_group = "objects")] "muted_speaker",
+	#[cfg(emojic_group = "people_and_body")] "mx_claus",
+	#[cfg(emojic_group = "flags")] "myanmar",
+	#[cfg(emojic_group = "people_and_body")] "nail_care",
+	#[cfg(emojic_group = "people_and_body")] "nail_polish",
+	#[cfg(emojic_group = "symbols")] "name_badge",
+	#[cfg(emojic_group = "flags")] "namibia",
+	#[cfg(emojic_group = "travel_and_places")] "national_park",
+	#[cfg(emojic_group = "flags")] "nauru",
+	#[cfg(emojic_group = "smileys_and_emotion")] "nauseated_face",
+	#[cfg(emojic_group = "activities")] "nazar_amulet",
+	#[cfg(emojic_group = "objects")] "necktie",
+	#[cfg(emojic_group = "symbols")] "negative_squared_cross_mark",
+	#[cfg(emojic_group = "flags")] "nepal",
+	#[cfg(emojic_group = "smileys_and_emotion")] "nerd_face",
+	#[cfg(emojic_group = "animals_and_nature")] "nest_with_eggs",
+	#[cfg(emojic_group = "activities")] "nesting_dolls",
+	#[cfg(emojic_group = "flags")] "netherlands",
+	#[cfg(emojic_group = "smileys_and_emotion")] "neutral_face",
+	#[cfg(emojic_group = "symbols")] "new",
+	#[cfg(emojic_group = "symbols")] "new_button",
+	#[cfg(emojic_group = "flags")] "new_caledonia",
+	#[cfg(emojic_group = "travel_and_places")] "new_moon",
+	#[cfg(emojic_group = "travel_and_places")] "new_moon_face",
+	#[cfg(emojic_group = "travel_and_places")] "new_moon_with_face",
+	#[cfg(emojic_group = "flags")] "new_zealand",
+	#[cfg(emojic_group = "objects")] "newspaper",
+	#[cfg(emojic_group = "objects")] "newspaper_roll",
+	#[cfg(emojic_group = "symbols")] "next_track_button",
+	#[cfg(emojic_group = "symbols")] "ng",
+	#[cfg(emojic_group = "symbols")] "ng_button",
+	#[cfg(emojic_group = "people_and_body")] "ng_man",
+	#[cfg(emojic_group = "people_and_body")] "ng_woman",
+	#[cfg(emojic_group = "flags")] "nicaragua",
+	#[cfg(emojic_group = "flags")] "niger",
+	#[cfg(emojic_group = "flags")] "nigeria",
+	#[cfg(emojic_group = "travel_and_places")] "night_with_stars",
+	#[cfg(emojic_group = "symbols")] "nine",
+	#[cfg(emojic_group = "travel_and_places")] "nine_o_clock",
+	#[cfg(emojic_group = "travel_and_places")] "nine_thirty",
+	#[cfg(emojic_group = "people_and_body")] "ninja",
+	#[cfg(emojic_group = "flags")] "niue",
+	#[cfg(emojic_group = "objects")] "no_bell",
+	#[cfg(emojic_group = "symbols")] "no_bicycles",
+	#[cfg(emojic_group = "symbols")] "no_entry",
+	#[cfg(emojic_group = "symbols")] "no_entry_sign",
+	#[cfg(emojic_group = "people_and_body")] "no_good",
+	#[cfg(emojic_group = "people_and_body")] "no_good_man",
+	#[cfg(emojic_group = "people_and_body")] "no_good_woman",
+	#[cfg(emojic_group = "symbols")] "no_littering",
+	#[cfg(emojic_group = "symbols")] "no_mobile_phones",
+	#[cfg(emojic_group = "smileys_and_emotion")] "no_mouth",
+	#[cfg(emojic_group = "symbols")] "no_one_under_eighteen",
+	#[cfg(emojic_group = "symbols")] "no_pedestrians",
+	#[cfg(emojic_group = "symbols")] "no_smoking",
+	#[cfg(emojic_group = "symbols")] "non-potable_water",
+	#[cfg(emojic_group = "symbols")] "non_potable_water",
+	#[cfg(emojic_group = "flags")] "norfolk_island",
+	#[cfg(emojic_group = "flags")] "north_korea",
+	#[cfg(emojic_group = "flags")] "northern_mariana_islands",
+	#[cfg(emojic_group = "flags")] "norway",
+	#[cfg(emojic_group = "people_and_body")] "nose",
+	#[cfg(emojic_group = "objects")] "notebook",
+	#[cfg(emojic_group = "objects")] "notebook_with_decorative_cover",
+	#[cfg(emojic_group = "objects")] "notes",
+	#[cfg(emojic_group = "objects")] "nut_and_bolt",
+	#[cfg(emojic_group = "symbols")] "o",
+	#[cfg(emojic_group = "symbols")] "o2",
+	#[cfg(emojic_group = "symbols")] "o_button_blood_type",
+	#[cfg(emojic_group = "travel_and_places")] "ocean",
+	#[cfg(emojic_group = "animals_and_nature")] "octopus",
+	#[cfg(emojic_group = "food_and_drink")] "oden",
+	#[cfg(emojic_group = "travel_and_places")] "office",
+	#[cfg(emojic_group = "travel_and_places")] "office_building",
+	#[cfg(emojic_group = "people_and_body")] "office_worker",
+	#[cfg(emojic_group = "smileys_and_emotion")] "ogre",
+	#[cfg(emojic_group = "travel_and_places")] "oil_drum",
+	#[cfg(emojic_group = "symbols")] "ok",
+	#[cfg(emojic_group = "symbols")] "ok_button",
+	#[cfg(emojic_group = "people_and_body")] "ok_hand",
+	#[cfg(emojic_group = "people_and_body")] "ok_man",
+	#[cfg(emojic_group = "people_and_body")] "ok_person",
+	#[cfg(emojic_group = "people_and_body")] "ok_woman",
+	#[cfg(emojic_group = "objects")] "old_key",
+	#[cfg(emojic_group = "people_and_body")] "older_adult",
+	#[cfg(emojic_group = "people_and_body")] "older_man",
+	#[cfg(emojic_group = "people_and_body")] "older_person",
+	#[cfg(emojic_group = "people_and_body")] "older_woman",
+	#[cfg(emojic_group = "food_and_drink")] "olive",
+	#[cfg(emojic_group = "symbols")] "om",
+	#[cfg(emojic_group = "flags")] "oman",
+	#[cfg(emojic_group = "symbols")] "on",
+	#[cfg(emojic_group = "symbols")] "on_arrow",
+	#[cfg(emojic_group = "travel_and_places")] "oncoming_automobile",
+	#[cfg(emojic_group = "travel_and_places")] "oncoming_bus",
+	#[cfg(emojic_group = "people_and_body")] "oncoming_fist",
+	#[cfg(emojic_group = "travel_and_places")] "oncoming_police_car",
+	#[cfg(emojic_group = "travel_and_places")] "oncoming_taxi",
+	#[cfg(emojic_group = "symbols")] "one",
+	#[cfg(emojic_group = "travel_and_places")] "one_o_clock",
+	#[cfg(emojic_group = "objects")] "one_piece_swimsuit",
+	#[cfg(emojic_group = "travel_and_places")] "one_thirty",
+	#[cfg(emojic_group = "food_and_drink")] "onion",
+	#[cfg(emojic_group = "objects")] "open_book",
+	#[cfg(emojic_group = "objects")] "open_file_folder",
+	#[cfg(emojic_group = "people_and_body")] "open_hands",
+	#[cfg(emojic_group = "objects")] "open_mailbox_with_lowered_flag",
+	#[cfg(emojic_group = "objects")] "open_mailbox_with_raised_flag",
+	#[cfg(emojic_group = "smileys_and_emotion")] "open_mouth",
+	#[cfg(emojic_group = "travel_and_places")] "open_umbrella",
+	#[cfg(emojic_group = "symbols")] "ophiuchus",
+	#[cfg(emojic_group = "objects")] "optical_disk",
+	#[cfg(emojic_group = "food_and_drink")] "orange",
+	#[cfg(emojic_group = "objects")] "orange_book",
+	#[cfg(emojic_group = "symbols")] "orange_circle",
+	#[cfg(emojic_group = "smileys_and_emotion")] "orange_heart",
+	#[cfg(emojic_group = "symbols")] "orange_square",
+	#[cfg(emojic_group = "animals_and_nature")] "orangutan",
+	#[cfg(emojic_group = "symbols")] "orthodox_cross",
+	#[cfg(emojic_group = "animals_and_nature")] "otter",
+	#[cfg(emojic_group = "objects")] "outbox_tray",
+	#[cfg(emojic_group = "animals_and_nature")] "owl",
+	#[cfg(emojic_group = "animals_and_nature")] "ox",
+	#[cfg(emojic_group = "food_and_drink")] "oyster",
+	#[cfg(emojic_group = "symbols")] "p_button",
+	#[cfg(emojic_group = "objects")] "package",
+	#[cfg(emojic_group = "objects")] "page_facing_up",
+	#[cfg(emojic_group = "objects")] "page_with_curl",
+	#[cfg(emojic_group = "objects")] "pager",
+	#[cfg(emojic_group = "objects")] "paintbrush",
+	#[cfg(emojic_group = "flags")] "pakistan",
+	#[cfg(emojic_group = "flags")] "palau",
+	#[cfg(emojic_group = "flags")] "palestinian_territories",
+	#[cfg(emojic_group = "people_and_body")] "palm_down_hand",
+	#[cfg(emojic_group = "animals_and_nature")] "palm_tree",
+	#[cfg(emojic_group = "people_and_body")] "palm_up_hand",
+	#[cfg(emojic_group = "people_and_body")] "palms_up_together",
+	#[cfg(emojic_group = "flags")] "panama",
+	#[cfg(emojic_group = "food_and_drink")] "pancakes",
+	#[cfg(emojic_group = "animals_and_nature")] "panda",
+	#[cfg(emojic_group = "animals_and_nature")] "panda_face",
+	#[cfg(emojic_group = "objects")] "paperclip",
+	#[cfg(emojic_group = "objects")] "paperclips",
+	#[cfg(emojic_group = "flags")] "papua_new_guinea",
+	#[cfg(emojic_group = "travel_and_places")] "parachute",
+	#[cfg(emojic_group = "flags")] "paraguay",
+	#[cfg(emojic_group = "travel_and_places")] "parasol_on_ground",
+	#[cfg(emojic_group = "symbols")] "parking",
+	#[cfg(emojic_group = "animals_and_nature")] "parrot",
+	#[cfg(emojic_group = "symbols")] "part_alternation_mark",
+	#[cfg(emojic_group = "travel_and_places")] "partly_sunny",
+	#[cfg(emojic_group = "activities")] "party_popper",
+	#[cfg(emojic_group = "smileys_and_emotion")] "partying_face",
+	#[cfg(emojic_group = "travel_and_places")] "passenger_ship",
+	#[cfg(emojic_group = "symbols")] "passport_control",
+	#[cfg(emojic_group = "symbols")] "pause_button",
+	#[cfg(emojic_group = "animals_and_nature")] "paw_prints",
+	#[cfg(emojic_group = "symbols")] "peace_symbol",
+	#[cfg(emojic_group = "food_and_drink")] "peach",
+	#[cfg(emojic_group = "animals_and_nature")] "peacock",
+	#[cfg(emojic_group = "food_and_drink")] "peanuts",
+	#[cfg(emojic_group = "food_and_drink")] "pear",
+	#[cfg(emojic_group = "objects")] "pen",
+	#[cfg(emojic_group = "objects")] "pencil",
+	#[cfg(emojic_group = "objects")] "pencil2",
+	#[cfg(emojic_group = "animals_and_nature")] "penguin",
+	#[cfg(emojic_group = "smileys_and_emotion")] "pensive",
+	#[cfg(emojic_group = "smileys_and_emotion")] "pensive_face",
+	#[cfg(emojic_group = "people_and_body")] "people_holding_hands",
+	#[cfg(emojic_group = "people_and_body")] "people_hugging",
+	#[cfg(emojic_group = "activities")] "performing_arts",
+	#[cfg(emojic_group = "smileys_and_emotion")] "persevere",
+	#[cfg(emojic_group = "smileys_and_emotion")] "persevering_face",
+	#[cfg(emojic_group = "people_and_body")] "person",
+	#[cfg(emojic_group = "people_and_body")] "person_bald",
+	#[cfg(emojic_group = "people_and_body")] "person_biking",
+	#[cfg(emojic_group = "people_and_body")] "person_bouncing_ball",
+	#[cfg(emojic_group = "people_and_body")] "person_bowing",
+	#[cfg(emojic_group = "people_and_body")] "person_cartwheeling",
+	#[cfg(emojic_group = "people_and_body")] "person_climbing",
+	#[cfg(emojic_group = "people_and_body")] "person_curly_hair",
+	#[cfg(emojic_group = "people_and_body")] "person_facepalming",
+	#[cfg(emojic_group = "people_and_body")] "person_feeding_baby",
+	#[cfg(emojic_group = "people_and_body")] "person_fencing",
+	#[cfg(emojic_group = "people_and_body")] "person_frowning",
+	#[cfg(emojic_group = "people_and_body")] "person_gesturing_no",
+	#[cfg(emojic_group = "people_and_body")] "person_gesturing_ok",
+	#[cfg(emojic_group = "people_and_body")] "person_getting_haircut",
+	#[cfg(emojic_group = "people_and_body")] "person_getting_massage",
+	#[cfg(emojic_group = "people_and_body")] "person_golfing",
+	#[cfg(emojic_group = "people_and_body")] "person_holding_hands",
+	#[cfg(emojic_group = "people_and_body")] "person_hugging",
+	#[cfg(emojic_group = "people_and_body")] "person_in_bed",
+	#[cfg(emojic_group = "people_and_body")] "person_in_lotus_position",
+	#[cfg(emojic_group = "people_and_body")] "person_in_manual_wheelchair",
+	#[cfg(emojic_group = "people_and_body")] "person_in_motorized_wheelchair",
+	#[cfg(emojic_group = "people_and_body")] "person_in_steamy_room",
+	#[cfg(emojic_group = "people_and_body")] "person_in_suit_levitating",
+	#[cfg(emojic_group = "people_and_body")] "person_in_tuxedo",
+	#[cfg(emojic_group = "people_and_body")] "person_juggling",
+	#[cfg(emojic_group = "people_and_body")] "person_kneeling",
+	#[cfg(emojic_group = "people_and_body")] "person_lifting_weights",
+	#[cfg(emojic_group = "people_and_body")] "person_mountain_biking",
+	#[cfg(emojic_group = "people_and_body")] "person_playing_handball",
+	#[cfg(emojic_group = "people_and_body")] "person_playing_water_polo",
+	#[cfg(emojic_group = "people_and_body")] "person_pouting",
+	#[cfg(emojic_group = "people_and_body")] "person_raising_hand",
+	#[cfg(emojic_group = "people_and_body")] "person_red_hair",
+	#[cfg(emojic_group = "people_and_body")] "person_rowing_boat",
+	#[cfg(emojic_group = "people_and_body")] "person_running",
+	#[cfg(emojic_group = "people_and_body")] "person_shrugging",
+	#[cfg(emojic_group = "people_and_body")] "person_standing",
+	#[cfg(emojic_group = "people_and_body")] "person_surfing",
+	#[cfg(emojic_group = "people_and_body")] "person_swimming",
+	#[cfg(emojic_group = "people_and_body")] "person_taking_bath",
+	#[cfg(emojic_group = "people_and_body")] "person_tipping_hand",
+	#[cfg(emojic_group = "people_and_body")] "person_walking",
+	#[cfg(emojic_group = "people_and_body")] "person_wearing_turban",
+	#[cfg(emojic_group = "people_and_body")] "person_white_hair",
+	#[cfg(emojic_group = "people_and_body")] "person_with_bunny_ears",
+	#[cfg(emojic_group = "people_and_body")] "person_with_crown",
+	#[cfg(emojic_group = "people_and_body")] "person_with_probing_cane",
+	#[cfg(emojic_group = "people_and_body")] "person_with_skullcap",
+	#[cfg(emojic_group = "people_and_body")] "person_with_turban",
+	#[cfg(emojic_group = "people_and_body")] "person_with_veil",
+	#[cfg(emojic_group = "people_and_body")] "person_with_white_cane",
+	#[cfg(emojic_group = "people_and_body")] "person_wrestling",
+	#[cfg(emojic_group = "flags")] "peru",
+	#[cfg(emojic_group = "objects")] "petri_dish",
+	#[cfg(emojic_group = "flags")] "philippines",
+	#[cfg(emojic_group = "objects")] "phone",
+	#[cfg(emojic_group = "objects")] "pick",
+	#[cfg(emojic_group = "travel_and_places")] "pickup_truck",
+	#[cfg(emojic_group = "food_and_drink")] "pie",
+	#[cfg(emojic_group = "animals_and_nature")] "pig",
+	#[cfg(emojic_group = "animals_and_nature")] "pig2",
+	#[cfg(emojic_group = "animals_and_nature")] "pig_face",
+	#[cfg(emojic_group = "animals_and_nature")] "pig_nose",
+	#[cfg(emojic_group = "smileys_and_emotion")] "pile_of_poo",
+	#[cfg(emojic_group = "objects")] "pill",
+	#[cfg(emojic_group = "people_and_body")] "pilot",
+	#[cfg(emojic_group = "activities")] "pinata",
+	#[cfg(emojic_group = "people_and_body")] "pinched_fingers",
+	#[cfg(emojic_group = "people_and_body")] "pinching_hand",
+	#[cfg(emojic_group = "activities")] "pine_decoration",
+	#[cfg(emojic_group = "food_and_drink")] "pineapple",
+	#[cfg(emojic_group = "activities")] "ping_pong",
+	#[cfg(emojic_group = "flags")] "pirate_flag",
+	#[cfg(emojic_group = "symbols")] "pisces",
+	#[cfg(emojic_group = "flags")] "pitcairn_islands",
+	#[cfg(emojic_group = "food_and_drink")] "pizza",
+	#[cfg(emojic_group = "objects")] "placard",
+	#[cfg(emojic_group = "symbols")] "place_of_worship",
+	#[cfg(emojic_group = "food_and_drink")] "plate_with_cutlery",
+	#[cfg(emojic_group = "symbols")] "play_button",
+	#[cfg(emojic_group = "symbols")] "play_or_pause_button",
+	#[cfg(emojic_group = "travel_and_places")] "playground_slide",
+	#[cfg(emojic_group = "smileys_and_emotion")] "pleading_face",
+	#[cfg(emojic_group = "objects")] "plunger",
+	#[cfg(emojic_group = "symbols")] "plus",
+	#[cfg(emojic_group = "people_and_body")] "point_down",
+	#[cfg(emojic_group = "people_and_body")] "point_left",
+	#[cfg(emojic_group = "people_and_body")] "point_right",
+	#[cfg(emojic_group = "people_and_body")] "point_up",
+	#[cfg(emojic_group = "people_and_body")] "point_up_2",
+	#[cfg(emojic_group = "flags")] "poland",
+	#[cfg(emojic_group = "animals_and_nature")] "polar_bear",
+	#[cfg(emojic_group = "travel_and_places")] "police_car",
+	#[cfg(emojic_group = "travel_and_places")] "police_car_light",
+	#[cfg(emojic_group = "people_and_body")] "police_officer",
+	#[cfg(emojic_group = "people_and_body")] "policeman",
+	#[cfg(emojic_group = "people_and_body")] "policewoman",
+	#[cfg(emojic_group = "animals_and_nature")] "poodle",
+	#[cfg(emojic_group = "activities")] "pool_8_ball",
+	#[cfg(emojic_group = "smileys_and_emotion")] "poop",
+	#[cfg(emojic_group = "food_and_drink")] "popcorn",
+	#[cfg(emojic_group = "flags")] "portugal",
+	#[cfg(emojic_group = "travel_and_places")] "post_office",
+	#[cfg(emojic_group = "objects")] "postal_horn",
+	#[cfg(emojic_group = "objects")] "postbox",
+	#[cfg(emojic_group = "food_and_drink")] "pot_of_food",
+	#[cfg(emojic_group = "symbols")] "potable_water",
+	#[cfg(emojic_group = "food_and_drink")] "potato",
+	#[cfg(emojic_group = "animals_and_nature")] "potted_plant",
+	#[cfg(emojic_group = "objects")] "pouch",
+	#[cfg(emojic_group = "food_and_drink")] "poultry_leg",
+	#[cfg(emojic_group = "objects")] "pound",
+	#[cfg(emojic_group = "objects")] "pound_banknote",
+	#[cfg(emojic_group = "food_and_drink")] "pouring_liquid",
+	#[cfg(emojic_group = "smileys_and_emotion")] "pout",
+	#[cfg(emojic_group = "smileys_and_emotion")] "pouting_cat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "pouting_face",
+	#[cfg(emojic_group = "people_and_body")] "pouting_man",
+	#[cfg(emojic_group = "people_and_body")] "pouting_woman",
+	#[cfg(emojic_group = "people_and_body")] "pray",
+	#[cfg(emojic_group = "objects")] "prayer_beads",
+	#[cfg(emojic_group = "people_and_body")] "pregnant_man",
+	#[cfg(emojic_group = "people_and_body")] "pregnant_person",
+	#[cfg(emojic_group = "people_and_body")] "pregnant_woman",
+	#[cfg(emojic_group = "food_and_drink")] "pretzel",
+	#[cfg(emojic_group = "symbols")] "previous_track_button",
+	#[cfg(emojic_group = "people_and_body")] "prince",
+	#[cfg(emojic_group = "people_and_body")] "princess",
+	#[cfg(emojic_group = "objects")] "printer",
+	#[cfg(emojic_group = "objects")] "probing_cane",
+	#[cfg(emojic_group = "symbols")] "prohibited",
+	#[cfg(emojic_group = "flags")] "puerto_rico",
+	#[cfg(emojic_group = "people_and_body")] "punch",
+	#[cfg(emojic_group = "symbols")] "purple_circle",
+	#[cfg(emojic_group = "smileys_and_emotion")] "purple_heart",
+	#[cfg(emojic_group = "symbols")] "purple_square",
+	#[cfg(emojic_group = "objects")] "purse",
+	#[cfg(emojic_group = "objects")] "pushpin",
+	#[cfg(emojic_group = "symbols")] "put_litter_in_its_place",
+	#[cfg(emojic_group = "activities")] "puzzle_piece",
+	#[cfg(emojic_group = "flags")] "qatar",
+	#[cfg(emojic_group = "symbols")] "question",
+	#[cfg(emojic_group = "animals_and_nature")] "rabbit",
+	#[cfg(emojic_group = "animals_and_nature")] "rabbit2",
+	#[cfg(emojic_group = "animals_and_nature")] "rabbit_face",
+	#[cfg(emojic_group = "animals_and_nature")] "raccoon",
+	#[cfg(emojic_group = "animals_and_nature")] "racehorse",
+	#[cfg(emojic_group = "travel_and_places")] "racing_car",
+	#[cfg(emojic_group = "objects")] "radio",
+	#[cfg(emojic_group = "symbols")] "radio_button",
+	#[cfg(emojic_group = "symbols")] "radioactive",
+	#[cfg(emojic_group = "smileys_and_emotion")] "rage",
+	#[cfg(emojic_group = "travel_and_places")] "railway_car",
+	#[cfg(emojic_group = "travel_and_places")] "railway_track",
+	#[cfg(emojic_group = "travel_and_places")] "rainbow",
+	#[cfg(emojic_group = "flags")] "rainbow_flag",
+	#[cfg(emojic_group = "people_and_body")] "raised_back_of_hand",
+	#[cfg(emojic_group = "smileys_and_emotion")] "raised_eyebrow",
+	#[cfg(emojic_group = "people_and_body")] "raised_fist",
+	#[cfg(emojic_group = "people_and_body")] "raised_hand",
+	#[cfg(emojic_group = "people_and_body")] "raised_hand_with_fingers_splayed",
+	#[cfg(emojic_group = "people_and_body")] "raised_hands",
+	#[cfg(emojic_group = "people_and_body")] "raising_hand",
+	#[cfg(emojic_group = "people_and_body")] "raising_hand_man",
+	#[cfg(emojic_group = "people_and_body")] "raising_hand_woman",
+	#[cfg(emojic_group = "people_and_body")] "raising_hands",
+	#[cfg(emojic_group = "animals_and_nature")] "ram",
+	#[cfg(emojic_group = "food_and_drink")] "ramen",
+	#[cfg(emojic_group = "animals_and_nature")] "rat",
+	#[cfg(emojic_group = "objects")] "razor",
+	#[cfg(emojic_group = "objects")] "receipt",
+	#[cfg(emojic_group = "symbols")] "record_button",
+	#[cfg(emojic_group = "symbols")] "recycle",
+	#[cfg(emojic_group = "symbols")] "recycling_symbol",
+	#[cfg(emojic_group = "food_and_drink")] "red_apple",
+	#[cfg(emojic_group = "travel_and_places")] "red_car",
+	#[cfg(emojic_group = "symbols")] "red_circle",
+	#[cfg(emojic_group = "activities")] "red_envelope",
+	#[cfg(emojic_group = "symbols")] "red_exclamation_mark",
+	#[cfg(emojic_group = "component")] "red_hair",
+	#[cfg(emojic_group = "people_and_body")] "red_haired_man",
+	#[cfg(emojic_group = "people_and_body")] "red_haired_woman",
+	#[cfg(emojic_group = "smileys_and_emotion")] "red_heart",
+	#[cfg(emojic_group = "objects")] "red_paper_lantern",
+	#[cfg(emojic_group = "symbols")] "red_question_mark",
+	#[cfg(emojic_group = "symbols")] "red_square",
+	#[cfg(emojic_group = "symbols")] "red_triangle_pointed_down",
+	#[cfg(emojic_group = "symbols")] "red_triangle_pointed_up",
+	#[cfg(emojic_group = "symbols")] "registered",
+	#[cfg(emojic_group = "smileys_and_emotion")] "relaxed",
+	#[cfg(emojic_group = "smileys_and_emotion")] "relieved",
+	#[cfg(emojic_group = "smileys_and_emotion")] "relieved_face",
+	#[cfg(emojic_group = "activities")] "reminder_ribbon",
+	#[cfg(emojic_group = "symbols")] "repeat",
+	#[cfg(emojic_group = "symbols")] "repeat_button",
+	#[cfg(emojic_group = "symbols")] "repeat_one",
+	#[cfg(emojic_group = "symbols")] "repeat_single_button",
+	#[cfg(emojic_group = "objects")] "rescue_worker_helmet",
+	#[cfg(emojic_group = "objects")] "rescue_worker_s_helmet",
+	#[cfg(emojic_group = "symbols")] "restroom",
+	#[cfg(emojic_group = "flags")] "reunion",
+	#[cfg(emojic_group = "symbols")] "reverse_button",
+	#[cfg(emojic_group = "smileys_and_emotion")] "revolving_hearts",
+	#[cfg(emojic_group = "symbols")] "rewind",
+	#[cfg(emojic_group = "animals_and_nature")] "rhinoceros",
+	#[cfg(emojic_group = "activities")] "ribbon",
+	#[cfg(emojic_group = "food_and_drink")] "rice",
+	#[cfg(emojic_group = "food_and_drink")] "rice_ball",
+	#[cfg(emojic_group = "food_and_drink")] "rice_cracker",
+	#[cfg(emojic_group = "activities")] "rice_scene",
+	#[cfg(emojic_group = "smileys_and_emotion")] "right_anger_bubble",
+	#[cfg(emojic_group = "symbols")] "right_arrow",
+	#[cfg(emojic_group = "symbols")] "right_arrow_curving_down",
+	#[cfg(emojic_group = "symbols")] "right_arrow_curving_left",
+	#[cfg(emojic_group = "symbols")] "right_arrow_curving_up",
+	#[cfg(emojic_group = "people_and_body")] "right_facing_fist",
+	#[cfg(emojic_group = "people_and_body")] "rightwards_hand",
+	#[cfg(emojic_group = "objects")] "ring",
+	#[cfg(emojic_group = "travel_and_places")] "ring_buoy",
+	#[cfg(emojic_group = "travel_and_places")] "ringed_planet",
+	#[cfg(emojic_group = "food_and_drink")] "roasted_sweet_potato",
+	#[cfg(emojic_group = "smileys_and_emotion")] "robot",
+	#[cfg(emojic_group = "travel_and_places")] "rock",
+	#[cfg(emojic_group = "travel_and_places")] "rocket",
+	#[cfg(emojic_group = "smileys_and_emotion")] "rofl",
+	#[cfg(emojic_group = "smileys_and_emotion")] "roll_eyes",
+	#[cfg(emojic_group = "objects")] "roll_of_paper",
+	#[cfg(emojic_group = "objects")] "rolled_up_newspaper",
+	#[cfg(emojic_group = "travel_and_places")] "roller_coaster",
+	#[cfg(emojic_group = "travel_and_places")] "roller_skate",
+	#[cfg(emojic_group = "smileys_and_emotion")] "rolling_on_the_floor_laughing",
+	#[cfg(emojic_group = "flags")] "romania",
+	#[cfg(emojic_group = "animals_and_nature")] "rooster",
+	#[cfg(emojic_group = "animals_and_nature")] "rose",
+	#[cfg(emojic_group = "animals_and_nature")] "rosette",
+	#[cfg(emojic_group = "travel_and_places")] "rotating_light",
+	#[cfg(emojic_group = "objects")] "round_pushpin",
+	#[cfg(emojic_group = "people_and_body")] "rowboat",
+	#[cfg(emojic_group = "people_and_body")] "rowing_man",
+	#[cfg(emojic_group = "people_and_body")] "rowing_woman",
+	#[cfg(emojic_group = "flags")] "ru",
+	#[cfg(emojic_group = "activities")] "rugby_football",
+	#[cfg(emojic_group = "people_and_body")] "runner",
+	#[cfg(emojic_group = "people_and_body")] "running",
+	#[cfg(emojic_group = "people_and_body")] "running_man",
+	#[cfg(emojic_group = "activities")] "running_shirt",
+	#[cfg(emojic_group = "activities")] "running_shirt_with_sash",
+	#[cfg(emojic_group = "objects")] "running_shoe",
+	#[cfg(emojic_group = "people_and_body")] "running_woman",
+	#[cfg(emojic_group = "flags")] "rwanda",
+	#[cfg(emojic_group = "symbols")] "sa",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sad_but_relieved_face",
+	#[cfg(emojic_group = "objects")] "safety_pin",
+	#[cfg(emojic_group = "objects")] "safety_vest",
+	#[cfg(emojic_group = "symbols")] "sagittarius",
+	#[cfg(emojic_group = "travel_and_places")] "sailboat",
+	#[cfg(emojic_group = "food_and_drink")] "sake",
+	#[cfg(emojic_group = "food_and_drink")] "salt",
+	#[cfg(emojic_group = "smileys_and_emotion")] "saluting_face",
+	#[cfg(emojic_group = "flags")] "samoa",
+	#[cfg(emojic_group = "flags")] "san_marino",
+	#[cfg(emojic_group = "objects")] "sandal",
+	#[cfg(emojic_group = "food_and_drink")] "sandwich",
+	#[cfg(emojic_group = "people_and_body")] "santa",
+	#[cfg(emojic_group = "people_and_body")] "santa_claus",
+	#[cfg(emojic_group = "flags")] "sao_tome_principe",
+	#[cfg(emojic_group = "objects")] "sari",
+	#[cfg(emojic_group = "people_and_body")] "sassy_man",
+	#[cfg(emojic_group = "people_and_body")] "sassy_woman",
+	#[cfg(emojic_group = "travel_and_places")] "satellite",
+	#[cfg(emojic_group = "objects")] "satellite_antenna",
+	#[cfg(emojic_group = "smileys_and_emotion")] "satisfied",
+	#[cfg(emojic_group = "flags")] "saudi_arabia",
+	#[cfg(emojic_group = "people_and_body")] "sauna_man",
+	#[cfg(emojic_group = "people_and_body")] "sauna_person",
+	#[cfg(emojic_group = "people_and_body")] "sauna_woman",
+	#[cfg(emojic_group = "animals_and_nature")] "sauropod",
+	#[cfg(emojic_group = "objects")] "saxophone",
+	#[cfg(emojic_group = "objects")] "scarf",
+	#[cfg(emojic_group = "travel_and_places")] "school",
+	#[cfg(emojic_group = "objects")] "school_satchel",
+	#[cfg(emojic_group = "people_and_body")] "scientist",
+	#[cfg(emojic_group = "objects")] "scissors",
+	#[cfg(emojic_group = "symbols")] "scorpio",
+	#[cfg(emojic_group = "animals_and_nature")] "scorpion",
+	#[cfg(emojic_group = "symbols")] "scorpius",
+	#[cfg(emojic_group = "flags")] "scotland",
+	#[cfg(emojic_group = "smileys_and_emotion")] "scream",
+	#[cfg(emojic_group = "smileys_and_emotion")] "scream_cat",
+	#[cfg(emojic_group = "objects")] "screwdriver",
+	#[cfg(emojic_group = "objects")] "scroll",
+	#[cfg(emojic_group = "animals_and_nature")] "seal",
+	#[cfg(emojic_group = "travel_and_places")] "seat",
+	#[cfg(emojic_group = "activities")] "second_place_medal",
+	#[cfg(emojic_group = "symbols")] "secret",
+	#[cfg(emojic_group = "smileys_and_emotion")] "see_no_evil",
+	#[cfg(emojic_group = "smileys_and_emotion")] "see_no_evil_monkey",
+	#[cfg(emojic_group = "animals_and_nature")] "seedling",
+	#[cfg(emojic_group = "people_and_body")] "selfie",
+	#[cfg(emojic_group = "flags")] "senegal",
+	#[cfg(emojic_group = "flags")] "serbia",
+	#[cfg(emojic_group = "animals_and_nature")] "service_dog",
+	#[cfg(emojic_group = "symbols")] "seven",
+	#[cfg(emojic_group = "travel_and_places")] "seven_o_clock",
+	#[cfg(emojic_group = "travel_and_places")] "seven_thirty",
+	#[cfg(emojic_group = "activities")] "sewing_needle",
+	#[cfg(emojic_group = "flags")] "seychelles",
+	#[cfg(emojic_group = "food_and_drink")] "shallow_pan_of_food",
+	#[cfg(emojic_group = "animals_and_nature")] "shamrock",
+	#[cfg(emojic_group = "animals_and_nature")] "shark",
+	#[cfg(emojic_group = "food_and_drink")] "shaved_ice",
+	#[cfg(emojic_group = "animals_and_nature")] "sheaf_of_rice",
+	#[cfg(emojic_group = "animals_and_nature")] "sheep",
+	#[cfg(emojic_group = "animals_and_nature")] "shell",
+	#[cfg(emojic_group = "objects")] "shield",
+	#[cfg(emojic_group = "travel_and_places")] "shinto_shrine",
+	#[cfg(emojic_group = "travel_and_places")] "ship",
+	#[cfg(emojic_group = "objects")] "shirt",
+	#[cfg(emojic_group = "smileys_and_emotion")] "shit",
+	#[cfg(emojic_group = "objects")] "shoe",
+	#[cfg(emojic_group = "travel_and_places")] "shooting_star",
+	#[cfg(emojic_group = "objects")] "shopping",
+	#[cfg(emojic_group = "objects")] "shopping_bags",
+	#[cfg(emojic_group = "objects")] "shopping_cart",
+	#[cfg(emojic_group = "food_and_drink")] "shortcake",
+	#[cfg(emojic_group = "objects")] "shorts",
+	#[cfg(emojic_group = "objects")] "shower",
+	#[cfg(emojic_group = "food_and_drink")] "shrimp",
+	#[cfg(emojic_group = "people_and_body")] "shrug",
+	#[cfg(emojic_group = "symbols")] "shuffle_tracks_button",
+	#[cfg(emojic_group = "smileys_and_emotion")] "shushing_face",
+	#[cfg(emojic_group = "flags")] "sierra_leone",
+	#[cfg(emojic_group = "people_and_body")] "sign_of_the_horns",
+	#[cfg(emojic_group = "symbols")] "signal_strength",
+	#[cfg(emojic_group = "flags")] "singapore",
+	#[cfg(emojic_group = "people_and_body")] "singer",
+	#[cfg(emojic_group = "flags")] "sint_maarten",
+	#[cfg(emojic_group = "symbols")] "six",
+	#[cfg(emojic_group = "travel_and_places")] "six_o_clock",
+	#[cfg(emojic_group = "symbols")] "six_pointed_star",
+	#[cfg(emojic_group = "travel_and_places")] "six_thirty",
+	#[cfg(emojic_group = "travel_and_places")] "skateboard",
+	#[cfg(emojic_group = "activities")] "ski",
+	#[cfg(emojic_group = "people_and_body")] "skier",
+	#[cfg(emojic_group = "activities")] "skis",
+	#[cfg(emojic_group = "smileys_and_emotion")] "skull",
+	#[cfg(emojic_group = "smileys_and_emotion")] "skull_and_crossbones",
+	#[cfg(emojic_group = "animals_and_nature")] "skunk",
+	#[cfg(emojic_group = "activities")] "sled",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sleeping",
+	#[cfg(emojic_group = "people_and_body")] "sleeping_bed",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sleeping_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sleepy",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sleepy_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "slightly_frowning_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "slightly_smiling_face",
+	#[cfg(emojic_group = "activities")] "slot_machine",
+	#[cfg(emojic_group = "animals_and_nature")] "sloth",
+	#[cfg(emojic_group = "flags")] "slovakia",
+	#[cfg(emojic_group = "flags")] "slovenia",
+	#[cfg(emojic_group = "travel_and_places")] "small_airplane",
+	#[cfg(emojic_group = "symbols")] "small_blue_diamond",
+	#[cfg(emojic_group = "symbols")] "small_orange_diamond",
+	#[cfg(emojic_group = "symbols")] "small_red_triangle",
+	#[cfg(emojic_group = "symbols")] "small_red_triangle_down",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smile",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smile_cat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiley",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiley_cat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling_cat_with_heart_eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling_face_with_halo",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling_face_with_heart_eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling_face_with_hearts",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling_face_with_horns",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling_face_with_smiling_eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling_face_with_sunglasses",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling_face_with_tear",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling_face_with_three_hearts",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smiling_imp",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smirk",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smirk_cat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "smirking_face",
+	#[cfg(emojic_group = "objects")] "smoking",
+	#[cfg(emojic_group = "animals_and_nature")] "snail",
+	#[cfg(emojic_group = "animals_and_nature")] "snake",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sneezing_face",
+	#[cfg(emojic_group = "travel_and_places")] "snow_capped_mountain",
+	#[cfg(emojic_group = "people_and_body")] "snowboarder",
+	#[cfg(emojic_group = "travel_and_places")] "snowflake",
+	#[cfg(emojic_group = "travel_and_places")] "snowman",
+	#[cfg(emojic_group = "travel_and_places")] "snowman_with_snow",
+	#[cfg(emojic_group = "travel_and_places")] "snowman_without_snow",
+	#[cfg(emojic_group = "objects")] "soap",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sob",
+	#[cfg(emojic_group = "activities")] "soccer",
+	#[cfg(emojic_group = "activities")] "soccer_ball",
+	#[cfg(emojic_group = "objects")] "socks",
+	#[cfg(emojic_group = "food_and_drink")] "soft_ice_cream",
+	#[cfg(emojic_group = "activities")] "softball",
+	#[cfg(emojic_group = "flags")] "solomon_islands",
+	#[cfg(emojic_group = "flags")] "somalia",
+	#[cfg(emojic_group = "symbols")] "soon",
+	#[cfg(emojic_group = "symbols")] "soon_arrow",
+	#[cfg(emojic_group = "symbols")] "sos",
+	#[cfg(emojic_group = "symbols")] "sos_button",
+	#[cfg(emojic_group = "objects")] "sound",
+	#[cfg(emojic_group = "flags")] "south_africa",
+	#[cfg(emojic_group = "flags")] "south_georgia_south_sandwich_islands",
+	#[cfg(emojic_group = "flags")] "south_sudan",
+	#[cfg(emojic_group = "smileys_and_emotion")] "space_invader",
+	#[cfg(emojic_group = "activities")] "spade_suit",
+	#[cfg(emojic_group = "activities")] "spades",
+	#[cfg(emojic_group = "food_and_drink")] "spaghetti",
+	#[cfg(emojic_group = "symbols")] "sparkle",
+	#[cfg(emojic_group = "activities")] "sparkler",
+	#[cfg(emojic_group = "activities")] "sparkles",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sparkling_heart",
+	#[cfg(emojic_group = "smileys_and_emotion")] "speak_no_evil",
+	#[cfg(emojic_group = "smileys_and_emotion")] "speak_no_evil_monkey",
+	#[cfg(emojic_group = "objects")] "speaker",
+	#[cfg(emojic_group = "objects")] "speaker_high_volume",
+	#[cfg(emojic_group = "objects")] "speaker_low_volume",
+	#[cfg(emojic_group = "objects")] "speaker_medium_volume",
+	#[cfg(emojic_group = "people_and_body")] "speaking_head",
+	#[cfg(emojic_group = "smileys_and_emotion")] "speech_balloon",
+	#[cfg(emojic_group = "travel_and_places")] "speedboat",
+	#[cfg(emojic_group = "animals_and_nature")] "spider",
+	#[cfg(emojic_group = "animals_and_nature")] "spider_web",
+	#[cfg(emojic_group = "objects")] "spiral_calendar",
+	#[cfg(emojic_group = "objects")] "spiral_notepad",
+	#[cfg(emojic_group = "animals_and_nature")] "spiral_shell",
+	#[cfg(emojic_group = "objects")] "sponge",
+	#[cfg(emojic_group = "food_and_drink")] "spoon",
+	#[cfg(emojic_group = "travel_and_places")] "sport_utility_vehicle",
+	#[cfg(emojic_group = "activities")] "sports_medal",
+	#[cfg(emojic_group = "animals_and_nature")] "spouting_whale",
+	#[cfg(emojic_group = "food_and_drink")] "squid",
+	#[cfg(emojic_group = "smileys_and_emotion")] "squinting_face_with_tongue",
+	#[cfg(emojic_group = "flags")] "sri_lanka",
+	#[cfg(emojic_group = "flags")] "st_barthelemy",
+	#[cfg(emojic_group = "flags")] "st_helena",
+	#[cfg(emojic_group = "flags")] "st_kitts_nevis",
+	#[cfg(emojic_group = "flags")] "st_lucia",
+	#[cfg(emojic_group = "flags")] "st_martin",
+	#[cfg(emojic_group = "flags")] "st_pierre_miquelon",
+	#[cfg(emojic_group = "flags")] "st_vincent_grenadines",
+	#[cfg(emojic_group = "travel_and_places")] "stadium",
+	#[cfg(emojic_group = "people_and_body")] "standing_man",
+	#[cfg(emojic_group = "people_and_body")] "standing_person",
+	#[cfg(emojic_group = "people_and_body")] "standing_woman",
+	#[cfg(emojic_group = "travel_and_places")] "star",
+	#[cfg(emojic_group = "travel_and_places")] "star2",
+	#[cfg(emojic_group = "symbols")] "star_and_crescent",
+	#[cfg(emojic_group = "symbols")] "star_of_david",
+	#[cfg(emojic_group = "smileys_and_emotion")] "star_struck",
+	#[cfg(emojic_group = "travel_and_places")] "stars",
+	#[cfg(emojic_group = "travel_and_places")] "station",
+	#[cfg(emojic_group = "travel_and_places")] "statue_of_liberty",
+	#[cfg(emojic_group = "travel_and_places")] "steam_locomotive",
+	#[cfg(emojic_group = "food_and_drink")] "steaming_bowl",
+	#[cfg(emojic_group = "objects")] "stethoscope",
+	#[cfg(emojic_group = "food_and_drink")] "stew",
+	#[cfg(emojic_group = "symbols")] "stop_button",
+	#[cfg(emojic_group = "travel_and_places")] "stop_sign",
+	#[cfg(emojic_group = "travel_and_places")] "stopwatch",
+	#[cfg(emojic_group = "objects")] "straight_ruler",
+	#[cfg(emojic_group = "food_and_drink")] "strawberry",
+	#[cfg(emojic_group = "smileys_and_emotion")] "stuck_out_tongue",
+	#[cfg(emojic_group = "smileys_and_emotion")] "stuck_out_tongue_closed_eyes",
+	#[cfg(emojic_group = "smileys_and_emotion")] "stuck_out_tongue_winking_eye",
+	#[cfg(emojic_group = "people_and_body")] "student",
+	#[cfg(emojic_group = "objects")] "studio_microphone",
+	#[cfg(emojic_group = "food_and_drink")] "stuffed_flatbread",
+	#[cfg(emojic_group = "flags")] "sudan",
+	#[cfg(emojic_group = "travel_and_places")] "sun",
+	#[cfg(emojic_group = "travel_and_places")] "sun_behind_cloud",
+	#[cfg(emojic_group = "travel_and_places")] "sun_behind_large_cloud",
+	#[cfg(emojic_group = "travel_and_places")] "sun_behind_rain_cloud",
+	#[cfg(emojic_group = "travel_and_places")] "sun_behind_small_cloud",
+	#[cfg(emojic_group = "travel_and_places")] "sun_with_face",
+	#[cfg(emojic_group = "animals_and_nature")] "sunflower",
+	#[cfg(emojic_group = "objects")] "sunglasses",
+	#[cfg(emojic_group = "travel_and_places")] "sunny",
+	#[cfg(emojic_group = "travel_and_places")] "sunrise",
+	#[cfg(emojic_group = "travel_and_places")] "sunrise_over_mountains",
+	#[cfg(emojic_group = "travel_and_places")] "sunset",
+	#[cfg(emojic_group = "people_and_body")] "superhero",
+	#[cfg(emojic_group = "people_and_body")] "superhero_man",
+	#[cfg(emojic_group = "people_and_body")] "superhero_woman",
+	#[cfg(emojic_group = "people_and_body")] "supervillain",
+	#[cfg(emojic_group = "people_and_body")] "supervillain_man",
+	#[cfg(emojic_group = "people_and_body")] "supervillain_woman",
+	#[cfg(emojic_group = "people_and_body")] "surfer",
+	#[cfg(emojic_group = "people_and_body")] "surfing_man",
+	#[cfg(emojic_group = "people_and_body")] "surfing_woman",
+	#[cfg(emojic_group = "flags")] "suriname",
+	#[cfg(emojic_group = "food_and_drink")] "sushi",
+	#[cfg(emojic_group = "travel_and_places")] "suspension_railway",
+	#[cfg(emojic_group = "flags")] "svalbard_jan_mayen",
+	#[cfg(emojic_group = "animals_and_nature")] "swan",
+	#[cfg(emojic_group = "flags")] "swaziland",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sweat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sweat_droplets",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sweat_drops",
+	#[cfg(emojic_group = "smileys_and_emotion")] "sweat_smile",
+	#[cfg(emojic_group = "flags")] "sweden",
+	#[cfg(emojic_group = "food_and_drink")] "sweet_potato",
+	#[cfg(emojic_group = "objects")] "swim_brief",
+	#[cfg(emojic_group = "people_and_body")] "swimmer",
+	#[cfg(emojic_group = "people_and_body")] "swimming_man",
+	#[cfg(emojic_group = "people_and_body")] "swimming_woman",
+	#[cfg(emojic_group = "flags")] "switzerland",
+	#[cfg(emojic_group = "symbols")] "symbols",
+	#[cfg(emojic_group = "travel_and_places")] "synagogue",
+	#[cfg(emojic_group = "flags")] "syria",
+	#[cfg(emojic_group = "objects")] "syringe",
+	#[cfg(emojic_group = "animals_and_nature")] "t-rex",
+	#[cfg(emojic_group = "animals_and_nature")] "t_rex",
+	#[cfg(emojic_group = "objects")] "t_shirt",
+	#[cfg(emojic_group = "food_and_drink")] "taco",
+	#[cfg(emojic_group = "activities")] "tada",
+	#[cfg(emojic_group = "flags")] "taiwan",
+	#[cfg(emojic_group = "flags")] "tajikistan",
+	#[cfg(emojic_group = "food_and_drink")] "takeout_box",
+	#[cfg(emojic_group = "food_and_drink")] "tamale",
+	#[cfg(emojic_group = "activities")] "tanabata_tree",
+	#[cfg(emojic_group = "food_and_drink")] "tangerine",
+	#[cfg(emojic_group = "flags")] "tanzania",
+	#[cfg(emojic_group = "symbols")] "taurus",
+	#[cfg(emojic_group = "travel_and_places")] "taxi",
+	#[cfg(emojic_group = "food_and_drink")] "tea",
+	#[cfg(emojic_group = "people_and_body")] "teacher",
+	#[cfg(emojic_group = "food_and_drink")] "teacup_without_handle",
+	#[cfg(emojic_group = "food_and_drink")] "teapot",
+	#[cfg(emojic_group = "objects")] "tear_off_calendar",
+	#[cfg(emojic_group = "people_and_body")] "technologist",
+	#[cfg(emojic_group = "activities")] "teddy_bear",
+	#[cfg(emojic_group = "objects")] "telephone",
+	#[cfg(emojic_group = "objects")] "telephone_receiver",
+	#[cfg(emojic_group = "objects")] "telescope",
+	#[cfg(emojic_group = "objects")] "television",
+	#[cfg(emojic_group = "travel_and_places")] "ten_o_clock",
+	#[cfg(emojic_group = "travel_and_places")] "ten_thirty",
+	#[cfg(emojic_group = "activities")] "tennis",
+	#[cfg(emojic_group = "travel_and_places")] "tent",
+	#[cfg(emojic_group = "objects")] "test_tube",
+	#[cfg(emojic_group = "flags")] "thailand",
+	#[cfg(emojic_group = "travel_and_places")] "thermometer",
+	#[cfg(emojic_group = "smileys_and_emotion")] "thinking",
+	#[cfg(emojic_group = "smileys_and_emotion")] "thinking_face",
+	#[cfg(emojic_group = "activities")] "third_place_medal",
+	#[cfg(emojic_group = "objects")] "thong_sandal",
+	#[cfg(emojic_group = "smileys_and_emotion")] "thought_balloon",
+	#[cfg(emojic_group = "activities")] "thread",
+	#[cfg(emojic_group = "symbols")] "three",
+	#[cfg(emojic_group = "travel_and_places")] "three_o_clock",
+	#[cfg(emojic_group = "travel_and_places")] "three_thirty",
+	#[cfg(emojic_group = "people_and_body")] "thumbs_down",
+	#[cfg(emojic_group = "people_and_body")] "thumbs_up",
+	#[cfg(emojic_group = "people_and_body")] "thumbsdown",
+	#[cfg(emojic_group = "people_and_body")] "thumbsup",
+	#[cfg(emojic_group = "activities")] "ticket",
+	#[cfg(emojic_group = "activities")] "tickets",
+	#[cfg(emojic_group = "animals_and_nature")] "tiger",
+	#[cfg(emojic_group = "animals_and_nature")] "tiger2",
+	#[cfg(emojic_group = "animals_and_nature")] "tiger_face",
+	#[cfg(emojic_group = "travel_and_places")] "timer_clock",
+	#[cfg(emojic_group = "flags")] "timor_leste",
+	#[cfg(emojic_group = "people_and_body")] "tipping_hand_man",
+	#[cfg(emojic_group = "people_and_body")] "tipping_hand_person",
+	#[cfg(emojic_group = "people_and_body")] "tipping_hand_woman",
+	#[cfg(emojic_group = "smileys_and_emotion")] "tired_face",
+	#[cfg(emojic_group = "symbols")] "tm",
+	#[cfg(emojic_group = "flags")] "togo",
+	#[cfg(emojic_group = "objects")] "toilet",
+	#[cfg(emojic_group = "flags")] "tokelau",
+	#[cfg(emojic_group = "travel_and_places")] "tokyo_tower",
+	#[cfg(emojic_group = "food_and_drink")] "tomato",
+	#[cfg(emojic_group = "flags")] "tonga",
+	#[cfg(emojic_group = "people_and_body")] "tongue",
+	#[cfg(emojic_group = "objects")] "toolbox",
+	#[cfg(emojic_group = "people_and_body")] "tooth",
+	#[cfg(emojic_group = "objects")] "toothbrush",
+	#[cfg(emojic_group = "symbols")] "top",
+	#[cfg(emojic_group = "symbols")] "top_arrow",
+	#[cfg(emojic_group = "objects")] "top_hat",
+	#[cfg(emojic_group = "objects")] "tophat",
+	#[cfg(emojic_group = "travel_and_places")] "tornado",
+	#[cfg(emojic_group = "flags")] "tr",
+	#[cfg(emojic_group = "objects")] "trackball",
+	#[cfg(emojic_group = "travel_and_places")] "tractor",
+	#[cfg(emojic_group = "symbols")] "trade_mark",
+	#[cfg(emojic_group = "travel_and_places")] "traffic_light",
+	#[cfg(emojic_group = "travel_and_places")] "train",
+	#[cfg(emojic_group = "travel_and_places")] "train2",
+	#[cfg(emojic_group = "travel_and_places")] "tram",
+	#[cfg(emojic_group = "travel_and_places")] "tram_car",
+	#[cfg(emojic_group = "flags")] "transgender_flag",
+	#[cfg(emojic_group = "symbols")] "transgender_symbol",
+	#[cfg(emojic_group = "flags")] "triangular_flag",
+	#[cfg(emojic_group = "flags")] "triangular_flag_on_post",
+	#[cfg(emojic_group = "objects")] "triangular_ruler",
+	#[cfg(emojic_group = "symbols")] "trident",
+	#[cfg(emojic_group = "symbols")] "trident_emblem",
+	#[cfg(emojic_group = "flags")] "trinidad_tobago",
+	#[cfg(emojic_group = "flags")] "tristan_da_cunha",
+	#[cfg(emojic_group = "smileys_and_emotion")] "triumph",
+	#[cfg(emojic_group = "people_and_body")] "troll",
+	#[cfg(emojic_group = "travel_and_places")] "trolleybus",
+	#[cfg(emojic_group = "activities")] "trophy",
+	#[cfg(emojic_group = "food_and_drink")] "tropical_drink",
+	#[cfg(emojic_group = "animals_and_nature")] "tropical_fish",
+	#[cfg(emojic_group = "travel_and_places")] "truck",
+	#[cfg(emojic_group = "objects")] "trumpet",
+	#[cfg(emojic_group = "objects")] "tshirt",
+	#[cfg(emojic_group = "animals_and_nature")] "tulip",
+	#[cfg(emojic_group = "food_and_drink")] "tumbler_glass",
+	#[cfg(emojic_group = "flags")] "tunisia",
+	#[cfg(emojic_group = "animals_and_nature")] "turkey",
+	#[cfg(emojic_group = "flags")] "turkmenistan",
+	#[cfg(emojic_group = "flags")] "turks_caicos_islands",
+	#[cfg(emojic_group = "animals_and_nature")] "turtle",
+	#[cfg(emojic_group = "flags")] "tuvalu",
+	#[cfg(emojic_group = "objects")] "tv",
+	#[cfg(emojic_group = "travel_and_places")] "twelve_o_clock",
+	#[cfg(emojic_group = "travel_and_places")] "twelve_thirty",
+	#[cfg(emojic_group = "symbols")] "twisted_rightwards_arrows",
+	#[cfg(emojic_group = "symbols")] "two",
+	#[cfg(emojic_group = "smileys_and_emotion")] "two_hearts",
+	#[cfg(emojic_group = "animals_and_nature")] "two_hump_camel",
+	#[cfg(emojic_group = "people_and_body")] "two_men_holding_hands",
+	#[cfg(emojic_group = "travel_and_places")] "two_o_clock",
+	#[cfg(emojic_group = "travel_and_places")] "two_thirty",
+	#[cfg(emojic_group = "people_and_body")] "two_women_holding_hands",
+	#[cfg(emojic_group = "symbols")] "u5272",
+	#[cfg(emojic_group = "symbols")] "u5408",
+	#[cfg(emojic_group = "symbols")] "u55b6",
+	#[cfg(emojic_group = "symbols")] "u6307",
+	#[cfg(emojic_group = "symbols")] "u6708",
+	#[cfg(emojic_group = "symbols")] "u6709",
+	#[cfg(emojic_group = "symbols")] "u6e80",
+	#[cfg(emojic_group = "symbols")] "u7121",
+	#[cfg(emojic_group = "symbols")] "u7533",
+	#[cfg(emojic_group = "symbols")] "u7981",
+	#[cfg(emojic_group = "symbols")] "u7a7a",
+	#[cfg(emojic_group = "flags")] "uganda",
+	#[cfg(emojic_group = "flags")] "uk",
+	#[cfg(emojic_group = "flags")] "ukraine",
+	#[cfg(emojic_group = "travel_and_places")] "umbrella",
+	#[cfg(emojic_group = "travel_and_places")] "umbrella_on_ground",
+	#[cfg(emojic_group = "travel_and_places")] "umbrella_with_rain_drops",
+	#[cfg(emojic_group = "smileys_and_emotion")] "unamused",
+	#[cfg(emojic_group = "smileys_and_emotion")] "unamused_face",
+	#[cfg(emojic_group = "symbols")] "underage",
+	#[cfg(emojic_group = "animals_and_nature")] "unicorn",
+	#[cfg(emojic_group = "flags")] "united_arab_emirates",
+	#[cfg(emojic_group = "flags")] "united_nations",
+	#[cfg(emojic_group = "objects")] "unlock",
+	#[cfg(emojic_group = "objects")] "unlocked",
+	#[cfg(emojic_group = "symbols")] "up",
+	#[cfg(emojic_group = "symbols")] "up_arrow",
+	#[cfg(emojic_group = "symbols")] "up_button",
+	#[cfg(emojic_group = "symbols")] "up_down_arrow",
+	#[cfg(emojic_group = "symbols")] "up_left_arrow",
+	#[cfg(emojic_group = "symbols")] "up_right_arrow",
+	#[cfg(emojic_group = "smileys_and_emotion")] "upside_down_face",
+	#[cfg(emojic_group = "symbols")] "upwards_button",
+	#[cfg(emojic_group = "flags")] "uruguay",
+	#[cfg(emojic_group = "flags")] "us",
+	#[cfg(emojic_group = "flags")] "us_outlying_islands",
+	#[cfg(emojic_group = "flags")] "us_virgin_islands",
+	#[cfg(emojic_group = "flags")] "uzbekistan",
+	#[cfg(emojic_group = "people_and_body")] "v",
+	#[cfg(emojic_group = "people_and_body")] "vampire",
+	#[cfg(emojic_group = "people_and_body")] "vampire_man",
+	#[cfg(emojic_group = "people_and_body")] "vampire_woman",
+	#[cfg(emojic_group = "flags")] "vanuatu",
+	#[cfg(emojic_group = "flags")] "vatican_city",
+	#[cfg(emojic_group = "flags")] "venezuela",
+	#[cfg(emojic_group = "travel_and_places")] "vertical_traffic_light",
+	#[cfg(emojic_group = "objects")] "vhs",
+	#[cfg(emojic_group = "symbols")] "vibration_mode",
+	#[cfg(emojic_group = "people_and_body")] "victory_hand",
+	#[cfg(emojic_group = "objects")] "video_camera",
+	#[cfg(emojic_group = "activities")] "video_game",
+	#[cfg(emojic_group = "objects")] "videocassette",
+	#[cfg(emojic_group = "flags")] "vietnam",
+	#[cfg(emojic_group = "objects")] "violin",
+	#[cfg(emojic_group = "symbols")] "virgo",
+	#[cfg(emojic_group = "travel_and_places")] "volcano",
+	#[cfg(emojic_group = "activities")] "volleyball",
+	#[cfg(emojic_group = "smileys_and_emotion")] "vomiting_face",
+	#[cfg(emojic_group = "symbols")] "vs",
+	#[cfg(emojic_group = "symbols")] "vs_button",
+	#[cfg(emojic_group = "people_and_body")] "vulcan_salute",
+	#[cfg(emojic_group = "food_and_drink")] "waffle",
+	#[cfg(emojic_group = "flags")] "wales",
+	#[cfg(emojic_group = "people_and_body")] "walking",
+	#[cfg(emojic_group = "people_and_body")] "walking_man",
+	#[cfg(emojic_group = "people_and_body")] "walking_woman",
+	#[cfg(emojic_group = "flags")] "wallis_futuna",
+	#[cfg(emojic_group = "travel_and_places")] "waning_crescent_moon",
+	#[cfg(emojic_group = "travel_and_places")] "waning_gibbous_moon",
+	#[cfg(emojic_group = "symbols")] "warning",
+	#[cfg(emojic_group = "objects")] "wastebasket",
+	#[cfg(emojic_group = "travel_and_places")] "watch",
+	#[cfg(emojic_group = "animals_and_nature")] "water_buffalo",
+	#[cfg(emojic_group = "symbols")] "water_closet",
+	#[cfg(emojic_group = "objects")] "water_pistol",
+	#[cfg(emojic_group = "people_and_body")] "water_polo",
+	#[cfg(emojic_group = "travel_and_places")] "water_wave",
+	#[cfg(emojic_group = "food_and_drink")] "watermelon",
+	#[cfg(emojic_group = "people_and_body")] "wave",
+	#[cfg(emojic_group = "people_and_body")] "waving_hand",
+	#[cfg(emojic_group = "symbols")] "wavy_dash",
+	#[cfg(emojic_group = "travel_and_places")] "waxing_crescent_moon",
+	#[cfg(emojic_group = "travel_and_places")] "waxing_gibbous_moon",
+	#[cfg(emojic_group = "symbols")] "wc",
+	#[cfg(emojic_group = "smileys_and_emotion")] "weary",
+	#[cfg(emojic_group = "smileys_and_emotion")] "weary_cat",
+	#[cfg(emojic_group = "smileys_and_emotion")] "weary_face",
+	#[cfg(emojic_group = "travel_and_places")] "wedding",
+	#[cfg(emojic_group = "people_and_body")] "weight_lifting",
+	#[cfg(emojic_group = "people_and_body")] "weight_lifting_man",
+	#[cfg(emojic_group = "people_and_body")] "weight_lifting_woman",
+	#[cfg(emojic_group = "flags")] "western_sahara",
+	#[cfg(emojic_group = "animals_and_nature")] "whale",
+	#[cfg(emojic_group = "animals_and_nature")] "whale2",
+	#[cfg(emojic_group = "travel_and_places")] "wheel",
+	#[cfg(emojic_group = "symbols")] "wheel_of_dharma",
+	#[cfg(emojic_group = "symbols")] "wheelchair",
+	#[cfg(emojic_group = "symbols")] "wheelchair_symbol",
+	#[cfg(emojic_group = "objects")] "white_cane",
+	#[cfg(emojic_group = "symbols")] "white_check_mark",
+	#[cfg(emojic_group = "symbols")] "white_circle",
+	#[cfg(emojic_group = "symbols")] "white_exclamation_mark",
+	#[cfg(emojic_group = "flags")] "white_flag",
+	#[cfg(emojic_group = "animals_and_nature")] "white_flower",
+	#[cfg(emojic_group = "component")] "white_hair",
+	#[cfg(emojic_group = "people_and_body")] "white_haired_man",
+	#[cfg(emojic_group = "people_and_body")] "white_haired_woman",
+	#[cfg(emojic_group = "smileys_and_emotion")] "white_heart",
+	#[cfg(emojic_group = "symbols")] "white_large_square",
+	#[cfg(emojic_group = "symbols")] "white_medium_small_square",
+	#[cfg(emojic_group = "symbols")] "white_medium_square",
+	#[cfg(emojic_group = "symbols")] "white_question_mark",
+	#[cfg(emojic_group = "symbols")] "white_small_square",
+	#[cfg(emojic_group = "symbols")] "white_square_button",
+	#[cfg(emojic_group = "animals_and_nature")] "wilted_flower",
+	#[cfg(emojic_group = "activities")] "wind_chime",
+	#[cfg(emojic_group = "travel_and_places")] "wind_face",
+	#[cfg(emojic_group = "objects")] "window",
+	#[cfg(emojic_group = "food_and_drink")] "wine_glass",
+	#[cfg(emojic_group = "smileys_and_emotion")] "wink",
+	#[cfg(emojic_group = "smileys_and_emotion")] "winking_face",
+	#[cfg(emojic_group = "smileys_and_emotion")] "winking_face_with_tongue",
+	#[cfg(emojic_group = "animals_and_nature")] "wolf",
+	#[cfg(emojic_group = "people_and_body")] "woman",
+	#[cfg(emojic_group = "people_and_body")] "woman_artist",
+	#[cfg(emojic_group = "people_and_body")] "woman_astronaut",
+	#[cfg(emojic_group = "people_and_body")] "woman_beard",
+	#[cfg(emojic_group = "people_and_body")] "woman_cartwheeling",
+	#[cfg(emojic_group = "people_and_body")] "woman_cook",
+	#[cfg(emojic_group = "people_and_body")] "woman_dancing",
+	#[cfg(emojic_group = "people_and_body")] "woman_facepalming",
+	#[cfg(emojic_group = "people_and_body")] "woman_factory_worker",
+	#[cfg(emojic_group = "people_and_body")] "woman_farmer",
+	#[cfg(emojic_group = "people_and_body")] "woman_feeding_baby",
+	#[cfg(emojic_group = "people_and_body")] "woman_firefighter",
+	#[cfg(emojic_group = "people_and_body")] "woman_health_worker",
+	#[cfg(emojic_group = "people_and_body")] "woman_in_manual_wheelchair",
+	#[cfg(emojic_group = "people_and_body")] "woman_in_motorized_wheelchair",
+	#[cfg(emojic_group = "people_and_body")] "woman_in_tuxedo",
+	#[cfg(emojic_group = "people_and_body")] "woman_judge",
+	#[cfg(emojic_group = "people_and_body")] "woman_juggling",
+	#[cfg(emojic_group = "people_and_body")] "woman_mechanic",
+	#[cfg(emojic_group = "people_and_body")] "woman_office_worker",
+	#[cfg(emojic_group = "people_and_body")] "woman_pilot",
+	#[cfg(emojic_group = "people_and_body")] "woman_playing_handball",
+	#[cfg(emojic_group = "people_and_body")] "woman_playing_water_polo",
+	#[cfg(emojic_group = "objects")] "woman_s_boot",
+	#[cfg(emojic_group = "objects")] "woman_s_clothes",
+	#[cfg(emojic_group = "objects")] "woman_s_hat",
+	#[cfg(emojic_group = "objects")] "woman_s_sandal",
+	#[cfg(emojic_group = "people_and_body")] "woman_scientist",
+	#[cfg(emojic_group = "people_and_body")] "woman_shrugging",
+	#[cfg(emojic_group = "people_and_body")] "woman_singer",
+	#[cfg(emojic_group = "people_and_body")] "woman_student",
+	#[cfg(emojic_group = "people_and_body")] "woman_teacher",
+	#[cfg(emojic_group = "people_and_body")] "woman_technologist",
+	#[cfg(emojic_group = "people_and_body")] "woman_with_headscarf",
+	#[cfg(emojic_group = "people_and_body")] "woman_with_probing_cane",
+	#[cfg(emojic_group = "people_and_body")] "woman_with_turban",
+	#[cfg(emojic_group = "people_and_body")] "woman_with_veil",
+	#[cfg(emojic_group = "objects")] "womans_clothes",
+	#[cfg(emojic_group = "objects")] "womans_hat",
+	#[cfg(emojic_group = "symbols")] "women_s_room",
+	#[cfg(emojic_group = "people_and_body")] "women_wrestling",
+	#[cfg(emojic_group = "symbols")] "womens",
+	#[cfg(emojic_group = "travel_and_places")] "wood",
+	#[cfg(emojic_group = "smileys_and_emotion")] "woozy_face",
+	#[cfg(emojic_group = "travel_and_places")] "world_map",
+	#[cfg(emojic_group = "animals_and_nature")] "worm",
+	#[cfg(emojic_group = "smileys_and_emotion")] "worried",
+	#[cfg(emojic_group = "smileys_and_emotion")] "worried_face",
+	#[cfg(emojic_group = "activities")] "wrapped_gift",
+	#[cfg(emojic_group = "objects")] "wrench",
+	#[cfg(emojic_group = "people_and_body")] "wrestling",
+	#[cfg(emojic_group = "people_and_body")] "writing_hand",
+	#[cfg(emojic_group = "symbols")] "x",
+	#[cfg(emojic_group = "objects")] "x_ray",
+	#[cfg(emojic_group = "activities")] "yarn",
+	#[cfg(emojic_group = "smileys_and_emotion")] "yawning_face",
+	#[cfg(emojic_group = "symbols")] "yellow_circle",
+	#[cfg(emojic_group = "smileys_and_emotion")] "yellow_heart",
+	#[cfg(emojic_group = "symbols")] "yellow_square",
+	#[cfg(emojic_group = "flags")] "yemen",
+	#[cfg(emojic_group = "objects")] "yen",
+	#[cfg(emojic_group = "objects")] "yen_banknote",
+	#[cfg(emojic_group = "symbols")] "yin_yang",
+	#[cfg(emojic_group = "activities")] "yo_yo",
+	#[cfg(emojic_group = "smileys_and_emotion")] "yum",
+	#[cfg(emojic_group = "flags")] "zambia",
+	#[cfg(emojic_group = "smileys_and_emotion")] "zany_face",
+	#[cfg(emojic_group = "travel_and_places")] "zap",
+	#[cfg(emojic_group = "animals_and_nature")] "zebra",
+	#[cfg(emojic_group = "symbols")] "zero",
+	#[cfg(emojic_group = "flags")] "zimbabwe",
+	#[cfg(emojic_group = "smileys_and_emotion")] "zipper_mouth_face",
+	#[cfg(emojic_group = "people_and_body")] "zombie",
+	#[cfg(emojic_group = "people_and_body")] "zombie_man",
+	#[cfg(emojic_group = "people_and_body")] "zombie_woman",
+	#[cfg(emojic_group = "smileys_and_emotion")] "zzz",
+];
+
+// The emojis of a range of versions, next to the constants
+#[doc(inline)]
+pub use crate::search::in_version_range;
+
+/// Component emojis, i.e. the bare skin tone and hair style modifiers.
+///
+/// These are the emojis of the [`crate::grouped::component`] group, which are usually applied
+/// via the attributes (such as [`Tone`](crate::Tone)) of the customizable emojis. However, they
+/// are useful to manually append a modifier to some non-standard sequence.
+///
+/// # Examples
+///
+/// ```rust
+/// use emojic::flat::components::LIGHT_SKIN_TONE;
+///
+/// let wave = format!("{}{}", emojic::flat::WAVING_HAND, LIGHT_SKIN_TONE);
+/// assert_eq!(wave, "👋🏻");
+/// ```
+#[cfg(emojic_group = "component")]
+pub mod components {
+	
+	
+	// begin hair_style 🦲🦱🦰
+		
+		// BALD 🦲
+		#[doc(inline)]
+		pub use crate::grouped::component::hair_style::BALD;
+		
+		// CURLY_HAIR 🦱
+		#[doc(inline)]
+		pub use crate::grouped::component::hair_style::CURLY_HAIR;
+		
+		// RED_HAIR 🦰
+		#[doc(inline)]
+		pub use crate::grouped::component::hair_style::RED_HAIR;
+		
+		// WHITE_HAIR 🦳
+		#[doc(inline)]
+		pub use crate::grouped::component::hair_style::WHITE_HAIR;
+		
+	// end hair_style
+	
+	// begin skin_tone 🏿🏻🏾
+		
+		// DARK_SKIN_TONE 🏿
+		#[doc(inline)]
+		pub use crate::grouped::component::skin_tone::DARK_SKIN_TONE;
+		
+		// LIGHT_SKIN_TONE 🏻
+		#[doc(inline)]
+		pub use crate::grouped::component::skin_tone::LIGHT_SKIN_TONE;
+		
+		// MEDIUM_DARK_SKIN_TONE 🏾
+		#[doc(inline)]
+		pub use crate::grouped::component::skin_tone::MEDIUM_DARK_SKIN_TONE;
+		
+		// MEDIUM_LIGHT_SKIN_TONE 🏼
+		#[doc(inline)]
+		pub use crate::grouped::component::skin_tone::MEDIUM_LIGHT_SKIN_TONE;
+		
+		// MEDIUM_SKIN_TONE 🏽
+		#[doc(inline)]
+		pub use crate::grouped::component::skin_tone::MEDIUM_SKIN_TONE;
+		
+	// end skin_tone
+	
+	
+}
+
 // EOF
\ No newline at end of file
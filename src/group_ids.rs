@@ -0,0 +1,592 @@
+// Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
+
+// Source: https://unicode.org/Public/emoji/15.1/emoji-test.txt (up to E14.0, names and groups of E13.1 kept)
+// Created at: 2026-10-16 13:54:22.395587319 UTC
+
+
+/// Identifies an emoji group, e.g. to `match` on the group of an emoji.
+///
+/// Unlike the [`Group`](crate::groups::Group) metadata, all groups are listed, regardless of the
+/// `group-*` features. The group of an emoji is returned by [`category_of`](crate::category_of).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GroupId {
+	/// Activities 🎨🥇🎟️
+	Activities,
+	/// Animals & Nature 🐸🐤🐜
+	AnimalsAndNature,
+	/// Component 🦲🏿
+	Component,
+	/// Flags 🇦🇫🏴🏴󠁧󠁢󠁥󠁮󠁧󠁿
+	Flags,
+	/// Food & Drink 🏺🍼🍱
+	FoodAndDrink,
+	/// Objects 📘🎒🧮
+	Objects,
+	/// People & Body 🫀💑🤛
+	PeopleAndBody,
+	/// Smileys & Emotion 😹💢😘
+	SmileysAndEmotion,
+	/// Symbols 🆎🔙📶
+	Symbols,
+	/// Travel & Places 🛎️🏦🏖️
+	TravelAndPlaces,
+}
+impl GroupId {
+	/// All groups in the order of the [`grouped`](crate::grouped) module
+	pub const ALL: &'static [GroupId] = &[
+		GroupId::Activities,
+		GroupId::AnimalsAndNature,
+		GroupId::Component,
+		GroupId::Flags,
+		GroupId::FoodAndDrink,
+		GroupId::Objects,
+		GroupId::PeopleAndBody,
+		GroupId::SmileysAndEmotion,
+		GroupId::Symbols,
+		GroupId::TravelAndPlaces,
+	];
+
+	/// The Unicode name of this group, e.g. `"Smileys & Emotion"`
+	pub const fn name(self) -> &'static str {
+		match self {
+			GroupId::Activities => "Activities",
+			GroupId::AnimalsAndNature => "Animals & Nature",
+			GroupId::Component => "Component",
+			GroupId::Flags => "Flags",
+			GroupId::FoodAndDrink => "Food & Drink",
+			GroupId::Objects => "Objects",
+			GroupId::PeopleAndBody => "People & Body",
+			GroupId::SmileysAndEmotion => "Smileys & Emotion",
+			GroupId::Symbols => "Symbols",
+			GroupId::TravelAndPlaces => "Travel & Places",
+		}
+	}
+}
+
+/// Identifies an emoji subgroup, e.g. to `match` on the subgroup of an emoji.
+///
+/// Unlike the [`Subgroup`](crate::groups::Subgroup) metadata, all subgroups are listed,
+/// regardless of the `group-*` features. The subgroup of an emoji is returned by
+/// [`category_of`](crate::category_of).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SubgroupId {
+	/// arts & crafts 🎨🖼️🪢
+	ArtsAndCrafts,
+	/// award-medal 🥇🎖️🥈
+	AwardMedal,
+	/// event 🎟️🎈🎏
+	Event,
+	/// game 🎯♟️♣️
+	Game,
+	/// sport 🏈🏸⚾
+	Sport,
+	/// animal-amphibian 🐸
+	AnimalAmphibian,
+	/// animal-bird 🐤🐦🐔
+	AnimalBird,
+	/// animal-bug 🐜🪲🐛
+	AnimalBug,
+	/// animal-mammal 🦡🦇🐻
+	AnimalMammal,
+	/// animal-marine 🐡🪸🐬
+	AnimalMarine,
+	/// animal-reptile 🐊🐉🐲
+	AnimalReptile,
+	/// plant-flower 🌼💐🌸
+	PlantFlower,
+	/// plant-other 🌵🌳🪹
+	PlantOther,
+	/// hair-style 🦲🦱🦰
+	HairStyle,
+	/// skin-tone 🏿🏻🏾
+	SkinTone,
+	/// country-flag 🇦🇫🇦🇽🇦🇱
+	CountryFlag,
+	/// flag 🏴🏁🎌
+	Flag,
+	/// subdivision-flag 🏴󠁧󠁢󠁥󠁮󠁧󠁿🏴󠁧󠁢󠁳󠁣󠁴󠁿🏴󠁧󠁢󠁷󠁬󠁳󠁿
+	SubdivisionFlag,
+	/// dishware 🏺🥢🍴
+	Dishware,
+	/// drink 🍼🍺🧃
+	Drink,
+	/// food-asian 🍱🍚🍛
+	FoodAsian,
+	/// food-fruit 🍌🫐🍒
+	FoodFruit,
+	/// food-marine 🦀🦞🦪
+	FoodMarine,
+	/// food-prepared 🥓🥯🥖
+	FoodPrepared,
+	/// food-sweet 🎂🍬🍫
+	FoodSweet,
+	/// food-vegetable 🥑🫘🫑
+	FoodVegetable,
+	/// book-paper 📘🔖📑
+	BookPaper,
+	/// clothing 🎒🩰👙
+	Clothing,
+	/// computer 🧮🔋💽
+	Computer,
+	/// household 🧺🛁🛏️
+	Household,
+	/// light & video 📷📸🕯️
+	LightAndVideo,
+	/// lock 🔑🔒🔐
+	Lock,
+	/// mail 🗳️📪📫
+	Mail,
+	/// medical 🩹🩼🩸
+	Medical,
+	/// money 💹🪙💳
+	Money,
+	/// music 🎛️🎧🎚️
+	Music,
+	/// musical-instrument 🪗🪕🥁
+	MusicalInstrument,
+	/// office 📊💼📅
+	Office,
+	/// other-object 🚬⚰️⚱️
+	OtherObject,
+	/// phone 📠📱📲
+	Phone,
+	/// science ⚗️🧬🔬
+	Science,
+	/// sound 🔔🔕📢
+	Sound,
+	/// tool 🪓⚖️🪃
+	Tool,
+	/// writing ✒️🖍️🖋️
+	Writing,
+	/// body-parts 🫀🫦🦴
+	BodyParts,
+	/// family 💑👪💏
+	Family,
+	/// hand-fingers-closed 🤛👊✊
+	HandFingersClosed,
+	/// hand-fingers-open 🖐️🫲🫳
+	HandFingersOpen,
+	/// hand-fingers-partial 🤙🤞🫰
+	HandFingersPartial,
+	/// hand-prop 💅🤳✍️
+	HandProp,
+	/// hand-single-finger 👇👈👉
+	HandSingleFinger,
+	/// hands 👏🙏🤝
+	Hands,
+	/// person 👶👦🧒
+	Person,
+	/// person-activity 🧗💇💆
+	PersonActivity,
+	/// person-fantasy 👼🧝🧚
+	PersonFantasy,
+	/// person-gesture 🧏🙇🤦
+	PersonGesture,
+	/// person-resting 🛌🧘🛀
+	PersonResting,
+	/// person-role 🧑‍🎨🧑‍🚀🤱
+	PersonRole,
+	/// person-sport 🏇🚴⛹️
+	PersonSport,
+	/// person-symbol 👥👤👣
+	PersonSymbol,
+	/// cat-face 😹😼😿
+	CatFace,
+	/// emotion 💢💓🖤
+	Emotion,
+	/// face-affection 😘😗😚
+	FaceAffection,
+	/// face-concerned 😧😰😲
+	FaceConcerned,
+	/// face-costume 👽👾🤡
+	FaceCostume,
+	/// face-glasses 🧐🤓😎
+	FaceGlasses,
+	/// face-hand 🤭🫢🫣
+	FaceHand,
+	/// face-hat 🤠🥸🥳
+	FaceHat,
+	/// face-negative 😠👿😤
+	FaceNegative,
+	/// face-neutral-skeptical 🫥😑😮‍💨
+	FaceNeutralSkeptical,
+	/// face-sleepy 🤤😔😌
+	FaceSleepy,
+	/// face-smiling 😁😂😀
+	FaceSmiling,
+	/// face-tongue 😋😛🤑
+	FaceTongue,
+	/// face-unwell 🥶🤯🤮
+	FaceUnwell,
+	/// monkey-face 🙉🙈🙊
+	MonkeyFace,
+	/// alphanum 🆎🅰️🅱️
+	Alphanum,
+	/// arrow 🔙🔃🔄
+	Arrow,
+	/// av-symbol 📶🔆🎦
+	AvSymbol,
+	/// currency 💱💲
+	Currency,
+	/// gender ♀️♂️⚧️
+	Gender,
+	/// geometric ⚫⬛◾
+	Geometric,
+	/// keycap 0️⃣1️⃣🔟
+	Keycap,
+	/// math ➗🟰♾️
+	Math,
+	/// other-symbol ☑️✔️✅
+	OtherSymbol,
+	/// punctuation ‼️⁉️❗
+	Punctuation,
+	/// religion ⚛️🔯✝️
+	Religion,
+	/// transport-sign 🏧🚼🛄
+	TransportSign,
+	/// warning ☣️🚸🚱
+	Warning,
+	/// zodiac ♒♈♋
+	Zodiac,
+	/// hotel 🛎️🧳
+	Hotel,
+	/// place-building 🏦🧱🏗️
+	PlaceBuilding,
+	/// place-geographic 🏖️🏕️🏜️
+	PlaceGeographic,
+	/// place-map 🧭🌎🌏
+	PlaceMap,
+	/// place-other 💈🌉🎠
+	PlaceOther,
+	/// place-religious ⛪🛕🕋
+	PlaceReligious,
+	/// sky & weather 🌂☁️🌩️
+	SkyAndWeather,
+	/// time ⏰🕗🕣
+	Time,
+	/// transport-air 🚡✈️🛬
+	TransportAir,
+	/// transport-ground 🚑🚛🚗
+	TransportGround,
+	/// transport-water ⚓🛶⛴️
+	TransportWater,
+}
+impl SubgroupId {
+	/// All subgroups in the order of the [`grouped`](crate::grouped) module
+	pub const ALL: &'static [SubgroupId] = &[
+		SubgroupId::ArtsAndCrafts,
+		SubgroupId::AwardMedal,
+		SubgroupId::Event,
+		SubgroupId::Game,
+		SubgroupId::Sport,
+		SubgroupId::AnimalAmphibian,
+		SubgroupId::AnimalBird,
+		SubgroupId::AnimalBug,
+		SubgroupId::AnimalMammal,
+		SubgroupId::AnimalMarine,
+		SubgroupId::AnimalReptile,
+		SubgroupId::PlantFlower,
+		SubgroupId::PlantOther,
+		SubgroupId::HairStyle,
+		SubgroupId::SkinTone,
+		SubgroupId::CountryFlag,
+		SubgroupId::Flag,
+		SubgroupId::SubdivisionFlag,
+		SubgroupId::Dishware,
+		SubgroupId::Drink,
+		SubgroupId::FoodAsian,
+		SubgroupId::FoodFruit,
+		SubgroupId::FoodMarine,
+		SubgroupId::FoodPrepared,
+		SubgroupId::FoodSweet,
+		SubgroupId::FoodVegetable,
+		SubgroupId::BookPaper,
+		SubgroupId::Clothing,
+		SubgroupId::Computer,
+		SubgroupId::Household,
+		SubgroupId::LightAndVideo,
+		SubgroupId::Lock,
+		SubgroupId::Mail,
+		SubgroupId::Medical,
+		SubgroupId::Money,
+		SubgroupId::Music,
+		SubgroupId::MusicalInstrument,
+		SubgroupId::Office,
+		SubgroupId::OtherObject,
+		SubgroupId::Phone,
+		SubgroupId::Science,
+		SubgroupId::Sound,
+		SubgroupId::Tool,
+		SubgroupId::Writing,
+		SubgroupId::BodyParts,
+		SubgroupId::Family,
+		SubgroupId::HandFingersClosed,
+		SubgroupId::HandFingersOpen,
+		SubgroupId::HandFingersPartial,
+		SubgroupId::HandProp,
+		SubgroupId::HandSingleFinger,
+		SubgroupId::Hands,
+		SubgroupId::Person,
+		SubgroupId::PersonActivity,
+		SubgroupId::PersonFantasy,
+		SubgroupId::PersonGesture,
+		SubgroupId::PersonResting,
+		SubgroupId::PersonRole,
+		SubgroupId::PersonSport,
+		SubgroupId::PersonSymbol,
+		SubgroupId::CatFace,
+		SubgroupId::Emotion,
+		SubgroupId::FaceAffection,
+		SubgroupId::FaceConcerned,
+		SubgroupId::FaceCostume,
+		SubgroupId::FaceGlasses,
+		SubgroupId::FaceHand,
+		SubgroupId::FaceHat,
+		SubgroupId::FaceNegative,
+		SubgroupId::FaceNeutralSkeptical,
+		SubgroupId::FaceSleepy,
+		SubgroupId::FaceSmiling,
+		SubgroupId::FaceTongue,
+		SubgroupId::FaceUnwell,
+		SubgroupId::MonkeyFace,
+		SubgroupId::Alphanum,
+		SubgroupId::Arrow,
+		SubgroupId::AvSymbol,
+		SubgroupId::Currency,
+		SubgroupId::Gender,
+		SubgroupId::Geometric,
+		SubgroupId::Keycap,
+		SubgroupId::Math,
+		SubgroupId::OtherSymbol,
+		SubgroupId::Punctuation,
+		SubgroupId::Religion,
+		SubgroupId::TransportSign,
+		SubgroupId::Warning,
+		SubgroupId::Zodiac,
+		SubgroupId::Hotel,
+		SubgroupId::PlaceBuilding,
+		SubgroupId::PlaceGeographic,
+		SubgroupId::PlaceMap,
+		SubgroupId::PlaceOther,
+		SubgroupId::PlaceReligious,
+		SubgroupId::SkyAndWeather,
+		SubgroupId::Time,
+		SubgroupId::TransportAir,
+		SubgroupId::TransportGround,
+		SubgroupId::TransportWater,
+	];
+
+	/// The Unicode name of this subgroup, e.g. `"face-smiling"`
+	pub const fn name(self) -> &'static str {
+		match self {
+			SubgroupId::ArtsAndCrafts => "arts & crafts",
+			SubgroupId::AwardMedal => "award-medal",
+			SubgroupId::Event => "event",
+			SubgroupId::Game => "game",
+			SubgroupId::Sport => "sport",
+			SubgroupId::AnimalAmphibian => "animal-amphibian",
+			SubgroupId::AnimalBird => "animal-bird",
+			SubgroupId::AnimalBug => "animal-bug",
+			SubgroupId::AnimalMammal => "animal-mammal",
+			SubgroupId::AnimalMarine => "animal-marine",
+			SubgroupId::AnimalReptile => "animal-reptile",
+			SubgroupId::PlantFlower => "plant-flower",
+			SubgroupId::PlantOther => "plant-other",
+			SubgroupId::HairStyle => "hair-style",
+			SubgroupId::SkinTone => "skin-tone",
+			SubgroupId::CountryFlag => "country-flag",
+			SubgroupId::Flag => "flag",
+			SubgroupId::SubdivisionFlag => "subdivision-flag",
+			SubgroupId::Dishware => "dishware",
+			SubgroupId::Drink => "drink",
+			SubgroupId::FoodAsian => "food-asian",
+			SubgroupId::FoodFruit => "food-fruit",
+			SubgroupId::FoodMarine => "food-marine",
+			SubgroupId::FoodPrepared => "food-prepared",
+			SubgroupId::FoodSweet => "food-sweet",
+			SubgroupId::FoodVegetable => "food-vegetable",
+			SubgroupId::BookPaper => "book-paper",
+			SubgroupId::Clothing => "clothing",
+			SubgroupId::Computer => "computer",
+			SubgroupId::Household => "household",
+			SubgroupId::LightAndVideo => "light & video",
+			SubgroupId::Lock => "lock",
+			SubgroupId::Mail => "mail",
+			SubgroupId::Medical => "medical",
+			SubgroupId::Money => "money",
+			SubgroupId::Music => "music",
+			SubgroupId::MusicalInstrument => "musical-instrument",
+			SubgroupId::Office => "office",
+			SubgroupId::OtherObject => "other-object",
+			SubgroupId::Phone => "phone",
+			SubgroupId::Science => "science",
+			SubgroupId::Sound => "sound",
+			SubgroupId::Tool => "tool",
+			SubgroupId::Writing => "writing",
+			SubgroupId::BodyParts => "body-parts",
+			SubgroupId::Family => "family",
+			SubgroupId::HandFingersClosed => "hand-fingers-closed",
+			SubgroupId::HandFingersOpen => "hand-fingers-open",
+			SubgroupId::HandFingersPartial => "hand-fingers-partial",
+			SubgroupId::HandProp => "hand-prop",
+			SubgroupId::HandSingleFinger => "hand-single-finger",
+			SubgroupId::Hands => "hands",
+			SubgroupId::Person => "person",
+			SubgroupId::PersonActivity => "person-activity",
+			SubgroupId::PersonFantasy => "person-fantasy",
+			SubgroupId::PersonGesture => "person-gesture",
+			SubgroupId::PersonResting => "person-resting",
+			SubgroupId::PersonRole => "person-role",
+			SubgroupId::PersonSport => "person-sport",
+			SubgroupId::PersonSymbol => "person-symbol",
+			SubgroupId::CatFace => "cat-face",
+			SubgroupId::Emotion => "emotion",
+			SubgroupId::FaceAffection => "face-affection",
+			SubgroupId::FaceConcerned => "face-concerned",
+			SubgroupId::FaceCostume => "face-costume",
+			SubgroupId::FaceGlasses => "face-glasses",
+			SubgroupId::FaceHand => "face-hand",
+			SubgroupId::FaceHat => "face-hat",
+			SubgroupId::FaceNegative => "face-negative",
+			SubgroupId::FaceNeutralSkeptical => "face-neutral-skeptical",
+			SubgroupId::FaceSleepy => "face-sleepy",
+			SubgroupId::FaceSmiling => "face-smiling",
+			SubgroupId::FaceTongue => "face-tongue",
+			SubgroupId::FaceUnwell => "face-unwell",
+			SubgroupId::MonkeyFace => "monkey-face",
+			SubgroupId::Alphanum => "alphanum",
+			SubgroupId::Arrow => "arrow",
+			SubgroupId::AvSymbol => "av-symbol",
+			SubgroupId::Currency => "currency",
+			SubgroupId::Gender => "gender",
+			SubgroupId::Geometric => "geometric",
+			SubgroupId::Keycap => "keycap",
+			SubgroupId::Math => "math",
+			SubgroupId::OtherSymbol => "other-symbol",
+			SubgroupId::Punctuation => "punctuation",
+			SubgroupId::Religion => "religion",
+			SubgroupId::TransportSign => "transport-sign",
+			SubgroupId::Warning => "warning",
+			SubgroupId::Zodiac => "zodiac",
+			SubgroupId::Hotel => "hotel",
+			SubgroupId::PlaceBuilding => "place-building",
+			SubgroupId::PlaceGeographic => "place-geographic",
+			SubgroupId::PlaceMap => "place-map",
+			SubgroupId::PlaceOther => "place-other",
+			SubgroupId::PlaceReligious => "place-religious",
+			SubgroupId::SkyAndWeather => "sky & weather",
+			SubgroupId::Time => "time",
+			SubgroupId::TransportAir => "transport-air",
+			SubgroupId::TransportGround => "transport-ground",
+			SubgroupId::TransportWater => "transport-water",
+		}
+	}
+
+	/// The group containing this subgroup
+	pub const fn group(self) -> GroupId {
+		match self {
+			SubgroupId::ArtsAndCrafts => GroupId::Activities,
+			SubgroupId::AwardMedal => GroupId::Activities,
+			SubgroupId::Event => GroupId::Activities,
+			SubgroupId::Game => GroupId::Activities,
+			SubgroupId::Sport => GroupId::Activities,
+			SubgroupId::AnimalAmphibian => GroupId::AnimalsAndNature,
+			SubgroupId::AnimalBird => GroupId::AnimalsAndNature,
+			SubgroupId::AnimalBug => GroupId::AnimalsAndNature,
+			SubgroupId::AnimalMammal => GroupId::AnimalsAndNature,
+			SubgroupId::AnimalMarine => GroupId::AnimalsAndNature,
+			SubgroupId::AnimalReptile => GroupId::AnimalsAndNature,
+			SubgroupId::PlantFlower => GroupId::AnimalsAndNature,
+			SubgroupId::PlantOther => GroupId::AnimalsAndNature,
+			SubgroupId::HairStyle => GroupId::Component,
+			SubgroupId::SkinTone => GroupId::Component,
+			SubgroupId::CountryFlag => GroupId::Flags,
+			SubgroupId::Flag => GroupId::Flags,
+			SubgroupId::SubdivisionFlag => GroupId::Flags,
+			SubgroupId::Dishware => GroupId::FoodAndDrink,
+			SubgroupId::Drink => GroupId::FoodAndDrink,
+			SubgroupId::FoodAsian => GroupId::FoodAndDrink,
+			SubgroupId::FoodFruit => GroupId::FoodAndDrink,
+			SubgroupId::FoodMarine => GroupId::FoodAndDrink,
+			SubgroupId::FoodPrepared => GroupId::FoodAndDrink,
+			SubgroupId::FoodSweet => GroupId::FoodAndDrink,
+			SubgroupId::FoodVegetable => GroupId::FoodAndDrink,
+			SubgroupId::BookPaper => GroupId::Objects,
+			SubgroupId::Clothing => GroupId::Objects,
+			SubgroupId::Computer => GroupId::Objects,
+			SubgroupId::Household => GroupId::Objects,
+			SubgroupId::LightAndVideo => GroupId::Objects,
+			SubgroupId::Lock => GroupId::Objects,
+			SubgroupId::Mail => GroupId::Objects,
+			SubgroupId::Medical => GroupId::Objects,
+			SubgroupId::Money => GroupId::Objects,
+			SubgroupId::Music => GroupId::Objects,
+			SubgroupId::MusicalInstrument => GroupId::Objects,
+			SubgroupId::Office => GroupId::Objects,
+			SubgroupId::OtherObject => GroupId::Objects,
+			SubgroupId::Phone => GroupId::Objects,
+			SubgroupId::Science => GroupId::Objects,
+			SubgroupId::Sound => GroupId::Objects,
+			SubgroupId::Tool => GroupId::Objects,
+			SubgroupId::Writing => GroupId::Objects,
+			SubgroupId::BodyParts => GroupId::PeopleAndBody,
+			SubgroupId::Family => GroupId::PeopleAndBody,
+			SubgroupId::HandFingersClosed => GroupId::PeopleAndBody,
+			SubgroupId::HandFingersOpen => GroupId::PeopleAndBody,
+			SubgroupId::HandFingersPartial => GroupId::PeopleAndBody,
+			SubgroupId::HandProp => GroupId::PeopleAndBody,
+			SubgroupId::HandSingleFinger => GroupId::PeopleAndBody,
+			SubgroupId::Hands => GroupId::PeopleAndBody,
+			SubgroupId::Person => GroupId::PeopleAndBody,
+			SubgroupId::PersonActivity => GroupId::PeopleAndBody,
+			SubgroupId::PersonFantasy => GroupId::PeopleAndBody,
+			SubgroupId::PersonGesture => GroupId::PeopleAndBody,
+			SubgroupId::PersonResting => GroupId::PeopleAndBody,
+			SubgroupId::PersonRole => GroupId::PeopleAndBody,
+			SubgroupId::PersonSport => GroupId::PeopleAndBody,
+			SubgroupId::PersonSymbol => GroupId::PeopleAndBody,
+			SubgroupId::CatFace => GroupId::SmileysAndEmotion,
+			SubgroupId::Emotion => GroupId::SmileysAndEmotion,
+			SubgroupId::FaceAffection => GroupId::SmileysAndEmotion,
+			SubgroupId::FaceConcerned => GroupId::SmileysAndEmotion,
+			SubgroupId::FaceCostume => GroupId::SmileysAndEmotion,
+			SubgroupId::FaceGlasses => GroupId::SmileysAndEmotion,
+			SubgroupId::FaceHand => GroupId::SmileysAndEmotion,
+			SubgroupId::FaceHat => GroupId::SmileysAndEmotion,
+			SubgroupId::FaceNegative => GroupId::SmileysAndEmotion,
+			SubgroupId::FaceNeutralSkeptical => GroupId::SmileysAndEmotion,
+			SubgroupId::FaceSleepy => GroupId::SmileysAndEmotion,
+			SubgroupId::FaceSmiling => GroupId::SmileysAndEmotion,
+			SubgroupId::FaceTongue => GroupId::SmileysAndEmotion,
+			SubgroupId::FaceUnwell => GroupId::SmileysAndEmotion,
+			SubgroupId::MonkeyFace => GroupId::SmileysAndEmotion,
+			SubgroupId::Alphanum => GroupId::Symbols,
+			SubgroupId::Arrow => GroupId::Symbols,
+			SubgroupId::AvSymbol => GroupId::Symbols,
+			SubgroupId::Currency => GroupId::Symbols,
+			SubgroupId::Gender => GroupId::Symbols,
+			SubgroupId::Geometric => GroupId::Symbols,
+			SubgroupId::Keycap => GroupId::Symbols,
+			SubgroupId::Math => GroupId::Symbols,
+			SubgroupId::OtherSymbol => GroupId::Symbols,
+			SubgroupId::Punctuation => GroupId::Symbols,
+			SubgroupId::Religion => GroupId::Symbols,
+			SubgroupId::TransportSign => GroupId::Symbols,
+			SubgroupId::Warning => GroupId::Symbols,
+			SubgroupId::Zodiac => GroupId::Symbols,
+			SubgroupId::Hotel => GroupId::TravelAndPlaces,
+			SubgroupId::PlaceBuilding => GroupId::TravelAndPlaces,
+			SubgroupId::PlaceGeographic => GroupId::TravelAndPlaces,
+			SubgroupId::PlaceMap => GroupId::TravelAndPlaces,
+			SubgroupId::PlaceOther => GroupId::TravelAndPlaces,
+			SubgroupId::PlaceReligious => GroupId::TravelAndPlaces,
+			SubgroupId::SkyAndWeather => GroupId::TravelAndPlaces,
+			SubgroupId::Time => GroupId::TravelAndPlaces,
+			SubgroupId::TransportAir => GroupId::TravelAndPlaces,
+			SubgroupId::TransportGround => GroupId::TravelAndPlaces,
+			SubgroupId::TransportWater => GroupId::TravelAndPlaces,
+		}
+	}
+}
+
+// EOF
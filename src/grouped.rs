@@ -1,7 +1,7 @@
 // Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
 
-// Source: https://unicode.org/Public/emoji/13.1/emoji-test.txt
-// Created at: 2022-10-24 05:19:18.898747236 UTC
+// Source: https://unicode.org/Public/emoji/15.1/emoji-test.txt (up to E14.0, names and groups of E13.1 kept)
+// Created at: 2026-10-16 13:54:22.365699748 UTC
 
 #![allow(unused_imports)]
 
@@ -19,12 +19,18 @@
 //! ```
 
 use crate::emojis::Emoji;
+use crate::groups::Group;
 
 
 
 /// activities 🎨🥇🎟️
+#[cfg(emojic_group = "activities")]
 pub mod activities {
 	use crate::emojis::Emoji;
+	use crate::groups::Subgroup;
+
+	/// The Unicode name of this group
+	pub const NAME: &str = "Activities";
 
 	
 	/// arts_and_crafts 🎨🖼️🪢
@@ -43,6 +49,9 @@ pub mod activities {
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "arts & crafts";
+
 		// List of all emoji statics
 
 		
@@ -93,7 +102,7 @@ pub static YARN :  Emoji = Emoji::new("yarn", Version(11, 0), "🧶"); // yarn
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ARTIST_PALETTE
 				
@@ -140,7 +149,7 @@ pub static YARN :  Emoji = Emoji::new("yarn", Version(11, 0), "🧶"); // yarn
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ARTIST_PALETTE, // ARTIST_PALETTE
@@ -180,11 +189,21 @@ pub static YARN :  Emoji = Emoji::new("yarn", Version(11, 0), "🧶"); // yarn
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // arts_and_crafts
 	
 	/// award_medal 🥇🎖️🥈
@@ -203,6 +222,9 @@ pub static YARN :  Emoji = Emoji::new("yarn", Version(11, 0), "🧶"); // yarn
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "award-medal";
+
 		// List of all emoji statics
 
 		
@@ -247,7 +269,7 @@ pub static TROPHY :  Emoji = Emoji::new("trophy", Version(0, 6), "🏆"); // tro
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // FIRST_PLACE_MEDAL
 				
@@ -288,7 +310,7 @@ pub static TROPHY :  Emoji = Emoji::new("trophy", Version(0, 6), "🏆"); // tro
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&FIRST_PLACE_MEDAL, // FIRST_PLACE_MEDAL
@@ -324,11 +346,21 @@ pub static TROPHY :  Emoji = Emoji::new("trophy", Version(0, 6), "🏆"); // tro
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // award_medal
 	
 	/// event 🎟️🎈🎏
@@ -347,6 +379,9 @@ pub static TROPHY :  Emoji = Emoji::new("trophy", Version(0, 6), "🏆"); // tro
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "event";
+
 		// List of all emoji statics
 
 		
@@ -481,7 +516,7 @@ pub static WRAPPED_GIFT :  Emoji = Emoji::new("wrapped gift", Version(0, 6), "
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ADMISSION_TICKETS
 				
@@ -612,7 +647,7 @@ pub static WRAPPED_GIFT :  Emoji = Emoji::new("wrapped gift", Version(0, 6), "
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ADMISSION_TICKETS, // ADMISSION_TICKETS
@@ -708,11 +743,21 @@ pub static WRAPPED_GIFT :  Emoji = Emoji::new("wrapped gift", Version(0, 6), "
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // event
 	
 	/// game 🎯♟️♣️
@@ -731,6 +776,9 @@ pub static WRAPPED_GIFT :  Emoji = Emoji::new("wrapped gift", Version(0, 6), "
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "game";
+
 		// List of all emoji statics
 
 		
@@ -812,6 +860,12 @@ pub static MAGIC_WAND :  Emoji = Emoji::new("magic wand", Version(13, 0), "🪄"
 #[doc="```"]
 pub static MAHJONG_RED_DRAGON :  Emoji = Emoji::new("mahjong red dragon", Version(0, 6), "🀄"); // mahjong red dragon
 		
+		#[doc="mirror ball 🪩"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::MIRROR_BALL;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", MIRROR_BALL); // 🪩 (`U+1FAA9`)"] #[doc="# assert_eq!(MIRROR_BALL.to_string().as_str(), \"🪩\");"]
+#[doc="```"]
+pub static MIRROR_BALL :  Emoji = Emoji::new("mirror ball", Version(14, 0), "🪩"); // mirror ball
+		
 		#[doc="nazar amulet 🧿"]#[doc=""]#[doc="Since E11.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::NAZAR_AMULET;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", NAZAR_AMULET); // 🧿 (`U+1F9FF`)"] #[doc="# assert_eq!(NAZAR_AMULET.to_string().as_str(), \"🧿\");"]
@@ -877,7 +931,7 @@ pub static YO_YO :  Emoji = Emoji::new("yo-yo", Version(12, 0), "🪀"); // yo-y
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BULLSEYE
 				
@@ -957,6 +1011,12 @@ pub static YO_YO :  Emoji = Emoji::new("yo-yo", Version(12, 0), "🪀"); // yo-y
 				
 			],
 			
+			&[ // MIRROR_BALL
+				
+				&MIRROR_BALL,
+				
+			],
+			
 			&[ // NAZAR_AMULET
 				
 				&NAZAR_AMULET,
@@ -1020,7 +1080,7 @@ pub static YO_YO :  Emoji = Emoji::new("yo-yo", Version(12, 0), "🪀"); // yo-y
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BULLSEYE, // BULLSEYE
@@ -1075,6 +1135,10 @@ pub static YO_YO :  Emoji = Emoji::new("yo-yo", Version(12, 0), "🪀"); // yo-y
 				
 			
 				
+				&MIRROR_BALL, // MIRROR_BALL
+				
+			
+				
 				&NAZAR_AMULET, // NAZAR_AMULET
 				
 			
@@ -1124,11 +1188,21 @@ pub static YO_YO :  Emoji = Emoji::new("yo-yo", Version(12, 0), "🪀"); // yo-y
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // game
 	
 	/// sport 🏈🏸⚾
@@ -1147,6 +1221,9 @@ pub static YO_YO :  Emoji = Emoji::new("yo-yo", Version(12, 0), "🪀"); // yo-y
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "sport";
+
 		// List of all emoji statics
 
 		
@@ -1317,7 +1394,7 @@ pub static VOLLEYBALL :  Emoji = Emoji::new("volleyball", Version(1, 0), "🏐")
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // AMERICAN_FOOTBALL
 				
@@ -1484,7 +1561,7 @@ pub static VOLLEYBALL :  Emoji = Emoji::new("volleyball", Version(1, 0), "🏐")
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&AMERICAN_FOOTBALL, // AMERICAN_FOOTBALL
@@ -1604,15 +1681,43 @@ pub static VOLLEYBALL :  Emoji = Emoji::new("volleyball", Version(1, 0), "🏐")
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // sport
 	
 
 
+	// Subgroup metadata
+
+	/// Contains the metadata of each subgroup of this group.
+	pub(crate) static SUBGROUPS: &[Subgroup] = &[
+		
+		Subgroup::new(arts_and_crafts::NAME, arts_and_crafts::ALL_VARIANTS, arts_and_crafts::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(award_medal::NAME, award_medal::ALL_VARIANTS, award_medal::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(event::NAME, event::ALL_VARIANTS, event::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(game::NAME, game::ALL_VARIANTS, game::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(sport::NAME, sport::ALL_VARIANTS, sport::ALL_BASE_EMOJIS),
+		
+	];
+
+
 	// Emoji iterators
 
 	/// Returns an iterator over all emoji variants of these subgroups grouped by base emojis
@@ -1631,6 +1736,11 @@ pub static VOLLEYBALL :  Emoji = Emoji::new("volleyball", Version(1, 0), "🏐")
 		
 	}
 
+	/// Returns an iterator over all emoji variants of these subgroups one by one
+	pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+		all_variants().flat_map(|variants| variants.iter().copied())
+	}
+
 	/// Returns an iterator over all base emojis of these subgroups (i.e. one for each static)
 	pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 		core::iter::empty()
@@ -1650,8 +1760,13 @@ pub static VOLLEYBALL :  Emoji = Emoji::new("volleyball", Version(1, 0), "🏐")
 } // activities
 
 /// animals_and_nature 🐸🐤🐜
+#[cfg(emojic_group = "animals_and_nature")]
 pub mod animals_and_nature {
 	use crate::emojis::Emoji;
+	use crate::groups::Subgroup;
+
+	/// The Unicode name of this group
+	pub const NAME: &str = "Animals & Nature";
 
 	
 	/// animal_amphibian 🐸
@@ -1670,6 +1785,9 @@ pub mod animals_and_nature {
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "animal-amphibian";
+
 		// List of all emoji statics
 
 		
@@ -1684,7 +1802,7 @@ pub static FROG :  Emoji = Emoji::new("frog", Version(0, 6), "🐸"); // frog
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // FROG
 				
@@ -1695,7 +1813,7 @@ pub static FROG :  Emoji = Emoji::new("frog", Version(0, 6), "🐸"); // frog
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&FROG, // FROG
@@ -1711,11 +1829,21 @@ pub static FROG :  Emoji = Emoji::new("frog", Version(0, 6), "🐸"); // frog
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // animal_amphibian
 	
 	/// animal_bird 🐤🐦🐔
@@ -1734,6 +1862,9 @@ pub static FROG :  Emoji = Emoji::new("frog", Version(0, 6), "🐸"); // frog
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "animal-bird";
+
 		// List of all emoji statics
 
 		
@@ -1850,7 +1981,7 @@ pub static TURKEY :  Emoji = Emoji::new("turkey", Version(1, 0), "🦃"); // tur
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BABY_CHICK
 				
@@ -1963,7 +2094,7 @@ pub static TURKEY :  Emoji = Emoji::new("turkey", Version(1, 0), "🦃"); // tur
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BABY_CHICK, // BABY_CHICK
@@ -2047,11 +2178,21 @@ pub static TURKEY :  Emoji = Emoji::new("turkey", Version(1, 0), "🦃"); // tur
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // animal_bird
 	
 	/// animal_bug 🐜🪲🐛
@@ -2070,6 +2211,9 @@ pub static TURKEY :  Emoji = Emoji::new("turkey", Version(1, 0), "🦃"); // tur
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "animal-bug";
+
 		// List of all emoji statics
 
 		
@@ -2174,7 +2318,7 @@ pub static WORM :  Emoji = Emoji::new("worm", Version(13, 0), "🪱"); // worm
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ANT
 				
@@ -2275,7 +2419,7 @@ pub static WORM :  Emoji = Emoji::new("worm", Version(13, 0), "🪱"); // worm
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ANT, // ANT
@@ -2351,11 +2495,21 @@ pub static WORM :  Emoji = Emoji::new("worm", Version(13, 0), "🪱"); // worm
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // animal_bug
 	
 	/// animal_mammal 🦡🦇🐻
@@ -2374,6 +2528,9 @@ pub static WORM :  Emoji = Emoji::new("worm", Version(13, 0), "🪱"); // worm
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "animal-mammal";
+
 		// List of all emoji statics
 
 		
@@ -2766,7 +2923,7 @@ pub static ZEBRA :  Emoji = Emoji::new("zebra", Version(5, 0), "🦓"); // zebra
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BADGER
 				
@@ -3155,7 +3312,7 @@ pub static ZEBRA :  Emoji = Emoji::new("zebra", Version(5, 0), "🦓"); // zebra
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BADGER, // BADGER
@@ -3423,14 +3580,24 @@ pub static ZEBRA :  Emoji = Emoji::new("zebra", Version(5, 0), "🦓"); // zebra
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // animal_mammal
 	
-	/// animal_marine 🐡🐬🐟
+	/// animal_marine 🐡🪸🐬
 	pub mod animal_marine {
 
 		use crate::emojis::Emoji;
@@ -3446,6 +3613,9 @@ pub static ZEBRA :  Emoji = Emoji::new("zebra", Version(5, 0), "🦓"); // zebra
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "animal-marine";
+
 		// List of all emoji statics
 
 		
@@ -3455,6 +3625,12 @@ pub static ZEBRA :  Emoji = Emoji::new("zebra", Version(5, 0), "🦓"); // zebra
 #[doc="```"]
 pub static BLOWFISH :  Emoji = Emoji::new("blowfish", Version(0, 6), "🐡"); // blowfish
 		
+		#[doc="coral 🪸"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::CORAL;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", CORAL); // 🪸 (`U+1FAB8`)"] #[doc="# assert_eq!(CORAL.to_string().as_str(), \"🪸\");"]
+#[doc="```"]
+pub static CORAL :  Emoji = Emoji::new("coral", Version(14, 0), "🪸"); // coral
+		
 		#[doc="dolphin 🐬"]#[doc=""]#[doc="Since E0.6"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::DOLPHIN;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", DOLPHIN); // 🐬 (`U+1F42C`)"] #[doc="# assert_eq!(DOLPHIN.to_string().as_str(), \"🐬\");"]
@@ -3514,7 +3690,7 @@ pub static WHALE :  Emoji = Emoji::new("whale", Version(1, 0), "🐋"); // whale
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BLOWFISH
 				
@@ -3522,6 +3698,12 @@ pub static WHALE :  Emoji = Emoji::new("whale", Version(1, 0), "🐋"); // whale
 				
 			],
 			
+			&[ // CORAL
+				
+				&CORAL,
+				
+			],
+			
 			&[ // DOLPHIN
 				
 				&DOLPHIN,
@@ -3579,13 +3761,17 @@ pub static WHALE :  Emoji = Emoji::new("whale", Version(1, 0), "🐋"); // whale
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BLOWFISH, // BLOWFISH
 				
 			
 				
+				&CORAL, // CORAL
+				
+			
+				
 				&DOLPHIN, // DOLPHIN
 				
 			
@@ -3631,11 +3817,21 @@ pub static WHALE :  Emoji = Emoji::new("whale", Version(1, 0), "🐋"); // whale
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // animal_marine
 	
 	/// animal_reptile 🐊🐉🐲
@@ -3654,6 +3850,9 @@ pub static WHALE :  Emoji = Emoji::new("whale", Version(1, 0), "🐋"); // whale
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "animal-reptile";
+
 		// List of all emoji statics
 
 		
@@ -3710,7 +3909,7 @@ pub static T_REX :  Emoji = Emoji::new("T-Rex", Version(5, 0), "🦖"); // T-Rex
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // CROCODILE
 				
@@ -3763,7 +3962,7 @@ pub static T_REX :  Emoji = Emoji::new("T-Rex", Version(5, 0), "🦖"); // T-Rex
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&CROCODILE, // CROCODILE
@@ -3807,11 +4006,21 @@ pub static T_REX :  Emoji = Emoji::new("T-Rex", Version(5, 0), "🦖"); // T-Rex
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // animal_reptile
 	
 	/// plant_flower 🌼💐🌸
@@ -3830,6 +4039,9 @@ pub static T_REX :  Emoji = Emoji::new("T-Rex", Version(5, 0), "🦖"); // T-Rex
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "plant-flower";
+
 		// List of all emoji statics
 
 		
@@ -3857,6 +4069,12 @@ pub static CHERRY_BLOSSOM :  Emoji = Emoji::new("cherry blossom", Version(0, 6),
 #[doc="```"]
 pub static HIBISCUS :  Emoji = Emoji::new("hibiscus", Version(0, 6), "🌺"); // hibiscus
 		
+		#[doc="lotus 🪷"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::LOTUS;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", LOTUS); // 🪷 (`U+1FAB7`)"] #[doc="# assert_eq!(LOTUS.to_string().as_str(), \"🪷\");"]
+#[doc="```"]
+pub static LOTUS :  Emoji = Emoji::new("lotus", Version(14, 0), "🪷"); // lotus
+		
 		#[doc="rose 🌹"]#[doc=""]#[doc="Since E0.6"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::ROSE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", ROSE); // 🌹 (`U+1F339`)"] #[doc="# assert_eq!(ROSE.to_string().as_str(), \"🌹\");"]
@@ -3898,7 +4116,7 @@ pub static WILTED_FLOWER :  Emoji = Emoji::new("wilted flower", Version(3, 0), "
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BLOSSOM
 				
@@ -3924,6 +4142,12 @@ pub static WILTED_FLOWER :  Emoji = Emoji::new("wilted flower", Version(3, 0), "
 				
 			],
 			
+			&[ // LOTUS
+				
+				&LOTUS,
+				
+			],
+			
 			&[ // ROSE
 				
 				&ROSE,
@@ -3963,7 +4187,7 @@ pub static WILTED_FLOWER :  Emoji = Emoji::new("wilted flower", Version(3, 0), "
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BLOSSOM, // BLOSSOM
@@ -3982,6 +4206,10 @@ pub static WILTED_FLOWER :  Emoji = Emoji::new("wilted flower", Version(3, 0), "
 				
 			
 				
+				&LOTUS, // LOTUS
+				
+			
+				
 				&ROSE, // ROSE
 				
 			
@@ -4015,14 +4243,24 @@ pub static WILTED_FLOWER :  Emoji = Emoji::new("wilted flower", Version(3, 0), "
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // plant_flower
 	
-	/// plant_other 🌵🌳🌲
+	/// plant_other 🌵🌳🪹
 	pub mod plant_other {
 
 		use crate::emojis::Emoji;
@@ -4038,6 +4276,9 @@ pub static WILTED_FLOWER :  Emoji = Emoji::new("wilted flower", Version(3, 0), "
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "plant-other";
+
 		// List of all emoji statics
 
 		
@@ -4053,6 +4294,12 @@ pub static CACTUS :  Emoji = Emoji::new("cactus", Version(0, 6), "🌵"); // cac
 #[doc="```"]
 pub static DECIDUOUS_TREE :  Emoji = Emoji::new("deciduous tree", Version(1, 0), "🌳"); // deciduous tree
 		
+		#[doc="empty nest 🪹"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::EMPTY_NEST;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", EMPTY_NEST); // 🪹 (`U+1FAB9`)"] #[doc="# assert_eq!(EMPTY_NEST.to_string().as_str(), \"🪹\");"]
+#[doc="```"]
+pub static EMPTY_NEST :  Emoji = Emoji::new("empty nest", Version(14, 0), "🪹"); // empty nest
+		
 		#[doc="evergreen tree 🌲"]#[doc=""]#[doc="Since E1.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::EVERGREEN_TREE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", EVERGREEN_TREE); // 🌲 (`U+1F332`)"] #[doc="# assert_eq!(EVERGREEN_TREE.to_string().as_str(), \"🌲\");"]
@@ -4089,6 +4336,12 @@ pub static LEAF_FLUTTERING_IN_WIND :  Emoji = Emoji::new("leaf fluttering in win
 #[doc="```"]
 pub static MAPLE_LEAF :  Emoji = Emoji::new("maple leaf", Version(0, 6), "🍁"); // maple leaf
 		
+		#[doc="nest with eggs 🪺"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::NEST_WITH_EGGS;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", NEST_WITH_EGGS); // 🪺 (`U+1FABA`)"] #[doc="# assert_eq!(NEST_WITH_EGGS.to_string().as_str(), \"🪺\");"]
+#[doc="```"]
+pub static NEST_WITH_EGGS :  Emoji = Emoji::new("nest with eggs", Version(14, 0), "🪺"); // nest with eggs
+		
 		#[doc="palm tree 🌴"]#[doc=""]#[doc="Since E0.6"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::PALM_TREE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", PALM_TREE); // 🌴 (`U+1F334`)"] #[doc="# assert_eq!(PALM_TREE.to_string().as_str(), \"🌴\");"]
@@ -4124,7 +4377,7 @@ pub static SHEAF_OF_RICE :  Emoji = Emoji::new("sheaf of rice", Version(0, 6), "
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // CACTUS
 				
@@ -4138,6 +4391,12 @@ pub static SHEAF_OF_RICE :  Emoji = Emoji::new("sheaf of rice", Version(0, 6), "
 				
 			],
 			
+			&[ // EMPTY_NEST
+				
+				&EMPTY_NEST,
+				
+			],
+			
 			&[ // EVERGREEN_TREE
 				
 				&EVERGREEN_TREE,
@@ -4174,6 +4433,12 @@ pub static SHEAF_OF_RICE :  Emoji = Emoji::new("sheaf of rice", Version(0, 6), "
 				
 			],
 			
+			&[ // NEST_WITH_EGGS
+				
+				&NEST_WITH_EGGS,
+				
+			],
+			
 			&[ // PALM_TREE
 				
 				&PALM_TREE,
@@ -4207,7 +4472,7 @@ pub static SHEAF_OF_RICE :  Emoji = Emoji::new("sheaf of rice", Version(0, 6), "
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&CACTUS, // CACTUS
@@ -4218,6 +4483,10 @@ pub static SHEAF_OF_RICE :  Emoji = Emoji::new("sheaf of rice", Version(0, 6), "
 				
 			
 				
+				&EMPTY_NEST, // EMPTY_NEST
+				
+			
+				
 				&EVERGREEN_TREE, // EVERGREEN_TREE
 				
 			
@@ -4242,6 +4511,10 @@ pub static SHEAF_OF_RICE :  Emoji = Emoji::new("sheaf of rice", Version(0, 6), "
 				
 			
 				
+				&NEST_WITH_EGGS, // NEST_WITH_EGGS
+				
+			
+				
 				&PALM_TREE, // PALM_TREE
 				
 			
@@ -4271,15 +4544,49 @@ pub static SHEAF_OF_RICE :  Emoji = Emoji::new("sheaf of rice", Version(0, 6), "
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // plant_other
 	
 
 
+	// Subgroup metadata
+
+	/// Contains the metadata of each subgroup of this group.
+	pub(crate) static SUBGROUPS: &[Subgroup] = &[
+		
+		Subgroup::new(animal_amphibian::NAME, animal_amphibian::ALL_VARIANTS, animal_amphibian::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(animal_bird::NAME, animal_bird::ALL_VARIANTS, animal_bird::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(animal_bug::NAME, animal_bug::ALL_VARIANTS, animal_bug::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(animal_mammal::NAME, animal_mammal::ALL_VARIANTS, animal_mammal::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(animal_marine::NAME, animal_marine::ALL_VARIANTS, animal_marine::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(animal_reptile::NAME, animal_reptile::ALL_VARIANTS, animal_reptile::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(plant_flower::NAME, plant_flower::ALL_VARIANTS, plant_flower::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(plant_other::NAME, plant_other::ALL_VARIANTS, plant_other::ALL_BASE_EMOJIS),
+		
+	];
+
+
 	// Emoji iterators
 
 	/// Returns an iterator over all emoji variants of these subgroups grouped by base emojis
@@ -4304,6 +4611,11 @@ pub static SHEAF_OF_RICE :  Emoji = Emoji::new("sheaf of rice", Version(0, 6), "
 		
 	}
 
+	/// Returns an iterator over all emoji variants of these subgroups one by one
+	pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+		all_variants().flat_map(|variants| variants.iter().copied())
+	}
+
 	/// Returns an iterator over all base emojis of these subgroups (i.e. one for each static)
 	pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 		core::iter::empty()
@@ -4329,8 +4641,13 @@ pub static SHEAF_OF_RICE :  Emoji = Emoji::new("sheaf of rice", Version(0, 6), "
 } // animals_and_nature
 
 /// component 🦲🏿
+#[cfg(emojic_group = "component")]
 pub mod component {
 	use crate::emojis::Emoji;
+	use crate::groups::Subgroup;
+
+	/// The Unicode name of this group
+	pub const NAME: &str = "Component";
 
 	
 	/// hair_style 🦲🦱🦰
@@ -4349,6 +4666,9 @@ pub mod component {
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "hair-style";
+
 		// List of all emoji statics
 
 		
@@ -4381,7 +4701,7 @@ pub static WHITE_HAIR :  Emoji = Emoji::new("white hair", Version(11, 0), "🦳"
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BALD
 				
@@ -4410,7 +4730,7 @@ pub static WHITE_HAIR :  Emoji = Emoji::new("white hair", Version(11, 0), "🦳"
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BALD, // BALD
@@ -4438,11 +4758,21 @@ pub static WHITE_HAIR :  Emoji = Emoji::new("white hair", Version(11, 0), "🦳"
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // hair_style
 	
 	/// skin_tone 🏿🏻🏾
@@ -4461,6 +4791,9 @@ pub static WHITE_HAIR :  Emoji = Emoji::new("white hair", Version(11, 0), "🦳"
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "skin-tone";
+
 		// List of all emoji statics
 
 		
@@ -4499,7 +4832,7 @@ pub static MEDIUM_SKIN_TONE :  Emoji = Emoji::new("medium skin tone", Version(1,
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // DARK_SKIN_TONE
 				
@@ -4534,7 +4867,7 @@ pub static MEDIUM_SKIN_TONE :  Emoji = Emoji::new("medium skin tone", Version(1,
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&DARK_SKIN_TONE, // DARK_SKIN_TONE
@@ -4566,15 +4899,37 @@ pub static MEDIUM_SKIN_TONE :  Emoji = Emoji::new("medium skin tone", Version(1,
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // skin_tone
 	
 
 
+	// Subgroup metadata
+
+	/// Contains the metadata of each subgroup of this group.
+	pub(crate) static SUBGROUPS: &[Subgroup] = &[
+		
+		Subgroup::new(hair_style::NAME, hair_style::ALL_VARIANTS, hair_style::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(skin_tone::NAME, skin_tone::ALL_VARIANTS, skin_tone::ALL_BASE_EMOJIS),
+		
+	];
+
+
 	// Emoji iterators
 
 	/// Returns an iterator over all emoji variants of these subgroups grouped by base emojis
@@ -4587,6 +4942,11 @@ pub static MEDIUM_SKIN_TONE :  Emoji = Emoji::new("medium skin tone", Version(1,
 		
 	}
 
+	/// Returns an iterator over all emoji variants of these subgroups one by one
+	pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+		all_variants().flat_map(|variants| variants.iter().copied())
+	}
+
 	/// Returns an iterator over all base emojis of these subgroups (i.e. one for each static)
 	pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 		core::iter::empty()
@@ -4600,8 +4960,13 @@ pub static MEDIUM_SKIN_TONE :  Emoji = Emoji::new("medium skin tone", Version(1,
 } // component
 
 /// flags 🇦🇫🏴🏴󠁧󠁢󠁥󠁮󠁧󠁿
+#[cfg(emojic_group = "flags")]
 pub mod flags {
 	use crate::emojis::Emoji;
+	use crate::groups::Subgroup;
+
+	/// The Unicode name of this group
+	pub const NAME: &str = "Flags";
 
 	
 	/// country_flag 🇦🇫🇦🇽🇦🇱
@@ -4620,6 +4985,9 @@ pub mod flags {
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "country-flag";
+
 		// List of all emoji statics
 
 		
@@ -6176,7 +6544,7 @@ pub static FLAG_ZIMBABWE :  Emoji = Emoji::new("flag: Zimbabwe", Version(2, 0),
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // FLAG_AFGHANISTAN
 				
@@ -7729,7 +8097,7 @@ pub static FLAG_ZIMBABWE :  Emoji = Emoji::new("flag: Zimbabwe", Version(2, 0),
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&FLAG_AFGHANISTAN, // FLAG_AFGHANISTAN
@@ -8773,11 +9141,21 @@ pub static FLAG_ZIMBABWE :  Emoji = Emoji::new("flag: Zimbabwe", Version(2, 0),
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // country_flag
 	
 	/// flag 🏴🏁🎌
@@ -8796,6 +9174,9 @@ pub static FLAG_ZIMBABWE :  Emoji = Emoji::new("flag: Zimbabwe", Version(2, 0),
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "flag";
+
 		// List of all emoji statics
 
 		
@@ -8852,7 +9233,7 @@ pub static WHITE_FLAG :  Emoji = Emoji::new("white flag", Version(0, 7), "🏳
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BLACK_FLAG
 				
@@ -8905,7 +9286,7 @@ pub static WHITE_FLAG :  Emoji = Emoji::new("white flag", Version(0, 7), "🏳
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BLACK_FLAG, // BLACK_FLAG
@@ -8949,11 +9330,21 @@ pub static WHITE_FLAG :  Emoji = Emoji::new("white flag", Version(0, 7), "🏳
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // flag
 	
 	/// subdivision_flag 🏴󠁧󠁢󠁥󠁮󠁧󠁿🏴󠁧󠁢󠁳󠁣󠁴󠁿🏴󠁧󠁢󠁷󠁬󠁳󠁿
@@ -8972,6 +9363,9 @@ pub static WHITE_FLAG :  Emoji = Emoji::new("white flag", Version(0, 7), "🏳
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "subdivision-flag";
+
 		// List of all emoji statics
 
 		
@@ -8998,7 +9392,7 @@ pub static FLAG_WALES :  Emoji = Emoji::new("flag: Wales", Version(5, 0), "🏴
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // FLAG_ENGLAND
 				
@@ -9021,7 +9415,7 @@ pub static FLAG_WALES :  Emoji = Emoji::new("flag: Wales", Version(5, 0), "🏴
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&FLAG_ENGLAND, // FLAG_ENGLAND
@@ -9045,15 +9439,39 @@ pub static FLAG_WALES :  Emoji = Emoji::new("flag: Wales", Version(5, 0), "🏴
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // subdivision_flag
 	
 
 
+	// Subgroup metadata
+
+	/// Contains the metadata of each subgroup of this group.
+	pub(crate) static SUBGROUPS: &[Subgroup] = &[
+		
+		Subgroup::new(country_flag::NAME, country_flag::ALL_VARIANTS, country_flag::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(flag::NAME, flag::ALL_VARIANTS, flag::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(subdivision_flag::NAME, subdivision_flag::ALL_VARIANTS, subdivision_flag::ALL_BASE_EMOJIS),
+		
+	];
+
+
 	// Emoji iterators
 
 	/// Returns an iterator over all emoji variants of these subgroups grouped by base emojis
@@ -9068,6 +9486,11 @@ pub static FLAG_WALES :  Emoji = Emoji::new("flag: Wales", Version(5, 0), "🏴
 		
 	}
 
+	/// Returns an iterator over all emoji variants of these subgroups one by one
+	pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+		all_variants().flat_map(|variants| variants.iter().copied())
+	}
+
 	/// Returns an iterator over all base emojis of these subgroups (i.e. one for each static)
 	pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 		core::iter::empty()
@@ -9083,8 +9506,13 @@ pub static FLAG_WALES :  Emoji = Emoji::new("flag: Wales", Version(5, 0), "🏴
 } // flags
 
 /// food_and_drink 🏺🍼🍱
+#[cfg(emojic_group = "food_and_drink")]
 pub mod food_and_drink {
 	use crate::emojis::Emoji;
+	use crate::groups::Subgroup;
+
+	/// The Unicode name of this group
+	pub const NAME: &str = "Food & Drink";
 
 	
 	/// dishware 🏺🥢🍴
@@ -9103,6 +9531,9 @@ pub mod food_and_drink {
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "dishware";
+
 		// List of all emoji statics
 
 		
@@ -9130,6 +9561,12 @@ pub static FORK_AND_KNIFE :  Emoji = Emoji::new("fork and knife", Version(0, 6),
 #[doc="```"]
 pub static FORK_AND_KNIFE_WITH_PLATE :  Emoji = Emoji::new("fork and knife with plate", Version(0, 7), "🍽️"); // fork and knife with plate
 		
+		#[doc="jar 🫙"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::JAR;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", JAR); // 🫙 (`U+1FAD9`)"] #[doc="# assert_eq!(JAR.to_string().as_str(), \"🫙\");"]
+#[doc="```"]
+pub static JAR :  Emoji = Emoji::new("jar", Version(14, 0), "🫙"); // jar
+		
 		#[doc="kitchen knife 🔪"]#[doc=""]#[doc="Since E0.6"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::KITCHEN_KNIFE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", KITCHEN_KNIFE); // 🔪 (`U+1F52A`)"] #[doc="# assert_eq!(KITCHEN_KNIFE.to_string().as_str(), \"🔪\");"]
@@ -9147,7 +9584,7 @@ pub static SPOON :  Emoji = Emoji::new("spoon", Version(3, 0), "🥄"); // spoon
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // AMPHORA
 				
@@ -9173,6 +9610,12 @@ pub static SPOON :  Emoji = Emoji::new("spoon", Version(3, 0), "🥄"); // spoon
 				
 			],
 			
+			&[ // JAR
+				
+				&JAR,
+				
+			],
+			
 			&[ // KITCHEN_KNIFE
 				
 				&KITCHEN_KNIFE,
@@ -9188,7 +9631,7 @@ pub static SPOON :  Emoji = Emoji::new("spoon", Version(3, 0), "🥄"); // spoon
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&AMPHORA, // AMPHORA
@@ -9207,6 +9650,10 @@ pub static SPOON :  Emoji = Emoji::new("spoon", Version(3, 0), "🥄"); // spoon
 				
 			
 				
+				&JAR, // JAR
+				
+			
+				
 				&KITCHEN_KNIFE, // KITCHEN_KNIFE
 				
 			
@@ -9224,11 +9671,21 @@ pub static SPOON :  Emoji = Emoji::new("spoon", Version(3, 0), "🥄"); // spoon
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // dishware
 	
 	/// drink 🍼🍺🧃
@@ -9247,6 +9704,9 @@ pub static SPOON :  Emoji = Emoji::new("spoon", Version(3, 0), "🥄"); // spoon
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "drink";
+
 		// List of all emoji statics
 
 		
@@ -9328,6 +9788,12 @@ pub static ICE :  Emoji = Emoji::new("ice", Version(12, 0), "🧊"); // ice
 #[doc="```"]
 pub static MATE :  Emoji = Emoji::new("mate", Version(12, 0), "🧉"); // mate
 		
+		#[doc="pouring liquid 🫗"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::POURING_LIQUID;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", POURING_LIQUID); // 🫗 (`U+1FAD7`)"] #[doc="# assert_eq!(POURING_LIQUID.to_string().as_str(), \"🫗\");"]
+#[doc="```"]
+pub static POURING_LIQUID :  Emoji = Emoji::new("pouring liquid", Version(14, 0), "🫗"); // pouring liquid
+		
 		#[doc="sake 🍶"]#[doc=""]#[doc="Since E0.6"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::SAKE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", SAKE); // 🍶 (`U+1F376`)"] #[doc="# assert_eq!(SAKE.to_string().as_str(), \"🍶\");"]
@@ -9369,7 +9835,7 @@ pub static WINE_GLASS :  Emoji = Emoji::new("wine glass", Version(0, 6), "🍷")
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BABY_BOTTLE
 				
@@ -9449,6 +9915,12 @@ pub static WINE_GLASS :  Emoji = Emoji::new("wine glass", Version(0, 6), "🍷")
 				
 			],
 			
+			&[ // POURING_LIQUID
+				
+				&POURING_LIQUID,
+				
+			],
+			
 			&[ // SAKE
 				
 				&SAKE,
@@ -9488,7 +9960,7 @@ pub static WINE_GLASS :  Emoji = Emoji::new("wine glass", Version(0, 6), "🍷")
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BABY_BOTTLE, // BABY_BOTTLE
@@ -9543,6 +10015,10 @@ pub static WINE_GLASS :  Emoji = Emoji::new("wine glass", Version(0, 6), "🍷")
 				
 			
 				
+				&POURING_LIQUID, // POURING_LIQUID
+				
+			
+				
 				&SAKE, // SAKE
 				
 			
@@ -9576,11 +10052,21 @@ pub static WINE_GLASS :  Emoji = Emoji::new("wine glass", Version(0, 6), "🍷")
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // drink
 	
 	/// food_asian 🍱🍚🍛
@@ -9599,6 +10085,9 @@ pub static WINE_GLASS :  Emoji = Emoji::new("wine glass", Version(0, 6), "🍷")
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "food-asian";
+
 		// List of all emoji statics
 
 		
@@ -9709,7 +10198,7 @@ pub static TAKEOUT_BOX :  Emoji = Emoji::new("takeout box", Version(5, 0), "🥡
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BENTO_BOX
 				
@@ -9816,7 +10305,7 @@ pub static TAKEOUT_BOX :  Emoji = Emoji::new("takeout box", Version(5, 0), "🥡
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BENTO_BOX, // BENTO_BOX
@@ -9896,11 +10385,21 @@ pub static TAKEOUT_BOX :  Emoji = Emoji::new("takeout box", Version(5, 0), "🥡
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // food_asian
 	
 	/// food_fruit 🍌🫐🍒
@@ -9919,6 +10418,9 @@ pub static TAKEOUT_BOX :  Emoji = Emoji::new("takeout box", Version(5, 0), "🥡
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "food-fruit";
+
 		// List of all emoji statics
 
 		
@@ -10041,7 +10543,7 @@ pub static WATERMELON :  Emoji = Emoji::new("watermelon", Version(0, 6), "🍉")
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BANANA
 				
@@ -10160,7 +10662,7 @@ pub static WATERMELON :  Emoji = Emoji::new("watermelon", Version(0, 6), "🍉")
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BANANA, // BANANA
@@ -10248,11 +10750,21 @@ pub static WATERMELON :  Emoji = Emoji::new("watermelon", Version(0, 6), "🍉")
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // food_fruit
 	
 	/// food_marine 🦀🦞🦪
@@ -10271,6 +10783,9 @@ pub static WATERMELON :  Emoji = Emoji::new("watermelon", Version(0, 6), "🍉")
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "food-marine";
+
 		// List of all emoji statics
 
 		
@@ -10309,7 +10824,7 @@ pub static SQUID :  Emoji = Emoji::new("squid", Version(3, 0), "🦑"); // squid
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // CRAB
 				
@@ -10344,7 +10859,7 @@ pub static SQUID :  Emoji = Emoji::new("squid", Version(3, 0), "🦑"); // squid
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&CRAB, // CRAB
@@ -10376,11 +10891,21 @@ pub static SQUID :  Emoji = Emoji::new("squid", Version(3, 0), "🦑"); // squid
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // food_marine
 	
 	/// food_prepared 🥓🥯🥖
@@ -10399,6 +10924,9 @@ pub static SQUID :  Emoji = Emoji::new("squid", Version(3, 0), "🦑"); // squid
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "food-prepared";
+
 		// List of all emoji statics
 
 		
@@ -10611,7 +11139,7 @@ pub static WAFFLE :  Emoji = Emoji::new("waffle", Version(12, 0), "🧇"); // wa
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BACON
 				
@@ -10820,7 +11348,7 @@ pub static WAFFLE :  Emoji = Emoji::new("waffle", Version(12, 0), "🧇"); // wa
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BACON, // BACON
@@ -10968,11 +11496,21 @@ pub static WAFFLE :  Emoji = Emoji::new("waffle", Version(12, 0), "🧇"); // wa
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // food_prepared
 	
 	/// food_sweet 🎂🍬🍫
@@ -10991,6 +11529,9 @@ pub static WAFFLE :  Emoji = Emoji::new("waffle", Version(12, 0), "🧇"); // wa
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "food-sweet";
+
 		// List of all emoji statics
 
 		
@@ -11083,7 +11624,7 @@ pub static SOFT_ICE_CREAM :  Emoji = Emoji::new("soft ice cream", Version(0, 6),
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BIRTHDAY_CAKE
 				
@@ -11172,7 +11713,7 @@ pub static SOFT_ICE_CREAM :  Emoji = Emoji::new("soft ice cream", Version(0, 6),
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BIRTHDAY_CAKE, // BIRTHDAY_CAKE
@@ -11240,14 +11781,24 @@ pub static SOFT_ICE_CREAM :  Emoji = Emoji::new("soft ice cream", Version(0, 6),
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // food_sweet
 	
-	/// food_vegetable 🥑🫑🥦
+	/// food_vegetable 🥑🫘🫑
 	pub mod food_vegetable {
 
 		use crate::emojis::Emoji;
@@ -11263,6 +11814,9 @@ pub static SOFT_ICE_CREAM :  Emoji = Emoji::new("soft ice cream", Version(0, 6),
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "food-vegetable";
+
 		// List of all emoji statics
 
 		
@@ -11272,6 +11826,12 @@ pub static SOFT_ICE_CREAM :  Emoji = Emoji::new("soft ice cream", Version(0, 6),
 #[doc="```"]
 pub static AVOCADO :  Emoji = Emoji::new("avocado", Version(3, 0), "🥑"); // avocado
 		
+		#[doc="beans 🫘"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::BEANS;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", BEANS); // 🫘 (`U+1FAD8`)"] #[doc="# assert_eq!(BEANS.to_string().as_str(), \"🫘\");"]
+#[doc="```"]
+pub static BEANS :  Emoji = Emoji::new("beans", Version(14, 0), "🫘"); // beans
+		
 		#[doc="bell pepper 🫑"]#[doc=""]#[doc="Since E13.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::BELL_PEPPER;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", BELL_PEPPER); // 🫑 (`U+1FAD1`)"] #[doc="# assert_eq!(BELL_PEPPER.to_string().as_str(), \"🫑\");"]
@@ -11361,7 +11921,7 @@ pub static POTATO :  Emoji = Emoji::new("potato", Version(3, 0), "🥔"); // pot
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // AVOCADO
 				
@@ -11369,6 +11929,12 @@ pub static POTATO :  Emoji = Emoji::new("potato", Version(3, 0), "🥔"); // pot
 				
 			],
 			
+			&[ // BEANS
+				
+				&BEANS,
+				
+			],
+			
 			&[ // BELL_PEPPER
 				
 				&BELL_PEPPER,
@@ -11456,13 +12022,17 @@ pub static POTATO :  Emoji = Emoji::new("potato", Version(3, 0), "🥔"); // pot
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&AVOCADO, // AVOCADO
 				
 			
 				
+				&BEANS, // BEANS
+				
+			
+				
 				&BELL_PEPPER, // BELL_PEPPER
 				
 			
@@ -11528,15 +12098,49 @@ pub static POTATO :  Emoji = Emoji::new("potato", Version(3, 0), "🥔"); // pot
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // food_vegetable
 	
 
 
+	// Subgroup metadata
+
+	/// Contains the metadata of each subgroup of this group.
+	pub(crate) static SUBGROUPS: &[Subgroup] = &[
+		
+		Subgroup::new(dishware::NAME, dishware::ALL_VARIANTS, dishware::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(drink::NAME, drink::ALL_VARIANTS, drink::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(food_asian::NAME, food_asian::ALL_VARIANTS, food_asian::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(food_fruit::NAME, food_fruit::ALL_VARIANTS, food_fruit::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(food_marine::NAME, food_marine::ALL_VARIANTS, food_marine::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(food_prepared::NAME, food_prepared::ALL_VARIANTS, food_prepared::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(food_sweet::NAME, food_sweet::ALL_VARIANTS, food_sweet::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(food_vegetable::NAME, food_vegetable::ALL_VARIANTS, food_vegetable::ALL_BASE_EMOJIS),
+		
+	];
+
+
 	// Emoji iterators
 
 	/// Returns an iterator over all emoji variants of these subgroups grouped by base emojis
@@ -11561,6 +12165,11 @@ pub static POTATO :  Emoji = Emoji::new("potato", Version(3, 0), "🥔"); // pot
 		
 	}
 
+	/// Returns an iterator over all emoji variants of these subgroups one by one
+	pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+		all_variants().flat_map(|variants| variants.iter().copied())
+	}
+
 	/// Returns an iterator over all base emojis of these subgroups (i.e. one for each static)
 	pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 		core::iter::empty()
@@ -11586,8 +12195,13 @@ pub static POTATO :  Emoji = Emoji::new("potato", Version(3, 0), "🥔"); // pot
 } // food_and_drink
 
 /// objects 📘🎒🧮
+#[cfg(emojic_group = "objects")]
 pub mod objects {
 	use crate::emojis::Emoji;
+	use crate::groups::Subgroup;
+
+	/// The Unicode name of this group
+	pub const NAME: &str = "Objects";
 
 	
 	/// book_paper 📘🔖📑
@@ -11606,6 +12220,9 @@ pub mod objects {
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "book-paper";
+
 		// List of all emoji statics
 
 		
@@ -11716,7 +12333,7 @@ pub static SCROLL :  Emoji = Emoji::new("scroll", Version(0, 6), "📜"); // scr
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BLUE_BOOK
 				
@@ -11823,7 +12440,7 @@ pub static SCROLL :  Emoji = Emoji::new("scroll", Version(0, 6), "📜"); // scr
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BLUE_BOOK, // BLUE_BOOK
@@ -11903,11 +12520,21 @@ pub static SCROLL :  Emoji = Emoji::new("scroll", Version(0, 6), "📜"); // scr
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // book_paper
 	
 	/// clothing 🎒🩰👙
@@ -11926,6 +12553,9 @@ pub static SCROLL :  Emoji = Emoji::new("scroll", Version(0, 6), "📜"); // scr
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "clothing";
+
 		// List of all emoji statics
 
 		
@@ -12204,7 +12834,7 @@ pub static WOMAN_S_SANDAL :  Emoji = Emoji::new("woman’s sandal", Version(0, 6
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BACKPACK
 				
@@ -12479,7 +13109,7 @@ pub static WOMAN_S_SANDAL :  Emoji = Emoji::new("woman’s sandal", Version(0, 6
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BACKPACK, // BACKPACK
@@ -12671,11 +13301,21 @@ pub static WOMAN_S_SANDAL :  Emoji = Emoji::new("woman’s sandal", Version(0, 6
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // clothing
 	
 	/// computer 🧮🔋💽
@@ -12694,6 +13334,9 @@ pub static WOMAN_S_SANDAL :  Emoji = Emoji::new("woman’s sandal", Version(0, 6
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "computer";
+
 		// List of all emoji statics
 
 		
@@ -12757,6 +13400,12 @@ pub static KEYBOARD :  Emoji = Emoji::new("keyboard", Version(1, 0), "⌨️");
 #[doc="```"]
 pub static LAPTOP :  Emoji = Emoji::new("laptop", Version(0, 6), "💻"); // laptop
 		
+		#[doc="low battery 🪫"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::LOW_BATTERY;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", LOW_BATTERY); // 🪫 (`U+1FAAB`)"] #[doc="# assert_eq!(LOW_BATTERY.to_string().as_str(), \"🪫\");"]
+#[doc="```"]
+pub static LOW_BATTERY :  Emoji = Emoji::new("low battery", Version(14, 0), "🪫"); // low battery
+		
 		#[doc="optical disk 💿"]#[doc=""]#[doc="Since E0.6"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::OPTICAL_DISK;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", OPTICAL_DISK); // 💿 (`U+1F4BF`)"] #[doc="# assert_eq!(OPTICAL_DISK.to_string().as_str(), \"💿\");"]
@@ -12780,7 +13429,7 @@ pub static TRACKBALL :  Emoji = Emoji::new("trackball", Version(0, 7), "🖲️"
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ABACUS
 				
@@ -12842,6 +13491,12 @@ pub static TRACKBALL :  Emoji = Emoji::new("trackball", Version(0, 7), "🖲️"
 				
 			],
 			
+			&[ // LOW_BATTERY
+				
+				&LOW_BATTERY,
+				
+			],
+			
 			&[ // OPTICAL_DISK
 				
 				&OPTICAL_DISK,
@@ -12863,7 +13518,7 @@ pub static TRACKBALL :  Emoji = Emoji::new("trackball", Version(0, 7), "🖲️"
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ABACUS, // ABACUS
@@ -12906,6 +13561,10 @@ pub static TRACKBALL :  Emoji = Emoji::new("trackball", Version(0, 7), "🖲️"
 				
 			
 				
+				&LOW_BATTERY, // LOW_BATTERY
+				
+			
+				
 				&OPTICAL_DISK, // OPTICAL_DISK
 				
 			
@@ -12927,11 +13586,21 @@ pub static TRACKBALL :  Emoji = Emoji::new("trackball", Version(0, 7), "🖲️"
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // computer
 	
 	/// household 🧺🛁🛏️
@@ -12950,6 +13619,9 @@ pub static TRACKBALL :  Emoji = Emoji::new("trackball", Version(0, 7), "🖲️"
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "household";
+
 		// List of all emoji statics
 
 		
@@ -12977,6 +13649,12 @@ pub static BED :  Emoji = Emoji::new("bed", Version(0, 7), "🛏️"); // bed
 #[doc="```"]
 pub static BROOM :  Emoji = Emoji::new("broom", Version(11, 0), "🧹"); // broom
 		
+		#[doc="bubbles 🫧"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::BUBBLES;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", BUBBLES); // 🫧 (`U+1FAE7`)"] #[doc="# assert_eq!(BUBBLES.to_string().as_str(), \"🫧\");"]
+#[doc="```"]
+pub static BUBBLES :  Emoji = Emoji::new("bubbles", Version(14, 0), "🫧"); // bubbles
+		
 		#[doc="bucket 🪣"]#[doc=""]#[doc="Since E13.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::BUCKET;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", BUCKET); // 🪣 (`U+1FAA3`)"] #[doc="# assert_eq!(BUCKET.to_string().as_str(), \"🪣\");"]
@@ -13102,7 +13780,7 @@ pub static WINDOW :  Emoji = Emoji::new("window", Version(13, 0), "🪟"); // wi
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BASKET
 				
@@ -13128,6 +13806,12 @@ pub static WINDOW :  Emoji = Emoji::new("window", Version(13, 0), "🪟"); // wi
 				
 			],
 			
+			&[ // BUBBLES
+				
+				&BUBBLES,
+				
+			],
+			
 			&[ // BUCKET
 				
 				&BUCKET,
@@ -13251,7 +13935,7 @@ pub static WINDOW :  Emoji = Emoji::new("window", Version(13, 0), "🪟"); // wi
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BASKET, // BASKET
@@ -13270,6 +13954,10 @@ pub static WINDOW :  Emoji = Emoji::new("window", Version(13, 0), "🪟"); // wi
 				
 			
 				
+				&BUBBLES, // BUBBLES
+				
+			
+				
 				&BUCKET, // BUCKET
 				
 			
@@ -13359,11 +14047,21 @@ pub static WINDOW :  Emoji = Emoji::new("window", Version(13, 0), "🪟"); // wi
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // household
 	
 	/// light_and_video 📷📸🕯️
@@ -13382,6 +14080,9 @@ pub static WINDOW :  Emoji = Emoji::new("window", Version(13, 0), "🪟"); // wi
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "light & video";
+
 		// List of all emoji statics
 
 		
@@ -13486,7 +14187,7 @@ pub static VIDEO_CAMERA :  Emoji = Emoji::new("video camera", Version(0, 6), "
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // CAMERA
 				
@@ -13587,7 +14288,7 @@ pub static VIDEO_CAMERA :  Emoji = Emoji::new("video camera", Version(0, 6), "
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&CAMERA, // CAMERA
@@ -13663,11 +14364,21 @@ pub static VIDEO_CAMERA :  Emoji = Emoji::new("video camera", Version(0, 6), "
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // light_and_video
 	
 	/// lock 🔑🔒🔐
@@ -13686,6 +14397,9 @@ pub static VIDEO_CAMERA :  Emoji = Emoji::new("video camera", Version(0, 6), "
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "lock";
+
 		// List of all emoji statics
 
 		
@@ -13730,7 +14444,7 @@ pub static UNLOCKED :  Emoji = Emoji::new("unlocked", Version(0, 6), "🔓"); //
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // KEY
 				
@@ -13771,7 +14485,7 @@ pub static UNLOCKED :  Emoji = Emoji::new("unlocked", Version(0, 6), "🔓"); //
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&KEY, // KEY
@@ -13807,11 +14521,21 @@ pub static UNLOCKED :  Emoji = Emoji::new("unlocked", Version(0, 6), "🔓"); //
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // lock
 	
 	/// mail 🗳️📪📫
@@ -13830,6 +14554,9 @@ pub static UNLOCKED :  Emoji = Emoji::new("unlocked", Version(0, 6), "🔓"); //
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "mail";
+
 		// List of all emoji statics
 
 		
@@ -13916,7 +14643,7 @@ pub static POSTBOX :  Emoji = Emoji::new("postbox", Version(0, 6), "📮"); // p
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BALLOT_BOX_WITH_BALLOT
 				
@@ -13999,7 +14726,7 @@ pub static POSTBOX :  Emoji = Emoji::new("postbox", Version(0, 6), "📮"); // p
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BALLOT_BOX_WITH_BALLOT, // BALLOT_BOX_WITH_BALLOT
@@ -14063,14 +14790,24 @@ pub static POSTBOX :  Emoji = Emoji::new("postbox", Version(0, 6), "📮"); // p
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // mail
 	
-	/// medical 🩹🩸💊
+	/// medical 🩹🩼🩸
 	pub mod medical {
 
 		use crate::emojis::Emoji;
@@ -14086,6 +14823,9 @@ pub static POSTBOX :  Emoji = Emoji::new("postbox", Version(0, 6), "📮"); // p
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "medical";
+
 		// List of all emoji statics
 
 		
@@ -14095,6 +14835,12 @@ pub static POSTBOX :  Emoji = Emoji::new("postbox", Version(0, 6), "📮"); // p
 #[doc="```"]
 pub static ADHESIVE_BANDAGE :  Emoji = Emoji::new("adhesive bandage", Version(12, 0), "🩹"); // adhesive bandage
 		
+		#[doc="crutch 🩼"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::CRUTCH;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", CRUTCH); // 🩼 (`U+1FA7C`)"] #[doc="# assert_eq!(CRUTCH.to_string().as_str(), \"🩼\");"]
+#[doc="```"]
+pub static CRUTCH :  Emoji = Emoji::new("crutch", Version(14, 0), "🩼"); // crutch
+		
 		#[doc="drop of blood 🩸"]#[doc=""]#[doc="Since E12.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::DROP_OF_BLOOD;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", DROP_OF_BLOOD); // 🩸 (`U+1FA78`)"] #[doc="# assert_eq!(DROP_OF_BLOOD.to_string().as_str(), \"🩸\");"]
@@ -14119,12 +14865,18 @@ pub static STETHOSCOPE :  Emoji = Emoji::new("stethoscope", Version(12, 0), "
 #[doc="```"]
 pub static SYRINGE :  Emoji = Emoji::new("syringe", Version(0, 6), "💉"); // syringe
 		
+		#[doc="x-ray 🩻"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::X_RAY;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", X_RAY); // 🩻 (`U+1FA7B`)"] #[doc="# assert_eq!(X_RAY.to_string().as_str(), \"🩻\");"]
+#[doc="```"]
+pub static X_RAY :  Emoji = Emoji::new("x-ray", Version(14, 0), "🩻"); // x-ray
+		
 
 
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ADHESIVE_BANDAGE
 				
@@ -14132,6 +14884,12 @@ pub static SYRINGE :  Emoji = Emoji::new("syringe", Version(0, 6), "💉"); // s
 				
 			],
 			
+			&[ // CRUTCH
+				
+				&CRUTCH,
+				
+			],
+			
 			&[ // DROP_OF_BLOOD
 				
 				&DROP_OF_BLOOD,
@@ -14156,16 +14914,26 @@ pub static SYRINGE :  Emoji = Emoji::new("syringe", Version(0, 6), "💉"); // s
 				
 			],
 			
+			&[ // X_RAY
+				
+				&X_RAY,
+				
+			],
+			
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ADHESIVE_BANDAGE, // ADHESIVE_BANDAGE
 				
 			
 				
+				&CRUTCH, // CRUTCH
+				
+			
+				
 				&DROP_OF_BLOOD, // DROP_OF_BLOOD
 				
 			
@@ -14181,6 +14949,10 @@ pub static SYRINGE :  Emoji = Emoji::new("syringe", Version(0, 6), "💉"); // s
 				&SYRINGE, // SYRINGE
 				
 			
+				
+				&X_RAY, // X_RAY
+				
+			
 		];
 
 
@@ -14191,11 +14963,21 @@ pub static SYRINGE :  Emoji = Emoji::new("syringe", Version(0, 6), "💉"); // s
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // medical
 	
 	/// money 💹🪙💳
@@ -14214,6 +14996,9 @@ pub static SYRINGE :  Emoji = Emoji::new("syringe", Version(0, 6), "💉"); // s
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "money";
+
 		// List of all emoji statics
 
 		
@@ -14282,7 +15067,7 @@ pub static YEN_BANKNOTE :  Emoji = Emoji::new("yen banknote", Version(0, 6), "
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // CHART_INCREASING_WITH_YEN
 				
@@ -14347,7 +15132,7 @@ pub static YEN_BANKNOTE :  Emoji = Emoji::new("yen banknote", Version(0, 6), "
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&CHART_INCREASING_WITH_YEN, // CHART_INCREASING_WITH_YEN
@@ -14399,11 +15184,21 @@ pub static YEN_BANKNOTE :  Emoji = Emoji::new("yen banknote", Version(0, 6), "
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // money
 	
 	/// music 🎛️🎧🎚️
@@ -14422,6 +15217,9 @@ pub static YEN_BANKNOTE :  Emoji = Emoji::new("yen banknote", Version(0, 6), "
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "music";
+
 		// List of all emoji statics
 
 		
@@ -14484,7 +15282,7 @@ pub static STUDIO_MICROPHONE :  Emoji = Emoji::new("studio microphone", Version(
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // CONTROL_KNOBS
 				
@@ -14543,7 +15341,7 @@ pub static STUDIO_MICROPHONE :  Emoji = Emoji::new("studio microphone", Version(
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&CONTROL_KNOBS, // CONTROL_KNOBS
@@ -14591,11 +15389,21 @@ pub static STUDIO_MICROPHONE :  Emoji = Emoji::new("studio microphone", Version(
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // music
 	
 	/// musical_instrument 🪗🪕🥁
@@ -14614,6 +15422,9 @@ pub static STUDIO_MICROPHONE :  Emoji = Emoji::new("studio microphone", Version(
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "musical-instrument";
+
 		// List of all emoji statics
 
 		
@@ -14676,7 +15487,7 @@ pub static VIOLIN :  Emoji = Emoji::new("violin", Version(0, 6), "🎻"); // vio
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ACCORDION
 				
@@ -14735,7 +15546,7 @@ pub static VIOLIN :  Emoji = Emoji::new("violin", Version(0, 6), "🎻"); // vio
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ACCORDION, // ACCORDION
@@ -14783,11 +15594,21 @@ pub static VIOLIN :  Emoji = Emoji::new("violin", Version(0, 6), "🎻"); // vio
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // musical_instrument
 	
 	/// office 📊💼📅
@@ -14806,6 +15627,9 @@ pub static VIOLIN :  Emoji = Emoji::new("violin", Version(0, 6), "🎻"); // vio
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "office";
+
 		// List of all emoji statics
 
 		
@@ -14952,7 +15776,7 @@ pub static WASTEBASKET :  Emoji = Emoji::new("wastebasket", Version(0, 7), "🗑
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BAR_CHART
 				
@@ -15095,7 +15919,7 @@ pub static WASTEBASKET :  Emoji = Emoji::new("wastebasket", Version(0, 7), "🗑
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BAR_CHART, // BAR_CHART
@@ -15199,11 +16023,21 @@ pub static WASTEBASKET :  Emoji = Emoji::new("wastebasket", Version(0, 7), "🗑
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // office
 	
 	/// other_object 🚬⚰️⚱️
@@ -15222,6 +16056,9 @@ pub static WASTEBASKET :  Emoji = Emoji::new("wastebasket", Version(0, 7), "🗑
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "other-object";
+
 		// List of all emoji statics
 
 		
@@ -15243,12 +16080,24 @@ pub static COFFIN :  Emoji = Emoji::new("coffin", Version(1, 0), "⚰️"); // c
 #[doc="```"]
 pub static FUNERAL_URN :  Emoji = Emoji::new("funeral urn", Version(1, 0), "⚱️"); // funeral urn
 		
+		#[doc="hamsa 🪬"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::HAMSA;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", HAMSA); // 🪬 (`U+1FAAC`)"] #[doc="# assert_eq!(HAMSA.to_string().as_str(), \"🪬\");"]
+#[doc="```"]
+pub static HAMSA :  Emoji = Emoji::new("hamsa", Version(14, 0), "🪬"); // hamsa
+		
 		#[doc="headstone 🪦"]#[doc=""]#[doc="Since E13.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::HEADSTONE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", HEADSTONE); // 🪦 (`U+1FAA6`)"] #[doc="# assert_eq!(HEADSTONE.to_string().as_str(), \"🪦\");"]
 #[doc="```"]
 pub static HEADSTONE :  Emoji = Emoji::new("headstone", Version(13, 0), "🪦"); // headstone
 		
+		#[doc="identification card 🪪"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::IDENTIFICATION_CARD;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", IDENTIFICATION_CARD); // 🪪 (`U+1FAAA`)"] #[doc="# assert_eq!(IDENTIFICATION_CARD.to_string().as_str(), \"🪪\");"]
+#[doc="```"]
+pub static IDENTIFICATION_CARD :  Emoji = Emoji::new("identification card", Version(14, 0), "🪪"); // identification card
+		
 		#[doc="moai 🗿"]#[doc=""]#[doc="Since E0.6"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::MOAI;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", MOAI); // 🗿 (`U+1F5FF`)"] #[doc="# assert_eq!(MOAI.to_string().as_str(), \"🗿\");"]
@@ -15266,7 +16115,7 @@ pub static PLACARD :  Emoji = Emoji::new("placard", Version(13, 0), "🪧"); //
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // CIGARETTE
 				
@@ -15286,12 +16135,24 @@ pub static PLACARD :  Emoji = Emoji::new("placard", Version(13, 0), "🪧"); //
 				
 			],
 			
+			&[ // HAMSA
+				
+				&HAMSA,
+				
+			],
+			
 			&[ // HEADSTONE
 				
 				&HEADSTONE,
 				
 			],
 			
+			&[ // IDENTIFICATION_CARD
+				
+				&IDENTIFICATION_CARD,
+				
+			],
+			
 			&[ // MOAI
 				
 				&MOAI,
@@ -15307,7 +16168,7 @@ pub static PLACARD :  Emoji = Emoji::new("placard", Version(13, 0), "🪧"); //
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&CIGARETTE, // CIGARETTE
@@ -15322,10 +16183,18 @@ pub static PLACARD :  Emoji = Emoji::new("placard", Version(13, 0), "🪧"); //
 				
 			
 				
+				&HAMSA, // HAMSA
+				
+			
+				
 				&HEADSTONE, // HEADSTONE
 				
 			
 				
+				&IDENTIFICATION_CARD, // IDENTIFICATION_CARD
+				
+			
+				
 				&MOAI, // MOAI
 				
 			
@@ -15343,11 +16212,21 @@ pub static PLACARD :  Emoji = Emoji::new("placard", Version(13, 0), "🪧"); //
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // other_object
 	
 	/// phone 📠📱📲
@@ -15366,6 +16245,9 @@ pub static PLACARD :  Emoji = Emoji::new("placard", Version(13, 0), "🪧"); //
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "phone";
+
 		// List of all emoji statics
 
 		
@@ -15410,7 +16292,7 @@ pub static TELEPHONE_RECEIVER :  Emoji = Emoji::new("telephone receiver", Versio
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // FAX_MACHINE
 				
@@ -15451,7 +16333,7 @@ pub static TELEPHONE_RECEIVER :  Emoji = Emoji::new("telephone receiver", Versio
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&FAX_MACHINE, // FAX_MACHINE
@@ -15487,11 +16369,21 @@ pub static TELEPHONE_RECEIVER :  Emoji = Emoji::new("telephone receiver", Versio
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // phone
 	
 	/// science ⚗️🧬🔬
@@ -15510,6 +16402,9 @@ pub static TELEPHONE_RECEIVER :  Emoji = Emoji::new("telephone receiver", Versio
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "science";
+
 		// List of all emoji statics
 
 		
@@ -15560,7 +16455,7 @@ pub static TEST_TUBE :  Emoji = Emoji::new("test tube", Version(11, 0), "🧪");
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ALEMBIC
 				
@@ -15607,7 +16502,7 @@ pub static TEST_TUBE :  Emoji = Emoji::new("test tube", Version(11, 0), "🧪");
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ALEMBIC, // ALEMBIC
@@ -15647,11 +16542,21 @@ pub static TEST_TUBE :  Emoji = Emoji::new("test tube", Version(11, 0), "🧪");
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // science
 	
 	/// sound 🔔🔕📢
@@ -15670,6 +16575,9 @@ pub static TEST_TUBE :  Emoji = Emoji::new("test tube", Version(11, 0), "🧪");
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "sound";
+
 		// List of all emoji statics
 
 		
@@ -15732,7 +16640,7 @@ pub static SPEAKER_MEDIUM_VOLUME :  Emoji = Emoji::new("speaker medium volume",
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BELL
 				
@@ -15791,7 +16699,7 @@ pub static SPEAKER_MEDIUM_VOLUME :  Emoji = Emoji::new("speaker medium volume",
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BELL, // BELL
@@ -15839,11 +16747,21 @@ pub static SPEAKER_MEDIUM_VOLUME :  Emoji = Emoji::new("speaker medium volume",
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // sound
 	
 	/// tool 🪓⚖️🪃
@@ -15862,6 +16780,9 @@ pub static SPEAKER_MEDIUM_VOLUME :  Emoji = Emoji::new("speaker medium volume",
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "tool";
+
 		// List of all emoji statics
 
 		
@@ -16020,7 +16941,7 @@ pub static WRENCH :  Emoji = Emoji::new("wrench", Version(0, 6), "🔧"); // wre
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // AXE
 				
@@ -16175,7 +17096,7 @@ pub static WRENCH :  Emoji = Emoji::new("wrench", Version(0, 6), "🔧"); // wre
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&AXE, // AXE
@@ -16287,11 +17208,21 @@ pub static WRENCH :  Emoji = Emoji::new("wrench", Version(0, 6), "🔧"); // wre
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // tool
 	
 	/// writing ✒️🖍️🖋️
@@ -16310,6 +17241,9 @@ pub static WRENCH :  Emoji = Emoji::new("wrench", Version(0, 6), "🔧"); // wre
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "writing";
+
 		// List of all emoji statics
 
 		
@@ -16360,7 +17294,7 @@ pub static PENCIL :  Emoji = Emoji::new("pencil", Version(0, 6), "✏️"); // p
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BLACK_NIB
 				
@@ -16407,7 +17341,7 @@ pub static PENCIL :  Emoji = Emoji::new("pencil", Version(0, 6), "✏️"); // p
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BLACK_NIB, // BLACK_NIB
@@ -16447,15 +17381,69 @@ pub static PENCIL :  Emoji = Emoji::new("pencil", Version(0, 6), "✏️"); // p
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // writing
 	
 
 
+	// Subgroup metadata
+
+	/// Contains the metadata of each subgroup of this group.
+	pub(crate) static SUBGROUPS: &[Subgroup] = &[
+		
+		Subgroup::new(book_paper::NAME, book_paper::ALL_VARIANTS, book_paper::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(clothing::NAME, clothing::ALL_VARIANTS, clothing::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(computer::NAME, computer::ALL_VARIANTS, computer::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(household::NAME, household::ALL_VARIANTS, household::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(light_and_video::NAME, light_and_video::ALL_VARIANTS, light_and_video::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(lock::NAME, lock::ALL_VARIANTS, lock::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(mail::NAME, mail::ALL_VARIANTS, mail::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(medical::NAME, medical::ALL_VARIANTS, medical::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(money::NAME, money::ALL_VARIANTS, money::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(music::NAME, music::ALL_VARIANTS, music::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(musical_instrument::NAME, musical_instrument::ALL_VARIANTS, musical_instrument::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(office::NAME, office::ALL_VARIANTS, office::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(other_object::NAME, other_object::ALL_VARIANTS, other_object::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(phone::NAME, phone::ALL_VARIANTS, phone::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(science::NAME, science::ALL_VARIANTS, science::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(sound::NAME, sound::ALL_VARIANTS, sound::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(tool::NAME, tool::ALL_VARIANTS, tool::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(writing::NAME, writing::ALL_VARIANTS, writing::ALL_BASE_EMOJIS),
+		
+	];
+
+
 	// Emoji iterators
 
 	/// Returns an iterator over all emoji variants of these subgroups grouped by base emojis
@@ -16500,6 +17488,11 @@ pub static PENCIL :  Emoji = Emoji::new("pencil", Version(0, 6), "✏️"); // p
 		
 	}
 
+	/// Returns an iterator over all emoji variants of these subgroups one by one
+	pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+		all_variants().flat_map(|variants| variants.iter().copied())
+	}
+
 	/// Returns an iterator over all base emojis of these subgroups (i.e. one for each static)
 	pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 		core::iter::empty()
@@ -16545,11 +17538,16 @@ pub static PENCIL :  Emoji = Emoji::new("pencil", Version(0, 6), "✏️"); // p
 } // objects
 
 /// people_and_body 🫀💑🤛
+#[cfg(emojic_group = "people_and_body")]
 pub mod people_and_body {
 	use crate::emojis::Emoji;
+	use crate::groups::Subgroup;
+
+	/// The Unicode name of this group
+	pub const NAME: &str = "People & Body";
 
 	
-	/// body_parts 🫀🦴🧠
+	/// body_parts 🫀🫦🦴
 	pub mod body_parts {
 
 		use crate::emojis::Emoji;
@@ -16565,6 +17563,9 @@ pub mod people_and_body {
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "body-parts";
+
 		// List of all emoji statics
 
 		
@@ -16574,6 +17575,12 @@ pub mod people_and_body {
 #[doc="```"]
 pub static ANATOMICAL_HEART :  Emoji = Emoji::new("anatomical heart", Version(13, 0), "🫀"); // anatomical heart
 		
+		#[doc="biting lip 🫦"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::BITING_LIP;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", BITING_LIP); // 🫦 (`U+1FAE6`)"] #[doc="# assert_eq!(BITING_LIP.to_string().as_str(), \"🫦\");"]
+#[doc="```"]
+pub static BITING_LIP :  Emoji = Emoji::new("biting lip", Version(14, 0), "🫦"); // biting lip
+		
 		#[doc="bone 🦴"]#[doc=""]#[doc="Since E11.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::BONE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", BONE); // 🦴 (`U+1F9B4`)"] #[doc="# assert_eq!(BONE.to_string().as_str(), \"🦴\");"]
@@ -16723,7 +17730,7 @@ pub static TOOTH :  Emoji = Emoji::new("tooth", Version(11, 0), "🦷"); // toot
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ANATOMICAL_HEART
 				
@@ -16731,6 +17738,12 @@ pub static TOOTH :  Emoji = Emoji::new("tooth", Version(11, 0), "🦷"); // toot
 				
 			],
 			
+			&[ // BITING_LIP
+				
+				&BITING_LIP,
+				
+			],
+			
 			&[ // BONE
 				
 				&BONE,
@@ -16890,13 +17903,17 @@ pub static TOOTH :  Emoji = Emoji::new("tooth", Version(11, 0), "🦷"); // toot
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ANATOMICAL_HEART, // ANATOMICAL_HEART
 				
 			
 				
+				&BITING_LIP, // BITING_LIP
+				
+			
+				
 				&BONE, // BONE
 				
 			
@@ -16970,11 +17987,21 @@ pub static TOOTH :  Emoji = Emoji::new("tooth", Version(11, 0), "🦷"); // toot
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // body_parts
 	
 	/// family 💑👪💏
@@ -16993,6 +18020,9 @@ pub static TOOTH :  Emoji = Emoji::new("tooth", Version(11, 0), "🦷"); // toot
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "family";
+
 		// List of all emoji statics
 
 		
@@ -17395,7 +18425,7 @@ pub static PERSON_HOLDING_HANDS: With<Pair,With<TonePair,Emoji>> = With::new(Wit
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // COUPLE_WITH_HEART
 				
@@ -18092,7 +19122,7 @@ pub static PERSON_HOLDING_HANDS: With<Pair,With<TonePair,Emoji>> = With::new(Wit
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&COUPLE_WITH_HEART.default.default, // COUPLE_WITH_HEART
@@ -18120,11 +19150,21 @@ pub static PERSON_HOLDING_HANDS: With<Pair,With<TonePair,Emoji>> = With::new(Wit
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // family
 	
 	/// hand_fingers_closed 🤛👊✊
@@ -18143,6 +19183,9 @@ pub static PERSON_HOLDING_HANDS: With<Pair,With<TonePair,Emoji>> = With::new(Wit
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "hand-fingers-closed";
+
 		// List of all emoji statics
 
 		
@@ -18235,7 +19278,7 @@ pub static THUMBS_UP: With<Tone,Emoji> = With::new(Emoji::new("thumbs up", Versi
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // LEFT_FACING_FIST
 				
@@ -18336,7 +19379,7 @@ pub static THUMBS_UP: With<Tone,Emoji> = With::new(Emoji::new("thumbs up", Versi
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&LEFT_FACING_FIST.default, // LEFT_FACING_FIST
@@ -18372,14 +19415,24 @@ pub static THUMBS_UP: With<Tone,Emoji> = With::new(Emoji::new("thumbs up", Versi
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // hand_fingers_closed
 	
-	/// hand_fingers_open 🖐️🤚✋
+	/// hand_fingers_open 🖐️🫲🫳
 	pub mod hand_fingers_open {
 
 		use crate::emojis::Emoji;
@@ -18395,6 +19448,9 @@ pub static THUMBS_UP: With<Tone,Emoji> = With::new(Emoji::new("thumbs up", Versi
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "hand-fingers-open";
+
 		// List of all emoji statics
 
 		
@@ -18412,6 +19468,48 @@ pub static HAND_WITH_FINGERS_SPLAYED: With<Tone,Emoji> = With::new(Emoji::new("h
 	]);
 
 		
+		#[doc="leftwards hand 🫲"]#[doc=""]#[doc="Since E14.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::LEFTWARDS_HAND;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", LEFTWARDS_HAND); // 🫲 (`U+1FAF2`)"] #[doc="# assert_eq!(LEFTWARDS_HAND.to_string().as_str(), \"🫲\");"]#[doc="println!(\"{}\", LEFTWARDS_HAND.tone(Tone::Light)); // 🫲🏻 (`U+1FAF2 U+1F3FB`)"] #[doc="# assert_eq!(LEFTWARDS_HAND.tone(Tone::Light).to_string().as_str(), \"🫲🏻\");"]#[doc="println!(\"{}\", LEFTWARDS_HAND.tone(Tone::MediumLight)); // 🫲🏼 (`U+1FAF2 U+1F3FC`)"] #[doc="# assert_eq!(LEFTWARDS_HAND.tone(Tone::MediumLight).to_string().as_str(), \"🫲🏼\");"]#[doc="println!(\"{}\", LEFTWARDS_HAND.tone(Tone::Medium)); // 🫲🏽 (`U+1FAF2 U+1F3FD`)"] #[doc="# assert_eq!(LEFTWARDS_HAND.tone(Tone::Medium).to_string().as_str(), \"🫲🏽\");"]#[doc="println!(\"{}\", LEFTWARDS_HAND.tone(Tone::MediumDark)); // 🫲🏾 (`U+1FAF2 U+1F3FE`)"] #[doc="# assert_eq!(LEFTWARDS_HAND.tone(Tone::MediumDark).to_string().as_str(), \"🫲🏾\");"]#[doc="println!(\"{}\", LEFTWARDS_HAND.tone(Tone::Dark)); // 🫲🏿 (`U+1FAF2 U+1F3FF`)"] #[doc="# assert_eq!(LEFTWARDS_HAND.tone(Tone::Dark).to_string().as_str(), \"🫲🏿\");"]
+#[doc="```"]
+pub static LEFTWARDS_HAND: With<Tone,Emoji> = With::new(Emoji::new("leftwards hand", Version(14, 0),"🫲"), 
+	&[
+		Emoji::new("leftwards hand: light skin tone", Version(14, 0),"🫲🏻"),
+	Emoji::new("leftwards hand: medium-light skin tone", Version(14, 0),"🫲🏼"),
+	Emoji::new("leftwards hand: medium skin tone", Version(14, 0),"🫲🏽"),
+	Emoji::new("leftwards hand: medium-dark skin tone", Version(14, 0),"🫲🏾"),
+	Emoji::new("leftwards hand: dark skin tone", Version(14, 0),"🫲🏿"),
+	]);
+
+		
+		#[doc="palm down hand 🫳"]#[doc=""]#[doc="Since E14.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::PALM_DOWN_HAND;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", PALM_DOWN_HAND); // 🫳 (`U+1FAF3`)"] #[doc="# assert_eq!(PALM_DOWN_HAND.to_string().as_str(), \"🫳\");"]#[doc="println!(\"{}\", PALM_DOWN_HAND.tone(Tone::Light)); // 🫳🏻 (`U+1FAF3 U+1F3FB`)"] #[doc="# assert_eq!(PALM_DOWN_HAND.tone(Tone::Light).to_string().as_str(), \"🫳🏻\");"]#[doc="println!(\"{}\", PALM_DOWN_HAND.tone(Tone::MediumLight)); // 🫳🏼 (`U+1FAF3 U+1F3FC`)"] #[doc="# assert_eq!(PALM_DOWN_HAND.tone(Tone::MediumLight).to_string().as_str(), \"🫳🏼\");"]#[doc="println!(\"{}\", PALM_DOWN_HAND.tone(Tone::Medium)); // 🫳🏽 (`U+1FAF3 U+1F3FD`)"] #[doc="# assert_eq!(PALM_DOWN_HAND.tone(Tone::Medium).to_string().as_str(), \"🫳🏽\");"]#[doc="println!(\"{}\", PALM_DOWN_HAND.tone(Tone::MediumDark)); // 🫳🏾 (`U+1FAF3 U+1F3FE`)"] #[doc="# assert_eq!(PALM_DOWN_HAND.tone(Tone::MediumDark).to_string().as_str(), \"🫳🏾\");"]#[doc="println!(\"{}\", PALM_DOWN_HAND.tone(Tone::Dark)); // 🫳🏿 (`U+1FAF3 U+1F3FF`)"] #[doc="# assert_eq!(PALM_DOWN_HAND.tone(Tone::Dark).to_string().as_str(), \"🫳🏿\");"]
+#[doc="```"]
+pub static PALM_DOWN_HAND: With<Tone,Emoji> = With::new(Emoji::new("palm down hand", Version(14, 0),"🫳"), 
+	&[
+		Emoji::new("palm down hand: light skin tone", Version(14, 0),"🫳🏻"),
+	Emoji::new("palm down hand: medium-light skin tone", Version(14, 0),"🫳🏼"),
+	Emoji::new("palm down hand: medium skin tone", Version(14, 0),"🫳🏽"),
+	Emoji::new("palm down hand: medium-dark skin tone", Version(14, 0),"🫳🏾"),
+	Emoji::new("palm down hand: dark skin tone", Version(14, 0),"🫳🏿"),
+	]);
+
+		
+		#[doc="palm up hand 🫴"]#[doc=""]#[doc="Since E14.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::PALM_UP_HAND;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", PALM_UP_HAND); // 🫴 (`U+1FAF4`)"] #[doc="# assert_eq!(PALM_UP_HAND.to_string().as_str(), \"🫴\");"]#[doc="println!(\"{}\", PALM_UP_HAND.tone(Tone::Light)); // 🫴🏻 (`U+1FAF4 U+1F3FB`)"] #[doc="# assert_eq!(PALM_UP_HAND.tone(Tone::Light).to_string().as_str(), \"🫴🏻\");"]#[doc="println!(\"{}\", PALM_UP_HAND.tone(Tone::MediumLight)); // 🫴🏼 (`U+1FAF4 U+1F3FC`)"] #[doc="# assert_eq!(PALM_UP_HAND.tone(Tone::MediumLight).to_string().as_str(), \"🫴🏼\");"]#[doc="println!(\"{}\", PALM_UP_HAND.tone(Tone::Medium)); // 🫴🏽 (`U+1FAF4 U+1F3FD`)"] #[doc="# assert_eq!(PALM_UP_HAND.tone(Tone::Medium).to_string().as_str(), \"🫴🏽\");"]#[doc="println!(\"{}\", PALM_UP_HAND.tone(Tone::MediumDark)); // 🫴🏾 (`U+1FAF4 U+1F3FE`)"] #[doc="# assert_eq!(PALM_UP_HAND.tone(Tone::MediumDark).to_string().as_str(), \"🫴🏾\");"]#[doc="println!(\"{}\", PALM_UP_HAND.tone(Tone::Dark)); // 🫴🏿 (`U+1FAF4 U+1F3FF`)"] #[doc="# assert_eq!(PALM_UP_HAND.tone(Tone::Dark).to_string().as_str(), \"🫴🏿\");"]
+#[doc="```"]
+pub static PALM_UP_HAND: With<Tone,Emoji> = With::new(Emoji::new("palm up hand", Version(14, 0),"🫴"), 
+	&[
+		Emoji::new("palm up hand: light skin tone", Version(14, 0),"🫴🏻"),
+	Emoji::new("palm up hand: medium-light skin tone", Version(14, 0),"🫴🏼"),
+	Emoji::new("palm up hand: medium skin tone", Version(14, 0),"🫴🏽"),
+	Emoji::new("palm up hand: medium-dark skin tone", Version(14, 0),"🫴🏾"),
+	Emoji::new("palm up hand: dark skin tone", Version(14, 0),"🫴🏿"),
+	]);
+
+		
 		#[doc="raised back of hand 🤚"]#[doc=""]#[doc="Since E3.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::RAISED_BACK_OF_HAND;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", RAISED_BACK_OF_HAND); // 🤚 (`U+1F91A`)"] #[doc="# assert_eq!(RAISED_BACK_OF_HAND.to_string().as_str(), \"🤚\");"]#[doc="println!(\"{}\", RAISED_BACK_OF_HAND.tone(Tone::Light)); // 🤚🏻 (`U+1F91A U+1F3FB`)"] #[doc="# assert_eq!(RAISED_BACK_OF_HAND.tone(Tone::Light).to_string().as_str(), \"🤚🏻\");"]#[doc="println!(\"{}\", RAISED_BACK_OF_HAND.tone(Tone::MediumLight)); // 🤚🏼 (`U+1F91A U+1F3FC`)"] #[doc="# assert_eq!(RAISED_BACK_OF_HAND.tone(Tone::MediumLight).to_string().as_str(), \"🤚🏼\");"]#[doc="println!(\"{}\", RAISED_BACK_OF_HAND.tone(Tone::Medium)); // 🤚🏽 (`U+1F91A U+1F3FD`)"] #[doc="# assert_eq!(RAISED_BACK_OF_HAND.tone(Tone::Medium).to_string().as_str(), \"🤚🏽\");"]#[doc="println!(\"{}\", RAISED_BACK_OF_HAND.tone(Tone::MediumDark)); // 🤚🏾 (`U+1F91A U+1F3FE`)"] #[doc="# assert_eq!(RAISED_BACK_OF_HAND.tone(Tone::MediumDark).to_string().as_str(), \"🤚🏾\");"]#[doc="println!(\"{}\", RAISED_BACK_OF_HAND.tone(Tone::Dark)); // 🤚🏿 (`U+1F91A U+1F3FF`)"] #[doc="# assert_eq!(RAISED_BACK_OF_HAND.tone(Tone::Dark).to_string().as_str(), \"🤚🏿\");"]
@@ -18440,6 +19538,20 @@ pub static RAISED_HAND: With<Tone,Emoji> = With::new(Emoji::new("raised hand", V
 	]);
 
 		
+		#[doc="rightwards hand 🫱"]#[doc=""]#[doc="Since E14.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::RIGHTWARDS_HAND;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", RIGHTWARDS_HAND); // 🫱 (`U+1FAF1`)"] #[doc="# assert_eq!(RIGHTWARDS_HAND.to_string().as_str(), \"🫱\");"]#[doc="println!(\"{}\", RIGHTWARDS_HAND.tone(Tone::Light)); // 🫱🏻 (`U+1FAF1 U+1F3FB`)"] #[doc="# assert_eq!(RIGHTWARDS_HAND.tone(Tone::Light).to_string().as_str(), \"🫱🏻\");"]#[doc="println!(\"{}\", RIGHTWARDS_HAND.tone(Tone::MediumLight)); // 🫱🏼 (`U+1FAF1 U+1F3FC`)"] #[doc="# assert_eq!(RIGHTWARDS_HAND.tone(Tone::MediumLight).to_string().as_str(), \"🫱🏼\");"]#[doc="println!(\"{}\", RIGHTWARDS_HAND.tone(Tone::Medium)); // 🫱🏽 (`U+1FAF1 U+1F3FD`)"] #[doc="# assert_eq!(RIGHTWARDS_HAND.tone(Tone::Medium).to_string().as_str(), \"🫱🏽\");"]#[doc="println!(\"{}\", RIGHTWARDS_HAND.tone(Tone::MediumDark)); // 🫱🏾 (`U+1FAF1 U+1F3FE`)"] #[doc="# assert_eq!(RIGHTWARDS_HAND.tone(Tone::MediumDark).to_string().as_str(), \"🫱🏾\");"]#[doc="println!(\"{}\", RIGHTWARDS_HAND.tone(Tone::Dark)); // 🫱🏿 (`U+1FAF1 U+1F3FF`)"] #[doc="# assert_eq!(RIGHTWARDS_HAND.tone(Tone::Dark).to_string().as_str(), \"🫱🏿\");"]
+#[doc="```"]
+pub static RIGHTWARDS_HAND: With<Tone,Emoji> = With::new(Emoji::new("rightwards hand", Version(14, 0),"🫱"), 
+	&[
+		Emoji::new("rightwards hand: light skin tone", Version(14, 0),"🫱🏻"),
+	Emoji::new("rightwards hand: medium-light skin tone", Version(14, 0),"🫱🏼"),
+	Emoji::new("rightwards hand: medium skin tone", Version(14, 0),"🫱🏽"),
+	Emoji::new("rightwards hand: medium-dark skin tone", Version(14, 0),"🫱🏾"),
+	Emoji::new("rightwards hand: dark skin tone", Version(14, 0),"🫱🏿"),
+	]);
+
+		
 		#[doc="vulcan salute 🖖"]#[doc=""]#[doc="Since E1.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::VULCAN_SALUTE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", VULCAN_SALUTE); // 🖖 (`U+1F596`)"] #[doc="# assert_eq!(VULCAN_SALUTE.to_string().as_str(), \"🖖\");"]#[doc="println!(\"{}\", VULCAN_SALUTE.tone(Tone::Light)); // 🖖🏻 (`U+1F596 U+1F3FB`)"] #[doc="# assert_eq!(VULCAN_SALUTE.tone(Tone::Light).to_string().as_str(), \"🖖🏻\");"]#[doc="println!(\"{}\", VULCAN_SALUTE.tone(Tone::MediumLight)); // 🖖🏼 (`U+1F596 U+1F3FC`)"] #[doc="# assert_eq!(VULCAN_SALUTE.tone(Tone::MediumLight).to_string().as_str(), \"🖖🏼\");"]#[doc="println!(\"{}\", VULCAN_SALUTE.tone(Tone::Medium)); // 🖖🏽 (`U+1F596 U+1F3FD`)"] #[doc="# assert_eq!(VULCAN_SALUTE.tone(Tone::Medium).to_string().as_str(), \"🖖🏽\");"]#[doc="println!(\"{}\", VULCAN_SALUTE.tone(Tone::MediumDark)); // 🖖🏾 (`U+1F596 U+1F3FE`)"] #[doc="# assert_eq!(VULCAN_SALUTE.tone(Tone::MediumDark).to_string().as_str(), \"🖖🏾\");"]#[doc="println!(\"{}\", VULCAN_SALUTE.tone(Tone::Dark)); // 🖖🏿 (`U+1F596 U+1F3FF`)"] #[doc="# assert_eq!(VULCAN_SALUTE.tone(Tone::Dark).to_string().as_str(), \"🖖🏿\");"]
@@ -18473,7 +19585,7 @@ pub static WAVING_HAND: With<Tone,Emoji> = With::new(Emoji::new("waving hand", V
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // HAND_WITH_FINGERS_SPLAYED
 				
@@ -18491,6 +19603,54 @@ pub static WAVING_HAND: With<Tone,Emoji> = With::new(Emoji::new("waving hand", V
 				
 			],
 			
+			&[ // LEFTWARDS_HAND
+				
+				&LEFTWARDS_HAND.default,
+				
+				&LEFTWARDS_HAND.tone(Tone::Light),
+				
+				&LEFTWARDS_HAND.tone(Tone::MediumLight),
+				
+				&LEFTWARDS_HAND.tone(Tone::Medium),
+				
+				&LEFTWARDS_HAND.tone(Tone::MediumDark),
+				
+				&LEFTWARDS_HAND.tone(Tone::Dark),
+				
+			],
+			
+			&[ // PALM_DOWN_HAND
+				
+				&PALM_DOWN_HAND.default,
+				
+				&PALM_DOWN_HAND.tone(Tone::Light),
+				
+				&PALM_DOWN_HAND.tone(Tone::MediumLight),
+				
+				&PALM_DOWN_HAND.tone(Tone::Medium),
+				
+				&PALM_DOWN_HAND.tone(Tone::MediumDark),
+				
+				&PALM_DOWN_HAND.tone(Tone::Dark),
+				
+			],
+			
+			&[ // PALM_UP_HAND
+				
+				&PALM_UP_HAND.default,
+				
+				&PALM_UP_HAND.tone(Tone::Light),
+				
+				&PALM_UP_HAND.tone(Tone::MediumLight),
+				
+				&PALM_UP_HAND.tone(Tone::Medium),
+				
+				&PALM_UP_HAND.tone(Tone::MediumDark),
+				
+				&PALM_UP_HAND.tone(Tone::Dark),
+				
+			],
+			
 			&[ // RAISED_BACK_OF_HAND
 				
 				&RAISED_BACK_OF_HAND.default,
@@ -18523,6 +19683,22 @@ pub static WAVING_HAND: With<Tone,Emoji> = With::new(Emoji::new("waving hand", V
 				
 			],
 			
+			&[ // RIGHTWARDS_HAND
+				
+				&RIGHTWARDS_HAND.default,
+				
+				&RIGHTWARDS_HAND.tone(Tone::Light),
+				
+				&RIGHTWARDS_HAND.tone(Tone::MediumLight),
+				
+				&RIGHTWARDS_HAND.tone(Tone::Medium),
+				
+				&RIGHTWARDS_HAND.tone(Tone::MediumDark),
+				
+				&RIGHTWARDS_HAND.tone(Tone::Dark),
+				
+			],
+			
 			&[ // VULCAN_SALUTE
 				
 				&VULCAN_SALUTE.default,
@@ -18558,13 +19734,25 @@ pub static WAVING_HAND: With<Tone,Emoji> = With::new(Emoji::new("waving hand", V
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&HAND_WITH_FINGERS_SPLAYED.default, // HAND_WITH_FINGERS_SPLAYED
 				
 			
 				
+				&LEFTWARDS_HAND.default, // LEFTWARDS_HAND
+				
+			
+				
+				&PALM_DOWN_HAND.default, // PALM_DOWN_HAND
+				
+			
+				
+				&PALM_UP_HAND.default, // PALM_UP_HAND
+				
+			
+				
 				&RAISED_BACK_OF_HAND.default, // RAISED_BACK_OF_HAND
 				
 			
@@ -18573,6 +19761,10 @@ pub static WAVING_HAND: With<Tone,Emoji> = With::new(Emoji::new("waving hand", V
 				
 			
 				
+				&RIGHTWARDS_HAND.default, // RIGHTWARDS_HAND
+				
+			
+				
 				&VULCAN_SALUTE.default, // VULCAN_SALUTE
 				
 			
@@ -18590,14 +19782,24 @@ pub static WAVING_HAND: With<Tone,Emoji> = With::new(Emoji::new("waving hand", V
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // hand_fingers_open
 	
-	/// hand_fingers_partial 🤙🤞🤟
+	/// hand_fingers_partial 🤙🤞🫰
 	pub mod hand_fingers_partial {
 
 		use crate::emojis::Emoji;
@@ -18613,6 +19815,9 @@ pub static WAVING_HAND: With<Tone,Emoji> = With::new(Emoji::new("waving hand", V
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "hand-fingers-partial";
+
 		// List of all emoji statics
 
 		
@@ -18644,6 +19849,20 @@ pub static CROSSED_FINGERS: With<Tone,Emoji> = With::new(Emoji::new("crossed fin
 	]);
 
 		
+		#[doc="hand with index finger and thumb crossed 🫰"]#[doc=""]#[doc="Since E14.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED); // 🫰 (`U+1FAF0`)"] #[doc="# assert_eq!(HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.to_string().as_str(), \"🫰\");"]#[doc="println!(\"{}\", HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::Light)); // 🫰🏻 (`U+1FAF0 U+1F3FB`)"] #[doc="# assert_eq!(HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::Light).to_string().as_str(), \"🫰🏻\");"]#[doc="println!(\"{}\", HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::MediumLight)); // 🫰🏼 (`U+1FAF0 U+1F3FC`)"] #[doc="# assert_eq!(HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::MediumLight).to_string().as_str(), \"🫰🏼\");"]#[doc="println!(\"{}\", HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::Medium)); // 🫰🏽 (`U+1FAF0 U+1F3FD`)"] #[doc="# assert_eq!(HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::Medium).to_string().as_str(), \"🫰🏽\");"]#[doc="println!(\"{}\", HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::MediumDark)); // 🫰🏾 (`U+1FAF0 U+1F3FE`)"] #[doc="# assert_eq!(HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::MediumDark).to_string().as_str(), \"🫰🏾\");"]#[doc="println!(\"{}\", HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::Dark)); // 🫰🏿 (`U+1FAF0 U+1F3FF`)"] #[doc="# assert_eq!(HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::Dark).to_string().as_str(), \"🫰🏿\");"]
+#[doc="```"]
+pub static HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED: With<Tone,Emoji> = With::new(Emoji::new("hand with index finger and thumb crossed", Version(14, 0),"🫰"), 
+	&[
+		Emoji::new("hand with index finger and thumb crossed: light skin tone", Version(14, 0),"🫰🏻"),
+	Emoji::new("hand with index finger and thumb crossed: medium-light skin tone", Version(14, 0),"🫰🏼"),
+	Emoji::new("hand with index finger and thumb crossed: medium skin tone", Version(14, 0),"🫰🏽"),
+	Emoji::new("hand with index finger and thumb crossed: medium-dark skin tone", Version(14, 0),"🫰🏾"),
+	Emoji::new("hand with index finger and thumb crossed: dark skin tone", Version(14, 0),"🫰🏿"),
+	]);
+
+		
 		#[doc="love-you gesture 🤟"]#[doc=""]#[doc="Since E5.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::LOVE_YOU_GESTURE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", LOVE_YOU_GESTURE); // 🤟 (`U+1F91F`)"] #[doc="# assert_eq!(LOVE_YOU_GESTURE.to_string().as_str(), \"🤟\");"]#[doc="println!(\"{}\", LOVE_YOU_GESTURE.tone(Tone::Light)); // 🤟🏻 (`U+1F91F U+1F3FB`)"] #[doc="# assert_eq!(LOVE_YOU_GESTURE.tone(Tone::Light).to_string().as_str(), \"🤟🏻\");"]#[doc="println!(\"{}\", LOVE_YOU_GESTURE.tone(Tone::MediumLight)); // 🤟🏼 (`U+1F91F U+1F3FC`)"] #[doc="# assert_eq!(LOVE_YOU_GESTURE.tone(Tone::MediumLight).to_string().as_str(), \"🤟🏼\");"]#[doc="println!(\"{}\", LOVE_YOU_GESTURE.tone(Tone::Medium)); // 🤟🏽 (`U+1F91F U+1F3FD`)"] #[doc="# assert_eq!(LOVE_YOU_GESTURE.tone(Tone::Medium).to_string().as_str(), \"🤟🏽\");"]#[doc="println!(\"{}\", LOVE_YOU_GESTURE.tone(Tone::MediumDark)); // 🤟🏾 (`U+1F91F U+1F3FE`)"] #[doc="# assert_eq!(LOVE_YOU_GESTURE.tone(Tone::MediumDark).to_string().as_str(), \"🤟🏾\");"]#[doc="println!(\"{}\", LOVE_YOU_GESTURE.tone(Tone::Dark)); // 🤟🏿 (`U+1F91F U+1F3FF`)"] #[doc="# assert_eq!(LOVE_YOU_GESTURE.tone(Tone::Dark).to_string().as_str(), \"🤟🏿\");"]
@@ -18733,7 +19952,7 @@ pub static VICTORY_HAND: With<Tone,Emoji> = With::new(Emoji::new("victory hand",
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // CALL_ME_HAND
 				
@@ -18767,6 +19986,22 @@ pub static VICTORY_HAND: With<Tone,Emoji> = With::new(Emoji::new("victory hand",
 				
 			],
 			
+			&[ // HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED
+				
+				&HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.default,
+				
+				&HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::Light),
+				
+				&HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::MediumLight),
+				
+				&HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::Medium),
+				
+				&HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::MediumDark),
+				
+				&HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.tone(Tone::Dark),
+				
+			],
+			
 			&[ // LOVE_YOU_GESTURE
 				
 				&LOVE_YOU_GESTURE.default,
@@ -18866,7 +20101,7 @@ pub static VICTORY_HAND: With<Tone,Emoji> = With::new(Emoji::new("victory hand",
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&CALL_ME_HAND.default, // CALL_ME_HAND
@@ -18877,6 +20112,10 @@ pub static VICTORY_HAND: With<Tone,Emoji> = With::new(Emoji::new("victory hand",
 				
 			
 				
+				&HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.default, // HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED
+				
+			
+				
 				&LOVE_YOU_GESTURE.default, // LOVE_YOU_GESTURE
 				
 			
@@ -18910,11 +20149,21 @@ pub static VICTORY_HAND: With<Tone,Emoji> = With::new(Emoji::new("victory hand",
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // hand_fingers_partial
 	
 	/// hand_prop 💅🤳✍️
@@ -18933,6 +20182,9 @@ pub static VICTORY_HAND: With<Tone,Emoji> = With::new(Emoji::new("victory hand",
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "hand-prop";
+
 		// List of all emoji statics
 
 		
@@ -18983,7 +20235,7 @@ pub static WRITING_HAND: With<Tone,Emoji> = With::new(Emoji::new("writing hand",
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // NAIL_POLISH
 				
@@ -19036,7 +20288,7 @@ pub static WRITING_HAND: With<Tone,Emoji> = With::new(Emoji::new("writing hand",
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&NAIL_POLISH.default, // NAIL_POLISH
@@ -19060,11 +20312,21 @@ pub static WRITING_HAND: With<Tone,Emoji> = With::new(Emoji::new("writing hand",
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // hand_prop
 	
 	/// hand_single_finger 👇👈👉
@@ -19083,6 +20345,9 @@ pub static WRITING_HAND: With<Tone,Emoji> = With::new(Emoji::new("writing hand",
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "hand-single-finger";
+
 		// List of all emoji statics
 
 		
@@ -19142,6 +20407,20 @@ pub static BACKHAND_INDEX_POINTING_UP: With<Tone,Emoji> = With::new(Emoji::new("
 	]);
 
 		
+		#[doc="index pointing at the viewer 🫵"]#[doc=""]#[doc="Since E14.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::INDEX_POINTING_AT_THE_VIEWER;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", INDEX_POINTING_AT_THE_VIEWER); // 🫵 (`U+1FAF5`)"] #[doc="# assert_eq!(INDEX_POINTING_AT_THE_VIEWER.to_string().as_str(), \"🫵\");"]#[doc="println!(\"{}\", INDEX_POINTING_AT_THE_VIEWER.tone(Tone::Light)); // 🫵🏻 (`U+1FAF5 U+1F3FB`)"] #[doc="# assert_eq!(INDEX_POINTING_AT_THE_VIEWER.tone(Tone::Light).to_string().as_str(), \"🫵🏻\");"]#[doc="println!(\"{}\", INDEX_POINTING_AT_THE_VIEWER.tone(Tone::MediumLight)); // 🫵🏼 (`U+1FAF5 U+1F3FC`)"] #[doc="# assert_eq!(INDEX_POINTING_AT_THE_VIEWER.tone(Tone::MediumLight).to_string().as_str(), \"🫵🏼\");"]#[doc="println!(\"{}\", INDEX_POINTING_AT_THE_VIEWER.tone(Tone::Medium)); // 🫵🏽 (`U+1FAF5 U+1F3FD`)"] #[doc="# assert_eq!(INDEX_POINTING_AT_THE_VIEWER.tone(Tone::Medium).to_string().as_str(), \"🫵🏽\");"]#[doc="println!(\"{}\", INDEX_POINTING_AT_THE_VIEWER.tone(Tone::MediumDark)); // 🫵🏾 (`U+1FAF5 U+1F3FE`)"] #[doc="# assert_eq!(INDEX_POINTING_AT_THE_VIEWER.tone(Tone::MediumDark).to_string().as_str(), \"🫵🏾\");"]#[doc="println!(\"{}\", INDEX_POINTING_AT_THE_VIEWER.tone(Tone::Dark)); // 🫵🏿 (`U+1FAF5 U+1F3FF`)"] #[doc="# assert_eq!(INDEX_POINTING_AT_THE_VIEWER.tone(Tone::Dark).to_string().as_str(), \"🫵🏿\");"]
+#[doc="```"]
+pub static INDEX_POINTING_AT_THE_VIEWER: With<Tone,Emoji> = With::new(Emoji::new("index pointing at the viewer", Version(14, 0),"🫵"), 
+	&[
+		Emoji::new("index pointing at the viewer: light skin tone", Version(14, 0),"🫵🏻"),
+	Emoji::new("index pointing at the viewer: medium-light skin tone", Version(14, 0),"🫵🏼"),
+	Emoji::new("index pointing at the viewer: medium skin tone", Version(14, 0),"🫵🏽"),
+	Emoji::new("index pointing at the viewer: medium-dark skin tone", Version(14, 0),"🫵🏾"),
+	Emoji::new("index pointing at the viewer: dark skin tone", Version(14, 0),"🫵🏿"),
+	]);
+
+		
 		#[doc="index pointing up ☝️"]#[doc=""]#[doc="Since E0.6"]#[doc=""] #[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::INDEX_POINTING_UP;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", INDEX_POINTING_UP); // ☝️ (`U+261D U+FE0F`)"] #[doc="# assert_eq!(INDEX_POINTING_UP.to_string().as_str(), \"☝️\");"]#[doc="println!(\"{}\", INDEX_POINTING_UP.tone(Tone::Light)); // ☝🏻 (`U+261D U+1F3FB`)"] #[doc="# assert_eq!(INDEX_POINTING_UP.tone(Tone::Light).to_string().as_str(), \"☝🏻\");"]#[doc="println!(\"{}\", INDEX_POINTING_UP.tone(Tone::MediumLight)); // ☝🏼 (`U+261D U+1F3FC`)"] #[doc="# assert_eq!(INDEX_POINTING_UP.tone(Tone::MediumLight).to_string().as_str(), \"☝🏼\");"]#[doc="println!(\"{}\", INDEX_POINTING_UP.tone(Tone::Medium)); // ☝🏽 (`U+261D U+1F3FD`)"] #[doc="# assert_eq!(INDEX_POINTING_UP.tone(Tone::Medium).to_string().as_str(), \"☝🏽\");"]#[doc="println!(\"{}\", INDEX_POINTING_UP.tone(Tone::MediumDark)); // ☝🏾 (`U+261D U+1F3FE`)"] #[doc="# assert_eq!(INDEX_POINTING_UP.tone(Tone::MediumDark).to_string().as_str(), \"☝🏾\");"]#[doc="println!(\"{}\", INDEX_POINTING_UP.tone(Tone::Dark)); // ☝🏿 (`U+261D U+1F3FF`)"] #[doc="# assert_eq!(INDEX_POINTING_UP.tone(Tone::Dark).to_string().as_str(), \"☝🏿\");"]
@@ -19175,7 +20454,7 @@ pub static MIDDLE_FINGER: With<Tone,Emoji> = With::new(Emoji::new("middle finger
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BACKHAND_INDEX_POINTING_DOWN
 				
@@ -19241,6 +20520,22 @@ pub static MIDDLE_FINGER: With<Tone,Emoji> = With::new(Emoji::new("middle finger
 				
 			],
 			
+			&[ // INDEX_POINTING_AT_THE_VIEWER
+				
+				&INDEX_POINTING_AT_THE_VIEWER.default,
+				
+				&INDEX_POINTING_AT_THE_VIEWER.tone(Tone::Light),
+				
+				&INDEX_POINTING_AT_THE_VIEWER.tone(Tone::MediumLight),
+				
+				&INDEX_POINTING_AT_THE_VIEWER.tone(Tone::Medium),
+				
+				&INDEX_POINTING_AT_THE_VIEWER.tone(Tone::MediumDark),
+				
+				&INDEX_POINTING_AT_THE_VIEWER.tone(Tone::Dark),
+				
+			],
+			
 			&[ // INDEX_POINTING_UP
 				
 				&INDEX_POINTING_UP.default,
@@ -19276,7 +20571,7 @@ pub static MIDDLE_FINGER: With<Tone,Emoji> = With::new(Emoji::new("middle finger
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BACKHAND_INDEX_POINTING_DOWN.default, // BACKHAND_INDEX_POINTING_DOWN
@@ -19295,6 +20590,10 @@ pub static MIDDLE_FINGER: With<Tone,Emoji> = With::new(Emoji::new("middle finger
 				
 			
 				
+				&INDEX_POINTING_AT_THE_VIEWER.default, // INDEX_POINTING_AT_THE_VIEWER
+				
+			
+				
 				&INDEX_POINTING_UP.default, // INDEX_POINTING_UP
 				
 			
@@ -19312,11 +20611,21 @@ pub static MIDDLE_FINGER: With<Tone,Emoji> = With::new(Emoji::new("middle finger
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // hand_single_finger
 	
 	/// hands 👏🙏🤝
@@ -19335,6 +20644,9 @@ pub static MIDDLE_FINGER: With<Tone,Emoji> = With::new(Emoji::new("middle finger
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "hands";
+
 		// List of all emoji statics
 
 		
@@ -19366,11 +20678,53 @@ pub static FOLDED_HANDS: With<Tone,Emoji> = With::new(Emoji::new("folded hands",
 	]);
 
 		
-		#[doc="handshake 🤝"]#[doc=""]#[doc="Since E3.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+		#[doc="handshake 🤝"]#[doc=""]#[doc="Since E3.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::HANDSHAKE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
-#[doc="println!(\"{}\", HANDSHAKE); // 🤝 (`U+1F91D`)"] #[doc="# assert_eq!(HANDSHAKE.to_string().as_str(), \"🤝\");"]
+#[doc="println!(\"{}\", HANDSHAKE); // 🤝 (`U+1F91D`)"] #[doc="# assert_eq!(HANDSHAKE.to_string().as_str(), \"🤝\");"]#[doc="println!(\"{}\", HANDSHAKE.tone(Tone::Light)); // 🤝🏻 (`U+1F91D U+1F3FB`)"] #[doc="# assert_eq!(HANDSHAKE.tone(Tone::Light).to_string().as_str(), \"🤝🏻\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::Light, Tone::MediumLight))); // 🫱🏻‍🫲🏼 (`U+1FAF1 U+1F3FB U+200D U+1FAF2 U+1F3FC`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::Light, Tone::MediumLight)).to_string().as_str(), \"🫱🏻‍🫲🏼\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::Light, Tone::Medium))); // 🫱🏻‍🫲🏽 (`U+1FAF1 U+1F3FB U+200D U+1FAF2 U+1F3FD`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::Light, Tone::Medium)).to_string().as_str(), \"🫱🏻‍🫲🏽\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::Light, Tone::MediumDark))); // 🫱🏻‍🫲🏾 (`U+1FAF1 U+1F3FB U+200D U+1FAF2 U+1F3FE`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::Light, Tone::MediumDark)).to_string().as_str(), \"🫱🏻‍🫲🏾\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::Light, Tone::Dark))); // 🫱🏻‍🫲🏿 (`U+1FAF1 U+1F3FB U+200D U+1FAF2 U+1F3FF`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::Light, Tone::Dark)).to_string().as_str(), \"🫱🏻‍🫲🏿\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::MediumLight, Tone::Light))); // 🫱🏼‍🫲🏻 (`U+1FAF1 U+1F3FC U+200D U+1FAF2 U+1F3FB`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::MediumLight, Tone::Light)).to_string().as_str(), \"🫱🏼‍🫲🏻\");"]#[doc="println!(\"{}\", HANDSHAKE.tone(Tone::MediumLight)); // 🤝🏼 (`U+1F91D U+1F3FC`)"] #[doc="# assert_eq!(HANDSHAKE.tone(Tone::MediumLight).to_string().as_str(), \"🤝🏼\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::MediumLight, Tone::Medium))); // 🫱🏼‍🫲🏽 (`U+1FAF1 U+1F3FC U+200D U+1FAF2 U+1F3FD`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::MediumLight, Tone::Medium)).to_string().as_str(), \"🫱🏼‍🫲🏽\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::MediumLight, Tone::MediumDark))); // 🫱🏼‍🫲🏾 (`U+1FAF1 U+1F3FC U+200D U+1FAF2 U+1F3FE`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::MediumLight, Tone::MediumDark)).to_string().as_str(), \"🫱🏼‍🫲🏾\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::MediumLight, Tone::Dark))); // 🫱🏼‍🫲🏿 (`U+1FAF1 U+1F3FC U+200D U+1FAF2 U+1F3FF`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::MediumLight, Tone::Dark)).to_string().as_str(), \"🫱🏼‍🫲🏿\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::Medium, Tone::Light))); // 🫱🏽‍🫲🏻 (`U+1FAF1 U+1F3FD U+200D U+1FAF2 U+1F3FB`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::Medium, Tone::Light)).to_string().as_str(), \"🫱🏽‍🫲🏻\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::Medium, Tone::MediumLight))); // 🫱🏽‍🫲🏼 (`U+1FAF1 U+1F3FD U+200D U+1FAF2 U+1F3FC`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::Medium, Tone::MediumLight)).to_string().as_str(), \"🫱🏽‍🫲🏼\");"]#[doc="println!(\"{}\", HANDSHAKE.tone(Tone::Medium)); // 🤝🏽 (`U+1F91D U+1F3FD`)"] #[doc="# assert_eq!(HANDSHAKE.tone(Tone::Medium).to_string().as_str(), \"🤝🏽\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::Medium, Tone::MediumDark))); // 🫱🏽‍🫲🏾 (`U+1FAF1 U+1F3FD U+200D U+1FAF2 U+1F3FE`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::Medium, Tone::MediumDark)).to_string().as_str(), \"🫱🏽‍🫲🏾\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::Medium, Tone::Dark))); // 🫱🏽‍🫲🏿 (`U+1FAF1 U+1F3FD U+200D U+1FAF2 U+1F3FF`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::Medium, Tone::Dark)).to_string().as_str(), \"🫱🏽‍🫲🏿\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::MediumDark, Tone::Light))); // 🫱🏾‍🫲🏻 (`U+1FAF1 U+1F3FE U+200D U+1FAF2 U+1F3FB`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::MediumDark, Tone::Light)).to_string().as_str(), \"🫱🏾‍🫲🏻\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::MediumDark, Tone::MediumLight))); // 🫱🏾‍🫲🏼 (`U+1FAF1 U+1F3FE U+200D U+1FAF2 U+1F3FC`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::MediumDark, Tone::MediumLight)).to_string().as_str(), \"🫱🏾‍🫲🏼\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::MediumDark, Tone::Medium))); // 🫱🏾‍🫲🏽 (`U+1FAF1 U+1F3FE U+200D U+1FAF2 U+1F3FD`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::MediumDark, Tone::Medium)).to_string().as_str(), \"🫱🏾‍🫲🏽\");"]#[doc="println!(\"{}\", HANDSHAKE.tone(Tone::MediumDark)); // 🤝🏾 (`U+1F91D U+1F3FE`)"] #[doc="# assert_eq!(HANDSHAKE.tone(Tone::MediumDark).to_string().as_str(), \"🤝🏾\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::MediumDark, Tone::Dark))); // 🫱🏾‍🫲🏿 (`U+1FAF1 U+1F3FE U+200D U+1FAF2 U+1F3FF`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::MediumDark, Tone::Dark)).to_string().as_str(), \"🫱🏾‍🫲🏿\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::Dark, Tone::Light))); // 🫱🏿‍🫲🏻 (`U+1FAF1 U+1F3FF U+200D U+1FAF2 U+1F3FB`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::Dark, Tone::Light)).to_string().as_str(), \"🫱🏿‍🫲🏻\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::Dark, Tone::MediumLight))); // 🫱🏿‍🫲🏼 (`U+1FAF1 U+1F3FF U+200D U+1FAF2 U+1F3FC`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::Dark, Tone::MediumLight)).to_string().as_str(), \"🫱🏿‍🫲🏼\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::Dark, Tone::Medium))); // 🫱🏿‍🫲🏽 (`U+1FAF1 U+1F3FF U+200D U+1FAF2 U+1F3FD`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::Dark, Tone::Medium)).to_string().as_str(), \"🫱🏿‍🫲🏽\");"]#[doc="println!(\"{}\", HANDSHAKE.tone((Tone::Dark, Tone::MediumDark))); // 🫱🏿‍🫲🏾 (`U+1FAF1 U+1F3FF U+200D U+1FAF2 U+1F3FE`)"] #[doc="# assert_eq!(HANDSHAKE.tone((Tone::Dark, Tone::MediumDark)).to_string().as_str(), \"🫱🏿‍🫲🏾\");"]#[doc="println!(\"{}\", HANDSHAKE.tone(Tone::Dark)); // 🤝🏿 (`U+1F91D U+1F3FF`)"] #[doc="# assert_eq!(HANDSHAKE.tone(Tone::Dark).to_string().as_str(), \"🤝🏿\");"]
+#[doc="```"]
+pub static HANDSHAKE: With<TonePair,Emoji> = With::new(Emoji::new("handshake", Version(3, 0),"🤝"), 
+	&[
+		Emoji::new("handshake: light skin tone", Version(14, 0),"🤝🏻"),
+	Emoji::new("handshake: light skin tone, medium-light skin tone", Version(14, 0),"🫱🏻‍🫲🏼"),
+	Emoji::new("handshake: light skin tone, medium skin tone", Version(14, 0),"🫱🏻‍🫲🏽"),
+	Emoji::new("handshake: light skin tone, medium-dark skin tone", Version(14, 0),"🫱🏻‍🫲🏾"),
+	Emoji::new("handshake: light skin tone, dark skin tone", Version(14, 0),"🫱🏻‍🫲🏿"),
+	Emoji::new("handshake: medium-light skin tone, light skin tone", Version(14, 0),"🫱🏼‍🫲🏻"),
+	Emoji::new("handshake: medium-light skin tone", Version(14, 0),"🤝🏼"),
+	Emoji::new("handshake: medium-light skin tone, medium skin tone", Version(14, 0),"🫱🏼‍🫲🏽"),
+	Emoji::new("handshake: medium-light skin tone, medium-dark skin tone", Version(14, 0),"🫱🏼‍🫲🏾"),
+	Emoji::new("handshake: medium-light skin tone, dark skin tone", Version(14, 0),"🫱🏼‍🫲🏿"),
+	Emoji::new("handshake: medium skin tone, light skin tone", Version(14, 0),"🫱🏽‍🫲🏻"),
+	Emoji::new("handshake: medium skin tone, medium-light skin tone", Version(14, 0),"🫱🏽‍🫲🏼"),
+	Emoji::new("handshake: medium skin tone", Version(14, 0),"🤝🏽"),
+	Emoji::new("handshake: medium skin tone, medium-dark skin tone", Version(14, 0),"🫱🏽‍🫲🏾"),
+	Emoji::new("handshake: medium skin tone, dark skin tone", Version(14, 0),"🫱🏽‍🫲🏿"),
+	Emoji::new("handshake: medium-dark skin tone, light skin tone", Version(14, 0),"🫱🏾‍🫲🏻"),
+	Emoji::new("handshake: medium-dark skin tone, medium-light skin tone", Version(14, 0),"🫱🏾‍🫲🏼"),
+	Emoji::new("handshake: medium-dark skin tone, medium skin tone", Version(14, 0),"🫱🏾‍🫲🏽"),
+	Emoji::new("handshake: medium-dark skin tone", Version(14, 0),"🤝🏾"),
+	Emoji::new("handshake: medium-dark skin tone, dark skin tone", Version(14, 0),"🫱🏾‍🫲🏿"),
+	Emoji::new("handshake: dark skin tone, light skin tone", Version(14, 0),"🫱🏿‍🫲🏻"),
+	Emoji::new("handshake: dark skin tone, medium-light skin tone", Version(14, 0),"🫱🏿‍🫲🏼"),
+	Emoji::new("handshake: dark skin tone, medium skin tone", Version(14, 0),"🫱🏿‍🫲🏽"),
+	Emoji::new("handshake: dark skin tone, medium-dark skin tone", Version(14, 0),"🫱🏿‍🫲🏾"),
+	Emoji::new("handshake: dark skin tone", Version(14, 0),"🤝🏿"),
+	]);
+
+		
+		#[doc="heart hands 🫶"]#[doc=""]#[doc="Since E14.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::HEART_HANDS;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", HEART_HANDS); // 🫶 (`U+1FAF6`)"] #[doc="# assert_eq!(HEART_HANDS.to_string().as_str(), \"🫶\");"]#[doc="println!(\"{}\", HEART_HANDS.tone(Tone::Light)); // 🫶🏻 (`U+1FAF6 U+1F3FB`)"] #[doc="# assert_eq!(HEART_HANDS.tone(Tone::Light).to_string().as_str(), \"🫶🏻\");"]#[doc="println!(\"{}\", HEART_HANDS.tone(Tone::MediumLight)); // 🫶🏼 (`U+1FAF6 U+1F3FC`)"] #[doc="# assert_eq!(HEART_HANDS.tone(Tone::MediumLight).to_string().as_str(), \"🫶🏼\");"]#[doc="println!(\"{}\", HEART_HANDS.tone(Tone::Medium)); // 🫶🏽 (`U+1FAF6 U+1F3FD`)"] #[doc="# assert_eq!(HEART_HANDS.tone(Tone::Medium).to_string().as_str(), \"🫶🏽\");"]#[doc="println!(\"{}\", HEART_HANDS.tone(Tone::MediumDark)); // 🫶🏾 (`U+1FAF6 U+1F3FE`)"] #[doc="# assert_eq!(HEART_HANDS.tone(Tone::MediumDark).to_string().as_str(), \"🫶🏾\");"]#[doc="println!(\"{}\", HEART_HANDS.tone(Tone::Dark)); // 🫶🏿 (`U+1FAF6 U+1F3FF`)"] #[doc="# assert_eq!(HEART_HANDS.tone(Tone::Dark).to_string().as_str(), \"🫶🏿\");"]
 #[doc="```"]
-pub static HANDSHAKE :  Emoji = Emoji::new("handshake", Version(3, 0), "🤝"); // handshake
+pub static HEART_HANDS: With<Tone,Emoji> = With::new(Emoji::new("heart hands", Version(14, 0),"🫶"), 
+	&[
+		Emoji::new("heart hands: light skin tone", Version(14, 0),"🫶🏻"),
+	Emoji::new("heart hands: medium-light skin tone", Version(14, 0),"🫶🏼"),
+	Emoji::new("heart hands: medium skin tone", Version(14, 0),"🫶🏽"),
+	Emoji::new("heart hands: medium-dark skin tone", Version(14, 0),"🫶🏾"),
+	Emoji::new("heart hands: dark skin tone", Version(14, 0),"🫶🏿"),
+	]);
+
 		
 		#[doc="open hands 👐"]#[doc=""]#[doc="Since E0.6"]#[doc=""] #[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::OPEN_HANDS;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
@@ -19419,7 +20773,7 @@ pub static RAISING_HANDS: With<Tone,Emoji> = With::new(Emoji::new("raising hands
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // CLAPPING_HANDS
 				
@@ -19455,7 +20809,73 @@ pub static RAISING_HANDS: With<Tone,Emoji> = With::new(Emoji::new("raising hands
 			
 			&[ // HANDSHAKE
 				
-				&HANDSHAKE,
+				&HANDSHAKE.default,
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Light, right: Tone::Light }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Light, right: Tone::MediumLight }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Light, right: Tone::Medium }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Light, right: Tone::MediumDark }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Light, right: Tone::Dark }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::MediumLight, right: Tone::Light }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::MediumLight, right: Tone::MediumLight }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::MediumLight, right: Tone::Medium }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::MediumLight, right: Tone::MediumDark }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::MediumLight, right: Tone::Dark }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Medium, right: Tone::Light }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Medium, right: Tone::MediumLight }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Medium, right: Tone::Medium }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Medium, right: Tone::MediumDark }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Medium, right: Tone::Dark }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::MediumDark, right: Tone::Light }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::MediumDark, right: Tone::MediumLight }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::MediumDark, right: Tone::Medium }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::MediumDark, right: Tone::MediumDark }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::MediumDark, right: Tone::Dark }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Dark, right: Tone::Light }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Dark, right: Tone::MediumLight }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Dark, right: Tone::Medium }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Dark, right: Tone::MediumDark }),
+				
+				&HANDSHAKE.tone_pair(TonePair{left: Tone::Dark, right: Tone::Dark }),
+				
+			],
+			
+			&[ // HEART_HANDS
+				
+				&HEART_HANDS.default,
+				
+				&HEART_HANDS.tone(Tone::Light),
+				
+				&HEART_HANDS.tone(Tone::MediumLight),
+				
+				&HEART_HANDS.tone(Tone::Medium),
+				
+				&HEART_HANDS.tone(Tone::MediumDark),
+				
+				&HEART_HANDS.tone(Tone::Dark),
 				
 			],
 			
@@ -19510,7 +20930,7 @@ pub static RAISING_HANDS: With<Tone,Emoji> = With::new(Emoji::new("raising hands
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&CLAPPING_HANDS.default, // CLAPPING_HANDS
@@ -19521,7 +20941,11 @@ pub static RAISING_HANDS: With<Tone,Emoji> = With::new(Emoji::new("raising hands
 				
 			
 				
-				&HANDSHAKE, // HANDSHAKE
+				&HANDSHAKE.default, // HANDSHAKE
+				
+			
+				
+				&HEART_HANDS.default, // HEART_HANDS
 				
 			
 				
@@ -19546,11 +20970,21 @@ pub static RAISING_HANDS: With<Tone,Emoji> = With::new(Emoji::new("raising hands
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // hands
 	
 	/// person 👶👦🧒
@@ -19569,6 +21003,9 @@ pub static RAISING_HANDS: With<Tone,Emoji> = With::new(Emoji::new("raising hands
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "person";
+
 		// List of all emoji statics
 
 		
@@ -19862,7 +21299,7 @@ pub static PERSON: With<Hair,With<Gender,With<Tone,Emoji>>> = With::new(With::ne
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BABY
 				
@@ -20231,7 +21668,7 @@ pub static PERSON: With<Hair,With<Gender,With<Tone,Emoji>>> = With::new(With::ne
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BABY.default, // BABY
@@ -20273,11 +21710,21 @@ pub static PERSON: With<Hair,With<Gender,With<Tone,Emoji>>> = With::new(With::ne
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // person
 	
 	/// person_activity 🧗💇💆
@@ -20296,6 +21743,9 @@ pub static PERSON: With<Hair,With<Gender,With<Tone,Emoji>>> = With::new(With::ne
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "person-activity";
+
 		// List of all emoji statics
 
 		
@@ -20706,7 +22156,7 @@ pub static PERSON_WITH_WHITE_CANE: With<Gender,With<Tone,Emoji>> = With::new(Wit
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // PERSON_CLIMBING
 				
@@ -21205,7 +22655,7 @@ pub static PERSON_WITH_WHITE_CANE: With<Gender,With<Tone,Emoji>> = With::new(Wit
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&PERSON_CLIMBING.default.default, // PERSON_CLIMBING
@@ -21275,11 +22725,21 @@ pub static PERSON_WITH_WHITE_CANE: With<Gender,With<Tone,Emoji>> = With::new(Wit
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // person_activity
 	
 	/// person_fantasy 👼🧝🧚
@@ -21298,6 +22758,9 @@ pub static PERSON_WITH_WHITE_CANE: With<Gender,With<Tone,Emoji>> = With::new(Wit
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "person-fantasy";
+
 		// List of all emoji statics
 
 		
@@ -21570,6 +23033,12 @@ pub static SUPERVILLAIN: With<Gender,With<Tone,Emoji>> = With::new(With::new(Emo
 	]);
 
 		
+		#[doc="troll 🧌"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::TROLL;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", TROLL); // 🧌 (`U+1F9CC`)"] #[doc="# assert_eq!(TROLL.to_string().as_str(), \"🧌\");"]
+#[doc="```"]
+pub static TROLL :  Emoji = Emoji::new("troll", Version(14, 0), "🧌"); // troll
+		
 		#[doc="vampire 🧛"]#[doc=""]#[doc="Since E5.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::VAMPIRE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", VAMPIRE); // 🧛 (`U+1F9DB`)"] #[doc="# assert_eq!(VAMPIRE.to_string().as_str(), \"🧛\");"]#[doc="println!(\"{}\", VAMPIRE.tone(Tone::Light)); // 🧛🏻 (`U+1F9DB U+1F3FB`)"] #[doc="# assert_eq!(VAMPIRE.tone(Tone::Light).to_string().as_str(), \"🧛🏻\");"]#[doc="println!(\"{}\", VAMPIRE.tone(Tone::MediumLight)); // 🧛🏼 (`U+1F9DB U+1F3FC`)"] #[doc="# assert_eq!(VAMPIRE.tone(Tone::MediumLight).to_string().as_str(), \"🧛🏼\");"]#[doc="println!(\"{}\", VAMPIRE.tone(Tone::Medium)); // 🧛🏽 (`U+1F9DB U+1F3FD`)"] #[doc="# assert_eq!(VAMPIRE.tone(Tone::Medium).to_string().as_str(), \"🧛🏽\");"]#[doc="println!(\"{}\", VAMPIRE.tone(Tone::MediumDark)); // 🧛🏾 (`U+1F9DB U+1F3FE`)"] #[doc="# assert_eq!(VAMPIRE.tone(Tone::MediumDark).to_string().as_str(), \"🧛🏾\");"]#[doc="println!(\"{}\", VAMPIRE.tone(Tone::Dark)); // 🧛🏿 (`U+1F9DB U+1F3FF`)"] #[doc="# assert_eq!(VAMPIRE.tone(Tone::Dark).to_string().as_str(), \"🧛🏿\");"]#[doc="println!(\"{}\", VAMPIRE.gender(Gender::Male)); // 🧛‍♂️ (`U+1F9DB U+200D U+2642 U+FE0F`)"] #[doc="# assert_eq!(VAMPIRE.gender(Gender::Male).to_string().as_str(), \"🧛‍♂️\");"]#[doc="println!(\"{}\", VAMPIRE.gender(Gender::Male).tone(Tone::Light)); // 🧛🏻‍♂️ (`U+1F9DB U+1F3FB U+200D U+2642 U+FE0F`)"] #[doc="# assert_eq!(VAMPIRE.gender(Gender::Male).tone(Tone::Light).to_string().as_str(), \"🧛🏻‍♂️\");"]#[doc="println!(\"{}\", VAMPIRE.gender(Gender::Male).tone(Tone::MediumLight)); // 🧛🏼‍♂️ (`U+1F9DB U+1F3FC U+200D U+2642 U+FE0F`)"] #[doc="# assert_eq!(VAMPIRE.gender(Gender::Male).tone(Tone::MediumLight).to_string().as_str(), \"🧛🏼‍♂️\");"]#[doc="println!(\"{}\", VAMPIRE.gender(Gender::Male).tone(Tone::Medium)); // 🧛🏽‍♂️ (`U+1F9DB U+1F3FD U+200D U+2642 U+FE0F`)"] #[doc="# assert_eq!(VAMPIRE.gender(Gender::Male).tone(Tone::Medium).to_string().as_str(), \"🧛🏽‍♂️\");"]#[doc="println!(\"{}\", VAMPIRE.gender(Gender::Male).tone(Tone::MediumDark)); // 🧛🏾‍♂️ (`U+1F9DB U+1F3FE U+200D U+2642 U+FE0F`)"] #[doc="# assert_eq!(VAMPIRE.gender(Gender::Male).tone(Tone::MediumDark).to_string().as_str(), \"🧛🏾‍♂️\");"]#[doc="println!(\"{}\", VAMPIRE.gender(Gender::Male).tone(Tone::Dark)); // 🧛🏿‍♂️ (`U+1F9DB U+1F3FF U+200D U+2642 U+FE0F`)"] #[doc="# assert_eq!(VAMPIRE.gender(Gender::Male).tone(Tone::Dark).to_string().as_str(), \"🧛🏿‍♂️\");"]#[doc="println!(\"{}\", VAMPIRE.gender(Gender::Female)); // 🧛‍♀️ (`U+1F9DB U+200D U+2640 U+FE0F`)"] #[doc="# assert_eq!(VAMPIRE.gender(Gender::Female).to_string().as_str(), \"🧛‍♀️\");"]#[doc="println!(\"{}\", VAMPIRE.gender(Gender::Female).tone(Tone::Light)); // 🧛🏻‍♀️ (`U+1F9DB U+1F3FB U+200D U+2640 U+FE0F`)"] #[doc="# assert_eq!(VAMPIRE.gender(Gender::Female).tone(Tone::Light).to_string().as_str(), \"🧛🏻‍♀️\");"]#[doc="println!(\"{}\", VAMPIRE.gender(Gender::Female).tone(Tone::MediumLight)); // 🧛🏼‍♀️ (`U+1F9DB U+1F3FC U+200D U+2640 U+FE0F`)"] #[doc="# assert_eq!(VAMPIRE.gender(Gender::Female).tone(Tone::MediumLight).to_string().as_str(), \"🧛🏼‍♀️\");"]#[doc="println!(\"{}\", VAMPIRE.gender(Gender::Female).tone(Tone::Medium)); // 🧛🏽‍♀️ (`U+1F9DB U+1F3FD U+200D U+2640 U+FE0F`)"] #[doc="# assert_eq!(VAMPIRE.gender(Gender::Female).tone(Tone::Medium).to_string().as_str(), \"🧛🏽‍♀️\");"]#[doc="println!(\"{}\", VAMPIRE.gender(Gender::Female).tone(Tone::MediumDark)); // 🧛🏾‍♀️ (`U+1F9DB U+1F3FE U+200D U+2640 U+FE0F`)"] #[doc="# assert_eq!(VAMPIRE.gender(Gender::Female).tone(Tone::MediumDark).to_string().as_str(), \"🧛🏾‍♀️\");"]#[doc="println!(\"{}\", VAMPIRE.gender(Gender::Female).tone(Tone::Dark)); // 🧛🏿‍♀️ (`U+1F9DB U+1F3FF U+200D U+2640 U+FE0F`)"] #[doc="# assert_eq!(VAMPIRE.gender(Gender::Female).tone(Tone::Dark).to_string().as_str(), \"🧛🏿‍♀️\");"]
@@ -21618,7 +23087,7 @@ pub static ZOMBIE: With<Gender,Emoji> = With::new(Emoji::new("zombie", Version(5
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BABY_ANGEL
 				
@@ -21942,6 +23411,12 @@ pub static ZOMBIE: With<Gender,Emoji> = With::new(Emoji::new("zombie", Version(5
 				
 			],
 			
+			&[ // TROLL
+				
+				&TROLL,
+				
+			],
+			
 			&[ // VAMPIRE
 				
 				&VAMPIRE.default.default,
@@ -21995,7 +23470,7 @@ pub static ZOMBIE: With<Gender,Emoji> = With::new(Emoji::new("zombie", Version(5
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BABY_ANGEL.default, // BABY_ANGEL
@@ -22050,6 +23525,10 @@ pub static ZOMBIE: With<Gender,Emoji> = With::new(Emoji::new("zombie", Version(5
 				
 			
 				
+				&TROLL, // TROLL
+				
+			
+				
 				&VAMPIRE.default.default, // VAMPIRE
 				
 			
@@ -22067,11 +23546,21 @@ pub static ZOMBIE: With<Gender,Emoji> = With::new(Emoji::new("zombie", Version(5
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // person_fantasy
 	
 	/// person_gesture 🧏🙇🤦
@@ -22090,6 +23579,9 @@ pub static ZOMBIE: With<Gender,Emoji> = With::new(Emoji::new("zombie", Version(5
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "person-gesture";
+
 		// List of all emoji statics
 
 		
@@ -22418,7 +23910,7 @@ pub static PERSON_TIPPING_HAND: With<Gender,With<Tone,Emoji>> = With::new(With::
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // DEAF_PERSON
 				
@@ -22823,7 +24315,7 @@ pub static PERSON_TIPPING_HAND: With<Gender,With<Tone,Emoji>> = With::new(With::
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&DEAF_PERSON.default.default, // DEAF_PERSON
@@ -22875,11 +24367,21 @@ pub static PERSON_TIPPING_HAND: With<Gender,With<Tone,Emoji>> = With::new(With::
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // person_gesture
 	
 	/// person_resting 🛌🧘🛀
@@ -22898,6 +24400,9 @@ pub static PERSON_TIPPING_HAND: With<Gender,With<Tone,Emoji>> = With::new(With::
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "person-resting";
+
 		// List of all emoji statics
 
 		
@@ -22966,7 +24471,7 @@ pub static PERSON_TAKING_BATH: With<Tone,Emoji> = With::new(Emoji::new("person t
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // PERSON_IN_BED
 				
@@ -23043,7 +24548,7 @@ pub static PERSON_TAKING_BATH: With<Tone,Emoji> = With::new(Emoji::new("person t
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&PERSON_IN_BED.default, // PERSON_IN_BED
@@ -23067,11 +24572,21 @@ pub static PERSON_TAKING_BATH: With<Tone,Emoji> = With::new(Emoji::new("person t
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // person_resting
 	
 	/// person_role 🧑‍🎨🧑‍🚀🤱
@@ -23090,6 +24605,9 @@ pub static PERSON_TAKING_BATH: With<Tone,Emoji> = With::new(Emoji::new("person t
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "person-role";
+
 		// List of all emoji statics
 
 		
@@ -23633,6 +25151,20 @@ pub static PERSON_WEARING_TURBAN: With<Gender,With<Tone,Emoji>> = With::new(With
 	]);
 
 		
+		#[doc="person with crown 🫅"]#[doc=""]#[doc="Since E14.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::PERSON_WITH_CROWN;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", PERSON_WITH_CROWN); // 🫅 (`U+1FAC5`)"] #[doc="# assert_eq!(PERSON_WITH_CROWN.to_string().as_str(), \"🫅\");"]#[doc="println!(\"{}\", PERSON_WITH_CROWN.tone(Tone::Light)); // 🫅🏻 (`U+1FAC5 U+1F3FB`)"] #[doc="# assert_eq!(PERSON_WITH_CROWN.tone(Tone::Light).to_string().as_str(), \"🫅🏻\");"]#[doc="println!(\"{}\", PERSON_WITH_CROWN.tone(Tone::MediumLight)); // 🫅🏼 (`U+1FAC5 U+1F3FC`)"] #[doc="# assert_eq!(PERSON_WITH_CROWN.tone(Tone::MediumLight).to_string().as_str(), \"🫅🏼\");"]#[doc="println!(\"{}\", PERSON_WITH_CROWN.tone(Tone::Medium)); // 🫅🏽 (`U+1FAC5 U+1F3FD`)"] #[doc="# assert_eq!(PERSON_WITH_CROWN.tone(Tone::Medium).to_string().as_str(), \"🫅🏽\");"]#[doc="println!(\"{}\", PERSON_WITH_CROWN.tone(Tone::MediumDark)); // 🫅🏾 (`U+1FAC5 U+1F3FE`)"] #[doc="# assert_eq!(PERSON_WITH_CROWN.tone(Tone::MediumDark).to_string().as_str(), \"🫅🏾\");"]#[doc="println!(\"{}\", PERSON_WITH_CROWN.tone(Tone::Dark)); // 🫅🏿 (`U+1FAC5 U+1F3FF`)"] #[doc="# assert_eq!(PERSON_WITH_CROWN.tone(Tone::Dark).to_string().as_str(), \"🫅🏿\");"]
+#[doc="```"]
+pub static PERSON_WITH_CROWN: With<Tone,Emoji> = With::new(Emoji::new("person with crown", Version(14, 0),"🫅"), 
+	&[
+		Emoji::new("person with crown: light skin tone", Version(14, 0),"🫅🏻"),
+	Emoji::new("person with crown: medium-light skin tone", Version(14, 0),"🫅🏼"),
+	Emoji::new("person with crown: medium skin tone", Version(14, 0),"🫅🏽"),
+	Emoji::new("person with crown: medium-dark skin tone", Version(14, 0),"🫅🏾"),
+	Emoji::new("person with crown: dark skin tone", Version(14, 0),"🫅🏿"),
+	]);
+
+		
 		#[doc="person with skullcap 👲"]#[doc=""]#[doc="Since E0.6"]#[doc=""] #[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::PERSON_WITH_SKULLCAP;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", PERSON_WITH_SKULLCAP); // 👲 (`U+1F472`)"] #[doc="# assert_eq!(PERSON_WITH_SKULLCAP.to_string().as_str(), \"👲\");"]#[doc="println!(\"{}\", PERSON_WITH_SKULLCAP.tone(Tone::Light)); // 👲🏻 (`U+1F472 U+1F3FB`)"] #[doc="# assert_eq!(PERSON_WITH_SKULLCAP.tone(Tone::Light).to_string().as_str(), \"👲🏻\");"]#[doc="println!(\"{}\", PERSON_WITH_SKULLCAP.tone(Tone::MediumLight)); // 👲🏼 (`U+1F472 U+1F3FC`)"] #[doc="# assert_eq!(PERSON_WITH_SKULLCAP.tone(Tone::MediumLight).to_string().as_str(), \"👲🏼\");"]#[doc="println!(\"{}\", PERSON_WITH_SKULLCAP.tone(Tone::Medium)); // 👲🏽 (`U+1F472 U+1F3FD`)"] #[doc="# assert_eq!(PERSON_WITH_SKULLCAP.tone(Tone::Medium).to_string().as_str(), \"👲🏽\");"]#[doc="println!(\"{}\", PERSON_WITH_SKULLCAP.tone(Tone::MediumDark)); // 👲🏾 (`U+1F472 U+1F3FE`)"] #[doc="# assert_eq!(PERSON_WITH_SKULLCAP.tone(Tone::MediumDark).to_string().as_str(), \"👲🏾\");"]#[doc="println!(\"{}\", PERSON_WITH_SKULLCAP.tone(Tone::Dark)); // 👲🏿 (`U+1F472 U+1F3FF`)"] #[doc="# assert_eq!(PERSON_WITH_SKULLCAP.tone(Tone::Dark).to_string().as_str(), \"👲🏿\");"]
@@ -23743,17 +25275,35 @@ pub static POLICE_OFFICER: With<Gender,With<Tone,Emoji>> = With::new(With::new(E
 	]);
 
 		
-		#[doc="pregnant person 🤰"]#[doc=""]#[doc="Since E3.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
-#[doc="use emojic::flat::PREGNANT_WOMAN;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
-#[doc="println!(\"{}\", PREGNANT_WOMAN); // 🤰 (`U+1F930`)"] #[doc="# assert_eq!(PREGNANT_WOMAN.to_string().as_str(), \"🤰\");"]#[doc="println!(\"{}\", PREGNANT_WOMAN.tone(Tone::Light)); // 🤰🏻 (`U+1F930 U+1F3FB`)"] #[doc="# assert_eq!(PREGNANT_WOMAN.tone(Tone::Light).to_string().as_str(), \"🤰🏻\");"]#[doc="println!(\"{}\", PREGNANT_WOMAN.tone(Tone::MediumLight)); // 🤰🏼 (`U+1F930 U+1F3FC`)"] #[doc="# assert_eq!(PREGNANT_WOMAN.tone(Tone::MediumLight).to_string().as_str(), \"🤰🏼\");"]#[doc="println!(\"{}\", PREGNANT_WOMAN.tone(Tone::Medium)); // 🤰🏽 (`U+1F930 U+1F3FD`)"] #[doc="# assert_eq!(PREGNANT_WOMAN.tone(Tone::Medium).to_string().as_str(), \"🤰🏽\");"]#[doc="println!(\"{}\", PREGNANT_WOMAN.tone(Tone::MediumDark)); // 🤰🏾 (`U+1F930 U+1F3FE`)"] #[doc="# assert_eq!(PREGNANT_WOMAN.tone(Tone::MediumDark).to_string().as_str(), \"🤰🏾\");"]#[doc="println!(\"{}\", PREGNANT_WOMAN.tone(Tone::Dark)); // 🤰🏿 (`U+1F930 U+1F3FF`)"] #[doc="# assert_eq!(PREGNANT_WOMAN.tone(Tone::Dark).to_string().as_str(), \"🤰🏿\");"]
+		#[doc="pregnant person 🫄"]#[doc=""]#[doc="Since E3.0"]#[doc=""] #[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::PREGNANT_PERSON;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", PREGNANT_PERSON); // 🫄 (`U+1FAC4`)"] #[doc="# assert_eq!(PREGNANT_PERSON.to_string().as_str(), \"🫄\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.tone(Tone::Light)); // 🫄🏻 (`U+1FAC4 U+1F3FB`)"] #[doc="# assert_eq!(PREGNANT_PERSON.tone(Tone::Light).to_string().as_str(), \"🫄🏻\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.tone(Tone::MediumLight)); // 🫄🏼 (`U+1FAC4 U+1F3FC`)"] #[doc="# assert_eq!(PREGNANT_PERSON.tone(Tone::MediumLight).to_string().as_str(), \"🫄🏼\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.tone(Tone::Medium)); // 🫄🏽 (`U+1FAC4 U+1F3FD`)"] #[doc="# assert_eq!(PREGNANT_PERSON.tone(Tone::Medium).to_string().as_str(), \"🫄🏽\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.tone(Tone::MediumDark)); // 🫄🏾 (`U+1FAC4 U+1F3FE`)"] #[doc="# assert_eq!(PREGNANT_PERSON.tone(Tone::MediumDark).to_string().as_str(), \"🫄🏾\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.tone(Tone::Dark)); // 🫄🏿 (`U+1FAC4 U+1F3FF`)"] #[doc="# assert_eq!(PREGNANT_PERSON.tone(Tone::Dark).to_string().as_str(), \"🫄🏿\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.gender(Gender::Male)); // 🫃 (`U+1FAC3`)"] #[doc="# assert_eq!(PREGNANT_PERSON.gender(Gender::Male).to_string().as_str(), \"🫃\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.gender(Gender::Male).tone(Tone::Light)); // 🫃🏻 (`U+1FAC3 U+1F3FB`)"] #[doc="# assert_eq!(PREGNANT_PERSON.gender(Gender::Male).tone(Tone::Light).to_string().as_str(), \"🫃🏻\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.gender(Gender::Male).tone(Tone::MediumLight)); // 🫃🏼 (`U+1FAC3 U+1F3FC`)"] #[doc="# assert_eq!(PREGNANT_PERSON.gender(Gender::Male).tone(Tone::MediumLight).to_string().as_str(), \"🫃🏼\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.gender(Gender::Male).tone(Tone::Medium)); // 🫃🏽 (`U+1FAC3 U+1F3FD`)"] #[doc="# assert_eq!(PREGNANT_PERSON.gender(Gender::Male).tone(Tone::Medium).to_string().as_str(), \"🫃🏽\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.gender(Gender::Male).tone(Tone::MediumDark)); // 🫃🏾 (`U+1FAC3 U+1F3FE`)"] #[doc="# assert_eq!(PREGNANT_PERSON.gender(Gender::Male).tone(Tone::MediumDark).to_string().as_str(), \"🫃🏾\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.gender(Gender::Male).tone(Tone::Dark)); // 🫃🏿 (`U+1FAC3 U+1F3FF`)"] #[doc="# assert_eq!(PREGNANT_PERSON.gender(Gender::Male).tone(Tone::Dark).to_string().as_str(), \"🫃🏿\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.gender(Gender::Female)); // 🤰 (`U+1F930`)"] #[doc="# assert_eq!(PREGNANT_PERSON.gender(Gender::Female).to_string().as_str(), \"🤰\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.gender(Gender::Female).tone(Tone::Light)); // 🤰🏻 (`U+1F930 U+1F3FB`)"] #[doc="# assert_eq!(PREGNANT_PERSON.gender(Gender::Female).tone(Tone::Light).to_string().as_str(), \"🤰🏻\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.gender(Gender::Female).tone(Tone::MediumLight)); // 🤰🏼 (`U+1F930 U+1F3FC`)"] #[doc="# assert_eq!(PREGNANT_PERSON.gender(Gender::Female).tone(Tone::MediumLight).to_string().as_str(), \"🤰🏼\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.gender(Gender::Female).tone(Tone::Medium)); // 🤰🏽 (`U+1F930 U+1F3FD`)"] #[doc="# assert_eq!(PREGNANT_PERSON.gender(Gender::Female).tone(Tone::Medium).to_string().as_str(), \"🤰🏽\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.gender(Gender::Female).tone(Tone::MediumDark)); // 🤰🏾 (`U+1F930 U+1F3FE`)"] #[doc="# assert_eq!(PREGNANT_PERSON.gender(Gender::Female).tone(Tone::MediumDark).to_string().as_str(), \"🤰🏾\");"]#[doc="println!(\"{}\", PREGNANT_PERSON.gender(Gender::Female).tone(Tone::Dark)); // 🤰🏿 (`U+1F930 U+1F3FF`)"] #[doc="# assert_eq!(PREGNANT_PERSON.gender(Gender::Female).tone(Tone::Dark).to_string().as_str(), \"🤰🏿\");"]
 #[doc="```"]
-pub static PREGNANT_WOMAN: With<Tone,Emoji> = With::new(Emoji::new("pregnant woman", Version(3, 0),"🤰"), 
+pub static PREGNANT_PERSON: With<Gender,With<Tone,Emoji>> = With::new(With::new(Emoji::new("pregnant person", Version(14, 0),"🫄"), 
+	&[
+		Emoji::new("pregnant person: light skin tone", Version(14, 0),"🫄🏻"),
+	Emoji::new("pregnant person: medium-light skin tone", Version(14, 0),"🫄🏼"),
+	Emoji::new("pregnant person: medium skin tone", Version(14, 0),"🫄🏽"),
+	Emoji::new("pregnant person: medium-dark skin tone", Version(14, 0),"🫄🏾"),
+	Emoji::new("pregnant person: dark skin tone", Version(14, 0),"🫄🏿"),
+	]), 
+	&[
+		With::new(Emoji::new("pregnant man", Version(14, 0),"🫃"), 
+	&[
+		Emoji::new("pregnant man: light skin tone", Version(14, 0),"🫃🏻"),
+	Emoji::new("pregnant man: medium-light skin tone", Version(14, 0),"🫃🏼"),
+	Emoji::new("pregnant man: medium skin tone", Version(14, 0),"🫃🏽"),
+	Emoji::new("pregnant man: medium-dark skin tone", Version(14, 0),"🫃🏾"),
+	Emoji::new("pregnant man: dark skin tone", Version(14, 0),"🫃🏿"),
+	]),
+	With::new(Emoji::new("pregnant woman", Version(3, 0),"🤰"), 
 	&[
 		Emoji::new("pregnant woman: light skin tone", Version(3, 0),"🤰🏻"),
 	Emoji::new("pregnant woman: medium-light skin tone", Version(3, 0),"🤰🏼"),
 	Emoji::new("pregnant woman: medium skin tone", Version(3, 0),"🤰🏽"),
 	Emoji::new("pregnant woman: medium-dark skin tone", Version(3, 0),"🤰🏾"),
 	Emoji::new("pregnant woman: dark skin tone", Version(3, 0),"🤰🏿"),
+	]),
 	]);
 
 		
@@ -23964,7 +25514,7 @@ pub static WOMAN_WITH_HEADSCARF: With<Tone,Emoji> = With::new(Emoji::new("woman
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ARTIST
 				
@@ -24638,6 +26188,22 @@ pub static WOMAN_WITH_HEADSCARF: With<Tone,Emoji> = With::new(Emoji::new("woman
 				
 			],
 			
+			&[ // PERSON_WITH_CROWN
+				
+				&PERSON_WITH_CROWN.default,
+				
+				&PERSON_WITH_CROWN.tone(Tone::Light),
+				
+				&PERSON_WITH_CROWN.tone(Tone::MediumLight),
+				
+				&PERSON_WITH_CROWN.tone(Tone::Medium),
+				
+				&PERSON_WITH_CROWN.tone(Tone::MediumDark),
+				
+				&PERSON_WITH_CROWN.tone(Tone::Dark),
+				
+			],
+			
 			&[ // PERSON_WITH_SKULLCAP
 				
 				&PERSON_WITH_SKULLCAP.default,
@@ -24774,19 +26340,43 @@ pub static WOMAN_WITH_HEADSCARF: With<Tone,Emoji> = With::new(Emoji::new("woman
 				
 			],
 			
-			&[ // PREGNANT_WOMAN
+			&[ // PREGNANT_PERSON
+				
+				&PREGNANT_PERSON.default.default,
+				
+				&PREGNANT_PERSON.default.tone(Tone::Light),
+				
+				&PREGNANT_PERSON.default.tone(Tone::MediumLight),
+				
+				&PREGNANT_PERSON.default.tone(Tone::Medium),
+				
+				&PREGNANT_PERSON.default.tone(Tone::MediumDark),
+				
+				&PREGNANT_PERSON.default.tone(Tone::Dark),
+				
+				&PREGNANT_PERSON.gender(Gender::Male).default,
+				
+				&PREGNANT_PERSON.gender(Gender::Male).tone(Tone::Light),
+				
+				&PREGNANT_PERSON.gender(Gender::Male).tone(Tone::MediumLight),
+				
+				&PREGNANT_PERSON.gender(Gender::Male).tone(Tone::Medium),
+				
+				&PREGNANT_PERSON.gender(Gender::Male).tone(Tone::MediumDark),
 				
-				&PREGNANT_WOMAN.default,
+				&PREGNANT_PERSON.gender(Gender::Male).tone(Tone::Dark),
 				
-				&PREGNANT_WOMAN.tone(Tone::Light),
+				&PREGNANT_PERSON.gender(Gender::Female).default,
 				
-				&PREGNANT_WOMAN.tone(Tone::MediumLight),
+				&PREGNANT_PERSON.gender(Gender::Female).tone(Tone::Light),
 				
-				&PREGNANT_WOMAN.tone(Tone::Medium),
+				&PREGNANT_PERSON.gender(Gender::Female).tone(Tone::MediumLight),
 				
-				&PREGNANT_WOMAN.tone(Tone::MediumDark),
+				&PREGNANT_PERSON.gender(Gender::Female).tone(Tone::Medium),
 				
-				&PREGNANT_WOMAN.tone(Tone::Dark),
+				&PREGNANT_PERSON.gender(Gender::Female).tone(Tone::MediumDark),
+				
+				&PREGNANT_PERSON.gender(Gender::Female).tone(Tone::Dark),
 				
 			],
 			
@@ -25041,7 +26631,7 @@ pub static WOMAN_WITH_HEADSCARF: With<Tone,Emoji> = With::new(Emoji::new("woman
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ARTIST.default.default, // ARTIST
@@ -25116,6 +26706,10 @@ pub static WOMAN_WITH_HEADSCARF: With<Tone,Emoji> = With::new(Emoji::new("woman
 				
 			
 				
+				&PERSON_WITH_CROWN.default, // PERSON_WITH_CROWN
+				
+			
+				
 				&PERSON_WITH_SKULLCAP.default, // PERSON_WITH_SKULLCAP
 				
 			
@@ -25132,7 +26726,7 @@ pub static WOMAN_WITH_HEADSCARF: With<Tone,Emoji> = With::new(Emoji::new("woman
 				
 			
 				
-				&PREGNANT_WOMAN.default, // PREGNANT_WOMAN
+				&PREGNANT_PERSON.default.default, // PREGNANT_PERSON
 				
 			
 				
@@ -25177,11 +26771,21 @@ pub static WOMAN_WITH_HEADSCARF: With<Tone,Emoji> = With::new(Emoji::new("woman
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // person_role
 	
 	/// person_sport 🏇🚴⛹️
@@ -25200,6 +26804,9 @@ pub static WOMAN_WITH_HEADSCARF: With<Tone,Emoji> = With::new(Emoji::new("woman
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "person-sport";
+
 		// List of all emoji statics
 
 		
@@ -25644,7 +27251,7 @@ pub static SNOWBOARDER: With<Tone,Emoji> = With::new(Emoji::new("snowboarder", V
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // HORSE_RACING
 				
@@ -26183,7 +27790,7 @@ pub static SNOWBOARDER: With<Tone,Emoji> = With::new(Emoji::new("snowboarder", V
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&HORSE_RACING.default, // HORSE_RACING
@@ -26263,11 +27870,21 @@ pub static SNOWBOARDER: With<Tone,Emoji> = With::new(Emoji::new("snowboarder", V
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // person_sport
 	
 	/// person_symbol 👥👤👣
@@ -26286,6 +27903,9 @@ pub static SNOWBOARDER: With<Tone,Emoji> = With::new(Emoji::new("snowboarder", V
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "person-symbol";
+
 		// List of all emoji statics
 
 		
@@ -26325,7 +27945,7 @@ pub static SPEAKING_HEAD :  Emoji = Emoji::new("speaking head", Version(0, 7), "
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BUSTS_IN_SILHOUETTE
 				
@@ -26360,7 +27980,7 @@ pub static SPEAKING_HEAD :  Emoji = Emoji::new("speaking head", Version(0, 7), "
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BUSTS_IN_SILHOUETTE, // BUSTS_IN_SILHOUETTE
@@ -26392,15 +28012,65 @@ pub static SPEAKING_HEAD :  Emoji = Emoji::new("speaking head", Version(0, 7), "
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // person_symbol
 	
 
 
+	// Subgroup metadata
+
+	/// Contains the metadata of each subgroup of this group.
+	pub(crate) static SUBGROUPS: &[Subgroup] = &[
+		
+		Subgroup::new(body_parts::NAME, body_parts::ALL_VARIANTS, body_parts::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(family::NAME, family::ALL_VARIANTS, family::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(hand_fingers_closed::NAME, hand_fingers_closed::ALL_VARIANTS, hand_fingers_closed::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(hand_fingers_open::NAME, hand_fingers_open::ALL_VARIANTS, hand_fingers_open::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(hand_fingers_partial::NAME, hand_fingers_partial::ALL_VARIANTS, hand_fingers_partial::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(hand_prop::NAME, hand_prop::ALL_VARIANTS, hand_prop::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(hand_single_finger::NAME, hand_single_finger::ALL_VARIANTS, hand_single_finger::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(hands::NAME, hands::ALL_VARIANTS, hands::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(person::NAME, person::ALL_VARIANTS, person::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(person_activity::NAME, person_activity::ALL_VARIANTS, person_activity::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(person_fantasy::NAME, person_fantasy::ALL_VARIANTS, person_fantasy::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(person_gesture::NAME, person_gesture::ALL_VARIANTS, person_gesture::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(person_resting::NAME, person_resting::ALL_VARIANTS, person_resting::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(person_role::NAME, person_role::ALL_VARIANTS, person_role::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(person_sport::NAME, person_sport::ALL_VARIANTS, person_sport::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(person_symbol::NAME, person_symbol::ALL_VARIANTS, person_symbol::ALL_BASE_EMOJIS),
+		
+	];
+
+
 	// Emoji iterators
 
 	/// Returns an iterator over all emoji variants of these subgroups grouped by base emojis
@@ -26441,6 +28111,11 @@ pub static SPEAKING_HEAD :  Emoji = Emoji::new("speaking head", Version(0, 7), "
 		
 	}
 
+	/// Returns an iterator over all emoji variants of these subgroups one by one
+	pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+		all_variants().flat_map(|variants| variants.iter().copied())
+	}
+
 	/// Returns an iterator over all base emojis of these subgroups (i.e. one for each static)
 	pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 		core::iter::empty()
@@ -26482,8 +28157,13 @@ pub static SPEAKING_HEAD :  Emoji = Emoji::new("speaking head", Version(0, 7), "
 } // people_and_body
 
 /// smileys_and_emotion 😹💢😘
+#[cfg(emojic_group = "smileys_and_emotion")]
 pub mod smileys_and_emotion {
 	use crate::emojis::Emoji;
+	use crate::groups::Subgroup;
+
+	/// The Unicode name of this group
+	pub const NAME: &str = "Smileys & Emotion";
 
 	
 	/// cat_face 😹😼😿
@@ -26502,6 +28182,9 @@ pub mod smileys_and_emotion {
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "cat-face";
+
 		// List of all emoji statics
 
 		
@@ -26564,7 +28247,7 @@ pub static WEARY_CAT :  Emoji = Emoji::new("weary cat", Version(0, 6), "🙀");
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // CAT_WITH_TEARS_OF_JOY
 				
@@ -26623,7 +28306,7 @@ pub static WEARY_CAT :  Emoji = Emoji::new("weary cat", Version(0, 6), "🙀");
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&CAT_WITH_TEARS_OF_JOY, // CAT_WITH_TEARS_OF_JOY
@@ -26671,11 +28354,21 @@ pub static WEARY_CAT :  Emoji = Emoji::new("weary cat", Version(0, 6), "🙀");
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // cat_face
 	
 	/// emotion 💢💓🖤
@@ -26694,6 +28387,9 @@ pub static WEARY_CAT :  Emoji = Emoji::new("weary cat", Version(0, 6), "🙀");
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "emotion";
+
 		// List of all emoji statics
 
 		
@@ -26924,7 +28620,7 @@ pub static ZZZ :  Emoji = Emoji::new("zzz", Version(0, 6), "💤"); // zzz
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ANGER_SYMBOL
 				
@@ -27151,7 +28847,7 @@ pub static ZZZ :  Emoji = Emoji::new("zzz", Version(0, 6), "💤"); // zzz
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ANGER_SYMBOL, // ANGER_SYMBOL
@@ -27311,11 +29007,21 @@ pub static ZZZ :  Emoji = Emoji::new("zzz", Version(0, 6), "💤"); // zzz
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // emotion
 	
 	/// face_affection 😘😗😚
@@ -27334,6 +29040,9 @@ pub static ZZZ :  Emoji = Emoji::new("zzz", Version(0, 6), "💤"); // zzz
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "face-affection";
+
 		// List of all emoji statics
 
 		
@@ -27396,7 +29105,7 @@ pub static STAR_STRUCK :  Emoji = Emoji::new("star-struck", Version(5, 0), "🤩
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // FACE_BLOWING_A_KISS
 				
@@ -27455,7 +29164,7 @@ pub static STAR_STRUCK :  Emoji = Emoji::new("star-struck", Version(5, 0), "🤩
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&FACE_BLOWING_A_KISS, // FACE_BLOWING_A_KISS
@@ -27503,11 +29212,21 @@ pub static STAR_STRUCK :  Emoji = Emoji::new("star-struck", Version(5, 0), "🤩
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // face_affection
 	
 	/// face_concerned 😧😰😲
@@ -27526,6 +29245,9 @@ pub static STAR_STRUCK :  Emoji = Emoji::new("star-struck", Version(5, 0), "🤩
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "face-concerned";
+
 		// List of all emoji statics
 
 		
@@ -27577,12 +29299,24 @@ pub static DISAPPOINTED_FACE :  Emoji = Emoji::new("disappointed face", Version(
 #[doc="```"]
 pub static DOWNCAST_FACE_WITH_SWEAT :  Emoji = Emoji::new("downcast face with sweat", Version(0, 6), "😓"); // downcast face with sweat
 		
+		#[doc="face holding back tears 🥹"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::FACE_HOLDING_BACK_TEARS;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", FACE_HOLDING_BACK_TEARS); // 🥹 (`U+1F979`)"] #[doc="# assert_eq!(FACE_HOLDING_BACK_TEARS.to_string().as_str(), \"🥹\");"]
+#[doc="```"]
+pub static FACE_HOLDING_BACK_TEARS :  Emoji = Emoji::new("face holding back tears", Version(14, 0), "🥹"); // face holding back tears
+		
 		#[doc="face screaming in fear 😱"]#[doc=""]#[doc="Since E0.6"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::FACE_SCREAMING_IN_FEAR;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", FACE_SCREAMING_IN_FEAR); // 😱 (`U+1F631`)"] #[doc="# assert_eq!(FACE_SCREAMING_IN_FEAR.to_string().as_str(), \"😱\");"]
 #[doc="```"]
 pub static FACE_SCREAMING_IN_FEAR :  Emoji = Emoji::new("face screaming in fear", Version(0, 6), "😱"); // face screaming in fear
 		
+		#[doc="face with diagonal mouth 🫤"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::FACE_WITH_DIAGONAL_MOUTH;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", FACE_WITH_DIAGONAL_MOUTH); // 🫤 (`U+1FAE4`)"] #[doc="# assert_eq!(FACE_WITH_DIAGONAL_MOUTH.to_string().as_str(), \"🫤\");"]
+#[doc="```"]
+pub static FACE_WITH_DIAGONAL_MOUTH :  Emoji = Emoji::new("face with diagonal mouth", Version(14, 0), "🫤"); // face with diagonal mouth
+		
 		#[doc="face with open mouth 😮"]#[doc=""]#[doc="Since E1.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::FACE_WITH_OPEN_MOUTH;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", FACE_WITH_OPEN_MOUTH); // 😮 (`U+1F62E`)"] #[doc="# assert_eq!(FACE_WITH_OPEN_MOUTH.to_string().as_str(), \"😮\");"]
@@ -27678,7 +29412,7 @@ pub static YAWNING_FACE :  Emoji = Emoji::new("yawning face", Version(12, 0), "
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ANGUISHED_FACE
 				
@@ -27728,12 +29462,24 @@ pub static YAWNING_FACE :  Emoji = Emoji::new("yawning face", Version(12, 0), "
 				
 			],
 			
+			&[ // FACE_HOLDING_BACK_TEARS
+				
+				&FACE_HOLDING_BACK_TEARS,
+				
+			],
+			
 			&[ // FACE_SCREAMING_IN_FEAR
 				
 				&FACE_SCREAMING_IN_FEAR,
 				
 			],
 			
+			&[ // FACE_WITH_DIAGONAL_MOUTH
+				
+				&FACE_WITH_DIAGONAL_MOUTH,
+				
+			],
+			
 			&[ // FACE_WITH_OPEN_MOUTH
 				
 				&FACE_WITH_OPEN_MOUTH,
@@ -27827,7 +29573,7 @@ pub static YAWNING_FACE :  Emoji = Emoji::new("yawning face", Version(12, 0), "
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ANGUISHED_FACE, // ANGUISHED_FACE
@@ -27862,10 +29608,18 @@ pub static YAWNING_FACE :  Emoji = Emoji::new("yawning face", Version(12, 0), "
 				
 			
 				
+				&FACE_HOLDING_BACK_TEARS, // FACE_HOLDING_BACK_TEARS
+				
+			
+				
 				&FACE_SCREAMING_IN_FEAR, // FACE_SCREAMING_IN_FEAR
 				
 			
 				
+				&FACE_WITH_DIAGONAL_MOUTH, // FACE_WITH_DIAGONAL_MOUTH
+				
+			
+				
 				&FACE_WITH_OPEN_MOUTH, // FACE_WITH_OPEN_MOUTH
 				
 			
@@ -27935,11 +29689,21 @@ pub static YAWNING_FACE :  Emoji = Emoji::new("yawning face", Version(12, 0), "
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // face_concerned
 	
 	/// face_costume 👽👾🤡
@@ -27958,6 +29722,9 @@ pub static YAWNING_FACE :  Emoji = Emoji::new("yawning face", Version(12, 0), "
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "face-costume";
+
 		// List of all emoji statics
 
 		
@@ -28014,7 +29781,7 @@ pub static ROBOT :  Emoji = Emoji::new("robot", Version(1, 0), "🤖"); // robot
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ALIEN
 				
@@ -28067,7 +29834,7 @@ pub static ROBOT :  Emoji = Emoji::new("robot", Version(1, 0), "🤖"); // robot
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ALIEN, // ALIEN
@@ -28111,11 +29878,21 @@ pub static ROBOT :  Emoji = Emoji::new("robot", Version(1, 0), "🤖"); // robot
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // face_costume
 	
 	/// face_glasses 🧐🤓😎
@@ -28134,6 +29911,9 @@ pub static ROBOT :  Emoji = Emoji::new("robot", Version(1, 0), "🤖"); // robot
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "face-glasses";
+
 		// List of all emoji statics
 
 		
@@ -28160,7 +29940,7 @@ pub static SMILING_FACE_WITH_SUNGLASSES :  Emoji = Emoji::new("smiling face with
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // FACE_WITH_MONOCLE
 				
@@ -28183,7 +29963,7 @@ pub static SMILING_FACE_WITH_SUNGLASSES :  Emoji = Emoji::new("smiling face with
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&FACE_WITH_MONOCLE, // FACE_WITH_MONOCLE
@@ -28207,14 +29987,24 @@ pub static SMILING_FACE_WITH_SUNGLASSES :  Emoji = Emoji::new("smiling face with
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // face_glasses
 	
-	/// face_hand 🤭🤗🤫
+	/// face_hand 🤭🫢🫣
 	pub mod face_hand {
 
 		use crate::emojis::Emoji;
@@ -28230,6 +30020,9 @@ pub static SMILING_FACE_WITH_SUNGLASSES :  Emoji = Emoji::new("smiling face with
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "face-hand";
+
 		// List of all emoji statics
 
 		
@@ -28239,12 +30032,30 @@ pub static SMILING_FACE_WITH_SUNGLASSES :  Emoji = Emoji::new("smiling face with
 #[doc="```"]
 pub static FACE_WITH_HAND_OVER_MOUTH :  Emoji = Emoji::new("face with hand over mouth", Version(5, 0), "🤭"); // face with hand over mouth
 		
+		#[doc="face with open eyes and hand over mouth 🫢"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::FACE_WITH_OPEN_EYES_AND_HAND_OVER_MOUTH;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", FACE_WITH_OPEN_EYES_AND_HAND_OVER_MOUTH); // 🫢 (`U+1FAE2`)"] #[doc="# assert_eq!(FACE_WITH_OPEN_EYES_AND_HAND_OVER_MOUTH.to_string().as_str(), \"🫢\");"]
+#[doc="```"]
+pub static FACE_WITH_OPEN_EYES_AND_HAND_OVER_MOUTH :  Emoji = Emoji::new("face with open eyes and hand over mouth", Version(14, 0), "🫢"); // face with open eyes and hand over mouth
+		
+		#[doc="face with peeking eye 🫣"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::FACE_WITH_PEEKING_EYE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", FACE_WITH_PEEKING_EYE); // 🫣 (`U+1FAE3`)"] #[doc="# assert_eq!(FACE_WITH_PEEKING_EYE.to_string().as_str(), \"🫣\");"]
+#[doc="```"]
+pub static FACE_WITH_PEEKING_EYE :  Emoji = Emoji::new("face with peeking eye", Version(14, 0), "🫣"); // face with peeking eye
+		
 		#[doc="hugging face 🤗"]#[doc=""]#[doc="Since E1.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::HUGGING_FACE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", HUGGING_FACE); // 🤗 (`U+1F917`)"] #[doc="# assert_eq!(HUGGING_FACE.to_string().as_str(), \"🤗\");"]
 #[doc="```"]
 pub static HUGGING_FACE :  Emoji = Emoji::new("hugging face", Version(1, 0), "🤗"); // hugging face
 		
+		#[doc="saluting face 🫡"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::SALUTING_FACE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", SALUTING_FACE); // 🫡 (`U+1FAE1`)"] #[doc="# assert_eq!(SALUTING_FACE.to_string().as_str(), \"🫡\");"]
+#[doc="```"]
+pub static SALUTING_FACE :  Emoji = Emoji::new("saluting face", Version(14, 0), "🫡"); // saluting face
+		
 		#[doc="shushing face 🤫"]#[doc=""]#[doc="Since E5.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::SHUSHING_FACE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", SHUSHING_FACE); // 🤫 (`U+1F92B`)"] #[doc="# assert_eq!(SHUSHING_FACE.to_string().as_str(), \"🤫\");"]
@@ -28262,7 +30073,7 @@ pub static THINKING_FACE :  Emoji = Emoji::new("thinking face", Version(1, 0), "
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // FACE_WITH_HAND_OVER_MOUTH
 				
@@ -28270,12 +30081,30 @@ pub static THINKING_FACE :  Emoji = Emoji::new("thinking face", Version(1, 0), "
 				
 			],
 			
+			&[ // FACE_WITH_OPEN_EYES_AND_HAND_OVER_MOUTH
+				
+				&FACE_WITH_OPEN_EYES_AND_HAND_OVER_MOUTH,
+				
+			],
+			
+			&[ // FACE_WITH_PEEKING_EYE
+				
+				&FACE_WITH_PEEKING_EYE,
+				
+			],
+			
 			&[ // HUGGING_FACE
 				
 				&HUGGING_FACE,
 				
 			],
 			
+			&[ // SALUTING_FACE
+				
+				&SALUTING_FACE,
+				
+			],
+			
 			&[ // SHUSHING_FACE
 				
 				&SHUSHING_FACE,
@@ -28291,17 +30120,29 @@ pub static THINKING_FACE :  Emoji = Emoji::new("thinking face", Version(1, 0), "
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&FACE_WITH_HAND_OVER_MOUTH, // FACE_WITH_HAND_OVER_MOUTH
 				
 			
 				
+				&FACE_WITH_OPEN_EYES_AND_HAND_OVER_MOUTH, // FACE_WITH_OPEN_EYES_AND_HAND_OVER_MOUTH
+				
+			
+				
+				&FACE_WITH_PEEKING_EYE, // FACE_WITH_PEEKING_EYE
+				
+			
+				
 				&HUGGING_FACE, // HUGGING_FACE
 				
 			
 				
+				&SALUTING_FACE, // SALUTING_FACE
+				
+			
+				
 				&SHUSHING_FACE, // SHUSHING_FACE
 				
 			
@@ -28319,11 +30160,21 @@ pub static THINKING_FACE :  Emoji = Emoji::new("thinking face", Version(1, 0), "
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // face_hand
 	
 	/// face_hat 🤠🥸🥳
@@ -28342,6 +30193,9 @@ pub static THINKING_FACE :  Emoji = Emoji::new("thinking face", Version(1, 0), "
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "face-hat";
+
 		// List of all emoji statics
 
 		
@@ -28368,7 +30222,7 @@ pub static PARTYING_FACE :  Emoji = Emoji::new("partying face", Version(11, 0),
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // COWBOY_HAT_FACE
 				
@@ -28391,7 +30245,7 @@ pub static PARTYING_FACE :  Emoji = Emoji::new("partying face", Version(11, 0),
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&COWBOY_HAT_FACE, // COWBOY_HAT_FACE
@@ -28415,11 +30269,21 @@ pub static PARTYING_FACE :  Emoji = Emoji::new("partying face", Version(11, 0),
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // face_hat
 	
 	/// face_negative 😠👿😤
@@ -28438,6 +30302,9 @@ pub static PARTYING_FACE :  Emoji = Emoji::new("partying face", Version(11, 0),
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "face-negative";
+
 		// List of all emoji statics
 
 		
@@ -28494,7 +30361,7 @@ pub static SMILING_FACE_WITH_HORNS :  Emoji = Emoji::new("smiling face with horn
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ANGRY_FACE
 				
@@ -28547,7 +30414,7 @@ pub static SMILING_FACE_WITH_HORNS :  Emoji = Emoji::new("smiling face with horn
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ANGRY_FACE, // ANGRY_FACE
@@ -28591,14 +30458,24 @@ pub static SMILING_FACE_WITH_HORNS :  Emoji = Emoji::new("smiling face with horn
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // face_negative
 	
-	/// face_neutral_skeptical 😑😮‍💨😶‍🌫️
+	/// face_neutral_skeptical 🫥😑😮‍💨
 	pub mod face_neutral_skeptical {
 
 		use crate::emojis::Emoji;
@@ -28614,9 +30491,18 @@ pub static SMILING_FACE_WITH_HORNS :  Emoji = Emoji::new("smiling face with horn
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "face-neutral-skeptical";
+
 		// List of all emoji statics
 
 		
+		#[doc="dotted line face 🫥"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::DOTTED_LINE_FACE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", DOTTED_LINE_FACE); // 🫥 (`U+1FAE5`)"] #[doc="# assert_eq!(DOTTED_LINE_FACE.to_string().as_str(), \"🫥\");"]
+#[doc="```"]
+pub static DOTTED_LINE_FACE :  Emoji = Emoji::new("dotted line face", Version(14, 0), "🫥"); // dotted line face
+		
 		#[doc="expressionless face 😑"]#[doc=""]#[doc="Since E1.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::EXPRESSIONLESS_FACE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", EXPRESSIONLESS_FACE); // 😑 (`U+1F611`)"] #[doc="# assert_eq!(EXPRESSIONLESS_FACE.to_string().as_str(), \"😑\");"]
@@ -28694,7 +30580,13 @@ pub static ZIPPER_MOUTH_FACE :  Emoji = Emoji::new("zipper-mouth face", Version(
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
+			
+			&[ // DOTTED_LINE_FACE
+				
+				&DOTTED_LINE_FACE,
+				
+			],
 			
 			&[ // EXPRESSIONLESS_FACE
 				
@@ -28771,7 +30663,11 @@ pub static ZIPPER_MOUTH_FACE :  Emoji = Emoji::new("zipper-mouth face", Version(
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
+			
+				
+				&DOTTED_LINE_FACE, // DOTTED_LINE_FACE
+				
 			
 				
 				&EXPRESSIONLESS_FACE, // EXPRESSIONLESS_FACE
@@ -28831,11 +30727,21 @@ pub static ZIPPER_MOUTH_FACE :  Emoji = Emoji::new("zipper-mouth face", Version(
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // face_neutral_skeptical
 	
 	/// face_sleepy 🤤😔😌
@@ -28854,6 +30760,9 @@ pub static ZIPPER_MOUTH_FACE :  Emoji = Emoji::new("zipper-mouth face", Version(
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "face-sleepy";
+
 		// List of all emoji statics
 
 		
@@ -28892,7 +30801,7 @@ pub static SLEEPY_FACE :  Emoji = Emoji::new("sleepy face", Version(0, 6), "😪
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // DROOLING_FACE
 				
@@ -28927,7 +30836,7 @@ pub static SLEEPY_FACE :  Emoji = Emoji::new("sleepy face", Version(0, 6), "😪
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&DROOLING_FACE, // DROOLING_FACE
@@ -28959,11 +30868,21 @@ pub static SLEEPY_FACE :  Emoji = Emoji::new("sleepy face", Version(0, 6), "😪
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // face_sleepy
 	
 	/// face_smiling 😁😂😀
@@ -28982,6 +30901,9 @@ pub static SLEEPY_FACE :  Emoji = Emoji::new("sleepy face", Version(0, 6), "😪
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "face-smiling";
+
 		// List of all emoji statics
 
 		
@@ -29027,6 +30949,12 @@ pub static GRINNING_FACE_WITH_SWEAT :  Emoji = Emoji::new("grinning face with sw
 #[doc="```"]
 pub static GRINNING_SQUINTING_FACE :  Emoji = Emoji::new("grinning squinting face", Version(0, 6), "😆"); // grinning squinting face
 		
+		#[doc="melting face 🫠"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::MELTING_FACE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", MELTING_FACE); // 🫠 (`U+1FAE0`)"] #[doc="# assert_eq!(MELTING_FACE.to_string().as_str(), \"🫠\");"]
+#[doc="```"]
+pub static MELTING_FACE :  Emoji = Emoji::new("melting face", Version(14, 0), "🫠"); // melting face
+		
 		#[doc="rolling on the floor laughing 🤣"]#[doc=""]#[doc="Since E3.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::ROLLING_ON_THE_FLOOR_LAUGHING;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", ROLLING_ON_THE_FLOOR_LAUGHING); // 🤣 (`U+1F923`)"] #[doc="# assert_eq!(ROLLING_ON_THE_FLOOR_LAUGHING.to_string().as_str(), \"🤣\");"]
@@ -29068,7 +30996,7 @@ pub static WINKING_FACE :  Emoji = Emoji::new("winking face", Version(0, 6), "
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BEAMING_FACE_WITH_SMILING_EYES
 				
@@ -29112,6 +31040,12 @@ pub static WINKING_FACE :  Emoji = Emoji::new("winking face", Version(0, 6), "
 				
 			],
 			
+			&[ // MELTING_FACE
+				
+				&MELTING_FACE,
+				
+			],
+			
 			&[ // ROLLING_ON_THE_FLOOR_LAUGHING
 				
 				&ROLLING_ON_THE_FLOOR_LAUGHING,
@@ -29151,7 +31085,7 @@ pub static WINKING_FACE :  Emoji = Emoji::new("winking face", Version(0, 6), "
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BEAMING_FACE_WITH_SMILING_EYES, // BEAMING_FACE_WITH_SMILING_EYES
@@ -29182,6 +31116,10 @@ pub static WINKING_FACE :  Emoji = Emoji::new("winking face", Version(0, 6), "
 				
 			
 				
+				&MELTING_FACE, // MELTING_FACE
+				
+			
+				
 				&ROLLING_ON_THE_FLOOR_LAUGHING, // ROLLING_ON_THE_FLOOR_LAUGHING
 				
 			
@@ -29215,11 +31153,21 @@ pub static WINKING_FACE :  Emoji = Emoji::new("winking face", Version(0, 6), "
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // face_smiling
 	
 	/// face_tongue 😋😛🤑
@@ -29238,6 +31186,9 @@ pub static WINKING_FACE :  Emoji = Emoji::new("winking face", Version(0, 6), "
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "face-tongue";
+
 		// List of all emoji statics
 
 		
@@ -29282,7 +31233,7 @@ pub static ZANY_FACE :  Emoji = Emoji::new("zany face", Version(5, 0), "🤪");
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // FACE_SAVORING_FOOD
 				
@@ -29323,7 +31274,7 @@ pub static ZANY_FACE :  Emoji = Emoji::new("zany face", Version(5, 0), "🤪");
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&FACE_SAVORING_FOOD, // FACE_SAVORING_FOOD
@@ -29359,11 +31310,21 @@ pub static ZANY_FACE :  Emoji = Emoji::new("zany face", Version(5, 0), "🤪");
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // face_tongue
 	
 	/// face_unwell 🥶🤯🤮
@@ -29382,6 +31343,9 @@ pub static ZANY_FACE :  Emoji = Emoji::new("zany face", Version(5, 0), "🤪");
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "face-unwell";
+
 		// List of all emoji statics
 
 		
@@ -29462,7 +31426,7 @@ pub static WOOZY_FACE :  Emoji = Emoji::new("woozy face", Version(11, 0), "🥴"
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // COLD_FACE
 				
@@ -29539,7 +31503,7 @@ pub static WOOZY_FACE :  Emoji = Emoji::new("woozy face", Version(11, 0), "🥴"
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&COLD_FACE, // COLD_FACE
@@ -29599,11 +31563,21 @@ pub static WOOZY_FACE :  Emoji = Emoji::new("woozy face", Version(11, 0), "🥴"
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // face_unwell
 	
 	/// monkey_face 🙉🙈🙊
@@ -29622,6 +31596,9 @@ pub static WOOZY_FACE :  Emoji = Emoji::new("woozy face", Version(11, 0), "🥴"
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "monkey-face";
+
 		// List of all emoji statics
 
 		
@@ -29648,7 +31625,7 @@ pub static SPEAK_NO_EVIL_MONKEY :  Emoji = Emoji::new("speak-no-evil monkey", Ve
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // HEAR_NO_EVIL_MONKEY
 				
@@ -29671,7 +31648,7 @@ pub static SPEAK_NO_EVIL_MONKEY :  Emoji = Emoji::new("speak-no-evil monkey", Ve
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&HEAR_NO_EVIL_MONKEY, // HEAR_NO_EVIL_MONKEY
@@ -29695,15 +31672,63 @@ pub static SPEAK_NO_EVIL_MONKEY :  Emoji = Emoji::new("speak-no-evil monkey", Ve
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // monkey_face
 	
 
 
+	// Subgroup metadata
+
+	/// Contains the metadata of each subgroup of this group.
+	pub(crate) static SUBGROUPS: &[Subgroup] = &[
+		
+		Subgroup::new(cat_face::NAME, cat_face::ALL_VARIANTS, cat_face::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(emotion::NAME, emotion::ALL_VARIANTS, emotion::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(face_affection::NAME, face_affection::ALL_VARIANTS, face_affection::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(face_concerned::NAME, face_concerned::ALL_VARIANTS, face_concerned::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(face_costume::NAME, face_costume::ALL_VARIANTS, face_costume::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(face_glasses::NAME, face_glasses::ALL_VARIANTS, face_glasses::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(face_hand::NAME, face_hand::ALL_VARIANTS, face_hand::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(face_hat::NAME, face_hat::ALL_VARIANTS, face_hat::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(face_negative::NAME, face_negative::ALL_VARIANTS, face_negative::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(face_neutral_skeptical::NAME, face_neutral_skeptical::ALL_VARIANTS, face_neutral_skeptical::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(face_sleepy::NAME, face_sleepy::ALL_VARIANTS, face_sleepy::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(face_smiling::NAME, face_smiling::ALL_VARIANTS, face_smiling::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(face_tongue::NAME, face_tongue::ALL_VARIANTS, face_tongue::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(face_unwell::NAME, face_unwell::ALL_VARIANTS, face_unwell::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(monkey_face::NAME, monkey_face::ALL_VARIANTS, monkey_face::ALL_BASE_EMOJIS),
+		
+	];
+
+
 	// Emoji iterators
 
 	/// Returns an iterator over all emoji variants of these subgroups grouped by base emojis
@@ -29742,6 +31767,11 @@ pub static SPEAK_NO_EVIL_MONKEY :  Emoji = Emoji::new("speak-no-evil monkey", Ve
 		
 	}
 
+	/// Returns an iterator over all emoji variants of these subgroups one by one
+	pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+		all_variants().flat_map(|variants| variants.iter().copied())
+	}
+
 	/// Returns an iterator over all base emojis of these subgroups (i.e. one for each static)
 	pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 		core::iter::empty()
@@ -29781,8 +31811,13 @@ pub static SPEAK_NO_EVIL_MONKEY :  Emoji = Emoji::new("speak-no-evil monkey", Ve
 } // smileys_and_emotion
 
 /// symbols 🆎🔙📶
+#[cfg(emojic_group = "symbols")]
 pub mod symbols {
 	use crate::emojis::Emoji;
+	use crate::groups::Subgroup;
+
+	/// The Unicode name of this group
+	pub const NAME: &str = "Symbols";
 
 	
 	/// alphanum 🆎🅰️🅱️
@@ -29801,6 +31836,9 @@ pub mod symbols {
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "alphanum";
+
 		// List of all emoji statics
 
 		
@@ -30043,7 +32081,7 @@ pub static VS_BUTTON :  Emoji = Emoji::new("VS button", Version(0, 6), "🆚");
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // AB_BUTTON_BLOOD_TYPE
 				
@@ -30282,7 +32320,7 @@ pub static VS_BUTTON :  Emoji = Emoji::new("VS button", Version(0, 6), "🆚");
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&AB_BUTTON_BLOOD_TYPE, // AB_BUTTON_BLOOD_TYPE
@@ -30450,11 +32488,21 @@ pub static VS_BUTTON :  Emoji = Emoji::new("VS button", Version(0, 6), "🆚");
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // alphanum
 	
 	/// arrow 🔙🔃🔄
@@ -30473,6 +32521,9 @@ pub static VS_BUTTON :  Emoji = Emoji::new("VS button", Version(0, 6), "🆚");
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "arrow";
+
 		// List of all emoji statics
 
 		
@@ -30607,7 +32658,7 @@ pub static UP_RIGHT_ARROW :  Emoji = Emoji::new("up-right arrow", Version(0, 6),
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BACK_ARROW
 				
@@ -30738,7 +32789,7 @@ pub static UP_RIGHT_ARROW :  Emoji = Emoji::new("up-right arrow", Version(0, 6),
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BACK_ARROW, // BACK_ARROW
@@ -30834,11 +32885,21 @@ pub static UP_RIGHT_ARROW :  Emoji = Emoji::new("up-right arrow", Version(0, 6),
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // arrow
 	
 	/// av_symbol 📶🔆🎦
@@ -30857,6 +32918,9 @@ pub static UP_RIGHT_ARROW :  Emoji = Emoji::new("up-right arrow", Version(0, 6),
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "av-symbol";
+
 		// List of all emoji statics
 
 		
@@ -31009,7 +33073,7 @@ pub static VIBRATION_MODE :  Emoji = Emoji::new("vibration mode", Version(0, 6),
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ANTENNA_BARS
 				
@@ -31158,7 +33222,7 @@ pub static VIBRATION_MODE :  Emoji = Emoji::new("vibration mode", Version(0, 6),
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ANTENNA_BARS, // ANTENNA_BARS
@@ -31266,11 +33330,21 @@ pub static VIBRATION_MODE :  Emoji = Emoji::new("vibration mode", Version(0, 6),
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // av_symbol
 	
 	/// currency 💱💲
@@ -31289,6 +33363,9 @@ pub static VIBRATION_MODE :  Emoji = Emoji::new("vibration mode", Version(0, 6),
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "currency";
+
 		// List of all emoji statics
 
 		
@@ -31309,7 +33386,7 @@ pub static HEAVY_DOLLAR_SIGN :  Emoji = Emoji::new("heavy dollar sign", Version(
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // CURRENCY_EXCHANGE
 				
@@ -31326,7 +33403,7 @@ pub static HEAVY_DOLLAR_SIGN :  Emoji = Emoji::new("heavy dollar sign", Version(
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&CURRENCY_EXCHANGE, // CURRENCY_EXCHANGE
@@ -31346,11 +33423,21 @@ pub static HEAVY_DOLLAR_SIGN :  Emoji = Emoji::new("heavy dollar sign", Version(
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // currency
 	
 	/// gender ♀️♂️⚧️
@@ -31369,6 +33456,9 @@ pub static HEAVY_DOLLAR_SIGN :  Emoji = Emoji::new("heavy dollar sign", Version(
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "gender";
+
 		// List of all emoji statics
 
 		
@@ -31395,7 +33485,7 @@ pub static TRANSGENDER_SYMBOL :  Emoji = Emoji::new("transgender symbol", Versio
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // FEMALE_SIGN
 				
@@ -31418,7 +33508,7 @@ pub static TRANSGENDER_SYMBOL :  Emoji = Emoji::new("transgender symbol", Versio
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&FEMALE_SIGN, // FEMALE_SIGN
@@ -31442,11 +33532,21 @@ pub static TRANSGENDER_SYMBOL :  Emoji = Emoji::new("transgender symbol", Versio
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // gender
 	
 	/// geometric ⚫⬛◾
@@ -31465,6 +33565,9 @@ pub static TRANSGENDER_SYMBOL :  Emoji = Emoji::new("transgender symbol", Versio
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "geometric";
+
 		// List of all emoji statics
 
 		
@@ -31677,7 +33780,7 @@ pub static YELLOW_SQUARE :  Emoji = Emoji::new("yellow square", Version(12, 0),
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BLACK_CIRCLE
 				
@@ -31886,7 +33989,7 @@ pub static YELLOW_SQUARE :  Emoji = Emoji::new("yellow square", Version(12, 0),
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BLACK_CIRCLE, // BLACK_CIRCLE
@@ -32034,11 +34137,21 @@ pub static YELLOW_SQUARE :  Emoji = Emoji::new("yellow square", Version(12, 0),
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // geometric
 	
 	/// keycap 0️⃣1️⃣🔟
@@ -32057,6 +34170,9 @@ pub static YELLOW_SQUARE :  Emoji = Emoji::new("yellow square", Version(12, 0),
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "keycap";
+
 		// List of all emoji statics
 
 		
@@ -32143,7 +34259,7 @@ pub static KEYCAP_HASH :  Emoji = Emoji::new("keycap: #", Version(0, 6), "#️
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // KEYCAP_0
 				
@@ -32226,7 +34342,7 @@ pub static KEYCAP_HASH :  Emoji = Emoji::new("keycap: #", Version(0, 6), "#️
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&KEYCAP_0, // KEYCAP_0
@@ -32290,14 +34406,24 @@ pub static KEYCAP_HASH :  Emoji = Emoji::new("keycap: #", Version(0, 6), "#️
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // keycap
 	
-	/// math ➗♾️➖
+	/// math ➗🟰♾️
 	pub mod math {
 
 		use crate::emojis::Emoji;
@@ -32313,6 +34439,9 @@ pub static KEYCAP_HASH :  Emoji = Emoji::new("keycap: #", Version(0, 6), "#️
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "math";
+
 		// List of all emoji statics
 
 		
@@ -32322,6 +34451,12 @@ pub static KEYCAP_HASH :  Emoji = Emoji::new("keycap: #", Version(0, 6), "#️
 #[doc="```"]
 pub static DIVIDE :  Emoji = Emoji::new("divide", Version(0, 6), "➗"); // divide
 		
+		#[doc="heavy equals sign 🟰"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::HEAVY_EQUALS_SIGN;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", HEAVY_EQUALS_SIGN); // 🟰 (`U+1F7F0`)"] #[doc="# assert_eq!(HEAVY_EQUALS_SIGN.to_string().as_str(), \"🟰\");"]
+#[doc="```"]
+pub static HEAVY_EQUALS_SIGN :  Emoji = Emoji::new("heavy equals sign", Version(14, 0), "🟰"); // heavy equals sign
+		
 		#[doc="infinity ♾️"]#[doc=""]#[doc="Since E11.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::INFINITY;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", INFINITY); // ♾️ (`U+267E U+FE0F`)"] #[doc="# assert_eq!(INFINITY.to_string().as_str(), \"♾️\");"]
@@ -32351,7 +34486,7 @@ pub static PLUS :  Emoji = Emoji::new("plus", Version(0, 6), "➕"); // plus
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // DIVIDE
 				
@@ -32359,6 +34494,12 @@ pub static PLUS :  Emoji = Emoji::new("plus", Version(0, 6), "➕"); // plus
 				
 			],
 			
+			&[ // HEAVY_EQUALS_SIGN
+				
+				&HEAVY_EQUALS_SIGN,
+				
+			],
+			
 			&[ // INFINITY
 				
 				&INFINITY,
@@ -32386,13 +34527,17 @@ pub static PLUS :  Emoji = Emoji::new("plus", Version(0, 6), "➕"); // plus
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&DIVIDE, // DIVIDE
 				
 			
 				
+				&HEAVY_EQUALS_SIGN, // HEAVY_EQUALS_SIGN
+				
+			
+				
 				&INFINITY, // INFINITY
 				
 			
@@ -32418,11 +34563,21 @@ pub static PLUS :  Emoji = Emoji::new("plus", Version(0, 6), "➕"); // plus
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // math
 	
 	/// other_symbol ☑️✔️✅
@@ -32441,6 +34596,9 @@ pub static PLUS :  Emoji = Emoji::new("plus", Version(0, 6), "➕"); // plus
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "other-symbol";
+
 		// List of all emoji statics
 
 		
@@ -32575,7 +34733,7 @@ pub static TRIDENT_EMBLEM :  Emoji = Emoji::new("trident emblem", Version(0, 6),
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // CHECK_BOX_WITH_CHECK
 				
@@ -32706,7 +34864,7 @@ pub static TRIDENT_EMBLEM :  Emoji = Emoji::new("trident emblem", Version(0, 6),
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&CHECK_BOX_WITH_CHECK, // CHECK_BOX_WITH_CHECK
@@ -32802,11 +34960,21 @@ pub static TRIDENT_EMBLEM :  Emoji = Emoji::new("trident emblem", Version(0, 6),
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // other_symbol
 	
 	/// punctuation ‼️⁉️❗
@@ -32825,6 +34993,9 @@ pub static TRIDENT_EMBLEM :  Emoji = Emoji::new("trident emblem", Version(0, 6),
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "punctuation";
+
 		// List of all emoji statics
 
 		
@@ -32875,7 +35046,7 @@ pub static WHITE_QUESTION_MARK :  Emoji = Emoji::new("white question mark", Vers
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // DOUBLE_EXCLAMATION_MARK
 				
@@ -32922,7 +35093,7 @@ pub static WHITE_QUESTION_MARK :  Emoji = Emoji::new("white question mark", Vers
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&DOUBLE_EXCLAMATION_MARK, // DOUBLE_EXCLAMATION_MARK
@@ -32962,11 +35133,21 @@ pub static WHITE_QUESTION_MARK :  Emoji = Emoji::new("white question mark", Vers
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // punctuation
 	
 	/// religion ⚛️🔯✝️
@@ -32985,6 +35166,9 @@ pub static WHITE_QUESTION_MARK :  Emoji = Emoji::new("white question mark", Vers
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "religion";
+
 		// List of all emoji statics
 
 		
@@ -33065,7 +35249,7 @@ pub static YIN_YANG :  Emoji = Emoji::new("yin yang", Version(0, 7), "☯️");
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ATOM_SYMBOL
 				
@@ -33142,7 +35326,7 @@ pub static YIN_YANG :  Emoji = Emoji::new("yin yang", Version(0, 7), "☯️");
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ATOM_SYMBOL, // ATOM_SYMBOL
@@ -33202,11 +35386,21 @@ pub static YIN_YANG :  Emoji = Emoji::new("yin yang", Version(0, 7), "☯️");
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // religion
 	
 	/// transport_sign 🏧🚼🛄
@@ -33225,6 +35419,9 @@ pub static YIN_YANG :  Emoji = Emoji::new("yin yang", Version(0, 7), "☯️");
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "transport-sign";
+
 		// List of all emoji statics
 
 		
@@ -33311,7 +35508,7 @@ pub static WOMEN_S_ROOM :  Emoji = Emoji::new("women’s room", Version(0, 6), "
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ATM_SIGN
 				
@@ -33394,7 +35591,7 @@ pub static WOMEN_S_ROOM :  Emoji = Emoji::new("women’s room", Version(0, 6), "
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ATM_SIGN, // ATM_SIGN
@@ -33458,11 +35655,21 @@ pub static WOMEN_S_ROOM :  Emoji = Emoji::new("women’s room", Version(0, 6), "
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // transport_sign
 	
 	/// warning ☣️🚸🚱
@@ -33481,6 +35688,9 @@ pub static WOMEN_S_ROOM :  Emoji = Emoji::new("women’s room", Version(0, 6), "
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "warning";
+
 		// List of all emoji statics
 
 		
@@ -33567,7 +35777,7 @@ pub static WARNING :  Emoji = Emoji::new("warning", Version(0, 6), "⚠️"); //
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BIOHAZARD
 				
@@ -33650,7 +35860,7 @@ pub static WARNING :  Emoji = Emoji::new("warning", Version(0, 6), "⚠️"); //
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BIOHAZARD, // BIOHAZARD
@@ -33714,11 +35924,21 @@ pub static WARNING :  Emoji = Emoji::new("warning", Version(0, 6), "⚠️"); //
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // warning
 	
 	/// zodiac ♒♈♋
@@ -33737,6 +35957,9 @@ pub static WARNING :  Emoji = Emoji::new("warning", Version(0, 6), "⚠️"); //
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "zodiac";
+
 		// List of all emoji statics
 
 		
@@ -33823,7 +36046,7 @@ pub static VIRGO :  Emoji = Emoji::new("Virgo", Version(0, 6), "♍"); // Virgo
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // AQUARIUS
 				
@@ -33906,7 +36129,7 @@ pub static VIRGO :  Emoji = Emoji::new("Virgo", Version(0, 6), "♍"); // Virgo
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&AQUARIUS, // AQUARIUS
@@ -33970,15 +36193,61 @@ pub static VIRGO :  Emoji = Emoji::new("Virgo", Version(0, 6), "♍"); // Virgo
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // zodiac
 	
 
 
+	// Subgroup metadata
+
+	/// Contains the metadata of each subgroup of this group.
+	pub(crate) static SUBGROUPS: &[Subgroup] = &[
+		
+		Subgroup::new(alphanum::NAME, alphanum::ALL_VARIANTS, alphanum::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(arrow::NAME, arrow::ALL_VARIANTS, arrow::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(av_symbol::NAME, av_symbol::ALL_VARIANTS, av_symbol::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(currency::NAME, currency::ALL_VARIANTS, currency::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(gender::NAME, gender::ALL_VARIANTS, gender::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(geometric::NAME, geometric::ALL_VARIANTS, geometric::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(keycap::NAME, keycap::ALL_VARIANTS, keycap::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(math::NAME, math::ALL_VARIANTS, math::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(other_symbol::NAME, other_symbol::ALL_VARIANTS, other_symbol::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(punctuation::NAME, punctuation::ALL_VARIANTS, punctuation::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(religion::NAME, religion::ALL_VARIANTS, religion::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(transport_sign::NAME, transport_sign::ALL_VARIANTS, transport_sign::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(warning::NAME, warning::ALL_VARIANTS, warning::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(zodiac::NAME, zodiac::ALL_VARIANTS, zodiac::ALL_BASE_EMOJIS),
+		
+	];
+
+
 	// Emoji iterators
 
 	/// Returns an iterator over all emoji variants of these subgroups grouped by base emojis
@@ -34015,6 +36284,11 @@ pub static VIRGO :  Emoji = Emoji::new("Virgo", Version(0, 6), "♍"); // Virgo
 		
 	}
 
+	/// Returns an iterator over all emoji variants of these subgroups one by one
+	pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+		all_variants().flat_map(|variants| variants.iter().copied())
+	}
+
 	/// Returns an iterator over all base emojis of these subgroups (i.e. one for each static)
 	pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 		core::iter::empty()
@@ -34052,8 +36326,13 @@ pub static VIRGO :  Emoji = Emoji::new("Virgo", Version(0, 6), "♍"); // Virgo
 } // symbols
 
 /// travel_and_places 🛎️🏦🏖️
+#[cfg(emojic_group = "travel_and_places")]
 pub mod travel_and_places {
 	use crate::emojis::Emoji;
+	use crate::groups::Subgroup;
+
+	/// The Unicode name of this group
+	pub const NAME: &str = "Travel & Places";
 
 	
 	/// hotel 🛎️🧳
@@ -34072,6 +36351,9 @@ pub mod travel_and_places {
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "hotel";
+
 		// List of all emoji statics
 
 		
@@ -34092,7 +36374,7 @@ pub static LUGGAGE :  Emoji = Emoji::new("luggage", Version(11, 0), "🧳"); //
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BELLHOP_BELL
 				
@@ -34109,7 +36391,7 @@ pub static LUGGAGE :  Emoji = Emoji::new("luggage", Version(11, 0), "🧳"); //
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BELLHOP_BELL, // BELLHOP_BELL
@@ -34129,11 +36411,21 @@ pub static LUGGAGE :  Emoji = Emoji::new("luggage", Version(11, 0), "🧳"); //
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // hotel
 	
 	/// place_building 🏦🧱🏗️
@@ -34152,6 +36444,9 @@ pub static LUGGAGE :  Emoji = Emoji::new("luggage", Version(11, 0), "🧳"); //
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "place-building";
+
 		// List of all emoji statics
 
 		
@@ -34322,7 +36617,7 @@ pub static WOOD :  Emoji = Emoji::new("wood", Version(13, 0), "🪵"); // wood
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BANK
 				
@@ -34489,7 +36784,7 @@ pub static WOOD :  Emoji = Emoji::new("wood", Version(13, 0), "🪵"); // wood
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BANK, // BANK
@@ -34609,11 +36904,21 @@ pub static WOOD :  Emoji = Emoji::new("wood", Version(13, 0), "🪵"); // wood
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // place_building
 	
 	/// place_geographic 🏖️🏕️🏜️
@@ -34632,6 +36937,9 @@ pub static WOOD :  Emoji = Emoji::new("wood", Version(13, 0), "🪵"); // wood
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "place-geographic";
+
 		// List of all emoji statics
 
 		
@@ -34694,7 +37002,7 @@ pub static VOLCANO :  Emoji = Emoji::new("volcano", Version(0, 6), "🌋"); // v
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BEACH_WITH_UMBRELLA
 				
@@ -34753,7 +37061,7 @@ pub static VOLCANO :  Emoji = Emoji::new("volcano", Version(0, 6), "🌋"); // v
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BEACH_WITH_UMBRELLA, // BEACH_WITH_UMBRELLA
@@ -34801,11 +37109,21 @@ pub static VOLCANO :  Emoji = Emoji::new("volcano", Version(0, 6), "🌋"); // v
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // place_geographic
 	
 	/// place_map 🧭🌎🌏
@@ -34824,6 +37142,9 @@ pub static VOLCANO :  Emoji = Emoji::new("volcano", Version(0, 6), "🌋"); // v
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "place-map";
+
 		// List of all emoji statics
 
 		
@@ -34874,7 +37195,7 @@ pub static WORLD_MAP :  Emoji = Emoji::new("world map", Version(0, 7), "🗺️"
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // COMPASS
 				
@@ -34921,7 +37242,7 @@ pub static WORLD_MAP :  Emoji = Emoji::new("world map", Version(0, 7), "🗺️"
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&COMPASS, // COMPASS
@@ -34961,11 +37282,21 @@ pub static WORLD_MAP :  Emoji = Emoji::new("world map", Version(0, 7), "🗺️"
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // place_map
 	
 	/// place_other 💈🌉🎠
@@ -34984,6 +37315,9 @@ pub static WORLD_MAP :  Emoji = Emoji::new("world map", Version(0, 7), "🗺️"
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "place-other";
+
 		// List of all emoji statics
 
 		
@@ -35053,6 +37387,12 @@ pub static HOT_SPRINGS :  Emoji = Emoji::new("hot springs", Version(0, 6), "♨
 #[doc="```"]
 pub static NIGHT_WITH_STARS :  Emoji = Emoji::new("night with stars", Version(0, 6), "🌃"); // night with stars
 		
+		#[doc="playground slide 🛝"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::PLAYGROUND_SLIDE;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", PLAYGROUND_SLIDE); // 🛝 (`U+1F6DD`)"] #[doc="# assert_eq!(PLAYGROUND_SLIDE.to_string().as_str(), \"🛝\");"]
+#[doc="```"]
+pub static PLAYGROUND_SLIDE :  Emoji = Emoji::new("playground slide", Version(14, 0), "🛝"); // playground slide
+		
 		#[doc="roller coaster 🎢"]#[doc=""]#[doc="Since E0.6"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::ROLLER_COASTER;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", ROLLER_COASTER); // 🎢 (`U+1F3A2`)"] #[doc="# assert_eq!(ROLLER_COASTER.to_string().as_str(), \"🎢\");"]
@@ -35088,7 +37428,7 @@ pub static TENT :  Emoji = Emoji::new("tent", Version(0, 6), "⛺"); // tent
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // BARBER_POLE
 				
@@ -35156,6 +37496,12 @@ pub static TENT :  Emoji = Emoji::new("tent", Version(0, 6), "⛺"); // tent
 				
 			],
 			
+			&[ // PLAYGROUND_SLIDE
+				
+				&PLAYGROUND_SLIDE,
+				
+			],
+			
 			&[ // ROLLER_COASTER
 				
 				&ROLLER_COASTER,
@@ -35189,7 +37535,7 @@ pub static TENT :  Emoji = Emoji::new("tent", Version(0, 6), "⛺"); // tent
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&BARBER_POLE, // BARBER_POLE
@@ -35236,6 +37582,10 @@ pub static TENT :  Emoji = Emoji::new("tent", Version(0, 6), "⛺"); // tent
 				
 			
 				
+				&PLAYGROUND_SLIDE, // PLAYGROUND_SLIDE
+				
+			
+				
 				&ROLLER_COASTER, // ROLLER_COASTER
 				
 			
@@ -35265,11 +37615,21 @@ pub static TENT :  Emoji = Emoji::new("tent", Version(0, 6), "⛺"); // tent
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // place_other
 	
 	/// place_religious ⛪🛕🕋
@@ -35288,6 +37648,9 @@ pub static TENT :  Emoji = Emoji::new("tent", Version(0, 6), "⛺"); // tent
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "place-religious";
+
 		// List of all emoji statics
 
 		
@@ -35332,7 +37695,7 @@ pub static SYNAGOGUE :  Emoji = Emoji::new("synagogue", Version(1, 0), "🕍");
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // CHURCH
 				
@@ -35373,7 +37736,7 @@ pub static SYNAGOGUE :  Emoji = Emoji::new("synagogue", Version(1, 0), "🕍");
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&CHURCH, // CHURCH
@@ -35409,11 +37772,21 @@ pub static SYNAGOGUE :  Emoji = Emoji::new("synagogue", Version(1, 0), "🕍");
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // place_religious
 	
 	/// sky_and_weather 🌂☁️🌩️
@@ -35432,6 +37805,9 @@ pub static SYNAGOGUE :  Emoji = Emoji::new("synagogue", Version(1, 0), "🕍");
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "sky & weather";
+
 		// List of all emoji statics
 
 		
@@ -35722,7 +38098,7 @@ pub static WIND_FACE :  Emoji = Emoji::new("wind face", Version(0, 7), "🌬️"
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // CLOSED_UMBRELLA
 				
@@ -36009,7 +38385,7 @@ pub static WIND_FACE :  Emoji = Emoji::new("wind face", Version(0, 7), "🌬️"
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&CLOSED_UMBRELLA, // CLOSED_UMBRELLA
@@ -36209,11 +38585,21 @@ pub static WIND_FACE :  Emoji = Emoji::new("wind face", Version(0, 7), "🌬️"
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // sky_and_weather
 	
 	/// time ⏰🕗🕣
@@ -36232,6 +38618,9 @@ pub static WIND_FACE :  Emoji = Emoji::new("wind face", Version(0, 7), "🌬️"
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "time";
+
 		// List of all emoji statics
 
 		
@@ -36426,7 +38815,7 @@ pub static WATCH :  Emoji = Emoji::new("watch", Version(0, 6), "⌚"); // watch
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ALARM_CLOCK
 				
@@ -36617,7 +39006,7 @@ pub static WATCH :  Emoji = Emoji::new("watch", Version(0, 6), "⌚"); // watch
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ALARM_CLOCK, // ALARM_CLOCK
@@ -36753,11 +39142,21 @@ pub static WATCH :  Emoji = Emoji::new("watch", Version(0, 6), "⌚"); // watch
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // time
 	
 	/// transport_air 🚡✈️🛬
@@ -36776,6 +39175,9 @@ pub static WATCH :  Emoji = Emoji::new("watch", Version(0, 6), "⌚"); // watch
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "transport-air";
+
 		// List of all emoji statics
 
 		
@@ -36862,7 +39264,7 @@ pub static SUSPENSION_RAILWAY :  Emoji = Emoji::new("suspension railway", Versio
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // AERIAL_TRAMWAY
 				
@@ -36945,7 +39347,7 @@ pub static SUSPENSION_RAILWAY :  Emoji = Emoji::new("suspension railway", Versio
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&AERIAL_TRAMWAY, // AERIAL_TRAMWAY
@@ -37009,11 +39411,21 @@ pub static SUSPENSION_RAILWAY :  Emoji = Emoji::new("suspension railway", Versio
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // transport_air
 	
 	/// transport_ground 🚑🚛🚗
@@ -37032,6 +39444,9 @@ pub static SUSPENSION_RAILWAY :  Emoji = Emoji::new("suspension railway", Versio
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "transport-ground";
+
 		// List of all emoji statics
 
 		
@@ -37329,12 +39744,18 @@ pub static TROLLEYBUS :  Emoji = Emoji::new("trolleybus", Version(1, 0), "🚎")
 #[doc="```"]
 pub static VERTICAL_TRAFFIC_LIGHT :  Emoji = Emoji::new("vertical traffic light", Version(1, 0), "🚦"); // vertical traffic light
 		
+		#[doc="wheel 🛞"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::WHEEL;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", WHEEL); // 🛞 (`U+1F6DE`)"] #[doc="# assert_eq!(WHEEL.to_string().as_str(), \"🛞\");"]
+#[doc="```"]
+pub static WHEEL :  Emoji = Emoji::new("wheel", Version(14, 0), "🛞"); // wheel
+		
 
 
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // AMBULANCE
 				
@@ -37630,10 +40051,16 @@ pub static VERTICAL_TRAFFIC_LIGHT :  Emoji = Emoji::new("vertical traffic light"
 				
 			],
 			
+			&[ // WHEEL
+				
+				&WHEEL,
+				
+			],
+			
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&AMBULANCE, // AMBULANCE
@@ -37831,6 +40258,10 @@ pub static VERTICAL_TRAFFIC_LIGHT :  Emoji = Emoji::new("vertical traffic light"
 				&VERTICAL_TRAFFIC_LIGHT, // VERTICAL_TRAFFIC_LIGHT
 				
 			
+				
+				&WHEEL, // WHEEL
+				
+			
 		];
 
 
@@ -37841,11 +40272,21 @@ pub static VERTICAL_TRAFFIC_LIGHT :  Emoji = Emoji::new("vertical traffic light"
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // transport_ground
 	
 	/// transport_water ⚓🛶⛴️
@@ -37864,6 +40305,9 @@ pub static VERTICAL_TRAFFIC_LIGHT :  Emoji = Emoji::new("vertical traffic light"
 		use crate::emojis::WithNoDef;
 		use crate::emojis::Version;
 
+		/// The Unicode name of this subgroup
+		pub const NAME: &str = "transport-water";
+
 		// List of all emoji statics
 
 		
@@ -37897,6 +40341,12 @@ pub static MOTOR_BOAT :  Emoji = Emoji::new("motor boat", Version(0, 7), "🛥
 #[doc="```"]
 pub static PASSENGER_SHIP :  Emoji = Emoji::new("passenger ship", Version(0, 7), "🛳️"); // passenger ship
 		
+		#[doc="ring buoy 🛟"]#[doc=""]#[doc="Since E14.0"]#[doc=""]#[doc="# Examples"] #[doc="```"]
+#[doc="use emojic::flat::RING_BUOY;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
+#[doc="println!(\"{}\", RING_BUOY); // 🛟 (`U+1F6DF`)"] #[doc="# assert_eq!(RING_BUOY.to_string().as_str(), \"🛟\");"]
+#[doc="```"]
+pub static RING_BUOY :  Emoji = Emoji::new("ring buoy", Version(14, 0), "🛟"); // ring buoy
+		
 		#[doc="sailboat ⛵"]#[doc=""]#[doc="Since E0.6"]#[doc=""]#[doc="# Examples"] #[doc="```"]
 #[doc="use emojic::flat::SAILBOAT;"]#[doc="use emojic::Tone;"]#[doc="use emojic::Gender;"]#[doc="use emojic::Hair;"]#[doc="use emojic::Pair;"] #[doc=""]
 #[doc="println!(\"{}\", SAILBOAT); // ⛵ (`U+26F5`)"] #[doc="# assert_eq!(SAILBOAT.to_string().as_str(), \"⛵\");"]
@@ -37920,7 +40370,7 @@ pub static SPEEDBOAT :  Emoji = Emoji::new("speedboat", Version(0, 6), "🚤");
 		// Statics with slice of all emojis
 
 		/// Contains the list of all variants of each emoji.
-		static ALL_VARIANTS: &[&[&Emoji]] = &[
+		pub(crate) static ALL_VARIANTS: &[&[&Emoji]] = &[
 			
 			&[ // ANCHOR
 				
@@ -37952,6 +40402,12 @@ pub static SPEEDBOAT :  Emoji = Emoji::new("speedboat", Version(0, 6), "🚤");
 				
 			],
 			
+			&[ // RING_BUOY
+				
+				&RING_BUOY,
+				
+			],
+			
 			&[ // SAILBOAT
 				
 				&SAILBOAT,
@@ -37973,7 +40429,7 @@ pub static SPEEDBOAT :  Emoji = Emoji::new("speedboat", Version(0, 6), "🚤");
 		];
 
 		/// Contains the base emoij of each emoji.
-		static ALL_BASE_EMOJIS: &[&Emoji] = &[
+		pub(crate) static ALL_BASE_EMOJIS: &[&Emoji] = &[
 			
 				
 				&ANCHOR, // ANCHOR
@@ -37996,6 +40452,10 @@ pub static SPEEDBOAT :  Emoji = Emoji::new("speedboat", Version(0, 6), "🚤");
 				
 			
 				
+				&RING_BUOY, // RING_BUOY
+				
+			
+				
 				&SAILBOAT, // SAILBOAT
 				
 			
@@ -38017,15 +40477,55 @@ pub static SPEEDBOAT :  Emoji = Emoji::new("speedboat", Version(0, 6), "🚤");
 			ALL_VARIANTS.iter().copied()
 		}
 
+		/// Returns an iterator over all emoji variants of this subgroup one by one
+		pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+			all_variants().flat_map(|variants| variants.iter().copied())
+		}
+
 		/// Returns an iterator over all base emojis of this subgroup (i.e. one for each static here)
 		pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 			ALL_BASE_EMOJIS.iter().copied()
 		}
 
+		/// Returns an iterator over the name and the grapheme of all base emojis of this subgroup
+		pub fn names() -> impl Iterator<Item=(&'static str, &'static str)> {
+			base_emojis().map(|e| (e.name, e.grapheme))
+		}
+
 	} // transport_water
 	
 
 
+	// Subgroup metadata
+
+	/// Contains the metadata of each subgroup of this group.
+	pub(crate) static SUBGROUPS: &[Subgroup] = &[
+		
+		Subgroup::new(hotel::NAME, hotel::ALL_VARIANTS, hotel::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(place_building::NAME, place_building::ALL_VARIANTS, place_building::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(place_geographic::NAME, place_geographic::ALL_VARIANTS, place_geographic::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(place_map::NAME, place_map::ALL_VARIANTS, place_map::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(place_other::NAME, place_other::ALL_VARIANTS, place_other::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(place_religious::NAME, place_religious::ALL_VARIANTS, place_religious::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(sky_and_weather::NAME, sky_and_weather::ALL_VARIANTS, sky_and_weather::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(time::NAME, time::ALL_VARIANTS, time::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(transport_air::NAME, transport_air::ALL_VARIANTS, transport_air::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(transport_ground::NAME, transport_ground::ALL_VARIANTS, transport_ground::ALL_BASE_EMOJIS),
+		
+		Subgroup::new(transport_water::NAME, transport_water::ALL_VARIANTS, transport_water::ALL_BASE_EMOJIS),
+		
+	];
+
+
 	// Emoji iterators
 
 	/// Returns an iterator over all emoji variants of these subgroups grouped by base emojis
@@ -38056,6 +40556,11 @@ pub static SPEEDBOAT :  Emoji = Emoji::new("speedboat", Version(0, 6), "🚤");
 		
 	}
 
+	/// Returns an iterator over all emoji variants of these subgroups one by one
+	pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+		all_variants().flat_map(|variants| variants.iter().copied())
+	}
+
 	/// Returns an iterator over all base emojis of these subgroups (i.e. one for each static)
 	pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
 		core::iter::empty()
@@ -38088,58 +40593,709 @@ pub static SPEEDBOAT :  Emoji = Emoji::new("speedboat", Version(0, 6), "🚤");
 
 
 
-// Emoji iterators
+// Group metadata
 
-/// Returns an iterator over all emoji variants of all groups together grouped by base emojis
-pub fn all_variants() -> impl Iterator<Item=&'static [&'static Emoji]> {
-	core::iter::empty()
+/// Contains the group name, the subgroup name, and all emoji variants of each subgroup.
+pub(crate) static ALL_SUBGROUPS: &[(&str, &str, &[&[&Emoji]])] = &[
 	
-		.chain(activities::all_variants())
 	
-		.chain(animals_and_nature::all_variants())
+	#[cfg(emojic_group = "activities")]
+	(activities::NAME, activities::arts_and_crafts::NAME, activities::arts_and_crafts::ALL_VARIANTS),
 	
-		.chain(component::all_variants())
+	#[cfg(emojic_group = "activities")]
+	(activities::NAME, activities::award_medal::NAME, activities::award_medal::ALL_VARIANTS),
 	
-		.chain(flags::all_variants())
+	#[cfg(emojic_group = "activities")]
+	(activities::NAME, activities::event::NAME, activities::event::ALL_VARIANTS),
 	
-		.chain(food_and_drink::all_variants())
+	#[cfg(emojic_group = "activities")]
+	(activities::NAME, activities::game::NAME, activities::game::ALL_VARIANTS),
 	
-		.chain(objects::all_variants())
+	#[cfg(emojic_group = "activities")]
+	(activities::NAME, activities::sport::NAME, activities::sport::ALL_VARIANTS),
 	
-		.chain(people_and_body::all_variants())
 	
-		.chain(smileys_and_emotion::all_variants())
 	
-		.chain(symbols::all_variants())
+	#[cfg(emojic_group = "animals_and_nature")]
+	(animals_and_nature::NAME, animals_and_nature::animal_amphibian::NAME, animals_and_nature::animal_amphibian::ALL_VARIANTS),
 	
-		.chain(travel_and_places::all_variants())
+	#[cfg(emojic_group = "animals_and_nature")]
+	(animals_and_nature::NAME, animals_and_nature::animal_bird::NAME, animals_and_nature::animal_bird::ALL_VARIANTS),
 	
-}
+	#[cfg(emojic_group = "animals_and_nature")]
+	(animals_and_nature::NAME, animals_and_nature::animal_bug::NAME, animals_and_nature::animal_bug::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "animals_and_nature")]
+	(animals_and_nature::NAME, animals_and_nature::animal_mammal::NAME, animals_and_nature::animal_mammal::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "animals_and_nature")]
+	(animals_and_nature::NAME, animals_and_nature::animal_marine::NAME, animals_and_nature::animal_marine::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "animals_and_nature")]
+	(animals_and_nature::NAME, animals_and_nature::animal_reptile::NAME, animals_and_nature::animal_reptile::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "animals_and_nature")]
+	(animals_and_nature::NAME, animals_and_nature::plant_flower::NAME, animals_and_nature::plant_flower::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "animals_and_nature")]
+	(animals_and_nature::NAME, animals_and_nature::plant_other::NAME, animals_and_nature::plant_other::ALL_VARIANTS),
+	
+	
+	
+	#[cfg(emojic_group = "component")]
+	(component::NAME, component::hair_style::NAME, component::hair_style::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "component")]
+	(component::NAME, component::skin_tone::NAME, component::skin_tone::ALL_VARIANTS),
+	
+	
+	
+	#[cfg(emojic_group = "flags")]
+	(flags::NAME, flags::country_flag::NAME, flags::country_flag::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "flags")]
+	(flags::NAME, flags::flag::NAME, flags::flag::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "flags")]
+	(flags::NAME, flags::subdivision_flag::NAME, flags::subdivision_flag::ALL_VARIANTS),
+	
+	
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	(food_and_drink::NAME, food_and_drink::dishware::NAME, food_and_drink::dishware::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	(food_and_drink::NAME, food_and_drink::drink::NAME, food_and_drink::drink::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	(food_and_drink::NAME, food_and_drink::food_asian::NAME, food_and_drink::food_asian::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	(food_and_drink::NAME, food_and_drink::food_fruit::NAME, food_and_drink::food_fruit::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	(food_and_drink::NAME, food_and_drink::food_marine::NAME, food_and_drink::food_marine::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	(food_and_drink::NAME, food_and_drink::food_prepared::NAME, food_and_drink::food_prepared::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	(food_and_drink::NAME, food_and_drink::food_sweet::NAME, food_and_drink::food_sweet::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	(food_and_drink::NAME, food_and_drink::food_vegetable::NAME, food_and_drink::food_vegetable::ALL_VARIANTS),
+	
+	
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::book_paper::NAME, objects::book_paper::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::clothing::NAME, objects::clothing::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::computer::NAME, objects::computer::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::household::NAME, objects::household::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::light_and_video::NAME, objects::light_and_video::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::lock::NAME, objects::lock::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::mail::NAME, objects::mail::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::medical::NAME, objects::medical::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::money::NAME, objects::money::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::music::NAME, objects::music::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::musical_instrument::NAME, objects::musical_instrument::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::office::NAME, objects::office::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::other_object::NAME, objects::other_object::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::phone::NAME, objects::phone::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::science::NAME, objects::science::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::sound::NAME, objects::sound::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::tool::NAME, objects::tool::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "objects")]
+	(objects::NAME, objects::writing::NAME, objects::writing::ALL_VARIANTS),
+	
+	
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::body_parts::NAME, people_and_body::body_parts::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::family::NAME, people_and_body::family::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::hand_fingers_closed::NAME, people_and_body::hand_fingers_closed::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::hand_fingers_open::NAME, people_and_body::hand_fingers_open::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::hand_fingers_partial::NAME, people_and_body::hand_fingers_partial::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::hand_prop::NAME, people_and_body::hand_prop::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::hand_single_finger::NAME, people_and_body::hand_single_finger::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::hands::NAME, people_and_body::hands::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::person::NAME, people_and_body::person::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::person_activity::NAME, people_and_body::person_activity::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::person_fantasy::NAME, people_and_body::person_fantasy::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::person_gesture::NAME, people_and_body::person_gesture::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::person_resting::NAME, people_and_body::person_resting::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::person_role::NAME, people_and_body::person_role::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::person_sport::NAME, people_and_body::person_sport::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	(people_and_body::NAME, people_and_body::person_symbol::NAME, people_and_body::person_symbol::ALL_VARIANTS),
+	
+	
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::cat_face::NAME, smileys_and_emotion::cat_face::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::emotion::NAME, smileys_and_emotion::emotion::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::face_affection::NAME, smileys_and_emotion::face_affection::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::face_concerned::NAME, smileys_and_emotion::face_concerned::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::face_costume::NAME, smileys_and_emotion::face_costume::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::face_glasses::NAME, smileys_and_emotion::face_glasses::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::face_hand::NAME, smileys_and_emotion::face_hand::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::face_hat::NAME, smileys_and_emotion::face_hat::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::face_negative::NAME, smileys_and_emotion::face_negative::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::face_neutral_skeptical::NAME, smileys_and_emotion::face_neutral_skeptical::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::face_sleepy::NAME, smileys_and_emotion::face_sleepy::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::face_smiling::NAME, smileys_and_emotion::face_smiling::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::face_tongue::NAME, smileys_and_emotion::face_tongue::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::face_unwell::NAME, smileys_and_emotion::face_unwell::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	(smileys_and_emotion::NAME, smileys_and_emotion::monkey_face::NAME, smileys_and_emotion::monkey_face::ALL_VARIANTS),
+	
+	
+	
+	#[cfg(emojic_group = "symbols")]
+	(symbols::NAME, symbols::alphanum::NAME, symbols::alphanum::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "symbols")]
+	(symbols::NAME, symbols::arrow::NAME, symbols::arrow::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "symbols")]
+	(symbols::NAME, symbols::av_symbol::NAME, symbols::av_symbol::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "symbols")]
+	(symbols::NAME, symbols::currency::NAME, symbols::currency::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "symbols")]
+	(symbols::NAME, symbols::gender::NAME, symbols::gender::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "symbols")]
+	(symbols::NAME, symbols::geometric::NAME, symbols::geometric::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "symbols")]
+	(symbols::NAME, symbols::keycap::NAME, symbols::keycap::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "symbols")]
+	(symbols::NAME, symbols::math::NAME, symbols::math::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "symbols")]
+	(symbols::NAME, symbols::other_symbol::NAME, symbols::other_symbol::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "symbols")]
+	(symbols::NAME, symbols::punctuation::NAME, symbols::punctuation::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "symbols")]
+	(symbols::NAME, symbols::religion::NAME, symbols::religion::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "symbols")]
+	(symbols::NAME, symbols::transport_sign::NAME, symbols::transport_sign::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "symbols")]
+	(symbols::NAME, symbols::warning::NAME, symbols::warning::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "symbols")]
+	(symbols::NAME, symbols::zodiac::NAME, symbols::zodiac::ALL_VARIANTS),
+	
+	
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	(travel_and_places::NAME, travel_and_places::hotel::NAME, travel_and_places::hotel::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	(travel_and_places::NAME, travel_and_places::place_building::NAME, travel_and_places::place_building::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	(travel_and_places::NAME, travel_and_places::place_geographic::NAME, travel_and_places::place_geographic::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	(travel_and_places::NAME, travel_and_places::place_map::NAME, travel_and_places::place_map::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	(travel_and_places::NAME, travel_and_places::place_other::NAME, travel_and_places::place_other::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	(travel_and_places::NAME, travel_and_places::place_religious::NAME, travel_and_places::place_religious::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	(travel_and_places::NAME, travel_and_places::sky_and_weather::NAME, travel_and_places::sky_and_weather::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	(travel_and_places::NAME, travel_and_places::time::NAME, travel_and_places::time::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	(travel_and_places::NAME, travel_and_places::transport_air::NAME, travel_and_places::transport_air::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	(travel_and_places::NAME, travel_and_places::transport_ground::NAME, travel_and_places::transport_ground::ALL_VARIANTS),
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	(travel_and_places::NAME, travel_and_places::transport_water::NAME, travel_and_places::transport_water::ALL_VARIANTS),
+	
+	
+];
 
-/// Returns an iterator over all base emojis of all groups together (i.e. one for each static)
-pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
-	core::iter::empty()
+/// Contains the base emojis of each subgroup.
+static ALL_BASE_EMOJIS: &[&[&Emoji]] = &[
+	
+	
+	#[cfg(emojic_group = "activities")]
+	activities::arts_and_crafts::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "activities")]
+	activities::award_medal::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "activities")]
+	activities::event::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "activities")]
+	activities::game::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "activities")]
+	activities::sport::ALL_BASE_EMOJIS,
+	
+	
+	
+	#[cfg(emojic_group = "animals_and_nature")]
+	animals_and_nature::animal_amphibian::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "animals_and_nature")]
+	animals_and_nature::animal_bird::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "animals_and_nature")]
+	animals_and_nature::animal_bug::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "animals_and_nature")]
+	animals_and_nature::animal_mammal::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "animals_and_nature")]
+	animals_and_nature::animal_marine::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "animals_and_nature")]
+	animals_and_nature::animal_reptile::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "animals_and_nature")]
+	animals_and_nature::plant_flower::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "animals_and_nature")]
+	animals_and_nature::plant_other::ALL_BASE_EMOJIS,
+	
+	
+	
+	#[cfg(emojic_group = "component")]
+	component::hair_style::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "component")]
+	component::skin_tone::ALL_BASE_EMOJIS,
+	
+	
+	
+	#[cfg(emojic_group = "flags")]
+	flags::country_flag::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "flags")]
+	flags::flag::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "flags")]
+	flags::subdivision_flag::ALL_BASE_EMOJIS,
+	
+	
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	food_and_drink::dishware::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	food_and_drink::drink::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	food_and_drink::food_asian::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	food_and_drink::food_fruit::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	food_and_drink::food_marine::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	food_and_drink::food_prepared::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	food_and_drink::food_sweet::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	food_and_drink::food_vegetable::ALL_BASE_EMOJIS,
+	
+	
+	
+	#[cfg(emojic_group = "objects")]
+	objects::book_paper::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::clothing::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::computer::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::household::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::light_and_video::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::lock::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::mail::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::medical::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::money::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::music::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::musical_instrument::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::office::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::other_object::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::phone::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::science::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::sound::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::tool::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "objects")]
+	objects::writing::ALL_BASE_EMOJIS,
+	
+	
+	
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::body_parts::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::family::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::hand_fingers_closed::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::hand_fingers_open::ALL_BASE_EMOJIS,
 	
-		.chain(activities::base_emojis())
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::hand_fingers_partial::ALL_BASE_EMOJIS,
 	
-		.chain(animals_and_nature::base_emojis())
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::hand_prop::ALL_BASE_EMOJIS,
 	
-		.chain(component::base_emojis())
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::hand_single_finger::ALL_BASE_EMOJIS,
 	
-		.chain(flags::base_emojis())
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::hands::ALL_BASE_EMOJIS,
 	
-		.chain(food_and_drink::base_emojis())
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::person::ALL_BASE_EMOJIS,
 	
-		.chain(objects::base_emojis())
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::person_activity::ALL_BASE_EMOJIS,
 	
-		.chain(people_and_body::base_emojis())
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::person_fantasy::ALL_BASE_EMOJIS,
 	
-		.chain(smileys_and_emotion::base_emojis())
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::person_gesture::ALL_BASE_EMOJIS,
 	
-		.chain(symbols::base_emojis())
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::person_resting::ALL_BASE_EMOJIS,
 	
-		.chain(travel_and_places::base_emojis())
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::person_role::ALL_BASE_EMOJIS,
 	
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::person_sport::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "people_and_body")]
+	people_and_body::person_symbol::ALL_BASE_EMOJIS,
+	
+	
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::cat_face::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::emotion::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::face_affection::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::face_concerned::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::face_costume::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::face_glasses::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::face_hand::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::face_hat::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::face_negative::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::face_neutral_skeptical::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::face_sleepy::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::face_smiling::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::face_tongue::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::face_unwell::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	smileys_and_emotion::monkey_face::ALL_BASE_EMOJIS,
+	
+	
+	
+	#[cfg(emojic_group = "symbols")]
+	symbols::alphanum::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "symbols")]
+	symbols::arrow::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "symbols")]
+	symbols::av_symbol::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "symbols")]
+	symbols::currency::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "symbols")]
+	symbols::gender::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "symbols")]
+	symbols::geometric::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "symbols")]
+	symbols::keycap::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "symbols")]
+	symbols::math::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "symbols")]
+	symbols::other_symbol::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "symbols")]
+	symbols::punctuation::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "symbols")]
+	symbols::religion::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "symbols")]
+	symbols::transport_sign::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "symbols")]
+	symbols::warning::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "symbols")]
+	symbols::zodiac::ALL_BASE_EMOJIS,
+	
+	
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	travel_and_places::hotel::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	travel_and_places::place_building::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	travel_and_places::place_geographic::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	travel_and_places::place_map::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	travel_and_places::place_other::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	travel_and_places::place_religious::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	travel_and_places::sky_and_weather::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	travel_and_places::time::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	travel_and_places::transport_air::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	travel_and_places::transport_ground::ALL_BASE_EMOJIS,
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	travel_and_places::transport_water::ALL_BASE_EMOJIS,
+	
+	
+];
+
+/// Contains the metadata of each group.
+pub(crate) static ALL_GROUPS: &[Group] = &[
+	
+	#[cfg(emojic_group = "activities")]
+	Group::new(activities::NAME, activities::SUBGROUPS),
+	
+	#[cfg(emojic_group = "animals_and_nature")]
+	Group::new(animals_and_nature::NAME, animals_and_nature::SUBGROUPS),
+	
+	#[cfg(emojic_group = "component")]
+	Group::new(component::NAME, component::SUBGROUPS),
+	
+	#[cfg(emojic_group = "flags")]
+	Group::new(flags::NAME, flags::SUBGROUPS),
+	
+	#[cfg(emojic_group = "food_and_drink")]
+	Group::new(food_and_drink::NAME, food_and_drink::SUBGROUPS),
+	
+	#[cfg(emojic_group = "objects")]
+	Group::new(objects::NAME, objects::SUBGROUPS),
+	
+	#[cfg(emojic_group = "people_and_body")]
+	Group::new(people_and_body::NAME, people_and_body::SUBGROUPS),
+	
+	#[cfg(emojic_group = "smileys_and_emotion")]
+	Group::new(smileys_and_emotion::NAME, smileys_and_emotion::SUBGROUPS),
+	
+	#[cfg(emojic_group = "symbols")]
+	Group::new(symbols::NAME, symbols::SUBGROUPS),
+	
+	#[cfg(emojic_group = "travel_and_places")]
+	Group::new(travel_and_places::NAME, travel_and_places::SUBGROUPS),
+	
+];
+
+
+// Emoji iterators
+
+/// Returns an iterator over all emoji variants of all groups together grouped by base emojis
+pub fn all_variants() -> impl Iterator<Item=&'static [&'static Emoji]> {
+	ALL_SUBGROUPS.iter().flat_map(|(_, _, variants)| variants.iter().copied())
+}
+
+/// Returns an iterator over all emoji variants of all groups together one by one
+pub fn all_variant_emojis() -> impl Iterator<Item=&'static Emoji> {
+	all_variants().flat_map(|variants| variants.iter().copied())
+}
+
+/// Returns an iterator over all base emojis of all groups together (i.e. one for each static)
+pub fn base_emojis() -> impl Iterator<Item=&'static Emoji> {
+	ALL_BASE_EMOJIS.iter().flat_map(|emojis| emojis.iter().copied())
 }
 
 // EOF
\ No newline at end of file
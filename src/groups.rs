@@ -0,0 +1,230 @@
+//! Runtime reflection over the emoji groups
+//!
+//! This module allows to browse the categorization of the [`grouped`](crate::grouped) module
+//! dynamically, e.g. to build a nested emoji picker without naming each module path.
+//!
+//! # Examples
+//!
+//! ```
+//! for group in emojic::groups() {
+//!     println!("{}:", group.name());
+//!     for subgroup in group.subgroups() {
+//!         let emojis: String = subgroup.emojis().map(|e| e.grapheme).collect();
+//!         println!("  {}: {}", subgroup.name(), emojis);
+//!     }
+//! }
+//! ```
+
+use crate::emojis::Emoji;
+
+pub use crate::group_ids::GroupId;
+pub use crate::group_ids::SubgroupId;
+
+/// An emoji group, such as "Smileys & Emotion".
+///
+/// All groups are listed by [`groups`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Group {
+    name: &'static str,
+    subgroups: &'static [Subgroup],
+}
+impl Group {
+    pub(crate) const fn new(name: &'static str, subgroups: &'static [Subgroup]) -> Self {
+        Group { name, subgroups }
+    }
+
+    /// The Unicode name of this group, e.g. `"Smileys & Emotion"`
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns an iterator over all subgroups of this group
+    pub fn subgroups(&self) -> impl Iterator<Item = Subgroup> {
+        self.subgroups.iter().copied()
+    }
+
+    /// Returns an iterator over all emoji variants of this group grouped by base emojis
+    pub fn all_variants(&self) -> impl Iterator<Item = &'static [&'static Emoji]> {
+        self.subgroups.iter().flat_map(|sub| sub.all_variants())
+    }
+
+    /// Returns an iterator over all base emojis of this group
+    pub fn emojis(&self) -> impl Iterator<Item = &'static Emoji> {
+        self.subgroups.iter().flat_map(|sub| sub.emojis())
+    }
+}
+
+/// An emoji subgroup, such as "face-smiling".
+///
+/// The subgroups are listed by [`Group::subgroups`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Subgroup {
+    name: &'static str,
+    variants: &'static [&'static [&'static Emoji]],
+    base_emojis: &'static [&'static Emoji],
+}
+impl Subgroup {
+    pub(crate) const fn new(
+        name: &'static str,
+        variants: &'static [&'static [&'static Emoji]],
+        base_emojis: &'static [&'static Emoji],
+    ) -> Self {
+        Subgroup {
+            name,
+            variants,
+            base_emojis,
+        }
+    }
+
+    /// The Unicode name of this subgroup, e.g. `"face-smiling"`
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns an iterator over all emoji variants of this subgroup grouped by base emojis
+    pub fn all_variants(&self) -> impl Iterator<Item = &'static [&'static Emoji]> {
+        self.variants.iter().copied()
+    }
+
+    /// Returns an iterator over all base emojis of this subgroup
+    pub fn emojis(&self) -> impl Iterator<Item = &'static Emoji> {
+        self.base_emojis.iter().copied()
+    }
+}
+
+/// Returns an iterator over all emoji groups.
+///
+/// The groups are in the order of the [`grouped`](crate::grouped) module, and only those
+/// included by the `group-*` features are present.
+///
+/// # Examples
+///
+/// ```
+/// let smileys = emojic::groups()
+///     .find(|g| g.name() == "Smileys & Emotion")
+///     .unwrap();
+/// let smiling = smileys
+///     .subgroups()
+///     .find(|s| s.name() == "face-smiling")
+///     .unwrap();
+/// assert!(smiling.emojis().any(|e| e == &emojic::flat::GRINNING_FACE));
+/// ```
+pub fn groups() -> impl Iterator<Item = Group> {
+    crate::grouped::ALL_GROUPS.iter().copied()
+}
+
+impl GroupId {
+    /// Looks up the group of the given Unicode [`name`](Self::name)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|group| group.name() == name)
+    }
+
+    /// Returns an iterator over all subgroups of this group
+    pub fn subgroups(self) -> impl Iterator<Item = SubgroupId> {
+        SubgroupId::ALL
+            .iter()
+            .copied()
+            .filter(move |subgroup| subgroup.group() == self)
+    }
+
+    /// Returns the metadata of this group, if it is included by the `group-*` features
+    pub fn group(self) -> Option<Group> {
+        groups().find(|group| group.name() == self.name())
+    }
+}
+
+impl SubgroupId {
+    /// Looks up the subgroup of the given Unicode [`name`](Self::name)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|subgroup| subgroup.name() == name)
+    }
+
+    /// Returns the metadata of this subgroup, if it is included by the `group-*` features
+    pub fn subgroup(self) -> Option<Subgroup> {
+        self.group()
+            .group()?
+            .subgroups()
+            .find(|subgroup| subgroup.name() == self.name())
+    }
+}
+
+/// Returns the group and subgroup of the given emoji.
+///
+/// This is the strongly-typed counterpart of [`group_of`](crate::group_of), which allows to
+/// `match` on the category of an emoji. The variants of an emoji are in the same category.
+/// `None` is only returned for emojis which are not part of this crate.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::category_of;
+/// use emojic::groups::{GroupId, SubgroupId};
+/// use emojic::Tone;
+///
+/// let describe = |e| match category_of(e) {
+///     Some((GroupId::SmileysAndEmotion, _)) => "smiley",
+///     Some((_, SubgroupId::AnimalMarine)) => "sea animal",
+///     Some(_) => "other",
+///     None => "unknown",
+/// };
+/// assert_eq!("smiley", describe(&emojic::flat::GRINNING_FACE));
+/// assert_eq!("sea animal", describe(&emojic::flat::OCTOPUS));
+/// assert_eq!("other", describe(emojic::flat::WAVING_HAND.tone(Tone::Dark)));
+/// ```
+pub fn category_of(emoji: &Emoji) -> Option<(GroupId, SubgroupId)> {
+    let (_, subgroup) = crate::search::group_of(emoji)?;
+    let subgroup = SubgroupId::from_name(subgroup)?;
+    Some((subgroup.group(), subgroup))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_match_grouped() {
+        assert!(groups()
+            .flat_map(|g| g.emojis())
+            .eq(crate::grouped::base_emojis()));
+        assert!(groups()
+            .flat_map(|g| g.all_variants())
+            .eq(crate::grouped::all_variants()));
+        assert!(groups()
+            .flat_map(|g| g.all_variants())
+            .flat_map(|variants| variants.iter().copied())
+            .eq(crate::grouped::all_variant_emojis()));
+        assert!(groups()
+            .flat_map(|g| g.subgroups().map(move |s| (g.name(), s.name())))
+            .eq(crate::grouped::ALL_SUBGROUPS
+                .iter()
+                .map(|&(group, subgroup, _)| (group, subgroup))));
+    }
+
+    #[test]
+    fn ids_match_groups() {
+        assert!(groups()
+            .map(|g| g.name())
+            .eq(GroupId::ALL.iter().map(|g| g.name())));
+        for &group in GroupId::ALL {
+            assert_eq!(Some(group), GroupId::from_name(group.name()));
+            let subgroups = group.group().unwrap().subgroups().map(|s| s.name());
+            assert!(subgroups.eq(group.subgroups().map(|s| s.name())));
+        }
+        for &subgroup in SubgroupId::ALL {
+            assert_eq!(Some(subgroup), SubgroupId::from_name(subgroup.name()));
+            assert_eq!(subgroup.name(), subgroup.subgroup().unwrap().name());
+        }
+        assert_eq!(None, GroupId::from_name("Emojis"));
+
+        // The categories are consistent with the grouping
+        for (group, subgroup, variants) in crate::grouped::ALL_SUBGROUPS {
+            for emoji in variants.iter().flat_map(|v| v.iter()) {
+                let (group_id, subgroup_id) = category_of(emoji).unwrap();
+                assert_eq!((*group, *subgroup), (group_id.name(), subgroup_id.name()));
+            }
+        }
+    }
+}
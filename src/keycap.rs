@@ -0,0 +1,75 @@
+//! Keycap utilities
+//!
+//! Besides the keycap constants (e.g. [`KEYCAP_1`](crate::flat::KEYCAP_1)), keycaps can be
+//! composed ad-hoc from their base char.
+
+use alloc::string::String;
+
+/// The variation selector requesting the emoji presentation of the base char.
+const VARIATION_SELECTOR: char = '\u{FE0F}';
+/// The combining enclosing keycap.
+const ENCLOSING_KEYCAP: char = '\u{20E3}';
+
+/// Generate an ad-hoc keycap emoji.
+///
+/// Unicode defines keycaps for the digits `0`-`9`, `#`, and `*`, as the sequence of the base char,
+/// `U+FE0F`, and `U+20E3`. This function always emits this fully-qualified form (i.e. including
+/// the `U+FE0F`), and returns `None` for any other char.
+///
+/// # Examples
+/// ```
+/// use emojic::keycap;
+///
+/// assert_eq!(
+///     keycap('1'), // 1️⃣
+///     Some(emojic::flat::KEYCAP_1.to_string())
+/// );
+/// assert_eq!(
+///     keycap('*'), // *️⃣
+///     Some(emojic::flat::KEYCAP_ASTERISK.to_string())
+/// );
+/// assert_eq!(keycap('a'), None);
+/// ```
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn keycap(c: char) -> Option<String> {
+    if c.is_ascii_digit() || c == '#' || c == '*' {
+        Some([c, VARIATION_SELECTOR, ENCLOSING_KEYCAP].iter().collect())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Tests are going to be on development systems => there will be std.
+    extern crate std;
+    use std::prelude::v1::*;
+
+    #[test]
+    fn all_keycaps() {
+        use super::keycap;
+        use crate::flat::*;
+
+        let expected = [
+            ('0', KEYCAP_0),
+            ('1', KEYCAP_1),
+            ('2', KEYCAP_2),
+            ('3', KEYCAP_3),
+            ('4', KEYCAP_4),
+            ('5', KEYCAP_5),
+            ('6', KEYCAP_6),
+            ('7', KEYCAP_7),
+            ('8', KEYCAP_8),
+            ('9', KEYCAP_9),
+            ('#', KEYCAP_HASH),
+            ('*', KEYCAP_ASTERISK),
+        ];
+        for (c, emoji) in expected.iter() {
+            assert_eq!(keycap(*c).as_deref(), Some(emoji.grapheme));
+        }
+
+        assert_eq!(keycap('a'), None);
+        assert_eq!(keycap(' '), None);
+        assert_eq!(keycap('١'), None); // Arabic-Indic digit one
+    }
+}
@@ -26,6 +26,9 @@
 //! emojic = "0.4"
 //! ```
 //!
+//! The minimum supported Rust version is 1.81, which stabilized `core::error::Error` for the
+//! error types of this `no_std` crate. The optional `clap` feature may need a newer one.
+//!
 //! ## 🔧 Example
 //!
 //! ```rust
@@ -78,7 +81,7 @@
 //! ```
 //!
 //! This crate contains emojis constants based on the
-//! [Full Emoji List v13.1](https://unicode.org/Public/emoji/13.1/emoji-test.txt).
+//! [Full Emoji List v14.0](https://unicode.org/Public/emoji/14.0/emoji-test.txt).
 //! Including its categorization:
 //!
 //! ```rust
@@ -92,11 +95,22 @@
 //!
 //! ```rust
 //! # let text =
-//! // Iterates all hand emoji: 👏, 🙏, 🤝, 👐, 🤲, 🙌
+//! // Iterates all hand emoji: 👏, 🙏, 🤝, 🫶, 👐, 🤲, 🙌
 //! emojic::grouped::people_and_body::hands::base_emojis()
 //! #    .map(|e| e.to_string())
 //! #    .collect::<String>();
-//! # assert_eq!("👏🙏🤝👐🤲🙌", text);
+//! # assert_eq!("👏🙏🤝🫶👐🤲🙌", text);
+//! ```
+//!
+//! Or just their names together with their graphemes, e.g. to label them:
+//!
+//! ```rust
+//! # let mut names =
+//! // Iterates: ("clapping hands", "👏"), ("folded hands", "🙏"), ...
+//! emojic::grouped::people_and_body::hands::names()
+//! # ;
+//! # assert_eq!(Some(("clapping hands", "👏")), names.next());
+//! # assert_eq!(Some(("folded hands", "🙏")), names.next());
 //! ```
 //!
 //! Additional, it has functions to generate (arbitrary) country and regional flags.
@@ -115,6 +129,16 @@
 //! # } // Only with `alloc`
 //! ```
 //!
+//! Emojis can also be searched by (a part of) their name via
+//! [`search::by_name`].
+//!
+//! ```rust
+//! # use emojic::search::by_name;
+//! # assert_eq!(Some("🦀"),
+//! by_name("crab").next() // 🦀
+//! # .map(|e| e.grapheme));
+//! ```
+//!
 //! Finally, this crate has allows parsing emoji aliases from the [github/gemoji](https://github.com/github/gemoji)
 //! list via [`parse_alias`](crate::text::parse_alias).
 //!
@@ -156,24 +180,36 @@
 //! platforms. However, some additional functions need the `alloc` crate (normally part of `std`),
 //! thus it is enabled by default.
 //!
-//! - `default`: (implies `alloc`) \
+//! - `default`: (implies `alloc` and `hash-tables`) \
 //!   Automatically enabled if not opt-out:
 //!   ```toml
 //!   [dependencies.emojic]
 //!   version = "0.4"
 //!   default-features = false
 //!   ```
-//! - `alloc`: (implies `hashbrown` and `lazy_static`) \
+//! - `alloc`: \
 //!   Requires a global allocator,
 //!   enables some additional functions: the [`parse_text`](crate::text::parse_text) function and the
 //!   ad-hoc flag-functions ([`country_flag`](crate::country_flag) & [`regional_flag`](crate::regional_flag))
-//!   (the flag constants are unaffected).
+//!   and the ad-hoc [`keycap`](crate::keycap) and [`zwj_sequence`](crate::zwj_sequence) functions
+//!   (the flag, keycap, and sequence constants are unaffected).
+//! - `hash-tables`: (implies `alloc`, `hashbrown`, and `lazy_static`) \
+//!   Uses hash maps, which are built on first use, for faster lookups (e.g. by
+//!   [`parse_alias`] and [`lookup`]). Without this feature, the aliases are binary searched in
+//!   a static sorted slice, and the graphemes are searched linearly, which needs no
+//!   initialization at all. So, to use `alloc` without `lazy_static` and `hashbrown`:
+//!   ```toml
+//!   [dependencies.emojic]
+//!   version = "0.4"
+//!   default-features = false
+//!   features = ["alloc"]
+//!   ```
 //!
 //!   Notice, that `lazy_static`, by default, pulls-in `std` to use mutices for waiting.
 //!   This is good if you do have `std` available, and bad if not. However, the alternative is
 //!   to instruct `lazy_static` to use spinlocks instead. Yet, since crate-features are unified by
 //!   Cargo, it would be bad for all user that have `std`, to requiring it by default.
-//!   Instead, if you want to use this `alloc` feature, but you don't have `std`
+//!   Instead, if you want to use this `hash-tables` feature, but you don't have `std`
 //!   (e.g. in your binary crate), you can simply add `lazy_static` yourself, and make it to use
 //!   spinlocks, which will apply globally. E.g. add to your `Cargo.toml`:
 //!   ```toml
@@ -183,34 +219,146 @@
 //!   ```
 //!   Also see: <https://github.com/rust-lang-nursery/lazy-static.rs/issues/150>
 //!
+//! - `serde`: \
+//!   Implements serde's `Serialize` and `Deserialize` for [`Emoji`](crate::emojis::Emoji)
+//!   and its attributes (such as [`Tone`](crate::Tone)). Since emojis are static, only the
+//!   emojis of this crate can be deserialized, which is done by looking up their grapheme.
+//!   Also adds the `emojis::EmojiSpec` to persist customized emojis.
+//! - `regex`: \
+//!   Enables the `EMOJI_REGEX` constant, a regular expression matching any
+//!   emoji, e.g. for use with the [`regex`](https://crates.io/crates/regex) crate.
+//!   This is a rather large string, thus it is opt-in.
+//! - `rand`: \
+//!   Enables the `random` and `random_from_group`
+//!   functions to pick random emojis, using the [`rand`](https://crates.io/crates/rand) crate.
+//! - `unicode-width`: \
+//!   Enables `Emoji::display_width`, to measure the number of columns an emoji occupies,
+//!   e.g. to align emojis in terminal tables.
+//! - `fst`: (requires `std`) \
+//!   Looks up aliases (e.g. by [`parse_alias`]) in a finite-state transducer, which is
+//!   embedded as static bytes, using the [`fst`](https://crates.io/crates/fst) crate. It takes
+//!   precedence over the `hash-tables` for these lookups. This trades speed for binary size:
+//!   the lookups are slower than with the hash map as well as with the sorted alias slice of the
+//!   `no_std` fallback (see the `aliases` bench), yet without `hash-tables`, a small release
+//!   binary using [`parse_alias`] shrinks by about 140 kB. Together with the `hash-tables`, the
+//!   binary grows instead, since the hash map is still used for the other alias functions.
+//!   Notice, that the `fst` crate always requires `std`, so this feature is not available for
+//!   `no_std` targets.
+//! - `clap`: (implies `alloc`) \
+//!   Enables the `clap` module with a value parser to parse emojis from command line
+//!   arguments, using the [`clap`](https://crates.io/crates/clap) crate (which requires `std`).
+//! - `group-activities`, `group-animals-and-nature`, `group-component`, `group-flags`,
+//!   `group-food-and-drink`, `group-objects`, `group-people-and-body`,
+//!   `group-smileys-and-emotion`, `group-symbols`, `group-travel-and-places`: \
+//!   Selects the emoji groups to be included, i.e. the modules of [`grouped`] and the
+//!   respective statics in [`flat`]. Aliases, lookups, and iterators only cover the included
+//!   emojis (the `EMOJI_REGEX` still matches all emojis). If none of these features is enabled,
+//!   which is the default, all groups are included.
+//!   This is useful to reduce the binary size, e.g. for WASM. For instance, a small release
+//!   binary using [`parse_alias`] shrinks from about 2.0 MB to 0.55 MB by only
+//!   including the smileys:
+//!   ```toml
+//!   [dependencies.emojic]
+//!   version = "0.4"
+//!   features = ["group-smileys-and-emotion"]
+//!   ```
+//!   Notice, that Cargo unifies features, thus if any crate in your dependency tree selects a
+//!   group, only the selected groups are included.
+//!
 //!
 
-use cfg_if::cfg_if;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-cfg_if! {
-    if #[cfg(feature = "alloc")] {
-        extern crate alloc;
-        use alloc::string::String;
+#[cfg(feature = "hash-tables")]
+#[rustfmt::skip]
+#[allow(clippy::needless_borrow)]
+mod alias; // Generated module
 
-        #[rustfmt::skip]
-        mod alias; // Generated module
-    } else {
-        #[rustfmt::skip]
-        mod matching; // Generated module
-    }
-}
+#[cfg(feature = "fst")]
+#[rustfmt::skip]
+#[allow(clippy::needless_borrow)]
+mod alias_fst; // Generated module
+
+// Also compiled for the tests, to cross-check it against the `alias` module
+#[cfg(any(not(feature = "hash-tables"), test))]
+#[rustfmt::skip]
+#[allow(clippy::needless_borrow)]
+#[cfg_attr(feature = "fst", allow(dead_code))] // the FST is used for the lookups instead
+mod matching; // Generated module
 
 #[rustfmt::skip]
 pub mod flat; // Generated module
 
 #[rustfmt::skip]
+#[allow(clippy::needless_borrow)]
 pub mod grouped; // Generated module
 
+#[rustfmt::skip]
+mod group_ids; // Generated module
+
+mod error;
+pub use error::Error;
+
+pub mod groups;
+pub use groups::category_of;
+pub use groups::groups;
+
+pub mod search;
+pub use search::base_of;
+pub use search::by_codepoint_prefix;
+pub use search::extract_tone;
+pub use search::extract_tones;
+pub use search::from_codepoints;
+pub use search::from_hex;
+pub use search::group_of;
+pub use search::is_emoji_start;
+pub use search::lookup;
+#[cfg(feature = "alloc")]
+pub use search::normalize_grapheme;
+pub use search::parse;
+pub use search::supports_gender;
+pub use search::supports_hair;
+pub use search::supports_tone;
+
+#[rustfmt::skip]
+mod starts; // Generated module
+
+#[rustfmt::skip]
+mod qualified; // Generated module
+
+#[rustfmt::skip]
+mod supports; // Generated module
+
+#[rustfmt::skip]
+mod versions; // Generated module
+
+#[cfg(feature = "regex")]
+#[rustfmt::skip]
+mod regex; // Generated module
+#[cfg(feature = "regex")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "regex")))]
+pub use crate::regex::EMOJI_REGEX;
+
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "rand")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "rand")))]
+pub use random::{random, random_from_group};
+
+#[cfg(feature = "clap")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "clap")))]
+pub mod clap;
+
 pub mod text;
 // Reexported for backwards compatibility
+pub use text::aliases_for;
 pub use text::parse_alias;
 
 pub mod emojis;
+pub use emojis::apply;
+pub use emojis::version_histogram;
+pub use emojis::Customizable;
 pub use emojis::Gender;
 pub use emojis::Hair;
 pub use emojis::Pair;
@@ -218,137 +366,114 @@ pub use emojis::Tone;
 
 use emojis::Emoji;
 
-/// Generate an ad-hoc country flag.
-///
-/// This function allows to create arbitrary country flags.
-///
-/// The Unicode standard defines country flags based on the two-letter country codes
-/// (see ISO 3166-1 alpha-2). Notice most (if not all) fonts support only the defined
-/// codes, however, this function does not test whether the given code is in deed a well defined
-/// country code.
-///
-/// # Panics
-/// If the provided string contains characters other than exactly two ASCII letters (A-Z).
-///
-/// # Examples
-/// ```
-/// use emojic::country_flag;
-///
-/// assert_eq!(
-///     country_flag("EU"), // 🇪🇺
-///     emojic::flat::FLAG_EUROPEAN_UNION.to_string()
-/// );
-/// ```
-///
-/// But there is no validity test:
-///
-/// ```rust
-/// use emojic::country_flag;
-///
-/// println!("{}",
-///     country_flag("ZZ"), // 🇿🇿 (an invalid flag)
-/// );
-/// ```
-#[cfg(feature = "alloc")]
-#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
-pub fn country_flag(country_code: &str) -> String {
-    assert!(
-        country_code.chars().all(|c| c.is_ascii_alphabetic()),
-        "Only chars A-Z are allowed as country_code"
-    );
-    assert!(
-        country_code.len() == 2,
-        "Only exactly two chars are allowed as country_code"
-    );
-
-    country_code
-        .to_ascii_uppercase()
-        .chars()
-        .map(|c| core::char::from_u32(c as u32 - 'A' as u32 + '\u{1F1E6}' as u32).unwrap())
-        .collect()
-}
-
-// TODO: Remove `contry_flag` (without U) before releasing v0.4.0!
+pub mod prelude;
 
-// That's embarrassing: Originally `country_flag` had been misspelled as `contry_flag`
-// and with that name it has been released as v0.3.0!
-// Therefore, this misspelled function is kept here to keep it compatible, however it will just
-// redirect to the now correctly named function.
+pub mod flags;
+#[cfg(feature = "alloc")]
+#[allow(deprecated)]
+pub use flags::contry_flag;
+#[cfg(feature = "alloc")]
+pub use flags::country_flag;
+#[cfg(feature = "alloc")]
+pub use flags::country_flag_bytes;
+#[cfg(feature = "alloc")]
+pub use flags::flag_to_country;
+#[cfg(feature = "alloc")]
+pub use flags::flag_to_region;
+#[cfg(feature = "alloc")]
+pub use flags::regional_flag;
+#[cfg(feature = "alloc")]
+pub use flags::try_country_flag;
+#[cfg(feature = "alloc")]
+pub use flags::try_country_flag_bytes;
+#[cfg(feature = "alloc")]
+pub use flags::try_regional_flag;
+pub use flags::FlagError;
 
-/// Generate an ad-hoc country flag (use [`country_flag`] instead).
 #[cfg(feature = "alloc")]
-#[doc(hidden)] // we don't really need this in the docs.
-#[deprecated = "Just use country_flag instead (with U)"]
-pub fn contry_flag(country_code: &str) -> String {
-    country_flag(country_code)
-}
+mod keycap;
+#[cfg(feature = "alloc")]
+pub use keycap::keycap;
 
-/// Generate an ad-hoc regional flag.
-///
-/// This function allows to create arbitrary regional flags.
-///
-/// The Unicode standard defines regional flags based the ISO regions (see ISO 3166-2) which
-/// consist of the two-letter country code (ISO 3166-1 alpha-2) combined with up to three
-/// further characters to specify the region.
-///
-/// # Panics
-/// If the provided string contains characters other than ASCII.
-///
-/// # Examples
-/// ```
-/// use emojic::regional_flag;
-///
-/// assert_eq!(
-///     regional_flag("GB-ENG"), // 🏴󠁧󠁢󠁥󠁮󠁧󠁿 (England region of United Kingdom (GB))
-///     emojic::flat::FLAG_ENGLAND.to_string()
-/// );
-/// ```
-///
-/// But there is no validity test:
-///
-/// ```rust
-/// use emojic::regional_flag;
-///
-/// println!("{}",
-///     regional_flag("ZZ-ABC") // 🏴󠁺󠁺󠁡󠁢󠁣󠁿 (an invalid flag)
-/// );
-/// ```
 #[cfg(feature = "alloc")]
-#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
-pub fn regional_flag(regional_code: &str) -> String {
-    assert!(
-        regional_code
-            .chars()
-            .all(|c| c.is_ascii_alphanumeric() || c == '-'),
-        "Only ascii chars are allowed as regional_code"
-    );
-
-    let regional_code = regional_code.to_ascii_lowercase();
-
-    let code = regional_code
-        .chars()
-        .filter(|c| c.is_ascii_alphanumeric())
-        .map(|c| core::char::from_u32(c as u32 + '\u{E0000}' as u32).unwrap());
-
-    core::iter::once('🏴') // start symbol
-        .chain(code) // code as tag sequence
-        .chain(core::iter::once('\u{E007F}')) // end sequence tag
-        .collect()
-}
+mod zwj;
+#[cfg(feature = "alloc")]
+pub use zwj::zwj_sequence;
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    // Tests are going to be on development systems => there will be std.
+    extern crate std;
+    use std::prelude::v1::*;
+
+    use core::error::Error;
+
+    use crate::emojis::Version;
+    use crate::flags::FlagError;
+    use crate::text::parse_into;
+    use crate::Tone;
 
     #[test]
-    #[cfg(feature = "alloc")]
-    fn country_flag_test() {
-        assert_eq!(crate::flat::FLAG_GERMANY.grapheme, &country_flag("DE"));
+    fn errors_are_boxable() {
+        fn boxed<E: Error + Send + Sync + 'static>(err: E) -> Box<dyn Error + Send + Sync> {
+            Box::new(err)
+        }
+
+        let errors = [
+            boxed("purple".parse::<Tone>().unwrap_err()),
+            boxed("13".parse::<Version>().unwrap_err()),
+            boxed(parse_into(":crab:", &mut []).unwrap_err()),
+            boxed(FlagError::InvalidChar('1')),
+        ];
+        for err in &errors {
+            assert!(!err.to_string().is_empty());
+        }
+        assert!(boxed("a.1".parse::<Version>().unwrap_err())
+            .source()
+            .is_some());
+
+        // All of them also as the unified error, which can be downcast again
+        let err = boxed(crate::Error::from(FlagError::InvalidChar('1')));
+        assert_eq!(
+            Some(&crate::Error::Flag(FlagError::InvalidChar('1'))),
+            err.downcast_ref::<crate::Error>()
+        );
+        assert!(
+            boxed(crate::Error::from("a.1".parse::<Version>().unwrap_err()))
+                .source()
+                .is_some()
+        );
+        #[cfg(feature = "alloc")]
+        {
+            let err = crate::text::parse_text_strict("a :crabs: b").unwrap_err();
+            assert_eq!(err.to_string(), crate::Error::from(err).to_string());
+        }
+
+        // Also propagated by `?`
+        fn strict(text: &str) -> Result<usize, Box<dyn Error>> {
+            let mut buf = [0; 16];
+            Ok(parse_into(text, &mut buf)?)
+        }
+        assert_eq!(4, strict(":crab:").unwrap());
+        assert!(strict(":crab::crab::crab::crab::crab:").is_err());
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn emoji_regex_compiles() {
+        let re = ::regex::Regex::new(&format!("^(?:{})$", crate::EMOJI_REGEX)).unwrap();
+
+        assert!(crate::search::all().all(|e| re.is_match(e.grapheme)));
+        assert!(!re.is_match("crab"));
+        assert!(!re.is_match("👽👽"));
     }
 
+    #[cfg(feature = "regex")]
     #[test]
-    #[cfg(feature = "alloc")]
-    fn regional_flag_test() {
-        assert_eq!(crate::flat::FLAG_ENGLAND.grapheme, &regional_flag("GB-ENG"));
+    fn emoji_regex_longest_first() {
+        let re = ::regex::Regex::new(crate::EMOJI_REGEX).unwrap();
+
+        let found: Vec<_> = re.find_iter("👨‍👩‍👧 👨 🧑🏿‍🤝‍🧑🏻").map(|m| m.as_str()).collect();
+        assert_eq!(found, ["👨‍👩‍👧", "👨", "🧑🏿‍🤝‍🧑🏻"]);
     }
 }
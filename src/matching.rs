@@ -1,7 +1,7 @@
 // Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
 
-// Source: https://unicode.org/Public/emoji/13.1/emoji-test.txt
-// Created at: 2022-10-24 05:19:19.000413135 UTC
+// Source: https://raw.githubusercontent.com/github/gemoji/v4.1.0/db/emoji.json
+// Created at: 2026-10-16 13:54:22.427741416 UTC
 
 #![allow(unused_imports)]
 
@@ -13,3463 +13,2667 @@ use crate::emojis::OneOrTwo;
 use crate::emojis::TonePair;
 use crate::emojis::Family;
 
-/// Matches string (without colons) to emojis
-pub(crate) fn matching(s: &str) -> Option<&'static crate::Emoji> {
-	let len = s.len();
-	let bytes = s.as_bytes();
-
-	if len == 0 {
-		None
-	} else if len == 1 {
-		match bytes[0] {
-			b'a' => Some(&crate::flat::A_BUTTON_BLOOD_TYPE as &crate::Emoji),
-			b'b' => Some(&crate::flat::B_BUTTON_BLOOD_TYPE as &crate::Emoji),
-			b'm' => Some(&crate::flat::CIRCLED_M as &crate::Emoji),
-			b'o' => Some(&crate::flat::HOLLOW_RED_CIRCLE as &crate::Emoji),
-			b'v' => Some(&crate::flat::VICTORY_HAND.default as &crate::Emoji),
-			b'x' => Some(&crate::flat::CROSS_MARK as &crate::Emoji),
+/// All aliases (without colons) with their emojis, sorted by the alias for binary search
+pub(crate) static ALIASES: &[(&str, &crate::Emoji)] = &[
+        #[cfg(emojic_group = "people_and_body")] ("+1" , &crate::flat::THUMBS_UP.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("-1" , &crate::flat::THUMBS_DOWN.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("100" , &crate::flat::HUNDRED_POINTS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("1234" , &crate::flat::INPUT_NUMBERS as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("1st_place_medal" , &crate::flat::FIRST_PLACE_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("2nd_place_medal" , &crate::flat::SECOND_PLACE_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("3rd_place_medal" , &crate::flat::THIRD_PLACE_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("8ball" , &crate::flat::POOL_8_BALL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("a" , &crate::flat::A_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("a_button_blood_type" , &crate::flat::A_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ab" , &crate::flat::AB_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ab_button_blood_type" , &crate::flat::AB_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("abacus" , &crate::flat::ABACUS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("abc" , &crate::flat::INPUT_LATIN_LETTERS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("abcd" , &crate::flat::INPUT_LATIN_LOWERCASE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("accept" , &crate::flat::JAPANESE_ACCEPTABLE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("accordion" , &crate::flat::ACCORDION as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("adhesive_bandage" , &crate::flat::ADHESIVE_BANDAGE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("admission_tickets" , &crate::flat::ADMISSION_TICKETS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("adult" , &crate::flat::PERSON.default.default.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("aerial_tramway" , &crate::flat::AERIAL_TRAMWAY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("afghanistan" , &crate::flat::FLAG_AFGHANISTAN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("airplane" , &crate::flat::AIRPLANE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("airplane_arrival" , &crate::flat::AIRPLANE_ARRIVAL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("airplane_departure" , &crate::flat::AIRPLANE_DEPARTURE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("aland_islands" , &crate::flat::FLAG_ALAND_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("alarm_clock" , &crate::flat::ALARM_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("albania" , &crate::flat::FLAG_ALBANIA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("alembic" , &crate::flat::ALEMBIC as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("algeria" , &crate::flat::FLAG_ALGERIA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("alien" , &crate::flat::ALIEN as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("alien_monster" , &crate::flat::ALIEN_MONSTER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ambulance" , &crate::flat::AMBULANCE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("american_football" , &crate::flat::AMERICAN_FOOTBALL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("american_samoa" , &crate::flat::FLAG_AMERICAN_SAMOA as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("amphora" , &crate::flat::AMPHORA as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("anatomical_heart" , &crate::flat::ANATOMICAL_HEART as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("anchor" , &crate::flat::ANCHOR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("andorra" , &crate::flat::FLAG_ANDORRA as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("angel" , &crate::flat::BABY_ANGEL.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("anger" , &crate::flat::ANGER_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("anger_symbol" , &crate::flat::ANGER_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("angola" , &crate::flat::FLAG_ANGOLA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("angry" , &crate::flat::ANGRY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("angry_face" , &crate::flat::ANGRY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("angry_face_with_horns" , &crate::flat::ANGRY_FACE_WITH_HORNS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("anguilla" , &crate::flat::FLAG_ANGUILLA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("anguished" , &crate::flat::ANGUISHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("anguished_face" , &crate::flat::ANGUISHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("ant" , &crate::flat::ANT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("antarctica" , &crate::flat::FLAG_ANTARCTICA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("antenna_bars" , &crate::flat::ANTENNA_BARS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("antigua_barbuda" , &crate::flat::FLAG_ANTIGUA_AND_BARBUDA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("anxious_face_with_sweat" , &crate::flat::ANXIOUS_FACE_WITH_SWEAT as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("apple" , &crate::flat::RED_APPLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("aquarius" , &crate::flat::AQUARIUS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("argentina" , &crate::flat::FLAG_ARGENTINA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("aries" , &crate::flat::ARIES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("armenia" , &crate::flat::FLAG_ARMENIA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_backward" , &crate::flat::REVERSE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_double_down" , &crate::flat::FAST_DOWN_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_double_up" , &crate::flat::FAST_UP_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_down" , &crate::flat::DOWN_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_down_small" , &crate::flat::DOWNWARDS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_forward" , &crate::flat::PLAY_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_heading_down" , &crate::flat::RIGHT_ARROW_CURVING_DOWN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_heading_up" , &crate::flat::RIGHT_ARROW_CURVING_UP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_left" , &crate::flat::LEFT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_lower_left" , &crate::flat::DOWN_LEFT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_lower_right" , &crate::flat::DOWN_RIGHT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_right" , &crate::flat::RIGHT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_right_hook" , &crate::flat::LEFT_ARROW_CURVING_RIGHT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_up" , &crate::flat::UP_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_up_down" , &crate::flat::UP_DOWN_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_up_small" , &crate::flat::UPWARDS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_upper_left" , &crate::flat::UP_LEFT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrow_upper_right" , &crate::flat::UP_RIGHT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrows_clockwise" , &crate::flat::CLOCKWISE_VERTICAL_ARROWS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("arrows_counterclockwise" , &crate::flat::COUNTERCLOCKWISE_ARROWS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("art" , &crate::flat::ARTIST_PALETTE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("articulated_lorry" , &crate::flat::ARTICULATED_LORRY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("artificial_satellite" , &crate::flat::SATELLITE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("artist" , &crate::flat::ARTIST.default.default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("artist_palette" , &crate::flat::ARTIST_PALETTE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("aruba" , &crate::flat::FLAG_ARUBA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ascension_island" , &crate::flat::FLAG_ASCENSION_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("asterisk" , &crate::flat::KEYCAP_ASTERISK as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("astonished" , &crate::flat::ASTONISHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("astonished_face" , &crate::flat::ASTONISHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("astronaut" , &crate::flat::ASTRONAUT.default.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("athletic_shoe" , &crate::flat::RUNNING_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("atm" , &crate::flat::ATM_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("atm_sign" , &crate::flat::ATM_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("atom_symbol" , &crate::flat::ATOM_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("australia" , &crate::flat::FLAG_AUSTRALIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("austria" , &crate::flat::FLAG_AUSTRIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("auto_rickshaw" , &crate::flat::AUTO_RICKSHAW as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("automobile" , &crate::flat::AUTOMOBILE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("avocado" , &crate::flat::AVOCADO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("axe" , &crate::flat::AXE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("azerbaijan" , &crate::flat::FLAG_AZERBAIJAN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("b" , &crate::flat::B_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("b_button_blood_type" , &crate::flat::B_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("baby" , &crate::flat::BABY.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("baby_angel" , &crate::flat::BABY_ANGEL.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("baby_bottle" , &crate::flat::BABY_BOTTLE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("baby_chick" , &crate::flat::BABY_CHICK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("baby_symbol" , &crate::flat::BABY_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("back" , &crate::flat::BACK_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("back_arrow" , &crate::flat::BACK_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("backhand_index_pointing_down" , &crate::flat::BACKHAND_INDEX_POINTING_DOWN.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("backhand_index_pointing_left" , &crate::flat::BACKHAND_INDEX_POINTING_LEFT.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("backhand_index_pointing_right" , &crate::flat::BACKHAND_INDEX_POINTING_RIGHT.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("backhand_index_pointing_up" , &crate::flat::BACKHAND_INDEX_POINTING_UP.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("backpack" , &crate::flat::BACKPACK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bacon" , &crate::flat::BACON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("badger" , &crate::flat::BADGER as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("badminton" , &crate::flat::BADMINTON as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bagel" , &crate::flat::BAGEL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("baggage_claim" , &crate::flat::BAGGAGE_CLAIM as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("baguette_bread" , &crate::flat::BAGUETTE_BREAD as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bahamas" , &crate::flat::FLAG_BAHAMAS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bahrain" , &crate::flat::FLAG_BAHRAIN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("balance_scale" , &crate::flat::BALANCE_SCALE as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("bald" , &crate::flat::BALD as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bald_man" , &crate::flat::PERSON.hair(Hair::Bald).gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bald_woman" , &crate::flat::PERSON.hair(Hair::Bald).gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("ballet_shoes" , &crate::flat::BALLET_SHOES as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("balloon" , &crate::flat::BALLOON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("ballot_box" , &crate::flat::BALLOT_BOX_WITH_BALLOT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("ballot_box_with_ballot" , &crate::flat::BALLOT_BOX_WITH_BALLOT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ballot_box_with_check" , &crate::flat::CHECK_BOX_WITH_CHECK as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("bamboo" , &crate::flat::PINE_DECORATION as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("banana" , &crate::flat::BANANA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("bangbang" , &crate::flat::DOUBLE_EXCLAMATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bangladesh" , &crate::flat::FLAG_BANGLADESH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("banjo" , &crate::flat::BANJO as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bank" , &crate::flat::BANK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bar_chart" , &crate::flat::BAR_CHART as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("barbados" , &crate::flat::FLAG_BARBADOS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("barber" , &crate::flat::BARBER_POLE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("barber_pole" , &crate::flat::BARBER_POLE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("baseball" , &crate::flat::BASEBALL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("basket" , &crate::flat::BASKET as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("basketball" , &crate::flat::BASKETBALL as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("basketball_man" , &crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("basketball_woman" , &crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("bat" , &crate::flat::BAT as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bath" , &crate::flat::PERSON_TAKING_BATH.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bathtub" , &crate::flat::BATHTUB as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("battery" , &crate::flat::BATTERY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("beach_umbrella" , &crate::flat::BEACH_WITH_UMBRELLA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("beach_with_umbrella" , &crate::flat::BEACH_WITH_UMBRELLA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("beaming_face_with_smiling_eyes" , &crate::flat::BEAMING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("beans" , &crate::flat::BEANS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("bear" , &crate::flat::BEAR as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bearded_person" , &crate::flat::PERSON.hair(Hair::Beard).default.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("beating_heart" , &crate::flat::BEATING_HEART as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("beaver" , &crate::flat::BEAVER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bed" , &crate::flat::BED as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("bee" , &crate::flat::HONEYBEE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("beer" , &crate::flat::BEER_MUG as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("beer_mug" , &crate::flat::BEER_MUG as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("beers" , &crate::flat::CLINKING_BEER_MUGS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("beetle" , &crate::flat::BEETLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("beginner" , &crate::flat::JAPANESE_SYMBOL_FOR_BEGINNER as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("belarus" , &crate::flat::FLAG_BELARUS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("belgium" , &crate::flat::FLAG_BELGIUM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("belize" , &crate::flat::FLAG_BELIZE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bell" , &crate::flat::BELL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bell_pepper" , &crate::flat::BELL_PEPPER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bell_with_slash" , &crate::flat::BELL_WITH_SLASH as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bellhop_bell" , &crate::flat::BELLHOP_BELL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("benin" , &crate::flat::FLAG_BENIN as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bento" , &crate::flat::BENTO_BOX as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bento_box" , &crate::flat::BENTO_BOX as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bermuda" , &crate::flat::FLAG_BERMUDA as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("beverage_box" , &crate::flat::BEVERAGE_BOX as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bhutan" , &crate::flat::FLAG_BHUTAN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bicycle" , &crate::flat::BICYCLE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bicyclist" , &crate::flat::PERSON_BIKING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bike" , &crate::flat::BICYCLE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("biking_man" , &crate::flat::PERSON_BIKING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("biking_woman" , &crate::flat::PERSON_BIKING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bikini" , &crate::flat::BIKINI as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("billed_cap" , &crate::flat::BILLED_CAP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("biohazard" , &crate::flat::BIOHAZARD as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("bird" , &crate::flat::BIRD as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("birthday" , &crate::flat::BIRTHDAY_CAKE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("birthday_cake" , &crate::flat::BIRTHDAY_CAKE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("bison" , &crate::flat::BISON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("biting_lip" , &crate::flat::BITING_LIP as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("black_cat" , &crate::flat::BLACK_CAT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("black_circle" , &crate::flat::BLACK_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("black_flag" , &crate::flat::BLACK_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("black_heart" , &crate::flat::BLACK_HEART as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("black_joker" , &crate::flat::JOKER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("black_large_square" , &crate::flat::BLACK_LARGE_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("black_medium_small_square" , &crate::flat::BLACK_MEDIUM_SMALL_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("black_medium_square" , &crate::flat::BLACK_MEDIUM_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("black_nib" , &crate::flat::BLACK_NIB as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("black_small_square" , &crate::flat::BLACK_SMALL_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("black_square_button" , &crate::flat::BLACK_SQUARE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("blond_haired_man" , &crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("blond_haired_person" , &crate::flat::PERSON.hair(Hair::Blond).default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("blond_haired_woman" , &crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("blonde_woman" , &crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("blossom" , &crate::flat::BLOSSOM as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("blowfish" , &crate::flat::BLOWFISH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("blue_book" , &crate::flat::BLUE_BOOK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("blue_car" , &crate::flat::SPORT_UTILITY_VEHICLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("blue_circle" , &crate::flat::BLUE_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("blue_heart" , &crate::flat::BLUE_HEART as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("blue_square" , &crate::flat::BLUE_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("blueberries" , &crate::flat::BLUEBERRIES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("blush" , &crate::flat::SMILING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("boar" , &crate::flat::BOAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("boat" , &crate::flat::SAILBOAT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bolivia" , &crate::flat::FLAG_BOLIVIA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("bomb" , &crate::flat::BOMB as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bone" , &crate::flat::BONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("book" , &crate::flat::OPEN_BOOK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bookmark" , &crate::flat::BOOKMARK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bookmark_tabs" , &crate::flat::BOOKMARK_TABS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("books" , &crate::flat::BOOKS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("boom" , &crate::flat::COLLISION as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("boomerang" , &crate::flat::BOOMERANG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("boot" , &crate::flat::WOMAN_S_BOOT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bosnia_herzegovina" , &crate::flat::FLAG_BOSNIA_AND_HERZEGOVINA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("botswana" , &crate::flat::FLAG_BOTSWANA as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bottle_with_popping_cork" , &crate::flat::BOTTLE_WITH_POPPING_CORK as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bouncing_ball_man" , &crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bouncing_ball_person" , &crate::flat::PERSON_BOUNCING_BALL.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bouncing_ball_woman" , &crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("bouquet" , &crate::flat::BOUQUET as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bouvet_island" , &crate::flat::FLAG_BOUVET_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bow" , &crate::flat::PERSON_BOWING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bow_and_arrow" , &crate::flat::BOW_AND_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bowing_man" , &crate::flat::PERSON_BOWING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bowing_woman" , &crate::flat::PERSON_BOWING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bowl_with_spoon" , &crate::flat::BOWL_WITH_SPOON as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("bowling" , &crate::flat::BOWLING as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("boxing_glove" , &crate::flat::BOXING_GLOVE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("boy" , &crate::flat::BOY.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("brain" , &crate::flat::BRAIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("brazil" , &crate::flat::FLAG_BRAZIL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bread" , &crate::flat::BREAD as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("breast_feeding" , &crate::flat::BREAST_FEEDING.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("brick" , &crate::flat::BRICK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bricks" , &crate::flat::BRICK as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bride_with_veil" , &crate::flat::PERSON_WITH_VEIL.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bridge_at_night" , &crate::flat::BRIDGE_AT_NIGHT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("briefcase" , &crate::flat::BRIEFCASE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("briefs" , &crate::flat::BRIEFS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("bright_button" , &crate::flat::BRIGHT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("british_indian_ocean_territory" , &crate::flat::FLAG_BRITISH_INDIAN_OCEAN_TERRITORY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("british_virgin_islands" , &crate::flat::FLAG_BRITISH_VIRGIN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("broccoli" , &crate::flat::BROCCOLI as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("broken_heart" , &crate::flat::BROKEN_HEART as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("broom" , &crate::flat::BROOM as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("brown_circle" , &crate::flat::BROWN_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("brown_heart" , &crate::flat::BROWN_HEART as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("brown_square" , &crate::flat::BROWN_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("brunei" , &crate::flat::FLAG_BRUNEI as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("bubble_tea" , &crate::flat::BUBBLE_TEA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bubbles" , &crate::flat::BUBBLES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bucket" , &crate::flat::BUCKET as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("bug" , &crate::flat::BUG as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("building_construction" , &crate::flat::BUILDING_CONSTRUCTION as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("bulb" , &crate::flat::LIGHT_BULB as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("bulgaria" , &crate::flat::FLAG_BULGARIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bullet_train" , &crate::flat::BULLET_TRAIN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bullettrain_front" , &crate::flat::BULLET_TRAIN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bullettrain_side" , &crate::flat::HIGH_SPEED_TRAIN as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("bullseye" , &crate::flat::BULLSEYE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("burkina_faso" , &crate::flat::FLAG_BURKINA_FASO as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("burrito" , &crate::flat::BURRITO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("burundi" , &crate::flat::FLAG_BURUNDI as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bus" , &crate::flat::BUS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("bus_stop" , &crate::flat::BUS_STOP as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("business_suit_levitating" , &crate::flat::PERSON_IN_SUIT_LEVITATING.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("busstop" , &crate::flat::BUS_STOP as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("bust_in_silhouette" , &crate::flat::BUST_IN_SILHOUETTE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("busts_in_silhouette" , &crate::flat::BUSTS_IN_SILHOUETTE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("butter" , &crate::flat::BUTTER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("butterfly" , &crate::flat::BUTTERFLY as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cactus" , &crate::flat::CACTUS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cake" , &crate::flat::SHORTCAKE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("calendar" , &crate::flat::CALENDAR as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("call_me_hand" , &crate::flat::CALL_ME_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("calling" , &crate::flat::MOBILE_PHONE_WITH_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cambodia" , &crate::flat::FLAG_CAMBODIA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("camel" , &crate::flat::CAMEL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("camera" , &crate::flat::CAMERA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("camera_flash" , &crate::flat::CAMERA_WITH_FLASH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("camera_with_flash" , &crate::flat::CAMERA_WITH_FLASH as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cameroon" , &crate::flat::FLAG_CAMEROON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("camping" , &crate::flat::CAMPING as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("canada" , &crate::flat::FLAG_CANADA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("canary_islands" , &crate::flat::FLAG_CANARY_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cancer" , &crate::flat::CANCER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("candle" , &crate::flat::CANDLE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("candy" , &crate::flat::CANDY as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("canned_food" , &crate::flat::CANNED_FOOD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("canoe" , &crate::flat::CANOE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cape_verde" , &crate::flat::FLAG_CAPE_VERDE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("capital_abcd" , &crate::flat::INPUT_LATIN_UPPERCASE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("capricorn" , &crate::flat::CAPRICORN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("car" , &crate::flat::AUTOMOBILE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("card_file_box" , &crate::flat::CARD_FILE_BOX as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("card_index" , &crate::flat::CARD_INDEX as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("card_index_dividers" , &crate::flat::CARD_INDEX_DIVIDERS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("caribbean_netherlands" , &crate::flat::FLAG_CARIBBEAN_NETHERLANDS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("carousel_horse" , &crate::flat::CAROUSEL_HORSE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("carp_streamer" , &crate::flat::CARP_STREAMER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("carpentry_saw" , &crate::flat::CARPENTRY_SAW as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("carrot" , &crate::flat::CARROT as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("cartwheeling" , &crate::flat::PERSON_CARTWHEELING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("castle" , &crate::flat::CASTLE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cat" , &crate::flat::CAT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cat2" , &crate::flat::CAT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cat_face" , &crate::flat::CAT_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cat_with_tears_of_joy" , &crate::flat::CAT_WITH_TEARS_OF_JOY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cat_with_wry_smile" , &crate::flat::CAT_WITH_WRY_SMILE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cayman_islands" , &crate::flat::FLAG_CAYMAN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("cd" , &crate::flat::OPTICAL_DISK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("central_african_republic" , &crate::flat::FLAG_CENTRAL_AFRICAN_REPUBLIC as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ceuta_melilla" , &crate::flat::FLAG_CEUTA_AND_MELILLA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("chad" , &crate::flat::FLAG_CHAD as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chains" , &crate::flat::CHAINS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chair" , &crate::flat::CHAIR as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("champagne" , &crate::flat::BOTTLE_WITH_POPPING_CORK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chart" , &crate::flat::CHART_INCREASING_WITH_YEN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chart_decreasing" , &crate::flat::CHART_DECREASING as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chart_increasing" , &crate::flat::CHART_INCREASING as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chart_increasing_with_yen" , &crate::flat::CHART_INCREASING_WITH_YEN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chart_with_downwards_trend" , &crate::flat::CHART_DECREASING as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("chart_with_upwards_trend" , &crate::flat::CHART_INCREASING as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("check_box_with_check" , &crate::flat::CHECK_BOX_WITH_CHECK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("check_mark" , &crate::flat::CHECK_MARK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("check_mark_button" , &crate::flat::CHECK_MARK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("checkered_flag" , &crate::flat::CHEQUERED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cheese" , &crate::flat::CHEESE_WEDGE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cheese_wedge" , &crate::flat::CHEESE_WEDGE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("chequered_flag" , &crate::flat::CHEQUERED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cherries" , &crate::flat::CHERRIES as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cherry_blossom" , &crate::flat::CHERRY_BLOSSOM as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("chess_pawn" , &crate::flat::CHESS_PAWN as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("chestnut" , &crate::flat::CHESTNUT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("chicken" , &crate::flat::CHICKEN as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("child" , &crate::flat::CHILD.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("children_crossing" , &crate::flat::CHILDREN_CROSSING as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("chile" , &crate::flat::FLAG_CHILE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("chipmunk" , &crate::flat::CHIPMUNK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("chocolate_bar" , &crate::flat::CHOCOLATE_BAR as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("chopsticks" , &crate::flat::CHOPSTICKS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("christmas_island" , &crate::flat::FLAG_CHRISTMAS_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("christmas_tree" , &crate::flat::CHRISTMAS_TREE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("church" , &crate::flat::CHURCH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("cigarette" , &crate::flat::CIGARETTE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cinema" , &crate::flat::CINEMA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("circled_m" , &crate::flat::CIRCLED_M as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("circus_tent" , &crate::flat::CIRCUS_TENT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("city_sunrise" , &crate::flat::SUNSET as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("city_sunset" , &crate::flat::CITYSCAPE_AT_DUSK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cityscape" , &crate::flat::CITYSCAPE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cityscape_at_dusk" , &crate::flat::CITYSCAPE_AT_DUSK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cl" , &crate::flat::CL_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cl_button" , &crate::flat::CL_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("clamp" , &crate::flat::CLAMP as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("clap" , &crate::flat::CLAPPING_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("clapper" , &crate::flat::CLAPPER_BOARD as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("clapper_board" , &crate::flat::CLAPPER_BOARD as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("clapping_hands" , &crate::flat::CLAPPING_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("classical_building" , &crate::flat::CLASSICAL_BUILDING as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("climbing" , &crate::flat::PERSON_CLIMBING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("climbing_man" , &crate::flat::PERSON_CLIMBING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("climbing_woman" , &crate::flat::PERSON_CLIMBING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("clinking_beer_mugs" , &crate::flat::CLINKING_BEER_MUGS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("clinking_glasses" , &crate::flat::CLINKING_GLASSES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("clipboard" , &crate::flat::CLIPBOARD as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("clipperton_island" , &crate::flat::FLAG_CLIPPERTON_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock1" , &crate::flat::ONE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock10" , &crate::flat::TEN_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock1030" , &crate::flat::TEN_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock11" , &crate::flat::ELEVEN_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock1130" , &crate::flat::ELEVEN_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock12" , &crate::flat::TWELVE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock1230" , &crate::flat::TWELVE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock130" , &crate::flat::ONE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock2" , &crate::flat::TWO_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock230" , &crate::flat::TWO_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock3" , &crate::flat::THREE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock330" , &crate::flat::THREE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock4" , &crate::flat::FOUR_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock430" , &crate::flat::FOUR_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock5" , &crate::flat::FIVE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock530" , &crate::flat::FIVE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock6" , &crate::flat::SIX_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock630" , &crate::flat::SIX_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock7" , &crate::flat::SEVEN_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock730" , &crate::flat::SEVEN_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock8" , &crate::flat::EIGHT_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock830" , &crate::flat::EIGHT_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock9" , &crate::flat::NINE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("clock930" , &crate::flat::NINE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("clockwise_vertical_arrows" , &crate::flat::CLOCKWISE_VERTICAL_ARROWS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("closed_book" , &crate::flat::CLOSED_BOOK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("closed_lock_with_key" , &crate::flat::LOCKED_WITH_KEY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("closed_mailbox_with_lowered_flag" , &crate::flat::CLOSED_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("closed_mailbox_with_raised_flag" , &crate::flat::CLOSED_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("closed_umbrella" , &crate::flat::CLOSED_UMBRELLA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cloud" , &crate::flat::CLOUD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cloud_with_lightning" , &crate::flat::CLOUD_WITH_LIGHTNING as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cloud_with_lightning_and_rain" , &crate::flat::CLOUD_WITH_LIGHTNING_AND_RAIN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cloud_with_rain" , &crate::flat::CLOUD_WITH_RAIN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cloud_with_snow" , &crate::flat::CLOUD_WITH_SNOW as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("clown_face" , &crate::flat::CLOWN_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("club_suit" , &crate::flat::CLUB_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("clubs" , &crate::flat::CLUB_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("clutch_bag" , &crate::flat::CLUTCH_BAG as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cn" , &crate::flat::FLAG_CHINA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("coat" , &crate::flat::COAT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cockroach" , &crate::flat::COCKROACH as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cocktail" , &crate::flat::COCKTAIL_GLASS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cocktail_glass" , &crate::flat::COCKTAIL_GLASS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("coconut" , &crate::flat::COCONUT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cocos_islands" , &crate::flat::FLAG_COCOS_KEELING_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("coffee" , &crate::flat::HOT_BEVERAGE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("coffin" , &crate::flat::COFFIN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("coin" , &crate::flat::COIN as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cold_face" , &crate::flat::COLD_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cold_sweat" , &crate::flat::ANXIOUS_FACE_WITH_SWEAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("collision" , &crate::flat::COLLISION as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("colombia" , &crate::flat::FLAG_COLOMBIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("comet" , &crate::flat::COMET as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("comoros" , &crate::flat::FLAG_COMOROS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("compass" , &crate::flat::COMPASS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("computer" , &crate::flat::LAPTOP as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("computer_disk" , &crate::flat::COMPUTER_DISK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("computer_mouse" , &crate::flat::COMPUTER_MOUSE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("confetti_ball" , &crate::flat::CONFETTI_BALL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("confounded" , &crate::flat::CONFOUNDED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("confounded_face" , &crate::flat::CONFOUNDED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("confused" , &crate::flat::CONFUSED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("confused_face" , &crate::flat::CONFUSED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("congo_brazzaville" , &crate::flat::FLAG_CONGO_BRAZZAVILLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("congo_kinshasa" , &crate::flat::FLAG_CONGO_KINSHASA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("congratulations" , &crate::flat::JAPANESE_CONGRATULATIONS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("construction" , &crate::flat::CONSTRUCTION as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("construction_worker" , &crate::flat::CONSTRUCTION_WORKER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("construction_worker_man" , &crate::flat::CONSTRUCTION_WORKER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("construction_worker_woman" , &crate::flat::CONSTRUCTION_WORKER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("control_knobs" , &crate::flat::CONTROL_KNOBS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("convenience_store" , &crate::flat::CONVENIENCE_STORE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("cook" , &crate::flat::COOK.default.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cook_islands" , &crate::flat::FLAG_COOK_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cooked_rice" , &crate::flat::COOKED_RICE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cookie" , &crate::flat::COOKIE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cooking" , &crate::flat::COOKING as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cool" , &crate::flat::COOL_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cool_button" , &crate::flat::COOL_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("cop" , &crate::flat::POLICE_OFFICER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("copyright" , &crate::flat::COPYRIGHT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("coral" , &crate::flat::CORAL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("corn" , &crate::flat::EAR_OF_CORN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("costa_rica" , &crate::flat::FLAG_COSTA_RICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cote_divoire" , &crate::flat::FLAG_COTE_D_IVOIRE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("couch_and_lamp" , &crate::flat::COUCH_AND_LAMP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("counterclockwise_arrows_button" , &crate::flat::COUNTERCLOCKWISE_ARROWS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couple" , &crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Mixed).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couple_with_heart" , &crate::flat::COUPLE_WITH_HEART.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couple_with_heart_man_man" , &crate::flat::COUPLE_WITH_HEART.pair(Pair::Males).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couple_with_heart_woman_man" , &crate::flat::COUPLE_WITH_HEART.pair(Pair::Mixed).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couple_with_heart_woman_woman" , &crate::flat::COUPLE_WITH_HEART.pair(Pair::Females).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couplekiss" , &crate::flat::KISS.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couplekiss_man_man" , &crate::flat::KISS.pair(Pair::Males).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couplekiss_man_woman" , &crate::flat::KISS.pair(Pair::Mixed).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("couplekiss_woman_woman" , &crate::flat::KISS.pair(Pair::Females).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cow" , &crate::flat::COW as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cow2" , &crate::flat::COW as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cow_face" , &crate::flat::COW_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cowboy_hat_face" , &crate::flat::COWBOY_HAT_FACE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("crab" , &crate::flat::CRAB as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("crayon" , &crate::flat::CRAYON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("credit_card" , &crate::flat::CREDIT_CARD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("crescent_moon" , &crate::flat::CRESCENT_MOON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("cricket" , &crate::flat::CRICKET as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("cricket_game" , &crate::flat::CRICKET_GAME as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("croatia" , &crate::flat::FLAG_CROATIA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("crocodile" , &crate::flat::CROCODILE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("croissant" , &crate::flat::CROISSANT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cross_mark" , &crate::flat::CROSS_MARK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("cross_mark_button" , &crate::flat::CROSS_MARK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("crossed_fingers" , &crate::flat::CROSSED_FINGERS.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("crossed_flags" , &crate::flat::CROSSED_FLAGS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("crossed_swords" , &crate::flat::CROSSED_SWORDS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("crown" , &crate::flat::CROWN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("crutch" , &crate::flat::CRUTCH as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cry" , &crate::flat::CRYING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("crying_cat" , &crate::flat::CRYING_CAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("crying_cat_face" , &crate::flat::CRYING_CAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("crying_face" , &crate::flat::CRYING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("crystal_ball" , &crate::flat::CRYSTAL_BALL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cuba" , &crate::flat::FLAG_CUBA as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cucumber" , &crate::flat::CUCUMBER as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cup_with_straw" , &crate::flat::CUP_WITH_STRAW as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cupcake" , &crate::flat::CUPCAKE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cupid" , &crate::flat::HEART_WITH_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("curacao" , &crate::flat::FLAG_CURACAO as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("curling_stone" , &crate::flat::CURLING_STONE as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("curly_hair" , &crate::flat::CURLY_HAIR as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("curly_haired_man" , &crate::flat::PERSON.hair(Hair::Curly).gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("curly_haired_woman" , &crate::flat::PERSON.hair(Hair::Curly).gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("curly_loop" , &crate::flat::CURLY_LOOP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("currency_exchange" , &crate::flat::CURRENCY_EXCHANGE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("curry" , &crate::flat::CURRY_RICE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("curry_rice" , &crate::flat::CURRY_RICE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("cursing_face" , &crate::flat::FACE_WITH_SYMBOLS_ON_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("custard" , &crate::flat::CUSTARD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("customs" , &crate::flat::CUSTOMS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("cut_of_meat" , &crate::flat::CUT_OF_MEAT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("cyclone" , &crate::flat::CYCLONE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("cyprus" , &crate::flat::FLAG_CYPRUS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("czech_republic" , &crate::flat::FLAG_CZECHIA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("dagger" , &crate::flat::DAGGER as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("dancer" , &crate::flat::PERSON_DANCING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("dancers" , &crate::flat::PERSON_WITH_BUNNY_EARS.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("dancing_men" , &crate::flat::PERSON_WITH_BUNNY_EARS.gender(Gender::Male) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("dancing_women" , &crate::flat::PERSON_WITH_BUNNY_EARS.gender(Gender::Female) as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("dango" , &crate::flat::DANGO as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("dark_skin_tone" , &crate::flat::DARK_SKIN_TONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("dark_sunglasses" , &crate::flat::SUNGLASSES as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("dart" , &crate::flat::BULLSEYE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("dash" , &crate::flat::DASHING_AWAY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("dashing_away" , &crate::flat::DASHING_AWAY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("date" , &crate::flat::CALENDAR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("de" , &crate::flat::FLAG_GERMANY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("deaf_man" , &crate::flat::DEAF_PERSON.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("deaf_person" , &crate::flat::DEAF_PERSON.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("deaf_woman" , &crate::flat::DEAF_PERSON.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("deciduous_tree" , &crate::flat::DECIDUOUS_TREE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("deer" , &crate::flat::DEER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("delivery_truck" , &crate::flat::DELIVERY_TRUCK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("denmark" , &crate::flat::FLAG_DENMARK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("department_store" , &crate::flat::DEPARTMENT_STORE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("derelict_house" , &crate::flat::DERELICT_HOUSE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("desert" , &crate::flat::DESERT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("desert_island" , &crate::flat::DESERT_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("desktop_computer" , &crate::flat::DESKTOP_COMPUTER as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("detective" , &crate::flat::DETECTIVE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("diamond_shape_with_a_dot_inside" , &crate::flat::DIAMOND_WITH_A_DOT as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("diamond_suit" , &crate::flat::DIAMOND_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("diamond_with_a_dot" , &crate::flat::DIAMOND_WITH_A_DOT as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("diamonds" , &crate::flat::DIAMOND_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("diego_garcia" , &crate::flat::FLAG_DIEGO_GARCIA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("dim_button" , &crate::flat::DIM_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("disappointed" , &crate::flat::DISAPPOINTED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("disappointed_face" , &crate::flat::DISAPPOINTED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("disappointed_relieved" , &crate::flat::SAD_BUT_RELIEVED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("disguised_face" , &crate::flat::DISGUISED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("divide" , &crate::flat::DIVIDE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("diving_mask" , &crate::flat::DIVING_MASK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("diya_lamp" , &crate::flat::DIYA_LAMP as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("dizzy" , &crate::flat::DIZZY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("dizzy_face" , &crate::flat::KNOCKED_OUT_FACE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("djibouti" , &crate::flat::FLAG_DJIBOUTI as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("dna" , &crate::flat::DNA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("do_not_litter" , &crate::flat::NO_LITTERING as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dodo" , &crate::flat::DODO as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dog" , &crate::flat::DOG as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dog2" , &crate::flat::DOG as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dog_face" , &crate::flat::DOG_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("dollar" , &crate::flat::DOLLAR_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("dollar_banknote" , &crate::flat::DOLLAR_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("dolls" , &crate::flat::JAPANESE_DOLLS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dolphin" , &crate::flat::DOLPHIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("dominica" , &crate::flat::FLAG_DOMINICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("dominican_republic" , &crate::flat::FLAG_DOMINICAN_REPUBLIC as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("door" , &crate::flat::DOOR as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("dotted_line_face" , &crate::flat::DOTTED_LINE_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("dotted_six_pointed_star" , &crate::flat::DOTTED_SIX_POINTED_STAR as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("double_curly_loop" , &crate::flat::DOUBLE_CURLY_LOOP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("double_exclamation_mark" , &crate::flat::DOUBLE_EXCLAMATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("doughnut" , &crate::flat::DOUGHNUT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dove" , &crate::flat::DOVE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("down_arrow" , &crate::flat::DOWN_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("down_left_arrow" , &crate::flat::DOWN_LEFT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("down_right_arrow" , &crate::flat::DOWN_RIGHT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("downcast_face_with_sweat" , &crate::flat::DOWNCAST_FACE_WITH_SWEAT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("downwards_button" , &crate::flat::DOWNWARDS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dragon" , &crate::flat::DRAGON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dragon_face" , &crate::flat::DRAGON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("dress" , &crate::flat::DRESS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("dromedary_camel" , &crate::flat::CAMEL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("drooling_face" , &crate::flat::DROOLING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("drop_of_blood" , &crate::flat::DROP_OF_BLOOD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("droplet" , &crate::flat::DROPLET as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("drum" , &crate::flat::DRUM as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("duck" , &crate::flat::DUCK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("dumpling" , &crate::flat::DUMPLING as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("dvd" , &crate::flat::DVD as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("e-mail" , &crate::flat::E_MAIL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("e_mail" , &crate::flat::E_MAIL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("eagle" , &crate::flat::EAGLE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ear" , &crate::flat::EAR.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("ear_of_corn" , &crate::flat::EAR_OF_CORN as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("ear_of_rice" , &crate::flat::SHEAF_OF_RICE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ear_with_hearing_aid" , &crate::flat::EAR_WITH_HEARING_AID.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("earth_africa" , &crate::flat::GLOBE_SHOWING_EUROPE_AFRICA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("earth_americas" , &crate::flat::GLOBE_SHOWING_AMERICAS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("earth_asia" , &crate::flat::GLOBE_SHOWING_ASIA_AUSTRALIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ecuador" , &crate::flat::FLAG_ECUADOR as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("egg" , &crate::flat::EGG as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("eggplant" , &crate::flat::EGGPLANT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("egypt" , &crate::flat::FLAG_EGYPT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("eight" , &crate::flat::KEYCAP_8 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("eight_o_clock" , &crate::flat::EIGHT_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("eight_pointed_black_star" , &crate::flat::EIGHT_POINTED_STAR as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("eight_pointed_star" , &crate::flat::EIGHT_POINTED_STAR as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("eight_spoked_asterisk" , &crate::flat::EIGHT_SPOKED_ASTERISK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("eight_thirty" , &crate::flat::EIGHT_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("eject_button" , &crate::flat::EJECT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("el_salvador" , &crate::flat::FLAG_EL_SALVADOR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("electric_plug" , &crate::flat::ELECTRIC_PLUG as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("elephant" , &crate::flat::ELEPHANT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("elevator" , &crate::flat::ELEVATOR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("eleven_o_clock" , &crate::flat::ELEVEN_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("eleven_thirty" , &crate::flat::ELEVEN_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("elf" , &crate::flat::ELF.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("elf_man" , &crate::flat::ELF.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("elf_woman" , &crate::flat::ELF.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("email" , &crate::flat::E_MAIL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("empty_nest" , &crate::flat::EMPTY_NEST as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("end" , &crate::flat::END_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("end_arrow" , &crate::flat::END_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("england" , &crate::flat::FLAG_ENGLAND as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("envelope" , &crate::flat::ENVELOPE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("envelope_with_arrow" , &crate::flat::ENVELOPE_WITH_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("equatorial_guinea" , &crate::flat::FLAG_EQUATORIAL_GUINEA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("eritrea" , &crate::flat::FLAG_ERITREA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("es" , &crate::flat::FLAG_SPAIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("estonia" , &crate::flat::FLAG_ESTONIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ethiopia" , &crate::flat::FLAG_ETHIOPIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("eu" , &crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("euro" , &crate::flat::EURO_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("euro_banknote" , &crate::flat::EURO_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("european_castle" , &crate::flat::CASTLE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("european_post_office" , &crate::flat::POST_OFFICE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("european_union" , &crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("evergreen_tree" , &crate::flat::EVERGREEN_TREE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("ewe" , &crate::flat::EWE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("exclamation" , &crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("exclamation_question_mark" , &crate::flat::EXCLAMATION_QUESTION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("exploding_head" , &crate::flat::EXPLODING_HEAD as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("expressionless" , &crate::flat::EXPRESSIONLESS_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("expressionless_face" , &crate::flat::EXPRESSIONLESS_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("eye" , &crate::flat::EYE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("eye_in_speech_bubble" , &crate::flat::EYE_IN_SPEECH_BUBBLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("eye_speech_bubble" , &crate::flat::EYE_IN_SPEECH_BUBBLE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("eyeglasses" , &crate::flat::GLASSES as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("eyes" , &crate::flat::EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_blowing_a_kiss" , &crate::flat::FACE_BLOWING_A_KISS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_exhaling" , &crate::flat::FACE_EXHALING as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_holding_back_tears" , &crate::flat::FACE_HOLDING_BACK_TEARS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_in_clouds" , &crate::flat::FACE_IN_CLOUDS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_savoring_food" , &crate::flat::FACE_SAVORING_FOOD as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_screaming_in_fear" , &crate::flat::FACE_SCREAMING_IN_FEAR as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_vomiting" , &crate::flat::FACE_VOMITING as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_diagonal_mouth" , &crate::flat::FACE_WITH_DIAGONAL_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_hand_over_mouth" , &crate::flat::FACE_WITH_HAND_OVER_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_head_bandage" , &crate::flat::FACE_WITH_HEAD_BANDAGE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_medical_mask" , &crate::flat::FACE_WITH_MEDICAL_MASK as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_monocle" , &crate::flat::FACE_WITH_MONOCLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_open_eyes_and_hand_over_mouth" , &crate::flat::FACE_WITH_OPEN_EYES_AND_HAND_OVER_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_open_mouth" , &crate::flat::FACE_WITH_OPEN_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_peeking_eye" , &crate::flat::FACE_WITH_PEEKING_EYE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_raised_eyebrow" , &crate::flat::FACE_WITH_RAISED_EYEBROW as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_rolling_eyes" , &crate::flat::FACE_WITH_ROLLING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_spiral_eyes" , &crate::flat::FACE_WITH_SPIRAL_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_steam_from_nose" , &crate::flat::FACE_WITH_STEAM_FROM_NOSE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_symbols_on_mouth" , &crate::flat::FACE_WITH_SYMBOLS_ON_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_tears_of_joy" , &crate::flat::FACE_WITH_TEARS_OF_JOY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_thermometer" , &crate::flat::FACE_WITH_THERMOMETER as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_with_tongue" , &crate::flat::FACE_WITH_TONGUE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("face_without_mouth" , &crate::flat::FACE_WITHOUT_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("facepalm" , &crate::flat::PERSON_FACEPALMING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("facepunch" , &crate::flat::ONCOMING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("factory" , &crate::flat::FACTORY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("factory_worker" , &crate::flat::FACTORY_WORKER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fairy" , &crate::flat::FAIRY.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fairy_man" , &crate::flat::FAIRY.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fairy_woman" , &crate::flat::FAIRY.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("falafel" , &crate::flat::FALAFEL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("falkland_islands" , &crate::flat::FLAG_FALKLAND_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("fallen_leaf" , &crate::flat::FALLEN_LEAF as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family" , &crate::flat::FAMILY.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_man_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_man_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_man_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_man_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_man_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_woman_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_woman_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_woman_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_woman_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_man_woman_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_woman_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_woman_boy_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_woman_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_woman_girl_boy" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("family_woman_woman_girl_girl" , &crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("farmer" , &crate::flat::FARMER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("faroe_islands" , &crate::flat::FLAG_FAROE_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("fast_down_button" , &crate::flat::FAST_DOWN_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("fast_forward" , &crate::flat::FAST_FORWARD_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("fast_forward_button" , &crate::flat::FAST_FORWARD_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("fast_reverse_button" , &crate::flat::FAST_REVERSE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("fast_up_button" , &crate::flat::FAST_UP_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("fax" , &crate::flat::FAX_MACHINE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("fax_machine" , &crate::flat::FAX_MACHINE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("fearful" , &crate::flat::FEARFUL_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("fearful_face" , &crate::flat::FEARFUL_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("feather" , &crate::flat::FEATHER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("feet" , &crate::flat::PAW_PRINTS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("female_detective" , &crate::flat::DETECTIVE.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("female_sign" , &crate::flat::FEMALE_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ferris_wheel" , &crate::flat::FERRIS_WHEEL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ferry" , &crate::flat::FERRY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("field_hockey" , &crate::flat::FIELD_HOCKEY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("fiji" , &crate::flat::FLAG_FIJI as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("file_cabinet" , &crate::flat::FILE_CABINET as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("file_folder" , &crate::flat::FILE_FOLDER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("film_frames" , &crate::flat::FILM_FRAMES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("film_projector" , &crate::flat::FILM_PROJECTOR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("film_strip" , &crate::flat::FILM_FRAMES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("finland" , &crate::flat::FLAG_FINLAND as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("fire" , &crate::flat::FIRE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("fire_engine" , &crate::flat::FIRE_ENGINE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("fire_extinguisher" , &crate::flat::FIRE_EXTINGUISHER as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("firecracker" , &crate::flat::FIRECRACKER as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("firefighter" , &crate::flat::FIREFIGHTER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("fireworks" , &crate::flat::FIREWORKS as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("first_place_medal" , &crate::flat::FIRST_PLACE_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("first_quarter_moon" , &crate::flat::FIRST_QUARTER_MOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("first_quarter_moon_face" , &crate::flat::FIRST_QUARTER_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("first_quarter_moon_with_face" , &crate::flat::FIRST_QUARTER_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("fish" , &crate::flat::FISH as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fish_cake" , &crate::flat::FISH_CAKE_WITH_SWIRL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fish_cake_with_swirl" , &crate::flat::FISH_CAKE_WITH_SWIRL as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("fishing_pole" , &crate::flat::FISHING_POLE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("fishing_pole_and_fish" , &crate::flat::FISHING_POLE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fist" , &crate::flat::RAISED_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fist_left" , &crate::flat::LEFT_FACING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fist_oncoming" , &crate::flat::ONCOMING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fist_raised" , &crate::flat::RAISED_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fist_right" , &crate::flat::RIGHT_FACING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("five" , &crate::flat::KEYCAP_5 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("five_o_clock" , &crate::flat::FIVE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("five_thirty" , &crate::flat::FIVE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_afghanistan" , &crate::flat::FLAG_AFGHANISTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_aland_islands" , &crate::flat::FLAG_ALAND_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_albania" , &crate::flat::FLAG_ALBANIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_algeria" , &crate::flat::FLAG_ALGERIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_american_samoa" , &crate::flat::FLAG_AMERICAN_SAMOA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_andorra" , &crate::flat::FLAG_ANDORRA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_angola" , &crate::flat::FLAG_ANGOLA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_anguilla" , &crate::flat::FLAG_ANGUILLA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_antarctica" , &crate::flat::FLAG_ANTARCTICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_antigua_and_barbuda" , &crate::flat::FLAG_ANTIGUA_AND_BARBUDA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_argentina" , &crate::flat::FLAG_ARGENTINA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_armenia" , &crate::flat::FLAG_ARMENIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_aruba" , &crate::flat::FLAG_ARUBA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_ascension_island" , &crate::flat::FLAG_ASCENSION_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_australia" , &crate::flat::FLAG_AUSTRALIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_austria" , &crate::flat::FLAG_AUSTRIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_azerbaijan" , &crate::flat::FLAG_AZERBAIJAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bahamas" , &crate::flat::FLAG_BAHAMAS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bahrain" , &crate::flat::FLAG_BAHRAIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bangladesh" , &crate::flat::FLAG_BANGLADESH as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_barbados" , &crate::flat::FLAG_BARBADOS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_belarus" , &crate::flat::FLAG_BELARUS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_belgium" , &crate::flat::FLAG_BELGIUM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_belize" , &crate::flat::FLAG_BELIZE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_benin" , &crate::flat::FLAG_BENIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bermuda" , &crate::flat::FLAG_BERMUDA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bhutan" , &crate::flat::FLAG_BHUTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bolivia" , &crate::flat::FLAG_BOLIVIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bosnia_and_herzegovina" , &crate::flat::FLAG_BOSNIA_AND_HERZEGOVINA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_botswana" , &crate::flat::FLAG_BOTSWANA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bouvet_island" , &crate::flat::FLAG_BOUVET_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_brazil" , &crate::flat::FLAG_BRAZIL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_british_indian_ocean_territory" , &crate::flat::FLAG_BRITISH_INDIAN_OCEAN_TERRITORY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_british_virgin_islands" , &crate::flat::FLAG_BRITISH_VIRGIN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_brunei" , &crate::flat::FLAG_BRUNEI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_bulgaria" , &crate::flat::FLAG_BULGARIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_burkina_faso" , &crate::flat::FLAG_BURKINA_FASO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_burundi" , &crate::flat::FLAG_BURUNDI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cambodia" , &crate::flat::FLAG_CAMBODIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cameroon" , &crate::flat::FLAG_CAMEROON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_canada" , &crate::flat::FLAG_CANADA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_canary_islands" , &crate::flat::FLAG_CANARY_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cape_verde" , &crate::flat::FLAG_CAPE_VERDE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_caribbean_netherlands" , &crate::flat::FLAG_CARIBBEAN_NETHERLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cayman_islands" , &crate::flat::FLAG_CAYMAN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_central_african_republic" , &crate::flat::FLAG_CENTRAL_AFRICAN_REPUBLIC as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_ceuta_and_melilla" , &crate::flat::FLAG_CEUTA_AND_MELILLA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_chad" , &crate::flat::FLAG_CHAD as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_chile" , &crate::flat::FLAG_CHILE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_china" , &crate::flat::FLAG_CHINA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_christmas_island" , &crate::flat::FLAG_CHRISTMAS_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_clipperton_island" , &crate::flat::FLAG_CLIPPERTON_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cocos_keeling_islands" , &crate::flat::FLAG_COCOS_KEELING_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_colombia" , &crate::flat::FLAG_COLOMBIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_comoros" , &crate::flat::FLAG_COMOROS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_congo_brazzaville" , &crate::flat::FLAG_CONGO_BRAZZAVILLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_congo_kinshasa" , &crate::flat::FLAG_CONGO_KINSHASA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cook_islands" , &crate::flat::FLAG_COOK_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_costa_rica" , &crate::flat::FLAG_COSTA_RICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cote_d_ivoire" , &crate::flat::FLAG_COTE_D_IVOIRE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_croatia" , &crate::flat::FLAG_CROATIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cuba" , &crate::flat::FLAG_CUBA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_curacao" , &crate::flat::FLAG_CURACAO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_cyprus" , &crate::flat::FLAG_CYPRUS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_czechia" , &crate::flat::FLAG_CZECHIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_denmark" , &crate::flat::FLAG_DENMARK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_diego_garcia" , &crate::flat::FLAG_DIEGO_GARCIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_djibouti" , &crate::flat::FLAG_DJIBOUTI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_dominica" , &crate::flat::FLAG_DOMINICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_dominican_republic" , &crate::flat::FLAG_DOMINICAN_REPUBLIC as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_ecuador" , &crate::flat::FLAG_ECUADOR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_egypt" , &crate::flat::FLAG_EGYPT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_el_salvador" , &crate::flat::FLAG_EL_SALVADOR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_england" , &crate::flat::FLAG_ENGLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_equatorial_guinea" , &crate::flat::FLAG_EQUATORIAL_GUINEA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_eritrea" , &crate::flat::FLAG_ERITREA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_estonia" , &crate::flat::FLAG_ESTONIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_eswatini" , &crate::flat::FLAG_ESWATINI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_ethiopia" , &crate::flat::FLAG_ETHIOPIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_european_union" , &crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_falkland_islands" , &crate::flat::FLAG_FALKLAND_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_faroe_islands" , &crate::flat::FLAG_FAROE_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_fiji" , &crate::flat::FLAG_FIJI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_finland" , &crate::flat::FLAG_FINLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_france" , &crate::flat::FLAG_FRANCE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_french_guiana" , &crate::flat::FLAG_FRENCH_GUIANA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_french_polynesia" , &crate::flat::FLAG_FRENCH_POLYNESIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_french_southern_territories" , &crate::flat::FLAG_FRENCH_SOUTHERN_TERRITORIES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_gabon" , &crate::flat::FLAG_GABON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_gambia" , &crate::flat::FLAG_GAMBIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_georgia" , &crate::flat::FLAG_GEORGIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_germany" , &crate::flat::FLAG_GERMANY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_ghana" , &crate::flat::FLAG_GHANA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_gibraltar" , &crate::flat::FLAG_GIBRALTAR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_greece" , &crate::flat::FLAG_GREECE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_greenland" , &crate::flat::FLAG_GREENLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_grenada" , &crate::flat::FLAG_GRENADA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_guadeloupe" , &crate::flat::FLAG_GUADELOUPE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_guam" , &crate::flat::FLAG_GUAM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_guatemala" , &crate::flat::FLAG_GUATEMALA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_guernsey" , &crate::flat::FLAG_GUERNSEY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_guinea" , &crate::flat::FLAG_GUINEA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_guinea_bissau" , &crate::flat::FLAG_GUINEA_BISSAU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_guyana" , &crate::flat::FLAG_GUYANA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_haiti" , &crate::flat::FLAG_HAITI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_heard_and_mcdonald_islands" , &crate::flat::FLAG_HEARD_AND_MCDONALD_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_honduras" , &crate::flat::FLAG_HONDURAS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_hong_kong_sar_china" , &crate::flat::FLAG_HONG_KONG_SAR_CHINA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_hungary" , &crate::flat::FLAG_HUNGARY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_iceland" , &crate::flat::FLAG_ICELAND as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("flag_in_hole" , &crate::flat::FLAG_IN_HOLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_india" , &crate::flat::FLAG_INDIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_indonesia" , &crate::flat::FLAG_INDONESIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_iran" , &crate::flat::FLAG_IRAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_iraq" , &crate::flat::FLAG_IRAQ as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_ireland" , &crate::flat::FLAG_IRELAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_isle_of_man" , &crate::flat::FLAG_ISLE_OF_MAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_israel" , &crate::flat::FLAG_ISRAEL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_italy" , &crate::flat::FLAG_ITALY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_jamaica" , &crate::flat::FLAG_JAMAICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_japan" , &crate::flat::FLAG_JAPAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_jersey" , &crate::flat::FLAG_JERSEY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_jordan" , &crate::flat::FLAG_JORDAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_kazakhstan" , &crate::flat::FLAG_KAZAKHSTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_kenya" , &crate::flat::FLAG_KENYA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_kiribati" , &crate::flat::FLAG_KIRIBATI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_kosovo" , &crate::flat::FLAG_KOSOVO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_kuwait" , &crate::flat::FLAG_KUWAIT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_kyrgyzstan" , &crate::flat::FLAG_KYRGYZSTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_laos" , &crate::flat::FLAG_LAOS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_latvia" , &crate::flat::FLAG_LATVIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_lebanon" , &crate::flat::FLAG_LEBANON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_lesotho" , &crate::flat::FLAG_LESOTHO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_liberia" , &crate::flat::FLAG_LIBERIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_libya" , &crate::flat::FLAG_LIBYA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_liechtenstein" , &crate::flat::FLAG_LIECHTENSTEIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_lithuania" , &crate::flat::FLAG_LITHUANIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_luxembourg" , &crate::flat::FLAG_LUXEMBOURG as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_macao_sar_china" , &crate::flat::FLAG_MACAO_SAR_CHINA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_madagascar" , &crate::flat::FLAG_MADAGASCAR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_malawi" , &crate::flat::FLAG_MALAWI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_malaysia" , &crate::flat::FLAG_MALAYSIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_maldives" , &crate::flat::FLAG_MALDIVES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_mali" , &crate::flat::FLAG_MALI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_malta" , &crate::flat::FLAG_MALTA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_marshall_islands" , &crate::flat::FLAG_MARSHALL_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_martinique" , &crate::flat::FLAG_MARTINIQUE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_mauritania" , &crate::flat::FLAG_MAURITANIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_mauritius" , &crate::flat::FLAG_MAURITIUS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_mayotte" , &crate::flat::FLAG_MAYOTTE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_mexico" , &crate::flat::FLAG_MEXICO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_micronesia" , &crate::flat::FLAG_MICRONESIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_moldova" , &crate::flat::FLAG_MOLDOVA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_monaco" , &crate::flat::FLAG_MONACO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_mongolia" , &crate::flat::FLAG_MONGOLIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_montenegro" , &crate::flat::FLAG_MONTENEGRO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_montserrat" , &crate::flat::FLAG_MONTSERRAT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_morocco" , &crate::flat::FLAG_MOROCCO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_mozambique" , &crate::flat::FLAG_MOZAMBIQUE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_myanmar_burma" , &crate::flat::FLAG_MYANMAR_BURMA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_namibia" , &crate::flat::FLAG_NAMIBIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_nauru" , &crate::flat::FLAG_NAURU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_nepal" , &crate::flat::FLAG_NEPAL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_netherlands" , &crate::flat::FLAG_NETHERLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_new_caledonia" , &crate::flat::FLAG_NEW_CALEDONIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_new_zealand" , &crate::flat::FLAG_NEW_ZEALAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_nicaragua" , &crate::flat::FLAG_NICARAGUA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_niger" , &crate::flat::FLAG_NIGER as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_nigeria" , &crate::flat::FLAG_NIGERIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_niue" , &crate::flat::FLAG_NIUE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_norfolk_island" , &crate::flat::FLAG_NORFOLK_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_north_korea" , &crate::flat::FLAG_NORTH_KOREA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_north_macedonia" , &crate::flat::FLAG_NORTH_MACEDONIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_northern_mariana_islands" , &crate::flat::FLAG_NORTHERN_MARIANA_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_norway" , &crate::flat::FLAG_NORWAY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_oman" , &crate::flat::FLAG_OMAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_pakistan" , &crate::flat::FLAG_PAKISTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_palau" , &crate::flat::FLAG_PALAU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_palestinian_territories" , &crate::flat::FLAG_PALESTINIAN_TERRITORIES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_panama" , &crate::flat::FLAG_PANAMA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_papua_new_guinea" , &crate::flat::FLAG_PAPUA_NEW_GUINEA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_paraguay" , &crate::flat::FLAG_PARAGUAY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_peru" , &crate::flat::FLAG_PERU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_philippines" , &crate::flat::FLAG_PHILIPPINES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_pitcairn_islands" , &crate::flat::FLAG_PITCAIRN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_poland" , &crate::flat::FLAG_POLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_portugal" , &crate::flat::FLAG_PORTUGAL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_puerto_rico" , &crate::flat::FLAG_PUERTO_RICO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_qatar" , &crate::flat::FLAG_QATAR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_reunion" , &crate::flat::FLAG_REUNION as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_romania" , &crate::flat::FLAG_ROMANIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_russia" , &crate::flat::FLAG_RUSSIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_rwanda" , &crate::flat::FLAG_RWANDA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_samoa" , &crate::flat::FLAG_SAMOA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_san_marino" , &crate::flat::FLAG_SAN_MARINO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_sao_tome_and_principe" , &crate::flat::FLAG_SAO_TOME_AND_PRINCIPE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_saudi_arabia" , &crate::flat::FLAG_SAUDI_ARABIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_scotland" , &crate::flat::FLAG_SCOTLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_senegal" , &crate::flat::FLAG_SENEGAL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_serbia" , &crate::flat::FLAG_SERBIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_seychelles" , &crate::flat::FLAG_SEYCHELLES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_sierra_leone" , &crate::flat::FLAG_SIERRA_LEONE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_singapore" , &crate::flat::FLAG_SINGAPORE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_sint_maarten" , &crate::flat::FLAG_SINT_MAARTEN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_slovakia" , &crate::flat::FLAG_SLOVAKIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_slovenia" , &crate::flat::FLAG_SLOVENIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_solomon_islands" , &crate::flat::FLAG_SOLOMON_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_somalia" , &crate::flat::FLAG_SOMALIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_south_africa" , &crate::flat::FLAG_SOUTH_AFRICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_south_georgia_and_south_sandwich_islands" , &crate::flat::FLAG_SOUTH_GEORGIA_AND_SOUTH_SANDWICH_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_south_korea" , &crate::flat::FLAG_SOUTH_KOREA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_south_sudan" , &crate::flat::FLAG_SOUTH_SUDAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_spain" , &crate::flat::FLAG_SPAIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_sri_lanka" , &crate::flat::FLAG_SRI_LANKA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_st_barthelemy" , &crate::flat::FLAG_ST_BARTHELEMY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_st_helena" , &crate::flat::FLAG_ST_HELENA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_st_kitts_and_nevis" , &crate::flat::FLAG_ST_KITTS_AND_NEVIS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_st_lucia" , &crate::flat::FLAG_ST_LUCIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_st_martin" , &crate::flat::FLAG_ST_MARTIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_st_pierre_and_miquelon" , &crate::flat::FLAG_ST_PIERRE_AND_MIQUELON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_st_vincent_and_grenadines" , &crate::flat::FLAG_ST_VINCENT_AND_GRENADINES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_sudan" , &crate::flat::FLAG_SUDAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_suriname" , &crate::flat::FLAG_SURINAME as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_svalbard_and_jan_mayen" , &crate::flat::FLAG_SVALBARD_AND_JAN_MAYEN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_sweden" , &crate::flat::FLAG_SWEDEN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_switzerland" , &crate::flat::FLAG_SWITZERLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_syria" , &crate::flat::FLAG_SYRIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_taiwan" , &crate::flat::FLAG_TAIWAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_tajikistan" , &crate::flat::FLAG_TAJIKISTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_tanzania" , &crate::flat::FLAG_TANZANIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_thailand" , &crate::flat::FLAG_THAILAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_timor_leste" , &crate::flat::FLAG_TIMOR_LESTE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_togo" , &crate::flat::FLAG_TOGO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_tokelau" , &crate::flat::FLAG_TOKELAU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_tonga" , &crate::flat::FLAG_TONGA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_trinidad_and_tobago" , &crate::flat::FLAG_TRINIDAD_AND_TOBAGO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_tristan_da_cunha" , &crate::flat::FLAG_TRISTAN_DA_CUNHA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_tunisia" , &crate::flat::FLAG_TUNISIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_turkey" , &crate::flat::FLAG_TURKEY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_turkmenistan" , &crate::flat::FLAG_TURKMENISTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_turks_and_caicos_islands" , &crate::flat::FLAG_TURKS_AND_CAICOS_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_tuvalu" , &crate::flat::FLAG_TUVALU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_uganda" , &crate::flat::FLAG_UGANDA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_ukraine" , &crate::flat::FLAG_UKRAINE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_united_arab_emirates" , &crate::flat::FLAG_UNITED_ARAB_EMIRATES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_united_kingdom" , &crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_united_nations" , &crate::flat::FLAG_UNITED_NATIONS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_united_states" , &crate::flat::FLAG_UNITED_STATES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_uruguay" , &crate::flat::FLAG_URUGUAY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_us_outlying_islands" , &crate::flat::FLAG_US_OUTLYING_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_us_virgin_islands" , &crate::flat::FLAG_US_VIRGIN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_uzbekistan" , &crate::flat::FLAG_UZBEKISTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_vanuatu" , &crate::flat::FLAG_VANUATU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_vatican_city" , &crate::flat::FLAG_VATICAN_CITY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_venezuela" , &crate::flat::FLAG_VENEZUELA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_vietnam" , &crate::flat::FLAG_VIETNAM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_wales" , &crate::flat::FLAG_WALES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_wallis_and_futuna" , &crate::flat::FLAG_WALLIS_AND_FUTUNA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_western_sahara" , &crate::flat::FLAG_WESTERN_SAHARA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_yemen" , &crate::flat::FLAG_YEMEN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_zambia" , &crate::flat::FLAG_ZAMBIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("flag_zimbabwe" , &crate::flat::FLAG_ZIMBABWE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("flags" , &crate::flat::CARP_STREAMER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("flamingo" , &crate::flat::FLAMINGO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("flashlight" , &crate::flat::FLASHLIGHT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("flat_shoe" , &crate::flat::FLAT_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("flatbread" , &crate::flat::FLATBREAD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("fleur_de_lis" , &crate::flat::FLEUR_DE_LIS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("flexed_biceps" , &crate::flat::FLEXED_BICEPS.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("flight_arrival" , &crate::flat::AIRPLANE_ARRIVAL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("flight_departure" , &crate::flat::AIRPLANE_DEPARTURE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("flipper" , &crate::flat::DOLPHIN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("floppy_disk" , &crate::flat::FLOPPY_DISK as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("flower_playing_cards" , &crate::flat::FLOWER_PLAYING_CARDS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("flushed" , &crate::flat::FLUSHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("flushed_face" , &crate::flat::FLUSHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("fly" , &crate::flat::FLY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("flying_disc" , &crate::flat::FLYING_DISC as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("flying_saucer" , &crate::flat::FLYING_SAUCER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("fog" , &crate::flat::FOG as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("foggy" , &crate::flat::FOGGY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("folded_hands" , &crate::flat::FOLDED_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fondue" , &crate::flat::FONDUE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("foot" , &crate::flat::FOOT.default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("football" , &crate::flat::AMERICAN_FOOTBALL as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("footprints" , &crate::flat::FOOTPRINTS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fork_and_knife" , &crate::flat::FORK_AND_KNIFE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fork_and_knife_with_plate" , &crate::flat::FORK_AND_KNIFE_WITH_PLATE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fortune_cookie" , &crate::flat::FORTUNE_COOKIE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("fountain" , &crate::flat::FOUNTAIN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("fountain_pen" , &crate::flat::FOUNTAIN_PEN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("four" , &crate::flat::KEYCAP_4 as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("four_leaf_clover" , &crate::flat::FOUR_LEAF_CLOVER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("four_o_clock" , &crate::flat::FOUR_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("four_thirty" , &crate::flat::FOUR_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("fox" , &crate::flat::FOX as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("fox_face" , &crate::flat::FOX as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("fr" , &crate::flat::FLAG_FRANCE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("framed_picture" , &crate::flat::FRAMED_PICTURE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("free" , &crate::flat::FREE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("free_button" , &crate::flat::FREE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("french_fries" , &crate::flat::FRENCH_FRIES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("french_guiana" , &crate::flat::FLAG_FRENCH_GUIANA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("french_polynesia" , &crate::flat::FLAG_FRENCH_POLYNESIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("french_southern_territories" , &crate::flat::FLAG_FRENCH_SOUTHERN_TERRITORIES as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fried_egg" , &crate::flat::COOKING as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fried_shrimp" , &crate::flat::FRIED_SHRIMP as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("fries" , &crate::flat::FRENCH_FRIES as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("frog" , &crate::flat::FROG as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("front_facing_baby_chick" , &crate::flat::FRONT_FACING_BABY_CHICK as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("frowning" , &crate::flat::FROWNING_FACE_WITH_OPEN_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("frowning_face" , &crate::flat::FROWNING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("frowning_face_with_open_mouth" , &crate::flat::FROWNING_FACE_WITH_OPEN_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("frowning_man" , &crate::flat::PERSON_FROWNING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("frowning_person" , &crate::flat::PERSON_FROWNING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("frowning_woman" , &crate::flat::PERSON_FROWNING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("fu" , &crate::flat::MIDDLE_FINGER.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("fuel_pump" , &crate::flat::FUEL_PUMP as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("fuelpump" , &crate::flat::FUEL_PUMP as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("full_moon" , &crate::flat::FULL_MOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("full_moon_face" , &crate::flat::FULL_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("full_moon_with_face" , &crate::flat::FULL_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("funeral_urn" , &crate::flat::FUNERAL_URN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("gabon" , &crate::flat::FLAG_GABON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("gambia" , &crate::flat::FLAG_GAMBIA as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("game_die" , &crate::flat::GAME_DIE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("garlic" , &crate::flat::GARLIC as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("gb" , &crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("gear" , &crate::flat::GEAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("gem" , &crate::flat::GEM_STONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("gem_stone" , &crate::flat::GEM_STONE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("gemini" , &crate::flat::GEMINI as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("genie" , &crate::flat::GENIE.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("genie_man" , &crate::flat::GENIE.gender(Gender::Male) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("genie_woman" , &crate::flat::GENIE.gender(Gender::Female) as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("georgia" , &crate::flat::FLAG_GEORGIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ghana" , &crate::flat::FLAG_GHANA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("ghost" , &crate::flat::GHOST as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("gibraltar" , &crate::flat::FLAG_GIBRALTAR as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("gift" , &crate::flat::WRAPPED_GIFT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("gift_heart" , &crate::flat::HEART_WITH_RIBBON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("giraffe" , &crate::flat::GIRAFFE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("girl" , &crate::flat::GIRL.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("glass_of_milk" , &crate::flat::GLASS_OF_MILK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("glasses" , &crate::flat::GLASSES as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("globe_showing_americas" , &crate::flat::GLOBE_SHOWING_AMERICAS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("globe_showing_asia_australia" , &crate::flat::GLOBE_SHOWING_ASIA_AUSTRALIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("globe_showing_europe_africa" , &crate::flat::GLOBE_SHOWING_EUROPE_AFRICA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("globe_with_meridians" , &crate::flat::GLOBE_WITH_MERIDIANS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("gloves" , &crate::flat::GLOVES as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("glowing_star" , &crate::flat::GLOWING_STAR as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("goal_net" , &crate::flat::GOAL_NET as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("goat" , &crate::flat::GOAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("goblin" , &crate::flat::GOBLIN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("goggles" , &crate::flat::GOGGLES as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("golf" , &crate::flat::FLAG_IN_HOLE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("golfing" , &crate::flat::PERSON_GOLFING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("golfing_man" , &crate::flat::PERSON_GOLFING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("golfing_woman" , &crate::flat::PERSON_GOLFING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("gorilla" , &crate::flat::GORILLA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("graduation_cap" , &crate::flat::GRADUATION_CAP as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("grapes" , &crate::flat::GRAPES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("greece" , &crate::flat::FLAG_GREECE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("green_apple" , &crate::flat::GREEN_APPLE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("green_book" , &crate::flat::GREEN_BOOK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("green_circle" , &crate::flat::GREEN_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("green_heart" , &crate::flat::GREEN_HEART as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("green_salad" , &crate::flat::GREEN_SALAD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("green_square" , &crate::flat::GREEN_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("greenland" , &crate::flat::FLAG_GREENLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("grenada" , &crate::flat::FLAG_GRENADA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("grey_exclamation" , &crate::flat::WHITE_EXCLAMATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("grey_question" , &crate::flat::WHITE_QUESTION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grimacing" , &crate::flat::GRIMACING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grimacing_face" , &crate::flat::GRIMACING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grin" , &crate::flat::BEAMING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning" , &crate::flat::GRINNING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning_cat" , &crate::flat::GRINNING_CAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning_cat_with_smiling_eyes" , &crate::flat::GRINNING_CAT_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning_face" , &crate::flat::GRINNING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning_face_with_big_eyes" , &crate::flat::GRINNING_FACE_WITH_BIG_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning_face_with_smiling_eyes" , &crate::flat::GRINNING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning_face_with_sweat" , &crate::flat::GRINNING_FACE_WITH_SWEAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("grinning_squinting_face" , &crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("growing_heart" , &crate::flat::GROWING_HEART as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("guadeloupe" , &crate::flat::FLAG_GUADELOUPE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("guam" , &crate::flat::FLAG_GUAM as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("guard" , &crate::flat::GUARD.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("guardsman" , &crate::flat::GUARD.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("guardswoman" , &crate::flat::GUARD.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("guatemala" , &crate::flat::FLAG_GUATEMALA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("guernsey" , &crate::flat::FLAG_GUERNSEY as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("guide_dog" , &crate::flat::GUIDE_DOG as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("guinea" , &crate::flat::FLAG_GUINEA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("guinea_bissau" , &crate::flat::FLAG_GUINEA_BISSAU as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("guitar" , &crate::flat::GUITAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("gun" , &crate::flat::WATER_PISTOL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("guyana" , &crate::flat::FLAG_GUYANA as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("haircut" , &crate::flat::PERSON_GETTING_HAIRCUT.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("haircut_man" , &crate::flat::PERSON_GETTING_HAIRCUT.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("haircut_woman" , &crate::flat::PERSON_GETTING_HAIRCUT.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("haiti" , &crate::flat::FLAG_HAITI as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("hamburger" , &crate::flat::HAMBURGER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("hammer" , &crate::flat::HAMMER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("hammer_and_pick" , &crate::flat::HAMMER_AND_PICK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("hammer_and_wrench" , &crate::flat::HAMMER_AND_WRENCH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("hamsa" , &crate::flat::HAMSA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("hamster" , &crate::flat::HAMSTER as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("hand" , &crate::flat::RAISED_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hand_over_mouth" , &crate::flat::FACE_WITH_HAND_OVER_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("hand_with_fingers_splayed" , &crate::flat::HAND_WITH_FINGERS_SPLAYED.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("hand_with_index_finger_and_thumb_crossed" , &crate::flat::HAND_WITH_INDEX_FINGER_AND_THUMB_CROSSED.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("handbag" , &crate::flat::HANDBAG as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("handball_person" , &crate::flat::PERSON_PLAYING_HANDBALL.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("handshake" , &crate::flat::HANDSHAKE.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hankey" , &crate::flat::PILE_OF_POO as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("hash" , &crate::flat::KEYCAP_HASH as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("hatched_chick" , &crate::flat::FRONT_FACING_BABY_CHICK as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("hatching_chick" , &crate::flat::HATCHING_CHICK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("headphone" , &crate::flat::HEADPHONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("headphones" , &crate::flat::HEADPHONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("headstone" , &crate::flat::HEADSTONE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("health_worker" , &crate::flat::HEALTH_WORKER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hear_no_evil" , &crate::flat::HEAR_NO_EVIL_MONKEY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hear_no_evil_monkey" , &crate::flat::HEAR_NO_EVIL_MONKEY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("heard_mcdonald_islands" , &crate::flat::FLAG_HEARD_AND_MCDONALD_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart" , &crate::flat::RED_HEART as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart_decoration" , &crate::flat::HEART_DECORATION as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart_exclamation" , &crate::flat::HEART_EXCLAMATION as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart_eyes" , &crate::flat::SMILING_FACE_WITH_HEART_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart_eyes_cat" , &crate::flat::SMILING_CAT_WITH_HEART_EYES as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("heart_hands" , &crate::flat::HEART_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart_on_fire" , &crate::flat::HEART_ON_FIRE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("heart_suit" , &crate::flat::HEART_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart_with_arrow" , &crate::flat::HEART_WITH_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heart_with_ribbon" , &crate::flat::HEART_WITH_RIBBON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heartbeat" , &crate::flat::BEATING_HEART as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heartpulse" , &crate::flat::GROWING_HEART as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("hearts" , &crate::flat::HEART_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_check_mark" , &crate::flat::CHECK_MARK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_division_sign" , &crate::flat::DIVIDE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_dollar_sign" , &crate::flat::HEAVY_DOLLAR_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_equals_sign" , &crate::flat::HEAVY_EQUALS_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_exclamation_mark" , &crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("heavy_heart_exclamation" , &crate::flat::HEART_EXCLAMATION as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_minus_sign" , &crate::flat::MINUS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_multiplication_x" , &crate::flat::MULTIPLY as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("heavy_plus_sign" , &crate::flat::PLUS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("hedgehog" , &crate::flat::HEDGEHOG as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("helicopter" , &crate::flat::HELICOPTER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("herb" , &crate::flat::HERB as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("hibiscus" , &crate::flat::HIBISCUS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("high_brightness" , &crate::flat::BRIGHT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("high_heel" , &crate::flat::HIGH_HEELED_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("high_heeled_shoe" , &crate::flat::HIGH_HEELED_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("high_speed_train" , &crate::flat::HIGH_SPEED_TRAIN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("high_voltage" , &crate::flat::HIGH_VOLTAGE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("hiking_boot" , &crate::flat::HIKING_BOOT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hindu_temple" , &crate::flat::HINDU_TEMPLE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("hippopotamus" , &crate::flat::HIPPOPOTAMUS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("hocho" , &crate::flat::KITCHEN_KNIFE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hole" , &crate::flat::HOLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("hollow_red_circle" , &crate::flat::HOLLOW_RED_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("honduras" , &crate::flat::FLAG_HONDURAS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("honey_pot" , &crate::flat::HONEY_POT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("honeybee" , &crate::flat::HONEYBEE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("hong_kong" , &crate::flat::FLAG_HONG_KONG_SAR_CHINA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("hook" , &crate::flat::HOOK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("horizontal_traffic_light" , &crate::flat::HORIZONTAL_TRAFFIC_LIGHT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("horse" , &crate::flat::HORSE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("horse_face" , &crate::flat::HORSE_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("horse_racing" , &crate::flat::HORSE_RACING.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hospital" , &crate::flat::HOSPITAL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("hot_beverage" , &crate::flat::HOT_BEVERAGE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("hot_dog" , &crate::flat::HOT_DOG as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hot_face" , &crate::flat::HOT_FACE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("hot_pepper" , &crate::flat::HOT_PEPPER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hot_springs" , &crate::flat::HOT_SPRINGS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("hotdog" , &crate::flat::HOT_DOG as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hotel" , &crate::flat::HOTEL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hotsprings" , &crate::flat::HOT_SPRINGS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hourglass" , &crate::flat::HOURGLASS_DONE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hourglass_done" , &crate::flat::HOURGLASS_DONE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hourglass_flowing_sand" , &crate::flat::HOURGLASS_NOT_DONE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hourglass_not_done" , &crate::flat::HOURGLASS_NOT_DONE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("house" , &crate::flat::HOUSE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("house_with_garden" , &crate::flat::HOUSE_WITH_GARDEN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("houses" , &crate::flat::HOUSES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hugging_face" , &crate::flat::HUGGING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hugs" , &crate::flat::HUGGING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hundred_points" , &crate::flat::HUNDRED_POINTS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("hungary" , &crate::flat::FLAG_HUNGARY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hushed" , &crate::flat::HUSHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("hushed_face" , &crate::flat::HUSHED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("hut" , &crate::flat::HUT as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("ice" , &crate::flat::ICE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("ice_cream" , &crate::flat::ICE_CREAM as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("ice_cube" , &crate::flat::ICE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("ice_hockey" , &crate::flat::ICE_HOCKEY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("ice_skate" , &crate::flat::ICE_SKATE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("icecream" , &crate::flat::SOFT_ICE_CREAM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("iceland" , &crate::flat::FLAG_ICELAND as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("id" , &crate::flat::ID_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("id_button" , &crate::flat::ID_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("identification_card" , &crate::flat::IDENTIFICATION_CARD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ideograph_advantage" , &crate::flat::JAPANESE_BARGAIN_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("imp" , &crate::flat::ANGRY_FACE_WITH_HORNS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("inbox_tray" , &crate::flat::INBOX_TRAY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("incoming_envelope" , &crate::flat::INCOMING_ENVELOPE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("index_pointing_at_the_viewer" , &crate::flat::INDEX_POINTING_AT_THE_VIEWER.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("index_pointing_up" , &crate::flat::INDEX_POINTING_UP.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("india" , &crate::flat::FLAG_INDIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("indonesia" , &crate::flat::FLAG_INDONESIA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("infinity" , &crate::flat::INFINITY as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("information" , &crate::flat::INFORMATION as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("information_desk_person" , &crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("information_source" , &crate::flat::INFORMATION as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("innocent" , &crate::flat::SMILING_FACE_WITH_HALO as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("input_latin_letters" , &crate::flat::INPUT_LATIN_LETTERS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("input_latin_lowercase" , &crate::flat::INPUT_LATIN_LOWERCASE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("input_latin_uppercase" , &crate::flat::INPUT_LATIN_UPPERCASE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("input_numbers" , &crate::flat::INPUT_NUMBERS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("input_symbols" , &crate::flat::INPUT_SYMBOLS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("interrobang" , &crate::flat::EXCLAMATION_QUESTION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("iphone" , &crate::flat::MOBILE_PHONE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("iran" , &crate::flat::FLAG_IRAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("iraq" , &crate::flat::FLAG_IRAQ as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ireland" , &crate::flat::FLAG_IRELAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("isle_of_man" , &crate::flat::FLAG_ISLE_OF_MAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("israel" , &crate::flat::FLAG_ISRAEL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("it" , &crate::flat::FLAG_ITALY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("izakaya_lantern" , &crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("jack_o_lantern" , &crate::flat::JACK_O_LANTERN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("jamaica" , &crate::flat::FLAG_JAMAICA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("japan" , &crate::flat::MAP_OF_JAPAN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_acceptable_button" , &crate::flat::JAPANESE_ACCEPTABLE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_application_button" , &crate::flat::JAPANESE_APPLICATION_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_bargain_button" , &crate::flat::JAPANESE_BARGAIN_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("japanese_castle" , &crate::flat::JAPANESE_CASTLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_congratulations_button" , &crate::flat::JAPANESE_CONGRATULATIONS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_discount_button" , &crate::flat::JAPANESE_DISCOUNT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("japanese_dolls" , &crate::flat::JAPANESE_DOLLS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_free_of_charge_button" , &crate::flat::JAPANESE_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("japanese_goblin" , &crate::flat::GOBLIN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_here_button" , &crate::flat::JAPANESE_HERE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_monthly_amount_button" , &crate::flat::JAPANESE_MONTHLY_AMOUNT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_no_vacancy_button" , &crate::flat::JAPANESE_NO_VACANCY_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_not_free_of_charge_button" , &crate::flat::JAPANESE_NOT_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("japanese_ogre" , &crate::flat::OGRE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_open_for_business_button" , &crate::flat::JAPANESE_OPEN_FOR_BUSINESS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_passing_grade_button" , &crate::flat::JAPANESE_PASSING_GRADE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("japanese_post_office" , &crate::flat::JAPANESE_POST_OFFICE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_prohibited_button" , &crate::flat::JAPANESE_PROHIBITED_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_reserved_button" , &crate::flat::JAPANESE_RESERVED_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_secret_button" , &crate::flat::JAPANESE_SECRET_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_service_charge_button" , &crate::flat::JAPANESE_SERVICE_CHARGE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_symbol_for_beginner" , &crate::flat::JAPANESE_SYMBOL_FOR_BEGINNER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("japanese_vacancy_button" , &crate::flat::JAPANESE_VACANCY_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("jar" , &crate::flat::JAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("jeans" , &crate::flat::JEANS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("jersey" , &crate::flat::FLAG_JERSEY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("jigsaw" , &crate::flat::PUZZLE_PIECE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("joker" , &crate::flat::JOKER as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("jordan" , &crate::flat::FLAG_JORDAN as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("joy" , &crate::flat::FACE_WITH_TEARS_OF_JOY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("joy_cat" , &crate::flat::CAT_WITH_TEARS_OF_JOY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("joystick" , &crate::flat::JOYSTICK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("jp" , &crate::flat::FLAG_JAPAN as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("judge" , &crate::flat::JUDGE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("juggling_person" , &crate::flat::PERSON_JUGGLING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("kaaba" , &crate::flat::KAABA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("kangaroo" , &crate::flat::KANGAROO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("kazakhstan" , &crate::flat::FLAG_KAZAKHSTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("kenya" , &crate::flat::FLAG_KENYA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("key" , &crate::flat::KEY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("keyboard" , &crate::flat::KEYBOARD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_0" , &crate::flat::KEYCAP_0 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_1" , &crate::flat::KEYCAP_1 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_10" , &crate::flat::KEYCAP_10 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_2" , &crate::flat::KEYCAP_2 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_3" , &crate::flat::KEYCAP_3 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_4" , &crate::flat::KEYCAP_4 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_5" , &crate::flat::KEYCAP_5 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_6" , &crate::flat::KEYCAP_6 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_7" , &crate::flat::KEYCAP_7 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_8" , &crate::flat::KEYCAP_8 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_9" , &crate::flat::KEYCAP_9 as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_asterisk" , &crate::flat::KEYCAP_ASTERISK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_hash" , &crate::flat::KEYCAP_HASH as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("keycap_ten" , &crate::flat::KEYCAP_10 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("kick_scooter" , &crate::flat::KICK_SCOOTER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("kimono" , &crate::flat::KIMONO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("kiribati" , &crate::flat::FLAG_KIRIBATI as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("kiss" , &crate::flat::KISS.default.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kiss_mark" , &crate::flat::KISS_MARK as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing" , &crate::flat::KISSING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing_cat" , &crate::flat::KISSING_CAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing_closed_eyes" , &crate::flat::KISSING_FACE_WITH_CLOSED_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing_face" , &crate::flat::KISSING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing_face_with_closed_eyes" , &crate::flat::KISSING_FACE_WITH_CLOSED_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing_face_with_smiling_eyes" , &crate::flat::KISSING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing_heart" , &crate::flat::FACE_BLOWING_A_KISS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("kissing_smiling_eyes" , &crate::flat::KISSING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("kitchen_knife" , &crate::flat::KITCHEN_KNIFE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("kite" , &crate::flat::KITE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("kiwi_fruit" , &crate::flat::KIWI_FRUIT as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("kneeling_man" , &crate::flat::PERSON_KNEELING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("kneeling_person" , &crate::flat::PERSON_KNEELING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("kneeling_woman" , &crate::flat::PERSON_KNEELING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("knife" , &crate::flat::KITCHEN_KNIFE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("knocked_out_face" , &crate::flat::KNOCKED_OUT_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("knot" , &crate::flat::KNOT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("koala" , &crate::flat::KOALA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("koko" , &crate::flat::JAPANESE_HERE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("kosovo" , &crate::flat::FLAG_KOSOVO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("kr" , &crate::flat::FLAG_SOUTH_KOREA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("kuwait" , &crate::flat::FLAG_KUWAIT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("kyrgyzstan" , &crate::flat::FLAG_KYRGYZSTAN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("lab_coat" , &crate::flat::LAB_COAT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("label" , &crate::flat::LABEL as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("lacrosse" , &crate::flat::LACROSSE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("ladder" , &crate::flat::LADDER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("lady_beetle" , &crate::flat::LADY_BEETLE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("lantern" , &crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("laos" , &crate::flat::FLAG_LAOS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("laptop" , &crate::flat::LAPTOP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("large_blue_circle" , &crate::flat::BLUE_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("large_blue_diamond" , &crate::flat::LARGE_BLUE_DIAMOND as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("large_orange_diamond" , &crate::flat::LARGE_ORANGE_DIAMOND as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("last_quarter_moon" , &crate::flat::LAST_QUARTER_MOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("last_quarter_moon_face" , &crate::flat::LAST_QUARTER_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("last_quarter_moon_with_face" , &crate::flat::LAST_QUARTER_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("last_track_button" , &crate::flat::LAST_TRACK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("latin_cross" , &crate::flat::LATIN_CROSS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("latvia" , &crate::flat::FLAG_LATVIA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("laughing" , &crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("leaf_fluttering_in_wind" , &crate::flat::LEAF_FLUTTERING_IN_WIND as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("leafy_green" , &crate::flat::LEAFY_GREEN as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("leaves" , &crate::flat::LEAF_FLUTTERING_IN_WIND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("lebanon" , &crate::flat::FLAG_LEBANON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("ledger" , &crate::flat::LEDGER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("left_arrow" , &crate::flat::LEFT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("left_arrow_curving_right" , &crate::flat::LEFT_ARROW_CURVING_RIGHT as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("left_facing_fist" , &crate::flat::LEFT_FACING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("left_luggage" , &crate::flat::LEFT_LUGGAGE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("left_right_arrow" , &crate::flat::LEFT_RIGHT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("left_speech_bubble" , &crate::flat::LEFT_SPEECH_BUBBLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("leftwards_arrow_with_hook" , &crate::flat::RIGHT_ARROW_CURVING_LEFT as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("leftwards_hand" , &crate::flat::LEFTWARDS_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("leg" , &crate::flat::LEG.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("lemon" , &crate::flat::LEMON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("leo" , &crate::flat::LEO as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("leopard" , &crate::flat::LEOPARD as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("lesotho" , &crate::flat::FLAG_LESOTHO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("level_slider" , &crate::flat::LEVEL_SLIDER as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("liberia" , &crate::flat::FLAG_LIBERIA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("libra" , &crate::flat::LIBRA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("libya" , &crate::flat::FLAG_LIBYA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("liechtenstein" , &crate::flat::FLAG_LIECHTENSTEIN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("light_bulb" , &crate::flat::LIGHT_BULB as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("light_rail" , &crate::flat::LIGHT_RAIL as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("light_skin_tone" , &crate::flat::LIGHT_SKIN_TONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("link" , &crate::flat::LINK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("linked_paperclips" , &crate::flat::LINKED_PAPERCLIPS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("lion" , &crate::flat::LION as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("lips" , &crate::flat::MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("lipstick" , &crate::flat::LIPSTICK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("lithuania" , &crate::flat::FLAG_LITHUANIA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("litter_in_bin_sign" , &crate::flat::LITTER_IN_BIN_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("lizard" , &crate::flat::LIZARD as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("llama" , &crate::flat::LLAMA as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("lobster" , &crate::flat::LOBSTER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("lock" , &crate::flat::LOCKED as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("lock_with_ink_pen" , &crate::flat::LOCKED_WITH_PEN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("locked" , &crate::flat::LOCKED as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("locked_with_key" , &crate::flat::LOCKED_WITH_KEY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("locked_with_pen" , &crate::flat::LOCKED_WITH_PEN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("locomotive" , &crate::flat::LOCOMOTIVE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("lollipop" , &crate::flat::LOLLIPOP as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("long_drum" , &crate::flat::LONG_DRUM as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("loop" , &crate::flat::DOUBLE_CURLY_LOOP as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("lotion_bottle" , &crate::flat::LOTION_BOTTLE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("lotus" , &crate::flat::LOTUS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("lotus_position" , &crate::flat::PERSON_IN_LOTUS_POSITION.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("lotus_position_man" , &crate::flat::PERSON_IN_LOTUS_POSITION.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("lotus_position_woman" , &crate::flat::PERSON_IN_LOTUS_POSITION.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("loud_sound" , &crate::flat::SPEAKER_HIGH_VOLUME as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("loudly_crying_face" , &crate::flat::LOUDLY_CRYING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("loudspeaker" , &crate::flat::LOUDSPEAKER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("love_hotel" , &crate::flat::LOVE_HOTEL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("love_letter" , &crate::flat::LOVE_LETTER as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("love_you_gesture" , &crate::flat::LOVE_YOU_GESTURE.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("low_battery" , &crate::flat::LOW_BATTERY as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("low_brightness" , &crate::flat::DIM_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("luggage" , &crate::flat::LUGGAGE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("lungs" , &crate::flat::LUNGS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("luxembourg" , &crate::flat::FLAG_LUXEMBOURG as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("lying_face" , &crate::flat::LYING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("m" , &crate::flat::CIRCLED_M as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("macau" , &crate::flat::FLAG_MACAO_SAR_CHINA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("macedonia" , &crate::flat::FLAG_NORTH_MACEDONIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("madagascar" , &crate::flat::FLAG_MADAGASCAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mag" , &crate::flat::MAGNIFYING_GLASS_TILTED_LEFT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mag_right" , &crate::flat::MAGNIFYING_GLASS_TILTED_RIGHT as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mage" , &crate::flat::MAGE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mage_man" , &crate::flat::MAGE.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mage_woman" , &crate::flat::MAGE.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("magic_wand" , &crate::flat::MAGIC_WAND as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("magnet" , &crate::flat::MAGNET as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("magnifying_glass_tilted_left" , &crate::flat::MAGNIFYING_GLASS_TILTED_LEFT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("magnifying_glass_tilted_right" , &crate::flat::MAGNIFYING_GLASS_TILTED_RIGHT as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("mahjong" , &crate::flat::MAHJONG_RED_DRAGON as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("mahjong_red_dragon" , &crate::flat::MAHJONG_RED_DRAGON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mailbox" , &crate::flat::CLOSED_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mailbox_closed" , &crate::flat::CLOSED_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mailbox_with_mail" , &crate::flat::OPEN_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mailbox_with_no_mail" , &crate::flat::OPEN_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("malawi" , &crate::flat::FLAG_MALAWI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("malaysia" , &crate::flat::FLAG_MALAYSIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("maldives" , &crate::flat::FLAG_MALDIVES as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("male_detective" , &crate::flat::DETECTIVE.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("male_sign" , &crate::flat::MALE_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("mali" , &crate::flat::FLAG_MALI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("malta" , &crate::flat::FLAG_MALTA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("mammoth" , &crate::flat::MAMMOTH as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man" , &crate::flat::PERSON.default.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_artist" , &crate::flat::ARTIST.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_astronaut" , &crate::flat::ASTRONAUT.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_beard" , &crate::flat::PERSON.hair(Hair::Beard).gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_cartwheeling" , &crate::flat::PERSON_CARTWHEELING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_cook" , &crate::flat::COOK.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_dancing" , &crate::flat::PERSON_DANCING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_facepalming" , &crate::flat::PERSON_FACEPALMING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_factory_worker" , &crate::flat::FACTORY_WORKER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_farmer" , &crate::flat::FARMER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_feeding_baby" , &crate::flat::PERSON_FEEDING_BABY.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_firefighter" , &crate::flat::FIREFIGHTER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_health_worker" , &crate::flat::HEALTH_WORKER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_in_manual_wheelchair" , &crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_in_motorized_wheelchair" , &crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_in_tuxedo" , &crate::flat::PERSON_IN_TUXEDO.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_judge" , &crate::flat::JUDGE.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_juggling" , &crate::flat::PERSON_JUGGLING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_mechanic" , &crate::flat::MECHANIC.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_office_worker" , &crate::flat::OFFICE_WORKER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_pilot" , &crate::flat::PILOT.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_playing_handball" , &crate::flat::PERSON_PLAYING_HANDBALL.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_playing_water_polo" , &crate::flat::PERSON_PLAYING_WATER_POLO.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("man_s_shoe" , &crate::flat::MAN_S_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_scientist" , &crate::flat::SCIENTIST.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_shrugging" , &crate::flat::PERSON_SHRUGGING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_singer" , &crate::flat::SINGER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_student" , &crate::flat::STUDENT.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_teacher" , &crate::flat::TEACHER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_technologist" , &crate::flat::TECHNOLOGIST.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_with_gua_pi_mao" , &crate::flat::PERSON_WITH_SKULLCAP.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_with_probing_cane" , &crate::flat::PERSON_WITH_WHITE_CANE.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_with_turban" , &crate::flat::PERSON_WEARING_TURBAN.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("man_with_veil" , &crate::flat::PERSON_WITH_VEIL.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("mandarin" , &crate::flat::TANGERINE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("mango" , &crate::flat::MANGO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mans_shoe" , &crate::flat::MAN_S_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("mantelpiece_clock" , &crate::flat::MANTELPIECE_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("manual_wheelchair" , &crate::flat::MANUAL_WHEELCHAIR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("map_of_japan" , &crate::flat::MAP_OF_JAPAN as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("maple_leaf" , &crate::flat::MAPLE_LEAF as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("marshall_islands" , &crate::flat::FLAG_MARSHALL_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("martial_arts_uniform" , &crate::flat::MARTIAL_ARTS_UNIFORM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("martinique" , &crate::flat::FLAG_MARTINIQUE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("mask" , &crate::flat::FACE_WITH_MEDICAL_MASK as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("massage" , &crate::flat::PERSON_GETTING_MASSAGE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("massage_man" , &crate::flat::PERSON_GETTING_MASSAGE.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("massage_woman" , &crate::flat::PERSON_GETTING_MASSAGE.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("mate" , &crate::flat::MATE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("mauritania" , &crate::flat::FLAG_MAURITANIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("mauritius" , &crate::flat::FLAG_MAURITIUS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("mayotte" , &crate::flat::FLAG_MAYOTTE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("meat_on_bone" , &crate::flat::MEAT_ON_BONE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mechanic" , &crate::flat::MECHANIC.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mechanical_arm" , &crate::flat::MECHANICAL_ARM as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mechanical_leg" , &crate::flat::MECHANICAL_LEG as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("medal_military" , &crate::flat::MILITARY_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("medal_sports" , &crate::flat::SPORTS_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("medical_symbol" , &crate::flat::MEDICAL_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("medium_dark_skin_tone" , &crate::flat::MEDIUM_DARK_SKIN_TONE as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("medium_light_skin_tone" , &crate::flat::MEDIUM_LIGHT_SKIN_TONE as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("medium_skin_tone" , &crate::flat::MEDIUM_SKIN_TONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mega" , &crate::flat::MEGAPHONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("megaphone" , &crate::flat::MEGAPHONE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("melon" , &crate::flat::MELON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("melting_face" , &crate::flat::MELTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("memo" , &crate::flat::MEMO as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("men_s_room" , &crate::flat::MEN_S_ROOM as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("men_wrestling" , &crate::flat::PERSON_WRESTLING.gender(Gender::Male) as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("mending_heart" , &crate::flat::MENDING_HEART as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("menorah" , &crate::flat::MENORAH as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("mens" , &crate::flat::MEN_S_ROOM as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mermaid" , &crate::flat::MERMAID.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("merman" , &crate::flat::MERMAN.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("merperson" , &crate::flat::MERPERSON.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("metal" , &crate::flat::SIGN_OF_THE_HORNS.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("metro" , &crate::flat::METRO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("mexico" , &crate::flat::FLAG_MEXICO as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("microbe" , &crate::flat::MICROBE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("micronesia" , &crate::flat::FLAG_MICRONESIA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("microphone" , &crate::flat::MICROPHONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("microscope" , &crate::flat::MICROSCOPE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("middle_finger" , &crate::flat::MIDDLE_FINGER.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("military_helmet" , &crate::flat::MILITARY_HELMET as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("military_medal" , &crate::flat::MILITARY_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("milk_glass" , &crate::flat::GLASS_OF_MILK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("milky_way" , &crate::flat::MILKY_WAY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("minibus" , &crate::flat::MINIBUS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("minidisc" , &crate::flat::COMPUTER_DISK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("minus" , &crate::flat::MINUS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mirror" , &crate::flat::MIRROR as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("mirror_ball" , &crate::flat::MIRROR_BALL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("moai" , &crate::flat::MOAI as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mobile_phone" , &crate::flat::MOBILE_PHONE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("mobile_phone_off" , &crate::flat::MOBILE_PHONE_OFF as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mobile_phone_with_arrow" , &crate::flat::MOBILE_PHONE_WITH_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("moldova" , &crate::flat::FLAG_MOLDOVA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("monaco" , &crate::flat::FLAG_MONACO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("money_bag" , &crate::flat::MONEY_BAG as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("money_mouth_face" , &crate::flat::MONEY_MOUTH_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("money_with_wings" , &crate::flat::MONEY_WITH_WINGS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("moneybag" , &crate::flat::MONEY_BAG as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("mongolia" , &crate::flat::FLAG_MONGOLIA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("monkey" , &crate::flat::MONKEY as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("monkey_face" , &crate::flat::MONKEY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("monocle_face" , &crate::flat::FACE_WITH_MONOCLE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("monorail" , &crate::flat::MONORAIL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("montenegro" , &crate::flat::FLAG_MONTENEGRO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("montserrat" , &crate::flat::FLAG_MONTSERRAT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("moon" , &crate::flat::WAXING_GIBBOUS_MOON as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("moon_cake" , &crate::flat::MOON_CAKE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("moon_viewing_ceremony" , &crate::flat::MOON_VIEWING_CEREMONY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("morocco" , &crate::flat::FLAG_MOROCCO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mortar_board" , &crate::flat::GRADUATION_CAP as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("mosque" , &crate::flat::MOSQUE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("mosquito" , &crate::flat::MOSQUITO as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("motor_boat" , &crate::flat::MOTOR_BOAT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("motor_scooter" , &crate::flat::MOTOR_SCOOTER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("motorcycle" , &crate::flat::MOTORCYCLE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("motorized_wheelchair" , &crate::flat::MOTORIZED_WHEELCHAIR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("motorway" , &crate::flat::MOTORWAY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("mount_fuji" , &crate::flat::MOUNT_FUJI as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("mountain" , &crate::flat::MOUNTAIN as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mountain_bicyclist" , &crate::flat::PERSON_MOUNTAIN_BIKING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mountain_biking_man" , &crate::flat::PERSON_MOUNTAIN_BIKING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mountain_biking_woman" , &crate::flat::PERSON_MOUNTAIN_BIKING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("mountain_cableway" , &crate::flat::MOUNTAIN_CABLEWAY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("mountain_railway" , &crate::flat::MOUNTAIN_RAILWAY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("mountain_snow" , &crate::flat::SNOW_CAPPED_MOUNTAIN as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("mouse" , &crate::flat::MOUSE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("mouse2" , &crate::flat::MOUSE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("mouse_face" , &crate::flat::MOUSE_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mouse_trap" , &crate::flat::MOUSE_TRAP as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mouth" , &crate::flat::MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("movie_camera" , &crate::flat::MOVIE_CAMERA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("moyai" , &crate::flat::MOAI as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("mozambique" , &crate::flat::FLAG_MOZAMBIQUE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mrs_claus" , &crate::flat::MRS_CLAUS.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("multiply" , &crate::flat::MULTIPLY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("muscle" , &crate::flat::FLEXED_BICEPS.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("mushroom" , &crate::flat::MUSHROOM as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("musical_keyboard" , &crate::flat::MUSICAL_KEYBOARD as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("musical_note" , &crate::flat::MUSICAL_NOTE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("musical_notes" , &crate::flat::MUSICAL_NOTES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("musical_score" , &crate::flat::MUSICAL_SCORE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("mute" , &crate::flat::MUTED_SPEAKER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("muted_speaker" , &crate::flat::MUTED_SPEAKER as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("mx_claus" , &crate::flat::MX_CLAUS.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("myanmar" , &crate::flat::FLAG_MYANMAR_BURMA as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("nail_care" , &crate::flat::NAIL_POLISH.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("nail_polish" , &crate::flat::NAIL_POLISH.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("name_badge" , &crate::flat::NAME_BADGE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("namibia" , &crate::flat::FLAG_NAMIBIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("national_park" , &crate::flat::NATIONAL_PARK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("nauru" , &crate::flat::FLAG_NAURU as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("nauseated_face" , &crate::flat::NAUSEATED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("nazar_amulet" , &crate::flat::NAZAR_AMULET as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("necktie" , &crate::flat::NECKTIE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("negative_squared_cross_mark" , &crate::flat::CROSS_MARK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("nepal" , &crate::flat::FLAG_NEPAL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("nerd_face" , &crate::flat::NERD_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("nest_with_eggs" , &crate::flat::NEST_WITH_EGGS as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("nesting_dolls" , &crate::flat::NESTING_DOLLS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("netherlands" , &crate::flat::FLAG_NETHERLANDS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("neutral_face" , &crate::flat::NEUTRAL_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("new" , &crate::flat::NEW_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("new_button" , &crate::flat::NEW_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("new_caledonia" , &crate::flat::FLAG_NEW_CALEDONIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("new_moon" , &crate::flat::NEW_MOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("new_moon_face" , &crate::flat::NEW_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("new_moon_with_face" , &crate::flat::NEW_MOON_FACE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("new_zealand" , &crate::flat::FLAG_NEW_ZEALAND as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("newspaper" , &crate::flat::NEWSPAPER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("newspaper_roll" , &crate::flat::ROLLED_UP_NEWSPAPER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("next_track_button" , &crate::flat::NEXT_TRACK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ng" , &crate::flat::NG_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ng_button" , &crate::flat::NG_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ng_man" , &crate::flat::PERSON_GESTURING_NO.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ng_woman" , &crate::flat::PERSON_GESTURING_NO.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("nicaragua" , &crate::flat::FLAG_NICARAGUA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("niger" , &crate::flat::FLAG_NIGER as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("nigeria" , &crate::flat::FLAG_NIGERIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("night_with_stars" , &crate::flat::NIGHT_WITH_STARS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("nine" , &crate::flat::KEYCAP_9 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("nine_o_clock" , &crate::flat::NINE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("nine_thirty" , &crate::flat::NINE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ninja" , &crate::flat::NINJA.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("niue" , &crate::flat::FLAG_NIUE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("no_bell" , &crate::flat::BELL_WITH_SLASH as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_bicycles" , &crate::flat::NO_BICYCLES as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_entry" , &crate::flat::NO_ENTRY as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_entry_sign" , &crate::flat::PROHIBITED as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("no_good" , &crate::flat::PERSON_GESTURING_NO.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("no_good_man" , &crate::flat::PERSON_GESTURING_NO.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("no_good_woman" , &crate::flat::PERSON_GESTURING_NO.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_littering" , &crate::flat::NO_LITTERING as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_mobile_phones" , &crate::flat::NO_MOBILE_PHONES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("no_mouth" , &crate::flat::FACE_WITHOUT_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_one_under_eighteen" , &crate::flat::NO_ONE_UNDER_EIGHTEEN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_pedestrians" , &crate::flat::NO_PEDESTRIANS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("no_smoking" , &crate::flat::NO_SMOKING as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("non-potable_water" , &crate::flat::NON_POTABLE_WATER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("non_potable_water" , &crate::flat::NON_POTABLE_WATER as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("norfolk_island" , &crate::flat::FLAG_NORFOLK_ISLAND as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("north_korea" , &crate::flat::FLAG_NORTH_KOREA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("northern_mariana_islands" , &crate::flat::FLAG_NORTHERN_MARIANA_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("norway" , &crate::flat::FLAG_NORWAY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("nose" , &crate::flat::NOSE.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("notebook" , &crate::flat::NOTEBOOK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("notebook_with_decorative_cover" , &crate::flat::NOTEBOOK_WITH_DECORATIVE_COVER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("notes" , &crate::flat::MUSICAL_NOTES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("nut_and_bolt" , &crate::flat::NUT_AND_BOLT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("o" , &crate::flat::HOLLOW_RED_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("o2" , &crate::flat::O_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("o_button_blood_type" , &crate::flat::O_BUTTON_BLOOD_TYPE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ocean" , &crate::flat::WATER_WAVE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("octopus" , &crate::flat::OCTOPUS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("oden" , &crate::flat::ODEN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("office" , &crate::flat::OFFICE_BUILDING as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("office_building" , &crate::flat::OFFICE_BUILDING as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("office_worker" , &crate::flat::OFFICE_WORKER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("ogre" , &crate::flat::OGRE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("oil_drum" , &crate::flat::OIL_DRUM as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ok" , &crate::flat::OK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ok_button" , &crate::flat::OK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ok_hand" , &crate::flat::OK_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ok_man" , &crate::flat::PERSON_GESTURING_OK.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ok_person" , &crate::flat::PERSON_GESTURING_OK.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("ok_woman" , &crate::flat::PERSON_GESTURING_OK.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("old_key" , &crate::flat::OLD_KEY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("older_adult" , &crate::flat::OLDER_PERSON.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("older_man" , &crate::flat::OLD_PERSON.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("older_person" , &crate::flat::OLDER_PERSON.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("older_woman" , &crate::flat::OLD_PERSON.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("olive" , &crate::flat::OLIVE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("om" , &crate::flat::OM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("oman" , &crate::flat::FLAG_OMAN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("on" , &crate::flat::ON_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("on_arrow" , &crate::flat::ON_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("oncoming_automobile" , &crate::flat::ONCOMING_AUTOMOBILE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("oncoming_bus" , &crate::flat::ONCOMING_BUS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("oncoming_fist" , &crate::flat::ONCOMING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("oncoming_police_car" , &crate::flat::ONCOMING_POLICE_CAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("oncoming_taxi" , &crate::flat::ONCOMING_TAXI as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("one" , &crate::flat::KEYCAP_1 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("one_o_clock" , &crate::flat::ONE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("one_piece_swimsuit" , &crate::flat::ONE_PIECE_SWIMSUIT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("one_thirty" , &crate::flat::ONE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("onion" , &crate::flat::ONION as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("open_book" , &crate::flat::OPEN_BOOK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("open_file_folder" , &crate::flat::OPEN_FILE_FOLDER as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("open_hands" , &crate::flat::OPEN_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("open_mailbox_with_lowered_flag" , &crate::flat::OPEN_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("open_mailbox_with_raised_flag" , &crate::flat::OPEN_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("open_mouth" , &crate::flat::FACE_WITH_OPEN_MOUTH as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("open_umbrella" , &crate::flat::UMBRELLA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("ophiuchus" , &crate::flat::OPHIUCHUS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("optical_disk" , &crate::flat::OPTICAL_DISK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("orange" , &crate::flat::TANGERINE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("orange_book" , &crate::flat::ORANGE_BOOK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("orange_circle" , &crate::flat::ORANGE_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("orange_heart" , &crate::flat::ORANGE_HEART as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("orange_square" , &crate::flat::ORANGE_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("orangutan" , &crate::flat::ORANGUTAN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("orthodox_cross" , &crate::flat::ORTHODOX_CROSS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("otter" , &crate::flat::OTTER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("outbox_tray" , &crate::flat::OUTBOX_TRAY as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("owl" , &crate::flat::OWL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("ox" , &crate::flat::OX as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("oyster" , &crate::flat::OYSTER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("p_button" , &crate::flat::P_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("package" , &crate::flat::PACKAGE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("page_facing_up" , &crate::flat::PAGE_FACING_UP as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("page_with_curl" , &crate::flat::PAGE_WITH_CURL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pager" , &crate::flat::PAGER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("paintbrush" , &crate::flat::PAINTBRUSH as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("pakistan" , &crate::flat::FLAG_PAKISTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("palau" , &crate::flat::FLAG_PALAU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("palestinian_territories" , &crate::flat::FLAG_PALESTINIAN_TERRITORIES as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("palm_down_hand" , &crate::flat::PALM_DOWN_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("palm_tree" , &crate::flat::PALM_TREE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("palm_up_hand" , &crate::flat::PALM_UP_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("palms_up_together" , &crate::flat::PALMS_UP_TOGETHER.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("panama" , &crate::flat::FLAG_PANAMA as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pancakes" , &crate::flat::PANCAKES as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("panda" , &crate::flat::PANDA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("panda_face" , &crate::flat::PANDA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("paperclip" , &crate::flat::PAPERCLIP as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("paperclips" , &crate::flat::LINKED_PAPERCLIPS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("papua_new_guinea" , &crate::flat::FLAG_PAPUA_NEW_GUINEA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("parachute" , &crate::flat::PARACHUTE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("paraguay" , &crate::flat::FLAG_PARAGUAY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("parasol_on_ground" , &crate::flat::UMBRELLA_ON_GROUND as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("parking" , &crate::flat::P_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("parrot" , &crate::flat::PARROT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("part_alternation_mark" , &crate::flat::PART_ALTERNATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("partly_sunny" , &crate::flat::SUN_BEHIND_CLOUD as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("party_popper" , &crate::flat::PARTY_POPPER as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("partying_face" , &crate::flat::PARTYING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("passenger_ship" , &crate::flat::PASSENGER_SHIP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("passport_control" , &crate::flat::PASSPORT_CONTROL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("pause_button" , &crate::flat::PAUSE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("paw_prints" , &crate::flat::PAW_PRINTS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("peace_symbol" , &crate::flat::PEACE_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("peach" , &crate::flat::PEACH as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("peacock" , &crate::flat::PEACOCK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("peanuts" , &crate::flat::PEANUTS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pear" , &crate::flat::PEAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pen" , &crate::flat::PEN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pencil" , &crate::flat::PENCIL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pencil2" , &crate::flat::PENCIL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("penguin" , &crate::flat::PENGUIN as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("pensive" , &crate::flat::PENSIVE_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("pensive_face" , &crate::flat::PENSIVE_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("people_holding_hands" , &crate::flat::PERSON_HOLDING_HANDS.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("people_hugging" , &crate::flat::PERSON_HUGGING as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("performing_arts" , &crate::flat::PERFORMING_ARTS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("persevere" , &crate::flat::PERSEVERING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("persevering_face" , &crate::flat::PERSEVERING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person" , &crate::flat::PERSON.default.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_bald" , &crate::flat::PERSON.hair(Hair::Bald).default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_biking" , &crate::flat::PERSON_BIKING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_bouncing_ball" , &crate::flat::PERSON_BOUNCING_BALL.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_bowing" , &crate::flat::PERSON_BOWING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_cartwheeling" , &crate::flat::PERSON_CARTWHEELING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_climbing" , &crate::flat::PERSON_CLIMBING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_curly_hair" , &crate::flat::PERSON.hair(Hair::Curly).default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_facepalming" , &crate::flat::PERSON_FACEPALMING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_feeding_baby" , &crate::flat::PERSON_FEEDING_BABY.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_fencing" , &crate::flat::PERSON_FENCING as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_frowning" , &crate::flat::PERSON_FROWNING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_gesturing_no" , &crate::flat::PERSON_GESTURING_NO.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_gesturing_ok" , &crate::flat::PERSON_GESTURING_OK.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_getting_haircut" , &crate::flat::PERSON_GETTING_HAIRCUT.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_getting_massage" , &crate::flat::PERSON_GETTING_MASSAGE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_golfing" , &crate::flat::PERSON_GOLFING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_holding_hands" , &crate::flat::PERSON_HOLDING_HANDS.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_hugging" , &crate::flat::PERSON_HUGGING as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_in_bed" , &crate::flat::PERSON_IN_BED.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_in_lotus_position" , &crate::flat::PERSON_IN_LOTUS_POSITION.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_in_manual_wheelchair" , &crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_in_motorized_wheelchair" , &crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_in_steamy_room" , &crate::flat::PERSON_IN_STEAMY_ROOM.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_in_suit_levitating" , &crate::flat::PERSON_IN_SUIT_LEVITATING.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_in_tuxedo" , &crate::flat::PERSON_IN_TUXEDO.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_juggling" , &crate::flat::PERSON_JUGGLING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_kneeling" , &crate::flat::PERSON_KNEELING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_lifting_weights" , &crate::flat::PERSON_LIFTING_WEIGHTS.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_mountain_biking" , &crate::flat::PERSON_MOUNTAIN_BIKING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_playing_handball" , &crate::flat::PERSON_PLAYING_HANDBALL.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_playing_water_polo" , &crate::flat::PERSON_PLAYING_WATER_POLO.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_pouting" , &crate::flat::PERSON_POUTING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_raising_hand" , &crate::flat::PERSON_RAISING_HAND.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_red_hair" , &crate::flat::PERSON.hair(Hair::Red).default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_rowing_boat" , &crate::flat::PERSON_ROWING_BOAT.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_running" , &crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_shrugging" , &crate::flat::PERSON_SHRUGGING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_standing" , &crate::flat::PERSON_STANDING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_surfing" , &crate::flat::PERSON_SURFING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_swimming" , &crate::flat::PERSON_SWIMMING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_taking_bath" , &crate::flat::PERSON_TAKING_BATH.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_tipping_hand" , &crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_walking" , &crate::flat::PERSON_WALKING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_wearing_turban" , &crate::flat::PERSON_WEARING_TURBAN.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_white_hair" , &crate::flat::PERSON.hair(Hair::White).default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_with_bunny_ears" , &crate::flat::PERSON_WITH_BUNNY_EARS.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_with_crown" , &crate::flat::PERSON_WITH_CROWN.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_with_probing_cane" , &crate::flat::PERSON_WITH_WHITE_CANE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_with_skullcap" , &crate::flat::PERSON_WITH_SKULLCAP.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_with_turban" , &crate::flat::PERSON_WEARING_TURBAN.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_with_veil" , &crate::flat::PERSON_WITH_VEIL.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_with_white_cane" , &crate::flat::PERSON_WITH_WHITE_CANE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("person_wrestling" , &crate::flat::PERSON_WRESTLING.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("peru" , &crate::flat::FLAG_PERU as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("petri_dish" , &crate::flat::PETRI_DISH as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("philippines" , &crate::flat::FLAG_PHILIPPINES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("phone" , &crate::flat::TELEPHONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pick" , &crate::flat::PICK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("pickup_truck" , &crate::flat::PICKUP_TRUCK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pie" , &crate::flat::PIE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("pig" , &crate::flat::PIG as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("pig2" , &crate::flat::PIG as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("pig_face" , &crate::flat::PIG_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("pig_nose" , &crate::flat::PIG_NOSE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("pile_of_poo" , &crate::flat::PILE_OF_POO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pill" , &crate::flat::PILL as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pilot" , &crate::flat::PILOT.default.default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("pinata" , &crate::flat::PINATA as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pinched_fingers" , &crate::flat::PINCHED_FINGERS.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pinching_hand" , &crate::flat::PINCHING_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("pine_decoration" , &crate::flat::PINE_DECORATION as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pineapple" , &crate::flat::PINEAPPLE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("ping_pong" , &crate::flat::PING_PONG as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("pirate_flag" , &crate::flat::PIRATE_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("pisces" , &crate::flat::PISCES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("pitcairn_islands" , &crate::flat::FLAG_PITCAIRN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pizza" , &crate::flat::PIZZA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("placard" , &crate::flat::PLACARD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("place_of_worship" , &crate::flat::PLACE_OF_WORSHIP as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("plate_with_cutlery" , &crate::flat::FORK_AND_KNIFE_WITH_PLATE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("play_button" , &crate::flat::PLAY_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("play_or_pause_button" , &crate::flat::PLAY_OR_PAUSE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("playground_slide" , &crate::flat::PLAYGROUND_SLIDE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("pleading_face" , &crate::flat::PLEADING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("plunger" , &crate::flat::PLUNGER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("plus" , &crate::flat::PLUS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("point_down" , &crate::flat::BACKHAND_INDEX_POINTING_DOWN.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("point_left" , &crate::flat::BACKHAND_INDEX_POINTING_LEFT.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("point_right" , &crate::flat::BACKHAND_INDEX_POINTING_RIGHT.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("point_up" , &crate::flat::INDEX_POINTING_UP.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("point_up_2" , &crate::flat::BACKHAND_INDEX_POINTING_UP.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("poland" , &crate::flat::FLAG_POLAND as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("polar_bear" , &crate::flat::POLAR_BEAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("police_car" , &crate::flat::POLICE_CAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("police_car_light" , &crate::flat::POLICE_CAR_LIGHT as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("police_officer" , &crate::flat::POLICE_OFFICER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("policeman" , &crate::flat::POLICE_OFFICER.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("policewoman" , &crate::flat::POLICE_OFFICER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("poodle" , &crate::flat::POODLE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("pool_8_ball" , &crate::flat::POOL_8_BALL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("poop" , &crate::flat::PILE_OF_POO as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("popcorn" , &crate::flat::POPCORN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("portugal" , &crate::flat::FLAG_PORTUGAL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("post_office" , &crate::flat::POST_OFFICE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("postal_horn" , &crate::flat::POSTAL_HORN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("postbox" , &crate::flat::POSTBOX as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pot_of_food" , &crate::flat::POT_OF_FOOD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("potable_water" , &crate::flat::POTABLE_WATER as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("potato" , &crate::flat::POTATO as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("potted_plant" , &crate::flat::POTTED_PLANT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pouch" , &crate::flat::CLUTCH_BAG as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("poultry_leg" , &crate::flat::POULTRY_LEG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pound" , &crate::flat::POUND_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pound_banknote" , &crate::flat::POUND_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pouring_liquid" , &crate::flat::POURING_LIQUID as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("pout" , &crate::flat::POUTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("pouting_cat" , &crate::flat::POUTING_CAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("pouting_face" , &crate::flat::POUTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pouting_man" , &crate::flat::PERSON_POUTING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pouting_woman" , &crate::flat::PERSON_POUTING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pray" , &crate::flat::FOLDED_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("prayer_beads" , &crate::flat::PRAYER_BEADS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pregnant_man" , &crate::flat::PREGNANT_PERSON.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pregnant_person" , &crate::flat::PREGNANT_PERSON.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("pregnant_woman" , &crate::flat::PREGNANT_PERSON.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("pretzel" , &crate::flat::PRETZEL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("previous_track_button" , &crate::flat::LAST_TRACK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("prince" , &crate::flat::PRINCE.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("princess" , &crate::flat::PRINCESS.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("printer" , &crate::flat::PRINTER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("probing_cane" , &crate::flat::WHITE_CANE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("prohibited" , &crate::flat::PROHIBITED as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("puerto_rico" , &crate::flat::FLAG_PUERTO_RICO as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("punch" , &crate::flat::ONCOMING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("purple_circle" , &crate::flat::PURPLE_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("purple_heart" , &crate::flat::PURPLE_HEART as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("purple_square" , &crate::flat::PURPLE_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("purse" , &crate::flat::PURSE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("pushpin" , &crate::flat::PUSHPIN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("put_litter_in_its_place" , &crate::flat::LITTER_IN_BIN_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("puzzle_piece" , &crate::flat::PUZZLE_PIECE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("qatar" , &crate::flat::FLAG_QATAR as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("question" , &crate::flat::RED_QUESTION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rabbit" , &crate::flat::RABBIT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rabbit2" , &crate::flat::RABBIT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rabbit_face" , &crate::flat::RABBIT_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("raccoon" , &crate::flat::RACCOON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("racehorse" , &crate::flat::HORSE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("racing_car" , &crate::flat::RACING_CAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("radio" , &crate::flat::RADIO as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("radio_button" , &crate::flat::RADIO_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("radioactive" , &crate::flat::RADIOACTIVE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("rage" , &crate::flat::POUTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("railway_car" , &crate::flat::RAILWAY_CAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("railway_track" , &crate::flat::RAILWAY_TRACK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("rainbow" , &crate::flat::RAINBOW as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("rainbow_flag" , &crate::flat::RAINBOW_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raised_back_of_hand" , &crate::flat::RAISED_BACK_OF_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("raised_eyebrow" , &crate::flat::FACE_WITH_RAISED_EYEBROW as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raised_fist" , &crate::flat::RAISED_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raised_hand" , &crate::flat::RAISED_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raised_hand_with_fingers_splayed" , &crate::flat::HAND_WITH_FINGERS_SPLAYED.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raised_hands" , &crate::flat::RAISING_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raising_hand" , &crate::flat::PERSON_RAISING_HAND.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raising_hand_man" , &crate::flat::PERSON_RAISING_HAND.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raising_hand_woman" , &crate::flat::PERSON_RAISING_HAND.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("raising_hands" , &crate::flat::RAISING_HANDS.default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("ram" , &crate::flat::RAM as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("ramen" , &crate::flat::STEAMING_BOWL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rat" , &crate::flat::RAT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("razor" , &crate::flat::RAZOR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("receipt" , &crate::flat::RECEIPT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("record_button" , &crate::flat::RECORD_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("recycle" , &crate::flat::RECYCLING_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("recycling_symbol" , &crate::flat::RECYCLING_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("red_apple" , &crate::flat::RED_APPLE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("red_car" , &crate::flat::AUTOMOBILE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("red_circle" , &crate::flat::RED_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("red_envelope" , &crate::flat::RED_ENVELOPE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("red_exclamation_mark" , &crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("red_hair" , &crate::flat::RED_HAIR as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("red_haired_man" , &crate::flat::PERSON.hair(Hair::Red).gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("red_haired_woman" , &crate::flat::PERSON.hair(Hair::Red).gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("red_heart" , &crate::flat::RED_HEART as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("red_paper_lantern" , &crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("red_question_mark" , &crate::flat::RED_QUESTION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("red_square" , &crate::flat::RED_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("red_triangle_pointed_down" , &crate::flat::RED_TRIANGLE_POINTED_DOWN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("red_triangle_pointed_up" , &crate::flat::RED_TRIANGLE_POINTED_UP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("registered" , &crate::flat::REGISTERED as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("relaxed" , &crate::flat::SMILING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("relieved" , &crate::flat::RELIEVED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("relieved_face" , &crate::flat::RELIEVED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("reminder_ribbon" , &crate::flat::REMINDER_RIBBON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("repeat" , &crate::flat::REPEAT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("repeat_button" , &crate::flat::REPEAT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("repeat_one" , &crate::flat::REPEAT_SINGLE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("repeat_single_button" , &crate::flat::REPEAT_SINGLE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("rescue_worker_helmet" , &crate::flat::RESCUE_WORKER_S_HELMET as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("rescue_worker_s_helmet" , &crate::flat::RESCUE_WORKER_S_HELMET as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("restroom" , &crate::flat::RESTROOM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("reunion" , &crate::flat::FLAG_REUNION as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("reverse_button" , &crate::flat::REVERSE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("revolving_hearts" , &crate::flat::REVOLVING_HEARTS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("rewind" , &crate::flat::FAST_REVERSE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rhinoceros" , &crate::flat::RHINOCEROS as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("ribbon" , &crate::flat::RIBBON as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("rice" , &crate::flat::COOKED_RICE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("rice_ball" , &crate::flat::RICE_BALL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("rice_cracker" , &crate::flat::RICE_CRACKER as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("rice_scene" , &crate::flat::MOON_VIEWING_CEREMONY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("right_anger_bubble" , &crate::flat::RIGHT_ANGER_BUBBLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("right_arrow" , &crate::flat::RIGHT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("right_arrow_curving_down" , &crate::flat::RIGHT_ARROW_CURVING_DOWN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("right_arrow_curving_left" , &crate::flat::RIGHT_ARROW_CURVING_LEFT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("right_arrow_curving_up" , &crate::flat::RIGHT_ARROW_CURVING_UP as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("right_facing_fist" , &crate::flat::RIGHT_FACING_FIST.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("rightwards_hand" , &crate::flat::RIGHTWARDS_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("ring" , &crate::flat::RING as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ring_buoy" , &crate::flat::RING_BUOY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ringed_planet" , &crate::flat::RINGED_PLANET as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("roasted_sweet_potato" , &crate::flat::ROASTED_SWEET_POTATO as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("robot" , &crate::flat::ROBOT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("rock" , &crate::flat::ROCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("rocket" , &crate::flat::ROCKET as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("rofl" , &crate::flat::ROLLING_ON_THE_FLOOR_LAUGHING as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("roll_eyes" , &crate::flat::FACE_WITH_ROLLING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("roll_of_paper" , &crate::flat::ROLL_OF_PAPER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("rolled_up_newspaper" , &crate::flat::ROLLED_UP_NEWSPAPER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("roller_coaster" , &crate::flat::ROLLER_COASTER as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("roller_skate" , &crate::flat::ROLLER_SKATE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("rolling_on_the_floor_laughing" , &crate::flat::ROLLING_ON_THE_FLOOR_LAUGHING as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("romania" , &crate::flat::FLAG_ROMANIA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rooster" , &crate::flat::ROOSTER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rose" , &crate::flat::ROSE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("rosette" , &crate::flat::ROSETTE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("rotating_light" , &crate::flat::POLICE_CAR_LIGHT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("round_pushpin" , &crate::flat::ROUND_PUSHPIN as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("rowboat" , &crate::flat::PERSON_ROWING_BOAT.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("rowing_man" , &crate::flat::PERSON_ROWING_BOAT.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("rowing_woman" , &crate::flat::PERSON_ROWING_BOAT.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ru" , &crate::flat::FLAG_RUSSIA as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("rugby_football" , &crate::flat::RUGBY_FOOTBALL as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("runner" , &crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("running" , &crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("running_man" , &crate::flat::PERSON_RUNNING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("running_shirt" , &crate::flat::RUNNING_SHIRT as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("running_shirt_with_sash" , &crate::flat::RUNNING_SHIRT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("running_shoe" , &crate::flat::RUNNING_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("running_woman" , &crate::flat::PERSON_RUNNING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("rwanda" , &crate::flat::FLAG_RWANDA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("sa" , &crate::flat::JAPANESE_SERVICE_CHARGE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sad_but_relieved_face" , &crate::flat::SAD_BUT_RELIEVED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("safety_pin" , &crate::flat::SAFETY_PIN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("safety_vest" , &crate::flat::SAFETY_VEST as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("sagittarius" , &crate::flat::SAGITTARIUS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sailboat" , &crate::flat::SAILBOAT as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("sake" , &crate::flat::SAKE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("salt" , &crate::flat::SALT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("saluting_face" , &crate::flat::SALUTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("samoa" , &crate::flat::FLAG_SAMOA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("san_marino" , &crate::flat::FLAG_SAN_MARINO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("sandal" , &crate::flat::WOMAN_S_SANDAL as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("sandwich" , &crate::flat::SANDWICH as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("santa" , &crate::flat::SANTA_CLAUS.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("santa_claus" , &crate::flat::SANTA_CLAUS.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("sao_tome_principe" , &crate::flat::FLAG_SAO_TOME_AND_PRINCIPE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("sari" , &crate::flat::SARI as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("sassy_man" , &crate::flat::PERSON_TIPPING_HAND.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("sassy_woman" , &crate::flat::PERSON_TIPPING_HAND.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("satellite" , &crate::flat::SATELLITE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("satellite_antenna" , &crate::flat::SATELLITE_ANTENNA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("satisfied" , &crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("saudi_arabia" , &crate::flat::FLAG_SAUDI_ARABIA as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("sauna_man" , &crate::flat::PERSON_IN_STEAMY_ROOM.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("sauna_person" , &crate::flat::PERSON_IN_STEAMY_ROOM.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("sauna_woman" , &crate::flat::PERSON_IN_STEAMY_ROOM.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("sauropod" , &crate::flat::SAUROPOD as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("saxophone" , &crate::flat::SAXOPHONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("scarf" , &crate::flat::SCARF as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("school" , &crate::flat::SCHOOL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("school_satchel" , &crate::flat::BACKPACK as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("scientist" , &crate::flat::SCIENTIST.default.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("scissors" , &crate::flat::SCISSORS as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("scorpio" , &crate::flat::SCORPIO as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("scorpion" , &crate::flat::SCORPION as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("scorpius" , &crate::flat::SCORPIO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("scotland" , &crate::flat::FLAG_SCOTLAND as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("scream" , &crate::flat::FACE_SCREAMING_IN_FEAR as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("scream_cat" , &crate::flat::WEARY_CAT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("screwdriver" , &crate::flat::SCREWDRIVER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("scroll" , &crate::flat::SCROLL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("seal" , &crate::flat::SEAL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("seat" , &crate::flat::SEAT as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("second_place_medal" , &crate::flat::SECOND_PLACE_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("secret" , &crate::flat::JAPANESE_SECRET_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("see_no_evil" , &crate::flat::SEE_NO_EVIL_MONKEY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("see_no_evil_monkey" , &crate::flat::SEE_NO_EVIL_MONKEY as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("seedling" , &crate::flat::SEEDLING as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("selfie" , &crate::flat::SELFIE.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("senegal" , &crate::flat::FLAG_SENEGAL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("serbia" , &crate::flat::FLAG_SERBIA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("service_dog" , &crate::flat::SERVICE_DOG as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("seven" , &crate::flat::KEYCAP_7 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("seven_o_clock" , &crate::flat::SEVEN_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("seven_thirty" , &crate::flat::SEVEN_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("sewing_needle" , &crate::flat::SEWING_NEEDLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("seychelles" , &crate::flat::FLAG_SEYCHELLES as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("shallow_pan_of_food" , &crate::flat::SHALLOW_PAN_OF_FOOD as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("shamrock" , &crate::flat::SHAMROCK as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("shark" , &crate::flat::SHARK as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("shaved_ice" , &crate::flat::SHAVED_ICE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("sheaf_of_rice" , &crate::flat::SHEAF_OF_RICE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("sheep" , &crate::flat::EWE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("shell" , &crate::flat::SPIRAL_SHELL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shield" , &crate::flat::SHIELD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("shinto_shrine" , &crate::flat::SHINTO_SHRINE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ship" , &crate::flat::SHIP as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shirt" , &crate::flat::T_SHIRT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("shit" , &crate::flat::PILE_OF_POO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shoe" , &crate::flat::MAN_S_SHOE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("shooting_star" , &crate::flat::SHOOTING_STAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shopping" , &crate::flat::SHOPPING_BAGS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shopping_bags" , &crate::flat::SHOPPING_BAGS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shopping_cart" , &crate::flat::SHOPPING_CART as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("shortcake" , &crate::flat::SHORTCAKE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shorts" , &crate::flat::SHORTS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("shower" , &crate::flat::SHOWER as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("shrimp" , &crate::flat::SHRIMP as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("shrug" , &crate::flat::PERSON_SHRUGGING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("shuffle_tracks_button" , &crate::flat::SHUFFLE_TRACKS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("shushing_face" , &crate::flat::SHUSHING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("sierra_leone" , &crate::flat::FLAG_SIERRA_LEONE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("sign_of_the_horns" , &crate::flat::SIGN_OF_THE_HORNS.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("signal_strength" , &crate::flat::ANTENNA_BARS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("singapore" , &crate::flat::FLAG_SINGAPORE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("singer" , &crate::flat::SINGER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("sint_maarten" , &crate::flat::FLAG_SINT_MAARTEN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("six" , &crate::flat::KEYCAP_6 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("six_o_clock" , &crate::flat::SIX_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("six_pointed_star" , &crate::flat::DOTTED_SIX_POINTED_STAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("six_thirty" , &crate::flat::SIX_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("skateboard" , &crate::flat::SKATEBOARD as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("ski" , &crate::flat::SKIS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("skier" , &crate::flat::SKIER as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("skis" , &crate::flat::SKIS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("skull" , &crate::flat::SKULL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("skull_and_crossbones" , &crate::flat::SKULL_AND_CROSSBONES as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("skunk" , &crate::flat::SKUNK as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("sled" , &crate::flat::SLED as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sleeping" , &crate::flat::SLEEPING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("sleeping_bed" , &crate::flat::PERSON_IN_BED.default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sleeping_face" , &crate::flat::SLEEPING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sleepy" , &crate::flat::SLEEPY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sleepy_face" , &crate::flat::SLEEPY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("slightly_frowning_face" , &crate::flat::SLIGHTLY_FROWNING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("slightly_smiling_face" , &crate::flat::SLIGHTLY_SMILING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("slot_machine" , &crate::flat::SLOT_MACHINE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("sloth" , &crate::flat::SLOTH as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("slovakia" , &crate::flat::FLAG_SLOVAKIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("slovenia" , &crate::flat::FLAG_SLOVENIA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("small_airplane" , &crate::flat::SMALL_AIRPLANE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("small_blue_diamond" , &crate::flat::SMALL_BLUE_DIAMOND as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("small_orange_diamond" , &crate::flat::SMALL_ORANGE_DIAMOND as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("small_red_triangle" , &crate::flat::RED_TRIANGLE_POINTED_UP as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("small_red_triangle_down" , &crate::flat::RED_TRIANGLE_POINTED_DOWN as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smile" , &crate::flat::GRINNING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smile_cat" , &crate::flat::GRINNING_CAT_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiley" , &crate::flat::GRINNING_FACE_WITH_BIG_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiley_cat" , &crate::flat::GRINNING_CAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_cat_with_heart_eyes" , &crate::flat::SMILING_CAT_WITH_HEART_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face" , &crate::flat::SMILING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_halo" , &crate::flat::SMILING_FACE_WITH_HALO as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_heart_eyes" , &crate::flat::SMILING_FACE_WITH_HEART_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_hearts" , &crate::flat::SMILING_FACE_WITH_HEARTS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_horns" , &crate::flat::SMILING_FACE_WITH_HORNS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_smiling_eyes" , &crate::flat::SMILING_FACE_WITH_SMILING_EYES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_sunglasses" , &crate::flat::SMILING_FACE_WITH_SUNGLASSES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_tear" , &crate::flat::SMILING_FACE_WITH_TEAR as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_face_with_three_hearts" , &crate::flat::SMILING_FACE_WITH_HEARTS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smiling_imp" , &crate::flat::SMILING_FACE_WITH_HORNS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smirk" , &crate::flat::SMIRKING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smirk_cat" , &crate::flat::CAT_WITH_WRY_SMILE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("smirking_face" , &crate::flat::SMIRKING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("smoking" , &crate::flat::CIGARETTE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("snail" , &crate::flat::SNAIL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("snake" , &crate::flat::SNAKE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sneezing_face" , &crate::flat::SNEEZING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("snow_capped_mountain" , &crate::flat::SNOW_CAPPED_MOUNTAIN as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("snowboarder" , &crate::flat::SNOWBOARDER.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("snowflake" , &crate::flat::SNOWFLAKE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("snowman" , &crate::flat::SNOWMAN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("snowman_with_snow" , &crate::flat::SNOWMAN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("snowman_without_snow" , &crate::flat::SNOWMAN_WITHOUT_SNOW as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("soap" , &crate::flat::SOAP as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sob" , &crate::flat::LOUDLY_CRYING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("soccer" , &crate::flat::SOCCER_BALL as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("soccer_ball" , &crate::flat::SOCCER_BALL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("socks" , &crate::flat::SOCKS as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("soft_ice_cream" , &crate::flat::SOFT_ICE_CREAM as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("softball" , &crate::flat::SOFTBALL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("solomon_islands" , &crate::flat::FLAG_SOLOMON_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("somalia" , &crate::flat::FLAG_SOMALIA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("soon" , &crate::flat::SOON_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("soon_arrow" , &crate::flat::SOON_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("sos" , &crate::flat::SOS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("sos_button" , &crate::flat::SOS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("sound" , &crate::flat::SPEAKER_MEDIUM_VOLUME as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("south_africa" , &crate::flat::FLAG_SOUTH_AFRICA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("south_georgia_south_sandwich_islands" , &crate::flat::FLAG_SOUTH_GEORGIA_AND_SOUTH_SANDWICH_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("south_sudan" , &crate::flat::FLAG_SOUTH_SUDAN as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("space_invader" , &crate::flat::ALIEN_MONSTER as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("spade_suit" , &crate::flat::SPADE_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("spades" , &crate::flat::SPADE_SUIT as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("spaghetti" , &crate::flat::SPAGHETTI as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("sparkle" , &crate::flat::SPARKLE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("sparkler" , &crate::flat::SPARKLER as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("sparkles" , &crate::flat::SPARKLES as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sparkling_heart" , &crate::flat::SPARKLING_HEART as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("speak_no_evil" , &crate::flat::SPEAK_NO_EVIL_MONKEY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("speak_no_evil_monkey" , &crate::flat::SPEAK_NO_EVIL_MONKEY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("speaker" , &crate::flat::SPEAKER_LOW_VOLUME as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("speaker_high_volume" , &crate::flat::SPEAKER_HIGH_VOLUME as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("speaker_low_volume" , &crate::flat::SPEAKER_LOW_VOLUME as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("speaker_medium_volume" , &crate::flat::SPEAKER_MEDIUM_VOLUME as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("speaking_head" , &crate::flat::SPEAKING_HEAD as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("speech_balloon" , &crate::flat::SPEECH_BALLOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("speedboat" , &crate::flat::SPEEDBOAT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("spider" , &crate::flat::SPIDER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("spider_web" , &crate::flat::SPIDER_WEB as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("spiral_calendar" , &crate::flat::SPIRAL_CALENDAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("spiral_notepad" , &crate::flat::SPIRAL_NOTEPAD as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("spiral_shell" , &crate::flat::SPIRAL_SHELL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("sponge" , &crate::flat::SPONGE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("spoon" , &crate::flat::SPOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sport_utility_vehicle" , &crate::flat::SPORT_UTILITY_VEHICLE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("sports_medal" , &crate::flat::SPORTS_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("spouting_whale" , &crate::flat::SPOUTING_WHALE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("squid" , &crate::flat::SQUID as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("squinting_face_with_tongue" , &crate::flat::SQUINTING_FACE_WITH_TONGUE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("sri_lanka" , &crate::flat::FLAG_SRI_LANKA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("st_barthelemy" , &crate::flat::FLAG_ST_BARTHELEMY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("st_helena" , &crate::flat::FLAG_ST_HELENA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("st_kitts_nevis" , &crate::flat::FLAG_ST_KITTS_AND_NEVIS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("st_lucia" , &crate::flat::FLAG_ST_LUCIA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("st_martin" , &crate::flat::FLAG_ST_MARTIN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("st_pierre_miquelon" , &crate::flat::FLAG_ST_PIERRE_AND_MIQUELON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("st_vincent_grenadines" , &crate::flat::FLAG_ST_VINCENT_AND_GRENADINES as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("stadium" , &crate::flat::STADIUM as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("standing_man" , &crate::flat::PERSON_STANDING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("standing_person" , &crate::flat::PERSON_STANDING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("standing_woman" , &crate::flat::PERSON_STANDING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("star" , &crate::flat::STAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("star2" , &crate::flat::GLOWING_STAR as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("star_and_crescent" , &crate::flat::STAR_AND_CRESCENT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("star_of_david" , &crate::flat::STAR_OF_DAVID as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("star_struck" , &crate::flat::STAR_STRUCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("stars" , &crate::flat::SHOOTING_STAR as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("station" , &crate::flat::STATION as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("statue_of_liberty" , &crate::flat::STATUE_OF_LIBERTY as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("steam_locomotive" , &crate::flat::LOCOMOTIVE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("steaming_bowl" , &crate::flat::STEAMING_BOWL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("stethoscope" , &crate::flat::STETHOSCOPE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("stew" , &crate::flat::POT_OF_FOOD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("stop_button" , &crate::flat::STOP_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("stop_sign" , &crate::flat::STOP_SIGN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("stopwatch" , &crate::flat::STOPWATCH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("straight_ruler" , &crate::flat::STRAIGHT_RULER as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("strawberry" , &crate::flat::STRAWBERRY as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("stuck_out_tongue" , &crate::flat::FACE_WITH_TONGUE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("stuck_out_tongue_closed_eyes" , &crate::flat::SQUINTING_FACE_WITH_TONGUE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("stuck_out_tongue_winking_eye" , &crate::flat::WINKING_FACE_WITH_TONGUE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("student" , &crate::flat::STUDENT.default.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("studio_microphone" , &crate::flat::STUDIO_MICROPHONE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("stuffed_flatbread" , &crate::flat::STUFFED_FLATBREAD as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("sudan" , &crate::flat::FLAG_SUDAN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sun" , &crate::flat::SUN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sun_behind_cloud" , &crate::flat::SUN_BEHIND_CLOUD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sun_behind_large_cloud" , &crate::flat::SUN_BEHIND_LARGE_CLOUD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sun_behind_rain_cloud" , &crate::flat::SUN_BEHIND_RAIN_CLOUD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sun_behind_small_cloud" , &crate::flat::SUN_BEHIND_SMALL_CLOUD as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sun_with_face" , &crate::flat::SUN_WITH_FACE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("sunflower" , &crate::flat::SUNFLOWER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("sunglasses" , &crate::flat::SUNGLASSES as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sunny" , &crate::flat::SUN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sunrise" , &crate::flat::SUNRISE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sunrise_over_mountains" , &crate::flat::SUNRISE_OVER_MOUNTAINS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("sunset" , &crate::flat::SUNSET as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("superhero" , &crate::flat::SUPERHERO.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("superhero_man" , &crate::flat::SUPERHERO.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("superhero_woman" , &crate::flat::SUPERHERO.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("supervillain" , &crate::flat::SUPERVILLAIN.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("supervillain_man" , &crate::flat::SUPERVILLAIN.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("supervillain_woman" , &crate::flat::SUPERVILLAIN.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("surfer" , &crate::flat::PERSON_SURFING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("surfing_man" , &crate::flat::PERSON_SURFING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("surfing_woman" , &crate::flat::PERSON_SURFING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("suriname" , &crate::flat::FLAG_SURINAME as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("sushi" , &crate::flat::SUSHI as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("suspension_railway" , &crate::flat::SUSPENSION_RAILWAY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("svalbard_jan_mayen" , &crate::flat::FLAG_SVALBARD_AND_JAN_MAYEN as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("swan" , &crate::flat::SWAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("swaziland" , &crate::flat::FLAG_ESWATINI as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sweat" , &crate::flat::DOWNCAST_FACE_WITH_SWEAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sweat_droplets" , &crate::flat::SWEAT_DROPLETS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sweat_drops" , &crate::flat::SWEAT_DROPLETS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("sweat_smile" , &crate::flat::GRINNING_FACE_WITH_SWEAT as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("sweden" , &crate::flat::FLAG_SWEDEN as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("sweet_potato" , &crate::flat::ROASTED_SWEET_POTATO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("swim_brief" , &crate::flat::BRIEFS as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("swimmer" , &crate::flat::PERSON_SWIMMING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("swimming_man" , &crate::flat::PERSON_SWIMMING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("swimming_woman" , &crate::flat::PERSON_SWIMMING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("switzerland" , &crate::flat::FLAG_SWITZERLAND as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("symbols" , &crate::flat::INPUT_SYMBOLS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("synagogue" , &crate::flat::SYNAGOGUE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("syria" , &crate::flat::FLAG_SYRIA as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("syringe" , &crate::flat::SYRINGE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("t-rex" , &crate::flat::T_REX as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("t_rex" , &crate::flat::T_REX as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("t_shirt" , &crate::flat::T_SHIRT as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("taco" , &crate::flat::TACO as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("tada" , &crate::flat::PARTY_POPPER as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("taiwan" , &crate::flat::FLAG_TAIWAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tajikistan" , &crate::flat::FLAG_TAJIKISTAN as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("takeout_box" , &crate::flat::TAKEOUT_BOX as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("tamale" , &crate::flat::TAMALE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("tanabata_tree" , &crate::flat::TANABATA_TREE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("tangerine" , &crate::flat::TANGERINE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tanzania" , &crate::flat::FLAG_TANZANIA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("taurus" , &crate::flat::TAURUS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("taxi" , &crate::flat::TAXI as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("tea" , &crate::flat::TEACUP_WITHOUT_HANDLE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("teacher" , &crate::flat::TEACHER.default.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("teacup_without_handle" , &crate::flat::TEACUP_WITHOUT_HANDLE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("teapot" , &crate::flat::TEAPOT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("tear_off_calendar" , &crate::flat::TEAR_OFF_CALENDAR as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("technologist" , &crate::flat::TECHNOLOGIST.default.default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("teddy_bear" , &crate::flat::TEDDY_BEAR as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("telephone" , &crate::flat::TELEPHONE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("telephone_receiver" , &crate::flat::TELEPHONE_RECEIVER as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("telescope" , &crate::flat::TELESCOPE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("television" , &crate::flat::TELEVISION as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ten_o_clock" , &crate::flat::TEN_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("ten_thirty" , &crate::flat::TEN_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("tennis" , &crate::flat::TENNIS as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("tent" , &crate::flat::TENT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("test_tube" , &crate::flat::TEST_TUBE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("thailand" , &crate::flat::FLAG_THAILAND as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("thermometer" , &crate::flat::THERMOMETER as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("thinking" , &crate::flat::THINKING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("thinking_face" , &crate::flat::THINKING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("third_place_medal" , &crate::flat::THIRD_PLACE_MEDAL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("thong_sandal" , &crate::flat::THONG_SANDAL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("thought_balloon" , &crate::flat::THOUGHT_BALLOON as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("thread" , &crate::flat::THREAD as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("three" , &crate::flat::KEYCAP_3 as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("three_o_clock" , &crate::flat::THREE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("three_thirty" , &crate::flat::THREE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("thumbs_down" , &crate::flat::THUMBS_DOWN.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("thumbs_up" , &crate::flat::THUMBS_UP.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("thumbsdown" , &crate::flat::THUMBS_DOWN.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("thumbsup" , &crate::flat::THUMBS_UP.default as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("ticket" , &crate::flat::TICKET as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("tickets" , &crate::flat::ADMISSION_TICKETS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("tiger" , &crate::flat::TIGER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("tiger2" , &crate::flat::TIGER as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("tiger_face" , &crate::flat::TIGER_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("timer_clock" , &crate::flat::TIMER_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("timor_leste" , &crate::flat::FLAG_TIMOR_LESTE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("tipping_hand_man" , &crate::flat::PERSON_TIPPING_HAND.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("tipping_hand_person" , &crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("tipping_hand_woman" , &crate::flat::PERSON_TIPPING_HAND.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("tired_face" , &crate::flat::TIRED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("tm" , &crate::flat::TRADE_MARK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("togo" , &crate::flat::FLAG_TOGO as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("toilet" , &crate::flat::TOILET as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tokelau" , &crate::flat::FLAG_TOKELAU as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("tokyo_tower" , &crate::flat::TOKYO_TOWER as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("tomato" , &crate::flat::TOMATO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tonga" , &crate::flat::FLAG_TONGA as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("tongue" , &crate::flat::TONGUE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("toolbox" , &crate::flat::TOOLBOX as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("tooth" , &crate::flat::TOOTH as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("toothbrush" , &crate::flat::TOOTHBRUSH as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("top" , &crate::flat::TOP_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("top_arrow" , &crate::flat::TOP_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("top_hat" , &crate::flat::TOP_HAT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("tophat" , &crate::flat::TOP_HAT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("tornado" , &crate::flat::TORNADO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tr" , &crate::flat::FLAG_TURKEY as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("trackball" , &crate::flat::TRACKBALL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("tractor" , &crate::flat::TRACTOR as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("trade_mark" , &crate::flat::TRADE_MARK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("traffic_light" , &crate::flat::HORIZONTAL_TRAFFIC_LIGHT as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("train" , &crate::flat::TRAIN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("train2" , &crate::flat::TRAIN as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("tram" , &crate::flat::TRAM as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("tram_car" , &crate::flat::TRAM_CAR as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("transgender_flag" , &crate::flat::TRANSGENDER_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("transgender_symbol" , &crate::flat::TRANSGENDER_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("triangular_flag" , &crate::flat::TRIANGULAR_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("triangular_flag_on_post" , &crate::flat::TRIANGULAR_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("triangular_ruler" , &crate::flat::TRIANGULAR_RULER as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("trident" , &crate::flat::TRIDENT_EMBLEM as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("trident_emblem" , &crate::flat::TRIDENT_EMBLEM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("trinidad_tobago" , &crate::flat::FLAG_TRINIDAD_AND_TOBAGO as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tristan_da_cunha" , &crate::flat::FLAG_TRISTAN_DA_CUNHA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("triumph" , &crate::flat::FACE_WITH_STEAM_FROM_NOSE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("troll" , &crate::flat::TROLL as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("trolleybus" , &crate::flat::TROLLEYBUS as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("trophy" , &crate::flat::TROPHY as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("tropical_drink" , &crate::flat::TROPICAL_DRINK as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("tropical_fish" , &crate::flat::TROPICAL_FISH as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("truck" , &crate::flat::DELIVERY_TRUCK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("trumpet" , &crate::flat::TRUMPET as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("tshirt" , &crate::flat::T_SHIRT as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("tulip" , &crate::flat::TULIP as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("tumbler_glass" , &crate::flat::TUMBLER_GLASS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tunisia" , &crate::flat::FLAG_TUNISIA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("turkey" , &crate::flat::TURKEY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("turkmenistan" , &crate::flat::FLAG_TURKMENISTAN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("turks_caicos_islands" , &crate::flat::FLAG_TURKS_AND_CAICOS_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("turtle" , &crate::flat::TURTLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("tuvalu" , &crate::flat::FLAG_TUVALU as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("tv" , &crate::flat::TELEVISION as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("twelve_o_clock" , &crate::flat::TWELVE_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("twelve_thirty" , &crate::flat::TWELVE_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("twisted_rightwards_arrows" , &crate::flat::SHUFFLE_TRACKS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("two" , &crate::flat::KEYCAP_2 as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("two_hearts" , &crate::flat::TWO_HEARTS as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("two_hump_camel" , &crate::flat::TWO_HUMP_CAMEL as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("two_men_holding_hands" , &crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Males).default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("two_o_clock" , &crate::flat::TWO_O_CLOCK as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("two_thirty" , &crate::flat::TWO_THIRTY as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("two_women_holding_hands" , &crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Females).default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u5272" , &crate::flat::JAPANESE_DISCOUNT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u5408" , &crate::flat::JAPANESE_PASSING_GRADE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u55b6" , &crate::flat::JAPANESE_OPEN_FOR_BUSINESS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u6307" , &crate::flat::JAPANESE_RESERVED_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u6708" , &crate::flat::JAPANESE_MONTHLY_AMOUNT_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u6709" , &crate::flat::JAPANESE_NOT_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u6e80" , &crate::flat::JAPANESE_NO_VACANCY_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u7121" , &crate::flat::JAPANESE_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u7533" , &crate::flat::JAPANESE_APPLICATION_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u7981" , &crate::flat::JAPANESE_PROHIBITED_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("u7a7a" , &crate::flat::JAPANESE_VACANCY_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("uganda" , &crate::flat::FLAG_UGANDA as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("uk" , &crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("ukraine" , &crate::flat::FLAG_UKRAINE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("umbrella" , &crate::flat::UMBRELLA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("umbrella_on_ground" , &crate::flat::UMBRELLA_ON_GROUND as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("umbrella_with_rain_drops" , &crate::flat::UMBRELLA_WITH_RAIN_DROPS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("unamused" , &crate::flat::UNAMUSED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("unamused_face" , &crate::flat::UNAMUSED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("underage" , &crate::flat::NO_ONE_UNDER_EIGHTEEN as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("unicorn" , &crate::flat::UNICORN as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("united_arab_emirates" , &crate::flat::FLAG_UNITED_ARAB_EMIRATES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("united_nations" , &crate::flat::FLAG_UNITED_NATIONS as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("unlock" , &crate::flat::UNLOCKED as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("unlocked" , &crate::flat::UNLOCKED as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("up" , &crate::flat::UP_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("up_arrow" , &crate::flat::UP_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("up_button" , &crate::flat::UP_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("up_down_arrow" , &crate::flat::UP_DOWN_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("up_left_arrow" , &crate::flat::UP_LEFT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("up_right_arrow" , &crate::flat::UP_RIGHT_ARROW as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("upside_down_face" , &crate::flat::UPSIDE_DOWN_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("upwards_button" , &crate::flat::UPWARDS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("uruguay" , &crate::flat::FLAG_URUGUAY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("us" , &crate::flat::FLAG_UNITED_STATES as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("us_outlying_islands" , &crate::flat::FLAG_US_OUTLYING_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("us_virgin_islands" , &crate::flat::FLAG_US_VIRGIN_ISLANDS as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("uzbekistan" , &crate::flat::FLAG_UZBEKISTAN as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("v" , &crate::flat::VICTORY_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("vampire" , &crate::flat::VAMPIRE.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("vampire_man" , &crate::flat::VAMPIRE.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("vampire_woman" , &crate::flat::VAMPIRE.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("vanuatu" , &crate::flat::FLAG_VANUATU as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("vatican_city" , &crate::flat::FLAG_VATICAN_CITY as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("venezuela" , &crate::flat::FLAG_VENEZUELA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("vertical_traffic_light" , &crate::flat::VERTICAL_TRAFFIC_LIGHT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("vhs" , &crate::flat::VIDEOCASSETTE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("vibration_mode" , &crate::flat::VIBRATION_MODE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("victory_hand" , &crate::flat::VICTORY_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("video_camera" , &crate::flat::VIDEO_CAMERA as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("video_game" , &crate::flat::VIDEO_GAME as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("videocassette" , &crate::flat::VIDEOCASSETTE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("vietnam" , &crate::flat::FLAG_VIETNAM as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("violin" , &crate::flat::VIOLIN as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("virgo" , &crate::flat::VIRGO as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("volcano" , &crate::flat::VOLCANO as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("volleyball" , &crate::flat::VOLLEYBALL as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("vomiting_face" , &crate::flat::FACE_VOMITING as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("vs" , &crate::flat::VS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("vs_button" , &crate::flat::VS_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("vulcan_salute" , &crate::flat::VULCAN_SALUTE.default as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("waffle" , &crate::flat::WAFFLE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("wales" , &crate::flat::FLAG_WALES as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("walking" , &crate::flat::PERSON_WALKING.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("walking_man" , &crate::flat::PERSON_WALKING.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("walking_woman" , &crate::flat::PERSON_WALKING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("wallis_futuna" , &crate::flat::FLAG_WALLIS_AND_FUTUNA as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("waning_crescent_moon" , &crate::flat::WANING_CRESCENT_MOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("waning_gibbous_moon" , &crate::flat::WANING_GIBBOUS_MOON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("warning" , &crate::flat::WARNING as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("wastebasket" , &crate::flat::WASTEBASKET as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("watch" , &crate::flat::WATCH as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("water_buffalo" , &crate::flat::WATER_BUFFALO as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("water_closet" , &crate::flat::WATER_CLOSET as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("water_pistol" , &crate::flat::WATER_PISTOL as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("water_polo" , &crate::flat::PERSON_PLAYING_WATER_POLO.default.default as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("water_wave" , &crate::flat::WATER_WAVE as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("watermelon" , &crate::flat::WATERMELON as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("wave" , &crate::flat::WAVING_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("waving_hand" , &crate::flat::WAVING_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("wavy_dash" , &crate::flat::WAVY_DASH as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("waxing_crescent_moon" , &crate::flat::WAXING_CRESCENT_MOON as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("waxing_gibbous_moon" , &crate::flat::WAXING_GIBBOUS_MOON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("wc" , &crate::flat::WATER_CLOSET as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("weary" , &crate::flat::WEARY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("weary_cat" , &crate::flat::WEARY_CAT as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("weary_face" , &crate::flat::WEARY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("wedding" , &crate::flat::WEDDING as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("weight_lifting" , &crate::flat::PERSON_LIFTING_WEIGHTS.default.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("weight_lifting_man" , &crate::flat::PERSON_LIFTING_WEIGHTS.gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("weight_lifting_woman" , &crate::flat::PERSON_LIFTING_WEIGHTS.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("western_sahara" , &crate::flat::FLAG_WESTERN_SAHARA as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("whale" , &crate::flat::WHALE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("whale2" , &crate::flat::WHALE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("wheel" , &crate::flat::WHEEL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("wheel_of_dharma" , &crate::flat::WHEEL_OF_DHARMA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("wheelchair" , &crate::flat::WHEELCHAIR_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("wheelchair_symbol" , &crate::flat::WHEELCHAIR_SYMBOL as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("white_cane" , &crate::flat::WHITE_CANE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_check_mark" , &crate::flat::CHECK_MARK_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_circle" , &crate::flat::WHITE_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_exclamation_mark" , &crate::flat::WHITE_EXCLAMATION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("white_flag" , &crate::flat::WHITE_FLAG as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("white_flower" , &crate::flat::WHITE_FLOWER as &crate::Emoji),
+#[cfg(emojic_group = "component")] ("white_hair" , &crate::flat::WHITE_HAIR as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("white_haired_man" , &crate::flat::PERSON.hair(Hair::White).gender(Gender::Male).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("white_haired_woman" , &crate::flat::PERSON.hair(Hair::White).gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("white_heart" , &crate::flat::WHITE_HEART as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_large_square" , &crate::flat::WHITE_LARGE_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_medium_small_square" , &crate::flat::WHITE_MEDIUM_SMALL_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_medium_square" , &crate::flat::WHITE_MEDIUM_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_question_mark" , &crate::flat::WHITE_QUESTION_MARK as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_small_square" , &crate::flat::WHITE_SMALL_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("white_square_button" , &crate::flat::WHITE_SQUARE_BUTTON as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("wilted_flower" , &crate::flat::WILTED_FLOWER as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("wind_chime" , &crate::flat::WIND_CHIME as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("wind_face" , &crate::flat::WIND_FACE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("window" , &crate::flat::WINDOW as &crate::Emoji),
+#[cfg(emojic_group = "food_and_drink")] ("wine_glass" , &crate::flat::WINE_GLASS as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("wink" , &crate::flat::WINKING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("winking_face" , &crate::flat::WINKING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("winking_face_with_tongue" , &crate::flat::WINKING_FACE_WITH_TONGUE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("wolf" , &crate::flat::WOLF as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman" , &crate::flat::PERSON.default.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_artist" , &crate::flat::ARTIST.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_astronaut" , &crate::flat::ASTRONAUT.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_beard" , &crate::flat::PERSON.hair(Hair::Beard).gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_cartwheeling" , &crate::flat::PERSON_CARTWHEELING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_cook" , &crate::flat::COOK.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_dancing" , &crate::flat::PERSON_DANCING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_facepalming" , &crate::flat::PERSON_FACEPALMING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_factory_worker" , &crate::flat::FACTORY_WORKER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_farmer" , &crate::flat::FARMER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_feeding_baby" , &crate::flat::PERSON_FEEDING_BABY.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_firefighter" , &crate::flat::FIREFIGHTER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_health_worker" , &crate::flat::HEALTH_WORKER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_in_manual_wheelchair" , &crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_in_motorized_wheelchair" , &crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_in_tuxedo" , &crate::flat::PERSON_IN_TUXEDO.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_judge" , &crate::flat::JUDGE.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_juggling" , &crate::flat::PERSON_JUGGLING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_mechanic" , &crate::flat::MECHANIC.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_office_worker" , &crate::flat::OFFICE_WORKER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_pilot" , &crate::flat::PILOT.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_playing_handball" , &crate::flat::PERSON_PLAYING_HANDBALL.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_playing_water_polo" , &crate::flat::PERSON_PLAYING_WATER_POLO.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("woman_s_boot" , &crate::flat::WOMAN_S_BOOT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("woman_s_clothes" , &crate::flat::WOMAN_S_CLOTHES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("woman_s_hat" , &crate::flat::WOMAN_S_HAT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("woman_s_sandal" , &crate::flat::WOMAN_S_SANDAL as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_scientist" , &crate::flat::SCIENTIST.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_shrugging" , &crate::flat::PERSON_SHRUGGING.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_singer" , &crate::flat::SINGER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_student" , &crate::flat::STUDENT.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_teacher" , &crate::flat::TEACHER.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_technologist" , &crate::flat::TECHNOLOGIST.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_with_headscarf" , &crate::flat::WOMAN_WITH_HEADSCARF.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_with_probing_cane" , &crate::flat::PERSON_WITH_WHITE_CANE.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_with_turban" , &crate::flat::PERSON_WEARING_TURBAN.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("woman_with_veil" , &crate::flat::PERSON_WITH_VEIL.gender(Gender::Female).default as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("womans_clothes" , &crate::flat::WOMAN_S_CLOTHES as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("womans_hat" , &crate::flat::WOMAN_S_HAT as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("women_s_room" , &crate::flat::WOMEN_S_ROOM as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("women_wrestling" , &crate::flat::PERSON_WRESTLING.gender(Gender::Female) as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("womens" , &crate::flat::WOMEN_S_ROOM as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("wood" , &crate::flat::WOOD as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("woozy_face" , &crate::flat::WOOZY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("world_map" , &crate::flat::WORLD_MAP as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("worm" , &crate::flat::WORM as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("worried" , &crate::flat::WORRIED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("worried_face" , &crate::flat::WORRIED_FACE as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("wrapped_gift" , &crate::flat::WRAPPED_GIFT as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("wrench" , &crate::flat::WRENCH as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("wrestling" , &crate::flat::PERSON_WRESTLING.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("writing_hand" , &crate::flat::WRITING_HAND.default as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("x" , &crate::flat::CROSS_MARK as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("x_ray" , &crate::flat::X_RAY as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("yarn" , &crate::flat::YARN as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("yawning_face" , &crate::flat::YAWNING_FACE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("yellow_circle" , &crate::flat::YELLOW_CIRCLE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("yellow_heart" , &crate::flat::YELLOW_HEART as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("yellow_square" , &crate::flat::YELLOW_SQUARE as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("yemen" , &crate::flat::FLAG_YEMEN as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("yen" , &crate::flat::YEN_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "objects")] ("yen_banknote" , &crate::flat::YEN_BANKNOTE as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("yin_yang" , &crate::flat::YIN_YANG as &crate::Emoji),
+#[cfg(emojic_group = "activities")] ("yo_yo" , &crate::flat::YO_YO as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("yum" , &crate::flat::FACE_SAVORING_FOOD as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("zambia" , &crate::flat::FLAG_ZAMBIA as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("zany_face" , &crate::flat::ZANY_FACE as &crate::Emoji),
+#[cfg(emojic_group = "travel_and_places")] ("zap" , &crate::flat::HIGH_VOLTAGE as &crate::Emoji),
+#[cfg(emojic_group = "animals_and_nature")] ("zebra" , &crate::flat::ZEBRA as &crate::Emoji),
+#[cfg(emojic_group = "symbols")] ("zero" , &crate::flat::KEYCAP_0 as &crate::Emoji),
+#[cfg(emojic_group = "flags")] ("zimbabwe" , &crate::flat::FLAG_ZIMBABWE as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("zipper_mouth_face" , &crate::flat::ZIPPER_MOUTH_FACE as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("zombie" , &crate::flat::ZOMBIE.default as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("zombie_man" , &crate::flat::ZOMBIE.gender(Gender::Male) as &crate::Emoji),
+#[cfg(emojic_group = "people_and_body")] ("zombie_woman" , &crate::flat::ZOMBIE.gender(Gender::Female) as &crate::Emoji),
+#[cfg(emojic_group = "smileys_and_emotion")] ("zzz" , &crate::flat::ZZZ as &crate::Emoji),
 
-			_ => None
-		}
-	} else {
-		let first = bytes[0];
-		let sec = bytes[1];
-		let rest = &s[2..];
+];
 
-		match first {
-			b'+' => match sec {
-				b'1' => match rest {
-					"" => Some(&crate::flat::THUMBS_UP.default as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'-' => match sec {
-				b'1' => match rest {
-					"" => Some(&crate::flat::THUMBS_DOWN.default as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'1' => match sec {
-				b'0' => match rest {
-					"0" => Some(&crate::flat::HUNDRED_POINTS as &crate::Emoji),
-					_ => None,
-				},
-				b'2' => match rest {
-					"34" => Some(&crate::flat::INPUT_NUMBERS as &crate::Emoji),
-					_ => None,
-				},
-				b's' => match rest {
-					"t_place_medal" => Some(&crate::flat::FIRST_PLACE_MEDAL as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'2' => match sec {
-				b'n' => match rest {
-					"d_place_medal" => Some(&crate::flat::SECOND_PLACE_MEDAL as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'3' => match sec {
-				b'r' => match rest {
-					"d_place_medal" => Some(&crate::flat::THIRD_PLACE_MEDAL as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'8' => match sec {
-				b'b' => match rest {
-					"all" => Some(&crate::flat::POOL_8_BALL as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'a' => match sec {
-				b'_' => match rest {
-					"button_blood_type" => Some(&crate::flat::A_BUTTON_BLOOD_TYPE as &crate::Emoji),
-					_ => None,
-				},
-				b'b' => match rest {
-					"" => Some(&crate::flat::AB_BUTTON_BLOOD_TYPE as &crate::Emoji),
-					"_button_blood_type" => Some(&crate::flat::AB_BUTTON_BLOOD_TYPE as &crate::Emoji),
-					"acus" => Some(&crate::flat::ABACUS as &crate::Emoji),
-					"c" => Some(&crate::flat::INPUT_LATIN_LETTERS as &crate::Emoji),
-					"cd" => Some(&crate::flat::INPUT_LATIN_LOWERCASE as &crate::Emoji),
-					_ => None,
-				},
-				b'c' => match rest {
-					"cept" => Some(&crate::flat::JAPANESE_ACCEPTABLE_BUTTON as &crate::Emoji),
-					"cordion" => Some(&crate::flat::ACCORDION as &crate::Emoji),
-					_ => None,
-				},
-				b'd' => match rest {
-					"hesive_bandage" => Some(&crate::flat::ADHESIVE_BANDAGE as &crate::Emoji),
-					"mission_tickets" => Some(&crate::flat::ADMISSION_TICKETS as &crate::Emoji),
-					"ult" => Some(&crate::flat::PERSON.default.default.default as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"rial_tramway" => Some(&crate::flat::AERIAL_TRAMWAY as &crate::Emoji),
-					_ => None,
-				},
-				b'f' => match rest {
-					"ghanistan" => Some(&crate::flat::FLAG_AFGHANISTAN as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"rplane" => Some(&crate::flat::AIRPLANE as &crate::Emoji),
-					"rplane_arrival" => Some(&crate::flat::AIRPLANE_ARRIVAL as &crate::Emoji),
-					"rplane_departure" => Some(&crate::flat::AIRPLANE_DEPARTURE as &crate::Emoji),
-					_ => None,
-				},
-				b'l' => match rest {
-					"and_islands" => Some(&crate::flat::FLAG_ALAND_ISLANDS as &crate::Emoji),
-					"arm_clock" => Some(&crate::flat::ALARM_CLOCK as &crate::Emoji),
-					"bania" => Some(&crate::flat::FLAG_ALBANIA as &crate::Emoji),
-					"embic" => Some(&crate::flat::ALEMBIC as &crate::Emoji),
-					"geria" => Some(&crate::flat::FLAG_ALGERIA as &crate::Emoji),
-					"ien" => Some(&crate::flat::ALIEN as &crate::Emoji),
-					"ien_monster" => Some(&crate::flat::ALIEN_MONSTER as &crate::Emoji),
-					_ => None,
-				},
-				b'm' => match rest {
-					"bulance" => Some(&crate::flat::AMBULANCE as &crate::Emoji),
-					"erican_football" => Some(&crate::flat::AMERICAN_FOOTBALL as &crate::Emoji),
-					"erican_samoa" => Some(&crate::flat::FLAG_AMERICAN_SAMOA as &crate::Emoji),
-					"phora" => Some(&crate::flat::AMPHORA as &crate::Emoji),
-					_ => None,
-				},
-				b'n' => match rest {
-					"atomical_heart" => Some(&crate::flat::ANATOMICAL_HEART as &crate::Emoji),
-					"chor" => Some(&crate::flat::ANCHOR as &crate::Emoji),
-					"dorra" => Some(&crate::flat::FLAG_ANDORRA as &crate::Emoji),
-					"gel" => Some(&crate::flat::BABY_ANGEL.default as &crate::Emoji),
-					"ger" => Some(&crate::flat::ANGER_SYMBOL as &crate::Emoji),
-					"ger_symbol" => Some(&crate::flat::ANGER_SYMBOL as &crate::Emoji),
-					"gola" => Some(&crate::flat::FLAG_ANGOLA as &crate::Emoji),
-					"gry" => Some(&crate::flat::ANGRY_FACE as &crate::Emoji),
-					"gry_face" => Some(&crate::flat::ANGRY_FACE as &crate::Emoji),
-					"gry_face_with_horns" => Some(&crate::flat::ANGRY_FACE_WITH_HORNS as &crate::Emoji),
-					"guilla" => Some(&crate::flat::FLAG_ANGUILLA as &crate::Emoji),
-					"guished" => Some(&crate::flat::ANGUISHED_FACE as &crate::Emoji),
-					"guished_face" => Some(&crate::flat::ANGUISHED_FACE as &crate::Emoji),
-					"t" => Some(&crate::flat::ANT as &crate::Emoji),
-					"tarctica" => Some(&crate::flat::FLAG_ANTARCTICA as &crate::Emoji),
-					"tenna_bars" => Some(&crate::flat::ANTENNA_BARS as &crate::Emoji),
-					"tigua_barbuda" => Some(&crate::flat::FLAG_ANTIGUA_AND_BARBUDA as &crate::Emoji),
-					"xious_face_with_sweat" => Some(&crate::flat::ANXIOUS_FACE_WITH_SWEAT as &crate::Emoji),
-					_ => None,
-				},
-				b'p' => match rest {
-					"ple" => Some(&crate::flat::RED_APPLE as &crate::Emoji),
-					_ => None,
-				},
-				b'q' => match rest {
-					"uarius" => Some(&crate::flat::AQUARIUS as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"gentina" => Some(&crate::flat::FLAG_ARGENTINA as &crate::Emoji),
-					"ies" => Some(&crate::flat::ARIES as &crate::Emoji),
-					"menia" => Some(&crate::flat::FLAG_ARMENIA as &crate::Emoji),
-					"row_backward" => Some(&crate::flat::REVERSE_BUTTON as &crate::Emoji),
-					"row_double_down" => Some(&crate::flat::FAST_DOWN_BUTTON as &crate::Emoji),
-					"row_double_up" => Some(&crate::flat::FAST_UP_BUTTON as &crate::Emoji),
-					"row_down" => Some(&crate::flat::DOWN_ARROW as &crate::Emoji),
-					"row_down_small" => Some(&crate::flat::DOWNWARDS_BUTTON as &crate::Emoji),
-					"row_forward" => Some(&crate::flat::PLAY_BUTTON as &crate::Emoji),
-					"row_heading_down" => Some(&crate::flat::RIGHT_ARROW_CURVING_DOWN as &crate::Emoji),
-					"row_heading_up" => Some(&crate::flat::RIGHT_ARROW_CURVING_UP as &crate::Emoji),
-					"row_left" => Some(&crate::flat::LEFT_ARROW as &crate::Emoji),
-					"row_lower_left" => Some(&crate::flat::DOWN_LEFT_ARROW as &crate::Emoji),
-					"row_lower_right" => Some(&crate::flat::DOWN_RIGHT_ARROW as &crate::Emoji),
-					"row_right" => Some(&crate::flat::RIGHT_ARROW as &crate::Emoji),
-					"row_right_hook" => Some(&crate::flat::LEFT_ARROW_CURVING_RIGHT as &crate::Emoji),
-					"row_up" => Some(&crate::flat::UP_ARROW as &crate::Emoji),
-					"row_up_down" => Some(&crate::flat::UP_DOWN_ARROW as &crate::Emoji),
-					"row_up_small" => Some(&crate::flat::UPWARDS_BUTTON as &crate::Emoji),
-					"row_upper_left" => Some(&crate::flat::UP_LEFT_ARROW as &crate::Emoji),
-					"row_upper_right" => Some(&crate::flat::UP_RIGHT_ARROW as &crate::Emoji),
-					"rows_clockwise" => Some(&crate::flat::CLOCKWISE_VERTICAL_ARROWS as &crate::Emoji),
-					"rows_counterclockwise" => Some(&crate::flat::COUNTERCLOCKWISE_ARROWS_BUTTON as &crate::Emoji),
-					"t" => Some(&crate::flat::ARTIST_PALETTE as &crate::Emoji),
-					"ticulated_lorry" => Some(&crate::flat::ARTICULATED_LORRY as &crate::Emoji),
-					"tificial_satellite" => Some(&crate::flat::SATELLITE as &crate::Emoji),
-					"tist" => Some(&crate::flat::ARTIST.default.default as &crate::Emoji),
-					"tist_palette" => Some(&crate::flat::ARTIST_PALETTE as &crate::Emoji),
-					"uba" => Some(&crate::flat::FLAG_ARUBA as &crate::Emoji),
-					_ => None,
-				},
-				b's' => match rest {
-					"cension_island" => Some(&crate::flat::FLAG_ASCENSION_ISLAND as &crate::Emoji),
-					"terisk" => Some(&crate::flat::KEYCAP_ASTERISK as &crate::Emoji),
-					"tonished" => Some(&crate::flat::ASTONISHED_FACE as &crate::Emoji),
-					"tonished_face" => Some(&crate::flat::ASTONISHED_FACE as &crate::Emoji),
-					"tronaut" => Some(&crate::flat::ASTRONAUT.default.default as &crate::Emoji),
-					_ => None,
-				},
-				b't' => match rest {
-					"hletic_shoe" => Some(&crate::flat::RUNNING_SHOE as &crate::Emoji),
-					"m" => Some(&crate::flat::ATM_SIGN as &crate::Emoji),
-					"m_sign" => Some(&crate::flat::ATM_SIGN as &crate::Emoji),
-					"om_symbol" => Some(&crate::flat::ATOM_SYMBOL as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"stralia" => Some(&crate::flat::FLAG_AUSTRALIA as &crate::Emoji),
-					"stria" => Some(&crate::flat::FLAG_AUSTRIA as &crate::Emoji),
-					"to_rickshaw" => Some(&crate::flat::AUTO_RICKSHAW as &crate::Emoji),
-					"tomobile" => Some(&crate::flat::AUTOMOBILE as &crate::Emoji),
-					_ => None,
-				},
-				b'v' => match rest {
-					"ocado" => Some(&crate::flat::AVOCADO as &crate::Emoji),
-					_ => None,
-				},
-				b'x' => match rest {
-					"e" => Some(&crate::flat::AXE as &crate::Emoji),
-					_ => None,
-				},
-				b'z' => match rest {
-					"erbaijan" => Some(&crate::flat::FLAG_AZERBAIJAN as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'b' => match sec {
-				b'_' => match rest {
-					"button_blood_type" => Some(&crate::flat::B_BUTTON_BLOOD_TYPE as &crate::Emoji),
-					_ => None,
-				},
-				b'a' => match rest {
-					"by" => Some(&crate::flat::BABY.default as &crate::Emoji),
-					"by_angel" => Some(&crate::flat::BABY_ANGEL.default as &crate::Emoji),
-					"by_bottle" => Some(&crate::flat::BABY_BOTTLE as &crate::Emoji),
-					"by_chick" => Some(&crate::flat::BABY_CHICK as &crate::Emoji),
-					"by_symbol" => Some(&crate::flat::BABY_SYMBOL as &crate::Emoji),
-					"ck" => Some(&crate::flat::BACK_ARROW as &crate::Emoji),
-					"ck_arrow" => Some(&crate::flat::BACK_ARROW as &crate::Emoji),
-					"ckhand_index_pointing_down" => Some(&crate::flat::BACKHAND_INDEX_POINTING_DOWN.default as &crate::Emoji),
-					"ckhand_index_pointing_left" => Some(&crate::flat::BACKHAND_INDEX_POINTING_LEFT.default as &crate::Emoji),
-					"ckhand_index_pointing_right" => Some(&crate::flat::BACKHAND_INDEX_POINTING_RIGHT.default as &crate::Emoji),
-					"ckhand_index_pointing_up" => Some(&crate::flat::BACKHAND_INDEX_POINTING_UP.default as &crate::Emoji),
-					"ckpack" => Some(&crate::flat::BACKPACK as &crate::Emoji),
-					"con" => Some(&crate::flat::BACON as &crate::Emoji),
-					"dger" => Some(&crate::flat::BADGER as &crate::Emoji),
-					"dminton" => Some(&crate::flat::BADMINTON as &crate::Emoji),
-					"gel" => Some(&crate::flat::BAGEL as &crate::Emoji),
-					"ggage_claim" => Some(&crate::flat::BAGGAGE_CLAIM as &crate::Emoji),
-					"guette_bread" => Some(&crate::flat::BAGUETTE_BREAD as &crate::Emoji),
-					"hamas" => Some(&crate::flat::FLAG_BAHAMAS as &crate::Emoji),
-					"hrain" => Some(&crate::flat::FLAG_BAHRAIN as &crate::Emoji),
-					"lance_scale" => Some(&crate::flat::BALANCE_SCALE as &crate::Emoji),
-					"ld" => Some(&crate::flat::BALD as &crate::Emoji),
-					"ld_man" => Some(&crate::flat::PERSON.hair(Hair::Bald).gender(Gender::Male).default as &crate::Emoji),
-					"ld_woman" => Some(&crate::flat::PERSON.hair(Hair::Bald).gender(Gender::Female).default as &crate::Emoji),
-					"llet_shoes" => Some(&crate::flat::BALLET_SHOES as &crate::Emoji),
-					"lloon" => Some(&crate::flat::BALLOON as &crate::Emoji),
-					"llot_box" => Some(&crate::flat::BALLOT_BOX_WITH_BALLOT as &crate::Emoji),
-					"llot_box_with_ballot" => Some(&crate::flat::BALLOT_BOX_WITH_BALLOT as &crate::Emoji),
-					"llot_box_with_check" => Some(&crate::flat::CHECK_BOX_WITH_CHECK as &crate::Emoji),
-					"mboo" => Some(&crate::flat::PINE_DECORATION as &crate::Emoji),
-					"nana" => Some(&crate::flat::BANANA as &crate::Emoji),
-					"ngbang" => Some(&crate::flat::DOUBLE_EXCLAMATION_MARK as &crate::Emoji),
-					"ngladesh" => Some(&crate::flat::FLAG_BANGLADESH as &crate::Emoji),
-					"njo" => Some(&crate::flat::BANJO as &crate::Emoji),
-					"nk" => Some(&crate::flat::BANK as &crate::Emoji),
-					"r_chart" => Some(&crate::flat::BAR_CHART as &crate::Emoji),
-					"rbados" => Some(&crate::flat::FLAG_BARBADOS as &crate::Emoji),
-					"rber" => Some(&crate::flat::BARBER_POLE as &crate::Emoji),
-					"rber_pole" => Some(&crate::flat::BARBER_POLE as &crate::Emoji),
-					"seball" => Some(&crate::flat::BASEBALL as &crate::Emoji),
-					"sket" => Some(&crate::flat::BASKET as &crate::Emoji),
-					"sketball" => Some(&crate::flat::BASKETBALL as &crate::Emoji),
-					"sketball_man" => Some(&crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Male).default as &crate::Emoji),
-					"sketball_woman" => Some(&crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Female).default as &crate::Emoji),
-					"t" => Some(&crate::flat::BAT as &crate::Emoji),
-					"th" => Some(&crate::flat::PERSON_TAKING_BATH.default as &crate::Emoji),
-					"thtub" => Some(&crate::flat::BATHTUB as &crate::Emoji),
-					"ttery" => Some(&crate::flat::BATTERY as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"ach_umbrella" => Some(&crate::flat::BEACH_WITH_UMBRELLA as &crate::Emoji),
-					"ach_with_umbrella" => Some(&crate::flat::BEACH_WITH_UMBRELLA as &crate::Emoji),
-					"aming_face_with_smiling_eyes" => Some(&crate::flat::BEAMING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-					"ar" => Some(&crate::flat::BEAR as &crate::Emoji),
-					"arded_person" => Some(&crate::flat::PERSON.hair(Hair::Beard).default.default as &crate::Emoji),
-					"ating_heart" => Some(&crate::flat::BEATING_HEART as &crate::Emoji),
-					"aver" => Some(&crate::flat::BEAVER as &crate::Emoji),
-					"d" => Some(&crate::flat::BED as &crate::Emoji),
-					"e" => Some(&crate::flat::HONEYBEE as &crate::Emoji),
-					"er" => Some(&crate::flat::BEER_MUG as &crate::Emoji),
-					"er_mug" => Some(&crate::flat::BEER_MUG as &crate::Emoji),
-					"ers" => Some(&crate::flat::CLINKING_BEER_MUGS as &crate::Emoji),
-					"etle" => Some(&crate::flat::BEETLE as &crate::Emoji),
-					"ginner" => Some(&crate::flat::JAPANESE_SYMBOL_FOR_BEGINNER as &crate::Emoji),
-					"larus" => Some(&crate::flat::FLAG_BELARUS as &crate::Emoji),
-					"lgium" => Some(&crate::flat::FLAG_BELGIUM as &crate::Emoji),
-					"lize" => Some(&crate::flat::FLAG_BELIZE as &crate::Emoji),
-					"ll" => Some(&crate::flat::BELL as &crate::Emoji),
-					"ll_pepper" => Some(&crate::flat::BELL_PEPPER as &crate::Emoji),
-					"ll_with_slash" => Some(&crate::flat::BELL_WITH_SLASH as &crate::Emoji),
-					"llhop_bell" => Some(&crate::flat::BELLHOP_BELL as &crate::Emoji),
-					"nin" => Some(&crate::flat::FLAG_BENIN as &crate::Emoji),
-					"nto" => Some(&crate::flat::BENTO_BOX as &crate::Emoji),
-					"nto_box" => Some(&crate::flat::BENTO_BOX as &crate::Emoji),
-					"rmuda" => Some(&crate::flat::FLAG_BERMUDA as &crate::Emoji),
-					"verage_box" => Some(&crate::flat::BEVERAGE_BOX as &crate::Emoji),
-					_ => None,
-				},
-				b'h' => match rest {
-					"utan" => Some(&crate::flat::FLAG_BHUTAN as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"cycle" => Some(&crate::flat::BICYCLE as &crate::Emoji),
-					"cyclist" => Some(&crate::flat::PERSON_BIKING.default.default as &crate::Emoji),
-					"ke" => Some(&crate::flat::BICYCLE as &crate::Emoji),
-					"king_man" => Some(&crate::flat::PERSON_BIKING.gender(Gender::Male).default as &crate::Emoji),
-					"king_woman" => Some(&crate::flat::PERSON_BIKING.gender(Gender::Female).default as &crate::Emoji),
-					"kini" => Some(&crate::flat::BIKINI as &crate::Emoji),
-					"lled_cap" => Some(&crate::flat::BILLED_CAP as &crate::Emoji),
-					"ohazard" => Some(&crate::flat::BIOHAZARD as &crate::Emoji),
-					"rd" => Some(&crate::flat::BIRD as &crate::Emoji),
-					"rthday" => Some(&crate::flat::BIRTHDAY_CAKE as &crate::Emoji),
-					"rthday_cake" => Some(&crate::flat::BIRTHDAY_CAKE as &crate::Emoji),
-					"son" => Some(&crate::flat::BISON as &crate::Emoji),
-					_ => None,
-				},
-				b'l' => match rest {
-					"ack_cat" => Some(&crate::flat::BLACK_CAT as &crate::Emoji),
-					"ack_circle" => Some(&crate::flat::BLACK_CIRCLE as &crate::Emoji),
-					"ack_flag" => Some(&crate::flat::BLACK_FLAG as &crate::Emoji),
-					"ack_heart" => Some(&crate::flat::BLACK_HEART as &crate::Emoji),
-					"ack_joker" => Some(&crate::flat::JOKER as &crate::Emoji),
-					"ack_large_square" => Some(&crate::flat::BLACK_LARGE_SQUARE as &crate::Emoji),
-					"ack_medium_small_square" => Some(&crate::flat::BLACK_MEDIUM_SMALL_SQUARE as &crate::Emoji),
-					"ack_medium_square" => Some(&crate::flat::BLACK_MEDIUM_SQUARE as &crate::Emoji),
-					"ack_nib" => Some(&crate::flat::BLACK_NIB as &crate::Emoji),
-					"ack_small_square" => Some(&crate::flat::BLACK_SMALL_SQUARE as &crate::Emoji),
-					"ack_square_button" => Some(&crate::flat::BLACK_SQUARE_BUTTON as &crate::Emoji),
-					"ond_haired_man" => Some(&crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Male).default as &crate::Emoji),
-					"ond_haired_person" => Some(&crate::flat::PERSON.hair(Hair::Blond).default.default as &crate::Emoji),
-					"ond_haired_woman" => Some(&crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Female).default as &crate::Emoji),
-					"onde_woman" => Some(&crate::flat::PERSON.hair(Hair::Blond).gender(Gender::Female).default as &crate::Emoji),
-					"ossom" => Some(&crate::flat::BLOSSOM as &crate::Emoji),
-					"owfish" => Some(&crate::flat::BLOWFISH as &crate::Emoji),
-					"ue_book" => Some(&crate::flat::BLUE_BOOK as &crate::Emoji),
-					"ue_car" => Some(&crate::flat::SPORT_UTILITY_VEHICLE as &crate::Emoji),
-					"ue_circle" => Some(&crate::flat::BLUE_CIRCLE as &crate::Emoji),
-					"ue_heart" => Some(&crate::flat::BLUE_HEART as &crate::Emoji),
-					"ue_square" => Some(&crate::flat::BLUE_SQUARE as &crate::Emoji),
-					"ueberries" => Some(&crate::flat::BLUEBERRIES as &crate::Emoji),
-					"ush" => Some(&crate::flat::SMILING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"ar" => Some(&crate::flat::BOAR as &crate::Emoji),
-					"at" => Some(&crate::flat::SAILBOAT as &crate::Emoji),
-					"livia" => Some(&crate::flat::FLAG_BOLIVIA as &crate::Emoji),
-					"mb" => Some(&crate::flat::BOMB as &crate::Emoji),
-					"ne" => Some(&crate::flat::BONE as &crate::Emoji),
-					"ok" => Some(&crate::flat::OPEN_BOOK as &crate::Emoji),
-					"okmark" => Some(&crate::flat::BOOKMARK as &crate::Emoji),
-					"okmark_tabs" => Some(&crate::flat::BOOKMARK_TABS as &crate::Emoji),
-					"oks" => Some(&crate::flat::BOOKS as &crate::Emoji),
-					"om" => Some(&crate::flat::COLLISION as &crate::Emoji),
-					"omerang" => Some(&crate::flat::BOOMERANG as &crate::Emoji),
-					"ot" => Some(&crate::flat::WOMAN_S_BOOT as &crate::Emoji),
-					"snia_herzegovina" => Some(&crate::flat::FLAG_BOSNIA_AND_HERZEGOVINA as &crate::Emoji),
-					"tswana" => Some(&crate::flat::FLAG_BOTSWANA as &crate::Emoji),
-					"ttle_with_popping_cork" => Some(&crate::flat::BOTTLE_WITH_POPPING_CORK as &crate::Emoji),
-					"uncing_ball_man" => Some(&crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Male).default as &crate::Emoji),
-					"uncing_ball_person" => Some(&crate::flat::PERSON_BOUNCING_BALL.default.default as &crate::Emoji),
-					"uncing_ball_woman" => Some(&crate::flat::PERSON_BOUNCING_BALL.gender(Gender::Female).default as &crate::Emoji),
-					"uquet" => Some(&crate::flat::BOUQUET as &crate::Emoji),
-					"uvet_island" => Some(&crate::flat::FLAG_BOUVET_ISLAND as &crate::Emoji),
-					"w" => Some(&crate::flat::PERSON_BOWING.default.default as &crate::Emoji),
-					"w_and_arrow" => Some(&crate::flat::BOW_AND_ARROW as &crate::Emoji),
-					"wing_man" => Some(&crate::flat::PERSON_BOWING.gender(Gender::Male).default as &crate::Emoji),
-					"wing_woman" => Some(&crate::flat::PERSON_BOWING.gender(Gender::Female).default as &crate::Emoji),
-					"wl_with_spoon" => Some(&crate::flat::BOWL_WITH_SPOON as &crate::Emoji),
-					"wling" => Some(&crate::flat::BOWLING as &crate::Emoji),
-					"xing_glove" => Some(&crate::flat::BOXING_GLOVE as &crate::Emoji),
-					"y" => Some(&crate::flat::BOY.default as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"ain" => Some(&crate::flat::BRAIN as &crate::Emoji),
-					"azil" => Some(&crate::flat::FLAG_BRAZIL as &crate::Emoji),
-					"ead" => Some(&crate::flat::BREAD as &crate::Emoji),
-					"east_feeding" => Some(&crate::flat::BREAST_FEEDING.default as &crate::Emoji),
-					"ick" => Some(&crate::flat::BRICK as &crate::Emoji),
-					"icks" => Some(&crate::flat::BRICK as &crate::Emoji),
-					"ide_with_veil" => Some(&crate::flat::PERSON_WITH_VEIL.gender(Gender::Female).default as &crate::Emoji),
-					"idge_at_night" => Some(&crate::flat::BRIDGE_AT_NIGHT as &crate::Emoji),
-					"iefcase" => Some(&crate::flat::BRIEFCASE as &crate::Emoji),
-					"iefs" => Some(&crate::flat::BRIEFS as &crate::Emoji),
-					"ight_button" => Some(&crate::flat::BRIGHT_BUTTON as &crate::Emoji),
-					"itish_indian_ocean_territory" => Some(&crate::flat::FLAG_BRITISH_INDIAN_OCEAN_TERRITORY as &crate::Emoji),
-					"itish_virgin_islands" => Some(&crate::flat::FLAG_BRITISH_VIRGIN_ISLANDS as &crate::Emoji),
-					"occoli" => Some(&crate::flat::BROCCOLI as &crate::Emoji),
-					"oken_heart" => Some(&crate::flat::BROKEN_HEART as &crate::Emoji),
-					"oom" => Some(&crate::flat::BROOM as &crate::Emoji),
-					"own_circle" => Some(&crate::flat::BROWN_CIRCLE as &crate::Emoji),
-					"own_heart" => Some(&crate::flat::BROWN_HEART as &crate::Emoji),
-					"own_square" => Some(&crate::flat::BROWN_SQUARE as &crate::Emoji),
-					"unei" => Some(&crate::flat::FLAG_BRUNEI as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"bble_tea" => Some(&crate::flat::BUBBLE_TEA as &crate::Emoji),
-					"cket" => Some(&crate::flat::BUCKET as &crate::Emoji),
-					"g" => Some(&crate::flat::BUG as &crate::Emoji),
-					"ilding_construction" => Some(&crate::flat::BUILDING_CONSTRUCTION as &crate::Emoji),
-					"lb" => Some(&crate::flat::LIGHT_BULB as &crate::Emoji),
-					"lgaria" => Some(&crate::flat::FLAG_BULGARIA as &crate::Emoji),
-					"llet_train" => Some(&crate::flat::BULLET_TRAIN as &crate::Emoji),
-					"llettrain_front" => Some(&crate::flat::BULLET_TRAIN as &crate::Emoji),
-					"llettrain_side" => Some(&crate::flat::HIGH_SPEED_TRAIN as &crate::Emoji),
-					"llseye" => Some(&crate::flat::BULLSEYE as &crate::Emoji),
-					"rkina_faso" => Some(&crate::flat::FLAG_BURKINA_FASO as &crate::Emoji),
-					"rrito" => Some(&crate::flat::BURRITO as &crate::Emoji),
-					"rundi" => Some(&crate::flat::FLAG_BURUNDI as &crate::Emoji),
-					"s" => Some(&crate::flat::BUS as &crate::Emoji),
-					"s_stop" => Some(&crate::flat::BUS_STOP as &crate::Emoji),
-					"siness_suit_levitating" => Some(&crate::flat::PERSON_IN_SUIT_LEVITATING.default as &crate::Emoji),
-					"sstop" => Some(&crate::flat::BUS_STOP as &crate::Emoji),
-					"st_in_silhouette" => Some(&crate::flat::BUST_IN_SILHOUETTE as &crate::Emoji),
-					"sts_in_silhouette" => Some(&crate::flat::BUSTS_IN_SILHOUETTE as &crate::Emoji),
-					"tter" => Some(&crate::flat::BUTTER as &crate::Emoji),
-					"tterfly" => Some(&crate::flat::BUTTERFLY as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'c' => match sec {
-				b'a' => match rest {
-					"ctus" => Some(&crate::flat::CACTUS as &crate::Emoji),
-					"ke" => Some(&crate::flat::SHORTCAKE as &crate::Emoji),
-					"lendar" => Some(&crate::flat::CALENDAR as &crate::Emoji),
-					"ll_me_hand" => Some(&crate::flat::CALL_ME_HAND.default as &crate::Emoji),
-					"lling" => Some(&crate::flat::MOBILE_PHONE_WITH_ARROW as &crate::Emoji),
-					"mbodia" => Some(&crate::flat::FLAG_CAMBODIA as &crate::Emoji),
-					"mel" => Some(&crate::flat::CAMEL as &crate::Emoji),
-					"mera" => Some(&crate::flat::CAMERA as &crate::Emoji),
-					"mera_flash" => Some(&crate::flat::CAMERA_WITH_FLASH as &crate::Emoji),
-					"mera_with_flash" => Some(&crate::flat::CAMERA_WITH_FLASH as &crate::Emoji),
-					"meroon" => Some(&crate::flat::FLAG_CAMEROON as &crate::Emoji),
-					"mping" => Some(&crate::flat::CAMPING as &crate::Emoji),
-					"nada" => Some(&crate::flat::FLAG_CANADA as &crate::Emoji),
-					"nary_islands" => Some(&crate::flat::FLAG_CANARY_ISLANDS as &crate::Emoji),
-					"ncer" => Some(&crate::flat::CANCER as &crate::Emoji),
-					"ndle" => Some(&crate::flat::CANDLE as &crate::Emoji),
-					"ndy" => Some(&crate::flat::CANDY as &crate::Emoji),
-					"nned_food" => Some(&crate::flat::CANNED_FOOD as &crate::Emoji),
-					"noe" => Some(&crate::flat::CANOE as &crate::Emoji),
-					"pe_verde" => Some(&crate::flat::FLAG_CAPE_VERDE as &crate::Emoji),
-					"pital_abcd" => Some(&crate::flat::INPUT_LATIN_UPPERCASE as &crate::Emoji),
-					"pricorn" => Some(&crate::flat::CAPRICORN as &crate::Emoji),
-					"r" => Some(&crate::flat::AUTOMOBILE as &crate::Emoji),
-					"rd_file_box" => Some(&crate::flat::CARD_FILE_BOX as &crate::Emoji),
-					"rd_index" => Some(&crate::flat::CARD_INDEX as &crate::Emoji),
-					"rd_index_dividers" => Some(&crate::flat::CARD_INDEX_DIVIDERS as &crate::Emoji),
-					"ribbean_netherlands" => Some(&crate::flat::FLAG_CARIBBEAN_NETHERLANDS as &crate::Emoji),
-					"rousel_horse" => Some(&crate::flat::CAROUSEL_HORSE as &crate::Emoji),
-					"rp_streamer" => Some(&crate::flat::CARP_STREAMER as &crate::Emoji),
-					"rpentry_saw" => Some(&crate::flat::CARPENTRY_SAW as &crate::Emoji),
-					"rrot" => Some(&crate::flat::CARROT as &crate::Emoji),
-					"rtwheeling" => Some(&crate::flat::PERSON_CARTWHEELING.default.default as &crate::Emoji),
-					"stle" => Some(&crate::flat::CASTLE as &crate::Emoji),
-					"t" => Some(&crate::flat::CAT as &crate::Emoji),
-					"t2" => Some(&crate::flat::CAT as &crate::Emoji),
-					"t_face" => Some(&crate::flat::CAT_FACE as &crate::Emoji),
-					"t_with_tears_of_joy" => Some(&crate::flat::CAT_WITH_TEARS_OF_JOY as &crate::Emoji),
-					"t_with_wry_smile" => Some(&crate::flat::CAT_WITH_WRY_SMILE as &crate::Emoji),
-					"yman_islands" => Some(&crate::flat::FLAG_CAYMAN_ISLANDS as &crate::Emoji),
-					_ => None,
-				},
-				b'd' => match rest {
-					"" => Some(&crate::flat::OPTICAL_DISK as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"ntral_african_republic" => Some(&crate::flat::FLAG_CENTRAL_AFRICAN_REPUBLIC as &crate::Emoji),
-					"uta_melilla" => Some(&crate::flat::FLAG_CEUTA_AND_MELILLA as &crate::Emoji),
-					_ => None,
-				},
-				b'h' => match rest {
-					"ad" => Some(&crate::flat::FLAG_CHAD as &crate::Emoji),
-					"ains" => Some(&crate::flat::CHAINS as &crate::Emoji),
-					"air" => Some(&crate::flat::CHAIR as &crate::Emoji),
-					"ampagne" => Some(&crate::flat::BOTTLE_WITH_POPPING_CORK as &crate::Emoji),
-					"art" => Some(&crate::flat::CHART_INCREASING_WITH_YEN as &crate::Emoji),
-					"art_decreasing" => Some(&crate::flat::CHART_DECREASING as &crate::Emoji),
-					"art_increasing" => Some(&crate::flat::CHART_INCREASING as &crate::Emoji),
-					"art_increasing_with_yen" => Some(&crate::flat::CHART_INCREASING_WITH_YEN as &crate::Emoji),
-					"art_with_downwards_trend" => Some(&crate::flat::CHART_DECREASING as &crate::Emoji),
-					"art_with_upwards_trend" => Some(&crate::flat::CHART_INCREASING as &crate::Emoji),
-					"eck_box_with_check" => Some(&crate::flat::CHECK_BOX_WITH_CHECK as &crate::Emoji),
-					"eck_mark" => Some(&crate::flat::CHECK_MARK as &crate::Emoji),
-					"eck_mark_button" => Some(&crate::flat::CHECK_MARK_BUTTON as &crate::Emoji),
-					"eckered_flag" => Some(&crate::flat::CHEQUERED_FLAG as &crate::Emoji),
-					"eese" => Some(&crate::flat::CHEESE_WEDGE as &crate::Emoji),
-					"eese_wedge" => Some(&crate::flat::CHEESE_WEDGE as &crate::Emoji),
-					"equered_flag" => Some(&crate::flat::CHEQUERED_FLAG as &crate::Emoji),
-					"erries" => Some(&crate::flat::CHERRIES as &crate::Emoji),
-					"erry_blossom" => Some(&crate::flat::CHERRY_BLOSSOM as &crate::Emoji),
-					"ess_pawn" => Some(&crate::flat::CHESS_PAWN as &crate::Emoji),
-					"estnut" => Some(&crate::flat::CHESTNUT as &crate::Emoji),
-					"icken" => Some(&crate::flat::CHICKEN as &crate::Emoji),
-					"ild" => Some(&crate::flat::CHILD.default as &crate::Emoji),
-					"ildren_crossing" => Some(&crate::flat::CHILDREN_CROSSING as &crate::Emoji),
-					"ile" => Some(&crate::flat::FLAG_CHILE as &crate::Emoji),
-					"ipmunk" => Some(&crate::flat::CHIPMUNK as &crate::Emoji),
-					"ocolate_bar" => Some(&crate::flat::CHOCOLATE_BAR as &crate::Emoji),
-					"opsticks" => Some(&crate::flat::CHOPSTICKS as &crate::Emoji),
-					"ristmas_island" => Some(&crate::flat::FLAG_CHRISTMAS_ISLAND as &crate::Emoji),
-					"ristmas_tree" => Some(&crate::flat::CHRISTMAS_TREE as &crate::Emoji),
-					"urch" => Some(&crate::flat::CHURCH as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"garette" => Some(&crate::flat::CIGARETTE as &crate::Emoji),
-					"nema" => Some(&crate::flat::CINEMA as &crate::Emoji),
-					"rcled_m" => Some(&crate::flat::CIRCLED_M as &crate::Emoji),
-					"rcus_tent" => Some(&crate::flat::CIRCUS_TENT as &crate::Emoji),
-					"ty_sunrise" => Some(&crate::flat::SUNSET as &crate::Emoji),
-					"ty_sunset" => Some(&crate::flat::CITYSCAPE_AT_DUSK as &crate::Emoji),
-					"tyscape" => Some(&crate::flat::CITYSCAPE as &crate::Emoji),
-					"tyscape_at_dusk" => Some(&crate::flat::CITYSCAPE_AT_DUSK as &crate::Emoji),
-					_ => None,
-				},
-				b'l' => match rest {
-					"" => Some(&crate::flat::CL_BUTTON as &crate::Emoji),
-					"_button" => Some(&crate::flat::CL_BUTTON as &crate::Emoji),
-					"amp" => Some(&crate::flat::CLAMP as &crate::Emoji),
-					"ap" => Some(&crate::flat::CLAPPING_HANDS.default as &crate::Emoji),
-					"apper" => Some(&crate::flat::CLAPPER_BOARD as &crate::Emoji),
-					"apper_board" => Some(&crate::flat::CLAPPER_BOARD as &crate::Emoji),
-					"apping_hands" => Some(&crate::flat::CLAPPING_HANDS.default as &crate::Emoji),
-					"assical_building" => Some(&crate::flat::CLASSICAL_BUILDING as &crate::Emoji),
-					"imbing" => Some(&crate::flat::PERSON_CLIMBING.default.default as &crate::Emoji),
-					"imbing_man" => Some(&crate::flat::PERSON_CLIMBING.gender(Gender::Male).default as &crate::Emoji),
-					"imbing_woman" => Some(&crate::flat::PERSON_CLIMBING.gender(Gender::Female).default as &crate::Emoji),
-					"inking_beer_mugs" => Some(&crate::flat::CLINKING_BEER_MUGS as &crate::Emoji),
-					"inking_glasses" => Some(&crate::flat::CLINKING_GLASSES as &crate::Emoji),
-					"ipboard" => Some(&crate::flat::CLIPBOARD as &crate::Emoji),
-					"ipperton_island" => Some(&crate::flat::FLAG_CLIPPERTON_ISLAND as &crate::Emoji),
-					"ock1" => Some(&crate::flat::ONE_O_CLOCK as &crate::Emoji),
-					"ock10" => Some(&crate::flat::TEN_O_CLOCK as &crate::Emoji),
-					"ock1030" => Some(&crate::flat::TEN_THIRTY as &crate::Emoji),
-					"ock11" => Some(&crate::flat::ELEVEN_O_CLOCK as &crate::Emoji),
-					"ock1130" => Some(&crate::flat::ELEVEN_THIRTY as &crate::Emoji),
-					"ock12" => Some(&crate::flat::TWELVE_O_CLOCK as &crate::Emoji),
-					"ock1230" => Some(&crate::flat::TWELVE_THIRTY as &crate::Emoji),
-					"ock130" => Some(&crate::flat::ONE_THIRTY as &crate::Emoji),
-					"ock2" => Some(&crate::flat::TWO_O_CLOCK as &crate::Emoji),
-					"ock230" => Some(&crate::flat::TWO_THIRTY as &crate::Emoji),
-					"ock3" => Some(&crate::flat::THREE_O_CLOCK as &crate::Emoji),
-					"ock330" => Some(&crate::flat::THREE_THIRTY as &crate::Emoji),
-					"ock4" => Some(&crate::flat::FOUR_O_CLOCK as &crate::Emoji),
-					"ock430" => Some(&crate::flat::FOUR_THIRTY as &crate::Emoji),
-					"ock5" => Some(&crate::flat::FIVE_O_CLOCK as &crate::Emoji),
-					"ock530" => Some(&crate::flat::FIVE_THIRTY as &crate::Emoji),
-					"ock6" => Some(&crate::flat::SIX_O_CLOCK as &crate::Emoji),
-					"ock630" => Some(&crate::flat::SIX_THIRTY as &crate::Emoji),
-					"ock7" => Some(&crate::flat::SEVEN_O_CLOCK as &crate::Emoji),
-					"ock730" => Some(&crate::flat::SEVEN_THIRTY as &crate::Emoji),
-					"ock8" => Some(&crate::flat::EIGHT_O_CLOCK as &crate::Emoji),
-					"ock830" => Some(&crate::flat::EIGHT_THIRTY as &crate::Emoji),
-					"ock9" => Some(&crate::flat::NINE_O_CLOCK as &crate::Emoji),
-					"ock930" => Some(&crate::flat::NINE_THIRTY as &crate::Emoji),
-					"ockwise_vertical_arrows" => Some(&crate::flat::CLOCKWISE_VERTICAL_ARROWS as &crate::Emoji),
-					"osed_book" => Some(&crate::flat::CLOSED_BOOK as &crate::Emoji),
-					"osed_lock_with_key" => Some(&crate::flat::LOCKED_WITH_KEY as &crate::Emoji),
-					"osed_mailbox_with_lowered_flag" => Some(&crate::flat::CLOSED_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
-					"osed_mailbox_with_raised_flag" => Some(&crate::flat::CLOSED_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
-					"osed_umbrella" => Some(&crate::flat::CLOSED_UMBRELLA as &crate::Emoji),
-					"oud" => Some(&crate::flat::CLOUD as &crate::Emoji),
-					"oud_with_lightning" => Some(&crate::flat::CLOUD_WITH_LIGHTNING as &crate::Emoji),
-					"oud_with_lightning_and_rain" => Some(&crate::flat::CLOUD_WITH_LIGHTNING_AND_RAIN as &crate::Emoji),
-					"oud_with_rain" => Some(&crate::flat::CLOUD_WITH_RAIN as &crate::Emoji),
-					"oud_with_snow" => Some(&crate::flat::CLOUD_WITH_SNOW as &crate::Emoji),
-					"own_face" => Some(&crate::flat::CLOWN_FACE as &crate::Emoji),
-					"ub_suit" => Some(&crate::flat::CLUB_SUIT as &crate::Emoji),
-					"ubs" => Some(&crate::flat::CLUB_SUIT as &crate::Emoji),
-					"utch_bag" => Some(&crate::flat::CLUTCH_BAG as &crate::Emoji),
-					_ => None,
-				},
-				b'n' => match rest {
-					"" => Some(&crate::flat::FLAG_CHINA as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"at" => Some(&crate::flat::COAT as &crate::Emoji),
-					"ckroach" => Some(&crate::flat::COCKROACH as &crate::Emoji),
-					"cktail" => Some(&crate::flat::COCKTAIL_GLASS as &crate::Emoji),
-					"cktail_glass" => Some(&crate::flat::COCKTAIL_GLASS as &crate::Emoji),
-					"conut" => Some(&crate::flat::COCONUT as &crate::Emoji),
-					"cos_islands" => Some(&crate::flat::FLAG_COCOS_KEELING_ISLANDS as &crate::Emoji),
-					"ffee" => Some(&crate::flat::HOT_BEVERAGE as &crate::Emoji),
-					"ffin" => Some(&crate::flat::COFFIN as &crate::Emoji),
-					"in" => Some(&crate::flat::COIN as &crate::Emoji),
-					"ld_face" => Some(&crate::flat::COLD_FACE as &crate::Emoji),
-					"ld_sweat" => Some(&crate::flat::ANXIOUS_FACE_WITH_SWEAT as &crate::Emoji),
-					"llision" => Some(&crate::flat::COLLISION as &crate::Emoji),
-					"lombia" => Some(&crate::flat::FLAG_COLOMBIA as &crate::Emoji),
-					"met" => Some(&crate::flat::COMET as &crate::Emoji),
-					"moros" => Some(&crate::flat::FLAG_COMOROS as &crate::Emoji),
-					"mpass" => Some(&crate::flat::COMPASS as &crate::Emoji),
-					"mputer" => Some(&crate::flat::LAPTOP as &crate::Emoji),
-					"mputer_disk" => Some(&crate::flat::COMPUTER_DISK as &crate::Emoji),
-					"mputer_mouse" => Some(&crate::flat::COMPUTER_MOUSE as &crate::Emoji),
-					"nfetti_ball" => Some(&crate::flat::CONFETTI_BALL as &crate::Emoji),
-					"nfounded" => Some(&crate::flat::CONFOUNDED_FACE as &crate::Emoji),
-					"nfounded_face" => Some(&crate::flat::CONFOUNDED_FACE as &crate::Emoji),
-					"nfused" => Some(&crate::flat::CONFUSED_FACE as &crate::Emoji),
-					"nfused_face" => Some(&crate::flat::CONFUSED_FACE as &crate::Emoji),
-					"ngo_brazzaville" => Some(&crate::flat::FLAG_CONGO_BRAZZAVILLE as &crate::Emoji),
-					"ngo_kinshasa" => Some(&crate::flat::FLAG_CONGO_KINSHASA as &crate::Emoji),
-					"ngratulations" => Some(&crate::flat::JAPANESE_CONGRATULATIONS_BUTTON as &crate::Emoji),
-					"nstruction" => Some(&crate::flat::CONSTRUCTION as &crate::Emoji),
-					"nstruction_worker" => Some(&crate::flat::CONSTRUCTION_WORKER.default.default as &crate::Emoji),
-					"nstruction_worker_man" => Some(&crate::flat::CONSTRUCTION_WORKER.gender(Gender::Male).default as &crate::Emoji),
-					"nstruction_worker_woman" => Some(&crate::flat::CONSTRUCTION_WORKER.gender(Gender::Female).default as &crate::Emoji),
-					"ntrol_knobs" => Some(&crate::flat::CONTROL_KNOBS as &crate::Emoji),
-					"nvenience_store" => Some(&crate::flat::CONVENIENCE_STORE as &crate::Emoji),
-					"ok" => Some(&crate::flat::COOK.default.default as &crate::Emoji),
-					"ok_islands" => Some(&crate::flat::FLAG_COOK_ISLANDS as &crate::Emoji),
-					"oked_rice" => Some(&crate::flat::COOKED_RICE as &crate::Emoji),
-					"okie" => Some(&crate::flat::COOKIE as &crate::Emoji),
-					"oking" => Some(&crate::flat::COOKING as &crate::Emoji),
-					"ol" => Some(&crate::flat::COOL_BUTTON as &crate::Emoji),
-					"ol_button" => Some(&crate::flat::COOL_BUTTON as &crate::Emoji),
-					"p" => Some(&crate::flat::POLICE_OFFICER.default.default as &crate::Emoji),
-					"pyright" => Some(&crate::flat::COPYRIGHT as &crate::Emoji),
-					"rn" => Some(&crate::flat::EAR_OF_CORN as &crate::Emoji),
-					"sta_rica" => Some(&crate::flat::FLAG_COSTA_RICA as &crate::Emoji),
-					"te_divoire" => Some(&crate::flat::FLAG_COTE_D_IVOIRE as &crate::Emoji),
-					"uch_and_lamp" => Some(&crate::flat::COUCH_AND_LAMP as &crate::Emoji),
-					"unterclockwise_arrows_button" => Some(&crate::flat::COUNTERCLOCKWISE_ARROWS_BUTTON as &crate::Emoji),
-					"uple" => Some(&crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Mixed).default as &crate::Emoji),
-					"uple_with_heart" => Some(&crate::flat::COUPLE_WITH_HEART.default.default as &crate::Emoji),
-					"uple_with_heart_man_man" => Some(&crate::flat::COUPLE_WITH_HEART.pair(Pair::Males).default as &crate::Emoji),
-					"uple_with_heart_woman_man" => Some(&crate::flat::COUPLE_WITH_HEART.pair(Pair::Mixed).default as &crate::Emoji),
-					"uple_with_heart_woman_woman" => Some(&crate::flat::COUPLE_WITH_HEART.pair(Pair::Females).default as &crate::Emoji),
-					"uplekiss" => Some(&crate::flat::KISS.default.default as &crate::Emoji),
-					"uplekiss_man_man" => Some(&crate::flat::KISS.pair(Pair::Males).default as &crate::Emoji),
-					"uplekiss_man_woman" => Some(&crate::flat::KISS.pair(Pair::Mixed).default as &crate::Emoji),
-					"uplekiss_woman_woman" => Some(&crate::flat::KISS.pair(Pair::Females).default as &crate::Emoji),
-					"w" => Some(&crate::flat::COW as &crate::Emoji),
-					"w2" => Some(&crate::flat::COW as &crate::Emoji),
-					"w_face" => Some(&crate::flat::COW_FACE as &crate::Emoji),
-					"wboy_hat_face" => Some(&crate::flat::COWBOY_HAT_FACE as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"ab" => Some(&crate::flat::CRAB as &crate::Emoji),
-					"ayon" => Some(&crate::flat::CRAYON as &crate::Emoji),
-					"edit_card" => Some(&crate::flat::CREDIT_CARD as &crate::Emoji),
-					"escent_moon" => Some(&crate::flat::CRESCENT_MOON as &crate::Emoji),
-					"icket" => Some(&crate::flat::CRICKET as &crate::Emoji),
-					"icket_game" => Some(&crate::flat::CRICKET_GAME as &crate::Emoji),
-					"oatia" => Some(&crate::flat::FLAG_CROATIA as &crate::Emoji),
-					"ocodile" => Some(&crate::flat::CROCODILE as &crate::Emoji),
-					"oissant" => Some(&crate::flat::CROISSANT as &crate::Emoji),
-					"oss_mark" => Some(&crate::flat::CROSS_MARK as &crate::Emoji),
-					"oss_mark_button" => Some(&crate::flat::CROSS_MARK_BUTTON as &crate::Emoji),
-					"ossed_fingers" => Some(&crate::flat::CROSSED_FINGERS.default as &crate::Emoji),
-					"ossed_flags" => Some(&crate::flat::CROSSED_FLAGS as &crate::Emoji),
-					"ossed_swords" => Some(&crate::flat::CROSSED_SWORDS as &crate::Emoji),
-					"own" => Some(&crate::flat::CROWN as &crate::Emoji),
-					"y" => Some(&crate::flat::CRYING_FACE as &crate::Emoji),
-					"ying_cat" => Some(&crate::flat::CRYING_CAT as &crate::Emoji),
-					"ying_cat_face" => Some(&crate::flat::CRYING_CAT as &crate::Emoji),
-					"ying_face" => Some(&crate::flat::CRYING_FACE as &crate::Emoji),
-					"ystal_ball" => Some(&crate::flat::CRYSTAL_BALL as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"ba" => Some(&crate::flat::FLAG_CUBA as &crate::Emoji),
-					"cumber" => Some(&crate::flat::CUCUMBER as &crate::Emoji),
-					"p_with_straw" => Some(&crate::flat::CUP_WITH_STRAW as &crate::Emoji),
-					"pcake" => Some(&crate::flat::CUPCAKE as &crate::Emoji),
-					"pid" => Some(&crate::flat::HEART_WITH_ARROW as &crate::Emoji),
-					"racao" => Some(&crate::flat::FLAG_CURACAO as &crate::Emoji),
-					"rling_stone" => Some(&crate::flat::CURLING_STONE as &crate::Emoji),
-					"rly_hair" => Some(&crate::flat::CURLY_HAIR as &crate::Emoji),
-					"rly_haired_man" => Some(&crate::flat::PERSON.hair(Hair::Curly).gender(Gender::Male).default as &crate::Emoji),
-					"rly_haired_woman" => Some(&crate::flat::PERSON.hair(Hair::Curly).gender(Gender::Female).default as &crate::Emoji),
-					"rly_loop" => Some(&crate::flat::CURLY_LOOP as &crate::Emoji),
-					"rrency_exchange" => Some(&crate::flat::CURRENCY_EXCHANGE as &crate::Emoji),
-					"rry" => Some(&crate::flat::CURRY_RICE as &crate::Emoji),
-					"rry_rice" => Some(&crate::flat::CURRY_RICE as &crate::Emoji),
-					"rsing_face" => Some(&crate::flat::FACE_WITH_SYMBOLS_ON_MOUTH as &crate::Emoji),
-					"stard" => Some(&crate::flat::CUSTARD as &crate::Emoji),
-					"stoms" => Some(&crate::flat::CUSTOMS as &crate::Emoji),
-					"t_of_meat" => Some(&crate::flat::CUT_OF_MEAT as &crate::Emoji),
-					_ => None,
-				},
-				b'y' => match rest {
-					"clone" => Some(&crate::flat::CYCLONE as &crate::Emoji),
-					"prus" => Some(&crate::flat::FLAG_CYPRUS as &crate::Emoji),
-					_ => None,
-				},
-				b'z' => match rest {
-					"ech_republic" => Some(&crate::flat::FLAG_CZECHIA as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'd' => match sec {
-				b'a' => match rest {
-					"gger" => Some(&crate::flat::DAGGER as &crate::Emoji),
-					"ncer" => Some(&crate::flat::PERSON_DANCING.gender(Gender::Female).default as &crate::Emoji),
-					"ncers" => Some(&crate::flat::PERSON_WITH_BUNNY_EARS.default as &crate::Emoji),
-					"ncing_men" => Some(&crate::flat::PERSON_WITH_BUNNY_EARS.gender(Gender::Male) as &crate::Emoji),
-					"ncing_women" => Some(&crate::flat::PERSON_WITH_BUNNY_EARS.gender(Gender::Female) as &crate::Emoji),
-					"ngo" => Some(&crate::flat::DANGO as &crate::Emoji),
-					"rk_skin_tone" => Some(&crate::flat::DARK_SKIN_TONE as &crate::Emoji),
-					"rk_sunglasses" => Some(&crate::flat::SUNGLASSES as &crate::Emoji),
-					"rt" => Some(&crate::flat::BULLSEYE as &crate::Emoji),
-					"sh" => Some(&crate::flat::DASHING_AWAY as &crate::Emoji),
-					"shing_away" => Some(&crate::flat::DASHING_AWAY as &crate::Emoji),
-					"te" => Some(&crate::flat::CALENDAR as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"" => Some(&crate::flat::FLAG_GERMANY as &crate::Emoji),
-					"af_man" => Some(&crate::flat::DEAF_PERSON.gender(Gender::Male).default as &crate::Emoji),
-					"af_person" => Some(&crate::flat::DEAF_PERSON.default.default as &crate::Emoji),
-					"af_woman" => Some(&crate::flat::DEAF_PERSON.gender(Gender::Female).default as &crate::Emoji),
-					"ciduous_tree" => Some(&crate::flat::DECIDUOUS_TREE as &crate::Emoji),
-					"er" => Some(&crate::flat::DEER as &crate::Emoji),
-					"livery_truck" => Some(&crate::flat::DELIVERY_TRUCK as &crate::Emoji),
-					"nmark" => Some(&crate::flat::FLAG_DENMARK as &crate::Emoji),
-					"partment_store" => Some(&crate::flat::DEPARTMENT_STORE as &crate::Emoji),
-					"relict_house" => Some(&crate::flat::DERELICT_HOUSE as &crate::Emoji),
-					"sert" => Some(&crate::flat::DESERT as &crate::Emoji),
-					"sert_island" => Some(&crate::flat::DESERT_ISLAND as &crate::Emoji),
-					"sktop_computer" => Some(&crate::flat::DESKTOP_COMPUTER as &crate::Emoji),
-					"tective" => Some(&crate::flat::DETECTIVE.default.default as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"amond_shape_with_a_dot_inside" => Some(&crate::flat::DIAMOND_WITH_A_DOT as &crate::Emoji),
-					"amond_suit" => Some(&crate::flat::DIAMOND_SUIT as &crate::Emoji),
-					"amond_with_a_dot" => Some(&crate::flat::DIAMOND_WITH_A_DOT as &crate::Emoji),
-					"amonds" => Some(&crate::flat::DIAMOND_SUIT as &crate::Emoji),
-					"ego_garcia" => Some(&crate::flat::FLAG_DIEGO_GARCIA as &crate::Emoji),
-					"m_button" => Some(&crate::flat::DIM_BUTTON as &crate::Emoji),
-					"sappointed" => Some(&crate::flat::DISAPPOINTED_FACE as &crate::Emoji),
-					"sappointed_face" => Some(&crate::flat::DISAPPOINTED_FACE as &crate::Emoji),
-					"sappointed_relieved" => Some(&crate::flat::SAD_BUT_RELIEVED_FACE as &crate::Emoji),
-					"sguised_face" => Some(&crate::flat::DISGUISED_FACE as &crate::Emoji),
-					"vide" => Some(&crate::flat::DIVIDE as &crate::Emoji),
-					"ving_mask" => Some(&crate::flat::DIVING_MASK as &crate::Emoji),
-					"ya_lamp" => Some(&crate::flat::DIYA_LAMP as &crate::Emoji),
-					"zzy" => Some(&crate::flat::DIZZY as &crate::Emoji),
-					"zzy_face" => Some(&crate::flat::KNOCKED_OUT_FACE as &crate::Emoji),
-					_ => None,
-				},
-				b'j' => match rest {
-					"ibouti" => Some(&crate::flat::FLAG_DJIBOUTI as &crate::Emoji),
-					_ => None,
-				},
-				b'n' => match rest {
-					"a" => Some(&crate::flat::DNA as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"_not_litter" => Some(&crate::flat::NO_LITTERING as &crate::Emoji),
-					"do" => Some(&crate::flat::DODO as &crate::Emoji),
-					"g" => Some(&crate::flat::DOG as &crate::Emoji),
-					"g2" => Some(&crate::flat::DOG as &crate::Emoji),
-					"g_face" => Some(&crate::flat::DOG_FACE as &crate::Emoji),
-					"llar" => Some(&crate::flat::DOLLAR_BANKNOTE as &crate::Emoji),
-					"llar_banknote" => Some(&crate::flat::DOLLAR_BANKNOTE as &crate::Emoji),
-					"lls" => Some(&crate::flat::JAPANESE_DOLLS as &crate::Emoji),
-					"lphin" => Some(&crate::flat::DOLPHIN as &crate::Emoji),
-					"minica" => Some(&crate::flat::FLAG_DOMINICA as &crate::Emoji),
-					"minican_republic" => Some(&crate::flat::FLAG_DOMINICAN_REPUBLIC as &crate::Emoji),
-					"or" => Some(&crate::flat::DOOR as &crate::Emoji),
-					"tted_six_pointed_star" => Some(&crate::flat::DOTTED_SIX_POINTED_STAR as &crate::Emoji),
-					"uble_curly_loop" => Some(&crate::flat::DOUBLE_CURLY_LOOP as &crate::Emoji),
-					"uble_exclamation_mark" => Some(&crate::flat::DOUBLE_EXCLAMATION_MARK as &crate::Emoji),
-					"ughnut" => Some(&crate::flat::DOUGHNUT as &crate::Emoji),
-					"ve" => Some(&crate::flat::DOVE as &crate::Emoji),
-					"wn_arrow" => Some(&crate::flat::DOWN_ARROW as &crate::Emoji),
-					"wn_left_arrow" => Some(&crate::flat::DOWN_LEFT_ARROW as &crate::Emoji),
-					"wn_right_arrow" => Some(&crate::flat::DOWN_RIGHT_ARROW as &crate::Emoji),
-					"wncast_face_with_sweat" => Some(&crate::flat::DOWNCAST_FACE_WITH_SWEAT as &crate::Emoji),
-					"wnwards_button" => Some(&crate::flat::DOWNWARDS_BUTTON as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"agon" => Some(&crate::flat::DRAGON as &crate::Emoji),
-					"agon_face" => Some(&crate::flat::DRAGON_FACE as &crate::Emoji),
-					"ess" => Some(&crate::flat::DRESS as &crate::Emoji),
-					"omedary_camel" => Some(&crate::flat::CAMEL as &crate::Emoji),
-					"ooling_face" => Some(&crate::flat::DROOLING_FACE as &crate::Emoji),
-					"op_of_blood" => Some(&crate::flat::DROP_OF_BLOOD as &crate::Emoji),
-					"oplet" => Some(&crate::flat::DROPLET as &crate::Emoji),
-					"um" => Some(&crate::flat::DRUM as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"ck" => Some(&crate::flat::DUCK as &crate::Emoji),
-					"mpling" => Some(&crate::flat::DUMPLING as &crate::Emoji),
-					_ => None,
-				},
-				b'v' => match rest {
-					"d" => Some(&crate::flat::DVD as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'e' => match sec {
-				b'-' => match rest {
-					"mail" => Some(&crate::flat::E_MAIL as &crate::Emoji),
-					_ => None,
-				},
-				b'_' => match rest {
-					"mail" => Some(&crate::flat::E_MAIL as &crate::Emoji),
-					_ => None,
-				},
-				b'a' => match rest {
-					"gle" => Some(&crate::flat::EAGLE as &crate::Emoji),
-					"r" => Some(&crate::flat::EAR.default as &crate::Emoji),
-					"r_of_corn" => Some(&crate::flat::EAR_OF_CORN as &crate::Emoji),
-					"r_of_rice" => Some(&crate::flat::SHEAF_OF_RICE as &crate::Emoji),
-					"r_with_hearing_aid" => Some(&crate::flat::EAR_WITH_HEARING_AID.default as &crate::Emoji),
-					"rth_africa" => Some(&crate::flat::GLOBE_SHOWING_EUROPE_AFRICA as &crate::Emoji),
-					"rth_americas" => Some(&crate::flat::GLOBE_SHOWING_AMERICAS as &crate::Emoji),
-					"rth_asia" => Some(&crate::flat::GLOBE_SHOWING_ASIA_AUSTRALIA as &crate::Emoji),
-					_ => None,
-				},
-				b'c' => match rest {
-					"uador" => Some(&crate::flat::FLAG_ECUADOR as &crate::Emoji),
-					_ => None,
-				},
-				b'g' => match rest {
-					"g" => Some(&crate::flat::EGG as &crate::Emoji),
-					"gplant" => Some(&crate::flat::EGGPLANT as &crate::Emoji),
-					"ypt" => Some(&crate::flat::FLAG_EGYPT as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"ght" => Some(&crate::flat::KEYCAP_8 as &crate::Emoji),
-					"ght_o_clock" => Some(&crate::flat::EIGHT_O_CLOCK as &crate::Emoji),
-					"ght_pointed_black_star" => Some(&crate::flat::EIGHT_POINTED_STAR as &crate::Emoji),
-					"ght_pointed_star" => Some(&crate::flat::EIGHT_POINTED_STAR as &crate::Emoji),
-					"ght_spoked_asterisk" => Some(&crate::flat::EIGHT_SPOKED_ASTERISK as &crate::Emoji),
-					"ght_thirty" => Some(&crate::flat::EIGHT_THIRTY as &crate::Emoji),
-					_ => None,
-				},
-				b'j' => match rest {
-					"ect_button" => Some(&crate::flat::EJECT_BUTTON as &crate::Emoji),
-					_ => None,
-				},
-				b'l' => match rest {
-					"_salvador" => Some(&crate::flat::FLAG_EL_SALVADOR as &crate::Emoji),
-					"ectric_plug" => Some(&crate::flat::ELECTRIC_PLUG as &crate::Emoji),
-					"ephant" => Some(&crate::flat::ELEPHANT as &crate::Emoji),
-					"evator" => Some(&crate::flat::ELEVATOR as &crate::Emoji),
-					"even_o_clock" => Some(&crate::flat::ELEVEN_O_CLOCK as &crate::Emoji),
-					"even_thirty" => Some(&crate::flat::ELEVEN_THIRTY as &crate::Emoji),
-					"f" => Some(&crate::flat::ELF.default.default as &crate::Emoji),
-					"f_man" => Some(&crate::flat::ELF.gender(Gender::Male).default as &crate::Emoji),
-					"f_woman" => Some(&crate::flat::ELF.gender(Gender::Female).default as &crate::Emoji),
-					_ => None,
-				},
-				b'm' => match rest {
-					"ail" => Some(&crate::flat::E_MAIL as &crate::Emoji),
-					_ => None,
-				},
-				b'n' => match rest {
-					"d" => Some(&crate::flat::END_ARROW as &crate::Emoji),
-					"d_arrow" => Some(&crate::flat::END_ARROW as &crate::Emoji),
-					"gland" => Some(&crate::flat::FLAG_ENGLAND as &crate::Emoji),
-					"velope" => Some(&crate::flat::ENVELOPE as &crate::Emoji),
-					"velope_with_arrow" => Some(&crate::flat::ENVELOPE_WITH_ARROW as &crate::Emoji),
-					_ => None,
-				},
-				b'q' => match rest {
-					"uatorial_guinea" => Some(&crate::flat::FLAG_EQUATORIAL_GUINEA as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"itrea" => Some(&crate::flat::FLAG_ERITREA as &crate::Emoji),
-					_ => None,
-				},
-				b's' => match rest {
-					"" => Some(&crate::flat::FLAG_SPAIN as &crate::Emoji),
-					"tonia" => Some(&crate::flat::FLAG_ESTONIA as &crate::Emoji),
-					_ => None,
-				},
-				b't' => match rest {
-					"hiopia" => Some(&crate::flat::FLAG_ETHIOPIA as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"" => Some(&crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
-					"ro" => Some(&crate::flat::EURO_BANKNOTE as &crate::Emoji),
-					"ro_banknote" => Some(&crate::flat::EURO_BANKNOTE as &crate::Emoji),
-					"ropean_castle" => Some(&crate::flat::CASTLE as &crate::Emoji),
-					"ropean_post_office" => Some(&crate::flat::POST_OFFICE as &crate::Emoji),
-					"ropean_union" => Some(&crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
-					_ => None,
-				},
-				b'v' => match rest {
-					"ergreen_tree" => Some(&crate::flat::EVERGREEN_TREE as &crate::Emoji),
-					_ => None,
-				},
-				b'w' => match rest {
-					"e" => Some(&crate::flat::EWE as &crate::Emoji),
-					_ => None,
-				},
-				b'x' => match rest {
-					"clamation" => Some(&crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
-					"clamation_question_mark" => Some(&crate::flat::EXCLAMATION_QUESTION_MARK as &crate::Emoji),
-					"ploding_head" => Some(&crate::flat::EXPLODING_HEAD as &crate::Emoji),
-					"pressionless" => Some(&crate::flat::EXPRESSIONLESS_FACE as &crate::Emoji),
-					"pressionless_face" => Some(&crate::flat::EXPRESSIONLESS_FACE as &crate::Emoji),
-					_ => None,
-				},
-				b'y' => match rest {
-					"e" => Some(&crate::flat::EYE as &crate::Emoji),
-					"e_in_speech_bubble" => Some(&crate::flat::EYE_IN_SPEECH_BUBBLE as &crate::Emoji),
-					"e_speech_bubble" => Some(&crate::flat::EYE_IN_SPEECH_BUBBLE as &crate::Emoji),
-					"eglasses" => Some(&crate::flat::GLASSES as &crate::Emoji),
-					"es" => Some(&crate::flat::EYES as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'f' => match sec {
-				b'a' => match rest {
-					"ce_blowing_a_kiss" => Some(&crate::flat::FACE_BLOWING_A_KISS as &crate::Emoji),
-					"ce_exhaling" => Some(&crate::flat::FACE_EXHALING as &crate::Emoji),
-					"ce_in_clouds" => Some(&crate::flat::FACE_IN_CLOUDS as &crate::Emoji),
-					"ce_savoring_food" => Some(&crate::flat::FACE_SAVORING_FOOD as &crate::Emoji),
-					"ce_screaming_in_fear" => Some(&crate::flat::FACE_SCREAMING_IN_FEAR as &crate::Emoji),
-					"ce_vomiting" => Some(&crate::flat::FACE_VOMITING as &crate::Emoji),
-					"ce_with_hand_over_mouth" => Some(&crate::flat::FACE_WITH_HAND_OVER_MOUTH as &crate::Emoji),
-					"ce_with_head_bandage" => Some(&crate::flat::FACE_WITH_HEAD_BANDAGE as &crate::Emoji),
-					"ce_with_medical_mask" => Some(&crate::flat::FACE_WITH_MEDICAL_MASK as &crate::Emoji),
-					"ce_with_monocle" => Some(&crate::flat::FACE_WITH_MONOCLE as &crate::Emoji),
-					"ce_with_open_mouth" => Some(&crate::flat::FACE_WITH_OPEN_MOUTH as &crate::Emoji),
-					"ce_with_raised_eyebrow" => Some(&crate::flat::FACE_WITH_RAISED_EYEBROW as &crate::Emoji),
-					"ce_with_rolling_eyes" => Some(&crate::flat::FACE_WITH_ROLLING_EYES as &crate::Emoji),
-					"ce_with_spiral_eyes" => Some(&crate::flat::FACE_WITH_SPIRAL_EYES as &crate::Emoji),
-					"ce_with_steam_from_nose" => Some(&crate::flat::FACE_WITH_STEAM_FROM_NOSE as &crate::Emoji),
-					"ce_with_symbols_on_mouth" => Some(&crate::flat::FACE_WITH_SYMBOLS_ON_MOUTH as &crate::Emoji),
-					"ce_with_tears_of_joy" => Some(&crate::flat::FACE_WITH_TEARS_OF_JOY as &crate::Emoji),
-					"ce_with_thermometer" => Some(&crate::flat::FACE_WITH_THERMOMETER as &crate::Emoji),
-					"ce_with_tongue" => Some(&crate::flat::FACE_WITH_TONGUE as &crate::Emoji),
-					"ce_without_mouth" => Some(&crate::flat::FACE_WITHOUT_MOUTH as &crate::Emoji),
-					"cepalm" => Some(&crate::flat::PERSON_FACEPALMING.default.default as &crate::Emoji),
-					"cepunch" => Some(&crate::flat::ONCOMING_FIST.default as &crate::Emoji),
-					"ctory" => Some(&crate::flat::FACTORY as &crate::Emoji),
-					"ctory_worker" => Some(&crate::flat::FACTORY_WORKER.default.default as &crate::Emoji),
-					"iry" => Some(&crate::flat::FAIRY.default.default as &crate::Emoji),
-					"iry_man" => Some(&crate::flat::FAIRY.gender(Gender::Male).default as &crate::Emoji),
-					"iry_woman" => Some(&crate::flat::FAIRY.gender(Gender::Female).default as &crate::Emoji),
-					"lafel" => Some(&crate::flat::FALAFEL as &crate::Emoji),
-					"lkland_islands" => Some(&crate::flat::FLAG_FALKLAND_ISLANDS as &crate::Emoji),
-					"llen_leaf" => Some(&crate::flat::FALLEN_LEAF as &crate::Emoji),
-					"mily" => Some(&crate::flat::FAMILY.default as &crate::Emoji),
-					"mily_man_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
-					"mily_man_boy_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
-					"mily_man_girl" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
-					"mily_man_girl_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
-					"mily_man_girl_girl" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Male), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
-					"mily_man_man_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
-					"mily_man_man_boy_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
-					"mily_man_man_girl" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
-					"mily_man_man_girl_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
-					"mily_man_man_girl_girl" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Males), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
-					"mily_man_woman_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
-					"mily_man_woman_boy_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
-					"mily_man_woman_girl" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
-					"mily_man_woman_girl_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
-					"mily_man_woman_girl_girl" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Mixed), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
-					"mily_woman_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
-					"mily_woman_boy_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
-					"mily_woman_girl" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
-					"mily_woman_girl_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
-					"mily_woman_girl_girl" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::One(Gender::Female), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
-					"mily_woman_woman_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::One(Gender::Male) }) as &crate::Emoji),
-					"mily_woman_woman_boy_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Males) }) as &crate::Emoji),
-					"mily_woman_woman_girl" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::One(Gender::Female) }) as &crate::Emoji),
-					"mily_woman_woman_girl_boy" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Mixed) }) as &crate::Emoji),
-					"mily_woman_woman_girl_girl" => Some(&crate::flat::FAMILY.family(Family{parents: OneOrTwo::Two(Pair::Females), children: OneOrTwo::Two(Pair::Females) }) as &crate::Emoji),
-					"rmer" => Some(&crate::flat::FARMER.default.default as &crate::Emoji),
-					"roe_islands" => Some(&crate::flat::FLAG_FAROE_ISLANDS as &crate::Emoji),
-					"st_down_button" => Some(&crate::flat::FAST_DOWN_BUTTON as &crate::Emoji),
-					"st_forward" => Some(&crate::flat::FAST_FORWARD_BUTTON as &crate::Emoji),
-					"st_forward_button" => Some(&crate::flat::FAST_FORWARD_BUTTON as &crate::Emoji),
-					"st_reverse_button" => Some(&crate::flat::FAST_REVERSE_BUTTON as &crate::Emoji),
-					"st_up_button" => Some(&crate::flat::FAST_UP_BUTTON as &crate::Emoji),
-					"x" => Some(&crate::flat::FAX_MACHINE as &crate::Emoji),
-					"x_machine" => Some(&crate::flat::FAX_MACHINE as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"arful" => Some(&crate::flat::FEARFUL_FACE as &crate::Emoji),
-					"arful_face" => Some(&crate::flat::FEARFUL_FACE as &crate::Emoji),
-					"ather" => Some(&crate::flat::FEATHER as &crate::Emoji),
-					"et" => Some(&crate::flat::PAW_PRINTS as &crate::Emoji),
-					"male_detective" => Some(&crate::flat::DETECTIVE.gender(Gender::Female).default as &crate::Emoji),
-					"male_sign" => Some(&crate::flat::FEMALE_SIGN as &crate::Emoji),
-					"rris_wheel" => Some(&crate::flat::FERRIS_WHEEL as &crate::Emoji),
-					"rry" => Some(&crate::flat::FERRY as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"eld_hockey" => Some(&crate::flat::FIELD_HOCKEY as &crate::Emoji),
-					"ji" => Some(&crate::flat::FLAG_FIJI as &crate::Emoji),
-					"le_cabinet" => Some(&crate::flat::FILE_CABINET as &crate::Emoji),
-					"le_folder" => Some(&crate::flat::FILE_FOLDER as &crate::Emoji),
-					"lm_frames" => Some(&crate::flat::FILM_FRAMES as &crate::Emoji),
-					"lm_projector" => Some(&crate::flat::FILM_PROJECTOR as &crate::Emoji),
-					"lm_strip" => Some(&crate::flat::FILM_FRAMES as &crate::Emoji),
-					"nland" => Some(&crate::flat::FLAG_FINLAND as &crate::Emoji),
-					"re" => Some(&crate::flat::FIRE as &crate::Emoji),
-					"re_engine" => Some(&crate::flat::FIRE_ENGINE as &crate::Emoji),
-					"re_extinguisher" => Some(&crate::flat::FIRE_EXTINGUISHER as &crate::Emoji),
-					"recracker" => Some(&crate::flat::FIRECRACKER as &crate::Emoji),
-					"refighter" => Some(&crate::flat::FIREFIGHTER.default.default as &crate::Emoji),
-					"reworks" => Some(&crate::flat::FIREWORKS as &crate::Emoji),
-					"rst_place_medal" => Some(&crate::flat::FIRST_PLACE_MEDAL as &crate::Emoji),
-					"rst_quarter_moon" => Some(&crate::flat::FIRST_QUARTER_MOON as &crate::Emoji),
-					"rst_quarter_moon_face" => Some(&crate::flat::FIRST_QUARTER_MOON_FACE as &crate::Emoji),
-					"rst_quarter_moon_with_face" => Some(&crate::flat::FIRST_QUARTER_MOON_FACE as &crate::Emoji),
-					"sh" => Some(&crate::flat::FISH as &crate::Emoji),
-					"sh_cake" => Some(&crate::flat::FISH_CAKE_WITH_SWIRL as &crate::Emoji),
-					"sh_cake_with_swirl" => Some(&crate::flat::FISH_CAKE_WITH_SWIRL as &crate::Emoji),
-					"shing_pole" => Some(&crate::flat::FISHING_POLE as &crate::Emoji),
-					"shing_pole_and_fish" => Some(&crate::flat::FISHING_POLE as &crate::Emoji),
-					"st" => Some(&crate::flat::RAISED_FIST.default as &crate::Emoji),
-					"st_left" => Some(&crate::flat::LEFT_FACING_FIST.default as &crate::Emoji),
-					"st_oncoming" => Some(&crate::flat::ONCOMING_FIST.default as &crate::Emoji),
-					"st_raised" => Some(&crate::flat::RAISED_FIST.default as &crate::Emoji),
-					"st_right" => Some(&crate::flat::RIGHT_FACING_FIST.default as &crate::Emoji),
-					"ve" => Some(&crate::flat::KEYCAP_5 as &crate::Emoji),
-					"ve_o_clock" => Some(&crate::flat::FIVE_O_CLOCK as &crate::Emoji),
-					"ve_thirty" => Some(&crate::flat::FIVE_THIRTY as &crate::Emoji),
-					_ => None,
-				},
-				b'l' => match rest {
-					"ag_afghanistan" => Some(&crate::flat::FLAG_AFGHANISTAN as &crate::Emoji),
-					"ag_aland_islands" => Some(&crate::flat::FLAG_ALAND_ISLANDS as &crate::Emoji),
-					"ag_albania" => Some(&crate::flat::FLAG_ALBANIA as &crate::Emoji),
-					"ag_algeria" => Some(&crate::flat::FLAG_ALGERIA as &crate::Emoji),
-					"ag_american_samoa" => Some(&crate::flat::FLAG_AMERICAN_SAMOA as &crate::Emoji),
-					"ag_andorra" => Some(&crate::flat::FLAG_ANDORRA as &crate::Emoji),
-					"ag_angola" => Some(&crate::flat::FLAG_ANGOLA as &crate::Emoji),
-					"ag_anguilla" => Some(&crate::flat::FLAG_ANGUILLA as &crate::Emoji),
-					"ag_antarctica" => Some(&crate::flat::FLAG_ANTARCTICA as &crate::Emoji),
-					"ag_antigua_and_barbuda" => Some(&crate::flat::FLAG_ANTIGUA_AND_BARBUDA as &crate::Emoji),
-					"ag_argentina" => Some(&crate::flat::FLAG_ARGENTINA as &crate::Emoji),
-					"ag_armenia" => Some(&crate::flat::FLAG_ARMENIA as &crate::Emoji),
-					"ag_aruba" => Some(&crate::flat::FLAG_ARUBA as &crate::Emoji),
-					"ag_ascension_island" => Some(&crate::flat::FLAG_ASCENSION_ISLAND as &crate::Emoji),
-					"ag_australia" => Some(&crate::flat::FLAG_AUSTRALIA as &crate::Emoji),
-					"ag_austria" => Some(&crate::flat::FLAG_AUSTRIA as &crate::Emoji),
-					"ag_azerbaijan" => Some(&crate::flat::FLAG_AZERBAIJAN as &crate::Emoji),
-					"ag_bahamas" => Some(&crate::flat::FLAG_BAHAMAS as &crate::Emoji),
-					"ag_bahrain" => Some(&crate::flat::FLAG_BAHRAIN as &crate::Emoji),
-					"ag_bangladesh" => Some(&crate::flat::FLAG_BANGLADESH as &crate::Emoji),
-					"ag_barbados" => Some(&crate::flat::FLAG_BARBADOS as &crate::Emoji),
-					"ag_belarus" => Some(&crate::flat::FLAG_BELARUS as &crate::Emoji),
-					"ag_belgium" => Some(&crate::flat::FLAG_BELGIUM as &crate::Emoji),
-					"ag_belize" => Some(&crate::flat::FLAG_BELIZE as &crate::Emoji),
-					"ag_benin" => Some(&crate::flat::FLAG_BENIN as &crate::Emoji),
-					"ag_bermuda" => Some(&crate::flat::FLAG_BERMUDA as &crate::Emoji),
-					"ag_bhutan" => Some(&crate::flat::FLAG_BHUTAN as &crate::Emoji),
-					"ag_bolivia" => Some(&crate::flat::FLAG_BOLIVIA as &crate::Emoji),
-					"ag_bosnia_and_herzegovina" => Some(&crate::flat::FLAG_BOSNIA_AND_HERZEGOVINA as &crate::Emoji),
-					"ag_botswana" => Some(&crate::flat::FLAG_BOTSWANA as &crate::Emoji),
-					"ag_bouvet_island" => Some(&crate::flat::FLAG_BOUVET_ISLAND as &crate::Emoji),
-					"ag_brazil" => Some(&crate::flat::FLAG_BRAZIL as &crate::Emoji),
-					"ag_british_indian_ocean_territory" => Some(&crate::flat::FLAG_BRITISH_INDIAN_OCEAN_TERRITORY as &crate::Emoji),
-					"ag_british_virgin_islands" => Some(&crate::flat::FLAG_BRITISH_VIRGIN_ISLANDS as &crate::Emoji),
-					"ag_brunei" => Some(&crate::flat::FLAG_BRUNEI as &crate::Emoji),
-					"ag_bulgaria" => Some(&crate::flat::FLAG_BULGARIA as &crate::Emoji),
-					"ag_burkina_faso" => Some(&crate::flat::FLAG_BURKINA_FASO as &crate::Emoji),
-					"ag_burundi" => Some(&crate::flat::FLAG_BURUNDI as &crate::Emoji),
-					"ag_cambodia" => Some(&crate::flat::FLAG_CAMBODIA as &crate::Emoji),
-					"ag_cameroon" => Some(&crate::flat::FLAG_CAMEROON as &crate::Emoji),
-					"ag_canada" => Some(&crate::flat::FLAG_CANADA as &crate::Emoji),
-					"ag_canary_islands" => Some(&crate::flat::FLAG_CANARY_ISLANDS as &crate::Emoji),
-					"ag_cape_verde" => Some(&crate::flat::FLAG_CAPE_VERDE as &crate::Emoji),
-					"ag_caribbean_netherlands" => Some(&crate::flat::FLAG_CARIBBEAN_NETHERLANDS as &crate::Emoji),
-					"ag_cayman_islands" => Some(&crate::flat::FLAG_CAYMAN_ISLANDS as &crate::Emoji),
-					"ag_central_african_republic" => Some(&crate::flat::FLAG_CENTRAL_AFRICAN_REPUBLIC as &crate::Emoji),
-					"ag_ceuta_and_melilla" => Some(&crate::flat::FLAG_CEUTA_AND_MELILLA as &crate::Emoji),
-					"ag_chad" => Some(&crate::flat::FLAG_CHAD as &crate::Emoji),
-					"ag_chile" => Some(&crate::flat::FLAG_CHILE as &crate::Emoji),
-					"ag_china" => Some(&crate::flat::FLAG_CHINA as &crate::Emoji),
-					"ag_christmas_island" => Some(&crate::flat::FLAG_CHRISTMAS_ISLAND as &crate::Emoji),
-					"ag_clipperton_island" => Some(&crate::flat::FLAG_CLIPPERTON_ISLAND as &crate::Emoji),
-					"ag_cocos_keeling_islands" => Some(&crate::flat::FLAG_COCOS_KEELING_ISLANDS as &crate::Emoji),
-					"ag_colombia" => Some(&crate::flat::FLAG_COLOMBIA as &crate::Emoji),
-					"ag_comoros" => Some(&crate::flat::FLAG_COMOROS as &crate::Emoji),
-					"ag_congo_brazzaville" => Some(&crate::flat::FLAG_CONGO_BRAZZAVILLE as &crate::Emoji),
-					"ag_congo_kinshasa" => Some(&crate::flat::FLAG_CONGO_KINSHASA as &crate::Emoji),
-					"ag_cook_islands" => Some(&crate::flat::FLAG_COOK_ISLANDS as &crate::Emoji),
-					"ag_costa_rica" => Some(&crate::flat::FLAG_COSTA_RICA as &crate::Emoji),
-					"ag_cote_d_ivoire" => Some(&crate::flat::FLAG_COTE_D_IVOIRE as &crate::Emoji),
-					"ag_croatia" => Some(&crate::flat::FLAG_CROATIA as &crate::Emoji),
-					"ag_cuba" => Some(&crate::flat::FLAG_CUBA as &crate::Emoji),
-					"ag_curacao" => Some(&crate::flat::FLAG_CURACAO as &crate::Emoji),
-					"ag_cyprus" => Some(&crate::flat::FLAG_CYPRUS as &crate::Emoji),
-					"ag_czechia" => Some(&crate::flat::FLAG_CZECHIA as &crate::Emoji),
-					"ag_denmark" => Some(&crate::flat::FLAG_DENMARK as &crate::Emoji),
-					"ag_diego_garcia" => Some(&crate::flat::FLAG_DIEGO_GARCIA as &crate::Emoji),
-					"ag_djibouti" => Some(&crate::flat::FLAG_DJIBOUTI as &crate::Emoji),
-					"ag_dominica" => Some(&crate::flat::FLAG_DOMINICA as &crate::Emoji),
-					"ag_dominican_republic" => Some(&crate::flat::FLAG_DOMINICAN_REPUBLIC as &crate::Emoji),
-					"ag_ecuador" => Some(&crate::flat::FLAG_ECUADOR as &crate::Emoji),
-					"ag_egypt" => Some(&crate::flat::FLAG_EGYPT as &crate::Emoji),
-					"ag_el_salvador" => Some(&crate::flat::FLAG_EL_SALVADOR as &crate::Emoji),
-					"ag_england" => Some(&crate::flat::FLAG_ENGLAND as &crate::Emoji),
-					"ag_equatorial_guinea" => Some(&crate::flat::FLAG_EQUATORIAL_GUINEA as &crate::Emoji),
-					"ag_eritrea" => Some(&crate::flat::FLAG_ERITREA as &crate::Emoji),
-					"ag_estonia" => Some(&crate::flat::FLAG_ESTONIA as &crate::Emoji),
-					"ag_eswatini" => Some(&crate::flat::FLAG_ESWATINI as &crate::Emoji),
-					"ag_ethiopia" => Some(&crate::flat::FLAG_ETHIOPIA as &crate::Emoji),
-					"ag_european_union" => Some(&crate::flat::FLAG_EUROPEAN_UNION as &crate::Emoji),
-					"ag_falkland_islands" => Some(&crate::flat::FLAG_FALKLAND_ISLANDS as &crate::Emoji),
-					"ag_faroe_islands" => Some(&crate::flat::FLAG_FAROE_ISLANDS as &crate::Emoji),
-					"ag_fiji" => Some(&crate::flat::FLAG_FIJI as &crate::Emoji),
-					"ag_finland" => Some(&crate::flat::FLAG_FINLAND as &crate::Emoji),
-					"ag_france" => Some(&crate::flat::FLAG_FRANCE as &crate::Emoji),
-					"ag_french_guiana" => Some(&crate::flat::FLAG_FRENCH_GUIANA as &crate::Emoji),
-					"ag_french_polynesia" => Some(&crate::flat::FLAG_FRENCH_POLYNESIA as &crate::Emoji),
-					"ag_french_southern_territories" => Some(&crate::flat::FLAG_FRENCH_SOUTHERN_TERRITORIES as &crate::Emoji),
-					"ag_gabon" => Some(&crate::flat::FLAG_GABON as &crate::Emoji),
-					"ag_gambia" => Some(&crate::flat::FLAG_GAMBIA as &crate::Emoji),
-					"ag_georgia" => Some(&crate::flat::FLAG_GEORGIA as &crate::Emoji),
-					"ag_germany" => Some(&crate::flat::FLAG_GERMANY as &crate::Emoji),
-					"ag_ghana" => Some(&crate::flat::FLAG_GHANA as &crate::Emoji),
-					"ag_gibraltar" => Some(&crate::flat::FLAG_GIBRALTAR as &crate::Emoji),
-					"ag_greece" => Some(&crate::flat::FLAG_GREECE as &crate::Emoji),
-					"ag_greenland" => Some(&crate::flat::FLAG_GREENLAND as &crate::Emoji),
-					"ag_grenada" => Some(&crate::flat::FLAG_GRENADA as &crate::Emoji),
-					"ag_guadeloupe" => Some(&crate::flat::FLAG_GUADELOUPE as &crate::Emoji),
-					"ag_guam" => Some(&crate::flat::FLAG_GUAM as &crate::Emoji),
-					"ag_guatemala" => Some(&crate::flat::FLAG_GUATEMALA as &crate::Emoji),
-					"ag_guernsey" => Some(&crate::flat::FLAG_GUERNSEY as &crate::Emoji),
-					"ag_guinea" => Some(&crate::flat::FLAG_GUINEA as &crate::Emoji),
-					"ag_guinea_bissau" => Some(&crate::flat::FLAG_GUINEA_BISSAU as &crate::Emoji),
-					"ag_guyana" => Some(&crate::flat::FLAG_GUYANA as &crate::Emoji),
-					"ag_haiti" => Some(&crate::flat::FLAG_HAITI as &crate::Emoji),
-					"ag_heard_and_mcdonald_islands" => Some(&crate::flat::FLAG_HEARD_AND_MCDONALD_ISLANDS as &crate::Emoji),
-					"ag_honduras" => Some(&crate::flat::FLAG_HONDURAS as &crate::Emoji),
-					"ag_hong_kong_sar_china" => Some(&crate::flat::FLAG_HONG_KONG_SAR_CHINA as &crate::Emoji),
-					"ag_hungary" => Some(&crate::flat::FLAG_HUNGARY as &crate::Emoji),
-					"ag_iceland" => Some(&crate::flat::FLAG_ICELAND as &crate::Emoji),
-					"ag_in_hole" => Some(&crate::flat::FLAG_IN_HOLE as &crate::Emoji),
-					"ag_india" => Some(&crate::flat::FLAG_INDIA as &crate::Emoji),
-					"ag_indonesia" => Some(&crate::flat::FLAG_INDONESIA as &crate::Emoji),
-					"ag_iran" => Some(&crate::flat::FLAG_IRAN as &crate::Emoji),
-					"ag_iraq" => Some(&crate::flat::FLAG_IRAQ as &crate::Emoji),
-					"ag_ireland" => Some(&crate::flat::FLAG_IRELAND as &crate::Emoji),
-					"ag_isle_of_man" => Some(&crate::flat::FLAG_ISLE_OF_MAN as &crate::Emoji),
-					"ag_israel" => Some(&crate::flat::FLAG_ISRAEL as &crate::Emoji),
-					"ag_italy" => Some(&crate::flat::FLAG_ITALY as &crate::Emoji),
-					"ag_jamaica" => Some(&crate::flat::FLAG_JAMAICA as &crate::Emoji),
-					"ag_japan" => Some(&crate::flat::FLAG_JAPAN as &crate::Emoji),
-					"ag_jersey" => Some(&crate::flat::FLAG_JERSEY as &crate::Emoji),
-					"ag_jordan" => Some(&crate::flat::FLAG_JORDAN as &crate::Emoji),
-					"ag_kazakhstan" => Some(&crate::flat::FLAG_KAZAKHSTAN as &crate::Emoji),
-					"ag_kenya" => Some(&crate::flat::FLAG_KENYA as &crate::Emoji),
-					"ag_kiribati" => Some(&crate::flat::FLAG_KIRIBATI as &crate::Emoji),
-					"ag_kosovo" => Some(&crate::flat::FLAG_KOSOVO as &crate::Emoji),
-					"ag_kuwait" => Some(&crate::flat::FLAG_KUWAIT as &crate::Emoji),
-					"ag_kyrgyzstan" => Some(&crate::flat::FLAG_KYRGYZSTAN as &crate::Emoji),
-					"ag_laos" => Some(&crate::flat::FLAG_LAOS as &crate::Emoji),
-					"ag_latvia" => Some(&crate::flat::FLAG_LATVIA as &crate::Emoji),
-					"ag_lebanon" => Some(&crate::flat::FLAG_LEBANON as &crate::Emoji),
-					"ag_lesotho" => Some(&crate::flat::FLAG_LESOTHO as &crate::Emoji),
-					"ag_liberia" => Some(&crate::flat::FLAG_LIBERIA as &crate::Emoji),
-					"ag_libya" => Some(&crate::flat::FLAG_LIBYA as &crate::Emoji),
-					"ag_liechtenstein" => Some(&crate::flat::FLAG_LIECHTENSTEIN as &crate::Emoji),
-					"ag_lithuania" => Some(&crate::flat::FLAG_LITHUANIA as &crate::Emoji),
-					"ag_luxembourg" => Some(&crate::flat::FLAG_LUXEMBOURG as &crate::Emoji),
-					"ag_macao_sar_china" => Some(&crate::flat::FLAG_MACAO_SAR_CHINA as &crate::Emoji),
-					"ag_madagascar" => Some(&crate::flat::FLAG_MADAGASCAR as &crate::Emoji),
-					"ag_malawi" => Some(&crate::flat::FLAG_MALAWI as &crate::Emoji),
-					"ag_malaysia" => Some(&crate::flat::FLAG_MALAYSIA as &crate::Emoji),
-					"ag_maldives" => Some(&crate::flat::FLAG_MALDIVES as &crate::Emoji),
-					"ag_mali" => Some(&crate::flat::FLAG_MALI as &crate::Emoji),
-					"ag_malta" => Some(&crate::flat::FLAG_MALTA as &crate::Emoji),
-					"ag_marshall_islands" => Some(&crate::flat::FLAG_MARSHALL_ISLANDS as &crate::Emoji),
-					"ag_martinique" => Some(&crate::flat::FLAG_MARTINIQUE as &crate::Emoji),
-					"ag_mauritania" => Some(&crate::flat::FLAG_MAURITANIA as &crate::Emoji),
-					"ag_mauritius" => Some(&crate::flat::FLAG_MAURITIUS as &crate::Emoji),
-					"ag_mayotte" => Some(&crate::flat::FLAG_MAYOTTE as &crate::Emoji),
-					"ag_mexico" => Some(&crate::flat::FLAG_MEXICO as &crate::Emoji),
-					"ag_micronesia" => Some(&crate::flat::FLAG_MICRONESIA as &crate::Emoji),
-					"ag_moldova" => Some(&crate::flat::FLAG_MOLDOVA as &crate::Emoji),
-					"ag_monaco" => Some(&crate::flat::FLAG_MONACO as &crate::Emoji),
-					"ag_mongolia" => Some(&crate::flat::FLAG_MONGOLIA as &crate::Emoji),
-					"ag_montenegro" => Some(&crate::flat::FLAG_MONTENEGRO as &crate::Emoji),
-					"ag_montserrat" => Some(&crate::flat::FLAG_MONTSERRAT as &crate::Emoji),
-					"ag_morocco" => Some(&crate::flat::FLAG_MOROCCO as &crate::Emoji),
-					"ag_mozambique" => Some(&crate::flat::FLAG_MOZAMBIQUE as &crate::Emoji),
-					"ag_myanmar_burma" => Some(&crate::flat::FLAG_MYANMAR_BURMA as &crate::Emoji),
-					"ag_namibia" => Some(&crate::flat::FLAG_NAMIBIA as &crate::Emoji),
-					"ag_nauru" => Some(&crate::flat::FLAG_NAURU as &crate::Emoji),
-					"ag_nepal" => Some(&crate::flat::FLAG_NEPAL as &crate::Emoji),
-					"ag_netherlands" => Some(&crate::flat::FLAG_NETHERLANDS as &crate::Emoji),
-					"ag_new_caledonia" => Some(&crate::flat::FLAG_NEW_CALEDONIA as &crate::Emoji),
-					"ag_new_zealand" => Some(&crate::flat::FLAG_NEW_ZEALAND as &crate::Emoji),
-					"ag_nicaragua" => Some(&crate::flat::FLAG_NICARAGUA as &crate::Emoji),
-					"ag_niger" => Some(&crate::flat::FLAG_NIGER as &crate::Emoji),
-					"ag_nigeria" => Some(&crate::flat::FLAG_NIGERIA as &crate::Emoji),
-					"ag_niue" => Some(&crate::flat::FLAG_NIUE as &crate::Emoji),
-					"ag_norfolk_island" => Some(&crate::flat::FLAG_NORFOLK_ISLAND as &crate::Emoji),
-					"ag_north_korea" => Some(&crate::flat::FLAG_NORTH_KOREA as &crate::Emoji),
-					"ag_north_macedonia" => Some(&crate::flat::FLAG_NORTH_MACEDONIA as &crate::Emoji),
-					"ag_northern_mariana_islands" => Some(&crate::flat::FLAG_NORTHERN_MARIANA_ISLANDS as &crate::Emoji),
-					"ag_norway" => Some(&crate::flat::FLAG_NORWAY as &crate::Emoji),
-					"ag_oman" => Some(&crate::flat::FLAG_OMAN as &crate::Emoji),
-					"ag_pakistan" => Some(&crate::flat::FLAG_PAKISTAN as &crate::Emoji),
-					"ag_palau" => Some(&crate::flat::FLAG_PALAU as &crate::Emoji),
-					"ag_palestinian_territories" => Some(&crate::flat::FLAG_PALESTINIAN_TERRITORIES as &crate::Emoji),
-					"ag_panama" => Some(&crate::flat::FLAG_PANAMA as &crate::Emoji),
-					"ag_papua_new_guinea" => Some(&crate::flat::FLAG_PAPUA_NEW_GUINEA as &crate::Emoji),
-					"ag_paraguay" => Some(&crate::flat::FLAG_PARAGUAY as &crate::Emoji),
-					"ag_peru" => Some(&crate::flat::FLAG_PERU as &crate::Emoji),
-					"ag_philippines" => Some(&crate::flat::FLAG_PHILIPPINES as &crate::Emoji),
-					"ag_pitcairn_islands" => Some(&crate::flat::FLAG_PITCAIRN_ISLANDS as &crate::Emoji),
-					"ag_poland" => Some(&crate::flat::FLAG_POLAND as &crate::Emoji),
-					"ag_portugal" => Some(&crate::flat::FLAG_PORTUGAL as &crate::Emoji),
-					"ag_puerto_rico" => Some(&crate::flat::FLAG_PUERTO_RICO as &crate::Emoji),
-					"ag_qatar" => Some(&crate::flat::FLAG_QATAR as &crate::Emoji),
-					"ag_reunion" => Some(&crate::flat::FLAG_REUNION as &crate::Emoji),
-					"ag_romania" => Some(&crate::flat::FLAG_ROMANIA as &crate::Emoji),
-					"ag_russia" => Some(&crate::flat::FLAG_RUSSIA as &crate::Emoji),
-					"ag_rwanda" => Some(&crate::flat::FLAG_RWANDA as &crate::Emoji),
-					"ag_samoa" => Some(&crate::flat::FLAG_SAMOA as &crate::Emoji),
-					"ag_san_marino" => Some(&crate::flat::FLAG_SAN_MARINO as &crate::Emoji),
-					"ag_sao_tome_and_principe" => Some(&crate::flat::FLAG_SAO_TOME_AND_PRINCIPE as &crate::Emoji),
-					"ag_saudi_arabia" => Some(&crate::flat::FLAG_SAUDI_ARABIA as &crate::Emoji),
-					"ag_scotland" => Some(&crate::flat::FLAG_SCOTLAND as &crate::Emoji),
-					"ag_senegal" => Some(&crate::flat::FLAG_SENEGAL as &crate::Emoji),
-					"ag_serbia" => Some(&crate::flat::FLAG_SERBIA as &crate::Emoji),
-					"ag_seychelles" => Some(&crate::flat::FLAG_SEYCHELLES as &crate::Emoji),
-					"ag_sierra_leone" => Some(&crate::flat::FLAG_SIERRA_LEONE as &crate::Emoji),
-					"ag_singapore" => Some(&crate::flat::FLAG_SINGAPORE as &crate::Emoji),
-					"ag_sint_maarten" => Some(&crate::flat::FLAG_SINT_MAARTEN as &crate::Emoji),
-					"ag_slovakia" => Some(&crate::flat::FLAG_SLOVAKIA as &crate::Emoji),
-					"ag_slovenia" => Some(&crate::flat::FLAG_SLOVENIA as &crate::Emoji),
-					"ag_solomon_islands" => Some(&crate::flat::FLAG_SOLOMON_ISLANDS as &crate::Emoji),
-					"ag_somalia" => Some(&crate::flat::FLAG_SOMALIA as &crate::Emoji),
-					"ag_south_africa" => Some(&crate::flat::FLAG_SOUTH_AFRICA as &crate::Emoji),
-					"ag_south_georgia_and_south_sandwich_islands" => Some(&crate::flat::FLAG_SOUTH_GEORGIA_AND_SOUTH_SANDWICH_ISLANDS as &crate::Emoji),
-					"ag_south_korea" => Some(&crate::flat::FLAG_SOUTH_KOREA as &crate::Emoji),
-					"ag_south_sudan" => Some(&crate::flat::FLAG_SOUTH_SUDAN as &crate::Emoji),
-					"ag_spain" => Some(&crate::flat::FLAG_SPAIN as &crate::Emoji),
-					"ag_sri_lanka" => Some(&crate::flat::FLAG_SRI_LANKA as &crate::Emoji),
-					"ag_st_barthelemy" => Some(&crate::flat::FLAG_ST_BARTHELEMY as &crate::Emoji),
-					"ag_st_helena" => Some(&crate::flat::FLAG_ST_HELENA as &crate::Emoji),
-					"ag_st_kitts_and_nevis" => Some(&crate::flat::FLAG_ST_KITTS_AND_NEVIS as &crate::Emoji),
-					"ag_st_lucia" => Some(&crate::flat::FLAG_ST_LUCIA as &crate::Emoji),
-					"ag_st_martin" => Some(&crate::flat::FLAG_ST_MARTIN as &crate::Emoji),
-					"ag_st_pierre_and_miquelon" => Some(&crate::flat::FLAG_ST_PIERRE_AND_MIQUELON as &crate::Emoji),
-					"ag_st_vincent_and_grenadines" => Some(&crate::flat::FLAG_ST_VINCENT_AND_GRENADINES as &crate::Emoji),
-					"ag_sudan" => Some(&crate::flat::FLAG_SUDAN as &crate::Emoji),
-					"ag_suriname" => Some(&crate::flat::FLAG_SURINAME as &crate::Emoji),
-					"ag_svalbard_and_jan_mayen" => Some(&crate::flat::FLAG_SVALBARD_AND_JAN_MAYEN as &crate::Emoji),
-					"ag_sweden" => Some(&crate::flat::FLAG_SWEDEN as &crate::Emoji),
-					"ag_switzerland" => Some(&crate::flat::FLAG_SWITZERLAND as &crate::Emoji),
-					"ag_syria" => Some(&crate::flat::FLAG_SYRIA as &crate::Emoji),
-					"ag_taiwan" => Some(&crate::flat::FLAG_TAIWAN as &crate::Emoji),
-					"ag_tajikistan" => Some(&crate::flat::FLAG_TAJIKISTAN as &crate::Emoji),
-					"ag_tanzania" => Some(&crate::flat::FLAG_TANZANIA as &crate::Emoji),
-					"ag_thailand" => Some(&crate::flat::FLAG_THAILAND as &crate::Emoji),
-					"ag_timor_leste" => Some(&crate::flat::FLAG_TIMOR_LESTE as &crate::Emoji),
-					"ag_togo" => Some(&crate::flat::FLAG_TOGO as &crate::Emoji),
-					"ag_tokelau" => Some(&crate::flat::FLAG_TOKELAU as &crate::Emoji),
-					"ag_tonga" => Some(&crate::flat::FLAG_TONGA as &crate::Emoji),
-					"ag_trinidad_and_tobago" => Some(&crate::flat::FLAG_TRINIDAD_AND_TOBAGO as &crate::Emoji),
-					"ag_tristan_da_cunha" => Some(&crate::flat::FLAG_TRISTAN_DA_CUNHA as &crate::Emoji),
-					"ag_tunisia" => Some(&crate::flat::FLAG_TUNISIA as &crate::Emoji),
-					"ag_turkey" => Some(&crate::flat::FLAG_TURKEY as &crate::Emoji),
-					"ag_turkmenistan" => Some(&crate::flat::FLAG_TURKMENISTAN as &crate::Emoji),
-					"ag_turks_and_caicos_islands" => Some(&crate::flat::FLAG_TURKS_AND_CAICOS_ISLANDS as &crate::Emoji),
-					"ag_tuvalu" => Some(&crate::flat::FLAG_TUVALU as &crate::Emoji),
-					"ag_uganda" => Some(&crate::flat::FLAG_UGANDA as &crate::Emoji),
-					"ag_ukraine" => Some(&crate::flat::FLAG_UKRAINE as &crate::Emoji),
-					"ag_united_arab_emirates" => Some(&crate::flat::FLAG_UNITED_ARAB_EMIRATES as &crate::Emoji),
-					"ag_united_kingdom" => Some(&crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
-					"ag_united_nations" => Some(&crate::flat::FLAG_UNITED_NATIONS as &crate::Emoji),
-					"ag_united_states" => Some(&crate::flat::FLAG_UNITED_STATES as &crate::Emoji),
-					"ag_uruguay" => Some(&crate::flat::FLAG_URUGUAY as &crate::Emoji),
-					"ag_us_outlying_islands" => Some(&crate::flat::FLAG_US_OUTLYING_ISLANDS as &crate::Emoji),
-					"ag_us_virgin_islands" => Some(&crate::flat::FLAG_US_VIRGIN_ISLANDS as &crate::Emoji),
-					"ag_uzbekistan" => Some(&crate::flat::FLAG_UZBEKISTAN as &crate::Emoji),
-					"ag_vanuatu" => Some(&crate::flat::FLAG_VANUATU as &crate::Emoji),
-					"ag_vatican_city" => Some(&crate::flat::FLAG_VATICAN_CITY as &crate::Emoji),
-					"ag_venezuela" => Some(&crate::flat::FLAG_VENEZUELA as &crate::Emoji),
-					"ag_vietnam" => Some(&crate::flat::FLAG_VIETNAM as &crate::Emoji),
-					"ag_wales" => Some(&crate::flat::FLAG_WALES as &crate::Emoji),
-					"ag_wallis_and_futuna" => Some(&crate::flat::FLAG_WALLIS_AND_FUTUNA as &crate::Emoji),
-					"ag_western_sahara" => Some(&crate::flat::FLAG_WESTERN_SAHARA as &crate::Emoji),
-					"ag_yemen" => Some(&crate::flat::FLAG_YEMEN as &crate::Emoji),
-					"ag_zambia" => Some(&crate::flat::FLAG_ZAMBIA as &crate::Emoji),
-					"ag_zimbabwe" => Some(&crate::flat::FLAG_ZIMBABWE as &crate::Emoji),
-					"ags" => Some(&crate::flat::CARP_STREAMER as &crate::Emoji),
-					"amingo" => Some(&crate::flat::FLAMINGO as &crate::Emoji),
-					"ashlight" => Some(&crate::flat::FLASHLIGHT as &crate::Emoji),
-					"at_shoe" => Some(&crate::flat::FLAT_SHOE as &crate::Emoji),
-					"atbread" => Some(&crate::flat::FLATBREAD as &crate::Emoji),
-					"eur_de_lis" => Some(&crate::flat::FLEUR_DE_LIS as &crate::Emoji),
-					"exed_biceps" => Some(&crate::flat::FLEXED_BICEPS.default as &crate::Emoji),
-					"ight_arrival" => Some(&crate::flat::AIRPLANE_ARRIVAL as &crate::Emoji),
-					"ight_departure" => Some(&crate::flat::AIRPLANE_DEPARTURE as &crate::Emoji),
-					"ipper" => Some(&crate::flat::DOLPHIN as &crate::Emoji),
-					"oppy_disk" => Some(&crate::flat::FLOPPY_DISK as &crate::Emoji),
-					"ower_playing_cards" => Some(&crate::flat::FLOWER_PLAYING_CARDS as &crate::Emoji),
-					"ushed" => Some(&crate::flat::FLUSHED_FACE as &crate::Emoji),
-					"ushed_face" => Some(&crate::flat::FLUSHED_FACE as &crate::Emoji),
-					"y" => Some(&crate::flat::FLY as &crate::Emoji),
-					"ying_disc" => Some(&crate::flat::FLYING_DISC as &crate::Emoji),
-					"ying_saucer" => Some(&crate::flat::FLYING_SAUCER as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"g" => Some(&crate::flat::FOG as &crate::Emoji),
-					"ggy" => Some(&crate::flat::FOGGY as &crate::Emoji),
-					"lded_hands" => Some(&crate::flat::FOLDED_HANDS.default as &crate::Emoji),
-					"ndue" => Some(&crate::flat::FONDUE as &crate::Emoji),
-					"ot" => Some(&crate::flat::FOOT.default as &crate::Emoji),
-					"otball" => Some(&crate::flat::AMERICAN_FOOTBALL as &crate::Emoji),
-					"otprints" => Some(&crate::flat::FOOTPRINTS as &crate::Emoji),
-					"rk_and_knife" => Some(&crate::flat::FORK_AND_KNIFE as &crate::Emoji),
-					"rk_and_knife_with_plate" => Some(&crate::flat::FORK_AND_KNIFE_WITH_PLATE as &crate::Emoji),
-					"rtune_cookie" => Some(&crate::flat::FORTUNE_COOKIE as &crate::Emoji),
-					"untain" => Some(&crate::flat::FOUNTAIN as &crate::Emoji),
-					"untain_pen" => Some(&crate::flat::FOUNTAIN_PEN as &crate::Emoji),
-					"ur" => Some(&crate::flat::KEYCAP_4 as &crate::Emoji),
-					"ur_leaf_clover" => Some(&crate::flat::FOUR_LEAF_CLOVER as &crate::Emoji),
-					"ur_o_clock" => Some(&crate::flat::FOUR_O_CLOCK as &crate::Emoji),
-					"ur_thirty" => Some(&crate::flat::FOUR_THIRTY as &crate::Emoji),
-					"x" => Some(&crate::flat::FOX as &crate::Emoji),
-					"x_face" => Some(&crate::flat::FOX as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"" => Some(&crate::flat::FLAG_FRANCE as &crate::Emoji),
-					"amed_picture" => Some(&crate::flat::FRAMED_PICTURE as &crate::Emoji),
-					"ee" => Some(&crate::flat::FREE_BUTTON as &crate::Emoji),
-					"ee_button" => Some(&crate::flat::FREE_BUTTON as &crate::Emoji),
-					"ench_fries" => Some(&crate::flat::FRENCH_FRIES as &crate::Emoji),
-					"ench_guiana" => Some(&crate::flat::FLAG_FRENCH_GUIANA as &crate::Emoji),
-					"ench_polynesia" => Some(&crate::flat::FLAG_FRENCH_POLYNESIA as &crate::Emoji),
-					"ench_southern_territories" => Some(&crate::flat::FLAG_FRENCH_SOUTHERN_TERRITORIES as &crate::Emoji),
-					"ied_egg" => Some(&crate::flat::COOKING as &crate::Emoji),
-					"ied_shrimp" => Some(&crate::flat::FRIED_SHRIMP as &crate::Emoji),
-					"ies" => Some(&crate::flat::FRENCH_FRIES as &crate::Emoji),
-					"og" => Some(&crate::flat::FROG as &crate::Emoji),
-					"ont_facing_baby_chick" => Some(&crate::flat::FRONT_FACING_BABY_CHICK as &crate::Emoji),
-					"owning" => Some(&crate::flat::FROWNING_FACE_WITH_OPEN_MOUTH as &crate::Emoji),
-					"owning_face" => Some(&crate::flat::FROWNING_FACE as &crate::Emoji),
-					"owning_face_with_open_mouth" => Some(&crate::flat::FROWNING_FACE_WITH_OPEN_MOUTH as &crate::Emoji),
-					"owning_man" => Some(&crate::flat::PERSON_FROWNING.gender(Gender::Male).default as &crate::Emoji),
-					"owning_person" => Some(&crate::flat::PERSON_FROWNING.default.default as &crate::Emoji),
-					"owning_woman" => Some(&crate::flat::PERSON_FROWNING.gender(Gender::Female).default as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"" => Some(&crate::flat::MIDDLE_FINGER.default as &crate::Emoji),
-					"el_pump" => Some(&crate::flat::FUEL_PUMP as &crate::Emoji),
-					"elpump" => Some(&crate::flat::FUEL_PUMP as &crate::Emoji),
-					"ll_moon" => Some(&crate::flat::FULL_MOON as &crate::Emoji),
-					"ll_moon_face" => Some(&crate::flat::FULL_MOON_FACE as &crate::Emoji),
-					"ll_moon_with_face" => Some(&crate::flat::FULL_MOON_FACE as &crate::Emoji),
-					"neral_urn" => Some(&crate::flat::FUNERAL_URN as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'g' => match sec {
-				b'a' => match rest {
-					"bon" => Some(&crate::flat::FLAG_GABON as &crate::Emoji),
-					"mbia" => Some(&crate::flat::FLAG_GAMBIA as &crate::Emoji),
-					"me_die" => Some(&crate::flat::GAME_DIE as &crate::Emoji),
-					"rlic" => Some(&crate::flat::GARLIC as &crate::Emoji),
-					_ => None,
-				},
-				b'b' => match rest {
-					"" => Some(&crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"ar" => Some(&crate::flat::GEAR as &crate::Emoji),
-					"m" => Some(&crate::flat::GEM_STONE as &crate::Emoji),
-					"m_stone" => Some(&crate::flat::GEM_STONE as &crate::Emoji),
-					"mini" => Some(&crate::flat::GEMINI as &crate::Emoji),
-					"nie" => Some(&crate::flat::GENIE.default as &crate::Emoji),
-					"nie_man" => Some(&crate::flat::GENIE.gender(Gender::Male) as &crate::Emoji),
-					"nie_woman" => Some(&crate::flat::GENIE.gender(Gender::Female) as &crate::Emoji),
-					"orgia" => Some(&crate::flat::FLAG_GEORGIA as &crate::Emoji),
-					_ => None,
-				},
-				b'h' => match rest {
-					"ana" => Some(&crate::flat::FLAG_GHANA as &crate::Emoji),
-					"ost" => Some(&crate::flat::GHOST as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"braltar" => Some(&crate::flat::FLAG_GIBRALTAR as &crate::Emoji),
-					"ft" => Some(&crate::flat::WRAPPED_GIFT as &crate::Emoji),
-					"ft_heart" => Some(&crate::flat::HEART_WITH_RIBBON as &crate::Emoji),
-					"raffe" => Some(&crate::flat::GIRAFFE as &crate::Emoji),
-					"rl" => Some(&crate::flat::GIRL.default as &crate::Emoji),
-					_ => None,
-				},
-				b'l' => match rest {
-					"ass_of_milk" => Some(&crate::flat::GLASS_OF_MILK as &crate::Emoji),
-					"asses" => Some(&crate::flat::GLASSES as &crate::Emoji),
-					"obe_showing_americas" => Some(&crate::flat::GLOBE_SHOWING_AMERICAS as &crate::Emoji),
-					"obe_showing_asia_australia" => Some(&crate::flat::GLOBE_SHOWING_ASIA_AUSTRALIA as &crate::Emoji),
-					"obe_showing_europe_africa" => Some(&crate::flat::GLOBE_SHOWING_EUROPE_AFRICA as &crate::Emoji),
-					"obe_with_meridians" => Some(&crate::flat::GLOBE_WITH_MERIDIANS as &crate::Emoji),
-					"oves" => Some(&crate::flat::GLOVES as &crate::Emoji),
-					"owing_star" => Some(&crate::flat::GLOWING_STAR as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"al_net" => Some(&crate::flat::GOAL_NET as &crate::Emoji),
-					"at" => Some(&crate::flat::GOAT as &crate::Emoji),
-					"blin" => Some(&crate::flat::GOBLIN as &crate::Emoji),
-					"ggles" => Some(&crate::flat::GOGGLES as &crate::Emoji),
-					"lf" => Some(&crate::flat::FLAG_IN_HOLE as &crate::Emoji),
-					"lfing" => Some(&crate::flat::PERSON_GOLFING.default.default as &crate::Emoji),
-					"lfing_man" => Some(&crate::flat::PERSON_GOLFING.gender(Gender::Male).default as &crate::Emoji),
-					"lfing_woman" => Some(&crate::flat::PERSON_GOLFING.gender(Gender::Female).default as &crate::Emoji),
-					"rilla" => Some(&crate::flat::GORILLA as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"aduation_cap" => Some(&crate::flat::GRADUATION_CAP as &crate::Emoji),
-					"apes" => Some(&crate::flat::GRAPES as &crate::Emoji),
-					"eece" => Some(&crate::flat::FLAG_GREECE as &crate::Emoji),
-					"een_apple" => Some(&crate::flat::GREEN_APPLE as &crate::Emoji),
-					"een_book" => Some(&crate::flat::GREEN_BOOK as &crate::Emoji),
-					"een_circle" => Some(&crate::flat::GREEN_CIRCLE as &crate::Emoji),
-					"een_heart" => Some(&crate::flat::GREEN_HEART as &crate::Emoji),
-					"een_salad" => Some(&crate::flat::GREEN_SALAD as &crate::Emoji),
-					"een_square" => Some(&crate::flat::GREEN_SQUARE as &crate::Emoji),
-					"eenland" => Some(&crate::flat::FLAG_GREENLAND as &crate::Emoji),
-					"enada" => Some(&crate::flat::FLAG_GRENADA as &crate::Emoji),
-					"ey_exclamation" => Some(&crate::flat::WHITE_EXCLAMATION_MARK as &crate::Emoji),
-					"ey_question" => Some(&crate::flat::WHITE_QUESTION_MARK as &crate::Emoji),
-					"imacing" => Some(&crate::flat::GRIMACING_FACE as &crate::Emoji),
-					"imacing_face" => Some(&crate::flat::GRIMACING_FACE as &crate::Emoji),
-					"in" => Some(&crate::flat::BEAMING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-					"inning" => Some(&crate::flat::GRINNING_FACE as &crate::Emoji),
-					"inning_cat" => Some(&crate::flat::GRINNING_CAT as &crate::Emoji),
-					"inning_cat_with_smiling_eyes" => Some(&crate::flat::GRINNING_CAT_WITH_SMILING_EYES as &crate::Emoji),
-					"inning_face" => Some(&crate::flat::GRINNING_FACE as &crate::Emoji),
-					"inning_face_with_big_eyes" => Some(&crate::flat::GRINNING_FACE_WITH_BIG_EYES as &crate::Emoji),
-					"inning_face_with_smiling_eyes" => Some(&crate::flat::GRINNING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-					"inning_face_with_sweat" => Some(&crate::flat::GRINNING_FACE_WITH_SWEAT as &crate::Emoji),
-					"inning_squinting_face" => Some(&crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
-					"owing_heart" => Some(&crate::flat::GROWING_HEART as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"adeloupe" => Some(&crate::flat::FLAG_GUADELOUPE as &crate::Emoji),
-					"am" => Some(&crate::flat::FLAG_GUAM as &crate::Emoji),
-					"ard" => Some(&crate::flat::GUARD.default.default as &crate::Emoji),
-					"ardsman" => Some(&crate::flat::GUARD.gender(Gender::Male).default as &crate::Emoji),
-					"ardswoman" => Some(&crate::flat::GUARD.gender(Gender::Female).default as &crate::Emoji),
-					"atemala" => Some(&crate::flat::FLAG_GUATEMALA as &crate::Emoji),
-					"ernsey" => Some(&crate::flat::FLAG_GUERNSEY as &crate::Emoji),
-					"ide_dog" => Some(&crate::flat::GUIDE_DOG as &crate::Emoji),
-					"inea" => Some(&crate::flat::FLAG_GUINEA as &crate::Emoji),
-					"inea_bissau" => Some(&crate::flat::FLAG_GUINEA_BISSAU as &crate::Emoji),
-					"itar" => Some(&crate::flat::GUITAR as &crate::Emoji),
-					"n" => Some(&crate::flat::WATER_PISTOL as &crate::Emoji),
-					"yana" => Some(&crate::flat::FLAG_GUYANA as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'h' => match sec {
-				b'a' => match rest {
-					"ircut" => Some(&crate::flat::PERSON_GETTING_HAIRCUT.default.default as &crate::Emoji),
-					"ircut_man" => Some(&crate::flat::PERSON_GETTING_HAIRCUT.gender(Gender::Male).default as &crate::Emoji),
-					"ircut_woman" => Some(&crate::flat::PERSON_GETTING_HAIRCUT.gender(Gender::Female).default as &crate::Emoji),
-					"iti" => Some(&crate::flat::FLAG_HAITI as &crate::Emoji),
-					"mburger" => Some(&crate::flat::HAMBURGER as &crate::Emoji),
-					"mmer" => Some(&crate::flat::HAMMER as &crate::Emoji),
-					"mmer_and_pick" => Some(&crate::flat::HAMMER_AND_PICK as &crate::Emoji),
-					"mmer_and_wrench" => Some(&crate::flat::HAMMER_AND_WRENCH as &crate::Emoji),
-					"mster" => Some(&crate::flat::HAMSTER as &crate::Emoji),
-					"nd" => Some(&crate::flat::RAISED_HAND.default as &crate::Emoji),
-					"nd_over_mouth" => Some(&crate::flat::FACE_WITH_HAND_OVER_MOUTH as &crate::Emoji),
-					"nd_with_fingers_splayed" => Some(&crate::flat::HAND_WITH_FINGERS_SPLAYED.default as &crate::Emoji),
-					"ndbag" => Some(&crate::flat::HANDBAG as &crate::Emoji),
-					"ndball_person" => Some(&crate::flat::PERSON_PLAYING_HANDBALL.default.default as &crate::Emoji),
-					"ndshake" => Some(&crate::flat::HANDSHAKE as &crate::Emoji),
-					"nkey" => Some(&crate::flat::PILE_OF_POO as &crate::Emoji),
-					"sh" => Some(&crate::flat::KEYCAP_HASH as &crate::Emoji),
-					"tched_chick" => Some(&crate::flat::FRONT_FACING_BABY_CHICK as &crate::Emoji),
-					"tching_chick" => Some(&crate::flat::HATCHING_CHICK as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"adphone" => Some(&crate::flat::HEADPHONE as &crate::Emoji),
-					"adphones" => Some(&crate::flat::HEADPHONE as &crate::Emoji),
-					"adstone" => Some(&crate::flat::HEADSTONE as &crate::Emoji),
-					"alth_worker" => Some(&crate::flat::HEALTH_WORKER.default.default as &crate::Emoji),
-					"ar_no_evil" => Some(&crate::flat::HEAR_NO_EVIL_MONKEY as &crate::Emoji),
-					"ar_no_evil_monkey" => Some(&crate::flat::HEAR_NO_EVIL_MONKEY as &crate::Emoji),
-					"ard_mcdonald_islands" => Some(&crate::flat::FLAG_HEARD_AND_MCDONALD_ISLANDS as &crate::Emoji),
-					"art" => Some(&crate::flat::RED_HEART as &crate::Emoji),
-					"art_decoration" => Some(&crate::flat::HEART_DECORATION as &crate::Emoji),
-					"art_exclamation" => Some(&crate::flat::HEART_EXCLAMATION as &crate::Emoji),
-					"art_eyes" => Some(&crate::flat::SMILING_FACE_WITH_HEART_EYES as &crate::Emoji),
-					"art_eyes_cat" => Some(&crate::flat::SMILING_CAT_WITH_HEART_EYES as &crate::Emoji),
-					"art_on_fire" => Some(&crate::flat::HEART_ON_FIRE as &crate::Emoji),
-					"art_suit" => Some(&crate::flat::HEART_SUIT as &crate::Emoji),
-					"art_with_arrow" => Some(&crate::flat::HEART_WITH_ARROW as &crate::Emoji),
-					"art_with_ribbon" => Some(&crate::flat::HEART_WITH_RIBBON as &crate::Emoji),
-					"artbeat" => Some(&crate::flat::BEATING_HEART as &crate::Emoji),
-					"artpulse" => Some(&crate::flat::GROWING_HEART as &crate::Emoji),
-					"arts" => Some(&crate::flat::HEART_SUIT as &crate::Emoji),
-					"avy_check_mark" => Some(&crate::flat::CHECK_MARK as &crate::Emoji),
-					"avy_division_sign" => Some(&crate::flat::DIVIDE as &crate::Emoji),
-					"avy_dollar_sign" => Some(&crate::flat::HEAVY_DOLLAR_SIGN as &crate::Emoji),
-					"avy_exclamation_mark" => Some(&crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
-					"avy_heart_exclamation" => Some(&crate::flat::HEART_EXCLAMATION as &crate::Emoji),
-					"avy_minus_sign" => Some(&crate::flat::MINUS as &crate::Emoji),
-					"avy_multiplication_x" => Some(&crate::flat::MULTIPLY as &crate::Emoji),
-					"avy_plus_sign" => Some(&crate::flat::PLUS as &crate::Emoji),
-					"dgehog" => Some(&crate::flat::HEDGEHOG as &crate::Emoji),
-					"licopter" => Some(&crate::flat::HELICOPTER as &crate::Emoji),
-					"rb" => Some(&crate::flat::HERB as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"biscus" => Some(&crate::flat::HIBISCUS as &crate::Emoji),
-					"gh_brightness" => Some(&crate::flat::BRIGHT_BUTTON as &crate::Emoji),
-					"gh_heel" => Some(&crate::flat::HIGH_HEELED_SHOE as &crate::Emoji),
-					"gh_heeled_shoe" => Some(&crate::flat::HIGH_HEELED_SHOE as &crate::Emoji),
-					"gh_speed_train" => Some(&crate::flat::HIGH_SPEED_TRAIN as &crate::Emoji),
-					"gh_voltage" => Some(&crate::flat::HIGH_VOLTAGE as &crate::Emoji),
-					"king_boot" => Some(&crate::flat::HIKING_BOOT as &crate::Emoji),
-					"ndu_temple" => Some(&crate::flat::HINDU_TEMPLE as &crate::Emoji),
-					"ppopotamus" => Some(&crate::flat::HIPPOPOTAMUS as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"cho" => Some(&crate::flat::KITCHEN_KNIFE as &crate::Emoji),
-					"le" => Some(&crate::flat::HOLE as &crate::Emoji),
-					"llow_red_circle" => Some(&crate::flat::HOLLOW_RED_CIRCLE as &crate::Emoji),
-					"nduras" => Some(&crate::flat::FLAG_HONDURAS as &crate::Emoji),
-					"ney_pot" => Some(&crate::flat::HONEY_POT as &crate::Emoji),
-					"neybee" => Some(&crate::flat::HONEYBEE as &crate::Emoji),
-					"ng_kong" => Some(&crate::flat::FLAG_HONG_KONG_SAR_CHINA as &crate::Emoji),
-					"ok" => Some(&crate::flat::HOOK as &crate::Emoji),
-					"rizontal_traffic_light" => Some(&crate::flat::HORIZONTAL_TRAFFIC_LIGHT as &crate::Emoji),
-					"rse" => Some(&crate::flat::HORSE as &crate::Emoji),
-					"rse_face" => Some(&crate::flat::HORSE_FACE as &crate::Emoji),
-					"rse_racing" => Some(&crate::flat::HORSE_RACING.default as &crate::Emoji),
-					"spital" => Some(&crate::flat::HOSPITAL as &crate::Emoji),
-					"t_beverage" => Some(&crate::flat::HOT_BEVERAGE as &crate::Emoji),
-					"t_dog" => Some(&crate::flat::HOT_DOG as &crate::Emoji),
-					"t_face" => Some(&crate::flat::HOT_FACE as &crate::Emoji),
-					"t_pepper" => Some(&crate::flat::HOT_PEPPER as &crate::Emoji),
-					"t_springs" => Some(&crate::flat::HOT_SPRINGS as &crate::Emoji),
-					"tdog" => Some(&crate::flat::HOT_DOG as &crate::Emoji),
-					"tel" => Some(&crate::flat::HOTEL as &crate::Emoji),
-					"tsprings" => Some(&crate::flat::HOT_SPRINGS as &crate::Emoji),
-					"urglass" => Some(&crate::flat::HOURGLASS_DONE as &crate::Emoji),
-					"urglass_done" => Some(&crate::flat::HOURGLASS_DONE as &crate::Emoji),
-					"urglass_flowing_sand" => Some(&crate::flat::HOURGLASS_NOT_DONE as &crate::Emoji),
-					"urglass_not_done" => Some(&crate::flat::HOURGLASS_NOT_DONE as &crate::Emoji),
-					"use" => Some(&crate::flat::HOUSE as &crate::Emoji),
-					"use_with_garden" => Some(&crate::flat::HOUSE_WITH_GARDEN as &crate::Emoji),
-					"uses" => Some(&crate::flat::HOUSES as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"gging_face" => Some(&crate::flat::HUGGING_FACE as &crate::Emoji),
-					"gs" => Some(&crate::flat::HUGGING_FACE as &crate::Emoji),
-					"ndred_points" => Some(&crate::flat::HUNDRED_POINTS as &crate::Emoji),
-					"ngary" => Some(&crate::flat::FLAG_HUNGARY as &crate::Emoji),
-					"shed" => Some(&crate::flat::HUSHED_FACE as &crate::Emoji),
-					"shed_face" => Some(&crate::flat::HUSHED_FACE as &crate::Emoji),
-					"t" => Some(&crate::flat::HUT as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'i' => match sec {
-				b'c' => match rest {
-					"e" => Some(&crate::flat::ICE as &crate::Emoji),
-					"e_cream" => Some(&crate::flat::ICE_CREAM as &crate::Emoji),
-					"e_cube" => Some(&crate::flat::ICE as &crate::Emoji),
-					"e_hockey" => Some(&crate::flat::ICE_HOCKEY as &crate::Emoji),
-					"e_skate" => Some(&crate::flat::ICE_SKATE as &crate::Emoji),
-					"ecream" => Some(&crate::flat::SOFT_ICE_CREAM as &crate::Emoji),
-					"eland" => Some(&crate::flat::FLAG_ICELAND as &crate::Emoji),
-					_ => None,
-				},
-				b'd' => match rest {
-					"" => Some(&crate::flat::ID_BUTTON as &crate::Emoji),
-					"_button" => Some(&crate::flat::ID_BUTTON as &crate::Emoji),
-					"eograph_advantage" => Some(&crate::flat::JAPANESE_BARGAIN_BUTTON as &crate::Emoji),
-					_ => None,
-				},
-				b'm' => match rest {
-					"p" => Some(&crate::flat::ANGRY_FACE_WITH_HORNS as &crate::Emoji),
-					_ => None,
-				},
-				b'n' => match rest {
-					"box_tray" => Some(&crate::flat::INBOX_TRAY as &crate::Emoji),
-					"coming_envelope" => Some(&crate::flat::INCOMING_ENVELOPE as &crate::Emoji),
-					"dex_pointing_up" => Some(&crate::flat::INDEX_POINTING_UP.default as &crate::Emoji),
-					"dia" => Some(&crate::flat::FLAG_INDIA as &crate::Emoji),
-					"donesia" => Some(&crate::flat::FLAG_INDONESIA as &crate::Emoji),
-					"finity" => Some(&crate::flat::INFINITY as &crate::Emoji),
-					"formation" => Some(&crate::flat::INFORMATION as &crate::Emoji),
-					"formation_desk_person" => Some(&crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
-					"formation_source" => Some(&crate::flat::INFORMATION as &crate::Emoji),
-					"nocent" => Some(&crate::flat::SMILING_FACE_WITH_HALO as &crate::Emoji),
-					"put_latin_letters" => Some(&crate::flat::INPUT_LATIN_LETTERS as &crate::Emoji),
-					"put_latin_lowercase" => Some(&crate::flat::INPUT_LATIN_LOWERCASE as &crate::Emoji),
-					"put_latin_uppercase" => Some(&crate::flat::INPUT_LATIN_UPPERCASE as &crate::Emoji),
-					"put_numbers" => Some(&crate::flat::INPUT_NUMBERS as &crate::Emoji),
-					"put_symbols" => Some(&crate::flat::INPUT_SYMBOLS as &crate::Emoji),
-					"terrobang" => Some(&crate::flat::EXCLAMATION_QUESTION_MARK as &crate::Emoji),
-					_ => None,
-				},
-				b'p' => match rest {
-					"hone" => Some(&crate::flat::MOBILE_PHONE as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"an" => Some(&crate::flat::FLAG_IRAN as &crate::Emoji),
-					"aq" => Some(&crate::flat::FLAG_IRAQ as &crate::Emoji),
-					"eland" => Some(&crate::flat::FLAG_IRELAND as &crate::Emoji),
-					_ => None,
-				},
-				b's' => match rest {
-					"le_of_man" => Some(&crate::flat::FLAG_ISLE_OF_MAN as &crate::Emoji),
-					"rael" => Some(&crate::flat::FLAG_ISRAEL as &crate::Emoji),
-					_ => None,
-				},
-				b't' => match rest {
-					"" => Some(&crate::flat::FLAG_ITALY as &crate::Emoji),
-					_ => None,
-				},
-				b'z' => match rest {
-					"akaya_lantern" => Some(&crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'j' => match sec {
-				b'a' => match rest {
-					"ck_o_lantern" => Some(&crate::flat::JACK_O_LANTERN as &crate::Emoji),
-					"maica" => Some(&crate::flat::FLAG_JAMAICA as &crate::Emoji),
-					"pan" => Some(&crate::flat::MAP_OF_JAPAN as &crate::Emoji),
-					"panese_acceptable_button" => Some(&crate::flat::JAPANESE_ACCEPTABLE_BUTTON as &crate::Emoji),
-					"panese_application_button" => Some(&crate::flat::JAPANESE_APPLICATION_BUTTON as &crate::Emoji),
-					"panese_bargain_button" => Some(&crate::flat::JAPANESE_BARGAIN_BUTTON as &crate::Emoji),
-					"panese_castle" => Some(&crate::flat::JAPANESE_CASTLE as &crate::Emoji),
-					"panese_congratulations_button" => Some(&crate::flat::JAPANESE_CONGRATULATIONS_BUTTON as &crate::Emoji),
-					"panese_discount_button" => Some(&crate::flat::JAPANESE_DISCOUNT_BUTTON as &crate::Emoji),
-					"panese_dolls" => Some(&crate::flat::JAPANESE_DOLLS as &crate::Emoji),
-					"panese_free_of_charge_button" => Some(&crate::flat::JAPANESE_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
-					"panese_goblin" => Some(&crate::flat::GOBLIN as &crate::Emoji),
-					"panese_here_button" => Some(&crate::flat::JAPANESE_HERE_BUTTON as &crate::Emoji),
-					"panese_monthly_amount_button" => Some(&crate::flat::JAPANESE_MONTHLY_AMOUNT_BUTTON as &crate::Emoji),
-					"panese_no_vacancy_button" => Some(&crate::flat::JAPANESE_NO_VACANCY_BUTTON as &crate::Emoji),
-					"panese_not_free_of_charge_button" => Some(&crate::flat::JAPANESE_NOT_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
-					"panese_ogre" => Some(&crate::flat::OGRE as &crate::Emoji),
-					"panese_open_for_business_button" => Some(&crate::flat::JAPANESE_OPEN_FOR_BUSINESS_BUTTON as &crate::Emoji),
-					"panese_passing_grade_button" => Some(&crate::flat::JAPANESE_PASSING_GRADE_BUTTON as &crate::Emoji),
-					"panese_post_office" => Some(&crate::flat::JAPANESE_POST_OFFICE as &crate::Emoji),
-					"panese_prohibited_button" => Some(&crate::flat::JAPANESE_PROHIBITED_BUTTON as &crate::Emoji),
-					"panese_reserved_button" => Some(&crate::flat::JAPANESE_RESERVED_BUTTON as &crate::Emoji),
-					"panese_secret_button" => Some(&crate::flat::JAPANESE_SECRET_BUTTON as &crate::Emoji),
-					"panese_service_charge_button" => Some(&crate::flat::JAPANESE_SERVICE_CHARGE_BUTTON as &crate::Emoji),
-					"panese_symbol_for_beginner" => Some(&crate::flat::JAPANESE_SYMBOL_FOR_BEGINNER as &crate::Emoji),
-					"panese_vacancy_button" => Some(&crate::flat::JAPANESE_VACANCY_BUTTON as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"ans" => Some(&crate::flat::JEANS as &crate::Emoji),
-					"rsey" => Some(&crate::flat::FLAG_JERSEY as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"gsaw" => Some(&crate::flat::PUZZLE_PIECE as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"ker" => Some(&crate::flat::JOKER as &crate::Emoji),
-					"rdan" => Some(&crate::flat::FLAG_JORDAN as &crate::Emoji),
-					"y" => Some(&crate::flat::FACE_WITH_TEARS_OF_JOY as &crate::Emoji),
-					"y_cat" => Some(&crate::flat::CAT_WITH_TEARS_OF_JOY as &crate::Emoji),
-					"ystick" => Some(&crate::flat::JOYSTICK as &crate::Emoji),
-					_ => None,
-				},
-				b'p' => match rest {
-					"" => Some(&crate::flat::FLAG_JAPAN as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"dge" => Some(&crate::flat::JUDGE.default.default as &crate::Emoji),
-					"ggling_person" => Some(&crate::flat::PERSON_JUGGLING.default.default as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'k' => match sec {
-				b'a' => match rest {
-					"aba" => Some(&crate::flat::KAABA as &crate::Emoji),
-					"ngaroo" => Some(&crate::flat::KANGAROO as &crate::Emoji),
-					"zakhstan" => Some(&crate::flat::FLAG_KAZAKHSTAN as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"nya" => Some(&crate::flat::FLAG_KENYA as &crate::Emoji),
-					"y" => Some(&crate::flat::KEY as &crate::Emoji),
-					"yboard" => Some(&crate::flat::KEYBOARD as &crate::Emoji),
-					"ycap_0" => Some(&crate::flat::KEYCAP_0 as &crate::Emoji),
-					"ycap_1" => Some(&crate::flat::KEYCAP_1 as &crate::Emoji),
-					"ycap_10" => Some(&crate::flat::KEYCAP_10 as &crate::Emoji),
-					"ycap_2" => Some(&crate::flat::KEYCAP_2 as &crate::Emoji),
-					"ycap_3" => Some(&crate::flat::KEYCAP_3 as &crate::Emoji),
-					"ycap_4" => Some(&crate::flat::KEYCAP_4 as &crate::Emoji),
-					"ycap_5" => Some(&crate::flat::KEYCAP_5 as &crate::Emoji),
-					"ycap_6" => Some(&crate::flat::KEYCAP_6 as &crate::Emoji),
-					"ycap_7" => Some(&crate::flat::KEYCAP_7 as &crate::Emoji),
-					"ycap_8" => Some(&crate::flat::KEYCAP_8 as &crate::Emoji),
-					"ycap_9" => Some(&crate::flat::KEYCAP_9 as &crate::Emoji),
-					"ycap_asterisk" => Some(&crate::flat::KEYCAP_ASTERISK as &crate::Emoji),
-					"ycap_hash" => Some(&crate::flat::KEYCAP_HASH as &crate::Emoji),
-					"ycap_ten" => Some(&crate::flat::KEYCAP_10 as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"ck_scooter" => Some(&crate::flat::KICK_SCOOTER as &crate::Emoji),
-					"mono" => Some(&crate::flat::KIMONO as &crate::Emoji),
-					"ribati" => Some(&crate::flat::FLAG_KIRIBATI as &crate::Emoji),
-					"ss" => Some(&crate::flat::KISS.default.default as &crate::Emoji),
-					"ss_mark" => Some(&crate::flat::KISS_MARK as &crate::Emoji),
-					"ssing" => Some(&crate::flat::KISSING_FACE as &crate::Emoji),
-					"ssing_cat" => Some(&crate::flat::KISSING_CAT as &crate::Emoji),
-					"ssing_closed_eyes" => Some(&crate::flat::KISSING_FACE_WITH_CLOSED_EYES as &crate::Emoji),
-					"ssing_face" => Some(&crate::flat::KISSING_FACE as &crate::Emoji),
-					"ssing_face_with_closed_eyes" => Some(&crate::flat::KISSING_FACE_WITH_CLOSED_EYES as &crate::Emoji),
-					"ssing_face_with_smiling_eyes" => Some(&crate::flat::KISSING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-					"ssing_heart" => Some(&crate::flat::FACE_BLOWING_A_KISS as &crate::Emoji),
-					"ssing_smiling_eyes" => Some(&crate::flat::KISSING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-					"tchen_knife" => Some(&crate::flat::KITCHEN_KNIFE as &crate::Emoji),
-					"te" => Some(&crate::flat::KITE as &crate::Emoji),
-					"wi_fruit" => Some(&crate::flat::KIWI_FRUIT as &crate::Emoji),
-					_ => None,
-				},
-				b'n' => match rest {
-					"eeling_man" => Some(&crate::flat::PERSON_KNEELING.gender(Gender::Male).default as &crate::Emoji),
-					"eeling_person" => Some(&crate::flat::PERSON_KNEELING.default.default as &crate::Emoji),
-					"eeling_woman" => Some(&crate::flat::PERSON_KNEELING.gender(Gender::Female).default as &crate::Emoji),
-					"ife" => Some(&crate::flat::KITCHEN_KNIFE as &crate::Emoji),
-					"ocked_out_face" => Some(&crate::flat::KNOCKED_OUT_FACE as &crate::Emoji),
-					"ot" => Some(&crate::flat::KNOT as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"ala" => Some(&crate::flat::KOALA as &crate::Emoji),
-					"ko" => Some(&crate::flat::JAPANESE_HERE_BUTTON as &crate::Emoji),
-					"sovo" => Some(&crate::flat::FLAG_KOSOVO as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"" => Some(&crate::flat::FLAG_SOUTH_KOREA as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"wait" => Some(&crate::flat::FLAG_KUWAIT as &crate::Emoji),
-					_ => None,
-				},
-				b'y' => match rest {
-					"rgyzstan" => Some(&crate::flat::FLAG_KYRGYZSTAN as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'l' => match sec {
-				b'a' => match rest {
-					"b_coat" => Some(&crate::flat::LAB_COAT as &crate::Emoji),
-					"bel" => Some(&crate::flat::LABEL as &crate::Emoji),
-					"crosse" => Some(&crate::flat::LACROSSE as &crate::Emoji),
-					"dder" => Some(&crate::flat::LADDER as &crate::Emoji),
-					"dy_beetle" => Some(&crate::flat::LADY_BEETLE as &crate::Emoji),
-					"ntern" => Some(&crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
-					"os" => Some(&crate::flat::FLAG_LAOS as &crate::Emoji),
-					"ptop" => Some(&crate::flat::LAPTOP as &crate::Emoji),
-					"rge_blue_circle" => Some(&crate::flat::BLUE_CIRCLE as &crate::Emoji),
-					"rge_blue_diamond" => Some(&crate::flat::LARGE_BLUE_DIAMOND as &crate::Emoji),
-					"rge_orange_diamond" => Some(&crate::flat::LARGE_ORANGE_DIAMOND as &crate::Emoji),
-					"st_quarter_moon" => Some(&crate::flat::LAST_QUARTER_MOON as &crate::Emoji),
-					"st_quarter_moon_face" => Some(&crate::flat::LAST_QUARTER_MOON_FACE as &crate::Emoji),
-					"st_quarter_moon_with_face" => Some(&crate::flat::LAST_QUARTER_MOON_FACE as &crate::Emoji),
-					"st_track_button" => Some(&crate::flat::LAST_TRACK_BUTTON as &crate::Emoji),
-					"tin_cross" => Some(&crate::flat::LATIN_CROSS as &crate::Emoji),
-					"tvia" => Some(&crate::flat::FLAG_LATVIA as &crate::Emoji),
-					"ughing" => Some(&crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"af_fluttering_in_wind" => Some(&crate::flat::LEAF_FLUTTERING_IN_WIND as &crate::Emoji),
-					"afy_green" => Some(&crate::flat::LEAFY_GREEN as &crate::Emoji),
-					"aves" => Some(&crate::flat::LEAF_FLUTTERING_IN_WIND as &crate::Emoji),
-					"banon" => Some(&crate::flat::FLAG_LEBANON as &crate::Emoji),
-					"dger" => Some(&crate::flat::LEDGER as &crate::Emoji),
-					"ft_arrow" => Some(&crate::flat::LEFT_ARROW as &crate::Emoji),
-					"ft_arrow_curving_right" => Some(&crate::flat::LEFT_ARROW_CURVING_RIGHT as &crate::Emoji),
-					"ft_facing_fist" => Some(&crate::flat::LEFT_FACING_FIST.default as &crate::Emoji),
-					"ft_luggage" => Some(&crate::flat::LEFT_LUGGAGE as &crate::Emoji),
-					"ft_right_arrow" => Some(&crate::flat::LEFT_RIGHT_ARROW as &crate::Emoji),
-					"ft_speech_bubble" => Some(&crate::flat::LEFT_SPEECH_BUBBLE as &crate::Emoji),
-					"ftwards_arrow_with_hook" => Some(&crate::flat::RIGHT_ARROW_CURVING_LEFT as &crate::Emoji),
-					"g" => Some(&crate::flat::LEG.default as &crate::Emoji),
-					"mon" => Some(&crate::flat::LEMON as &crate::Emoji),
-					"o" => Some(&crate::flat::LEO as &crate::Emoji),
-					"opard" => Some(&crate::flat::LEOPARD as &crate::Emoji),
-					"sotho" => Some(&crate::flat::FLAG_LESOTHO as &crate::Emoji),
-					"vel_slider" => Some(&crate::flat::LEVEL_SLIDER as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"beria" => Some(&crate::flat::FLAG_LIBERIA as &crate::Emoji),
-					"bra" => Some(&crate::flat::LIBRA as &crate::Emoji),
-					"bya" => Some(&crate::flat::FLAG_LIBYA as &crate::Emoji),
-					"echtenstein" => Some(&crate::flat::FLAG_LIECHTENSTEIN as &crate::Emoji),
-					"ght_bulb" => Some(&crate::flat::LIGHT_BULB as &crate::Emoji),
-					"ght_rail" => Some(&crate::flat::LIGHT_RAIL as &crate::Emoji),
-					"ght_skin_tone" => Some(&crate::flat::LIGHT_SKIN_TONE as &crate::Emoji),
-					"nk" => Some(&crate::flat::LINK as &crate::Emoji),
-					"nked_paperclips" => Some(&crate::flat::LINKED_PAPERCLIPS as &crate::Emoji),
-					"on" => Some(&crate::flat::LION as &crate::Emoji),
-					"ps" => Some(&crate::flat::MOUTH as &crate::Emoji),
-					"pstick" => Some(&crate::flat::LIPSTICK as &crate::Emoji),
-					"thuania" => Some(&crate::flat::FLAG_LITHUANIA as &crate::Emoji),
-					"tter_in_bin_sign" => Some(&crate::flat::LITTER_IN_BIN_SIGN as &crate::Emoji),
-					"zard" => Some(&crate::flat::LIZARD as &crate::Emoji),
-					_ => None,
-				},
-				b'l' => match rest {
-					"ama" => Some(&crate::flat::LLAMA as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"bster" => Some(&crate::flat::LOBSTER as &crate::Emoji),
-					"ck" => Some(&crate::flat::LOCKED as &crate::Emoji),
-					"ck_with_ink_pen" => Some(&crate::flat::LOCKED_WITH_PEN as &crate::Emoji),
-					"cked" => Some(&crate::flat::LOCKED as &crate::Emoji),
-					"cked_with_key" => Some(&crate::flat::LOCKED_WITH_KEY as &crate::Emoji),
-					"cked_with_pen" => Some(&crate::flat::LOCKED_WITH_PEN as &crate::Emoji),
-					"comotive" => Some(&crate::flat::LOCOMOTIVE as &crate::Emoji),
-					"llipop" => Some(&crate::flat::LOLLIPOP as &crate::Emoji),
-					"ng_drum" => Some(&crate::flat::LONG_DRUM as &crate::Emoji),
-					"op" => Some(&crate::flat::DOUBLE_CURLY_LOOP as &crate::Emoji),
-					"tion_bottle" => Some(&crate::flat::LOTION_BOTTLE as &crate::Emoji),
-					"tus_position" => Some(&crate::flat::PERSON_IN_LOTUS_POSITION.default.default as &crate::Emoji),
-					"tus_position_man" => Some(&crate::flat::PERSON_IN_LOTUS_POSITION.gender(Gender::Male).default as &crate::Emoji),
-					"tus_position_woman" => Some(&crate::flat::PERSON_IN_LOTUS_POSITION.gender(Gender::Female).default as &crate::Emoji),
-					"ud_sound" => Some(&crate::flat::SPEAKER_HIGH_VOLUME as &crate::Emoji),
-					"udly_crying_face" => Some(&crate::flat::LOUDLY_CRYING_FACE as &crate::Emoji),
-					"udspeaker" => Some(&crate::flat::LOUDSPEAKER as &crate::Emoji),
-					"ve_hotel" => Some(&crate::flat::LOVE_HOTEL as &crate::Emoji),
-					"ve_letter" => Some(&crate::flat::LOVE_LETTER as &crate::Emoji),
-					"ve_you_gesture" => Some(&crate::flat::LOVE_YOU_GESTURE.default as &crate::Emoji),
-					"w_brightness" => Some(&crate::flat::DIM_BUTTON as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"ggage" => Some(&crate::flat::LUGGAGE as &crate::Emoji),
-					"ngs" => Some(&crate::flat::LUNGS as &crate::Emoji),
-					"xembourg" => Some(&crate::flat::FLAG_LUXEMBOURG as &crate::Emoji),
-					_ => None,
-				},
-				b'y' => match rest {
-					"ing_face" => Some(&crate::flat::LYING_FACE as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'm' => match sec {
-				b'a' => match rest {
-					"cau" => Some(&crate::flat::FLAG_MACAO_SAR_CHINA as &crate::Emoji),
-					"cedonia" => Some(&crate::flat::FLAG_NORTH_MACEDONIA as &crate::Emoji),
-					"dagascar" => Some(&crate::flat::FLAG_MADAGASCAR as &crate::Emoji),
-					"g" => Some(&crate::flat::MAGNIFYING_GLASS_TILTED_LEFT as &crate::Emoji),
-					"g_right" => Some(&crate::flat::MAGNIFYING_GLASS_TILTED_RIGHT as &crate::Emoji),
-					"ge" => Some(&crate::flat::MAGE.default.default as &crate::Emoji),
-					"ge_man" => Some(&crate::flat::MAGE.gender(Gender::Male).default as &crate::Emoji),
-					"ge_woman" => Some(&crate::flat::MAGE.gender(Gender::Female).default as &crate::Emoji),
-					"gic_wand" => Some(&crate::flat::MAGIC_WAND as &crate::Emoji),
-					"gnet" => Some(&crate::flat::MAGNET as &crate::Emoji),
-					"gnifying_glass_tilted_left" => Some(&crate::flat::MAGNIFYING_GLASS_TILTED_LEFT as &crate::Emoji),
-					"gnifying_glass_tilted_right" => Some(&crate::flat::MAGNIFYING_GLASS_TILTED_RIGHT as &crate::Emoji),
-					"hjong" => Some(&crate::flat::MAHJONG_RED_DRAGON as &crate::Emoji),
-					"hjong_red_dragon" => Some(&crate::flat::MAHJONG_RED_DRAGON as &crate::Emoji),
-					"ilbox" => Some(&crate::flat::CLOSED_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
-					"ilbox_closed" => Some(&crate::flat::CLOSED_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
-					"ilbox_with_mail" => Some(&crate::flat::OPEN_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
-					"ilbox_with_no_mail" => Some(&crate::flat::OPEN_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
-					"lawi" => Some(&crate::flat::FLAG_MALAWI as &crate::Emoji),
-					"laysia" => Some(&crate::flat::FLAG_MALAYSIA as &crate::Emoji),
-					"ldives" => Some(&crate::flat::FLAG_MALDIVES as &crate::Emoji),
-					"le_detective" => Some(&crate::flat::DETECTIVE.gender(Gender::Male).default as &crate::Emoji),
-					"le_sign" => Some(&crate::flat::MALE_SIGN as &crate::Emoji),
-					"li" => Some(&crate::flat::FLAG_MALI as &crate::Emoji),
-					"lta" => Some(&crate::flat::FLAG_MALTA as &crate::Emoji),
-					"mmoth" => Some(&crate::flat::MAMMOTH as &crate::Emoji),
-					"n" => Some(&crate::flat::PERSON.default.gender(Gender::Male).default as &crate::Emoji),
-					"n_artist" => Some(&crate::flat::ARTIST.gender(Gender::Male).default as &crate::Emoji),
-					"n_astronaut" => Some(&crate::flat::ASTRONAUT.gender(Gender::Male).default as &crate::Emoji),
-					"n_beard" => Some(&crate::flat::PERSON.hair(Hair::Beard).gender(Gender::Male).default as &crate::Emoji),
-					"n_cartwheeling" => Some(&crate::flat::PERSON_CARTWHEELING.gender(Gender::Male).default as &crate::Emoji),
-					"n_cook" => Some(&crate::flat::COOK.gender(Gender::Male).default as &crate::Emoji),
-					"n_dancing" => Some(&crate::flat::PERSON_DANCING.gender(Gender::Male).default as &crate::Emoji),
-					"n_facepalming" => Some(&crate::flat::PERSON_FACEPALMING.gender(Gender::Male).default as &crate::Emoji),
-					"n_factory_worker" => Some(&crate::flat::FACTORY_WORKER.gender(Gender::Male).default as &crate::Emoji),
-					"n_farmer" => Some(&crate::flat::FARMER.gender(Gender::Male).default as &crate::Emoji),
-					"n_feeding_baby" => Some(&crate::flat::PERSON_FEEDING_BABY.gender(Gender::Male).default as &crate::Emoji),
-					"n_firefighter" => Some(&crate::flat::FIREFIGHTER.gender(Gender::Male).default as &crate::Emoji),
-					"n_health_worker" => Some(&crate::flat::HEALTH_WORKER.gender(Gender::Male).default as &crate::Emoji),
-					"n_in_manual_wheelchair" => Some(&crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.gender(Gender::Male).default as &crate::Emoji),
-					"n_in_motorized_wheelchair" => Some(&crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.gender(Gender::Male).default as &crate::Emoji),
-					"n_in_tuxedo" => Some(&crate::flat::PERSON_IN_TUXEDO.gender(Gender::Male).default as &crate::Emoji),
-					"n_judge" => Some(&crate::flat::JUDGE.gender(Gender::Male).default as &crate::Emoji),
-					"n_juggling" => Some(&crate::flat::PERSON_JUGGLING.gender(Gender::Male).default as &crate::Emoji),
-					"n_mechanic" => Some(&crate::flat::MECHANIC.gender(Gender::Male).default as &crate::Emoji),
-					"n_office_worker" => Some(&crate::flat::OFFICE_WORKER.gender(Gender::Male).default as &crate::Emoji),
-					"n_pilot" => Some(&crate::flat::PILOT.gender(Gender::Male).default as &crate::Emoji),
-					"n_playing_handball" => Some(&crate::flat::PERSON_PLAYING_HANDBALL.gender(Gender::Male).default as &crate::Emoji),
-					"n_playing_water_polo" => Some(&crate::flat::PERSON_PLAYING_WATER_POLO.gender(Gender::Male).default as &crate::Emoji),
-					"n_s_shoe" => Some(&crate::flat::MAN_S_SHOE as &crate::Emoji),
-					"n_scientist" => Some(&crate::flat::SCIENTIST.gender(Gender::Male).default as &crate::Emoji),
-					"n_shrugging" => Some(&crate::flat::PERSON_SHRUGGING.gender(Gender::Male).default as &crate::Emoji),
-					"n_singer" => Some(&crate::flat::SINGER.gender(Gender::Male).default as &crate::Emoji),
-					"n_student" => Some(&crate::flat::STUDENT.gender(Gender::Male).default as &crate::Emoji),
-					"n_teacher" => Some(&crate::flat::TEACHER.gender(Gender::Male).default as &crate::Emoji),
-					"n_technologist" => Some(&crate::flat::TECHNOLOGIST.gender(Gender::Male).default as &crate::Emoji),
-					"n_with_gua_pi_mao" => Some(&crate::flat::PERSON_WITH_SKULLCAP.default as &crate::Emoji),
-					"n_with_probing_cane" => Some(&crate::flat::PERSON_WITH_WHITE_CANE.gender(Gender::Male).default as &crate::Emoji),
-					"n_with_turban" => Some(&crate::flat::PERSON_WEARING_TURBAN.gender(Gender::Male).default as &crate::Emoji),
-					"n_with_veil" => Some(&crate::flat::PERSON_WITH_VEIL.gender(Gender::Male).default as &crate::Emoji),
-					"ndarin" => Some(&crate::flat::TANGERINE as &crate::Emoji),
-					"ngo" => Some(&crate::flat::MANGO as &crate::Emoji),
-					"ns_shoe" => Some(&crate::flat::MAN_S_SHOE as &crate::Emoji),
-					"ntelpiece_clock" => Some(&crate::flat::MANTELPIECE_CLOCK as &crate::Emoji),
-					"nual_wheelchair" => Some(&crate::flat::MANUAL_WHEELCHAIR as &crate::Emoji),
-					"p_of_japan" => Some(&crate::flat::MAP_OF_JAPAN as &crate::Emoji),
-					"ple_leaf" => Some(&crate::flat::MAPLE_LEAF as &crate::Emoji),
-					"rshall_islands" => Some(&crate::flat::FLAG_MARSHALL_ISLANDS as &crate::Emoji),
-					"rtial_arts_uniform" => Some(&crate::flat::MARTIAL_ARTS_UNIFORM as &crate::Emoji),
-					"rtinique" => Some(&crate::flat::FLAG_MARTINIQUE as &crate::Emoji),
-					"sk" => Some(&crate::flat::FACE_WITH_MEDICAL_MASK as &crate::Emoji),
-					"ssage" => Some(&crate::flat::PERSON_GETTING_MASSAGE.default.default as &crate::Emoji),
-					"ssage_man" => Some(&crate::flat::PERSON_GETTING_MASSAGE.gender(Gender::Male).default as &crate::Emoji),
-					"ssage_woman" => Some(&crate::flat::PERSON_GETTING_MASSAGE.gender(Gender::Female).default as &crate::Emoji),
-					"te" => Some(&crate::flat::MATE as &crate::Emoji),
-					"uritania" => Some(&crate::flat::FLAG_MAURITANIA as &crate::Emoji),
-					"uritius" => Some(&crate::flat::FLAG_MAURITIUS as &crate::Emoji),
-					"yotte" => Some(&crate::flat::FLAG_MAYOTTE as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"at_on_bone" => Some(&crate::flat::MEAT_ON_BONE as &crate::Emoji),
-					"chanic" => Some(&crate::flat::MECHANIC.default.default as &crate::Emoji),
-					"chanical_arm" => Some(&crate::flat::MECHANICAL_ARM as &crate::Emoji),
-					"chanical_leg" => Some(&crate::flat::MECHANICAL_LEG as &crate::Emoji),
-					"dal_military" => Some(&crate::flat::MILITARY_MEDAL as &crate::Emoji),
-					"dal_sports" => Some(&crate::flat::SPORTS_MEDAL as &crate::Emoji),
-					"dical_symbol" => Some(&crate::flat::MEDICAL_SYMBOL as &crate::Emoji),
-					"dium_dark_skin_tone" => Some(&crate::flat::MEDIUM_DARK_SKIN_TONE as &crate::Emoji),
-					"dium_light_skin_tone" => Some(&crate::flat::MEDIUM_LIGHT_SKIN_TONE as &crate::Emoji),
-					"dium_skin_tone" => Some(&crate::flat::MEDIUM_SKIN_TONE as &crate::Emoji),
-					"ga" => Some(&crate::flat::MEGAPHONE as &crate::Emoji),
-					"gaphone" => Some(&crate::flat::MEGAPHONE as &crate::Emoji),
-					"lon" => Some(&crate::flat::MELON as &crate::Emoji),
-					"mo" => Some(&crate::flat::MEMO as &crate::Emoji),
-					"n_s_room" => Some(&crate::flat::MEN_S_ROOM as &crate::Emoji),
-					"n_wrestling" => Some(&crate::flat::PERSON_WRESTLING.gender(Gender::Male) as &crate::Emoji),
-					"nding_heart" => Some(&crate::flat::MENDING_HEART as &crate::Emoji),
-					"norah" => Some(&crate::flat::MENORAH as &crate::Emoji),
-					"ns" => Some(&crate::flat::MEN_S_ROOM as &crate::Emoji),
-					"rmaid" => Some(&crate::flat::MERMAID.default as &crate::Emoji),
-					"rman" => Some(&crate::flat::MERMAN.default as &crate::Emoji),
-					"rperson" => Some(&crate::flat::MERPERSON.default as &crate::Emoji),
-					"tal" => Some(&crate::flat::SIGN_OF_THE_HORNS.default as &crate::Emoji),
-					"tro" => Some(&crate::flat::METRO as &crate::Emoji),
-					"xico" => Some(&crate::flat::FLAG_MEXICO as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"crobe" => Some(&crate::flat::MICROBE as &crate::Emoji),
-					"cronesia" => Some(&crate::flat::FLAG_MICRONESIA as &crate::Emoji),
-					"crophone" => Some(&crate::flat::MICROPHONE as &crate::Emoji),
-					"croscope" => Some(&crate::flat::MICROSCOPE as &crate::Emoji),
-					"ddle_finger" => Some(&crate::flat::MIDDLE_FINGER.default as &crate::Emoji),
-					"litary_helmet" => Some(&crate::flat::MILITARY_HELMET as &crate::Emoji),
-					"litary_medal" => Some(&crate::flat::MILITARY_MEDAL as &crate::Emoji),
-					"lk_glass" => Some(&crate::flat::GLASS_OF_MILK as &crate::Emoji),
-					"lky_way" => Some(&crate::flat::MILKY_WAY as &crate::Emoji),
-					"nibus" => Some(&crate::flat::MINIBUS as &crate::Emoji),
-					"nidisc" => Some(&crate::flat::COMPUTER_DISK as &crate::Emoji),
-					"nus" => Some(&crate::flat::MINUS as &crate::Emoji),
-					"rror" => Some(&crate::flat::MIRROR as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"ai" => Some(&crate::flat::MOAI as &crate::Emoji),
-					"bile_phone" => Some(&crate::flat::MOBILE_PHONE as &crate::Emoji),
-					"bile_phone_off" => Some(&crate::flat::MOBILE_PHONE_OFF as &crate::Emoji),
-					"bile_phone_with_arrow" => Some(&crate::flat::MOBILE_PHONE_WITH_ARROW as &crate::Emoji),
-					"ldova" => Some(&crate::flat::FLAG_MOLDOVA as &crate::Emoji),
-					"naco" => Some(&crate::flat::FLAG_MONACO as &crate::Emoji),
-					"ney_bag" => Some(&crate::flat::MONEY_BAG as &crate::Emoji),
-					"ney_mouth_face" => Some(&crate::flat::MONEY_MOUTH_FACE as &crate::Emoji),
-					"ney_with_wings" => Some(&crate::flat::MONEY_WITH_WINGS as &crate::Emoji),
-					"neybag" => Some(&crate::flat::MONEY_BAG as &crate::Emoji),
-					"ngolia" => Some(&crate::flat::FLAG_MONGOLIA as &crate::Emoji),
-					"nkey" => Some(&crate::flat::MONKEY as &crate::Emoji),
-					"nkey_face" => Some(&crate::flat::MONKEY_FACE as &crate::Emoji),
-					"nocle_face" => Some(&crate::flat::FACE_WITH_MONOCLE as &crate::Emoji),
-					"norail" => Some(&crate::flat::MONORAIL as &crate::Emoji),
-					"ntenegro" => Some(&crate::flat::FLAG_MONTENEGRO as &crate::Emoji),
-					"ntserrat" => Some(&crate::flat::FLAG_MONTSERRAT as &crate::Emoji),
-					"on" => Some(&crate::flat::WAXING_GIBBOUS_MOON as &crate::Emoji),
-					"on_cake" => Some(&crate::flat::MOON_CAKE as &crate::Emoji),
-					"on_viewing_ceremony" => Some(&crate::flat::MOON_VIEWING_CEREMONY as &crate::Emoji),
-					"rocco" => Some(&crate::flat::FLAG_MOROCCO as &crate::Emoji),
-					"rtar_board" => Some(&crate::flat::GRADUATION_CAP as &crate::Emoji),
-					"sque" => Some(&crate::flat::MOSQUE as &crate::Emoji),
-					"squito" => Some(&crate::flat::MOSQUITO as &crate::Emoji),
-					"tor_boat" => Some(&crate::flat::MOTOR_BOAT as &crate::Emoji),
-					"tor_scooter" => Some(&crate::flat::MOTOR_SCOOTER as &crate::Emoji),
-					"torcycle" => Some(&crate::flat::MOTORCYCLE as &crate::Emoji),
-					"torized_wheelchair" => Some(&crate::flat::MOTORIZED_WHEELCHAIR as &crate::Emoji),
-					"torway" => Some(&crate::flat::MOTORWAY as &crate::Emoji),
-					"unt_fuji" => Some(&crate::flat::MOUNT_FUJI as &crate::Emoji),
-					"untain" => Some(&crate::flat::MOUNTAIN as &crate::Emoji),
-					"untain_bicyclist" => Some(&crate::flat::PERSON_MOUNTAIN_BIKING.default.default as &crate::Emoji),
-					"untain_biking_man" => Some(&crate::flat::PERSON_MOUNTAIN_BIKING.gender(Gender::Male).default as &crate::Emoji),
-					"untain_biking_woman" => Some(&crate::flat::PERSON_MOUNTAIN_BIKING.gender(Gender::Female).default as &crate::Emoji),
-					"untain_cableway" => Some(&crate::flat::MOUNTAIN_CABLEWAY as &crate::Emoji),
-					"untain_railway" => Some(&crate::flat::MOUNTAIN_RAILWAY as &crate::Emoji),
-					"untain_snow" => Some(&crate::flat::SNOW_CAPPED_MOUNTAIN as &crate::Emoji),
-					"use" => Some(&crate::flat::MOUSE as &crate::Emoji),
-					"use2" => Some(&crate::flat::MOUSE as &crate::Emoji),
-					"use_face" => Some(&crate::flat::MOUSE_FACE as &crate::Emoji),
-					"use_trap" => Some(&crate::flat::MOUSE_TRAP as &crate::Emoji),
-					"uth" => Some(&crate::flat::MOUTH as &crate::Emoji),
-					"vie_camera" => Some(&crate::flat::MOVIE_CAMERA as &crate::Emoji),
-					"yai" => Some(&crate::flat::MOAI as &crate::Emoji),
-					"zambique" => Some(&crate::flat::FLAG_MOZAMBIQUE as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"s_claus" => Some(&crate::flat::MRS_CLAUS.default as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"ltiply" => Some(&crate::flat::MULTIPLY as &crate::Emoji),
-					"scle" => Some(&crate::flat::FLEXED_BICEPS.default as &crate::Emoji),
-					"shroom" => Some(&crate::flat::MUSHROOM as &crate::Emoji),
-					"sical_keyboard" => Some(&crate::flat::MUSICAL_KEYBOARD as &crate::Emoji),
-					"sical_note" => Some(&crate::flat::MUSICAL_NOTE as &crate::Emoji),
-					"sical_notes" => Some(&crate::flat::MUSICAL_NOTES as &crate::Emoji),
-					"sical_score" => Some(&crate::flat::MUSICAL_SCORE as &crate::Emoji),
-					"te" => Some(&crate::flat::MUTED_SPEAKER as &crate::Emoji),
-					"ted_speaker" => Some(&crate::flat::MUTED_SPEAKER as &crate::Emoji),
-					_ => None,
-				},
-				b'x' => match rest {
-					"_claus" => Some(&crate::flat::MX_CLAUS.default as &crate::Emoji),
-					_ => None,
-				},
-				b'y' => match rest {
-					"anmar" => Some(&crate::flat::FLAG_MYANMAR_BURMA as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'n' => match sec {
-				b'a' => match rest {
-					"il_care" => Some(&crate::flat::NAIL_POLISH.default as &crate::Emoji),
-					"il_polish" => Some(&crate::flat::NAIL_POLISH.default as &crate::Emoji),
-					"me_badge" => Some(&crate::flat::NAME_BADGE as &crate::Emoji),
-					"mibia" => Some(&crate::flat::FLAG_NAMIBIA as &crate::Emoji),
-					"tional_park" => Some(&crate::flat::NATIONAL_PARK as &crate::Emoji),
-					"uru" => Some(&crate::flat::FLAG_NAURU as &crate::Emoji),
-					"useated_face" => Some(&crate::flat::NAUSEATED_FACE as &crate::Emoji),
-					"zar_amulet" => Some(&crate::flat::NAZAR_AMULET as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"cktie" => Some(&crate::flat::NECKTIE as &crate::Emoji),
-					"gative_squared_cross_mark" => Some(&crate::flat::CROSS_MARK_BUTTON as &crate::Emoji),
-					"pal" => Some(&crate::flat::FLAG_NEPAL as &crate::Emoji),
-					"rd_face" => Some(&crate::flat::NERD_FACE as &crate::Emoji),
-					"sting_dolls" => Some(&crate::flat::NESTING_DOLLS as &crate::Emoji),
-					"therlands" => Some(&crate::flat::FLAG_NETHERLANDS as &crate::Emoji),
-					"utral_face" => Some(&crate::flat::NEUTRAL_FACE as &crate::Emoji),
-					"w" => Some(&crate::flat::NEW_BUTTON as &crate::Emoji),
-					"w_button" => Some(&crate::flat::NEW_BUTTON as &crate::Emoji),
-					"w_caledonia" => Some(&crate::flat::FLAG_NEW_CALEDONIA as &crate::Emoji),
-					"w_moon" => Some(&crate::flat::NEW_MOON as &crate::Emoji),
-					"w_moon_face" => Some(&crate::flat::NEW_MOON_FACE as &crate::Emoji),
-					"w_moon_with_face" => Some(&crate::flat::NEW_MOON_FACE as &crate::Emoji),
-					"w_zealand" => Some(&crate::flat::FLAG_NEW_ZEALAND as &crate::Emoji),
-					"wspaper" => Some(&crate::flat::NEWSPAPER as &crate::Emoji),
-					"wspaper_roll" => Some(&crate::flat::ROLLED_UP_NEWSPAPER as &crate::Emoji),
-					"xt_track_button" => Some(&crate::flat::NEXT_TRACK_BUTTON as &crate::Emoji),
-					_ => None,
-				},
-				b'g' => match rest {
-					"" => Some(&crate::flat::NG_BUTTON as &crate::Emoji),
-					"_button" => Some(&crate::flat::NG_BUTTON as &crate::Emoji),
-					"_man" => Some(&crate::flat::PERSON_GESTURING_NO.gender(Gender::Male).default as &crate::Emoji),
-					"_woman" => Some(&crate::flat::PERSON_GESTURING_NO.gender(Gender::Female).default as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"caragua" => Some(&crate::flat::FLAG_NICARAGUA as &crate::Emoji),
-					"ger" => Some(&crate::flat::FLAG_NIGER as &crate::Emoji),
-					"geria" => Some(&crate::flat::FLAG_NIGERIA as &crate::Emoji),
-					"ght_with_stars" => Some(&crate::flat::NIGHT_WITH_STARS as &crate::Emoji),
-					"ne" => Some(&crate::flat::KEYCAP_9 as &crate::Emoji),
-					"ne_o_clock" => Some(&crate::flat::NINE_O_CLOCK as &crate::Emoji),
-					"ne_thirty" => Some(&crate::flat::NINE_THIRTY as &crate::Emoji),
-					"nja" => Some(&crate::flat::NINJA.default as &crate::Emoji),
-					"ue" => Some(&crate::flat::FLAG_NIUE as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"_bell" => Some(&crate::flat::BELL_WITH_SLASH as &crate::Emoji),
-					"_bicycles" => Some(&crate::flat::NO_BICYCLES as &crate::Emoji),
-					"_entry" => Some(&crate::flat::NO_ENTRY as &crate::Emoji),
-					"_entry_sign" => Some(&crate::flat::PROHIBITED as &crate::Emoji),
-					"_good" => Some(&crate::flat::PERSON_GESTURING_NO.default.default as &crate::Emoji),
-					"_good_man" => Some(&crate::flat::PERSON_GESTURING_NO.gender(Gender::Male).default as &crate::Emoji),
-					"_good_woman" => Some(&crate::flat::PERSON_GESTURING_NO.gender(Gender::Female).default as &crate::Emoji),
-					"_littering" => Some(&crate::flat::NO_LITTERING as &crate::Emoji),
-					"_mobile_phones" => Some(&crate::flat::NO_MOBILE_PHONES as &crate::Emoji),
-					"_mouth" => Some(&crate::flat::FACE_WITHOUT_MOUTH as &crate::Emoji),
-					"_one_under_eighteen" => Some(&crate::flat::NO_ONE_UNDER_EIGHTEEN as &crate::Emoji),
-					"_pedestrians" => Some(&crate::flat::NO_PEDESTRIANS as &crate::Emoji),
-					"_smoking" => Some(&crate::flat::NO_SMOKING as &crate::Emoji),
-					"n-potable_water" => Some(&crate::flat::NON_POTABLE_WATER as &crate::Emoji),
-					"n_potable_water" => Some(&crate::flat::NON_POTABLE_WATER as &crate::Emoji),
-					"rfolk_island" => Some(&crate::flat::FLAG_NORFOLK_ISLAND as &crate::Emoji),
-					"rth_korea" => Some(&crate::flat::FLAG_NORTH_KOREA as &crate::Emoji),
-					"rthern_mariana_islands" => Some(&crate::flat::FLAG_NORTHERN_MARIANA_ISLANDS as &crate::Emoji),
-					"rway" => Some(&crate::flat::FLAG_NORWAY as &crate::Emoji),
-					"se" => Some(&crate::flat::NOSE.default as &crate::Emoji),
-					"tebook" => Some(&crate::flat::NOTEBOOK as &crate::Emoji),
-					"tebook_with_decorative_cover" => Some(&crate::flat::NOTEBOOK_WITH_DECORATIVE_COVER as &crate::Emoji),
-					"tes" => Some(&crate::flat::MUSICAL_NOTES as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"t_and_bolt" => Some(&crate::flat::NUT_AND_BOLT as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'o' => match sec {
-				b'2' => match rest {
-					"" => Some(&crate::flat::O_BUTTON_BLOOD_TYPE as &crate::Emoji),
-					_ => None,
-				},
-				b'_' => match rest {
-					"button_blood_type" => Some(&crate::flat::O_BUTTON_BLOOD_TYPE as &crate::Emoji),
-					_ => None,
-				},
-				b'c' => match rest {
-					"ean" => Some(&crate::flat::WATER_WAVE as &crate::Emoji),
-					"topus" => Some(&crate::flat::OCTOPUS as &crate::Emoji),
-					_ => None,
-				},
-				b'd' => match rest {
-					"en" => Some(&crate::flat::ODEN as &crate::Emoji),
-					_ => None,
-				},
-				b'f' => match rest {
-					"fice" => Some(&crate::flat::OFFICE_BUILDING as &crate::Emoji),
-					"fice_building" => Some(&crate::flat::OFFICE_BUILDING as &crate::Emoji),
-					"fice_worker" => Some(&crate::flat::OFFICE_WORKER.default.default as &crate::Emoji),
-					_ => None,
-				},
-				b'g' => match rest {
-					"re" => Some(&crate::flat::OGRE as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"l_drum" => Some(&crate::flat::OIL_DRUM as &crate::Emoji),
-					_ => None,
-				},
-				b'k' => match rest {
-					"" => Some(&crate::flat::OK_BUTTON as &crate::Emoji),
-					"_button" => Some(&crate::flat::OK_BUTTON as &crate::Emoji),
-					"_hand" => Some(&crate::flat::OK_HAND.default as &crate::Emoji),
-					"_man" => Some(&crate::flat::PERSON_GESTURING_OK.gender(Gender::Male).default as &crate::Emoji),
-					"_person" => Some(&crate::flat::PERSON_GESTURING_OK.default.default as &crate::Emoji),
-					"_woman" => Some(&crate::flat::PERSON_GESTURING_OK.gender(Gender::Female).default as &crate::Emoji),
-					_ => None,
-				},
-				b'l' => match rest {
-					"d_key" => Some(&crate::flat::OLD_KEY as &crate::Emoji),
-					"der_adult" => Some(&crate::flat::OLDER_PERSON.default as &crate::Emoji),
-					"der_man" => Some(&crate::flat::OLD_PERSON.gender(Gender::Male).default as &crate::Emoji),
-					"der_person" => Some(&crate::flat::OLDER_PERSON.default as &crate::Emoji),
-					"der_woman" => Some(&crate::flat::OLD_PERSON.gender(Gender::Female).default as &crate::Emoji),
-					"ive" => Some(&crate::flat::OLIVE as &crate::Emoji),
-					_ => None,
-				},
-				b'm' => match rest {
-					"" => Some(&crate::flat::OM as &crate::Emoji),
-					"an" => Some(&crate::flat::FLAG_OMAN as &crate::Emoji),
-					_ => None,
-				},
-				b'n' => match rest {
-					"" => Some(&crate::flat::ON_ARROW as &crate::Emoji),
-					"_arrow" => Some(&crate::flat::ON_ARROW as &crate::Emoji),
-					"coming_automobile" => Some(&crate::flat::ONCOMING_AUTOMOBILE as &crate::Emoji),
-					"coming_bus" => Some(&crate::flat::ONCOMING_BUS as &crate::Emoji),
-					"coming_fist" => Some(&crate::flat::ONCOMING_FIST.default as &crate::Emoji),
-					"coming_police_car" => Some(&crate::flat::ONCOMING_POLICE_CAR as &crate::Emoji),
-					"coming_taxi" => Some(&crate::flat::ONCOMING_TAXI as &crate::Emoji),
-					"e" => Some(&crate::flat::KEYCAP_1 as &crate::Emoji),
-					"e_o_clock" => Some(&crate::flat::ONE_O_CLOCK as &crate::Emoji),
-					"e_piece_swimsuit" => Some(&crate::flat::ONE_PIECE_SWIMSUIT as &crate::Emoji),
-					"e_thirty" => Some(&crate::flat::ONE_THIRTY as &crate::Emoji),
-					"ion" => Some(&crate::flat::ONION as &crate::Emoji),
-					_ => None,
-				},
-				b'p' => match rest {
-					"en_book" => Some(&crate::flat::OPEN_BOOK as &crate::Emoji),
-					"en_file_folder" => Some(&crate::flat::OPEN_FILE_FOLDER as &crate::Emoji),
-					"en_hands" => Some(&crate::flat::OPEN_HANDS.default as &crate::Emoji),
-					"en_mailbox_with_lowered_flag" => Some(&crate::flat::OPEN_MAILBOX_WITH_LOWERED_FLAG as &crate::Emoji),
-					"en_mailbox_with_raised_flag" => Some(&crate::flat::OPEN_MAILBOX_WITH_RAISED_FLAG as &crate::Emoji),
-					"en_mouth" => Some(&crate::flat::FACE_WITH_OPEN_MOUTH as &crate::Emoji),
-					"en_umbrella" => Some(&crate::flat::UMBRELLA as &crate::Emoji),
-					"hiuchus" => Some(&crate::flat::OPHIUCHUS as &crate::Emoji),
-					"tical_disk" => Some(&crate::flat::OPTICAL_DISK as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"ange" => Some(&crate::flat::TANGERINE as &crate::Emoji),
-					"ange_book" => Some(&crate::flat::ORANGE_BOOK as &crate::Emoji),
-					"ange_circle" => Some(&crate::flat::ORANGE_CIRCLE as &crate::Emoji),
-					"ange_heart" => Some(&crate::flat::ORANGE_HEART as &crate::Emoji),
-					"ange_square" => Some(&crate::flat::ORANGE_SQUARE as &crate::Emoji),
-					"angutan" => Some(&crate::flat::ORANGUTAN as &crate::Emoji),
-					"thodox_cross" => Some(&crate::flat::ORTHODOX_CROSS as &crate::Emoji),
-					_ => None,
-				},
-				b't' => match rest {
-					"ter" => Some(&crate::flat::OTTER as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"tbox_tray" => Some(&crate::flat::OUTBOX_TRAY as &crate::Emoji),
-					_ => None,
-				},
-				b'w' => match rest {
-					"l" => Some(&crate::flat::OWL as &crate::Emoji),
-					_ => None,
-				},
-				b'x' => match rest {
-					"" => Some(&crate::flat::OX as &crate::Emoji),
-					_ => None,
-				},
-				b'y' => match rest {
-					"ster" => Some(&crate::flat::OYSTER as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'p' => match sec {
-				b'_' => match rest {
-					"button" => Some(&crate::flat::P_BUTTON as &crate::Emoji),
-					_ => None,
-				},
-				b'a' => match rest {
-					"ckage" => Some(&crate::flat::PACKAGE as &crate::Emoji),
-					"ge_facing_up" => Some(&crate::flat::PAGE_FACING_UP as &crate::Emoji),
-					"ge_with_curl" => Some(&crate::flat::PAGE_WITH_CURL as &crate::Emoji),
-					"ger" => Some(&crate::flat::PAGER as &crate::Emoji),
-					"intbrush" => Some(&crate::flat::PAINTBRUSH as &crate::Emoji),
-					"kistan" => Some(&crate::flat::FLAG_PAKISTAN as &crate::Emoji),
-					"lau" => Some(&crate::flat::FLAG_PALAU as &crate::Emoji),
-					"lestinian_territories" => Some(&crate::flat::FLAG_PALESTINIAN_TERRITORIES as &crate::Emoji),
-					"lm_tree" => Some(&crate::flat::PALM_TREE as &crate::Emoji),
-					"lms_up_together" => Some(&crate::flat::PALMS_UP_TOGETHER.default as &crate::Emoji),
-					"nama" => Some(&crate::flat::FLAG_PANAMA as &crate::Emoji),
-					"ncakes" => Some(&crate::flat::PANCAKES as &crate::Emoji),
-					"nda" => Some(&crate::flat::PANDA as &crate::Emoji),
-					"nda_face" => Some(&crate::flat::PANDA as &crate::Emoji),
-					"perclip" => Some(&crate::flat::PAPERCLIP as &crate::Emoji),
-					"perclips" => Some(&crate::flat::LINKED_PAPERCLIPS as &crate::Emoji),
-					"pua_new_guinea" => Some(&crate::flat::FLAG_PAPUA_NEW_GUINEA as &crate::Emoji),
-					"rachute" => Some(&crate::flat::PARACHUTE as &crate::Emoji),
-					"raguay" => Some(&crate::flat::FLAG_PARAGUAY as &crate::Emoji),
-					"rasol_on_ground" => Some(&crate::flat::UMBRELLA_ON_GROUND as &crate::Emoji),
-					"rking" => Some(&crate::flat::P_BUTTON as &crate::Emoji),
-					"rrot" => Some(&crate::flat::PARROT as &crate::Emoji),
-					"rt_alternation_mark" => Some(&crate::flat::PART_ALTERNATION_MARK as &crate::Emoji),
-					"rtly_sunny" => Some(&crate::flat::SUN_BEHIND_CLOUD as &crate::Emoji),
-					"rty_popper" => Some(&crate::flat::PARTY_POPPER as &crate::Emoji),
-					"rtying_face" => Some(&crate::flat::PARTYING_FACE as &crate::Emoji),
-					"ssenger_ship" => Some(&crate::flat::PASSENGER_SHIP as &crate::Emoji),
-					"ssport_control" => Some(&crate::flat::PASSPORT_CONTROL as &crate::Emoji),
-					"use_button" => Some(&crate::flat::PAUSE_BUTTON as &crate::Emoji),
-					"w_prints" => Some(&crate::flat::PAW_PRINTS as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"ace_symbol" => Some(&crate::flat::PEACE_SYMBOL as &crate::Emoji),
-					"ach" => Some(&crate::flat::PEACH as &crate::Emoji),
-					"acock" => Some(&crate::flat::PEACOCK as &crate::Emoji),
-					"anuts" => Some(&crate::flat::PEANUTS as &crate::Emoji),
-					"ar" => Some(&crate::flat::PEAR as &crate::Emoji),
-					"n" => Some(&crate::flat::PEN as &crate::Emoji),
-					"ncil" => Some(&crate::flat::PENCIL as &crate::Emoji),
-					"ncil2" => Some(&crate::flat::PENCIL as &crate::Emoji),
-					"nguin" => Some(&crate::flat::PENGUIN as &crate::Emoji),
-					"nsive" => Some(&crate::flat::PENSIVE_FACE as &crate::Emoji),
-					"nsive_face" => Some(&crate::flat::PENSIVE_FACE as &crate::Emoji),
-					"ople_holding_hands" => Some(&crate::flat::PERSON_HOLDING_HANDS.default.default as &crate::Emoji),
-					"ople_hugging" => Some(&crate::flat::PERSON_HUGGING as &crate::Emoji),
-					"rforming_arts" => Some(&crate::flat::PERFORMING_ARTS as &crate::Emoji),
-					"rsevere" => Some(&crate::flat::PERSEVERING_FACE as &crate::Emoji),
-					"rsevering_face" => Some(&crate::flat::PERSEVERING_FACE as &crate::Emoji),
-					"rson" => Some(&crate::flat::PERSON.default.default.default as &crate::Emoji),
-					"rson_bald" => Some(&crate::flat::PERSON.hair(Hair::Bald).default.default as &crate::Emoji),
-					"rson_biking" => Some(&crate::flat::PERSON_BIKING.default.default as &crate::Emoji),
-					"rson_bouncing_ball" => Some(&crate::flat::PERSON_BOUNCING_BALL.default.default as &crate::Emoji),
-					"rson_bowing" => Some(&crate::flat::PERSON_BOWING.default.default as &crate::Emoji),
-					"rson_cartwheeling" => Some(&crate::flat::PERSON_CARTWHEELING.default.default as &crate::Emoji),
-					"rson_climbing" => Some(&crate::flat::PERSON_CLIMBING.default.default as &crate::Emoji),
-					"rson_curly_hair" => Some(&crate::flat::PERSON.hair(Hair::Curly).default.default as &crate::Emoji),
-					"rson_facepalming" => Some(&crate::flat::PERSON_FACEPALMING.default.default as &crate::Emoji),
-					"rson_feeding_baby" => Some(&crate::flat::PERSON_FEEDING_BABY.default.default as &crate::Emoji),
-					"rson_fencing" => Some(&crate::flat::PERSON_FENCING as &crate::Emoji),
-					"rson_frowning" => Some(&crate::flat::PERSON_FROWNING.default.default as &crate::Emoji),
-					"rson_gesturing_no" => Some(&crate::flat::PERSON_GESTURING_NO.default.default as &crate::Emoji),
-					"rson_gesturing_ok" => Some(&crate::flat::PERSON_GESTURING_OK.default.default as &crate::Emoji),
-					"rson_getting_haircut" => Some(&crate::flat::PERSON_GETTING_HAIRCUT.default.default as &crate::Emoji),
-					"rson_getting_massage" => Some(&crate::flat::PERSON_GETTING_MASSAGE.default.default as &crate::Emoji),
-					"rson_golfing" => Some(&crate::flat::PERSON_GOLFING.default.default as &crate::Emoji),
-					"rson_holding_hands" => Some(&crate::flat::PERSON_HOLDING_HANDS.default.default as &crate::Emoji),
-					"rson_hugging" => Some(&crate::flat::PERSON_HUGGING as &crate::Emoji),
-					"rson_in_bed" => Some(&crate::flat::PERSON_IN_BED.default as &crate::Emoji),
-					"rson_in_lotus_position" => Some(&crate::flat::PERSON_IN_LOTUS_POSITION.default.default as &crate::Emoji),
-					"rson_in_manual_wheelchair" => Some(&crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.default.default as &crate::Emoji),
-					"rson_in_motorized_wheelchair" => Some(&crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.default.default as &crate::Emoji),
-					"rson_in_steamy_room" => Some(&crate::flat::PERSON_IN_STEAMY_ROOM.default.default as &crate::Emoji),
-					"rson_in_suit_levitating" => Some(&crate::flat::PERSON_IN_SUIT_LEVITATING.default as &crate::Emoji),
-					"rson_in_tuxedo" => Some(&crate::flat::PERSON_IN_TUXEDO.default.default as &crate::Emoji),
-					"rson_juggling" => Some(&crate::flat::PERSON_JUGGLING.default.default as &crate::Emoji),
-					"rson_kneeling" => Some(&crate::flat::PERSON_KNEELING.default.default as &crate::Emoji),
-					"rson_lifting_weights" => Some(&crate::flat::PERSON_LIFTING_WEIGHTS.default.default as &crate::Emoji),
-					"rson_mountain_biking" => Some(&crate::flat::PERSON_MOUNTAIN_BIKING.default.default as &crate::Emoji),
-					"rson_playing_handball" => Some(&crate::flat::PERSON_PLAYING_HANDBALL.default.default as &crate::Emoji),
-					"rson_playing_water_polo" => Some(&crate::flat::PERSON_PLAYING_WATER_POLO.default.default as &crate::Emoji),
-					"rson_pouting" => Some(&crate::flat::PERSON_POUTING.default.default as &crate::Emoji),
-					"rson_raising_hand" => Some(&crate::flat::PERSON_RAISING_HAND.default.default as &crate::Emoji),
-					"rson_red_hair" => Some(&crate::flat::PERSON.hair(Hair::Red).default.default as &crate::Emoji),
-					"rson_rowing_boat" => Some(&crate::flat::PERSON_ROWING_BOAT.default.default as &crate::Emoji),
-					"rson_running" => Some(&crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
-					"rson_shrugging" => Some(&crate::flat::PERSON_SHRUGGING.default.default as &crate::Emoji),
-					"rson_standing" => Some(&crate::flat::PERSON_STANDING.default.default as &crate::Emoji),
-					"rson_surfing" => Some(&crate::flat::PERSON_SURFING.default.default as &crate::Emoji),
-					"rson_swimming" => Some(&crate::flat::PERSON_SWIMMING.default.default as &crate::Emoji),
-					"rson_taking_bath" => Some(&crate::flat::PERSON_TAKING_BATH.default as &crate::Emoji),
-					"rson_tipping_hand" => Some(&crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
-					"rson_walking" => Some(&crate::flat::PERSON_WALKING.default.default as &crate::Emoji),
-					"rson_wearing_turban" => Some(&crate::flat::PERSON_WEARING_TURBAN.default.default as &crate::Emoji),
-					"rson_white_hair" => Some(&crate::flat::PERSON.hair(Hair::White).default.default as &crate::Emoji),
-					"rson_with_bunny_ears" => Some(&crate::flat::PERSON_WITH_BUNNY_EARS.default as &crate::Emoji),
-					"rson_with_probing_cane" => Some(&crate::flat::PERSON_WITH_WHITE_CANE.default.default as &crate::Emoji),
-					"rson_with_skullcap" => Some(&crate::flat::PERSON_WITH_SKULLCAP.default as &crate::Emoji),
-					"rson_with_turban" => Some(&crate::flat::PERSON_WEARING_TURBAN.default.default as &crate::Emoji),
-					"rson_with_veil" => Some(&crate::flat::PERSON_WITH_VEIL.default.default as &crate::Emoji),
-					"rson_with_white_cane" => Some(&crate::flat::PERSON_WITH_WHITE_CANE.default.default as &crate::Emoji),
-					"rson_wrestling" => Some(&crate::flat::PERSON_WRESTLING.default as &crate::Emoji),
-					"ru" => Some(&crate::flat::FLAG_PERU as &crate::Emoji),
-					"tri_dish" => Some(&crate::flat::PETRI_DISH as &crate::Emoji),
-					_ => None,
-				},
-				b'h' => match rest {
-					"ilippines" => Some(&crate::flat::FLAG_PHILIPPINES as &crate::Emoji),
-					"one" => Some(&crate::flat::TELEPHONE as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"ck" => Some(&crate::flat::PICK as &crate::Emoji),
-					"ckup_truck" => Some(&crate::flat::PICKUP_TRUCK as &crate::Emoji),
-					"e" => Some(&crate::flat::PIE as &crate::Emoji),
-					"g" => Some(&crate::flat::PIG as &crate::Emoji),
-					"g2" => Some(&crate::flat::PIG as &crate::Emoji),
-					"g_face" => Some(&crate::flat::PIG_FACE as &crate::Emoji),
-					"g_nose" => Some(&crate::flat::PIG_NOSE as &crate::Emoji),
-					"le_of_poo" => Some(&crate::flat::PILE_OF_POO as &crate::Emoji),
-					"ll" => Some(&crate::flat::PILL as &crate::Emoji),
-					"lot" => Some(&crate::flat::PILOT.default.default as &crate::Emoji),
-					"nata" => Some(&crate::flat::PINATA as &crate::Emoji),
-					"nched_fingers" => Some(&crate::flat::PINCHED_FINGERS.default as &crate::Emoji),
-					"nching_hand" => Some(&crate::flat::PINCHING_HAND.default as &crate::Emoji),
-					"ne_decoration" => Some(&crate::flat::PINE_DECORATION as &crate::Emoji),
-					"neapple" => Some(&crate::flat::PINEAPPLE as &crate::Emoji),
-					"ng_pong" => Some(&crate::flat::PING_PONG as &crate::Emoji),
-					"rate_flag" => Some(&crate::flat::PIRATE_FLAG as &crate::Emoji),
-					"sces" => Some(&crate::flat::PISCES as &crate::Emoji),
-					"tcairn_islands" => Some(&crate::flat::FLAG_PITCAIRN_ISLANDS as &crate::Emoji),
-					"zza" => Some(&crate::flat::PIZZA as &crate::Emoji),
-					_ => None,
-				},
-				b'l' => match rest {
-					"acard" => Some(&crate::flat::PLACARD as &crate::Emoji),
-					"ace_of_worship" => Some(&crate::flat::PLACE_OF_WORSHIP as &crate::Emoji),
-					"ate_with_cutlery" => Some(&crate::flat::FORK_AND_KNIFE_WITH_PLATE as &crate::Emoji),
-					"ay_button" => Some(&crate::flat::PLAY_BUTTON as &crate::Emoji),
-					"ay_or_pause_button" => Some(&crate::flat::PLAY_OR_PAUSE_BUTTON as &crate::Emoji),
-					"eading_face" => Some(&crate::flat::PLEADING_FACE as &crate::Emoji),
-					"unger" => Some(&crate::flat::PLUNGER as &crate::Emoji),
-					"us" => Some(&crate::flat::PLUS as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"int_down" => Some(&crate::flat::BACKHAND_INDEX_POINTING_DOWN.default as &crate::Emoji),
-					"int_left" => Some(&crate::flat::BACKHAND_INDEX_POINTING_LEFT.default as &crate::Emoji),
-					"int_right" => Some(&crate::flat::BACKHAND_INDEX_POINTING_RIGHT.default as &crate::Emoji),
-					"int_up" => Some(&crate::flat::INDEX_POINTING_UP.default as &crate::Emoji),
-					"int_up_2" => Some(&crate::flat::BACKHAND_INDEX_POINTING_UP.default as &crate::Emoji),
-					"land" => Some(&crate::flat::FLAG_POLAND as &crate::Emoji),
-					"lar_bear" => Some(&crate::flat::POLAR_BEAR as &crate::Emoji),
-					"lice_car" => Some(&crate::flat::POLICE_CAR as &crate::Emoji),
-					"lice_car_light" => Some(&crate::flat::POLICE_CAR_LIGHT as &crate::Emoji),
-					"lice_officer" => Some(&crate::flat::POLICE_OFFICER.default.default as &crate::Emoji),
-					"liceman" => Some(&crate::flat::POLICE_OFFICER.gender(Gender::Male).default as &crate::Emoji),
-					"licewoman" => Some(&crate::flat::POLICE_OFFICER.gender(Gender::Female).default as &crate::Emoji),
-					"odle" => Some(&crate::flat::POODLE as &crate::Emoji),
-					"ol_8_ball" => Some(&crate::flat::POOL_8_BALL as &crate::Emoji),
-					"op" => Some(&crate::flat::PILE_OF_POO as &crate::Emoji),
-					"pcorn" => Some(&crate::flat::POPCORN as &crate::Emoji),
-					"rtugal" => Some(&crate::flat::FLAG_PORTUGAL as &crate::Emoji),
-					"st_office" => Some(&crate::flat::POST_OFFICE as &crate::Emoji),
-					"stal_horn" => Some(&crate::flat::POSTAL_HORN as &crate::Emoji),
-					"stbox" => Some(&crate::flat::POSTBOX as &crate::Emoji),
-					"t_of_food" => Some(&crate::flat::POT_OF_FOOD as &crate::Emoji),
-					"table_water" => Some(&crate::flat::POTABLE_WATER as &crate::Emoji),
-					"tato" => Some(&crate::flat::POTATO as &crate::Emoji),
-					"tted_plant" => Some(&crate::flat::POTTED_PLANT as &crate::Emoji),
-					"uch" => Some(&crate::flat::CLUTCH_BAG as &crate::Emoji),
-					"ultry_leg" => Some(&crate::flat::POULTRY_LEG as &crate::Emoji),
-					"und" => Some(&crate::flat::POUND_BANKNOTE as &crate::Emoji),
-					"und_banknote" => Some(&crate::flat::POUND_BANKNOTE as &crate::Emoji),
-					"ut" => Some(&crate::flat::POUTING_FACE as &crate::Emoji),
-					"uting_cat" => Some(&crate::flat::POUTING_CAT as &crate::Emoji),
-					"uting_face" => Some(&crate::flat::POUTING_FACE as &crate::Emoji),
-					"uting_man" => Some(&crate::flat::PERSON_POUTING.gender(Gender::Male).default as &crate::Emoji),
-					"uting_woman" => Some(&crate::flat::PERSON_POUTING.gender(Gender::Female).default as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"ay" => Some(&crate::flat::FOLDED_HANDS.default as &crate::Emoji),
-					"ayer_beads" => Some(&crate::flat::PRAYER_BEADS as &crate::Emoji),
-					"egnant_woman" => Some(&crate::flat::PREGNANT_WOMAN.default as &crate::Emoji),
-					"etzel" => Some(&crate::flat::PRETZEL as &crate::Emoji),
-					"evious_track_button" => Some(&crate::flat::LAST_TRACK_BUTTON as &crate::Emoji),
-					"ince" => Some(&crate::flat::PRINCE.default as &crate::Emoji),
-					"incess" => Some(&crate::flat::PRINCESS.default as &crate::Emoji),
-					"inter" => Some(&crate::flat::PRINTER as &crate::Emoji),
-					"obing_cane" => Some(&crate::flat::WHITE_CANE as &crate::Emoji),
-					"ohibited" => Some(&crate::flat::PROHIBITED as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"erto_rico" => Some(&crate::flat::FLAG_PUERTO_RICO as &crate::Emoji),
-					"nch" => Some(&crate::flat::ONCOMING_FIST.default as &crate::Emoji),
-					"rple_circle" => Some(&crate::flat::PURPLE_CIRCLE as &crate::Emoji),
-					"rple_heart" => Some(&crate::flat::PURPLE_HEART as &crate::Emoji),
-					"rple_square" => Some(&crate::flat::PURPLE_SQUARE as &crate::Emoji),
-					"rse" => Some(&crate::flat::PURSE as &crate::Emoji),
-					"shpin" => Some(&crate::flat::PUSHPIN as &crate::Emoji),
-					"t_litter_in_its_place" => Some(&crate::flat::LITTER_IN_BIN_SIGN as &crate::Emoji),
-					"zzle_piece" => Some(&crate::flat::PUZZLE_PIECE as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'q' => match sec {
-				b'a' => match rest {
-					"tar" => Some(&crate::flat::FLAG_QATAR as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"estion" => Some(&crate::flat::RED_QUESTION_MARK as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'r' => match sec {
-				b'a' => match rest {
-					"bbit" => Some(&crate::flat::RABBIT as &crate::Emoji),
-					"bbit2" => Some(&crate::flat::RABBIT as &crate::Emoji),
-					"bbit_face" => Some(&crate::flat::RABBIT_FACE as &crate::Emoji),
-					"ccoon" => Some(&crate::flat::RACCOON as &crate::Emoji),
-					"cehorse" => Some(&crate::flat::HORSE as &crate::Emoji),
-					"cing_car" => Some(&crate::flat::RACING_CAR as &crate::Emoji),
-					"dio" => Some(&crate::flat::RADIO as &crate::Emoji),
-					"dio_button" => Some(&crate::flat::RADIO_BUTTON as &crate::Emoji),
-					"dioactive" => Some(&crate::flat::RADIOACTIVE as &crate::Emoji),
-					"ge" => Some(&crate::flat::POUTING_FACE as &crate::Emoji),
-					"ilway_car" => Some(&crate::flat::RAILWAY_CAR as &crate::Emoji),
-					"ilway_track" => Some(&crate::flat::RAILWAY_TRACK as &crate::Emoji),
-					"inbow" => Some(&crate::flat::RAINBOW as &crate::Emoji),
-					"inbow_flag" => Some(&crate::flat::RAINBOW_FLAG as &crate::Emoji),
-					"ised_back_of_hand" => Some(&crate::flat::RAISED_BACK_OF_HAND.default as &crate::Emoji),
-					"ised_eyebrow" => Some(&crate::flat::FACE_WITH_RAISED_EYEBROW as &crate::Emoji),
-					"ised_fist" => Some(&crate::flat::RAISED_FIST.default as &crate::Emoji),
-					"ised_hand" => Some(&crate::flat::RAISED_HAND.default as &crate::Emoji),
-					"ised_hand_with_fingers_splayed" => Some(&crate::flat::HAND_WITH_FINGERS_SPLAYED.default as &crate::Emoji),
-					"ised_hands" => Some(&crate::flat::RAISING_HANDS.default as &crate::Emoji),
-					"ising_hand" => Some(&crate::flat::PERSON_RAISING_HAND.default.default as &crate::Emoji),
-					"ising_hand_man" => Some(&crate::flat::PERSON_RAISING_HAND.gender(Gender::Male).default as &crate::Emoji),
-					"ising_hand_woman" => Some(&crate::flat::PERSON_RAISING_HAND.gender(Gender::Female).default as &crate::Emoji),
-					"ising_hands" => Some(&crate::flat::RAISING_HANDS.default as &crate::Emoji),
-					"m" => Some(&crate::flat::RAM as &crate::Emoji),
-					"men" => Some(&crate::flat::STEAMING_BOWL as &crate::Emoji),
-					"t" => Some(&crate::flat::RAT as &crate::Emoji),
-					"zor" => Some(&crate::flat::RAZOR as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"ceipt" => Some(&crate::flat::RECEIPT as &crate::Emoji),
-					"cord_button" => Some(&crate::flat::RECORD_BUTTON as &crate::Emoji),
-					"cycle" => Some(&crate::flat::RECYCLING_SYMBOL as &crate::Emoji),
-					"cycling_symbol" => Some(&crate::flat::RECYCLING_SYMBOL as &crate::Emoji),
-					"d_apple" => Some(&crate::flat::RED_APPLE as &crate::Emoji),
-					"d_car" => Some(&crate::flat::AUTOMOBILE as &crate::Emoji),
-					"d_circle" => Some(&crate::flat::RED_CIRCLE as &crate::Emoji),
-					"d_envelope" => Some(&crate::flat::RED_ENVELOPE as &crate::Emoji),
-					"d_exclamation_mark" => Some(&crate::flat::RED_EXCLAMATION_MARK as &crate::Emoji),
-					"d_hair" => Some(&crate::flat::RED_HAIR as &crate::Emoji),
-					"d_haired_man" => Some(&crate::flat::PERSON.hair(Hair::Red).gender(Gender::Male).default as &crate::Emoji),
-					"d_haired_woman" => Some(&crate::flat::PERSON.hair(Hair::Red).gender(Gender::Female).default as &crate::Emoji),
-					"d_heart" => Some(&crate::flat::RED_HEART as &crate::Emoji),
-					"d_paper_lantern" => Some(&crate::flat::RED_PAPER_LANTERN as &crate::Emoji),
-					"d_question_mark" => Some(&crate::flat::RED_QUESTION_MARK as &crate::Emoji),
-					"d_square" => Some(&crate::flat::RED_SQUARE as &crate::Emoji),
-					"d_triangle_pointed_down" => Some(&crate::flat::RED_TRIANGLE_POINTED_DOWN as &crate::Emoji),
-					"d_triangle_pointed_up" => Some(&crate::flat::RED_TRIANGLE_POINTED_UP as &crate::Emoji),
-					"gistered" => Some(&crate::flat::REGISTERED as &crate::Emoji),
-					"laxed" => Some(&crate::flat::SMILING_FACE as &crate::Emoji),
-					"lieved" => Some(&crate::flat::RELIEVED_FACE as &crate::Emoji),
-					"lieved_face" => Some(&crate::flat::RELIEVED_FACE as &crate::Emoji),
-					"minder_ribbon" => Some(&crate::flat::REMINDER_RIBBON as &crate::Emoji),
-					"peat" => Some(&crate::flat::REPEAT_BUTTON as &crate::Emoji),
-					"peat_button" => Some(&crate::flat::REPEAT_BUTTON as &crate::Emoji),
-					"peat_one" => Some(&crate::flat::REPEAT_SINGLE_BUTTON as &crate::Emoji),
-					"peat_single_button" => Some(&crate::flat::REPEAT_SINGLE_BUTTON as &crate::Emoji),
-					"scue_worker_helmet" => Some(&crate::flat::RESCUE_WORKER_S_HELMET as &crate::Emoji),
-					"scue_worker_s_helmet" => Some(&crate::flat::RESCUE_WORKER_S_HELMET as &crate::Emoji),
-					"stroom" => Some(&crate::flat::RESTROOM as &crate::Emoji),
-					"union" => Some(&crate::flat::FLAG_REUNION as &crate::Emoji),
-					"verse_button" => Some(&crate::flat::REVERSE_BUTTON as &crate::Emoji),
-					"volving_hearts" => Some(&crate::flat::REVOLVING_HEARTS as &crate::Emoji),
-					"wind" => Some(&crate::flat::FAST_REVERSE_BUTTON as &crate::Emoji),
-					_ => None,
-				},
-				b'h' => match rest {
-					"inoceros" => Some(&crate::flat::RHINOCEROS as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"bbon" => Some(&crate::flat::RIBBON as &crate::Emoji),
-					"ce" => Some(&crate::flat::COOKED_RICE as &crate::Emoji),
-					"ce_ball" => Some(&crate::flat::RICE_BALL as &crate::Emoji),
-					"ce_cracker" => Some(&crate::flat::RICE_CRACKER as &crate::Emoji),
-					"ce_scene" => Some(&crate::flat::MOON_VIEWING_CEREMONY as &crate::Emoji),
-					"ght_anger_bubble" => Some(&crate::flat::RIGHT_ANGER_BUBBLE as &crate::Emoji),
-					"ght_arrow" => Some(&crate::flat::RIGHT_ARROW as &crate::Emoji),
-					"ght_arrow_curving_down" => Some(&crate::flat::RIGHT_ARROW_CURVING_DOWN as &crate::Emoji),
-					"ght_arrow_curving_left" => Some(&crate::flat::RIGHT_ARROW_CURVING_LEFT as &crate::Emoji),
-					"ght_arrow_curving_up" => Some(&crate::flat::RIGHT_ARROW_CURVING_UP as &crate::Emoji),
-					"ght_facing_fist" => Some(&crate::flat::RIGHT_FACING_FIST.default as &crate::Emoji),
-					"ng" => Some(&crate::flat::RING as &crate::Emoji),
-					"nged_planet" => Some(&crate::flat::RINGED_PLANET as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"asted_sweet_potato" => Some(&crate::flat::ROASTED_SWEET_POTATO as &crate::Emoji),
-					"bot" => Some(&crate::flat::ROBOT as &crate::Emoji),
-					"ck" => Some(&crate::flat::ROCK as &crate::Emoji),
-					"cket" => Some(&crate::flat::ROCKET as &crate::Emoji),
-					"fl" => Some(&crate::flat::ROLLING_ON_THE_FLOOR_LAUGHING as &crate::Emoji),
-					"ll_eyes" => Some(&crate::flat::FACE_WITH_ROLLING_EYES as &crate::Emoji),
-					"ll_of_paper" => Some(&crate::flat::ROLL_OF_PAPER as &crate::Emoji),
-					"lled_up_newspaper" => Some(&crate::flat::ROLLED_UP_NEWSPAPER as &crate::Emoji),
-					"ller_coaster" => Some(&crate::flat::ROLLER_COASTER as &crate::Emoji),
-					"ller_skate" => Some(&crate::flat::ROLLER_SKATE as &crate::Emoji),
-					"lling_on_the_floor_laughing" => Some(&crate::flat::ROLLING_ON_THE_FLOOR_LAUGHING as &crate::Emoji),
-					"mania" => Some(&crate::flat::FLAG_ROMANIA as &crate::Emoji),
-					"oster" => Some(&crate::flat::ROOSTER as &crate::Emoji),
-					"se" => Some(&crate::flat::ROSE as &crate::Emoji),
-					"sette" => Some(&crate::flat::ROSETTE as &crate::Emoji),
-					"tating_light" => Some(&crate::flat::POLICE_CAR_LIGHT as &crate::Emoji),
-					"und_pushpin" => Some(&crate::flat::ROUND_PUSHPIN as &crate::Emoji),
-					"wboat" => Some(&crate::flat::PERSON_ROWING_BOAT.default.default as &crate::Emoji),
-					"wing_man" => Some(&crate::flat::PERSON_ROWING_BOAT.gender(Gender::Male).default as &crate::Emoji),
-					"wing_woman" => Some(&crate::flat::PERSON_ROWING_BOAT.gender(Gender::Female).default as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"" => Some(&crate::flat::FLAG_RUSSIA as &crate::Emoji),
-					"gby_football" => Some(&crate::flat::RUGBY_FOOTBALL as &crate::Emoji),
-					"nner" => Some(&crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
-					"nning" => Some(&crate::flat::PERSON_RUNNING.default.default as &crate::Emoji),
-					"nning_man" => Some(&crate::flat::PERSON_RUNNING.gender(Gender::Male).default as &crate::Emoji),
-					"nning_shirt" => Some(&crate::flat::RUNNING_SHIRT as &crate::Emoji),
-					"nning_shirt_with_sash" => Some(&crate::flat::RUNNING_SHIRT as &crate::Emoji),
-					"nning_shoe" => Some(&crate::flat::RUNNING_SHOE as &crate::Emoji),
-					"nning_woman" => Some(&crate::flat::PERSON_RUNNING.gender(Gender::Female).default as &crate::Emoji),
-					_ => None,
-				},
-				b'w' => match rest {
-					"anda" => Some(&crate::flat::FLAG_RWANDA as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b's' => match sec {
-				b'a' => match rest {
-					"" => Some(&crate::flat::JAPANESE_SERVICE_CHARGE_BUTTON as &crate::Emoji),
-					"d_but_relieved_face" => Some(&crate::flat::SAD_BUT_RELIEVED_FACE as &crate::Emoji),
-					"fety_pin" => Some(&crate::flat::SAFETY_PIN as &crate::Emoji),
-					"fety_vest" => Some(&crate::flat::SAFETY_VEST as &crate::Emoji),
-					"gittarius" => Some(&crate::flat::SAGITTARIUS as &crate::Emoji),
-					"ilboat" => Some(&crate::flat::SAILBOAT as &crate::Emoji),
-					"ke" => Some(&crate::flat::SAKE as &crate::Emoji),
-					"lt" => Some(&crate::flat::SALT as &crate::Emoji),
-					"moa" => Some(&crate::flat::FLAG_SAMOA as &crate::Emoji),
-					"n_marino" => Some(&crate::flat::FLAG_SAN_MARINO as &crate::Emoji),
-					"ndal" => Some(&crate::flat::WOMAN_S_SANDAL as &crate::Emoji),
-					"ndwich" => Some(&crate::flat::SANDWICH as &crate::Emoji),
-					"nta" => Some(&crate::flat::SANTA_CLAUS.default as &crate::Emoji),
-					"nta_claus" => Some(&crate::flat::SANTA_CLAUS.default as &crate::Emoji),
-					"o_tome_principe" => Some(&crate::flat::FLAG_SAO_TOME_AND_PRINCIPE as &crate::Emoji),
-					"ri" => Some(&crate::flat::SARI as &crate::Emoji),
-					"ssy_man" => Some(&crate::flat::PERSON_TIPPING_HAND.gender(Gender::Male).default as &crate::Emoji),
-					"ssy_woman" => Some(&crate::flat::PERSON_TIPPING_HAND.gender(Gender::Female).default as &crate::Emoji),
-					"tellite" => Some(&crate::flat::SATELLITE as &crate::Emoji),
-					"tellite_antenna" => Some(&crate::flat::SATELLITE_ANTENNA as &crate::Emoji),
-					"tisfied" => Some(&crate::flat::GRINNING_SQUINTING_FACE as &crate::Emoji),
-					"udi_arabia" => Some(&crate::flat::FLAG_SAUDI_ARABIA as &crate::Emoji),
-					"una_man" => Some(&crate::flat::PERSON_IN_STEAMY_ROOM.gender(Gender::Male).default as &crate::Emoji),
-					"una_person" => Some(&crate::flat::PERSON_IN_STEAMY_ROOM.default.default as &crate::Emoji),
-					"una_woman" => Some(&crate::flat::PERSON_IN_STEAMY_ROOM.gender(Gender::Female).default as &crate::Emoji),
-					"uropod" => Some(&crate::flat::SAUROPOD as &crate::Emoji),
-					"xophone" => Some(&crate::flat::SAXOPHONE as &crate::Emoji),
-					_ => None,
-				},
-				b'c' => match rest {
-					"arf" => Some(&crate::flat::SCARF as &crate::Emoji),
-					"hool" => Some(&crate::flat::SCHOOL as &crate::Emoji),
-					"hool_satchel" => Some(&crate::flat::BACKPACK as &crate::Emoji),
-					"ientist" => Some(&crate::flat::SCIENTIST.default.default as &crate::Emoji),
-					"issors" => Some(&crate::flat::SCISSORS as &crate::Emoji),
-					"orpio" => Some(&crate::flat::SCORPIO as &crate::Emoji),
-					"orpion" => Some(&crate::flat::SCORPION as &crate::Emoji),
-					"orpius" => Some(&crate::flat::SCORPIO as &crate::Emoji),
-					"otland" => Some(&crate::flat::FLAG_SCOTLAND as &crate::Emoji),
-					"ream" => Some(&crate::flat::FACE_SCREAMING_IN_FEAR as &crate::Emoji),
-					"ream_cat" => Some(&crate::flat::WEARY_CAT as &crate::Emoji),
-					"rewdriver" => Some(&crate::flat::SCREWDRIVER as &crate::Emoji),
-					"roll" => Some(&crate::flat::SCROLL as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"al" => Some(&crate::flat::SEAL as &crate::Emoji),
-					"at" => Some(&crate::flat::SEAT as &crate::Emoji),
-					"cond_place_medal" => Some(&crate::flat::SECOND_PLACE_MEDAL as &crate::Emoji),
-					"cret" => Some(&crate::flat::JAPANESE_SECRET_BUTTON as &crate::Emoji),
-					"e_no_evil" => Some(&crate::flat::SEE_NO_EVIL_MONKEY as &crate::Emoji),
-					"e_no_evil_monkey" => Some(&crate::flat::SEE_NO_EVIL_MONKEY as &crate::Emoji),
-					"edling" => Some(&crate::flat::SEEDLING as &crate::Emoji),
-					"lfie" => Some(&crate::flat::SELFIE.default as &crate::Emoji),
-					"negal" => Some(&crate::flat::FLAG_SENEGAL as &crate::Emoji),
-					"rbia" => Some(&crate::flat::FLAG_SERBIA as &crate::Emoji),
-					"rvice_dog" => Some(&crate::flat::SERVICE_DOG as &crate::Emoji),
-					"ven" => Some(&crate::flat::KEYCAP_7 as &crate::Emoji),
-					"ven_o_clock" => Some(&crate::flat::SEVEN_O_CLOCK as &crate::Emoji),
-					"ven_thirty" => Some(&crate::flat::SEVEN_THIRTY as &crate::Emoji),
-					"wing_needle" => Some(&crate::flat::SEWING_NEEDLE as &crate::Emoji),
-					"ychelles" => Some(&crate::flat::FLAG_SEYCHELLES as &crate::Emoji),
-					_ => None,
-				},
-				b'h' => match rest {
-					"allow_pan_of_food" => Some(&crate::flat::SHALLOW_PAN_OF_FOOD as &crate::Emoji),
-					"amrock" => Some(&crate::flat::SHAMROCK as &crate::Emoji),
-					"ark" => Some(&crate::flat::SHARK as &crate::Emoji),
-					"aved_ice" => Some(&crate::flat::SHAVED_ICE as &crate::Emoji),
-					"eaf_of_rice" => Some(&crate::flat::SHEAF_OF_RICE as &crate::Emoji),
-					"eep" => Some(&crate::flat::EWE as &crate::Emoji),
-					"ell" => Some(&crate::flat::SPIRAL_SHELL as &crate::Emoji),
-					"ield" => Some(&crate::flat::SHIELD as &crate::Emoji),
-					"into_shrine" => Some(&crate::flat::SHINTO_SHRINE as &crate::Emoji),
-					"ip" => Some(&crate::flat::SHIP as &crate::Emoji),
-					"irt" => Some(&crate::flat::T_SHIRT as &crate::Emoji),
-					"it" => Some(&crate::flat::PILE_OF_POO as &crate::Emoji),
-					"oe" => Some(&crate::flat::MAN_S_SHOE as &crate::Emoji),
-					"ooting_star" => Some(&crate::flat::SHOOTING_STAR as &crate::Emoji),
-					"opping" => Some(&crate::flat::SHOPPING_BAGS as &crate::Emoji),
-					"opping_bags" => Some(&crate::flat::SHOPPING_BAGS as &crate::Emoji),
-					"opping_cart" => Some(&crate::flat::SHOPPING_CART as &crate::Emoji),
-					"ortcake" => Some(&crate::flat::SHORTCAKE as &crate::Emoji),
-					"orts" => Some(&crate::flat::SHORTS as &crate::Emoji),
-					"ower" => Some(&crate::flat::SHOWER as &crate::Emoji),
-					"rimp" => Some(&crate::flat::SHRIMP as &crate::Emoji),
-					"rug" => Some(&crate::flat::PERSON_SHRUGGING.default.default as &crate::Emoji),
-					"uffle_tracks_button" => Some(&crate::flat::SHUFFLE_TRACKS_BUTTON as &crate::Emoji),
-					"ushing_face" => Some(&crate::flat::SHUSHING_FACE as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"erra_leone" => Some(&crate::flat::FLAG_SIERRA_LEONE as &crate::Emoji),
-					"gn_of_the_horns" => Some(&crate::flat::SIGN_OF_THE_HORNS.default as &crate::Emoji),
-					"gnal_strength" => Some(&crate::flat::ANTENNA_BARS as &crate::Emoji),
-					"ngapore" => Some(&crate::flat::FLAG_SINGAPORE as &crate::Emoji),
-					"nger" => Some(&crate::flat::SINGER.default.default as &crate::Emoji),
-					"nt_maarten" => Some(&crate::flat::FLAG_SINT_MAARTEN as &crate::Emoji),
-					"x" => Some(&crate::flat::KEYCAP_6 as &crate::Emoji),
-					"x_o_clock" => Some(&crate::flat::SIX_O_CLOCK as &crate::Emoji),
-					"x_pointed_star" => Some(&crate::flat::DOTTED_SIX_POINTED_STAR as &crate::Emoji),
-					"x_thirty" => Some(&crate::flat::SIX_THIRTY as &crate::Emoji),
-					_ => None,
-				},
-				b'k' => match rest {
-					"ateboard" => Some(&crate::flat::SKATEBOARD as &crate::Emoji),
-					"i" => Some(&crate::flat::SKIS as &crate::Emoji),
-					"ier" => Some(&crate::flat::SKIER as &crate::Emoji),
-					"is" => Some(&crate::flat::SKIS as &crate::Emoji),
-					"ull" => Some(&crate::flat::SKULL as &crate::Emoji),
-					"ull_and_crossbones" => Some(&crate::flat::SKULL_AND_CROSSBONES as &crate::Emoji),
-					"unk" => Some(&crate::flat::SKUNK as &crate::Emoji),
-					_ => None,
-				},
-				b'l' => match rest {
-					"ed" => Some(&crate::flat::SLED as &crate::Emoji),
-					"eeping" => Some(&crate::flat::SLEEPING_FACE as &crate::Emoji),
-					"eeping_bed" => Some(&crate::flat::PERSON_IN_BED.default as &crate::Emoji),
-					"eeping_face" => Some(&crate::flat::SLEEPING_FACE as &crate::Emoji),
-					"eepy" => Some(&crate::flat::SLEEPY_FACE as &crate::Emoji),
-					"eepy_face" => Some(&crate::flat::SLEEPY_FACE as &crate::Emoji),
-					"ightly_frowning_face" => Some(&crate::flat::SLIGHTLY_FROWNING_FACE as &crate::Emoji),
-					"ightly_smiling_face" => Some(&crate::flat::SLIGHTLY_SMILING_FACE as &crate::Emoji),
-					"ot_machine" => Some(&crate::flat::SLOT_MACHINE as &crate::Emoji),
-					"oth" => Some(&crate::flat::SLOTH as &crate::Emoji),
-					"ovakia" => Some(&crate::flat::FLAG_SLOVAKIA as &crate::Emoji),
-					"ovenia" => Some(&crate::flat::FLAG_SLOVENIA as &crate::Emoji),
-					_ => None,
-				},
-				b'm' => match rest {
-					"all_airplane" => Some(&crate::flat::SMALL_AIRPLANE as &crate::Emoji),
-					"all_blue_diamond" => Some(&crate::flat::SMALL_BLUE_DIAMOND as &crate::Emoji),
-					"all_orange_diamond" => Some(&crate::flat::SMALL_ORANGE_DIAMOND as &crate::Emoji),
-					"all_red_triangle" => Some(&crate::flat::RED_TRIANGLE_POINTED_UP as &crate::Emoji),
-					"all_red_triangle_down" => Some(&crate::flat::RED_TRIANGLE_POINTED_DOWN as &crate::Emoji),
-					"ile" => Some(&crate::flat::GRINNING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-					"ile_cat" => Some(&crate::flat::GRINNING_CAT_WITH_SMILING_EYES as &crate::Emoji),
-					"iley" => Some(&crate::flat::GRINNING_FACE_WITH_BIG_EYES as &crate::Emoji),
-					"iley_cat" => Some(&crate::flat::GRINNING_CAT as &crate::Emoji),
-					"iling_cat_with_heart_eyes" => Some(&crate::flat::SMILING_CAT_WITH_HEART_EYES as &crate::Emoji),
-					"iling_face" => Some(&crate::flat::SMILING_FACE as &crate::Emoji),
-					"iling_face_with_halo" => Some(&crate::flat::SMILING_FACE_WITH_HALO as &crate::Emoji),
-					"iling_face_with_heart_eyes" => Some(&crate::flat::SMILING_FACE_WITH_HEART_EYES as &crate::Emoji),
-					"iling_face_with_hearts" => Some(&crate::flat::SMILING_FACE_WITH_HEARTS as &crate::Emoji),
-					"iling_face_with_horns" => Some(&crate::flat::SMILING_FACE_WITH_HORNS as &crate::Emoji),
-					"iling_face_with_smiling_eyes" => Some(&crate::flat::SMILING_FACE_WITH_SMILING_EYES as &crate::Emoji),
-					"iling_face_with_sunglasses" => Some(&crate::flat::SMILING_FACE_WITH_SUNGLASSES as &crate::Emoji),
-					"iling_face_with_tear" => Some(&crate::flat::SMILING_FACE_WITH_TEAR as &crate::Emoji),
-					"iling_face_with_three_hearts" => Some(&crate::flat::SMILING_FACE_WITH_HEARTS as &crate::Emoji),
-					"iling_imp" => Some(&crate::flat::SMILING_FACE_WITH_HORNS as &crate::Emoji),
-					"irk" => Some(&crate::flat::SMIRKING_FACE as &crate::Emoji),
-					"irk_cat" => Some(&crate::flat::CAT_WITH_WRY_SMILE as &crate::Emoji),
-					"irking_face" => Some(&crate::flat::SMIRKING_FACE as &crate::Emoji),
-					"oking" => Some(&crate::flat::CIGARETTE as &crate::Emoji),
-					_ => None,
-				},
-				b'n' => match rest {
-					"ail" => Some(&crate::flat::SNAIL as &crate::Emoji),
-					"ake" => Some(&crate::flat::SNAKE as &crate::Emoji),
-					"eezing_face" => Some(&crate::flat::SNEEZING_FACE as &crate::Emoji),
-					"ow_capped_mountain" => Some(&crate::flat::SNOW_CAPPED_MOUNTAIN as &crate::Emoji),
-					"owboarder" => Some(&crate::flat::SNOWBOARDER.default as &crate::Emoji),
-					"owflake" => Some(&crate::flat::SNOWFLAKE as &crate::Emoji),
-					"owman" => Some(&crate::flat::SNOWMAN as &crate::Emoji),
-					"owman_with_snow" => Some(&crate::flat::SNOWMAN as &crate::Emoji),
-					"owman_without_snow" => Some(&crate::flat::SNOWMAN_WITHOUT_SNOW as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"ap" => Some(&crate::flat::SOAP as &crate::Emoji),
-					"b" => Some(&crate::flat::LOUDLY_CRYING_FACE as &crate::Emoji),
-					"ccer" => Some(&crate::flat::SOCCER_BALL as &crate::Emoji),
-					"ccer_ball" => Some(&crate::flat::SOCCER_BALL as &crate::Emoji),
-					"cks" => Some(&crate::flat::SOCKS as &crate::Emoji),
-					"ft_ice_cream" => Some(&crate::flat::SOFT_ICE_CREAM as &crate::Emoji),
-					"ftball" => Some(&crate::flat::SOFTBALL as &crate::Emoji),
-					"lomon_islands" => Some(&crate::flat::FLAG_SOLOMON_ISLANDS as &crate::Emoji),
-					"malia" => Some(&crate::flat::FLAG_SOMALIA as &crate::Emoji),
-					"on" => Some(&crate::flat::SOON_ARROW as &crate::Emoji),
-					"on_arrow" => Some(&crate::flat::SOON_ARROW as &crate::Emoji),
-					"s" => Some(&crate::flat::SOS_BUTTON as &crate::Emoji),
-					"s_button" => Some(&crate::flat::SOS_BUTTON as &crate::Emoji),
-					"und" => Some(&crate::flat::SPEAKER_MEDIUM_VOLUME as &crate::Emoji),
-					"uth_africa" => Some(&crate::flat::FLAG_SOUTH_AFRICA as &crate::Emoji),
-					"uth_georgia_south_sandwich_islands" => Some(&crate::flat::FLAG_SOUTH_GEORGIA_AND_SOUTH_SANDWICH_ISLANDS as &crate::Emoji),
-					"uth_sudan" => Some(&crate::flat::FLAG_SOUTH_SUDAN as &crate::Emoji),
-					_ => None,
-				},
-				b'p' => match rest {
-					"ace_invader" => Some(&crate::flat::ALIEN_MONSTER as &crate::Emoji),
-					"ade_suit" => Some(&crate::flat::SPADE_SUIT as &crate::Emoji),
-					"ades" => Some(&crate::flat::SPADE_SUIT as &crate::Emoji),
-					"aghetti" => Some(&crate::flat::SPAGHETTI as &crate::Emoji),
-					"arkle" => Some(&crate::flat::SPARKLE as &crate::Emoji),
-					"arkler" => Some(&crate::flat::SPARKLER as &crate::Emoji),
-					"arkles" => Some(&crate::flat::SPARKLES as &crate::Emoji),
-					"arkling_heart" => Some(&crate::flat::SPARKLING_HEART as &crate::Emoji),
-					"eak_no_evil" => Some(&crate::flat::SPEAK_NO_EVIL_MONKEY as &crate::Emoji),
-					"eak_no_evil_monkey" => Some(&crate::flat::SPEAK_NO_EVIL_MONKEY as &crate::Emoji),
-					"eaker" => Some(&crate::flat::SPEAKER_LOW_VOLUME as &crate::Emoji),
-					"eaker_high_volume" => Some(&crate::flat::SPEAKER_HIGH_VOLUME as &crate::Emoji),
-					"eaker_low_volume" => Some(&crate::flat::SPEAKER_LOW_VOLUME as &crate::Emoji),
-					"eaker_medium_volume" => Some(&crate::flat::SPEAKER_MEDIUM_VOLUME as &crate::Emoji),
-					"eaking_head" => Some(&crate::flat::SPEAKING_HEAD as &crate::Emoji),
-					"eech_balloon" => Some(&crate::flat::SPEECH_BALLOON as &crate::Emoji),
-					"eedboat" => Some(&crate::flat::SPEEDBOAT as &crate::Emoji),
-					"ider" => Some(&crate::flat::SPIDER as &crate::Emoji),
-					"ider_web" => Some(&crate::flat::SPIDER_WEB as &crate::Emoji),
-					"iral_calendar" => Some(&crate::flat::SPIRAL_CALENDAR as &crate::Emoji),
-					"iral_notepad" => Some(&crate::flat::SPIRAL_NOTEPAD as &crate::Emoji),
-					"iral_shell" => Some(&crate::flat::SPIRAL_SHELL as &crate::Emoji),
-					"onge" => Some(&crate::flat::SPONGE as &crate::Emoji),
-					"oon" => Some(&crate::flat::SPOON as &crate::Emoji),
-					"ort_utility_vehicle" => Some(&crate::flat::SPORT_UTILITY_VEHICLE as &crate::Emoji),
-					"orts_medal" => Some(&crate::flat::SPORTS_MEDAL as &crate::Emoji),
-					"outing_whale" => Some(&crate::flat::SPOUTING_WHALE as &crate::Emoji),
-					_ => None,
-				},
-				b'q' => match rest {
-					"uid" => Some(&crate::flat::SQUID as &crate::Emoji),
-					"uinting_face_with_tongue" => Some(&crate::flat::SQUINTING_FACE_WITH_TONGUE as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"i_lanka" => Some(&crate::flat::FLAG_SRI_LANKA as &crate::Emoji),
-					_ => None,
-				},
-				b't' => match rest {
-					"_barthelemy" => Some(&crate::flat::FLAG_ST_BARTHELEMY as &crate::Emoji),
-					"_helena" => Some(&crate::flat::FLAG_ST_HELENA as &crate::Emoji),
-					"_kitts_nevis" => Some(&crate::flat::FLAG_ST_KITTS_AND_NEVIS as &crate::Emoji),
-					"_lucia" => Some(&crate::flat::FLAG_ST_LUCIA as &crate::Emoji),
-					"_martin" => Some(&crate::flat::FLAG_ST_MARTIN as &crate::Emoji),
-					"_pierre_miquelon" => Some(&crate::flat::FLAG_ST_PIERRE_AND_MIQUELON as &crate::Emoji),
-					"_vincent_grenadines" => Some(&crate::flat::FLAG_ST_VINCENT_AND_GRENADINES as &crate::Emoji),
-					"adium" => Some(&crate::flat::STADIUM as &crate::Emoji),
-					"anding_man" => Some(&crate::flat::PERSON_STANDING.gender(Gender::Male).default as &crate::Emoji),
-					"anding_person" => Some(&crate::flat::PERSON_STANDING.default.default as &crate::Emoji),
-					"anding_woman" => Some(&crate::flat::PERSON_STANDING.gender(Gender::Female).default as &crate::Emoji),
-					"ar" => Some(&crate::flat::STAR as &crate::Emoji),
-					"ar2" => Some(&crate::flat::GLOWING_STAR as &crate::Emoji),
-					"ar_and_crescent" => Some(&crate::flat::STAR_AND_CRESCENT as &crate::Emoji),
-					"ar_of_david" => Some(&crate::flat::STAR_OF_DAVID as &crate::Emoji),
-					"ar_struck" => Some(&crate::flat::STAR_STRUCK as &crate::Emoji),
-					"ars" => Some(&crate::flat::SHOOTING_STAR as &crate::Emoji),
-					"ation" => Some(&crate::flat::STATION as &crate::Emoji),
-					"atue_of_liberty" => Some(&crate::flat::STATUE_OF_LIBERTY as &crate::Emoji),
-					"eam_locomotive" => Some(&crate::flat::LOCOMOTIVE as &crate::Emoji),
-					"eaming_bowl" => Some(&crate::flat::STEAMING_BOWL as &crate::Emoji),
-					"ethoscope" => Some(&crate::flat::STETHOSCOPE as &crate::Emoji),
-					"ew" => Some(&crate::flat::POT_OF_FOOD as &crate::Emoji),
-					"op_button" => Some(&crate::flat::STOP_BUTTON as &crate::Emoji),
-					"op_sign" => Some(&crate::flat::STOP_SIGN as &crate::Emoji),
-					"opwatch" => Some(&crate::flat::STOPWATCH as &crate::Emoji),
-					"raight_ruler" => Some(&crate::flat::STRAIGHT_RULER as &crate::Emoji),
-					"rawberry" => Some(&crate::flat::STRAWBERRY as &crate::Emoji),
-					"uck_out_tongue" => Some(&crate::flat::FACE_WITH_TONGUE as &crate::Emoji),
-					"uck_out_tongue_closed_eyes" => Some(&crate::flat::SQUINTING_FACE_WITH_TONGUE as &crate::Emoji),
-					"uck_out_tongue_winking_eye" => Some(&crate::flat::WINKING_FACE_WITH_TONGUE as &crate::Emoji),
-					"udent" => Some(&crate::flat::STUDENT.default.default as &crate::Emoji),
-					"udio_microphone" => Some(&crate::flat::STUDIO_MICROPHONE as &crate::Emoji),
-					"uffed_flatbread" => Some(&crate::flat::STUFFED_FLATBREAD as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"dan" => Some(&crate::flat::FLAG_SUDAN as &crate::Emoji),
-					"n" => Some(&crate::flat::SUN as &crate::Emoji),
-					"n_behind_cloud" => Some(&crate::flat::SUN_BEHIND_CLOUD as &crate::Emoji),
-					"n_behind_large_cloud" => Some(&crate::flat::SUN_BEHIND_LARGE_CLOUD as &crate::Emoji),
-					"n_behind_rain_cloud" => Some(&crate::flat::SUN_BEHIND_RAIN_CLOUD as &crate::Emoji),
-					"n_behind_small_cloud" => Some(&crate::flat::SUN_BEHIND_SMALL_CLOUD as &crate::Emoji),
-					"n_with_face" => Some(&crate::flat::SUN_WITH_FACE as &crate::Emoji),
-					"nflower" => Some(&crate::flat::SUNFLOWER as &crate::Emoji),
-					"nglasses" => Some(&crate::flat::SUNGLASSES as &crate::Emoji),
-					"nny" => Some(&crate::flat::SUN as &crate::Emoji),
-					"nrise" => Some(&crate::flat::SUNRISE as &crate::Emoji),
-					"nrise_over_mountains" => Some(&crate::flat::SUNRISE_OVER_MOUNTAINS as &crate::Emoji),
-					"nset" => Some(&crate::flat::SUNSET as &crate::Emoji),
-					"perhero" => Some(&crate::flat::SUPERHERO.default.default as &crate::Emoji),
-					"perhero_man" => Some(&crate::flat::SUPERHERO.gender(Gender::Male).default as &crate::Emoji),
-					"perhero_woman" => Some(&crate::flat::SUPERHERO.gender(Gender::Female).default as &crate::Emoji),
-					"pervillain" => Some(&crate::flat::SUPERVILLAIN.default.default as &crate::Emoji),
-					"pervillain_man" => Some(&crate::flat::SUPERVILLAIN.gender(Gender::Male).default as &crate::Emoji),
-					"pervillain_woman" => Some(&crate::flat::SUPERVILLAIN.gender(Gender::Female).default as &crate::Emoji),
-					"rfer" => Some(&crate::flat::PERSON_SURFING.default.default as &crate::Emoji),
-					"rfing_man" => Some(&crate::flat::PERSON_SURFING.gender(Gender::Male).default as &crate::Emoji),
-					"rfing_woman" => Some(&crate::flat::PERSON_SURFING.gender(Gender::Female).default as &crate::Emoji),
-					"riname" => Some(&crate::flat::FLAG_SURINAME as &crate::Emoji),
-					"shi" => Some(&crate::flat::SUSHI as &crate::Emoji),
-					"spension_railway" => Some(&crate::flat::SUSPENSION_RAILWAY as &crate::Emoji),
-					_ => None,
-				},
-				b'v' => match rest {
-					"albard_jan_mayen" => Some(&crate::flat::FLAG_SVALBARD_AND_JAN_MAYEN as &crate::Emoji),
-					_ => None,
-				},
-				b'w' => match rest {
-					"an" => Some(&crate::flat::SWAN as &crate::Emoji),
-					"aziland" => Some(&crate::flat::FLAG_ESWATINI as &crate::Emoji),
-					"eat" => Some(&crate::flat::DOWNCAST_FACE_WITH_SWEAT as &crate::Emoji),
-					"eat_droplets" => Some(&crate::flat::SWEAT_DROPLETS as &crate::Emoji),
-					"eat_drops" => Some(&crate::flat::SWEAT_DROPLETS as &crate::Emoji),
-					"eat_smile" => Some(&crate::flat::GRINNING_FACE_WITH_SWEAT as &crate::Emoji),
-					"eden" => Some(&crate::flat::FLAG_SWEDEN as &crate::Emoji),
-					"eet_potato" => Some(&crate::flat::ROASTED_SWEET_POTATO as &crate::Emoji),
-					"im_brief" => Some(&crate::flat::BRIEFS as &crate::Emoji),
-					"immer" => Some(&crate::flat::PERSON_SWIMMING.default.default as &crate::Emoji),
-					"imming_man" => Some(&crate::flat::PERSON_SWIMMING.gender(Gender::Male).default as &crate::Emoji),
-					"imming_woman" => Some(&crate::flat::PERSON_SWIMMING.gender(Gender::Female).default as &crate::Emoji),
-					"itzerland" => Some(&crate::flat::FLAG_SWITZERLAND as &crate::Emoji),
-					_ => None,
-				},
-				b'y' => match rest {
-					"mbols" => Some(&crate::flat::INPUT_SYMBOLS as &crate::Emoji),
-					"nagogue" => Some(&crate::flat::SYNAGOGUE as &crate::Emoji),
-					"ria" => Some(&crate::flat::FLAG_SYRIA as &crate::Emoji),
-					"ringe" => Some(&crate::flat::SYRINGE as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b't' => match sec {
-				b'-' => match rest {
-					"rex" => Some(&crate::flat::T_REX as &crate::Emoji),
-					_ => None,
-				},
-				b'_' => match rest {
-					"rex" => Some(&crate::flat::T_REX as &crate::Emoji),
-					"shirt" => Some(&crate::flat::T_SHIRT as &crate::Emoji),
-					_ => None,
-				},
-				b'a' => match rest {
-					"co" => Some(&crate::flat::TACO as &crate::Emoji),
-					"da" => Some(&crate::flat::PARTY_POPPER as &crate::Emoji),
-					"iwan" => Some(&crate::flat::FLAG_TAIWAN as &crate::Emoji),
-					"jikistan" => Some(&crate::flat::FLAG_TAJIKISTAN as &crate::Emoji),
-					"keout_box" => Some(&crate::flat::TAKEOUT_BOX as &crate::Emoji),
-					"male" => Some(&crate::flat::TAMALE as &crate::Emoji),
-					"nabata_tree" => Some(&crate::flat::TANABATA_TREE as &crate::Emoji),
-					"ngerine" => Some(&crate::flat::TANGERINE as &crate::Emoji),
-					"nzania" => Some(&crate::flat::FLAG_TANZANIA as &crate::Emoji),
-					"urus" => Some(&crate::flat::TAURUS as &crate::Emoji),
-					"xi" => Some(&crate::flat::TAXI as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"a" => Some(&crate::flat::TEACUP_WITHOUT_HANDLE as &crate::Emoji),
-					"acher" => Some(&crate::flat::TEACHER.default.default as &crate::Emoji),
-					"acup_without_handle" => Some(&crate::flat::TEACUP_WITHOUT_HANDLE as &crate::Emoji),
-					"apot" => Some(&crate::flat::TEAPOT as &crate::Emoji),
-					"ar_off_calendar" => Some(&crate::flat::TEAR_OFF_CALENDAR as &crate::Emoji),
-					"chnologist" => Some(&crate::flat::TECHNOLOGIST.default.default as &crate::Emoji),
-					"ddy_bear" => Some(&crate::flat::TEDDY_BEAR as &crate::Emoji),
-					"lephone" => Some(&crate::flat::TELEPHONE as &crate::Emoji),
-					"lephone_receiver" => Some(&crate::flat::TELEPHONE_RECEIVER as &crate::Emoji),
-					"lescope" => Some(&crate::flat::TELESCOPE as &crate::Emoji),
-					"levision" => Some(&crate::flat::TELEVISION as &crate::Emoji),
-					"n_o_clock" => Some(&crate::flat::TEN_O_CLOCK as &crate::Emoji),
-					"n_thirty" => Some(&crate::flat::TEN_THIRTY as &crate::Emoji),
-					"nnis" => Some(&crate::flat::TENNIS as &crate::Emoji),
-					"nt" => Some(&crate::flat::TENT as &crate::Emoji),
-					"st_tube" => Some(&crate::flat::TEST_TUBE as &crate::Emoji),
-					_ => None,
-				},
-				b'h' => match rest {
-					"ailand" => Some(&crate::flat::FLAG_THAILAND as &crate::Emoji),
-					"ermometer" => Some(&crate::flat::THERMOMETER as &crate::Emoji),
-					"inking" => Some(&crate::flat::THINKING_FACE as &crate::Emoji),
-					"inking_face" => Some(&crate::flat::THINKING_FACE as &crate::Emoji),
-					"ird_place_medal" => Some(&crate::flat::THIRD_PLACE_MEDAL as &crate::Emoji),
-					"ong_sandal" => Some(&crate::flat::THONG_SANDAL as &crate::Emoji),
-					"ought_balloon" => Some(&crate::flat::THOUGHT_BALLOON as &crate::Emoji),
-					"read" => Some(&crate::flat::THREAD as &crate::Emoji),
-					"ree" => Some(&crate::flat::KEYCAP_3 as &crate::Emoji),
-					"ree_o_clock" => Some(&crate::flat::THREE_O_CLOCK as &crate::Emoji),
-					"ree_thirty" => Some(&crate::flat::THREE_THIRTY as &crate::Emoji),
-					"umbs_down" => Some(&crate::flat::THUMBS_DOWN.default as &crate::Emoji),
-					"umbs_up" => Some(&crate::flat::THUMBS_UP.default as &crate::Emoji),
-					"umbsdown" => Some(&crate::flat::THUMBS_DOWN.default as &crate::Emoji),
-					"umbsup" => Some(&crate::flat::THUMBS_UP.default as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"cket" => Some(&crate::flat::TICKET as &crate::Emoji),
-					"ckets" => Some(&crate::flat::ADMISSION_TICKETS as &crate::Emoji),
-					"ger" => Some(&crate::flat::TIGER as &crate::Emoji),
-					"ger2" => Some(&crate::flat::TIGER as &crate::Emoji),
-					"ger_face" => Some(&crate::flat::TIGER_FACE as &crate::Emoji),
-					"mer_clock" => Some(&crate::flat::TIMER_CLOCK as &crate::Emoji),
-					"mor_leste" => Some(&crate::flat::FLAG_TIMOR_LESTE as &crate::Emoji),
-					"pping_hand_man" => Some(&crate::flat::PERSON_TIPPING_HAND.gender(Gender::Male).default as &crate::Emoji),
-					"pping_hand_person" => Some(&crate::flat::PERSON_TIPPING_HAND.default.default as &crate::Emoji),
-					"pping_hand_woman" => Some(&crate::flat::PERSON_TIPPING_HAND.gender(Gender::Female).default as &crate::Emoji),
-					"red_face" => Some(&crate::flat::TIRED_FACE as &crate::Emoji),
-					_ => None,
-				},
-				b'm' => match rest {
-					"" => Some(&crate::flat::TRADE_MARK as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"go" => Some(&crate::flat::FLAG_TOGO as &crate::Emoji),
-					"ilet" => Some(&crate::flat::TOILET as &crate::Emoji),
-					"kelau" => Some(&crate::flat::FLAG_TOKELAU as &crate::Emoji),
-					"kyo_tower" => Some(&crate::flat::TOKYO_TOWER as &crate::Emoji),
-					"mato" => Some(&crate::flat::TOMATO as &crate::Emoji),
-					"nga" => Some(&crate::flat::FLAG_TONGA as &crate::Emoji),
-					"ngue" => Some(&crate::flat::TONGUE as &crate::Emoji),
-					"olbox" => Some(&crate::flat::TOOLBOX as &crate::Emoji),
-					"oth" => Some(&crate::flat::TOOTH as &crate::Emoji),
-					"othbrush" => Some(&crate::flat::TOOTHBRUSH as &crate::Emoji),
-					"p" => Some(&crate::flat::TOP_ARROW as &crate::Emoji),
-					"p_arrow" => Some(&crate::flat::TOP_ARROW as &crate::Emoji),
-					"p_hat" => Some(&crate::flat::TOP_HAT as &crate::Emoji),
-					"phat" => Some(&crate::flat::TOP_HAT as &crate::Emoji),
-					"rnado" => Some(&crate::flat::TORNADO as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"" => Some(&crate::flat::FLAG_TURKEY as &crate::Emoji),
-					"ackball" => Some(&crate::flat::TRACKBALL as &crate::Emoji),
-					"actor" => Some(&crate::flat::TRACTOR as &crate::Emoji),
-					"ade_mark" => Some(&crate::flat::TRADE_MARK as &crate::Emoji),
-					"affic_light" => Some(&crate::flat::HORIZONTAL_TRAFFIC_LIGHT as &crate::Emoji),
-					"ain" => Some(&crate::flat::TRAIN as &crate::Emoji),
-					"ain2" => Some(&crate::flat::TRAIN as &crate::Emoji),
-					"am" => Some(&crate::flat::TRAM as &crate::Emoji),
-					"am_car" => Some(&crate::flat::TRAM_CAR as &crate::Emoji),
-					"ansgender_flag" => Some(&crate::flat::TRANSGENDER_FLAG as &crate::Emoji),
-					"ansgender_symbol" => Some(&crate::flat::TRANSGENDER_SYMBOL as &crate::Emoji),
-					"iangular_flag" => Some(&crate::flat::TRIANGULAR_FLAG as &crate::Emoji),
-					"iangular_flag_on_post" => Some(&crate::flat::TRIANGULAR_FLAG as &crate::Emoji),
-					"iangular_ruler" => Some(&crate::flat::TRIANGULAR_RULER as &crate::Emoji),
-					"ident" => Some(&crate::flat::TRIDENT_EMBLEM as &crate::Emoji),
-					"ident_emblem" => Some(&crate::flat::TRIDENT_EMBLEM as &crate::Emoji),
-					"inidad_tobago" => Some(&crate::flat::FLAG_TRINIDAD_AND_TOBAGO as &crate::Emoji),
-					"istan_da_cunha" => Some(&crate::flat::FLAG_TRISTAN_DA_CUNHA as &crate::Emoji),
-					"iumph" => Some(&crate::flat::FACE_WITH_STEAM_FROM_NOSE as &crate::Emoji),
-					"olleybus" => Some(&crate::flat::TROLLEYBUS as &crate::Emoji),
-					"ophy" => Some(&crate::flat::TROPHY as &crate::Emoji),
-					"opical_drink" => Some(&crate::flat::TROPICAL_DRINK as &crate::Emoji),
-					"opical_fish" => Some(&crate::flat::TROPICAL_FISH as &crate::Emoji),
-					"uck" => Some(&crate::flat::DELIVERY_TRUCK as &crate::Emoji),
-					"umpet" => Some(&crate::flat::TRUMPET as &crate::Emoji),
-					_ => None,
-				},
-				b's' => match rest {
-					"hirt" => Some(&crate::flat::T_SHIRT as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"lip" => Some(&crate::flat::TULIP as &crate::Emoji),
-					"mbler_glass" => Some(&crate::flat::TUMBLER_GLASS as &crate::Emoji),
-					"nisia" => Some(&crate::flat::FLAG_TUNISIA as &crate::Emoji),
-					"rkey" => Some(&crate::flat::TURKEY as &crate::Emoji),
-					"rkmenistan" => Some(&crate::flat::FLAG_TURKMENISTAN as &crate::Emoji),
-					"rks_caicos_islands" => Some(&crate::flat::FLAG_TURKS_AND_CAICOS_ISLANDS as &crate::Emoji),
-					"rtle" => Some(&crate::flat::TURTLE as &crate::Emoji),
-					"valu" => Some(&crate::flat::FLAG_TUVALU as &crate::Emoji),
-					_ => None,
-				},
-				b'v' => match rest {
-					"" => Some(&crate::flat::TELEVISION as &crate::Emoji),
-					_ => None,
-				},
-				b'w' => match rest {
-					"elve_o_clock" => Some(&crate::flat::TWELVE_O_CLOCK as &crate::Emoji),
-					"elve_thirty" => Some(&crate::flat::TWELVE_THIRTY as &crate::Emoji),
-					"isted_rightwards_arrows" => Some(&crate::flat::SHUFFLE_TRACKS_BUTTON as &crate::Emoji),
-					"o" => Some(&crate::flat::KEYCAP_2 as &crate::Emoji),
-					"o_hearts" => Some(&crate::flat::TWO_HEARTS as &crate::Emoji),
-					"o_hump_camel" => Some(&crate::flat::TWO_HUMP_CAMEL as &crate::Emoji),
-					"o_men_holding_hands" => Some(&crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Males).default as &crate::Emoji),
-					"o_o_clock" => Some(&crate::flat::TWO_O_CLOCK as &crate::Emoji),
-					"o_thirty" => Some(&crate::flat::TWO_THIRTY as &crate::Emoji),
-					"o_women_holding_hands" => Some(&crate::flat::PERSON_HOLDING_HANDS.pair(Pair::Females).default as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'u' => match sec {
-				b'5' => match rest {
-					"272" => Some(&crate::flat::JAPANESE_DISCOUNT_BUTTON as &crate::Emoji),
-					"408" => Some(&crate::flat::JAPANESE_PASSING_GRADE_BUTTON as &crate::Emoji),
-					"5b6" => Some(&crate::flat::JAPANESE_OPEN_FOR_BUSINESS_BUTTON as &crate::Emoji),
-					_ => None,
-				},
-				b'6' => match rest {
-					"307" => Some(&crate::flat::JAPANESE_RESERVED_BUTTON as &crate::Emoji),
-					"708" => Some(&crate::flat::JAPANESE_MONTHLY_AMOUNT_BUTTON as &crate::Emoji),
-					"709" => Some(&crate::flat::JAPANESE_NOT_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
-					"e80" => Some(&crate::flat::JAPANESE_NO_VACANCY_BUTTON as &crate::Emoji),
-					_ => None,
-				},
-				b'7' => match rest {
-					"121" => Some(&crate::flat::JAPANESE_FREE_OF_CHARGE_BUTTON as &crate::Emoji),
-					"533" => Some(&crate::flat::JAPANESE_APPLICATION_BUTTON as &crate::Emoji),
-					"981" => Some(&crate::flat::JAPANESE_PROHIBITED_BUTTON as &crate::Emoji),
-					"a7a" => Some(&crate::flat::JAPANESE_VACANCY_BUTTON as &crate::Emoji),
-					_ => None,
-				},
-				b'g' => match rest {
-					"anda" => Some(&crate::flat::FLAG_UGANDA as &crate::Emoji),
-					_ => None,
-				},
-				b'k' => match rest {
-					"" => Some(&crate::flat::FLAG_UNITED_KINGDOM as &crate::Emoji),
-					"raine" => Some(&crate::flat::FLAG_UKRAINE as &crate::Emoji),
-					_ => None,
-				},
-				b'm' => match rest {
-					"brella" => Some(&crate::flat::UMBRELLA as &crate::Emoji),
-					"brella_on_ground" => Some(&crate::flat::UMBRELLA_ON_GROUND as &crate::Emoji),
-					"brella_with_rain_drops" => Some(&crate::flat::UMBRELLA_WITH_RAIN_DROPS as &crate::Emoji),
-					_ => None,
-				},
-				b'n' => match rest {
-					"amused" => Some(&crate::flat::UNAMUSED_FACE as &crate::Emoji),
-					"amused_face" => Some(&crate::flat::UNAMUSED_FACE as &crate::Emoji),
-					"derage" => Some(&crate::flat::NO_ONE_UNDER_EIGHTEEN as &crate::Emoji),
-					"icorn" => Some(&crate::flat::UNICORN as &crate::Emoji),
-					"ited_arab_emirates" => Some(&crate::flat::FLAG_UNITED_ARAB_EMIRATES as &crate::Emoji),
-					"ited_nations" => Some(&crate::flat::FLAG_UNITED_NATIONS as &crate::Emoji),
-					"lock" => Some(&crate::flat::UNLOCKED as &crate::Emoji),
-					"locked" => Some(&crate::flat::UNLOCKED as &crate::Emoji),
-					_ => None,
-				},
-				b'p' => match rest {
-					"" => Some(&crate::flat::UP_BUTTON as &crate::Emoji),
-					"_arrow" => Some(&crate::flat::UP_ARROW as &crate::Emoji),
-					"_button" => Some(&crate::flat::UP_BUTTON as &crate::Emoji),
-					"_down_arrow" => Some(&crate::flat::UP_DOWN_ARROW as &crate::Emoji),
-					"_left_arrow" => Some(&crate::flat::UP_LEFT_ARROW as &crate::Emoji),
-					"_right_arrow" => Some(&crate::flat::UP_RIGHT_ARROW as &crate::Emoji),
-					"side_down_face" => Some(&crate::flat::UPSIDE_DOWN_FACE as &crate::Emoji),
-					"wards_button" => Some(&crate::flat::UPWARDS_BUTTON as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"uguay" => Some(&crate::flat::FLAG_URUGUAY as &crate::Emoji),
-					_ => None,
-				},
-				b's' => match rest {
-					"" => Some(&crate::flat::FLAG_UNITED_STATES as &crate::Emoji),
-					"_outlying_islands" => Some(&crate::flat::FLAG_US_OUTLYING_ISLANDS as &crate::Emoji),
-					"_virgin_islands" => Some(&crate::flat::FLAG_US_VIRGIN_ISLANDS as &crate::Emoji),
-					_ => None,
-				},
-				b'z' => match rest {
-					"bekistan" => Some(&crate::flat::FLAG_UZBEKISTAN as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'v' => match sec {
-				b'a' => match rest {
-					"mpire" => Some(&crate::flat::VAMPIRE.default.default as &crate::Emoji),
-					"mpire_man" => Some(&crate::flat::VAMPIRE.gender(Gender::Male).default as &crate::Emoji),
-					"mpire_woman" => Some(&crate::flat::VAMPIRE.gender(Gender::Female).default as &crate::Emoji),
-					"nuatu" => Some(&crate::flat::FLAG_VANUATU as &crate::Emoji),
-					"tican_city" => Some(&crate::flat::FLAG_VATICAN_CITY as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"nezuela" => Some(&crate::flat::FLAG_VENEZUELA as &crate::Emoji),
-					"rtical_traffic_light" => Some(&crate::flat::VERTICAL_TRAFFIC_LIGHT as &crate::Emoji),
-					_ => None,
-				},
-				b'h' => match rest {
-					"s" => Some(&crate::flat::VIDEOCASSETTE as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"bration_mode" => Some(&crate::flat::VIBRATION_MODE as &crate::Emoji),
-					"ctory_hand" => Some(&crate::flat::VICTORY_HAND.default as &crate::Emoji),
-					"deo_camera" => Some(&crate::flat::VIDEO_CAMERA as &crate::Emoji),
-					"deo_game" => Some(&crate::flat::VIDEO_GAME as &crate::Emoji),
-					"deocassette" => Some(&crate::flat::VIDEOCASSETTE as &crate::Emoji),
-					"etnam" => Some(&crate::flat::FLAG_VIETNAM as &crate::Emoji),
-					"olin" => Some(&crate::flat::VIOLIN as &crate::Emoji),
-					"rgo" => Some(&crate::flat::VIRGO as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"lcano" => Some(&crate::flat::VOLCANO as &crate::Emoji),
-					"lleyball" => Some(&crate::flat::VOLLEYBALL as &crate::Emoji),
-					"miting_face" => Some(&crate::flat::FACE_VOMITING as &crate::Emoji),
-					_ => None,
-				},
-				b's' => match rest {
-					"" => Some(&crate::flat::VS_BUTTON as &crate::Emoji),
-					"_button" => Some(&crate::flat::VS_BUTTON as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"lcan_salute" => Some(&crate::flat::VULCAN_SALUTE.default as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'w' => match sec {
-				b'a' => match rest {
-					"ffle" => Some(&crate::flat::WAFFLE as &crate::Emoji),
-					"les" => Some(&crate::flat::FLAG_WALES as &crate::Emoji),
-					"lking" => Some(&crate::flat::PERSON_WALKING.default.default as &crate::Emoji),
-					"lking_man" => Some(&crate::flat::PERSON_WALKING.gender(Gender::Male).default as &crate::Emoji),
-					"lking_woman" => Some(&crate::flat::PERSON_WALKING.gender(Gender::Female).default as &crate::Emoji),
-					"llis_futuna" => Some(&crate::flat::FLAG_WALLIS_AND_FUTUNA as &crate::Emoji),
-					"ning_crescent_moon" => Some(&crate::flat::WANING_CRESCENT_MOON as &crate::Emoji),
-					"ning_gibbous_moon" => Some(&crate::flat::WANING_GIBBOUS_MOON as &crate::Emoji),
-					"rning" => Some(&crate::flat::WARNING as &crate::Emoji),
-					"stebasket" => Some(&crate::flat::WASTEBASKET as &crate::Emoji),
-					"tch" => Some(&crate::flat::WATCH as &crate::Emoji),
-					"ter_buffalo" => Some(&crate::flat::WATER_BUFFALO as &crate::Emoji),
-					"ter_closet" => Some(&crate::flat::WATER_CLOSET as &crate::Emoji),
-					"ter_pistol" => Some(&crate::flat::WATER_PISTOL as &crate::Emoji),
-					"ter_polo" => Some(&crate::flat::PERSON_PLAYING_WATER_POLO.default.default as &crate::Emoji),
-					"ter_wave" => Some(&crate::flat::WATER_WAVE as &crate::Emoji),
-					"termelon" => Some(&crate::flat::WATERMELON as &crate::Emoji),
-					"ve" => Some(&crate::flat::WAVING_HAND.default as &crate::Emoji),
-					"ving_hand" => Some(&crate::flat::WAVING_HAND.default as &crate::Emoji),
-					"vy_dash" => Some(&crate::flat::WAVY_DASH as &crate::Emoji),
-					"xing_crescent_moon" => Some(&crate::flat::WAXING_CRESCENT_MOON as &crate::Emoji),
-					"xing_gibbous_moon" => Some(&crate::flat::WAXING_GIBBOUS_MOON as &crate::Emoji),
-					_ => None,
-				},
-				b'c' => match rest {
-					"" => Some(&crate::flat::WATER_CLOSET as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"ary" => Some(&crate::flat::WEARY_FACE as &crate::Emoji),
-					"ary_cat" => Some(&crate::flat::WEARY_CAT as &crate::Emoji),
-					"ary_face" => Some(&crate::flat::WEARY_FACE as &crate::Emoji),
-					"dding" => Some(&crate::flat::WEDDING as &crate::Emoji),
-					"ight_lifting" => Some(&crate::flat::PERSON_LIFTING_WEIGHTS.default.default as &crate::Emoji),
-					"ight_lifting_man" => Some(&crate::flat::PERSON_LIFTING_WEIGHTS.gender(Gender::Male).default as &crate::Emoji),
-					"ight_lifting_woman" => Some(&crate::flat::PERSON_LIFTING_WEIGHTS.gender(Gender::Female).default as &crate::Emoji),
-					"stern_sahara" => Some(&crate::flat::FLAG_WESTERN_SAHARA as &crate::Emoji),
-					_ => None,
-				},
-				b'h' => match rest {
-					"ale" => Some(&crate::flat::WHALE as &crate::Emoji),
-					"ale2" => Some(&crate::flat::WHALE as &crate::Emoji),
-					"eel_of_dharma" => Some(&crate::flat::WHEEL_OF_DHARMA as &crate::Emoji),
-					"eelchair" => Some(&crate::flat::WHEELCHAIR_SYMBOL as &crate::Emoji),
-					"eelchair_symbol" => Some(&crate::flat::WHEELCHAIR_SYMBOL as &crate::Emoji),
-					"ite_cane" => Some(&crate::flat::WHITE_CANE as &crate::Emoji),
-					"ite_check_mark" => Some(&crate::flat::CHECK_MARK_BUTTON as &crate::Emoji),
-					"ite_circle" => Some(&crate::flat::WHITE_CIRCLE as &crate::Emoji),
-					"ite_exclamation_mark" => Some(&crate::flat::WHITE_EXCLAMATION_MARK as &crate::Emoji),
-					"ite_flag" => Some(&crate::flat::WHITE_FLAG as &crate::Emoji),
-					"ite_flower" => Some(&crate::flat::WHITE_FLOWER as &crate::Emoji),
-					"ite_hair" => Some(&crate::flat::WHITE_HAIR as &crate::Emoji),
-					"ite_haired_man" => Some(&crate::flat::PERSON.hair(Hair::White).gender(Gender::Male).default as &crate::Emoji),
-					"ite_haired_woman" => Some(&crate::flat::PERSON.hair(Hair::White).gender(Gender::Female).default as &crate::Emoji),
-					"ite_heart" => Some(&crate::flat::WHITE_HEART as &crate::Emoji),
-					"ite_large_square" => Some(&crate::flat::WHITE_LARGE_SQUARE as &crate::Emoji),
-					"ite_medium_small_square" => Some(&crate::flat::WHITE_MEDIUM_SMALL_SQUARE as &crate::Emoji),
-					"ite_medium_square" => Some(&crate::flat::WHITE_MEDIUM_SQUARE as &crate::Emoji),
-					"ite_question_mark" => Some(&crate::flat::WHITE_QUESTION_MARK as &crate::Emoji),
-					"ite_small_square" => Some(&crate::flat::WHITE_SMALL_SQUARE as &crate::Emoji),
-					"ite_square_button" => Some(&crate::flat::WHITE_SQUARE_BUTTON as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"lted_flower" => Some(&crate::flat::WILTED_FLOWER as &crate::Emoji),
-					"nd_chime" => Some(&crate::flat::WIND_CHIME as &crate::Emoji),
-					"nd_face" => Some(&crate::flat::WIND_FACE as &crate::Emoji),
-					"ndow" => Some(&crate::flat::WINDOW as &crate::Emoji),
-					"ne_glass" => Some(&crate::flat::WINE_GLASS as &crate::Emoji),
-					"nk" => Some(&crate::flat::WINKING_FACE as &crate::Emoji),
-					"nking_face" => Some(&crate::flat::WINKING_FACE as &crate::Emoji),
-					"nking_face_with_tongue" => Some(&crate::flat::WINKING_FACE_WITH_TONGUE as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"lf" => Some(&crate::flat::WOLF as &crate::Emoji),
-					"man" => Some(&crate::flat::PERSON.default.gender(Gender::Female).default as &crate::Emoji),
-					"man_artist" => Some(&crate::flat::ARTIST.gender(Gender::Female).default as &crate::Emoji),
-					"man_astronaut" => Some(&crate::flat::ASTRONAUT.gender(Gender::Female).default as &crate::Emoji),
-					"man_beard" => Some(&crate::flat::PERSON.hair(Hair::Beard).gender(Gender::Female).default as &crate::Emoji),
-					"man_cartwheeling" => Some(&crate::flat::PERSON_CARTWHEELING.gender(Gender::Female).default as &crate::Emoji),
-					"man_cook" => Some(&crate::flat::COOK.gender(Gender::Female).default as &crate::Emoji),
-					"man_dancing" => Some(&crate::flat::PERSON_DANCING.gender(Gender::Female).default as &crate::Emoji),
-					"man_facepalming" => Some(&crate::flat::PERSON_FACEPALMING.gender(Gender::Female).default as &crate::Emoji),
-					"man_factory_worker" => Some(&crate::flat::FACTORY_WORKER.gender(Gender::Female).default as &crate::Emoji),
-					"man_farmer" => Some(&crate::flat::FARMER.gender(Gender::Female).default as &crate::Emoji),
-					"man_feeding_baby" => Some(&crate::flat::PERSON_FEEDING_BABY.gender(Gender::Female).default as &crate::Emoji),
-					"man_firefighter" => Some(&crate::flat::FIREFIGHTER.gender(Gender::Female).default as &crate::Emoji),
-					"man_health_worker" => Some(&crate::flat::HEALTH_WORKER.gender(Gender::Female).default as &crate::Emoji),
-					"man_in_manual_wheelchair" => Some(&crate::flat::PERSON_IN_MANUAL_WHEELCHAIR.gender(Gender::Female).default as &crate::Emoji),
-					"man_in_motorized_wheelchair" => Some(&crate::flat::PERSON_IN_MOTORIZED_WHEELCHAIR.gender(Gender::Female).default as &crate::Emoji),
-					"man_in_tuxedo" => Some(&crate::flat::PERSON_IN_TUXEDO.gender(Gender::Female).default as &crate::Emoji),
-					"man_judge" => Some(&crate::flat::JUDGE.gender(Gender::Female).default as &crate::Emoji),
-					"man_juggling" => Some(&crate::flat::PERSON_JUGGLING.gender(Gender::Female).default as &crate::Emoji),
-					"man_mechanic" => Some(&crate::flat::MECHANIC.gender(Gender::Female).default as &crate::Emoji),
-					"man_office_worker" => Some(&crate::flat::OFFICE_WORKER.gender(Gender::Female).default as &crate::Emoji),
-					"man_pilot" => Some(&crate::flat::PILOT.gender(Gender::Female).default as &crate::Emoji),
-					"man_playing_handball" => Some(&crate::flat::PERSON_PLAYING_HANDBALL.gender(Gender::Female).default as &crate::Emoji),
-					"man_playing_water_polo" => Some(&crate::flat::PERSON_PLAYING_WATER_POLO.gender(Gender::Female).default as &crate::Emoji),
-					"man_s_boot" => Some(&crate::flat::WOMAN_S_BOOT as &crate::Emoji),
-					"man_s_clothes" => Some(&crate::flat::WOMAN_S_CLOTHES as &crate::Emoji),
-					"man_s_hat" => Some(&crate::flat::WOMAN_S_HAT as &crate::Emoji),
-					"man_s_sandal" => Some(&crate::flat::WOMAN_S_SANDAL as &crate::Emoji),
-					"man_scientist" => Some(&crate::flat::SCIENTIST.gender(Gender::Female).default as &crate::Emoji),
-					"man_shrugging" => Some(&crate::flat::PERSON_SHRUGGING.gender(Gender::Female).default as &crate::Emoji),
-					"man_singer" => Some(&crate::flat::SINGER.gender(Gender::Female).default as &crate::Emoji),
-					"man_student" => Some(&crate::flat::STUDENT.gender(Gender::Female).default as &crate::Emoji),
-					"man_teacher" => Some(&crate::flat::TEACHER.gender(Gender::Female).default as &crate::Emoji),
-					"man_technologist" => Some(&crate::flat::TECHNOLOGIST.gender(Gender::Female).default as &crate::Emoji),
-					"man_with_headscarf" => Some(&crate::flat::WOMAN_WITH_HEADSCARF.default as &crate::Emoji),
-					"man_with_probing_cane" => Some(&crate::flat::PERSON_WITH_WHITE_CANE.gender(Gender::Female).default as &crate::Emoji),
-					"man_with_turban" => Some(&crate::flat::PERSON_WEARING_TURBAN.gender(Gender::Female).default as &crate::Emoji),
-					"man_with_veil" => Some(&crate::flat::PERSON_WITH_VEIL.gender(Gender::Female).default as &crate::Emoji),
-					"mans_clothes" => Some(&crate::flat::WOMAN_S_CLOTHES as &crate::Emoji),
-					"mans_hat" => Some(&crate::flat::WOMAN_S_HAT as &crate::Emoji),
-					"men_s_room" => Some(&crate::flat::WOMEN_S_ROOM as &crate::Emoji),
-					"men_wrestling" => Some(&crate::flat::PERSON_WRESTLING.gender(Gender::Female) as &crate::Emoji),
-					"mens" => Some(&crate::flat::WOMEN_S_ROOM as &crate::Emoji),
-					"od" => Some(&crate::flat::WOOD as &crate::Emoji),
-					"ozy_face" => Some(&crate::flat::WOOZY_FACE as &crate::Emoji),
-					"rld_map" => Some(&crate::flat::WORLD_MAP as &crate::Emoji),
-					"rm" => Some(&crate::flat::WORM as &crate::Emoji),
-					"rried" => Some(&crate::flat::WORRIED_FACE as &crate::Emoji),
-					"rried_face" => Some(&crate::flat::WORRIED_FACE as &crate::Emoji),
-					_ => None,
-				},
-				b'r' => match rest {
-					"apped_gift" => Some(&crate::flat::WRAPPED_GIFT as &crate::Emoji),
-					"ench" => Some(&crate::flat::WRENCH as &crate::Emoji),
-					"estling" => Some(&crate::flat::PERSON_WRESTLING.default as &crate::Emoji),
-					"iting_hand" => Some(&crate::flat::WRITING_HAND.default as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'y' => match sec {
-				b'a' => match rest {
-					"rn" => Some(&crate::flat::YARN as &crate::Emoji),
-					"wning_face" => Some(&crate::flat::YAWNING_FACE as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"llow_circle" => Some(&crate::flat::YELLOW_CIRCLE as &crate::Emoji),
-					"llow_heart" => Some(&crate::flat::YELLOW_HEART as &crate::Emoji),
-					"llow_square" => Some(&crate::flat::YELLOW_SQUARE as &crate::Emoji),
-					"men" => Some(&crate::flat::FLAG_YEMEN as &crate::Emoji),
-					"n" => Some(&crate::flat::YEN_BANKNOTE as &crate::Emoji),
-					"n_banknote" => Some(&crate::flat::YEN_BANKNOTE as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"n_yang" => Some(&crate::flat::YIN_YANG as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"_yo" => Some(&crate::flat::YO_YO as &crate::Emoji),
-					_ => None,
-				},
-				b'u' => match rest {
-					"m" => Some(&crate::flat::FACE_SAVORING_FOOD as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-			b'z' => match sec {
-				b'a' => match rest {
-					"mbia" => Some(&crate::flat::FLAG_ZAMBIA as &crate::Emoji),
-					"ny_face" => Some(&crate::flat::ZANY_FACE as &crate::Emoji),
-					"p" => Some(&crate::flat::HIGH_VOLTAGE as &crate::Emoji),
-					_ => None,
-				},
-				b'e' => match rest {
-					"bra" => Some(&crate::flat::ZEBRA as &crate::Emoji),
-					"ro" => Some(&crate::flat::KEYCAP_0 as &crate::Emoji),
-					_ => None,
-				},
-				b'i' => match rest {
-					"mbabwe" => Some(&crate::flat::FLAG_ZIMBABWE as &crate::Emoji),
-					"pper_mouth_face" => Some(&crate::flat::ZIPPER_MOUTH_FACE as &crate::Emoji),
-					_ => None,
-				},
-				b'o' => match rest {
-					"mbie" => Some(&crate::flat::ZOMBIE.default as &crate::Emoji),
-					"mbie_man" => Some(&crate::flat::ZOMBIE.gender(Gender::Male) as &crate::Emoji),
-					"mbie_woman" => Some(&crate::flat::ZOMBIE.gender(Gender::Female) as &crate::Emoji),
-					_ => None,
-				},
-				b'z' => match rest {
-					"z" => Some(&crate::flat::ZZZ as &crate::Emoji),
-					_ => None,
-				},
-				_ => None,
-			},
-
-			_ => None
-		}
-	}
+/// Matches string (without colons) to emojis
+pub(crate) fn matching(s: &str) -> Option<&'static crate::Emoji> {
+	ALIASES
+		.binary_search_by(|(alias, _)| (*alias).cmp(s))
+		.ok()
+		.map(|idx| ALIASES[idx].1)
 }
 
 // EOF
\ No newline at end of file
@@ -0,0 +1,54 @@
+//! Commonly used items for glob imports
+//!
+//! This module re-exports the emoji type, the attribute enums, the customization traits, and
+//! the most common parsing and construction functions, to be imported all at once:
+//!
+//! ```
+//! use emojic::prelude::*;
+//!
+//! let hands = parse_alias(":raised_hands:").unwrap();
+//! assert_eq!(Some(emojic::flat::RAISING_HANDS.tone(Tone::Dark)), hands.apply_tone(Tone::Dark));
+//!
+//! # #[cfg(feature = "alloc")] { // only with alloc
+//! let text: String = "I :heart: Rust".chars().emojify().collect();
+//! assert_eq!("I ❤️ Rust", text);
+//! # } // only with alloc
+//! ```
+//!
+//! The emoji constants are intentionally not included, since there are thousands of them.
+//! Import them from [`flat`](crate::flat) (or [`grouped`](crate::grouped)) instead.
+
+pub use crate::emojis::Attribute;
+pub use crate::emojis::Attributes;
+pub use crate::emojis::Customizable;
+pub use crate::emojis::Emoji;
+pub use crate::emojis::Family;
+pub use crate::emojis::Gender;
+pub use crate::emojis::Hair;
+pub use crate::emojis::Labeled;
+pub use crate::emojis::OneOrTwo;
+pub use crate::emojis::Pair;
+pub use crate::emojis::Tone;
+pub use crate::emojis::TonePair;
+pub use crate::emojis::With;
+pub use crate::emojis::WithNoDef;
+
+pub use crate::lookup;
+pub use crate::parse;
+pub use crate::parse_alias;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub use crate::country_flag;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub use crate::keycap;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub use crate::regional_flag;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub use crate::text::parse_text;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub use crate::text::EmojifyExt;
@@ -0,0 +1,838 @@
+// Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
+
+// Source: https://unicode.org/Public/emoji/15.1/emoji-test.txt (up to E14.0, names and groups of E13.1 kept)
+// Created at: 2026-10-16 13:57:54.233382148 UTC
+
+/// Maps the fully-qualified graphemes to the minimally-qualified ones, of those emojis which have
+/// such an alternate form, sorted by the fully-qualified grapheme to allow binary searching.
+pub(crate) static MINIMALLY_QUALIFIED: &[(&str, &str)] = &[
+	("⛹\u{fe0f}\u{200d}♀\u{fe0f}", "⛹\u{fe0f}\u{200d}♀"),
+	("⛹\u{fe0f}\u{200d}♂\u{fe0f}", "⛹\u{fe0f}\u{200d}♂"),
+	("⛹🏻\u{200d}♀\u{fe0f}", "⛹🏻\u{200d}♀"),
+	("⛹🏻\u{200d}♂\u{fe0f}", "⛹🏻\u{200d}♂"),
+	("⛹🏼\u{200d}♀\u{fe0f}", "⛹🏼\u{200d}♀"),
+	("⛹🏼\u{200d}♂\u{fe0f}", "⛹🏼\u{200d}♂"),
+	("⛹🏽\u{200d}♀\u{fe0f}", "⛹🏽\u{200d}♀"),
+	("⛹🏽\u{200d}♂\u{fe0f}", "⛹🏽\u{200d}♂"),
+	("⛹🏾\u{200d}♀\u{fe0f}", "⛹🏾\u{200d}♀"),
+	("⛹🏾\u{200d}♂\u{fe0f}", "⛹🏾\u{200d}♂"),
+	("⛹🏿\u{200d}♀\u{fe0f}", "⛹🏿\u{200d}♀"),
+	("⛹🏿\u{200d}♂\u{fe0f}", "⛹🏿\u{200d}♂"),
+	("🏃\u{200d}♀\u{fe0f}", "🏃\u{200d}♀"),
+	("🏃\u{200d}♂\u{fe0f}", "🏃\u{200d}♂"),
+	("🏃🏻\u{200d}♀\u{fe0f}", "🏃🏻\u{200d}♀"),
+	("🏃🏻\u{200d}♂\u{fe0f}", "🏃🏻\u{200d}♂"),
+	("🏃🏼\u{200d}♀\u{fe0f}", "🏃🏼\u{200d}♀"),
+	("🏃🏼\u{200d}♂\u{fe0f}", "🏃🏼\u{200d}♂"),
+	("🏃🏽\u{200d}♀\u{fe0f}", "🏃🏽\u{200d}♀"),
+	("🏃🏽\u{200d}♂\u{fe0f}", "🏃🏽\u{200d}♂"),
+	("🏃🏾\u{200d}♀\u{fe0f}", "🏃🏾\u{200d}♀"),
+	("🏃🏾\u{200d}♂\u{fe0f}", "🏃🏾\u{200d}♂"),
+	("🏃🏿\u{200d}♀\u{fe0f}", "🏃🏿\u{200d}♀"),
+	("🏃🏿\u{200d}♂\u{fe0f}", "🏃🏿\u{200d}♂"),
+	("🏄\u{200d}♀\u{fe0f}", "🏄\u{200d}♀"),
+	("🏄\u{200d}♂\u{fe0f}", "🏄\u{200d}♂"),
+	("🏄🏻\u{200d}♀\u{fe0f}", "🏄🏻\u{200d}♀"),
+	("🏄🏻\u{200d}♂\u{fe0f}", "🏄🏻\u{200d}♂"),
+	("🏄🏼\u{200d}♀\u{fe0f}", "🏄🏼\u{200d}♀"),
+	("🏄🏼\u{200d}♂\u{fe0f}", "🏄🏼\u{200d}♂"),
+	("🏄🏽\u{200d}♀\u{fe0f}", "🏄🏽\u{200d}♀"),
+	("🏄🏽\u{200d}♂\u{fe0f}", "🏄🏽\u{200d}♂"),
+	("🏄🏾\u{200d}♀\u{fe0f}", "🏄🏾\u{200d}♀"),
+	("🏄🏾\u{200d}♂\u{fe0f}", "🏄🏾\u{200d}♂"),
+	("🏄🏿\u{200d}♀\u{fe0f}", "🏄🏿\u{200d}♀"),
+	("🏄🏿\u{200d}♂\u{fe0f}", "🏄🏿\u{200d}♂"),
+	("🏊\u{200d}♀\u{fe0f}", "🏊\u{200d}♀"),
+	("🏊\u{200d}♂\u{fe0f}", "🏊\u{200d}♂"),
+	("🏊🏻\u{200d}♀\u{fe0f}", "🏊🏻\u{200d}♀"),
+	("🏊🏻\u{200d}♂\u{fe0f}", "🏊🏻\u{200d}♂"),
+	("🏊🏼\u{200d}♀\u{fe0f}", "🏊🏼\u{200d}♀"),
+	("🏊🏼\u{200d}♂\u{fe0f}", "🏊🏼\u{200d}♂"),
+	("🏊🏽\u{200d}♀\u{fe0f}", "🏊🏽\u{200d}♀"),
+	("🏊🏽\u{200d}♂\u{fe0f}", "🏊🏽\u{200d}♂"),
+	("🏊🏾\u{200d}♀\u{fe0f}", "🏊🏾\u{200d}♀"),
+	("🏊🏾\u{200d}♂\u{fe0f}", "🏊🏾\u{200d}♂"),
+	("🏊🏿\u{200d}♀\u{fe0f}", "🏊🏿\u{200d}♀"),
+	("🏊🏿\u{200d}♂\u{fe0f}", "🏊🏿\u{200d}♂"),
+	("🏋\u{fe0f}\u{200d}♀\u{fe0f}", "🏋\u{fe0f}\u{200d}♀"),
+	("🏋\u{fe0f}\u{200d}♂\u{fe0f}", "🏋\u{fe0f}\u{200d}♂"),
+	("🏋🏻\u{200d}♀\u{fe0f}", "🏋🏻\u{200d}♀"),
+	("🏋🏻\u{200d}♂\u{fe0f}", "🏋🏻\u{200d}♂"),
+	("🏋🏼\u{200d}♀\u{fe0f}", "🏋🏼\u{200d}♀"),
+	("🏋🏼\u{200d}♂\u{fe0f}", "🏋🏼\u{200d}♂"),
+	("🏋🏽\u{200d}♀\u{fe0f}", "🏋🏽\u{200d}♀"),
+	("🏋🏽\u{200d}♂\u{fe0f}", "🏋🏽\u{200d}♂"),
+	("🏋🏾\u{200d}♀\u{fe0f}", "🏋🏾\u{200d}♀"),
+	("🏋🏾\u{200d}♂\u{fe0f}", "🏋🏾\u{200d}♂"),
+	("🏋🏿\u{200d}♀\u{fe0f}", "🏋🏿\u{200d}♀"),
+	("🏋🏿\u{200d}♂\u{fe0f}", "🏋🏿\u{200d}♂"),
+	("🏌\u{fe0f}\u{200d}♀\u{fe0f}", "🏌\u{fe0f}\u{200d}♀"),
+	("🏌\u{fe0f}\u{200d}♂\u{fe0f}", "🏌\u{fe0f}\u{200d}♂"),
+	("🏌🏻\u{200d}♀\u{fe0f}", "🏌🏻\u{200d}♀"),
+	("🏌🏻\u{200d}♂\u{fe0f}", "🏌🏻\u{200d}♂"),
+	("🏌🏼\u{200d}♀\u{fe0f}", "🏌🏼\u{200d}♀"),
+	("🏌🏼\u{200d}♂\u{fe0f}", "🏌🏼\u{200d}♂"),
+	("🏌🏽\u{200d}♀\u{fe0f}", "🏌🏽\u{200d}♀"),
+	("🏌🏽\u{200d}♂\u{fe0f}", "🏌🏽\u{200d}♂"),
+	("🏌🏾\u{200d}♀\u{fe0f}", "🏌🏾\u{200d}♀"),
+	("🏌🏾\u{200d}♂\u{fe0f}", "🏌🏾\u{200d}♂"),
+	("🏌🏿\u{200d}♀\u{fe0f}", "🏌🏿\u{200d}♀"),
+	("🏌🏿\u{200d}♂\u{fe0f}", "🏌🏿\u{200d}♂"),
+	("🏳\u{fe0f}\u{200d}⚧\u{fe0f}", "🏳\u{fe0f}\u{200d}⚧"),
+	("🏴\u{200d}☠\u{fe0f}", "🏴\u{200d}☠"),
+	("🐻\u{200d}❄\u{fe0f}", "🐻\u{200d}❄"),
+	("👁\u{fe0f}\u{200d}🗨\u{fe0f}", "👁\u{fe0f}\u{200d}🗨"),
+	("👨\u{200d}⚕\u{fe0f}", "👨\u{200d}⚕"),
+	("👨\u{200d}⚖\u{fe0f}", "👨\u{200d}⚖"),
+	("👨\u{200d}✈\u{fe0f}", "👨\u{200d}✈"),
+	("👨\u{200d}❤\u{fe0f}\u{200d}👨", "👨\u{200d}❤\u{200d}👨"),
+	("👨\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨", "👨\u{200d}❤\u{200d}💋\u{200d}👨"),
+	("👨🏻\u{200d}⚕\u{fe0f}", "👨🏻\u{200d}⚕"),
+	("👨🏻\u{200d}⚖\u{fe0f}", "👨🏻\u{200d}⚖"),
+	("👨🏻\u{200d}✈\u{fe0f}", "👨🏻\u{200d}✈"),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏻", "👨🏻\u{200d}❤\u{200d}👨🏻"),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏼", "👨🏻\u{200d}❤\u{200d}👨🏼"),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏽", "👨🏻\u{200d}❤\u{200d}👨🏽"),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏾", "👨🏻\u{200d}❤\u{200d}👨🏾"),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏿", "👨🏻\u{200d}❤\u{200d}👨🏿"),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", "👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏻"),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", "👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏼"),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", "👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏽"),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", "👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏾"),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", "👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏿"),
+	("👨🏼\u{200d}⚕\u{fe0f}", "👨🏼\u{200d}⚕"),
+	("👨🏼\u{200d}⚖\u{fe0f}", "👨🏼\u{200d}⚖"),
+	("👨🏼\u{200d}✈\u{fe0f}", "👨🏼\u{200d}✈"),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏻", "👨🏼\u{200d}❤\u{200d}👨🏻"),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏼", "👨🏼\u{200d}❤\u{200d}👨🏼"),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏽", "👨🏼\u{200d}❤\u{200d}👨🏽"),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏾", "👨🏼\u{200d}❤\u{200d}👨🏾"),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏿", "👨🏼\u{200d}❤\u{200d}👨🏿"),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", "👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏻"),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", "👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏼"),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", "👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏽"),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", "👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏾"),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", "👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏿"),
+	("👨🏽\u{200d}⚕\u{fe0f}", "👨🏽\u{200d}⚕"),
+	("👨🏽\u{200d}⚖\u{fe0f}", "👨🏽\u{200d}⚖"),
+	("👨🏽\u{200d}✈\u{fe0f}", "👨🏽\u{200d}✈"),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏻", "👨🏽\u{200d}❤\u{200d}👨🏻"),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏼", "👨🏽\u{200d}❤\u{200d}👨🏼"),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏽", "👨🏽\u{200d}❤\u{200d}👨🏽"),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏾", "👨🏽\u{200d}❤\u{200d}👨🏾"),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿", "👨🏽\u{200d}❤\u{200d}👨🏿"),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", "👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏻"),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", "👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏼"),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", "👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏽"),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", "👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏾"),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", "👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏿"),
+	("👨🏾\u{200d}⚕\u{fe0f}", "👨🏾\u{200d}⚕"),
+	("👨🏾\u{200d}⚖\u{fe0f}", "👨🏾\u{200d}⚖"),
+	("👨🏾\u{200d}✈\u{fe0f}", "👨🏾\u{200d}✈"),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏻", "👨🏾\u{200d}❤\u{200d}👨🏻"),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏼", "👨🏾\u{200d}❤\u{200d}👨🏼"),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏽", "👨🏾\u{200d}❤\u{200d}👨🏽"),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏾", "👨🏾\u{200d}❤\u{200d}👨🏾"),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏿", "👨🏾\u{200d}❤\u{200d}👨🏿"),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", "👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏻"),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", "👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏼"),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", "👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏽"),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", "👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏾"),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", "👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏿"),
+	("👨🏿\u{200d}⚕\u{fe0f}", "👨🏿\u{200d}⚕"),
+	("👨🏿\u{200d}⚖\u{fe0f}", "👨🏿\u{200d}⚖"),
+	("👨🏿\u{200d}✈\u{fe0f}", "👨🏿\u{200d}✈"),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏻", "👨🏿\u{200d}❤\u{200d}👨🏻"),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏼", "👨🏿\u{200d}❤\u{200d}👨🏼"),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏽", "👨🏿\u{200d}❤\u{200d}👨🏽"),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏾", "👨🏿\u{200d}❤\u{200d}👨🏾"),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏿", "👨🏿\u{200d}❤\u{200d}👨🏿"),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", "👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏻"),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", "👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏼"),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", "👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏽"),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", "👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏾"),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", "👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏿"),
+	("👩\u{200d}⚕\u{fe0f}", "👩\u{200d}⚕"),
+	("👩\u{200d}⚖\u{fe0f}", "👩\u{200d}⚖"),
+	("👩\u{200d}✈\u{fe0f}", "👩\u{200d}✈"),
+	("👩\u{200d}❤\u{fe0f}\u{200d}👨", "👩\u{200d}❤\u{200d}👨"),
+	("👩\u{200d}❤\u{fe0f}\u{200d}👩", "👩\u{200d}❤\u{200d}👩"),
+	("👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨", "👩\u{200d}❤\u{200d}💋\u{200d}👨"),
+	("👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩", "👩\u{200d}❤\u{200d}💋\u{200d}👩"),
+	("👩🏻\u{200d}⚕\u{fe0f}", "👩🏻\u{200d}⚕"),
+	("👩🏻\u{200d}⚖\u{fe0f}", "👩🏻\u{200d}⚖"),
+	("👩🏻\u{200d}✈\u{fe0f}", "👩🏻\u{200d}✈"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏻", "👩🏻\u{200d}❤\u{200d}👨🏻"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏼", "👩🏻\u{200d}❤\u{200d}👨🏼"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏽", "👩🏻\u{200d}❤\u{200d}👨🏽"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏾", "👩🏻\u{200d}❤\u{200d}👨🏾"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏿", "👩🏻\u{200d}❤\u{200d}👨🏿"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏻", "👩🏻\u{200d}❤\u{200d}👩🏻"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏼", "👩🏻\u{200d}❤\u{200d}👩🏼"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏽", "👩🏻\u{200d}❤\u{200d}👩🏽"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏾", "👩🏻\u{200d}❤\u{200d}👩🏾"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏿", "👩🏻\u{200d}❤\u{200d}👩🏿"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", "👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏻"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", "👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏼"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", "👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏽"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", "👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏾"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", "👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏿"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", "👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏻"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", "👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏼"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", "👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏽"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", "👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏾"),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", "👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏿"),
+	("👩🏼\u{200d}⚕\u{fe0f}", "👩🏼\u{200d}⚕"),
+	("👩🏼\u{200d}⚖\u{fe0f}", "👩🏼\u{200d}⚖"),
+	("👩🏼\u{200d}✈\u{fe0f}", "👩🏼\u{200d}✈"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏻", "👩🏼\u{200d}❤\u{200d}👨🏻"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏼", "👩🏼\u{200d}❤\u{200d}👨🏼"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏽", "👩🏼\u{200d}❤\u{200d}👨🏽"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏾", "👩🏼\u{200d}❤\u{200d}👨🏾"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏿", "👩🏼\u{200d}❤\u{200d}👨🏿"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏻", "👩🏼\u{200d}❤\u{200d}👩🏻"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏼", "👩🏼\u{200d}❤\u{200d}👩🏼"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏽", "👩🏼\u{200d}❤\u{200d}👩🏽"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏾", "👩🏼\u{200d}❤\u{200d}👩🏾"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏿", "👩🏼\u{200d}❤\u{200d}👩🏿"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", "👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏻"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", "👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏼"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", "👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏽"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", "👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏾"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", "👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏿"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", "👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏻"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", "👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏼"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", "👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏽"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", "👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏾"),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", "👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏿"),
+	("👩🏽\u{200d}⚕\u{fe0f}", "👩🏽\u{200d}⚕"),
+	("👩🏽\u{200d}⚖\u{fe0f}", "👩🏽\u{200d}⚖"),
+	("👩🏽\u{200d}✈\u{fe0f}", "👩🏽\u{200d}✈"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏻", "👩🏽\u{200d}❤\u{200d}👨🏻"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏼", "👩🏽\u{200d}❤\u{200d}👨🏼"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏽", "👩🏽\u{200d}❤\u{200d}👨🏽"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏾", "👩🏽\u{200d}❤\u{200d}👨🏾"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿", "👩🏽\u{200d}❤\u{200d}👨🏿"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏻", "👩🏽\u{200d}❤\u{200d}👩🏻"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏼", "👩🏽\u{200d}❤\u{200d}👩🏼"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏽", "👩🏽\u{200d}❤\u{200d}👩🏽"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏾", "👩🏽\u{200d}❤\u{200d}👩🏾"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏿", "👩🏽\u{200d}❤\u{200d}👩🏿"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", "👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏻"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", "👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏼"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", "👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏽"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", "👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏾"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", "👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏿"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", "👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏻"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", "👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏼"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", "👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏽"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", "👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏾"),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", "👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏿"),
+	("👩🏾\u{200d}⚕\u{fe0f}", "👩🏾\u{200d}⚕"),
+	("👩🏾\u{200d}⚖\u{fe0f}", "👩🏾\u{200d}⚖"),
+	("👩🏾\u{200d}✈\u{fe0f}", "👩🏾\u{200d}✈"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏻", "👩🏾\u{200d}❤\u{200d}👨🏻"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏼", "👩🏾\u{200d}❤\u{200d}👨🏼"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏽", "👩🏾\u{200d}❤\u{200d}👨🏽"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏾", "👩🏾\u{200d}❤\u{200d}👨🏾"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏿", "👩🏾\u{200d}❤\u{200d}👨🏿"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏻", "👩🏾\u{200d}❤\u{200d}👩🏻"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏼", "👩🏾\u{200d}❤\u{200d}👩🏼"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏽", "👩🏾\u{200d}❤\u{200d}👩🏽"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏾", "👩🏾\u{200d}❤\u{200d}👩🏾"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏿", "👩🏾\u{200d}❤\u{200d}👩🏿"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", "👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏻"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", "👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏼"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", "👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏽"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", "👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏾"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", "👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏿"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", "👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏻"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", "👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏼"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", "👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏽"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", "👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏾"),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", "👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏿"),
+	("👩🏿\u{200d}⚕\u{fe0f}", "👩🏿\u{200d}⚕"),
+	("👩🏿\u{200d}⚖\u{fe0f}", "👩🏿\u{200d}⚖"),
+	("👩🏿\u{200d}✈\u{fe0f}", "👩🏿\u{200d}✈"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏻", "👩🏿\u{200d}❤\u{200d}👨🏻"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏼", "👩🏿\u{200d}❤\u{200d}👨🏼"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏽", "👩🏿\u{200d}❤\u{200d}👨🏽"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏾", "👩🏿\u{200d}❤\u{200d}👨🏾"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏿", "👩🏿\u{200d}❤\u{200d}👨🏿"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏻", "👩🏿\u{200d}❤\u{200d}👩🏻"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏼", "👩🏿\u{200d}❤\u{200d}👩🏼"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏽", "👩🏿\u{200d}❤\u{200d}👩🏽"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏾", "👩🏿\u{200d}❤\u{200d}👩🏾"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏿", "👩🏿\u{200d}❤\u{200d}👩🏿"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", "👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏻"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", "👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏼"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", "👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏽"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", "👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏾"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", "👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏿"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", "👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏻"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", "👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏼"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", "👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏽"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", "👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏾"),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", "👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏿"),
+	("👮\u{200d}♀\u{fe0f}", "👮\u{200d}♀"),
+	("👮\u{200d}♂\u{fe0f}", "👮\u{200d}♂"),
+	("👮🏻\u{200d}♀\u{fe0f}", "👮🏻\u{200d}♀"),
+	("👮🏻\u{200d}♂\u{fe0f}", "👮🏻\u{200d}♂"),
+	("👮🏼\u{200d}♀\u{fe0f}", "👮🏼\u{200d}♀"),
+	("👮🏼\u{200d}♂\u{fe0f}", "👮🏼\u{200d}♂"),
+	("👮🏽\u{200d}♀\u{fe0f}", "👮🏽\u{200d}♀"),
+	("👮🏽\u{200d}♂\u{fe0f}", "👮🏽\u{200d}♂"),
+	("👮🏾\u{200d}♀\u{fe0f}", "👮🏾\u{200d}♀"),
+	("👮🏾\u{200d}♂\u{fe0f}", "👮🏾\u{200d}♂"),
+	("👮🏿\u{200d}♀\u{fe0f}", "👮🏿\u{200d}♀"),
+	("👮🏿\u{200d}♂\u{fe0f}", "👮🏿\u{200d}♂"),
+	("👯\u{200d}♀\u{fe0f}", "👯\u{200d}♀"),
+	("👯\u{200d}♂\u{fe0f}", "👯\u{200d}♂"),
+	("👰\u{200d}♀\u{fe0f}", "👰\u{200d}♀"),
+	("👰\u{200d}♂\u{fe0f}", "👰\u{200d}♂"),
+	("👰🏻\u{200d}♀\u{fe0f}", "👰🏻\u{200d}♀"),
+	("👰🏻\u{200d}♂\u{fe0f}", "👰🏻\u{200d}♂"),
+	("👰🏼\u{200d}♀\u{fe0f}", "👰🏼\u{200d}♀"),
+	("👰🏼\u{200d}♂\u{fe0f}", "👰🏼\u{200d}♂"),
+	("👰🏽\u{200d}♀\u{fe0f}", "👰🏽\u{200d}♀"),
+	("👰🏽\u{200d}♂\u{fe0f}", "👰🏽\u{200d}♂"),
+	("👰🏾\u{200d}♀\u{fe0f}", "👰🏾\u{200d}♀"),
+	("👰🏾\u{200d}♂\u{fe0f}", "👰🏾\u{200d}♂"),
+	("👰🏿\u{200d}♀\u{fe0f}", "👰🏿\u{200d}♀"),
+	("👰🏿\u{200d}♂\u{fe0f}", "👰🏿\u{200d}♂"),
+	("👱\u{200d}♀\u{fe0f}", "👱\u{200d}♀"),
+	("👱\u{200d}♂\u{fe0f}", "👱\u{200d}♂"),
+	("👱🏻\u{200d}♀\u{fe0f}", "👱🏻\u{200d}♀"),
+	("👱🏻\u{200d}♂\u{fe0f}", "👱🏻\u{200d}♂"),
+	("👱🏼\u{200d}♀\u{fe0f}", "👱🏼\u{200d}♀"),
+	("👱🏼\u{200d}♂\u{fe0f}", "👱🏼\u{200d}♂"),
+	("👱🏽\u{200d}♀\u{fe0f}", "👱🏽\u{200d}♀"),
+	("👱🏽\u{200d}♂\u{fe0f}", "👱🏽\u{200d}♂"),
+	("👱🏾\u{200d}♀\u{fe0f}", "👱🏾\u{200d}♀"),
+	("👱🏾\u{200d}♂\u{fe0f}", "👱🏾\u{200d}♂"),
+	("👱🏿\u{200d}♀\u{fe0f}", "👱🏿\u{200d}♀"),
+	("👱🏿\u{200d}♂\u{fe0f}", "👱🏿\u{200d}♂"),
+	("👳\u{200d}♀\u{fe0f}", "👳\u{200d}♀"),
+	("👳\u{200d}♂\u{fe0f}", "👳\u{200d}♂"),
+	("👳🏻\u{200d}♀\u{fe0f}", "👳🏻\u{200d}♀"),
+	("👳🏻\u{200d}♂\u{fe0f}", "👳🏻\u{200d}♂"),
+	("👳🏼\u{200d}♀\u{fe0f}", "👳🏼\u{200d}♀"),
+	("👳🏼\u{200d}♂\u{fe0f}", "👳🏼\u{200d}♂"),
+	("👳🏽\u{200d}♀\u{fe0f}", "👳🏽\u{200d}♀"),
+	("👳🏽\u{200d}♂\u{fe0f}", "👳🏽\u{200d}♂"),
+	("👳🏾\u{200d}♀\u{fe0f}", "👳🏾\u{200d}♀"),
+	("👳🏾\u{200d}♂\u{fe0f}", "👳🏾\u{200d}♂"),
+	("👳🏿\u{200d}♀\u{fe0f}", "👳🏿\u{200d}♀"),
+	("👳🏿\u{200d}♂\u{fe0f}", "👳🏿\u{200d}♂"),
+	("👷\u{200d}♀\u{fe0f}", "👷\u{200d}♀"),
+	("👷\u{200d}♂\u{fe0f}", "👷\u{200d}♂"),
+	("👷🏻\u{200d}♀\u{fe0f}", "👷🏻\u{200d}♀"),
+	("👷🏻\u{200d}♂\u{fe0f}", "👷🏻\u{200d}♂"),
+	("👷🏼\u{200d}♀\u{fe0f}", "👷🏼\u{200d}♀"),
+	("👷🏼\u{200d}♂\u{fe0f}", "👷🏼\u{200d}♂"),
+	("👷🏽\u{200d}♀\u{fe0f}", "👷🏽\u{200d}♀"),
+	("👷🏽\u{200d}♂\u{fe0f}", "👷🏽\u{200d}♂"),
+	("👷🏾\u{200d}♀\u{fe0f}", "👷🏾\u{200d}♀"),
+	("👷🏾\u{200d}♂\u{fe0f}", "👷🏾\u{200d}♂"),
+	("👷🏿\u{200d}♀\u{fe0f}", "👷🏿\u{200d}♀"),
+	("👷🏿\u{200d}♂\u{fe0f}", "👷🏿\u{200d}♂"),
+	("💁\u{200d}♀\u{fe0f}", "💁\u{200d}♀"),
+	("💁\u{200d}♂\u{fe0f}", "💁\u{200d}♂"),
+	("💁🏻\u{200d}♀\u{fe0f}", "💁🏻\u{200d}♀"),
+	("💁🏻\u{200d}♂\u{fe0f}", "💁🏻\u{200d}♂"),
+	("💁🏼\u{200d}♀\u{fe0f}", "💁🏼\u{200d}♀"),
+	("💁🏼\u{200d}♂\u{fe0f}", "💁🏼\u{200d}♂"),
+	("💁🏽\u{200d}♀\u{fe0f}", "💁🏽\u{200d}♀"),
+	("💁🏽\u{200d}♂\u{fe0f}", "💁🏽\u{200d}♂"),
+	("💁🏾\u{200d}♀\u{fe0f}", "💁🏾\u{200d}♀"),
+	("💁🏾\u{200d}♂\u{fe0f}", "💁🏾\u{200d}♂"),
+	("💁🏿\u{200d}♀\u{fe0f}", "💁🏿\u{200d}♀"),
+	("💁🏿\u{200d}♂\u{fe0f}", "💁🏿\u{200d}♂"),
+	("💂\u{200d}♀\u{fe0f}", "💂\u{200d}♀"),
+	("💂\u{200d}♂\u{fe0f}", "💂\u{200d}♂"),
+	("💂🏻\u{200d}♀\u{fe0f}", "💂🏻\u{200d}♀"),
+	("💂🏻\u{200d}♂\u{fe0f}", "💂🏻\u{200d}♂"),
+	("💂🏼\u{200d}♀\u{fe0f}", "💂🏼\u{200d}♀"),
+	("💂🏼\u{200d}♂\u{fe0f}", "💂🏼\u{200d}♂"),
+	("💂🏽\u{200d}♀\u{fe0f}", "💂🏽\u{200d}♀"),
+	("💂🏽\u{200d}♂\u{fe0f}", "💂🏽\u{200d}♂"),
+	("💂🏾\u{200d}♀\u{fe0f}", "💂🏾\u{200d}♀"),
+	("💂🏾\u{200d}♂\u{fe0f}", "💂🏾\u{200d}♂"),
+	("💂🏿\u{200d}♀\u{fe0f}", "💂🏿\u{200d}♀"),
+	("💂🏿\u{200d}♂\u{fe0f}", "💂🏿\u{200d}♂"),
+	("💆\u{200d}♀\u{fe0f}", "💆\u{200d}♀"),
+	("💆\u{200d}♂\u{fe0f}", "💆\u{200d}♂"),
+	("💆🏻\u{200d}♀\u{fe0f}", "💆🏻\u{200d}♀"),
+	("💆🏻\u{200d}♂\u{fe0f}", "💆🏻\u{200d}♂"),
+	("💆🏼\u{200d}♀\u{fe0f}", "💆🏼\u{200d}♀"),
+	("💆🏼\u{200d}♂\u{fe0f}", "💆🏼\u{200d}♂"),
+	("💆🏽\u{200d}♀\u{fe0f}", "💆🏽\u{200d}♀"),
+	("💆🏽\u{200d}♂\u{fe0f}", "💆🏽\u{200d}♂"),
+	("💆🏾\u{200d}♀\u{fe0f}", "💆🏾\u{200d}♀"),
+	("💆🏾\u{200d}♂\u{fe0f}", "💆🏾\u{200d}♂"),
+	("💆🏿\u{200d}♀\u{fe0f}", "💆🏿\u{200d}♀"),
+	("💆🏿\u{200d}♂\u{fe0f}", "💆🏿\u{200d}♂"),
+	("💇\u{200d}♀\u{fe0f}", "💇\u{200d}♀"),
+	("💇\u{200d}♂\u{fe0f}", "💇\u{200d}♂"),
+	("💇🏻\u{200d}♀\u{fe0f}", "💇🏻\u{200d}♀"),
+	("💇🏻\u{200d}♂\u{fe0f}", "💇🏻\u{200d}♂"),
+	("💇🏼\u{200d}♀\u{fe0f}", "💇🏼\u{200d}♀"),
+	("💇🏼\u{200d}♂\u{fe0f}", "💇🏼\u{200d}♂"),
+	("💇🏽\u{200d}♀\u{fe0f}", "💇🏽\u{200d}♀"),
+	("💇🏽\u{200d}♂\u{fe0f}", "💇🏽\u{200d}♂"),
+	("💇🏾\u{200d}♀\u{fe0f}", "💇🏾\u{200d}♀"),
+	("💇🏾\u{200d}♂\u{fe0f}", "💇🏾\u{200d}♂"),
+	("💇🏿\u{200d}♀\u{fe0f}", "💇🏿\u{200d}♀"),
+	("💇🏿\u{200d}♂\u{fe0f}", "💇🏿\u{200d}♂"),
+	("🕵\u{fe0f}\u{200d}♀\u{fe0f}", "🕵\u{fe0f}\u{200d}♀"),
+	("🕵\u{fe0f}\u{200d}♂\u{fe0f}", "🕵\u{fe0f}\u{200d}♂"),
+	("🕵🏻\u{200d}♀\u{fe0f}", "🕵🏻\u{200d}♀"),
+	("🕵🏻\u{200d}♂\u{fe0f}", "🕵🏻\u{200d}♂"),
+	("🕵🏼\u{200d}♀\u{fe0f}", "🕵🏼\u{200d}♀"),
+	("🕵🏼\u{200d}♂\u{fe0f}", "🕵🏼\u{200d}♂"),
+	("🕵🏽\u{200d}♀\u{fe0f}", "🕵🏽\u{200d}♀"),
+	("🕵🏽\u{200d}♂\u{fe0f}", "🕵🏽\u{200d}♂"),
+	("🕵🏾\u{200d}♀\u{fe0f}", "🕵🏾\u{200d}♀"),
+	("🕵🏾\u{200d}♂\u{fe0f}", "🕵🏾\u{200d}♂"),
+	("🕵🏿\u{200d}♀\u{fe0f}", "🕵🏿\u{200d}♀"),
+	("🕵🏿\u{200d}♂\u{fe0f}", "🕵🏿\u{200d}♂"),
+	("😶\u{200d}🌫\u{fe0f}", "😶\u{200d}🌫"),
+	("🙅\u{200d}♀\u{fe0f}", "🙅\u{200d}♀"),
+	("🙅\u{200d}♂\u{fe0f}", "🙅\u{200d}♂"),
+	("🙅🏻\u{200d}♀\u{fe0f}", "🙅🏻\u{200d}♀"),
+	("🙅🏻\u{200d}♂\u{fe0f}", "🙅🏻\u{200d}♂"),
+	("🙅🏼\u{200d}♀\u{fe0f}", "🙅🏼\u{200d}♀"),
+	("🙅🏼\u{200d}♂\u{fe0f}", "🙅🏼\u{200d}♂"),
+	("🙅🏽\u{200d}♀\u{fe0f}", "🙅🏽\u{200d}♀"),
+	("🙅🏽\u{200d}♂\u{fe0f}", "🙅🏽\u{200d}♂"),
+	("🙅🏾\u{200d}♀\u{fe0f}", "🙅🏾\u{200d}♀"),
+	("🙅🏾\u{200d}♂\u{fe0f}", "🙅🏾\u{200d}♂"),
+	("🙅🏿\u{200d}♀\u{fe0f}", "🙅🏿\u{200d}♀"),
+	("🙅🏿\u{200d}♂\u{fe0f}", "🙅🏿\u{200d}♂"),
+	("🙆\u{200d}♀\u{fe0f}", "🙆\u{200d}♀"),
+	("🙆\u{200d}♂\u{fe0f}", "🙆\u{200d}♂"),
+	("🙆🏻\u{200d}♀\u{fe0f}", "🙆🏻\u{200d}♀"),
+	("🙆🏻\u{200d}♂\u{fe0f}", "🙆🏻\u{200d}♂"),
+	("🙆🏼\u{200d}♀\u{fe0f}", "🙆🏼\u{200d}♀"),
+	("🙆🏼\u{200d}♂\u{fe0f}", "🙆🏼\u{200d}♂"),
+	("🙆🏽\u{200d}♀\u{fe0f}", "🙆🏽\u{200d}♀"),
+	("🙆🏽\u{200d}♂\u{fe0f}", "🙆🏽\u{200d}♂"),
+	("🙆🏾\u{200d}♀\u{fe0f}", "🙆🏾\u{200d}♀"),
+	("🙆🏾\u{200d}♂\u{fe0f}", "🙆🏾\u{200d}♂"),
+	("🙆🏿\u{200d}♀\u{fe0f}", "🙆🏿\u{200d}♀"),
+	("🙆🏿\u{200d}♂\u{fe0f}", "🙆🏿\u{200d}♂"),
+	("🙇\u{200d}♀\u{fe0f}", "🙇\u{200d}♀"),
+	("🙇\u{200d}♂\u{fe0f}", "🙇\u{200d}♂"),
+	("🙇🏻\u{200d}♀\u{fe0f}", "🙇🏻\u{200d}♀"),
+	("🙇🏻\u{200d}♂\u{fe0f}", "🙇🏻\u{200d}♂"),
+	("🙇🏼\u{200d}♀\u{fe0f}", "🙇🏼\u{200d}♀"),
+	("🙇🏼\u{200d}♂\u{fe0f}", "🙇🏼\u{200d}♂"),
+	("🙇🏽\u{200d}♀\u{fe0f}", "🙇🏽\u{200d}♀"),
+	("🙇🏽\u{200d}♂\u{fe0f}", "🙇🏽\u{200d}♂"),
+	("🙇🏾\u{200d}♀\u{fe0f}", "🙇🏾\u{200d}♀"),
+	("🙇🏾\u{200d}♂\u{fe0f}", "🙇🏾\u{200d}♂"),
+	("🙇🏿\u{200d}♀\u{fe0f}", "🙇🏿\u{200d}♀"),
+	("🙇🏿\u{200d}♂\u{fe0f}", "🙇🏿\u{200d}♂"),
+	("🙋\u{200d}♀\u{fe0f}", "🙋\u{200d}♀"),
+	("🙋\u{200d}♂\u{fe0f}", "🙋\u{200d}♂"),
+	("🙋🏻\u{200d}♀\u{fe0f}", "🙋🏻\u{200d}♀"),
+	("🙋🏻\u{200d}♂\u{fe0f}", "🙋🏻\u{200d}♂"),
+	("🙋🏼\u{200d}♀\u{fe0f}", "🙋🏼\u{200d}♀"),
+	("🙋🏼\u{200d}♂\u{fe0f}", "🙋🏼\u{200d}♂"),
+	("🙋🏽\u{200d}♀\u{fe0f}", "🙋🏽\u{200d}♀"),
+	("🙋🏽\u{200d}♂\u{fe0f}", "🙋🏽\u{200d}♂"),
+	("🙋🏾\u{200d}♀\u{fe0f}", "🙋🏾\u{200d}♀"),
+	("🙋🏾\u{200d}♂\u{fe0f}", "🙋🏾\u{200d}♂"),
+	("🙋🏿\u{200d}♀\u{fe0f}", "🙋🏿\u{200d}♀"),
+	("🙋🏿\u{200d}♂\u{fe0f}", "🙋🏿\u{200d}♂"),
+	("🙍\u{200d}♀\u{fe0f}", "🙍\u{200d}♀"),
+	("🙍\u{200d}♂\u{fe0f}", "🙍\u{200d}♂"),
+	("🙍🏻\u{200d}♀\u{fe0f}", "🙍🏻\u{200d}♀"),
+	("🙍🏻\u{200d}♂\u{fe0f}", "🙍🏻\u{200d}♂"),
+	("🙍🏼\u{200d}♀\u{fe0f}", "🙍🏼\u{200d}♀"),
+	("🙍🏼\u{200d}♂\u{fe0f}", "🙍🏼\u{200d}♂"),
+	("🙍🏽\u{200d}♀\u{fe0f}", "🙍🏽\u{200d}♀"),
+	("🙍🏽\u{200d}♂\u{fe0f}", "🙍🏽\u{200d}♂"),
+	("🙍🏾\u{200d}♀\u{fe0f}", "🙍🏾\u{200d}♀"),
+	("🙍🏾\u{200d}♂\u{fe0f}", "🙍🏾\u{200d}♂"),
+	("🙍🏿\u{200d}♀\u{fe0f}", "🙍🏿\u{200d}♀"),
+	("🙍🏿\u{200d}♂\u{fe0f}", "🙍🏿\u{200d}♂"),
+	("🙎\u{200d}♀\u{fe0f}", "🙎\u{200d}♀"),
+	("🙎\u{200d}♂\u{fe0f}", "🙎\u{200d}♂"),
+	("🙎🏻\u{200d}♀\u{fe0f}", "🙎🏻\u{200d}♀"),
+	("🙎🏻\u{200d}♂\u{fe0f}", "🙎🏻\u{200d}♂"),
+	("🙎🏼\u{200d}♀\u{fe0f}", "🙎🏼\u{200d}♀"),
+	("🙎🏼\u{200d}♂\u{fe0f}", "🙎🏼\u{200d}♂"),
+	("🙎🏽\u{200d}♀\u{fe0f}", "🙎🏽\u{200d}♀"),
+	("🙎🏽\u{200d}♂\u{fe0f}", "🙎🏽\u{200d}♂"),
+	("🙎🏾\u{200d}♀\u{fe0f}", "🙎🏾\u{200d}♀"),
+	("🙎🏾\u{200d}♂\u{fe0f}", "🙎🏾\u{200d}♂"),
+	("🙎🏿\u{200d}♀\u{fe0f}", "🙎🏿\u{200d}♀"),
+	("🙎🏿\u{200d}♂\u{fe0f}", "🙎🏿\u{200d}♂"),
+	("🚣\u{200d}♀\u{fe0f}", "🚣\u{200d}♀"),
+	("🚣\u{200d}♂\u{fe0f}", "🚣\u{200d}♂"),
+	("🚣🏻\u{200d}♀\u{fe0f}", "🚣🏻\u{200d}♀"),
+	("🚣🏻\u{200d}♂\u{fe0f}", "🚣🏻\u{200d}♂"),
+	("🚣🏼\u{200d}♀\u{fe0f}", "🚣🏼\u{200d}♀"),
+	("🚣🏼\u{200d}♂\u{fe0f}", "🚣🏼\u{200d}♂"),
+	("🚣🏽\u{200d}♀\u{fe0f}", "🚣🏽\u{200d}♀"),
+	("🚣🏽\u{200d}♂\u{fe0f}", "🚣🏽\u{200d}♂"),
+	("🚣🏾\u{200d}♀\u{fe0f}", "🚣🏾\u{200d}♀"),
+	("🚣🏾\u{200d}♂\u{fe0f}", "🚣🏾\u{200d}♂"),
+	("🚣🏿\u{200d}♀\u{fe0f}", "🚣🏿\u{200d}♀"),
+	("🚣🏿\u{200d}♂\u{fe0f}", "🚣🏿\u{200d}♂"),
+	("🚴\u{200d}♀\u{fe0f}", "🚴\u{200d}♀"),
+	("🚴\u{200d}♂\u{fe0f}", "🚴\u{200d}♂"),
+	("🚴🏻\u{200d}♀\u{fe0f}", "🚴🏻\u{200d}♀"),
+	("🚴🏻\u{200d}♂\u{fe0f}", "🚴🏻\u{200d}♂"),
+	("🚴🏼\u{200d}♀\u{fe0f}", "🚴🏼\u{200d}♀"),
+	("🚴🏼\u{200d}♂\u{fe0f}", "🚴🏼\u{200d}♂"),
+	("🚴🏽\u{200d}♀\u{fe0f}", "🚴🏽\u{200d}♀"),
+	("🚴🏽\u{200d}♂\u{fe0f}", "🚴🏽\u{200d}♂"),
+	("🚴🏾\u{200d}♀\u{fe0f}", "🚴🏾\u{200d}♀"),
+	("🚴🏾\u{200d}♂\u{fe0f}", "🚴🏾\u{200d}♂"),
+	("🚴🏿\u{200d}♀\u{fe0f}", "🚴🏿\u{200d}♀"),
+	("🚴🏿\u{200d}♂\u{fe0f}", "🚴🏿\u{200d}♂"),
+	("🚵\u{200d}♀\u{fe0f}", "🚵\u{200d}♀"),
+	("🚵\u{200d}♂\u{fe0f}", "🚵\u{200d}♂"),
+	("🚵🏻\u{200d}♀\u{fe0f}", "🚵🏻\u{200d}♀"),
+	("🚵🏻\u{200d}♂\u{fe0f}", "🚵🏻\u{200d}♂"),
+	("🚵🏼\u{200d}♀\u{fe0f}", "🚵🏼\u{200d}♀"),
+	("🚵🏼\u{200d}♂\u{fe0f}", "🚵🏼\u{200d}♂"),
+	("🚵🏽\u{200d}♀\u{fe0f}", "🚵🏽\u{200d}♀"),
+	("🚵🏽\u{200d}♂\u{fe0f}", "🚵🏽\u{200d}♂"),
+	("🚵🏾\u{200d}♀\u{fe0f}", "🚵🏾\u{200d}♀"),
+	("🚵🏾\u{200d}♂\u{fe0f}", "🚵🏾\u{200d}♂"),
+	("🚵🏿\u{200d}♀\u{fe0f}", "🚵🏿\u{200d}♀"),
+	("🚵🏿\u{200d}♂\u{fe0f}", "🚵🏿\u{200d}♂"),
+	("🚶\u{200d}♀\u{fe0f}", "🚶\u{200d}♀"),
+	("🚶\u{200d}♂\u{fe0f}", "🚶\u{200d}♂"),
+	("🚶🏻\u{200d}♀\u{fe0f}", "🚶🏻\u{200d}♀"),
+	("🚶🏻\u{200d}♂\u{fe0f}", "🚶🏻\u{200d}♂"),
+	("🚶🏼\u{200d}♀\u{fe0f}", "🚶🏼\u{200d}♀"),
+	("🚶🏼\u{200d}♂\u{fe0f}", "🚶🏼\u{200d}♂"),
+	("🚶🏽\u{200d}♀\u{fe0f}", "🚶🏽\u{200d}♀"),
+	("🚶🏽\u{200d}♂\u{fe0f}", "🚶🏽\u{200d}♂"),
+	("🚶🏾\u{200d}♀\u{fe0f}", "🚶🏾\u{200d}♀"),
+	("🚶🏾\u{200d}♂\u{fe0f}", "🚶🏾\u{200d}♂"),
+	("🚶🏿\u{200d}♀\u{fe0f}", "🚶🏿\u{200d}♀"),
+	("🚶🏿\u{200d}♂\u{fe0f}", "🚶🏿\u{200d}♂"),
+	("🤦\u{200d}♀\u{fe0f}", "🤦\u{200d}♀"),
+	("🤦\u{200d}♂\u{fe0f}", "🤦\u{200d}♂"),
+	("🤦🏻\u{200d}♀\u{fe0f}", "🤦🏻\u{200d}♀"),
+	("🤦🏻\u{200d}♂\u{fe0f}", "🤦🏻\u{200d}♂"),
+	("🤦🏼\u{200d}♀\u{fe0f}", "🤦🏼\u{200d}♀"),
+	("🤦🏼\u{200d}♂\u{fe0f}", "🤦🏼\u{200d}♂"),
+	("🤦🏽\u{200d}♀\u{fe0f}", "🤦🏽\u{200d}♀"),
+	("🤦🏽\u{200d}♂\u{fe0f}", "🤦🏽\u{200d}♂"),
+	("🤦🏾\u{200d}♀\u{fe0f}", "🤦🏾\u{200d}♀"),
+	("🤦🏾\u{200d}♂\u{fe0f}", "🤦🏾\u{200d}♂"),
+	("🤦🏿\u{200d}♀\u{fe0f}", "🤦🏿\u{200d}♀"),
+	("🤦🏿\u{200d}♂\u{fe0f}", "🤦🏿\u{200d}♂"),
+	("🤵\u{200d}♀\u{fe0f}", "🤵\u{200d}♀"),
+	("🤵\u{200d}♂\u{fe0f}", "🤵\u{200d}♂"),
+	("🤵🏻\u{200d}♀\u{fe0f}", "🤵🏻\u{200d}♀"),
+	("🤵🏻\u{200d}♂\u{fe0f}", "🤵🏻\u{200d}♂"),
+	("🤵🏼\u{200d}♀\u{fe0f}", "🤵🏼\u{200d}♀"),
+	("🤵🏼\u{200d}♂\u{fe0f}", "🤵🏼\u{200d}♂"),
+	("🤵🏽\u{200d}♀\u{fe0f}", "🤵🏽\u{200d}♀"),
+	("🤵🏽\u{200d}♂\u{fe0f}", "🤵🏽\u{200d}♂"),
+	("🤵🏾\u{200d}♀\u{fe0f}", "🤵🏾\u{200d}♀"),
+	("🤵🏾\u{200d}♂\u{fe0f}", "🤵🏾\u{200d}♂"),
+	("🤵🏿\u{200d}♀\u{fe0f}", "🤵🏿\u{200d}♀"),
+	("🤵🏿\u{200d}♂\u{fe0f}", "🤵🏿\u{200d}♂"),
+	("🤷\u{200d}♀\u{fe0f}", "🤷\u{200d}♀"),
+	("🤷\u{200d}♂\u{fe0f}", "🤷\u{200d}♂"),
+	("🤷🏻\u{200d}♀\u{fe0f}", "🤷🏻\u{200d}♀"),
+	("🤷🏻\u{200d}♂\u{fe0f}", "🤷🏻\u{200d}♂"),
+	("🤷🏼\u{200d}♀\u{fe0f}", "🤷🏼\u{200d}♀"),
+	("🤷🏼\u{200d}♂\u{fe0f}", "🤷🏼\u{200d}♂"),
+	("🤷🏽\u{200d}♀\u{fe0f}", "🤷🏽\u{200d}♀"),
+	("🤷🏽\u{200d}♂\u{fe0f}", "🤷🏽\u{200d}♂"),
+	("🤷🏾\u{200d}♀\u{fe0f}", "🤷🏾\u{200d}♀"),
+	("🤷🏾\u{200d}♂\u{fe0f}", "🤷🏾\u{200d}♂"),
+	("🤷🏿\u{200d}♀\u{fe0f}", "🤷🏿\u{200d}♀"),
+	("🤷🏿\u{200d}♂\u{fe0f}", "🤷🏿\u{200d}♂"),
+	("🤸\u{200d}♀\u{fe0f}", "🤸\u{200d}♀"),
+	("🤸\u{200d}♂\u{fe0f}", "🤸\u{200d}♂"),
+	("🤸🏻\u{200d}♀\u{fe0f}", "🤸🏻\u{200d}♀"),
+	("🤸🏻\u{200d}♂\u{fe0f}", "🤸🏻\u{200d}♂"),
+	("🤸🏼\u{200d}♀\u{fe0f}", "🤸🏼\u{200d}♀"),
+	("🤸🏼\u{200d}♂\u{fe0f}", "🤸🏼\u{200d}♂"),
+	("🤸🏽\u{200d}♀\u{fe0f}", "🤸🏽\u{200d}♀"),
+	("🤸🏽\u{200d}♂\u{fe0f}", "🤸🏽\u{200d}♂"),
+	("🤸🏾\u{200d}♀\u{fe0f}", "🤸🏾\u{200d}♀"),
+	("🤸🏾\u{200d}♂\u{fe0f}", "🤸🏾\u{200d}♂"),
+	("🤸🏿\u{200d}♀\u{fe0f}", "🤸🏿\u{200d}♀"),
+	("🤸🏿\u{200d}♂\u{fe0f}", "🤸🏿\u{200d}♂"),
+	("🤹\u{200d}♀\u{fe0f}", "🤹\u{200d}♀"),
+	("🤹\u{200d}♂\u{fe0f}", "🤹\u{200d}♂"),
+	("🤹🏻\u{200d}♀\u{fe0f}", "🤹🏻\u{200d}♀"),
+	("🤹🏻\u{200d}♂\u{fe0f}", "🤹🏻\u{200d}♂"),
+	("🤹🏼\u{200d}♀\u{fe0f}", "🤹🏼\u{200d}♀"),
+	("🤹🏼\u{200d}♂\u{fe0f}", "🤹🏼\u{200d}♂"),
+	("🤹🏽\u{200d}♀\u{fe0f}", "🤹🏽\u{200d}♀"),
+	("🤹🏽\u{200d}♂\u{fe0f}", "🤹🏽\u{200d}♂"),
+	("🤹🏾\u{200d}♀\u{fe0f}", "🤹🏾\u{200d}♀"),
+	("🤹🏾\u{200d}♂\u{fe0f}", "🤹🏾\u{200d}♂"),
+	("🤹🏿\u{200d}♀\u{fe0f}", "🤹🏿\u{200d}♀"),
+	("🤹🏿\u{200d}♂\u{fe0f}", "🤹🏿\u{200d}♂"),
+	("🤼\u{200d}♀\u{fe0f}", "🤼\u{200d}♀"),
+	("🤼\u{200d}♂\u{fe0f}", "🤼\u{200d}♂"),
+	("🤽\u{200d}♀\u{fe0f}", "🤽\u{200d}♀"),
+	("🤽\u{200d}♂\u{fe0f}", "🤽\u{200d}♂"),
+	("🤽🏻\u{200d}♀\u{fe0f}", "🤽🏻\u{200d}♀"),
+	("🤽🏻\u{200d}♂\u{fe0f}", "🤽🏻\u{200d}♂"),
+	("🤽🏼\u{200d}♀\u{fe0f}", "🤽🏼\u{200d}♀"),
+	("🤽🏼\u{200d}♂\u{fe0f}", "🤽🏼\u{200d}♂"),
+	("🤽🏽\u{200d}♀\u{fe0f}", "🤽🏽\u{200d}♀"),
+	("🤽🏽\u{200d}♂\u{fe0f}", "🤽🏽\u{200d}♂"),
+	("🤽🏾\u{200d}♀\u{fe0f}", "🤽🏾\u{200d}♀"),
+	("🤽🏾\u{200d}♂\u{fe0f}", "🤽🏾\u{200d}♂"),
+	("🤽🏿\u{200d}♀\u{fe0f}", "🤽🏿\u{200d}♀"),
+	("🤽🏿\u{200d}♂\u{fe0f}", "🤽🏿\u{200d}♂"),
+	("🤾\u{200d}♀\u{fe0f}", "🤾\u{200d}♀"),
+	("🤾\u{200d}♂\u{fe0f}", "🤾\u{200d}♂"),
+	("🤾🏻\u{200d}♀\u{fe0f}", "🤾🏻\u{200d}♀"),
+	("🤾🏻\u{200d}♂\u{fe0f}", "🤾🏻\u{200d}♂"),
+	("🤾🏼\u{200d}♀\u{fe0f}", "🤾🏼\u{200d}♀"),
+	("🤾🏼\u{200d}♂\u{fe0f}", "🤾🏼\u{200d}♂"),
+	("🤾🏽\u{200d}♀\u{fe0f}", "🤾🏽\u{200d}♀"),
+	("🤾🏽\u{200d}♂\u{fe0f}", "🤾🏽\u{200d}♂"),
+	("🤾🏾\u{200d}♀\u{fe0f}", "🤾🏾\u{200d}♀"),
+	("🤾🏾\u{200d}♂\u{fe0f}", "🤾🏾\u{200d}♂"),
+	("🤾🏿\u{200d}♀\u{fe0f}", "🤾🏿\u{200d}♀"),
+	("🤾🏿\u{200d}♂\u{fe0f}", "🤾🏿\u{200d}♂"),
+	("🦸\u{200d}♀\u{fe0f}", "🦸\u{200d}♀"),
+	("🦸\u{200d}♂\u{fe0f}", "🦸\u{200d}♂"),
+	("🦸🏻\u{200d}♀\u{fe0f}", "🦸🏻\u{200d}♀"),
+	("🦸🏻\u{200d}♂\u{fe0f}", "🦸🏻\u{200d}♂"),
+	("🦸🏼\u{200d}♀\u{fe0f}", "🦸🏼\u{200d}♀"),
+	("🦸🏼\u{200d}♂\u{fe0f}", "🦸🏼\u{200d}♂"),
+	("🦸🏽\u{200d}♀\u{fe0f}", "🦸🏽\u{200d}♀"),
+	("🦸🏽\u{200d}♂\u{fe0f}", "🦸🏽\u{200d}♂"),
+	("🦸🏾\u{200d}♀\u{fe0f}", "🦸🏾\u{200d}♀"),
+	("🦸🏾\u{200d}♂\u{fe0f}", "🦸🏾\u{200d}♂"),
+	("🦸🏿\u{200d}♀\u{fe0f}", "🦸🏿\u{200d}♀"),
+	("🦸🏿\u{200d}♂\u{fe0f}", "🦸🏿\u{200d}♂"),
+	("🦹\u{200d}♀\u{fe0f}", "🦹\u{200d}♀"),
+	("🦹\u{200d}♂\u{fe0f}", "🦹\u{200d}♂"),
+	("🦹🏻\u{200d}♀\u{fe0f}", "🦹🏻\u{200d}♀"),
+	("🦹🏻\u{200d}♂\u{fe0f}", "🦹🏻\u{200d}♂"),
+	("🦹🏼\u{200d}♀\u{fe0f}", "🦹🏼\u{200d}♀"),
+	("🦹🏼\u{200d}♂\u{fe0f}", "🦹🏼\u{200d}♂"),
+	("🦹🏽\u{200d}♀\u{fe0f}", "🦹🏽\u{200d}♀"),
+	("🦹🏽\u{200d}♂\u{fe0f}", "🦹🏽\u{200d}♂"),
+	("🦹🏾\u{200d}♀\u{fe0f}", "🦹🏾\u{200d}♀"),
+	("🦹🏾\u{200d}♂\u{fe0f}", "🦹🏾\u{200d}♂"),
+	("🦹🏿\u{200d}♀\u{fe0f}", "🦹🏿\u{200d}♀"),
+	("🦹🏿\u{200d}♂\u{fe0f}", "🦹🏿\u{200d}♂"),
+	("🧍\u{200d}♀\u{fe0f}", "🧍\u{200d}♀"),
+	("🧍\u{200d}♂\u{fe0f}", "🧍\u{200d}♂"),
+	("🧍🏻\u{200d}♀\u{fe0f}", "🧍🏻\u{200d}♀"),
+	("🧍🏻\u{200d}♂\u{fe0f}", "🧍🏻\u{200d}♂"),
+	("🧍🏼\u{200d}♀\u{fe0f}", "🧍🏼\u{200d}♀"),
+	("🧍🏼\u{200d}♂\u{fe0f}", "🧍🏼\u{200d}♂"),
+	("🧍🏽\u{200d}♀\u{fe0f}", "🧍🏽\u{200d}♀"),
+	("🧍🏽\u{200d}♂\u{fe0f}", "🧍🏽\u{200d}♂"),
+	("🧍🏾\u{200d}♀\u{fe0f}", "🧍🏾\u{200d}♀"),
+	("🧍🏾\u{200d}♂\u{fe0f}", "🧍🏾\u{200d}♂"),
+	("🧍🏿\u{200d}♀\u{fe0f}", "🧍🏿\u{200d}♀"),
+	("🧍🏿\u{200d}♂\u{fe0f}", "🧍🏿\u{200d}♂"),
+	("🧎\u{200d}♀\u{fe0f}", "🧎\u{200d}♀"),
+	("🧎\u{200d}♂\u{fe0f}", "🧎\u{200d}♂"),
+	("🧎🏻\u{200d}♀\u{fe0f}", "🧎🏻\u{200d}♀"),
+	("🧎🏻\u{200d}♂\u{fe0f}", "🧎🏻\u{200d}♂"),
+	("🧎🏼\u{200d}♀\u{fe0f}", "🧎🏼\u{200d}♀"),
+	("🧎🏼\u{200d}♂\u{fe0f}", "🧎🏼\u{200d}♂"),
+	("🧎🏽\u{200d}♀\u{fe0f}", "🧎🏽\u{200d}♀"),
+	("🧎🏽\u{200d}♂\u{fe0f}", "🧎🏽\u{200d}♂"),
+	("🧎🏾\u{200d}♀\u{fe0f}", "🧎🏾\u{200d}♀"),
+	("🧎🏾\u{200d}♂\u{fe0f}", "🧎🏾\u{200d}♂"),
+	("🧎🏿\u{200d}♀\u{fe0f}", "🧎🏿\u{200d}♀"),
+	("🧎🏿\u{200d}♂\u{fe0f}", "🧎🏿\u{200d}♂"),
+	("🧏\u{200d}♀\u{fe0f}", "🧏\u{200d}♀"),
+	("🧏\u{200d}♂\u{fe0f}", "🧏\u{200d}♂"),
+	("🧏🏻\u{200d}♀\u{fe0f}", "🧏🏻\u{200d}♀"),
+	("🧏🏻\u{200d}♂\u{fe0f}", "🧏🏻\u{200d}♂"),
+	("🧏🏼\u{200d}♀\u{fe0f}", "🧏🏼\u{200d}♀"),
+	("🧏🏼\u{200d}♂\u{fe0f}", "🧏🏼\u{200d}♂"),
+	("🧏🏽\u{200d}♀\u{fe0f}", "🧏🏽\u{200d}♀"),
+	("🧏🏽\u{200d}♂\u{fe0f}", "🧏🏽\u{200d}♂"),
+	("🧏🏾\u{200d}♀\u{fe0f}", "🧏🏾\u{200d}♀"),
+	("🧏🏾\u{200d}♂\u{fe0f}", "🧏🏾\u{200d}♂"),
+	("🧏🏿\u{200d}♀\u{fe0f}", "🧏🏿\u{200d}♀"),
+	("🧏🏿\u{200d}♂\u{fe0f}", "🧏🏿\u{200d}♂"),
+	("🧑\u{200d}⚕\u{fe0f}", "🧑\u{200d}⚕"),
+	("🧑\u{200d}⚖\u{fe0f}", "🧑\u{200d}⚖"),
+	("🧑\u{200d}✈\u{fe0f}", "🧑\u{200d}✈"),
+	("🧑🏻\u{200d}⚕\u{fe0f}", "🧑🏻\u{200d}⚕"),
+	("🧑🏻\u{200d}⚖\u{fe0f}", "🧑🏻\u{200d}⚖"),
+	("🧑🏻\u{200d}✈\u{fe0f}", "🧑🏻\u{200d}✈"),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", "🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏼"),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", "🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏽"),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", "🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏾"),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", "🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏿"),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏼", "🧑🏻\u{200d}❤\u{200d}🧑🏼"),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏽", "🧑🏻\u{200d}❤\u{200d}🧑🏽"),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏾", "🧑🏻\u{200d}❤\u{200d}🧑🏾"),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏿", "🧑🏻\u{200d}❤\u{200d}🧑🏿"),
+	("🧑🏼\u{200d}⚕\u{fe0f}", "🧑🏼\u{200d}⚕"),
+	("🧑🏼\u{200d}⚖\u{fe0f}", "🧑🏼\u{200d}⚖"),
+	("🧑🏼\u{200d}✈\u{fe0f}", "🧑🏼\u{200d}✈"),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", "🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏻"),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", "🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏽"),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", "🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏾"),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", "🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏿"),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏻", "🧑🏼\u{200d}❤\u{200d}🧑🏻"),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏽", "🧑🏼\u{200d}❤\u{200d}🧑🏽"),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏾", "🧑🏼\u{200d}❤\u{200d}🧑🏾"),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏿", "🧑🏼\u{200d}❤\u{200d}🧑🏿"),
+	("🧑🏽\u{200d}⚕\u{fe0f}", "🧑🏽\u{200d}⚕"),
+	("🧑🏽\u{200d}⚖\u{fe0f}", "🧑🏽\u{200d}⚖"),
+	("🧑🏽\u{200d}✈\u{fe0f}", "🧑🏽\u{200d}✈"),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", "🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏻"),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", "🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏼"),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", "🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏾"),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", "🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏿"),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏻", "🧑🏽\u{200d}❤\u{200d}🧑🏻"),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏼", "🧑🏽\u{200d}❤\u{200d}🧑🏼"),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏾", "🧑🏽\u{200d}❤\u{200d}🧑🏾"),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏿", "🧑🏽\u{200d}❤\u{200d}🧑🏿"),
+	("🧑🏾\u{200d}⚕\u{fe0f}", "🧑🏾\u{200d}⚕"),
+	("🧑🏾\u{200d}⚖\u{fe0f}", "🧑🏾\u{200d}⚖"),
+	("🧑🏾\u{200d}✈\u{fe0f}", "🧑🏾\u{200d}✈"),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", "🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏻"),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", "🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏼"),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", "🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏽"),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", "🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏿"),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏻", "🧑🏾\u{200d}❤\u{200d}🧑🏻"),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏼", "🧑🏾\u{200d}❤\u{200d}🧑🏼"),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏽", "🧑🏾\u{200d}❤\u{200d}🧑🏽"),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏿", "🧑🏾\u{200d}❤\u{200d}🧑🏿"),
+	("🧑🏿\u{200d}⚕\u{fe0f}", "🧑🏿\u{200d}⚕"),
+	("🧑🏿\u{200d}⚖\u{fe0f}", "🧑🏿\u{200d}⚖"),
+	("🧑🏿\u{200d}✈\u{fe0f}", "🧑🏿\u{200d}✈"),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", "🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏻"),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", "🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏼"),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", "🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏽"),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", "🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏾"),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏻", "🧑🏿\u{200d}❤\u{200d}🧑🏻"),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏼", "🧑🏿\u{200d}❤\u{200d}🧑🏼"),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏽", "🧑🏿\u{200d}❤\u{200d}🧑🏽"),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏾", "🧑🏿\u{200d}❤\u{200d}🧑🏾"),
+	("🧔\u{200d}♀\u{fe0f}", "🧔\u{200d}♀"),
+	("🧔\u{200d}♂\u{fe0f}", "🧔\u{200d}♂"),
+	("🧔🏻\u{200d}♀\u{fe0f}", "🧔🏻\u{200d}♀"),
+	("🧔🏻\u{200d}♂\u{fe0f}", "🧔🏻\u{200d}♂"),
+	("🧔🏼\u{200d}♀\u{fe0f}", "🧔🏼\u{200d}♀"),
+	("🧔🏼\u{200d}♂\u{fe0f}", "🧔🏼\u{200d}♂"),
+	("🧔🏽\u{200d}♀\u{fe0f}", "🧔🏽\u{200d}♀"),
+	("🧔🏽\u{200d}♂\u{fe0f}", "🧔🏽\u{200d}♂"),
+	("🧔🏾\u{200d}♀\u{fe0f}", "🧔🏾\u{200d}♀"),
+	("🧔🏾\u{200d}♂\u{fe0f}", "🧔🏾\u{200d}♂"),
+	("🧔🏿\u{200d}♀\u{fe0f}", "🧔🏿\u{200d}♀"),
+	("🧔🏿\u{200d}♂\u{fe0f}", "🧔🏿\u{200d}♂"),
+	("🧖\u{200d}♀\u{fe0f}", "🧖\u{200d}♀"),
+	("🧖\u{200d}♂\u{fe0f}", "🧖\u{200d}♂"),
+	("🧖🏻\u{200d}♀\u{fe0f}", "🧖🏻\u{200d}♀"),
+	("🧖🏻\u{200d}♂\u{fe0f}", "🧖🏻\u{200d}♂"),
+	("🧖🏼\u{200d}♀\u{fe0f}", "🧖🏼\u{200d}♀"),
+	("🧖🏼\u{200d}♂\u{fe0f}", "🧖🏼\u{200d}♂"),
+	("🧖🏽\u{200d}♀\u{fe0f}", "🧖🏽\u{200d}♀"),
+	("🧖🏽\u{200d}♂\u{fe0f}", "🧖🏽\u{200d}♂"),
+	("🧖🏾\u{200d}♀\u{fe0f}", "🧖🏾\u{200d}♀"),
+	("🧖🏾\u{200d}♂\u{fe0f}", "🧖🏾\u{200d}♂"),
+	("🧖🏿\u{200d}♀\u{fe0f}", "🧖🏿\u{200d}♀"),
+	("🧖🏿\u{200d}♂\u{fe0f}", "🧖🏿\u{200d}♂"),
+	("🧗\u{200d}♀\u{fe0f}", "🧗\u{200d}♀"),
+	("🧗\u{200d}♂\u{fe0f}", "🧗\u{200d}♂"),
+	("🧗🏻\u{200d}♀\u{fe0f}", "🧗🏻\u{200d}♀"),
+	("🧗🏻\u{200d}♂\u{fe0f}", "🧗🏻\u{200d}♂"),
+	("🧗🏼\u{200d}♀\u{fe0f}", "🧗🏼\u{200d}♀"),
+	("🧗🏼\u{200d}♂\u{fe0f}", "🧗🏼\u{200d}♂"),
+	("🧗🏽\u{200d}♀\u{fe0f}", "🧗🏽\u{200d}♀"),
+	("🧗🏽\u{200d}♂\u{fe0f}", "🧗🏽\u{200d}♂"),
+	("🧗🏾\u{200d}♀\u{fe0f}", "🧗🏾\u{200d}♀"),
+	("🧗🏾\u{200d}♂\u{fe0f}", "🧗🏾\u{200d}♂"),
+	("🧗🏿\u{200d}♀\u{fe0f}", "🧗🏿\u{200d}♀"),
+	("🧗🏿\u{200d}♂\u{fe0f}", "🧗🏿\u{200d}♂"),
+	("🧘\u{200d}♀\u{fe0f}", "🧘\u{200d}♀"),
+	("🧘\u{200d}♂\u{fe0f}", "🧘\u{200d}♂"),
+	("🧘🏻\u{200d}♀\u{fe0f}", "🧘🏻\u{200d}♀"),
+	("🧘🏻\u{200d}♂\u{fe0f}", "🧘🏻\u{200d}♂"),
+	("🧘🏼\u{200d}♀\u{fe0f}", "🧘🏼\u{200d}♀"),
+	("🧘🏼\u{200d}♂\u{fe0f}", "🧘🏼\u{200d}♂"),
+	("🧘🏽\u{200d}♀\u{fe0f}", "🧘🏽\u{200d}♀"),
+	("🧘🏽\u{200d}♂\u{fe0f}", "🧘🏽\u{200d}♂"),
+	("🧘🏾\u{200d}♀\u{fe0f}", "🧘🏾\u{200d}♀"),
+	("🧘🏾\u{200d}♂\u{fe0f}", "🧘🏾\u{200d}♂"),
+	("🧘🏿\u{200d}♀\u{fe0f}", "🧘🏿\u{200d}♀"),
+	("🧘🏿\u{200d}♂\u{fe0f}", "🧘🏿\u{200d}♂"),
+	("🧙\u{200d}♀\u{fe0f}", "🧙\u{200d}♀"),
+	("🧙\u{200d}♂\u{fe0f}", "🧙\u{200d}♂"),
+	("🧙🏻\u{200d}♀\u{fe0f}", "🧙🏻\u{200d}♀"),
+	("🧙🏻\u{200d}♂\u{fe0f}", "🧙🏻\u{200d}♂"),
+	("🧙🏼\u{200d}♀\u{fe0f}", "🧙🏼\u{200d}♀"),
+	("🧙🏼\u{200d}♂\u{fe0f}", "🧙🏼\u{200d}♂"),
+	("🧙🏽\u{200d}♀\u{fe0f}", "🧙🏽\u{200d}♀"),
+	("🧙🏽\u{200d}♂\u{fe0f}", "🧙🏽\u{200d}♂"),
+	("🧙🏾\u{200d}♀\u{fe0f}", "🧙🏾\u{200d}♀"),
+	("🧙🏾\u{200d}♂\u{fe0f}", "🧙🏾\u{200d}♂"),
+	("🧙🏿\u{200d}♀\u{fe0f}", "🧙🏿\u{200d}♀"),
+	("🧙🏿\u{200d}♂\u{fe0f}", "🧙🏿\u{200d}♂"),
+	("🧚\u{200d}♀\u{fe0f}", "🧚\u{200d}♀"),
+	("🧚\u{200d}♂\u{fe0f}", "🧚\u{200d}♂"),
+	("🧚🏻\u{200d}♀\u{fe0f}", "🧚🏻\u{200d}♀"),
+	("🧚🏻\u{200d}♂\u{fe0f}", "🧚🏻\u{200d}♂"),
+	("🧚🏼\u{200d}♀\u{fe0f}", "🧚🏼\u{200d}♀"),
+	("🧚🏼\u{200d}♂\u{fe0f}", "🧚🏼\u{200d}♂"),
+	("🧚🏽\u{200d}♀\u{fe0f}", "🧚🏽\u{200d}♀"),
+	("🧚🏽\u{200d}♂\u{fe0f}", "🧚🏽\u{200d}♂"),
+	("🧚🏾\u{200d}♀\u{fe0f}", "🧚🏾\u{200d}♀"),
+	("🧚🏾\u{200d}♂\u{fe0f}", "🧚🏾\u{200d}♂"),
+	("🧚🏿\u{200d}♀\u{fe0f}", "🧚🏿\u{200d}♀"),
+	("🧚🏿\u{200d}♂\u{fe0f}", "🧚🏿\u{200d}♂"),
+	("🧛\u{200d}♀\u{fe0f}", "🧛\u{200d}♀"),
+	("🧛\u{200d}♂\u{fe0f}", "🧛\u{200d}♂"),
+	("🧛🏻\u{200d}♀\u{fe0f}", "🧛🏻\u{200d}♀"),
+	("🧛🏻\u{200d}♂\u{fe0f}", "🧛🏻\u{200d}♂"),
+	("🧛🏼\u{200d}♀\u{fe0f}", "🧛🏼\u{200d}♀"),
+	("🧛🏼\u{200d}♂\u{fe0f}", "🧛🏼\u{200d}♂"),
+	("🧛🏽\u{200d}♀\u{fe0f}", "🧛🏽\u{200d}♀"),
+	("🧛🏽\u{200d}♂\u{fe0f}", "🧛🏽\u{200d}♂"),
+	("🧛🏾\u{200d}♀\u{fe0f}", "🧛🏾\u{200d}♀"),
+	("🧛🏾\u{200d}♂\u{fe0f}", "🧛🏾\u{200d}♂"),
+	("🧛🏿\u{200d}♀\u{fe0f}", "🧛🏿\u{200d}♀"),
+	("🧛🏿\u{200d}♂\u{fe0f}", "🧛🏿\u{200d}♂"),
+	("🧜\u{200d}♀\u{fe0f}", "🧜\u{200d}♀"),
+	("🧜\u{200d}♂\u{fe0f}", "🧜\u{200d}♂"),
+	("🧜🏻\u{200d}♀\u{fe0f}", "🧜🏻\u{200d}♀"),
+	("🧜🏻\u{200d}♂\u{fe0f}", "🧜🏻\u{200d}♂"),
+	("🧜🏼\u{200d}♀\u{fe0f}", "🧜🏼\u{200d}♀"),
+	("🧜🏼\u{200d}♂\u{fe0f}", "🧜🏼\u{200d}♂"),
+	("🧜🏽\u{200d}♀\u{fe0f}", "🧜🏽\u{200d}♀"),
+	("🧜🏽\u{200d}♂\u{fe0f}", "🧜🏽\u{200d}♂"),
+	("🧜🏾\u{200d}♀\u{fe0f}", "🧜🏾\u{200d}♀"),
+	("🧜🏾\u{200d}♂\u{fe0f}", "🧜🏾\u{200d}♂"),
+	("🧜🏿\u{200d}♀\u{fe0f}", "🧜🏿\u{200d}♀"),
+	("🧜🏿\u{200d}♂\u{fe0f}", "🧜🏿\u{200d}♂"),
+	("🧝\u{200d}♀\u{fe0f}", "🧝\u{200d}♀"),
+	("🧝\u{200d}♂\u{fe0f}", "🧝\u{200d}♂"),
+	("🧝🏻\u{200d}♀\u{fe0f}", "🧝🏻\u{200d}♀"),
+	("🧝🏻\u{200d}♂\u{fe0f}", "🧝🏻\u{200d}♂"),
+	("🧝🏼\u{200d}♀\u{fe0f}", "🧝🏼\u{200d}♀"),
+	("🧝🏼\u{200d}♂\u{fe0f}", "🧝🏼\u{200d}♂"),
+	("🧝🏽\u{200d}♀\u{fe0f}", "🧝🏽\u{200d}♀"),
+	("🧝🏽\u{200d}♂\u{fe0f}", "🧝🏽\u{200d}♂"),
+	("🧝🏾\u{200d}♀\u{fe0f}", "🧝🏾\u{200d}♀"),
+	("🧝🏾\u{200d}♂\u{fe0f}", "🧝🏾\u{200d}♂"),
+	("🧝🏿\u{200d}♀\u{fe0f}", "🧝🏿\u{200d}♀"),
+	("🧝🏿\u{200d}♂\u{fe0f}", "🧝🏿\u{200d}♂"),
+	("🧞\u{200d}♀\u{fe0f}", "🧞\u{200d}♀"),
+	("🧞\u{200d}♂\u{fe0f}", "🧞\u{200d}♂"),
+	("🧟\u{200d}♀\u{fe0f}", "🧟\u{200d}♀"),
+	("🧟\u{200d}♂\u{fe0f}", "🧟\u{200d}♂"),
+];
+
+// EOF
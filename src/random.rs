@@ -0,0 +1,95 @@
+//! Random emojis
+//!
+//! This module contains functions to pick emojis at random, e.g. for random reactions.
+//! It requires the `rand` feature.
+
+use rand::Rng;
+
+use crate::emojis::Emoji;
+use crate::search::all;
+
+/// Picks a random emoji, uniformly sampled from all emojis of this crate, including all variants.
+///
+/// # Examples
+/// ```
+/// use rand::SeedableRng;
+///
+/// let mut rng = rand::rngs::SmallRng::seed_from_u64(42);
+/// let emoji = emojic::random(&mut rng);
+/// assert_eq!(emojic::lookup(emoji.grapheme), Some(emoji));
+/// ```
+pub fn random<R: Rng + ?Sized>(rng: &mut R) -> &'static Emoji {
+    choose(all, rng).expect("there are emojis")
+}
+
+/// Picks a random emoji of the given group, uniformly sampled from all emojis of that group
+/// including all their variants.
+///
+/// The `group` is the Unicode name of the group, such as
+/// [`grouped::animals_and_nature::NAME`](crate::grouped::animals_and_nature::NAME) (i.e.
+/// `"Animals & Nature"`), and as returned by [`group_of`](crate::group_of).
+/// Returns `None` if there is no such group.
+///
+/// # Examples
+/// ```
+/// use emojic::grouped::animals_and_nature;
+/// use rand::SeedableRng;
+///
+/// let mut rng = rand::rngs::SmallRng::seed_from_u64(42);
+/// let emoji = emojic::random_from_group(animals_and_nature::NAME, &mut rng).unwrap();
+/// assert_eq!(emojic::group_of(emoji).unwrap().0, animals_and_nature::NAME);
+///
+/// assert_eq!(emojic::random_from_group("Dinosaurs", &mut rng), None);
+/// ```
+pub fn random_from_group<R: Rng + ?Sized>(group: &str, rng: &mut R) -> Option<&'static Emoji> {
+    let emojis = || {
+        crate::grouped::ALL_SUBGROUPS
+            .iter()
+            .filter(move |(g, _, _)| *g == group)
+            .flat_map(|(_, _, variants)| variants.iter())
+            .flat_map(|v| v.iter().copied())
+    };
+    choose(emojis, rng)
+}
+
+/// Uniformly chooses an element of the iterators created by `emojis`.
+///
+/// `emojis` is called twice, first to count the elements and then to pick one of them.
+fn choose<I, R>(emojis: impl Fn() -> I, rng: &mut R) -> Option<I::Item>
+where
+    I: Iterator,
+    R: Rng + ?Sized,
+{
+    let len = emojis().count();
+    if len == 0 {
+        None
+    } else {
+        emojis().nth(rng.gen_range(0..len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn random_covers_variants() {
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(0);
+        // With enough samples, we should see at least one toned emoji
+        let toned = (0..1000)
+            .map(|_| random(&mut rng))
+            .any(|e| e.grapheme.contains('\u{1F3FF}'));
+        assert!(toned);
+    }
+
+    #[test]
+    fn random_from_all_groups() {
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(0);
+        for (group, _, _) in crate::grouped::ALL_SUBGROUPS {
+            let emoji = random_from_group(group, &mut rng).unwrap();
+            assert_eq!(crate::group_of(emoji).unwrap().0, *group);
+        }
+        assert_eq!(random_from_group("", &mut rng), None);
+    }
+}
@@ -0,0 +1,26 @@
+// Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
+
+// Source: https://unicode.org/Public/emoji/15.1/emoji-test.txt (up to E14.0, names and groups of E13.1 kept)
+// Created at: 2026-10-16 13:54:22.452193168 UTC
+
+/// A regular expression matching any single emoji.
+///
+/// This is an alternation (`a|b|c`) of the escaped graphemes of all emojis of this crate,
+/// including all their variants, ordered longest first, so that e.g. 👨‍👩‍👧 is matched as a
+/// whole instead of just its leading 👨.
+///
+/// Since an alternation binds weaker than anything else, it must be enclosed in a group when
+/// combined with other patterns, e.g. for anchoring: `^(?:EMOJI_REGEX)$` instead of
+/// `^EMOJI_REGEX$` (the latter would only anchor the first and the last emoji of the list).
+///
+/// # Examples
+///
+/// ```
+/// let re = regex::Regex::new(emojic::EMOJI_REGEX).unwrap();
+///
+/// let found: Vec<_> = re.find_iter("I 💖 🦀 & 👨‍👩‍👧").map(|m| m.as_str()).collect();
+/// assert_eq!(found, ["💖", "🦀", "👨‍👩‍👧"]);
+/// ```
+pub const EMOJI_REGEX: &str = "👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻|👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼|👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽|👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾|👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿|👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻|👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼|👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽|👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾|👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿|👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻|👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼|👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽|👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾|👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿|👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻|👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼|👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽|👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾|👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿|👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻|👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼|👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽|👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾|👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿|👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻|👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼|👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽|👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾|👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿|👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻|👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼|👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽|👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾|👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿|👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻|👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼|👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽|👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾|👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿|👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻|👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼|👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽|👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾|👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿|👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻|👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼|👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽|👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾|👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿|👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻|👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼|👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽|👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾|👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿|👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻|👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼|👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽|👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾|👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿|👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻|👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼|👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽|👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾|👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿|👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻|👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼|👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽|👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾|👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿|👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻|👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼|👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽|👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾|👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿|🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼|🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽|🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾|🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿|🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻|🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽|🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾|🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿|🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻|🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼|🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾|🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿|🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻|🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼|🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽|🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿|🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻|🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼|🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽|🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾|🏴\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}|🏴\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}|🏴\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}|👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏻|👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏼|👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏽|👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏾|👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏿|👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏻|👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏼|👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏽|👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏾|👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏿|👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏻|👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏼|👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏽|👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏾|👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿|👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏻|👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏼|👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏽|👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏾|👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏿|👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏻|👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏼|👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏽|👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏾|👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏿|👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏻|👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏼|👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏽|👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏾|👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏿|👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏻|👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏼|👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏽|👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏾|👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏿|👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏻|👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏼|👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏽|👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏾|👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏿|👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏻|👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏼|👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏽|👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏾|👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏿|👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏻|👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏼|👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏽|👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏾|👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿|👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏻|👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏼|👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏽|👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏾|👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏿|👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏻|👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏼|👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏽|👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏾|👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏿|👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏻|👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏼|👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏽|👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏾|👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏿|👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏻|👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏼|👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏽|👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏾|👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏿|👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏻|👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏼|👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏽|👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏾|👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏿|🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏼|🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏽|🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏾|🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏿|🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏻|🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏽|🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏾|🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏿|🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏻|🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏼|🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏾|🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏿|🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏻|🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏼|🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏽|🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏿|🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏻|🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏼|🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏽|🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏾|👨\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨|👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨|👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩|👨🏻\u{200d}🤝\u{200d}👨🏼|👨🏻\u{200d}🤝\u{200d}👨🏽|👨🏻\u{200d}🤝\u{200d}👨🏾|👨🏻\u{200d}🤝\u{200d}👨🏿|👨🏼\u{200d}🤝\u{200d}👨🏻|👨🏼\u{200d}🤝\u{200d}👨🏽|👨🏼\u{200d}🤝\u{200d}👨🏾|👨🏼\u{200d}🤝\u{200d}👨🏿|👨🏽\u{200d}🤝\u{200d}👨🏻|👨🏽\u{200d}🤝\u{200d}👨🏼|👨🏽\u{200d}🤝\u{200d}👨🏾|👨🏽\u{200d}🤝\u{200d}👨🏿|👨🏾\u{200d}🤝\u{200d}👨🏻|👨🏾\u{200d}🤝\u{200d}👨🏼|👨🏾\u{200d}🤝\u{200d}👨🏽|👨🏾\u{200d}🤝\u{200d}👨🏿|👨🏿\u{200d}🤝\u{200d}👨🏻|👨🏿\u{200d}🤝\u{200d}👨🏼|👨🏿\u{200d}🤝\u{200d}👨🏽|👨🏿\u{200d}🤝\u{200d}👨🏾|👩🏻\u{200d}🤝\u{200d}👨🏼|👩🏻\u{200d}🤝\u{200d}👨🏽|👩🏻\u{200d}🤝\u{200d}👨🏾|👩🏻\u{200d}🤝\u{200d}👨🏿|👩🏻\u{200d}🤝\u{200d}👩🏼|👩🏻\u{200d}🤝\u{200d}👩🏽|👩🏻\u{200d}🤝\u{200d}👩🏾|👩🏻\u{200d}🤝\u{200d}👩🏿|👩🏼\u{200d}🤝\u{200d}👨🏻|👩🏼\u{200d}🤝\u{200d}👨🏽|👩🏼\u{200d}🤝\u{200d}👨🏾|👩🏼\u{200d}🤝\u{200d}👨🏿|👩🏼\u{200d}🤝\u{200d}👩🏻|👩🏼\u{200d}🤝\u{200d}👩🏽|👩🏼\u{200d}🤝\u{200d}👩🏾|👩🏼\u{200d}🤝\u{200d}👩🏿|👩🏽\u{200d}🤝\u{200d}👨🏻|👩🏽\u{200d}🤝\u{200d}👨🏼|👩🏽\u{200d}🤝\u{200d}👨🏾|👩🏽\u{200d}🤝\u{200d}👨🏿|👩🏽\u{200d}🤝\u{200d}👩🏻|👩🏽\u{200d}🤝\u{200d}👩🏼|👩🏽\u{200d}🤝\u{200d}👩🏾|👩🏽\u{200d}🤝\u{200d}👩🏿|👩🏾\u{200d}🤝\u{200d}👨🏻|👩🏾\u{200d}🤝\u{200d}👨🏼|👩🏾\u{200d}🤝\u{200d}👨🏽|👩🏾\u{200d}🤝\u{200d}👨🏿|👩🏾\u{200d}🤝\u{200d}👩🏻|👩🏾\u{200d}🤝\u{200d}👩🏼|👩🏾\u{200d}🤝\u{200d}👩🏽|👩🏾\u{200d}🤝\u{200d}👩🏿|👩🏿\u{200d}🤝\u{200d}👨🏻|👩🏿\u{200d}🤝\u{200d}👨🏼|👩🏿\u{200d}🤝\u{200d}👨🏽|👩🏿\u{200d}🤝\u{200d}👨🏾|👩🏿\u{200d}🤝\u{200d}👩🏻|👩🏿\u{200d}🤝\u{200d}👩🏼|👩🏿\u{200d}🤝\u{200d}👩🏽|👩🏿\u{200d}🤝\u{200d}👩🏾|🧑🏻\u{200d}🤝\u{200d}🧑🏻|🧑🏻\u{200d}🤝\u{200d}🧑🏼|🧑🏻\u{200d}🤝\u{200d}🧑🏽|🧑🏻\u{200d}🤝\u{200d}🧑🏾|🧑🏻\u{200d}🤝\u{200d}🧑🏿|🧑🏼\u{200d}🤝\u{200d}🧑🏻|🧑🏼\u{200d}🤝\u{200d}🧑🏼|🧑🏼\u{200d}🤝\u{200d}🧑🏽|🧑🏼\u{200d}🤝\u{200d}🧑🏾|🧑🏼\u{200d}🤝\u{200d}🧑🏿|🧑🏽\u{200d}🤝\u{200d}🧑🏻|🧑🏽\u{200d}🤝\u{200d}🧑🏼|🧑🏽\u{200d}🤝\u{200d}🧑🏽|🧑🏽\u{200d}🤝\u{200d}🧑🏾|🧑🏽\u{200d}🤝\u{200d}🧑🏿|🧑🏾\u{200d}🤝\u{200d}🧑🏻|🧑🏾\u{200d}🤝\u{200d}🧑🏼|🧑🏾\u{200d}🤝\u{200d}🧑🏽|🧑🏾\u{200d}🤝\u{200d}🧑🏾|🧑🏾\u{200d}🤝\u{200d}🧑🏿|🧑🏿\u{200d}🤝\u{200d}🧑🏻|🧑🏿\u{200d}🤝\u{200d}🧑🏼|🧑🏿\u{200d}🤝\u{200d}🧑🏽|🧑🏿\u{200d}🤝\u{200d}🧑🏾|🧑🏿\u{200d}🤝\u{200d}🧑🏿|👨\u{200d}👨\u{200d}👦\u{200d}👦|👨\u{200d}👨\u{200d}👧\u{200d}👦|👨\u{200d}👨\u{200d}👧\u{200d}👧|👨\u{200d}👩\u{200d}👦\u{200d}👦|👨\u{200d}👩\u{200d}👧\u{200d}👦|👨\u{200d}👩\u{200d}👧\u{200d}👧|👩\u{200d}👩\u{200d}👦\u{200d}👦|👩\u{200d}👩\u{200d}👧\u{200d}👦|👩\u{200d}👩\u{200d}👧\u{200d}👧|👨\u{200d}❤\u{fe0f}\u{200d}👨|👩\u{200d}❤\u{fe0f}\u{200d}👨|👩\u{200d}❤\u{fe0f}\u{200d}👩|🫱🏻\u{200d}🫲🏼|🫱🏻\u{200d}🫲🏽|🫱🏻\u{200d}🫲🏾|🫱🏻\u{200d}🫲🏿|🫱🏼\u{200d}🫲🏻|🫱🏼\u{200d}🫲🏽|🫱🏼\u{200d}🫲🏾|🫱🏼\u{200d}🫲🏿|🫱🏽\u{200d}🫲🏻|🫱🏽\u{200d}🫲🏼|🫱🏽\u{200d}🫲🏾|🫱🏽\u{200d}🫲🏿|🫱🏾\u{200d}🫲🏻|🫱🏾\u{200d}🫲🏼|🫱🏾\u{200d}🫲🏽|🫱🏾\u{200d}🫲🏿|🫱🏿\u{200d}🫲🏻|🫱🏿\u{200d}🫲🏼|🫱🏿\u{200d}🫲🏽|🫱🏿\u{200d}🫲🏾|👨\u{200d}👦\u{200d}👦|👨\u{200d}👧\u{200d}👦|👨\u{200d}👧\u{200d}👧|👨\u{200d}👨\u{200d}👦|👨\u{200d}👨\u{200d}👧|👨\u{200d}👩\u{200d}👦|👨\u{200d}👩\u{200d}👧|👩\u{200d}👦\u{200d}👦|👩\u{200d}👧\u{200d}👦|👩\u{200d}👧\u{200d}👧|👩\u{200d}👩\u{200d}👦|👩\u{200d}👩\u{200d}👧|🧑\u{200d}🤝\u{200d}🧑|🏃🏻\u{200d}♀\u{fe0f}|🏃🏻\u{200d}♂\u{fe0f}|🏃🏼\u{200d}♀\u{fe0f}|🏃🏼\u{200d}♂\u{fe0f}|🏃🏽\u{200d}♀\u{fe0f}|🏃🏽\u{200d}♂\u{fe0f}|🏃🏾\u{200d}♀\u{fe0f}|🏃🏾\u{200d}♂\u{fe0f}|🏃🏿\u{200d}♀\u{fe0f}|🏃🏿\u{200d}♂\u{fe0f}|🏄🏻\u{200d}♀\u{fe0f}|🏄🏻\u{200d}♂\u{fe0f}|🏄🏼\u{200d}♀\u{fe0f}|🏄🏼\u{200d}♂\u{fe0f}|🏄🏽\u{200d}♀\u{fe0f}|🏄🏽\u{200d}♂\u{fe0f}|🏄🏾\u{200d}♀\u{fe0f}|🏄🏾\u{200d}♂\u{fe0f}|🏄🏿\u{200d}♀\u{fe0f}|🏄🏿\u{200d}♂\u{fe0f}|🏊🏻\u{200d}♀\u{fe0f}|🏊🏻\u{200d}♂\u{fe0f}|🏊🏼\u{200d}♀\u{fe0f}|🏊🏼\u{200d}♂\u{fe0f}|🏊🏽\u{200d}♀\u{fe0f}|🏊🏽\u{200d}♂\u{fe0f}|🏊🏾\u{200d}♀\u{fe0f}|🏊🏾\u{200d}♂\u{fe0f}|🏊🏿\u{200d}♀\u{fe0f}|🏊🏿\u{200d}♂\u{fe0f}|🏋🏻\u{200d}♀\u{fe0f}|🏋🏻\u{200d}♂\u{fe0f}|🏋🏼\u{200d}♀\u{fe0f}|🏋🏼\u{200d}♂\u{fe0f}|🏋🏽\u{200d}♀\u{fe0f}|🏋🏽\u{200d}♂\u{fe0f}|🏋🏾\u{200d}♀\u{fe0f}|🏋🏾\u{200d}♂\u{fe0f}|🏋🏿\u{200d}♀\u{fe0f}|🏋🏿\u{200d}♂\u{fe0f}|🏌🏻\u{200d}♀\u{fe0f}|🏌🏻\u{200d}♂\u{fe0f}|🏌🏼\u{200d}♀\u{fe0f}|🏌🏼\u{200d}♂\u{fe0f}|🏌🏽\u{200d}♀\u{fe0f}|🏌🏽\u{200d}♂\u{fe0f}|🏌🏾\u{200d}♀\u{fe0f}|🏌🏾\u{200d}♂\u{fe0f}|🏌🏿\u{200d}♀\u{fe0f}|🏌🏿\u{200d}♂\u{fe0f}|👁\u{fe0f}\u{200d}🗨\u{fe0f}|👨🏻\u{200d}⚕\u{fe0f}|👨🏻\u{200d}⚖\u{fe0f}|👨🏻\u{200d}✈\u{fe0f}|👨🏼\u{200d}⚕\u{fe0f}|👨🏼\u{200d}⚖\u{fe0f}|👨🏼\u{200d}✈\u{fe0f}|👨🏽\u{200d}⚕\u{fe0f}|👨🏽\u{200d}⚖\u{fe0f}|👨🏽\u{200d}✈\u{fe0f}|👨🏾\u{200d}⚕\u{fe0f}|👨🏾\u{200d}⚖\u{fe0f}|👨🏾\u{200d}✈\u{fe0f}|👨🏿\u{200d}⚕\u{fe0f}|👨🏿\u{200d}⚖\u{fe0f}|👨🏿\u{200d}✈\u{fe0f}|👩🏻\u{200d}⚕\u{fe0f}|👩🏻\u{200d}⚖\u{fe0f}|👩🏻\u{200d}✈\u{fe0f}|👩🏼\u{200d}⚕\u{fe0f}|👩🏼\u{200d}⚖\u{fe0f}|👩🏼\u{200d}✈\u{fe0f}|👩🏽\u{200d}⚕\u{fe0f}|👩🏽\u{200d}⚖\u{fe0f}|👩🏽\u{200d}✈\u{fe0f}|👩🏾\u{200d}⚕\u{fe0f}|👩🏾\u{200d}⚖\u{fe0f}|👩🏾\u{200d}✈\u{fe0f}|👩🏿\u{200d}⚕\u{fe0f}|👩🏿\u{200d}⚖\u{fe0f}|👩🏿\u{200d}✈\u{fe0f}|👮🏻\u{200d}♀\u{fe0f}|👮🏻\u{200d}♂\u{fe0f}|👮🏼\u{200d}♀\u{fe0f}|👮🏼\u{200d}♂\u{fe0f}|👮🏽\u{200d}♀\u{fe0f}|👮🏽\u{200d}♂\u{fe0f}|👮🏾\u{200d}♀\u{fe0f}|👮🏾\u{200d}♂\u{fe0f}|👮🏿\u{200d}♀\u{fe0f}|👮🏿\u{200d}♂\u{fe0f}|👰🏻\u{200d}♀\u{fe0f}|👰🏻\u{200d}♂\u{fe0f}|👰🏼\u{200d}♀\u{fe0f}|👰🏼\u{200d}♂\u{fe0f}|👰🏽\u{200d}♀\u{fe0f}|👰🏽\u{200d}♂\u{fe0f}|👰🏾\u{200d}♀\u{fe0f}|👰🏾\u{200d}♂\u{fe0f}|👰🏿\u{200d}♀\u{fe0f}|👰🏿\u{200d}♂\u{fe0f}|👱🏻\u{200d}♀\u{fe0f}|👱🏻\u{200d}♂\u{fe0f}|👱🏼\u{200d}♀\u{fe0f}|👱🏼\u{200d}♂\u{fe0f}|👱🏽\u{200d}♀\u{fe0f}|👱🏽\u{200d}♂\u{fe0f}|👱🏾\u{200d}♀\u{fe0f}|👱🏾\u{200d}♂\u{fe0f}|👱🏿\u{200d}♀\u{fe0f}|👱🏿\u{200d}♂\u{fe0f}|👳🏻\u{200d}♀\u{fe0f}|👳🏻\u{200d}♂\u{fe0f}|👳🏼\u{200d}♀\u{fe0f}|👳🏼\u{200d}♂\u{fe0f}|👳🏽\u{200d}♀\u{fe0f}|👳🏽\u{200d}♂\u{fe0f}|👳🏾\u{200d}♀\u{fe0f}|👳🏾\u{200d}♂\u{fe0f}|👳🏿\u{200d}♀\u{fe0f}|👳🏿\u{200d}♂\u{fe0f}|👷🏻\u{200d}♀\u{fe0f}|👷🏻\u{200d}♂\u{fe0f}|👷🏼\u{200d}♀\u{fe0f}|👷🏼\u{200d}♂\u{fe0f}|👷🏽\u{200d}♀\u{fe0f}|👷🏽\u{200d}♂\u{fe0f}|👷🏾\u{200d}♀\u{fe0f}|👷🏾\u{200d}♂\u{fe0f}|👷🏿\u{200d}♀\u{fe0f}|👷🏿\u{200d}♂\u{fe0f}|💁🏻\u{200d}♀\u{fe0f}|💁🏻\u{200d}♂\u{fe0f}|💁🏼\u{200d}♀\u{fe0f}|💁🏼\u{200d}♂\u{fe0f}|💁🏽\u{200d}♀\u{fe0f}|💁🏽\u{200d}♂\u{fe0f}|💁🏾\u{200d}♀\u{fe0f}|💁🏾\u{200d}♂\u{fe0f}|💁🏿\u{200d}♀\u{fe0f}|💁🏿\u{200d}♂\u{fe0f}|💂🏻\u{200d}♀\u{fe0f}|💂🏻\u{200d}♂\u{fe0f}|💂🏼\u{200d}♀\u{fe0f}|💂🏼\u{200d}♂\u{fe0f}|💂🏽\u{200d}♀\u{fe0f}|💂🏽\u{200d}♂\u{fe0f}|💂🏾\u{200d}♀\u{fe0f}|💂🏾\u{200d}♂\u{fe0f}|💂🏿\u{200d}♀\u{fe0f}|💂🏿\u{200d}♂\u{fe0f}|💆🏻\u{200d}♀\u{fe0f}|💆🏻\u{200d}♂\u{fe0f}|💆🏼\u{200d}♀\u{fe0f}|💆🏼\u{200d}♂\u{fe0f}|💆🏽\u{200d}♀\u{fe0f}|💆🏽\u{200d}♂\u{fe0f}|💆🏾\u{200d}♀\u{fe0f}|💆🏾\u{200d}♂\u{fe0f}|💆🏿\u{200d}♀\u{fe0f}|💆🏿\u{200d}♂\u{fe0f}|💇🏻\u{200d}♀\u{fe0f}|💇🏻\u{200d}♂\u{fe0f}|💇🏼\u{200d}♀\u{fe0f}|💇🏼\u{200d}♂\u{fe0f}|💇🏽\u{200d}♀\u{fe0f}|💇🏽\u{200d}♂\u{fe0f}|💇🏾\u{200d}♀\u{fe0f}|💇🏾\u{200d}♂\u{fe0f}|💇🏿\u{200d}♀\u{fe0f}|💇🏿\u{200d}♂\u{fe0f}|🕵🏻\u{200d}♀\u{fe0f}|🕵🏻\u{200d}♂\u{fe0f}|🕵🏼\u{200d}♀\u{fe0f}|🕵🏼\u{200d}♂\u{fe0f}|🕵🏽\u{200d}♀\u{fe0f}|🕵🏽\u{200d}♂\u{fe0f}|🕵🏾\u{200d}♀\u{fe0f}|🕵🏾\u{200d}♂\u{fe0f}|🕵🏿\u{200d}♀\u{fe0f}|🕵🏿\u{200d}♂\u{fe0f}|🙅🏻\u{200d}♀\u{fe0f}|🙅🏻\u{200d}♂\u{fe0f}|🙅🏼\u{200d}♀\u{fe0f}|🙅🏼\u{200d}♂\u{fe0f}|🙅🏽\u{200d}♀\u{fe0f}|🙅🏽\u{200d}♂\u{fe0f}|🙅🏾\u{200d}♀\u{fe0f}|🙅🏾\u{200d}♂\u{fe0f}|🙅🏿\u{200d}♀\u{fe0f}|🙅🏿\u{200d}♂\u{fe0f}|🙆🏻\u{200d}♀\u{fe0f}|🙆🏻\u{200d}♂\u{fe0f}|🙆🏼\u{200d}♀\u{fe0f}|🙆🏼\u{200d}♂\u{fe0f}|🙆🏽\u{200d}♀\u{fe0f}|🙆🏽\u{200d}♂\u{fe0f}|🙆🏾\u{200d}♀\u{fe0f}|🙆🏾\u{200d}♂\u{fe0f}|🙆🏿\u{200d}♀\u{fe0f}|🙆🏿\u{200d}♂\u{fe0f}|🙇🏻\u{200d}♀\u{fe0f}|🙇🏻\u{200d}♂\u{fe0f}|🙇🏼\u{200d}♀\u{fe0f}|🙇🏼\u{200d}♂\u{fe0f}|🙇🏽\u{200d}♀\u{fe0f}|🙇🏽\u{200d}♂\u{fe0f}|🙇🏾\u{200d}♀\u{fe0f}|🙇🏾\u{200d}♂\u{fe0f}|🙇🏿\u{200d}♀\u{fe0f}|🙇🏿\u{200d}♂\u{fe0f}|🙋🏻\u{200d}♀\u{fe0f}|🙋🏻\u{200d}♂\u{fe0f}|🙋🏼\u{200d}♀\u{fe0f}|🙋🏼\u{200d}♂\u{fe0f}|🙋🏽\u{200d}♀\u{fe0f}|🙋🏽\u{200d}♂\u{fe0f}|🙋🏾\u{200d}♀\u{fe0f}|🙋🏾\u{200d}♂\u{fe0f}|🙋🏿\u{200d}♀\u{fe0f}|🙋🏿\u{200d}♂\u{fe0f}|🙍🏻\u{200d}♀\u{fe0f}|🙍🏻\u{200d}♂\u{fe0f}|🙍🏼\u{200d}♀\u{fe0f}|🙍🏼\u{200d}♂\u{fe0f}|🙍🏽\u{200d}♀\u{fe0f}|🙍🏽\u{200d}♂\u{fe0f}|🙍🏾\u{200d}♀\u{fe0f}|🙍🏾\u{200d}♂\u{fe0f}|🙍🏿\u{200d}♀\u{fe0f}|🙍🏿\u{200d}♂\u{fe0f}|🙎🏻\u{200d}♀\u{fe0f}|🙎🏻\u{200d}♂\u{fe0f}|🙎🏼\u{200d}♀\u{fe0f}|🙎🏼\u{200d}♂\u{fe0f}|🙎🏽\u{200d}♀\u{fe0f}|🙎🏽\u{200d}♂\u{fe0f}|🙎🏾\u{200d}♀\u{fe0f}|🙎🏾\u{200d}♂\u{fe0f}|🙎🏿\u{200d}♀\u{fe0f}|🙎🏿\u{200d}♂\u{fe0f}|🚣🏻\u{200d}♀\u{fe0f}|🚣🏻\u{200d}♂\u{fe0f}|🚣🏼\u{200d}♀\u{fe0f}|🚣🏼\u{200d}♂\u{fe0f}|🚣🏽\u{200d}♀\u{fe0f}|🚣🏽\u{200d}♂\u{fe0f}|🚣🏾\u{200d}♀\u{fe0f}|🚣🏾\u{200d}♂\u{fe0f}|🚣🏿\u{200d}♀\u{fe0f}|🚣🏿\u{200d}♂\u{fe0f}|🚴🏻\u{200d}♀\u{fe0f}|🚴🏻\u{200d}♂\u{fe0f}|🚴🏼\u{200d}♀\u{fe0f}|🚴🏼\u{200d}♂\u{fe0f}|🚴🏽\u{200d}♀\u{fe0f}|🚴🏽\u{200d}♂\u{fe0f}|🚴🏾\u{200d}♀\u{fe0f}|🚴🏾\u{200d}♂\u{fe0f}|🚴🏿\u{200d}♀\u{fe0f}|🚴🏿\u{200d}♂\u{fe0f}|🚵🏻\u{200d}♀\u{fe0f}|🚵🏻\u{200d}♂\u{fe0f}|🚵🏼\u{200d}♀\u{fe0f}|🚵🏼\u{200d}♂\u{fe0f}|🚵🏽\u{200d}♀\u{fe0f}|🚵🏽\u{200d}♂\u{fe0f}|🚵🏾\u{200d}♀\u{fe0f}|🚵🏾\u{200d}♂\u{fe0f}|🚵🏿\u{200d}♀\u{fe0f}|🚵🏿\u{200d}♂\u{fe0f}|🚶🏻\u{200d}♀\u{fe0f}|🚶🏻\u{200d}♂\u{fe0f}|🚶🏼\u{200d}♀\u{fe0f}|🚶🏼\u{200d}♂\u{fe0f}|🚶🏽\u{200d}♀\u{fe0f}|🚶🏽\u{200d}♂\u{fe0f}|🚶🏾\u{200d}♀\u{fe0f}|🚶🏾\u{200d}♂\u{fe0f}|🚶🏿\u{200d}♀\u{fe0f}|🚶🏿\u{200d}♂\u{fe0f}|🤦🏻\u{200d}♀\u{fe0f}|🤦🏻\u{200d}♂\u{fe0f}|🤦🏼\u{200d}♀\u{fe0f}|🤦🏼\u{200d}♂\u{fe0f}|🤦🏽\u{200d}♀\u{fe0f}|🤦🏽\u{200d}♂\u{fe0f}|🤦🏾\u{200d}♀\u{fe0f}|🤦🏾\u{200d}♂\u{fe0f}|🤦🏿\u{200d}♀\u{fe0f}|🤦🏿\u{200d}♂\u{fe0f}|🤵🏻\u{200d}♀\u{fe0f}|🤵🏻\u{200d}♂\u{fe0f}|🤵🏼\u{200d}♀\u{fe0f}|🤵🏼\u{200d}♂\u{fe0f}|🤵🏽\u{200d}♀\u{fe0f}|🤵🏽\u{200d}♂\u{fe0f}|🤵🏾\u{200d}♀\u{fe0f}|🤵🏾\u{200d}♂\u{fe0f}|🤵🏿\u{200d}♀\u{fe0f}|🤵🏿\u{200d}♂\u{fe0f}|🤷🏻\u{200d}♀\u{fe0f}|🤷🏻\u{200d}♂\u{fe0f}|🤷🏼\u{200d}♀\u{fe0f}|🤷🏼\u{200d}♂\u{fe0f}|🤷🏽\u{200d}♀\u{fe0f}|🤷🏽\u{200d}♂\u{fe0f}|🤷🏾\u{200d}♀\u{fe0f}|🤷🏾\u{200d}♂\u{fe0f}|🤷🏿\u{200d}♀\u{fe0f}|🤷🏿\u{200d}♂\u{fe0f}|🤸🏻\u{200d}♀\u{fe0f}|🤸🏻\u{200d}♂\u{fe0f}|🤸🏼\u{200d}♀\u{fe0f}|🤸🏼\u{200d}♂\u{fe0f}|🤸🏽\u{200d}♀\u{fe0f}|🤸🏽\u{200d}♂\u{fe0f}|🤸🏾\u{200d}♀\u{fe0f}|🤸🏾\u{200d}♂\u{fe0f}|🤸🏿\u{200d}♀\u{fe0f}|🤸🏿\u{200d}♂\u{fe0f}|🤹🏻\u{200d}♀\u{fe0f}|🤹🏻\u{200d}♂\u{fe0f}|🤹🏼\u{200d}♀\u{fe0f}|🤹🏼\u{200d}♂\u{fe0f}|🤹🏽\u{200d}♀\u{fe0f}|🤹🏽\u{200d}♂\u{fe0f}|🤹🏾\u{200d}♀\u{fe0f}|🤹🏾\u{200d}♂\u{fe0f}|🤹🏿\u{200d}♀\u{fe0f}|🤹🏿\u{200d}♂\u{fe0f}|🤽🏻\u{200d}♀\u{fe0f}|🤽🏻\u{200d}♂\u{fe0f}|🤽🏼\u{200d}♀\u{fe0f}|🤽🏼\u{200d}♂\u{fe0f}|🤽🏽\u{200d}♀\u{fe0f}|🤽🏽\u{200d}♂\u{fe0f}|🤽🏾\u{200d}♀\u{fe0f}|🤽🏾\u{200d}♂\u{fe0f}|🤽🏿\u{200d}♀\u{fe0f}|🤽🏿\u{200d}♂\u{fe0f}|🤾🏻\u{200d}♀\u{fe0f}|🤾🏻\u{200d}♂\u{fe0f}|🤾🏼\u{200d}♀\u{fe0f}|🤾🏼\u{200d}♂\u{fe0f}|🤾🏽\u{200d}♀\u{fe0f}|🤾🏽\u{200d}♂\u{fe0f}|🤾🏾\u{200d}♀\u{fe0f}|🤾🏾\u{200d}♂\u{fe0f}|🤾🏿\u{200d}♀\u{fe0f}|🤾🏿\u{200d}♂\u{fe0f}|🦸🏻\u{200d}♀\u{fe0f}|🦸🏻\u{200d}♂\u{fe0f}|🦸🏼\u{200d}♀\u{fe0f}|🦸🏼\u{200d}♂\u{fe0f}|🦸🏽\u{200d}♀\u{fe0f}|🦸🏽\u{200d}♂\u{fe0f}|🦸🏾\u{200d}♀\u{fe0f}|🦸🏾\u{200d}♂\u{fe0f}|🦸🏿\u{200d}♀\u{fe0f}|🦸🏿\u{200d}♂\u{fe0f}|🦹🏻\u{200d}♀\u{fe0f}|🦹🏻\u{200d}♂\u{fe0f}|🦹🏼\u{200d}♀\u{fe0f}|🦹🏼\u{200d}♂\u{fe0f}|🦹🏽\u{200d}♀\u{fe0f}|🦹🏽\u{200d}♂\u{fe0f}|🦹🏾\u{200d}♀\u{fe0f}|🦹🏾\u{200d}♂\u{fe0f}|🦹🏿\u{200d}♀\u{fe0f}|🦹🏿\u{200d}♂\u{fe0f}|🧍🏻\u{200d}♀\u{fe0f}|🧍🏻\u{200d}♂\u{fe0f}|🧍🏼\u{200d}♀\u{fe0f}|🧍🏼\u{200d}♂\u{fe0f}|🧍🏽\u{200d}♀\u{fe0f}|🧍🏽\u{200d}♂\u{fe0f}|🧍🏾\u{200d}♀\u{fe0f}|🧍🏾\u{200d}♂\u{fe0f}|🧍🏿\u{200d}♀\u{fe0f}|🧍🏿\u{200d}♂\u{fe0f}|🧎🏻\u{200d}♀\u{fe0f}|🧎🏻\u{200d}♂\u{fe0f}|🧎🏼\u{200d}♀\u{fe0f}|🧎🏼\u{200d}♂\u{fe0f}|🧎🏽\u{200d}♀\u{fe0f}|🧎🏽\u{200d}♂\u{fe0f}|🧎🏾\u{200d}♀\u{fe0f}|🧎🏾\u{200d}♂\u{fe0f}|🧎🏿\u{200d}♀\u{fe0f}|🧎🏿\u{200d}♂\u{fe0f}|🧏🏻\u{200d}♀\u{fe0f}|🧏🏻\u{200d}♂\u{fe0f}|🧏🏼\u{200d}♀\u{fe0f}|🧏🏼\u{200d}♂\u{fe0f}|🧏🏽\u{200d}♀\u{fe0f}|🧏🏽\u{200d}♂\u{fe0f}|🧏🏾\u{200d}♀\u{fe0f}|🧏🏾\u{200d}♂\u{fe0f}|🧏🏿\u{200d}♀\u{fe0f}|🧏🏿\u{200d}♂\u{fe0f}|🧑🏻\u{200d}⚕\u{fe0f}|🧑🏻\u{200d}⚖\u{fe0f}|🧑🏻\u{200d}✈\u{fe0f}|🧑🏼\u{200d}⚕\u{fe0f}|🧑🏼\u{200d}⚖\u{fe0f}|🧑🏼\u{200d}✈\u{fe0f}|🧑🏽\u{200d}⚕\u{fe0f}|🧑🏽\u{200d}⚖\u{fe0f}|🧑🏽\u{200d}✈\u{fe0f}|🧑🏾\u{200d}⚕\u{fe0f}|🧑🏾\u{200d}⚖\u{fe0f}|🧑🏾\u{200d}✈\u{fe0f}|🧑🏿\u{200d}⚕\u{fe0f}|🧑🏿\u{200d}⚖\u{fe0f}|🧑🏿\u{200d}✈\u{fe0f}|🧔🏻\u{200d}♀\u{fe0f}|🧔🏻\u{200d}♂\u{fe0f}|🧔🏼\u{200d}♀\u{fe0f}|🧔🏼\u{200d}♂\u{fe0f}|🧔🏽\u{200d}♀\u{fe0f}|🧔🏽\u{200d}♂\u{fe0f}|🧔🏾\u{200d}♀\u{fe0f}|🧔🏾\u{200d}♂\u{fe0f}|🧔🏿\u{200d}♀\u{fe0f}|🧔🏿\u{200d}♂\u{fe0f}|🧖🏻\u{200d}♀\u{fe0f}|🧖🏻\u{200d}♂\u{fe0f}|🧖🏼\u{200d}♀\u{fe0f}|🧖🏼\u{200d}♂\u{fe0f}|🧖🏽\u{200d}♀\u{fe0f}|🧖🏽\u{200d}♂\u{fe0f}|🧖🏾\u{200d}♀\u{fe0f}|🧖🏾\u{200d}♂\u{fe0f}|🧖🏿\u{200d}♀\u{fe0f}|🧖🏿\u{200d}♂\u{fe0f}|🧗🏻\u{200d}♀\u{fe0f}|🧗🏻\u{200d}♂\u{fe0f}|🧗🏼\u{200d}♀\u{fe0f}|🧗🏼\u{200d}♂\u{fe0f}|🧗🏽\u{200d}♀\u{fe0f}|🧗🏽\u{200d}♂\u{fe0f}|🧗🏾\u{200d}♀\u{fe0f}|🧗🏾\u{200d}♂\u{fe0f}|🧗🏿\u{200d}♀\u{fe0f}|🧗🏿\u{200d}♂\u{fe0f}|🧘🏻\u{200d}♀\u{fe0f}|🧘🏻\u{200d}♂\u{fe0f}|🧘🏼\u{200d}♀\u{fe0f}|🧘🏼\u{200d}♂\u{fe0f}|🧘🏽\u{200d}♀\u{fe0f}|🧘🏽\u{200d}♂\u{fe0f}|🧘🏾\u{200d}♀\u{fe0f}|🧘🏾\u{200d}♂\u{fe0f}|🧘🏿\u{200d}♀\u{fe0f}|🧘🏿\u{200d}♂\u{fe0f}|🧙🏻\u{200d}♀\u{fe0f}|🧙🏻\u{200d}♂\u{fe0f}|🧙🏼\u{200d}♀\u{fe0f}|🧙🏼\u{200d}♂\u{fe0f}|🧙🏽\u{200d}♀\u{fe0f}|🧙🏽\u{200d}♂\u{fe0f}|🧙🏾\u{200d}♀\u{fe0f}|🧙🏾\u{200d}♂\u{fe0f}|🧙🏿\u{200d}♀\u{fe0f}|🧙🏿\u{200d}♂\u{fe0f}|🧚🏻\u{200d}♀\u{fe0f}|🧚🏻\u{200d}♂\u{fe0f}|🧚🏼\u{200d}♀\u{fe0f}|🧚🏼\u{200d}♂\u{fe0f}|🧚🏽\u{200d}♀\u{fe0f}|🧚🏽\u{200d}♂\u{fe0f}|🧚🏾\u{200d}♀\u{fe0f}|🧚🏾\u{200d}♂\u{fe0f}|🧚🏿\u{200d}♀\u{fe0f}|🧚🏿\u{200d}♂\u{fe0f}|🧛🏻\u{200d}♀\u{fe0f}|🧛🏻\u{200d}♂\u{fe0f}|🧛🏼\u{200d}♀\u{fe0f}|🧛🏼\u{200d}♂\u{fe0f}|🧛🏽\u{200d}♀\u{fe0f}|🧛🏽\u{200d}♂\u{fe0f}|🧛🏾\u{200d}♀\u{fe0f}|🧛🏾\u{200d}♂\u{fe0f}|🧛🏿\u{200d}♀\u{fe0f}|🧛🏿\u{200d}♂\u{fe0f}|🧜🏻\u{200d}♀\u{fe0f}|🧜🏻\u{200d}♂\u{fe0f}|🧜🏼\u{200d}♀\u{fe0f}|🧜🏼\u{200d}♂\u{fe0f}|🧜🏽\u{200d}♀\u{fe0f}|🧜🏽\u{200d}♂\u{fe0f}|🧜🏾\u{200d}♀\u{fe0f}|🧜🏾\u{200d}♂\u{fe0f}|🧜🏿\u{200d}♀\u{fe0f}|🧜🏿\u{200d}♂\u{fe0f}|🧝🏻\u{200d}♀\u{fe0f}|🧝🏻\u{200d}♂\u{fe0f}|🧝🏼\u{200d}♀\u{fe0f}|🧝🏼\u{200d}♂\u{fe0f}|🧝🏽\u{200d}♀\u{fe0f}|🧝🏽\u{200d}♂\u{fe0f}|🧝🏾\u{200d}♀\u{fe0f}|🧝🏾\u{200d}♂\u{fe0f}|🧝🏿\u{200d}♀\u{fe0f}|🧝🏿\u{200d}♂\u{fe0f}|⛹🏻\u{200d}♀\u{fe0f}|⛹🏻\u{200d}♂\u{fe0f}|⛹🏼\u{200d}♀\u{fe0f}|⛹🏼\u{200d}♂\u{fe0f}|⛹🏽\u{200d}♀\u{fe0f}|⛹🏽\u{200d}♂\u{fe0f}|⛹🏾\u{200d}♀\u{fe0f}|⛹🏾\u{200d}♂\u{fe0f}|⛹🏿\u{200d}♀\u{fe0f}|⛹🏿\u{200d}♂\u{fe0f}|🏋\u{fe0f}\u{200d}♀\u{fe0f}|🏋\u{fe0f}\u{200d}♂\u{fe0f}|🏌\u{fe0f}\u{200d}♀\u{fe0f}|🏌\u{fe0f}\u{200d}♂\u{fe0f}|🏳\u{fe0f}\u{200d}⚧\u{fe0f}|🕵\u{fe0f}\u{200d}♀\u{fe0f}|🕵\u{fe0f}\u{200d}♂\u{fe0f}|⛹\u{fe0f}\u{200d}♀\u{fe0f}|⛹\u{fe0f}\u{200d}♂\u{fe0f}|👨🏻\u{200d}🌾|👨🏻\u{200d}🍳|👨🏻\u{200d}🍼|👨🏻\u{200d}🎓|👨🏻\u{200d}🎤|👨🏻\u{200d}🎨|👨🏻\u{200d}🏫|👨🏻\u{200d}🏭|👨🏻\u{200d}💻|👨🏻\u{200d}💼|👨🏻\u{200d}🔧|👨🏻\u{200d}🔬|👨🏻\u{200d}🚀|👨🏻\u{200d}🚒|👨🏻\u{200d}🦯|👨🏻\u{200d}🦰|👨🏻\u{200d}🦱|👨🏻\u{200d}🦲|👨🏻\u{200d}🦳|👨🏻\u{200d}🦼|👨🏻\u{200d}🦽|👨🏼\u{200d}🌾|👨🏼\u{200d}🍳|👨🏼\u{200d}🍼|👨🏼\u{200d}🎓|👨🏼\u{200d}🎤|👨🏼\u{200d}🎨|👨🏼\u{200d}🏫|👨🏼\u{200d}🏭|👨🏼\u{200d}💻|👨🏼\u{200d}💼|👨🏼\u{200d}🔧|👨🏼\u{200d}🔬|👨🏼\u{200d}🚀|👨🏼\u{200d}🚒|👨🏼\u{200d}🦯|👨🏼\u{200d}🦰|👨🏼\u{200d}🦱|👨🏼\u{200d}🦲|👨🏼\u{200d}🦳|👨🏼\u{200d}🦼|👨🏼\u{200d}🦽|👨🏽\u{200d}🌾|👨🏽\u{200d}🍳|👨🏽\u{200d}🍼|👨🏽\u{200d}🎓|👨🏽\u{200d}🎤|👨🏽\u{200d}🎨|👨🏽\u{200d}🏫|👨🏽\u{200d}🏭|👨🏽\u{200d}💻|👨🏽\u{200d}💼|👨🏽\u{200d}🔧|👨🏽\u{200d}🔬|👨🏽\u{200d}🚀|👨🏽\u{200d}🚒|👨🏽\u{200d}🦯|👨🏽\u{200d}🦰|👨🏽\u{200d}🦱|👨🏽\u{200d}🦲|👨🏽\u{200d}🦳|👨🏽\u{200d}🦼|👨🏽\u{200d}🦽|👨🏾\u{200d}🌾|👨🏾\u{200d}🍳|👨🏾\u{200d}🍼|👨🏾\u{200d}🎓|👨🏾\u{200d}🎤|👨🏾\u{200d}🎨|👨🏾\u{200d}🏫|👨🏾\u{200d}🏭|👨🏾\u{200d}💻|👨🏾\u{200d}💼|👨🏾\u{200d}🔧|👨🏾\u{200d}🔬|👨🏾\u{200d}🚀|👨🏾\u{200d}🚒|👨🏾\u{200d}🦯|👨🏾\u{200d}🦰|👨🏾\u{200d}🦱|👨🏾\u{200d}🦲|👨🏾\u{200d}🦳|👨🏾\u{200d}🦼|👨🏾\u{200d}🦽|👨🏿\u{200d}🌾|👨🏿\u{200d}🍳|👨🏿\u{200d}🍼|👨🏿\u{200d}🎓|👨🏿\u{200d}🎤|👨🏿\u{200d}🎨|👨🏿\u{200d}🏫|👨🏿\u{200d}🏭|👨🏿\u{200d}💻|👨🏿\u{200d}💼|👨🏿\u{200d}🔧|👨🏿\u{200d}🔬|👨🏿\u{200d}🚀|👨🏿\u{200d}🚒|👨🏿\u{200d}🦯|👨🏿\u{200d}🦰|👨🏿\u{200d}🦱|👨🏿\u{200d}🦲|👨🏿\u{200d}🦳|👨🏿\u{200d}🦼|👨🏿\u{200d}🦽|👩🏻\u{200d}🌾|👩🏻\u{200d}🍳|👩🏻\u{200d}🍼|👩🏻\u{200d}🎓|👩🏻\u{200d}🎤|👩🏻\u{200d}🎨|👩🏻\u{200d}🏫|👩🏻\u{200d}🏭|👩🏻\u{200d}💻|👩🏻\u{200d}💼|👩🏻\u{200d}🔧|👩🏻\u{200d}🔬|👩🏻\u{200d}🚀|👩🏻\u{200d}🚒|👩🏻\u{200d}🦯|👩🏻\u{200d}🦰|👩🏻\u{200d}🦱|👩🏻\u{200d}🦲|👩🏻\u{200d}🦳|👩🏻\u{200d}🦼|👩🏻\u{200d}🦽|👩🏼\u{200d}🌾|👩🏼\u{200d}🍳|👩🏼\u{200d}🍼|👩🏼\u{200d}🎓|👩🏼\u{200d}🎤|👩🏼\u{200d}🎨|👩🏼\u{200d}🏫|👩🏼\u{200d}🏭|👩🏼\u{200d}💻|👩🏼\u{200d}💼|👩🏼\u{200d}🔧|👩🏼\u{200d}🔬|👩🏼\u{200d}🚀|👩🏼\u{200d}🚒|👩🏼\u{200d}🦯|👩🏼\u{200d}🦰|👩🏼\u{200d}🦱|👩🏼\u{200d}🦲|👩🏼\u{200d}🦳|👩🏼\u{200d}🦼|👩🏼\u{200d}🦽|👩🏽\u{200d}🌾|👩🏽\u{200d}🍳|👩🏽\u{200d}🍼|👩🏽\u{200d}🎓|👩🏽\u{200d}🎤|👩🏽\u{200d}🎨|👩🏽\u{200d}🏫|👩🏽\u{200d}🏭|👩🏽\u{200d}💻|👩🏽\u{200d}💼|👩🏽\u{200d}🔧|👩🏽\u{200d}🔬|👩🏽\u{200d}🚀|👩🏽\u{200d}🚒|👩🏽\u{200d}🦯|👩🏽\u{200d}🦰|👩🏽\u{200d}🦱|👩🏽\u{200d}🦲|👩🏽\u{200d}🦳|👩🏽\u{200d}🦼|👩🏽\u{200d}🦽|👩🏾\u{200d}🌾|👩🏾\u{200d}🍳|👩🏾\u{200d}🍼|👩🏾\u{200d}🎓|👩🏾\u{200d}🎤|👩🏾\u{200d}🎨|👩🏾\u{200d}🏫|👩🏾\u{200d}🏭|👩🏾\u{200d}💻|👩🏾\u{200d}💼|👩🏾\u{200d}🔧|👩🏾\u{200d}🔬|👩🏾\u{200d}🚀|👩🏾\u{200d}🚒|👩🏾\u{200d}🦯|👩🏾\u{200d}🦰|👩🏾\u{200d}🦱|👩🏾\u{200d}🦲|👩🏾\u{200d}🦳|👩🏾\u{200d}🦼|👩🏾\u{200d}🦽|👩🏿\u{200d}🌾|👩🏿\u{200d}🍳|👩🏿\u{200d}🍼|👩🏿\u{200d}🎓|👩🏿\u{200d}🎤|👩🏿\u{200d}🎨|👩🏿\u{200d}🏫|👩🏿\u{200d}🏭|👩🏿\u{200d}💻|👩🏿\u{200d}💼|👩🏿\u{200d}🔧|👩🏿\u{200d}🔬|👩🏿\u{200d}🚀|👩🏿\u{200d}🚒|👩🏿\u{200d}🦯|👩🏿\u{200d}🦰|👩🏿\u{200d}🦱|👩🏿\u{200d}🦲|👩🏿\u{200d}🦳|👩🏿\u{200d}🦼|👩🏿\u{200d}🦽|🧑🏻\u{200d}🌾|🧑🏻\u{200d}🍳|🧑🏻\u{200d}🍼|🧑🏻\u{200d}🎄|🧑🏻\u{200d}🎓|🧑🏻\u{200d}🎤|🧑🏻\u{200d}🎨|🧑🏻\u{200d}🏫|🧑🏻\u{200d}🏭|🧑🏻\u{200d}💻|🧑🏻\u{200d}💼|🧑🏻\u{200d}🔧|🧑🏻\u{200d}🔬|🧑🏻\u{200d}🚀|🧑🏻\u{200d}🚒|🧑🏻\u{200d}🦯|🧑🏻\u{200d}🦰|🧑🏻\u{200d}🦱|🧑🏻\u{200d}🦲|🧑🏻\u{200d}🦳|🧑🏻\u{200d}🦼|🧑🏻\u{200d}🦽|🧑🏼\u{200d}🌾|🧑🏼\u{200d}🍳|🧑🏼\u{200d}🍼|🧑🏼\u{200d}🎄|🧑🏼\u{200d}🎓|🧑🏼\u{200d}🎤|🧑🏼\u{200d}🎨|🧑🏼\u{200d}🏫|🧑🏼\u{200d}🏭|🧑🏼\u{200d}💻|🧑🏼\u{200d}💼|🧑🏼\u{200d}🔧|🧑🏼\u{200d}🔬|🧑🏼\u{200d}🚀|🧑🏼\u{200d}🚒|🧑🏼\u{200d}🦯|🧑🏼\u{200d}🦰|🧑🏼\u{200d}🦱|🧑🏼\u{200d}🦲|🧑🏼\u{200d}🦳|🧑🏼\u{200d}🦼|🧑🏼\u{200d}🦽|🧑🏽\u{200d}🌾|🧑🏽\u{200d}🍳|🧑🏽\u{200d}🍼|🧑🏽\u{200d}🎄|🧑🏽\u{200d}🎓|🧑🏽\u{200d}🎤|🧑🏽\u{200d}🎨|🧑🏽\u{200d}🏫|🧑🏽\u{200d}🏭|🧑🏽\u{200d}💻|🧑🏽\u{200d}💼|🧑🏽\u{200d}🔧|🧑🏽\u{200d}🔬|🧑🏽\u{200d}🚀|🧑🏽\u{200d}🚒|🧑🏽\u{200d}🦯|🧑🏽\u{200d}🦰|🧑🏽\u{200d}🦱|🧑🏽\u{200d}🦲|🧑🏽\u{200d}🦳|🧑🏽\u{200d}🦼|🧑🏽\u{200d}🦽|🧑🏾\u{200d}🌾|🧑🏾\u{200d}🍳|🧑🏾\u{200d}🍼|🧑🏾\u{200d}🎄|🧑🏾\u{200d}🎓|🧑🏾\u{200d}🎤|🧑🏾\u{200d}🎨|🧑🏾\u{200d}🏫|🧑🏾\u{200d}🏭|🧑🏾\u{200d}💻|🧑🏾\u{200d}💼|🧑🏾\u{200d}🔧|🧑🏾\u{200d}🔬|🧑🏾\u{200d}🚀|🧑🏾\u{200d}🚒|🧑🏾\u{200d}🦯|🧑🏾\u{200d}🦰|🧑🏾\u{200d}🦱|🧑🏾\u{200d}🦲|🧑🏾\u{200d}🦳|🧑🏾\u{200d}🦼|🧑🏾\u{200d}🦽|🧑🏿\u{200d}🌾|🧑🏿\u{200d}🍳|🧑🏿\u{200d}🍼|🧑🏿\u{200d}🎄|🧑🏿\u{200d}🎓|🧑🏿\u{200d}🎤|🧑🏿\u{200d}🎨|🧑🏿\u{200d}🏫|🧑🏿\u{200d}🏭|🧑🏿\u{200d}💻|🧑🏿\u{200d}💼|🧑🏿\u{200d}🔧|🧑🏿\u{200d}🔬|🧑🏿\u{200d}🚀|🧑🏿\u{200d}🚒|🧑🏿\u{200d}🦯|🧑🏿\u{200d}🦰|🧑🏿\u{200d}🦱|🧑🏿\u{200d}🦲|🧑🏿\u{200d}🦳|🧑🏿\u{200d}🦼|🧑🏿\u{200d}🦽|🏳\u{fe0f}\u{200d}🌈|😶\u{200d}🌫\u{fe0f}|❤\u{fe0f}\u{200d}🔥|❤\u{fe0f}\u{200d}🩹|🏃\u{200d}♀\u{fe0f}|🏃\u{200d}♂\u{fe0f}|🏄\u{200d}♀\u{fe0f}|🏄\u{200d}♂\u{fe0f}|🏊\u{200d}♀\u{fe0f}|🏊\u{200d}♂\u{fe0f}|🏴\u{200d}☠\u{fe0f}|🐻\u{200d}❄\u{fe0f}|👨\u{200d}⚕\u{fe0f}|👨\u{200d}⚖\u{fe0f}|👨\u{200d}✈\u{fe0f}|👩\u{200d}⚕\u{fe0f}|👩\u{200d}⚖\u{fe0f}|👩\u{200d}✈\u{fe0f}|👮\u{200d}♀\u{fe0f}|👮\u{200d}♂\u{fe0f}|👯\u{200d}♀\u{fe0f}|👯\u{200d}♂\u{fe0f}|👰\u{200d}♀\u{fe0f}|👰\u{200d}♂\u{fe0f}|👱\u{200d}♀\u{fe0f}|👱\u{200d}♂\u{fe0f}|👳\u{200d}♀\u{fe0f}|👳\u{200d}♂\u{fe0f}|👷\u{200d}♀\u{fe0f}|👷\u{200d}♂\u{fe0f}|💁\u{200d}♀\u{fe0f}|💁\u{200d}♂\u{fe0f}|💂\u{200d}♀\u{fe0f}|💂\u{200d}♂\u{fe0f}|💆\u{200d}♀\u{fe0f}|💆\u{200d}♂\u{fe0f}|💇\u{200d}♀\u{fe0f}|💇\u{200d}♂\u{fe0f}|🙅\u{200d}♀\u{fe0f}|🙅\u{200d}♂\u{fe0f}|🙆\u{200d}♀\u{fe0f}|🙆\u{200d}♂\u{fe0f}|🙇\u{200d}♀\u{fe0f}|🙇\u{200d}♂\u{fe0f}|🙋\u{200d}♀\u{fe0f}|🙋\u{200d}♂\u{fe0f}|🙍\u{200d}♀\u{fe0f}|🙍\u{200d}♂\u{fe0f}|🙎\u{200d}♀\u{fe0f}|🙎\u{200d}♂\u{fe0f}|🚣\u{200d}♀\u{fe0f}|🚣\u{200d}♂\u{fe0f}|🚴\u{200d}♀\u{fe0f}|🚴\u{200d}♂\u{fe0f}|🚵\u{200d}♀\u{fe0f}|🚵\u{200d}♂\u{fe0f}|🚶\u{200d}♀\u{fe0f}|🚶\u{200d}♂\u{fe0f}|🤦\u{200d}♀\u{fe0f}|🤦\u{200d}♂\u{fe0f}|🤵\u{200d}♀\u{fe0f}|🤵\u{200d}♂\u{fe0f}|🤷\u{200d}♀\u{fe0f}|🤷\u{200d}♂\u{fe0f}|🤸\u{200d}♀\u{fe0f}|🤸\u{200d}♂\u{fe0f}|🤹\u{200d}♀\u{fe0f}|🤹\u{200d}♂\u{fe0f}|🤼\u{200d}♀\u{fe0f}|🤼\u{200d}♂\u{fe0f}|🤽\u{200d}♀\u{fe0f}|🤽\u{200d}♂\u{fe0f}|🤾\u{200d}♀\u{fe0f}|🤾\u{200d}♂\u{fe0f}|🦸\u{200d}♀\u{fe0f}|🦸\u{200d}♂\u{fe0f}|🦹\u{200d}♀\u{fe0f}|🦹\u{200d}♂\u{fe0f}|🧍\u{200d}♀\u{fe0f}|🧍\u{200d}♂\u{fe0f}|🧎\u{200d}♀\u{fe0f}|🧎\u{200d}♂\u{fe0f}|🧏\u{200d}♀\u{fe0f}|🧏\u{200d}♂\u{fe0f}|🧑\u{200d}⚕\u{fe0f}|🧑\u{200d}⚖\u{fe0f}|🧑\u{200d}✈\u{fe0f}|🧔\u{200d}♀\u{fe0f}|🧔\u{200d}♂\u{fe0f}|🧖\u{200d}♀\u{fe0f}|🧖\u{200d}♂\u{fe0f}|🧗\u{200d}♀\u{fe0f}|🧗\u{200d}♂\u{fe0f}|🧘\u{200d}♀\u{fe0f}|🧘\u{200d}♂\u{fe0f}|🧙\u{200d}♀\u{fe0f}|🧙\u{200d}♂\u{fe0f}|🧚\u{200d}♀\u{fe0f}|🧚\u{200d}♂\u{fe0f}|🧛\u{200d}♀\u{fe0f}|🧛\u{200d}♂\u{fe0f}|🧜\u{200d}♀\u{fe0f}|🧜\u{200d}♂\u{fe0f}|🧝\u{200d}♀\u{fe0f}|🧝\u{200d}♂\u{fe0f}|🧞\u{200d}♀\u{fe0f}|🧞\u{200d}♂\u{fe0f}|🧟\u{200d}♀\u{fe0f}|🧟\u{200d}♂\u{fe0f}|🐕\u{200d}🦺|👨\u{200d}🌾|👨\u{200d}🍳|👨\u{200d}🍼|👨\u{200d}🎓|👨\u{200d}🎤|👨\u{200d}🎨|👨\u{200d}🏫|👨\u{200d}🏭|👨\u{200d}👦|👨\u{200d}👧|👨\u{200d}💻|👨\u{200d}💼|👨\u{200d}🔧|👨\u{200d}🔬|👨\u{200d}🚀|👨\u{200d}🚒|👨\u{200d}🦯|👨\u{200d}🦰|👨\u{200d}🦱|👨\u{200d}🦲|👨\u{200d}🦳|👨\u{200d}🦼|👨\u{200d}🦽|👩\u{200d}🌾|👩\u{200d}🍳|👩\u{200d}🍼|👩\u{200d}🎓|👩\u{200d}🎤|👩\u{200d}🎨|👩\u{200d}🏫|👩\u{200d}🏭|👩\u{200d}👦|👩\u{200d}👧|👩\u{200d}💻|👩\u{200d}💼|👩\u{200d}🔧|👩\u{200d}🔬|👩\u{200d}🚀|👩\u{200d}🚒|👩\u{200d}🦯|👩\u{200d}🦰|👩\u{200d}🦱|👩\u{200d}🦲|👩\u{200d}🦳|👩\u{200d}🦼|👩\u{200d}🦽|😮\u{200d}💨|😵\u{200d}💫|🧑\u{200d}🌾|🧑\u{200d}🍳|🧑\u{200d}🍼|🧑\u{200d}🎄|🧑\u{200d}🎓|🧑\u{200d}🎤|🧑\u{200d}🎨|🧑\u{200d}🏫|🧑\u{200d}🏭|🧑\u{200d}💻|🧑\u{200d}💼|🧑\u{200d}🔧|🧑\u{200d}🔬|🧑\u{200d}🚀|🧑\u{200d}🚒|🧑\u{200d}🦯|🧑\u{200d}🦰|🧑\u{200d}🦱|🧑\u{200d}🦲|🧑\u{200d}🦳|🧑\u{200d}🦼|🧑\u{200d}🦽|🐈\u{200d}⬛|🇦🇨|🇦🇩|🇦🇪|🇦🇫|🇦🇬|🇦🇮|🇦🇱|🇦🇲|🇦🇴|🇦🇶|🇦🇷|🇦🇸|🇦🇹|🇦🇺|🇦🇼|🇦🇽|🇦🇿|🇧🇦|🇧🇧|🇧🇩|🇧🇪|🇧🇫|🇧🇬|🇧🇭|🇧🇮|🇧🇯|🇧🇱|🇧🇲|🇧🇳|🇧🇴|🇧🇶|🇧🇷|🇧🇸|🇧🇹|🇧🇻|🇧🇼|🇧🇾|🇧🇿|🇨🇦|🇨🇨|🇨🇩|🇨🇫|🇨🇬|🇨🇭|🇨🇮|🇨🇰|🇨🇱|🇨🇲|🇨🇳|🇨🇴|🇨🇵|🇨🇷|🇨🇺|🇨🇻|🇨🇼|🇨🇽|🇨🇾|🇨🇿|🇩🇪|🇩🇬|🇩🇯|🇩🇰|🇩🇲|🇩🇴|🇩🇿|🇪🇦|🇪🇨|🇪🇪|🇪🇬|🇪🇭|🇪🇷|🇪🇸|🇪🇹|🇪🇺|🇫🇮|🇫🇯|🇫🇰|🇫🇲|🇫🇴|🇫🇷|🇬🇦|🇬🇧|🇬🇩|🇬🇪|🇬🇫|🇬🇬|🇬🇭|🇬🇮|🇬🇱|🇬🇲|🇬🇳|🇬🇵|🇬🇶|🇬🇷|🇬🇸|🇬🇹|🇬🇺|🇬🇼|🇬🇾|🇭🇰|🇭🇲|🇭🇳|🇭🇷|🇭🇹|🇭🇺|🇮🇨|🇮🇩|🇮🇪|🇮🇱|🇮🇲|🇮🇳|🇮🇴|🇮🇶|🇮🇷|🇮🇸|🇮🇹|🇯🇪|🇯🇲|🇯🇴|🇯🇵|🇰🇪|🇰🇬|🇰🇭|🇰🇮|🇰🇲|🇰🇳|🇰🇵|🇰🇷|🇰🇼|🇰🇾|🇰🇿|🇱🇦|🇱🇧|🇱🇨|🇱🇮|🇱🇰|🇱🇷|🇱🇸|🇱🇹|🇱🇺|🇱🇻|🇱🇾|🇲🇦|🇲🇨|🇲🇩|🇲🇪|🇲🇫|🇲🇬|🇲🇭|🇲🇰|🇲🇱|🇲🇲|🇲🇳|🇲🇴|🇲🇵|🇲🇶|🇲🇷|🇲🇸|🇲🇹|🇲🇺|🇲🇻|🇲🇼|🇲🇽|🇲🇾|🇲🇿|🇳🇦|🇳🇨|🇳🇪|🇳🇫|🇳🇬|🇳🇮|🇳🇱|🇳🇴|🇳🇵|🇳🇷|🇳🇺|🇳🇿|🇴🇲|🇵🇦|🇵🇪|🇵🇫|🇵🇬|🇵🇭|🇵🇰|🇵🇱|🇵🇲|🇵🇳|🇵🇷|🇵🇸|🇵🇹|🇵🇼|🇵🇾|🇶🇦|🇷🇪|🇷🇴|🇷🇸|🇷🇺|🇷🇼|🇸🇦|🇸🇧|🇸🇨|🇸🇩|🇸🇪|🇸🇬|🇸🇭|🇸🇮|🇸🇯|🇸🇰|🇸🇱|🇸🇲|🇸🇳|🇸🇴|🇸🇷|🇸🇸|🇸🇹|🇸🇻|🇸🇽|🇸🇾|🇸🇿|🇹🇦|🇹🇨|🇹🇩|🇹🇫|🇹🇬|🇹🇭|🇹🇯|🇹🇰|🇹🇱|🇹🇲|🇹🇳|🇹🇴|🇹🇷|🇹🇹|🇹🇻|🇹🇼|🇹🇿|🇺🇦|🇺🇬|🇺🇲|🇺🇳|🇺🇸|🇺🇾|🇺🇿|🇻🇦|🇻🇨|🇻🇪|🇻🇬|🇻🇮|🇻🇳|🇻🇺|🇼🇫|🇼🇸|🇽🇰|🇾🇪|🇾🇹|🇿🇦|🇿🇲|🇿🇼|🎅🏻|🎅🏼|🎅🏽|🎅🏾|🎅🏿|🏂🏻|🏂🏼|🏂🏽|🏂🏾|🏂🏿|🏃🏻|🏃🏼|🏃🏽|🏃🏾|🏃🏿|🏄🏻|🏄🏼|🏄🏽|🏄🏾|🏄🏿|🏇🏻|🏇🏼|🏇🏽|🏇🏾|🏇🏿|🏊🏻|🏊🏼|🏊🏽|🏊🏾|🏊🏿|🏋🏻|🏋🏼|🏋🏽|🏋🏾|🏋🏿|🏌🏻|🏌🏼|🏌🏽|🏌🏾|🏌🏿|👂🏻|👂🏼|👂🏽|👂🏾|👂🏿|👃🏻|👃🏼|👃🏽|👃🏾|👃🏿|👆🏻|👆🏼|👆🏽|👆🏾|👆🏿|👇🏻|👇🏼|👇🏽|👇🏾|👇🏿|👈🏻|👈🏼|👈🏽|👈🏾|👈🏿|👉🏻|👉🏼|👉🏽|👉🏾|👉🏿|👊🏻|👊🏼|👊🏽|👊🏾|👊🏿|👋🏻|👋🏼|👋🏽|👋🏾|👋🏿|👌🏻|👌🏼|👌🏽|👌🏾|👌🏿|👍🏻|👍🏼|👍🏽|👍🏾|👍🏿|👎🏻|👎🏼|👎🏽|👎🏾|👎🏿|👏🏻|👏🏼|👏🏽|👏🏾|👏🏿|👐🏻|👐🏼|👐🏽|👐🏾|👐🏿|👦🏻|👦🏼|👦🏽|👦🏾|👦🏿|👧🏻|👧🏼|👧🏽|👧🏾|👧🏿|👨🏻|👨🏼|👨🏽|👨🏾|👨🏿|👩🏻|👩🏼|👩🏽|👩🏾|👩🏿|👫🏻|👫🏼|👫🏽|👫🏾|👫🏿|👬🏻|👬🏼|👬🏽|👬🏾|👬🏿|👭🏻|👭🏼|👭🏽|👭🏾|👭🏿|👮🏻|👮🏼|👮🏽|👮🏾|👮🏿|👰🏻|👰🏼|👰🏽|👰🏾|👰🏿|👱🏻|👱🏼|👱🏽|👱🏾|👱🏿|👲🏻|👲🏼|👲🏽|👲🏾|👲🏿|👳🏻|👳🏼|👳🏽|👳🏾|👳🏿|👴🏻|👴🏼|👴🏽|👴🏾|👴🏿|👵🏻|👵🏼|👵🏽|👵🏾|👵🏿|👶🏻|👶🏼|👶🏽|👶🏾|👶🏿|👷🏻|👷🏼|👷🏽|👷🏾|👷🏿|👸🏻|👸🏼|👸🏽|👸🏾|👸🏿|👼🏻|👼🏼|👼🏽|👼🏾|👼🏿|💁🏻|💁🏼|💁🏽|💁🏾|💁🏿|💂🏻|💂🏼|💂🏽|💂🏾|💂🏿|💃🏻|💃🏼|💃🏽|💃🏾|💃🏿|💅🏻|💅🏼|💅🏽|💅🏾|💅🏿|💆🏻|💆🏼|💆🏽|💆🏾|💆🏿|💇🏻|💇🏼|💇🏽|💇🏾|💇🏿|💏🏻|💏🏼|💏🏽|💏🏾|💏🏿|💑🏻|💑🏼|💑🏽|💑🏾|💑🏿|💪🏻|💪🏼|💪🏽|💪🏾|💪🏿|🕴🏻|🕴🏼|🕴🏽|🕴🏾|🕴🏿|🕵🏻|🕵🏼|🕵🏽|🕵🏾|🕵🏿|🕺🏻|🕺🏼|🕺🏽|🕺🏾|🕺🏿|🖐🏻|🖐🏼|🖐🏽|🖐🏾|🖐🏿|🖕🏻|🖕🏼|🖕🏽|🖕🏾|🖕🏿|🖖🏻|🖖🏼|🖖🏽|🖖🏾|🖖🏿|🙅🏻|🙅🏼|🙅🏽|🙅🏾|🙅🏿|🙆🏻|🙆🏼|🙆🏽|🙆🏾|🙆🏿|🙇🏻|🙇🏼|🙇🏽|🙇🏾|🙇🏿|🙋🏻|🙋🏼|🙋🏽|🙋🏾|🙋🏿|🙌🏻|🙌🏼|🙌🏽|🙌🏾|🙌🏿|🙍🏻|🙍🏼|🙍🏽|🙍🏾|🙍🏿|🙎🏻|🙎🏼|🙎🏽|🙎🏾|🙎🏿|🙏🏻|🙏🏼|🙏🏽|🙏🏾|🙏🏿|🚣🏻|🚣🏼|🚣🏽|🚣🏾|🚣🏿|🚴🏻|🚴🏼|🚴🏽|🚴🏾|🚴🏿|🚵🏻|🚵🏼|🚵🏽|🚵🏾|🚵🏿|🚶🏻|🚶🏼|🚶🏽|🚶🏾|🚶🏿|🛀🏻|🛀🏼|🛀🏽|🛀🏾|🛀🏿|🛌🏻|🛌🏼|🛌🏽|🛌🏾|🛌🏿|🤌🏻|🤌🏼|🤌🏽|🤌🏾|🤌🏿|🤏🏻|🤏🏼|🤏🏽|🤏🏾|🤏🏿|🤘🏻|🤘🏼|🤘🏽|🤘🏾|🤘🏿|🤙🏻|🤙🏼|🤙🏽|🤙🏾|🤙🏿|🤚🏻|🤚🏼|🤚🏽|🤚🏾|🤚🏿|🤛🏻|🤛🏼|🤛🏽|🤛🏾|🤛🏿|🤜🏻|🤜🏼|🤜🏽|🤜🏾|🤜🏿|🤝🏻|🤝🏼|🤝🏽|🤝🏾|🤝🏿|🤞🏻|🤞🏼|🤞🏽|🤞🏾|🤞🏿|🤟🏻|🤟🏼|🤟🏽|🤟🏾|🤟🏿|🤦🏻|🤦🏼|🤦🏽|🤦🏾|🤦🏿|🤰🏻|🤰🏼|🤰🏽|🤰🏾|🤰🏿|🤱🏻|🤱🏼|🤱🏽|🤱🏾|🤱🏿|🤲🏻|🤲🏼|🤲🏽|🤲🏾|🤲🏿|🤳🏻|🤳🏼|🤳🏽|🤳🏾|🤳🏿|🤴🏻|🤴🏼|🤴🏽|🤴🏾|🤴🏿|🤵🏻|🤵🏼|🤵🏽|🤵🏾|🤵🏿|🤶🏻|🤶🏼|🤶🏽|🤶🏾|🤶🏿|🤷🏻|🤷🏼|🤷🏽|🤷🏾|🤷🏿|🤸🏻|🤸🏼|🤸🏽|🤸🏾|🤸🏿|🤹🏻|🤹🏼|🤹🏽|🤹🏾|🤹🏿|🤽🏻|🤽🏼|🤽🏽|🤽🏾|🤽🏿|🤾🏻|🤾🏼|🤾🏽|🤾🏾|🤾🏿|🥷🏻|🥷🏼|🥷🏽|🥷🏾|🥷🏿|🦵🏻|🦵🏼|🦵🏽|🦵🏾|🦵🏿|🦶🏻|🦶🏼|🦶🏽|🦶🏾|🦶🏿|🦸🏻|🦸🏼|🦸🏽|🦸🏾|🦸🏿|🦹🏻|🦹🏼|🦹🏽|🦹🏾|🦹🏿|🦻🏻|🦻🏼|🦻🏽|🦻🏾|🦻🏿|🧍🏻|🧍🏼|🧍🏽|🧍🏾|🧍🏿|🧎🏻|🧎🏼|🧎🏽|🧎🏾|🧎🏿|🧏🏻|🧏🏼|🧏🏽|🧏🏾|🧏🏿|🧑🏻|🧑🏼|🧑🏽|🧑🏾|🧑🏿|🧒🏻|🧒🏼|🧒🏽|🧒🏾|🧒🏿|🧓🏻|🧓🏼|🧓🏽|🧓🏾|🧓🏿|🧔🏻|🧔🏼|🧔🏽|🧔🏾|🧔🏿|🧕🏻|🧕🏼|🧕🏽|🧕🏾|🧕🏿|🧖🏻|🧖🏼|🧖🏽|🧖🏾|🧖🏿|🧗🏻|🧗🏼|🧗🏽|🧗🏾|🧗🏿|🧘🏻|🧘🏼|🧘🏽|🧘🏾|🧘🏿|🧙🏻|🧙🏼|🧙🏽|🧙🏾|🧙🏿|🧚🏻|🧚🏼|🧚🏽|🧚🏾|🧚🏿|🧛🏻|🧛🏼|🧛🏽|🧛🏾|🧛🏿|🧜🏻|🧜🏼|🧜🏽|🧜🏾|🧜🏿|🧝🏻|🧝🏼|🧝🏽|🧝🏾|🧝🏿|🫃🏻|🫃🏼|🫃🏽|🫃🏾|🫃🏿|🫄🏻|🫄🏼|🫄🏽|🫄🏾|🫄🏿|🫅🏻|🫅🏼|🫅🏽|🫅🏾|🫅🏿|🫰🏻|🫰🏼|🫰🏽|🫰🏾|🫰🏿|🫱🏻|🫱🏼|🫱🏽|🫱🏾|🫱🏿|🫲🏻|🫲🏼|🫲🏽|🫲🏾|🫲🏿|🫳🏻|🫳🏼|🫳🏽|🫳🏾|🫳🏿|🫴🏻|🫴🏼|🫴🏽|🫴🏾|🫴🏿|🫵🏻|🫵🏼|🫵🏽|🫵🏾|🫵🏿|🫶🏻|🫶🏼|🫶🏽|🫶🏾|🫶🏿|\\#\u{fe0f}\u{20e3}|\\*\u{fe0f}\u{20e3}|0\u{fe0f}\u{20e3}|1\u{fe0f}\u{20e3}|2\u{fe0f}\u{20e3}|3\u{fe0f}\u{20e3}|4\u{fe0f}\u{20e3}|5\u{fe0f}\u{20e3}|6\u{fe0f}\u{20e3}|7\u{fe0f}\u{20e3}|8\u{fe0f}\u{20e3}|9\u{fe0f}\u{20e3}|☝🏻|☝🏼|☝🏽|☝🏾|☝🏿|⛹🏻|⛹🏼|⛹🏽|⛹🏾|⛹🏿|✊🏻|✊🏼|✊🏽|✊🏾|✊🏿|✋🏻|✋🏼|✋🏽|✋🏾|✋🏿|✌🏻|✌🏼|✌🏽|✌🏾|✌🏿|✍🏻|✍🏼|✍🏽|✍🏾|✍🏿|🅰\u{fe0f}|🅱\u{fe0f}|🅾\u{fe0f}|🅿\u{fe0f}|🈂\u{fe0f}|🈷\u{fe0f}|🌡\u{fe0f}|🌤\u{fe0f}|🌥\u{fe0f}|🌦\u{fe0f}|🌧\u{fe0f}|🌨\u{fe0f}|🌩\u{fe0f}|🌪\u{fe0f}|🌫\u{fe0f}|🌬\u{fe0f}|🌶\u{fe0f}|🍽\u{fe0f}|🎖\u{fe0f}|🎗\u{fe0f}|🎙\u{fe0f}|🎚\u{fe0f}|🎛\u{fe0f}|🎞\u{fe0f}|🎟\u{fe0f}|🏋\u{fe0f}|🏌\u{fe0f}|🏍\u{fe0f}|🏎\u{fe0f}|🏔\u{fe0f}|🏕\u{fe0f}|🏖\u{fe0f}|🏗\u{fe0f}|🏘\u{fe0f}|🏙\u{fe0f}|🏚\u{fe0f}|🏛\u{fe0f}|🏜\u{fe0f}|🏝\u{fe0f}|🏞\u{fe0f}|🏟\u{fe0f}|🏳\u{fe0f}|🏵\u{fe0f}|🏷\u{fe0f}|🐿\u{fe0f}|👁\u{fe0f}|📽\u{fe0f}|🕉\u{fe0f}|🕊\u{fe0f}|🕯\u{fe0f}|🕰\u{fe0f}|🕳\u{fe0f}|🕴\u{fe0f}|🕵\u{fe0f}|🕶\u{fe0f}|🕷\u{fe0f}|🕸\u{fe0f}|🕹\u{fe0f}|🖇\u{fe0f}|🖊\u{fe0f}|🖋\u{fe0f}|🖌\u{fe0f}|🖍\u{fe0f}|🖐\u{fe0f}|🖥\u{fe0f}|🖨\u{fe0f}|🖱\u{fe0f}|🖲\u{fe0f}|🖼\u{fe0f}|🗂\u{fe0f}|🗃\u{fe0f}|🗄\u{fe0f}|🗑\u{fe0f}|🗒\u{fe0f}|🗓\u{fe0f}|🗜\u{fe0f}|🗝\u{fe0f}|🗞\u{fe0f}|🗡\u{fe0f}|🗣\u{fe0f}|🗨\u{fe0f}|🗯\u{fe0f}|🗳\u{fe0f}|🗺\u{fe0f}|🛋\u{fe0f}|🛍\u{fe0f}|🛎\u{fe0f}|🛏\u{fe0f}|🛠\u{fe0f}|🛡\u{fe0f}|🛢\u{fe0f}|🛣\u{fe0f}|🛤\u{fe0f}|🛥\u{fe0f}|🛩\u{fe0f}|🛰\u{fe0f}|🛳\u{fe0f}|‼\u{fe0f}|⁉\u{fe0f}|™\u{fe0f}|ℹ\u{fe0f}|↔\u{fe0f}|↕\u{fe0f}|↖\u{fe0f}|↗\u{fe0f}|↘\u{fe0f}|↙\u{fe0f}|↩\u{fe0f}|↪\u{fe0f}|⌨\u{fe0f}|⏏\u{fe0f}|⏭\u{fe0f}|⏮\u{fe0f}|⏯\u{fe0f}|⏱\u{fe0f}|⏲\u{fe0f}|⏸\u{fe0f}|⏹\u{fe0f}|⏺\u{fe0f}|Ⓜ\u{fe0f}|▪\u{fe0f}|▫\u{fe0f}|▶\u{fe0f}|◀\u{fe0f}|◻\u{fe0f}|◼\u{fe0f}|☀\u{fe0f}|☁\u{fe0f}|☂\u{fe0f}|☃\u{fe0f}|☄\u{fe0f}|☎\u{fe0f}|☑\u{fe0f}|☘\u{fe0f}|☝\u{fe0f}|☠\u{fe0f}|☢\u{fe0f}|☣\u{fe0f}|☦\u{fe0f}|☪\u{fe0f}|☮\u{fe0f}|☯\u{fe0f}|☸\u{fe0f}|☹\u{fe0f}|☺\u{fe0f}|♀\u{fe0f}|♂\u{fe0f}|♟\u{fe0f}|♠\u{fe0f}|♣\u{fe0f}|♥\u{fe0f}|♦\u{fe0f}|♨\u{fe0f}|♻\u{fe0f}|♾\u{fe0f}|⚒\u{fe0f}|⚔\u{fe0f}|⚕\u{fe0f}|⚖\u{fe0f}|⚗\u{fe0f}|⚙\u{fe0f}|⚛\u{fe0f}|⚜\u{fe0f}|⚠\u{fe0f}|⚧\u{fe0f}|⚰\u{fe0f}|⚱\u{fe0f}|⛈\u{fe0f}|⛏\u{fe0f}|⛑\u{fe0f}|⛓\u{fe0f}|⛩\u{fe0f}|⛰\u{fe0f}|⛱\u{fe0f}|⛴\u{fe0f}|⛷\u{fe0f}|⛸\u{fe0f}|⛹\u{fe0f}|✂\u{fe0f}|✈\u{fe0f}|✉\u{fe0f}|✌\u{fe0f}|✍\u{fe0f}|✏\u{fe0f}|✒\u{fe0f}|✔\u{fe0f}|✖\u{fe0f}|✝\u{fe0f}|✡\u{fe0f}|✳\u{fe0f}|✴\u{fe0f}|❄\u{fe0f}|❇\u{fe0f}|❣\u{fe0f}|❤\u{fe0f}|➡\u{fe0f}|⤴\u{fe0f}|⤵\u{fe0f}|⬅\u{fe0f}|⬆\u{fe0f}|⬇\u{fe0f}|〰\u{fe0f}|〽\u{fe0f}|㊗\u{fe0f}|㊙\u{fe0f}|©\u{fe0f}|®\u{fe0f}|🀄|🃏|🆎|🆑|🆒|🆓|🆔|🆕|🆖|🆗|🆘|🆙|🆚|🈁|🈚|🈯|🈲|🈳|🈴|🈵|🈶|🈸|🈹|🈺|🉐|🉑|🌀|🌁|🌂|🌃|🌄|🌅|🌆|🌇|🌈|🌉|🌊|🌋|🌌|🌍|🌎|🌏|🌐|🌑|🌒|🌓|🌔|🌕|🌖|🌗|🌘|🌙|🌚|🌛|🌜|🌝|🌞|🌟|🌠|🌭|🌮|🌯|🌰|🌱|🌲|🌳|🌴|🌵|🌷|🌸|🌹|🌺|🌻|🌼|🌽|🌾|🌿|🍀|🍁|🍂|🍃|🍄|🍅|🍆|🍇|🍈|🍉|🍊|🍋|🍌|🍍|🍎|🍏|🍐|🍑|🍒|🍓|🍔|🍕|🍖|🍗|🍘|🍙|🍚|🍛|🍜|🍝|🍞|🍟|🍠|🍡|🍢|🍣|🍤|🍥|🍦|🍧|🍨|🍩|🍪|🍫|🍬|🍭|🍮|🍯|🍰|🍱|🍲|🍳|🍴|🍵|🍶|🍷|🍸|🍹|🍺|🍻|🍼|🍾|🍿|🎀|🎁|🎂|🎃|🎄|🎅|🎆|🎇|🎈|🎉|🎊|🎋|🎌|🎍|🎎|🎏|🎐|🎑|🎒|🎓|🎠|🎡|🎢|🎣|🎤|🎥|🎦|🎧|🎨|🎩|🎪|🎫|🎬|🎭|🎮|🎯|🎰|🎱|🎲|🎳|🎴|🎵|🎶|🎷|🎸|🎹|🎺|🎻|🎼|🎽|🎾|🎿|🏀|🏁|🏂|🏃|🏄|🏅|🏆|🏇|🏈|🏉|🏊|🏏|🏐|🏑|🏒|🏓|🏠|🏡|🏢|🏣|🏤|🏥|🏦|🏧|🏨|🏩|🏪|🏫|🏬|🏭|🏮|🏯|🏰|🏴|🏸|🏹|🏺|🏻|🏼|🏽|🏾|🏿|🐀|🐁|🐂|🐃|🐄|🐅|🐆|🐇|🐈|🐉|🐊|🐋|🐌|🐍|🐎|🐏|🐐|🐑|🐒|🐓|🐔|🐕|🐖|🐗|🐘|🐙|🐚|🐛|🐜|🐝|🐞|🐟|🐠|🐡|🐢|🐣|🐤|🐥|🐦|🐧|🐨|🐩|🐪|🐫|🐬|🐭|🐮|🐯|🐰|🐱|🐲|🐳|🐴|🐵|🐶|🐷|🐸|🐹|🐺|🐻|🐼|🐽|🐾|👀|👂|👃|👄|👅|👆|👇|👈|👉|👊|👋|👌|👍|👎|👏|👐|👑|👒|👓|👔|👕|👖|👗|👘|👙|👚|👛|👜|👝|👞|👟|👠|👡|👢|👣|👤|👥|👦|👧|👨|👩|👪|👫|👬|👭|👮|👯|👰|👱|👲|👳|👴|👵|👶|👷|👸|👹|👺|👻|👼|👽|👾|👿|💀|💁|💂|💃|💄|💅|💆|💇|💈|💉|💊|💋|💌|💍|💎|💏|💐|💑|💒|💓|💔|💕|💖|💗|💘|💙|💚|💛|💜|💝|💞|💟|💠|💡|💢|💣|💤|💥|💦|💧|💨|💩|💪|💫|💬|💭|💮|💯|💰|💱|💲|💳|💴|💵|💶|💷|💸|💹|💺|💻|💼|💽|💾|💿|📀|📁|📂|📃|📄|📅|📆|📇|📈|📉|📊|📋|📌|📍|📎|📏|📐|📑|📒|📓|📔|📕|📖|📗|📘|📙|📚|📛|📜|📝|📞|📟|📠|📡|📢|📣|📤|📥|📦|📧|📨|📩|📪|📫|📬|📭|📮|📯|📰|📱|📲|📳|📴|📵|📶|📷|📸|📹|📺|📻|📼|📿|🔀|🔁|🔂|🔃|🔄|🔅|🔆|🔇|🔈|🔉|🔊|🔋|🔌|🔍|🔎|🔏|🔐|🔑|🔒|🔓|🔔|🔕|🔖|🔗|🔘|🔙|🔚|🔛|🔜|🔝|🔞|🔟|🔠|🔡|🔢|🔣|🔤|🔥|🔦|🔧|🔨|🔩|🔪|🔫|🔬|🔭|🔮|🔯|🔰|🔱|🔲|🔳|🔴|🔵|🔶|🔷|🔸|🔹|🔺|🔻|🔼|🔽|🕋|🕌|🕍|🕎|🕐|🕑|🕒|🕓|🕔|🕕|🕖|🕗|🕘|🕙|🕚|🕛|🕜|🕝|🕞|🕟|🕠|🕡|🕢|🕣|🕤|🕥|🕦|🕧|🕺|🖕|🖖|🖤|🗻|🗼|🗽|🗾|🗿|😀|😁|😂|😃|😄|😅|😆|😇|😈|😉|😊|😋|😌|😍|😎|😏|😐|😑|😒|😓|😔|😕|😖|😗|😘|😙|😚|😛|😜|😝|😞|😟|😠|😡|😢|😣|😤|😥|😦|😧|😨|😩|😪|😫|😬|😭|😮|😯|😰|😱|😲|😳|😴|😵|😶|😷|😸|😹|😺|😻|😼|😽|😾|😿|🙀|🙁|🙂|🙃|🙄|🙅|🙆|🙇|🙈|🙉|🙊|🙋|🙌|🙍|🙎|🙏|🚀|🚁|🚂|🚃|🚄|🚅|🚆|🚇|🚈|🚉|🚊|🚋|🚌|🚍|🚎|🚏|🚐|🚑|🚒|🚓|🚔|🚕|🚖|🚗|🚘|🚙|🚚|🚛|🚜|🚝|🚞|🚟|🚠|🚡|🚢|🚣|🚤|🚥|🚦|🚧|🚨|🚩|🚪|🚫|🚬|🚭|🚮|🚯|🚰|🚱|🚲|🚳|🚴|🚵|🚶|🚷|🚸|🚹|🚺|🚻|🚼|🚽|🚾|🚿|🛀|🛁|🛂|🛃|🛄|🛅|🛌|🛐|🛑|🛒|🛕|🛖|🛗|🛝|🛞|🛟|🛫|🛬|🛴|🛵|🛶|🛷|🛸|🛹|🛺|🛻|🛼|🟠|🟡|🟢|🟣|🟤|🟥|🟦|🟧|🟨|🟩|🟪|🟫|🟰|🤌|🤍|🤎|🤏|🤐|🤑|🤒|🤓|🤔|🤕|🤖|🤗|🤘|🤙|🤚|🤛|🤜|🤝|🤞|🤟|🤠|🤡|🤢|🤣|🤤|🤥|🤦|🤧|🤨|🤩|🤪|🤫|🤬|🤭|🤮|🤯|🤰|🤱|🤲|🤳|🤴|🤵|🤶|🤷|🤸|🤹|🤺|🤼|🤽|🤾|🤿|🥀|🥁|🥂|🥃|🥄|🥅|🥇|🥈|🥉|🥊|🥋|🥌|🥍|🥎|🥏|🥐|🥑|🥒|🥓|🥔|🥕|🥖|🥗|🥘|🥙|🥚|🥛|🥜|🥝|🥞|🥟|🥠|🥡|🥢|🥣|🥤|🥥|🥦|🥧|🥨|🥩|🥪|🥫|🥬|🥭|🥮|🥯|🥰|🥱|🥲|🥳|🥴|🥵|🥶|🥷|🥸|🥹|🥺|🥻|🥼|🥽|🥾|🥿|🦀|🦁|🦂|🦃|🦄|🦅|🦆|🦇|🦈|🦉|🦊|🦋|🦌|🦍|🦎|🦏|🦐|🦑|🦒|🦓|🦔|🦕|🦖|🦗|🦘|🦙|🦚|🦛|🦜|🦝|🦞|🦟|🦠|🦡|🦢|🦣|🦤|🦥|🦦|🦧|🦨|🦩|🦪|🦫|🦬|🦭|🦮|🦯|🦰|🦱|🦲|🦳|🦴|🦵|🦶|🦷|🦸|🦹|🦺|🦻|🦼|🦽|🦾|🦿|🧀|🧁|🧂|🧃|🧄|🧅|🧆|🧇|🧈|🧉|🧊|🧋|🧌|🧍|🧎|🧏|🧐|🧑|🧒|🧓|🧔|🧕|🧖|🧗|🧘|🧙|🧚|🧛|🧜|🧝|🧞|🧟|🧠|🧡|🧢|🧣|🧤|🧥|🧦|🧧|🧨|🧩|🧪|🧫|🧬|🧭|🧮|🧯|🧰|🧱|🧲|🧳|🧴|🧵|🧶|🧷|🧸|🧹|🧺|🧻|🧼|🧽|🧾|🧿|🩰|🩱|🩲|🩳|🩴|🩸|🩹|🩺|🩻|🩼|🪀|🪁|🪂|🪃|🪄|🪅|🪆|🪐|🪑|🪒|🪓|🪔|🪕|🪖|🪗|🪘|🪙|🪚|🪛|🪜|🪝|🪞|🪟|🪠|🪡|🪢|🪣|🪤|🪥|🪦|🪧|🪨|🪩|🪪|🪫|🪬|🪰|🪱|🪲|🪳|🪴|🪵|🪶|🪷|🪸|🪹|🪺|🫀|🫁|🫂|🫃|🫄|🫅|🫐|🫑|🫒|🫓|🫔|🫕|🫖|🫗|🫘|🫙|🫠|🫡|🫢|🫣|🫤|🫥|🫦|🫧|🫰|🫱|🫲|🫳|🫴|🫵|🫶|⌚|⌛|⏩|⏪|⏫|⏬|⏰|⏳|◽|◾|☔|☕|♈|♉|♊|♋|♌|♍|♎|♏|♐|♑|♒|♓|♿|⚓|⚡|⚪|⚫|⚽|⚾|⛄|⛅|⛎|⛔|⛪|⛲|⛳|⛵|⛺|⛽|✅|✊|✋|✨|❌|❎|❓|❔|❕|❗|➕|➖|➗|➰|➿|⬛|⬜|⭐|⭕";
+
+// EOF
@@ -0,0 +1,1290 @@
+//! Searching for emojis
+//!
+//! This module contains utilities to find emojis without knowing their exact constant or alias,
+//! for instance, by looking for a part of their [`name`](crate::emojis::Emoji::name) or by
+//! their actual Unicode grapheme.
+//!
+//! None of the functions in here require `alloc`, however, with `alloc` some of them use
+//! faster lookup tables.
+
+use core::convert::TryFrom;
+use core::ops::RangeBounds;
+
+use crate::emojis::Emoji;
+use crate::emojis::Version;
+use crate::Gender;
+use crate::Hair;
+use crate::Tone;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "hash-tables")]
+lazy_static::lazy_static! {
+    /// Maps graphemes to emojis (including all variants)
+    static ref GRAPHEME_MAP: hashbrown::HashMap<&'static str, &'static Emoji> =
+        all().map(|e| (e.grapheme, e)).collect();
+
+    /// Maps graphemes without variation selectors to emojis (including all variants)
+    static ref UNQUALIFIED_MAP: hashbrown::HashMap<String, &'static Emoji> =
+        all().map(|e| (unqualified_chars(e.grapheme).collect(), e)).collect();
+
+    /// All emojis (including all variants) sorted by grapheme, thus by their codepoints
+    static ref SORTED_EMOJIS: Vec<&'static Emoji> = {
+        let mut emojis: Vec<_> = all().collect();
+        emojis.sort_unstable_by_key(|e| e.grapheme);
+        emojis
+    };
+
+    /// The length in bytes of the longest grapheme
+    static ref MAX_GRAPHEME_LEN: usize = all().map(|e| e.grapheme.len()).max().unwrap_or(0);
+
+    /// Maps graphemes to the names of their group and subgroup
+    static ref GROUP_MAP: hashbrown::HashMap<&'static str, (&'static str, &'static str)> =
+        crate::grouped::ALL_SUBGROUPS
+            .iter()
+            .flat_map(|&(group, subgroup, variants)| {
+                variants
+                    .iter()
+                    .flat_map(|v| v.iter())
+                    .map(move |e| (e.grapheme, (group, subgroup)))
+            })
+            .collect();
+
+    /// Maps graphemes to their base emoji (including the base emojis themselves)
+    static ref BASE_MAP: hashbrown::HashMap<&'static str, &'static Emoji> = {
+        let bases: hashbrown::HashSet<&'static str> =
+            crate::grouped::base_emojis().map(|e| e.grapheme).collect();
+        crate::grouped::all_variants()
+            .flat_map(|variants| with_bases(variants, |v| bases.contains(v.grapheme)))
+            .map(|(variant, base)| (variant.grapheme, base))
+            .collect()
+    };
+
+    /// Maps graphemes to all variants of their emoji (including themselves)
+    static ref VARIANTS_MAP: hashbrown::HashMap<&'static str, &'static [&'static Emoji]> =
+        crate::grouped::all_variants()
+            .flat_map(|variants| variants.iter().map(move |v| (v.grapheme, variants)))
+            .collect();
+
+    /// Maps graphemes without gender and variation selectors to emojis (including all variants)
+    static ref UNGENDERED_MAP: hashbrown::HashMap<String, Vec<&'static Emoji>> = {
+        let mut map = hashbrown::HashMap::<_, Vec<_>>::new();
+        for e in all() {
+            map.entry(ungendered_chars(e.grapheme).collect()).or_default().push(e);
+        }
+        map
+    };
+}
+
+/// Returns an iterator over every single emoji of this crate, including all variants.
+pub(crate) fn all() -> impl Iterator<Item = &'static Emoji> {
+    crate::grouped::all_variant_emojis()
+}
+
+/// Looks up the emoji of the given grapheme (i.e. its Unicode sequence).
+///
+/// This is the reverse of [`Emoji::grapheme`], it works for the default emojis as well as for
+/// all their variants (e.g. toned or gendered ones). The grapheme must match exactly, except for
+/// the emoji presentation selector (U+FE0F), which is frequently missing in copy-pasted emojis.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::lookup;
+/// use emojic::Tone;
+///
+/// assert_eq!(Some(&emojic::flat::CRAB), lookup("🦀"));
+/// assert_eq!(Some("person: dark skin tone"), lookup("🧑🏿").map(|e| e.name));
+/// assert_eq!(
+///     Some(emojic::flat::PERSON.tone(Tone::Dark)),
+///     lookup("🧑🏿")
+/// );
+/// assert_eq!(None, lookup("crab"));
+///
+/// // With or without the variation selector
+/// assert_eq!(Some(&emojic::flat::SMILING_FACE), lookup("\u{263A}\u{FE0F}"));
+/// assert_eq!(Some(&emojic::flat::SMILING_FACE), lookup("\u{263A}"));
+/// ```
+pub fn lookup(grapheme: &str) -> Option<&'static Emoji> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hash-tables")] {
+            // If we have alloc, we use the faster hash maps
+            GRAPHEME_MAP
+                .get(grapheme)
+                .or_else(|| UNQUALIFIED_MAP.get(&unqualified_chars(grapheme).collect::<String>()))
+                .copied()
+        } else {
+            // As a fallback, we simply go through all of them
+            all().find(|e| e.grapheme == grapheme).or_else(|| {
+                all().find(|e| unqualified_chars(e.grapheme).eq(unqualified_chars(grapheme)))
+            })
+        }
+    }
+}
+
+/// Parses an emoji given either as grapheme or as alias.
+///
+/// This is the single entry point for accepting "an emoji" from users, it tries in order:
+/// [`lookup`] of the grapheme, [`parse_alias`](crate::parse_alias) of the colon-fenced alias,
+/// the bare alias name (without colons), and finally the colon-fenced grapheme (as frequently
+/// pasted into alias fields, e.g. `:🦀:`).
+///
+/// # Examples
+///
+/// ```
+/// use emojic::parse;
+///
+/// assert_eq!(Some(&emojic::flat::CRAB), parse("🦀"));
+/// assert_eq!(Some(&emojic::flat::CRAB), parse(":crab:"));
+/// assert_eq!(Some(&emojic::flat::CRAB), parse("crab"));
+/// assert_eq!(Some(&emojic::flat::CRAB), parse(":🦀:"));
+/// assert_eq!(Some(&*emojic::flat::THUMBS_UP), parse("+1"));
+/// assert_eq!(Some(&emojic::flat::RED_HEART), parse(":\u{2764}:")); // unqualified
+/// assert_eq!(None, parse("crabs"));
+/// assert_eq!(None, parse(":🦀🦀:"));
+/// assert_eq!(None, parse(""));
+/// ```
+pub fn parse(s: &str) -> Option<&'static Emoji> {
+    lookup(s)
+        .or_else(|| crate::text::parse_alias(s))
+        .or_else(|| crate::text::parse_pure_alias(s))
+        .or_else(|| {
+            s.strip_prefix(':')
+                .and_then(|s| s.strip_suffix(':'))
+                .and_then(lookup)
+        })
+}
+
+/// Looks up the emoji consisting of the given codepoints.
+///
+/// This works just like [`lookup`], but takes the Unicode scalar values of the grapheme, e.g. as
+/// listed in the Unicode emoji data files.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::from_codepoints;
+///
+/// assert_eq!(Some(&*emojic::flat::WAVING_HAND), from_codepoints(&['\u{1F44B}']));
+/// assert_eq!(Some(&emojic::flat::CRAB), from_codepoints(&['🦀']));
+/// assert_eq!(None, from_codepoints(&['a']));
+/// ```
+pub fn from_codepoints(codepoints: &[char]) -> Option<&'static Emoji> {
+    lookup_chars(codepoints.iter().copied())
+}
+
+/// Looks up the emoji consisting of the given hexadecimal codepoints.
+///
+/// The codepoints are separated by whitespace and may be prefixed by `U+`, thus this accepts
+/// the notation of the Unicode emoji data files (e.g. `"1F44B 1F3FF"`) as well as the one of
+/// [`Emoji::codepoints_notation`] (e.g. `"U+1F44B U+1F3FF"`). Otherwise, this works just like
+/// [`lookup`].
+///
+/// # Examples
+///
+/// ```
+/// use emojic::from_hex;
+/// use emojic::Tone;
+///
+/// assert_eq!(Some(&*emojic::flat::WAVING_HAND), from_hex("1F44B"));
+/// assert_eq!(Some(emojic::flat::WAVING_HAND.tone(Tone::Dark)), from_hex("1F44B 1F3FF"));
+/// assert_eq!(Some(emojic::flat::WAVING_HAND.tone(Tone::Dark)), from_hex("U+1F44B U+1F3FF"));
+/// assert_eq!(None, from_hex("1F44B XYZ"));
+/// assert_eq!(None, from_hex("+1F44B"));
+/// assert_eq!(None, from_hex(""));
+/// ```
+pub fn from_hex(hex: &str) -> Option<&'static Emoji> {
+    fn parse(code: &str) -> Option<char> {
+        let code = code
+            .strip_prefix("U+")
+            .or_else(|| code.strip_prefix("u+"))
+            .unwrap_or(code);
+        // `from_str_radix` would also accept a sign, e.g. `"+1F44B"`
+        if !code.starts_with(|c: char| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        u32::from_str_radix(code, 16).ok().and_then(char::from_u32)
+    }
+
+    if hex.split_whitespace().all(|code| parse(code).is_some()) {
+        lookup_chars(hex.split_whitespace().filter_map(parse))
+    } else {
+        None
+    }
+}
+
+/// Iterates over all emojis whose grapheme starts with the given codepoints.
+///
+/// This allows to narrow down the emojis while their codepoints are typed one after another,
+/// e.g. in an input method. The codepoints must match exactly, including any emoji
+/// presentation selector (U+FE0F). All emojis are yielded for an empty `prefix`. With the
+/// `hash-tables` feature, the emojis are sorted by their codepoints, otherwise they come in the
+/// order of the [`grouped`](crate::grouped) lists.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::by_codepoint_prefix;
+///
+/// // Waving hand 👋 and all its toned variants
+/// assert_eq!(6, by_codepoint_prefix(&['\u{1F44B}']).count());
+/// assert!(by_codepoint_prefix(&['\u{1F468}', '\u{200D}']).all(|e| e.grapheme.starts_with("👨\u{200D}")));
+/// assert_eq!(None, by_codepoint_prefix(&['a']).next());
+/// ```
+pub fn by_codepoint_prefix(prefix: &[char]) -> impl Iterator<Item = &'static Emoji> + '_ {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hash-tables")] {
+            // If we have alloc, we scan the range of the sorted emojis
+            let prefix: String = prefix.iter().collect();
+            let start = SORTED_EMOJIS.partition_point(|e| e.grapheme < prefix.as_str());
+            SORTED_EMOJIS[start..]
+                .iter()
+                .copied()
+                .take_while(move |e| e.grapheme.starts_with(prefix.as_str()))
+        } else {
+            // As a fallback, we simply go through all of them
+            all().filter(move |e| {
+                let mut chars = e.grapheme.chars();
+                prefix.iter().all(|&c| chars.next() == Some(c))
+            })
+        }
+    }
+}
+
+/// Looks up the emoji consisting of the given chars, as by [`lookup`]
+fn lookup_chars(chars: impl Iterator<Item = char> + Clone) -> Option<&'static Emoji> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hash-tables")] {
+            lookup(&chars.collect::<String>())
+        } else {
+            all().find(|e| e.grapheme.chars().eq(chars.clone())).or_else(|| {
+                all().find(|e| {
+                    unqualified_chars(e.grapheme).eq(chars.clone().filter(|&c| c != '\u{FE0F}'))
+                })
+            })
+        }
+    }
+}
+
+/// Normalizes the given grapheme to the fully-qualified form of its emoji.
+///
+/// Emojis frequently differ only in the presence of the emoji presentation selector (U+FE0F),
+/// e.g. when they are copy-pasted. This returns the [`grapheme`](Emoji::grapheme) of the emoji
+/// found by [`lookup`], which is always fully-qualified. If there is no such emoji, the given
+/// grapheme is returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::normalize_grapheme;
+///
+/// assert_eq!("\u{263A}\u{FE0F}", normalize_grapheme("\u{263A}")); // ☺️
+/// assert_eq!("\u{263A}\u{FE0F}", normalize_grapheme("\u{263A}\u{FE0F}"));
+/// assert_eq!("1\u{FE0F}\u{20E3}", normalize_grapheme("1\u{20E3}")); // 1️⃣
+/// assert_eq!("crab", normalize_grapheme("crab"));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn normalize_grapheme(grapheme: &str) -> String {
+    lookup(grapheme).map_or(grapheme, |e| e.grapheme).into()
+}
+
+/// Tests whether the given char is the first one of any emoji.
+///
+/// This is a fast pre-filter for scanning texts for emojis, since the chars which can never
+/// start an emoji (such as most ASCII chars) can be skipped before trying to [`lookup`] any
+/// emoji. Notice, that the digits, `#`, and `*` start the keycap emojis (e.g. 1️⃣).
+///
+/// The chars are those of all emojis, regardless of the selected `group-*` features.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::is_emoji_start;
+///
+/// assert!(is_emoji_start('🦀'));
+/// assert!(is_emoji_start('👨')); // e.g. of 👨‍👩‍👧
+/// assert!(is_emoji_start('1')); // of 1️⃣
+/// assert!(!is_emoji_start('a'));
+/// assert!(!is_emoji_start('\u{200D}')); // the zero width joiner
+/// ```
+pub fn is_emoji_start(c: char) -> bool {
+    crate::starts::EMOJI_STARTS.binary_search(&c).is_ok()
+}
+
+/// Looks up the names of the group and subgroup of the given emoji.
+///
+/// These are the Unicode names of the categories of the [`grouped`](crate::grouped) module
+/// (also available as the `NAME` constant of each module). For instance, 👋 is in the group
+/// `"People & Body"` and in its subgroup `"hand-fingers-open"`. The variants of an emoji are in
+/// the same group. Since the emoji is identified by its grapheme, `None` is only returned for
+/// emojis which are not part of this crate.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::group_of;
+/// use emojic::Tone;
+///
+/// assert_eq!(
+///     Some(("People & Body", "hand-fingers-open")),
+///     group_of(&emojic::flat::WAVING_HAND)
+/// );
+/// assert_eq!(
+///     Some((emojic::grouped::animals_and_nature::NAME, "animal-marine")),
+///     group_of(&emojic::flat::OCTOPUS)
+/// );
+/// assert_eq!(
+///     group_of(&emojic::flat::WAVING_HAND),
+///     group_of(emojic::flat::WAVING_HAND.tone(Tone::Dark))
+/// );
+/// ```
+pub fn group_of(emoji: &Emoji) -> Option<(&'static str, &'static str)> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hash-tables")] {
+            // If we have alloc, we use the faster hash map
+            GROUP_MAP.get(emoji.grapheme).copied()
+        } else {
+            // As a fallback, we simply go through all of them
+            crate::grouped::ALL_SUBGROUPS
+                .iter()
+                .find(|(_, _, variants)| {
+                    variants
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .any(|e| e.grapheme == emoji.grapheme)
+                })
+                .map(|&(group, subgroup, _)| (group, subgroup))
+        }
+    }
+}
+
+/// Looks up the longest emoji that is a prefix of the given `text`.
+///
+/// Since many emojis are composed of other emojis (e.g. 👨‍👩‍👧 consists of 👨, 👩, and 👧),
+/// the longest match is the one that was actually meant.
+pub(crate) fn lookup_prefix(text: &str) -> Option<&'static Emoji> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hash-tables")] {
+            // If we have alloc, we look up every prefix in the hash map
+            text.char_indices()
+                .map(|(i, c)| i + c.len_utf8())
+                .take_while(|end| *end <= *MAX_GRAPHEME_LEN)
+                .filter_map(|end| GRAPHEME_MAP.get(&text[..end]))
+                .last()
+                .copied()
+        } else {
+            // As a fallback, we simply go through all of them
+            all()
+                .filter(|e| text.starts_with(e.grapheme))
+                .max_by_key(|e| e.grapheme.len())
+        }
+    }
+}
+
+/// Looks up all variants of the given emoji (including itself), such as its toned ones.
+///
+/// The variants are in the order of the [`grouped`](crate::grouped) lists, thus the default
+/// variant comes first.
+pub(crate) fn variants_of(emoji: &Emoji) -> Option<&'static [&'static Emoji]> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hash-tables")] {
+            // If we have alloc, we use the faster hash map
+            VARIANTS_MAP.get(emoji.grapheme).copied()
+        } else {
+            // As a fallback, we simply go through all of them
+            crate::grouped::all_variants()
+                .find(|variants| variants.iter().any(|v| v.grapheme == emoji.grapheme))
+        }
+    }
+}
+
+/// Pairs each of the `variants` with its base emoji, i.e. the closest preceding base emoji
+/// according to `is_base` (or the variant itself if it is one).
+///
+/// The first of the `variants` is always treated as a base, in order to never lose a variant.
+fn with_bases(
+    variants: &'static [&'static Emoji],
+    is_base: impl Fn(&Emoji) -> bool,
+) -> impl Iterator<Item = (&'static Emoji, &'static Emoji)> {
+    variants.iter().scan(None, move |base, &variant| {
+        if base.is_none() || is_base(variant) {
+            *base = Some(variant);
+        }
+        base.map(|base| (variant, base))
+    })
+}
+
+/// Looks up the base emoji of the given `emoji`, i.e. the emoji it is a variant of.
+///
+/// The base of a toned, gendered, or otherwise customized variant is the default emoji of the
+/// [`grouped`](crate::grouped) lists (e.g. 👋 for 👋🏿), which allows to collapse all
+/// variants of an emoji. A base emoji is its own base. If an emoji has no default, because each
+/// of its variants has an explicit gender (e.g. 🕺 and 💃), the base is the respective untoned
+/// emoji of that gender. `None` is only returned for emojis which are not part of this crate.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::base_of;
+/// use emojic::flat::{CRAB, PERSON_DANCING, PERSON_FACEPALMING, WAVING_HAND};
+/// use emojic::{Gender, Tone};
+///
+/// assert_eq!(Some(&WAVING_HAND.default), base_of(WAVING_HAND.tone(Tone::Dark)));
+/// assert_eq!(Some(&WAVING_HAND.default), base_of(&WAVING_HAND));
+/// assert_eq!(
+///     Some(&PERSON_FACEPALMING.default.default),
+///     base_of(PERSON_FACEPALMING.gender(Gender::Female).tone(Tone::Light))
+/// );
+/// assert_eq!(Some(&CRAB), base_of(&CRAB));
+///
+/// // No default, thus the gendered emojis are bases
+/// let woman_dancing = PERSON_DANCING.gender(Gender::Female);
+/// assert_eq!(Some(&woman_dancing.default), base_of(woman_dancing.tone(Tone::Medium)));
+/// let man_dancing = PERSON_DANCING.gender(Gender::Male);
+/// assert_eq!(Some(&man_dancing.default), base_of(man_dancing.tone(Tone::Medium)));
+/// ```
+pub fn base_of(emoji: &Emoji) -> Option<&'static Emoji> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hash-tables")] {
+            // If we have alloc, we use the faster hash map
+            BASE_MAP.get(emoji.grapheme).copied()
+        } else {
+            // As a fallback, we go through the variants and all base emojis
+            with_bases(variants_of(emoji)?, |v| {
+                crate::grouped::base_emojis().any(|b| b.grapheme == v.grapheme)
+            })
+            .find(|(variant, _)| variant.grapheme == emoji.grapheme)
+            .map(|(_, base)| base)
+        }
+    }
+}
+
+/// Looks up the customization flags supported by `emoji`, see [`supports_tone`]
+fn supported_flags(emoji: &Emoji) -> u8 {
+    let supports = crate::supports::SUPPORTS;
+    supports
+        .binary_search_by_key(&emoji.grapheme, |&(grapheme, _)| grapheme)
+        .map_or(0, |idx| supports[idx].1)
+}
+
+/// Tells whether the given emoji can be customized by skin [`Tone`].
+///
+/// This is the case if its constant has a skin tone attribute, i.e. it is a `With<Tone, _>`
+/// (or `With<TonePair, _>`) at any level. All variants of such an emoji support it, thus the
+/// toned variants as well, which allows to offer a skin tone selection for any of them.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::flat::{CRAB, PERSON_FACEPALMING, WAVING_HAND};
+/// use emojic::{supports_tone, Gender, Tone};
+///
+/// assert!(supports_tone(&WAVING_HAND));
+/// assert!(supports_tone(WAVING_HAND.tone(Tone::Dark)));
+/// assert!(supports_tone(PERSON_FACEPALMING.gender(Gender::Female)));
+/// assert!(!supports_tone(&CRAB));
+/// ```
+pub fn supports_tone(emoji: &Emoji) -> bool {
+    supported_flags(emoji) & crate::supports::TONE != 0
+}
+
+/// Tells whether the given emoji can be customized by [`Gender`].
+///
+/// This is the case if its constant has a gender attribute, i.e. it is a `With<Gender, _>` (or
+/// `With<Pair, _>`, `With<Family, _>`) at any level. All variants of such an emoji support it,
+/// see [`supports_tone`].
+///
+/// # Examples
+///
+/// ```
+/// use emojic::flat::{FAMILY, PERSON_FACEPALMING, WAVING_HAND};
+/// use emojic::{supports_gender, Gender, Tone};
+///
+/// assert!(supports_gender(&PERSON_FACEPALMING));
+/// assert!(supports_gender(PERSON_FACEPALMING.gender(Gender::Male).tone(Tone::Light)));
+/// assert!(supports_gender(&FAMILY));
+/// assert!(!supports_gender(&WAVING_HAND));
+/// ```
+pub fn supports_gender(emoji: &Emoji) -> bool {
+    supported_flags(emoji) & crate::supports::GENDER != 0
+}
+
+/// Tells whether the given emoji can be customized by [`Hair`] style.
+///
+/// This is the case if its constant has a hair style attribute, i.e. it is a `With<Hair, _>` at
+/// any level. All variants of such an emoji support it, see [`supports_tone`].
+///
+/// # Examples
+///
+/// ```
+/// use emojic::flat::{PERSON, PERSON_FACEPALMING};
+/// use emojic::{supports_hair, Hair};
+///
+/// assert!(supports_hair(&PERSON));
+/// assert!(supports_hair(PERSON.hair(Hair::Red)));
+/// assert!(!supports_hair(&PERSON_FACEPALMING));
+/// ```
+pub fn supports_hair(emoji: &Emoji) -> bool {
+    supported_flags(emoji) & crate::supports::HAIR != 0
+}
+
+/// Looks up the variant of `emoji` where every person has the given skin `tone`.
+///
+/// The variant is found by its grapheme, which must equal the one of `emoji` except for the
+/// skin tone modifiers (and variation selectors), thus `emoji` may already be a toned variant.
+/// Only the [`variants_of`] `emoji` are considered, like the `With<Tone, _>` of its constant.
+pub(crate) fn toned(emoji: &Emoji, tone: Tone) -> Option<&'static Emoji> {
+    let modifier = tone.modifier();
+
+    variants_of(emoji)?.iter().copied().find(|v| {
+        v.grapheme.contains(modifier)
+            && v.grapheme
+                .chars()
+                .all(|c| c == modifier || !is_tone_modifier(c))
+            && untoned_chars(v.grapheme).eq(untoned_chars(emoji.grapheme))
+    })
+}
+
+/// Looks up the variant of `emoji` depicting a single person of the given `gender`.
+///
+/// The variant is found by its grapheme, which must equal the one of `emoji` except for the
+/// gender, i.e. man and woman are equivalent to person, boy and girl to child, and the gender
+/// signs are ignored. Thus `emoji` may already be a gendered (and even toned) variant. Unlike
+/// tones, genders may cross constants (e.g. 🧒 and 👦), thus all emojis are considered.
+pub(crate) fn gendered(emoji: &Emoji, gender: Gender) -> Option<&'static Emoji> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hash-tables")] {
+            // If we have alloc, we use the faster hash map
+            UNGENDERED_MAP
+                .get(&ungendered_chars(emoji.grapheme).collect::<String>())?
+                .iter()
+                .copied()
+                .find(|v| gender_of(v.grapheme) == Some(gender))
+        } else {
+            // As a fallback, we simply go through all of them
+            all().find(|v| {
+                gender_of(v.grapheme) == Some(gender)
+                    && ungendered_chars(v.grapheme).eq(ungendered_chars(emoji.grapheme))
+            })
+        }
+    }
+}
+
+/// Returns the gender of all the people of `grapheme`, if they have one and the same
+fn gender_of(grapheme: &str) -> Option<Gender> {
+    let mut genders = grapheme.chars().filter_map(|c| match c {
+        '\u{2642}' | '\u{1F468}' | '\u{1F466}' => Some(Gender::Male),
+        '\u{2640}' | '\u{1F469}' | '\u{1F467}' => Some(Gender::Female),
+        _ => None,
+    });
+    let first = genders.next()?;
+    if genders.all(|g| g == first) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+/// Iterates the chars of `grapheme` without gender and variation selectors.
+///
+/// Men and women are replaced by persons, boys and girls by children, and the gender signs
+/// (including their joiners) are removed.
+fn ungendered_chars(grapheme: &str) -> impl Iterator<Item = char> + '_ {
+    ungender(grapheme.chars())
+}
+
+/// Removes the gender and variation selectors from `chars`, see [`ungendered_chars`]
+fn ungender(chars: impl Iterator<Item = char>) -> impl Iterator<Item = char> {
+    let mut chars = chars.filter(|&c| c != '\u{FE0F}').peekable();
+    core::iter::from_fn(move || loop {
+        let c = chars.next()?;
+        match c {
+            '\u{2642}' | '\u{2640}' => continue,
+            '\u{200D}' if matches!(chars.peek(), Some('\u{2642}') | Some('\u{2640}')) => continue,
+            '\u{1F468}' | '\u{1F469}' => return Some('\u{1F9D1}'),
+            '\u{1F466}' | '\u{1F467}' => return Some('\u{1F9D2}'),
+            c => return Some(c),
+        }
+    })
+}
+
+/// Looks up the variant of `emoji` depicting a person with the given `hair` style.
+///
+/// The variant is found by its grapheme, which must equal the one of `emoji` except for the
+/// hair style and the gender, while the gender itself must match. Thus `emoji` may already have
+/// a hair style, and men with beard (🧔‍♂️) are found for men (👨). Only the [`variants_of`]
+/// `emoji` are considered.
+pub(crate) fn haired(emoji: &Emoji, hair: Hair) -> Option<&'static Emoji> {
+    let gender = gender_of(emoji.grapheme);
+
+    variants_of(emoji)?.iter().copied().find(|v| {
+        hair_of(v.grapheme) == Some(hair)
+            && gender_of(v.grapheme) == gender
+            && ungender(unhaired_chars(v.grapheme)).eq(ungender(unhaired_chars(emoji.grapheme)))
+    })
+}
+
+/// Returns the hair style of the person in `grapheme`, if it has one
+fn hair_of(grapheme: &str) -> Option<Hair> {
+    grapheme.chars().find_map(|c| match c {
+        '\u{1F9D4}' => Some(Hair::Beard),
+        '\u{1F471}' => Some(Hair::Blond),
+        '\u{1F9B0}' => Some(Hair::Red),
+        '\u{1F9B1}' => Some(Hair::Curly),
+        '\u{1F9B3}' => Some(Hair::White),
+        '\u{1F9B2}' => Some(Hair::Bald),
+        _ => None,
+    })
+}
+
+/// Iterates the chars of `grapheme` without hair style.
+///
+/// Persons with beard or blond hair are replaced by persons, and the hair components (including
+/// their joiners) are removed.
+fn unhaired_chars(grapheme: &str) -> impl Iterator<Item = char> + '_ {
+    let is_component = |c: &char| matches!(c, '\u{1F9B0}'..='\u{1F9B3}');
+    let mut chars = grapheme.chars().peekable();
+    core::iter::from_fn(move || loop {
+        let c = chars.next()?;
+        match c {
+            c if is_component(&c) => continue,
+            '\u{200D}' if chars.peek().is_some_and(is_component) => continue,
+            '\u{1F9D4}' | '\u{1F471}' => return Some('\u{1F9D1}'),
+            c => return Some(c),
+        }
+    })
+}
+
+/// Reads the skin tone out of the given emoji grapheme.
+///
+/// Returns the tone of the first Fitzpatrick modifier in `grapheme`, or `None` if it is not
+/// toned. For emojis with two differently toned people, this is the tone of the first one,
+/// see [`extract_tones`] to get all of them. The grapheme does not need to be an emoji of this
+/// crate.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::extract_tone;
+/// use emojic::Tone;
+///
+/// assert_eq!(Some(Tone::Dark), extract_tone("👋🏿"));
+/// assert_eq!(Some(Tone::Light), extract_tone("🧑🏻‍🤝‍🧑🏿"));
+/// assert_eq!(None, extract_tone("👋"));
+/// ```
+pub fn extract_tone(grapheme: &str) -> Option<Tone> {
+    extract_tones(grapheme).next()
+}
+
+/// Reads all skin tones out of the given emoji grapheme.
+///
+/// Yields the tone of each Fitzpatrick modifier in `grapheme` in order, thus for the emojis of
+/// this crate, there are at most two tones (one for each person). Notice, that some emojis
+/// of two equally toned people (e.g. 💑🏽) contain just a single modifier.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::extract_tones;
+/// use emojic::Tone;
+///
+/// let tones: Vec<Tone> = extract_tones("🧑🏻‍🤝‍🧑🏿").collect();
+/// assert_eq!(tones, [Tone::Light, Tone::Dark]);
+/// assert_eq!(None, extract_tones("🦀").next());
+/// ```
+pub fn extract_tones(grapheme: &str) -> impl Iterator<Item = Tone> + '_ {
+    grapheme.chars().filter_map(|c| Tone::try_from(c).ok())
+}
+
+/// Tests whether the given char is a Fitzpatrick modifier
+fn is_tone_modifier(c: char) -> bool {
+    Tone::try_from(c).is_ok()
+}
+
+/// Iterates the chars of `grapheme` without variation selectors
+pub(crate) fn unqualified_chars(grapheme: &str) -> impl Iterator<Item = char> + '_ {
+    grapheme.chars().filter(|&c| c != '\u{FE0F}')
+}
+
+/// Iterates the chars of `grapheme` without skin tone modifiers and variation selectors
+fn untoned_chars(grapheme: &str) -> impl Iterator<Item = char> + '_ {
+    grapheme
+        .chars()
+        .filter(|&c| !is_tone_modifier(c) && c != '\u{FE0F}')
+}
+
+/// Iterates over all emojis introduced within the given `range` of Unicode Emoji versions.
+///
+/// This includes all variants (e.g. the toned ones), each by its own
+/// [`since`](Emoji::since) version. Combined with [`Version::MIN`] and [`Version::MAX`], this
+/// allows e.g. to select the emojis supported by a platform. The number of emojis of each version
+/// is given by [`version_histogram`](crate::version_histogram).
+///
+/// # Examples
+///
+/// ```
+/// use emojic::emojis::Version;
+/// use emojic::search::in_version_range;
+///
+/// // Emojis introduced by 12.0, 12.1, and 13.0
+/// let recent: Vec<_> = in_version_range(Version(12, 0)..=Version(13, 0)).collect();
+/// assert!(recent.contains(&&emojic::flat::SLOTH));
+/// assert!(!recent.contains(&&emojic::flat::CRAB));
+///
+/// // Emojis supported by a platform up to Emoji 11.0
+/// assert!(in_version_range(Version::MIN..=Version(11, 0)).all(|e| e.since <= Version(11, 0)));
+/// assert_eq!(None, in_version_range(Version(15, 0)..=Version::MAX).next());
+/// ```
+pub fn in_version_range(range: impl RangeBounds<Version>) -> impl Iterator<Item = &'static Emoji> {
+    all().filter(move |e| range.contains(&e.since))
+}
+
+/// Searches all emojis whose name contains the given `query`.
+///
+/// The comparison is ASCII case-insensitive and treats underscores as spaces, so `"waving hand"`,
+/// `"Waving Hand"`, and `"WAVING_HAND"` all find 👋. The results include all variants of an emoji
+/// (e.g. the toned ones) and are ordered by the length of their name, thus the shorter (and
+/// generally more basic) emojis come first. An empty `query` yields all emojis.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::search::by_name;
+///
+/// let mut found = by_name("smiling face");
+/// assert_eq!(Some("☺️"), found.next().map(|e| e.grapheme)); // "smiling face"
+/// assert_eq!(Some("🙂"), found.next().map(|e| e.grapheme)); // "slightly smiling face"
+///
+/// assert_eq!(
+///     Some(&*emojic::flat::WAVING_HAND),
+///     by_name("WAVING_HAND").next()
+/// );
+/// ```
+pub fn by_name(query: &str) -> impl Iterator<Item = &'static Emoji> + '_ {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "alloc")] {
+            // If we have alloc, we filter all emojis once and sort the matches (the sort is
+            // stable, so equally long names keep their order)
+            let mut found: Vec<&'static Emoji> =
+                all().filter(|e| contains_folded(e.name, query)).collect();
+            found.sort_by_key(|e| e.name.len());
+            found.into_iter()
+        } else {
+            // As a fallback, we go through all of them once per name length
+            let max_len = all().map(|e| e.name.len()).max().unwrap_or(0);
+
+            (0..=max_len).flat_map(move |len| {
+                all().filter(move |e| e.name.len() == len && contains_folded(e.name, query))
+            })
+        }
+    }
+}
+
+/// Searches the emojis matching the given `query`, ranked by relevance.
+///
+/// The emojis are scored by how well their name matches: an exact name match scores `1.0`, a
+/// name starting with the `query` scores `0.75`, and a name containing it scores `0.5`.
+///
+/// The lowest tier is meant for keyword tags (e.g. "party" for 🎉), but this crate has no tag
+/// table yet, because the generator does not keep the gemoji tags. Until it does, the aliases
+/// stand in for them: emojis whose name doesn't match at all, but one of their aliases (e.g.
+/// `:+1:`) does, score `0.25`. Names are compared like in [`by_name`], i.e. ASCII case-insensitive and with underscores
+/// treated as spaces.
+///
+/// Returns at most `limit` emojis with their score, the best ones first, ties are ordered
+/// alphabetically by name. An empty `query` matches nothing.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::search::query;
+///
+/// let found = query("crab", 3);
+/// assert_eq!((&emojic::flat::CRAB, 1.0), found[0]);
+///
+/// // The aliases are considered too
+/// assert!(query("thumbsup", 10)
+///     .iter()
+///     .any(|&(e, _)| e == &*emojic::flat::THUMBS_UP));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn query(query: &str, limit: usize) -> Vec<(&'static Emoji, f32)> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    // The aliases stand in for the tags of the lowest tier, as there is no tag table yet.
+    // Sorted, so it can be binary searched for each emoji
+    let mut alias_hits: Vec<&str> = crate::text::all_aliases()
+        .filter(|(alias, _)| contains_folded(alias, query))
+        .map(|(_, emoji)| emoji.grapheme)
+        .collect();
+    alias_hits.sort_unstable();
+    alias_hits.dedup();
+
+    let mut found: Vec<(&'static Emoji, f32)> = all()
+        .filter_map(|e| {
+            let score = if e.name.len() == query.len() && starts_with_folded(e.name, query) {
+                1.0
+            } else if starts_with_folded(e.name, query) {
+                0.75
+            } else if contains_folded(e.name, query) {
+                0.5
+            } else if alias_hits.binary_search(&e.grapheme).is_ok() {
+                0.25
+            } else {
+                return None;
+            };
+            Some((e, score))
+        })
+        .collect();
+
+    found.sort_by(|(a, a_score), (b, b_score)| {
+        b_score
+            .partial_cmp(a_score)
+            .unwrap_or(core::cmp::Ordering::Equal)
+            .then_with(|| a.name.cmp(b.name))
+    });
+    found.truncate(limit);
+    found
+}
+
+/// Folds a char for comparisons in [`contains_folded`]
+fn fold(c: char) -> char {
+    if c == '_' {
+        ' '
+    } else {
+        c.to_ascii_lowercase()
+    }
+}
+
+/// Tests whether `needle` is contained in `haystack` (case-insensitive, `_` equals ` `)
+fn contains_folded(haystack: &str, needle: &str) -> bool {
+    needle.is_empty()
+        || haystack.char_indices().any(|(i, _)| {
+            let mut rest = haystack[i..].chars();
+            needle
+                .chars()
+                .all(|n| rest.next().map(fold) == Some(fold(n)))
+        })
+}
+
+/// Tests whether `haystack` starts with `needle` (case-insensitive, `_` equals ` `)
+#[cfg(feature = "alloc")]
+fn starts_with_folded(haystack: &str, needle: &str) -> bool {
+    let mut rest = haystack.chars();
+    needle
+        .chars()
+        .all(|n| rest.next().map(fold) == Some(fold(n)))
+}
+
+#[cfg(test)]
+mod tests {
+    // Tests are going to be on development systems => there will be std.
+    extern crate std;
+    use std::prelude::v1::*;
+
+    use super::*;
+
+    #[test]
+    fn extract_tones_of_variants() {
+        use crate::flat;
+
+        assert_eq!(None, extract_tone(flat::WAVING_HAND.grapheme));
+        for &tone in Tone::ALL.iter() {
+            assert_eq!(
+                Some(tone),
+                extract_tone(flat::WAVING_HAND.tone(tone).grapheme)
+            );
+        }
+        let couple = flat::COUPLE_WITH_HEART.tone((Tone::Light, Tone::Dark));
+        assert_eq!(
+            vec![Tone::Light, Tone::Dark],
+            extract_tones(couple.grapheme).collect::<Vec<_>>()
+        );
+        // Equally toned couples use a single modifier
+        let couple = flat::COUPLE_WITH_HEART.tone((Tone::Medium, Tone::Medium));
+        assert_eq!(
+            vec![Tone::Medium],
+            extract_tones(couple.grapheme).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn emoji_starts() {
+        assert!(crate::starts::EMOJI_STARTS.windows(2).all(|w| w[0] < w[1]));
+        for emoji in all() {
+            let first = emoji.grapheme.chars().next().unwrap();
+            assert!(is_emoji_start(first));
+        }
+        assert!(!('a'..='z').any(is_emoji_start));
+    }
+
+    #[test]
+    fn supports_like_customizable() {
+        use crate::emojis::Customizable;
+
+        let supports = crate::supports::SUPPORTS;
+        assert!(supports.windows(2).all(|w| w[0].0 < w[1].0));
+        for emoji in crate::grouped::base_emojis() {
+            assert!(!supports_tone(emoji) || emoji.apply_tone(Tone::Dark).is_some());
+            assert!(!supports_hair(emoji) || emoji.apply_hair(Hair::Bald).is_some());
+        }
+        for &(grapheme, _) in supports {
+            assert!(lookup(grapheme).is_some());
+        }
+    }
+
+    #[test]
+    fn codepoint_prefix_like_filter() {
+        for prefix in &[
+            &['\u{1F9D1}'][..],
+            &['\u{1F9D1}', '\u{1F3FD}'],
+            &['1', '\u{FE0F}'],
+            &[],
+        ] {
+            let prefix_str: String = prefix.iter().collect();
+            let mut expected: Vec<_> = all()
+                .filter(|e| e.grapheme.starts_with(&prefix_str))
+                .collect();
+            let mut found: Vec<_> = by_codepoint_prefix(prefix).collect();
+            expected.sort_by_key(|e| e.grapheme);
+            found.sort_by_key(|e| e.grapheme);
+            assert_eq!(expected, found);
+        }
+    }
+
+    #[test]
+    fn minimally_qualified_lookup() {
+        let qualified = crate::qualified::MINIMALLY_QUALIFIED;
+        assert!(qualified.windows(2).all(|w| w[0].0 < w[1].0));
+        for &(grapheme, minimal) in qualified {
+            let emoji = all().find(|e| e.grapheme == grapheme).unwrap();
+            assert_eq!(Some(minimal), emoji.minimally_qualified());
+            assert_eq!(Some(emoji), lookup(minimal));
+            assert!(emoji.same_grapheme(minimal));
+        }
+    }
+
+    #[test]
+    fn lookup_variants() {
+        use crate::{Gender, Pair, Tone};
+
+        assert_eq!(Some(&crate::flat::ALIEN), lookup("👽"));
+        assert_eq!(
+            Some(&**crate::flat::TECHNOLOGIST.gender(Gender::Female)),
+            lookup("👩‍💻")
+        );
+        assert_eq!(
+            Some(crate::flat::FAMILY.gender(Pair::Mixed.with_children(Gender::Female))),
+            lookup("👨‍👩‍👧")
+        );
+        assert_eq!(
+            Some(crate::flat::COUPLE_WITH_HEART.tone((Tone::Light, Tone::Dark))),
+            lookup("🧑🏻‍❤️‍🧑🏿")
+        );
+    }
+
+    #[test]
+    fn lookup_none() {
+        assert_eq!(None, lookup(""));
+        assert_eq!(None, lookup("👽👽"));
+        assert_eq!(None, lookup(":alien:"));
+    }
+
+    #[test]
+    fn lookup_all() {
+        assert!(all().all(|e| lookup(e.grapheme) == Some(e)));
+    }
+
+    #[test]
+    fn from_hex_roundtrip() {
+        for emoji in all() {
+            let codepoints: Vec<char> = emoji.codepoints().collect();
+            assert_eq!(Some(emoji), from_codepoints(&codepoints));
+            assert_eq!(
+                Some(emoji),
+                from_hex(&emoji.codepoints_notation().to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn from_hex_no_signs() {
+        assert_eq!(Some(&crate::flat::CRAB), from_hex("1F980"));
+        for hex in ["+1F980", "U++1F980", "U+-1F980", "1F44B +1F3FF", "U+", "+"] {
+            assert_eq!(None, from_hex(hex), "{:?}", hex);
+        }
+    }
+
+    #[test]
+    fn lookup_unqualified() {
+        // Stripping the variation selectors must not make emojis ambiguous
+        for emoji in all() {
+            let unqualified: String = unqualified_chars(emoji.grapheme).collect();
+            assert_eq!(Some(emoji), lookup(&unqualified));
+        }
+        assert_eq!(Some(&crate::flat::HEART_SUIT), lookup("\u{2665}\u{FE0F}"));
+        assert_eq!(Some(&crate::flat::HEART_SUIT), lookup("\u{2665}"));
+    }
+
+    #[test]
+    fn lookup_prefix_longest() {
+        assert_eq!(Some(&crate::flat::ALIEN), lookup_prefix("👽👽"));
+        assert_eq!(Some("👨‍👩‍👧"), lookup_prefix("👨‍👩‍👧 & co").map(|e| e.grapheme));
+        assert_eq!(Some("👨"), lookup_prefix("👨 & co").map(|e| e.grapheme));
+        assert_eq!(None, lookup_prefix("co 👨"));
+    }
+
+    #[test]
+    fn toned_variants() {
+        use crate::flat;
+
+        assert_eq!(
+            Some(flat::WAVING_HAND.tone(Tone::Dark)),
+            toned(&flat::WAVING_HAND, Tone::Dark)
+        );
+        assert_eq!(
+            Some(flat::WAVING_HAND.tone(Tone::Light)),
+            toned(flat::WAVING_HAND.tone(Tone::Dark), Tone::Light)
+        );
+        assert_eq!(
+            Some("man: medium skin tone, red hair"),
+            toned(lookup("👨‍🦰").unwrap(), Tone::Medium).map(|e| e.name)
+        );
+        assert_eq!(
+            Some("🧑🏿‍🤝‍🧑🏿"),
+            toned(&flat::PERSON_HOLDING_HANDS, Tone::Dark).map(|e| e.grapheme)
+        );
+        assert_eq!(None, toned(&flat::CRAB, Tone::Dark));
+        // The skin tone components are emojis of their own, not toned variants
+        assert_eq!(None, toned(&flat::LIGHT_SKIN_TONE, Tone::Dark));
+    }
+
+    #[test]
+    fn group_of_test() {
+        use crate::grouped;
+
+        assert_eq!(
+            Some((grouped::flags::NAME, grouped::flags::country_flag::NAME)),
+            group_of(&crate::flat::FLAG_TURKEY)
+        );
+        assert_eq!(
+            Some(("Smileys & Emotion", "face-smiling")),
+            group_of(&crate::flat::GRINNING_FACE)
+        );
+        assert_eq!(
+            Some(("Travel & Places", "sky & weather")),
+            group_of(&crate::flat::SUN)
+        );
+        assert_eq!(
+            None,
+            group_of(&Emoji {
+                grapheme: "🦀🦀",
+                ..crate::flat::CRAB
+            })
+        );
+    }
+
+    #[test]
+    fn group_of_all() {
+        for (group, subgroup, variants) in crate::grouped::ALL_SUBGROUPS {
+            for e in variants.iter().flat_map(|v| v.iter()) {
+                assert_eq!(Some((*group, *subgroup)), group_of(e));
+            }
+        }
+    }
+
+    #[test]
+    fn version_histogram_matches_emojis() {
+        for &(version, count) in crate::version_histogram() {
+            assert_eq!(count, all().filter(|e| e.since == version).count());
+        }
+        let total: usize = crate::version_histogram().iter().map(|(_, n)| n).sum();
+        assert_eq!(all().count(), total);
+    }
+
+    #[test]
+    fn all_names_like_emojis() {
+        let mut names: Vec<&str> = all().map(|e| e.name).collect();
+        names.sort_unstable();
+        assert_eq!(crate::flat::ALL_NAMES, &names[..]);
+    }
+
+    #[test]
+    fn in_version_range_like_histogram() {
+        let histogram = crate::version_histogram();
+        let count = |range: core::ops::RangeInclusive<Version>| {
+            histogram
+                .iter()
+                .filter(|(v, _)| range.contains(v))
+                .map(|(_, n)| n)
+                .sum::<usize>()
+        };
+
+        for range in [
+            Version(12, 0)..=Version(13, 0),
+            Version::MIN..=Version(0, 7),
+            Version(13, 1)..=Version::MAX,
+            Version::MIN..=Version::MAX,
+        ] {
+            assert_eq!(count(range.clone()), in_version_range(range).count());
+        }
+        assert_eq!(0, in_version_range(Version(1, 0)..Version(1, 0)).count());
+    }
+
+    #[test]
+    fn emojis_of_14_0() {
+        use crate::flat;
+
+        assert_eq!(Version(14, 0), flat::MELTING_FACE.since);
+        assert_eq!("melting face", flat::MELTING_FACE.name);
+        assert_eq!(Some(&flat::MELTING_FACE), lookup("🫠"));
+        assert!(in_version_range(Version(14, 0)..).any(|e| e == &flat::MELTING_FACE));
+        assert!(flat::in_version_range(Version(14, 0)..).any(|e| e == &flat::MELTING_FACE));
+
+        assert_eq!("🫶🏿", flat::HEART_HANDS.tone(Tone::Dark).grapheme);
+        // Handshakes got skin tones in 14.0
+        let handshake = flat::HANDSHAKE.tone((Tone::Light, Tone::Dark));
+        assert_eq!("🫱🏻‍🫲🏿", handshake.grapheme);
+        assert_eq!(Version(14, 0), handshake.since);
+        assert_eq!(Version(3, 0), flat::HANDSHAKE.default.since);
+    }
+
+    #[test]
+    fn gendered_variants() {
+        use crate::flat;
+        use crate::Hair;
+
+        let red_hair = flat::PERSON.hair(Hair::Red);
+        assert_eq!(
+            Some(red_hair.gender(Gender::Male).tone(Tone::Dark)),
+            gendered(red_hair.tone(Tone::Dark), Gender::Male)
+        );
+        assert_eq!(
+            Some(&**flat::PERSON_FACEPALMING.gender(Gender::Female)),
+            gendered(
+                flat::PERSON_FACEPALMING.gender(Gender::Male),
+                Gender::Female
+            )
+        );
+        assert_eq!(
+            Some(flat::BOY.tone(Tone::Light)),
+            gendered(flat::CHILD.tone(Tone::Light), Gender::Male)
+        );
+        assert_eq!(None, gendered(&flat::CRAB, Gender::Male));
+        assert_eq!(None, gendered(&flat::RAISING_HANDS, Gender::Male));
+    }
+
+    #[test]
+    fn haired_variants() {
+        use crate::flat;
+
+        let woman = flat::PERSON.gender(Gender::Female);
+        assert_eq!(
+            Some(&**flat::PERSON.hair(Hair::Curly).gender(Gender::Female)),
+            haired(woman, Hair::Curly)
+        );
+        assert_eq!(
+            Some(
+                flat::PERSON
+                    .hair(Hair::Beard)
+                    .gender(Gender::Male)
+                    .tone(Tone::Dark)
+            ),
+            haired(
+                flat::PERSON
+                    .hair(Hair::Red)
+                    .gender(Gender::Male)
+                    .tone(Tone::Dark),
+                Hair::Beard
+            )
+        );
+        for &hair in Hair::ALL.iter() {
+            assert_eq!(
+                Some(&***flat::PERSON.hair(hair)),
+                haired(&flat::PERSON, hair)
+            );
+        }
+        assert_eq!(None, haired(&flat::CRAB, Hair::Bald));
+        assert_eq!(None, haired(&flat::PERSON_FACEPALMING, Hair::Bald));
+    }
+
+    #[test]
+    fn by_name_smile() {
+        let found: Vec<_> = by_name("smiling").map(|e| e.grapheme).collect();
+
+        assert!(found.contains(&"😊"));
+        assert!(found.contains(&"😄"));
+        assert!(found.contains(&"🙂"));
+    }
+
+    #[test]
+    fn by_name_shortest_first() {
+        let found: Vec<_> = by_name("waving hand").collect();
+
+        assert_eq!(&*crate::flat::WAVING_HAND, found[0]);
+        assert!(found.windows(2).all(|w| w[0].name.len() <= w[1].name.len()));
+        assert_eq!(6, found.len()); // default plus five skin tones
+    }
+
+    #[test]
+    fn by_name_constant_style() {
+        assert_eq!(
+            Some(&crate::flat::ALIEN_MONSTER),
+            by_name("ALIEN_MONSTER").next()
+        );
+    }
+
+    #[test]
+    fn by_name_empty() {
+        assert_eq!(all().count(), by_name("").count());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn query_ranking() {
+        let found = query("waving hand", 10);
+
+        assert_eq!((&*crate::flat::WAVING_HAND, 1.0), found[0]);
+        assert!(found[1..6].iter().all(|&(_, score)| score == 0.75));
+        assert!(found[1..6].windows(2).all(|w| w[0].0.name < w[1].0.name));
+        assert!(found.windows(2).all(|w| w[0].1 >= w[1].1));
+
+        assert_eq!(3, query("waving hand", 3).len());
+        assert!(query("", 10).is_empty());
+        assert!(query("no such emoji", 10).is_empty());
+
+        // Only matched by an alias
+        assert_eq!(
+            vec![(&*crate::flat::THUMBS_UP, 0.25)],
+            query("thumbsup", 10)
+        );
+    }
+
+    #[test]
+    fn by_name_nothing() {
+        assert_eq!(None, by_name("rustacean").next());
+    }
+
+    #[test]
+    fn base_of_variants() {
+        let bases: Vec<_> = crate::grouped::base_emojis().collect();
+
+        for variants in crate::grouped::all_variants() {
+            for variant in variants {
+                let base = base_of(variant).unwrap();
+                assert!(bases.contains(&base));
+                assert_eq!(Some(base), base_of(base));
+                assert!(variants.contains(&base));
+            }
+        }
+        // Every base emoji is its own base
+        for base in bases {
+            assert_eq!(Some(base), base_of(base));
+        }
+    }
+}
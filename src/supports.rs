@@ -0,0 +1,2136 @@
+// Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
+
+// Source: https://unicode.org/Public/emoji/15.1/emoji-test.txt (up to E14.0, names and groups of E13.1 kept)
+// Created at: 2026-10-16 13:54:59.246822878 UTC
+
+/// The emoji can be customized by skin tone, see [`crate::supports_tone`]
+pub(crate) const TONE: u8 = 1 << 0;
+/// The emoji can be customized by gender, see [`crate::supports_gender`]
+pub(crate) const GENDER: u8 = 1 << 1;
+/// The emoji can be customized by hair style, see [`crate::supports_hair`]
+pub(crate) const HAIR: u8 = 1 << 2;
+
+/// The customizations supported by the emojis (including all their variants), as flags of
+/// [`TONE`], [`GENDER`], and [`HAIR`], sorted by grapheme to allow binary searching.
+///
+/// Emojis which can not be customized at all are not listed.
+pub(crate) static SUPPORTS: &[(&str, u8)] = &[
+	("☝\u{fe0f}", TONE),
+	("☝🏻", TONE),
+	("☝🏼", TONE),
+	("☝🏽", TONE),
+	("☝🏾", TONE),
+	("☝🏿", TONE),
+	("⛹\u{fe0f}", GENDER | TONE),
+	("⛹\u{fe0f}\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("⛹\u{fe0f}\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("⛹🏻", GENDER | TONE),
+	("⛹🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("⛹🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("⛹🏼", GENDER | TONE),
+	("⛹🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("⛹🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("⛹🏽", GENDER | TONE),
+	("⛹🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("⛹🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("⛹🏾", GENDER | TONE),
+	("⛹🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("⛹🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("⛹🏿", GENDER | TONE),
+	("⛹🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("⛹🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("✊", TONE),
+	("✊🏻", TONE),
+	("✊🏼", TONE),
+	("✊🏽", TONE),
+	("✊🏾", TONE),
+	("✊🏿", TONE),
+	("✋", TONE),
+	("✋🏻", TONE),
+	("✋🏼", TONE),
+	("✋🏽", TONE),
+	("✋🏾", TONE),
+	("✋🏿", TONE),
+	("✌\u{fe0f}", TONE),
+	("✌🏻", TONE),
+	("✌🏼", TONE),
+	("✌🏽", TONE),
+	("✌🏾", TONE),
+	("✌🏿", TONE),
+	("✍\u{fe0f}", TONE),
+	("✍🏻", TONE),
+	("✍🏼", TONE),
+	("✍🏽", TONE),
+	("✍🏾", TONE),
+	("✍🏿", TONE),
+	("🎅", TONE),
+	("🎅🏻", TONE),
+	("🎅🏼", TONE),
+	("🎅🏽", TONE),
+	("🎅🏾", TONE),
+	("🎅🏿", TONE),
+	("🏂", TONE),
+	("🏂🏻", TONE),
+	("🏂🏼", TONE),
+	("🏂🏽", TONE),
+	("🏂🏾", TONE),
+	("🏂🏿", TONE),
+	("🏃", GENDER | TONE),
+	("🏃\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏃\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏃🏻", GENDER | TONE),
+	("🏃🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏃🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏃🏼", GENDER | TONE),
+	("🏃🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏃🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏃🏽", GENDER | TONE),
+	("🏃🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏃🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏃🏾", GENDER | TONE),
+	("🏃🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏃🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏃🏿", GENDER | TONE),
+	("🏃🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏃🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏄", GENDER | TONE),
+	("🏄\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏄\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏄🏻", GENDER | TONE),
+	("🏄🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏄🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏄🏼", GENDER | TONE),
+	("🏄🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏄🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏄🏽", GENDER | TONE),
+	("🏄🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏄🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏄🏾", GENDER | TONE),
+	("🏄🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏄🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏄🏿", GENDER | TONE),
+	("🏄🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏄🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏇", TONE),
+	("🏇🏻", TONE),
+	("🏇🏼", TONE),
+	("🏇🏽", TONE),
+	("🏇🏾", TONE),
+	("🏇🏿", TONE),
+	("🏊", GENDER | TONE),
+	("🏊\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏊\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏊🏻", GENDER | TONE),
+	("🏊🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏊🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏊🏼", GENDER | TONE),
+	("🏊🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏊🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏊🏽", GENDER | TONE),
+	("🏊🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏊🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏊🏾", GENDER | TONE),
+	("🏊🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏊🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏊🏿", GENDER | TONE),
+	("🏊🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏊🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏋\u{fe0f}", GENDER | TONE),
+	("🏋\u{fe0f}\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏋\u{fe0f}\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏋🏻", GENDER | TONE),
+	("🏋🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏋🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏋🏼", GENDER | TONE),
+	("🏋🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏋🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏋🏽", GENDER | TONE),
+	("🏋🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏋🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏋🏾", GENDER | TONE),
+	("🏋🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏋🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏋🏿", GENDER | TONE),
+	("🏋🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏋🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏌\u{fe0f}", GENDER | TONE),
+	("🏌\u{fe0f}\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏌\u{fe0f}\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏌🏻", GENDER | TONE),
+	("🏌🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏌🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏌🏼", GENDER | TONE),
+	("🏌🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏌🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏌🏽", GENDER | TONE),
+	("🏌🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏌🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏌🏾", GENDER | TONE),
+	("🏌🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏌🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🏌🏿", GENDER | TONE),
+	("🏌🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🏌🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👂", TONE),
+	("👂🏻", TONE),
+	("👂🏼", TONE),
+	("👂🏽", TONE),
+	("👂🏾", TONE),
+	("👂🏿", TONE),
+	("👃", TONE),
+	("👃🏻", TONE),
+	("👃🏼", TONE),
+	("👃🏽", TONE),
+	("👃🏾", TONE),
+	("👃🏿", TONE),
+	("👆", TONE),
+	("👆🏻", TONE),
+	("👆🏼", TONE),
+	("👆🏽", TONE),
+	("👆🏾", TONE),
+	("👆🏿", TONE),
+	("👇", TONE),
+	("👇🏻", TONE),
+	("👇🏼", TONE),
+	("👇🏽", TONE),
+	("👇🏾", TONE),
+	("👇🏿", TONE),
+	("👈", TONE),
+	("👈🏻", TONE),
+	("👈🏼", TONE),
+	("👈🏽", TONE),
+	("👈🏾", TONE),
+	("👈🏿", TONE),
+	("👉", TONE),
+	("👉🏻", TONE),
+	("👉🏼", TONE),
+	("👉🏽", TONE),
+	("👉🏾", TONE),
+	("👉🏿", TONE),
+	("👊", TONE),
+	("👊🏻", TONE),
+	("👊🏼", TONE),
+	("👊🏽", TONE),
+	("👊🏾", TONE),
+	("👊🏿", TONE),
+	("👋", TONE),
+	("👋🏻", TONE),
+	("👋🏼", TONE),
+	("👋🏽", TONE),
+	("👋🏾", TONE),
+	("👋🏿", TONE),
+	("👌", TONE),
+	("👌🏻", TONE),
+	("👌🏼", TONE),
+	("👌🏽", TONE),
+	("👌🏾", TONE),
+	("👌🏿", TONE),
+	("👍", TONE),
+	("👍🏻", TONE),
+	("👍🏼", TONE),
+	("👍🏽", TONE),
+	("👍🏾", TONE),
+	("👍🏿", TONE),
+	("👎", TONE),
+	("👎🏻", TONE),
+	("👎🏼", TONE),
+	("👎🏽", TONE),
+	("👎🏾", TONE),
+	("👎🏿", TONE),
+	("👏", TONE),
+	("👏🏻", TONE),
+	("👏🏼", TONE),
+	("👏🏽", TONE),
+	("👏🏾", TONE),
+	("👏🏿", TONE),
+	("👐", TONE),
+	("👐🏻", TONE),
+	("👐🏼", TONE),
+	("👐🏽", TONE),
+	("👐🏾", TONE),
+	("👐🏿", TONE),
+	("👦", TONE),
+	("👦🏻", TONE),
+	("👦🏼", TONE),
+	("👦🏽", TONE),
+	("👦🏾", TONE),
+	("👦🏿", TONE),
+	("👧", TONE),
+	("👧🏻", TONE),
+	("👧🏼", TONE),
+	("👧🏽", TONE),
+	("👧🏾", TONE),
+	("👧🏿", TONE),
+	("👨", HAIR | GENDER | TONE),
+	("👨\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("👨\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("👨\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("👨\u{200d}❤\u{fe0f}\u{200d}👨", GENDER | TONE),
+	("👨\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨", GENDER | TONE),
+	("👨\u{200d}🌾", GENDER | TONE),
+	("👨\u{200d}🍳", GENDER | TONE),
+	("👨\u{200d}🍼", GENDER | TONE),
+	("👨\u{200d}🎓", GENDER | TONE),
+	("👨\u{200d}🎤", GENDER | TONE),
+	("👨\u{200d}🎨", GENDER | TONE),
+	("👨\u{200d}🏫", GENDER | TONE),
+	("👨\u{200d}🏭", GENDER | TONE),
+	("👨\u{200d}👦", GENDER),
+	("👨\u{200d}👦\u{200d}👦", GENDER),
+	("👨\u{200d}👧", GENDER),
+	("👨\u{200d}👧\u{200d}👦", GENDER),
+	("👨\u{200d}👧\u{200d}👧", GENDER),
+	("👨\u{200d}👨\u{200d}👦", GENDER),
+	("👨\u{200d}👨\u{200d}👦\u{200d}👦", GENDER),
+	("👨\u{200d}👨\u{200d}👧", GENDER),
+	("👨\u{200d}👨\u{200d}👧\u{200d}👦", GENDER),
+	("👨\u{200d}👨\u{200d}👧\u{200d}👧", GENDER),
+	("👨\u{200d}👩\u{200d}👦", GENDER),
+	("👨\u{200d}👩\u{200d}👦\u{200d}👦", GENDER),
+	("👨\u{200d}👩\u{200d}👧", GENDER),
+	("👨\u{200d}👩\u{200d}👧\u{200d}👦", GENDER),
+	("👨\u{200d}👩\u{200d}👧\u{200d}👧", GENDER),
+	("👨\u{200d}💻", GENDER | TONE),
+	("👨\u{200d}💼", GENDER | TONE),
+	("👨\u{200d}🔧", GENDER | TONE),
+	("👨\u{200d}🔬", GENDER | TONE),
+	("👨\u{200d}🚀", GENDER | TONE),
+	("👨\u{200d}🚒", GENDER | TONE),
+	("👨\u{200d}🦯", GENDER | TONE),
+	("👨\u{200d}🦰", HAIR | GENDER | TONE),
+	("👨\u{200d}🦱", HAIR | GENDER | TONE),
+	("👨\u{200d}🦲", HAIR | GENDER | TONE),
+	("👨\u{200d}🦳", HAIR | GENDER | TONE),
+	("👨\u{200d}🦼", GENDER | TONE),
+	("👨\u{200d}🦽", GENDER | TONE),
+	("👨🏻", HAIR | GENDER | TONE),
+	("👨🏻\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("👨🏻\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("👨🏻\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏻", GENDER | TONE),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏼", GENDER | TONE),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏽", GENDER | TONE),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏾", GENDER | TONE),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏿", GENDER | TONE),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", GENDER | TONE),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", GENDER | TONE),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", GENDER | TONE),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", GENDER | TONE),
+	("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", GENDER | TONE),
+	("👨🏻\u{200d}🌾", GENDER | TONE),
+	("👨🏻\u{200d}🍳", GENDER | TONE),
+	("👨🏻\u{200d}🍼", GENDER | TONE),
+	("👨🏻\u{200d}🎓", GENDER | TONE),
+	("👨🏻\u{200d}🎤", GENDER | TONE),
+	("👨🏻\u{200d}🎨", GENDER | TONE),
+	("👨🏻\u{200d}🏫", GENDER | TONE),
+	("👨🏻\u{200d}🏭", GENDER | TONE),
+	("👨🏻\u{200d}💻", GENDER | TONE),
+	("👨🏻\u{200d}💼", GENDER | TONE),
+	("👨🏻\u{200d}🔧", GENDER | TONE),
+	("👨🏻\u{200d}🔬", GENDER | TONE),
+	("👨🏻\u{200d}🚀", GENDER | TONE),
+	("👨🏻\u{200d}🚒", GENDER | TONE),
+	("👨🏻\u{200d}🤝\u{200d}👨🏼", GENDER | TONE),
+	("👨🏻\u{200d}🤝\u{200d}👨🏽", GENDER | TONE),
+	("👨🏻\u{200d}🤝\u{200d}👨🏾", GENDER | TONE),
+	("👨🏻\u{200d}🤝\u{200d}👨🏿", GENDER | TONE),
+	("👨🏻\u{200d}🦯", GENDER | TONE),
+	("👨🏻\u{200d}🦰", HAIR | GENDER | TONE),
+	("👨🏻\u{200d}🦱", HAIR | GENDER | TONE),
+	("👨🏻\u{200d}🦲", HAIR | GENDER | TONE),
+	("👨🏻\u{200d}🦳", HAIR | GENDER | TONE),
+	("👨🏻\u{200d}🦼", GENDER | TONE),
+	("👨🏻\u{200d}🦽", GENDER | TONE),
+	("👨🏼", HAIR | GENDER | TONE),
+	("👨🏼\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("👨🏼\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("👨🏼\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏻", GENDER | TONE),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏼", GENDER | TONE),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏽", GENDER | TONE),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏾", GENDER | TONE),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏿", GENDER | TONE),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", GENDER | TONE),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", GENDER | TONE),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", GENDER | TONE),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", GENDER | TONE),
+	("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", GENDER | TONE),
+	("👨🏼\u{200d}🌾", GENDER | TONE),
+	("👨🏼\u{200d}🍳", GENDER | TONE),
+	("👨🏼\u{200d}🍼", GENDER | TONE),
+	("👨🏼\u{200d}🎓", GENDER | TONE),
+	("👨🏼\u{200d}🎤", GENDER | TONE),
+	("👨🏼\u{200d}🎨", GENDER | TONE),
+	("👨🏼\u{200d}🏫", GENDER | TONE),
+	("👨🏼\u{200d}🏭", GENDER | TONE),
+	("👨🏼\u{200d}💻", GENDER | TONE),
+	("👨🏼\u{200d}💼", GENDER | TONE),
+	("👨🏼\u{200d}🔧", GENDER | TONE),
+	("👨🏼\u{200d}🔬", GENDER | TONE),
+	("👨🏼\u{200d}🚀", GENDER | TONE),
+	("👨🏼\u{200d}🚒", GENDER | TONE),
+	("👨🏼\u{200d}🤝\u{200d}👨🏻", GENDER | TONE),
+	("👨🏼\u{200d}🤝\u{200d}👨🏽", GENDER | TONE),
+	("👨🏼\u{200d}🤝\u{200d}👨🏾", GENDER | TONE),
+	("👨🏼\u{200d}🤝\u{200d}👨🏿", GENDER | TONE),
+	("👨🏼\u{200d}🦯", GENDER | TONE),
+	("👨🏼\u{200d}🦰", HAIR | GENDER | TONE),
+	("👨🏼\u{200d}🦱", HAIR | GENDER | TONE),
+	("👨🏼\u{200d}🦲", HAIR | GENDER | TONE),
+	("👨🏼\u{200d}🦳", HAIR | GENDER | TONE),
+	("👨🏼\u{200d}🦼", GENDER | TONE),
+	("👨🏼\u{200d}🦽", GENDER | TONE),
+	("👨🏽", HAIR | GENDER | TONE),
+	("👨🏽\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("👨🏽\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("👨🏽\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏻", GENDER | TONE),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏼", GENDER | TONE),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏽", GENDER | TONE),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏾", GENDER | TONE),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿", GENDER | TONE),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", GENDER | TONE),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", GENDER | TONE),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", GENDER | TONE),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", GENDER | TONE),
+	("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", GENDER | TONE),
+	("👨🏽\u{200d}🌾", GENDER | TONE),
+	("👨🏽\u{200d}🍳", GENDER | TONE),
+	("👨🏽\u{200d}🍼", GENDER | TONE),
+	("👨🏽\u{200d}🎓", GENDER | TONE),
+	("👨🏽\u{200d}🎤", GENDER | TONE),
+	("👨🏽\u{200d}🎨", GENDER | TONE),
+	("👨🏽\u{200d}🏫", GENDER | TONE),
+	("👨🏽\u{200d}🏭", GENDER | TONE),
+	("👨🏽\u{200d}💻", GENDER | TONE),
+	("👨🏽\u{200d}💼", GENDER | TONE),
+	("👨🏽\u{200d}🔧", GENDER | TONE),
+	("👨🏽\u{200d}🔬", GENDER | TONE),
+	("👨🏽\u{200d}🚀", GENDER | TONE),
+	("👨🏽\u{200d}🚒", GENDER | TONE),
+	("👨🏽\u{200d}🤝\u{200d}👨🏻", GENDER | TONE),
+	("👨🏽\u{200d}🤝\u{200d}👨🏼", GENDER | TONE),
+	("👨🏽\u{200d}🤝\u{200d}👨🏾", GENDER | TONE),
+	("👨🏽\u{200d}🤝\u{200d}👨🏿", GENDER | TONE),
+	("👨🏽\u{200d}🦯", GENDER | TONE),
+	("👨🏽\u{200d}🦰", HAIR | GENDER | TONE),
+	("👨🏽\u{200d}🦱", HAIR | GENDER | TONE),
+	("👨🏽\u{200d}🦲", HAIR | GENDER | TONE),
+	("👨🏽\u{200d}🦳", HAIR | GENDER | TONE),
+	("👨🏽\u{200d}🦼", GENDER | TONE),
+	("👨🏽\u{200d}🦽", GENDER | TONE),
+	("👨🏾", HAIR | GENDER | TONE),
+	("👨🏾\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("👨🏾\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("👨🏾\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏻", GENDER | TONE),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏼", GENDER | TONE),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏽", GENDER | TONE),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏾", GENDER | TONE),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏿", GENDER | TONE),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", GENDER | TONE),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", GENDER | TONE),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", GENDER | TONE),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", GENDER | TONE),
+	("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", GENDER | TONE),
+	("👨🏾\u{200d}🌾", GENDER | TONE),
+	("👨🏾\u{200d}🍳", GENDER | TONE),
+	("👨🏾\u{200d}🍼", GENDER | TONE),
+	("👨🏾\u{200d}🎓", GENDER | TONE),
+	("👨🏾\u{200d}🎤", GENDER | TONE),
+	("👨🏾\u{200d}🎨", GENDER | TONE),
+	("👨🏾\u{200d}🏫", GENDER | TONE),
+	("👨🏾\u{200d}🏭", GENDER | TONE),
+	("👨🏾\u{200d}💻", GENDER | TONE),
+	("👨🏾\u{200d}💼", GENDER | TONE),
+	("👨🏾\u{200d}🔧", GENDER | TONE),
+	("👨🏾\u{200d}🔬", GENDER | TONE),
+	("👨🏾\u{200d}🚀", GENDER | TONE),
+	("👨🏾\u{200d}🚒", GENDER | TONE),
+	("👨🏾\u{200d}🤝\u{200d}👨🏻", GENDER | TONE),
+	("👨🏾\u{200d}🤝\u{200d}👨🏼", GENDER | TONE),
+	("👨🏾\u{200d}🤝\u{200d}👨🏽", GENDER | TONE),
+	("👨🏾\u{200d}🤝\u{200d}👨🏿", GENDER | TONE),
+	("👨🏾\u{200d}🦯", GENDER | TONE),
+	("👨🏾\u{200d}🦰", HAIR | GENDER | TONE),
+	("👨🏾\u{200d}🦱", HAIR | GENDER | TONE),
+	("👨🏾\u{200d}🦲", HAIR | GENDER | TONE),
+	("👨🏾\u{200d}🦳", HAIR | GENDER | TONE),
+	("👨🏾\u{200d}🦼", GENDER | TONE),
+	("👨🏾\u{200d}🦽", GENDER | TONE),
+	("👨🏿", HAIR | GENDER | TONE),
+	("👨🏿\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("👨🏿\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("👨🏿\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏻", GENDER | TONE),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏼", GENDER | TONE),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏽", GENDER | TONE),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏾", GENDER | TONE),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏿", GENDER | TONE),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", GENDER | TONE),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", GENDER | TONE),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", GENDER | TONE),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", GENDER | TONE),
+	("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", GENDER | TONE),
+	("👨🏿\u{200d}🌾", GENDER | TONE),
+	("👨🏿\u{200d}🍳", GENDER | TONE),
+	("👨🏿\u{200d}🍼", GENDER | TONE),
+	("👨🏿\u{200d}🎓", GENDER | TONE),
+	("👨🏿\u{200d}🎤", GENDER | TONE),
+	("👨🏿\u{200d}🎨", GENDER | TONE),
+	("👨🏿\u{200d}🏫", GENDER | TONE),
+	("👨🏿\u{200d}🏭", GENDER | TONE),
+	("👨🏿\u{200d}💻", GENDER | TONE),
+	("👨🏿\u{200d}💼", GENDER | TONE),
+	("👨🏿\u{200d}🔧", GENDER | TONE),
+	("👨🏿\u{200d}🔬", GENDER | TONE),
+	("👨🏿\u{200d}🚀", GENDER | TONE),
+	("👨🏿\u{200d}🚒", GENDER | TONE),
+	("👨🏿\u{200d}🤝\u{200d}👨🏻", GENDER | TONE),
+	("👨🏿\u{200d}🤝\u{200d}👨🏼", GENDER | TONE),
+	("👨🏿\u{200d}🤝\u{200d}👨🏽", GENDER | TONE),
+	("👨🏿\u{200d}🤝\u{200d}👨🏾", GENDER | TONE),
+	("👨🏿\u{200d}🦯", GENDER | TONE),
+	("👨🏿\u{200d}🦰", HAIR | GENDER | TONE),
+	("👨🏿\u{200d}🦱", HAIR | GENDER | TONE),
+	("👨🏿\u{200d}🦲", HAIR | GENDER | TONE),
+	("👨🏿\u{200d}🦳", HAIR | GENDER | TONE),
+	("👨🏿\u{200d}🦼", GENDER | TONE),
+	("👨🏿\u{200d}🦽", GENDER | TONE),
+	("👩", HAIR | GENDER | TONE),
+	("👩\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("👩\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("👩\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("👩\u{200d}❤\u{fe0f}\u{200d}👨", GENDER | TONE),
+	("👩\u{200d}❤\u{fe0f}\u{200d}👩", GENDER | TONE),
+	("👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨", GENDER | TONE),
+	("👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩", GENDER | TONE),
+	("👩\u{200d}🌾", GENDER | TONE),
+	("👩\u{200d}🍳", GENDER | TONE),
+	("👩\u{200d}🍼", GENDER | TONE),
+	("👩\u{200d}🎓", GENDER | TONE),
+	("👩\u{200d}🎤", GENDER | TONE),
+	("👩\u{200d}🎨", GENDER | TONE),
+	("👩\u{200d}🏫", GENDER | TONE),
+	("👩\u{200d}🏭", GENDER | TONE),
+	("👩\u{200d}👦", GENDER),
+	("👩\u{200d}👦\u{200d}👦", GENDER),
+	("👩\u{200d}👧", GENDER),
+	("👩\u{200d}👧\u{200d}👦", GENDER),
+	("👩\u{200d}👧\u{200d}👧", GENDER),
+	("👩\u{200d}👩\u{200d}👦", GENDER),
+	("👩\u{200d}👩\u{200d}👦\u{200d}👦", GENDER),
+	("👩\u{200d}👩\u{200d}👧", GENDER),
+	("👩\u{200d}👩\u{200d}👧\u{200d}👦", GENDER),
+	("👩\u{200d}👩\u{200d}👧\u{200d}👧", GENDER),
+	("👩\u{200d}💻", GENDER | TONE),
+	("👩\u{200d}💼", GENDER | TONE),
+	("👩\u{200d}🔧", GENDER | TONE),
+	("👩\u{200d}🔬", GENDER | TONE),
+	("👩\u{200d}🚀", GENDER | TONE),
+	("👩\u{200d}🚒", GENDER | TONE),
+	("👩\u{200d}🦯", GENDER | TONE),
+	("👩\u{200d}🦰", HAIR | GENDER | TONE),
+	("👩\u{200d}🦱", HAIR | GENDER | TONE),
+	("👩\u{200d}🦲", HAIR | GENDER | TONE),
+	("👩\u{200d}🦳", HAIR | GENDER | TONE),
+	("👩\u{200d}🦼", GENDER | TONE),
+	("👩\u{200d}🦽", GENDER | TONE),
+	("👩🏻", HAIR | GENDER | TONE),
+	("👩🏻\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("👩🏻\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("👩🏻\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏻", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏼", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏽", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏾", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏿", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏻", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏼", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏽", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏾", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏿", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", GENDER | TONE),
+	("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", GENDER | TONE),
+	("👩🏻\u{200d}🌾", GENDER | TONE),
+	("👩🏻\u{200d}🍳", GENDER | TONE),
+	("👩🏻\u{200d}🍼", GENDER | TONE),
+	("👩🏻\u{200d}🎓", GENDER | TONE),
+	("👩🏻\u{200d}🎤", GENDER | TONE),
+	("👩🏻\u{200d}🎨", GENDER | TONE),
+	("👩🏻\u{200d}🏫", GENDER | TONE),
+	("👩🏻\u{200d}🏭", GENDER | TONE),
+	("👩🏻\u{200d}💻", GENDER | TONE),
+	("👩🏻\u{200d}💼", GENDER | TONE),
+	("👩🏻\u{200d}🔧", GENDER | TONE),
+	("👩🏻\u{200d}🔬", GENDER | TONE),
+	("👩🏻\u{200d}🚀", GENDER | TONE),
+	("👩🏻\u{200d}🚒", GENDER | TONE),
+	("👩🏻\u{200d}🤝\u{200d}👨🏼", GENDER | TONE),
+	("👩🏻\u{200d}🤝\u{200d}👨🏽", GENDER | TONE),
+	("👩🏻\u{200d}🤝\u{200d}👨🏾", GENDER | TONE),
+	("👩🏻\u{200d}🤝\u{200d}👨🏿", GENDER | TONE),
+	("👩🏻\u{200d}🤝\u{200d}👩🏼", GENDER | TONE),
+	("👩🏻\u{200d}🤝\u{200d}👩🏽", GENDER | TONE),
+	("👩🏻\u{200d}🤝\u{200d}👩🏾", GENDER | TONE),
+	("👩🏻\u{200d}🤝\u{200d}👩🏿", GENDER | TONE),
+	("👩🏻\u{200d}🦯", GENDER | TONE),
+	("👩🏻\u{200d}🦰", HAIR | GENDER | TONE),
+	("👩🏻\u{200d}🦱", HAIR | GENDER | TONE),
+	("👩🏻\u{200d}🦲", HAIR | GENDER | TONE),
+	("👩🏻\u{200d}🦳", HAIR | GENDER | TONE),
+	("👩🏻\u{200d}🦼", GENDER | TONE),
+	("👩🏻\u{200d}🦽", GENDER | TONE),
+	("👩🏼", HAIR | GENDER | TONE),
+	("👩🏼\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("👩🏼\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("👩🏼\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏻", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏼", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏽", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏾", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏿", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏻", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏼", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏽", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏾", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏿", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", GENDER | TONE),
+	("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", GENDER | TONE),
+	("👩🏼\u{200d}🌾", GENDER | TONE),
+	("👩🏼\u{200d}🍳", GENDER | TONE),
+	("👩🏼\u{200d}🍼", GENDER | TONE),
+	("👩🏼\u{200d}🎓", GENDER | TONE),
+	("👩🏼\u{200d}🎤", GENDER | TONE),
+	("👩🏼\u{200d}🎨", GENDER | TONE),
+	("👩🏼\u{200d}🏫", GENDER | TONE),
+	("👩🏼\u{200d}🏭", GENDER | TONE),
+	("👩🏼\u{200d}💻", GENDER | TONE),
+	("👩🏼\u{200d}💼", GENDER | TONE),
+	("👩🏼\u{200d}🔧", GENDER | TONE),
+	("👩🏼\u{200d}🔬", GENDER | TONE),
+	("👩🏼\u{200d}🚀", GENDER | TONE),
+	("👩🏼\u{200d}🚒", GENDER | TONE),
+	("👩🏼\u{200d}🤝\u{200d}👨🏻", GENDER | TONE),
+	("👩🏼\u{200d}🤝\u{200d}👨🏽", GENDER | TONE),
+	("👩🏼\u{200d}🤝\u{200d}👨🏾", GENDER | TONE),
+	("👩🏼\u{200d}🤝\u{200d}👨🏿", GENDER | TONE),
+	("👩🏼\u{200d}🤝\u{200d}👩🏻", GENDER | TONE),
+	("👩🏼\u{200d}🤝\u{200d}👩🏽", GENDER | TONE),
+	("👩🏼\u{200d}🤝\u{200d}👩🏾", GENDER | TONE),
+	("👩🏼\u{200d}🤝\u{200d}👩🏿", GENDER | TONE),
+	("👩🏼\u{200d}🦯", GENDER | TONE),
+	("👩🏼\u{200d}🦰", HAIR | GENDER | TONE),
+	("👩🏼\u{200d}🦱", HAIR | GENDER | TONE),
+	("👩🏼\u{200d}🦲", HAIR | GENDER | TONE),
+	("👩🏼\u{200d}🦳", HAIR | GENDER | TONE),
+	("👩🏼\u{200d}🦼", GENDER | TONE),
+	("👩🏼\u{200d}🦽", GENDER | TONE),
+	("👩🏽", HAIR | GENDER | TONE),
+	("👩🏽\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("👩🏽\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("👩🏽\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏻", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏼", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏽", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏾", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏻", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏼", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏽", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏾", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏿", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", GENDER | TONE),
+	("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", GENDER | TONE),
+	("👩🏽\u{200d}🌾", GENDER | TONE),
+	("👩🏽\u{200d}🍳", GENDER | TONE),
+	("👩🏽\u{200d}🍼", GENDER | TONE),
+	("👩🏽\u{200d}🎓", GENDER | TONE),
+	("👩🏽\u{200d}🎤", GENDER | TONE),
+	("👩🏽\u{200d}🎨", GENDER | TONE),
+	("👩🏽\u{200d}🏫", GENDER | TONE),
+	("👩🏽\u{200d}🏭", GENDER | TONE),
+	("👩🏽\u{200d}💻", GENDER | TONE),
+	("👩🏽\u{200d}💼", GENDER | TONE),
+	("👩🏽\u{200d}🔧", GENDER | TONE),
+	("👩🏽\u{200d}🔬", GENDER | TONE),
+	("👩🏽\u{200d}🚀", GENDER | TONE),
+	("👩🏽\u{200d}🚒", GENDER | TONE),
+	("👩🏽\u{200d}🤝\u{200d}👨🏻", GENDER | TONE),
+	("👩🏽\u{200d}🤝\u{200d}👨🏼", GENDER | TONE),
+	("👩🏽\u{200d}🤝\u{200d}👨🏾", GENDER | TONE),
+	("👩🏽\u{200d}🤝\u{200d}👨🏿", GENDER | TONE),
+	("👩🏽\u{200d}🤝\u{200d}👩🏻", GENDER | TONE),
+	("👩🏽\u{200d}🤝\u{200d}👩🏼", GENDER | TONE),
+	("👩🏽\u{200d}🤝\u{200d}👩🏾", GENDER | TONE),
+	("👩🏽\u{200d}🤝\u{200d}👩🏿", GENDER | TONE),
+	("👩🏽\u{200d}🦯", GENDER | TONE),
+	("👩🏽\u{200d}🦰", HAIR | GENDER | TONE),
+	("👩🏽\u{200d}🦱", HAIR | GENDER | TONE),
+	("👩🏽\u{200d}🦲", HAIR | GENDER | TONE),
+	("👩🏽\u{200d}🦳", HAIR | GENDER | TONE),
+	("👩🏽\u{200d}🦼", GENDER | TONE),
+	("👩🏽\u{200d}🦽", GENDER | TONE),
+	("👩🏾", HAIR | GENDER | TONE),
+	("👩🏾\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("👩🏾\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("👩🏾\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏻", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏼", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏽", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏾", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏿", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏻", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏼", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏽", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏾", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏿", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", GENDER | TONE),
+	("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", GENDER | TONE),
+	("👩🏾\u{200d}🌾", GENDER | TONE),
+	("👩🏾\u{200d}🍳", GENDER | TONE),
+	("👩🏾\u{200d}🍼", GENDER | TONE),
+	("👩🏾\u{200d}🎓", GENDER | TONE),
+	("👩🏾\u{200d}🎤", GENDER | TONE),
+	("👩🏾\u{200d}🎨", GENDER | TONE),
+	("👩🏾\u{200d}🏫", GENDER | TONE),
+	("👩🏾\u{200d}🏭", GENDER | TONE),
+	("👩🏾\u{200d}💻", GENDER | TONE),
+	("👩🏾\u{200d}💼", GENDER | TONE),
+	("👩🏾\u{200d}🔧", GENDER | TONE),
+	("👩🏾\u{200d}🔬", GENDER | TONE),
+	("👩🏾\u{200d}🚀", GENDER | TONE),
+	("👩🏾\u{200d}🚒", GENDER | TONE),
+	("👩🏾\u{200d}🤝\u{200d}👨🏻", GENDER | TONE),
+	("👩🏾\u{200d}🤝\u{200d}👨🏼", GENDER | TONE),
+	("👩🏾\u{200d}🤝\u{200d}👨🏽", GENDER | TONE),
+	("👩🏾\u{200d}🤝\u{200d}👨🏿", GENDER | TONE),
+	("👩🏾\u{200d}🤝\u{200d}👩🏻", GENDER | TONE),
+	("👩🏾\u{200d}🤝\u{200d}👩🏼", GENDER | TONE),
+	("👩🏾\u{200d}🤝\u{200d}👩🏽", GENDER | TONE),
+	("👩🏾\u{200d}🤝\u{200d}👩🏿", GENDER | TONE),
+	("👩🏾\u{200d}🦯", GENDER | TONE),
+	("👩🏾\u{200d}🦰", HAIR | GENDER | TONE),
+	("👩🏾\u{200d}🦱", HAIR | GENDER | TONE),
+	("👩🏾\u{200d}🦲", HAIR | GENDER | TONE),
+	("👩🏾\u{200d}🦳", HAIR | GENDER | TONE),
+	("👩🏾\u{200d}🦼", GENDER | TONE),
+	("👩🏾\u{200d}🦽", GENDER | TONE),
+	("👩🏿", HAIR | GENDER | TONE),
+	("👩🏿\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("👩🏿\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("👩🏿\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏻", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏼", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏽", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏾", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏿", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏻", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏼", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏽", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏾", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏿", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", GENDER | TONE),
+	("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", GENDER | TONE),
+	("👩🏿\u{200d}🌾", GENDER | TONE),
+	("👩🏿\u{200d}🍳", GENDER | TONE),
+	("👩🏿\u{200d}🍼", GENDER | TONE),
+	("👩🏿\u{200d}🎓", GENDER | TONE),
+	("👩🏿\u{200d}🎤", GENDER | TONE),
+	("👩🏿\u{200d}🎨", GENDER | TONE),
+	("👩🏿\u{200d}🏫", GENDER | TONE),
+	("👩🏿\u{200d}🏭", GENDER | TONE),
+	("👩🏿\u{200d}💻", GENDER | TONE),
+	("👩🏿\u{200d}💼", GENDER | TONE),
+	("👩🏿\u{200d}🔧", GENDER | TONE),
+	("👩🏿\u{200d}🔬", GENDER | TONE),
+	("👩🏿\u{200d}🚀", GENDER | TONE),
+	("👩🏿\u{200d}🚒", GENDER | TONE),
+	("👩🏿\u{200d}🤝\u{200d}👨🏻", GENDER | TONE),
+	("👩🏿\u{200d}🤝\u{200d}👨🏼", GENDER | TONE),
+	("👩🏿\u{200d}🤝\u{200d}👨🏽", GENDER | TONE),
+	("👩🏿\u{200d}🤝\u{200d}👨🏾", GENDER | TONE),
+	("👩🏿\u{200d}🤝\u{200d}👩🏻", GENDER | TONE),
+	("👩🏿\u{200d}🤝\u{200d}👩🏼", GENDER | TONE),
+	("👩🏿\u{200d}🤝\u{200d}👩🏽", GENDER | TONE),
+	("👩🏿\u{200d}🤝\u{200d}👩🏾", GENDER | TONE),
+	("👩🏿\u{200d}🦯", GENDER | TONE),
+	("👩🏿\u{200d}🦰", HAIR | GENDER | TONE),
+	("👩🏿\u{200d}🦱", HAIR | GENDER | TONE),
+	("👩🏿\u{200d}🦲", HAIR | GENDER | TONE),
+	("👩🏿\u{200d}🦳", HAIR | GENDER | TONE),
+	("👩🏿\u{200d}🦼", GENDER | TONE),
+	("👩🏿\u{200d}🦽", GENDER | TONE),
+	("👪", GENDER),
+	("👫", GENDER | TONE),
+	("👫🏻", GENDER | TONE),
+	("👫🏼", GENDER | TONE),
+	("👫🏽", GENDER | TONE),
+	("👫🏾", GENDER | TONE),
+	("👫🏿", GENDER | TONE),
+	("👬", GENDER | TONE),
+	("👬🏻", GENDER | TONE),
+	("👬🏼", GENDER | TONE),
+	("👬🏽", GENDER | TONE),
+	("👬🏾", GENDER | TONE),
+	("👬🏿", GENDER | TONE),
+	("👭", GENDER | TONE),
+	("👭🏻", GENDER | TONE),
+	("👭🏼", GENDER | TONE),
+	("👭🏽", GENDER | TONE),
+	("👭🏾", GENDER | TONE),
+	("👭🏿", GENDER | TONE),
+	("👮", GENDER | TONE),
+	("👮\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👮\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👮🏻", GENDER | TONE),
+	("👮🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👮🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👮🏼", GENDER | TONE),
+	("👮🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👮🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👮🏽", GENDER | TONE),
+	("👮🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👮🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👮🏾", GENDER | TONE),
+	("👮🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👮🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👮🏿", GENDER | TONE),
+	("👮🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👮🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👯", GENDER),
+	("👯\u{200d}♀\u{fe0f}", GENDER),
+	("👯\u{200d}♂\u{fe0f}", GENDER),
+	("👰", GENDER | TONE),
+	("👰\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👰\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👰🏻", GENDER | TONE),
+	("👰🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👰🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👰🏼", GENDER | TONE),
+	("👰🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👰🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👰🏽", GENDER | TONE),
+	("👰🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👰🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👰🏾", GENDER | TONE),
+	("👰🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👰🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👰🏿", GENDER | TONE),
+	("👰🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👰🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👱", HAIR | GENDER | TONE),
+	("👱\u{200d}♀\u{fe0f}", HAIR | GENDER | TONE),
+	("👱\u{200d}♂\u{fe0f}", HAIR | GENDER | TONE),
+	("👱🏻", HAIR | GENDER | TONE),
+	("👱🏻\u{200d}♀\u{fe0f}", HAIR | GENDER | TONE),
+	("👱🏻\u{200d}♂\u{fe0f}", HAIR | GENDER | TONE),
+	("👱🏼", HAIR | GENDER | TONE),
+	("👱🏼\u{200d}♀\u{fe0f}", HAIR | GENDER | TONE),
+	("👱🏼\u{200d}♂\u{fe0f}", HAIR | GENDER | TONE),
+	("👱🏽", HAIR | GENDER | TONE),
+	("👱🏽\u{200d}♀\u{fe0f}", HAIR | GENDER | TONE),
+	("👱🏽\u{200d}♂\u{fe0f}", HAIR | GENDER | TONE),
+	("👱🏾", HAIR | GENDER | TONE),
+	("👱🏾\u{200d}♀\u{fe0f}", HAIR | GENDER | TONE),
+	("👱🏾\u{200d}♂\u{fe0f}", HAIR | GENDER | TONE),
+	("👱🏿", HAIR | GENDER | TONE),
+	("👱🏿\u{200d}♀\u{fe0f}", HAIR | GENDER | TONE),
+	("👱🏿\u{200d}♂\u{fe0f}", HAIR | GENDER | TONE),
+	("👲", TONE),
+	("👲🏻", TONE),
+	("👲🏼", TONE),
+	("👲🏽", TONE),
+	("👲🏾", TONE),
+	("👲🏿", TONE),
+	("👳", GENDER | TONE),
+	("👳\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👳\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👳🏻", GENDER | TONE),
+	("👳🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👳🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👳🏼", GENDER | TONE),
+	("👳🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👳🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👳🏽", GENDER | TONE),
+	("👳🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👳🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👳🏾", GENDER | TONE),
+	("👳🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👳🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👳🏿", GENDER | TONE),
+	("👳🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👳🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👴", GENDER | TONE),
+	("👴🏻", GENDER | TONE),
+	("👴🏼", GENDER | TONE),
+	("👴🏽", GENDER | TONE),
+	("👴🏾", GENDER | TONE),
+	("👴🏿", GENDER | TONE),
+	("👵", GENDER | TONE),
+	("👵🏻", GENDER | TONE),
+	("👵🏼", GENDER | TONE),
+	("👵🏽", GENDER | TONE),
+	("👵🏾", GENDER | TONE),
+	("👵🏿", GENDER | TONE),
+	("👶", TONE),
+	("👶🏻", TONE),
+	("👶🏼", TONE),
+	("👶🏽", TONE),
+	("👶🏾", TONE),
+	("👶🏿", TONE),
+	("👷", GENDER | TONE),
+	("👷\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👷\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👷🏻", GENDER | TONE),
+	("👷🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👷🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👷🏼", GENDER | TONE),
+	("👷🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👷🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👷🏽", GENDER | TONE),
+	("👷🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👷🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👷🏾", GENDER | TONE),
+	("👷🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👷🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👷🏿", GENDER | TONE),
+	("👷🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("👷🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("👸", TONE),
+	("👸🏻", TONE),
+	("👸🏼", TONE),
+	("👸🏽", TONE),
+	("👸🏾", TONE),
+	("👸🏿", TONE),
+	("👼", TONE),
+	("👼🏻", TONE),
+	("👼🏼", TONE),
+	("👼🏽", TONE),
+	("👼🏾", TONE),
+	("👼🏿", TONE),
+	("💁", GENDER | TONE),
+	("💁\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💁\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💁🏻", GENDER | TONE),
+	("💁🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💁🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💁🏼", GENDER | TONE),
+	("💁🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💁🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💁🏽", GENDER | TONE),
+	("💁🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💁🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💁🏾", GENDER | TONE),
+	("💁🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💁🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💁🏿", GENDER | TONE),
+	("💁🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💁🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💂", GENDER | TONE),
+	("💂\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💂\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💂🏻", GENDER | TONE),
+	("💂🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💂🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💂🏼", GENDER | TONE),
+	("💂🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💂🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💂🏽", GENDER | TONE),
+	("💂🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💂🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💂🏾", GENDER | TONE),
+	("💂🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💂🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💂🏿", GENDER | TONE),
+	("💂🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💂🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💃", GENDER | TONE),
+	("💃🏻", GENDER | TONE),
+	("💃🏼", GENDER | TONE),
+	("💃🏽", GENDER | TONE),
+	("💃🏾", GENDER | TONE),
+	("💃🏿", GENDER | TONE),
+	("💅", TONE),
+	("💅🏻", TONE),
+	("💅🏼", TONE),
+	("💅🏽", TONE),
+	("💅🏾", TONE),
+	("💅🏿", TONE),
+	("💆", GENDER | TONE),
+	("💆\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💆\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💆🏻", GENDER | TONE),
+	("💆🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💆🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💆🏼", GENDER | TONE),
+	("💆🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💆🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💆🏽", GENDER | TONE),
+	("💆🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💆🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💆🏾", GENDER | TONE),
+	("💆🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💆🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💆🏿", GENDER | TONE),
+	("💆🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💆🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💇", GENDER | TONE),
+	("💇\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💇\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💇🏻", GENDER | TONE),
+	("💇🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💇🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💇🏼", GENDER | TONE),
+	("💇🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💇🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💇🏽", GENDER | TONE),
+	("💇🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💇🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💇🏾", GENDER | TONE),
+	("💇🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💇🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💇🏿", GENDER | TONE),
+	("💇🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("💇🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("💏", GENDER | TONE),
+	("💏🏻", GENDER | TONE),
+	("💏🏼", GENDER | TONE),
+	("💏🏽", GENDER | TONE),
+	("💏🏾", GENDER | TONE),
+	("💏🏿", GENDER | TONE),
+	("💑", GENDER | TONE),
+	("💑🏻", GENDER | TONE),
+	("💑🏼", GENDER | TONE),
+	("💑🏽", GENDER | TONE),
+	("💑🏾", GENDER | TONE),
+	("💑🏿", GENDER | TONE),
+	("💪", TONE),
+	("💪🏻", TONE),
+	("💪🏼", TONE),
+	("💪🏽", TONE),
+	("💪🏾", TONE),
+	("💪🏿", TONE),
+	("🕴\u{fe0f}", TONE),
+	("🕴🏻", TONE),
+	("🕴🏼", TONE),
+	("🕴🏽", TONE),
+	("🕴🏾", TONE),
+	("🕴🏿", TONE),
+	("🕵\u{fe0f}", GENDER | TONE),
+	("🕵\u{fe0f}\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🕵\u{fe0f}\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🕵🏻", GENDER | TONE),
+	("🕵🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🕵🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🕵🏼", GENDER | TONE),
+	("🕵🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🕵🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🕵🏽", GENDER | TONE),
+	("🕵🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🕵🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🕵🏾", GENDER | TONE),
+	("🕵🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🕵🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🕵🏿", GENDER | TONE),
+	("🕵🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🕵🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🕺", GENDER | TONE),
+	("🕺🏻", GENDER | TONE),
+	("🕺🏼", GENDER | TONE),
+	("🕺🏽", GENDER | TONE),
+	("🕺🏾", GENDER | TONE),
+	("🕺🏿", GENDER | TONE),
+	("🖐\u{fe0f}", TONE),
+	("🖐🏻", TONE),
+	("🖐🏼", TONE),
+	("🖐🏽", TONE),
+	("🖐🏾", TONE),
+	("🖐🏿", TONE),
+	("🖕", TONE),
+	("🖕🏻", TONE),
+	("🖕🏼", TONE),
+	("🖕🏽", TONE),
+	("🖕🏾", TONE),
+	("🖕🏿", TONE),
+	("🖖", TONE),
+	("🖖🏻", TONE),
+	("🖖🏼", TONE),
+	("🖖🏽", TONE),
+	("🖖🏾", TONE),
+	("🖖🏿", TONE),
+	("🙅", GENDER | TONE),
+	("🙅\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙅\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙅🏻", GENDER | TONE),
+	("🙅🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙅🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙅🏼", GENDER | TONE),
+	("🙅🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙅🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙅🏽", GENDER | TONE),
+	("🙅🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙅🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙅🏾", GENDER | TONE),
+	("🙅🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙅🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙅🏿", GENDER | TONE),
+	("🙅🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙅🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙆", GENDER | TONE),
+	("🙆\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙆\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙆🏻", GENDER | TONE),
+	("🙆🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙆🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙆🏼", GENDER | TONE),
+	("🙆🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙆🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙆🏽", GENDER | TONE),
+	("🙆🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙆🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙆🏾", GENDER | TONE),
+	("🙆🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙆🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙆🏿", GENDER | TONE),
+	("🙆🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙆🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙇", GENDER | TONE),
+	("🙇\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙇\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙇🏻", GENDER | TONE),
+	("🙇🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙇🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙇🏼", GENDER | TONE),
+	("🙇🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙇🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙇🏽", GENDER | TONE),
+	("🙇🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙇🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙇🏾", GENDER | TONE),
+	("🙇🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙇🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙇🏿", GENDER | TONE),
+	("🙇🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙇🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙋", GENDER | TONE),
+	("🙋\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙋\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙋🏻", GENDER | TONE),
+	("🙋🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙋🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙋🏼", GENDER | TONE),
+	("🙋🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙋🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙋🏽", GENDER | TONE),
+	("🙋🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙋🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙋🏾", GENDER | TONE),
+	("🙋🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙋🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙋🏿", GENDER | TONE),
+	("🙋🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙋🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙌", TONE),
+	("🙌🏻", TONE),
+	("🙌🏼", TONE),
+	("🙌🏽", TONE),
+	("🙌🏾", TONE),
+	("🙌🏿", TONE),
+	("🙍", GENDER | TONE),
+	("🙍\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙍\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙍🏻", GENDER | TONE),
+	("🙍🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙍🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙍🏼", GENDER | TONE),
+	("🙍🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙍🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙍🏽", GENDER | TONE),
+	("🙍🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙍🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙍🏾", GENDER | TONE),
+	("🙍🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙍🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙍🏿", GENDER | TONE),
+	("🙍🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙍🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙎", GENDER | TONE),
+	("🙎\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙎\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙎🏻", GENDER | TONE),
+	("🙎🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙎🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙎🏼", GENDER | TONE),
+	("🙎🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙎🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙎🏽", GENDER | TONE),
+	("🙎🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙎🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙎🏾", GENDER | TONE),
+	("🙎🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙎🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙎🏿", GENDER | TONE),
+	("🙎🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🙎🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🙏", TONE),
+	("🙏🏻", TONE),
+	("🙏🏼", TONE),
+	("🙏🏽", TONE),
+	("🙏🏾", TONE),
+	("🙏🏿", TONE),
+	("🚣", GENDER | TONE),
+	("🚣\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚣\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚣🏻", GENDER | TONE),
+	("🚣🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚣🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚣🏼", GENDER | TONE),
+	("🚣🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚣🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚣🏽", GENDER | TONE),
+	("🚣🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚣🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚣🏾", GENDER | TONE),
+	("🚣🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚣🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚣🏿", GENDER | TONE),
+	("🚣🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚣🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚴", GENDER | TONE),
+	("🚴\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚴\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚴🏻", GENDER | TONE),
+	("🚴🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚴🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚴🏼", GENDER | TONE),
+	("🚴🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚴🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚴🏽", GENDER | TONE),
+	("🚴🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚴🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚴🏾", GENDER | TONE),
+	("🚴🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚴🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚴🏿", GENDER | TONE),
+	("🚴🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚴🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚵", GENDER | TONE),
+	("🚵\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚵\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚵🏻", GENDER | TONE),
+	("🚵🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚵🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚵🏼", GENDER | TONE),
+	("🚵🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚵🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚵🏽", GENDER | TONE),
+	("🚵🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚵🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚵🏾", GENDER | TONE),
+	("🚵🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚵🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚵🏿", GENDER | TONE),
+	("🚵🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚵🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚶", GENDER | TONE),
+	("🚶\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚶\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚶🏻", GENDER | TONE),
+	("🚶🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚶🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚶🏼", GENDER | TONE),
+	("🚶🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚶🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚶🏽", GENDER | TONE),
+	("🚶🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚶🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚶🏾", GENDER | TONE),
+	("🚶🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚶🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🚶🏿", GENDER | TONE),
+	("🚶🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🚶🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🛀", TONE),
+	("🛀🏻", TONE),
+	("🛀🏼", TONE),
+	("🛀🏽", TONE),
+	("🛀🏾", TONE),
+	("🛀🏿", TONE),
+	("🛌", TONE),
+	("🛌🏻", TONE),
+	("🛌🏼", TONE),
+	("🛌🏽", TONE),
+	("🛌🏾", TONE),
+	("🛌🏿", TONE),
+	("🤌", TONE),
+	("🤌🏻", TONE),
+	("🤌🏼", TONE),
+	("🤌🏽", TONE),
+	("🤌🏾", TONE),
+	("🤌🏿", TONE),
+	("🤏", TONE),
+	("🤏🏻", TONE),
+	("🤏🏼", TONE),
+	("🤏🏽", TONE),
+	("🤏🏾", TONE),
+	("🤏🏿", TONE),
+	("🤘", TONE),
+	("🤘🏻", TONE),
+	("🤘🏼", TONE),
+	("🤘🏽", TONE),
+	("🤘🏾", TONE),
+	("🤘🏿", TONE),
+	("🤙", TONE),
+	("🤙🏻", TONE),
+	("🤙🏼", TONE),
+	("🤙🏽", TONE),
+	("🤙🏾", TONE),
+	("🤙🏿", TONE),
+	("🤚", TONE),
+	("🤚🏻", TONE),
+	("🤚🏼", TONE),
+	("🤚🏽", TONE),
+	("🤚🏾", TONE),
+	("🤚🏿", TONE),
+	("🤛", TONE),
+	("🤛🏻", TONE),
+	("🤛🏼", TONE),
+	("🤛🏽", TONE),
+	("🤛🏾", TONE),
+	("🤛🏿", TONE),
+	("🤜", TONE),
+	("🤜🏻", TONE),
+	("🤜🏼", TONE),
+	("🤜🏽", TONE),
+	("🤜🏾", TONE),
+	("🤜🏿", TONE),
+	("🤝", TONE),
+	("🤝🏻", TONE),
+	("🤝🏼", TONE),
+	("🤝🏽", TONE),
+	("🤝🏾", TONE),
+	("🤝🏿", TONE),
+	("🤞", TONE),
+	("🤞🏻", TONE),
+	("🤞🏼", TONE),
+	("🤞🏽", TONE),
+	("🤞🏾", TONE),
+	("🤞🏿", TONE),
+	("🤟", TONE),
+	("🤟🏻", TONE),
+	("🤟🏼", TONE),
+	("🤟🏽", TONE),
+	("🤟🏾", TONE),
+	("🤟🏿", TONE),
+	("🤦", GENDER | TONE),
+	("🤦\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤦\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤦🏻", GENDER | TONE),
+	("🤦🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤦🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤦🏼", GENDER | TONE),
+	("🤦🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤦🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤦🏽", GENDER | TONE),
+	("🤦🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤦🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤦🏾", GENDER | TONE),
+	("🤦🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤦🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤦🏿", GENDER | TONE),
+	("🤦🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤦🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤰", GENDER | TONE),
+	("🤰🏻", GENDER | TONE),
+	("🤰🏼", GENDER | TONE),
+	("🤰🏽", GENDER | TONE),
+	("🤰🏾", GENDER | TONE),
+	("🤰🏿", GENDER | TONE),
+	("🤱", TONE),
+	("🤱🏻", TONE),
+	("🤱🏼", TONE),
+	("🤱🏽", TONE),
+	("🤱🏾", TONE),
+	("🤱🏿", TONE),
+	("🤲", TONE),
+	("🤲🏻", TONE),
+	("🤲🏼", TONE),
+	("🤲🏽", TONE),
+	("🤲🏾", TONE),
+	("🤲🏿", TONE),
+	("🤳", TONE),
+	("🤳🏻", TONE),
+	("🤳🏼", TONE),
+	("🤳🏽", TONE),
+	("🤳🏾", TONE),
+	("🤳🏿", TONE),
+	("🤴", TONE),
+	("🤴🏻", TONE),
+	("🤴🏼", TONE),
+	("🤴🏽", TONE),
+	("🤴🏾", TONE),
+	("🤴🏿", TONE),
+	("🤵", GENDER | TONE),
+	("🤵\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤵\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤵🏻", GENDER | TONE),
+	("🤵🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤵🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤵🏼", GENDER | TONE),
+	("🤵🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤵🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤵🏽", GENDER | TONE),
+	("🤵🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤵🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤵🏾", GENDER | TONE),
+	("🤵🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤵🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤵🏿", GENDER | TONE),
+	("🤵🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤵🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤶", TONE),
+	("🤶🏻", TONE),
+	("🤶🏼", TONE),
+	("🤶🏽", TONE),
+	("🤶🏾", TONE),
+	("🤶🏿", TONE),
+	("🤷", GENDER | TONE),
+	("🤷\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤷\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤷🏻", GENDER | TONE),
+	("🤷🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤷🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤷🏼", GENDER | TONE),
+	("🤷🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤷🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤷🏽", GENDER | TONE),
+	("🤷🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤷🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤷🏾", GENDER | TONE),
+	("🤷🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤷🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤷🏿", GENDER | TONE),
+	("🤷🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤷🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤸", GENDER | TONE),
+	("🤸\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤸\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤸🏻", GENDER | TONE),
+	("🤸🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤸🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤸🏼", GENDER | TONE),
+	("🤸🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤸🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤸🏽", GENDER | TONE),
+	("🤸🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤸🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤸🏾", GENDER | TONE),
+	("🤸🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤸🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤸🏿", GENDER | TONE),
+	("🤸🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤸🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤹", GENDER | TONE),
+	("🤹\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤹\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤹🏻", GENDER | TONE),
+	("🤹🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤹🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤹🏼", GENDER | TONE),
+	("🤹🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤹🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤹🏽", GENDER | TONE),
+	("🤹🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤹🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤹🏾", GENDER | TONE),
+	("🤹🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤹🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤹🏿", GENDER | TONE),
+	("🤹🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤹🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤼", GENDER),
+	("🤼\u{200d}♀\u{fe0f}", GENDER),
+	("🤼\u{200d}♂\u{fe0f}", GENDER),
+	("🤽", GENDER | TONE),
+	("🤽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤽🏻", GENDER | TONE),
+	("🤽🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤽🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤽🏼", GENDER | TONE),
+	("🤽🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤽🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤽🏽", GENDER | TONE),
+	("🤽🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤽🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤽🏾", GENDER | TONE),
+	("🤽🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤽🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤽🏿", GENDER | TONE),
+	("🤽🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤽🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤾", GENDER | TONE),
+	("🤾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤾🏻", GENDER | TONE),
+	("🤾🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤾🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤾🏼", GENDER | TONE),
+	("🤾🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤾🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤾🏽", GENDER | TONE),
+	("🤾🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤾🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤾🏾", GENDER | TONE),
+	("🤾🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤾🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🤾🏿", GENDER | TONE),
+	("🤾🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🤾🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🥷", TONE),
+	("🥷🏻", TONE),
+	("🥷🏼", TONE),
+	("🥷🏽", TONE),
+	("🥷🏾", TONE),
+	("🥷🏿", TONE),
+	("🦵", TONE),
+	("🦵🏻", TONE),
+	("🦵🏼", TONE),
+	("🦵🏽", TONE),
+	("🦵🏾", TONE),
+	("🦵🏿", TONE),
+	("🦶", TONE),
+	("🦶🏻", TONE),
+	("🦶🏼", TONE),
+	("🦶🏽", TONE),
+	("🦶🏾", TONE),
+	("🦶🏿", TONE),
+	("🦸", GENDER | TONE),
+	("🦸\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🦸\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🦸🏻", GENDER | TONE),
+	("🦸🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🦸🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🦸🏼", GENDER | TONE),
+	("🦸🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🦸🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🦸🏽", GENDER | TONE),
+	("🦸🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🦸🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🦸🏾", GENDER | TONE),
+	("🦸🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🦸🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🦸🏿", GENDER | TONE),
+	("🦸🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🦸🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🦹", GENDER | TONE),
+	("🦹\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🦹\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🦹🏻", GENDER | TONE),
+	("🦹🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🦹🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🦹🏼", GENDER | TONE),
+	("🦹🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🦹🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🦹🏽", GENDER | TONE),
+	("🦹🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🦹🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🦹🏾", GENDER | TONE),
+	("🦹🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🦹🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🦹🏿", GENDER | TONE),
+	("🦹🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🦹🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🦻", TONE),
+	("🦻🏻", TONE),
+	("🦻🏼", TONE),
+	("🦻🏽", TONE),
+	("🦻🏾", TONE),
+	("🦻🏿", TONE),
+	("🧍", GENDER | TONE),
+	("🧍\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧍\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧍🏻", GENDER | TONE),
+	("🧍🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧍🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧍🏼", GENDER | TONE),
+	("🧍🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧍🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧍🏽", GENDER | TONE),
+	("🧍🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧍🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧍🏾", GENDER | TONE),
+	("🧍🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧍🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧍🏿", GENDER | TONE),
+	("🧍🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧍🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧎", GENDER | TONE),
+	("🧎\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧎\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧎🏻", GENDER | TONE),
+	("🧎🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧎🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧎🏼", GENDER | TONE),
+	("🧎🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧎🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧎🏽", GENDER | TONE),
+	("🧎🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧎🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧎🏾", GENDER | TONE),
+	("🧎🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧎🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧎🏿", GENDER | TONE),
+	("🧎🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧎🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧏", GENDER | TONE),
+	("🧏\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧏\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧏🏻", GENDER | TONE),
+	("🧏🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧏🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧏🏼", GENDER | TONE),
+	("🧏🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧏🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧏🏽", GENDER | TONE),
+	("🧏🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧏🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧏🏾", GENDER | TONE),
+	("🧏🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧏🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧏🏿", GENDER | TONE),
+	("🧏🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧏🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧑", HAIR | GENDER | TONE),
+	("🧑\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("🧑\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("🧑\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("🧑\u{200d}🌾", GENDER | TONE),
+	("🧑\u{200d}🍳", GENDER | TONE),
+	("🧑\u{200d}🍼", GENDER | TONE),
+	("🧑\u{200d}🎄", TONE),
+	("🧑\u{200d}🎓", GENDER | TONE),
+	("🧑\u{200d}🎤", GENDER | TONE),
+	("🧑\u{200d}🎨", GENDER | TONE),
+	("🧑\u{200d}🏫", GENDER | TONE),
+	("🧑\u{200d}🏭", GENDER | TONE),
+	("🧑\u{200d}💻", GENDER | TONE),
+	("🧑\u{200d}💼", GENDER | TONE),
+	("🧑\u{200d}🔧", GENDER | TONE),
+	("🧑\u{200d}🔬", GENDER | TONE),
+	("🧑\u{200d}🚀", GENDER | TONE),
+	("🧑\u{200d}🚒", GENDER | TONE),
+	("🧑\u{200d}🤝\u{200d}🧑", GENDER | TONE),
+	("🧑\u{200d}🦯", GENDER | TONE),
+	("🧑\u{200d}🦰", HAIR | GENDER | TONE),
+	("🧑\u{200d}🦱", HAIR | GENDER | TONE),
+	("🧑\u{200d}🦲", HAIR | GENDER | TONE),
+	("🧑\u{200d}🦳", HAIR | GENDER | TONE),
+	("🧑\u{200d}🦼", GENDER | TONE),
+	("🧑\u{200d}🦽", GENDER | TONE),
+	("🧑🏻", HAIR | GENDER | TONE),
+	("🧑🏻\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("🧑🏻\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("🧑🏻\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", GENDER | TONE),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", GENDER | TONE),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", GENDER | TONE),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", GENDER | TONE),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏼", GENDER | TONE),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏽", GENDER | TONE),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏾", GENDER | TONE),
+	("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏿", GENDER | TONE),
+	("🧑🏻\u{200d}🌾", GENDER | TONE),
+	("🧑🏻\u{200d}🍳", GENDER | TONE),
+	("🧑🏻\u{200d}🍼", GENDER | TONE),
+	("🧑🏻\u{200d}🎄", TONE),
+	("🧑🏻\u{200d}🎓", GENDER | TONE),
+	("🧑🏻\u{200d}🎤", GENDER | TONE),
+	("🧑🏻\u{200d}🎨", GENDER | TONE),
+	("🧑🏻\u{200d}🏫", GENDER | TONE),
+	("🧑🏻\u{200d}🏭", GENDER | TONE),
+	("🧑🏻\u{200d}💻", GENDER | TONE),
+	("🧑🏻\u{200d}💼", GENDER | TONE),
+	("🧑🏻\u{200d}🔧", GENDER | TONE),
+	("🧑🏻\u{200d}🔬", GENDER | TONE),
+	("🧑🏻\u{200d}🚀", GENDER | TONE),
+	("🧑🏻\u{200d}🚒", GENDER | TONE),
+	("🧑🏻\u{200d}🤝\u{200d}🧑🏻", GENDER | TONE),
+	("🧑🏻\u{200d}🤝\u{200d}🧑🏼", GENDER | TONE),
+	("🧑🏻\u{200d}🤝\u{200d}🧑🏽", GENDER | TONE),
+	("🧑🏻\u{200d}🤝\u{200d}🧑🏾", GENDER | TONE),
+	("🧑🏻\u{200d}🤝\u{200d}🧑🏿", GENDER | TONE),
+	("🧑🏻\u{200d}🦯", GENDER | TONE),
+	("🧑🏻\u{200d}🦰", HAIR | GENDER | TONE),
+	("🧑🏻\u{200d}🦱", HAIR | GENDER | TONE),
+	("🧑🏻\u{200d}🦲", HAIR | GENDER | TONE),
+	("🧑🏻\u{200d}🦳", HAIR | GENDER | TONE),
+	("🧑🏻\u{200d}🦼", GENDER | TONE),
+	("🧑🏻\u{200d}🦽", GENDER | TONE),
+	("🧑🏼", HAIR | GENDER | TONE),
+	("🧑🏼\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("🧑🏼\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("🧑🏼\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", GENDER | TONE),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", GENDER | TONE),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", GENDER | TONE),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", GENDER | TONE),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏻", GENDER | TONE),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏽", GENDER | TONE),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏾", GENDER | TONE),
+	("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏿", GENDER | TONE),
+	("🧑🏼\u{200d}🌾", GENDER | TONE),
+	("🧑🏼\u{200d}🍳", GENDER | TONE),
+	("🧑🏼\u{200d}🍼", GENDER | TONE),
+	("🧑🏼\u{200d}🎄", TONE),
+	("🧑🏼\u{200d}🎓", GENDER | TONE),
+	("🧑🏼\u{200d}🎤", GENDER | TONE),
+	("🧑🏼\u{200d}🎨", GENDER | TONE),
+	("🧑🏼\u{200d}🏫", GENDER | TONE),
+	("🧑🏼\u{200d}🏭", GENDER | TONE),
+	("🧑🏼\u{200d}💻", GENDER | TONE),
+	("🧑🏼\u{200d}💼", GENDER | TONE),
+	("🧑🏼\u{200d}🔧", GENDER | TONE),
+	("🧑🏼\u{200d}🔬", GENDER | TONE),
+	("🧑🏼\u{200d}🚀", GENDER | TONE),
+	("🧑🏼\u{200d}🚒", GENDER | TONE),
+	("🧑🏼\u{200d}🤝\u{200d}🧑🏻", GENDER | TONE),
+	("🧑🏼\u{200d}🤝\u{200d}🧑🏼", GENDER | TONE),
+	("🧑🏼\u{200d}🤝\u{200d}🧑🏽", GENDER | TONE),
+	("🧑🏼\u{200d}🤝\u{200d}🧑🏾", GENDER | TONE),
+	("🧑🏼\u{200d}🤝\u{200d}🧑🏿", GENDER | TONE),
+	("🧑🏼\u{200d}🦯", GENDER | TONE),
+	("🧑🏼\u{200d}🦰", HAIR | GENDER | TONE),
+	("🧑🏼\u{200d}🦱", HAIR | GENDER | TONE),
+	("🧑🏼\u{200d}🦲", HAIR | GENDER | TONE),
+	("🧑🏼\u{200d}🦳", HAIR | GENDER | TONE),
+	("🧑🏼\u{200d}🦼", GENDER | TONE),
+	("🧑🏼\u{200d}🦽", GENDER | TONE),
+	("🧑🏽", HAIR | GENDER | TONE),
+	("🧑🏽\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("🧑🏽\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("🧑🏽\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", GENDER | TONE),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", GENDER | TONE),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", GENDER | TONE),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", GENDER | TONE),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏻", GENDER | TONE),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏼", GENDER | TONE),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏾", GENDER | TONE),
+	("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏿", GENDER | TONE),
+	("🧑🏽\u{200d}🌾", GENDER | TONE),
+	("🧑🏽\u{200d}🍳", GENDER | TONE),
+	("🧑🏽\u{200d}🍼", GENDER | TONE),
+	("🧑🏽\u{200d}🎄", TONE),
+	("🧑🏽\u{200d}🎓", GENDER | TONE),
+	("🧑🏽\u{200d}🎤", GENDER | TONE),
+	("🧑🏽\u{200d}🎨", GENDER | TONE),
+	("🧑🏽\u{200d}🏫", GENDER | TONE),
+	("🧑🏽\u{200d}🏭", GENDER | TONE),
+	("🧑🏽\u{200d}💻", GENDER | TONE),
+	("🧑🏽\u{200d}💼", GENDER | TONE),
+	("🧑🏽\u{200d}🔧", GENDER | TONE),
+	("🧑🏽\u{200d}🔬", GENDER | TONE),
+	("🧑🏽\u{200d}🚀", GENDER | TONE),
+	("🧑🏽\u{200d}🚒", GENDER | TONE),
+	("🧑🏽\u{200d}🤝\u{200d}🧑🏻", GENDER | TONE),
+	("🧑🏽\u{200d}🤝\u{200d}🧑🏼", GENDER | TONE),
+	("🧑🏽\u{200d}🤝\u{200d}🧑🏽", GENDER | TONE),
+	("🧑🏽\u{200d}🤝\u{200d}🧑🏾", GENDER | TONE),
+	("🧑🏽\u{200d}🤝\u{200d}🧑🏿", GENDER | TONE),
+	("🧑🏽\u{200d}🦯", GENDER | TONE),
+	("🧑🏽\u{200d}🦰", HAIR | GENDER | TONE),
+	("🧑🏽\u{200d}🦱", HAIR | GENDER | TONE),
+	("🧑🏽\u{200d}🦲", HAIR | GENDER | TONE),
+	("🧑🏽\u{200d}🦳", HAIR | GENDER | TONE),
+	("🧑🏽\u{200d}🦼", GENDER | TONE),
+	("🧑🏽\u{200d}🦽", GENDER | TONE),
+	("🧑🏾", HAIR | GENDER | TONE),
+	("🧑🏾\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("🧑🏾\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("🧑🏾\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", GENDER | TONE),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", GENDER | TONE),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", GENDER | TONE),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", GENDER | TONE),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏻", GENDER | TONE),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏼", GENDER | TONE),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏽", GENDER | TONE),
+	("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏿", GENDER | TONE),
+	("🧑🏾\u{200d}🌾", GENDER | TONE),
+	("🧑🏾\u{200d}🍳", GENDER | TONE),
+	("🧑🏾\u{200d}🍼", GENDER | TONE),
+	("🧑🏾\u{200d}🎄", TONE),
+	("🧑🏾\u{200d}🎓", GENDER | TONE),
+	("🧑🏾\u{200d}🎤", GENDER | TONE),
+	("🧑🏾\u{200d}🎨", GENDER | TONE),
+	("🧑🏾\u{200d}🏫", GENDER | TONE),
+	("🧑🏾\u{200d}🏭", GENDER | TONE),
+	("🧑🏾\u{200d}💻", GENDER | TONE),
+	("🧑🏾\u{200d}💼", GENDER | TONE),
+	("🧑🏾\u{200d}🔧", GENDER | TONE),
+	("🧑🏾\u{200d}🔬", GENDER | TONE),
+	("🧑🏾\u{200d}🚀", GENDER | TONE),
+	("🧑🏾\u{200d}🚒", GENDER | TONE),
+	("🧑🏾\u{200d}🤝\u{200d}🧑🏻", GENDER | TONE),
+	("🧑🏾\u{200d}🤝\u{200d}🧑🏼", GENDER | TONE),
+	("🧑🏾\u{200d}🤝\u{200d}🧑🏽", GENDER | TONE),
+	("🧑🏾\u{200d}🤝\u{200d}🧑🏾", GENDER | TONE),
+	("🧑🏾\u{200d}🤝\u{200d}🧑🏿", GENDER | TONE),
+	("🧑🏾\u{200d}🦯", GENDER | TONE),
+	("🧑🏾\u{200d}🦰", HAIR | GENDER | TONE),
+	("🧑🏾\u{200d}🦱", HAIR | GENDER | TONE),
+	("🧑🏾\u{200d}🦲", HAIR | GENDER | TONE),
+	("🧑🏾\u{200d}🦳", HAIR | GENDER | TONE),
+	("🧑🏾\u{200d}🦼", GENDER | TONE),
+	("🧑🏾\u{200d}🦽", GENDER | TONE),
+	("🧑🏿", HAIR | GENDER | TONE),
+	("🧑🏿\u{200d}⚕\u{fe0f}", GENDER | TONE),
+	("🧑🏿\u{200d}⚖\u{fe0f}", GENDER | TONE),
+	("🧑🏿\u{200d}✈\u{fe0f}", GENDER | TONE),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", GENDER | TONE),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", GENDER | TONE),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", GENDER | TONE),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", GENDER | TONE),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏻", GENDER | TONE),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏼", GENDER | TONE),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏽", GENDER | TONE),
+	("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏾", GENDER | TONE),
+	("🧑🏿\u{200d}🌾", GENDER | TONE),
+	("🧑🏿\u{200d}🍳", GENDER | TONE),
+	("🧑🏿\u{200d}🍼", GENDER | TONE),
+	("🧑🏿\u{200d}🎄", TONE),
+	("🧑🏿\u{200d}🎓", GENDER | TONE),
+	("🧑🏿\u{200d}🎤", GENDER | TONE),
+	("🧑🏿\u{200d}🎨", GENDER | TONE),
+	("🧑🏿\u{200d}🏫", GENDER | TONE),
+	("🧑🏿\u{200d}🏭", GENDER | TONE),
+	("🧑🏿\u{200d}💻", GENDER | TONE),
+	("🧑🏿\u{200d}💼", GENDER | TONE),
+	("🧑🏿\u{200d}🔧", GENDER | TONE),
+	("🧑🏿\u{200d}🔬", GENDER | TONE),
+	("🧑🏿\u{200d}🚀", GENDER | TONE),
+	("🧑🏿\u{200d}🚒", GENDER | TONE),
+	("🧑🏿\u{200d}🤝\u{200d}🧑🏻", GENDER | TONE),
+	("🧑🏿\u{200d}🤝\u{200d}🧑🏼", GENDER | TONE),
+	("🧑🏿\u{200d}🤝\u{200d}🧑🏽", GENDER | TONE),
+	("🧑🏿\u{200d}🤝\u{200d}🧑🏾", GENDER | TONE),
+	("🧑🏿\u{200d}🤝\u{200d}🧑🏿", GENDER | TONE),
+	("🧑🏿\u{200d}🦯", GENDER | TONE),
+	("🧑🏿\u{200d}🦰", HAIR | GENDER | TONE),
+	("🧑🏿\u{200d}🦱", HAIR | GENDER | TONE),
+	("🧑🏿\u{200d}🦲", HAIR | GENDER | TONE),
+	("🧑🏿\u{200d}🦳", HAIR | GENDER | TONE),
+	("🧑🏿\u{200d}🦼", GENDER | TONE),
+	("🧑🏿\u{200d}🦽", GENDER | TONE),
+	("🧒", TONE),
+	("🧒🏻", TONE),
+	("🧒🏼", TONE),
+	("🧒🏽", TONE),
+	("🧒🏾", TONE),
+	("🧒🏿", TONE),
+	("🧓", TONE),
+	("🧓🏻", TONE),
+	("🧓🏼", TONE),
+	("🧓🏽", TONE),
+	("🧓🏾", TONE),
+	("🧓🏿", TONE),
+	("🧔", HAIR | GENDER | TONE),
+	("🧔\u{200d}♀\u{fe0f}", HAIR | GENDER | TONE),
+	("🧔\u{200d}♂\u{fe0f}", HAIR | GENDER | TONE),
+	("🧔🏻", HAIR | GENDER | TONE),
+	("🧔🏻\u{200d}♀\u{fe0f}", HAIR | GENDER | TONE),
+	("🧔🏻\u{200d}♂\u{fe0f}", HAIR | GENDER | TONE),
+	("🧔🏼", HAIR | GENDER | TONE),
+	("🧔🏼\u{200d}♀\u{fe0f}", HAIR | GENDER | TONE),
+	("🧔🏼\u{200d}♂\u{fe0f}", HAIR | GENDER | TONE),
+	("🧔🏽", HAIR | GENDER | TONE),
+	("🧔🏽\u{200d}♀\u{fe0f}", HAIR | GENDER | TONE),
+	("🧔🏽\u{200d}♂\u{fe0f}", HAIR | GENDER | TONE),
+	("🧔🏾", HAIR | GENDER | TONE),
+	("🧔🏾\u{200d}♀\u{fe0f}", HAIR | GENDER | TONE),
+	("🧔🏾\u{200d}♂\u{fe0f}", HAIR | GENDER | TONE),
+	("🧔🏿", HAIR | GENDER | TONE),
+	("🧔🏿\u{200d}♀\u{fe0f}", HAIR | GENDER | TONE),
+	("🧔🏿\u{200d}♂\u{fe0f}", HAIR | GENDER | TONE),
+	("🧕", TONE),
+	("🧕🏻", TONE),
+	("🧕🏼", TONE),
+	("🧕🏽", TONE),
+	("🧕🏾", TONE),
+	("🧕🏿", TONE),
+	("🧖", GENDER | TONE),
+	("🧖\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧖\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧖🏻", GENDER | TONE),
+	("🧖🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧖🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧖🏼", GENDER | TONE),
+	("🧖🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧖🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧖🏽", GENDER | TONE),
+	("🧖🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧖🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧖🏾", GENDER | TONE),
+	("🧖🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧖🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧖🏿", GENDER | TONE),
+	("🧖🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧖🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧗", GENDER | TONE),
+	("🧗\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧗\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧗🏻", GENDER | TONE),
+	("🧗🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧗🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧗🏼", GENDER | TONE),
+	("🧗🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧗🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧗🏽", GENDER | TONE),
+	("🧗🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧗🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧗🏾", GENDER | TONE),
+	("🧗🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧗🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧗🏿", GENDER | TONE),
+	("🧗🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧗🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧘", GENDER | TONE),
+	("🧘\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧘\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧘🏻", GENDER | TONE),
+	("🧘🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧘🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧘🏼", GENDER | TONE),
+	("🧘🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧘🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧘🏽", GENDER | TONE),
+	("🧘🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧘🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧘🏾", GENDER | TONE),
+	("🧘🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧘🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧘🏿", GENDER | TONE),
+	("🧘🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧘🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧙", GENDER | TONE),
+	("🧙\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧙\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧙🏻", GENDER | TONE),
+	("🧙🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧙🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧙🏼", GENDER | TONE),
+	("🧙🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧙🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧙🏽", GENDER | TONE),
+	("🧙🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧙🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧙🏾", GENDER | TONE),
+	("🧙🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧙🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧙🏿", GENDER | TONE),
+	("🧙🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧙🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧚", GENDER | TONE),
+	("🧚\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧚\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧚🏻", GENDER | TONE),
+	("🧚🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧚🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧚🏼", GENDER | TONE),
+	("🧚🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧚🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧚🏽", GENDER | TONE),
+	("🧚🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧚🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧚🏾", GENDER | TONE),
+	("🧚🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧚🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧚🏿", GENDER | TONE),
+	("🧚🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧚🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧛", GENDER | TONE),
+	("🧛\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧛\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧛🏻", GENDER | TONE),
+	("🧛🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧛🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧛🏼", GENDER | TONE),
+	("🧛🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧛🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧛🏽", GENDER | TONE),
+	("🧛🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧛🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧛🏾", GENDER | TONE),
+	("🧛🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧛🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧛🏿", GENDER | TONE),
+	("🧛🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧛🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧜", TONE),
+	("🧜\u{200d}♀\u{fe0f}", TONE),
+	("🧜\u{200d}♂\u{fe0f}", TONE),
+	("🧜🏻", TONE),
+	("🧜🏻\u{200d}♀\u{fe0f}", TONE),
+	("🧜🏻\u{200d}♂\u{fe0f}", TONE),
+	("🧜🏼", TONE),
+	("🧜🏼\u{200d}♀\u{fe0f}", TONE),
+	("🧜🏼\u{200d}♂\u{fe0f}", TONE),
+	("🧜🏽", TONE),
+	("🧜🏽\u{200d}♀\u{fe0f}", TONE),
+	("🧜🏽\u{200d}♂\u{fe0f}", TONE),
+	("🧜🏾", TONE),
+	("🧜🏾\u{200d}♀\u{fe0f}", TONE),
+	("🧜🏾\u{200d}♂\u{fe0f}", TONE),
+	("🧜🏿", TONE),
+	("🧜🏿\u{200d}♀\u{fe0f}", TONE),
+	("🧜🏿\u{200d}♂\u{fe0f}", TONE),
+	("🧝", GENDER | TONE),
+	("🧝\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧝\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧝🏻", GENDER | TONE),
+	("🧝🏻\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧝🏻\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧝🏼", GENDER | TONE),
+	("🧝🏼\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧝🏼\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧝🏽", GENDER | TONE),
+	("🧝🏽\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧝🏽\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧝🏾", GENDER | TONE),
+	("🧝🏾\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧝🏾\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧝🏿", GENDER | TONE),
+	("🧝🏿\u{200d}♀\u{fe0f}", GENDER | TONE),
+	("🧝🏿\u{200d}♂\u{fe0f}", GENDER | TONE),
+	("🧞", GENDER),
+	("🧞\u{200d}♀\u{fe0f}", GENDER),
+	("🧞\u{200d}♂\u{fe0f}", GENDER),
+	("🧟", GENDER),
+	("🧟\u{200d}♀\u{fe0f}", GENDER),
+	("🧟\u{200d}♂\u{fe0f}", GENDER),
+	("🫃", GENDER | TONE),
+	("🫃🏻", GENDER | TONE),
+	("🫃🏼", GENDER | TONE),
+	("🫃🏽", GENDER | TONE),
+	("🫃🏾", GENDER | TONE),
+	("🫃🏿", GENDER | TONE),
+	("🫄", GENDER | TONE),
+	("🫄🏻", GENDER | TONE),
+	("🫄🏼", GENDER | TONE),
+	("🫄🏽", GENDER | TONE),
+	("🫄🏾", GENDER | TONE),
+	("🫄🏿", GENDER | TONE),
+	("🫅", TONE),
+	("🫅🏻", TONE),
+	("🫅🏼", TONE),
+	("🫅🏽", TONE),
+	("🫅🏾", TONE),
+	("🫅🏿", TONE),
+	("🫰", TONE),
+	("🫰🏻", TONE),
+	("🫰🏼", TONE),
+	("🫰🏽", TONE),
+	("🫰🏾", TONE),
+	("🫰🏿", TONE),
+	("🫱", TONE),
+	("🫱🏻", TONE),
+	("🫱🏻\u{200d}🫲🏼", TONE),
+	("🫱🏻\u{200d}🫲🏽", TONE),
+	("🫱🏻\u{200d}🫲🏾", TONE),
+	("🫱🏻\u{200d}🫲🏿", TONE),
+	("🫱🏼", TONE),
+	("🫱🏼\u{200d}🫲🏻", TONE),
+	("🫱🏼\u{200d}🫲🏽", TONE),
+	("🫱🏼\u{200d}🫲🏾", TONE),
+	("🫱🏼\u{200d}🫲🏿", TONE),
+	("🫱🏽", TONE),
+	("🫱🏽\u{200d}🫲🏻", TONE),
+	("🫱🏽\u{200d}🫲🏼", TONE),
+	("🫱🏽\u{200d}🫲🏾", TONE),
+	("🫱🏽\u{200d}🫲🏿", TONE),
+	("🫱🏾", TONE),
+	("🫱🏾\u{200d}🫲🏻", TONE),
+	("🫱🏾\u{200d}🫲🏼", TONE),
+	("🫱🏾\u{200d}🫲🏽", TONE),
+	("🫱🏾\u{200d}🫲🏿", TONE),
+	("🫱🏿", TONE),
+	("🫱🏿\u{200d}🫲🏻", TONE),
+	("🫱🏿\u{200d}🫲🏼", TONE),
+	("🫱🏿\u{200d}🫲🏽", TONE),
+	("🫱🏿\u{200d}🫲🏾", TONE),
+	("🫲", TONE),
+	("🫲🏻", TONE),
+	("🫲🏼", TONE),
+	("🫲🏽", TONE),
+	("🫲🏾", TONE),
+	("🫲🏿", TONE),
+	("🫳", TONE),
+	("🫳🏻", TONE),
+	("🫳🏼", TONE),
+	("🫳🏽", TONE),
+	("🫳🏾", TONE),
+	("🫳🏿", TONE),
+	("🫴", TONE),
+	("🫴🏻", TONE),
+	("🫴🏼", TONE),
+	("🫴🏽", TONE),
+	("🫴🏾", TONE),
+	("🫴🏿", TONE),
+	("🫵", TONE),
+	("🫵🏻", TONE),
+	("🫵🏼", TONE),
+	("🫵🏽", TONE),
+	("🫵🏾", TONE),
+	("🫵🏿", TONE),
+	("🫶", TONE),
+	("🫶🏻", TONE),
+	("🫶🏼", TONE),
+	("🫶🏽", TONE),
+	("🫶🏾", TONE),
+	("🫶🏿", TONE),
+];
+
+// EOF
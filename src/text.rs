@@ -8,14 +8,100 @@
 //! Based on the [`parse_alias`] function, the [`parse_text`] function takes
 //! an entire text and looks for such colon-fenced emoji names, which will then
 //! be translated into the Unicode equivalent, and the entire text, with the
-//! emojis replaced is returned.
+//! emojis replaced is returned. Without allocating, [`write_parsed`] writes it
+//! into any `fmt::Write` instead, and [`parse_into`] into a plain byte buffer.
+//! To replace the aliases by something else than the emoji itself (e.g. an image), use
+//! [`replace_with`].
+//! For more control, the [`EmojiTextParser`] yields the individual [`Fragment`]s
+//! of such a text. Text which is streamed in as chars can be processed char by char with
+//! [`EmojifyExt::emojify`].
+//!
+//! The reverse is done by the [`demojize`] function, which replaces the Unicode
+//! emojis in a text by their colon-fenced names, while [`strip_emojis`] just
+//! removes them, and [`count_emojis`] counts them (or [`contains_emoji`] just
+//! checks for any). All of them are based on the [`EmojiScanner`], which splits a text into
+//! its Unicode emojis and the plain text in between.
+//!
+//! For user input, [`parse_alias_loose`] is more forgiving than [`parse_alias`], it ignores the
+//! case and whether words are separated by spaces, hyphens, or underscores.
+//!
+//! All the names of an emoji are listed by [`aliases_for`], and if a name is misspelled,
+//! [`suggest_aliases`] finds the closest valid ones. Such misspelled names are reported by
+//! [`parse_text_strict`], and counted along with the replaced emojis by [`parse_text_stats`].
 
 use core::fmt;
 
 use crate::emojis::Emoji;
+use crate::Tone;
 
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
 #[cfg(feature = "alloc")]
 use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "hash-tables")]
+lazy_static::lazy_static! {
+    /// Maps graphemes to their preferred alias (without colons)
+    static ref ALIAS_BY_GRAPHEME: hashbrown::HashMap<&'static str, &'static str> = {
+        let mut map: hashbrown::HashMap<&'static str, &'static str> = hashbrown::HashMap::new();
+        for (&alias, &emoji) in crate::alias::GEMOJI_MAP.iter() {
+            map.entry(emoji.grapheme)
+                .and_modify(|best| {
+                    if alias_rank(alias, emoji) < alias_rank(best, emoji) {
+                        *best = alias;
+                    }
+                })
+                .or_insert(alias);
+        }
+        map
+    };
+
+    /// Maps loosely folded aliases (see [`loose_alias_chars`]) to their emojis
+    static ref LOOSE_ALIAS_MAP: hashbrown::HashMap<String, &'static Emoji> =
+        crate::alias::GEMOJI_MAP
+            .iter()
+            .map(|(&alias, &emoji)| (loose_alias_chars(alias).collect(), emoji))
+            .collect();
+
+    /// Maps graphemes to all their aliases (without colons), sorted alphabetically
+    static ref ALIASES_BY_GRAPHEME: hashbrown::HashMap<&'static str, Vec<&'static str>> = {
+        let mut map: hashbrown::HashMap<&'static str, Vec<&'static str>> = hashbrown::HashMap::new();
+        for (&alias, &emoji) in crate::alias::GEMOJI_MAP.iter() {
+            map.entry(emoji.grapheme).or_default().push(alias);
+        }
+        for aliases in map.values_mut() {
+            aliases.sort_unstable();
+        }
+        map
+    };
+}
+
+/// Ranks the aliases of an emoji, the lowest is the preferred one.
+///
+/// The preferred aliases are those that spell out the name of the emoji, the remaining ones are
+/// ranked alphabetically.
+#[cfg(feature = "alloc")]
+fn alias_rank<'a>(alias: &'a str, emoji: &Emoji) -> (bool, &'a str) {
+    let words = emoji
+        .name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty());
+    let spells_name = alias.split('_').count() == words.clone().count()
+        && alias
+            .split('_')
+            .zip(words)
+            .all(|(a, w)| a.eq_ignore_ascii_case(w));
+
+    (!spells_name, alias)
+}
+
+/// Returns the skin tone of a Fitzpatrick modifier
+#[cfg(feature = "alloc")]
+fn skin_tone(c: char) -> Option<Tone> {
+    core::convert::TryFrom::try_from(c).ok()
+}
 
 /// Parses the given Emoji name into a unicode Emoji.
 ///
@@ -24,6 +110,8 @@ use alloc::string::String;
 /// And additonally all the constant names (as listed in [`crate::flat`]) are also valid aliases
 /// when spelled in lowercase.
 ///
+/// To also accept emojis given as grapheme (e.g. `🦀` or `:🦀:`), use [`parse`](crate::parse).
+///
 /// # Examples
 ///
 /// ```
@@ -52,19 +140,270 @@ pub fn parse_alias(inp: &str) -> Option<&'static Emoji> {
     }
 }
 
+/// Parses the given Emoji name into a unicode Emoji, tolerating variations in its spelling.
+///
+/// Unlike [`parse_alias`], the name is matched case-insensitively, and spaces, hyphens, and
+/// underscores are interchangeable, thus `:Waving Hand:`, `:waving-hand:`, and `:WAVING_HAND:`
+/// all find 👋. A leading sign is kept as is, so `:+1:` and `:-1:` remain distinct.
+///
+/// An exact match with [`parse_alias`] is always preferred.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::text::parse_alias_loose;
+///
+/// assert_eq!(Some(&*emojic::flat::WAVING_HAND), parse_alias_loose(":Waving Hand:"));
+/// assert_eq!(Some(&*emojic::flat::WAVING_HAND), parse_alias_loose(":waving-hand:"));
+/// assert_eq!(Some(&*emojic::flat::WAVING_HAND), parse_alias_loose(":WAVING_HAND:"));
+/// assert_eq!(Some(&*emojic::flat::THUMBS_DOWN), parse_alias_loose(":-1:"));
+/// assert_eq!(Some(&emojic::flat::E_MAIL), parse_alias_loose(":E Mail:"));
+///
+/// assert_eq!(None, parse_alias_loose(":waving__hand:"));
+/// ```
+pub fn parse_alias_loose(inp: &str) -> Option<&'static Emoji> {
+    parse_alias(inp).or_else(|| {
+        if inp.starts_with(':') && inp.ends_with(':') && inp.is_ascii() && inp.len() > 2 {
+            parse_loose_alias(&inp[1..(inp.len() - 1)])
+        } else {
+            None
+        }
+    })
+}
+
+/// Parses the given Emoji name into all the Unicode Emojis it might refer to.
+///
+/// Unlike [`parse_alias`], which returns just the emoji of the name, this function yields
+/// that emoji followed by all its variants (such as the toned or gendered ones), i.e. every
+/// candidate that a user might have meant. If the emoji has no variants, only the emoji itself is
+/// yielded, and if the name is unknown, nothing.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::text::parse_alias_all;
+///
+/// let hands: Vec<_> = parse_alias_all(":wave:").map(|e| e.grapheme).collect();
+/// assert_eq!(hands, ["👋", "👋🏻", "👋🏼", "👋🏽", "👋🏾", "👋🏿"]);
+///
+/// assert_eq!(parse_alias_all(":crab:").count(), 1);
+/// assert_eq!(parse_alias_all(":rustacean:").count(), 0);
+/// ```
+pub fn parse_alias_all(inp: &str) -> impl Iterator<Item = &'static Emoji> {
+    let emoji = parse_alias(inp);
+    let variants = emoji
+        .and_then(crate::search::variants_of)
+        .unwrap_or_default();
+
+    emoji
+        .into_iter()
+        .chain(variants.iter().copied().filter(move |v| Some(*v) != emoji))
+}
+
+/// Parses each of the given Emoji names (e.g. `:tada:`) lazily, see [`parse_alias`].
+///
+/// The resulting iterator yields one item per name, which is `None` if the name is unknown.
+/// To skip the unknown ones, use [`parse_aliases_present`].
+///
+/// # Examples
+///
+/// ```
+/// use emojic::text::parse_aliases;
+///
+/// let reactions: Vec<_> = parse_aliases(vec![":tada:", ":rustacean:", ":+1:"])
+///     .map(|e| e.map(|e| e.grapheme))
+///     .collect();
+/// assert_eq!(reactions, [Some("🎉"), None, Some("👍")]);
+/// ```
+pub fn parse_aliases<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+) -> impl Iterator<Item = Option<&'static Emoji>> {
+    names.into_iter().map(parse_alias)
+}
+
+/// Parses the given Emoji names (e.g. `:tada:`) lazily, skipping the unknown ones.
+///
+/// This is the same as [`parse_aliases`], but filtering out the `None`s.
+///
+/// # Examples
+///
+/// ```
+/// use emojic::text::parse_aliases_present;
+///
+/// let reactions: Vec<_> = parse_aliases_present(":tada: :rustacean: :+1:".split(' '))
+///     .map(|e| e.grapheme)
+///     .collect();
+/// assert_eq!(reactions, ["🎉", "👍"]);
+/// ```
+pub fn parse_aliases_present<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+) -> impl Iterator<Item = &'static Emoji> {
+    names.into_iter().filter_map(parse_alias)
+}
+
+/// Returns all aliases (without colons) of the given emoji, sorted alphabetically.
+///
+/// These are all the names which [`parse_alias`] accepts for this very emoji. Notice that the
+/// variants of an emoji (e.g. the toned ones) usually have no alias of their own, and thus
+/// yield nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// use emojic::aliases_for;
+/// use emojic::flat::{CRAB, THUMBS_UP};
+/// use emojic::Tone;
+///
+/// let thumbs: Vec<_> = aliases_for(&THUMBS_UP).collect();
+/// assert_eq!(thumbs, ["+1", "thumbs_up", "thumbsup"]);
+///
+/// assert_eq!(aliases_for(&CRAB).collect::<Vec<_>>(), ["crab"]);
+/// assert_eq!(aliases_for(THUMBS_UP.tone(Tone::Dark)).next(), None);
+/// ```
+pub fn aliases_for(emoji: &Emoji) -> impl Iterator<Item = &'static str> {
+    let grapheme = emoji.grapheme;
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hash-tables")] {
+            // If we have alloc, we use the inverted hash map
+            ALIASES_BY_GRAPHEME
+                .get(grapheme)
+                .map(Vec::as_slice)
+                .unwrap_or_default()
+                .iter()
+                .copied()
+        } else {
+            // As a fallback, we go through the sorted list of all aliases
+            crate::matching::ALIASES
+                .iter()
+                .filter(move |(_, e)| e.grapheme == grapheme)
+                .map(|(alias, _)| *alias)
+        }
+    }
+}
+
+/// Returns an iterator over all aliases (without colons) and their emojis, in no particular order
+#[cfg(feature = "alloc")]
+pub(crate) fn all_aliases() -> impl Iterator<Item = (&'static str, &'static Emoji)> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hash-tables")] {
+            crate::alias::GEMOJI_MAP.iter().map(|(&alias, &emoji)| (alias, emoji))
+        } else {
+            crate::matching::ALIASES.iter().copied()
+        }
+    }
+}
+
 /// Parses a pice of string into an emoji (no colons)
-fn parse_pure_alias(inp: &str) -> Option<&'static Emoji> {
+pub(crate) fn parse_pure_alias(inp: &str) -> Option<&'static Emoji> {
     cfg_if::cfg_if! {
-        if #[cfg(feature = "alloc")] {
+        if #[cfg(feature = "fst")] {
+            // If requested, we use the compact FST
+            crate::alias_fst::lookup(inp)
+        } else if #[cfg(feature = "hash-tables")] {
             // If we have alloc, we use the faster hash map
             crate::alias::GEMOJI_MAP.get(inp).cloned()
         } else {
-            // As a fallback, we can also use a huge match statement
+            // As a fallback, we binary search the sorted slice
             crate::matching::matching(inp)
         }
     }
 }
 
+/// Parses a piece of string into an emoji (no colons) as by [`parse_alias_loose`]
+fn parse_loose_alias(inp: &str) -> Option<&'static Emoji> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hash-tables")] {
+            // If we have alloc, we use the faster hash map
+            LOOSE_ALIAS_MAP.get(&loose_alias_chars(inp).collect::<String>()).copied()
+        } else {
+            // As a fallback, we simply go through all of them
+            crate::matching::ALIASES
+                .iter()
+                .find(|(alias, _)| loose_alias_chars(alias).eq(loose_alias_chars(inp)))
+                .map(|(_, emoji)| *emoji)
+        }
+    }
+}
+
+/// Iterates the chars of an alias folded for loose comparisons.
+///
+/// The chars are lowercased and separators become underscores, except for a leading sign (as in
+/// `-1`).
+fn loose_alias_chars(alias: &str) -> impl Iterator<Item = char> + '_ {
+    alias.chars().enumerate().map(|(i, c)| match c {
+        ' ' | '-' | '_' if i > 0 => '_',
+        c => c.to_ascii_lowercase(),
+    })
+}
+
+/// The maximum edit distance of suggestions by [`suggest_aliases`]
+#[cfg(feature = "alloc")]
+const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+/// Suggests valid emoji aliases that are similar to the given (possibly misspelled) one.
+///
+/// The given `name` may be colon-fenced or not. The returned aliases are without colons, they
+/// are ranked by their (case-insensitive) Levenshtein distance to `name`, where ties are ordered
+/// alphabetically. Only aliases with a distance of at most 3 are returned, and not more than
+/// `max_results` of them.
+///
+/// This is useful to give hints to users who mistyped an alias, which
+/// [`parse_alias`] could not find.
+///
+/// # Examples
+///
+/// ```rust
+/// use emojic::text::suggest_aliases;
+///
+/// assert_eq!(None, emojic::parse_alias(":tchnologist:"));
+/// assert_eq!(vec!["technologist"], suggest_aliases(":tchnologist:", 1));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn suggest_aliases(name: &str, max_results: usize) -> Vec<&'static str> {
+    let name = name.strip_prefix(':').unwrap_or(name);
+    let name = name.strip_suffix(':').unwrap_or(name);
+
+    // Reused for all distance computations
+    let mut row = Vec::new();
+
+    let mut found: Vec<(usize, &'static str)> = all_aliases()
+        .map(|(alias, _)| alias)
+        .filter(|alias| alias.len().abs_diff(name.len()) <= MAX_SUGGESTION_DISTANCE)
+        .map(|alias| (levenshtein(name, alias, &mut row), alias))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .collect();
+    found.sort_unstable();
+
+    found
+        .into_iter()
+        .take(max_results)
+        .map(|(_, alias)| alias)
+        .collect()
+}
+
+/// Computes the Levenshtein distance between `from` and the `to` alias.
+///
+/// The `row` is just a buffer, which is overwritten and can be reused between calls.
+#[cfg(feature = "alloc")]
+fn levenshtein(from: &str, to: &str, row: &mut Vec<usize>) -> usize {
+    row.clear();
+    row.extend(0..=to.chars().count());
+
+    for (i, a) in from.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in to.chars().enumerate() {
+            let above = row[j + 1];
+            let substitution = diagonal + usize::from(a.to_ascii_lowercase() != b);
+            row[j + 1] = substitution.min(above + 1).min(row[j] + 1);
+            diagonal = above;
+        }
+    }
+
+    row[row.len() - 1]
+}
+
 /// Replaces all gemojis (`:[a-z0-9_+-]+:`) found in `text` with their Unicode equivalent.
 ///
 /// This function is a convenience function for [`EmojiTextParser`]:
@@ -97,155 +436,1515 @@ fn parse_pure_alias(inp: &str) -> Option<&'static Emoji> {
 ///     "Neither std::iter::Iterator nor :rustaceans: are emojis",
 /// );
 /// ```
+///
+/// ```rust
+/// use emojic::text::parse_text;
+/// assert_eq!(&parse_text("Bye :wave::skin-tone-3:"), "Bye 👋🏼");
+/// ```
 #[cfg(feature = "alloc")]
 #[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
 pub fn parse_text(text: &str) -> String {
     EmojiTextParser::new(text).collect()
 }
 
-/// Finds and replaces gemojis (`:[a-z0-9_+-]+:`) in text.
+/// Replaces the gemojis (`:[a-z0-9_+-]+:`) in the given `text` by whatever `replacement` returns
+/// for their emoji.
 ///
-/// This is the iterator behind [`parse_text`].
+/// This generalizes [`parse_text`], which replaces each alias by the emoji itself, i.e. it is
+/// equivalent to `replace_with(text, |e| e.grapheme)`. This allows to render the emojis
+/// differently, e.g. as images. Unknown aliases are kept as they are, and an alias with a skin
+/// tone suffix is passed as the toned emoji.
 ///
-/// `EmojiTextParser` simply splits its input text into multiple fragments,
-/// those of plain text, and those which are replacement emoji sequences.
+/// # Example
 ///
-/// Notice, that since this is simple iterator, it dose not depend on `alloc`,
-/// unlike the convenience function [`parse_text`].
+/// ```rust
+/// use emojic::text::replace_with;
+///
+/// let html = replace_with("Hello :waving_hand:!", |e| {
+///     format!(r#"<img alt="{}" src="{}.png">"#, e.grapheme, e.name.replace(' ', "_"))
+/// });
+/// assert_eq!(html, r#"Hello <img alt="👋" src="waving_hand.png">!"#);
+///
+/// // Some renderings are static
+/// let named = replace_with(":crab: and :unknown:", |e| e.name);
+/// assert_eq!(named, "crab and :unknown:");
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn replace_with<S: AsRef<str>>(
+    text: &str,
+    mut replacement: impl FnMut(&'static Emoji) -> S,
+) -> String {
+    let mut output = String::with_capacity(text.len());
+    for frag in EmojiTextParser::new(text).fragments() {
+        match frag {
+            Fragment::Emoji(emoji) => output.push_str(replacement(emoji).as_ref()),
+            Fragment::Text(text) => output.push_str(text),
+        }
+    }
+    output
+}
+
+/// Writes the given `text` with its gemojis (`:[a-z0-9_+-]+:`) replaced into `out`.
+///
+/// This is an alternative to [`parse_text`] which does not allocate a `String`, and thus
+/// also works without the `alloc` feature, e.g. writing into a fixed-size buffer (such as the
+/// `String` of the `heapless` crate) or directly into some logger.
 ///
 /// # Example
 ///
 /// ```rust
-/// use emojic::text::EmojiTextParser;
+/// use emojic::text::write_parsed;
 ///
-/// let input = "Hello :waving_hand:, I am a :technologist:.";
-/// let mut parser = EmojiTextParser::new(input);
+/// let mut out = String::new(); // or any other `fmt::Write`
+/// write_parsed(&mut out, "Hello :waving_hand:, I am a :technologist:.").unwrap();
+/// assert_eq!(out, "Hello 👋, I am a 🧑‍💻.");
+/// ```
+pub fn write_parsed(out: &mut impl fmt::Write, text: &str) -> fmt::Result {
+    EmojiTextParser::new(text).try_for_each(|frag| out.write_str(frag))
+}
+
+/// Error of a too small buffer, as returned by [`parse_into`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufferTooSmall {
+    /// The number of bytes needed to hold the entire parsed text
+    pub needed: usize,
+}
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "Buffer too small, {} bytes needed", self.needed)
+    }
+}
+impl core::error::Error for BufferTooSmall {}
+
+/// Writes the given `text` with its gemojis (`:[a-z0-9_+-]+:`) replaced into the buffer `out`.
 ///
-/// assert_eq!(Some("Hello "), parser.next());
-/// assert_eq!(Some("👋"), parser.next());
-/// assert_eq!(Some(", I am a "), parser.next());
-/// assert_eq!(Some("🧑‍💻"), parser.next());
-/// assert_eq!(Some("."), parser.next());
-/// assert_eq!(None, parser.next());
+/// This is an alternative to [`parse_text`] for environments without any allocator, the
+/// returned number of bytes written to the beginning of `out` are valid UTF-8. If `out` is too
+/// small, the number of bytes needed is returned as error instead, while the content of `out` is
+/// unspecified.
+///
+/// # Example
+///
+/// ```rust
+/// use emojic::text::{parse_into, BufferTooSmall};
+///
+/// let mut buf = [0; 32];
+/// let len = parse_into("Hello :waving_hand:!", &mut buf).unwrap();
+/// assert_eq!(core::str::from_utf8(&buf[..len]), Ok("Hello 👋!"));
+///
+/// let mut buf = [0; 8];
+/// assert_eq!(
+///     Err(BufferTooSmall { needed: 11 }),
+///     parse_into("Hello :waving_hand:!", &mut buf)
+/// );
 /// ```
+pub fn parse_into(text: &str, out: &mut [u8]) -> Result<usize, BufferTooSmall> {
+    let mut len = 0;
+    for frag in EmojiTextParser::new(text) {
+        let end = len + frag.len();
+        // Once a fragment didn't fit, we just count the remaining ones
+        if let Some(dst) = out.get_mut(len..end) {
+            dst.copy_from_slice(frag.as_bytes());
+        }
+        len = end;
+    }
+
+    if len <= out.len() {
+        Ok(len)
+    } else {
+        Err(BufferTooSmall { needed: len })
+    }
+}
+
+/// Error of an unknown alias in a text, as returned by [`parse_text_strict`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnknownAlias<'a> {
+    /// The unknown alias (without colons)
+    pub alias: &'a str,
+    /// The byte offset of the alias' opening colon in the text
+    pub offset: usize,
+}
+impl<'a> fmt::Display for UnknownAlias<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "Unknown emoji alias :{}: at byte {}",
+            self.alias, self.offset
+        )
+    }
+}
+impl<'a> core::error::Error for UnknownAlias<'a> {}
+
+/// Replaces all gemojis found in `text` with their Unicode equivalent, failing on unknown ones.
 ///
-#[derive(Debug, Clone)]
-pub struct EmojiTextParser<'a> {
-    /// The original string that is parsed, used to fetch the output strings of
-    /// the plain text sequences.
-    original: &'a str,
-    /// The next index into `original` to be processed, might be `original.len()`
-    next_pos: usize,
-    /// Indicates whether the next call to `next` has to process an emoji.
-    emoji_fragment_start: bool,
+/// This is the strict version of [`parse_text`]. Instead of keeping unknown colon-fenced aliases
+/// as literal text, the first one found is returned as error. Only what looks like an alias
+/// (i.e. `:[a-z0-9_+-]+:`) is regarded, thus other colons in the text are fine. This is useful
+/// for linting texts for misspelled aliases. Notice however, that e.g. a path such as
+/// `std::iter::Iterator` contains such a token (`:iter:`), and thus is an error, too.
+///
+/// # Example
+///
+/// ```rust
+/// use emojic::text::{parse_text_strict, UnknownAlias};
+///
+/// assert_eq!(
+///     parse_text_strict("Hello :waving_hand:, see std::iter").as_deref(),
+///     Ok("Hello 👋, see std::iter"),
+/// );
+/// assert_eq!(
+///     parse_text_strict("Hello :wavin_hand:!"),
+///     Err(UnknownAlias { alias: "wavin_hand", offset: 6 }),
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn parse_text_strict(text: &str) -> Result<String, UnknownAlias<'_>> {
+    let mut parser = EmojiTextParser::new(text);
+    let mut output = String::with_capacity(text.len());
+
+    loop {
+        let start = parser.fragment_start();
+        let frag = match parser.next_fragment() {
+            Some(frag) => frag,
+            None => return Ok(output),
+        };
+
+        if let Some(alias) = unknown_alias(text, start, frag) {
+            return Err(UnknownAlias {
+                alias,
+                offset: start,
+            });
+        }
+
+        output.push_str(frag.as_str());
+    }
+}
+
+/// Returns the unknown alias (without colons), if `frag` at the byte offset `start` of `text`
+/// is one.
+#[cfg(feature = "alloc")]
+fn unknown_alias<'a>(text: &str, start: usize, frag: Fragment<'a>) -> Option<&'a str> {
+    // An unknown alias is kept as text, without its closing colon
+    let alias = match frag {
+        Fragment::Text(t) => t.strip_prefix(':')?,
+        Fragment::Emoji(_) => return None,
+    };
+    if !alias.is_empty()
+        && alias.chars().all(EmojiTextParser::is_valid_emoji_char)
+        && text[start + 1 + alias.len()..].starts_with(':')
+    {
+        Some(alias)
+    } else {
+        None
+    }
+}
+
+/// Statistics of the replacements done by [`parse_text_stats`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReplacementStats {
+    /// The number of aliases replaced by their emoji
+    pub replaced: usize,
+    /// The number of unknown colon-fenced aliases kept as text, see [`parse_text_strict`]
+    pub unknown: usize,
+    /// The distinct emojis replaced, in the order of their first occurrence
+    pub emojis: Vec<&'static Emoji>,
+}
+
+/// Replaces all gemojis found in `text` with their Unicode equivalent, just as [`parse_text`],
+/// and also returns statistics about these replacements.
+///
+/// # Example
+///
+/// ```rust
+/// use emojic::text::parse_text_stats;
+///
+/// let (text, stats) = parse_text_stats(":+1: :tada: :thumbsup: :+1: :tadaa:");
+/// assert_eq!(text, "👍 🎉 👍 👍 :tadaa:");
+/// assert_eq!(4, stats.replaced);
+/// assert_eq!(1, stats.unknown);
+/// assert_eq!(
+///     vec![&*emojic::flat::THUMBS_UP, &emojic::flat::PARTY_POPPER],
+///     stats.emojis
+/// );
+///
+/// println!("You used {} emojis ({} unique)", stats.replaced, stats.emojis.len());
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn parse_text_stats(text: &str) -> (String, ReplacementStats) {
+    let mut parser = EmojiTextParser::new(text);
+    let mut output = String::with_capacity(text.len());
+    let mut stats = ReplacementStats::default();
+
+    loop {
+        let start = parser.fragment_start();
+        let frag = match parser.next_fragment() {
+            Some(frag) => frag,
+            None => return (output, stats),
+        };
+
+        match frag {
+            Fragment::Emoji(emoji) => {
+                stats.replaced += 1;
+                if !stats.emojis.contains(&emoji) {
+                    stats.emojis.push(emoji);
+                }
+            }
+            Fragment::Text(_) => {
+                if unknown_alias(text, start, frag).is_some() {
+                    stats.unknown += 1;
+                }
+            }
+        }
+
+        output.push_str(frag.as_str());
+    }
+}
+
+/// Replaces all Unicode emojis found in `text` by their colon-fenced alias.
+///
+/// This is the reverse of [`parse_text`]. If an emoji has multiple aliases, the one spelling out
+/// its name is preferred (e.g. `:thumbs_up:` instead of `:+1:`). Emojis which are composed
+/// of other emojis (e.g. 👨‍👩‍👧) are always replaced as a whole.
+///
+/// Since toned emojis have no aliases on their own, they are represented by the alias of
+/// their untoned emoji followed by a skin tone alias (e.g. `:waving_hand::skin-tone-6:`), as it
+/// is customary on GitHub and Slack, where `:skin-tone-2:` is [`Tone::Light`](crate::Tone::Light)
+/// and `:skin-tone-6:` is [`Tone::Dark`](crate::Tone::Dark). Emojis without any alias are kept as they are.
+///
+/// # Example
+///
+/// ```rust
+/// use emojic::text::demojize;
+/// assert_eq!(
+///     &demojize("Hello 👋, I am a 🧑‍💻."),
+///     "Hello :waving_hand:, I am a :technologist:.",
+/// );
+/// assert_eq!(&demojize("👋🏿"), ":waving_hand::skin-tone-6:");
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn demojize(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+
+    for frag in EmojiScanner::new(text) {
+        match frag {
+            Fragment::Emoji(e) => push_alias(&mut output, e.grapheme),
+            Fragment::Text(t) => output.push_str(t),
+        }
+    }
+
+    output
+}
+
+/// Tests whether the aliases in `text` survive a round trip through [`parse_text`] and
+/// [`demojize`], i.e. whether `demojize(&parse_text(text)) == text`.
+///
+/// Aliases are lossy: an emoji with multiple aliases is always demojized to its preferred one
+/// (e.g. `:wave:` becomes 👋 and then `:waving_hand:`). Thus, this is useful to detect whether a
+/// text, e.g. in an editor, would change when it is stored as emojis and edited as aliases.
+/// Notice, Unicode emojis already contained in `text` are replaced by [`demojize`] as well,
+/// therefore such a text is never round-trip safe.
+///
+/// # Example
+///
+/// ```rust
+/// use emojic::text::is_roundtrip_safe;
+/// assert!(is_roundtrip_safe("Hello :waving_hand::skin-tone-6:!"));
+/// assert!(is_roundtrip_safe("Neither :) nor :rustaceans: are emojis"));
+/// assert!(!is_roundtrip_safe("Hello :wave:!"));
+/// assert!(!is_roundtrip_safe("Hello 👋!"));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn is_roundtrip_safe(text: &str) -> bool {
+    demojize(&parse_text(text)) == text
+}
+
+/// Removes all Unicode emojis from `text`.
+///
+/// Emojis which are composed of other emojis (e.g. 👨‍👩‍👧 or 👋🏿) are removed as a whole, while
+/// all other text, including the whitespace around the emojis, is kept as it is.
+///
+/// Unless `collapse_spaces` is set, which drops any whitespace that would follow other
+/// whitespace (or start the text) just because an emoji in between was removed.
+///
+/// # Example
+///
+/// ```rust
+/// use emojic::text::strip_emojis;
+/// assert_eq!(&strip_emojis("Hi 👋, I am a 🧑‍💻.", false), "Hi , I am a .");
+/// assert_eq!(&strip_emojis("Hi 👋 there", false), "Hi  there");
+/// assert_eq!(&strip_emojis("Hi 👋 there", true), "Hi there");
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn strip_emojis(text: &str, collapse_spaces: bool) -> String {
+    let mut output = String::with_capacity(text.len());
+    // Whether an emoji was removed since the last non-whitespace char
+    let mut removed = false;
+
+    for frag in EmojiScanner::new(text) {
+        let t = match frag {
+            Fragment::Emoji(_) => {
+                removed = true;
+                continue;
+            }
+            Fragment::Text(t) => t,
+        };
+        for c in t.chars() {
+            let after_space = output.chars().last().map_or(true, char::is_whitespace);
+            if !c.is_whitespace() {
+                removed = false;
+                output.push(c);
+            } else if !(collapse_spaces && removed && after_space) {
+                output.push(c);
+            }
+        }
+    }
+
+    output
+}
+
+/// Counts the Unicode emojis in `text`.
+///
+/// Emojis which are composed of other emojis (e.g. 👨‍👩‍👧 or 🇩🇪) are counted only once.
+///
+/// Notice, this function does not need `alloc`, however, without it, it is considerably
+/// slower.
+///
+/// # Example
+///
+/// ```rust
+/// use emojic::text::count_emojis;
+/// assert_eq!(count_emojis("Hi 👋, I am a 🧑‍💻."), 2);
+/// assert_eq!(count_emojis("👨‍👩‍👧"), 1);
+/// assert_eq!(count_emojis("Hi :wave:"), 0);
+/// ```
+pub fn count_emojis(text: &str) -> usize {
+    EmojiScanner::new(text)
+        .filter(|frag| matches!(frag, Fragment::Emoji(_)))
+        .count()
+}
+
+/// Tests whether `text` contains any Unicode emoji.
+///
+/// This is a cheaper alternative to `count_emojis(text) > 0`, as it stops at the first emoji.
+/// Just like [`count_emojis`] it does not need `alloc`.
+///
+/// # Example
+///
+/// ```rust
+/// use emojic::text::contains_emoji;
+/// assert!(contains_emoji("Hi 👋"));
+/// assert!(!contains_emoji("Hi :wave:"));
+/// ```
+pub fn contains_emoji(text: &str) -> bool {
+    EmojiScanner::new(text).any(|frag| matches!(frag, Fragment::Emoji(_)))
+}
+
+/// Returns the preferred alias (without colons) of the given emoji `grapheme`, if any
+#[cfg(feature = "alloc")]
+fn preferred_alias(grapheme: &str) -> Option<&'static str> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hash-tables")] {
+            ALIAS_BY_GRAPHEME.get(grapheme).copied()
+        } else {
+            all_aliases()
+                .filter(|(_, emoji)| emoji.grapheme == grapheme)
+                .min_by_key(|&(alias, emoji)| alias_rank(alias, emoji))
+                .map(|(alias, _)| alias)
+        }
+    }
+}
+
+/// Appends the colon-fenced alias of the given emoji `grapheme` to `output`
+#[cfg(feature = "alloc")]
+fn push_alias(output: &mut String, grapheme: &str) {
+    if let Some(alias) = preferred_alias(grapheme) {
+        output.push(':');
+        output.push_str(alias);
+        output.push(':');
+        return;
+    }
+
+    // Toned emojis have no alias, so try the untoned one (which might need a variation selector)
+    let mut tones = grapheme.chars().filter_map(skin_tone);
+    if let Some(tone) = tones.next() {
+        if tones.all(|t| t == tone) {
+            let mut untoned: String = grapheme
+                .chars()
+                .filter(|c| skin_tone(*c).is_none())
+                .collect();
+            let alias = preferred_alias(&untoned).or_else(|| {
+                untoned.push('\u{FE0F}');
+                preferred_alias(&untoned)
+            });
+            if let Some(alias) = alias {
+                output.push(':');
+                output.push_str(alias);
+                output.push_str("::skin-tone-");
+                output.push(char::from(b'2' + tone as u8));
+                output.push(':');
+                return;
+            }
+        }
+    }
+
+    output.push_str(grapheme);
+}
+
+/// The start of a skin tone suffix of an alias, e.g. `:wave::skin-tone-3:`
+const SKIN_TONE_PREFIX: &str = ":skin-tone-";
+/// The length of a skin tone suffix, i.e. the prefix, the number, and the closing colon
+const SKIN_TONE_SUFFIX_LEN: usize = SKIN_TONE_PREFIX.len() + 2;
+
+/// Finds and replaces gemojis (`:[a-z0-9_+-]+:`) in text.
+///
+/// This is the iterator behind [`parse_text`].
+///
+/// `EmojiTextParser` simply splits its input text into multiple fragments,
+/// those of plain text, and those which are replacement emoji sequences.
+///
+/// An alias may be followed by a skin tone suffix, as in `:wave::skin-tone-3:` (with the tones
+/// numbered from 2 for light to 6 for dark, as [`demojize`] writes them), which is then replaced
+/// by the respective toned emoji. If the emoji has no such variant, the suffix is kept as text.
+///
+/// Instead of colons, the aliases may be enclosed by other delimiters, see
+/// [`with_delimiters`](Self::with_delimiters). To keep some delimiters as they are, they can be
+/// escaped, see [`with_escape`](Self::with_escape).
+///
+/// Notice, that since this is simple iterator, it dose not depend on `alloc`,
+/// unlike the convenience function [`parse_text`].
+///
+/// # Example
+///
+/// ```rust
+/// use emojic::text::EmojiTextParser;
+///
+/// let input = "Hello :waving_hand:, I am a :technologist:.";
+/// let mut parser = EmojiTextParser::new(input);
+///
+/// assert_eq!(Some("Hello "), parser.next());
+/// assert_eq!(Some("👋"), parser.next());
+/// assert_eq!(Some(", I am a "), parser.next());
+/// assert_eq!(Some("🧑‍💻"), parser.next());
+/// assert_eq!(Some("."), parser.next());
+/// assert_eq!(None, parser.next());
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct EmojiTextParser<'a> {
+    /// The original string that is parsed, used to fetch the output strings of
+    /// the plain text sequences.
+    original: &'a str,
+    /// The next index into `original` to be processed, might be `original.len()`
+    next_pos: usize,
+    /// Indicates whether the next call to `next` has to process an emoji.
+    emoji_fragment_start: bool,
+    /// The delimiter opening an alias
+    open: char,
+    /// The delimiter closing an alias
+    close: char,
+    /// The char escaping a delimiter, if any
+    escape: Option<char>,
+    /// Indicates whether the char at `next_pos` is an escaped one, i.e. just text.
+    escaped: bool,
+}
+impl<'a> EmojiTextParser<'a> {
+    /// Creates a new parser for the given `original` text.
+    pub fn new(original: &'a str) -> Self {
+        Self::with_delimiters(original, ':', ':')
+    }
+
+    /// Creates a new parser for the given `original` text, with aliases enclosed by the given
+    /// delimiters instead of colons.
+    ///
+    /// This allows to parse other shortcode conventions, e.g. `[waving_hand]`. Also the skin
+    /// tone suffix uses these delimiters, e.g. `[wave][skin-tone-3]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the delimiters could be part of an alias (i.e. is an ASCII alphanumeric
+    /// char or any of `_`, `+`, or `-`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use emojic::text::EmojiTextParser;
+    ///
+    /// let input = "Hello [waving_hand], I am a [technologist] [wave][skin-tone-3]";
+    /// let parsed: String = EmojiTextParser::with_delimiters(input, '[', ']').collect();
+    /// assert_eq!(parsed, "Hello 👋, I am a 🧑‍💻 👋🏼");
+    ///
+    /// // Unknown aliases and the colons are just text
+    /// let input = "[unknown] :crab: [crab]";
+    /// let parsed: String = EmojiTextParser::with_delimiters(input, '[', ']').collect();
+    /// assert_eq!(parsed, "[unknown] :crab: 🦀");
+    /// ```
+    pub fn with_delimiters(original: &'a str, open: char, close: char) -> Self {
+        assert!(
+            !Self::is_valid_emoji_char(open) && !Self::is_valid_emoji_char(close),
+            "Invalid alias delimiters: {:?} and {:?}",
+            open,
+            close
+        );
+
+        Self {
+            original,
+            next_pos: 0,
+            // The very beginning is never a emoji
+            emoji_fragment_start: false,
+            open,
+            close,
+            escape: None,
+            escaped: false,
+        }
+    }
+
+    /// Enables escaping the delimiters by the given `escape` char.
+    ///
+    /// An escaped delimiter (e.g. `\:`) is output as just the delimiter, and it neither opens nor
+    /// closes an alias, thus `\:crab\:` becomes a literal `:crab:`. The `escape` char itself is
+    /// escaped by doubling it. Any other occurrence of the `escape` char is just text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `escape` char could be part of an alias (see
+    /// [`with_delimiters`](Self::with_delimiters)) or is one of the delimiters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use emojic::text::EmojiTextParser;
+    ///
+    /// let input = r"Write \:crab\: to get :crab:, and C:\\:crab:";
+    /// let parsed: String = EmojiTextParser::new(input).with_escape('\\').collect();
+    /// assert_eq!(parsed, r"Write :crab: to get 🦀, and C:\🦀");
+    /// ```
+    pub fn with_escape(mut self, escape: char) -> Self {
+        assert!(
+            !Self::is_valid_emoji_char(escape) && escape != self.open && escape != self.close,
+            "Invalid escape char: {:?}",
+            escape
+        );
+
+        self.escape = Some(escape);
+        self
+    }
+
+    /// Turns this parser into an iterator over typed [`Fragment`]s.
+    ///
+    /// Unlike this parser itself, which yields the emojis just as strings, this allows to tell
+    /// which fragments are replaced emojis and which are plain text. Also, there are no empty
+    /// text fragments.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use emojic::text::{EmojiTextParser, Fragment};
+    ///
+    /// let mut fragments = EmojiTextParser::new("Hello :waving_hand:!").fragments();
+    ///
+    /// assert_eq!(Some(Fragment::Text("Hello ")), fragments.next());
+    /// assert_eq!(Some(Fragment::Emoji(&emojic::flat::WAVING_HAND)), fragments.next());
+    /// assert_eq!(Some(Fragment::Text("!")), fragments.next());
+    /// assert_eq!(None, fragments.next());
+    /// ```
+    pub fn fragments(self) -> Fragments<'a> {
+        Fragments {
+            back: self.original.len(),
+            parser: self,
+        }
+    }
+
+    /// Creates a parser for text given as chars, e.g. as it is streamed in.
+    ///
+    /// Unlike [`new`](Self::new), this does not need the entire text upfront, instead the
+    /// returned [`EmojiCharsParser`] consumes the chars as it goes, and yields owned fragments,
+    /// which concatenated are equal to what [`parse_text`] returns. However, since an alias can
+    /// only be replaced when its closing colon is found, plain text fragments are only yielded
+    /// at the next colon or at the end of the chars.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use emojic::text::EmojiTextParser;
+    ///
+    /// let input = "Hello :waving_hand:, I am a :technologist:.";
+    /// let mut parser = EmojiTextParser::from_chars(input.chars());
+    ///
+    /// assert_eq!(Some("Hello "), parser.next().as_deref());
+    /// assert_eq!(Some("👋"), parser.next().as_deref());
+    /// assert_eq!(Some(", I am a "), parser.next().as_deref());
+    /// assert_eq!(Some("🧑‍💻"), parser.next().as_deref());
+    /// assert_eq!(Some("."), parser.next().as_deref());
+    /// assert_eq!(None, parser.next());
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+    pub fn from_chars<I: IntoIterator<Item = char>>(chars: I) -> EmojiCharsParser<I::IntoIter> {
+        EmojiCharsParser {
+            source: Lookahead::new(chars.into_iter()),
+            alias: None,
+        }
+    }
+
+    /// Returns the offset of the next fragment in `original`, including its opening delimiter
+    fn fragment_start(&self) -> usize {
+        if self.emoji_fragment_start {
+            self.next_pos - self.open.len_utf8()
+        } else {
+            self.next_pos
+        }
+    }
+
+    fn is_valid_emoji_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == '_' || c == '+' || c == '-'
+    }
+
+    /// Parses a skin tone suffix such as `:skin-tone-N:` (with `N` from 2 to 6, and enclosed by
+    /// the given delimiters) at the start of `text`, returning the tone and the length of the
+    /// suffix.
+    fn skin_tone_suffix(text: &str, open: char, close: char) -> Option<(Tone, usize)> {
+        let rest = text.strip_prefix(open)?;
+        let rest = rest.strip_prefix(&SKIN_TONE_PREFIX[1..])?;
+        let tone = match rest.as_bytes().first()? {
+            b'2' => Tone::Light,
+            b'3' => Tone::MediumLight,
+            b'4' => Tone::Medium,
+            b'5' => Tone::MediumDark,
+            b'6' => Tone::Dark,
+            _ => return None,
+        };
+        rest[1..].strip_prefix(close)?;
+
+        Some((
+            tone,
+            SKIN_TONE_SUFFIX_LEN - 2 + open.len_utf8() + close.len_utf8(),
+        ))
+    }
+
+    /// Tests whether the given char can be escaped
+    fn is_escapable(&self, c: char) -> bool {
+        c == self.open || c == self.close || Some(c) == self.escape
+    }
+
+    fn text_until_next_open(&mut self, start_idx: usize, skip: usize) -> &'a str {
+        let from = start_idx + skip;
+        let mut chars = self.original[from..].char_indices().peekable();
+
+        while let Some((i, c)) = chars.next() {
+            let idx = from + i;
+            if c == self.open {
+                // Found an opening delimiter, so let's continue next time behind it
+                self.emoji_fragment_start = true;
+                self.next_pos = idx + c.len_utf8();
+
+                return &self.original[start_idx..idx];
+            } else if Some(c) == self.escape
+                && chars.peek().is_some_and(|&(_, n)| self.is_escapable(n))
+            {
+                // Found an escaped char, so let's continue next time with it as text
+                self.emoji_fragment_start = false;
+                self.escaped = true;
+                self.next_pos = idx + c.len_utf8();
+
+                return &self.original[start_idx..idx];
+            }
+        }
+
+        // There are no further fragment
+        self.emoji_fragment_start = false;
+        self.next_pos = self.original.len(); // the end
+
+        &self.original[start_idx..]
+    }
+}
+impl<'a> EmojiTextParser<'a> {
+    /// Returns the next fragment, the actual implementation of the iterators
+    fn next_fragment(&mut self) -> Option<Fragment<'a>> {
+        if self.emoji_fragment_start {
+            // We must be preceded by the opening delimiter, so `next_pos` must not be zero
+            debug_assert!(self.next_pos > 0);
+
+            let start_idx = self.next_pos - self.open.len_utf8();
+
+            let chars = self.original[self.next_pos..].char_indices();
+
+            // Validate all chars for an emoji alias
+            for (i, c) in chars {
+                if c == self.close {
+                    let current_pos = self.next_pos + i;
+                    // This is the closing delimiter
+
+                    let emoji_name = &self.original[self.next_pos..current_pos];
+                    let emoji = if emoji_name.is_empty() {
+                        None
+                    } else {
+                        crate::text::parse_pure_alias(emoji_name)
+                    };
+
+                    if let Some(e) = emoji {
+                        self.emoji_fragment_start = false;
+                        self.next_pos = current_pos + self.close.len_utf8();
+
+                        // An alias might be followed by a skin tone, e.g. `:wave::skin-tone-3:`
+                        let rest = &self.original[self.next_pos..];
+                        if let Some((tone, len)) =
+                            Self::skin_tone_suffix(rest, self.open, self.close)
+                        {
+                            if let Some(toned) = crate::search::toned(e, tone) {
+                                self.next_pos += len;
+                                return Some(Fragment::Emoji(toned));
+                            }
+                        }
+
+                        return Some(Fragment::Emoji(e));
+                    } else if self.open == self.close {
+                        // Here a user might have misspelled a emoji name.
+                        // However, the conservative thing to do is to ignore it
+                        // => meaning we output it as normal text, while the closing
+                        // delimiter might open the next alias
+                        self.emoji_fragment_start = true;
+                        self.next_pos = current_pos + self.close.len_utf8();
+                        return Some(Fragment::Text(&self.original[start_idx..current_pos]));
+                    } else {
+                        // Same as above, but the closing delimiter is just text, too
+                        let skip = current_pos + self.close.len_utf8() - start_idx;
+                        return Some(Fragment::Text(self.text_until_next_open(start_idx, skip)));
+                    }
+                } else if Self::is_valid_emoji_char(c) {
+                    // A valid emoji char, lets continue
+                } else {
+                    // An invalid char, this makes this part just normal text,
+                    // so lets output everything until the next colon
+                    let skip = self.open.len_utf8();
+                    return Some(Fragment::Text(self.text_until_next_open(start_idx, skip)));
+                }
+            }
+
+            // Here we hit the end of the text, but we have not found our ending
+            // delimiter, so this is just text
+
+            // There are no further fragment
+            self.emoji_fragment_start = false;
+            self.next_pos = self.original.len(); // the end
+
+            Some(Fragment::Text(&self.original[start_idx..]))
+        } else if self.next_pos < self.original.len() {
+            // we basically look for the next opening delimiter, but an escaped char is just text
+            let skip = if self.escaped {
+                self.escaped = false;
+                self.original[self.next_pos..]
+                    .chars()
+                    .next()
+                    .map_or(0, char::len_utf8)
+            } else {
+                0
+            };
+            Some(Fragment::Text(
+                self.text_until_next_open(self.next_pos, skip),
+            ))
+        } else {
+            // No more text left
+            None
+        }
+    }
+}
+impl<'a> Iterator for EmojiTextParser<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_fragment().map(|frag| frag.as_str())
+    }
+}
+impl<'a> fmt::Display for EmojiTextParser<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let copy = self.clone();
+        for frag in copy {
+            write!(fmt, "{}", frag)?
+        }
+        Ok(())
+    }
+}
+
+/// A fragment of text as yielded by [`EmojiTextParser::fragments`] or [`EmojiScanner`].
+///
+/// This allows to tell apart the plain text from the replaced emojis, e.g. to give the
+/// emojis some special markup:
+///
+/// ```rust
+/// use emojic::text::{EmojiTextParser, Fragment};
+///
+/// let html: String = EmojiTextParser::new("I :heart: Rust")
+///     .fragments()
+///     .map(|frag| match frag {
+///         Fragment::Text(text) => text.to_string(),
+///         Fragment::Emoji(e) => format!("<span title=\"{}\">{}</span>", e.name, e),
+///     })
+///     .collect();
+///
+/// assert_eq!(html, "I <span title=\"red heart\">❤️</span> Rust");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fragment<'a> {
+    /// Plain text, which is output as it is.
+    Text(&'a str),
+    /// An emoji, which replaced its alias, or which was found in the text as it is.
+    Emoji(&'static Emoji),
+}
+impl<'a> Fragment<'a> {
+    /// Returns the text of this fragment, for an emoji, that is its grapheme.
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            Fragment::Text(text) => text,
+            Fragment::Emoji(e) => e.grapheme,
+        }
+    }
+}
+impl<'a> fmt::Display for Fragment<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+/// An iterator over the [`Fragment`]s of a text.
+///
+/// This is created by [`EmojiTextParser::fragments`].
+///
+/// The fragments can also be iterated in reverse, e.g. for right-to-left rendering. However,
+/// since the aliases can only be told apart from the front, each fragment from the back is found
+/// by parsing the remaining text from the front, which makes reverse iteration quadratic in the
+/// number of fragments.
+///
+/// # Example
+///
+/// ```rust
+/// use emojic::text::{EmojiTextParser, Fragment};
+///
+/// let mut fragments = EmojiTextParser::new("Hello :waving_hand:!").fragments();
+///
+/// assert_eq!(Some(Fragment::Text("!")), fragments.next_back());
+/// assert_eq!(Some(Fragment::Text("Hello ")), fragments.next());
+/// assert_eq!(Some(Fragment::Emoji(&emojic::flat::WAVING_HAND)), fragments.next_back());
+/// assert_eq!(None, fragments.next());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Fragments<'a> {
+    parser: EmojiTextParser<'a>,
+    /// The offset of the fragments already yielded from the back
+    back: usize,
+}
+impl<'a> Iterator for Fragments<'a> {
+    type Item = Fragment<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip the empty text fragments, which the parser yields e.g. between two aliases
+        while self.parser.fragment_start() < self.back {
+            match self.parser.next_fragment()? {
+                Fragment::Text("") => {}
+                frag => return Some(frag),
+            }
+        }
+        None
+    }
+}
+impl<'a> DoubleEndedIterator for Fragments<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // The last fragment is found by parsing from the front
+        let mut parser = self.parser.clone();
+        let mut last = None;
+        while parser.fragment_start() < self.back {
+            let start = parser.fragment_start();
+            match parser.next_fragment() {
+                Some(Fragment::Text("")) => {}
+                Some(frag) => last = Some((start, frag)),
+                None => break,
+            }
+        }
+
+        let (start, frag) = last?;
+        self.back = start;
+        Some(frag)
+    }
+}
+impl<'a> fmt::Display for Fragments<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        self.clone()
+            .try_for_each(|frag| fmt.write_str(frag.as_str()))
+    }
+}
+
+/// Splits a text into its Unicode emojis and the plain text in between.
+///
+/// This is the counterpart of the [`EmojiTextParser`] (which finds the `:aliases:`) for emojis
+/// that are already in the text, and the basis of e.g. [`demojize`] and [`count_emojis`]. Of
+/// emojis which are composed of other emojis (e.g. 👨‍👩‍👧 or 👋🏿), the longest match is yielded
+/// as a whole. Flags are only found where the regional indicators pair up as they are rendered,
+/// i.e. from the start of their run. The text fragments are never empty.
+///
+/// The fragments can also be iterated in reverse, which, like for [`Fragments`], is quadratic
+/// in the number of fragments, since the longest matches are found from the front.
+///
+/// This scanner does not need `alloc`, however, without it (or more precisely without the
+/// `hash-tables` feature), it is considerably slower.
+///
+/// # Example
+///
+/// ```rust
+/// use emojic::text::{EmojiScanner, Fragment};
+///
+/// let mut scanner = EmojiScanner::new("Hi 👋🏿, I am a 👨‍👩‍👧");
+/// assert_eq!(Some(Fragment::Text("Hi ")), scanner.next());
+/// assert_eq!(Some("👋🏿"), scanner.next().map(|frag| frag.as_str()));
+/// assert_eq!(Some(Fragment::Text(", I am a ")), scanner.next());
+/// assert_eq!(Some("family: man, woman, girl"), match scanner.next() {
+///     Some(Fragment::Emoji(e)) => Some(e.name),
+///     _ => None,
+/// });
+/// assert_eq!(None, scanner.next());
+/// ```
+#[derive(Debug, Clone)]
+pub struct EmojiScanner<'a> {
+    /// The text not yet scanned
+    rest: &'a str,
+}
+impl<'a> EmojiScanner<'a> {
+    /// Creates a scanner over the given `text`
+    pub fn new(text: &'a str) -> Self {
+        EmojiScanner { rest: text }
+    }
+}
+impl<'a> Iterator for EmojiScanner<'a> {
+    type Item = Fragment<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        if let Some(e) = crate::search::lookup_prefix(self.rest) {
+            self.rest = &self.rest[e.grapheme.len()..];
+            return Some(Fragment::Emoji(e));
+        }
+
+        // The text lasts until the next emoji, the cheap check of the first char comes first.
+        // Regional indicators pair up from the start of their run, thus only every other one
+        // may start a flag (e.g. the 🇺🇩 of 🇺🇩🇪 is no flag, yet 🇩🇪 must not be found either).
+        let mut indicators = 0;
+        let end = self
+            .rest
+            .char_indices()
+            .find(|&(i, c)| {
+                let second_indicator = is_regional_indicator(c) && indicators % 2 == 1;
+                indicators = if is_regional_indicator(c) {
+                    indicators + 1
+                } else {
+                    0
+                };
+                i > 0
+                    && !second_indicator
+                    && crate::search::is_emoji_start(c)
+                    && crate::search::lookup_prefix(&self.rest[i..]).is_some()
+            })
+            .map_or(self.rest.len(), |(i, _)| i);
+        let (text, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(Fragment::Text(text))
+    }
+}
+impl<'a> DoubleEndedIterator for EmojiScanner<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // Since the longest match wins, the last fragment is found by scanning from the front
+        let mut scanner = self.clone();
+        loop {
+            let start = self.rest.len() - scanner.rest.len();
+            let frag = scanner.next()?;
+            if scanner.rest.is_empty() {
+                self.rest = &self.rest[..start];
+                return Some(frag);
+            }
+        }
+    }
+}
+
+/// Tests whether `c` is a regional indicator symbol (🇦 to 🇿), two of which make up a flag
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c, '\u{1F1E6}'..='\u{1F1FF}')
+}
+
+/// Finds and replaces gemojis (`:[a-z0-9_+-]+:`) in text given as chars.
+///
+/// This is the counterpart of the [`EmojiTextParser`] for text which is not available as a
+/// whole, it is created by [`EmojiTextParser::from_chars`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone)]
+pub struct EmojiCharsParser<I> {
+    /// The source of the text
+    source: Lookahead<I>,
+    /// The potential alias (including its opening colon) being read, if any
+    alias: Option<String>,
+}
+
+/// A source of chars which allows to put back chars that have been read ahead
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+struct Lookahead<I> {
+    /// The source of the text
+    chars: I,
+    /// Chars which have been read ahead and still need to be processed
+    pending: VecDeque<char>,
+}
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = char>> Lookahead<I> {
+    fn new(chars: I) -> Self {
+        Lookahead {
+            chars,
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        self.pending.pop_front().or_else(|| self.chars.next())
+    }
+
+    /// Tests whether `look` is the beginning of a skin tone suffix
+    fn is_skin_tone_suffix_start(look: &str) -> bool {
+        look.chars()
+            .enumerate()
+            .all(|(i, c)| match SKIN_TONE_PREFIX.as_bytes().get(i) {
+                Some(&b) => c == char::from(b),
+                None if i == SKIN_TONE_PREFIX.len() => ('2'..='6').contains(&c),
+                None => i + 1 == SKIN_TONE_SUFFIX_LEN && c == ':',
+            })
+    }
+
+    /// Reads a potential skin tone suffix and returns the respectively toned `emoji`, if any.
+    ///
+    /// Otherwise, all the chars read ahead are kept for further processing.
+    fn read_skin_tone(&mut self, emoji: &'static Emoji) -> Option<&'static Emoji> {
+        let mut look = String::new();
+        while look.len() < SKIN_TONE_SUFFIX_LEN {
+            match self.next_char() {
+                Some(c) => look.push(c),
+                None => break,
+            }
+            if !Self::is_skin_tone_suffix_start(&look) {
+                break;
+            }
+        }
+
+        let toned = EmojiTextParser::skin_tone_suffix(&look, ':', ':')
+            .and_then(|(tone, _)| crate::search::toned(emoji, tone));
+        if toned.is_none() {
+            for c in look.chars().rev() {
+                self.pending.push_front(c);
+            }
+        }
+        toned
+    }
+}
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = char>> Iterator for EmojiCharsParser<I> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut text = String::new();
+
+        while let Some(c) = self.source.next_char() {
+            if let Some(alias) = &mut self.alias {
+                if c == ':' {
+                    alias.push(c);
+
+                    if let Some(e) = crate::parse_alias(alias) {
+                        self.alias = None;
+                        let e = self.source.read_skin_tone(e).unwrap_or(e);
+                        return Some(e.grapheme.into());
+                    } else {
+                        // Not an alias, so output it as text, but the closing colon might
+                        // be the beginning of the next alias
+                        alias.pop();
+                        return self.alias.replace(":".into());
+                    }
+                } else if EmojiTextParser::is_valid_emoji_char(c) {
+                    // A valid emoji char, lets continue
+                    alias.push(c);
+                } else {
+                    // An invalid char, this makes this part just normal text
+                    text = self.alias.take().unwrap_or_default();
+                    text.push(c);
+                }
+            } else if c == ':' {
+                // Potentially the beginning of an alias
+                self.alias = Some(":".into());
+                if !text.is_empty() {
+                    return Some(text);
+                }
+            } else {
+                text.push(c);
+            }
+        }
+
+        // Here we hit the end of the chars, so any unfinished alias is just text
+        if let Some(alias) = self.alias.take() {
+            text.push_str(&alias);
+        }
+
+        if text.is_empty() {
+            None
+        } else {
+            Some(text)
+        }
+    }
+}
+
+/// Replaces gemojis (`:[a-z0-9_+-]+:`) in a stream of chars by their emojis, char by char.
+///
+/// Unlike the [`EmojiCharsParser`], which yields whole fragments, this adaptor yields the plain
+/// text chars as soon as they are read, only the chars of a potential alias are held back until
+/// it is known whether it is actually an alias. It is created by [`EmojifyExt::emojify`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone)]
+pub struct Emojify<I> {
+    /// The source of the text
+    source: Lookahead<I>,
+    /// Chars which have been processed and are ready to be yielded
+    output: VecDeque<char>,
+}
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = char>> Emojify<I> {
+    /// Reads the rest of a potential alias after its opening colon into the output
+    fn read_alias(&mut self) {
+        let mut alias = String::from(":");
+
+        while let Some(c) = self.source.next_char() {
+            if c == ':' {
+                alias.push(c);
+                if let Some(e) = crate::parse_alias(&alias) {
+                    let e = self.source.read_skin_tone(e).unwrap_or(e);
+                    self.output.extend(e.grapheme.chars());
+                } else {
+                    // Not an alias, but the closing colon might be the beginning of the next one
+                    alias.pop();
+                    self.source.pending.push_front(c);
+                    self.output.extend(alias.chars());
+                }
+                return;
+            } else if EmojiTextParser::is_valid_emoji_char(c) {
+                alias.push(c);
+            } else {
+                // An invalid char, this makes this part just normal text
+                alias.push(c);
+                break;
+            }
+        }
+
+        self.output.extend(alias.chars());
+    }
+}
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = char>> Iterator for Emojify<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.output.pop_front() {
+            return Some(c);
+        }
+
+        match self.source.next_char()? {
+            ':' => {
+                self.read_alias();
+                self.output.pop_front()
+            }
+            c => Some(c),
+        }
+    }
+}
+
+/// Extension trait to replace gemojis in any iterator over chars, see [`Emojify`].
+///
+/// # Example
+///
+/// ```rust
+/// use emojic::text::EmojifyExt;
+///
+/// let input = "Hello :waving_hand:, it is 12:30 :crab:";
+/// let output: String = input.chars().emojify().collect();
+///
+/// assert_eq!("Hello 👋, it is 12:30 🦀", output);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub trait EmojifyExt: Iterator<Item = char> + Sized {
+    /// Replaces the gemojis in these chars by their emojis, as [`parse_text`] does for a `&str`
+    fn emojify(self) -> Emojify<Self> {
+        Emojify {
+            source: Lookahead::new(self),
+            output: VecDeque::new(),
+        }
+    }
 }
-impl<'a> EmojiTextParser<'a> {
-    /// Creates a new parser for the given `original` text.
-    pub fn new(original: &'a str) -> Self {
-        Self {
-            original,
-            next_pos: 0,
-            /// The very beginning is never a emoji
-            emoji_fragment_start: false,
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = char>> EmojifyExt for I {}
+
+#[cfg(test)]
+mod tests {
+    // Tests are going to be on development systems => there will be std.
+    extern crate std;
+    use std::prelude::v1::*;
+
+    use super::*;
+
+    #[test]
+    fn matching_aliases_sorted() {
+        // Required for the binary search
+        let aliases = crate::matching::ALIASES;
+        assert!(aliases.windows(2).all(|w| w[0].0 < w[1].0));
+        for (alias, emoji) in aliases {
+            assert_eq!(Some(*emoji), parse_pure_alias(alias));
         }
+        assert_eq!(None, parse_pure_alias("not_an_alias"));
+
+        // The plain alias list is the same
+        assert!(aliases
+            .iter()
+            .map(|&(alias, _)| alias)
+            .eq(crate::flat::ALL_ALIASES.iter().copied()));
     }
 
-    fn is_valid_emoji_char(c: char) -> bool {
-        c.is_ascii_alphanumeric() || c == '_' || c == '+' || c == '-'
+    #[test]
+    fn matching_signed_aliases() {
+        use crate::flat::{THUMBS_DOWN, THUMBS_UP};
+
+        // `+` and `-` sort before the digits and letters, they must be found by both backends
+        for &(alias, emoji) in &[("+1", &*THUMBS_UP), ("-1", &*THUMBS_DOWN)] {
+            assert_eq!(Some(emoji), crate::matching::matching(alias));
+            assert_eq!(Some(emoji), parse_pure_alias(alias));
+            assert_eq!(Some(emoji), parse_alias(&format!(":{}:", alias)));
+        }
+        assert_eq!(None, crate::matching::matching("+2"));
+        assert_eq!(None, crate::matching::matching("THUMBSUP"));
+        let text: String = EmojiTextParser::new(":+1::-1:").collect();
+        assert_eq!("👍👎", text);
     }
 
-    fn text_until_next_colon(&mut self, start_idx: usize, skip: usize) -> &'a str {
-        if let Some(colon_idx) = self.original[(start_idx + skip)..].find(':') {
-            let true_colon_idx = start_idx + skip + colon_idx;
-            // Found a colon, so let's continue next time behind it
-            self.emoji_fragment_start = true;
-            self.next_pos = true_colon_idx + 1;
+    #[cfg(feature = "fst")]
+    #[test]
+    fn fst_aliases_like_matching() {
+        for (alias, emoji) in crate::matching::ALIASES {
+            assert_eq!(Some(*emoji), crate::alias_fst::lookup(alias));
+        }
+        assert_eq!(None, crate::alias_fst::lookup(""));
+        assert_eq!(None, crate::alias_fst::lookup("thumbs"));
+        assert_eq!(None, crate::alias_fst::lookup("thumbsup_"));
+    }
 
-            &self.original[start_idx..true_colon_idx]
-        } else {
-            // There are no further fragment
-            self.emoji_fragment_start = false;
-            self.next_pos = self.original.len(); // the end
+    #[cfg(feature = "hash-tables")]
+    #[test]
+    fn matching_aliases_like_hash_map() {
+        // Without `hash-tables`, the very same aliases must be available
+        let aliases = crate::matching::ALIASES;
+        assert_eq!(crate::alias::GEMOJI_MAP.len(), aliases.len());
+        for (alias, emoji) in aliases {
+            assert_eq!(Some(emoji), crate::alias::GEMOJI_MAP.get(alias));
+        }
+        for (alias, emoji) in crate::alias::GEMOJI_MAP.iter() {
+            assert_eq!(Some(*emoji), crate::matching::matching(alias));
+        }
+    }
 
-            &self.original[start_idx..]
+    #[test]
+    fn aliases_for_test() {
+        use crate::flat::*;
+
+        assert_eq!(
+            ["+1", "thumbs_up", "thumbsup"],
+            aliases_for(&THUMBS_UP).collect::<Vec<_>>()[..]
+        );
+        assert_eq!(
+            ["technologist"],
+            aliases_for(&TECHNOLOGIST).collect::<Vec<_>>()[..]
+        );
+        // Every alias maps back to its emoji
+        for emoji in crate::search::all() {
+            for alias in aliases_for(emoji) {
+                assert_eq!(
+                    Some(emoji.grapheme),
+                    parse_pure_alias(alias).map(|e| e.grapheme)
+                );
+            }
         }
     }
-}
-impl<'a> Iterator for EmojiTextParser<'a> {
-    type Item = &'a str;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.emoji_fragment_start {
-            // We must be preceded by a colon, so `next_pos` must not be zero
-            debug_assert!(self.next_pos > 0);
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn parse_text_strict_test() {
+        let texts = [
+            "",
+            ":",
+            "::",
+            "Hello :wave::skin-tone-3: :crab:",
+            "Time 10:30 and std::iter",
+            "Unclosed :crab",
+            ":not an alias:",
+            "Umlauts :ü: are not in aliases",
+            "::crab::",
+        ];
+        for text in texts.iter() {
+            assert_eq!(Ok(parse_text(text)), parse_text_strict(text), "{:?}", text);
+        }
 
-            let start_idx = self.next_pos - 1;
+        let err = |alias, offset| Err(UnknownAlias { alias, offset });
+        assert_eq!(err("crabs", 0), parse_text_strict(":crabs:"));
+        assert_eq!(err("crabs", 8), parse_text_strict("A :crab::crabs:"));
+        assert_eq!(err("10", 4), parse_text_strict("At 1:10:00"));
+        assert_eq!(err("iter", 4), parse_text_strict("std::iter::Iterator"));
+        // The tone can not be applied to a crab
+        assert_eq!(
+            err("skin-tone-3", 6),
+            parse_text_strict(":crab::skin-tone-3:")
+        );
+    }
 
-            let chars = self.original[self.next_pos..].char_indices();
+    #[test]
+    fn parse_alias_all_test() {
+        // The alias refers to a variant, it comes first, followed by all other technologists
+        let found: Vec<_> = parse_alias_all(":man_technologist:").collect();
+        assert_eq!(18, found.len());
+        assert_eq!(
+            &**crate::flat::TECHNOLOGIST.gender(crate::Gender::Male),
+            found[0]
+        );
 
-            // Validate all chars for an emoji alias
-            for (i, c) in chars {
-                if c == ':' {
-                    let current_pos = self.next_pos + i;
-                    // This is the second colon
+        let found: Vec<_> = parse_alias_all(":+1:").map(|e| e.grapheme).collect();
+        assert_eq!(["👍", "👍🏻", "👍🏼", "👍🏽", "👍🏾", "👍🏿"], &found[..]);
 
-                    let emoji_name = &self.original[start_idx..=current_pos];
+        assert_eq!(None, parse_alias_all("crab").next());
+    }
 
-                    if let Some(e) = crate::parse_alias(emoji_name) {
-                        self.emoji_fragment_start = false;
-                        self.next_pos = current_pos + 1;
-                        return Some(e.grapheme);
-                    } else {
-                        // Here a user might have misspelled a emoji name.
-                        // However, the conservative thing to do is to ignore it
-                        // => meaning we output it as normal text
-                        self.emoji_fragment_start = true;
-                        self.next_pos = current_pos + 1;
-                        return Some(&self.original[start_idx..current_pos]);
-                    }
-                } else if Self::is_valid_emoji_char(c) {
-                    // A valid emoji char, lets continue
-                } else {
-                    // An invalid char, this makes this part just normal text,
-                    // so lets output everything until the next colon
-                    return Some(self.text_until_next_colon(start_idx, 1));
-                }
-            }
+    #[test]
+    fn parse_aliases_test() {
+        let names = [":crab:", "crab", ":wave:", "", ":wave::skin-tone-3:"];
+        let found: Vec<_> = parse_aliases(names.iter().copied()).collect();
+        let expected: Vec<_> = names.iter().map(|name| parse_alias(name)).collect();
+        assert_eq!(expected, found);
 
-            // Here we hit the end of the text, but we have not found our ending
-            // colon, so this is just text
+        let present: Vec<_> = parse_aliases_present(names.iter().copied()).collect();
+        assert_eq!(
+            [&crate::flat::CRAB, &*crate::flat::WAVING_HAND],
+            &present[..]
+        );
 
-            // There are no further fragment
-            self.emoji_fragment_start = false;
-            self.next_pos = self.original.len(); // the end
+        assert_eq!(None, parse_aliases(None).next());
+    }
 
-            Some(&self.original[start_idx..])
-        } else if self.next_pos < self.original.len() {
-            // we basically look for the next colon
-            Some(self.text_until_next_colon(self.next_pos, 0))
-        } else {
-            // No more text left
-            None
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn parse_alias_loose_test() {
+        // Folding the aliases must not make them ambiguous
+        for (alias, emoji) in all_aliases() {
+            let shouted = alias.to_ascii_uppercase().replace('_', " ");
+            assert_eq!(Some(emoji), parse_loose_alias(&shouted), "{}", alias);
         }
+        assert_eq!(Some(&crate::flat::T_REX), parse_alias_loose(":t_rex:"));
+        assert_eq!(None, parse_alias_loose(":_1:"));
+        assert_eq!(None, parse_alias_loose("Crab"));
     }
-}
-impl<'a> fmt::Display for EmojiTextParser<'a> {
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let copy = self.clone();
-        for frag in copy {
-            write!(fmt, "{}", frag)?
+
+    #[test]
+    fn parse_into_test() {
+        let text = "Hello :waving_hand:, I am a :technologist:.";
+        let parsed = "Hello 👋, I am a 🧑‍💻.";
+
+        let mut buf = [0; 64];
+        let len = parse_into(text, &mut buf).unwrap();
+        assert_eq!(Ok(parsed), core::str::from_utf8(&buf[..len]));
+
+        // Exactly fitting
+        assert_eq!(Ok(parsed.len()), parse_into(text, &mut buf[..parsed.len()]));
+
+        // Too small, at any fragment
+        for size in 0..parsed.len() {
+            assert_eq!(
+                Err(BufferTooSmall {
+                    needed: parsed.len()
+                }),
+                parse_into(text, &mut buf[..size])
+            );
         }
-        Ok(())
+        assert_eq!(Ok(0), parse_into("", &mut []));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    // Tests are going to be on development systems => there will be std.
-    extern crate std;
-    use std::prelude::v1::*;
+    #[test]
+    fn parser_delimiters_test() {
+        let parse = |text, open, close| {
+            EmojiTextParser::with_delimiters(text, open, close)
+                .fragments()
+                .collect::<Vec<_>>()
+        };
 
-    use super::*;
+        assert_eq!(
+            vec![
+                Fragment::Text("a "),
+                Fragment::Text("«nope» "),
+                Fragment::Emoji(&crate::flat::CRAB),
+                Fragment::Emoji(crate::flat::WAVING_HAND.tone(Tone::Dark)),
+                Fragment::Text(" "),
+                Fragment::Text("«crab"),
+            ],
+            parse("a «nope» «crab»«wave»«skin-tone-6» «crab", '«', '»')
+        );
+        assert_eq!(
+            vec![
+                Fragment::Text("%nope"),
+                Fragment::Emoji(&crate::flat::CRAB),
+                Fragment::Text("%"),
+                Fragment::Text("%"),
+            ],
+            parse("%nope%crab%%%", '%', '%')
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn parser_invalid_delimiters_test() {
+        EmojiTextParser::with_delimiters("", '_', ':');
+    }
+
+    #[test]
+    fn parser_escape_test() {
+        let cases = [
+            (r"\:crab\:", ":crab:"),
+            (r"\:crab:", ":crab:"),
+            (r":crab\:", ":crab:"),
+            (r"\\:crab:", r"\🦀"),
+            (r"\\\:crab:", r"\:crab:"),
+            (r"\a :crab: \", r"\a 🦀 \"),
+            (r":crab:\::crab:", "🦀:🦀"),
+            (r"::\::", "::::"),
+        ];
+        for &(input, output) in &cases {
+            let parsed: String = EmojiTextParser::new(input).with_escape('\\').collect();
+            assert_eq!(output, parsed);
+        }
+
+        // Escaping works with other delimiters, too
+        let parsed =
+            EmojiTextParser::with_delimiters(r"[crab] \[crab\]", '[', ']').with_escape('\\');
+        assert!(parsed.eq(["", "🦀", " ", "[crab", "]"]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn parser_invalid_escape_test() {
+        EmojiTextParser::new("").with_escape(':');
+    }
 
     #[test]
     fn parser_test() {
@@ -353,6 +2052,421 @@ mod tests {
         assert_eq!(output, &parser.collect::<String>());
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn replace_with_like_parse_text() {
+        for text in &[
+            "Hello :waving_hand:, I am a :technologist:.",
+            "Bye :wave::skin-tone-3: :",
+            ":unknown::crab::",
+            "",
+        ] {
+            assert_eq!(parse_text(text), replace_with(text, |e| e.grapheme));
+        }
+
+        let mut found = Vec::new();
+        let text = replace_with(":crab: :+1::skin-tone-6:", |e| {
+            found.push(e);
+            "x"
+        });
+        assert_eq!("x x", text);
+        assert_eq!(
+            vec![&crate::flat::CRAB, crate::flat::THUMBS_UP.tone(Tone::Dark)],
+            found
+        );
+    }
+
+    #[test]
+    fn fragments_rev_like_forward() {
+        let texts = [
+            "Hello :waving_hand:, I am a :technologist:.",
+            ":crab::crab: :x:crab: :wave::skin-tone-3::skin-tone-3:",
+            ":unknown:crab:: a:b:c :",
+            "Hi 👋🏿, I am a 👨‍👩‍👧 👨👩 text",
+            "👋👋",
+            "👨‍👩‍👧👋🏿👋",
+            "🇩🇪🇹🇷👋 🇺🇩🇪🇹🇷 🏴󠁧󠁢󠁥󠁮󠁧󠁿🏴‍☠️🇩🇪",
+            "",
+        ];
+        for text in &texts {
+            let forward: Vec<_> = EmojiTextParser::new(text).fragments().collect();
+            let mut backward: Vec<_> = EmojiTextParser::new(text).fragments().rev().collect();
+            backward.reverse();
+            assert_eq!(forward, backward);
+
+            let forward: Vec<_> = EmojiScanner::new(text).collect();
+            let mut backward: Vec<_> = EmojiScanner::new(text).rev().collect();
+            backward.reverse();
+            assert_eq!(forward, backward);
+        }
+
+        // Both ends meet
+        let mut fragments = EmojiTextParser::new(":crab: and :crab:").fragments();
+        assert_eq!(
+            Some(Fragment::Emoji(&crate::flat::CRAB)),
+            fragments.next_back()
+        );
+        assert_eq!(Some(Fragment::Emoji(&crate::flat::CRAB)), fragments.next());
+        assert_eq!(" and ", fragments.to_string());
+        assert_eq!(Some(Fragment::Text(" and ")), fragments.next_back());
+        assert_eq!(None, fragments.next());
+        assert_eq!(None, fragments.next_back());
+
+        let mut scanner = EmojiScanner::new("🦀 and 🦀");
+        assert_eq!(
+            Some(Fragment::Emoji(&crate::flat::CRAB)),
+            scanner.next_back()
+        );
+        assert_eq!(Some(Fragment::Emoji(&crate::flat::CRAB)), scanner.next());
+        assert_eq!(Some(Fragment::Text(" and ")), scanner.next_back());
+        assert_eq!(None, scanner.next());
+    }
+
+    #[test]
+    fn fragments_test() {
+        let input = ":crab: :cat :+1::skin-tone-6:";
+        let mut fragments = EmojiTextParser::new(input).fragments();
+
+        assert_eq!(Some(Fragment::Emoji(&crate::flat::CRAB)), fragments.next());
+        assert_eq!(Some(Fragment::Text(" ")), fragments.next());
+        assert_eq!(Some(Fragment::Text(":cat ")), fragments.next());
+        assert_eq!(
+            Some(Fragment::Emoji(
+                crate::flat::THUMBS_UP.tone(crate::Tone::Dark)
+            )),
+            fragments.next()
+        );
+        assert_eq!(None, fragments.next());
+    }
+
+    #[test]
+    fn fragments_display() {
+        let input = "Hello :waving_hand:, I am a :tchnologist:.";
+        let fragments = EmojiTextParser::new(input).fragments();
+
+        assert_eq!(
+            EmojiTextParser::new(input).collect::<String>(),
+            fragments.to_string()
+        );
+        assert_eq!("👋", Fragment::Emoji(&crate::flat::WAVING_HAND).to_string());
+    }
+
+    #[test]
+    fn write_parsed_test() {
+        /// A fixed-size buffer, to show that no allocation is needed
+        struct Buf {
+            bytes: [u8; 32],
+            len: usize,
+        }
+        impl fmt::Write for Buf {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.len + s.len();
+                let dst = self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?;
+                dst.copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let mut buf = Buf {
+            bytes: [0; 32],
+            len: 0,
+        };
+        assert_eq!(Ok(()), write_parsed(&mut buf, "I :heart: :crab:!"));
+        assert_eq!(Ok("I ❤️ 🦀!"), core::str::from_utf8(&buf.bytes[..buf.len]));
+
+        // Too long for the buffer
+        assert_eq!(Err(fmt::Error), write_parsed(&mut buf, &"🦀".repeat(8)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn chars_parser_test() {
+        let input = "Hello :waving_hand:, I am a :tchnologist:.";
+        let parser = EmojiTextParser::from_chars(input.chars());
+
+        assert_eq!(
+            vec!["Hello ", "👋", ", I am a ", ":tchnologist", ":."],
+            parser.collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn chars_parser_like_parse_text() {
+        let inputs = [
+            "",
+            ":",
+            ":::",
+            "Hello :: I am: a technologist, :=: :).",
+            "100: :100:100:100: :100",
+            ":thumbs_up::+1::-1::thumbs_down:",
+            "abc::technologist::def",
+            "abc:::technologist:::def",
+            ":wave::skin-tone-2: :woman_technologist::skin-tone-6::+1:",
+            ":crab::skin-tone-3: :wave::skin-tone-7: :skin-tone-3:",
+            ":wave::skin-tone-",
+            ":wave::skin:wave:",
+            ":wave::crab:",
+            "ünïcödé :crab:ü",
+        ];
+
+        for input in &inputs {
+            let output: String = EmojiTextParser::from_chars(input.chars()).collect();
+            assert_eq!(parse_text(input), output, "input: {:?}", input);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn emojify_like_parse_text() {
+        let inputs = [
+            "",
+            ":",
+            ":::",
+            "Hello :: I am: a technologist, :=: :).",
+            "100: :100:100:100: :100",
+            ":thumbs_up::+1::-1::thumbs_down:",
+            "abc:::technologist:::def",
+            ":wave::skin-tone-2: :woman_technologist::skin-tone-6::+1:",
+            ":crab::skin-tone-3: :wave::skin-tone-7: :skin-tone-3:",
+            ":wave::skin-tone-",
+            ":wave::skin:wave:",
+            "ünïcödé :crab:ü",
+        ];
+
+        for input in &inputs {
+            let output: String = input.chars().emojify().collect();
+            assert_eq!(parse_text(input), output, "input: {:?}", input);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn emojify_is_lazy() {
+        // The plain text must be yielded before the end of the (here endless) input
+        let mut chars = "ab :crab: "
+            .chars()
+            .chain(core::iter::repeat('x'))
+            .emojify();
+
+        assert_eq!(Some('a'), chars.next());
+        assert_eq!(Some('b'), chars.next());
+        assert_eq!(Some(' '), chars.next());
+        assert_eq!(Some('🦀'), chars.next());
+        assert_eq!(Some(' '), chars.next());
+        assert_eq!(Some('x'), chars.next());
+    }
+
+    #[test]
+    fn parser_skin_tones() {
+        let input = ":wave::skin-tone-2: :woman_technologist::skin-tone-6::+1:";
+        let output = "👋🏻 👩🏿‍💻👍";
+
+        let parser = EmojiTextParser::new(input);
+
+        assert_eq!(output, &parser.collect::<String>());
+    }
+
+    #[test]
+    fn parser_skin_tones_unsupported() {
+        // Crabs have no skin tones, and there is no seventh tone
+        let input = ":crab::skin-tone-3: :wave::skin-tone-7: :skin-tone-3:";
+        let output = "🦀:skin-tone-3: 👋:skin-tone-7: :skin-tone-3:";
+
+        let parser = EmojiTextParser::new(input);
+
+        assert_eq!(output, &parser.collect::<String>());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn demojize_roundtrip() {
+        let input = "Hi 👋🏿, I am a 👩🏽‍💻 🖐🏼";
+
+        assert_eq!(input, parse_text(&demojize(input)));
+        assert!(is_roundtrip_safe(&demojize(input)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn roundtrip_safe_aliases() {
+        assert!(is_roundtrip_safe(""));
+        assert!(is_roundtrip_safe(":thumbs_up: :family_man_woman_girl:"));
+        assert!(!is_roundtrip_safe(":+1:"));
+        assert!(!is_roundtrip_safe(
+            ":waving_hand::skin-tone-6::waving_hand:👋"
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn demojize_test() {
+        assert_eq!(":crab: and :alien:", demojize("🦀 and 👽"));
+        assert_eq!(":thumbs_up::thumbs_up:", demojize("👍👍"));
+        assert_eq!("Neither :) nor <3", demojize("Neither :) nor <3"));
+        assert_eq!("", demojize(""));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn demojize_zwj() {
+        assert_eq!(":family_man_woman_girl:", demojize("👨‍👩‍👧"));
+        assert_eq!(":man::woman::girl:", demojize("👨👩👧"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn demojize_adjacent() {
+        assert_eq!(":waving_hand::waving_hand:", demojize("👋👋"));
+        assert_eq!(":family_man_woman_girl::waving_hand:", demojize("👨‍👩‍👧👋"));
+        assert_eq!(":man::family_man_woman_girl:", demojize("👨👨‍👩‍👧"));
+        assert_eq!(":waving_hand::skin-tone-6::waving_hand:", demojize("👋🏿👋"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn demojize_adjacent_flags() {
+        assert_eq!(":flag_germany::flag_turkey:", demojize("🇩🇪🇹🇷"));
+        assert_eq!(":waving_hand::flag_germany:", demojize("👋🇩🇪"));
+        assert_eq!(":flag_germany::waving_hand:", demojize("🇩🇪👋"));
+        assert_eq!(":flag_england::pirate_flag:", demojize("🏴󠁧󠁢󠁥󠁮󠁧󠁿🏴‍☠️"));
+        // An unpaired regional indicator is kept as it is
+        assert_eq!(":flag_united_states:🇪", demojize("🇺🇸🇪"));
+        // Regional indicators pair up from the start, and 🇺🇩 is no flag, thus neither is 🇩🇪
+        assert_eq!("🇺🇩🇪", demojize("🇺🇩🇪"));
+        assert_eq!("🇺🇩:flag_germany:", demojize("🇺🇩🇩🇪"));
+        assert_eq!("a🇺🇩🇪 :flag_germany:", demojize("a🇺🇩🇪 🇩🇪"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn demojize_tones() {
+        assert_eq!(":waving_hand::skin-tone-2:", demojize("👋🏻"));
+        assert_eq!(":woman_technologist::skin-tone-4:", demojize("👩🏽‍💻"));
+        assert_eq!(":hand_with_fingers_splayed::skin-tone-3:", demojize("🖐🏼"));
+        // Two different tones can not be expressed via aliases
+        assert_eq!("🧑🏻‍🤝‍🧑🏿", demojize("🧑🏻‍🤝‍🧑🏿"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn strip_emojis_test() {
+        assert_eq!("Hi  & ", strip_emojis("Hi 👨‍👩‍👧 & 👋🏿", false));
+        assert_eq!("Hi & ", strip_emojis("Hi 👨‍👩‍👧 & 👋🏿", true));
+        assert_eq!("Flags: ", strip_emojis("Flags: 🇩🇪🇹🇷🏴‍☠️", false));
+        assert_eq!("no emojis :)", strip_emojis("no emojis :)", true));
+        assert_eq!("", strip_emojis("🦀🦀🦀", false));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn strip_emojis_collapse() {
+        // Only whitespace that doubles because of a removed emoji is dropped
+        assert_eq!("a  b c", strip_emojis("a  b 🦀 c", true));
+        assert_eq!("a\nb", strip_emojis("a\n🦀 b", true));
+        assert_eq!("b", strip_emojis("🦀 b", true));
+        assert_eq!(" b", strip_emojis("🦀 b", false));
+    }
+
+    #[test]
+    fn emoji_scanner_test() {
+        use crate::flat::CRAB;
+
+        assert_eq!(None, EmojiScanner::new("").next());
+        assert_eq!(
+            vec![
+                Fragment::Emoji(&CRAB),
+                Fragment::Emoji(&CRAB),
+                Fragment::Text(" 1 ü "),
+                Fragment::Emoji(&CRAB),
+            ],
+            EmojiScanner::new("🦀🦀 1 ü 🦀").collect::<Vec<_>>()
+        );
+
+        // The fragments make up the entire text
+        let text = "a 👋🏿 and a 👩🏽‍💻, 🇩🇪🇹🇷 and 👨‍👩‍👧 👨 👧 :)";
+        let fragments: Vec<_> = EmojiScanner::new(text).collect();
+        assert_eq!(
+            text,
+            fragments.iter().map(|f| f.as_str()).collect::<String>()
+        );
+        assert!(fragments.iter().all(|f| !f.as_str().is_empty()));
+        assert!(fragments
+            .windows(2)
+            .all(|w| { !matches!((w[0], w[1]), (Fragment::Text(_), Fragment::Text(_))) }));
+    }
+
+    #[test]
+    fn count_emojis_test() {
+        assert_eq!(0, count_emojis(""));
+        assert_eq!(0, count_emojis("no emojis :)"));
+        assert_eq!(3, count_emojis("🦀🦀🦀"));
+        assert_eq!(2, count_emojis("a 👋🏿 and a 👩🏽‍💻"));
+    }
+
+    #[test]
+    fn count_emojis_zwj() {
+        assert_eq!(1, count_emojis("👨‍👩‍👧"));
+        assert_eq!(2, count_emojis("👨‍👩‍👧👨‍👩‍👧‍👦"));
+        assert_eq!(3, count_emojis("👨‍👩‍👧 👨 👧"));
+        assert_eq!(1, count_emojis("🧑🏻‍❤️‍🧑🏿"));
+    }
+
+    #[test]
+    fn count_emojis_flags() {
+        assert_eq!(2, count_emojis("🇩🇪🇹🇷"));
+        assert_eq!(1, count_emojis("🏴‍☠️"));
+        assert_eq!(1, count_emojis("🏴󠁧󠁢󠁳󠁣󠁴󠁿"));
+    }
+
+    #[test]
+    fn contains_emoji_test() {
+        assert!(!contains_emoji(""));
+        assert!(!contains_emoji("no emojis :) <3"));
+        assert!(contains_emoji("🦀"));
+        assert!(contains_emoji("the end 👨‍👩‍👧"));
+        assert!(contains_emoji("flag: 🇹🇷!"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn levenshtein_test() {
+        let mut row = Vec::new();
+        assert_eq!(0, levenshtein("crab", "crab", &mut row));
+        assert_eq!(0, levenshtein("CRAB", "crab", &mut row));
+        assert_eq!(1, levenshtein("crb", "crab", &mut row));
+        assert_eq!(3, levenshtein("kitten", "sitting", &mut row));
+        assert_eq!(4, levenshtein("", "crab", &mut row));
+        assert_eq!(4, levenshtein("crab", "", &mut row));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn suggest_aliases_test() {
+        assert_eq!(vec!["technologist"], suggest_aliases("tchnologist", 5));
+        assert_eq!(vec!["crab"], suggest_aliases(":crab:", 1));
+        assert!(suggest_aliases(":rustaceans_are_no_emojis:", 5).is_empty());
+        assert!(suggest_aliases(":cat:", 0).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn suggest_aliases_ties() {
+        let suggestions = suggest_aliases(":cay:", 10);
+        assert_eq!(["car", "cat"], suggestions[..2]);
+        assert!(suggestions.len() <= 10);
+
+        let mut row = Vec::new();
+        assert!(suggestions.windows(2).all(|w| {
+            let d = (
+                levenshtein("cay", w[0], &mut row),
+                levenshtein("cay", w[1], &mut row),
+            );
+            d.0 < d.1 || (d.0 == d.1 && w[0] < w[1])
+        }));
+    }
+
     #[test]
     fn parse_alias_test() {
         assert_eq!(
@@ -0,0 +1,26 @@
+// Code generated by github.com/orhanbalci/emojic/emojic-gen DO NOT EDIT.
+
+// Source: https://unicode.org/Public/emoji/15.1/emoji-test.txt (up to E14.0, names and groups of E13.1 kept)
+// Created at: 2026-10-16 13:54:22.465953212 UTC
+
+use crate::emojis::Version;
+
+/// The number of emojis of this crate (including all their variants) per Unicode Emoji version
+/// in which they were introduced, sorted by version.
+pub(crate) static VERSION_HISTOGRAM: &[(Version, usize)] = &[
+	(Version(0, 6), 719),
+	(Version(0, 7), 139),
+	(Version(1, 0), 490),
+	(Version(2, 0), 286),
+	(Version(3, 0), 157),
+	(Version(4, 0), 598),
+	(Version(5, 0), 239),
+	(Version(11, 0), 161),
+	(Version(12, 0), 230),
+	(Version(12, 1), 168),
+	(Version(13, 0), 117),
+	(Version(13, 1), 217),
+	(Version(14, 0), 112),
+];
+
+// EOF
@@ -0,0 +1,116 @@
+//! ZWJ sequence utilities
+//!
+//! Many emojis (e.g. 🏴‍☠️ or 👩‍🔬) are sequences of other emojis joined by the zero width joiner
+//! (`U+200D`). Besides the constants of such sequences, they can be composed ad-hoc from their
+//! parts.
+
+use core::convert::TryFrom;
+
+use alloc::string::String;
+
+use crate::emojis::Emoji;
+use crate::Tone;
+
+/// The zero width joiner, which glues the parts of a sequence together.
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+/// The variation selector requesting the emoji presentation of the preceding char.
+const VARIATION_SELECTOR: char = '\u{FE0F}';
+
+/// Generate an ad-hoc ZWJ sequence from the given emojis.
+///
+/// The graphemes of the `parts` are joined by the zero width joiner (`U+200D`). Since the
+/// graphemes of this crate are fully-qualified, each part keeps its `U+FE0F` if it needs one
+/// (e.g. ☠️). Skin tone modifiers (e.g. [`LIGHT_SKIN_TONE`](crate::flat::components::LIGHT_SKIN_TONE))
+/// are not joined, but directly appended to the preceding part, replacing its `U+FE0F`, if any.
+///
+/// Just like [`country_flag`](crate::country_flag), this function does not validate whether the
+/// result is a sequence sanctioned by Unicode, thus it may well be rendered as its separate
+/// parts.
+///
+/// # Examples
+/// ```
+/// use emojic::flat::{SKULL_AND_CROSSBONES, BLACK_FLAG, PIRATE_FLAG};
+/// use emojic::zwj_sequence;
+///
+/// assert_eq!(
+///     zwj_sequence(&[&BLACK_FLAG, &SKULL_AND_CROSSBONES]), // 🏴‍☠️
+///     PIRATE_FLAG.to_string()
+/// );
+/// ```
+///
+/// ```
+/// use emojic::flat::components::{MEDIUM_SKIN_TONE, RED_HAIR};
+/// use emojic::flat::PERSON;
+/// use emojic::{zwj_sequence, Gender, Hair, Tone};
+///
+/// let woman = PERSON.gender(Gender::Female);
+/// assert_eq!(
+///     zwj_sequence(&[woman, &MEDIUM_SKIN_TONE, &RED_HAIR]), // 👩🏽‍🦰
+///     PERSON.hair(Hair::Red).gender(Gender::Female).tone(Tone::Medium).to_string()
+/// );
+/// ```
+#[cfg_attr(feature = "doc_cfg", doc(cfg(feature = "alloc")))]
+pub fn zwj_sequence(parts: &[&Emoji]) -> String {
+    let mut sequence = String::new();
+    for (i, part) in parts.iter().enumerate() {
+        let mut chars = part.grapheme.chars();
+        let is_modifier = match (chars.next(), chars.next()) {
+            (Some(c), None) => Tone::try_from(c).is_ok(),
+            _ => false,
+        };
+
+        if is_modifier {
+            if sequence.ends_with(VARIATION_SELECTOR) {
+                sequence.pop();
+            }
+        } else if i > 0 {
+            sequence.push(ZERO_WIDTH_JOINER);
+        }
+        sequence.push_str(part.grapheme);
+    }
+    sequence
+}
+
+#[cfg(test)]
+mod tests {
+    // Tests are going to be on development systems => there will be std.
+    extern crate std;
+    use std::prelude::v1::*;
+
+    use super::*;
+
+    #[test]
+    fn rebuild_zwj_emojis() {
+        let mut rebuilt = 0;
+        for emoji in crate::search::all() {
+            // Those sequences whose parts are emojis on their own
+            let parts: Option<Vec<&Emoji>> = emoji
+                .grapheme
+                .split(ZERO_WIDTH_JOINER)
+                .map(|part| crate::lookup(part).filter(|e| e.grapheme == part))
+                .collect();
+            match parts {
+                Some(parts) if parts.len() > 1 => {
+                    assert_eq!(emoji.grapheme, zwj_sequence(&parts));
+                    rebuilt += 1;
+                }
+                _ => {}
+            }
+        }
+        assert!(rebuilt > 1000);
+    }
+
+    #[test]
+    fn trivial_sequences() {
+        use crate::flat::components::DARK_SKIN_TONE;
+        use crate::flat::{CRAB, WAVING_HAND};
+
+        assert_eq!("", zwj_sequence(&[]));
+        assert_eq!("🦀", zwj_sequence(&[&CRAB]));
+        assert_eq!("🦀\u{200D}🦀", zwj_sequence(&[&CRAB, &CRAB]));
+        assert_eq!(
+            WAVING_HAND.tone(Tone::Dark).grapheme,
+            zwj_sequence(&[&WAVING_HAND, &DARK_SKIN_TONE])
+        );
+    }
+}